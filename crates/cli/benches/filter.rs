@@ -71,6 +71,7 @@ fn create_issue(created_at: chrono::DateTime<Utc>) -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -76,6 +76,7 @@ fn create_test_issue(id: &str) -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -14,7 +14,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ("COLOR", "COLOR"),
         ("WOK_STATE_DIR", "WOK_STATE_DIR"),
         ("XDG_STATE_HOME", "XDG_STATE_HOME"),
+        ("XDG_CONFIG_HOME", "XDG_CONFIG_HOME"),
         ("WOK_DAEMON_BINARY", "WOK_DAEMON_BINARY"),
+        ("WOK_ERROR_FORMAT", "WOK_ERROR_FORMAT"),
+        ("WOK_TRACE_JSON", "WOK_TRACE_JSON"),
+        ("LC_ALL", "LC_ALL"),
+        ("LC_MESSAGES", "LC_MESSAGES"),
+        ("LANG", "LANG"),
+        ("COLUMNS", "COLUMNS"),
+        ("PAGER", "PAGER"),
+        ("BROWSER", "BROWSER"),
+        ("USER", "USER"),
+        ("PATH", "PATH"),
+        ("FORCE_HYPERLINK", "FORCE_HYPERLINK"),
+        ("TERM", "TERM"),
+        ("TERM_PROGRAM", "TERM_PROGRAM"),
+        ("VTE_VERSION", "VTE_VERSION"),
+        ("KONSOLE_VERSION", "KONSOLE_VERSION"),
+        ("WT_SESSION", "WT_SESSION"),
     ];
 
     for (const_name, env_name) in &vars {
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Issue aging against the `[aging]` thresholds.
+//!
+//! An issue's age is the time since it entered its current status: its last
+//! status change, or its creation when it never changed. Past the threshold
+//! for that status, it is stale. Going stale records a `stale` event once
+//! per stay in a status, which fires `issue.stale` hooks.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::config::AgingConfig;
+use crate::db::Database;
+use crate::error::Result;
+use crate::models::{Action, Event, Issue};
+
+/// An issue past the aging threshold for its status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Stale {
+    /// How long the issue has been in its status.
+    pub age: Duration,
+    /// The threshold it passed.
+    pub limit: Duration,
+}
+
+impl Stale {
+    /// How `issue`, in its status since `since`, has aged by `now`; `None`
+    /// while it is within its threshold or its status has none.
+    pub(crate) fn check(
+        config: &AgingConfig,
+        issue: &Issue,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Option<Stale> {
+        let limit = config.threshold(issue.status)?;
+        let age = now - since;
+        (age > limit).then_some(Stale { age, limit })
+    }
+}
+
+impl fmt::Display for Stale {
+    /// `stale 9d`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stale {}", format_age(self.age))
+    }
+}
+
+/// An age in its largest whole unit: `3w`, `9d`, `5h`, `12m`.
+pub(crate) fn format_age(age: Duration) -> String {
+    if age.num_weeks() >= 2 {
+        format!("{}w", age.num_weeks())
+    } else if age.num_days() >= 1 {
+        format!("{}d", age.num_days())
+    } else if age.num_hours() >= 1 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}m", age.num_minutes().max(0))
+    }
+}
+
+/// When each of `issues` entered its current status.
+pub(crate) fn status_since(
+    db: &Database,
+    issues: &[&Issue],
+) -> Result<HashMap<String, DateTime<Utc>>> {
+    let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    let changed = db.get_status_changed_at_batch(&ids)?;
+    Ok(issues
+        .iter()
+        .map(|issue| {
+            let since = changed.get(&issue.id).copied().unwrap_or(issue.created_at);
+            (issue.id.clone(), since)
+        })
+        .collect())
+}
+
+/// The stale issues among `issues`, by ID.
+pub(crate) fn stale_issues(
+    db: &Database,
+    config: &AgingConfig,
+    issues: &[&Issue],
+    now: DateTime<Utc>,
+) -> Result<HashMap<String, Stale>> {
+    if !config.is_set() {
+        return Ok(HashMap::new());
+    }
+    let since = status_since(db, issues)?;
+    Ok(issues
+        .iter()
+        .filter_map(|issue| {
+            let stale = Stale::check(config, issue, *since.get(&issue.id)?, now)?;
+            Some((issue.id.clone(), stale))
+        })
+        .collect())
+}
+
+/// Log a `stale` event for each of `issues` that is stale at `now` and has
+/// not been marked since entering its status. Returns the new events, which
+/// record the status as the old value and the age as the new one.
+pub(crate) fn mark_stale(
+    db: &Database,
+    config: &AgingConfig,
+    issues: &[&Issue],
+    now: DateTime<Utc>,
+) -> Result<Vec<Event>> {
+    let since = status_since(db, issues)?;
+    let mut marked = Vec::new();
+    for issue in issues {
+        let Some(&entered) = since.get(&issue.id) else {
+            continue;
+        };
+        let Some(stale) = Stale::check(config, issue, entered, now) else {
+            continue;
+        };
+        let already = db
+            .get_events(&issue.id)?
+            .iter()
+            .any(|e| e.action == Action::Stale && e.created_at >= entered);
+        if already {
+            continue;
+        }
+        let event = Event::new(issue.id.clone(), Action::Stale)
+            .with_values(Some(issue.status.to_string()), Some(format_age(stale.age)))
+            .with_timestamp(now);
+        db.log_event(&event)?;
+        marked.push(event);
+    }
+    Ok(marked)
+}
+
+#[cfg(test)]
+#[path = "aging_tests.rs"]
+mod tests;
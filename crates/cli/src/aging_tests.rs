@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use chrono::Duration;
+use yare::parameterized;
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+fn aging(todo: Option<&str>, in_progress: Option<&str>) -> AgingConfig {
+    AgingConfig {
+        todo: todo.map(String::from),
+        in_progress: in_progress.map(String::from),
+    }
+}
+
+#[parameterized(
+    minutes = { Duration::minutes(12), "12m" },
+    hours = { Duration::minutes(300), "5h" },
+    days = { Duration::hours(9 * 24 + 3), "9d" },
+    one_week_in_days = { Duration::days(10), "10d" },
+    weeks = { Duration::days(22), "3w" },
+    negative = { Duration::minutes(-5), "0m" },
+)]
+fn formats_age_in_largest_whole_unit(age: Duration, expected: &str) {
+    assert_eq!(format_age(age), expected);
+}
+
+#[test]
+fn stale_only_past_the_threshold_for_the_status() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Waiting")
+        .create_and_start("test-2", IssueType::Task, "Underway");
+    let waiting = ctx.db.get_issue("test-1").unwrap();
+    let underway = ctx.db.get_issue("test-2").unwrap();
+    let config = aging(None, Some("7d"));
+    let now = Utc::now();
+
+    let within = Stale::check(&config, &underway, now - Duration::days(6), now);
+    assert_eq!(within, None);
+    let past = Stale::check(&config, &underway, now - Duration::days(9), now).unwrap();
+    assert_eq!(past.limit, Duration::days(7));
+    assert_eq!(past.to_string(), "stale 9d");
+    // No todo threshold, however long it waits
+    assert_eq!(
+        Stale::check(&config, &waiting, now - Duration::days(90), now),
+        None
+    );
+}
+
+#[test]
+fn age_counts_from_the_last_status_change() {
+    let mut ctx = TestContext::new();
+    ctx.create_and_start("test-1", IssueType::Task, "Restarted");
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    let config = aging(Some("3d"), Some("3d"));
+    let later = Utc::now() + Duration::days(5);
+
+    let stale = stale_issues(&ctx.db, &config, &[&issue], later).unwrap();
+    assert!(stale.contains_key("test-1"));
+
+    let restarted =
+        Event::new("test-1".to_string(), Action::Started).with_timestamp(later - Duration::days(1));
+    ctx.db.log_event(&restarted).unwrap();
+    let stale = stale_issues(&ctx.db, &config, &[&issue], later).unwrap();
+    assert!(stale.is_empty());
+}
+
+#[test]
+fn unset_thresholds_mark_nothing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Old");
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    let later = Utc::now() + Duration::days(365);
+
+    let stale = stale_issues(&ctx.db, &AgingConfig::default(), &[&issue], later).unwrap();
+    assert!(stale.is_empty());
+    assert!(!aging(Some("soon"), None).is_set());
+}
+
+#[test]
+fn mark_stale_logs_once_per_stay_in_a_status() {
+    let mut ctx = TestContext::new();
+    ctx.create_and_start("test-1", IssueType::Task, "Stuck");
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    let config = aging(None, Some("7d"));
+    let later = Utc::now() + Duration::days(8);
+
+    let marked = mark_stale(&ctx.db, &config, &[&issue], later).unwrap();
+    assert_eq!(marked.len(), 1);
+    assert_eq!(marked[0].action, Action::Stale);
+    assert_eq!(marked[0].old_value.as_deref(), Some("in_progress"));
+    assert_eq!(marked[0].new_value.as_deref(), Some("8d"));
+
+    let again = mark_stale(&ctx.db, &config, &[&issue], later + Duration::days(1)).unwrap();
+    assert!(again.is_empty());
+
+    // Started again: a new stay, which can go stale anew
+    let restarted =
+        Event::new("test-1".to_string(), Action::Started).with_timestamp(later + Duration::days(2));
+    ctx.db.log_event(&restarted).unwrap();
+    let anew = mark_stale(&ctx.db, &config, &[&issue], later + Duration::days(10)).unwrap();
+    assert_eq!(anew.len(), 1);
+}
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! User-defined subcommands from the `[alias]` table.
+//!
+//! ```toml
+//! [alias]
+//! wip = "list -s in_progress --assignee @me"
+//! ```
+//!
+//! Aliases are expanded before clap parses the command line, as git does:
+//! `wok wip -o json` runs `wok list -s in_progress --assignee @me -o json`.
+//! An alias may expand to another alias but never back to itself, and
+//! built-in commands cannot be shadowed.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use clap::CommandFactory;
+
+use crate::cli::Cli;
+use crate::error::{Error, Result};
+
+/// Global options whose value is a separate word, which may come before
+/// the alias name.
+const VALUE_OPTIONS: [&str; 3] = ["-C", "--directory", "--error-format"];
+
+/// Whether `name` is a built-in command, which no alias may replace.
+pub fn is_builtin(name: &str) -> bool {
+    name == "help" || Cli::command().find_subcommand(name).is_some()
+}
+
+/// Replace the command word of `args` (program name first) while it names
+/// an alias.
+pub fn expand(mut args: Vec<String>, aliases: &BTreeMap<String, String>) -> Result<Vec<String>> {
+    let Some(pos) = command_position(&args) else {
+        return Ok(args);
+    };
+    let mut chain: Vec<String> = Vec::new();
+    while let Some(expansion) = aliases.get(&args[pos]) {
+        let name = args[pos].clone();
+        if is_builtin(&name) {
+            break;
+        }
+        if chain.contains(&name) {
+            chain.push(name);
+            return Err(Error::AliasLoop {
+                chain: chain.join(" -> "),
+            });
+        }
+        let words = parse(&name, expansion)?;
+        chain.push(name);
+        args.splice(pos..=pos, words);
+    }
+    Ok(args)
+}
+
+/// The words an alias expands to.
+///
+/// Words are split on whitespace; single and double quotes group words and
+/// a backslash escapes the next character, as in a shell.
+pub(crate) fn parse(name: &str, expansion: &str) -> Result<Vec<String>> {
+    let invalid = |reason: &str| Error::InvalidAlias {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    };
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+    let mut chars = expansion.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None | Some('"'), '\\') => {
+                word.push(chars.next().ok_or_else(|| invalid("trailing backslash"))?);
+                in_word = true;
+            }
+            (Some(_), c) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if quote.is_some() {
+        return Err(invalid("unbalanced quotes"));
+    }
+    if in_word {
+        words.push(word);
+    }
+    if words.is_empty() {
+        return Err(invalid("expands to nothing"));
+    }
+    Ok(words)
+}
+
+/// Index of the first word that is not a global option or its value.
+fn command_position(args: &[String]) -> Option<usize> {
+    let mut i = 1;
+    while let Some(arg) = args.get(i) {
+        if VALUE_OPTIONS.contains(&arg.as_str()) {
+            i += 2;
+        } else if arg.starts_with('-') {
+            i += 1;
+        } else {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// The `-C` / `--directory` value, if given before the command.
+pub(crate) fn directory(args: &[String]) -> Option<PathBuf> {
+    let end = command_position(args).unwrap_or(args.len());
+    let mut globals = args.iter().take(end).skip(1);
+    while let Some(arg) = globals.next() {
+        if arg == "-C" || arg == "--directory" {
+            return globals.next().map(PathBuf::from);
+        }
+        if let Some(dir) = arg
+            .strip_prefix("--directory=")
+            .or_else(|| arg.strip_prefix("-C="))
+            .or_else(|| arg.strip_prefix("-C"))
+        {
+            return Some(PathBuf::from(dir));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "alias_tests.rs"]
+mod tests;
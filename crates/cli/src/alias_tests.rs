@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use yare::parameterized;
+
+fn words(line: &str) -> Vec<String> {
+    line.split(' ').map(String::from).collect()
+}
+
+fn aliases(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(name, expansion)| (name.to_string(), expansion.to_string()))
+        .collect()
+}
+
+#[parameterized(
+    plain = { "wok wip", "wok list -s in_progress" },
+    keeps_trailing_args = { "wok wip -o json", "wok list -s in_progress -o json" },
+    after_globals = { "wok --quiet -C /tmp wip", "wok --quiet -C /tmp list -s in_progress" },
+    chained = { "wok mine-wip", "wok list -s in_progress -a @me" },
+    unknown_left_alone = { "wok wipe", "wok wipe" },
+    builtin_not_shadowed = { "wok list", "wok list" },
+    no_command = { "wok --quiet", "wok --quiet" },
+)]
+fn expands_command_word(line: &str, expected: &str) {
+    let table = aliases(&[
+        ("wip", "list -s in_progress"),
+        ("mine-wip", "wip -a @me"),
+        ("list", "ready"),
+    ]);
+    assert_eq!(expand(words(line), &table).unwrap(), words(expected));
+}
+
+#[test]
+fn only_the_command_word_is_expanded() {
+    let table = aliases(&[("wip", "list -s in_progress")]);
+    assert_eq!(
+        expand(words("wok show wip"), &table).unwrap(),
+        words("wok show wip")
+    );
+}
+
+#[parameterized(
+    self_loop = { &[("a", "a -s todo")], "a -> a" },
+    cycle = { &[("a", "b"), ("b", "a")], "a -> b -> a" },
+)]
+fn loops_are_rejected(entries: &[(&str, &str)], chain: &str) {
+    let err = expand(words("wok a"), &aliases(entries)).unwrap_err();
+    match err {
+        Error::AliasLoop { chain: found } => assert_eq!(found, chain),
+        other => panic!("unexpected error: {}", other),
+    }
+}
+
+#[parameterized(
+    single_quotes = { "new 'Fix login' -l a", &["new", "Fix login", "-l", "a"] },
+    double_quotes = { r#"new "say \"hi\"""#, &["new", "say \"hi\""] },
+    escaped_space = { r"new Fix\ login", &["new", "Fix login"] },
+    extra_spaces = { "  list   -s todo ", &["list", "-s", "todo"] },
+    empty_quotes = { "new ''", &["new", ""] },
+)]
+fn parse_splits_like_a_shell(expansion: &str, expected: &[&str]) {
+    assert_eq!(parse("x", expansion).unwrap(), expected);
+}
+
+#[parameterized(
+    empty = { "" },
+    blank = { "   " },
+    unbalanced = { "new 'Fix" },
+    trailing_backslash = { r"list \" },
+)]
+fn parse_rejects(expansion: &str) {
+    assert!(matches!(
+        parse("x", expansion),
+        Err(Error::InvalidAlias { .. })
+    ));
+}
+
+#[parameterized(
+    separate = { "wok -C dir wip", Some("dir") },
+    long = { "wok --directory dir wip", Some("dir") },
+    equals = { "wok --directory=dir wip", Some("dir") },
+    attached = { "wok -Cdir wip", Some("dir") },
+    after_command = { "wok wip -C dir", None },
+    absent = { "wok wip", None },
+)]
+fn directory_is_read_from_globals(line: &str, expected: Option<&str>) {
+    assert_eq!(directory(&words(line)), expected.map(PathBuf::from));
+}
+
+#[test]
+fn builtins_include_help() {
+    assert!(is_builtin("list"));
+    assert!(is_builtin("help"));
+    assert!(!is_builtin("wip"));
+}
@@ -6,8 +6,12 @@
 //! These structs are used with `#[command(flatten)]` to reduce duplication
 //! across commands that share common filter patterns.
 
+use std::num::NonZeroUsize;
+
 use clap::Args;
 
+use super::SortKey;
+
 /// Common filter arguments for type, label, and prefix filtering.
 #[derive(Args, Clone, Debug, Default)]
 pub struct TypeLabelArgs {
@@ -34,6 +38,21 @@ pub struct AssigneeArgs {
     /// Show only unassigned issues
     #[arg(long, conflicts_with = "assignee")]
     pub unassigned: bool,
+
+    /// Show only issues assigned to you (same as --assignee @me)
+    #[arg(long, conflicts_with_all = ["assignee", "unassigned"])]
+    pub mine: bool,
+}
+
+impl AssigneeArgs {
+    /// Assignee filters, with `--mine` expanded to `@me`.
+    pub fn assignees(&self) -> Vec<String> {
+        if self.mine {
+            vec![crate::commands::assign::ME.to_string()]
+        } else {
+            self.assignee.clone()
+        }
+    }
 }
 
 /// Limit arguments for paginated results.
@@ -46,3 +65,27 @@ pub struct LimitArgs {
     #[arg(long, conflicts_with = "limit")]
     pub no_limit: bool,
 }
+
+/// Keyset pagination arguments for `-o json-paged` on list and search.
+#[derive(Args, Clone, Debug, Default)]
+pub struct PageArgs {
+    /// Continue after a previous page (its next_cursor), with -o json-paged
+    #[arg(long, value_name = "token")]
+    pub cursor: Option<String>,
+
+    /// Issues per page with -o json-paged (default: 100)
+    #[arg(long, value_name = "n")]
+    pub page_size: Option<NonZeroUsize>,
+}
+
+/// Sort arguments shared by list, ready, and search.
+#[derive(Args, Clone, Debug, Default)]
+pub struct SortArgs {
+    /// Sort by created, updated, priority, title, or due (default: the command's own order)
+    #[arg(long, value_enum, value_name = "key")]
+    pub sort: Option<SortKey>,
+
+    /// Reverse the --sort order
+    #[arg(long, requires = "sort")]
+    pub reverse: bool,
+}
@@ -8,7 +8,7 @@ use crate::help;
 use clap::{Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 
-pub use args::{AssigneeArgs, LimitArgs, TypeLabelArgs};
+pub use args::{AssigneeArgs, LimitArgs, PageArgs, SortArgs, TypeLabelArgs};
 
 /// Parse a string that must not be empty or whitespace-only.
 fn non_empty_string(s: &str) -> Result<String, String> {
@@ -29,6 +29,168 @@ pub enum OutputFormat {
     Id,
 }
 
+/// Output format for list and search, which add paged and streamed JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ListFormat {
+    #[default]
+    Text,
+    Json,
+    /// `{"issues": [...], "next_cursor": ...}`, one page at a time
+    JsonPaged,
+    /// One JSON object per line, written as issues are read
+    Ndjson,
+    #[value(alias = "ids")]
+    Id,
+}
+
+/// Output format for `wok log`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    /// One JSON event per line, newest first
+    Ndjson,
+}
+
+/// Output format for `wok digest`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum DigestFormat {
+    /// Headed sections, ready to paste into chat or email
+    #[default]
+    Markdown,
+    Json,
+}
+
+/// Output format for `wok standup`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum StandupFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Stdout format for `wok export -o`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON issue record per line, the same records as the export file
+    Ndjson,
+}
+
+/// Record layout for `wok export --format`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum RecordFormat {
+    /// wok records, read back by `wok import`
+    #[default]
+    Wok,
+    /// beads records, for `bd import` (and `wok import --format bd`)
+    Bd,
+}
+
+/// What `wok import` does with a record that matches an existing issue.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OnConflict {
+    /// Leave the existing issue as it is
+    Skip,
+    /// Update the existing issue from the record
+    #[default]
+    Update,
+    /// Create a new issue with a fresh ID
+    Duplicate,
+}
+
+/// How command failures are reported on stderr.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ErrorFormat {
+    /// `error: <message>` with hint lines
+    #[default]
+    Text,
+    /// One JSON object with code, message, ids and suggestions
+    Json,
+}
+
+impl ErrorFormat {
+    /// The `--error-format` flag if given, else `WOK_ERROR_FORMAT`, else text.
+    pub fn resolve(flag: Option<ErrorFormat>) -> ErrorFormat {
+        flag.or_else(|| {
+            crate::env::error_format().and_then(|v| ErrorFormat::from_str(&v, true).ok())
+        })
+        .unwrap_or_default()
+    }
+}
+
+/// Ordering for ready and list output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Order {
+    /// The command's usual order
+    #[default]
+    Default,
+    /// Issues whose completion unblocks the most open work first
+    Topo,
+}
+
+/// Dependency edges `wok tree` follows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum TreeRel {
+    /// Issues each issue blocks
+    Blocks,
+    /// Issues each issue tracks
+    Tracks,
+    /// Both
+    #[default]
+    All,
+}
+
+/// Where a project's database lives (`wok config mode`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StorageMode {
+    /// .wok/issues.db, no daemon
+    Private,
+    /// The user-level database served by the daemon
+    Shared,
+}
+
+/// Sort key for `--sort` on list, ready, and search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SortKey {
+    /// Newest first
+    Created,
+    /// Most recently updated first
+    Updated,
+    /// Highest priority (priority:0) first
+    Priority,
+    /// Alphabetical, ignoring case
+    Title,
+    /// Soonest due:YYYY-MM-DD label first; issues without one last
+    Due,
+}
+
+/// What `wok list --group-by` groups issues under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    Status,
+    Type,
+    Assignee,
+    /// The part of each label before `:` (an issue can land in several groups)
+    LabelPrefix,
+    /// The issue ID prefix
+    Prefix,
+}
+
+/// A column of `wok list --columns` output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ListColumn {
+    Id,
+    /// Short ID alias (prj-12)
+    Short,
+    Type,
+    Status,
+    Title,
+    Assignee,
+    Labels,
+    /// The issue's estimate (3pt, 2d)
+    Estimate,
+}
+
 #[derive(Parser)]
 #[command(name = "wok")]
 #[command(version = env!("CARGO_PKG_VERSION"))]
@@ -51,6 +213,22 @@ pub struct Cli {
     #[arg(short = 'C', long = "directory", global = true, value_name = "path")]
     pub directory: Option<String>,
 
+    /// Report errors as text or json (default: $WOK_ERROR_FORMAT, else text)
+    #[arg(long, global = true, value_name = "format")]
+    pub error_format: Option<ErrorFormat>,
+
+    /// Suppress normal output; only the exit code and errors remain
+    #[arg(long, global = true)]
+    pub quiet: bool,
+
+    /// Open the database read-only; reads never wait on other writers
+    #[arg(long, global = true)]
+    pub read_only: bool,
+
+    /// Print where the command spent its time to stderr when it finishes
+    #[arg(long, global = true)]
+    pub trace: bool,
+
     /// Print version
     #[arg(short = 'v', short_alias = 'V', long = "version", action = clap::ArgAction::Version)]
     version: (),
@@ -74,6 +252,7 @@ Examples:
   wok new idea \"Better caching\"          Create idea for future consideration
   wok new task \"Multi\" -l a,b,c          Create task with multiple labels
   wok new \"Task\" -a alice                Create task assigned to alice
+  wok new \"Task\" --estimate 3pt          Create task estimated at 3 points
   wok new bug \"Fix bug\" --blocks prj-1   Create bug that blocks prj-1
   wok new \"Task\" --tracked-by prj-feat   Create task tracked by a feature
   wok new task \"My task\" -o id           Create task, output only ID
@@ -107,6 +286,10 @@ Examples:
         #[arg(long, hide = true, value_parser = clap::value_parser!(u8).range(0..=4))]
         priority: Option<u8>,
 
+        /// Estimate, in points (3pt) or working time (4h, 2d); adds an estimate: label
+        #[arg(long)]
+        estimate: Option<String>,
+
         /// Add initial description note (hidden, use --note instead)
         #[arg(long, hide = true)]
         description: Option<String>,
@@ -134,6 +317,10 @@ Examples:
         /// Create issue with specific prefix (overrides config prefix)
         #[arg(long, short = 'p')]
         prefix: Option<String>,
+
+        /// Skip the check for open issues with similar titles
+        #[arg(long)]
+        force: bool,
     },
 
     /// Start work on issue(s) (todo -> in_progress)
@@ -142,6 +329,10 @@ Examples:
         /// Issue ID(s)
         #[arg(required = true)]
         ids: Vec<String>,
+
+        /// Fail unless the issue is still at this revision (see `wok show -o json`)
+        #[arg(long, value_name = "REV")]
+        if_match: Option<u32>,
     },
 
     /// Mark issue(s) as done (in_progress -> done, or todo -> done with reason)
@@ -162,6 +353,10 @@ Examples:
         /// Reason (required when transitioning from todo)
         #[arg(long, short)]
         reason: Option<String>,
+
+        /// Fail unless the issue is still at this revision (see `wok show -o json`)
+        #[arg(long, value_name = "REV")]
+        if_match: Option<u32>,
     },
 
     /// Close issue(s) without completing (requires reason for agent)
@@ -174,6 +369,10 @@ Examples:
         /// Reason for closing [required for agent]
         #[arg(long, short, value_name = "REASON")]
         reason: Option<String>,
+
+        /// Fail unless the issue is still at this revision (see `wok show -o json`)
+        #[arg(long, value_name = "REV")]
+        if_match: Option<u32>,
     },
 
     /// Return issue(s) to todo (in_progress, done, or closed -> todo)
@@ -186,6 +385,10 @@ Examples:
         /// Reason for reopening [required for agent, required from done/closed]
         #[arg(long, short, value_name = "REASON")]
         reason: Option<String>,
+
+        /// Fail unless the issue is still at this revision (see `wok show -o json`)
+        #[arg(long, value_name = "REV")]
+        if_match: Option<u32>,
     },
 
     /// Edit an issue's description, title, type, or assignee
@@ -196,7 +399,8 @@ Examples:
   wok edit prj-1 title \"New title\"                    Update title
   wok edit prj-1 type bug                               Change type to bug
   wok edit prj-1 assignee alice                         Assign to alice
-  wok edit prj-1 assignee none                          Clear assignment"),
+  wok edit prj-1 assignee none                          Clear assignment
+  wok edit prj-1 estimate 2d                            Estimate two days of work"),
         group = clap::ArgGroup::new("field_flags")
             .args(["flag_title", "flag_description", "flag_type", "flag_assignee"])
             .multiple(false)
@@ -205,7 +409,7 @@ Examples:
         /// Issue ID
         id: String,
 
-        /// Attribute to edit (title, description, type, assignee)
+        /// Attribute to edit (title, description, type, assignee, estimate)
         #[arg(conflicts_with_all = ["flag_title", "flag_description", "flag_type", "flag_assignee"])]
         attr: Option<String>,
 
@@ -238,6 +442,33 @@ Examples:
             id = "flag_assignee"
         )]
         flag_assignee: Option<String>,
+
+        /// Fail unless the issue is still at this revision (see `wok show -o json`)
+        #[arg(long, value_name = "REV")]
+        if_match: Option<u32>,
+    },
+
+    /// Assign an issue to someone
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok assign prj-1 alice          Assign to alice
+  wok assign prj-1 @me            Assign to yourself (user in config)")
+    )]
+    Assign {
+        /// Issue ID
+        id: String,
+
+        /// Assignee name, or @me for yourself
+        who: String,
+    },
+
+    /// Clear an issue's assignee
+    #[command(arg_required_else_help = true)]
+    Unassign {
+        /// Issue ID
+        id: String,
     },
 
     /// List issues
@@ -248,6 +479,7 @@ Examples:
   wok list -s done                List completed issues
   wok list -s todo --blocked      List blocked todo issues
   wok list -t bug                 List bugs only
+  wok list --mine                 List issues assigned to you
   wok list -l urgent              List issues with 'urgent' label
   wok list -l a -l b              List issues with label 'a' AND label 'b'
   wok list -l a,b -l c            List issues with (label 'a' OR 'b') AND label 'c'
@@ -255,17 +487,29 @@ Examples:
   wok list --unassigned           List unassigned issues
   wok list -q \"age < 3d\"          List issues created in last 3 days
   wok list -q \"updated > 1w\"      List issues not updated in 7+ days
+  wok list -q \"label = a or assignee != alice\"
+                                  Combine fields with and/or
   wok list --limit 10             Show only first 10 results
+  wok list --order topo           Show blockers before what they block
+  wok list --stale                Show issues stuck in their status too long
+  wok list --columns id,title     Show only the chosen columns
+  wok list --all --pager          Page long output through $PAGER
+  wok list --group-by assignee    Show issues under a header per assignee
+  wok list --sort updated         Most recently updated first
   wok list -o json                Output in JSON format
+  wok list -o json-paged --all    First page of all issues, oldest first
+  wok list --all -o ndjson | jq   Stream one JSON object per issue
   wok list -o id                  Output only IDs (space-separated)
 
 Filter Expressions (-q/--filter):
   Syntax: FIELD [OPERATOR VALUE]
-  Fields: age, activity, completed, skipped, closed
+  Fields: age, activity, completed, skipped, closed,
+          label, assignee, status, type, priority
   Status shortcuts: 'closed', 'skipped', 'completed' (no operator needed)
-  Operators: < <= > >= = != (or: lt lte gt gte eq ne)
+  Operators: < <= > >= = != (or: lt lte gt gte eq ne), in (a, b)
   Values: durations (30d, 1w, 24h, 5m, 10s), dates (2024-01-01), or 'now'
-  Duration units: ms, s, m, h, d, w, M (30d), y (365d)"))]
+  Duration units: ms, s, m, h, d, w, M (30d), y (365d)
+  Combine with 'and', 'or' and parentheses: \"label = urgent and priority <= 1\""))]
     List {
         /// Filter by status (comma-separated for OR, repeat for AND)
         #[arg(long, short)]
@@ -277,7 +521,7 @@ Filter Expressions (-q/--filter):
         #[command(flatten)]
         assignee_args: AssigneeArgs,
 
-        /// Filter expression (e.g., "age < 3d", "updated > 1w")
+        /// Filter expression (e.g., "age < 3d", "label = urgent and priority <= 1")
         #[arg(long = "filter", short = 'q')]
         filter: Vec<String>,
 
@@ -288,13 +532,39 @@ Filter Expressions (-q/--filter):
         #[arg(long)]
         blocked: bool,
 
+        /// Show only issues past their [aging] threshold
+        #[arg(long)]
+        stale: bool,
+
         /// Show all issues (ignore default status filter)
         #[arg(long)]
         all: bool,
 
-        /// Output format (text, json, id)
+        /// Order: default, or topo (issues that unblock the most work first)
+        #[arg(long, value_enum, default_value = "default")]
+        order: Order,
+
+        #[command(flatten)]
+        sorting: SortArgs,
+
+        /// Text columns to show, comma-separated (id, short, type, status, title, assignee, labels, estimate)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        columns: Vec<ListColumn>,
+
+        /// Page long output through $PAGER when writing to a terminal
+        #[arg(long)]
+        pager: bool,
+
+        /// Group issues: status, type, assignee, label-prefix, or prefix
+        #[arg(long, value_enum, value_name = "field")]
+        group_by: Option<GroupBy>,
+
+        #[command(flatten)]
+        paging: PageArgs,
+
+        /// Output format (text, json, json-paged, ndjson, id)
         #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
-        output: OutputFormat,
+        output: ListFormat,
     },
 
     /// Show ready issues (unblocked todo items)
@@ -305,7 +575,11 @@ Examples:
   wok ready -l urgent             Show ready urgent issues
   wok ready -a alice              Show ready issues assigned to alice
   wok ready --unassigned          Show only unassigned ready issues
-  wok ready --all-assignees       Show all ready issues regardless of assignment"))]
+  wok ready --all-assignees       Show all ready issues regardless of assignment
+  wok ready --order topo          Show issues that unblock the most work first
+  wok ready --sort due            Show the soonest due first
+  wok ready --explain             Also show why other open issues are not ready
+  wok ready --capacity 5pt        Plan what fits in 5 points, highest priority first"))]
     Ready {
         #[command(flatten)]
         type_label: TypeLabelArgs,
@@ -322,11 +596,50 @@ Examples:
         #[arg(long, conflicts_with = "assignee", conflicts_with = "unassigned")]
         all_assignees: bool,
 
+        /// Order: default, or topo (issues that unblock the most work first)
+        #[arg(long, value_enum, default_value = "default")]
+        order: Order,
+
+        #[command(flatten)]
+        sorting: SortArgs,
+
+        /// Also list open issues that are not ready, and why
+        #[arg(long)]
+        explain: bool,
+
+        /// Pick ready issues whose estimates fit this budget (5pt, 3d), by priority
+        #[arg(long, value_name = "ESTIMATE")]
+        capacity: Option<String>,
+
         /// Output format (text, json)
         #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
         output: OutputFormat,
     },
 
+    /// Pick the single best issue to work on next
+    #[command(after_help = colors::examples("\
+Examples:
+  wok next                        Show the highest-priority, oldest ready issue
+  wok next -t bug -l backend      Only consider backend bugs
+  wok next --claim -a agent-1     Start it and assign it to agent-1
+  wok next --claim -o id          Claim for yourself and print only the ID"))]
+    Next {
+        #[command(flatten)]
+        type_label: TypeLabelArgs,
+
+        /// Who is picking (default: @me); their issues are candidates too
+        #[arg(long, short)]
+        assignee: Option<String>,
+
+        /// Start the issue and assign it, atomically
+        #[arg(long)]
+        claim: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
     /// Search issues by text
     #[command(
         arg_required_else_help = true,
@@ -338,14 +651,18 @@ Examples:
   wok search \"task\" -a alice        Search issues assigned to alice
   wok search \"auth\" -q \"age < 30d\"  Search with time filter
   wok search \"auth\" -n 5            Limit to 5 results
+  wok search \"auth\" --sort title    Sort results by title
+  wok search \"auth\" -o json-paged  JSON results, one page at a time
 
 Filter Expressions (-q/--filter):
   Syntax: FIELD [OPERATOR VALUE]
-  Fields: age, activity, completed, skipped, closed
+  Fields: age, activity, completed, skipped, closed,
+          label, assignee, status, type, priority
   Status shortcuts: 'closed', 'skipped', 'completed' (no operator needed)
-  Operators: < <= > >= = != (or: lt lte gt gte eq ne)
+  Operators: < <= > >= = != (or: lt lte gt gte eq ne), in (a, b)
   Values: durations (30d, 1w, 24h, 5m, 10s), dates (2024-01-01), or 'now'
-  Duration units: ms, s, m, h, d, w, M (30d), y (365d)")
+  Duration units: ms, s, m, h, d, w, M (30d), y (365d)
+  Combine with 'and', 'or' and parentheses: \"label = urgent and priority <= 1\"")
     )]
     Search {
         /// Search query
@@ -361,16 +678,22 @@ Filter Expressions (-q/--filter):
         #[command(flatten)]
         assignee_args: AssigneeArgs,
 
-        /// Filter expression (e.g., "age < 3d", "updated > 1w")
+        /// Filter expression (e.g., "age < 3d", "label = urgent and priority <= 1")
         #[arg(long = "filter", short = 'q')]
         filter: Vec<String>,
 
         #[command(flatten)]
         limits: LimitArgs,
 
-        /// Output format (text, json)
+        #[command(flatten)]
+        sorting: SortArgs,
+
+        #[command(flatten)]
+        paging: PageArgs,
+
+        /// Output format (text, json, json-paged, ndjson, id)
         #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
-        output: OutputFormat,
+        output: ListFormat,
     },
 
     /// Show full details of issue(s)
@@ -382,6 +705,15 @@ Filter Expressions (-q/--filter):
         /// Output format (text, json)
         #[arg(long = "output", short = 'o', default_value = "text")]
         output: String,
+        /// Print notes as written, without rendering markdown
+        #[arg(long)]
+        raw: bool,
+        /// Show the issue as it was at a date, time, HLC, or duration ago
+        #[arg(long, value_name = "WHEN", conflicts_with = "diff")]
+        as_of: Option<String>,
+        /// Show what changed since a date, time, HLC, or duration ago
+        #[arg(long, value_name = "WHEN")]
+        diff: Option<String>,
     },
 
     /// Show dependency tree rooted at an issue
@@ -390,6 +722,21 @@ Filter Expressions (-q/--filter):
         /// Issue ID(s)
         #[arg(num_args = 1..)]
         ids: Vec<String>,
+        /// Levels below the root to show (default: all)
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+        /// Edges to follow: blocks, tracks, or all
+        #[arg(long, value_enum, default_value = "all")]
+        rel: TreeRel,
+        /// Show what the issue depends on (parents and blockers) instead
+        #[arg(long)]
+        up: bool,
+        /// Print titles as written, without rendering markdown
+        #[arg(long)]
+        raw: bool,
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
     },
 
     /// Add an external link to an issue
@@ -401,13 +748,20 @@ Examples:
   wok link prj-a3f2 https://gitlab.com/org/project/issues/456
   wok link prj-a3f2 jira://PE-5555
   wok link prj-a3f2 https://company.atlassian.net/browse/PE-5555 --reason import
-  wok link prj-a3f2 https://company.atlassian.net/wiki/spaces/DOC/pages/123")
+  wok link prj-a3f2 https://company.atlassian.net/wiki/spaces/DOC/pages/123
+  wok link refresh prj-a3f2                  Fetch titles and states of its links"),
+        args_conflicts_with_subcommands = true,
+        subcommand_negates_reqs = true
     )]
     Link {
+        #[command(subcommand)]
+        command: Option<LinkCommand>,
         /// Issue ID
-        id: String,
+        #[arg(required = true)]
+        id: Option<String>,
         /// External URL or shorthand (e.g., jira://PE-5555)
-        url: String,
+        #[arg(required = true)]
+        url: Option<String>,
         /// Relationship reason (import, blocks, tracks, tracked-by)
         #[arg(long, short)]
         reason: Option<String>,
@@ -419,7 +773,8 @@ Examples:
         after_help = colors::examples("\
 Examples:
   wok unlink prj-a3f2 https://github.com/org/repo/issues/123
-  wok unlink prj-a3f2 jira://PE-5555")
+  wok unlink prj-a3f2 jira://PE-5555
+  wok link restore prj-a3f2 jira://PE-5555     Bring the link back")
     )]
     Unlink {
         /// Issue ID
@@ -428,6 +783,175 @@ Examples:
         url: String,
     },
 
+    /// Open an issue's external link in the browser
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok open prj-a3f2                 Open the issue's link
+  wok open prj-a3f2 --link jira     Open its Jira link
+  wok open prj-a3f2 --all           Open every link
+  wok open prj-a3f2 --web           Open the open.url page for the issue
+  wok open prj-a3f2 --print         Print the URL instead")
+    )]
+    Open {
+        /// Issue ID
+        id: String,
+        /// Only links of this type (github, jira, gitlab, confluence, or a [links] type)
+        #[arg(long, value_name = "TYPE")]
+        link: Option<String>,
+        /// Open the open.url page instead of a link
+        #[arg(long, conflicts_with_all = ["link", "all"])]
+        web: bool,
+        /// Open every matching link instead of choosing one
+        #[arg(long)]
+        all: bool,
+        /// Print the URL(s) instead of opening them
+        #[arg(long)]
+        print: bool,
+    },
+
+    /// Switch to a git branch for an issue, creating it if needed
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok branch prj-a3f2               Create or switch to the issue's branch
+
+The name comes from git.branch (default: {type}/{id}-{slug}), and the
+branch is linked to the issue as branch://<name>.")
+    )]
+    Branch {
+        /// Issue ID
+        id: String,
+    },
+
+    /// Find open issues that look like duplicates
+    #[command(after_help = colors::examples("\
+Examples:
+  wok dedupe                 List likely duplicate pairs
+  wok dedupe -i              Confirm merging each pair
+  wok dedupe -o json         Pairs as JSON
+
+Merging works as in 'wok merge', keeping the older issue of each pair."))]
+    Dedupe {
+        /// Ask whether to merge each pair
+        #[arg(long, short = 'i')]
+        interactive: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Merge a duplicate issue into another and close it
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok merge prj-b2 prj-a1            Merge prj-b2 into prj-a1
+  wok merge prj-b2 prj-a1 --dry-run  Show what would move
+
+Notes, labels, links and dependencies move to the target with the events
+that recorded them, and the source is marked a duplicate of the target and
+closed as \"duplicate of <target>\".")
+    )]
+    Merge {
+        /// Issue to merge away
+        from: String,
+
+        /// Issue to keep
+        into: String,
+
+        /// Show what would move without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Split an issue into new issues it tracks
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok split prj-a3f2 \"Parse input\" \"Render output\"
+  wok split prj-a3f2 \"Fix API\" \"Fix UI\" -t bug --copy-labels
+  wok split prj-a3f2 \"Part 1\" \"Part 2\" -o id
+
+The original becomes an epic unless it is one or a feature, and gets a note
+listing the parts.")
+    )]
+    Split {
+        /// Issue to split
+        id: String,
+
+        /// Titles of the new issues
+        #[arg(required = true, value_parser = non_empty_string)]
+        titles: Vec<String>,
+
+        /// Type of the new issues
+        #[arg(long = "type", short = 't', default_value = "task")]
+        issue_type: String,
+
+        /// Give the new issues the original's labels
+        #[arg(long)]
+        copy_labels: bool,
+
+        /// Give the new issues the original's assignee
+        #[arg(long)]
+        copy_assignee: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Find stale open issues and close them in bulk
+    #[command(after_help = colors::examples("\
+Examples:
+  wok sweep                                   Report issues stale under [sweep]
+  wok sweep -q \"activity > 90d\" -s todo       Report idle todo issues
+  wok sweep --close --dry-run                 Show what would be closed
+  wok sweep --close --reason stale -l sweep:2024q3
+
+Without --filter, [sweep] filter decides what is stale (default: activity > 90d)."))]
+    Sweep {
+        /// Filter expression (e.g., \"activity > 90d\"); all must match
+        #[arg(long = "filter", short = 'q')]
+        filter: Vec<String>,
+
+        /// Only issues with this status (todo, in_progress)
+        #[arg(long, short)]
+        status: Vec<String>,
+
+        /// Close the issues found
+        #[arg(long)]
+        close: bool,
+
+        /// Close reason (default: [sweep] reason, or \"stale\")
+        #[arg(long, requires = "close")]
+        reason: Option<String>,
+
+        /// Label to add to each closed issue
+        #[arg(long, short, requires = "close")]
+        label: Option<String>,
+
+        /// With --close, show what would be closed without closing
+        #[arg(long, requires = "close")]
+        dry_run: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Pull request helpers
+    #[command(subcommand)]
+    Pr(PrCommand),
+
     /// Add dependency between issues
     #[command(
         arg_required_else_help = true,
@@ -437,18 +961,27 @@ Examples:
   wok dep prj-1 blocked-by prj-2 prj-3    prj-1 is blocked by prj-2 and prj-3
   wok dep prj-1 blocks prj-2,prj-3        Comma-separated target IDs
   wok dep prj-feat tracks prj-task        Feature tracks a task
-  wok dep prj-task tracked-by prj-feat    Task is tracked by feature")
+  wok dep prj-task tracked-by prj-feat    Task is tracked by feature
+  wok dep prj-1 relates-to prj-2          Related, without blocking either
+  wok dep prj-3 duplicate-of prj-1        prj-3 duplicates prj-1
+  wok dep prj-feat tracks prj-a prj-b --auto-epic
+                                          Make prj-feat an epic if it tracks enough")
     )]
     Dep {
         /// Source issue ID
         from_id: String,
 
-        /// Relationship: blocks, blocked-by, tracks (contains), tracked-by
+        /// Relationship: blocks, blocked-by, tracks (contains), tracked-by,
+        /// relates-to, duplicate-of
         rel: String,
 
         /// Target issue ID(s)
         #[arg(required = true)]
         to_ids: Vec<String>,
+
+        /// Convert trackers of [epic] threshold+ issues to epics, whatever [epic] auto says
+        #[arg(long)]
+        auto_epic: bool,
     },
 
     /// Remove dependency between issues
@@ -457,7 +990,8 @@ Examples:
         /// Source issue ID
         from_id: String,
 
-        /// Relationship: blocks, blocked-by, tracks, tracked-by
+        /// Relationship: blocks, blocked-by, tracks, tracked-by, relates-to,
+        /// duplicate-of
         rel: String,
 
         /// Target issue ID(s)
@@ -498,17 +1032,83 @@ Examples:
     },
 
     /// Add a note to an issue
-    #[command(arg_required_else_help = true)]
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok note prj-1 \"Tried caching, no gain\"           Add a note
+  wok note prj-1 --kind decision \"Use SQLite FTS\"   Record a decision
+  wok note prj-1 --kind blocker \"Waiting on API\"    Record a blocker
+  wok note prj-1 --edit 12 \"Tried caching: 5% gain\" Change note #12 (see wok notes)
+  wok note prj-1 --delete 12                        Delete note #12
+  wok note prj-1 --restore 12                       Bring note #12 back")
+    )]
     Note {
         /// Issue ID
         id: String,
 
         /// Note content
-        content: String,
+        #[arg(required_unless_present_any = ["delete", "restore"])]
+        content: Option<String>,
 
         /// Replace the most recent note instead of adding a new one
         #[arg(long)]
         replace: bool,
+
+        /// Note kind: decision, blocker, worklog, handoff
+        #[arg(long, conflicts_with = "replace")]
+        kind: Option<String>,
+
+        /// Change the content of the note with this ID
+        #[arg(long, value_name = "NOTE_ID", conflicts_with_all = ["replace", "kind"])]
+        edit: Option<i64>,
+
+        /// Delete the note with this ID, keeping it for --restore
+        #[arg(
+            long,
+            value_name = "NOTE_ID",
+            conflicts_with_all = ["content", "replace", "kind", "edit"]
+        )]
+        delete: Option<i64>,
+
+        /// Bring back the deleted note with this ID
+        #[arg(
+            long,
+            value_name = "NOTE_ID",
+            conflicts_with_all = ["content", "replace", "kind", "edit", "delete"]
+        )]
+        restore: Option<i64>,
+    },
+
+    /// List an issue's notes
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok notes prj-1                  All notes, grouped as in show
+  wok notes prj-1 --kind blocker   Only blocker notes
+  wok notes prj-1 -o json          Notes as JSON
+  wok notes prj-1 --deleted        Deleted notes, for wok note --restore")
+    )]
+    Notes {
+        /// Issue ID
+        id: String,
+
+        /// Only notes of this kind: decision, blocker, worklog, handoff
+        #[arg(long)]
+        kind: Option<String>,
+
+        /// List deleted notes instead
+        #[arg(long)]
+        deleted: bool,
+
+        /// Output format (text, json)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+
+        /// Print notes as written, without rendering markdown
+        #[arg(long)]
+        raw: bool,
     },
 
     /// View event log
@@ -516,9 +1116,142 @@ Examples:
         /// Issue ID (optional, shows all if omitted)
         id: Option<String>,
 
+        /// Only events by this person or agent
+        #[arg(long, value_name = "NAME")]
+        actor: Option<String>,
+
         #[command(flatten)]
         limits: LimitArgs,
-    },
+
+        /// Output format (text, ndjson)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: LogFormat,
+    },
+
+    /// Show recent activity across issues, grouped by day
+    #[command(after_help = colors::examples("\
+Examples:
+  wok activity                     Activity from the last 7 days
+  wok activity --since 1d          Activity from the last day
+  wok activity --since 2026-01-15  Activity since a date
+  wok activity -a alice            Activity on issues assigned to alice
+  wok activity -o json             Activity as JSON"))]
+    Activity {
+        /// Start of the feed: date, time, HLC, or duration ago (default: 7d)
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+
+        /// Filter by current assignee (comma-separated for OR)
+        #[arg(long, short, value_delimiter = ',')]
+        assignee: Vec<String>,
+
+        /// Filter by ID prefix (e.g., -p oj matches oj-*)
+        #[arg(long, short)]
+        prefix: Option<String>,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Summarize a period's work for posting to chat or email
+    #[command(after_help = colors::examples("\
+Examples:
+  wok digest                       The last 7 days, as markdown
+  wok digest --since 1w -a team    What the team queue did this week
+  wok digest --since 2026-01-01    Everything since a date
+  wok digest -o json               The same sections as JSON
+
+Sections: completed, created, stalled (in progress with no activity in the
+period), newly blocked, and due within 7 days or overdue."))]
+    Digest {
+        /// Start of the period: date, time, HLC, or duration ago (default: 7d)
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+
+        /// Filter by current assignee (comma-separated for OR)
+        #[arg(long, short, value_delimiter = ',')]
+        assignee: Vec<String>,
+
+        /// Filter by ID prefix (e.g., -p oj matches oj-*)
+        #[arg(long, short)]
+        prefix: Option<String>,
+
+        /// Output format (markdown, json)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "markdown")]
+        output: DigestFormat,
+    },
+
+    /// Print yesterday / today / blockers for a standup
+    #[command(after_help = colors::examples("\
+Examples:
+  wok standup                      Your standup (the configured user)
+  wok standup -a alice             Alice's standup
+  wok standup --team               One standup per team member
+  wok standup --since 3d           Monday's standup, covering the weekend
+
+Yesterday: issues done or progressed since --since (default: 1d).
+Today: issues in progress. Blockers: open issues blocking today's work,
+directly or transitively, and unresolved external blockers."))]
+    Standup {
+        /// Whose standup (comma-separated for several; default: @me)
+        #[arg(long, short, value_delimiter = ',', conflicts_with = "team")]
+        assignee: Vec<String>,
+
+        /// One standup per member of the configured team, or per assignee
+        /// with something to report when no team is configured
+        #[arg(long)]
+        team: bool,
+
+        /// Start of "yesterday": date, time, HLC, or duration ago (default: 1d)
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+
+        /// Output format (text, json)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: StandupFormat,
+    },
+
+    /// Follow issue(s): their events show up in `wok inbox`
+    #[command(after_help = colors::examples("\
+Examples:
+  wok subscribe prj-1 prj-2        Follow two issues
+  wok subscribe                    List the issues you follow"))]
+    Subscribe {
+        /// Issue ID(s); lists your subscriptions if omitted
+        ids: Vec<String>,
+    },
+
+    /// Stop following issue(s)
+    #[command(arg_required_else_help = true)]
+    Unsubscribe {
+        /// Issue ID(s)
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
+    /// Show events on subscribed issues since you last checked
+    #[command(after_help = colors::examples("\
+Examples:
+  wok inbox                        New events, then mark them read
+  wok inbox --peek                 New events, left unread
+  wok inbox -o json                New events as JSON (same shape as activity)
+
+Subscriptions and read state belong to the current user (user in config,
+else your git user.name)."))]
+    Inbox {
+        /// Show new events without marking them read
+        #[arg(long)]
+        peek: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Take issues from work queues (assignees named queue:<name>)
+    #[command(subcommand)]
+    Queue(QueueCommand),
 
     // ─────────────────────────────────────────────────────────────────────────
     // Setup & Configuration
@@ -528,7 +1261,12 @@ Examples:
 Examples:
   wok init                          Initialize with auto-detected prefix (user-level mode)
   wok init --prefix myproj          Initialize with custom prefix
-  wok init --private                Initialize in private mode (local db, no daemon)"))]
+  wok init --private                Initialize in private mode (local db, no daemon)
+  wok init --prefix proj --from issues.jsonl
+                                    Initialize and import, renaming IDs to proj-*
+  wok init --from .beads/issues.jsonl --keep-ids
+                                    Import beads issues under their own IDs
+  wok init --join ../mono           Share the database of the project in ../mono"))]
     Init {
         /// ID prefix for issues (2+ lowercase alphanumeric, defaults to directory name)
         #[arg(long)]
@@ -541,21 +1279,49 @@ Examples:
         /// Use private mode (project-local database, no daemon)
         #[arg(long)]
         private: bool,
+
+        /// Import issues from a JSONL export once initialized ('-' for stdin)
+        #[arg(long, value_name = "FILE")]
+        from: Option<String>,
+
+        /// Format of --from: wok (default) or bd (beads)
+        #[arg(long = "format", short = 'f', default_value = "wok", requires = "from")]
+        format: String,
+
+        /// Keep imported IDs instead of giving them the new prefix
+        #[arg(long, requires = "from")]
+        keep_ids: bool,
+
+        /// Join a workspace, by the path of a project in it or its ID, to share its database
+        #[arg(long, value_name = "PATH|ID", conflicts_with = "private")]
+        join: Option<String>,
     },
 
     /// Export all issues to JSONL
     #[command(arg_required_else_help = true)]
     Export {
         /// Output file path
-        filepath: String,
+        #[arg(required_unless_present = "output")]
+        filepath: Option<String>,
+
+        /// Write to stdout instead of a file (ndjson)
+        #[arg(long = "output", short = 'o', value_enum, conflicts_with = "filepath")]
+        output: Option<ExportFormat>,
+
+        /// Record format: wok (default) or bd (beads)
+        #[arg(long = "format", short = 'f', value_enum, default_value = "wok")]
+        format: RecordFormat,
     },
 
-    /// Import issues from JSONL file
+    /// Import issues from a JSONL or CSV file
     #[command(after_help = colors::examples("\
 Examples:
   wok import issues.jsonl           Import from file
   wok import -                      Import from stdin
   wok import --format bd beads.jsonl  Import beads format
+  wok import --map jira.toml jira.jsonl  Import another tracker's export
+  wok import backlog.csv --column Owner=assignee  Import a spreadsheet
+  wok import --on-conflict skip issues.jsonl  Only create issues not seen before
   wok import --dry-run issues.jsonl   Preview without applying"))]
     Import {
         /// Input file (use '-' for stdin)
@@ -566,10 +1332,26 @@ Examples:
         #[arg(long)]
         input: Option<String>,
 
-        /// Input format: wok (default) or bd (beads)
+        /// Input format: wok (default), bd (beads), csv, gitlab, or gitea
         #[arg(long = "format", short = 'f', default_value = "wok")]
         format: String,
 
+        /// Map a CSV column to a field (title, type, status, labels, assignee, description, id, label:PREFIX, ignore)
+        #[arg(long = "column", value_name = "HEADER=FIELD")]
+        column: Vec<String>,
+
+        /// Ask which field each unrecognized CSV column maps to
+        #[arg(long)]
+        interactive: bool,
+
+        /// Mapping file describing how another tracker's JSONL maps to issues
+        #[arg(long, value_name = "FILE", conflicts_with = "format")]
+        map: Option<String>,
+
+        /// What to do with records already imported or whose ID exists
+        #[arg(long, value_enum, default_value = "update")]
+        on_conflict: OnConflict,
+
         /// Preview changes without applying
         #[arg(long)]
         dry_run: bool,
@@ -582,6 +1364,40 @@ Examples:
         type_label: TypeLabelArgs,
     },
 
+    /// Back up the database and project config
+    #[command(after_help = colors::examples("\
+Examples:
+  wok backup                      Back up to .wok/backups/<timestamp>/
+  wok backup --to ~/wok-backup    Back up to a directory of your choice"))]
+    Backup {
+        /// Directory to write the backup to (must not exist or be empty)
+        #[arg(long, value_name = "DIR")]
+        to: Option<String>,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Restore the database and project config from a backup
+    #[command(after_help = colors::examples("\
+Examples:
+  wok restore .wok/backups/20260314T120000Z          Verify, then restore
+  wok restore --check .wok/backups/20260314T120000Z  Only verify the backup"))]
+    Restore {
+        /// Backup directory written by 'wok backup'
+        #[arg(value_name = "BACKUP")]
+        archive: String,
+
+        /// Only verify the backup
+        #[arg(long)]
+        check: bool,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
     /// Generate shell completions
     #[command(
         arg_required_else_help = true,
@@ -589,12 +1405,31 @@ Examples:
 Examples:
   wok completion bash > ~/.local/share/bash-completion/completions/wok
   wok completion zsh > ~/.zfunc/_wok
-  wok completion fish > ~/.config/fish/completions/wok.fish")
+  wok completion fish > ~/.config/fish/completions/wok.fish
+  wok completion bash --dynamic   Also complete issue IDs, labels and assignees")
     )]
     Completion {
         /// Shell to generate completions for
         #[arg(value_enum)]
         shell: Shell,
+
+        /// Complete issue IDs, labels, assignees and prefixes from the
+        /// database (bash, zsh and fish)
+        #[arg(long)]
+        dynamic: bool,
+    },
+
+    /// Print completion candidates for the word at `index` (used by
+    /// `wok completion --dynamic` scripts)
+    #[command(name = "_complete", hide = true)]
+    Complete {
+        /// Position of the word being completed, counting the program name
+        #[arg(long)]
+        index: usize,
+
+        /// The command line being completed, program name first
+        #[arg(last = true)]
+        words: Vec<String>,
     },
 
     /// Manage the wokd daemon
@@ -613,8 +1448,71 @@ Examples:
     #[command(subcommand)]
     Config(ConfigCommand),
 
+    /// Manage command aliases ([alias] in .wok/config.toml)
+    #[command(subcommand)]
+    Alias(AliasCommand),
+
+    /// Describe, rename and color labels
+    #[command(subcommand)]
+    Labels(LabelsCommand),
+
     /// Output issue tracker onboarding template
-    Prime,
+    #[command(after_help = colors::examples("\
+Examples:
+  wok prime                    Output the default (claude) template
+  wok prime --profile cursor   Output the template for Cursor
+  wok prime --profile review   Output .wok/prime/review.md"))]
+    Prime {
+        /// Agent profile: claude, cursor, generic, or a name from .wok/prime/
+        #[arg(long)]
+        profile: Option<String>,
+    },
+
+    /// Apply pending database migrations (backs up first)
+    #[command(after_help = colors::examples("\
+Examples:
+  wok upgrade            Back up the database and apply migrations
+  wok upgrade --check    Report compatibility and pending migrations only"))]
+    Upgrade {
+        /// Only report what would be upgraded
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Serve a read-only web dashboard of the issues
+    #[command(after_help = colors::examples("\
+Examples:
+  wok serve                          Serve on http://127.0.0.1:8080
+  wok serve --port 9000              Use another port
+  wok serve --host 0.0.0.0           Share on the local network
+
+Pages: / (issues, /?all with closed ones), /board, /graph, /issue/<id>.
+Each page reads the database when loaded; nothing can be changed from it."))]
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+        /// Address to listen on; 0.0.0.0 shares the dashboard on the network
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+    },
+
+    /// Serve JSON-RPC 2.0 for editor plugins
+    ///
+    /// Reads LSP-framed requests on stdin and writes responses and change
+    /// notifications on stdout.
+    #[command(after_help = colors::examples("\
+Examples:
+  wok rpc --stdio          Serve requests on stdin until 'exit'
+  wok schema rpc           Print every method's params and result
+
+Methods: initialize, wok/query, wok/mutate, shutdown, exit.
+The server sends wok/didChange when events are logged by any process."))]
+    Rpc {
+        /// Talk over stdin and stdout, the only transport
+        #[arg(long, required = true)]
+        stdio: bool,
+    },
 
     /// Output JSON Schema for commands with JSON output
     ///
@@ -623,10 +1521,11 @@ Examples:
         subcommand,
         after_help = colors::examples("\
 Examples:
-  wok schema list    Output schema for 'wok list -o json'
-  wok schema show    Output schema for 'wok show <id> -o json'
+  wok schema list          Output schema for 'wok list -o json'
+  wok schema show          Output schema for 'wok show <id> -o json'
+  wok schema all --bundle  One document covering every schema
 
-Available schemas: list, show, ready, search")
+Available schemas: list, show, ready, search, page, activity, digest, standup, log, export, hook, rpc")
     )]
     Schema(SchemaCommand),
 }
@@ -634,6 +1533,81 @@ Available schemas: list, show, ready, search")
 /// Configuration management commands.
 #[derive(Subcommand)]
 pub enum ConfigCommand {
+    /// Print the value of a setting
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok config get prefix            Print the issue ID prefix
+  wok config get limits.list       Print the default 'wok list' limit")
+    )]
+    Get {
+        /// Setting name (see 'wok config list')
+        key: String,
+    },
+
+    /// Change a setting in .wok/config.toml
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok config set wip.limit 3       At most 3 in-progress issues per assignee
+  wok config set color never       Never color output
+  wok config set limits.list 0     'wok list' shows every issue by default
+  wok config set --user user alice Be @me in every project")
+    )]
+    Set {
+        /// Setting name (see 'wok config list')
+        key: String,
+
+        /// New value
+        value: String,
+
+        /// Write ~/.config/wok/config.toml, which applies to every project
+        #[arg(long)]
+        user: bool,
+    },
+
+    /// Restore a setting's default
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok config unset wip.limit       Remove the WIP limit
+  wok config unset --user color    Remove color from the user config")
+    )]
+    Unset {
+        /// Setting name (see 'wok config list')
+        key: String,
+
+        /// Edit ~/.config/wok/config.toml instead of the project config
+        #[arg(long)]
+        user: bool,
+    },
+
+    /// List every setting with its effective value
+    ///
+    /// Values come from, lowest precedence first: built-in defaults, the user
+    /// config (~/.config/wok/config.toml), the project's .wok/config.toml,
+    /// and WOK_<KEY> environment variables such as WOK_WIP_LIMIT.
+    #[command(
+        visible_alias = "show",
+        after_help = colors::examples("\
+Examples:
+  wok config list                  Show all settings
+  wok config show --origin         Also show where each value comes from
+  wok config list -o json          Output as JSON")
+    )]
+    List {
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+
+        /// Show the layer and file or variable each value comes from
+        #[arg(long)]
+        origin: bool,
+    },
+
     /// Rename a prefix, updating all issues with that prefix
     ///
     /// Renames issues from `old-XXXX` to `new-XXXX`. Updates the config file
@@ -658,10 +1632,204 @@ Examples:
   wok config prefixes              List all prefixes with issue counts
   wok config prefixes -o json      Output as JSON"))]
     Prefixes {
-        /// Output format
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+    /// List the projects in this project's workspace
+    #[command(after_help = colors::examples("\
+Examples:
+  wok config workspaces            List the workspace's projects and prefixes
+  wok config workspaces -o json    Output as JSON"))]
+    Workspaces {
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+    /// Manage automation rules (.wok/rules.toml)
+    #[command(subcommand)]
+    Rules(RulesCommand),
+
+    /// Move the project between private and shared mode
+    ///
+    /// Copies the project's issues, with their labels, notes, events,
+    /// dependencies, links and short IDs, into the other database in one
+    /// transaction, then updates the config. The old database is left in
+    /// place. Issues whose ID is taken by a different issue get a new ID.
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok config mode shared           Move .wok/issues.db into the shared database
+  wok config mode private          Copy this project's issues into .wok/issues.db")
+    )]
+    Mode {
+        /// Mode to switch to
+        #[arg(value_enum)]
+        mode: StorageMode,
+    },
+}
+
+/// Alias management commands.
+#[derive(Subcommand)]
+pub enum AliasCommand {
+    /// List defined aliases
+    #[command(after_help = colors::examples("\
+Examples:
+  wok alias list                   List aliases and their expansions
+  wok alias list -o json           Output as JSON"))]
+    List {
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Define an alias, replacing any with the same name
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok alias add wip \"list -s in_progress\"       'wok wip' lists started issues
+  wok alias add mine \"list --assignee @me\"      Aliases may add flags at use: 'wok mine -o json'
+  wok alias add bugs \"list -t bug -l 'team:api'\" Quote words as in a shell")
+    )]
+    Add {
+        /// Name to run as 'wok <name>'
+        name: String,
+
+        /// Command line the alias expands to, without 'wok'
+        expansion: String,
+    },
+
+    /// Remove an alias
+    #[command(arg_required_else_help = true)]
+    Remove {
+        /// Alias name
+        name: String,
+    },
+}
+
+/// Label registry commands.
+#[derive(Subcommand)]
+pub enum LabelsCommand {
+    /// List labels with how many issues carry each
+    #[command(after_help = colors::examples("\
+Examples:
+  wok labels list                  Labels in use and registered
+  wok labels list --namespace team Only team:* labels
+  wok labels list -o json          Output as JSON"))]
+    List {
+        /// Only labels in this namespace (team for team:*)
+        #[arg(long)]
+        namespace: Option<String>,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Describe a label, or a namespace written with its colon (team:)
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok labels describe team:alpha \"Platform team\"   Describe a label
+  wok labels describe team: \"Owning team\"         Describe the namespace
+  wok labels describe team:alpha \"\"               Clear the description")
+    )]
+    Describe {
+        /// Label or namespace (team:)
+        name: String,
+
+        /// Description; empty clears it
+        description: String,
+    },
+
+    /// Rename a label on every issue carrying it
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok labels rename urgent priority:high   Rename one label
+  wok labels rename team: squad:           Rename a namespace: team:* becomes squad:*")
+    )]
+    Rename {
+        /// Label or namespace (team:) to rename
+        old: String,
+
+        /// New name
+        new: String,
+    },
+
+    /// Report labels that break the key:value rules in [labels]
+    #[command(after_help = colors::examples("\
+Examples:
+  wok labels audit                 List nonconforming labels and their fixes
+  wok labels audit --fix           Rename the labels that have a fix
+  wok labels audit -o json         Output as JSON
+
+Rules (.wok/config.toml):
+  [labels.keys.priority]   type = \"int\", min = 0, max = 4
+  [labels.keys.team]       values = [\"alpha\", \"beta\"], aliases = { platform = \"alpha\" }
+  [labels] strict = true   Only declared keys may be used"))]
+    Audit {
+        /// Rename each nonconforming label that maps to a conforming one
+        #[arg(long)]
+        fix: bool,
+
+        /// Output format (text, json, id)
         #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
         output: OutputFormat,
     },
+
+    /// Show or set the colors labels are displayed in
+    #[command(after_help = colors::examples("\
+Examples:
+  wok labels colors                   List label colors
+  wok labels colors bug red           Show bug in red
+  wok labels colors team: 74          Color every team:* label (256-color code)
+  wok labels colors bug none          Clear the color
+
+Label Colors:
+  Names: black, red, green, yellow, blue, magenta, cyan, white, gray,
+         orange, purple, pink
+  Codes: 0-255 (256-color palette)"))]
+    Colors {
+        /// Label or namespace (team:); omit to list colors
+        name: Option<String>,
+
+        /// Color to set, or none to clear
+        #[arg(requires = "name")]
+        color: Option<String>,
+    },
+}
+
+/// Automation rules commands.
+#[derive(Subcommand)]
+pub enum RulesCommand {
+    /// List configured automation rules
+    #[command(after_help = colors::examples("\
+Examples:
+  wok config rules list            List all configured rules
+  wok config rules list -o json    Output as JSON"))]
+    List {
+        /// Output format (text, json)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Trace which rules would fire for an issue, without applying them
+    #[command(after_help = colors::examples("\
+Examples:
+  wok config rules test prj-1               Trace with default event (created)
+  wok config rules test prj-1 --event done  Trace with specific event"))]
+    Test {
+        /// Issue ID to use
+        id: String,
+        /// Event to simulate (default: created)
+        #[arg(long)]
+        event: Option<String>,
+    },
 }
 
 /// Daemon management commands.
@@ -677,6 +1845,25 @@ pub enum DaemonCommand {
         #[arg(long)]
         foreground: bool,
     },
+    /// Stop the daemon if it is running, then start it
+    Restart,
+    /// Check the lock, pid file, socket, version and database, and remove stale files
+    Health,
+    /// Install a systemd unit (Linux) or launchd job (macOS) that runs the daemon
+    InstallService {
+        /// Install for the current user instead of system-wide
+        #[arg(long)]
+        user: bool,
+        /// Print the unit instead of installing it
+        #[arg(long)]
+        print: bool,
+    },
+    /// Stop and remove the unit written by install-service
+    UninstallService {
+        /// Remove the current user's unit instead of the system-wide one
+        #[arg(long)]
+        user: bool,
+    },
     /// View daemon logs
     Logs {
         /// Follow log output (tail -f)
@@ -692,10 +1879,39 @@ pub enum SchemaCommand {
     List,
     /// Output JSON Schema for 'wok show' JSON output
     Show,
+    /// Output JSON Schema for 'wok tree' JSON output
+    Tree,
     /// Output JSON Schema for 'wok ready' JSON output
     Ready,
     /// Output JSON Schema for 'wok search' JSON output
     Search,
+    /// Output JSON Schema for '-o json-paged' output of list and search
+    Page,
+    /// Output JSON Schema for 'wok activity' JSON output
+    Activity,
+    /// Output JSON Schema for 'wok digest -o json'
+    Digest,
+    /// Output JSON Schema for 'wok standup -o json'
+    Standup,
+    /// Output JSON Schema for each line of 'wok log -o ndjson'
+    Log,
+    /// Output JSON Schema for each line of 'wok export'
+    Export,
+    /// Output JSON Schema for the payload hooks receive on stdin
+    Hook,
+    /// Output JSON Schema for the 'wok rpc' methods and notifications
+    Rpc,
+    /// Output every schema, keyed by name
+    All {
+        /// Emit one JSON Schema document whose definitions are shared
+        #[arg(long)]
+        bundle: bool,
+    },
+    /// Show the database's schema version and pending migrations
+    DbVersion {
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
 }
 
 /// Claude Code hooks management commands.
@@ -707,9 +1923,12 @@ Examples:
   wok hooks install              Install to local scope (default)
   wok hooks install -y           Non-interactive, local scope
   wok hooks install project      Install to project scope
-  wok hooks install -i           Interactive picker"))]
+  wok hooks install -i           Interactive picker
+  wok hooks install -y --context --auto-note
+                                 Also inject issues and note shell failures
+  wok hooks install git          Install git commit-msg and post-commit hooks"))]
     Install {
-        /// Target scope (local, project, user)
+        /// Target scope (local, project, user), or git for git hooks
         scope: Option<String>,
 
         /// Force interactive mode (TUI picker)
@@ -719,16 +1938,117 @@ Examples:
         /// Force non-interactive mode (auto-confirm)
         #[arg(long, short = 'y', conflicts_with = "interactive")]
         yes: bool,
+
+        /// Also inject in-progress and ready issues at session start
+        #[arg(long)]
+        context: bool,
+
+        /// Also note failed shell commands on the in-progress issue
+        #[arg(long)]
+        auto_note: bool,
     },
 
     /// Uninstall Claude Code hooks
     Uninstall {
-        /// Target scope (local, project, user)
+        /// Target scope (local, project, user), or git for git hooks
         scope: Option<String>,
     },
 
     /// Show hooks installation status
     Status,
+
+    /// Print in-progress and ready issues (SessionStart hook)
+    Context,
+
+    /// Note a failed shell command read from stdin (PostToolUse hook)
+    NoteFailure,
+
+    /// Add the current issue to a commit message file (git commit-msg hook)
+    CommitMsg {
+        /// Commit message file
+        file: String,
+    },
+
+    /// Note the new commit on its issue (git post-commit hook)
+    PostCommit,
+}
+
+/// External link commands.
+#[derive(Subcommand)]
+pub enum LinkCommand {
+    /// Fetch the titles and states of linked GitHub, GitLab and Jira issues
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok link refresh prj-a3f2            Refresh the issue's links
+  wok link refresh prj-a3f2 prj-b4c5   Refresh several issues' links
+  wok link refresh --all               Refresh every link
+
+Tokens are read from [api.github], [api.gitlab] and [api.jira] in the
+config, or from GITHUB_TOKEN, GITLAB_TOKEN and JIRA_API_TOKEN.")
+    )]
+    Refresh {
+        /// Issue IDs
+        #[arg(required_unless_present = "all")]
+        ids: Vec<String>,
+        /// Refresh the links of every issue
+        #[arg(long, conflicts_with = "ids")]
+        all: bool,
+    },
+
+    /// Mark a linked external issue resolved, so it stops blocking
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok link resolve prj-a3f2 jira://PE-5555
+  wok link resolve prj-a3f2 https://github.com/org/repo/issues/123
+
+With [api] configured, the daemon and `wok link refresh` resolve links
+whose external issue is closed, merged or done.")
+    )]
+    Resolve {
+        /// Issue ID
+        id: String,
+        /// External URL or shorthand, as linked
+        url: String,
+    },
+
+    /// Bring back a link removed with `wok unlink`
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok link restore prj-a3f2 jira://PE-5555
+  wok link restore prj-a3f2 https://github.com/org/repo/issues/123")
+    )]
+    Restore {
+        /// Issue ID
+        id: String,
+        /// External URL or shorthand, as linked
+        url: String,
+    },
+}
+
+/// Pull request helper commands.
+#[derive(Subcommand)]
+pub enum PrCommand {
+    /// Print a pull request body for an issue
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok pr describe prj-a3f2                               Print the PR body
+  wok pr describe prj-a3f2 | gh pr create --body-file -  Open a PR with it
+
+.wok/templates/pr.md overrides the template; it may use {{id}}, {{title}},
+{{description}}, {{summary}}, {{decisions}} and {{closes}}.")
+    )]
+    Describe {
+        /// Issue ID
+        id: String,
+    },
 }
 
 /// Issue hooks management commands.
@@ -749,7 +2069,8 @@ Examples:
     #[command(after_help = colors::examples("\
 Examples:
   wok hook test my-hook prj-1              Test with default event (created)
-  wok hook test my-hook prj-1 --event done Test with specific event"))]
+  wok hook test my-hook prj-1 --event done Test with specific event
+  wok hook test my-hook prj-1 --run        Fire the hook now and report the result"))]
     Test {
         /// Hook name to test
         name: String,
@@ -758,6 +2079,73 @@ Examples:
         /// Event to simulate (default: created)
         #[arg(long)]
         event: Option<String>,
+        /// Fire the hook synchronously with a synthetic payload
+        #[arg(long)]
+        run: bool,
+    },
+
+    /// Show recent hook executions
+    #[command(after_help = colors::examples("\
+Examples:
+  wok hook runs                  Show the 20 most recent runs
+  wok hook runs --failed         Show only failed runs
+  wok hook runs -n 50 -o json    Output 50 runs as JSON"))]
+    Runs {
+        /// Only show runs that failed or timed out
+        #[arg(long)]
+        failed: bool,
+        /// Maximum number of runs to show
+        #[arg(short = 'n', long, default_value = "20")]
+        limit: usize,
+        /// Output format (text, json)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Mark issues past their [aging] threshold stale and fire issue.stale hooks
+    #[command(after_help = colors::examples("\
+Examples:
+  wok hook stale                 Check now instead of waiting for the daemon
+
+Each issue is marked once per stay in a status. The daemon runs this check
+every 15 minutes for projects whose hooks listen for issue.stale."))]
+    Stale,
+}
+
+/// Work queue commands.
+#[derive(Subcommand)]
+pub enum QueueCommand {
+    /// Assign the oldest ready issue in a queue to yourself
+    #[command(
+        arg_required_else_help = true,
+        after_help = colors::examples("\
+Examples:
+  wok queue take merge            Take the next issue from queue:merge
+  wok queue take merge -a bot-1   Take it on behalf of bot-1
+  wok queue take merge -o id      Print only the taken issue's ID")
+    )]
+    Take {
+        /// Queue name (e.g., merge for queue:merge)
+        queue: String,
+
+        /// Who takes the issue (default: @me)
+        #[arg(long, short)]
+        assignee: Option<String>,
+
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Show how many issues are waiting in each queue
+    #[command(after_help = colors::examples("\
+Examples:
+  wok queue list                  Show queue depths
+  wok queue list -o json          Output as JSON"))]
+    List {
+        /// Output format (text, json, id)
+        #[arg(long = "output", short = 'o', value_enum, default_value = "text")]
+        output: OutputFormat,
     },
 }
 
@@ -20,6 +20,7 @@ fn test_dep_single_target() {
             from_id,
             rel,
             to_ids,
+            ..
         } => {
             assert_eq!(from_id, "prj-a");
             assert_eq!(rel, "blocks");
@@ -37,6 +38,7 @@ fn test_dep_multiple_targets() {
             from_id,
             rel,
             to_ids,
+            ..
         } => {
             assert_eq!(from_id, "prj-a");
             assert_eq!(rel, "tracks");
@@ -46,6 +48,20 @@ fn test_dep_multiple_targets() {
     }
 }
 
+#[test]
+fn test_dep_auto_epic_flag() {
+    let cli = parse(&["wok", "dep", "prj-a", "tracks", "prj-b", "--auto-epic"]).unwrap();
+    match cli.command {
+        Command::Dep { auto_epic, .. } => assert!(auto_epic),
+        _ => panic!("Expected Dep command"),
+    }
+    let cli = parse(&["wok", "dep", "prj-a", "tracks", "prj-b"]).unwrap();
+    match cli.command {
+        Command::Dep { auto_epic, .. } => assert!(!auto_epic),
+        _ => panic!("Expected Dep command"),
+    }
+}
+
 #[test]
 fn test_dep_requires_target() {
     let result = parse(&["wok", "dep", "prj-a", "blocks"]);
@@ -137,9 +153,10 @@ fn test_note_command() {
             id,
             content,
             replace,
+            ..
         } => {
             assert_eq!(id, "prj-1234");
-            assert_eq!(content, "This is a note");
+            assert_eq!(content.as_deref(), Some("This is a note"));
             assert!(!replace);
         }
         _ => panic!("Expected Note command"),
@@ -154,15 +171,111 @@ fn test_note_command_with_replace() {
             id,
             content,
             replace,
+            ..
         } => {
             assert_eq!(id, "prj-1234");
-            assert_eq!(content, "Updated note");
+            assert_eq!(content.as_deref(), Some("Updated note"));
             assert!(replace);
         }
         _ => panic!("Expected Note command"),
     }
 }
 
+#[test]
+fn test_note_command_with_kind() {
+    let cli = parse(&["wok", "note", "prj-1234", "Use FTS", "--kind", "decision"]).unwrap();
+    match cli.command {
+        Command::Note { kind, .. } => assert_eq!(kind.as_deref(), Some("decision")),
+        _ => panic!("Expected Note command"),
+    }
+}
+
+#[test]
+fn test_note_command_kind_conflicts_with_replace() {
+    let result = parse(&[
+        "wok",
+        "note",
+        "prj-1234",
+        "x",
+        "--kind",
+        "blocker",
+        "--replace",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_notes_command_with_kind_and_output() {
+    let cli = parse(&[
+        "wok", "notes", "prj-1234", "--kind", "blocker", "-o", "json",
+    ])
+    .unwrap();
+    match cli.command {
+        Command::Notes {
+            id, kind, output, ..
+        } => {
+            assert_eq!(id, "prj-1234");
+            assert_eq!(kind.as_deref(), Some("blocker"));
+            assert!(matches!(output, OutputFormat::Json));
+        }
+        _ => panic!("Expected Notes command"),
+    }
+}
+
+#[test]
+fn test_note_command_with_edit() {
+    let cli = parse(&["wok", "note", "prj-1234", "--edit", "12", "Fixed typo"]).unwrap();
+    match cli.command {
+        Command::Note { content, edit, .. } => {
+            assert_eq!(content.as_deref(), Some("Fixed typo"));
+            assert_eq!(edit, Some(12));
+        }
+        _ => panic!("Expected Note command"),
+    }
+    assert!(parse(&["wok", "note", "prj-1234", "--edit", "12"]).is_err());
+    assert!(parse(&["wok", "note", "prj-1234", "--edit", "12", "x", "--replace"]).is_err());
+}
+
+#[test]
+fn test_note_command_delete_and_restore_take_no_content() {
+    let cli = parse(&["wok", "note", "prj-1234", "--delete", "12"]).unwrap();
+    match cli.command {
+        Command::Note {
+            content, delete, ..
+        } => {
+            assert!(content.is_none());
+            assert_eq!(delete, Some(12));
+        }
+        _ => panic!("Expected Note command"),
+    }
+    let cli = parse(&["wok", "note", "prj-1234", "--restore", "12"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Command::Note {
+            restore: Some(12),
+            ..
+        }
+    ));
+
+    assert!(parse(&["wok", "note", "prj-1234", "--delete", "12", "x"]).is_err());
+    assert!(parse(&[
+        "wok",
+        "note",
+        "prj-1234",
+        "--delete",
+        "12",
+        "--restore",
+        "12"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_notes_command_deleted() {
+    let cli = parse(&["wok", "notes", "prj-1234", "--deleted"]).unwrap();
+    assert!(matches!(cli.command, Command::Notes { deleted: true, .. }));
+}
+
 #[test]
 fn test_note_command_rejects_r_shorthand() {
     // -r short flag was removed from 'note' command
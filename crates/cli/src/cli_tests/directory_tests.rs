@@ -49,3 +49,32 @@ fn parse_dash_c_after_subcommand() {
     let cli = Cli::try_parse_from(["wok", "list", "-C", "/tmp"]).unwrap();
     assert_eq!(cli.directory, Some("/tmp".to_string()));
 }
+
+#[test]
+fn parse_error_format_is_global() {
+    let cli = Cli::try_parse_from(["wok", "--error-format", "json", "list"]).unwrap();
+    assert_eq!(cli.error_format, Some(ErrorFormat::Json));
+
+    let cli = Cli::try_parse_from(["wok", "show", "prj-1", "--error-format=text"]).unwrap();
+    assert_eq!(cli.error_format, Some(ErrorFormat::Text));
+
+    let cli = Cli::try_parse_from(["wok", "list"]).unwrap();
+    assert_eq!(cli.error_format, None);
+}
+
+#[test]
+fn parse_error_format_rejects_unknown_value() {
+    assert!(Cli::try_parse_from(["wok", "--error-format", "xml", "list"]).is_err());
+}
+
+#[test]
+fn parse_quiet_is_global() {
+    let cli = Cli::try_parse_from(["wok", "--quiet", "done", "prj-1"]).unwrap();
+    assert!(cli.quiet);
+
+    let cli = Cli::try_parse_from(["wok", "done", "prj-1", "--quiet"]).unwrap();
+    assert!(cli.quiet);
+
+    let cli = Cli::try_parse_from(["wok", "done", "prj-1"]).unwrap();
+    assert!(!cli.quiet);
+}
@@ -28,6 +28,15 @@ fn test_edit_title() {
     }
 }
 
+#[test]
+fn test_edit_with_if_match() {
+    let cli = parse(&["wok", "edit", "prj-1234", "title", "New", "--if-match", "7"]).unwrap();
+    match cli.command {
+        Command::Edit { if_match, .. } => assert_eq!(if_match, Some(7)),
+        _ => panic!("Expected Edit command"),
+    }
+}
+
 #[test]
 fn test_edit_description() {
     let cli = parse(&[
@@ -204,3 +213,17 @@ fn test_edit_help_hides_flags() {
         "flag --assignee should be hidden"
     );
 }
+
+#[test]
+fn test_assign_and_unassign_commands() {
+    let cli = parse(&["wok", "assign", "prj-1", "@me"]).unwrap();
+    assert!(matches!(cli.command, Command::Assign { id, who } if id == "prj-1" && who == "@me"));
+
+    let cli = parse(&["wok", "unassign", "prj-1"]).unwrap();
+    assert!(matches!(cli.command, Command::Unassign { id } if id == "prj-1"));
+}
+
+#[test]
+fn test_assign_requires_who() {
+    assert!(parse(&["wok", "assign", "prj-1"]).is_err());
+}
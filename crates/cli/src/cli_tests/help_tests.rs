@@ -19,7 +19,7 @@ fn all_subcommands_in_help() {
 
     // Commands that are shown together with [un] prefix
     // The [un]X format covers both X and unX commands
-    let un_prefixed: &[&str] = &["dep", "label", "link"];
+    let un_prefixed: &[&str] = &["dep", "label", "link", "assign", "subscribe"];
 
     for sub in cmd.get_subcommands() {
         let name = sub.get_name();
@@ -19,6 +19,7 @@ fn test_hooks_install_with_y_flag() {
             scope,
             interactive,
             yes,
+            ..
         }) => {
             assert!(scope.is_none());
             assert!(!interactive);
@@ -36,6 +37,7 @@ fn test_hooks_install_with_y_flag_and_scope() {
             scope,
             interactive,
             yes,
+            ..
         }) => {
             assert_eq!(scope, Some("local".to_string()));
             assert!(!interactive);
@@ -53,6 +55,7 @@ fn test_hooks_install_with_yes_long_flag() {
             scope,
             interactive,
             yes,
+            ..
         }) => {
             assert!(scope.is_none());
             assert!(!interactive);
@@ -62,6 +65,32 @@ fn test_hooks_install_with_yes_long_flag() {
     }
 }
 
+#[test]
+fn test_hooks_install_with_features() {
+    let cli = parse(&["wok", "hooks", "install", "-y", "--context", "--auto-note"]).unwrap();
+    match cli.command {
+        Command::Hooks(HooksCommand::Install {
+            context, auto_note, ..
+        }) => {
+            assert!(context);
+            assert!(auto_note);
+        }
+        _ => panic!("Expected Hooks Install command"),
+    }
+}
+
+#[test]
+fn test_hooks_handler_subcommands() {
+    assert!(matches!(
+        parse(&["wok", "hooks", "context"]).unwrap().command,
+        Command::Hooks(HooksCommand::Context)
+    ));
+    assert!(matches!(
+        parse(&["wok", "hooks", "note-failure"]).unwrap().command,
+        Command::Hooks(HooksCommand::NoteFailure)
+    ));
+}
+
 #[test]
 fn test_hooks_install_rejects_q_shorthand() {
     // -q short flag was renamed to -y
@@ -70,10 +99,14 @@ fn test_hooks_install_rejects_q_shorthand() {
 }
 
 #[test]
-fn test_hooks_install_rejects_quiet_long_flag() {
-    // --quiet was renamed to --yes
-    let result = parse(&["wok", "hooks", "install", "--quiet"]);
-    assert!(result.is_err());
+fn test_hooks_install_quiet_does_not_mean_yes() {
+    // --quiet was renamed to --yes; --quiet is now the global output flag
+    let cli = parse(&["wok", "hooks", "install", "--quiet"]).unwrap();
+    assert!(cli.quiet);
+    match cli.command {
+        Command::Hooks(HooksCommand::Install { yes, .. }) => assert!(!yes),
+        _ => panic!("Expected Hooks Install command"),
+    }
 }
 
 #[test]
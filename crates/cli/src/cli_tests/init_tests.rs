@@ -74,6 +74,7 @@ fn should_parse_init_args(args: &[&str], expected: InitExpected) {
             prefix,
             path,
             private,
+            ..
         } => {
             assert_eq!(
                 prefix.as_deref(),
@@ -108,3 +109,50 @@ fn should_accept_private_flag() {
         _ => panic!("Expected Init command"),
     }
 }
+
+#[test]
+fn should_accept_from_with_format_and_keep_ids() {
+    let cli = parse(&[
+        "wok",
+        "init",
+        "--from",
+        "beads.jsonl",
+        "-f",
+        "bd",
+        "--keep-ids",
+    ])
+    .unwrap();
+    match cli.command {
+        Command::Init {
+            from,
+            format,
+            keep_ids,
+            ..
+        } => {
+            assert_eq!(from.as_deref(), Some("beads.jsonl"));
+            assert_eq!(format, "bd");
+            assert!(keep_ids);
+        }
+        _ => panic!("Expected Init command"),
+    }
+}
+
+#[test]
+fn should_require_from_for_import_options() {
+    assert!(parse(&["wok", "init", "--keep-ids"]).is_err());
+    assert!(parse(&["wok", "init", "--format", "bd"]).is_err());
+    assert!(parse(&["wok", "init"]).is_ok());
+}
+
+#[test]
+fn should_accept_join_without_private() {
+    let cli = parse(&["wok", "init", "--join", "../mono", "--prefix", "app"]).unwrap();
+    match cli.command {
+        Command::Init { join, prefix, .. } => {
+            assert_eq!(join.as_deref(), Some("../mono"));
+            assert_eq!(prefix.as_deref(), Some("app"));
+        }
+        _ => panic!("Expected Init command"),
+    }
+    assert!(parse(&["wok", "init", "--join", "../mono", "--private"]).is_err());
+}
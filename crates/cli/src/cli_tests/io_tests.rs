@@ -16,10 +16,17 @@ fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
 fn test_log_without_id() {
     let cli = parse(&["wok", "log"]).unwrap();
     match cli.command {
-        Command::Log { id, limits } => {
+        Command::Log {
+            id,
+            actor,
+            limits,
+            output,
+        } => {
+            assert!(actor.is_none());
             assert!(id.is_none());
             assert!(limits.limit.is_none()); // default handled by command impl
             assert!(!limits.no_limit);
+            assert_eq!(output, LogFormat::Text);
         }
         _ => panic!("Expected Log command"),
     }
@@ -72,6 +79,17 @@ fn test_log_with_no_limit() {
     }
 }
 
+#[test]
+fn test_log_with_actor() {
+    let cli = parse(&["wok", "log", "--actor", "claude-1"]).unwrap();
+    match cli.command {
+        Command::Log { actor, .. } => {
+            assert_eq!(actor.as_deref(), Some("claude-1"));
+        }
+        _ => panic!("Expected Log command"),
+    }
+}
+
 #[test]
 fn test_log_rejects_l_shorthand() {
     // -l short flag was removed from 'log' command
@@ -79,18 +97,193 @@ fn test_log_rejects_l_shorthand() {
     assert!(result.is_err());
 }
 
+// Activity command
+#[test]
+fn test_activity_defaults() {
+    let cli = parse(&["wok", "activity"]).unwrap();
+    match cli.command {
+        Command::Activity {
+            since,
+            assignee,
+            prefix,
+            output,
+        } => {
+            assert!(since.is_none());
+            assert!(assignee.is_empty());
+            assert!(prefix.is_none());
+            assert!(matches!(output, OutputFormat::Text));
+        }
+        _ => panic!("Expected Activity command"),
+    }
+}
+
+#[test]
+fn test_activity_with_filters() {
+    let cli = parse(&[
+        "wok",
+        "activity",
+        "--since",
+        "1d",
+        "-a",
+        "alice,bob",
+        "-p",
+        "api",
+        "-o",
+        "json",
+    ])
+    .unwrap();
+    match cli.command {
+        Command::Activity {
+            since,
+            assignee,
+            prefix,
+            output,
+        } => {
+            assert_eq!(since.as_deref(), Some("1d"));
+            assert_eq!(assignee, vec!["alice", "bob"]);
+            assert_eq!(prefix.as_deref(), Some("api"));
+            assert!(matches!(output, OutputFormat::Json));
+        }
+        _ => panic!("Expected Activity command"),
+    }
+}
+
+// Digest command
+#[test]
+fn test_digest_defaults_to_markdown() {
+    let cli = parse(&["wok", "digest", "--since", "1w", "-a", "team"]).unwrap();
+    match cli.command {
+        Command::Digest {
+            since,
+            assignee,
+            prefix,
+            output,
+        } => {
+            assert_eq!(since.as_deref(), Some("1w"));
+            assert_eq!(assignee, vec!["team"]);
+            assert!(prefix.is_none());
+            assert!(matches!(output, DigestFormat::Markdown));
+        }
+        _ => panic!("Expected Digest command"),
+    }
+}
+
+#[test]
+fn test_digest_rejects_id_output() {
+    assert!(parse(&["wok", "digest", "-o", "json"]).is_ok());
+    assert!(parse(&["wok", "digest", "-o", "id"]).is_err());
+}
+
+// Standup command
+#[test]
+fn test_standup_team_conflicts_with_assignee() {
+    match parse(&["wok", "standup", "--team"]).unwrap().command {
+        Command::Standup {
+            assignee,
+            team,
+            since,
+            output,
+        } => {
+            assert!(assignee.is_empty());
+            assert!(team);
+            assert!(since.is_none());
+            assert!(matches!(output, StandupFormat::Text));
+        }
+        _ => panic!("Expected Standup command"),
+    }
+    assert!(parse(&["wok", "standup", "--team", "-a", "alice"]).is_err());
+}
+
+// Queue command
+#[test]
+fn test_queue_take() {
+    let cli = parse(&["wok", "queue", "take", "merge", "-a", "bot-1", "-o", "id"]).unwrap();
+    match cli.command {
+        Command::Queue(QueueCommand::Take {
+            queue,
+            assignee,
+            output,
+        }) => {
+            assert_eq!(queue, "merge");
+            assert_eq!(assignee.as_deref(), Some("bot-1"));
+            assert!(matches!(output, OutputFormat::Id));
+        }
+        _ => panic!("Expected Queue Take command"),
+    }
+}
+
+#[test]
+fn test_queue_take_requires_queue() {
+    assert!(parse(&["wok", "queue", "take"]).is_err());
+}
+
+#[test]
+fn test_queue_list() {
+    let cli = parse(&["wok", "queue", "list", "-o", "json"]).unwrap();
+    match cli.command {
+        Command::Queue(QueueCommand::List { output }) => {
+            assert!(matches!(output, OutputFormat::Json));
+        }
+        _ => panic!("Expected Queue List command"),
+    }
+}
+
 // Export command
 #[test]
 fn test_export_command() {
     let cli = parse(&["wok", "export", "/tmp/issues.jsonl"]).unwrap();
     match cli.command {
-        Command::Export { filepath } => {
-            assert_eq!(filepath, "/tmp/issues.jsonl");
+        Command::Export {
+            filepath,
+            output,
+            format,
+        } => {
+            assert_eq!(filepath.as_deref(), Some("/tmp/issues.jsonl"));
+            assert!(output.is_none());
+            assert_eq!(format, RecordFormat::Wok);
+        }
+        _ => panic!("Expected Export command"),
+    }
+}
+
+#[test]
+fn test_export_ndjson_to_stdout() {
+    let cli = parse(&["wok", "export", "-o", "ndjson"]).unwrap();
+    match cli.command {
+        Command::Export {
+            filepath, output, ..
+        } => {
+            assert!(filepath.is_none());
+            assert_eq!(output, Some(ExportFormat::Ndjson));
         }
         _ => panic!("Expected Export command"),
     }
 }
 
+#[test]
+fn test_export_format_bd() {
+    let cli = parse(&["wok", "export", "--format", "bd", "-o", "ndjson"]).unwrap();
+    match cli.command {
+        Command::Export { format, .. } => assert_eq!(format, RecordFormat::Bd),
+        _ => panic!("Expected Export command"),
+    }
+    assert!(parse(&["wok", "export", "--format", "jira", "out.jsonl"]).is_err());
+}
+
+#[test]
+fn test_export_ndjson_conflicts_with_filepath() {
+    assert!(parse(&["wok", "export", "out.jsonl", "-o", "ndjson"]).is_err());
+}
+
+#[test]
+fn test_log_ndjson() {
+    let cli = parse(&["wok", "log", "-o", "ndjson"]).unwrap();
+    match cli.command {
+        Command::Log { output, .. } => assert_eq!(output, LogFormat::Ndjson),
+        _ => panic!("Expected Log command"),
+    }
+}
+
 // Import command tests
 #[test]
 fn test_import_with_file() {
@@ -163,7 +356,7 @@ fn test_import_accepts_p_shorthand() {
 fn test_completion_bash() {
     let cli = parse(&["wok", "completion", "bash"]).unwrap();
     match cli.command {
-        Command::Completion { shell } => {
+        Command::Completion { shell, .. } => {
             assert_eq!(shell, clap_complete::Shell::Bash);
         }
         _ => panic!("Expected Completion command"),
@@ -174,13 +367,47 @@ fn test_completion_bash() {
 fn test_completion_zsh() {
     let cli = parse(&["wok", "completion", "zsh"]).unwrap();
     match cli.command {
-        Command::Completion { shell } => {
+        Command::Completion { shell, .. } => {
             assert_eq!(shell, clap_complete::Shell::Zsh);
         }
         _ => panic!("Expected Completion command"),
     }
 }
 
+#[test]
+fn test_completion_dynamic() {
+    let cli = parse(&["wok", "completion", "fish", "--dynamic"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Command::Completion {
+            shell: clap_complete::Shell::Fish,
+            dynamic: true
+        }
+    ));
+}
+
+#[test]
+fn test_complete_takes_raw_words() {
+    let cli = parse(&[
+        "wok",
+        "_complete",
+        "--index",
+        "2",
+        "--",
+        "wok",
+        "list",
+        "--label",
+    ])
+    .unwrap();
+    match cli.command {
+        Command::Complete { index, words } => {
+            assert_eq!(index, 2);
+            assert_eq!(words, vec!["wok", "list", "--label"]);
+        }
+        _ => panic!("Expected Complete command"),
+    }
+}
+
 #[test]
 fn test_completion_invalid_shell() {
     let result = parse(&["wok", "completion", "invalid"]);
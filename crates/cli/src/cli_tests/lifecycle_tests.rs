@@ -16,7 +16,7 @@ fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
 fn test_start_command() {
     let cli = parse(&["wok", "start", "prj-1234"]).unwrap();
     match cli.command {
-        Command::Start { ids } => assert_eq!(ids, vec!["prj-1234"]),
+        Command::Start { ids, .. } => assert_eq!(ids, vec!["prj-1234"]),
         _ => panic!("Expected Start command"),
     }
 }
@@ -25,7 +25,7 @@ fn test_start_command() {
 fn test_start_command_multiple() {
     let cli = parse(&["wok", "start", "prj-1", "prj-2", "prj-3"]).unwrap();
     match cli.command {
-        Command::Start { ids } => assert_eq!(ids, vec!["prj-1", "prj-2", "prj-3"]),
+        Command::Start { ids, .. } => assert_eq!(ids, vec!["prj-1", "prj-2", "prj-3"]),
         _ => panic!("Expected Start command"),
     }
 }
@@ -34,7 +34,7 @@ fn test_start_command_multiple() {
 fn test_done_command() {
     let cli = parse(&["wok", "done", "prj-1234"]).unwrap();
     match cli.command {
-        Command::Done { ids, reason } => {
+        Command::Done { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert!(reason.is_none());
         }
@@ -42,11 +42,24 @@ fn test_done_command() {
     }
 }
 
+#[test]
+fn test_done_with_if_match() {
+    let cli = parse(&["wok", "done", "prj-1234", "--if-match", "3"]).unwrap();
+    match cli.command {
+        Command::Done { ids, if_match, .. } => {
+            assert_eq!(ids, vec!["prj-1234"]);
+            assert_eq!(if_match, Some(3));
+        }
+        _ => panic!("Expected Done command"),
+    }
+    assert!(parse(&["wok", "start", "prj-1234", "--if-match", "latest"]).is_err());
+}
+
 #[test]
 fn test_done_command_multiple() {
     let cli = parse(&["wok", "done", "prj-1", "prj-2"]).unwrap();
     match cli.command {
-        Command::Done { ids, reason } => {
+        Command::Done { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1", "prj-2"]);
             assert!(reason.is_none());
         }
@@ -58,7 +71,7 @@ fn test_done_command_multiple() {
 fn test_done_with_reason() {
     let cli = parse(&["wok", "done", "prj-1234", "-r", "Already complete"]).unwrap();
     match cli.command {
-        Command::Done { ids, reason } => {
+        Command::Done { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert_eq!(reason, Some("Already complete".to_string()));
         }
@@ -70,7 +83,7 @@ fn test_done_with_reason() {
 fn test_done_multiple_with_reason() {
     let cli = parse(&["wok", "done", "prj-1", "prj-2", "-r", "upstream"]).unwrap();
     match cli.command {
-        Command::Done { ids, reason } => {
+        Command::Done { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1", "prj-2"]);
             assert_eq!(reason, Some("upstream".to_string()));
         }
@@ -82,7 +95,7 @@ fn test_done_multiple_with_reason() {
 fn test_close_command() {
     let cli = parse(&["wok", "close", "prj-1234", "-r", "wontfix"]).unwrap();
     match cli.command {
-        Command::Close { ids, reason } => {
+        Command::Close { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert_eq!(reason, Some("wontfix".to_string()));
         }
@@ -94,7 +107,7 @@ fn test_close_command() {
 fn test_close_command_multiple() {
     let cli = parse(&["wok", "close", "prj-1", "prj-2", "-r", "duplicate"]).unwrap();
     match cli.command {
-        Command::Close { ids, reason } => {
+        Command::Close { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1", "prj-2"]);
             assert_eq!(reason, Some("duplicate".to_string()));
         }
@@ -107,7 +120,7 @@ fn test_close_without_reason() {
     // Reason is now optional (auto-populated for human interactive sessions)
     let cli = parse(&["wok", "close", "prj-1234"]).unwrap();
     match cli.command {
-        Command::Close { ids, reason } => {
+        Command::Close { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert!(reason.is_none());
         }
@@ -119,7 +132,7 @@ fn test_close_without_reason() {
 fn test_reopen_command() {
     let cli = parse(&["wok", "reopen", "prj-1234", "--reason", "regression"]).unwrap();
     match cli.command {
-        Command::Reopen { ids, reason } => {
+        Command::Reopen { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert_eq!(reason, Some("regression".to_string()));
         }
@@ -131,7 +144,7 @@ fn test_reopen_command() {
 fn test_reopen_command_multiple() {
     let cli = parse(&["wok", "reopen", "prj-1", "prj-2", "--reason", "regression"]).unwrap();
     match cli.command {
-        Command::Reopen { ids, reason } => {
+        Command::Reopen { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1", "prj-2"]);
             assert_eq!(reason, Some("regression".to_string()));
         }
@@ -144,7 +157,7 @@ fn test_reopen_without_reason() {
     // Reason is now optional (auto-populated for human interactive sessions)
     let cli = parse(&["wok", "reopen", "prj-1234"]).unwrap();
     match cli.command {
-        Command::Reopen { ids, reason } => {
+        Command::Reopen { ids, reason, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert!(reason.is_none());
         }
@@ -94,6 +94,18 @@ fn test_list_blocked_flag() {
     }
 }
 
+#[test]
+fn test_list_stale_flag() {
+    let cli = parse(&["wok", "list", "--stale"]).unwrap();
+    match cli.command {
+        Command::List { stale, blocked, .. } => {
+            assert!(stale);
+            assert!(!blocked);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
 #[test]
 fn test_list_blocked_short_flag_fails() {
     // -b short flag was removed for --blocked
@@ -160,19 +172,36 @@ fn test_ready_default() {
             assignee,
             unassigned,
             all_assignees,
+            order,
+            sorting,
+            explain,
+            capacity,
             output,
         } => {
+            assert!(!explain);
+            assert!(capacity.is_none());
             assert!(type_label.r#type.is_empty());
             assert!(type_label.label.is_empty());
             assert!(assignee.is_empty());
             assert!(!unassigned);
             assert!(!all_assignees);
+            assert_eq!(order, Order::Default);
+            assert_eq!(sorting.sort, None);
             assert!(matches!(output, OutputFormat::Text));
         }
         _ => panic!("Expected Ready command"),
     }
 }
 
+#[test]
+fn test_ready_with_capacity() {
+    let cli = parse(&["wok", "ready", "--capacity", "5pt"]).unwrap();
+    match cli.command {
+        Command::Ready { capacity, .. } => assert_eq!(capacity.as_deref(), Some("5pt")),
+        _ => panic!("Expected Ready command"),
+    }
+}
+
 #[test]
 fn test_ready_with_type_filter() {
     let cli = parse(&["wok", "ready", "-t", "bug"]).unwrap();
@@ -231,6 +260,127 @@ fn test_ready_accepts_label_flag() {
     }
 }
 
+#[parameterized(
+    ready = { "ready" },
+    list = { "list" },
+)]
+fn test_order_topo(command: &str) {
+    let cli = parse(&["wok", command, "--order", "topo"]).unwrap();
+    let order = match cli.command {
+        Command::Ready { order, .. } | Command::List { order, .. } => order,
+        _ => panic!("Expected Ready or List command"),
+    };
+    assert_eq!(order, Order::Topo);
+}
+
+#[test]
+fn test_list_columns_and_pager() {
+    let cli = parse(&["wok", "list", "--columns", "id,status,title", "--pager"]).unwrap();
+    match cli.command {
+        Command::List { columns, pager, .. } => {
+            assert_eq!(
+                columns,
+                vec![ListColumn::Id, ListColumn::Status, ListColumn::Title]
+            );
+            assert!(pager);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[parameterized(
+    status = { "status", GroupBy::Status },
+    label_prefix = { "label-prefix", GroupBy::LabelPrefix },
+    prefix = { "prefix", GroupBy::Prefix },
+)]
+fn test_list_group_by(value: &str, expected: GroupBy) {
+    let cli = parse(&["wok", "list", "--group-by", value]).unwrap();
+    match cli.command {
+        Command::List { group_by, .. } => assert_eq!(group_by, Some(expected)),
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[parameterized(
+    list = { "list" },
+    ready = { "ready" },
+)]
+fn test_sort_and_reverse(command: &str) {
+    let cli = parse(&["wok", command, "--sort", "due", "--reverse"]).unwrap();
+    let sorting = match cli.command {
+        Command::List { sorting, .. } | Command::Ready { sorting, .. } => sorting,
+        _ => panic!("Expected List or Ready command"),
+    };
+    assert_eq!(sorting.sort, Some(SortKey::Due));
+    assert!(sorting.reverse);
+}
+
+#[test]
+fn test_search_sort() {
+    let cli = parse(&["wok", "search", "auth", "--sort", "title"]).unwrap();
+    match cli.command {
+        Command::Search { sorting, .. } => assert_eq!(sorting.sort, Some(SortKey::Title)),
+        _ => panic!("Expected Search command"),
+    }
+}
+
+#[test]
+fn test_reverse_requires_sort() {
+    assert!(parse(&["wok", "list", "--reverse"]).is_err());
+}
+
+#[test]
+fn test_list_rejects_unknown_column() {
+    assert!(parse(&["wok", "list", "--columns", "id,priority"]).is_err());
+}
+
+#[test]
+fn test_order_rejects_unknown_value() {
+    assert!(parse(&["wok", "ready", "--order", "random"]).is_err());
+}
+
+// Next command tests
+#[test]
+fn test_next_defaults() {
+    let cli = parse(&["wok", "next"]).unwrap();
+    match cli.command {
+        Command::Next {
+            assignee,
+            claim,
+            output,
+            ..
+        } => {
+            assert!(assignee.is_none());
+            assert!(!claim);
+            assert!(matches!(output, OutputFormat::Text));
+        }
+        _ => panic!("Expected Next command"),
+    }
+}
+
+#[test]
+fn test_next_claim_with_filters() {
+    let cli = parse(&[
+        "wok", "next", "--claim", "-a", "agent-1", "-t", "bug", "-l", "backend", "-o", "json",
+    ])
+    .unwrap();
+    match cli.command {
+        Command::Next {
+            type_label,
+            assignee,
+            claim,
+            output,
+        } => {
+            assert_eq!(type_label.r#type, vec!["bug"]);
+            assert_eq!(type_label.label, vec!["backend"]);
+            assert_eq!(assignee.as_deref(), Some("agent-1"));
+            assert!(claim);
+            assert!(matches!(output, OutputFormat::Json));
+        }
+        _ => panic!("Expected Next command"),
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Phase 4: Parameterized output format tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -240,7 +390,7 @@ fn test_list_output_format_text_default() {
     let cli = parse(&["wok", "list"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Text));
+            assert!(matches!(output, ListFormat::Text));
         }
         _ => panic!("Expected List command"),
     }
@@ -251,7 +401,7 @@ fn test_list_output_format_json_long() {
     let cli = parse(&["wok", "list", "--output", "json"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Json));
+            assert!(matches!(output, ListFormat::Json));
         }
         _ => panic!("Expected List command"),
     }
@@ -262,7 +412,7 @@ fn test_list_output_format_json_short() {
     let cli = parse(&["wok", "list", "-o", "json"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Json));
+            assert!(matches!(output, ListFormat::Json));
         }
         _ => panic!("Expected List command"),
     }
@@ -273,7 +423,7 @@ fn test_list_output_format_ids_long() {
     let cli = parse(&["wok", "list", "--output", "ids"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Id));
+            assert!(matches!(output, ListFormat::Id));
         }
         _ => panic!("Expected List command"),
     }
@@ -284,7 +434,7 @@ fn test_list_output_format_ids_short() {
     let cli = parse(&["wok", "list", "-o", "ids"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Id));
+            assert!(matches!(output, ListFormat::Id));
         }
         _ => panic!("Expected List command"),
     }
@@ -295,7 +445,7 @@ fn test_list_output_format_id_alias() {
     let cli = parse(&["wok", "list", "-o", "id"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Id));
+            assert!(matches!(output, ListFormat::Id));
         }
         _ => panic!("Expected List command"),
     }
@@ -306,12 +456,29 @@ fn test_list_output_format_text_explicit() {
     let cli = parse(&["wok", "list", "-o", "text"]).unwrap();
     match cli.command {
         Command::List { output, .. } => {
-            assert!(matches!(output, OutputFormat::Text));
+            assert!(matches!(output, ListFormat::Text));
         }
         _ => panic!("Expected List command"),
     }
 }
 
+#[test]
+fn test_list_output_format_json_paged() {
+    let cli = parse(&["wok", "list", "-o", "json-paged", "--page-size", "50"]).unwrap();
+    match cli.command {
+        Command::List { output, paging, .. } => {
+            assert_eq!(output, ListFormat::JsonPaged);
+            assert_eq!(paging.page_size.map(|n| n.get()), Some(50));
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn test_list_page_size_rejects_zero() {
+    assert!(parse(&["wok", "list", "-o", "json-paged", "--page-size", "0"]).is_err());
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Phase 5: Parameterized filter flag tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -351,3 +518,23 @@ fn test_list_limit_parsing(args: &[&str], expected_limit: Option<usize>) {
         _ => panic!("Expected List command"),
     }
 }
+
+#[test]
+fn test_list_mine_expands_to_me() {
+    let cli = parse(&["wok", "list", "--mine"]).unwrap();
+    match cli.command {
+        Command::List { assignee_args, .. } => {
+            assert!(assignee_args.mine);
+            assert_eq!(assignee_args.assignees(), vec!["@me"]);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[parameterized(
+    with_assignee = { &["wok", "list", "--mine", "-a", "bob"] },
+    with_unassigned = { &["wok", "list", "--mine", "--unassigned"] },
+)]
+fn test_list_mine_conflicts(args: &[&str]) {
+    assert!(parse(args).is_err());
+}
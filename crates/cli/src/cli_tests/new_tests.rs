@@ -25,6 +25,7 @@ fn test_new_with_title_only() {
             link,
             assignee,
             priority,
+            estimate,
             description,
             blocks,
             blocked_by,
@@ -32,6 +33,7 @@ fn test_new_with_title_only() {
             tracked_by,
             output,
             prefix,
+            force,
         } => {
             assert_eq!(type_or_title, "My issue title");
             assert!(title.is_none());
@@ -40,6 +42,7 @@ fn test_new_with_title_only() {
             assert!(link.is_empty());
             assert!(assignee.is_none());
             assert!(priority.is_none());
+            assert!(estimate.is_none());
             assert!(description.is_none());
             assert!(blocks.is_empty());
             assert!(blocked_by.is_empty());
@@ -47,6 +50,7 @@ fn test_new_with_title_only() {
             assert!(tracked_by.is_empty());
             assert!(matches!(output, OutputFormat::Text));
             assert!(prefix.is_none());
+            assert!(!force);
         }
         _ => panic!("Expected New command"),
     }
@@ -191,6 +195,15 @@ fn test_new_description_no_short_flag() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_new_with_estimate() {
+    let cli = parse(&["wok", "new", "task", "My task", "--estimate", "3pt"]).unwrap();
+    match cli.command {
+        Command::New { estimate, .. } => assert_eq!(estimate.as_deref(), Some("3pt")),
+        _ => panic!("Expected New command"),
+    }
+}
+
 // Priority flag tests (hidden argument)
 #[test]
 fn test_new_with_priority() {
@@ -587,3 +600,9 @@ fn test_new_prefix_with_other_flags() {
         _ => panic!("Expected New command"),
     }
 }
+
+#[test]
+fn test_new_with_force() {
+    let cli = parse(&["wok", "new", "Fix crash", "--force"]).unwrap();
+    assert!(matches!(cli.command, Command::New { force: true, .. }));
+}
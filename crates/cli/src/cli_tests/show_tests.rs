@@ -16,7 +16,7 @@ fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
 fn test_show_command() {
     let cli = parse(&["wok", "show", "prj-1234"]).unwrap();
     match cli.command {
-        Command::Show { ids, output } => {
+        Command::Show { ids, output, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert_eq!(output, "text"); // default output
         }
@@ -28,7 +28,7 @@ fn test_show_command() {
 fn test_show_command_with_json_output() {
     let cli = parse(&["wok", "show", "prj-1234", "--output", "json"]).unwrap();
     match cli.command {
-        Command::Show { ids, output } => {
+        Command::Show { ids, output, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert_eq!(output, "json");
         }
@@ -40,7 +40,7 @@ fn test_show_command_with_json_output() {
 fn test_show_command_with_output_short() {
     let cli = parse(&["wok", "show", "prj-1234", "-o", "json"]).unwrap();
     match cli.command {
-        Command::Show { ids, output } => {
+        Command::Show { ids, output, .. } => {
             assert_eq!(ids, vec!["prj-1234"]);
             assert_eq!(output, "json");
         }
@@ -52,7 +52,7 @@ fn test_show_command_with_output_short() {
 fn test_show_command_multiple_ids() {
     let cli = parse(&["wok", "show", "prj-1", "prj-2", "prj-3"]).unwrap();
     match cli.command {
-        Command::Show { ids, output } => {
+        Command::Show { ids, output, .. } => {
             assert_eq!(ids, vec!["prj-1", "prj-2", "prj-3"]);
             assert_eq!(output, "text");
         }
@@ -64,7 +64,7 @@ fn test_show_command_multiple_ids() {
 fn test_show_command_multiple_ids_with_json() {
     let cli = parse(&["wok", "show", "prj-1", "prj-2", "-o", "json"]).unwrap();
     match cli.command {
-        Command::Show { ids, output } => {
+        Command::Show { ids, output, .. } => {
             assert_eq!(ids, vec!["prj-1", "prj-2"]);
             assert_eq!(output, "json");
         }
@@ -76,7 +76,7 @@ fn test_show_command_multiple_ids_with_json() {
 fn test_tree_command() {
     let cli = parse(&["wok", "tree", "prj-1234"]).unwrap();
     match cli.command {
-        Command::Tree { ids } => assert_eq!(ids, vec!["prj-1234"]),
+        Command::Tree { ids, .. } => assert_eq!(ids, vec!["prj-1234"]),
         _ => panic!("Expected Tree command"),
     }
 }
@@ -85,7 +85,40 @@ fn test_tree_command() {
 fn test_tree_command_multiple_ids() {
     let cli = parse(&["wok", "tree", "prj-1234", "prj-5678"]).unwrap();
     match cli.command {
-        Command::Tree { ids } => assert_eq!(ids, vec!["prj-1234", "prj-5678"]),
+        Command::Tree { ids, .. } => assert_eq!(ids, vec!["prj-1234", "prj-5678"]),
         _ => panic!("Expected Tree command"),
     }
 }
+
+#[test]
+fn test_show_and_tree_raw_flag() {
+    let cli = parse(&["wok", "show", "prj-1", "--raw"]).unwrap();
+    assert!(matches!(cli.command, Command::Show { raw: true, .. }));
+
+    let cli = parse(&["wok", "tree", "prj-1", "--raw"]).unwrap();
+    assert!(matches!(cli.command, Command::Tree { raw: true, .. }));
+
+    let cli = parse(&["wok", "show", "prj-1"]).unwrap();
+    assert!(matches!(cli.command, Command::Show { raw: false, .. }));
+}
+
+#[test]
+fn test_show_as_of_and_diff() {
+    let cli = parse(&["wok", "show", "prj-1", "--as-of", "2026-01-15"]).unwrap();
+    match cli.command {
+        Command::Show { as_of, diff, .. } => {
+            assert_eq!(as_of.as_deref(), Some("2026-01-15"));
+            assert_eq!(diff, None);
+        }
+        _ => panic!("Expected Show command"),
+    }
+
+    let cli = parse(&["wok", "show", "prj-1", "--diff", "3d"]).unwrap();
+    assert!(matches!(cli.command, Command::Show { diff: Some(d), .. } if d == "3d"));
+}
+
+#[test]
+fn test_show_as_of_conflicts_with_diff() {
+    let result = parse(&["wok", "show", "prj-1", "--as-of", "1d", "--diff", "2d"]);
+    assert!(result.is_err());
+}
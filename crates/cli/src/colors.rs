@@ -1,13 +1,27 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Terminal color utilities for help output.
+//! Terminal color utilities for help and issue output.
 //!
 //! Respects environment variables:
 //! - `NO_COLOR=1`: Disables colors
 //! - `COLOR=1`: Forces colors even without TTY
+//!
+//! Without either, the `color` setting decides, then whether stdout is a TTY.
 
+use std::collections::HashMap;
 use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::config::ColorMode;
+
+/// The `color` setting, stored as `ColorMode as u8`.
+static MODE: AtomicU8 = AtomicU8::new(ColorMode::Auto as u8);
+
+/// Apply the `color` setting for the rest of the process.
+pub fn set_mode(mode: ColorMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
 
 /// ANSI 256-color codes matching v0 help conventions
 pub mod codes {
@@ -22,6 +36,7 @@ pub mod codes {
     pub const HEADER_START: &str = "\x1b[38;5;74m";
     pub const LITERAL_START: &str = "\x1b[38;5;250m";
     pub const CONTEXT_START: &str = "\x1b[38;5;245m";
+    pub const BOLD_START: &str = "\x1b[1m";
     pub const RESET: &str = "\x1b[0m";
 }
 
@@ -37,8 +52,12 @@ pub fn should_colorize() -> bool {
         return true;
     }
 
-    // Default: enable colors only if stdout is a TTY
-    std::io::stdout().is_terminal()
+    match MODE.load(Ordering::Relaxed) {
+        m if m == ColorMode::Always as u8 => true,
+        m if m == ColorMode::Never as u8 => false,
+        // Default: enable colors only if stdout is a TTY
+        _ => std::io::stdout().is_terminal(),
+    }
 }
 
 /// Format a 256-color ANSI escape sequence for foreground color.
@@ -64,6 +83,87 @@ pub fn context(text: &str) -> String {
     format!("{}{}{}", fg256(codes::CONTEXT), text, RESET)
 }
 
+/// Apply bold weight to text.
+pub fn bold(text: &str) -> String {
+    format!("{}{}{}", codes::BOLD_START, text, RESET)
+}
+
+/// Label color names and their 256-color codes.
+pub const LABEL_COLORS: [(&str, u8); 12] = [
+    ("black", 0),
+    ("red", 1),
+    ("green", 2),
+    ("yellow", 3),
+    ("blue", 4),
+    ("magenta", 5),
+    ("cyan", 6),
+    ("white", 7),
+    ("gray", 8),
+    ("orange", 208),
+    ("purple", 141),
+    ("pink", 211),
+];
+
+/// Parse a label color: a name from [`LABEL_COLORS`] or a code from 0 to 255.
+pub fn parse_label_color(color: &str) -> Option<u8> {
+    let color = color.trim().to_ascii_lowercase();
+    LABEL_COLORS
+        .iter()
+        .find(|(name, _)| *name == color)
+        .map(|&(_, code)| code)
+        .or_else(|| color.parse().ok())
+}
+
+/// Display colors for labels, taken from the label registry.
+///
+/// A label without a color of its own uses its namespace's (`team:`).
+#[derive(Debug, Default)]
+pub struct LabelPalette {
+    colors: HashMap<String, u8>,
+}
+
+impl LabelPalette {
+    /// Build a palette from `(name, color)` pairs, skipping unknown colors.
+    pub fn new<I, S>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (S, S)>,
+        S: AsRef<str>,
+    {
+        let colors = entries
+            .into_iter()
+            .filter_map(|(name, color)| {
+                parse_label_color(color.as_ref()).map(|code| (name.as_ref().to_string(), code))
+            })
+            .collect();
+        Self { colors }
+    }
+
+    /// The color code for a label, falling back to its namespace.
+    pub fn color(&self, label: &str) -> Option<u8> {
+        self.colors.get(label).copied().or_else(|| {
+            let ns = wk_core::label::namespace(label)?;
+            self.colors.get(&format!("{}:", ns)).copied()
+        })
+    }
+
+    /// Paint a label in its color when colors are enabled.
+    pub fn paint(&self, label: &str) -> String {
+        Self::paint_code(label, self.color(label))
+    }
+
+    /// Paint a namespace name (`team`) in the color of `team:`.
+    pub fn paint_namespace(&self, ns: &str) -> String {
+        Self::paint_code(ns, self.colors.get(&format!("{}:", ns)).copied())
+    }
+
+    fn paint_code(text: &str, code: Option<u8>) -> String {
+        match code {
+            Some(code) if should_colorize() => format!("{}{}{}", fg256(code), text, RESET),
+            _ => text.to_string(),
+        }
+    }
+}
+
 /// Colorize an examples help block.
 ///
 /// Expects format like:
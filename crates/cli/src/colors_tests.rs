@@ -4,6 +4,7 @@
 #![allow(clippy::unwrap_used)]
 
 use super::*;
+use yare::parameterized;
 
 // =============================================================================
 // Helper functions for testing
@@ -399,6 +400,51 @@ Filter Expressions (-q/--filter):
     assert_eq!(stripped, input);
 }
 
+// =============================================================================
+// Label colors
+// =============================================================================
+
+#[parameterized(
+    name = { "red", Some(1) },
+    upper = { "Blue", Some(4) },
+    extended = { "orange", Some(208) },
+    code = { "74", Some(74) },
+    out_of_range = { "256", None },
+    unknown = { "teal", None },
+)]
+fn parse_label_color_accepts_names_and_codes(input: &str, expected: Option<u8>) {
+    assert_eq!(parse_label_color(input), expected);
+}
+
+#[test]
+fn label_palette_falls_back_to_namespace() {
+    let palette = LabelPalette::new([("bug", "red"), ("team:", "74"), ("team:ops", "green")]);
+
+    assert_eq!(palette.color("bug"), Some(1));
+    assert_eq!(palette.color("team:alpha"), Some(74));
+    assert_eq!(palette.color("team:ops"), Some(2));
+    assert_eq!(palette.color("feature"), None);
+}
+
+#[test]
+fn label_palette_skips_unknown_colors() {
+    let palette = LabelPalette::new([("bug", "teal")]);
+    assert_eq!(palette.color("bug"), None);
+    assert_eq!(palette.paint("bug"), "bug");
+}
+
+#[test]
+fn label_palette_paints_only_with_colors_enabled() {
+    let palette = LabelPalette::new([("bug", "red")]);
+    let painted = palette.paint("bug");
+    assert_eq!(strip_ansi(&painted), "bug");
+    if should_colorize() {
+        assert!(painted.starts_with("\x1b[38;5;1m"));
+    } else {
+        assert_eq!(painted, "bug");
+    }
+}
+
 // =============================================================================
 // Edge cases and robustness
 // =============================================================================
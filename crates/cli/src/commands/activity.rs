@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::cli::OutputFormat;
+use crate::db::Database;
+use crate::display::format_event_detail;
+use crate::error::Result;
+use crate::history::{format_point, parse_point};
+use crate::models::{Event, Issue};
+use crate::schema::activity::{ActivityEntryJson, ActivityOutputJson};
+
+use super::assign::resolve_assignees;
+use super::filtering::matches_prefix;
+use super::open_db;
+
+/// How far back the feed goes when `--since` is not given.
+const DEFAULT_SINCE: &str = "7d";
+
+pub fn run(
+    since: Option<String>,
+    assignee: Vec<String>,
+    prefix: Option<String>,
+    output: OutputFormat,
+) -> Result<()> {
+    let since = parse_point(since.as_deref().unwrap_or(DEFAULT_SINCE), Utc::now())?;
+    let (db, config, work_dir) = open_db()?;
+    let assignee = resolve_assignees(assignee, &config, &work_dir)?;
+    let prefix = prefix.or((!config.prefix.is_empty()).then_some(config.prefix));
+    run_impl(&db, since, &assignee, prefix, output)
+}
+
+/// Internal implementation that accepts db for testing.
+///
+/// `assignee` matches the issue's current assignee. Events for issues that
+/// no longer exist are skipped.
+pub(crate) fn run_impl(
+    db: &Database,
+    since: DateTime<Utc>,
+    assignee: &[String],
+    prefix: Option<String>,
+    output: OutputFormat,
+) -> Result<()> {
+    let mut issues: HashMap<String, Option<Issue>> = HashMap::new();
+    let mut feed: Vec<(Event, &Issue)> = Vec::new();
+    let events = db.get_events_since(since)?;
+
+    for event in &events {
+        if !matches_prefix(&prefix, &event.issue_id) {
+            continue;
+        }
+        issues
+            .entry(event.issue_id.clone())
+            .or_insert_with(|| db.get_issue(&event.issue_id).ok());
+    }
+    for event in events {
+        let Some(Some(issue)) = issues.get(&event.issue_id) else {
+            continue;
+        };
+        let assigned = issue.assignee.as_deref();
+        if !assignee.is_empty() && !assignee.iter().any(|a| Some(a.as_str()) == assigned) {
+            continue;
+        }
+        feed.push((event, issue));
+    }
+
+    match output {
+        OutputFormat::Json => {
+            let entries = feed
+                .iter()
+                .map(|(event, issue)| ActivityEntryJson {
+                    event,
+                    title: &issue.title,
+                    assignee: issue.assignee.as_deref(),
+                })
+                .collect();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&ActivityOutputJson(entries))?
+            );
+        }
+        OutputFormat::Id => {
+            let mut seen = HashSet::new();
+            for (event, _) in &feed {
+                if seen.insert(&event.issue_id) {
+                    println!("{}", event.issue_id);
+                }
+            }
+        }
+        OutputFormat::Text => {
+            if feed.is_empty() {
+                println!("No activity since {}", format_point(since));
+            } else {
+                print!("{}", format_activity(&feed));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Text feed grouped under a heading per day.
+pub(crate) fn format_activity(feed: &[(Event, &Issue)]) -> String {
+    let mut output = String::new();
+    let mut current_day: Option<NaiveDate> = None;
+
+    for (event, issue) in feed {
+        let day = event.created_at.date_naive();
+        if current_day != Some(day) {
+            if current_day.is_some() {
+                output.push('\n');
+            }
+            output.push_str(&format!("{}\n", day.format("%Y-%m-%d")));
+            current_day = Some(day);
+        }
+        output.push_str(&format!(
+            "  {}  {} {}{}  ({})\n",
+            event.created_at.format("%H:%M"),
+            event.issue_id,
+            event.action,
+            format_event_detail(event),
+            issue.title
+        ));
+    }
+
+    output
+}
+
+#[cfg(test)]
+#[path = "activity_tests.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::{Action, IssueType};
+
+fn at(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+}
+
+fn event(issue_id: &str, action: Action, when: &str) -> Event {
+    let mut event = Event::new(issue_id.to_string(), action);
+    event.created_at = at(when);
+    event
+}
+
+#[test]
+fn test_format_activity_groups_by_day() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Fix login")
+        .create_issue("test-2", IssueType::Bug, "Crash on save");
+    let first = ctx.db.get_issue("test-1").unwrap();
+    let second = ctx.db.get_issue("test-2").unwrap();
+
+    let labeled = event("test-2", Action::Labeled, "2026-01-16T08:05:00Z")
+        .with_values(None, Some("urgent".to_string()));
+    let feed = vec![
+        (
+            event("test-1", Action::Started, "2026-01-15T09:30:00Z"),
+            &first,
+        ),
+        (
+            event("test-2", Action::Created, "2026-01-15T17:00:00Z"),
+            &second,
+        ),
+        (labeled, &second),
+    ];
+
+    assert_eq!(
+        format_activity(&feed),
+        "2026-01-15\n  09:30  test-1 started  (Fix login)\n  17:00  test-2 created  (Crash on save)\n\n2026-01-16\n  08:05  test-2 labeled urgent  (Crash on save)\n"
+    );
+}
+
+#[test]
+fn test_run_impl_text_and_json() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_label("test-1", "urgent");
+    let since = Utc::now() - Duration::hours(1);
+
+    assert!(run_impl(&ctx.db, since, &[], None, OutputFormat::Text).is_ok());
+    assert!(run_impl(&ctx.db, since, &[], None, OutputFormat::Json).is_ok());
+    assert!(run_impl(&ctx.db, since, &[], None, OutputFormat::Id).is_ok());
+}
+
+#[test]
+fn test_run_impl_with_no_events() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let future = Utc::now() + Duration::days(1);
+
+    assert!(run_impl(&ctx.db, future, &[], None, OutputFormat::Text).is_ok());
+}
+
+#[test]
+fn test_run_impl_with_filters() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let since = Utc::now() - Duration::hours(1);
+
+    let assignee = vec!["alice".to_string()];
+    assert!(run_impl(&ctx.db, since, &assignee, None, OutputFormat::Text).is_ok());
+    let prefix = Some("other".to_string());
+    assert!(run_impl(&ctx.db, since, &[], prefix, OutputFormat::Text).is_ok());
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok alias` - manage the `[alias]` table in `.wok/config.toml`.
+
+use std::path::Path;
+
+use crate::alias;
+use crate::cli::{AliasCommand, OutputFormat};
+use crate::config::{find_work_dir, Config};
+use crate::error::{Error, Result};
+
+/// Execute an alias subcommand.
+pub fn run(cmd: AliasCommand) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    match cmd {
+        AliasCommand::List { output } => list(&Config::load(&work_dir)?, output),
+        AliasCommand::Add { name, expansion } => {
+            add(&work_dir, &name, &expansion)?;
+            println!("Added alias {} = {}", name, expansion);
+            Ok(())
+        }
+        AliasCommand::Remove { name } => {
+            remove(&work_dir, &name)?;
+            println!("Removed alias {}", name);
+            Ok(())
+        }
+    }
+}
+
+fn list(config: &Config, output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Text => {
+            if config.alias.is_empty() {
+                println!("No aliases defined.");
+            }
+            for (name, expansion) in &config.alias {
+                println!("{} = {}", name, expansion);
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&config.alias)?),
+        OutputFormat::Id => {
+            for name in config.alias.keys() {
+                println!("{}", name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Define `name`, checking that it can be run and expands without a loop.
+pub(crate) fn add(work_dir: &Path, name: &str, expansion: &str) -> Result<()> {
+    let invalid = |reason: String| Error::InvalidAlias {
+        name: name.to_string(),
+        reason,
+    };
+    if name.is_empty() || name.starts_with('-') || name.contains(char::is_whitespace) {
+        return Err(invalid(
+            "names are single words not starting with '-'".to_string(),
+        ));
+    }
+    if alias::is_builtin(name) {
+        return Err(invalid(format!("'{}' is a built-in command", name)));
+    }
+    alias::parse(name, expansion)?;
+
+    let mut config = Config::load_file(work_dir)?;
+    config.alias.insert(name.to_string(), expansion.to_string());
+    alias::expand(vec!["wok".to_string(), name.to_string()], &config.alias)?;
+    config.save(work_dir)
+}
+
+pub(crate) fn remove(work_dir: &Path, name: &str) -> Result<()> {
+    let mut config = Config::load_file(work_dir)?;
+    if config.alias.remove(name).is_none() {
+        return Err(Error::AliasNotFound(name.to_string()));
+    }
+    config.save(work_dir)
+}
+
+#[cfg(test)]
+#[path = "alias_tests.rs"]
+mod tests;
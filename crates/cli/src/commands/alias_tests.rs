@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::config::init_work_dir;
+use tempfile::TempDir;
+
+fn setup() -> (TempDir, std::path::PathBuf) {
+    let temp = TempDir::new().unwrap();
+    let work_dir = init_work_dir(temp.path(), "test").unwrap();
+    (temp, work_dir)
+}
+
+#[test]
+fn add_saves_to_config() {
+    let (_temp, work_dir) = setup();
+    add(&work_dir, "wip", "list -s in_progress").unwrap();
+
+    let config = Config::load(&work_dir).unwrap();
+    assert_eq!(
+        config.alias.get("wip").map(String::as_str),
+        Some("list -s in_progress")
+    );
+    let content = std::fs::read_to_string(work_dir.join("config.toml")).unwrap();
+    assert!(content.contains("[alias]"));
+}
+
+#[test]
+fn add_replaces_existing() {
+    let (_temp, work_dir) = setup();
+    add(&work_dir, "wip", "list -s in_progress").unwrap();
+    add(&work_dir, "wip", "ready").unwrap();
+
+    let config = Config::load(&work_dir).unwrap();
+    assert_eq!(config.alias.get("wip").map(String::as_str), Some("ready"));
+}
+
+#[test]
+fn add_rejects_builtin_names() {
+    let (_temp, work_dir) = setup();
+    let err = add(&work_dir, "list", "ready").unwrap_err();
+    assert!(err.to_string().contains("built-in"));
+}
+
+#[test]
+fn add_rejects_bad_names() {
+    let (_temp, work_dir) = setup();
+    for name in ["", "-x", "two words"] {
+        assert!(add(&work_dir, name, "list").is_err(), "{:?}", name);
+    }
+}
+
+#[test]
+fn add_rejects_loops_without_saving() {
+    let (_temp, work_dir) = setup();
+    add(&work_dir, "a", "b").unwrap();
+    let err = add(&work_dir, "b", "a -s todo").unwrap_err();
+    assert!(matches!(err, Error::AliasLoop { .. }));
+
+    let config = Config::load(&work_dir).unwrap();
+    assert!(!config.alias.contains_key("b"));
+}
+
+#[test]
+fn remove_deletes_alias() {
+    let (_temp, work_dir) = setup();
+    add(&work_dir, "wip", "list -s in_progress").unwrap();
+    remove(&work_dir, "wip").unwrap();
+
+    assert!(Config::load(&work_dir).unwrap().alias.is_empty());
+}
+
+#[test]
+fn remove_unknown_alias_fails() {
+    let (_temp, work_dir) = setup();
+    let err = remove(&work_dir, "wip").unwrap_err();
+    assert!(matches!(err, Error::AliasNotFound(_)));
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+
+use crate::config::Config;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::{Action, Event};
+use crate::t;
+use crate::validate::validate_assignee;
+
+use super::{apply_mutation, open_db};
+
+/// Assignee placeholder for the current user.
+pub const ME: &str = "@me";
+
+pub fn assign(id: &str, who: &str) -> Result<()> {
+    let (db, config, work_dir) = open_db()?;
+    let who = resolve_assignee(who, &config, &work_dir)?;
+    check_roster(&who, &config)?;
+    assign_impl(&db, id, &who)
+}
+
+pub fn unassign(id: &str) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    unassign_impl(&db, id)
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn assign_impl(db: &Database, id: &str, who: &str) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    let issue = db.get_issue(&resolved_id)?;
+    validate_assignee(who)?;
+    let who = who.trim();
+
+    if issue.assignee.as_deref() == Some(who) {
+        println!("{}", t!("assign.already", id = resolved_id, assignee = who));
+        return Ok(());
+    }
+
+    apply_mutation(
+        db,
+        Event::new(resolved_id.clone(), Action::Assigned)
+            .with_values(issue.assignee, Some(who.to_string())),
+        || db.set_assignee(&resolved_id, who),
+    )?;
+
+    println!(
+        "{}",
+        t!("assign.assigned", id = resolved_id, assignee = who)
+    );
+    Ok(())
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn unassign_impl(db: &Database, id: &str) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    let issue = db.get_issue(&resolved_id)?;
+
+    if issue.assignee.is_none() {
+        println!("{}", t!("assign.not_assigned", id = resolved_id));
+        return Ok(());
+    }
+
+    apply_mutation(
+        db,
+        Event::new(resolved_id.clone(), Action::Unassigned).with_values(issue.assignee, None),
+        || db.clear_assignee(&resolved_id),
+    )?;
+
+    println!("{}", t!("assign.unassigned", id = resolved_id));
+    Ok(())
+}
+
+/// The current user: `user` from config, else `.wok/current/assignee`.
+pub(crate) fn current_user(config: &Config, work_dir: &Path) -> Option<String> {
+    config
+        .user
+        .clone()
+        .or_else(|| std::fs::read_to_string(work_dir.join("current").join("assignee")).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Who events and notes are recorded as: `actor` (or `WOK_ACTOR`), else
+/// the current user.
+pub(crate) fn current_actor(config: &Config, work_dir: &Path) -> Option<String> {
+    config
+        .actor
+        .clone()
+        .or_else(|| current_user(config, work_dir))
+}
+
+/// Resolve `@me` to the current user; other names pass through unchanged.
+pub(crate) fn resolve_assignee(name: &str, config: &Config, work_dir: &Path) -> Result<String> {
+    if name.trim() != ME {
+        return Ok(name.to_string());
+    }
+    current_user(config, work_dir).ok_or(Error::UserNotConfigured)
+}
+
+/// Resolve `@me` in a list of assignee filters.
+pub(crate) fn resolve_assignees(
+    names: Vec<String>,
+    config: &Config,
+    work_dir: &Path,
+) -> Result<Vec<String>> {
+    names
+        .iter()
+        .map(|name| resolve_assignee(name, config, work_dir))
+        .collect()
+}
+
+/// Check an assignee against the team roster, if one is configured.
+pub(crate) fn check_roster(name: &str, config: &Config) -> Result<()> {
+    let name = name.trim();
+    if config.team.is_empty() || config.team.iter().any(|member| member == name) {
+        return Ok(());
+    }
+    Err(Error::UnknownAssignee {
+        name: name.to_string(),
+        team: config.team.join(", "),
+    })
+}
+
+#[cfg(test)]
+#[path = "assign_tests.rs"]
+mod tests;
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use yare::parameterized;
+
+fn last_action(ctx: &TestContext, id: &str) -> Action {
+    ctx.db.get_events(id).unwrap().last().unwrap().action
+}
+
+#[test]
+fn assign_sets_assignee_and_logs_event() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+
+    assign_impl(&ctx.db, "test-1", "alice").unwrap();
+
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    assert_eq!(issue.assignee.as_deref(), Some("alice"));
+    assert_eq!(last_action(&ctx, "test-1"), Action::Assigned);
+}
+
+#[test]
+fn assign_same_person_is_idempotent() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+    assign_impl(&ctx.db, "test-1", "alice").unwrap();
+    let events_before = ctx.db.get_events("test-1").unwrap().len();
+
+    assign_impl(&ctx.db, "test-1", "alice").unwrap();
+
+    assert_eq!(ctx.db.get_events("test-1").unwrap().len(), events_before);
+}
+
+#[test]
+fn assign_rejects_empty_name() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+
+    let result = assign_impl(&ctx.db, "test-1", "  ");
+    assert!(matches!(result, Err(Error::FieldEmpty { .. })));
+}
+
+#[test]
+fn unassign_clears_assignee() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+    assign_impl(&ctx.db, "test-1", "alice").unwrap();
+
+    unassign_impl(&ctx.db, "test-1").unwrap();
+
+    assert_eq!(ctx.db.get_issue("test-1").unwrap().assignee, None);
+    assert_eq!(last_action(&ctx, "test-1"), Action::Unassigned);
+}
+
+#[test]
+fn unassign_when_unassigned_is_noop() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+    let events_before = ctx.db.get_events("test-1").unwrap().len();
+
+    unassign_impl(&ctx.db, "test-1").unwrap();
+
+    assert_eq!(ctx.db.get_events("test-1").unwrap().len(), events_before);
+}
+
+#[test]
+fn resolve_me_uses_config_user() {
+    let mut ctx = TestContext::new();
+    ctx.config.user = Some("alice".to_string());
+
+    let who = resolve_assignee("@me", &ctx.config, &ctx.work_dir).unwrap();
+    assert_eq!(who, "alice");
+}
+
+#[test]
+fn resolve_me_falls_back_to_current_assignee() {
+    let ctx = TestContext::new();
+    std::fs::create_dir_all(ctx.work_dir.join("current")).unwrap();
+    std::fs::write(ctx.work_dir.join("current").join("assignee"), "bob\n").unwrap();
+
+    let who = resolve_assignee("@me", &ctx.config, &ctx.work_dir).unwrap();
+    assert_eq!(who, "bob");
+}
+
+#[test]
+fn resolve_me_without_user_fails() {
+    let ctx = TestContext::new();
+
+    let result = resolve_assignee("@me", &ctx.config, &ctx.work_dir);
+    assert!(matches!(result, Err(Error::UserNotConfigured)));
+}
+
+#[test]
+fn resolve_other_names_unchanged() {
+    let ctx = TestContext::new();
+
+    let who = resolve_assignee("carol", &ctx.config, &ctx.work_dir).unwrap();
+    assert_eq!(who, "carol");
+}
+
+#[test]
+fn resolve_assignees_expands_me_in_list() {
+    let mut ctx = TestContext::new();
+    ctx.config.user = Some("alice".to_string());
+
+    let names = vec!["@me".to_string(), "bob".to_string()];
+    let resolved = resolve_assignees(names, &ctx.config, &ctx.work_dir).unwrap();
+    assert_eq!(resolved, vec!["alice", "bob"]);
+}
+
+#[parameterized(
+    no_roster = { &[], "anyone", true },
+    member = { &["alice", "bob"], "bob", true },
+    trimmed_member = { &["alice"], " alice ", true },
+    non_member = { &["alice", "bob"], "mallory", false },
+)]
+fn roster_check(team: &[&str], name: &str, allowed: bool) {
+    let mut ctx = TestContext::new();
+    ctx.config.team = team.iter().map(|s| s.to_string()).collect();
+
+    let result = check_roster(name, &ctx.config);
+    assert_eq!(result.is_ok(), allowed);
+    if !allowed {
+        assert!(matches!(result, Err(Error::UnknownAssignee { .. })));
+    }
+}
@@ -0,0 +1,456 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok backup` and `wok restore` - snapshot the database and project
+//! config, and put a snapshot back.
+//!
+//! A backup is a directory holding `issues.db`, copied with SQLite's online
+//! backup API so it is consistent even while other processes write, the
+//! `.wok/` config files that exist, and a `manifest.json` recording each
+//! file's size and SHA-256. `wok restore` checks all of that, and the
+//! database's own integrity, before it changes anything, then backs up the
+//! current state so the restore itself can be undone.
+//!
+//! With `[backup] auto = true`, imports, prefix renames and `wok sweep
+//! --close` first take an automatic backup into `.wok/backups/`; only the
+//! newest `[backup] keep` of those are kept.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use wk_core::migrate::LATEST_VERSION;
+
+use crate::cli::OutputFormat;
+use crate::config::{find_work_dir, get_db_path, wok_state_dir, Config, CONFIG_FILE_NAME};
+use crate::daemon;
+use crate::error::{Error, Result};
+
+use super::open_db;
+
+/// Directory under `.wok/` that backups go to unless `--to` says otherwise.
+pub(crate) const BACKUPS_DIR: &str = "backups";
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+const DB_FILE: &str = "issues.db";
+
+/// Version of the backup layout, recorded in the manifest.
+const FORMAT: u32 = 1;
+
+/// Project files a backup carries when they exist.
+const PROJECT_FILES: [&str; 4] = [CONFIG_FILE_NAME, "hooks.toml", "hooks.json", "rules.toml"];
+
+/// Database pages copied per backup step.
+const PAGES_PER_STEP: i32 = 1024;
+
+/// Marks automatic backups, the only ones rotation removes.
+const AUTO_MARKER: &str = "-auto-";
+
+/// What a backup holds, stored as its `manifest.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Manifest {
+    pub format: u32,
+    pub created_at: DateTime<Utc>,
+    pub wok_version: String,
+    /// The database's schema version when it was backed up.
+    pub schema_version: u32,
+    pub issues: usize,
+    /// Command an automatic backup was taken before.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub files: Vec<BackupFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BackupFile {
+    /// Name within the backup directory.
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+impl Manifest {
+    fn has(&self, path: &str) -> bool {
+        self.files.iter().any(|f| f.path == path)
+    }
+}
+
+/// JSON output of `wok backup` and `wok restore`.
+#[derive(Debug, Serialize)]
+struct Report<'a> {
+    path: String,
+    /// Where `wok restore` saved the state it replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous: Option<String>,
+    #[serde(flatten)]
+    manifest: &'a Manifest,
+}
+
+/// `wok backup [--to <dir>]`
+pub fn backup(to: Option<String>, output: OutputFormat) -> Result<()> {
+    let (db, _config, work_dir) = open_db()?;
+    let dest = match to {
+        Some(path) => PathBuf::from(path),
+        None => new_backup_path(&work_dir, None)?,
+    };
+    let manifest = backup_impl(&db.conn, &work_dir, &dest, None)?;
+
+    match output {
+        OutputFormat::Json => print_json(&dest, None, &manifest)?,
+        OutputFormat::Id => println!("{}", dest.display()),
+        OutputFormat::Text => println!(
+            "Backed up {} issue(s) to {}",
+            manifest.issues,
+            dest.display()
+        ),
+    }
+    Ok(())
+}
+
+/// `wok restore <archive> [--check]`
+pub fn restore(archive: String, check: bool, output: OutputFormat) -> Result<()> {
+    let archive = PathBuf::from(archive);
+    let work_dir = find_work_dir()?;
+    let manifest = verify(&archive)?;
+
+    if check {
+        match output {
+            OutputFormat::Json => print_json(&archive, None, &manifest)?,
+            OutputFormat::Id => println!("{}", archive.display()),
+            OutputFormat::Text => println!(
+                "{} is a valid backup of {} issue(s) from {}",
+                archive.display(),
+                manifest.issues,
+                manifest.created_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+        }
+        return Ok(());
+    }
+
+    if crate::read_only::is_read_only() {
+        return Err(Error::ReadOnly);
+    }
+    // The daemon keeps the shared database open; replacing it underneath
+    // would race its writes.
+    if let Some(info) = daemon::detect_daemon(&wok_state_dir())? {
+        return Err(Error::RestoreDaemonRunning { pid: info.pid });
+    }
+
+    // Rotate only after restoring, which may be from an automatic backup
+    // that rotation would otherwise remove first.
+    let (previous, keep) = {
+        let (db, config, _) = open_db()?;
+        let previous = new_backup_path(&work_dir, Some("restore"))?;
+        backup_impl(&db.conn, &work_dir, &previous, Some("restore"))?;
+        (previous, config.backup.keep())
+    };
+    restore_impl(&archive, &manifest, &work_dir)?;
+    rotate(&work_dir.join(BACKUPS_DIR), keep)?;
+
+    match output {
+        OutputFormat::Json => print_json(&archive, Some(&previous), &manifest)?,
+        OutputFormat::Id => println!("{}", previous.display()),
+        OutputFormat::Text => {
+            println!("Backed up the current state to {}", previous.display());
+            println!(
+                "Restored {} issue(s) from {}",
+                manifest.issues,
+                archive.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn print_json(path: &Path, previous: Option<&Path>, manifest: &Manifest) -> Result<()> {
+    let report = Report {
+        path: path.display().to_string(),
+        previous: previous.map(|p| p.display().to_string()),
+        manifest,
+    };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+/// Back up before `reason` (a command name) when `[backup] auto` is on.
+///
+/// Returns where the backup went, or `None` when automatic backups are off.
+pub(crate) fn auto_backup(
+    conn: &Connection,
+    config: &Config,
+    work_dir: &Path,
+    reason: &str,
+) -> Result<Option<PathBuf>> {
+    if !config.backup.auto() {
+        return Ok(None);
+    }
+    backup_before(conn, config, work_dir, reason).map(Some)
+}
+
+/// Back up before `reason` (a command name) whatever `[backup] auto` says,
+/// for commands that rewrite the whole database.
+///
+/// The backup is rotated with the automatic ones and returns where it went.
+pub(crate) fn backup_before(
+    conn: &Connection,
+    config: &Config,
+    work_dir: &Path,
+    reason: &str,
+) -> Result<PathBuf> {
+    let dest = new_backup_path(work_dir, Some(reason))?;
+    backup_impl(conn, work_dir, &dest, Some(reason))?;
+    rotate(&work_dir.join(BACKUPS_DIR), config.backup.keep())?;
+    Ok(dest)
+}
+
+/// Write a backup of the database behind `conn` and `work_dir`'s project
+/// files to the directory `dest`, which must not exist or be empty.
+///
+/// The backup is assembled next to `dest` and renamed into place, so a
+/// failure never leaves a partial backup behind.
+pub(crate) fn backup_impl(
+    conn: &Connection,
+    work_dir: &Path,
+    dest: &Path,
+    reason: Option<&str>,
+) -> Result<Manifest> {
+    if dest.is_file() || (dest.is_dir() && fs::read_dir(dest)?.next().is_some()) {
+        return Err(Error::BackupExists(dest.display().to_string()));
+    }
+    let mut staging = dest.as_os_str().to_owned();
+    staging.push(".partial");
+    let staging = PathBuf::from(staging);
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    fs::create_dir_all(&staging)?;
+
+    match write_backup(conn, work_dir, &staging, reason) {
+        Ok(manifest) => {
+            if dest.is_dir() {
+                fs::remove_dir(dest)?;
+            }
+            fs::rename(&staging, dest)?;
+            Ok(manifest)
+        }
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging);
+            Err(e)
+        }
+    }
+}
+
+fn write_backup(
+    conn: &Connection,
+    work_dir: &Path,
+    dir: &Path,
+    reason: Option<&str>,
+) -> Result<Manifest> {
+    let (schema_version, issues) = {
+        let mut copy = Connection::open(dir.join(DB_FILE))?;
+        Backup::new(conn, &mut copy)?.run_to_completion(PAGES_PER_STEP, Duration::ZERO, None)?;
+        // A rollback-journal copy is a single file that can be opened
+        // read-only without creating -wal and -shm files beside it.
+        copy.query_row("PRAGMA journal_mode = DELETE", [], |_| Ok(()))?;
+        let version: u32 = copy.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        let issues: i64 = copy.query_row("SELECT COUNT(*) FROM issues", [], |row| row.get(0))?;
+        let issues = usize::try_from(issues)
+            .map_err(|_| Error::CorruptedData(format!("issue count {}", issues)))?;
+        (version, issues)
+    };
+
+    let mut files = vec![describe(dir, DB_FILE)?];
+    for name in PROJECT_FILES {
+        let source = work_dir.join(name);
+        if source.is_file() {
+            fs::copy(&source, dir.join(name))?;
+            files.push(describe(dir, name)?);
+        }
+    }
+
+    let manifest = Manifest {
+        format: FORMAT,
+        created_at: Utc::now(),
+        wok_version: daemon::CLI_VERSION.to_string(),
+        schema_version,
+        issues,
+        reason: reason.map(str::to_string),
+        files,
+    };
+    fs::write(
+        dir.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    )?;
+    Ok(manifest)
+}
+
+fn describe(dir: &Path, name: &str) -> Result<BackupFile> {
+    let bytes = fs::read(dir.join(name))?;
+    let size = u64::try_from(bytes.len())
+        .map_err(|_| Error::CorruptedData(format!("{} is too large", name)))?;
+    Ok(BackupFile {
+        path: name.to_string(),
+        size,
+        sha256: hex::encode(Sha256::digest(&bytes)),
+    })
+}
+
+/// Check that `archive` is a complete, uncorrupted backup this wok can
+/// restore, and return its manifest.
+pub(crate) fn verify(archive: &Path) -> Result<Manifest> {
+    let invalid = |reason: String| Error::InvalidBackup {
+        path: archive.display().to_string(),
+        reason,
+    };
+
+    let text = fs::read_to_string(archive.join(MANIFEST_FILE))
+        .map_err(|e| invalid(format!("cannot read {}: {}", MANIFEST_FILE, e)))?;
+    let manifest: Manifest = serde_json::from_str(&text)
+        .map_err(|e| invalid(format!("cannot parse {}: {}", MANIFEST_FILE, e)))?;
+    if manifest.format != FORMAT {
+        return Err(invalid(format!("unknown format {}", manifest.format)));
+    }
+    for required in [DB_FILE, CONFIG_FILE_NAME] {
+        if !manifest.has(required) {
+            return Err(invalid(format!("{} is not listed", required)));
+        }
+    }
+
+    for file in &manifest.files {
+        if file.path != DB_FILE && !PROJECT_FILES.contains(&file.path.as_str()) {
+            return Err(invalid(format!("unexpected file {}", file.path)));
+        }
+        let bytes = fs::read(archive.join(&file.path))
+            .map_err(|e| invalid(format!("cannot read {}: {}", file.path, e)))?;
+        if u64::try_from(bytes.len()).ok() != Some(file.size)
+            || hex::encode(Sha256::digest(&bytes)) != file.sha256
+        {
+            return Err(invalid(format!(
+                "{} does not match its checksum",
+                file.path
+            )));
+        }
+    }
+
+    let (integrity, version) = (|| -> rusqlite::Result<(String, u32)> {
+        let conn =
+            Connection::open_with_flags(archive.join(DB_FILE), OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        let integrity = conn.query_row("PRAGMA integrity_check", [], |row| row.get(0))?;
+        let version = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        Ok((integrity, version))
+    })()
+    .map_err(|e| invalid(format!("cannot read {}: {}", DB_FILE, e)))?;
+    if integrity != "ok" {
+        return Err(invalid(format!(
+            "{} failed its integrity check: {}",
+            DB_FILE, integrity
+        )));
+    }
+    if version > LATEST_VERSION {
+        return Err(Error::SchemaTooNew {
+            version,
+            supported: LATEST_VERSION,
+        });
+    }
+
+    let config = fs::read_to_string(archive.join(CONFIG_FILE_NAME))?;
+    toml::from_str::<Config>(&config)
+        .map_err(|e| invalid(format!("cannot parse {}: {}", CONFIG_FILE_NAME, e)))?;
+    Ok(manifest)
+}
+
+/// Put a verified backup's project files and database in place.
+///
+/// Project files the backup lacks are removed, so hooks and rules match
+/// the backup too. The database goes where the restored config says it
+/// lives. Returns that database path.
+pub(crate) fn restore_impl(
+    archive: &Path,
+    manifest: &Manifest,
+    work_dir: &Path,
+) -> Result<PathBuf> {
+    for name in PROJECT_FILES {
+        let target = work_dir.join(name);
+        if manifest.has(name) {
+            fs::copy(archive.join(name), &target)?;
+        } else if target.is_file() {
+            fs::remove_file(&target)?;
+        }
+    }
+
+    let config = Config::load(work_dir)?;
+    let db_path = get_db_path(work_dir, &config);
+    if let Some(parent) = db_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let source =
+        Connection::open_with_flags(archive.join(DB_FILE), OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let mut target = Connection::open(&db_path)?;
+    target.busy_timeout(Duration::from_secs(5))?;
+    Backup::new(&source, &mut target)?.run_to_completion(PAGES_PER_STEP, Duration::ZERO, None)?;
+    Ok(db_path)
+}
+
+/// A fresh directory name under `.wok/backups/`, such as
+/// `20260314T120000Z` or `20260314T120000Z-auto-import`.
+fn new_backup_path(work_dir: &Path, reason: Option<&str>) -> Result<PathBuf> {
+    let dir = work_dir.join(BACKUPS_DIR);
+    fs::create_dir_all(&dir)?;
+    let gitignore = dir.join(".gitignore");
+    if !gitignore.exists() {
+        fs::write(&gitignore, "*\n")?;
+    }
+
+    let mut name = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    if let Some(reason) = reason {
+        name = format!("{}{}{}", name, AUTO_MARKER, reason);
+    }
+    let mut path = dir.join(&name);
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{}.{}", name, n));
+        n += 1;
+    }
+    Ok(path)
+}
+
+/// Remove all but the newest `keep` (at least one) automatic backups in
+/// `dir`. Backups taken with `wok backup` are never removed.
+pub(crate) fn rotate(dir: &Path, keep: usize) -> Result<()> {
+    let mut auto: Vec<(String, PathBuf)> = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.contains(AUTO_MARKER) && !name.ends_with(".partial") && entry.path().is_dir() {
+            auto.push((name, entry.path()));
+        }
+    }
+    // Names start with a UTC timestamp, so they sort oldest first; a `.N`
+    // suffix only breaks ties within one second.
+    auto.sort_by(|a, b| sort_key(&a.0).cmp(&sort_key(&b.0)));
+    let excess = auto.len().saturating_sub(keep.max(1));
+    for (_, path) in auto.into_iter().take(excess) {
+        fs::remove_dir_all(path)?;
+    }
+    Ok(())
+}
+
+/// `(timestamp, n)` for a name made by [`new_backup_path`].
+fn sort_key(name: &str) -> (&str, u32) {
+    let timestamp = name.split(AUTO_MARKER).next().unwrap_or(name);
+    let n = name
+        .rsplit_once('.')
+        .and_then(|(_, n)| n.parse().ok())
+        .unwrap_or(1);
+    (timestamp, n)
+}
+
+#[cfg(test)]
+#[path = "backup_tests.rs"]
+mod tests;
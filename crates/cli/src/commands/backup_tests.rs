@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::db::Database;
+use tempfile::{tempdir, TempDir};
+
+/// A private-mode project whose database lives in `.wok/issues.db`.
+fn project() -> (TempDir, PathBuf, Database) {
+    let dir = tempdir().unwrap();
+    let work_dir = dir.path().join(".wok");
+    fs::create_dir_all(&work_dir).unwrap();
+    Config::new_private("prj".to_string())
+        .unwrap()
+        .save(&work_dir)
+        .unwrap();
+    let db = Database::open(&work_dir.join(DB_FILE)).unwrap();
+    (dir, work_dir, db)
+}
+
+fn add_issue(db: &Database, id: &str, title: &str) {
+    db.conn
+        .execute(
+            "INSERT INTO issues (id, type, title, status, created_at, updated_at)
+             VALUES (?1, 'task', ?2, 'todo', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z')",
+            [id, title],
+        )
+        .unwrap();
+}
+
+fn titles(db_path: &Path) -> Vec<String> {
+    let conn = Connection::open(db_path).unwrap();
+    let mut stmt = conn
+        .prepare("SELECT title FROM issues ORDER BY id")
+        .unwrap();
+    let titles = stmt
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<rusqlite::Result<_>>()
+        .unwrap();
+    titles
+}
+
+#[test]
+fn backup_writes_a_verifiable_manifest() {
+    let (dir, work_dir, db) = project();
+    add_issue(&db, "prj-a1", "First");
+    fs::write(work_dir.join("rules.toml"), "").unwrap();
+    let dest = dir.path().join("backup");
+
+    let manifest = backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+
+    assert_eq!(manifest.issues, 1);
+    assert_eq!(manifest.schema_version, LATEST_VERSION);
+    let paths: Vec<_> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, ["issues.db", "config.toml", "rules.toml"]);
+    assert_eq!(verify(&dest).unwrap(), manifest);
+    assert_eq!(titles(&dest.join(DB_FILE)), ["First"]);
+}
+
+#[test]
+fn backup_leaves_no_sqlite_side_files() {
+    let (dir, work_dir, db) = project();
+    let dest = dir.path().join("backup");
+
+    backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+    verify(&dest).unwrap();
+
+    let mut names: Vec<_> = fs::read_dir(&dest)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["config.toml", "issues.db", "manifest.json"]);
+}
+
+#[test]
+fn backup_refuses_a_non_empty_destination() {
+    let (dir, work_dir, db) = project();
+    let dest = dir.path().join("backup");
+    fs::create_dir_all(&dest).unwrap();
+    fs::write(dest.join("keep.txt"), "mine").unwrap();
+
+    let err = backup_impl(&db.conn, &work_dir, &dest, None).unwrap_err();
+
+    assert!(matches!(err, Error::BackupExists(_)));
+    assert_eq!(fs::read_to_string(dest.join("keep.txt")).unwrap(), "mine");
+}
+
+#[test]
+fn backup_into_an_empty_directory() {
+    let (dir, work_dir, db) = project();
+    let dest = dir.path().join("backup");
+    fs::create_dir_all(&dest).unwrap();
+
+    backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+
+    verify(&dest).unwrap();
+    assert!(!dir.path().join("backup.partial").exists());
+}
+
+#[test]
+fn verify_rejects_a_modified_file() {
+    let (dir, work_dir, db) = project();
+    let dest = dir.path().join("backup");
+    backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+    fs::write(dest.join("config.toml"), "prefix = \"evil\"\n").unwrap();
+
+    let err = verify(&dest).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("config.toml does not match its checksum"));
+    assert!(matches!(err, Error::InvalidBackup { .. }));
+}
+
+#[test]
+fn verify_rejects_a_directory_without_manifest() {
+    let dir = tempdir().unwrap();
+
+    let err = verify(dir.path()).unwrap_err();
+
+    assert!(err.to_string().contains("cannot read manifest.json"));
+}
+
+#[test]
+fn verify_rejects_files_outside_the_backup() {
+    let (dir, work_dir, db) = project();
+    let dest = dir.path().join("backup");
+    let mut manifest = backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+    manifest.files.push(BackupFile {
+        path: "../secret".to_string(),
+        size: 0,
+        sha256: String::new(),
+    });
+    fs::write(
+        dest.join(MANIFEST_FILE),
+        serde_json::to_string(&manifest).unwrap(),
+    )
+    .unwrap();
+
+    let err = verify(&dest).unwrap_err();
+
+    assert!(err.to_string().contains("unexpected file ../secret"));
+}
+
+#[test]
+fn verify_rejects_a_newer_schema() {
+    let (dir, work_dir, db) = project();
+    db.conn
+        .execute_batch(&format!("PRAGMA user_version = {}", LATEST_VERSION + 1))
+        .unwrap();
+    let dest = dir.path().join("backup");
+    backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+
+    let err = verify(&dest).unwrap_err();
+
+    assert!(matches!(err, Error::SchemaTooNew { .. }));
+}
+
+#[test]
+fn restore_replaces_database_and_project_files() {
+    let (dir, work_dir, db) = project();
+    add_issue(&db, "prj-a1", "Before");
+    let dest = dir.path().join("backup");
+    let manifest = backup_impl(&db.conn, &work_dir, &dest, None).unwrap();
+
+    add_issue(&db, "prj-b2", "After");
+    fs::write(work_dir.join("hooks.toml"), "").unwrap();
+    drop(db);
+
+    let db_path = restore_impl(&dest, &manifest, &work_dir).unwrap();
+
+    assert_eq!(db_path, work_dir.join(DB_FILE));
+    assert_eq!(titles(&db_path), ["Before"]);
+    assert!(!work_dir.join("hooks.toml").exists());
+    assert_eq!(
+        fs::read(work_dir.join("config.toml")).unwrap(),
+        fs::read(dest.join("config.toml")).unwrap()
+    );
+}
+
+#[test]
+fn auto_backup_is_off_by_default() {
+    let (_dir, work_dir, db) = project();
+    let config = Config::load(&work_dir).unwrap();
+
+    assert_eq!(
+        auto_backup(&db.conn, &config, &work_dir, "import").unwrap(),
+        None
+    );
+    assert!(!work_dir.join(BACKUPS_DIR).exists());
+}
+
+#[test]
+fn auto_backups_rotate_but_manual_backups_stay() {
+    let (_dir, work_dir, db) = project();
+    let mut config = Config::load(&work_dir).unwrap();
+    config.backup.auto = Some(true);
+    config.backup.keep = Some(2);
+
+    let manual = new_backup_path(&work_dir, None).unwrap();
+    backup_impl(&db.conn, &work_dir, &manual, None).unwrap();
+    let made: Vec<_> = (0..3)
+        .map(|_| {
+            auto_backup(&db.conn, &config, &work_dir, "import")
+                .unwrap()
+                .unwrap()
+        })
+        .collect();
+
+    assert!(manual.exists());
+    assert!(!made[0].exists());
+    assert!(made[1].exists() && made[2].exists());
+    assert_eq!(
+        fs::read_to_string(work_dir.join(BACKUPS_DIR).join(".gitignore")).unwrap(),
+        "*\n"
+    );
+    let manifest = verify(&made[2]).unwrap();
+    assert_eq!(manifest.reason.as_deref(), Some("import"));
+}
+
+#[test]
+fn sort_key_orders_same_second_backups() {
+    let mut names = vec![
+        "20260101T000000Z-auto-import.10",
+        "20260101T000001Z-auto-import",
+        "20260101T000000Z-auto-import.2",
+        "20260101T000000Z-auto-import",
+    ];
+    names.sort_by(|a, b| sort_key(a).cmp(&sort_key(b)));
+    assert_eq!(
+        names,
+        [
+            "20260101T000000Z-auto-import",
+            "20260101T000000Z-auto-import.2",
+            "20260101T000000Z-auto-import.10",
+            "20260101T000001Z-auto-import",
+        ]
+    );
+}
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok branch` - switch to a git branch for an issue.
+//!
+//! The branch is named from the `[git] branch` template and recorded on the
+//! issue as a `branch://<name>` link, which the git hooks use to tell which
+//! issue a commit belongs to.
+
+use crate::db::Database;
+use crate::error::Result;
+use crate::git;
+use crate::models::{Action, Event, Issue, Link, LinkType};
+
+use super::{apply_mutation, open_db};
+
+/// Longest `{slug}`, so long titles still give usable branch names.
+const SLUG_MAX_LEN: usize = 40;
+
+pub fn run(id: &str) -> Result<()> {
+    let (db, config, _work_dir) = open_db()?;
+    let issue = db.get_issue(&db.resolve_id(id)?)?;
+    let name = branch_name(&issue, config.git.branch_template());
+
+    if git::branch_exists(&name) {
+        git::git(&["switch", &name])?;
+        println!("Switched to branch {}", name);
+    } else {
+        git::git(&["switch", "-c", &name])?;
+        println!("Created branch {}", name);
+    }
+    if link_branch(&db, &issue.id, &name)? {
+        println!("Linked {} to {}", name, issue.id);
+    }
+    Ok(())
+}
+
+/// The branch for `issue` from `template`.
+pub(crate) fn branch_name(issue: &Issue, template: &str) -> String {
+    let prefix = issue.id.split_once('-').map_or("", |(prefix, _)| prefix);
+    let name = template
+        .replace("{id}", &issue.id)
+        .replace("{type}", issue.issue_type.as_str())
+        .replace("{prefix}", prefix)
+        .replace("{slug}", &slug(&issue.title));
+    // An empty slug leaves a dangling separator.
+    name.trim_end_matches(['-', '/', '_']).to_string()
+}
+
+/// `title` lowercased with runs of anything but letters and digits as one
+/// hyphen, cut at a hyphen to at most [`SLUG_MAX_LEN`] characters.
+pub(crate) fn slug(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.len() > SLUG_MAX_LEN {
+        let cut = slug[..=SLUG_MAX_LEN].rfind('-').unwrap_or(SLUG_MAX_LEN);
+        slug.truncate(cut);
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+fn branch_url(name: &str) -> String {
+    format!("branch://{}", name)
+}
+
+/// Link branch `name` to issue `id`, unless it already is. Returns whether
+/// a link was added.
+pub(crate) fn link_branch(db: &Database, id: &str, name: &str) -> Result<bool> {
+    let url = branch_url(name);
+    if db.get_link_by_url(id, &url)?.is_some() {
+        return Ok(false);
+    }
+    let mut link = Link::new(id.to_string());
+    link.link_type = Some(LinkType::from_name("branch"));
+    link.url = Some(url.clone());
+    link.external_id = Some(name.to_string());
+    apply_mutation(
+        db,
+        Event::new(id.to_string(), Action::Linked).with_values(None, Some(url)),
+        || db.add_link(&link),
+    )?;
+    Ok(true)
+}
+
+/// The issue branch `name` was made for: the most recent one linked to it.
+pub(crate) fn branch_issue(db: &Database, name: &str) -> Result<Option<String>> {
+    let links = db.find_links(&branch_url(name))?;
+    Ok(links.into_iter().next_back().map(|link| link.issue_id))
+}
+
+#[cfg(test)]
+#[path = "branch_tests.rs"]
+mod tests;
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::config::GitConfig;
+use crate::models::IssueType;
+use yare::parameterized;
+
+#[parameterized(
+    words = { "Fix login page", "fix-login-page" },
+    punctuation = { "Crash: null deref (again!)", "crash-null-deref-again" },
+    non_ascii = { "Café menu", "caf-menu" },
+    empty = { "!!!", "" },
+    long = {
+        "Rework the session storage so tokens survive restarts",
+        "rework-the-session-storage-so-tokens"
+    },
+)]
+fn slug_is_short_and_hyphenated(title: &str, expected: &str) {
+    assert_eq!(slug(title), expected);
+    assert!(slug(title).len() <= SLUG_MAX_LEN);
+}
+
+#[parameterized(
+    default = { GitConfig::DEFAULT_BRANCH, "feature/prj-a1-fix-login" },
+    flat = { "{id}-{slug}", "prj-a1-fix-login" },
+    prefixed = { "{prefix}/{slug}", "prj/fix-login" },
+)]
+fn branch_name_fills_the_template(template: &str, expected: &str) {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Feature, "Fix login");
+    let issue = ctx.db.get_issue("prj-a1").unwrap();
+    assert_eq!(branch_name(&issue, template), expected);
+}
+
+#[test]
+fn empty_slug_leaves_no_trailing_separator() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "???");
+    let issue = ctx.db.get_issue("prj-a1").unwrap();
+    assert_eq!(
+        branch_name(&issue, GitConfig::DEFAULT_BRANCH),
+        "task/prj-a1"
+    );
+}
+
+#[test]
+fn linking_a_branch_is_idempotent() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "Fix login");
+
+    assert!(link_branch(&ctx.db, "prj-a1", "task/prj-a1-fix-login").unwrap());
+    assert!(!link_branch(&ctx.db, "prj-a1", "task/prj-a1-fix-login").unwrap());
+
+    let links = ctx.db.get_links("prj-a1").unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].link_type, Some(LinkType::from_name("branch")));
+    assert_eq!(
+        links[0].external_id.as_deref(),
+        Some("task/prj-a1-fix-login")
+    );
+}
+
+#[test]
+fn branch_issue_is_the_latest_linked() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "First");
+    ctx.create_issue("prj-b2", IssueType::Task, "Second");
+
+    assert_eq!(branch_issue(&ctx.db, "shared").unwrap(), None);
+    link_branch(&ctx.db, "prj-a1", "shared").unwrap();
+    link_branch(&ctx.db, "prj-b2", "shared").unwrap();
+    assert_eq!(
+        branch_issue(&ctx.db, "shared").unwrap().as_deref(),
+        Some("prj-b2")
+    );
+}
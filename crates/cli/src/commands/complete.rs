@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dynamic shell completion (`wok _complete`).
+//!
+//! The scripts printed by `wok completion --dynamic` call this hidden command
+//! with the words typed so far. Subcommands, flags and fixed values come from
+//! the clap definition; issue IDs, labels, assignees and prefixes come from
+//! the database, which is only opened when one of those is wanted.
+
+use clap::{Arg, Command, CommandFactory};
+
+use crate::cli::Cli;
+use crate::db::Database;
+use crate::error::Result;
+
+/// Database values an argument takes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueKind {
+    Issue,
+    Label,
+    /// `wok label <id>... <label>...`: IDs first, then either.
+    IssueOrLabel,
+    Assignee,
+    Prefix,
+}
+
+impl ValueKind {
+    /// The kind of value `arg` takes, by argument ID.
+    fn of(arg: &Arg) -> Option<Self> {
+        match arg.get_id().as_str() {
+            "id" | "ids" | "from_id" | "to_ids" | "blocks" | "blocked_by" | "tracks"
+            | "tracked_by" => Some(ValueKind::Issue),
+            "label" => Some(ValueKind::Label),
+            "args" => Some(ValueKind::IssueOrLabel),
+            "assignee" | "flag_assignee" | "who" => Some(ValueKind::Assignee),
+            "prefix" | "old_prefix" => Some(ValueKind::Prefix),
+            _ => None,
+        }
+    }
+
+    /// Values of this kind starting with `start`. `nth` is the position of
+    /// the word among the argument's values.
+    fn lookup(self, db: &Database, nth: usize, start: &str) -> Vec<String> {
+        let values = match self {
+            ValueKind::Issue => db.get_ids_starting_with(start),
+            ValueKind::Label => db.get_distinct_labels(),
+            ValueKind::IssueOrLabel if nth == 0 => db.get_ids_starting_with(start),
+            ValueKind::IssueOrLabel => db.get_ids_starting_with(start).and_then(|mut ids| {
+                ids.extend(db.get_distinct_labels()?);
+                Ok(ids)
+            }),
+            ValueKind::Assignee => db.get_distinct_assignees(),
+            ValueKind::Prefix => db
+                .list_prefixes()
+                .map(|prefixes| prefixes.into_iter().map(|p| p.prefix).collect()),
+        };
+        values.unwrap_or_default()
+    }
+}
+
+/// Print the candidates for `words[index]`, one per line.
+///
+/// Completion never fails: outside a wok project only candidates from the
+/// command definition are offered.
+pub fn run(index: usize, words: &[String]) -> Result<()> {
+    let open = || super::open_db().ok().map(|(db, _, _)| db);
+    for candidate in candidates(words, index, open) {
+        println!("{}", candidate);
+    }
+    Ok(())
+}
+
+/// Candidates for `words[index]`, where `words[0]` is the program name.
+pub(crate) fn candidates(
+    words: &[String],
+    index: usize,
+    open: impl FnOnce() -> Option<Database>,
+) -> Vec<String> {
+    let mut root = Cli::command();
+    root.build();
+    let current = words.get(index).map_or("", String::as_str);
+
+    // Walk the words before the cursor to find the subcommand being typed
+    // and what the current word fills in.
+    let mut cmd = &root;
+    let mut pending: Option<&Arg> = None;
+    let mut positional = 0;
+    for word in words.iter().take(index).skip(1) {
+        if pending.take().is_some() {
+            continue;
+        }
+        if let Some(long) = word.strip_prefix("--") {
+            if !long.contains('=') {
+                pending = takes_value(cmd.get_arguments().find(|a| a.get_long() == Some(long)));
+            }
+        } else if let Some(short) = word.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // Only a lone short flag (`-l`) takes the next word as its value.
+            let mut chars = short.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                pending = takes_value(cmd.get_arguments().find(|a| a.get_short() == Some(c)));
+            }
+        } else if let Some(sub) = cmd.find_subcommand(word).filter(|_| positional == 0) {
+            cmd = sub;
+        } else {
+            positional += 1;
+        }
+    }
+
+    let found = if let Some(arg) = pending {
+        values(arg, 0, current, open)
+    } else if let Some((long, value)) = current.strip_prefix("--").and_then(|s| s.split_once('=')) {
+        cmd.get_arguments()
+            .find(|a| a.get_long() == Some(long))
+            .map(|arg| values(arg, 0, value, open))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| format!("--{}={}", long, v))
+            .collect()
+    } else if current.starts_with('-') {
+        cmd.get_arguments()
+            .filter(|a| !a.is_positional() && !a.is_hide_set())
+            .filter_map(Arg::get_long)
+            .map(|long| format!("--{}", long))
+            .collect()
+    } else if positional == 0 && cmd.has_subcommands() {
+        cmd.get_subcommands()
+            .filter(|c| !c.is_hide_set())
+            .map(|c| c.get_name().to_string())
+            .collect()
+    } else if let Some((arg, nth)) = positional_arg(cmd, positional) {
+        values(arg, nth, current, open)
+    } else {
+        Vec::new()
+    };
+
+    found
+        .into_iter()
+        .filter(|c| c.starts_with(current))
+        .collect()
+}
+
+/// `arg`, if it consumes the following word as its value.
+fn takes_value(arg: Option<&Arg>) -> Option<&Arg> {
+    arg.filter(|a| a.get_action().takes_values())
+}
+
+/// The positional argument filled by the `nth` positional word, and the
+/// word's position among that argument's values.
+fn positional_arg(cmd: &Command, mut nth: usize) -> Option<(&Arg, usize)> {
+    for arg in cmd.get_positionals() {
+        let many = arg.get_num_args().is_some_and(|n| n.max_values() > 1);
+        if many || nth == 0 {
+            return Some((arg, nth));
+        }
+        nth -= 1;
+    }
+    None
+}
+
+/// Values for `arg` given the `typed` text so far.
+///
+/// Comma-separated lists (`-l a,b`) complete their last item.
+fn values(
+    arg: &Arg,
+    nth: usize,
+    typed: &str,
+    open: impl FnOnce() -> Option<Database>,
+) -> Vec<String> {
+    let (head, tail) = match typed.rfind(',') {
+        Some(i) => typed.split_at(i + 1),
+        None => ("", typed),
+    };
+    let possible: Vec<String> = arg
+        .get_possible_values()
+        .into_iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    let values = if !possible.is_empty() {
+        possible
+    } else if let Some(kind) = ValueKind::of(arg) {
+        open()
+            .map(|db| kind.lookup(&db, nth, tail))
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    values
+        .into_iter()
+        .map(|v| format!("{}{}", head, v))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "complete_tests.rs"]
+mod tests;
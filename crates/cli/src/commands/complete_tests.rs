@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use yare::parameterized;
+
+/// A database with two labeled issues under the `test` prefix, one of
+/// them assigned.
+fn seeded() -> Database {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-a1", IssueType::Task, "First")
+        .create_issue("test-b2", IssueType::Bug, "Second")
+        .add_label("test-a1", "urgent")
+        .add_label("test-b2", "backend");
+    ctx.db.set_assignee("test-a1", "alice").unwrap();
+    ctx.db.ensure_prefix("test").unwrap();
+    let TestContext { db, .. } = ctx;
+    db
+}
+
+fn complete(line: &[&str]) -> Vec<String> {
+    let words: Vec<String> = line.iter().map(|w| w.to_string()).collect();
+    candidates(&words, words.len() - 1, || Some(seeded()))
+}
+
+#[parameterized(
+    start_ids = { &["wok", "start", ""], &["test-a1", "test-b2"] },
+    id_prefix = { &["wok", "show", "test-b"], &["test-b2"] },
+    dep_targets = { &["wok", "dep", "test-a1", "blocks", ""], &["test-a1", "test-b2"] },
+    label_flag = { &["wok", "list", "-l", ""], &["backend", "urgent"] },
+    label_list = { &["wok", "list", "--label", "backend,u"], &["backend,urgent"] },
+    label_equals = { &["wok", "list", "--label=b"], &["--label=backend"] },
+    assignee_positional = { &["wok", "assign", "test-a1", ""], &["alice"] },
+    assignee_flag = { &["wok", "ready", "-a", "al"], &["alice"] },
+    prefix_flag = { &["wok", "list", "--prefix", ""], &["test"] },
+    label_command_ids = { &["wok", "label", ""], &["test-a1", "test-b2"] },
+    label_command_labels = { &["wok", "label", "test-a1", "u"], &["urgent"] },
+)]
+fn completes_database_values(line: &[&str], expected: &[&str]) {
+    assert_eq!(complete(line), expected);
+}
+
+#[test]
+fn completes_subcommands_without_hidden_ones() {
+    let found = complete(&["wok", ""]);
+    assert!(found.contains(&"start".to_string()));
+    assert!(!found.iter().any(|c| c.starts_with('_')));
+    assert_eq!(complete(&["wok", "star"]), vec!["start"]);
+}
+
+#[test]
+fn completes_nested_subcommands() {
+    assert_eq!(complete(&["wok", "config", "rul"]), vec!["rules"]);
+}
+
+#[test]
+fn completes_long_flags() {
+    assert_eq!(complete(&["wok", "list", "--lab"]), vec!["--label"]);
+    assert!(complete(&["wok", "list", "--"]).contains(&"--quiet".to_string()));
+}
+
+#[test]
+fn completes_possible_values_without_database() {
+    let words: Vec<String> = ["wok", "list", "-o", "json"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let found = candidates(&words, 3, || None);
+    assert_eq!(found, vec!["json", "json-paged"]);
+}
+
+#[test]
+fn skips_option_values_when_counting_positionals() {
+    assert_eq!(
+        complete(&[
+            "wok",
+            "--error-format",
+            "json",
+            "assign",
+            "--quiet",
+            "test-a1",
+            ""
+        ]),
+        vec!["alice"]
+    );
+    assert_eq!(complete(&["wok", "-C", "start", "star"]), vec!["start"]);
+}
+
+#[test]
+fn offers_nothing_from_database_outside_a_project() {
+    let words: Vec<String> = ["wok", "start", ""].iter().map(|w| w.to_string()).collect();
+    assert!(candidates(&words, 2, || None).is_empty());
+}
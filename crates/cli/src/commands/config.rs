@@ -4,26 +4,120 @@
 use std::path::Path;
 
 use crate::cli::{ConfigCommand, OutputFormat};
-use crate::config::{find_work_dir, get_db_path, Config};
+use crate::config::{find_work_dir, get_db_path, wok_state_dir, Config};
 use crate::db::Database;
 use crate::error::{Error, Result};
 use crate::id::validate_prefix;
+use crate::layers::{self, Layers};
+use crate::settings::{self, SETTINGS};
+use crate::workspace::{self, Registry};
 
 use super::open_db;
 
 /// Execute a config subcommand.
 pub fn run(cmd: ConfigCommand) -> Result<()> {
     match cmd {
+        ConfigCommand::Get { key } => {
+            let config = Config::load(&find_work_dir()?)?;
+            if let Some(value) = settings::find(&key)?.get(&config) {
+                println!("{}", value);
+            }
+            Ok(())
+        }
+        ConfigCommand::Set { key, value, user } => {
+            set(&key, Some(&value), user)?;
+            println!("Set {} = {}", key, value);
+            Ok(())
+        }
+        ConfigCommand::Unset { key, user } => {
+            set(&key, None, user)?;
+            println!("Unset {}", key);
+            Ok(())
+        }
+        ConfigCommand::List { output, origin } => {
+            let layers = Layers::load(&find_work_dir()?)?;
+            list(&layers.resolve()?, origin.then_some(&layers), output)
+        }
         ConfigCommand::Rename {
             old_prefix,
             new_prefix,
         } => {
             let (db, config, _) = open_db()?;
             let work_dir = find_work_dir()?;
+            super::backup::auto_backup(&db.conn, &config, &work_dir, "rename")?;
             run_rename_prefix(&db, &config, &work_dir, &old_prefix, &new_prefix)
         }
         ConfigCommand::Prefixes { output } => run_list_prefixes(output),
+        ConfigCommand::Workspaces { output } => run_list_workspaces(output),
+        ConfigCommand::Rules(cmd) => super::rules::run(cmd),
+        ConfigCommand::Mode { mode } => super::mode::run(mode),
+    }
+}
+
+/// Set `key` in the project or user config, or restore its default for `None`.
+fn set(key: &str, value: Option<&str>, user: bool) -> Result<()> {
+    if user {
+        layers::set_user(key, value)?;
+        Ok(())
+    } else {
+        set_project(&find_work_dir()?, key, value)
+    }
+}
+
+/// Set `key` in the project config, or restore its default for `None`.
+pub(crate) fn set_project(work_dir: &Path, key: &str, value: Option<&str>) -> Result<()> {
+    let setting = settings::find(key)?;
+    let mut config = Config::load_file(work_dir)?;
+    setting.set(&mut config, value)?;
+    config.save(work_dir)
+}
+
+/// List settings, with where each comes from when `layers` is given.
+fn list(config: &Config, layers: Option<&Layers>, output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Text => {
+            for setting in SETTINGS {
+                let value = setting.get(config);
+                let value = value.as_deref().unwrap_or("(unset)");
+                match layers {
+                    Some(layers) => {
+                        println!(
+                            "{} = {}  ({})",
+                            setting.key,
+                            value,
+                            layers.origin(setting.key)
+                        )
+                    }
+                    None => println!("{} = {}", setting.key, value),
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let json: Vec<_> = SETTINGS
+                .iter()
+                .map(|setting| {
+                    let mut json = serde_json::json!({
+                        "key": setting.key,
+                        "value": setting.get(config),
+                        "description": setting.about,
+                    });
+                    if let Some(layers) = layers {
+                        let origin = layers.origin(setting.key);
+                        json["origin"] = origin.layer().into();
+                        json["source"] = origin.source().into();
+                    }
+                    json
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        OutputFormat::Id => {
+            for setting in SETTINGS {
+                println!("{}", setting.key);
+            }
+        }
     }
+    Ok(())
 }
 
 /// List all prefixes in the issue tracker.
@@ -80,6 +174,78 @@ fn run_list_prefixes(output: OutputFormat) -> Result<()> {
     Ok(())
 }
 
+/// List the projects in the current project's workspace.
+fn run_list_workspaces(output: OutputFormat) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let config = Config::load(&work_dir)?;
+    let Some(id) = config.workspace_id.clone() else {
+        match output {
+            OutputFormat::Text => {
+                println!("Not part of a workspace.");
+                println!("  hint: run 'wok init --join <path>' in another project to share this one's database");
+            }
+            OutputFormat::Json => {
+                let json = serde_json::json!({ "id": null, "projects": [] });
+                println!("{}", serde_json::to_string_pretty(&json)?);
+            }
+            OutputFormat::Id => {}
+        }
+        return Ok(());
+    };
+
+    let root = config
+        .workspace_root(&work_dir)
+        .unwrap_or_else(|| work_dir.clone());
+    let root_prefix = Config::load_file(&root)?.prefix;
+    let registry = Registry::load(&wok_state_dir())?;
+    let projects: Vec<_> = workspace::projects(&registry, &id, &root, &work_dir)
+        .into_iter()
+        .map(|project| {
+            let prefix = Config::load_file(&project).ok().map(|c| {
+                if c.prefix.is_empty() {
+                    root_prefix.clone()
+                } else {
+                    c.prefix
+                }
+            });
+            let is_root = project == root;
+            let dir = project.parent().unwrap_or(&project).to_path_buf();
+            (dir, prefix, is_root)
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Text => {
+            println!("Workspace {}", id);
+            for (dir, prefix, is_root) in &projects {
+                let prefix = prefix.as_deref().unwrap_or("(missing)");
+                let marker = if *is_root { " (root)" } else { "" };
+                println!("  {}  {}{}", dir.display(), prefix, marker);
+            }
+        }
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "id": id,
+                "projects": projects.iter().map(|(dir, prefix, is_root)| {
+                    serde_json::json!({
+                        "path": dir.display().to_string(),
+                        "prefix": prefix,
+                        "is_root": is_root,
+                        "missing": prefix.is_none(),
+                    })
+                }).collect::<Vec<_>>()
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        OutputFormat::Id => {
+            for (dir, _, _) in &projects {
+                println!("{}", dir.display());
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Rename the issue ID prefix across all issues and config.
 pub(crate) fn run_rename_prefix(
     db: &Database,
@@ -109,7 +275,7 @@ pub(crate) fn run_rename_prefix(
 
     // 5. Update config file if old_prefix matches current config prefix
     if config.prefix == old_prefix {
-        let mut new_config = config.clone();
+        let mut new_config = Config::load_file(work_dir)?;
         new_config.prefix = new_prefix.to_string();
         new_config.save(work_dir)?;
     }
@@ -149,7 +315,7 @@ fn rename_all_issue_ids(db: &Database, old_prefix: &str, new_prefix: &str) -> Re
             [&old_pattern, &new_pattern, &like_pattern],
         )?;
 
-        // Update labels, notes, events, links tables
+        // Update labels, notes, events, links, subscriptions, hook_runs tables
         tx.execute(
             "UPDATE labels SET issue_id = replace(issue_id, ?1, ?2) WHERE issue_id LIKE ?3",
             [&old_pattern, &new_pattern, &like_pattern],
@@ -166,6 +332,14 @@ fn rename_all_issue_ids(db: &Database, old_prefix: &str, new_prefix: &str) -> Re
             "UPDATE links SET issue_id = replace(issue_id, ?1, ?2) WHERE issue_id LIKE ?3",
             [&old_pattern, &new_pattern, &like_pattern],
         )?;
+        tx.execute(
+            "UPDATE subscriptions SET issue_id = replace(issue_id, ?1, ?2) WHERE issue_id LIKE ?3",
+            [&old_pattern, &new_pattern, &like_pattern],
+        )?;
+        tx.execute(
+            "UPDATE hook_runs SET issue_id = replace(issue_id, ?1, ?2) WHERE issue_id LIKE ?3",
+            [&old_pattern, &new_pattern, &like_pattern],
+        )?;
 
         tx.commit()?;
         Ok(())
@@ -177,6 +351,7 @@ fn rename_all_issue_ids(db: &Database, old_prefix: &str, new_prefix: &str) -> Re
     // Update the prefixes table (outside transaction since it doesn't have foreign key constraints)
     if result.is_ok() {
         db.rename_prefix(old_prefix, new_prefix)?;
+        db.rename_short_id_prefix(old_prefix, new_prefix)?;
     }
 
     result
@@ -251,6 +251,43 @@ fn test_rename_prefix_updates_events() {
     assert!(events.len() >= 2);
 }
 
+#[test]
+fn test_rename_prefix_updates_subscriptions_and_hook_runs() {
+    use crate::models::HookRun;
+
+    let mut test_ctx = ConfigTestContext::new("old");
+    test_ctx
+        .ctx
+        .create_issue("old-a1b2", IssueType::Task, "Test issue");
+    test_ctx.ctx.db.subscribe("old-a1b2", "alice").unwrap();
+    let run = HookRun {
+        id: 0,
+        hook_name: "notify".to_string(),
+        event: "issue.created".to_string(),
+        issue_id: "old-a1b2".to_string(),
+        exit_code: Some(0),
+        duration_ms: 5,
+        stderr_tail: None,
+        error: None,
+        created_at: chrono::Utc::now(),
+    };
+    test_ctx.ctx.db.log_hook_run(&run).unwrap();
+
+    let result = run_rename_prefix(
+        &test_ctx.ctx.db,
+        &test_ctx.ctx.config,
+        test_ctx.work_dir(),
+        "old",
+        "new",
+    );
+    assert!(result.is_ok());
+
+    let subscriptions = test_ctx.ctx.db.get_subscriptions("alice").unwrap();
+    assert_eq!(subscriptions, vec!["new-a1b2"]);
+    let runs = test_ctx.ctx.db.get_hook_runs(10, false).unwrap();
+    assert_eq!(runs[0].issue_id, "new-a1b2");
+}
+
 #[test]
 fn test_rename_prefix_updates_config_file() {
     let mut test_ctx = ConfigTestContext::new("old");
@@ -433,3 +470,34 @@ fn test_rename_prefix_valid_prefixes() {
         );
     }
 }
+
+// === Settings Tests ===
+
+#[test]
+fn test_set_writes_config_file() {
+    let test_ctx = ConfigTestContext::new("prj");
+    set_project(test_ctx.work_dir(), "wip.limit", Some("2")).unwrap();
+    set_project(test_ctx.work_dir(), "limits.list", Some("0")).unwrap();
+
+    let config = Config::load(test_ctx.work_dir()).unwrap();
+    assert_eq!(config.wip.limit, Some(2));
+    assert_eq!(config.limits.list, Some(0));
+
+    set_project(test_ctx.work_dir(), "wip.limit", None).unwrap();
+    let config = Config::load(test_ctx.work_dir()).unwrap();
+    assert_eq!(config.wip.limit, None);
+}
+
+#[test]
+fn test_set_rejects_unknown_key_without_writing() {
+    let test_ctx = ConfigTestContext::new("prj");
+    let before = std::fs::read_to_string(test_ctx.work_dir().join("config.toml")).unwrap();
+
+    let result = set_project(test_ctx.work_dir(), "colour", Some("never"));
+    assert!(matches!(result, Err(Error::UnknownSetting(_))));
+    let result = set_project(test_ctx.work_dir(), "wip.limit", Some("many"));
+    assert!(matches!(result, Err(Error::InvalidSetting { .. })));
+
+    let after = std::fs::read_to_string(test_ctx.work_dir().join("config.toml")).unwrap();
+    assert_eq!(before, after);
+}
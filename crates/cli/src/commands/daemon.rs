@@ -6,8 +6,13 @@
 //! Commands for controlling the wokd daemon that manages the shared
 //! user-level database.
 
+use std::fs;
+use std::io;
+use std::path::Path;
+
 use crate::config::wok_state_dir;
 use crate::daemon;
+use crate::daemon::service::{self, Manager, Service};
 use crate::error::{Error, Result};
 
 /// Show daemon status.
@@ -28,6 +33,21 @@ pub fn status() -> Result<()> {
         }
     }
 
+    let installed = Manager::current().ok().and_then(|_| {
+        [true, false]
+            .into_iter()
+            .filter_map(|user| Service::detect(user).ok())
+            .find(Service::installed)
+    });
+    match installed {
+        Some(service) => println!(
+            "Service: {} ({})",
+            service.kind(),
+            service.unit_path.display()
+        ),
+        None => println!("Service: not installed"),
+    }
+
     Ok(())
 }
 
@@ -83,6 +103,259 @@ pub fn start(foreground: bool) -> Result<()> {
     Ok(())
 }
 
+/// Stop the daemon if it is running, then start it again.
+pub fn restart() -> Result<()> {
+    let daemon_dir = wok_state_dir();
+
+    // The lock, not a ping, so a daemon that stopped answering restarts too
+    let was_running = daemon::lock_held(&daemon_dir);
+    if was_running {
+        daemon::stop_daemon_forcefully(&daemon_dir)?;
+    }
+    let info = daemon::spawn_daemon(&daemon_dir)
+        .map_err(|e| Error::Daemon(format!("failed to start daemon: {}", e)))?;
+    let verb = if was_running { "restarted" } else { "started" };
+    println!("Daemon {} (PID: {})", verb, info.pid);
+
+    Ok(())
+}
+
+/// Write a service manager unit for wokd and start it, or with `print`
+/// only show the unit.
+pub fn install_service(user: bool, print: bool) -> Result<()> {
+    let service = Service::detect(user)?.resolve_wokd()?;
+    if print {
+        print!("{}", service.render());
+        return Ok(());
+    }
+
+    let path = &service.unit_path;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| unit_error(path, e))?;
+    }
+    fs::write(path, service.render()).map_err(|e| unit_error(path, e))?;
+
+    // A daemon spawned on demand holds the instance lock, and the unit's
+    // wokd would exit at once; hand the lock over
+    let daemon_dir = wok_state_dir();
+    if daemon::lock_held(&daemon_dir) {
+        daemon::stop_daemon_forcefully(&daemon_dir)?;
+    }
+    service::run_commands(&service.enable_commands())?;
+    println!("Installed {} at {}", service.kind(), path.display());
+
+    Ok(())
+}
+
+/// Stop and remove the wokd unit written by `install-service`.
+pub fn uninstall_service(user: bool) -> Result<()> {
+    let service = Service::detect(user)?;
+    let path = &service.unit_path;
+    if !service.installed() {
+        println!("No {} installed at {}.", service.kind(), path.display());
+        return Ok(());
+    }
+
+    service::run_commands(&service.disable_commands())?;
+    fs::remove_file(path).map_err(|e| unit_error(path, e))?;
+    // Only forgets the removed unit, which is already stopped
+    let _ = service::run_commands(&service.reload_commands());
+    println!("Removed {} at {}", service.kind(), path.display());
+
+    Ok(())
+}
+
+/// System units live where only root may write.
+fn unit_error(path: &Path, e: io::Error) -> Error {
+    if e.kind() == io::ErrorKind::PermissionDenied {
+        Error::PermissionDenied {
+            target: path.display().to_string(),
+        }
+    } else {
+        Error::Io(e)
+    }
+}
+
+/// How one health check came out.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Outcome {
+    Ok,
+    /// Stale state was found and removed.
+    Fixed,
+    /// A problem the check cannot fix, with what to do about it.
+    Fail {
+        hint: String,
+    },
+}
+
+/// One line of `wok daemon health`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Check {
+    pub name: &'static str,
+    pub detail: String,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Check {
+            name,
+            detail: detail.into(),
+            outcome: Outcome::Ok,
+        }
+    }
+
+    fn fixed(name: &'static str, detail: impl Into<String>) -> Self {
+        Check {
+            name,
+            detail: detail.into(),
+            outcome: Outcome::Fixed,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Check {
+            name,
+            detail: detail.into(),
+            outcome: Outcome::Fail { hint: hint.into() },
+        }
+    }
+}
+
+/// Check the daemon and report what is wrong; fails when a check does.
+pub fn health() -> Result<()> {
+    let checks = check_health(&wok_state_dir());
+
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for check in &checks {
+        let label = match check.outcome {
+            Outcome::Ok => "ok",
+            Outcome::Fixed => "fixed",
+            Outcome::Fail { .. } => "fail",
+        };
+        println!(
+            "{:<5}  {:<width$}  {}",
+            label,
+            check.name,
+            check.detail,
+            width = width
+        );
+        if let Outcome::Fail { hint } = &check.outcome {
+            println!("       {:<width$}  hint: {}", "", hint, width = width);
+        }
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|c| matches!(c.outcome, Outcome::Fail { .. }))
+        .count();
+    if failed > 0 {
+        return Err(Error::Daemon(format!(
+            "{} daemon health check(s) failed",
+            failed
+        )));
+    }
+    Ok(())
+}
+
+/// Run every check against the daemon of `daemon_dir`, removing its pid
+/// file and socket when no daemon holds the lock.
+///
+/// The instance lock decides whether a daemon is running: the kernel
+/// releases it when wokd dies, even from SIGKILL, while the pid file and
+/// socket linger (and the pid may since belong to another process).
+pub(crate) fn check_health(daemon_dir: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+    let pid_path = daemon::get_pid_path(daemon_dir);
+    let socket_path = daemon::get_socket_path(daemon_dir);
+    let pid = daemon::read_pid_file(&pid_path);
+
+    if daemon::lock_held(daemon_dir) {
+        match pid {
+            Some(pid) if daemon::process_alive(pid) => {
+                checks.push(Check::ok("lock", format!("held by PID {}", pid)));
+            }
+            Some(pid) => checks.push(Check::fail(
+                "lock",
+                format!("held, but PID {} in the pid file is not running", pid),
+                format!(
+                    "another wokd holds {}; stop it, then run `wok daemon restart`",
+                    daemon::get_lock_path(daemon_dir).display()
+                ),
+            )),
+            None => checks.push(Check::fail(
+                "lock",
+                "held by a daemon that wrote no pid file",
+                format!(
+                    "find the process holding {} (e.g. `fuser {}`) and stop it",
+                    daemon::get_lock_path(daemon_dir).display(),
+                    daemon::get_lock_path(daemon_dir).display()
+                ),
+            )),
+        }
+
+        match daemon::get_daemon_version(daemon_dir) {
+            Ok(Some(version)) => {
+                checks.push(Check::ok("socket", "answering"));
+                if version == daemon::CLI_VERSION {
+                    checks.push(Check::ok("version", format!("v{}", version)));
+                } else {
+                    checks.push(Check::fail(
+                        "version",
+                        format!(
+                            "daemon v{} differs from CLI v{}",
+                            version,
+                            daemon::CLI_VERSION
+                        ),
+                        "run `wok daemon restart` to start this version",
+                    ));
+                }
+            }
+            Ok(None) => checks.push(Check::fail(
+                "socket",
+                format!("{} is missing", socket_path.display()),
+                "run `wok daemon restart`",
+            )),
+            Err(e) => checks.push(Check::fail(
+                "socket",
+                format!("not answering: {}", e),
+                "run `wok daemon restart`",
+            )),
+        }
+    } else {
+        checks.push(Check::ok("lock", "not held (daemon not running)"));
+        if let Some(pid) = pid {
+            let _ = fs::remove_file(&pid_path);
+            checks.push(Check::fixed(
+                "pid file",
+                format!("removed stale pid file for PID {}", pid),
+            ));
+        }
+        if socket_path.exists() {
+            let _ = fs::remove_file(&socket_path);
+            checks.push(Check::fixed("socket", "removed stale socket"));
+        }
+    }
+
+    checks.push(check_database(&daemon_dir.join("issues.db")));
+    checks
+}
+
+/// Whether the shared database can be written.
+fn check_database(db_path: &Path) -> Check {
+    if !db_path.exists() {
+        return Check::ok("database", "not created yet");
+    }
+    match fs::OpenOptions::new().append(true).open(db_path) {
+        Ok(_) => Check::ok("database", format!("{} is writable", db_path.display())),
+        Err(e) => Check::fail(
+            "database",
+            format!("{} is not writable: {}", db_path.display(), e),
+            "check the file's owner and permissions",
+        ),
+    }
+}
+
 /// View daemon logs.
 pub fn logs(follow: bool) -> Result<()> {
     let daemon_dir = wok_state_dir();
@@ -111,3 +384,7 @@ pub fn logs(follow: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+#[path = "daemon_tests.rs"]
+mod tests;
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use fs2::FileExt;
+use tempfile::tempdir;
+
+use super::*;
+
+/// A PID that belonged to a process which has exited.
+fn dead_pid() -> u32 {
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    pid
+}
+
+fn outcomes(checks: &[Check]) -> Vec<(&'static str, &Outcome)> {
+    checks.iter().map(|c| (c.name, &c.outcome)).collect()
+}
+
+#[test]
+fn test_health_with_no_daemon_state() {
+    let dir = tempdir().unwrap();
+
+    let checks = check_health(dir.path());
+
+    assert_eq!(
+        outcomes(&checks),
+        [("lock", &Outcome::Ok), ("database", &Outcome::Ok)]
+    );
+}
+
+#[test]
+fn test_health_removes_state_left_by_a_killed_daemon() {
+    let dir = tempdir().unwrap();
+    let pid_path = daemon::get_pid_path(dir.path());
+    let socket_path = daemon::get_socket_path(dir.path());
+    fs::write(&pid_path, dead_pid().to_string()).unwrap();
+    fs::write(&socket_path, "").unwrap();
+    fs::write(daemon::get_lock_path(dir.path()), "").unwrap();
+
+    let checks = check_health(dir.path());
+
+    assert_eq!(
+        outcomes(&checks),
+        [
+            ("lock", &Outcome::Ok),
+            ("pid file", &Outcome::Fixed),
+            ("socket", &Outcome::Fixed),
+            ("database", &Outcome::Ok),
+        ]
+    );
+    assert!(!pid_path.exists());
+    assert!(!socket_path.exists());
+}
+
+#[test]
+fn test_health_keeps_state_while_the_lock_is_held() {
+    let dir = tempdir().unwrap();
+    let pid_path = daemon::get_pid_path(dir.path());
+    fs::write(&pid_path, dead_pid().to_string()).unwrap();
+    let lock = fs::File::create(daemon::get_lock_path(dir.path())).unwrap();
+    lock.lock_exclusive().unwrap();
+
+    let checks = check_health(dir.path());
+
+    assert!(matches!(checks[0].outcome, Outcome::Fail { .. }));
+    assert!(checks[0].detail.contains("not running"));
+    assert_eq!(checks[1].name, "socket");
+    assert!(matches!(checks[1].outcome, Outcome::Fail { .. }));
+    assert!(pid_path.exists());
+}
+
+#[test]
+fn test_health_checks_existing_database() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("issues.db"), "").unwrap();
+
+    let checks = check_health(dir.path());
+
+    let database = checks.last().unwrap();
+    assert_eq!(database.outcome, Outcome::Ok);
+    assert!(database.detail.ends_with("is writable"));
+}
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Finding likely duplicate issues.
+//!
+//! Titles are compared by the average of two scores: the overlap of their
+//! character trigrams, which tolerates typos and word forms, and of their
+//! words, which keeps "Task 1" and "Task 2" apart. `wok new` warns about open
+//! issues similar to the one it created, and `wok dedupe` scans every open
+//! issue for pairs, optionally merging them.
+
+use std::collections::HashSet;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crate::cli::OutputFormat;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::Issue;
+
+use super::filtering::matches_prefix;
+use super::merge::merge_impl;
+use super::open_db;
+
+/// Score from which two titles count as similar.
+pub(crate) const SIMILAR_THRESHOLD: f64 = 0.6;
+
+/// Most similar issues `wok new` lists.
+const MAX_WARNINGS: usize = 5;
+
+/// How similar titles `a` and `b` are, from 0 (nothing shared) to 1.
+pub(crate) fn similarity(a: &str, b: &str) -> f64 {
+    let (words_a, words_b) = (words(a), words(b));
+    if words_a.is_empty() || words_b.is_empty() {
+        return 0.0;
+    }
+    let (grams_a, grams_b) = (trigrams(&words_a), trigrams(&words_b));
+    let shared_grams = count(grams_a.intersection(&grams_b).count());
+    let dice = 2.0 * shared_grams / count(grams_a.len() + grams_b.len());
+    let shared_words = count(words_a.intersection(&words_b).count());
+    let jaccard = shared_words / count(words_a.union(&words_b).count());
+    (dice + jaccard) / 2.0
+}
+
+/// A word or trigram count as a float, saturating at `u32::MAX`.
+fn count(n: usize) -> f64 {
+    u32::try_from(n).map_or(f64::from(u32::MAX), f64::from)
+}
+
+/// Lowercase runs of letters and digits.
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Trigrams of each word padded with a space on both sides.
+fn trigrams(words: &HashSet<String>) -> HashSet<String> {
+    let mut grams = HashSet::new();
+    for word in words {
+        let chars: Vec<char> = format!(" {} ", word).chars().collect();
+        grams.extend(chars.windows(3).map(|w| w.iter().collect::<String>()));
+    }
+    grams
+}
+
+/// Open issues whose titles are similar to `issue`'s, most similar first.
+pub(crate) fn similar_issues(db: &Database, issue: &Issue) -> Result<Vec<(Issue, f64)>> {
+    let prefix = issue
+        .id
+        .split_once('-')
+        .map(|(prefix, _)| prefix.to_string());
+    let mut similar: Vec<(Issue, f64)> = db
+        .get_all_issues()?
+        .into_iter()
+        .filter(|other| {
+            other.id != issue.id && other.status.is_active() && matches_prefix(&prefix, &other.id)
+        })
+        .map(|other| {
+            let score = similarity(&issue.title, &other.title);
+            (other, score)
+        })
+        .filter(|(_, score)| *score >= SIMILAR_THRESHOLD)
+        .collect();
+    similar.sort_by(|(a, x), (b, y)| y.total_cmp(x).then_with(|| a.id.cmp(&b.id)));
+    Ok(similar)
+}
+
+/// Warn on stderr about open issues similar to the newly created `id`.
+pub(crate) fn warn_similar(db: &Database, id: &str) -> Result<()> {
+    let issue = db.get_issue(id)?;
+    let similar = similar_issues(db, &issue)?;
+    if similar.is_empty() {
+        return Ok(());
+    }
+    let (count, verb) = match similar.len() {
+        1 => ("1 similar issue".to_string(), "exists"),
+        n => (format!("{} similar issues", n), "exist"),
+    };
+    eprintln!("warning: {} {}:", count, verb);
+    for (other, _) in similar.iter().take(MAX_WARNINGS) {
+        eprintln!("  {}: {}", other.id, other.title);
+    }
+    eprintln!("  hint: 'wok merge <id> <into>' merges a duplicate; --force skips this check");
+    Ok(())
+}
+
+/// A likely duplicate: `duplicate` is the newer of the two.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Pair {
+    pub keep: Issue,
+    pub duplicate: Issue,
+    pub score: f64,
+}
+
+/// Pairs of open issues with similar titles, most similar first.
+pub(crate) fn find_pairs(db: &Database, prefix: &Option<String>) -> Result<Vec<Pair>> {
+    let issues: Vec<Issue> = db
+        .get_all_issues()?
+        .into_iter()
+        .filter(|issue| issue.status.is_active() && matches_prefix(prefix, &issue.id))
+        .collect();
+    let mut pairs = Vec::new();
+    for (i, a) in issues.iter().enumerate() {
+        for b in &issues[i + 1..] {
+            let score = similarity(&a.title, &b.title);
+            if score < SIMILAR_THRESHOLD {
+                continue;
+            }
+            let (keep, duplicate) = if (b.created_at, &b.id) < (a.created_at, &a.id) {
+                (b, a)
+            } else {
+                (a, b)
+            };
+            pairs.push(Pair {
+                keep: keep.clone(),
+                duplicate: duplicate.clone(),
+                score,
+            });
+        }
+    }
+    pairs.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.keep.id.cmp(&b.keep.id))
+            .then_with(|| a.duplicate.id.cmp(&b.duplicate.id))
+    });
+    Ok(pairs)
+}
+
+pub fn run(output: OutputFormat, interactive: bool) -> Result<()> {
+    let (db, config, _work_dir) = open_db()?;
+    if interactive && !(io::stdin().is_terminal() && io::stdout().is_terminal()) {
+        return Err(Error::TtyRequired);
+    }
+    let prefix = (!config.prefix.is_empty()).then_some(config.prefix);
+    let pairs = find_pairs(&db, &prefix)?;
+
+    match output {
+        OutputFormat::Json => {
+            let json: Vec<_> = pairs
+                .iter()
+                .map(|pair| {
+                    serde_json::json!({
+                        "keep": pair.keep.id,
+                        "duplicate": pair.duplicate.id,
+                        "score": (pair.score * 100.0).round() / 100.0,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(());
+        }
+        OutputFormat::Id => {
+            for pair in &pairs {
+                println!("{} {}", pair.duplicate.id, pair.keep.id);
+            }
+            return Ok(());
+        }
+        OutputFormat::Text => {}
+    }
+
+    if pairs.is_empty() {
+        println!("No likely duplicates.");
+        return Ok(());
+    }
+    let mut merged: HashSet<String> = HashSet::new();
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    for pair in &pairs {
+        println!(
+            "{:.0}%  {}: {}\n     {}: {}",
+            pair.score * 100.0,
+            pair.duplicate.id,
+            pair.duplicate.title,
+            pair.keep.id,
+            pair.keep.title
+        );
+        // An issue already merged away can't take part in another merge.
+        if !interactive || merged.contains(&pair.keep.id) || merged.contains(&pair.duplicate.id) {
+            continue;
+        }
+        print!(
+            "Merge {} into {}? [y/N/q] ",
+            pair.duplicate.id, pair.keep.id
+        );
+        io::stdout().flush()?;
+        let answer = lines.next().transpose()?.unwrap_or_default();
+        match answer.trim().to_lowercase().as_str() {
+            "y" | "yes" => {
+                merge_impl(&db, &pair.duplicate.id, &pair.keep.id)?;
+                println!("Merged {} into {}", pair.duplicate.id, pair.keep.id);
+                merged.insert(pair.duplicate.id.clone());
+            }
+            "q" | "quit" => break,
+            _ => {}
+        }
+    }
+    if !interactive {
+        println!();
+        println!("hint: 'wok dedupe -i' merges them one by one");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "dedupe_tests.rs"]
+mod tests;
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::{IssueType, Status};
+use yare::parameterized;
+
+#[parameterized(
+    reordered = { "Fix login crash", "Fix crash on login" },
+    plural = { "Login page crashes", "Login page crash" },
+    case_and_punctuation = { "Add CSV export!", "add csv export" },
+)]
+fn similar_titles(a: &str, b: &str) {
+    let score = similarity(a, b);
+    assert!(score >= SIMILAR_THRESHOLD, "{} vs {}: {}", a, b, score);
+}
+
+#[parameterized(
+    numbered = { "Task 1", "Task 2" },
+    unrelated = { "Fix login crash", "Write release notes" },
+    shared_word = { "Update docs", "Update dependencies" },
+    empty = { "!!!", "Anything" },
+)]
+fn different_titles(a: &str, b: &str) {
+    let score = similarity(a, b);
+    assert!(score < SIMILAR_THRESHOLD, "{} vs {}: {}", a, b, score);
+}
+
+#[test]
+fn identical_titles_score_one() {
+    assert!((similarity("Fix login", "fix LOGIN") - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn similar_issues_are_open_and_share_the_prefix() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "Fix login crash")
+        .create_completed("prj-b2", IssueType::Task, "Fix login crash")
+        .create_issue("other-c3", IssueType::Task, "Fix login crash")
+        .create_issue("prj-d4", IssueType::Task, "Write release notes")
+        .create_issue("prj-e5", IssueType::Task, "Fix the login crash");
+
+    let issue = ctx.db.get_issue("prj-e5").unwrap();
+    let similar = similar_issues(&ctx.db, &issue).unwrap();
+    let ids: Vec<&str> = similar.iter().map(|(i, _)| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["prj-a1"]);
+}
+
+#[test]
+fn pairs_keep_the_older_issue() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "Fix login crash");
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    ctx.create_issue("prj-00", IssueType::Task, "Fix crash on login")
+        .create_issue_with_status(
+            "prj-c3",
+            IssueType::Task,
+            "Write release notes",
+            Status::InProgress,
+        );
+
+    let pairs = find_pairs(&ctx.db, &None).unwrap();
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].keep.id, "prj-a1");
+    assert_eq!(pairs[0].duplicate.id, "prj-00");
+}
@@ -1,16 +1,77 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+use crate::config::{AutoEpic, EpicConfig};
 use crate::db::Database;
 
 use super::{apply_mutation, open_db};
 use crate::error::Result;
-use crate::models::{Action, Event, Relation, UserRelation};
+use crate::models::{Action, Event, IssueType, Relation, UserRelation};
+use crate::t;
 
-pub fn add(from_id: &str, rel: &str, to_ids: &[String]) -> Result<()> {
+pub fn add(from_id: &str, rel: &str, to_ids: &[String], auto_epic: bool) -> Result<()> {
     let to_ids = super::new::expand_ids(to_ids);
-    let (db, _config, _work_dir) = open_db()?;
-    add_impl(&db, from_id, rel, &to_ids)
+    let (db, config, _work_dir) = open_db()?;
+    add_impl(&db, from_id, rel, &to_ids)?;
+
+    let trackers = match rel.parse()? {
+        UserRelation::Tracks => vec![from_id.to_string()],
+        UserRelation::TrackedBy => to_ids,
+        UserRelation::Blocks
+        | UserRelation::BlockedBy
+        | UserRelation::RelatesTo
+        | UserRelation::DuplicateOf => Vec::new(),
+    };
+    let mut resolved = Vec::new();
+    for id in &trackers {
+        let id = db.resolve_id(id)?;
+        if !resolved.contains(&id) {
+            resolved.push(id);
+        }
+    }
+    promote_epics(&db, &resolved, &config.epic, auto_epic)
+}
+
+/// Suggest or apply the epic type for trackers of `threshold`+ issues.
+///
+/// `force` converts regardless of the configured mode (`wok dep --auto-epic`).
+pub(crate) fn promote_epics(
+    db: &Database,
+    tracker_ids: &[String],
+    epic: &EpicConfig,
+    force: bool,
+) -> Result<()> {
+    let mode = if force { AutoEpic::Convert } else { epic.auto };
+    if mode == AutoEpic::Off {
+        return Ok(());
+    }
+
+    for id in tracker_ids {
+        let issue = db.get_issue(id)?;
+        if issue.issue_type == IssueType::Epic {
+            continue;
+        }
+        let tracked = db.get_tracked(id)?.len();
+        if tracked < epic.threshold {
+            continue;
+        }
+
+        if mode == AutoEpic::Suggest {
+            eprintln!("{}", t!("dep.epic_hint", id = id, count = tracked));
+            continue;
+        }
+        apply_mutation(
+            db,
+            Event::new(id.clone(), Action::Edited).with_values(
+                Some(issue.issue_type.as_str().to_string()),
+                Some(IssueType::Epic.as_str().to_string()),
+            ),
+            || db.update_issue_type(id, IssueType::Epic),
+        )?;
+        eprintln!("{}", t!("dep.epic_converted", id = id, count = tracked));
+    }
+
+    Ok(())
 }
 
 /// Internal implementation that accepts db for testing.
@@ -28,55 +89,97 @@ pub(crate) fn add_impl(db: &Database, from_id: &str, rel: &str, to_ids: &[String
 
         match user_rel {
             UserRelation::Blocks => {
-                db.add_dependency(&resolved_from, &resolved_to, Relation::Blocks)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Related)
                         .with_values(None, Some(format!("blocks {}", resolved_to))),
+                    || db.add_dependency(&resolved_from, &resolved_to, Relation::Blocks),
                 )?;
 
-                println!("{} blocks {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!("dep.blocks", from = resolved_from, to = resolved_to)
+                );
             }
             UserRelation::BlockedBy => {
                 // "A blocked by B" means "B blocks A"
-                db.add_dependency(&resolved_to, &resolved_from, Relation::Blocks)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Related)
                         .with_values(None, Some(format!("blocked by {}", resolved_to))),
+                    || db.add_dependency(&resolved_to, &resolved_from, Relation::Blocks),
                 )?;
 
-                println!("{} blocked by {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!("dep.blocked_by", from = resolved_from, to = resolved_to)
+                );
             }
             UserRelation::Tracks => {
                 // A tracks B means:
                 // - A tracks B
                 // - B tracked-by A
-                db.add_dependency(&resolved_from, &resolved_to, Relation::Tracks)?;
-                db.add_dependency(&resolved_to, &resolved_from, Relation::TrackedBy)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Related)
                         .with_values(None, Some(format!("tracks {}", resolved_to))),
+                    || {
+                        db.add_dependency(&resolved_from, &resolved_to, Relation::Tracks)?;
+                        db.add_dependency(&resolved_to, &resolved_from, Relation::TrackedBy)
+                    },
                 )?;
 
-                println!("{} tracks {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!("dep.tracks", from = resolved_from, to = resolved_to)
+                );
             }
             UserRelation::TrackedBy => {
                 // "A tracked by B" means "B tracks A"
-                db.add_dependency(&resolved_to, &resolved_from, Relation::Tracks)?;
-                db.add_dependency(&resolved_from, &resolved_to, Relation::TrackedBy)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Related)
                         .with_values(None, Some(format!("tracked by {}", resolved_to))),
+                    || {
+                        db.add_dependency(&resolved_to, &resolved_from, Relation::Tracks)?;
+                        db.add_dependency(&resolved_from, &resolved_to, Relation::TrackedBy)
+                    },
                 )?;
 
-                println!("{} tracked by {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!("dep.tracked_by", from = resolved_from, to = resolved_to)
+                );
+            }
+            UserRelation::RelatesTo => {
+                // Symmetric: each issue lists the other as related
+                apply_mutation(
+                    db,
+                    Event::new(resolved_from.clone(), Action::Associated)
+                        .with_values(None, Some(resolved_to.clone())),
+                    || {
+                        db.add_dependency(&resolved_from, &resolved_to, Relation::RelatesTo)?;
+                        db.add_dependency(&resolved_to, &resolved_from, Relation::RelatesTo)
+                    },
+                )?;
+
+                println!(
+                    "{}",
+                    t!("dep.relates_to", from = resolved_from, to = resolved_to)
+                );
+            }
+            UserRelation::DuplicateOf => {
+                apply_mutation(
+                    db,
+                    Event::new(resolved_from.clone(), Action::Duplicated)
+                        .with_values(None, Some(resolved_to.clone())),
+                    || db.add_dependency(&resolved_from, &resolved_to, Relation::DuplicateOf),
+                )?;
+
+                println!(
+                    "{}",
+                    t!("dep.duplicate_of", from = resolved_from, to = resolved_to)
+                );
             }
         }
     }
@@ -108,52 +211,109 @@ pub(crate) fn remove_impl(
 
         match user_rel {
             UserRelation::Blocks => {
-                db.remove_dependency(&resolved_from, &resolved_to, Relation::Blocks)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Unrelated)
                         .with_values(None, Some(format!("blocks {}", resolved_to))),
+                    || db.remove_dependency(&resolved_from, &resolved_to, Relation::Blocks),
                 )?;
 
-                println!("Removed: {} blocks {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!("dep.removed_blocks", from = resolved_from, to = resolved_to)
+                );
             }
             UserRelation::BlockedBy => {
                 // "A blocked by B" means "B blocks A"
-                db.remove_dependency(&resolved_to, &resolved_from, Relation::Blocks)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Unrelated)
                         .with_values(None, Some(format!("blocked by {}", resolved_to))),
+                    || db.remove_dependency(&resolved_to, &resolved_from, Relation::Blocks),
                 )?;
 
-                println!("Removed: {} blocked by {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!(
+                        "dep.removed_blocked_by",
+                        from = resolved_from,
+                        to = resolved_to
+                    )
+                );
             }
             UserRelation::Tracks => {
-                db.remove_dependency(&resolved_from, &resolved_to, Relation::Tracks)?;
-                db.remove_dependency(&resolved_to, &resolved_from, Relation::TrackedBy)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Unrelated)
                         .with_values(None, Some(format!("tracks {}", resolved_to))),
+                    || {
+                        db.remove_dependency(&resolved_from, &resolved_to, Relation::Tracks)?;
+                        db.remove_dependency(&resolved_to, &resolved_from, Relation::TrackedBy)
+                    },
                 )?;
 
-                println!("Removed: {} tracks {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!("dep.removed_tracks", from = resolved_from, to = resolved_to)
+                );
             }
             UserRelation::TrackedBy => {
                 // "A tracked by B" means "B tracks A"
-                db.remove_dependency(&resolved_to, &resolved_from, Relation::Tracks)?;
-                db.remove_dependency(&resolved_from, &resolved_to, Relation::TrackedBy)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_from.clone(), Action::Unrelated)
                         .with_values(None, Some(format!("tracked by {}", resolved_to))),
+                    || {
+                        db.remove_dependency(&resolved_to, &resolved_from, Relation::Tracks)?;
+                        db.remove_dependency(&resolved_from, &resolved_to, Relation::TrackedBy)
+                    },
+                )?;
+
+                println!(
+                    "{}",
+                    t!(
+                        "dep.removed_tracked_by",
+                        from = resolved_from,
+                        to = resolved_to
+                    )
+                );
+            }
+            UserRelation::RelatesTo => {
+                apply_mutation(
+                    db,
+                    Event::new(resolved_from.clone(), Action::Dissociated)
+                        .with_values(None, Some(resolved_to.clone())),
+                    || {
+                        db.remove_dependency(&resolved_from, &resolved_to, Relation::RelatesTo)?;
+                        db.remove_dependency(&resolved_to, &resolved_from, Relation::RelatesTo)
+                    },
+                )?;
+
+                println!(
+                    "{}",
+                    t!(
+                        "dep.removed_relates_to",
+                        from = resolved_from,
+                        to = resolved_to
+                    )
+                );
+            }
+            UserRelation::DuplicateOf => {
+                apply_mutation(
+                    db,
+                    Event::new(resolved_from.clone(), Action::Unduplicated)
+                        .with_values(None, Some(resolved_to.clone())),
+                    || db.remove_dependency(&resolved_from, &resolved_to, Relation::DuplicateOf),
                 )?;
 
-                println!("Removed: {} tracked by {}", resolved_from, resolved_to);
+                println!(
+                    "{}",
+                    t!(
+                        "dep.removed_duplicate_of",
+                        from = resolved_from,
+                        to = resolved_to
+                    )
+                );
             }
         }
     }
@@ -34,6 +34,7 @@ fn create_issue(db: &Database, id: &str) {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&issue).unwrap();
 }
@@ -375,3 +376,148 @@ fn test_add_impl_blocked_by_alternate_spellings() {
         .iter()
         .any(|dep| dep.relation == Relation::Blocks && dep.to_id == "c"));
 }
+
+#[test]
+fn test_relates_to_is_symmetric_and_non_blocking() {
+    let ctx = setup_test_context();
+    create_issue(&ctx.db, "a");
+    create_issue(&ctx.db, "b");
+
+    add_impl(&ctx.db, "a", "relates-to", &["b".to_string()]).unwrap();
+
+    assert_eq!(ctx.db.get_related("a").unwrap(), vec!["b"]);
+    assert_eq!(ctx.db.get_related("b").unwrap(), vec!["a"]);
+    assert!(ctx.db.get_blockers("a").unwrap().is_empty());
+    assert!(ctx.db.get_blockers("b").unwrap().is_empty());
+    let events = ctx.db.get_events("a").unwrap();
+    assert!(events
+        .iter()
+        .any(|e| e.action == Action::Associated && e.new_value.as_deref() == Some("b")));
+
+    // Removing from either side removes both directions
+    remove_impl(&ctx.db, "b", "relates-to", &["a".to_string()]).unwrap();
+    assert!(ctx.db.get_related("a").unwrap().is_empty());
+    assert!(ctx.db.get_related("b").unwrap().is_empty());
+}
+
+#[test]
+fn test_duplicate_of_is_directional() {
+    let ctx = setup_test_context();
+    create_issue(&ctx.db, "copy");
+    create_issue(&ctx.db, "orig");
+
+    add_impl(&ctx.db, "copy", "duplicate-of", &["orig".to_string()]).unwrap();
+
+    assert_eq!(ctx.db.get_duplicate_of("copy").unwrap(), vec!["orig"]);
+    assert_eq!(ctx.db.get_duplicates("orig").unwrap(), vec!["copy"]);
+    assert!(ctx.db.get_duplicate_of("orig").unwrap().is_empty());
+    assert!(ctx.db.get_blockers("orig").unwrap().is_empty());
+
+    remove_impl(&ctx.db, "copy", "duplicates", &["orig".to_string()]).unwrap();
+    assert!(ctx.db.get_duplicates("orig").unwrap().is_empty());
+    let actions: Vec<Action> = ctx
+        .db
+        .get_events("copy")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.action)
+        .collect();
+    assert_eq!(actions, vec![Action::Duplicated, Action::Unduplicated]);
+}
+
+// Tests for promote_epics
+
+/// A parent tracking three children.
+fn tracker_context() -> TestContext {
+    let ctx = setup_test_context();
+    for id in ["parent", "c1", "c2", "c3"] {
+        create_issue(&ctx.db, id);
+    }
+    let children = ["c1", "c2", "c3"].map(String::from);
+    add_impl(&ctx.db, "parent", "tracks", &children).unwrap();
+    ctx
+}
+
+fn epic_config(auto: AutoEpic, threshold: usize) -> EpicConfig {
+    EpicConfig { auto, threshold }
+}
+
+#[test]
+fn test_promote_epics_converts_at_threshold() {
+    let ctx = tracker_context();
+
+    promote_epics(
+        &ctx.db,
+        &["parent".to_string()],
+        &epic_config(AutoEpic::Convert, 3),
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.db.get_issue("parent").unwrap().issue_type,
+        IssueType::Epic
+    );
+    let event = ctx.db.get_events("parent").unwrap().pop().unwrap();
+    assert_eq!(event.action, Action::Edited);
+    assert_eq!(event.old_value.as_deref(), Some("task"));
+    assert_eq!(event.new_value.as_deref(), Some("epic"));
+}
+
+#[test]
+fn test_promote_epics_below_threshold_is_noop() {
+    let ctx = tracker_context();
+
+    promote_epics(
+        &ctx.db,
+        &["parent".to_string()],
+        &epic_config(AutoEpic::Convert, 4),
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.db.get_issue("parent").unwrap().issue_type,
+        IssueType::Task
+    );
+}
+
+#[test]
+fn test_promote_epics_suggest_and_off_leave_type() {
+    let ctx = tracker_context();
+    let events_before = ctx.db.get_events("parent").unwrap().len();
+
+    for auto in [AutoEpic::Suggest, AutoEpic::Off] {
+        promote_epics(
+            &ctx.db,
+            &["parent".to_string()],
+            &epic_config(auto, 3),
+            false,
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        ctx.db.get_issue("parent").unwrap().issue_type,
+        IssueType::Task
+    );
+    assert_eq!(ctx.db.get_events("parent").unwrap().len(), events_before);
+}
+
+#[test]
+fn test_promote_epics_force_overrides_off() {
+    let ctx = tracker_context();
+
+    promote_epics(
+        &ctx.db,
+        &["parent".to_string()],
+        &epic_config(AutoEpic::Off, 3),
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.db.get_issue("parent").unwrap().issue_type,
+        IssueType::Epic
+    );
+}
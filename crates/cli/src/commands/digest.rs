@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok digest` - a summary of a period's work, for posting to chat or email.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::cli::DigestFormat;
+use crate::db::Database;
+use crate::error::Result;
+use crate::history::parse_point;
+use crate::models::{Issue, LinkRel, Relation, Status};
+use crate::schema::digest::{DigestIssueJson, DigestOutputJson};
+
+use super::assign::resolve_assignees;
+use super::filtering::matches_prefix;
+use super::open_db;
+use super::sorting::due_from_tags;
+
+/// How far back the digest goes when `--since` is not given.
+const DEFAULT_SINCE: &str = "7d";
+
+/// How far ahead a due date counts as upcoming.
+const DUE_AHEAD_DAYS: i64 = 7;
+
+pub fn run(
+    since: Option<String>,
+    assignee: Vec<String>,
+    prefix: Option<String>,
+    output: DigestFormat,
+) -> Result<()> {
+    let now = Utc::now();
+    let since = parse_point(since.as_deref().unwrap_or(DEFAULT_SINCE), now)?;
+    let (db, config, work_dir) = open_db()?;
+    let assignee = resolve_assignees(assignee, &config, &work_dir)?;
+    let prefix = prefix.or((!config.prefix.is_empty()).then_some(config.prefix));
+
+    let digest = build(&db, since, now, &assignee, &prefix)?;
+    match output {
+        DigestFormat::Markdown => print!("{}", format_markdown(&digest, &assignee)),
+        DigestFormat::Json => println!("{}", serde_json::to_string_pretty(&digest)?),
+    }
+    Ok(())
+}
+
+/// Sort issues into the digest's sections.
+///
+/// `assignee` matches the issue's current assignee, as in `wok activity`.
+pub(crate) fn build(
+    db: &Database,
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+    assignee: &[String],
+    prefix: &Option<String>,
+) -> Result<DigestOutputJson> {
+    let issues: Vec<Issue> = db
+        .get_all_issues()?
+        .into_iter()
+        .filter(|issue| matches_prefix(prefix, &issue.id))
+        .filter(|issue| {
+            assignee.is_empty() || assignee.iter().any(|a| issue.assignee.as_ref() == Some(a))
+        })
+        .collect();
+    let active: HashSet<String> = db
+        .get_events_since(since)?
+        .into_iter()
+        .map(|event| event.issue_id)
+        .collect();
+    let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    let labels = db.get_labels_batch(&ids)?;
+    let due_by = (now + Duration::days(DUE_AHEAD_DAYS)).date_naive();
+
+    let mut digest = DigestOutputJson {
+        since,
+        until: now,
+        completed: Vec::new(),
+        created: Vec::new(),
+        stalled: Vec::new(),
+        blocked: Vec::new(),
+        due: Vec::new(),
+    };
+    // Oldest first, so each section reads in order
+    for issue in issues.iter().rev() {
+        if issue.status == Status::Done && issue.closed_at.is_some_and(|at| at >= since) {
+            digest.completed.push(DigestIssueJson {
+                closed_at: issue.closed_at,
+                ..entry(issue)
+            });
+        }
+        if issue.created_at >= since {
+            digest.created.push(entry(issue));
+        }
+        if issue.status.is_terminal() {
+            continue;
+        }
+        if issue.status == Status::InProgress && !active.contains(&issue.id) {
+            let last_activity = db
+                .get_events(&issue.id)?
+                .last()
+                .map(|event| event.created_at);
+            digest.stalled.push(DigestIssueJson {
+                last_activity: last_activity.or(Some(issue.updated_at)),
+                ..entry(issue)
+            });
+        }
+        let blocked_by = new_blockers(db, &issue.id, since)?;
+        if !blocked_by.is_empty() {
+            digest.blocked.push(DigestIssueJson {
+                blocked_by,
+                ..entry(issue)
+            });
+        }
+        let due = labels.get(&issue.id).and_then(|tags| due_from_tags(tags));
+        if due.is_some_and(|due| due <= due_by) {
+            digest.due.push(DigestIssueJson {
+                due,
+                ..entry(issue)
+            });
+        }
+    }
+
+    digest.completed.sort_by_key(|item| item.closed_at);
+    digest.stalled.sort_by_key(|item| item.last_activity);
+    digest.due.sort_by_key(|item| item.due);
+    Ok(digest)
+}
+
+/// Blockers of `issue_id` added since `since` that still block it: open
+/// issues, then unresolved external links.
+fn new_blockers(db: &Database, issue_id: &str, since: DateTime<Utc>) -> Result<Vec<String>> {
+    let mut blockers = Vec::new();
+    for dep in db.get_deps_to(issue_id)? {
+        if dep.relation == Relation::Blocks
+            && dep.created_at >= since
+            && db.get_issue(&dep.from_id)?.status.is_active()
+        {
+            blockers.push(dep.from_id);
+        }
+    }
+    for link in db.get_links(issue_id)? {
+        if link.rel == Some(LinkRel::Blocks)
+            && link.resolved_at.is_none()
+            && link.created_at >= since
+        {
+            blockers.extend(link.url);
+        }
+    }
+    Ok(blockers)
+}
+
+fn entry(issue: &Issue) -> DigestIssueJson {
+    DigestIssueJson {
+        id: issue.id.clone(),
+        issue_type: issue.issue_type,
+        status: issue.status,
+        title: issue.title.clone(),
+        assignee: issue.assignee.clone(),
+        closed_at: None,
+        last_activity: None,
+        blocked_by: Vec::new(),
+        due: None,
+    }
+}
+
+/// What follows an issue's title in its section.
+type Detail = fn(&DigestIssueJson) -> String;
+
+/// The digest as markdown: a heading for the period, then one section per
+/// list. Empty sections say so rather than disappearing, so a quiet week
+/// still reads as checked.
+pub(crate) fn format_markdown(digest: &DigestOutputJson, assignee: &[String]) -> String {
+    let mut output = format!(
+        "# Digest: {} to {}",
+        digest.since.format("%Y-%m-%d"),
+        digest.until.format("%Y-%m-%d")
+    );
+    if !assignee.is_empty() {
+        output.push_str(&format!(" ({})", assignee.join(", ")));
+    }
+    output.push('\n');
+
+    let today = digest.until.date_naive();
+    let sections: [(&str, &[DigestIssueJson], Detail); 5] = [
+        ("Completed", &digest.completed, |_| String::new()),
+        ("Created", &digest.created, |item| {
+            format!(" [{}]", item.status)
+        }),
+        ("Stalled", &digest.stalled, |item| {
+            match item.last_activity {
+                Some(at) => format!(" - idle since {}", at.format("%Y-%m-%d")),
+                None => String::new(),
+            }
+        }),
+        ("Newly blocked", &digest.blocked, |item| {
+            format!(" - blocked by {}", item.blocked_by.join(", "))
+        }),
+        ("Due soon", &digest.due, |item| match item.due {
+            Some(due) => format!(" - due {}", due),
+            None => String::new(),
+        }),
+    ];
+    for (heading, items, detail) in sections {
+        output.push_str(&format!("\n## {} ({})\n\n", heading, items.len()));
+        if items.is_empty() {
+            output.push_str("_None_\n");
+        }
+        for item in items {
+            let overdue = match item.due {
+                Some(due) if due < today => " (overdue)",
+                _ => "",
+            };
+            let assignee = match &item.assignee {
+                Some(assignee) => format!(" ({})", assignee),
+                None => String::new(),
+            };
+            output.push_str(&format!(
+                "- `{}` {}{}{}{}\n",
+                item.id,
+                item.title,
+                assignee,
+                detail(item),
+                overdue
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+#[path = "digest_tests.rs"]
+mod tests;
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::{Action, Event, IssueType};
+
+fn at(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+}
+
+fn ids(items: &[DigestIssueJson]) -> Vec<&str> {
+    items.iter().map(|item| item.id.as_str()).collect()
+}
+
+#[test]
+fn completed_and_created_cover_the_period() {
+    let mut ctx = TestContext::new();
+    ctx.create_completed("test-1", IssueType::Task, "Shipped")
+        .create_and_start("test-2", IssueType::Bug, "Underway")
+        .create_issue("test-3", IssueType::Task, "Closed")
+        .close_issue("test-3");
+    let now = Utc::now();
+
+    let digest = build(&ctx.db, now - Duration::hours(1), now, &[], &None).unwrap();
+
+    assert_eq!(ids(&digest.completed), ["test-1"]);
+    assert!(digest.completed[0].closed_at.is_some());
+    assert_eq!(ids(&digest.created), ["test-1", "test-2", "test-3"]);
+    assert!(digest.stalled.is_empty());
+
+    let later = now + Duration::days(8);
+    let digest = build(&ctx.db, now + Duration::days(1), later, &[], &None).unwrap();
+    assert!(digest.completed.is_empty());
+    assert!(digest.created.is_empty());
+}
+
+#[test]
+fn in_progress_issues_without_events_are_stalled() {
+    let mut ctx = TestContext::new();
+    ctx.create_and_start("test-1", IssueType::Task, "Idle")
+        .create_and_start("test-2", IssueType::Task, "Busy")
+        .create_issue("test-3", IssueType::Task, "Not started");
+    let since = Utc::now() + Duration::seconds(1);
+    let idle_since = ctx
+        .db
+        .get_events("test-1")
+        .unwrap()
+        .last()
+        .unwrap()
+        .created_at;
+    // An event in the period keeps test-2 off the list
+    let mut event = Event::new("test-2".to_string(), Action::Noted);
+    event.created_at = since + Duration::hours(1);
+    ctx.db.log_event(&event).unwrap();
+
+    let digest = build(&ctx.db, since, since + Duration::days(7), &[], &None).unwrap();
+
+    assert_eq!(ids(&digest.stalled), ["test-1"]);
+    assert_eq!(digest.stalled[0].last_activity, Some(idle_since));
+}
+
+#[test]
+fn new_open_blockers_are_listed() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Blocker")
+        .create_issue("test-2", IssueType::Task, "Blocked")
+        .create_completed("test-3", IssueType::Task, "Done blocker")
+        .create_issue("test-4", IssueType::Task, "Not blocked")
+        .blocks("test-1", "test-2")
+        .blocks("test-3", "test-4");
+    let now = Utc::now();
+
+    let digest = build(&ctx.db, now - Duration::hours(1), now, &[], &None).unwrap();
+    assert_eq!(ids(&digest.blocked), ["test-2"]);
+    assert_eq!(digest.blocked[0].blocked_by, ["test-1"]);
+
+    // Blockers added before the period are not news
+    let digest = build(&ctx.db, now + Duration::hours(1), now, &[], &None).unwrap();
+    assert!(digest.blocked.is_empty());
+}
+
+#[test]
+fn due_soon_includes_overdue_and_skips_closed() {
+    let mut ctx = TestContext::new();
+    let now = at("2026-03-10T12:00:00Z");
+    ctx.create_issue("test-1", IssueType::Task, "Soon")
+        .add_label("test-1", "due:2026-03-15")
+        .create_issue("test-2", IssueType::Task, "Late")
+        .add_label("test-2", "due:2026-03-01")
+        .create_issue("test-3", IssueType::Task, "Later")
+        .add_label("test-3", "due:2026-04-01")
+        .create_completed("test-4", IssueType::Task, "Done on time")
+        .add_label("test-4", "due:2026-03-12");
+
+    let digest = build(&ctx.db, now - Duration::days(7), now, &[], &None).unwrap();
+
+    assert_eq!(ids(&digest.due), ["test-2", "test-1"]);
+    assert_eq!(
+        digest.due[1].due,
+        Some(NaiveDate::from_ymd_opt(2026, 3, 15).unwrap())
+    );
+}
+
+#[test]
+fn filters_by_assignee_and_prefix() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Team work")
+        .create_issue("test-2", IssueType::Task, "Someone else's")
+        .create_issue("other-1", IssueType::Task, "Other project");
+    ctx.db.set_assignee("test-1", "team").unwrap();
+    let now = Utc::now();
+    let since = now - Duration::hours(1);
+
+    let team = vec!["team".to_string()];
+    let digest = build(&ctx.db, since, now, &team, &None).unwrap();
+    assert_eq!(ids(&digest.created), ["test-1"]);
+
+    let prefix = Some("other".to_string());
+    let digest = build(&ctx.db, since, now, &[], &prefix).unwrap();
+    assert_eq!(ids(&digest.created), ["other-1"]);
+}
+
+#[test]
+fn markdown_has_a_section_per_list() {
+    let item = |id: &str, title: &str| DigestIssueJson {
+        id: id.to_string(),
+        issue_type: IssueType::Task,
+        status: Status::Todo,
+        title: title.to_string(),
+        assignee: None,
+        closed_at: None,
+        last_activity: None,
+        blocked_by: Vec::new(),
+        due: None,
+    };
+    let digest = DigestOutputJson {
+        since: at("2026-03-03T12:00:00Z"),
+        until: at("2026-03-10T12:00:00Z"),
+        completed: vec![DigestIssueJson {
+            status: Status::Done,
+            assignee: Some("alice".to_string()),
+            ..item("prj-1", "Fix login")
+        }],
+        created: vec![item("prj-2", "Add export")],
+        stalled: vec![DigestIssueJson {
+            last_activity: Some(at("2026-02-20T08:00:00Z")),
+            ..item("prj-3", "Refactor db")
+        }],
+        blocked: vec![],
+        due: vec![DigestIssueJson {
+            due: NaiveDate::from_ymd_opt(2026, 3, 9),
+            ..item("prj-4", "Release notes")
+        }],
+    };
+
+    assert_eq!(
+        format_markdown(&digest, &["alice".to_string()]),
+        "# Digest: 2026-03-03 to 2026-03-10 (alice)\n\
+         \n## Completed (1)\n\n- `prj-1` Fix login (alice)\n\
+         \n## Created (1)\n\n- `prj-2` Add export [todo]\n\
+         \n## Stalled (1)\n\n- `prj-3` Refactor db - idle since 2026-02-20\n\
+         \n## Newly blocked (0)\n\n_None_\n\
+         \n## Due soon (1)\n\n- `prj-4` Release notes - due 2026-03-09 (overdue)\n"
+    );
+}
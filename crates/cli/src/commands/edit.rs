@@ -5,18 +5,31 @@ use std::str::FromStr;
 
 use crate::db::Database;
 
-use super::{apply_mutation, open_db};
+use super::assign::{assign_impl, check_roster, resolve_assignee, unassign_impl};
+use super::{apply_mutation, apply_mutation_if, expect_revision, open_db};
 use crate::error::{Error, Result};
+use crate::estimate::{Estimate, ESTIMATE_PREFIX};
 use crate::models::{Action, Event, IssueType, Status};
-use crate::validate::{
-    validate_and_normalize_title, validate_and_trim_description, validate_assignee,
-};
-
-pub fn run(id: &str, attr: &str, value: &str) -> Result<()> {
-    let (mut db, _config, _work_dir) = open_db()?;
+use crate::t;
+use crate::validate::{validate_and_normalize_title, validate_and_trim_description};
+
+pub fn run(id: &str, attr: &str, value: &str, if_match: Option<u32>) -> Result<()> {
+    let (mut db, config, work_dir) = open_db()?;
+    expect_revision(&db, &[id.to_string()], if_match)?;
+    if attr.eq_ignore_ascii_case("assignee") && !is_clear(value) {
+        let who = resolve_assignee(value, &config, &work_dir)?;
+        check_roster(&who, &config)?;
+        return run_impl(&mut db, id, attr, &who);
+    }
     run_impl(&mut db, id, attr, value)
 }
 
+/// Whether a value clears the attribute (empty or "none").
+fn is_clear(value: &str) -> bool {
+    let trimmed = value.trim();
+    trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none")
+}
+
 /// Internal implementation that accepts db for testing.
 pub(crate) fn run_impl(db: &mut Database, id: &str, attr: &str, value: &str) -> Result<()> {
     let resolved_id = db.resolve_id(id)?;
@@ -27,90 +40,115 @@ pub(crate) fn run_impl(db: &mut Database, id: &str, attr: &str, value: &str) ->
             let normalized = validate_and_normalize_title(value)?;
 
             let old_title = issue.title.clone();
-            db.update_issue_title(&resolved_id, &normalized.title)?;
-
             apply_mutation(
                 db,
                 Event::new(resolved_id.clone(), Action::Edited)
                     .with_values(Some(old_title), Some(normalized.title.clone())),
+                || db.update_issue_title(&resolved_id, &normalized.title),
             )?;
 
             // If normalization extracted a description, add it as a note
             if let Some(extracted) = normalized.extracted_description {
                 if issue.status != Status::Closed {
-                    db.add_note(&resolved_id, issue.status, &extracted)?;
-
                     apply_mutation(
                         db,
                         Event::new(resolved_id.clone(), Action::Noted)
-                            .with_values(None, Some(extracted)),
+                            .with_values(None, Some(extracted.clone())),
+                        || {
+                            db.add_note(&resolved_id, issue.status, &extracted)?;
+                            db.record_mentions(&resolved_id, &extracted)
+                        },
                     )?;
                 }
             }
 
-            println!("Updated title of {} to: {}", resolved_id, normalized.title);
+            println!(
+                "{}",
+                t!("edit.title", id = resolved_id, title = normalized.title)
+            );
         }
         "type" => {
             let new_type = IssueType::from_str(value)?;
             let old_type = issue.issue_type;
 
             if new_type != old_type {
-                db.update_issue_type(&resolved_id, new_type)?;
-
                 apply_mutation(
                     db,
                     Event::new(resolved_id.clone(), Action::Edited).with_values(
                         Some(old_type.as_str().to_string()),
                         Some(new_type.as_str().to_string()),
                     ),
+                    || db.update_issue_type(&resolved_id, new_type),
                 )?;
 
-                println!("Updated type of {} to: {}", resolved_id, new_type.as_str());
+                println!(
+                    "{}",
+                    t!(
+                        "edit.type",
+                        id = resolved_id,
+                        issue_type = new_type.as_str()
+                    )
+                );
             }
         }
         "description" => {
             let trimmed_desc = validate_and_trim_description(value)?;
             let old_desc = issue.description.clone();
-            db.update_issue_description(&resolved_id, &trimmed_desc)?;
-
             apply_mutation(
                 db,
                 Event::new(resolved_id.clone(), Action::Edited)
-                    .with_values(old_desc, Some(trimmed_desc)),
+                    .with_values(old_desc, Some(trimmed_desc.clone())),
+                || {
+                    db.update_issue_description(&resolved_id, &trimmed_desc)?;
+                    db.record_mentions(&resolved_id, &trimmed_desc)
+                },
             )?;
 
-            println!("Updated description of {}", resolved_id);
+            println!("{}", t!("edit.description", id = resolved_id));
         }
         "assignee" => {
-            let old_assignee = issue.assignee.clone();
-            let trimmed = value.trim();
-
-            // Clear assignee if value is empty or "none"
-            if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
-                if old_assignee.is_none() {
-                    println!("{} is not assigned", resolved_id);
-                } else {
-                    db.clear_assignee(&resolved_id)?;
-
-                    apply_mutation(
+            if is_clear(value) {
+                unassign_impl(db, &resolved_id)?;
+            } else {
+                assign_impl(db, &resolved_id, value)?;
+            }
+        }
+        "estimate" => {
+            let estimate = if is_clear(value) {
+                None
+            } else {
+                Some(value.parse::<Estimate>()?)
+            };
+            let new_label = estimate.map(|e| e.label());
+            let labels = db.get_labels(&resolved_id)?;
+
+            // An issue has one estimate: drop any other
+            for old in labels.iter().filter(|l| l.starts_with(ESTIMATE_PREFIX)) {
+                if Some(old) != new_label.as_ref() {
+                    apply_mutation_if(
                         db,
-                        Event::new(resolved_id.clone(), Action::Unassigned)
-                            .with_values(old_assignee, None),
+                        Event::new(resolved_id.clone(), Action::Unlabeled)
+                            .with_values(None, Some(old.clone())),
+                        || db.remove_label(&resolved_id, old),
                     )?;
-
-                    println!("Unassigned {}", resolved_id);
                 }
-            } else {
-                validate_assignee(trimmed)?;
-                db.set_assignee(&resolved_id, trimmed)?;
-
-                apply_mutation(
-                    db,
-                    Event::new(resolved_id.clone(), Action::Assigned)
-                        .with_values(old_assignee, Some(trimmed.to_string())),
-                )?;
-
-                println!("Assigned {} to {}", resolved_id, trimmed);
+            }
+            match (estimate, new_label) {
+                (Some(estimate), Some(label)) => {
+                    if !labels.contains(&label) {
+                        apply_mutation(
+                            db,
+                            Event::new(resolved_id.clone(), Action::Labeled)
+                                .with_values(None, Some(label.clone())),
+                            || db.add_label(&resolved_id, &label),
+                        )?;
+                    }
+                    println!(
+                        "{}",
+                        t!("edit.estimate", id = resolved_id, estimate = estimate)
+                    );
+                }
+                _ => println!("{}", t!("edit.estimate_cleared", id = resolved_id)),
             }
         }
         _ => {
@@ -5,7 +5,9 @@
 #![allow(clippy::expect_used)]
 
 use crate::commands::edit::run_impl;
+use crate::commands::expect_revision;
 use crate::commands::testing::TestContext;
+use crate::error::Error;
 use crate::models::{Action, IssueType, Status};
 use crate::validate::MAX_DESCRIPTION_LENGTH;
 
@@ -328,3 +330,94 @@ fn test_title_normalized_no_note_on_closed_issue() {
     let notes = ctx.db.get_notes("test-1").unwrap();
     assert!(notes.is_empty());
 }
+
+#[test]
+fn test_estimate_replaces_previous_estimate() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Sized")
+        .add_label("test-1", "estimate:3pt");
+
+    run_impl(&mut ctx.db, "test-1", "estimate", "1.5d").unwrap();
+
+    let labels = ctx.db.get_labels("test-1").unwrap();
+    assert_eq!(labels, vec!["estimate:12h".to_string()]);
+    let actions: Vec<Action> = ctx
+        .db
+        .get_events("test-1")
+        .unwrap()
+        .iter()
+        .map(|e| e.action)
+        .collect();
+    assert!(actions.contains(&Action::Unlabeled));
+    assert!(actions.contains(&Action::Labeled));
+}
+
+#[test]
+fn test_estimate_none_clears_it() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Sized")
+        .add_label("test-1", "estimate:3pt")
+        .add_label("test-1", "backend");
+
+    run_impl(&mut ctx.db, "test-1", "estimate", "none").unwrap();
+
+    assert_eq!(
+        ctx.db.get_labels("test-1").unwrap(),
+        vec!["backend".to_string()]
+    );
+}
+
+#[test]
+fn test_invalid_estimate_fails() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Sized");
+
+    let result = run_impl(&mut ctx.db, "test-1", "estimate", "a lot");
+    assert!(matches!(result, Err(Error::InvalidEstimate { .. })));
+}
+
+#[test]
+fn test_if_match_current_revision_applies() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Original title");
+
+    expect_revision(&ctx.db, &["test-1".to_string()], Some(1)).unwrap();
+    run_impl(&mut ctx.db, "test-1", "title", "Updated title").unwrap();
+
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    assert_eq!(issue.title, "Updated title");
+    assert_eq!(issue.revision, 2);
+}
+
+#[test]
+fn test_if_match_stale_revision_conflicts() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Original title");
+    run_impl(&mut ctx.db, "test-1", "title", "Theirs").unwrap();
+
+    let result = expect_revision(&ctx.db, &["test-1".to_string()], Some(1));
+    assert!(matches!(
+        result,
+        Err(Error::Conflict {
+            expected: 1,
+            actual: 2,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_if_match_guards_estimate_labels() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Sized");
+    expect_revision(&ctx.db, &["test-1".to_string()], Some(1)).unwrap();
+    // Another writer changes the issue after the check
+    ctx.db
+        .conn
+        .execute("UPDATE issues SET revision = 2 WHERE id = 'test-1'", [])
+        .unwrap();
+
+    let result = run_impl(&mut ctx.db, "test-1", "estimate", "2d");
+    assert!(matches!(result, Err(Error::Conflict { .. })));
+    assert!(ctx.db.get_labels("test-1").unwrap().is_empty());
+}
@@ -4,61 +4,65 @@
 use std::fs::File;
 use std::io::{BufWriter, Write};
 
-use serde::Serialize;
-
+use crate::cli::{ExportFormat, RecordFormat};
 use crate::db::Database;
-use crate::error::Result;
-use crate::models::{Dependency, Event, Issue, Link, Note};
+use crate::error::{Error, Result};
+use crate::models::Issue;
+use crate::ndjson;
+use crate::schema::export::{ExportRecord, EXPORT_SCHEMA_VERSION};
 use crate::validate::validate_export_path;
+use wk_core::query::IssueQuery;
 
+use super::export_beads::beads_record;
 use super::open_db;
 
-#[derive(Serialize)]
-struct ExportedIssue {
-    #[serde(flatten)]
-    issue: Issue,
-    labels: Vec<String>,
-    notes: Vec<Note>,
-    deps: Vec<Dependency>,
-    links: Vec<Link>,
-    events: Vec<Event>,
-}
-
-pub fn run(filepath: &str) -> Result<()> {
-    // Validate export path
-    validate_export_path(filepath)?;
+pub fn run(
+    filepath: Option<&str>,
+    output: Option<ExportFormat>,
+    format: RecordFormat,
+) -> Result<()> {
+    match (output, filepath) {
+        (Some(ExportFormat::Ndjson), _) => {
+            let (db, _, _) = open_db()?;
+            let mut rows = db.stream_issues(&IssueQuery::default())?;
+            let issues = rows.iter()?;
+            match format {
+                RecordFormat::Wok => {
+                    ndjson::print_lines(issues.map(|issue| export_record(&db, issue?)))?
+                }
+                RecordFormat::Bd => {
+                    ndjson::print_lines(issues.map(|issue| beads_record(&db, issue?)))?
+                }
+            };
+            Ok(())
+        }
+        (None, Some(filepath)) => {
+            // Validate export path
+            validate_export_path(filepath)?;
 
-    let (db, _, _) = open_db()?;
-    run_impl(&db, filepath)
+            let (db, _, _) = open_db()?;
+            run_impl(&db, filepath, format)
+        }
+        (None, None) => Err(Error::ExportPathEmpty),
+    }
 }
 
 /// Internal implementation that accepts db for testing.
-pub(crate) fn run_impl(db: &Database, filepath: &str) -> Result<()> {
-    let issues = db.get_all_issues()?;
+pub(crate) fn run_impl(db: &Database, filepath: &str, format: RecordFormat) -> Result<()> {
     let file = File::create(filepath)?;
     let mut writer = BufWriter::new(file);
 
-    let mut count = 0;
-    for issue in issues {
-        let labels = db.get_labels(&issue.id)?;
-        let notes = db.get_notes(&issue.id)?;
-        let deps = db.get_deps_from(&issue.id)?;
-        let links = db.get_links(&issue.id)?;
-        let events = db.get_events(&issue.id)?;
-
-        let exported = ExportedIssue {
-            issue,
-            labels,
-            notes,
-            deps,
-            links,
-            events,
-        };
-
-        let json = serde_json::to_string(&exported)?;
-        writeln!(writer, "{}", json)?;
-        count += 1;
-    }
+    // Stream issues so memory stays flat however large the database is.
+    let mut rows = db.stream_issues(&IssueQuery::default())?;
+    let issues = rows.iter()?;
+    let count = match format {
+        RecordFormat::Wok => {
+            ndjson::write_lines(&mut writer, issues.map(|issue| export_record(db, issue?)))?
+        }
+        RecordFormat::Bd => {
+            ndjson::write_lines(&mut writer, issues.map(|issue| beads_record(db, issue?)))?
+        }
+    };
 
     writer.flush()?;
     println!("Exported {} issues to {}", count, filepath);
@@ -66,6 +70,19 @@ pub(crate) fn run_impl(db: &Database, filepath: &str) -> Result<()> {
     Ok(())
 }
 
+/// The export record for `issue`: the issue with everything attached to it.
+fn export_record(db: &Database, issue: Issue) -> Result<ExportRecord> {
+    Ok(ExportRecord {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        labels: db.get_labels(&issue.id)?,
+        notes: db.get_notes(&issue.id)?,
+        deps: db.get_deps_from(&issue.id)?,
+        links: db.get_links(&issue.id)?,
+        events: db.get_events(&issue.id)?,
+        issue,
+    })
+}
+
 #[cfg(test)]
 #[path = "export_tests.rs"]
 mod tests;
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Beads records for `wok export --format bd`.
+//!
+//! Each issue becomes the record `bd import` reads, mapped back the way
+//! `wok import --format bd` maps records in: `todo` is `open`, `done` and
+//! `closed` are `closed` (a `closed` issue's reason names a failure), the
+//! priority label is `priority`, and tracked-by and blocking issues are
+//! dependencies on the issue's own record. What bd has no field for (the
+//! `idea` type, note kinds, external links, duplicate marks) goes under
+//! `metadata`, keyed `wok_*`.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::db::{parse_priority_value, priority_from_tags, Database};
+use crate::error::Result;
+use crate::models::{Action, Event, Issue, IssueType, LinkRel, Relation, Status};
+
+use super::import::is_failure_reason;
+
+/// One line of a beads export.
+#[derive(Debug, Serialize)]
+pub(crate) struct BeadsRecord {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub status: &'static str,
+    pub priority: u8,
+    pub issue_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_reason: Option<String>,
+    pub labels: Vec<String>,
+    pub dependencies: Vec<BeadsDependency>,
+    pub comments: Vec<BeadsComment>,
+    pub events: Vec<BeadsEvent>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct BeadsDependency {
+    pub depends_on_id: String,
+    #[serde(rename = "type")]
+    pub dep_type: &'static str,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct BeadsComment {
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct BeadsEvent {
+    pub event_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The beads record for `issue`.
+pub(crate) fn beads_record(db: &Database, issue: Issue) -> Result<BeadsRecord> {
+    let mut metadata = BTreeMap::new();
+    let labels = db.get_labels(&issue.id)?;
+    let priority = priority_from_tags(&labels);
+    let labels = labels
+        .into_iter()
+        .filter(|l| !is_priority_label(l))
+        .collect();
+
+    let events = db.get_events(&issue.id)?;
+    let reason = last_close_reason(&events);
+
+    // The close reason travels as close_reason, not as a comment too
+    let notes: Vec<_> = db
+        .get_notes(&issue.id)?
+        .into_iter()
+        .filter(|n| !(n.status == Status::Closed && reason.as_deref() == Some(&n.content)))
+        .collect();
+    if notes.iter().any(|n| n.kind.is_some()) {
+        let kinds: Vec<Value> = notes
+            .iter()
+            .map(|n| n.kind.map_or(Value::Null, |k| json!(k.as_str())))
+            .collect();
+        metadata.insert("wok_note_kinds".to_string(), Value::Array(kinds));
+    }
+    let comments = notes
+        .into_iter()
+        .map(|n| BeadsComment {
+            text: n.content,
+            created_at: n.created_at,
+        })
+        .collect();
+
+    let mut dependencies: Vec<BeadsDependency> = db
+        .get_deps_to(&issue.id)?
+        .into_iter()
+        .filter(|d| d.relation == Relation::Blocks)
+        .map(|d| BeadsDependency {
+            depends_on_id: d.from_id,
+            dep_type: "blocks",
+            created_at: d.created_at,
+        })
+        .collect();
+    let mut duplicate_of = Vec::new();
+    for dep in db.get_deps_from(&issue.id)? {
+        let dep_type = match dep.relation {
+            Relation::TrackedBy => "parent-child",
+            Relation::RelatesTo => "related",
+            Relation::DuplicateOf => {
+                duplicate_of.push(dep.to_id);
+                continue;
+            }
+            // Blocks and tracks are carried by the other issue's record;
+            // references are found again from the text
+            Relation::Blocks | Relation::Tracks | Relation::References => continue,
+        };
+        dependencies.push(BeadsDependency {
+            depends_on_id: dep.to_id,
+            dep_type,
+            created_at: dep.created_at,
+        });
+    }
+    if !duplicate_of.is_empty() {
+        metadata.insert("wok_duplicate_of".to_string(), json!(duplicate_of));
+    }
+
+    let links: Vec<Value> = db
+        .get_links(&issue.id)?
+        .into_iter()
+        // Import links only record where wok got the issue from
+        .filter(|l| l.rel != Some(LinkRel::Import))
+        .filter_map(|l| l.url.map(Value::String))
+        .collect();
+    if !links.is_empty() {
+        metadata.insert("wok_links".to_string(), Value::Array(links));
+    }
+
+    let issue_type = match issue.issue_type {
+        IssueType::Bug => "bug",
+        IssueType::Feature => "feature",
+        IssueType::Epic => "epic",
+        IssueType::Chore => "chore",
+        IssueType::Task => "task",
+        IssueType::Idea => {
+            metadata.insert("wok_type".to_string(), json!("idea"));
+            "task"
+        }
+    };
+
+    Ok(BeadsRecord {
+        title: issue.title,
+        description: issue.description,
+        status: beads_status(issue.status),
+        priority,
+        issue_type,
+        assignee: issue.assignee,
+        created_at: issue.created_at,
+        updated_at: issue.updated_at,
+        closed_at: issue.closed_at,
+        close_reason: close_reason(issue.status, reason),
+        labels,
+        dependencies,
+        comments,
+        events: events.iter().filter_map(beads_event).collect(),
+        metadata,
+        id: issue.id,
+    })
+}
+
+/// The bd status of a wok status.
+fn beads_status(status: Status) -> &'static str {
+    match status {
+        Status::Todo => "open",
+        Status::InProgress => "in_progress",
+        Status::Done | Status::Closed => "closed",
+    }
+}
+
+/// A bd status for a wok status value in an event, if it is one.
+fn beads_status_value(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .and_then(|v| v.parse().ok())
+        .map(|status| beads_status(status).to_string())
+}
+
+/// Whether `label` is one `priority_from_tags` reads.
+fn is_priority_label(label: &str) -> bool {
+    label
+        .strip_prefix("priority:")
+        .or_else(|| label.strip_prefix("p:"))
+        .and_then(parse_priority_value)
+        .is_some()
+}
+
+/// The reason the issue was last finished with.
+fn last_close_reason(events: &[Event]) -> Option<String> {
+    events
+        .iter()
+        .rev()
+        .find(|e| matches!(e.action, Action::Done | Action::Closed))
+        .and_then(|e| e.reason.clone())
+}
+
+/// The bd close reason of a finished issue. bd tells done from closed
+/// issues by the reason alone, so a closed issue's reason always names a
+/// failure.
+fn close_reason(status: Status, reason: Option<String>) -> Option<String> {
+    match status {
+        Status::Done => reason,
+        Status::Closed => Some(match reason {
+            Some(reason) if is_failure_reason(&reason) => reason,
+            Some(reason) => format!("wontfix: {}", reason),
+            None => "wontfix".to_string(),
+        }),
+        Status::Todo | Status::InProgress => None,
+    }
+}
+
+/// The bd history entry for a wok event, for the actions bd records.
+fn beads_event(event: &Event) -> Option<BeadsEvent> {
+    let status_change = || {
+        (
+            beads_status_value(&event.old_value),
+            beads_status_value(&event.new_value),
+        )
+    };
+    // wok records a removed label or dependency as the new value; events
+    // imported from bd carry it as the old one
+    let removed = || event.new_value.clone().or_else(|| event.old_value.clone());
+    let (event_type, (old_value, new_value)) = match event.action {
+        Action::Created => ("created", (None, None)),
+        Action::Edited => (
+            "updated",
+            (event.old_value.clone(), event.new_value.clone()),
+        ),
+        Action::Started | Action::Stopped => ("status_changed", status_change()),
+        Action::Done | Action::Closed => ("closed", status_change()),
+        Action::Reopened => ("reopened", status_change()),
+        Action::Labeled => ("label_added", (None, event.new_value.clone())),
+        Action::Unlabeled => ("label_removed", (removed(), None)),
+        Action::Related => ("dependency_added", (None, event.new_value.clone())),
+        Action::Unrelated => ("dependency_removed", (removed(), None)),
+        Action::Assigned | Action::Unassigned => (
+            "assignee_changed",
+            (event.old_value.clone(), event.new_value.clone()),
+        ),
+        Action::Noted => ("commented", (None, None)),
+        Action::Unnoted
+        | Action::Linked
+        | Action::Unlinked
+        | Action::Unblocked
+        | Action::Associated
+        | Action::Dissociated
+        | Action::Duplicated
+        | Action::Unduplicated
+        | Action::Stale => return None,
+    };
+    Some(BeadsEvent {
+        event_type,
+        old_value,
+        new_value,
+        comment: event.reason.clone(),
+        created_at: event.created_at,
+    })
+}
+
+#[cfg(test)]
+#[path = "export_beads_tests.rs"]
+mod tests;
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::cli::{OnConflict, RecordFormat};
+use crate::commands::export;
+use crate::commands::import::{self, Source};
+use crate::commands::testing::TestContext;
+use crate::models::{Link, NoteKind};
+
+fn record(ctx: &TestContext, id: &str) -> BeadsRecord {
+    beads_record(&ctx.db, ctx.db.get_issue(id).unwrap()).unwrap()
+}
+
+#[test]
+fn status_type_and_priority_map_to_bd() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Bug, "Crash")
+        .add_label("test-1", "priority:1")
+        .add_label("test-1", "backend")
+        .create_and_start("test-2", IssueType::Idea, "Maybe");
+
+    let bug = record(&ctx, "test-1");
+    assert_eq!(bug.status, "open");
+    assert_eq!(bug.issue_type, "bug");
+    assert_eq!(bug.priority, 1);
+    assert_eq!(bug.labels, ["backend"]);
+    assert!(bug.metadata.is_empty());
+
+    let idea = record(&ctx, "test-2");
+    assert_eq!(idea.status, "in_progress");
+    assert_eq!(idea.issue_type, "task");
+    assert_eq!(idea.priority, 2);
+    assert_eq!(idea.metadata["wok_type"], "idea");
+}
+
+#[test]
+fn closed_issue_reason_names_a_failure() {
+    assert_eq!(
+        close_reason(Status::Done, Some("shipped".into())),
+        Some("shipped".into())
+    );
+    assert_eq!(close_reason(Status::Done, None), None);
+    assert_eq!(
+        close_reason(Status::Closed, Some("not needed".into())),
+        Some("wontfix: not needed".into())
+    );
+    assert_eq!(
+        close_reason(Status::Closed, Some("rejected upstream".into())),
+        Some("rejected upstream".into())
+    );
+    assert_eq!(close_reason(Status::Closed, None), Some("wontfix".into()));
+    assert_eq!(close_reason(Status::Todo, Some("x".into())), None);
+}
+
+#[test]
+fn close_reason_note_is_not_repeated_as_a_comment() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Old")
+        .add_note("test-1", "Context");
+    ctx.db
+        .update_issue_status("test-1", Status::Closed)
+        .unwrap();
+    ctx.db
+        .add_note("test-1", Status::Closed, "not needed")
+        .unwrap();
+    let event = Event::new("test-1".to_string(), Action::Closed)
+        .with_values(Some("todo".into()), Some("closed".into()))
+        .with_reason(Some("not needed".into()));
+    ctx.db.log_event(&event).unwrap();
+
+    let rec = record(&ctx, "test-1");
+    assert_eq!(rec.status, "closed");
+    assert_eq!(rec.close_reason.as_deref(), Some("wontfix: not needed"));
+    let texts: Vec<_> = rec.comments.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts, ["Context"]);
+
+    let closed = rec
+        .events
+        .iter()
+        .find(|e| e.event_type == "closed")
+        .unwrap();
+    assert_eq!(closed.old_value.as_deref(), Some("open"));
+    assert_eq!(closed.new_value.as_deref(), Some("closed"));
+    assert_eq!(closed.comment.as_deref(), Some("not needed"));
+}
+
+#[test]
+fn dependencies_are_recorded_on_the_dependent_issue() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-epic", IssueType::Epic, "Epic")
+        .create_issue("test-a", IssueType::Task, "A")
+        .create_issue("test-b", IssueType::Task, "B")
+        .tracks("test-epic", "test-a")
+        .blocks("test-a", "test-b");
+
+    let deps = |id| -> Vec<(String, &str)> {
+        record(&ctx, id)
+            .dependencies
+            .into_iter()
+            .map(|d| (d.depends_on_id, d.dep_type))
+            .collect()
+    };
+    assert!(deps("test-epic").is_empty());
+    assert_eq!(deps("test-a"), [("test-epic".to_string(), "parent-child")]);
+    assert_eq!(deps("test-b"), [("test-a".to_string(), "blocks")]);
+}
+
+#[test]
+fn removals_export_the_removed_value() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "A")
+        .create_issue("test-2", IssueType::Task, "B")
+        .add_label("test-1", "backend")
+        .blocks("test-1", "test-2");
+    crate::commands::label::remove_impl(&ctx.db, &["test-1".to_string()], "backend").unwrap();
+    crate::commands::dep::remove_impl(&ctx.db, "test-1", "blocks", &["test-2".to_string()])
+        .unwrap();
+
+    let rec = record(&ctx, "test-1");
+    let value = |event_type| {
+        let event = rec
+            .events
+            .iter()
+            .find(|e| e.event_type == event_type)
+            .unwrap();
+        event.old_value.clone()
+    };
+    assert_eq!(value("label_removed").as_deref(), Some("backend"));
+    assert_eq!(
+        value("dependency_removed").as_deref(),
+        Some("blocks test-2")
+    );
+}
+
+#[test]
+fn what_bd_lacks_goes_under_metadata() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task")
+        .create_issue("test-2", IssueType::Task, "Same task")
+        .add_dependency("test-2", "test-1", Relation::DuplicateOf);
+    ctx.db
+        .add_note_of_kind("test-1", Status::Todo, Some(NoteKind::Decision), "Use X")
+        .unwrap();
+    ctx.db.add_note("test-1", Status::Todo, "Plain").unwrap();
+    let mut link = Link::new("test-1".to_string());
+    link.url = Some("https://example.com/spec".to_string());
+    ctx.db.add_link(&link).unwrap();
+    let mut import = Link::new("test-1".to_string());
+    import.url = Some("https://github.com/o/r/issues/1".to_string());
+    import.rel = Some(LinkRel::Import);
+    ctx.db.add_link(&import).unwrap();
+
+    let rec = record(&ctx, "test-1");
+    assert_eq!(rec.metadata["wok_note_kinds"], json!(["decision", null]));
+    assert_eq!(
+        rec.metadata["wok_links"],
+        json!(["https://example.com/spec"])
+    );
+    assert_eq!(
+        record(&ctx, "test-2").metadata["wok_duplicate_of"],
+        json!(["test-1"])
+    );
+}
+
+#[test]
+fn export_then_import_round_trips() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-a", IssueType::Feature, "A")
+        .add_label("test-a", "p:0")
+        .add_label("test-a", "ui")
+        .add_note("test-a", "Started on it")
+        .create_issue("test-b", IssueType::Task, "B")
+        .blocks("test-a", "test-b")
+        .create_completed("test-c", IssueType::Bug, "C")
+        .create_issue("test-d", IssueType::Task, "D")
+        .close_issue("test-d");
+    ctx.db.set_assignee("test-a", "ann").unwrap();
+
+    let path = ctx.work_dir.join("issues.jsonl");
+    export::run_impl(&ctx.db, path.to_str().unwrap(), RecordFormat::Bd).unwrap();
+
+    let mut target = TestContext::new();
+    import::run_impl(
+        &mut target.db,
+        &target.config,
+        path.to_str().unwrap(),
+        &Source::Beads,
+        OnConflict::Update,
+        false,
+        vec![],
+        vec![],
+        vec![],
+        None,
+    )
+    .unwrap();
+
+    let a = target.db.get_issue("test-a").unwrap();
+    assert_eq!(a.issue_type, IssueType::Feature);
+    assert_eq!(a.assignee.as_deref(), Some("ann"));
+    assert!(target
+        .db
+        .get_labels("test-a")
+        .unwrap()
+        .contains(&"ui".to_string()));
+    let notes = target.db.get_notes("test-a").unwrap();
+    assert!(notes.iter().any(|n| n.content == "Started on it"));
+    let blockers = target.db.get_deps_to("test-b").unwrap();
+    assert!(blockers
+        .iter()
+        .any(|d| d.from_id == "test-a" && d.relation == Relation::Blocks));
+    assert_eq!(target.db.get_issue("test-c").unwrap().status, Status::Done);
+    assert_eq!(
+        target.db.get_issue("test-d").unwrap().status,
+        Status::Closed
+    );
+}
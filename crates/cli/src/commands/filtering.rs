@@ -5,6 +5,8 @@
 //!
 //! This module provides shared filtering utilities used by list, search, and ready commands.
 
+use wk_core::query::Cond;
+
 use crate::error::{Error, Result};
 
 /// A label matcher that can be positive (Has) or negative (NotHas).
@@ -33,14 +35,36 @@ impl LabelMatcher {
     }
 
     /// Check if this matcher matches the given issue labels.
+    ///
+    /// A trailing `*` matches any label with that prefix (e.g. `release:*`).
     pub fn matches(&self, issue_labels: &[String]) -> bool {
         match self {
-            LabelMatcher::Has(label) => issue_labels.contains(label),
-            LabelMatcher::NotHas(label) => !issue_labels.contains(label),
+            LabelMatcher::Has(pattern) => issue_labels.iter().any(|l| label_matches(pattern, l)),
+            LabelMatcher::NotHas(pattern) => {
+                !issue_labels.iter().any(|l| label_matches(pattern, l))
+            }
+        }
+    }
+}
+
+impl LabelMatcher {
+    /// The equivalent database condition.
+    pub fn to_cond(&self) -> Cond {
+        match self {
+            LabelMatcher::Has(pattern) => Cond::Label(pattern.clone()),
+            LabelMatcher::NotHas(pattern) => Cond::Not(Box::new(Cond::Label(pattern.clone()))),
         }
     }
 }
 
+/// Match a label against a pattern, supporting a trailing `*` wildcard.
+pub(crate) fn label_matches(pattern: &str, label: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => label.starts_with(prefix),
+        None => pattern == label,
+    }
+}
+
 /// Parse filter values: comma-separated values within each Vec entry are OR'd,
 /// multiple Vec entries are AND'd together.
 /// Returns None if no filters provided, Some(groups) otherwise.
@@ -104,6 +128,24 @@ pub fn matches_label_groups(
     }
 }
 
+/// Database condition for filter groups, with the same OR-within, AND-across
+/// semantics as [`matches_filter_groups`]. `None` when there are no groups.
+pub fn filter_groups_cond<T>(
+    groups: &Option<Vec<Vec<T>>>,
+    group_cond: impl Fn(&[T]) -> Cond,
+) -> Option<Cond> {
+    groups
+        .as_ref()
+        .map(|groups| Cond::All(groups.iter().map(|g| group_cond(g)).collect()))
+}
+
+/// Database condition for label filter groups.
+pub fn label_groups_cond(groups: &Option<Vec<Vec<LabelMatcher>>>) -> Option<Cond> {
+    filter_groups_cond(groups, |group| {
+        Cond::Any(group.iter().map(LabelMatcher::to_cond).collect())
+    })
+}
+
 /// Check if an issue ID matches the given prefix filter.
 /// The prefix is the portion of the ID before the first hyphen.
 pub fn matches_prefix(prefix: &Option<String>, issue_id: &str) -> bool {
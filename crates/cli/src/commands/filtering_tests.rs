@@ -54,6 +54,17 @@ fn test_label_matcher_matches_not_has() {
     assert!(!matcher.matches(&["bug".to_string(), "wontfix".to_string()]));
 }
 
+#[test]
+fn test_label_matcher_trailing_wildcard() {
+    let matcher = LabelMatcher::Has("release:*".to_string());
+    assert!(matcher.matches(&["release:1.2".to_string()]));
+    assert!(!matcher.matches(&["release".to_string(), "prerelease:1".to_string()]));
+
+    let matcher = LabelMatcher::NotHas("release:*".to_string());
+    assert!(matcher.matches(&["bug".to_string()]));
+    assert!(!matcher.matches(&["release:1.2".to_string()]));
+}
+
 // =============================================================================
 // matches_label_groups with LabelMatcher tests
 // =============================================================================
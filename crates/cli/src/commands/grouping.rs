@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Grouping for issue lists (`--group-by`).
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::cli::GroupBy;
+use crate::models::{Issue, Status};
+
+/// Group name for issues with no assignee.
+pub(crate) const UNASSIGNED: &str = "(unassigned)";
+
+/// Group name for issues with no `prefix:value` label.
+pub(crate) const NO_LABEL_PREFIX: &str = "(none)";
+
+/// Lifecycle order for status groups; other fields group alphabetically.
+const STATUS_ORDER: [Status; 4] = [
+    Status::Todo,
+    Status::InProgress,
+    Status::Done,
+    Status::Closed,
+];
+
+/// Split issues into named groups, keeping their order within each group.
+///
+/// Status groups follow the lifecycle, the rest sort by name with the
+/// catch-all group last. Under `label-prefix` an issue appears once per
+/// distinct prefix it carries.
+pub(crate) fn group_issues<'a>(
+    issues: &'a [Issue],
+    by: GroupBy,
+    labels: &HashMap<String, Vec<String>>,
+) -> Vec<(String, Vec<&'a Issue>)> {
+    if by == GroupBy::Status {
+        return STATUS_ORDER
+            .iter()
+            .map(|status| {
+                let members: Vec<&Issue> = issues.iter().filter(|i| i.status == *status).collect();
+                (status.to_string(), members)
+            })
+            .filter(|(_, members)| !members.is_empty())
+            .collect();
+    }
+
+    let mut groups: BTreeMap<String, Vec<&Issue>> = BTreeMap::new();
+    let mut catch_all: Vec<&Issue> = Vec::new();
+    for issue in issues {
+        let keys = group_keys(issue, by, labels);
+        if keys.is_empty() {
+            catch_all.push(issue);
+        }
+        for key in keys {
+            groups.entry(key).or_default().push(issue);
+        }
+    }
+
+    let mut grouped: Vec<(String, Vec<&Issue>)> = groups.into_iter().collect();
+    if !catch_all.is_empty() {
+        let name = match by {
+            GroupBy::Assignee => UNASSIGNED,
+            _ => NO_LABEL_PREFIX,
+        };
+        grouped.push((name.to_string(), catch_all));
+    }
+    grouped
+}
+
+/// The groups one issue belongs to; empty means the catch-all group.
+fn group_keys(issue: &Issue, by: GroupBy, labels: &HashMap<String, Vec<String>>) -> Vec<String> {
+    match by {
+        GroupBy::Status => vec![issue.status.to_string()],
+        GroupBy::Type => vec![issue.issue_type.to_string()],
+        GroupBy::Assignee => issue.assignee.iter().cloned().collect(),
+        GroupBy::Prefix => issue
+            .id
+            .split_once('-')
+            .map(|(prefix, _)| prefix.to_string())
+            .into_iter()
+            .collect(),
+        GroupBy::LabelPrefix => {
+            let mut prefixes: Vec<String> = labels
+                .get(&issue.id)
+                .into_iter()
+                .flatten()
+                .filter_map(|label| label.split_once(':'))
+                .map(|(prefix, _)| prefix.to_string())
+                .collect();
+            prefixes.sort();
+            prefixes.dedup();
+            prefixes
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "grouping_tests.rs"]
+mod tests;
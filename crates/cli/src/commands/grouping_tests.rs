@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use yare::parameterized;
+
+/// Groups as (name, ids) for easy comparison.
+fn grouped(ctx: &TestContext, by: GroupBy) -> Vec<(String, Vec<String>)> {
+    let mut issues = ctx.db.list_issues(None, None, None).unwrap();
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+    let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    let labels = ctx.db.get_labels_batch(&ids).unwrap();
+    group_issues(&issues, by, &labels)
+        .into_iter()
+        .map(|(name, members)| (name, members.iter().map(|i| i.id.clone()).collect()))
+        .collect()
+}
+
+fn context() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One")
+        .create_issue("test-2", IssueType::Bug, "Two")
+        .create_issue("other-3", IssueType::Task, "Three")
+        .start_issue("test-2")
+        .add_label("test-1", "mod:cli")
+        .add_label("test-1", "priority:1")
+        .add_label("test-2", "mod:core")
+        .add_label("other-3", "urgent");
+    ctx.db.set_assignee("test-2", "bob").unwrap();
+    ctx.db.set_assignee("other-3", "alice").unwrap();
+    ctx
+}
+
+fn group(name: &str, ids: &[&str]) -> (String, Vec<String>) {
+    (
+        name.to_string(),
+        ids.iter().map(|id| id.to_string()).collect(),
+    )
+}
+
+#[parameterized(
+    status = { GroupBy::Status, vec![group("todo", &["other-3", "test-1"]), group("in_progress", &["test-2"])] },
+    issue_type = { GroupBy::Type, vec![group("bug", &["test-2"]), group("task", &["other-3", "test-1"])] },
+    assignee = { GroupBy::Assignee, vec![group("alice", &["other-3"]), group("bob", &["test-2"]), group(UNASSIGNED, &["test-1"])] },
+    prefix = { GroupBy::Prefix, vec![group("other", &["other-3"]), group("test", &["test-1", "test-2"])] },
+)]
+fn groups_by_field(by: GroupBy, expected: Vec<(String, Vec<String>)>) {
+    let ctx = context();
+    assert_eq!(grouped(&ctx, by), expected);
+}
+
+#[test]
+fn label_prefix_puts_issue_in_each_prefix_group() {
+    let ctx = context();
+
+    assert_eq!(
+        grouped(&ctx, GroupBy::LabelPrefix),
+        vec![
+            group("mod", &["test-1", "test-2"]),
+            group("priority", &["test-1"]),
+            group(NO_LABEL_PREFIX, &["other-3"]),
+        ]
+    );
+}
+
+#[test]
+fn empty_input_has_no_groups() {
+    let labels = HashMap::new();
+    assert!(group_issues(&[], GroupBy::Status, &labels).is_empty());
+    assert!(group_issues(&[], GroupBy::Assignee, &labels).is_empty());
+}
@@ -3,13 +3,25 @@
 
 //! Issue hooks management commands.
 //!
-//! Commands for listing configured hooks and testing them.
+//! Commands for listing configured hooks, testing them, and firing
+//! `issue.stale` hooks.
 
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+
+use crate::aging::mark_stale;
 use crate::cli::OutputFormat;
-use crate::error::Result;
-use crate::hooks::{load_hooks_config, test_hook, HookEvent};
+use crate::config::Config;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::hooks::executor::execute_hook;
+use crate::hooks::runner::record_run;
+use crate::hooks::{load_hooks_config, test_hook, HookEvent, HookPayload};
+use crate::models::{Action, Event, HookRun, Issue, Status};
+use wk_core::query::{Cond, IssueQuery};
 
-use super::open_db;
+use super::{notify_mutation, open_db};
 
 /// Run the hook list command.
 pub fn list(output: OutputFormat) -> Result<()> {
@@ -44,7 +56,12 @@ pub fn list(output: OutputFormat) -> Result<()> {
                     if let Some(filter) = &hook.filter {
                         println!("    Filter: {}", filter);
                     }
-                    println!("    Run: {}", hook.run);
+                    if let Some(run) = &hook.run {
+                        println!("    Run: {}", run);
+                    }
+                    if let Some(url) = &hook.url {
+                        println!("    URL: {}", url);
+                    }
                 }
             }
         }
@@ -54,7 +71,9 @@ pub fn list(output: OutputFormat) -> Result<()> {
 }
 
 /// Run the hook test command.
-pub fn test(name: String, id: String, event: Option<String>) -> Result<()> {
+///
+/// With `run`, the hook is fired synchronously and the outcome recorded.
+pub fn test(name: String, id: String, event: Option<String>, run: bool) -> Result<()> {
     let (db, _, work_dir) = open_db()?;
 
     // Resolve the issue ID
@@ -66,6 +85,11 @@ pub fn test(name: String, id: String, event: Option<String>) -> Result<()> {
         None => HookEvent::Created,
     };
 
+    if run {
+        let hook_run = fire_impl(&db, &work_dir, &name, &resolved_id, test_event)?;
+        return report_fired(&hook_run);
+    }
+
     match test_hook(&db, &work_dir, &name, &resolved_id, test_event)? {
         None => {
             println!(
@@ -94,8 +118,134 @@ pub fn test(name: String, id: String, event: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Fire a hook once with a synthetic event, ignoring its event and filter.
+pub(crate) fn fire_impl(
+    db: &Database,
+    work_dir: &Path,
+    name: &str,
+    issue_id: &str,
+    test_event: HookEvent,
+) -> Result<HookRun> {
+    let hook = load_hooks_config(work_dir)?
+        .and_then(|config| config.hooks.into_iter().find(|h| h.name == name))
+        .ok_or_else(|| Error::Config(format!("hook not found: {}", name)))?;
+
+    let issue = db.get_issue(issue_id)?;
+    let labels = db.get_labels(issue_id)?;
+    let event = Event::new(issue_id.to_string(), Action::from(test_event));
+    let payload = HookPayload::from_event(&event, &issue, labels);
+
+    let run = execute_hook(&hook, &payload, work_dir);
+    record_run(db, &run);
+    Ok(run)
+}
+
+fn report_fired(run: &HookRun) -> Result<()> {
+    println!(
+        "Fired hook '{}' for issue '{}' on event '{}' ({}ms).",
+        run.hook_name, run.issue_id, run.event, run.duration_ms
+    );
+    if let Some(tail) = &run.stderr_tail {
+        println!("stderr:");
+        for line in tail.lines() {
+            println!("  {}", line);
+        }
+    }
+    match run.failure_reason() {
+        None => Ok(()),
+        Some(reason) => Err(Error::HookFailed {
+            name: run.hook_name.clone(),
+            reason,
+        }),
+    }
+}
+
+/// Run the hook runs command.
+pub fn runs(failed: bool, limit: usize, output: OutputFormat) -> Result<()> {
+    let (db, _, _) = open_db()?;
+    runs_impl(&db, failed, limit, output)
+}
+
+/// Show recent hook executions, newest first.
+pub(crate) fn runs_impl(
+    db: &Database,
+    failed: bool,
+    limit: usize,
+    output: OutputFormat,
+) -> Result<()> {
+    let runs = db.get_hook_runs(limit, failed)?;
+
+    if matches!(output, OutputFormat::Json) {
+        let json = serde_json::to_string_pretty(&runs).map_err(|e| Error::Config(e.to_string()))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if runs.is_empty() {
+        if failed {
+            println!("No failed hook runs.");
+        } else {
+            println!("No hook runs recorded.");
+        }
+        return Ok(());
+    }
+
+    for run in &runs {
+        let outcome = run.failure_reason().unwrap_or_else(|| "ok".to_string());
+        println!(
+            "{}  {}  {}  {}  {}  {}ms",
+            run.created_at.format("%Y-%m-%d %H:%M:%S"),
+            run.hook_name,
+            run.event,
+            run.issue_id,
+            outcome,
+            run.duration_ms
+        );
+        if let Some(tail) = &run.stderr_tail {
+            for line in tail.lines() {
+                println!("    {}", line);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the hook stale command: mark issues that went stale and fire their
+/// `issue.stale` hooks. The daemon runs this periodically for projects
+/// with such hooks.
+pub fn stale() -> Result<()> {
+    let (db, config, _) = open_db()?;
+    let marked = stale_impl(&db, &config, Utc::now())?;
+    for event in &marked {
+        notify_mutation(&db, event);
+        println!(
+            "{} is stale: {} for {}",
+            event.issue_id,
+            event.old_value.as_deref().unwrap_or_default(),
+            event.new_value.as_deref().unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+/// Log a `stale` event for each of the project's open issues that went
+/// stale by `now`.
+pub(crate) fn stale_impl(db: &Database, config: &Config, now: DateTime<Utc>) -> Result<Vec<Event>> {
+    if !config.aging.is_set() {
+        return Ok(Vec::new());
+    }
+    let mut conds = vec![Cond::Status(vec![Status::Todo, Status::InProgress])];
+    if !config.prefix.is_empty() {
+        conds.push(Cond::IdPrefix(config.prefix.clone()));
+    }
+    let issues = db.query_issues(&IssueQuery::new(Cond::All(conds)))?;
+    let open: Vec<&Issue> = issues.iter().collect();
+    mark_stale(db, &config.aging, &open, now)
+}
+
 /// Parse an event name into a HookEvent.
-fn parse_event(event: &str) -> Result<HookEvent> {
+pub(crate) fn parse_event(event: &str) -> Result<HookEvent> {
     match event {
         "issue.created" | "created" => Ok(HookEvent::Created),
         "issue.edited" | "edited" => Ok(HookEvent::Edited),
@@ -109,11 +259,17 @@ fn parse_event(event: &str) -> Result<HookEvent> {
         "issue.assigned" | "assigned" => Ok(HookEvent::Assigned),
         "issue.unassigned" | "unassigned" => Ok(HookEvent::Unassigned),
         "issue.noted" | "noted" => Ok(HookEvent::Noted),
+        "issue.unnoted" | "unnoted" => Ok(HookEvent::Unnoted),
         "issue.linked" | "linked" => Ok(HookEvent::Linked),
         "issue.unlinked" | "unlinked" => Ok(HookEvent::Unlinked),
         "issue.related" | "related" => Ok(HookEvent::Related),
         "issue.unrelated" | "unrelated" => Ok(HookEvent::Unrelated),
         "issue.unblocked" | "unblocked" => Ok(HookEvent::Unblocked),
+        "issue.associated" | "associated" => Ok(HookEvent::Associated),
+        "issue.dissociated" | "dissociated" => Ok(HookEvent::Dissociated),
+        "issue.duplicated" | "duplicated" => Ok(HookEvent::Duplicated),
+        "issue.unduplicated" | "unduplicated" => Ok(HookEvent::Unduplicated),
+        "issue.stale" | "stale" => Ok(HookEvent::Stale),
         _ => Err(crate::error::Error::Config(format!(
             "unknown event: {}",
             event
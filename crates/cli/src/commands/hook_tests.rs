@@ -1,4 +1,72 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-// Hook command tests are covered by BATS specs in tests/specs/cli/unit/hook.bats
+// Most hook command behavior is covered by BATS specs in tests/specs/cli/unit/hook.bats
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+fn write_hooks(ctx: &TestContext, toml: &str) {
+    std::fs::write(ctx.work_dir.join("hooks.toml"), toml).unwrap();
+}
+
+#[test]
+fn fire_ignores_filter_and_records_run() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+    write_hooks(
+        &ctx,
+        r#"
+[[hooks]]
+name = "fails"
+events = ["issue.done"]
+filter = "-t bug"
+run = "exit 3"
+"#,
+    );
+
+    let run = fire_impl(
+        &ctx.db,
+        &ctx.work_dir,
+        "fails",
+        "test-1",
+        HookEvent::Created,
+    )
+    .unwrap();
+
+    assert_eq!(run.exit_code, Some(3));
+    assert_eq!(run.event, "issue.created");
+    let recorded = ctx.db.get_hook_runs(10, true).unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].hook_name, "fails");
+}
+
+#[test]
+fn fire_unknown_hook_is_an_error() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Task");
+
+    let err = fire_impl(&ctx.db, &ctx.work_dir, "nope", "test-1", HookEvent::Created);
+
+    assert!(matches!(err, Err(Error::Config(_))));
+    assert!(ctx.db.get_hook_runs(10, false).unwrap().is_empty());
+}
+
+#[test]
+fn stale_marks_only_the_projects_open_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_and_start("test-1", IssueType::Task, "Stuck")
+        .create_and_start("other-1", IssueType::Task, "Elsewhere")
+        .create_completed("test-2", IssueType::Task, "Finished");
+    ctx.config.aging.in_progress = Some("7d".to_string());
+    let later = chrono::Utc::now() + chrono::Duration::days(8);
+
+    let marked = stale_impl(&ctx.db, &ctx.config, later).unwrap();
+
+    let ids: Vec<&str> = marked.iter().map(|e| e.issue_id.as_str()).collect();
+    assert_eq!(ids, ["test-1"]);
+    assert!(stale_impl(&ctx.db, &ctx.config, later).unwrap().is_empty());
+}
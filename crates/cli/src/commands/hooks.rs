@@ -4,9 +4,17 @@
 //! Claude Code hooks management commands.
 //!
 //! Commands for installing, uninstalling, and checking status of
-//! Claude Code hooks that integrate wk with AI assistants.
+//! Claude Code hooks that integrate wk with AI assistants, plus the
+//! handlers those hooks run (`wk hooks context`, `wk hooks note-failure`).
+//!
+//! `wk hooks install git` installs git hooks instead: `commit-msg` adds the
+//! current issue's ID to commit messages, and `post-commit` notes each
+//! commit on its issue. The current issue is the one `wok branch` linked
+//! the checked-out branch to, or else the only in-progress issue.
 
-use std::io::{self, IsTerminal, Write};
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 
 use crossterm::{
     cursor, event,
@@ -14,17 +22,46 @@ use crossterm::{
     terminal, ExecutableCommand, QueueableCommand,
 };
 
+use crate::cli::{Order, OutputFormat, SortArgs};
+use crate::db::Database;
+use crate::display::format_issue_line;
 use crate::error::{Error, Result};
+use crate::git;
+use crate::models::{Action, Event, Issue, Status};
+use crate::validate::validate_and_trim_note;
+use wk_core::hook_job::stderr_tail;
 use wk_core::hooks::{
-    check_all_hooks, install_hooks, should_use_interactive, uninstall_hooks, HookScope,
+    check_all_hooks, install_hooks_with, should_use_interactive, uninstall_hooks, HookFeature,
+    HookScope,
 };
 
+use super::branch::branch_issue;
+use super::filtering::matches_prefix;
+use super::{apply_mutation, open_db, ready};
+
 #[cfg(test)]
 #[path = "hooks_tests.rs"]
 mod tests;
 
 /// Run the hooks install command.
-pub fn install(scope: Option<String>, force_interactive: bool, force_yes: bool) -> Result<()> {
+pub fn install(
+    scope: Option<String>,
+    force_interactive: bool,
+    force_yes: bool,
+    context: bool,
+    auto_note: bool,
+) -> Result<()> {
+    if scope.as_deref() == Some(GIT_SCOPE) {
+        return install_git();
+    }
+    let mut features = Vec::new();
+    if context {
+        features.push(HookFeature::Context);
+    }
+    if auto_note {
+        features.push(HookFeature::FailureNotes);
+    }
+
     // Determine the scope
     let scope = match scope {
         Some(s) => HookScope::parse(&s).ok_or_else(|| Error::InvalidScope {
@@ -51,7 +88,7 @@ pub fn install(scope: Option<String>, force_interactive: bool, force_yes: bool)
     };
 
     // Install hooks
-    let path = install_hooks(scope).map_err(|e| {
+    let path = install_hooks_with(scope, &features).map_err(|e| {
         if e.kind() == io::ErrorKind::PermissionDenied {
             Error::PermissionDenied {
                 target: scope.display_name().to_string(),
@@ -71,6 +108,9 @@ pub fn install(scope: Option<String>, force_interactive: bool, force_yes: bool)
 
 /// Run the hooks uninstall command.
 pub fn uninstall(scope: Option<String>) -> Result<()> {
+    if scope.as_deref() == Some(GIT_SCOPE) {
+        return uninstall_git();
+    }
     let scope = match scope {
         Some(s) => HookScope::parse(&s).ok_or_else(|| Error::InvalidScope {
             scope: s.to_string(),
@@ -114,9 +154,366 @@ pub fn status() -> Result<()> {
         }
     }
 
+    let installed_git = git::hooks_dir().ok().filter(|dir| {
+        GIT_HOOKS
+            .iter()
+            .all(|(name, _)| is_wok_hook(&dir.join(name)))
+    });
+    if let Some(dir) = installed_git {
+        println!();
+        println!("Git hooks installed ({})", dir.display());
+    }
+
     Ok(())
 }
 
+/// Run the hooks context command.
+///
+/// Prints nothing outside a wok project, so the hook is harmless there.
+pub fn context() -> Result<()> {
+    let Ok((db, config, work_dir)) = open_db() else {
+        return Ok(());
+    };
+    let prefix = (!config.prefix.is_empty()).then_some(config.prefix);
+    context_impl(&db, &work_dir, prefix)
+}
+
+/// Print the in-progress issues, then the ready queue.
+pub(crate) fn context_impl(db: &Database, work_dir: &Path, prefix: Option<String>) -> Result<()> {
+    println!("## wok: current work");
+    println!();
+    println!("In progress:");
+    let in_progress = in_progress_issues(db, &prefix)?;
+    if in_progress.is_empty() {
+        println!("(none)");
+    }
+    for issue in &in_progress {
+        println!("{}", format_issue_line(issue));
+    }
+    println!();
+    println!("Ready:");
+    ready::run_impl(
+        db,
+        work_dir,
+        Vec::new(),
+        Vec::new(),
+        prefix,
+        Vec::new(),
+        false,
+        false,
+        OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
+    )
+}
+
+/// Run the hooks note-failure command.
+///
+/// Reads a Claude Code PostToolUse payload from stdin. Never fails: the
+/// hook must not interrupt the session, so problems are only warned about.
+pub fn note_failure() -> Result<()> {
+    let mut input = String::new();
+    if io::stdin().read_to_string(&mut input).is_err() {
+        return Ok(());
+    }
+    let Ok((db, config, _)) = open_db() else {
+        return Ok(());
+    };
+    let prefix = (!config.prefix.is_empty()).then_some(config.prefix);
+    if let Err(e) = note_failure_impl(&db, prefix, &input) {
+        eprintln!("warning: could not note shell failure: {}", e);
+    }
+    Ok(())
+}
+
+/// Note a failed shell command on the in-progress issue.
+///
+/// Does nothing unless exactly one issue is in progress, since otherwise the
+/// failure can't be attributed. Returns the ID of the issue noted.
+pub(crate) fn note_failure_impl(
+    db: &Database,
+    prefix: Option<String>,
+    input: &str,
+) -> Result<Option<String>> {
+    let Some(note) = failure_note(input) else {
+        return Ok(None);
+    };
+    let in_progress = in_progress_issues(db, &prefix)?;
+    let [issue] = in_progress.as_slice() else {
+        return Ok(None);
+    };
+
+    let content = validate_and_trim_note(&note)?;
+    apply_mutation(
+        db,
+        Event::new(issue.id.clone(), Action::Noted).with_values(None, Some(content.clone())),
+        || db.add_note(&issue.id, issue.status, &content),
+    )?;
+    Ok(Some(issue.id.clone()))
+}
+
+/// Build a note from a PostToolUse payload if it describes a failed shell command.
+pub(crate) fn failure_note(input: &str) -> Option<String> {
+    let payload: serde_json::Value = serde_json::from_str(input).ok()?;
+    if payload.get("tool_name")?.as_str()? != "Bash" {
+        return None;
+    }
+    let response = payload.get("tool_response")?;
+    let flag = |key: &str| response.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+
+    // Field names differ between Claude Code versions
+    let exit_code = ["exit_code", "exitCode", "returnCode"]
+        .iter()
+        .find_map(|key| response.get(*key).and_then(|v| v.as_i64()))
+        .filter(|code| *code != 0);
+    let reason = match exit_code {
+        Some(code) => format!("exit {}", code),
+        None if flag("interrupted") => "interrupted".to_string(),
+        None if flag("is_error") => "error".to_string(),
+        None => return None,
+    };
+
+    let command = payload
+        .pointer("/tool_input/command")
+        .and_then(|c| c.as_str())
+        .unwrap_or("")
+        .trim();
+    let mut note = format!("Shell command failed ({}): `{}`", reason, command);
+    let stderr = response
+        .get("stderr")
+        .and_then(|s| s.as_str())
+        .map(|s| stderr_tail(s.as_bytes()))
+        .unwrap_or_default();
+    if !stderr.is_empty() {
+        note.push_str("\n\n```\n");
+        note.push_str(&stderr);
+        note.push_str("\n```");
+    }
+    Some(note)
+}
+
+/// `wk hooks install <scope>` value that installs the git hooks.
+const GIT_SCOPE: &str = "git";
+
+/// Marks hooks wk installed, so they can be replaced and removed.
+const GIT_HOOK_MARKER: &str = "# Installed by 'wok hooks install git'";
+
+/// Git hooks by file name, with the wk command each runs.
+const GIT_HOOKS: [(&str, &str); 2] = [
+    ("commit-msg", "hooks commit-msg \"$1\""),
+    ("post-commit", "hooks post-commit"),
+];
+
+/// The script for a git hook running `command`. It prefers the wk that
+/// installed it, falls back to `wok` on PATH, and never fails the commit.
+fn git_hook_script(command: &str) -> String {
+    let exe = std::env::current_exe()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| "wok".to_string());
+    format!(
+        "#!/bin/sh\n{marker}\nwok='{exe}'\n[ -x \"$wok\" ] || wok=wok\n\
+         command -v \"$wok\" >/dev/null 2>&1 || exit 0\n\"$wok\" {command} || true\n",
+        marker = GIT_HOOK_MARKER,
+        exe = exe,
+        command = command,
+    )
+}
+
+fn is_wok_hook(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|script| script.contains(GIT_HOOK_MARKER))
+}
+
+/// Install the git hooks into the current repository.
+fn install_git() -> Result<()> {
+    let dir = git::hooks_dir()?;
+    let hooks: Vec<(PathBuf, &str)> = GIT_HOOKS
+        .iter()
+        .map(|(name, command)| (dir.join(name), *command))
+        .collect();
+    // Check every hook first so none is installed if one is in the way.
+    for (path, command) in &hooks {
+        if path.exists() && !is_wok_hook(path) {
+            return Err(Error::GitHookExists {
+                path: path.display().to_string(),
+                command: format!("wok {}", command),
+            });
+        }
+    }
+    fs::create_dir_all(&dir)?;
+    for (path, command) in &hooks {
+        fs::write(path, git_hook_script(command))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+        }
+    }
+    println!("Installed git hooks to {}", dir.display());
+    Ok(())
+}
+
+/// Remove the git hooks wk installed, leaving any others alone.
+fn uninstall_git() -> Result<()> {
+    let dir = git::hooks_dir()?;
+    for (name, _) in GIT_HOOKS {
+        let path = dir.join(name);
+        if is_wok_hook(&path) {
+            fs::remove_file(&path)?;
+        }
+    }
+    println!("Uninstalled git hooks from {}", dir.display());
+    Ok(())
+}
+
+/// Run the hooks commit-msg command (git `commit-msg` hook).
+///
+/// Adds a `Refs: <id>` trailer for the current issue to the message in
+/// `file`. Never fails, so a commit is never blocked by wk.
+pub fn commit_msg(file: &str) -> Result<()> {
+    let file = Path::new(file);
+    let Ok((db, config, _)) = open_db() else {
+        return Ok(());
+    };
+    let prefix = (!config.prefix.is_empty()).then_some(config.prefix);
+    let added = fs::read_to_string(file)
+        .map_err(Error::from)
+        .and_then(|message| {
+            commit_msg_impl(&db, prefix, git::current_branch().as_deref(), &message)
+        })
+        .and_then(|id| match id {
+            Some(id) => add_trailer(file, &id),
+            None => Ok(()),
+        });
+    if let Err(e) = added {
+        eprintln!(
+            "warning: could not add the issue to the commit message: {}",
+            e
+        );
+    }
+    Ok(())
+}
+
+/// The issue ID to add to commit `message` made on `branch`, if any: none
+/// when there is no current issue, the message is empty (the commit will be
+/// aborted) or already mentions it.
+pub(crate) fn commit_msg_impl(
+    db: &Database,
+    prefix: Option<String>,
+    branch: Option<&str>,
+    message: &str,
+) -> Result<Option<String>> {
+    let text: Vec<&str> = message
+        .lines()
+        .take_while(|line| !line.starts_with("# ------------------------ >8"))
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+    if text.iter().all(|line| line.trim().is_empty()) {
+        return Ok(None);
+    }
+    let Some(issue) = current_issue(db, &prefix, branch)? else {
+        return Ok(None);
+    };
+    if text.iter().any(|line| line.contains(&issue.id)) {
+        return Ok(None);
+    }
+    Ok(Some(issue.id))
+}
+
+fn add_trailer(file: &Path, id: &str) -> Result<()> {
+    let file = file.display().to_string();
+    let trailer = format!("Refs: {}", id);
+    git::git(&[
+        "interpret-trailers",
+        "--in-place",
+        "--trailer",
+        &trailer,
+        &file,
+    ])?;
+    Ok(())
+}
+
+/// Run the hooks post-commit command (git `post-commit` hook).
+///
+/// Notes the new commit on its issue. Never fails.
+pub fn post_commit() -> Result<()> {
+    let Ok((db, config, _)) = open_db() else {
+        return Ok(());
+    };
+    let prefix = (!config.prefix.is_empty()).then_some(config.prefix);
+    let noted = git::head_commit().and_then(|(sha, message)| {
+        post_commit_impl(
+            &db,
+            prefix,
+            git::current_branch().as_deref(),
+            &sha,
+            &message,
+        )
+    });
+    if let Err(e) = noted {
+        eprintln!("warning: could not note the commit: {}", e);
+    }
+    Ok(())
+}
+
+/// Note commit `sha` on the open issue its `message` mentions, or else the
+/// current issue. Returns the ID of the issue noted.
+pub(crate) fn post_commit_impl(
+    db: &Database,
+    prefix: Option<String>,
+    branch: Option<&str>,
+    sha: &str,
+    message: &str,
+) -> Result<Option<String>> {
+    let mentioned = message
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .filter(|word| word.contains('-') && matches_prefix(&prefix, word))
+        .find_map(|word| db.get_issue(word).ok());
+    let issue = match mentioned {
+        Some(issue) => Some(issue),
+        None => current_issue(db, &prefix, branch)?,
+    };
+    let Some(issue) = issue.filter(|issue| issue.status.is_active()) else {
+        return Ok(None);
+    };
+
+    let subject = message.lines().next().unwrap_or_default().trim();
+    let short_sha = sha.get(..7).unwrap_or(sha);
+    let content = validate_and_trim_note(&format!("Commit {}: {}", short_sha, subject))?;
+    apply_mutation(
+        db,
+        Event::new(issue.id.clone(), Action::Noted).with_values(None, Some(content.clone())),
+        || db.add_note(&issue.id, issue.status, &content),
+    )?;
+    Ok(Some(issue.id))
+}
+
+/// The issue being worked on: the one `branch` was made for by `wk branch`,
+/// or else the only in-progress issue.
+fn current_issue(
+    db: &Database,
+    prefix: &Option<String>,
+    branch: Option<&str>,
+) -> Result<Option<Issue>> {
+    if let Some(branch) = branch {
+        if let Some(id) = branch_issue(db, branch)? {
+            return Ok(Some(db.get_issue(&id)?));
+        }
+    }
+    let mut in_progress = in_progress_issues(db, prefix)?;
+    Ok(match in_progress.len() {
+        1 => in_progress.pop(),
+        _ => None,
+    })
+}
+
+fn in_progress_issues(db: &Database, prefix: &Option<String>) -> Result<Vec<Issue>> {
+    let mut issues = db.list_issues(Some(Status::InProgress), None, None)?;
+    issues.retain(|issue| matches_prefix(prefix, &issue.id));
+    Ok(issues)
+}
+
 /// Scope picker items with their descriptions.
 const SCOPE_ITEMS: [(&str, &str); 3] = [
     ("local", "Per-project, git-ignored"),
@@ -20,3 +20,183 @@ fn scope_case_insensitive() {
     assert_eq!(HookScope::parse("Project"), Some(HookScope::Project));
     assert_eq!(HookScope::parse("USER"), Some(HookScope::User));
 }
+
+mod failure_notes {
+    use super::super::*;
+    use crate::commands::testing::TestContext;
+    use crate::models::{IssueType, Status};
+
+    fn bash_payload(response: serde_json::Value) -> String {
+        serde_json::json!({
+            "hook_event_name": "PostToolUse",
+            "tool_name": "Bash",
+            "tool_input": {"command": "cargo test"},
+            "tool_response": response,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn note_includes_exit_code_command_and_stderr() {
+        let input = bash_payload(serde_json::json!({
+            "exit_code": 101,
+            "stdout": "",
+            "stderr": "error: test failed\n",
+        }));
+
+        let note = failure_note(&input).unwrap();
+
+        assert!(note.starts_with("Shell command failed (exit 101): `cargo test`"));
+        assert!(note.contains("error: test failed"));
+    }
+
+    #[test]
+    fn interrupted_command_is_a_failure() {
+        let input = bash_payload(serde_json::json!({"interrupted": true, "stderr": ""}));
+        assert_eq!(
+            failure_note(&input).as_deref(),
+            Some("Shell command failed (interrupted): `cargo test`")
+        );
+    }
+
+    #[test]
+    fn successful_or_other_tools_are_ignored() {
+        let ok = bash_payload(serde_json::json!({"exit_code": 0, "stdout": "ok"}));
+        assert!(failure_note(&ok).is_none());
+
+        let edit = serde_json::json!({
+            "tool_name": "Edit",
+            "tool_response": {"exit_code": 1},
+        })
+        .to_string();
+        assert!(failure_note(&edit).is_none());
+
+        assert!(failure_note("not json").is_none());
+    }
+
+    #[test]
+    fn notes_the_single_in_progress_issue() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "Work", Status::InProgress);
+        ctx.create_issue("test-2", IssueType::Task, "Later");
+        let input = bash_payload(serde_json::json!({"exit_code": 2}));
+
+        let noted = note_failure_impl(&ctx.db, None, &input).unwrap();
+
+        assert_eq!(noted.as_deref(), Some("test-1"));
+        let notes = ctx.db.get_notes("test-1").unwrap();
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].content.contains("exit 2"));
+    }
+
+    #[test]
+    fn skips_when_in_progress_issue_is_ambiguous() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "A", Status::InProgress);
+        ctx.create_issue_with_status("test-2", IssueType::Task, "B", Status::InProgress);
+        let input = bash_payload(serde_json::json!({"exit_code": 2}));
+
+        assert_eq!(note_failure_impl(&ctx.db, None, &input).unwrap(), None);
+        assert!(ctx.db.get_notes("test-1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn prefix_limits_candidates() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "A", Status::InProgress);
+        ctx.create_issue_with_status("other-1", IssueType::Task, "B", Status::InProgress);
+        let input = bash_payload(serde_json::json!({"exit_code": 2}));
+
+        let noted = note_failure_impl(&ctx.db, Some("test".to_string()), &input).unwrap();
+
+        assert_eq!(noted.as_deref(), Some("test-1"));
+    }
+}
+
+mod git_hooks {
+    use super::super::*;
+    use crate::commands::branch::link_branch;
+    use crate::commands::testing::TestContext;
+    use crate::models::{IssueType, Status};
+
+    const MESSAGE: &str = "Fix login\n\n# Please enter the commit message.\n";
+
+    #[test]
+    fn commit_msg_uses_the_branch_issue() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue("test-1", IssueType::Task, "Linked");
+        ctx.create_issue_with_status("test-2", IssueType::Task, "Started", Status::InProgress);
+        link_branch(&ctx.db, "test-1", "task/test-1-linked").unwrap();
+
+        let id = commit_msg_impl(&ctx.db, None, Some("task/test-1-linked"), MESSAGE).unwrap();
+        assert_eq!(id.as_deref(), Some("test-1"));
+    }
+
+    #[test]
+    fn commit_msg_falls_back_to_the_in_progress_issue() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "Started", Status::InProgress);
+
+        let id = commit_msg_impl(&ctx.db, None, Some("main"), MESSAGE).unwrap();
+        assert_eq!(id.as_deref(), Some("test-1"));
+        assert_eq!(
+            commit_msg_impl(&ctx.db, None, None, MESSAGE)
+                .unwrap()
+                .as_deref(),
+            Some("test-1")
+        );
+    }
+
+    #[test]
+    fn commit_msg_skips_empty_or_tagged_messages() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "Started", Status::InProgress);
+
+        let empty = "\n# Please enter the commit message.\n";
+        assert_eq!(commit_msg_impl(&ctx.db, None, None, empty).unwrap(), None);
+        let tagged = "Fix login (test-1)\n";
+        assert_eq!(commit_msg_impl(&ctx.db, None, None, tagged).unwrap(), None);
+        // The ID only in the diff below the scissors line still gets added.
+        let verbose = "Fix login\n# ------------------------ >8 ------------------------\ntest-1\n";
+        assert_eq!(
+            commit_msg_impl(&ctx.db, None, None, verbose)
+                .unwrap()
+                .as_deref(),
+            Some("test-1")
+        );
+    }
+
+    #[test]
+    fn post_commit_notes_the_mentioned_issue() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue("test-1", IssueType::Task, "Mentioned");
+        ctx.create_issue_with_status("test-2", IssueType::Task, "Started", Status::InProgress);
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+
+        let noted =
+            post_commit_impl(&ctx.db, None, None, sha, "Fix login\n\nRefs: test-1\n").unwrap();
+
+        assert_eq!(noted.as_deref(), Some("test-1"));
+        let notes = ctx.db.get_notes("test-1").unwrap();
+        assert_eq!(notes[0].content, "Commit 0123456: Fix login");
+        assert!(ctx.db.get_notes("test-2").unwrap().is_empty());
+    }
+
+    #[test]
+    fn post_commit_falls_back_to_the_current_issue() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "Started", Status::InProgress);
+
+        let noted = post_commit_impl(&ctx.db, None, None, "abc1234", "Tidy up").unwrap();
+        assert_eq!(noted.as_deref(), Some("test-1"));
+    }
+
+    #[test]
+    fn post_commit_skips_closed_issues() {
+        let mut ctx = TestContext::new();
+        ctx.create_issue_with_status("test-1", IssueType::Task, "Done", Status::Done);
+
+        let noted = post_commit_impl(&ctx.db, None, None, "abc1234", "Follow up test-1").unwrap();
+        assert_eq!(noted, None);
+    }
+}
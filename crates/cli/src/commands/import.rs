@@ -1,31 +1,82 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use std::collections::HashSet;
-use std::io::{self, BufRead, BufReader};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
 
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
+use crate::cli::OnConflict;
 use crate::config::Config;
 use crate::db::Database;
 use crate::error::{Error, Result};
-use crate::models::{Action, Event, Issue, IssueType, Link, LinkRel, LinkType, Relation, Status};
+use crate::id::generate_unique_id;
+use crate::models::{
+    parse_link_url, Action, Event, Issue, IssueType, Link, LinkRel, LinkType, NoteKind, Relation,
+    Status,
+};
+use crate::schema::export::EXPORT_SCHEMA_VERSION;
 
 use super::filtering::{
     matches_filter_groups, matches_label_groups, parse_filter_groups, LabelMatcher,
 };
+use super::import_csv::{read_rows, CsvColumns, CsvRow};
+use super::import_forge::Forge;
+use super::import_map::{MappedIssue, Mapping};
 use super::open_db;
 
-// Type alias for imported issue data
-// (issue, labels, notes, deps, close_data, links)
-type ImportedIssue = (
-    Issue,
-    Vec<String>,
-    Vec<(Status, String)>,
-    Vec<(String, String, Relation)>,
-    Option<CloseData>,
-    Vec<ImportedLink>,
-);
+// An issue read from an import file, with everything attached to it
+struct ImportedIssue {
+    issue: Issue,
+    labels: Vec<String>,
+    notes: Vec<ImportedNote>,
+    deps: Vec<ImportedDep>,
+    close_data: Option<CloseData>,
+    links: Vec<ImportedLink>,
+    // The issue's history; wok and beads exports carry one
+    events: Vec<Event>,
+    // What the source record held that the issue could not keep
+    dropped: Vec<String>,
+}
+
+// Imported note; wok exports keep when it was written
+struct ImportedNote {
+    status: Status,
+    kind: Option<NoteKind>,
+    content: String,
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl ImportedNote {
+    fn plain(status: Status, content: String) -> Self {
+        ImportedNote {
+            status,
+            kind: None,
+            content,
+            created_at: None,
+        }
+    }
+}
+
+// Imported dependency; wok exports keep when it was made
+struct ImportedDep {
+    from_id: String,
+    to_id: String,
+    relation: Relation,
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl ImportedDep {
+    fn new(from_id: String, to_id: String, relation: Relation) -> Self {
+        ImportedDep {
+            from_id,
+            to_id,
+            relation,
+            created_at: None,
+        }
+    }
+}
 
 // Close event data from bd import
 struct CloseData {
@@ -39,11 +90,66 @@ struct ImportedLink {
     url: Option<String>,
     external_id: Option<String>,
     rel: Option<LinkRel>,
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl ImportedLink {
+    // The import link recording which source record an issue came from
+    fn import_key(source: LinkType, external_id: String) -> Self {
+        ImportedLink {
+            link_type: Some(source),
+            url: None,
+            external_id: Some(external_id),
+            rel: Some(LinkRel::Import),
+            created_at: None,
+        }
+    }
+
+    fn to_link(&self, issue_id: &str) -> Link {
+        let mut link = Link::new(issue_id.to_string());
+        link.link_type = self.link_type.clone();
+        link.url = self.url.clone();
+        link.external_id = self.external_id.clone();
+        link.rel = self.rel;
+        if let Some(created_at) = self.created_at {
+            link.created_at = created_at;
+        }
+        link
+    }
+
+    // Whether `links` already holds this link
+    fn is_in(&self, links: &[Link]) -> bool {
+        links.iter().any(|l| match &self.url {
+            Some(_) => l.url == self.url,
+            None => {
+                l.url.is_none()
+                    && l.link_type == self.link_type
+                    && l.external_id == self.external_id
+                    && l.rel == self.rel
+            }
+        })
+    }
 }
 
-// wk native export format (matches export.rs ExportedIssue)
+// Link type of the import links recording beads IDs
+const BEADS_SOURCE: &str = "beads";
+
+// The source and external ID an entry was imported under, from its import link
+fn external_key(links: &[ImportedLink]) -> Option<(&LinkType, &str)> {
+    links
+        .iter()
+        .find_map(|l| match (l.rel, &l.link_type, &l.external_id) {
+            (Some(LinkRel::Import), Some(source), Some(id)) => Some((source, id.as_str())),
+            _ => None,
+        })
+}
+
+// wk native export format (matches schema::export::ExportRecord)
 #[derive(Deserialize)]
 struct WkIssue {
+    // NOTE(compat): Absent in exports from before versioned records
+    #[serde(default)]
+    schema_version: Option<u32>,
     #[serde(flatten)]
     issue: Issue,
     labels: Vec<String>,
@@ -51,8 +157,6 @@ struct WkIssue {
     deps: Vec<WkDependency>,
     #[serde(default)]
     links: Vec<Link>,
-    // NOTE(compat): Required for JSON deserialization
-    #[allow(dead_code)]
     events: Vec<Event>,
 }
 
@@ -67,9 +171,10 @@ struct WkNote {
     issue_id: String,
     status: Status,
     content: String,
-    // NOTE(compat): Required for JSON deserialization
-    #[allow(dead_code)]
-    created_at: chrono::DateTime<chrono::Utc>,
+    // NOTE(compat): Absent in exports from before note kinds
+    #[serde(default)]
+    kind: Option<NoteKind>,
+    created_at: DateTime<Utc>,
 }
 
 // Dependency format in wk export (uses Relation enum)
@@ -78,34 +183,49 @@ struct WkDependency {
     from_id: String,
     to_id: String,
     relation: Relation,
-    // NOTE(compat): Required for JSON deserialization
-    #[allow(dead_code)]
-    created_at: chrono::DateTime<chrono::Utc>,
+    created_at: DateTime<Utc>,
 }
 
 // Beads export format
-#[derive(Deserialize)]
+#[derive(Default, Deserialize)]
 struct BeadsIssue {
     id: String,
     title: String,
     #[serde(default)]
     description: Option<String>,
+    #[serde(default)]
+    design: Option<String>,
+    #[serde(default)]
+    acceptance_criteria: Option<String>,
+    // Free-form notes field, distinct from comments
+    #[serde(default)]
+    notes: Option<String>,
     status: String,
+    // 0 (critical) to 4 (backlog)
     #[serde(default)]
-    priority: i32,
+    priority: Option<i64>,
     issue_type: String,
+    #[serde(default)]
+    assignee: Option<String>,
     created_at: String,
     updated_at: String,
     #[serde(default)]
+    closed_at: Option<String>,
+    #[serde(default)]
     labels: Vec<String>,
     #[serde(default)]
     dependencies: Vec<BeadsDependency>,
     #[serde(default)]
     comments: Vec<BeadsComment>,
     #[serde(default)]
+    events: Vec<BeadsEvent>,
+    #[serde(default)]
     close_reason: Option<String>,
     #[serde(default)]
     delete_reason: Option<String>,
+    // Fields wok has no place for, named in the compatibility report
+    #[serde(flatten)]
+    other: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Deserialize)]
@@ -113,14 +233,31 @@ struct BeadsDependency {
     depends_on_id: String,
     #[serde(rename = "type")]
     dep_type: String,
+    #[serde(default)]
+    created_at: Option<String>,
 }
 
 #[derive(Deserialize)]
 struct BeadsComment {
     #[serde(alias = "content")] // Accept both "text" and "content" for backwards compat
     text: String,
-    // NOTE(compat): Required for JSON deserialization
-    #[allow(dead_code)]
+    #[serde(default)]
+    author: Option<String>,
+    created_at: String,
+}
+
+// One entry of a beads issue's history
+#[derive(Deserialize)]
+struct BeadsEvent {
+    event_type: String,
+    #[serde(default)]
+    actor: Option<String>,
+    #[serde(default)]
+    old_value: Option<String>,
+    #[serde(default)]
+    new_value: Option<String>,
+    #[serde(default)]
+    comment: Option<String>,
     created_at: String,
 }
 
@@ -129,9 +266,12 @@ struct BeadsComment {
 struct ImportResult {
     created: usize,
     updated: usize,
+    skipped: usize,
     filtered: usize,
     collisions: Vec<String>,
     missing_deps: Vec<(String, String)>,
+    // (issue, what), for the compatibility report
+    dropped: Vec<(String, String)>,
 }
 
 // Format detection
@@ -143,6 +283,9 @@ fn detect_format<'a>(path: &str, explicit_format: &'a str) -> &'a str {
     if path.ends_with(".beads/issues.jsonl") {
         return "bd";
     }
+    if path.to_lowercase().ends_with(".csv") {
+        return "csv";
+    }
     explicit_format
 }
 
@@ -161,7 +304,7 @@ const FAILURE_WORDS: &[&str] = &[
     "aborted",
 ];
 
-fn is_failure_reason(reason: &str) -> bool {
+pub(crate) fn is_failure_reason(reason: &str) -> bool {
     let lower = reason.to_lowercase();
     FAILURE_WORDS.iter().any(|w| lower.contains(w))
 }
@@ -194,12 +337,70 @@ fn convert_beads_status(
 fn convert_beads_dep_type(dep_type: &str) -> Relation {
     match dep_type {
         "blocks" => Relation::Blocks,
-        "parent" | "tracks" => Relation::Tracks,
+        "parent" | "tracks" | "contains" => Relation::Tracks,
         "parent-child" | "child-of" | "tracked-by" => Relation::TrackedBy,
+        "related" => Relation::RelatesTo,
         _ => Relation::Blocks, // Default fallback
     }
 }
 
+// Whether convert_beads_dep_type knows the type rather than falling back
+fn is_known_beads_dep_type(dep_type: &str) -> bool {
+    matches!(
+        dep_type,
+        "blocks"
+            | "parent"
+            | "tracks"
+            | "contains"
+            | "parent-child"
+            | "child-of"
+            | "tracked-by"
+            | "related"
+    )
+}
+
+// The wok action of a beads history entry, if it has one
+fn convert_beads_event(event: &BeadsEvent) -> Option<Action> {
+    let status = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(|v| convert_beads_status(v, &event.comment, &event.comment))
+    };
+    Some(match event.event_type.as_str() {
+        "created" => Action::Created,
+        "updated" => Action::Edited,
+        "commented" => Action::Noted,
+        "reopened" => Action::Reopened,
+        "closed" => match &event.comment {
+            Some(reason) if is_failure_reason(reason) => Action::Closed,
+            _ => Action::Done,
+        },
+        "label_added" => Action::Labeled,
+        "label_removed" => Action::Unlabeled,
+        "dependency_added" => Action::Related,
+        "dependency_removed" => Action::Unrelated,
+        "assignee_changed" if event.new_value.is_some() => Action::Assigned,
+        "assignee_changed" => Action::Unassigned,
+        "status_changed" => match (status(&event.old_value), status(&event.new_value)?) {
+            (_, Status::InProgress) => Action::Started,
+            (_, Status::Done) => Action::Done,
+            (_, Status::Closed) => Action::Closed,
+            (Some(Status::Done | Status::Closed), Status::Todo) => Action::Reopened,
+            (_, Status::Todo) => Action::Stopped,
+        },
+        _ => return None,
+    })
+}
+
+// Parse a beads timestamp, naming the field it came from
+fn parse_beads_time(field: &str, value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| Error::InvalidTimestamp {
+            reason: format!("{}: {}", field, e),
+        })
+}
+
 // Type conversion for beads format
 fn convert_beads_type(issue_type: &str) -> IssueType {
     match issue_type {
@@ -213,73 +414,143 @@ fn convert_beads_type(issue_type: &str) -> IssueType {
 
 // Convert beads issue to internal format
 fn convert_beads_issue(bd: BeadsIssue) -> Result<ImportedIssue> {
-    let created_at = chrono::DateTime::parse_from_rfc3339(&bd.created_at)
-        .map_err(|e| Error::InvalidTimestamp {
-            reason: format!("created_at: {}", e),
-        })?
-        .with_timezone(&chrono::Utc);
-    let updated_at = chrono::DateTime::parse_from_rfc3339(&bd.updated_at)
-        .map_err(|e| Error::InvalidTimestamp {
-            reason: format!("updated_at: {}", e),
-        })?
-        .with_timezone(&chrono::Utc);
+    let created_at = parse_beads_time("created_at", &bd.created_at)?;
+    let updated_at = parse_beads_time("updated_at", &bd.updated_at)?;
+    let status = convert_beads_status(&bd.status, &bd.close_reason, &bd.delete_reason);
+    let closed_at = match &bd.closed_at {
+        Some(value) => Some(parse_beads_time("closed_at", value)?),
+        None => status.is_terminal().then_some(updated_at),
+    };
+    let mut dropped: Vec<String> = bd
+        .other
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(field, _)| format!("field '{}'", field))
+        .collect();
 
     let issue = Issue {
         id: bd.id.clone(),
         issue_type: convert_beads_type(&bd.issue_type),
         title: bd.title,
         description: bd.description,
-        status: convert_beads_status(&bd.status, &bd.close_reason, &bd.delete_reason),
-        assignee: None,
+        status,
+        assignee: bd.assignee.filter(|a| !a.is_empty()),
         created_at,
         updated_at,
-        closed_at: None,
+        closed_at,
         last_status_hlc: None,
         last_title_hlc: None,
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
 
     // Start with labels
     let mut labels = bd.labels;
 
-    // Add priority as label (only if > 0 and <= 4)
-    if bd.priority > 0 && bd.priority <= 4 {
-        labels.push(format!("priority:{}", bd.priority));
+    // Add priority as label; beads' P0 has no wok label
+    match bd.priority {
+        Some(p @ 1..=4) => labels.push(format!("priority:{}", p)),
+        Some(p) => dropped.push(format!("priority {}", p)),
+        None => {}
+    }
+
+    // Long-form fields become notes, written when the issue was
+    let mut notes: Vec<ImportedNote> = Vec::new();
+    for (heading, kind, text) in [
+        ("Design", Some(NoteKind::Decision), bd.design),
+        ("Acceptance criteria", None, bd.acceptance_criteria),
+        ("Notes", None, bd.notes),
+    ] {
+        if let Some(text) = text.filter(|t| !t.trim().is_empty()) {
+            notes.push(ImportedNote {
+                status: Status::Todo,
+                kind,
+                content: format!("{}:\n{}", heading, text),
+                created_at: Some(created_at),
+            });
+        }
     }
 
     // Convert comments to notes (using text field)
-    let mut notes: Vec<(Status, String)> = bd
-        .comments
-        .into_iter()
-        .map(|c| (Status::Todo, c.text))
-        .collect();
+    for comment in bd.comments {
+        if comment.author.is_some() && !dropped.iter().any(|d| d == "comment authors") {
+            dropped.push("comment authors".to_string());
+        }
+        notes.push(ImportedNote {
+            created_at: Some(parse_beads_time(
+                "comments.created_at",
+                &comment.created_at,
+            )?),
+            ..ImportedNote::plain(Status::Todo, comment.text)
+        });
+    }
 
     // Convert dependencies using proper type mapping
     // In beads format, "depends_on_id" is the issue this one depends on.
     // For "blocks" type: depends_on_id blocks this issue (depends_on_id is the blocker)
     // For other types: this issue has the relationship to depends_on_id
-    let deps: Vec<(String, String, Relation)> = bd
-        .dependencies
-        .into_iter()
-        .map(|d| {
-            let rel = convert_beads_dep_type(&d.dep_type);
-            if d.dep_type == "blocks" {
-                // "blocks" means depends_on_id blocks this issue
-                (d.depends_on_id, bd.id.clone(), rel)
-            } else {
-                (bd.id.clone(), d.depends_on_id, rel)
-            }
-        })
-        .collect();
+    let mut deps: Vec<ImportedDep> = Vec::new();
+    for d in bd.dependencies {
+        let rel = convert_beads_dep_type(&d.dep_type);
+        if !is_known_beads_dep_type(&d.dep_type) {
+            dropped.push(format!(
+                "dependency type '{}' on {} (imported as {})",
+                d.dep_type, d.depends_on_id, rel
+            ));
+        }
+        let created_at = d
+            .created_at
+            .as_deref()
+            .map(|t| parse_beads_time("dependencies.created_at", t))
+            .transpose()?;
+        let mut add = |from_id: String, to_id: String| {
+            deps.push(ImportedDep {
+                created_at,
+                ..ImportedDep::new(from_id, to_id, rel)
+            })
+        };
+        if d.dep_type == "blocks" {
+            // "blocks" means depends_on_id blocks this issue
+            add(d.depends_on_id, bd.id.clone());
+        } else if rel == Relation::RelatesTo {
+            // Stored both ways, as `wok dep` does
+            add(bd.id.clone(), d.depends_on_id.clone());
+            add(d.depends_on_id, bd.id.clone());
+        } else {
+            add(bd.id.clone(), d.depends_on_id);
+        }
+    }
+
+    // Status history becomes events
+    let mut events = Vec::new();
+    for event in &bd.events {
+        let Some(action) = convert_beads_event(event) else {
+            dropped.push(format!("event '{}'", event.event_type));
+            continue;
+        };
+        if event.actor.is_some() && !dropped.iter().any(|d| d == "event actors") {
+            dropped.push("event actors".to_string());
+        }
+        events.push(
+            Event::new(bd.id.clone(), action)
+                .with_values(event.old_value.clone(), event.new_value.clone())
+                .with_reason(event.comment.clone())
+                .with_timestamp(parse_beads_time("events.created_at", &event.created_at)?),
+        );
+    }
+    // The history already says how the issue was closed
+    let closed_in_history = events
+        .iter()
+        .any(|e| matches!(e.action, Action::Done | Action::Closed));
 
     // Build close data if applicable (for closed or tombstone status)
     let close_data = if bd.status == "closed" {
         bd.close_reason.clone().map(|reason| {
             let is_failure = is_failure_reason(&reason);
             // Add close reason as a note (always Closed status so it shows under "Close Reason:")
-            notes.push((Status::Closed, reason.clone()));
+            notes.push(ImportedNote::plain(Status::Closed, reason.clone()));
             CloseData { reason, is_failure }
         })
     } else if bd.status == "tombstone" {
@@ -288,36 +559,59 @@ fn convert_beads_issue(bd: BeadsIssue) -> Result<ImportedIssue> {
             .delete_reason
             .clone()
             .unwrap_or_else(|| "deleted".to_string());
-        notes.push((Status::Closed, reason.clone()));
+        notes.push(ImportedNote::plain(Status::Closed, reason.clone()));
         Some(CloseData {
             reason,
             is_failure: true, // Tombstone is always a "failure" (not completed)
         })
     } else {
         None
-    };
-
-    // Beads doesn't have external links
-    let links: Vec<ImportedLink> = Vec::new();
-
-    Ok((issue, labels, notes, deps, close_data, links))
+    }
+    .filter(|_| !closed_in_history);
+
+    // Beads doesn't have external links; keep the beads ID to find the issue on re-import
+    let links = vec![ImportedLink::import_key(
+        LinkType::Other(BEADS_SOURCE.to_string()),
+        bd.id,
+    )];
+
+    Ok(ImportedIssue {
+        issue,
+        labels,
+        notes,
+        deps,
+        close_data,
+        links,
+        events,
+        dropped,
+    })
 }
 
 // Convert wk issue to internal format
 fn convert_wk_issue(wk: WkIssue) -> ImportedIssue {
-    let notes: Vec<(Status, String)> = wk
+    let notes = wk
         .notes
         .into_iter()
-        .map(|n| (n.status, n.content))
+        .map(|n| ImportedNote {
+            status: n.status,
+            kind: n.kind,
+            content: n.content,
+            created_at: Some(n.created_at),
+        })
         .collect();
 
-    let deps: Vec<(String, String, Relation)> = wk
+    let deps = wk
         .deps
         .into_iter()
-        .map(|d| (d.from_id, d.to_id, d.relation))
+        .map(|d| ImportedDep {
+            from_id: d.from_id,
+            to_id: d.to_id,
+            relation: d.relation,
+            created_at: Some(d.created_at),
+        })
         .collect();
 
-    let links: Vec<ImportedLink> = wk
+    let links = wk
         .links
         .into_iter()
         .map(|l| ImportedLink {
@@ -325,10 +619,288 @@ fn convert_wk_issue(wk: WkIssue) -> ImportedIssue {
             url: l.url,
             external_id: l.external_id,
             rel: l.rel,
+            created_at: Some(l.created_at),
         })
         .collect();
 
-    (wk.issue, wk.labels, notes, deps, None, links) // wk format has no close_data
+    ImportedIssue {
+        issue: wk.issue,
+        labels: wk.labels,
+        notes,
+        deps,
+        close_data: None, // wk format has no close_data
+        links,
+        events: wk.events,
+        dropped: Vec::new(),
+    }
+}
+
+// Convert a record read through a --map file to internal format
+fn convert_mapped_issue(source: LinkType, mapped: MappedIssue) -> ImportedIssue {
+    // The record's page, as a link of the tracker it names
+    let page = mapped.url.map(|url| {
+        let (link_type, external_id) = parse_link_url(&url);
+        ImportedLink {
+            link_type: link_type.or_else(|| Some(source.clone())),
+            url: Some(url),
+            external_id,
+            rel: None,
+            created_at: None,
+        }
+    });
+    ImportedIssue {
+        notes: mapped
+            .notes
+            .into_iter()
+            .map(|content| ImportedNote::plain(Status::Todo, content))
+            .collect(),
+        deps: mapped
+            .deps
+            .into_iter()
+            .map(|(from_id, to_id, relation)| ImportedDep::new(from_id, to_id, relation))
+            .collect(),
+        links: std::iter::once(ImportedLink::import_key(source, mapped.external_id))
+            .chain(page)
+            .collect(),
+        issue: mapped.issue,
+        labels: mapped.labels,
+        close_data: None,
+        events: Vec::new(),
+        dropped: Vec::new(),
+    }
+}
+
+// Convert a spreadsheet row to internal format
+fn convert_csv_row(source: &LinkType, row: CsvRow) -> ImportedIssue {
+    ImportedIssue {
+        issue: row.issue,
+        labels: row.labels,
+        notes: Vec::new(),
+        deps: Vec::new(),
+        close_data: None,
+        links: row
+            .external_id
+            .map(|id| ImportedLink::import_key(source.clone(), id))
+            .into_iter()
+            .collect(),
+        events: Vec::new(),
+        dropped: Vec::new(),
+    }
+}
+
+// The source of import links for CSV rows: the file's name
+fn csv_source(path: &str) -> &str {
+    match path {
+        "-" => "csv",
+        path => std::path::Path::new(path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("csv"),
+    }
+}
+
+/// What an import file holds.
+pub(crate) enum Source<'a> {
+    /// wok export records.
+    Wok,
+    /// Beads issues.
+    Beads,
+    /// Another tracker's JSONL records, read through a `--map` file.
+    Mapped(&'a Mapping),
+    /// A GitLab or Gitea API dump, read through the forge's mapping.
+    Forge { forge: Forge, mapping: &'a Mapping },
+    /// Spreadsheet rows, given new IDs under `prefix`.
+    Csv {
+        columns: &'a CsvColumns,
+        prefix: &'a str,
+    },
+}
+
+impl Source<'static> {
+    /// The JSONL source `format` names, or the one `path` looks like.
+    pub(crate) fn jsonl(path: &str, format: &str) -> Self {
+        match detect_format(path, format) {
+            "bd" => Source::Beads,
+            _ => Source::Wok,
+        }
+    }
+}
+
+/// Issues read from an import file, not yet applied.
+pub(crate) struct ImportFile {
+    entries: Vec<ImportedIssue>,
+}
+
+/// How [`ImportFile::remap_prefix`] renamed issues.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct PrefixRemap {
+    /// Prefixes that were replaced.
+    pub from: BTreeSet<String>,
+    pub renamed: usize,
+    /// IDs left alone because their renamed form was already taken.
+    pub kept: Vec<String>,
+}
+
+impl ImportFile {
+    /// Parse `path` ('-' for stdin) as `source`.
+    pub(crate) fn read(path: &str, source: &Source) -> Result<Self> {
+        // Open input
+        let mut reader: Box<dyn BufRead> = if path == "-" {
+            Box::new(BufReader::new(io::stdin()))
+        } else {
+            let file = std::fs::File::open(path).map_err(|e| {
+                Error::Io(std::io::Error::other(format!(
+                    "cannot open {}: {}",
+                    path, e
+                )))
+            })?;
+            Box::new(BufReader::new(file))
+        };
+
+        if let Source::Csv { columns, prefix } = source {
+            let mut text = String::new();
+            reader.read_to_string(&mut text)?;
+            let rows = read_rows(
+                &text,
+                columns,
+                prefix,
+                &mut io::stdin().lock(),
+                &mut io::stdout(),
+            )?;
+            let source = LinkType::from_name(csv_source(path));
+            let entries = rows
+                .into_iter()
+                .map(|row| convert_csv_row(&source, row))
+                .collect();
+            return Ok(ImportFile { entries });
+        }
+
+        if let Source::Forge { forge, mapping } = source {
+            let mut text = String::new();
+            reader.read_to_string(&mut text)?;
+            let entries = forge
+                .records(&text)?
+                .into_iter()
+                .map(|record| {
+                    let mapped = mapping.convert(&record).map_err(|reason| {
+                        let reference = record["wok_ref"].as_str().unwrap_or_default();
+                        Error::InvalidRecord {
+                            record: format!("{} issue {}", forge.name(), reference),
+                            reason,
+                        }
+                    })?;
+                    Ok(convert_mapped_issue(mapping.source(), mapped))
+                })
+                .collect::<Result<_>>()?;
+            return Ok(ImportFile { entries });
+        }
+
+        // Parse input
+        let mut entries: Vec<ImportedIssue> = Vec::new();
+
+        for (line_num, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let entry = match source {
+                Source::Mapped(mapping) => {
+                    let parse_error = |reason: String| Error::ParseLineError {
+                        line: line_num + 1,
+                        reason,
+                    };
+                    let record: serde_json::Value =
+                        serde_json::from_str(line).map_err(|e| parse_error(e.to_string()))?;
+                    convert_mapped_issue(
+                        mapping.source(),
+                        mapping.convert(&record).map_err(parse_error)?,
+                    )
+                }
+                Source::Beads => {
+                    let bd: BeadsIssue =
+                        serde_json::from_str(line).map_err(|e| Error::ParseLineError {
+                            line: line_num + 1,
+                            reason: e.to_string(),
+                        })?;
+                    convert_beads_issue(bd)?
+                }
+                _ => {
+                    let wk: WkIssue =
+                        serde_json::from_str(line).map_err(|e| Error::ParseLineError {
+                            line: line_num + 1,
+                            reason: e.to_string(),
+                        })?;
+                    match wk.schema_version {
+                        Some(version) if version > EXPORT_SCHEMA_VERSION => {
+                            return Err(Error::ParseLineError {
+                                line: line_num + 1,
+                                reason: format!(
+                                    "export schema version {} is newer than this wok reads ({}); upgrade wok",
+                                    version, EXPORT_SCHEMA_VERSION
+                                ),
+                            });
+                        }
+                        _ => convert_wk_issue(wk),
+                    }
+                }
+            };
+
+            entries.push(entry);
+        }
+
+        Ok(ImportFile { entries })
+    }
+
+    /// Give every issue `prefix` in place of its own, keeping the rest of
+    /// its ID, and rewrite dependencies to match.
+    pub(crate) fn remap_prefix(&mut self, prefix: &str) -> PrefixRemap {
+        let mut remap = PrefixRemap::default();
+        let mut taken: HashSet<String> = self.entries.iter().map(|e| e.issue.id.clone()).collect();
+        let mut renames: HashMap<String, String> = HashMap::new();
+        for ImportedIssue { issue, .. } in &self.entries {
+            let Some((old, rest)) = issue.id.split_once('-') else {
+                continue;
+            };
+            if old == prefix {
+                continue;
+            }
+            let new_id = format!("{}-{}", prefix, rest);
+            if !taken.insert(new_id.clone()) {
+                remap.kept.push(issue.id.clone());
+                continue;
+            }
+            remap.from.insert(old.to_string());
+            renames.insert(issue.id.clone(), new_id);
+        }
+
+        let rename = |id: &mut String| {
+            if let Some(new_id) = renames.get(id.as_str()) {
+                *id = new_id.clone();
+            }
+        };
+        for entry in &mut self.entries {
+            rename(&mut entry.issue.id);
+            for dep in &mut entry.deps {
+                rename(&mut dep.from_id);
+                rename(&mut dep.to_id);
+            }
+        }
+        remap.renamed = renames.len();
+        remap
+    }
+
+    /// Import every issue into `db` and print the summary.
+    pub(crate) fn import(self, db: &mut Database) -> Result<()> {
+        apply(
+            db,
+            self.entries,
+            ImportResult::default(),
+            OnConflict::Update,
+            false,
+        )
+    }
 }
 
 // TODO(refactor): Consider using an options struct to bundle parameters
@@ -337,6 +909,10 @@ pub fn run(
     file: Option<String>,
     input: Option<String>,
     format: &str,
+    column: Vec<String>,
+    interactive: bool,
+    map: Option<String>,
+    on_conflict: OnConflict,
     dry_run: bool,
     status: Vec<String>,
     issue_type: Vec<String>,
@@ -350,10 +926,76 @@ pub fn run(
         Some(_) => "-",
         None => return Err(Error::NoInputFile),
     };
+    let mapping = map
+        .map(|map| Mapping::load(std::path::Path::new(&map)))
+        .transpose()?;
+
+    let format = detect_format(path, format);
+    let csv = mapping.is_none() && format == "csv";
+    let forge = Forge::from_format(format).filter(|_| mapping.is_none());
+    if !csv && !column.is_empty() {
+        return Err(Error::IncompatibleOptions {
+            option: "--column",
+            with: "a format other than csv",
+        });
+    }
+    if !csv && interactive {
+        return Err(Error::IncompatibleOptions {
+            option: "--interactive",
+            with: "a format other than csv",
+        });
+    }
+    // Prompts read stdin, so the rows cannot come from it
+    if interactive && (path == "-" || !(io::stdin().is_terminal() && io::stdout().is_terminal())) {
+        return Err(Error::TtyRequired);
+    }
+    let columns = CsvColumns::parse(&column, interactive)?;
+
+    let (mut db, config, work_dir) = open_db()?;
+    if csv && config.prefix.is_empty() {
+        return Err(Error::CannotCreateIssue {
+            reason: "project has no prefix configured\n  hint: CSV rows get new IDs under the project prefix".to_string(),
+        });
+    }
+    if forge.is_some() && config.prefix.is_empty() {
+        return Err(Error::CannotCreateIssue {
+            reason: "project has no prefix configured\n  hint: issues keep their number under the project prefix".to_string(),
+        });
+    }
+    let forge = forge
+        .map(|forge| {
+            forge
+                .mapping(&config.prefix)
+                .map(|mapping| (forge, mapping))
+        })
+        .transpose()?;
+    let source = match (&mapping, &forge) {
+        (Some(mapping), _) => Source::Mapped(mapping),
+        (None, Some((forge, mapping))) => Source::Forge {
+            forge: *forge,
+            mapping,
+        },
+        (None, None) if csv => Source::Csv {
+            columns: &columns,
+            prefix: &config.prefix,
+        },
+        (None, None) => Source::jsonl(path, format),
+    };
 
-    let (mut db, config, _) = open_db()?;
+    if !dry_run {
+        super::backup::auto_backup(&db.conn, &config, &work_dir, "import")?;
+    }
     run_impl(
-        &mut db, &config, path, format, dry_run, status, issue_type, label, prefix,
+        &mut db,
+        &config,
+        path,
+        &source,
+        on_conflict,
+        dry_run,
+        status,
+        issue_type,
+        label,
+        prefix,
     )
 }
 
@@ -363,28 +1005,15 @@ pub(crate) fn run_impl(
     db: &mut Database,
     _config: &Config,
     path: &str,
-    format: &str,
+    source: &Source,
+    on_conflict: OnConflict,
     dry_run: bool,
     status: Vec<String>,
     issue_type: Vec<String>,
     label: Vec<String>,
     prefix: Option<String>,
 ) -> Result<()> {
-    // Detect format
-    let format = detect_format(path, format);
-
-    // Open input
-    let reader: Box<dyn BufRead> = if path == "-" {
-        Box::new(BufReader::new(io::stdin()))
-    } else {
-        let file = std::fs::File::open(path).map_err(|e| {
-            Error::Io(std::io::Error::other(format!(
-                "cannot open {}: {}",
-                path, e
-            )))
-        })?;
-        Box::new(BufReader::new(file))
-    };
+    let entries = ImportFile::read(path, source)?.entries;
 
     // Parse filters
     let status_groups = parse_filter_groups(&status, |s| Ok(s.parse::<Status>()?))?;
@@ -392,43 +1021,12 @@ pub(crate) fn run_impl(
         parse_filter_groups(&issue_type, |s| s.parse::<IssueType>().map_err(Into::into))?;
     let label_groups = parse_filter_groups(&label, LabelMatcher::parse)?;
 
-    // Parse input
-    let mut entries: Vec<ImportedIssue> = Vec::new();
-
-    for (line_num, line) in reader.lines().enumerate() {
-        let line = line?;
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
-        let entry = match format {
-            "bd" => {
-                let bd: BeadsIssue =
-                    serde_json::from_str(line).map_err(|e| Error::ParseLineError {
-                        line: line_num + 1,
-                        reason: e.to_string(),
-                    })?;
-                convert_beads_issue(bd)?
-            }
-            _ => {
-                let wk: WkIssue =
-                    serde_json::from_str(line).map_err(|e| Error::ParseLineError {
-                        line: line_num + 1,
-                        reason: e.to_string(),
-                    })?;
-                convert_wk_issue(wk)
-            }
-        };
-
-        entries.push(entry);
-    }
-
     // Apply filters
     let mut filtered_entries = Vec::new();
     let mut result = ImportResult::default();
 
-    for (issue, labels, notes, deps, close_data, links) in entries {
+    for entry in entries {
+        let issue = &entry.issue;
         // Filter by prefix
         if let Some(ref pfx) = prefix {
             if !issue.id.starts_with(pfx) {
@@ -445,151 +1043,286 @@ pub(crate) fn run_impl(
             result.filtered += 1;
             continue;
         }
-        if !matches_label_groups(&label_groups, &labels) {
+        if !matches_label_groups(&label_groups, &entry.labels) {
             result.filtered += 1;
             continue;
         }
-        filtered_entries.push((issue, labels, notes, deps, close_data, links));
+        filtered_entries.push(entry);
+    }
+
+    apply(db, filtered_entries, result, on_conflict, dry_run)
+}
+
+fn add_note(db: &Database, issue_id: &str, note: &ImportedNote) -> Result<i64> {
+    let created_at = note.created_at.unwrap_or_else(Utc::now);
+    Ok(db.add_note_at(issue_id, note.status, note.kind, &note.content, created_at)?)
+}
+
+/// Log an exported event against the issue it was imported as.
+fn log_event(db: &Database, issue_id: &str, event: &Event) -> Result<i64> {
+    let mut event = event.clone();
+    event.issue_id = issue_id.to_string();
+    Ok(db.log_event(&event)?)
+}
+
+/// Whether two events record the same change, whatever their IDs.
+fn same_event(a: &Event, b: &Event) -> bool {
+    a.action == b.action
+        && a.old_value == b.old_value
+        && a.new_value == b.new_value
+        && a.reason == b.reason
+        && a.created_at == b.created_at
+}
+
+/// Where an entry is imported to.
+enum Target {
+    /// A new issue with this ID.
+    Create(String),
+    /// The existing issue it matches, updated from it.
+    Update(Box<Issue>),
+    /// The existing issue it matches, left alone.
+    Skip(String),
+}
+
+impl Target {
+    fn id(&self) -> &str {
+        match self {
+            Target::Create(id) | Target::Skip(id) => id,
+            Target::Update(existing) => &existing.id,
+        }
+    }
+}
+
+/// The issue an earlier import made of this entry (by its import link), or
+/// else the one with its ID.
+fn find_existing(db: &Database, issue: &Issue, links: &[ImportedLink]) -> Result<Option<Issue>> {
+    if let Some((source, external_id)) = external_key(links) {
+        if let Some(link) = db.find_import_link(source, external_id)? {
+            return Ok(Some(db.get_issue(&link.issue_id)?));
+        }
+    }
+    match db.get_issue(&issue.id).map_err(Error::from) {
+        Ok(existing) => Ok(Some(existing)),
+        Err(Error::IssueNotFound(_)) => Ok(None),
+        Err(e) => Err(e),
     }
+}
 
+/// Create or update each entry, then print the summary.
+fn apply(
+    db: &mut Database,
+    filtered_entries: Vec<ImportedIssue>,
+    mut result: ImportResult,
+    on_conflict: OnConflict,
+    dry_run: bool,
+) -> Result<()> {
     // Collect existing IDs for dependency checking
     let existing_ids: HashSet<String> = db
         .list_issues(None, None, None)?
         .into_iter()
         .map(|i| i.id)
         .collect();
-    let import_ids: HashSet<String> = filtered_entries
+
+    // Decide where every entry goes first, so dependencies between entries
+    // follow them to the issues they end up as
+    let mut taken = existing_ids.clone();
+    let mut targets = Vec::with_capacity(filtered_entries.len());
+    for entry in &filtered_entries {
+        let issue = &entry.issue;
+        let target = match (find_existing(db, issue, &entry.links)?, on_conflict) {
+            (None, _) => Target::Create(issue.id.clone()),
+            (Some(existing), OnConflict::Skip) => Target::Skip(existing.id),
+            (Some(existing), OnConflict::Update) => Target::Update(Box::new(existing)),
+            (Some(_), OnConflict::Duplicate) => {
+                let prefix = issue
+                    .id
+                    .split_once('-')
+                    .map_or(issue.id.as_str(), |(p, _)| p);
+                Target::Create(generate_unique_id(
+                    prefix,
+                    &issue.title,
+                    &issue.created_at,
+                    |id| taken.contains(id),
+                ))
+            }
+        };
+        taken.insert(target.id().to_string());
+        targets.push(target);
+    }
+    let renames: HashMap<&str, &str> = filtered_entries
         .iter()
-        .map(|(i, _, _, _, _, _)| i.id.clone())
+        .zip(&targets)
+        .map(|(entry, target)| (entry.issue.id.as_str(), target.id()))
         .collect();
+    let rename = |id: &str| renames.get(id).copied().unwrap_or(id).to_string();
+    let import_ids: HashSet<&str> = targets.iter().map(Target::id).collect();
+
+    // What a dry run would do to each entry
+    let mut preview: Vec<[String; 5]> = Vec::new();
+
+    // Dependencies are added once every issue exists
+    let mut new_deps: Vec<(String, String, Relation, Option<DateTime<Utc>>)> = Vec::new();
 
     // Process imports
-    for (issue, labels, notes, deps, close_data, links) in &filtered_entries {
-        // Check for missing dependencies
-        for (_, to_id, _) in deps {
-            if !existing_ids.contains(to_id) && !import_ids.contains(to_id) {
-                result.missing_deps.push((issue.id.clone(), to_id.clone()));
+    for (entry, target) in filtered_entries.iter().zip(&targets) {
+        let ImportedIssue {
+            issue,
+            labels,
+            notes,
+            deps,
+            close_data,
+            links,
+            events,
+            dropped,
+        } = entry;
+        let id = target.id();
+        if dry_run {
+            let action = match target {
+                Target::Create(_) => "create",
+                Target::Update(_) => "update",
+                Target::Skip(_) => "skip",
+            };
+            preview.push([
+                action.to_string(),
+                id.to_string(),
+                issue.issue_type.to_string(),
+                issue.status.to_string(),
+                issue.title.clone(),
+            ]);
+        }
+        if let Target::Skip(_) = target {
+            result.skipped += 1;
+            continue;
+        }
+        for what in dropped {
+            result.dropped.push((id.to_string(), what.clone()));
+        }
+
+        for dep in deps {
+            let (from_id, to_id) = (rename(&dep.from_id), rename(&dep.to_id));
+            if existing_ids.contains(&to_id) || import_ids.contains(to_id.as_str()) {
+                new_deps.push((from_id, to_id, dep.relation, dep.created_at));
+            } else {
+                result.missing_deps.push((id.to_string(), to_id));
             }
         }
 
-        // Check if issue exists
-        match db.get_issue(&issue.id).map_err(crate::error::Error::from) {
-            Ok(existing) => {
+        match target {
+            Target::Update(existing) => {
                 // Check for collision (different content)
                 if existing.title != issue.title || existing.status != issue.status {
-                    result.collisions.push(issue.id.clone());
+                    result.collisions.push(id.to_string());
                 }
 
                 if !dry_run {
                     // Update issue
                     if existing.title != issue.title {
-                        db.update_issue_title(&issue.id, &issue.title)?;
+                        db.update_issue_title(id, &issue.title)?;
                     }
                     if existing.status != issue.status {
-                        db.update_issue_status(&issue.id, issue.status)?;
+                        db.update_issue_status(id, issue.status)?;
                     }
                     if existing.issue_type != issue.issue_type {
-                        db.update_issue_type(&issue.id, issue.issue_type)?;
+                        db.update_issue_type(id, issue.issue_type)?;
                     }
 
                     // Sync labels
-                    let existing_labels = db.get_labels(&issue.id)?;
+                    let existing_labels = db.get_labels(id)?;
                     for l in &existing_labels {
                         if !labels.contains(l) {
-                            db.remove_label(&issue.id, l)?;
+                            db.remove_label(id, l)?;
                         }
                     }
                     for l in labels {
                         if !existing_labels.contains(l) {
-                            db.add_label(&issue.id, l)?;
+                            db.add_label(id, l)?;
                         }
                     }
 
                     // Add new notes
-                    let existing_notes = db.get_notes(&issue.id)?;
-                    for (status, content) in notes {
-                        if !existing_notes.iter().any(|n| n.content == *content) {
-                            db.add_note(&issue.id, *status, content)?;
+                    let existing_notes = db.get_notes(id)?;
+                    for note in notes {
+                        if !existing_notes.iter().any(|n| n.content == note.content) {
+                            add_note(db, id, note)?;
                         }
                     }
 
-                    // Add deps (idempotent via INSERT OR IGNORE)
-                    for (from_id, to_id, rel) in deps {
-                        // Only add if target exists
-                        if existing_ids.contains(to_id) || import_ids.contains(to_id) {
-                            let _ = db.add_dependency(from_id, to_id, *rel);
+                    // Add events not already in the history
+                    let existing_events = db.get_events(id)?;
+                    for event in events {
+                        if !existing_events.iter().any(|e| same_event(e, event)) {
+                            log_event(db, id, event)?;
                         }
                     }
 
-                    // Add links (check if URL already exists to avoid duplicates)
-                    let existing_links = db.get_links(&issue.id)?;
+                    // Add links not already on the issue
+                    let existing_links = db.get_links(id)?;
                     for imported_link in links {
-                        let url_exists = existing_links.iter().any(|l| l.url == imported_link.url);
-                        if !url_exists {
-                            let mut link = Link::new(issue.id.clone());
-                            link.link_type = imported_link.link_type;
-                            link.url = imported_link.url.clone();
-                            link.external_id = imported_link.external_id.clone();
-                            link.rel = imported_link.rel;
-                            db.add_link(&link)?;
+                        if !imported_link.is_in(&existing_links) {
+                            db.add_link(&imported_link.to_link(id))?;
                         }
                     }
                 }
                 result.updated += 1;
             }
-            Err(Error::IssueNotFound(_)) => {
+            Target::Create(_) => {
                 if !dry_run {
                     // Create new issue
-                    db.create_issue(issue)?;
+                    let mut issue = issue.clone();
+                    issue.id = id.to_string();
+                    db.create_issue(&issue)?;
 
                     // Add labels
                     for l in labels {
-                        db.add_label(&issue.id, l)?;
+                        db.add_label(id, l)?;
                     }
 
                     // Add notes
-                    for (status, content) in notes {
-                        db.add_note(&issue.id, *status, content)?;
+                    for note in notes {
+                        add_note(db, id, note)?;
                     }
 
-                    // Add deps (only if target exists or will be created)
-                    for (from_id, to_id, rel) in deps {
-                        if existing_ids.contains(to_id) || import_ids.contains(to_id) {
-                            let _ = db.add_dependency(from_id, to_id, *rel);
-                        }
+                    // Restore the history, or log close event if applicable
+                    for event in events {
+                        log_event(db, id, event)?;
                     }
-
-                    // Log close event if applicable
                     if let Some(cd) = close_data {
                         let action = if cd.is_failure {
                             Action::Closed
                         } else {
                             Action::Done
                         };
-                        let event = Event::new(issue.id.clone(), action)
-                            .with_reason(Some(cd.reason.clone()));
+                        let event =
+                            Event::new(id.to_string(), action).with_reason(Some(cd.reason.clone()));
                         db.log_event(&event)?;
                     }
 
                     // Add links
                     for imported_link in links {
-                        let mut link = Link::new(issue.id.clone());
-                        link.link_type = imported_link.link_type;
-                        link.url = imported_link.url.clone();
-                        link.external_id = imported_link.external_id.clone();
-                        link.rel = imported_link.rel;
-                        db.add_link(&link)?;
+                        db.add_link(&imported_link.to_link(id))?;
                     }
                 }
                 result.created += 1;
             }
-            Err(e) => return Err(e),
+            Target::Skip(_) => {}
+        }
+    }
+
+    if !dry_run {
+        // Idempotent via INSERT OR IGNORE; dependencies that would form a
+        // cycle are dropped
+        for (from_id, to_id, rel, created_at) in new_deps {
+            let _ =
+                db.add_dependency_at(&from_id, &to_id, rel, created_at.unwrap_or_else(Utc::now));
         }
     }
 
     // Print results
     if dry_run {
         println!("Dry run - no changes made");
+        if !preview.is_empty() {
+            print_preview(&preview);
+        }
     }
 
     println!("Import summary:");
@@ -599,6 +1332,9 @@ pub(crate) fn run_impl(
     if result.updated > 0 {
         println!("  update: {}", result.updated);
     }
+    if result.skipped > 0 {
+        println!("  skip: {}", result.skipped);
+    }
     if result.filtered > 0 {
         println!("  filtered: {}", result.filtered);
     }
@@ -623,9 +1359,45 @@ pub(crate) fn run_impl(
         }
     }
 
+    if !result.dropped.is_empty() {
+        eprintln!(
+            "\nCompatibility report: {} item(s) not carried over:",
+            result.dropped.len()
+        );
+        for (issue_id, what) in &result.dropped {
+            eprintln!("  - {}: {}", issue_id, what);
+        }
+    }
+
     Ok(())
 }
 
+// Print the dry-run rows as an aligned table
+fn print_preview(rows: &[[String; 5]]) {
+    let header = ["ACTION", "ID", "TYPE", "STATUS", "TITLE"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(rows) {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {:<w3$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+    println!();
+}
+
 #[cfg(test)]
 #[path = "import_tests.rs"]
 mod tests;
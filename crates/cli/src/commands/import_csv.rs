@@ -0,0 +1,386 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Spreadsheet rows for `wok import --format csv`.
+//!
+//! The header row names each column. Known headers (`title`, `type`,
+//! `status`, `labels`, `assignee`, `description`, `id`, and a few common
+//! synonyms) map to issue fields by themselves; any other column must be
+//! mapped with `--column HEADER=FIELD` or, with `--interactive`, at a
+//! prompt. Labels are a semicolon-separated list.
+
+use std::collections::HashSet;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+use chrono::Utc;
+
+use crate::error::{Error, Result};
+use crate::id::generate_unique_id;
+use crate::models::{Issue, IssueType, Status};
+
+/// The issue field a column fills.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum CsvField {
+    /// The row's ID in the spreadsheet, kept to find the issue on re-import.
+    Id,
+    Title,
+    Type,
+    Status,
+    /// Semicolon-separated labels.
+    Labels,
+    Assignee,
+    Description,
+    /// One label: this prefix and the cell (`label:sprint:` makes `sprint:12`).
+    Label(String),
+    Ignore,
+}
+
+/// Field names accepted by `--column` and the prompt.
+const FIELD_NAMES: &str =
+    "title, type, status, labels, assignee, description, id, label:PREFIX, ignore";
+
+impl FromStr for CsvField {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(prefix) = s.strip_prefix("label:") {
+            return Ok(CsvField::Label(prefix.to_string()));
+        }
+        match s.to_lowercase().as_str() {
+            "id" => Ok(CsvField::Id),
+            "title" => Ok(CsvField::Title),
+            "type" => Ok(CsvField::Type),
+            "status" => Ok(CsvField::Status),
+            "labels" => Ok(CsvField::Labels),
+            "assignee" => Ok(CsvField::Assignee),
+            "description" => Ok(CsvField::Description),
+            "ignore" => Ok(CsvField::Ignore),
+            _ => Err(Error::InvalidCsvColumn {
+                spec: s.to_string(),
+                reason: format!("expected one of: {}", FIELD_NAMES),
+            }),
+        }
+    }
+}
+
+impl CsvField {
+    fn name(&self) -> &str {
+        match self {
+            CsvField::Id => "id",
+            CsvField::Title => "title",
+            CsvField::Type => "type",
+            CsvField::Status => "status",
+            CsvField::Labels => "labels",
+            CsvField::Assignee => "assignee",
+            CsvField::Description => "description",
+            CsvField::Label(prefix) => prefix,
+            CsvField::Ignore => "ignore",
+        }
+    }
+
+    /// The field a header names by itself, ignoring case and separators.
+    fn for_header(header: &str) -> Option<Self> {
+        let name: String = header
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        match name.as_str() {
+            "id" | "key" => Some(CsvField::Id),
+            "title" | "summary" | "name" => Some(CsvField::Title),
+            "type" | "issuetype" | "kind" => Some(CsvField::Type),
+            "status" | "state" => Some(CsvField::Status),
+            "labels" | "tags" => Some(CsvField::Labels),
+            "assignee" | "owner" => Some(CsvField::Assignee),
+            "description" | "details" | "body" | "notes" => Some(CsvField::Description),
+            _ => None,
+        }
+    }
+}
+
+/// How columns map to fields beyond their headers.
+#[derive(Debug, Default)]
+pub(crate) struct CsvColumns {
+    /// `--column HEADER=FIELD`, in order.
+    overrides: Vec<(String, CsvField)>,
+    /// Ask at the terminal about columns with no mapping.
+    interactive: bool,
+}
+
+impl CsvColumns {
+    /// Parse `--column HEADER=FIELD` values.
+    pub(crate) fn parse(specs: &[String], interactive: bool) -> Result<Self> {
+        let overrides = specs
+            .iter()
+            .map(|spec| {
+                let (header, field) =
+                    spec.rsplit_once('=')
+                        .ok_or_else(|| Error::InvalidCsvColumn {
+                            spec: spec.clone(),
+                            reason: "expected HEADER=FIELD, e.g. 'Summary=title'".to_string(),
+                        })?;
+                Ok((header.trim().to_string(), field.parse()?))
+            })
+            .collect::<Result<_>>()?;
+        Ok(CsvColumns {
+            overrides,
+            interactive,
+        })
+    }
+
+    fn field(&self, header: &str) -> Option<CsvField> {
+        self.overrides
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(header.trim()))
+            .map(|(_, field)| field.clone())
+            .or_else(|| CsvField::for_header(header))
+    }
+
+    /// The field of every column, prompting for the unknown ones when
+    /// interactive.
+    fn resolve(
+        &self,
+        headers: &[String],
+        input: &mut dyn BufRead,
+        output: &mut dyn Write,
+    ) -> Result<Vec<CsvField>> {
+        let unknown: Vec<&String> = headers.iter().filter(|h| self.field(h).is_none()).collect();
+        if !unknown.is_empty() && !self.interactive {
+            let columns: Vec<&str> = unknown.iter().map(|h| h.as_str()).collect();
+            return Err(Error::UnknownCsvColumns {
+                columns: columns.join(", "),
+                example: unknown[0].clone(),
+            });
+        }
+        let mut fields = Vec::with_capacity(headers.len());
+        for header in headers {
+            fields.push(match self.field(header) {
+                Some(field) => field,
+                None => prompt_field(header, input, output)?,
+            });
+        }
+
+        let mut single = HashSet::new();
+        for field in &fields {
+            let once = matches!(
+                field,
+                CsvField::Id
+                    | CsvField::Title
+                    | CsvField::Type
+                    | CsvField::Status
+                    | CsvField::Assignee
+                    | CsvField::Description
+            );
+            if once && !single.insert(field) {
+                return Err(Error::ParseLineError {
+                    line: 1,
+                    reason: format!("more than one column maps to {}", field.name()),
+                });
+            }
+        }
+        if !fields.contains(&CsvField::Title) {
+            return Err(Error::ParseLineError {
+                line: 1,
+                reason: "no title column; map one with --column 'HEADER=title'".to_string(),
+            });
+        }
+        Ok(fields)
+    }
+}
+
+/// Ask which field `header` maps to until the answer is one; blank ignores
+/// the column.
+fn prompt_field(header: &str, input: &mut dyn BufRead, output: &mut dyn Write) -> Result<CsvField> {
+    loop {
+        write!(
+            output,
+            "Column '{}' maps to ({}) [ignore]: ",
+            header, FIELD_NAMES
+        )?;
+        output.flush()?;
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            return Ok(CsvField::Ignore);
+        }
+        if answer.trim().is_empty() {
+            return Ok(CsvField::Ignore);
+        }
+        match answer.parse() {
+            Ok(field) => return Ok(field),
+            Err(e) => writeln!(output, "{}", e)?,
+        }
+    }
+}
+
+/// One spreadsheet row as an issue.
+#[derive(Debug)]
+pub(crate) struct CsvRow {
+    pub issue: Issue,
+    pub labels: Vec<String>,
+    /// The `id` column, when there is one.
+    pub external_id: Option<String>,
+}
+
+/// Read `text` as CSV rows, giving each a new ID under `prefix`. Unknown
+/// columns are asked about on `input`/`output` when `columns` allows.
+pub(crate) fn read_rows(
+    text: &str,
+    columns: &CsvColumns,
+    prefix: &str,
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<Vec<CsvRow>> {
+    let mut records = parse_records(text)?.into_iter();
+    let Some((_, headers)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let fields = columns.resolve(&headers, input, output)?;
+
+    let mut taken = HashSet::new();
+    let mut rows = Vec::new();
+    for (line, cells) in records {
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let row = convert_row(&fields, &cells, prefix, &mut taken)
+            .map_err(|reason| Error::ParseLineError { line, reason })?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn convert_row(
+    fields: &[CsvField],
+    cells: &[String],
+    prefix: &str,
+    taken: &mut HashSet<String>,
+) -> std::result::Result<CsvRow, String> {
+    let mut title = None;
+    let mut issue_type = IssueType::Task;
+    let mut status = Status::Todo;
+    let mut assignee = None;
+    let mut description = None;
+    let mut external_id = None;
+    let mut labels = Vec::new();
+
+    for (field, cell) in fields.iter().zip(cells) {
+        let value = cell.trim();
+        if value.is_empty() {
+            continue;
+        }
+        match field {
+            CsvField::Id => external_id = Some(value.to_string()),
+            CsvField::Title => title = Some(value.to_string()),
+            CsvField::Type => {
+                issue_type = value
+                    .to_lowercase()
+                    .parse()
+                    .map_err(|_| format!("unknown type '{}'", value))?
+            }
+            CsvField::Status => {
+                status = value
+                    .to_lowercase()
+                    .replace([' ', '-'], "_")
+                    .parse()
+                    .map_err(|_| format!("unknown status '{}'", value))?
+            }
+            CsvField::Labels => labels.extend(
+                value
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|label| !label.is_empty())
+                    .map(String::from),
+            ),
+            CsvField::Assignee => assignee = Some(value.to_string()),
+            CsvField::Description => description = Some(value.to_string()),
+            CsvField::Label(label_prefix) => labels.push(format!("{}{}", label_prefix, value)),
+            CsvField::Ignore => {}
+        }
+    }
+    let title = title.ok_or("empty title")?;
+
+    let now = Utc::now();
+    let id = generate_unique_id(prefix, &title, &now, |id| taken.contains(id));
+    taken.insert(id.clone());
+    Ok(CsvRow {
+        issue: Issue {
+            id,
+            issue_type,
+            title,
+            description,
+            status,
+            assignee,
+            created_at: now,
+            updated_at: now,
+            closed_at: status.is_terminal().then_some(now),
+            last_status_hlc: None,
+            last_title_hlc: None,
+            last_type_hlc: None,
+            last_description_hlc: None,
+            last_assignee_hlc: None,
+            revision: 1,
+        },
+        labels,
+        external_id,
+    })
+}
+
+/// Split RFC 4180 text into records, each with the line it starts on.
+/// Quoted cells may hold commas, newlines, and doubled quotes.
+fn parse_records(text: &str) -> Result<Vec<(usize, Vec<String>)>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    cell.push(c);
+                }
+                _ => cell.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if cell.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut cell)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut cell));
+                records.push((start, std::mem::take(&mut record)));
+                line += 1;
+                start = line;
+            }
+            _ => cell.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(Error::ParseLineError {
+            line: start,
+            reason: "unterminated quoted field".to_string(),
+        });
+    }
+    if !cell.is_empty() || !record.is_empty() {
+        record.push(cell);
+        records.push((start, record));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+#[path = "import_csv_tests.rs"]
+mod tests;
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use std::io::Cursor;
+
+fn rows(text: &str, columns: &CsvColumns) -> Result<Vec<CsvRow>> {
+    read_rows(text, columns, "proj", &mut Cursor::new(""), &mut Vec::new())
+}
+
+#[test]
+fn parse_records_handles_quotes_and_line_endings() {
+    let text = "\u{feff}a,b,c\r\n\"x, y\",\"say \"\"hi\"\"\",\"two\nlines\"\r\nlast,,\n";
+
+    let records = parse_records(text).unwrap();
+
+    assert_eq!(
+        records,
+        [
+            (1, vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            (
+                2,
+                vec![
+                    "x, y".to_string(),
+                    "say \"hi\"".to_string(),
+                    "two\nlines".to_string()
+                ]
+            ),
+            (4, vec!["last".to_string(), String::new(), String::new()]),
+        ]
+    );
+}
+
+#[test]
+fn parse_records_reports_unterminated_quotes() {
+    let err = parse_records("title\n\"open\n").unwrap_err();
+
+    assert!(matches!(err, Error::ParseLineError { line: 2, .. }));
+}
+
+#[test]
+fn known_headers_map_to_fields() {
+    let text = "Summary,Issue Type,State,Tags,Owner,Details\n\
+                Fix login,Bug,In Progress,backend; auth ;,alice,Users are logged out\n";
+
+    let rows = rows(text, &CsvColumns::default()).unwrap();
+
+    assert_eq!(rows.len(), 1);
+    let issue = &rows[0].issue;
+    assert!(issue.id.starts_with("proj-"));
+    assert_eq!(issue.title, "Fix login");
+    assert_eq!(issue.issue_type, IssueType::Bug);
+    assert_eq!(issue.status, Status::InProgress);
+    assert_eq!(issue.assignee.as_deref(), Some("alice"));
+    assert_eq!(issue.description.as_deref(), Some("Users are logged out"));
+    assert_eq!(rows[0].labels, ["backend", "auth"]);
+    assert_eq!(rows[0].external_id, None);
+}
+
+#[test]
+fn column_overrides_map_other_headers() {
+    let columns = CsvColumns::parse(
+        &[
+            "Ticket=id".to_string(),
+            "Sprint=label:sprint:".to_string(),
+            "Notes=ignore".to_string(),
+        ],
+        false,
+    )
+    .unwrap();
+    let text = "Ticket,Title,Sprint,Notes,Status\nT-1,Ship it,12,skip me,done\n";
+
+    let rows = rows(text, &columns).unwrap();
+
+    assert_eq!(rows[0].external_id.as_deref(), Some("T-1"));
+    assert_eq!(rows[0].labels, ["sprint:12"]);
+    assert_eq!(rows[0].issue.description, None);
+    assert_eq!(rows[0].issue.status, Status::Done);
+    assert!(rows[0].issue.closed_at.is_some());
+}
+
+#[test]
+fn unknown_columns_are_an_error_unless_interactive() {
+    let text = "Title,Priority,Estimate\nShip it,high,3\n";
+
+    let err = rows(text, &CsvColumns::default()).unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::UnknownCsvColumns { ref columns, ref example }
+            if columns == "Priority, Estimate" && example == "Priority"
+    ));
+}
+
+#[test]
+fn interactive_prompts_for_unknown_columns() {
+    let columns = CsvColumns::parse(&[], true).unwrap();
+    let text = "Title,Priority,Estimate\nShip it,high,3\n";
+    let mut input = Cursor::new("bogus\nlabel:priority:\n\n");
+    let mut output = Vec::new();
+
+    let rows = read_rows(text, &columns, "proj", &mut input, &mut output).unwrap();
+
+    assert_eq!(rows[0].labels, ["priority:high"]);
+    let prompts = String::from_utf8(output).unwrap();
+    assert_eq!(prompts.matches("Column 'Priority' maps to").count(), 2);
+    assert_eq!(prompts.matches("Column 'Estimate' maps to").count(), 1);
+}
+
+#[test]
+fn column_specs_must_name_a_field() {
+    for spec in ["Summary", "Summary=priority"] {
+        let err = CsvColumns::parse(&[spec.to_string()], false).unwrap_err();
+        assert!(matches!(err, Error::InvalidCsvColumn { .. }), "{}", spec);
+    }
+}
+
+#[test]
+fn header_row_needs_one_title_column() {
+    let err = rows("Status\ntodo\n", &CsvColumns::default()).unwrap_err();
+    assert!(matches!(err, Error::ParseLineError { line: 1, .. }));
+
+    let err = rows("Title,Summary\na,b\n", &CsvColumns::default()).unwrap_err();
+    assert!(matches!(err, Error::ParseLineError { line: 1, .. }));
+}
+
+#[test]
+fn bad_cells_name_their_line() {
+    let text = "Title,Status\nfine,todo\n\n,\nodd,triage\n";
+
+    let err = rows(text, &CsvColumns::default()).unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::ParseLineError { line: 5, ref reason } if reason == "unknown status 'triage'"
+    ));
+}
+
+#[test]
+fn rows_get_distinct_ids() {
+    let text = "Title\nSame\nSame\n";
+
+    let rows = rows(text, &CsvColumns::default()).unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_ne!(rows[0].issue.id, rows[1].issue.id);
+}
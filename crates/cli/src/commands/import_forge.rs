@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok import --format gitlab` and `--format gitea`: issues as the GitLab
+//! and Gitea REST APIs return them, read through built-in [`Mapping`]s.
+//!
+//! A dump is one or more JSON values, each an issue or an array of issues,
+//! so saved API pages can be concatenated as they are (`curl ... >> dump`)
+//! or written one issue per line. Issues keep the number they have in
+//! their project under the project prefix (`group/app#12` -> `prj-12`).
+//!
+//! The APIs list relations separately; a record can carry them inline, as
+//! the responses of GitLab's `issues/:iid/links` under `links`, or of
+//! Gitea's `issues/{index}/blocks` and `issues/{index}/dependencies` under
+//! `blocks` and `dependencies`. Relations to issues of other projects are
+//! left out, as their numbers name different issues here.
+
+use serde_json::{Map, Value};
+
+use crate::error::{Error, Result};
+
+use super::import_map::Mapping;
+
+/// Field mapping for GitLab issues.
+const GITLAB: &str = r#"
+source = "gitlab"
+
+[fields]
+id = "wok_ref"
+title = "title"
+description = "description"
+status = "state"
+type = "issue_type"
+assignee = "assignee.username"
+labels = "labels"
+notes = "notes[].body"
+created_at = "created_at"
+updated_at = "updated_at"
+url = "web_url"
+
+[status]
+opened = "todo"
+closed = "done"
+
+[type]
+issue = "task"
+incident = "bug"
+"*" = "task"
+
+[deps]
+blocks = "wok_blocks"
+blocked_by = "wok_blocked_by"
+"#;
+
+/// Field mapping for Gitea (and Forgejo) issues.
+const GITEA: &str = r#"
+source = "gitea"
+
+[fields]
+id = "wok_ref"
+title = "title"
+description = "body"
+status = "state"
+assignee = "assignee.login"
+labels = "labels[].name"
+notes = "comments[].body"
+created_at = "created_at"
+updated_at = "updated_at"
+url = "html_url"
+
+[status]
+open = "todo"
+closed = "done"
+
+[deps]
+blocks = "wok_blocks"
+blocked_by = "wok_blocked_by"
+"#;
+
+/// A forge whose API dumps `wok import` reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Forge {
+    Gitlab,
+    Gitea,
+}
+
+impl Forge {
+    /// The forge `format` names.
+    pub(crate) fn from_format(format: &str) -> Option<Self> {
+        match format {
+            "gitlab" => Some(Forge::Gitlab),
+            "gitea" => Some(Forge::Gitea),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Forge::Gitlab => "gitlab",
+            Forge::Gitea => "gitea",
+        }
+    }
+
+    /// The mapping of this forge's issues, giving them `prefix`.
+    pub(crate) fn mapping(self, prefix: &str) -> Result<Mapping> {
+        let toml = match self {
+            Forge::Gitlab => GITLAB,
+            Forge::Gitea => GITEA,
+        };
+        let mapping: Mapping = toml::from_str(toml).map_err(|e| Error::InvalidMapping {
+            path: format!("built-in {} mapping", self.name()),
+            reason: e.to_string(),
+        })?;
+        Ok(mapping.with_prefix(prefix))
+    }
+
+    /// The issues in a dump, ready for [`Mapping::convert`]. Gitea pull
+    /// requests, which its issue API lists too, are left out.
+    pub(crate) fn records(self, text: &str) -> Result<Vec<Value>> {
+        let mut records = Vec::new();
+        for value in serde_json::Deserializer::from_str(text).into_iter::<Value>() {
+            let value = value.map_err(|e| Error::ParseLineError {
+                line: e.line(),
+                reason: e.to_string(),
+            })?;
+            match value {
+                Value::Array(items) => records.extend(items),
+                value => records.push(value),
+            }
+        }
+        Ok(records
+            .into_iter()
+            .filter_map(|record| match record {
+                Value::Object(record) => self.normalize(record),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Add the fields the mapping reads that the API does not give as such:
+    /// `wok_ref`, the issue's reference, and `wok_blocks` and
+    /// `wok_blocked_by`, the numbers of issues it blocks or waits on.
+    fn normalize(self, mut record: Map<String, Value>) -> Option<Value> {
+        let (number, reference, blocks, blocked_by) = match self {
+            Forge::Gitlab => {
+                let number = record.get("iid").and_then(issue_number)?;
+                let project = record.get("project_id").cloned();
+                // Labels come as objects with `with_labels_details`
+                if let Some(Value::Array(labels)) = record.get_mut("labels") {
+                    for label in labels.iter_mut() {
+                        if let Some(name) = label.get("name").cloned() {
+                            *label = name;
+                        }
+                    }
+                }
+                // System notes are GitLab's own log of changes
+                if let Some(Value::Array(notes)) = record.get_mut("notes") {
+                    notes.retain(|note| note.get("system") != Some(&Value::Bool(true)));
+                }
+                let links = linked(&record, "links", |link| {
+                    link.get("project_id") == project.as_ref() || link.get("project_id").is_none()
+                });
+                let of_type = |kind: &str| -> Vec<Value> {
+                    links
+                        .iter()
+                        .filter(|link| link.get("link_type").and_then(Value::as_str) == Some(kind))
+                        .filter_map(|link| link.get("iid").and_then(issue_number))
+                        .collect()
+                };
+                let reference = record
+                    .get("references")
+                    .and_then(|r| r.get("full"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                (
+                    number,
+                    reference,
+                    of_type("blocks"),
+                    of_type("is_blocked_by"),
+                )
+            }
+            Forge::Gitea => {
+                if record.get("pull_request").is_some_and(|pr| !pr.is_null()) {
+                    return None;
+                }
+                let number = record.get("number").and_then(issue_number)?;
+                let repo = record
+                    .get("repository")
+                    .and_then(|r| r.get("full_name"))
+                    .cloned();
+                let same_repo = |issue: &Value| {
+                    let other = issue.get("repository").and_then(|r| r.get("full_name"));
+                    other.is_none() || other == repo.as_ref()
+                };
+                let numbers = |key: &str| -> Vec<Value> {
+                    linked(&record, key, same_repo)
+                        .iter()
+                        .filter_map(|issue| issue.get("number").and_then(issue_number))
+                        .collect()
+                };
+                let reference = repo
+                    .as_ref()
+                    .and_then(Value::as_str)
+                    .map(|repo| format!("{}#{}", repo, text(&number)));
+                (
+                    number,
+                    reference,
+                    numbers("blocks"),
+                    numbers("dependencies"),
+                )
+            }
+        };
+        let reference = reference.unwrap_or_else(|| text(&number));
+        record.insert("wok_ref".to_string(), Value::String(reference));
+        record.insert("wok_blocks".to_string(), Value::Array(blocks));
+        record.insert("wok_blocked_by".to_string(), Value::Array(blocked_by));
+        Some(Value::Object(record))
+    }
+}
+
+/// The issues under `key` that `keep` accepts.
+fn linked<'a>(
+    record: &'a Map<String, Value>,
+    key: &str,
+    keep: impl Fn(&Value) -> bool,
+) -> Vec<&'a Value> {
+    match record.get(key) {
+        Some(Value::Array(issues)) => issues.iter().filter(|issue| keep(issue)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// An issue number, as the APIs write it.
+fn issue_number(value: &Value) -> Option<Value> {
+    match value {
+        Value::Number(n) if n.is_u64() => Some(value.clone()),
+        Value::String(s) if s.parse::<u64>().is_ok() => Some(value.clone()),
+        _ => None,
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "import_forge_tests.rs"]
+mod tests;
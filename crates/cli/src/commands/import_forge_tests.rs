@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::models::{IssueType, LinkType, Relation, Status};
+
+fn gitlab_issue(iid: u64, title: &str) -> Value {
+    serde_json::json!({
+        "id": 9000 + iid,
+        "iid": iid,
+        "project_id": 7,
+        "title": title,
+        "description": "Steps to reproduce",
+        "state": "opened",
+        "issue_type": "incident",
+        "labels": ["backend", "regression"],
+        "assignee": {"username": "ann"},
+        "created_at": "2026-03-01T10:00:00.000Z",
+        "updated_at": "2026-03-02T10:00:00.000Z",
+        "web_url": format!("https://gitlab.com/acme/app/-/issues/{}", iid),
+        "references": {"full": format!("acme/app#{}", iid)},
+    })
+}
+
+#[test]
+fn mappings_parse() {
+    assert_eq!(
+        Forge::Gitlab.mapping("prj").unwrap().source(),
+        LinkType::Gitlab
+    );
+    assert_eq!(
+        Forge::Gitea.mapping("prj").unwrap().source(),
+        LinkType::Other("gitea".to_string())
+    );
+}
+
+#[test]
+fn from_format_names_the_forges() {
+    assert_eq!(Forge::from_format("gitlab"), Some(Forge::Gitlab));
+    assert_eq!(Forge::from_format("gitea"), Some(Forge::Gitea));
+    assert_eq!(Forge::from_format("bd"), None);
+}
+
+#[test]
+fn records_reads_concatenated_pages_and_lines() {
+    let page1 = Value::Array(vec![gitlab_issue(1, "One"), gitlab_issue(2, "Two")]);
+    let page2 = Value::Array(vec![gitlab_issue(3, "Three")]);
+    let text = format!("{:#}\n{}\n{}\n", page1, page2, gitlab_issue(4, "Four"));
+
+    let records = Forge::Gitlab.records(&text).unwrap();
+
+    let refs: Vec<_> = records.iter().map(|r| r["wok_ref"].clone()).collect();
+    assert_eq!(
+        refs,
+        ["acme/app#1", "acme/app#2", "acme/app#3", "acme/app#4"]
+    );
+}
+
+#[test]
+fn records_reports_the_line_of_bad_json() {
+    let err = Forge::Gitlab
+        .records("[{\"iid\": 1},\n{oops}]")
+        .unwrap_err();
+    assert!(
+        matches!(err, Error::ParseLineError { line: 2, .. }),
+        "{err}"
+    );
+}
+
+#[test]
+fn gitlab_issue_converts() {
+    let mut record = gitlab_issue(12, "Login fails");
+    record["labels"] = serde_json::json!([{"name": "backend"}, {"name": "ui"}]);
+    record["notes"] = serde_json::json!([
+        {"body": "Seen on staging", "system": false},
+        {"body": "added ~backend label", "system": true},
+    ]);
+    let text = record.to_string();
+
+    let records = Forge::Gitlab.records(&text).unwrap();
+    let mapped = Forge::Gitlab
+        .mapping("prj")
+        .unwrap()
+        .convert(&records[0])
+        .unwrap();
+
+    assert_eq!(mapped.issue.id, "prj-12");
+    assert_eq!(mapped.external_id, "acme/app#12");
+    assert_eq!(mapped.issue.issue_type, IssueType::Bug);
+    assert_eq!(mapped.issue.status, Status::Todo);
+    assert_eq!(mapped.issue.assignee.as_deref(), Some("ann"));
+    assert_eq!(mapped.labels, ["backend", "ui"]);
+    assert_eq!(mapped.notes, ["Seen on staging"]);
+    assert_eq!(
+        mapped.url.as_deref(),
+        Some("https://gitlab.com/acme/app/-/issues/12")
+    );
+}
+
+#[test]
+fn gitlab_links_become_blocking_deps_within_the_project() {
+    let mut record = gitlab_issue(5, "Middle");
+    record["state"] = "closed".into();
+    record["links"] = serde_json::json!([
+        {"iid": 4, "project_id": 7, "link_type": "is_blocked_by"},
+        {"iid": 6, "project_id": 7, "link_type": "blocks"},
+        {"iid": 8, "project_id": 7, "link_type": "relates_to"},
+        {"iid": 9, "project_id": 99, "link_type": "blocks"},
+    ]);
+
+    let records = Forge::Gitlab.records(&record.to_string()).unwrap();
+    let mapped = Forge::Gitlab
+        .mapping("prj")
+        .unwrap()
+        .convert(&records[0])
+        .unwrap();
+
+    assert_eq!(mapped.issue.status, Status::Done);
+    assert_eq!(
+        mapped.deps,
+        [
+            ("prj-5".to_string(), "prj-6".to_string(), Relation::Blocks),
+            ("prj-4".to_string(), "prj-5".to_string(), Relation::Blocks),
+        ]
+    );
+}
+
+#[test]
+fn gitea_issue_converts_and_pull_requests_are_skipped() {
+    let text = serde_json::json!([
+        {
+            "number": 3,
+            "title": "Crash on start",
+            "body": "Panics",
+            "state": "open",
+            "labels": [{"name": "bug"}],
+            "assignee": {"login": "bo"},
+            "comments": [{"body": "Confirmed"}],
+            "created_at": "2026-03-01T10:00:00+01:00",
+            "updated_at": "2026-03-01T12:00:00+01:00",
+            "html_url": "https://git.example.com/acme/app/issues/3",
+            "repository": {"full_name": "acme/app"},
+            "pull_request": null,
+            "dependencies": [
+                {"number": 1, "repository": {"full_name": "acme/app"}},
+                {"number": 2, "repository": {"full_name": "acme/other"}},
+            ],
+        },
+        {
+            "number": 4,
+            "title": "Fix crash",
+            "state": "open",
+            "pull_request": {"merged": false},
+        },
+    ])
+    .to_string();
+
+    let records = Forge::Gitea.records(&text).unwrap();
+    assert_eq!(records.len(), 1);
+    let mapped = Forge::Gitea
+        .mapping("prj")
+        .unwrap()
+        .convert(&records[0])
+        .unwrap();
+
+    assert_eq!(mapped.issue.id, "prj-3");
+    assert_eq!(mapped.external_id, "acme/app#3");
+    assert_eq!(mapped.issue.description.as_deref(), Some("Panics"));
+    assert_eq!(mapped.issue.assignee.as_deref(), Some("bo"));
+    assert_eq!(mapped.labels, ["bug"]);
+    assert_eq!(mapped.notes, ["Confirmed"]);
+    assert_eq!(
+        mapped.deps,
+        [("prj-1".to_string(), "prj-3".to_string(), Relation::Blocks)]
+    );
+}
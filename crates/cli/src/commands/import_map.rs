@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Field mappings for `wok import --map`: how records of another tracker's
+//! JSONL export become wok issues.
+//!
+//! A mapping file names where each field is found in a record, as a dotted
+//! path (`fields.status.name`; `[]` after a key flattens an array, as in
+//! `fields.components[].name`), and how values translate:
+//!
+//! ```toml
+//! source = "jira"                # where records come from (default: file name)
+//! prefix = "eng"                 # give every issue this ID prefix
+//!
+//! [fields]
+//! id = "key"
+//! title = "fields.summary"
+//! description = "fields.description"
+//! status = "fields.status.name"
+//! type = "fields.issuetype.name"
+//! assignee = "fields.assignee.emailAddress"
+//! labels = "fields.labels"
+//! notes = "fields.comment.comments[].body"
+//! created_at = "fields.created"
+//! updated_at = "fields.updated"
+//! url = "fields.self"            # recorded as a link to the issue
+//!
+//! [status]                       # source value -> wok status; "*" for the rest
+//! "To Do" = "todo"
+//! "In Review" = "in_progress"
+//!
+//! [type]
+//! Story = "feature"
+//!
+//! [assignee]
+//! "jdoe@example.com" = "john"
+//!
+//! [labels]
+//! prefix = "jira:"               # prepended to labels from fields.labels
+//! [labels.fields]                # more labels, from other fields
+//! "fields.priority.name" = "priority:"
+//!
+//! [deps]
+//! blocked_by = "blocked_by[]"
+//! tracked_by = "fields.parent.key"
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::id::validate_prefix;
+use crate::models::{Issue, IssueType, LinkType, Relation, Status};
+
+/// Map key that matches every source value not listed.
+const FALLBACK: &str = "*";
+
+/// A `--map` file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Mapping {
+    /// Name of the tracker records come from, kept with each issue so that
+    /// importing a record again finds it.
+    #[serde(default)]
+    source: Option<String>,
+    /// Prefix every imported ID (and dependency) is given.
+    #[serde(default)]
+    prefix: Option<String>,
+    fields: FieldPaths,
+    #[serde(default)]
+    status: BTreeMap<String, Status>,
+    #[serde(default, rename = "type")]
+    types: BTreeMap<String, IssueType>,
+    #[serde(default)]
+    assignee: BTreeMap<String, String>,
+    #[serde(default)]
+    labels: LabelRules,
+    #[serde(default)]
+    deps: DepPaths,
+}
+
+/// Where each issue field is found in a record.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldPaths {
+    id: String,
+    title: String,
+    description: Option<String>,
+    status: Option<String>,
+    #[serde(rename = "type")]
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    labels: Option<String>,
+    notes: Option<String>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+    url: Option<String>,
+}
+
+/// How labels are named.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LabelRules {
+    /// Prepended to each label from `fields.labels`.
+    #[serde(default)]
+    prefix: String,
+    /// Paths of more fields to turn into labels, with the prefix each gets.
+    #[serde(default)]
+    fields: BTreeMap<String, String>,
+}
+
+/// Paths of the IDs a record depends on, by relation.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DepPaths {
+    blocks: Option<String>,
+    blocked_by: Option<String>,
+    tracks: Option<String>,
+    tracked_by: Option<String>,
+}
+
+/// One record converted by a [`Mapping`].
+#[derive(Debug)]
+pub(crate) struct MappedIssue {
+    pub issue: Issue,
+    /// The record's ID in its source tracker.
+    pub external_id: String,
+    pub labels: Vec<String>,
+    pub notes: Vec<String>,
+    /// `(from, to, relation)`, as stored.
+    pub deps: Vec<(String, String, Relation)>,
+    /// The record's page in its source tracker.
+    pub url: Option<String>,
+}
+
+impl Mapping {
+    /// Read and check a mapping file.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let invalid = |reason: String| Error::InvalidMapping {
+            path: path.display().to_string(),
+            reason,
+        };
+        let content = std::fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let mut mapping: Mapping = toml::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+        if mapping.source.is_none() {
+            mapping.source = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string());
+        }
+        if let Some(prefix) = &mapping.prefix {
+            if !validate_prefix(prefix) {
+                return Err(invalid(format!(
+                    "prefix '{}' must be 2+ lowercase alphanumeric with at least one letter",
+                    prefix
+                )));
+            }
+        }
+        Ok(mapping)
+    }
+
+    /// Give every imported ID (and dependency) `prefix`.
+    pub(crate) fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// The tracker records come from, as the type of their import links.
+    pub(crate) fn source(&self) -> LinkType {
+        LinkType::from_name(self.source.as_deref().unwrap_or("import"))
+    }
+
+    /// Convert one record. Errors name the field that could not be read.
+    pub(crate) fn convert(&self, record: &Value) -> std::result::Result<MappedIssue, String> {
+        let fields = &self.fields;
+        let external_id = required(record, "id", &fields.id)?;
+        let id = self.issue_id(&external_id);
+        let title = required(record, "title", &fields.title)?;
+
+        let status = match optional(record, fields.status.as_deref()) {
+            Some(value) => translate(&self.status, &value)
+                .ok_or_else(|| format!("unknown status '{}'; map it under [status]", value))?,
+            None => Status::Todo,
+        };
+        let issue_type = match optional(record, fields.issue_type.as_deref()) {
+            Some(value) => translate(&self.types, &value)
+                .ok_or_else(|| format!("unknown type '{}'; map it under [type]", value))?,
+            None => IssueType::Task,
+        };
+        let assignee = optional(record, fields.assignee.as_deref())
+            .map(|who| lookup(&self.assignee, &who).cloned().unwrap_or(who));
+
+        let created_at = match optional(record, fields.created_at.as_deref()) {
+            Some(value) => parse_time(&value).map_err(|e| format!("created_at: {}", e))?,
+            None => Utc::now(),
+        };
+        let updated_at = match optional(record, fields.updated_at.as_deref()) {
+            Some(value) => parse_time(&value).map_err(|e| format!("updated_at: {}", e))?,
+            None => created_at,
+        };
+
+        let mut labels: Vec<String> = strings(record, fields.labels.as_deref())
+            .into_iter()
+            .map(|label| format!("{}{}", self.labels.prefix, label))
+            .collect();
+        for (path, prefix) in &self.labels.fields {
+            for value in strings(record, Some(path)) {
+                labels.push(format!("{}{}", prefix, value));
+            }
+        }
+        let mut seen = std::collections::HashSet::new();
+        labels.retain(|label| seen.insert(label.clone()));
+
+        let mut deps = Vec::new();
+        let paths = &self.deps;
+        for (path, relation, outgoing) in [
+            (&paths.blocks, Relation::Blocks, true),
+            (&paths.blocked_by, Relation::Blocks, false),
+            (&paths.tracks, Relation::Tracks, true),
+            (&paths.tracked_by, Relation::Tracks, false),
+        ] {
+            for other in strings(record, path.as_deref()) {
+                let other = self.issue_id(&other);
+                deps.push(match outgoing {
+                    true => (id.clone(), other, relation),
+                    false => (other, id.clone(), relation),
+                });
+            }
+        }
+
+        Ok(MappedIssue {
+            issue: Issue {
+                id,
+                issue_type,
+                title,
+                description: optional(record, fields.description.as_deref()),
+                status,
+                assignee,
+                created_at,
+                updated_at,
+                closed_at: status.is_terminal().then_some(updated_at),
+                last_status_hlc: None,
+                last_title_hlc: None,
+                last_type_hlc: None,
+                last_description_hlc: None,
+                last_assignee_hlc: None,
+                revision: 1,
+            },
+            external_id,
+            labels,
+            notes: strings(record, fields.notes.as_deref()),
+            deps,
+            url: optional(record, fields.url.as_deref()),
+        })
+    }
+
+    /// The wok ID of a source ID: with a `prefix`, the prefix and the part
+    /// after the source's own (`ENG-12`, `group/app#12` -> `eng-12`).
+    fn issue_id(&self, source: &str) -> String {
+        match &self.prefix {
+            Some(prefix) => {
+                let rest = source
+                    .rsplit_once(['-', '#'])
+                    .map_or(source, |(_, rest)| rest);
+                format!("{}-{}", prefix, rest.to_lowercase())
+            }
+            None => source.to_string(),
+        }
+    }
+}
+
+/// The values at a dotted `path`; `key[]` steps into every element of an
+/// array. Missing keys and nulls yield nothing.
+fn resolve<'a>(record: &'a Value, path: &str) -> Vec<&'a Value> {
+    let mut values = vec![record];
+    for segment in path.split('.') {
+        let (key, each) = match segment.strip_suffix("[]") {
+            Some(key) => (key, true),
+            None => (segment, false),
+        };
+        values = values
+            .into_iter()
+            .filter_map(|value| match key {
+                "" => Some(value),
+                key => value.get(key),
+            })
+            .flat_map(|value| match (each, value) {
+                (true, Value::Array(items)) => items.iter().collect(),
+                _ => vec![value],
+            })
+            .filter(|value| !value.is_null())
+            .collect();
+    }
+    values
+}
+
+/// The text of every value at `path`, arrays flattened.
+fn strings(record: &Value, path: Option<&str>) -> Vec<String> {
+    let Some(path) = path else {
+        return Vec::new();
+    };
+    resolve(record, path)
+        .into_iter()
+        .flat_map(|value| match value {
+            Value::Array(items) => items.iter().filter_map(text).collect::<Vec<_>>(),
+            value => text(value).into_iter().collect(),
+        })
+        .collect()
+}
+
+/// The first text value at `path`.
+fn optional(record: &Value, path: Option<&str>) -> Option<String> {
+    strings(record, path).into_iter().next()
+}
+
+fn required(record: &Value, field: &str, path: &str) -> std::result::Result<String, String> {
+    optional(record, Some(path)).ok_or_else(|| format!("no {} at '{}'", field, path))
+}
+
+/// Strings as they are, numbers and booleans as written; nothing else.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// The entry for `value`, ignoring case, else the `*` entry.
+fn lookup<'a, T>(map: &'a BTreeMap<String, T>, value: &str) -> Option<&'a T> {
+    map.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(value))
+        .or_else(|| map.iter().find(|(key, _)| *key == FALLBACK))
+        .map(|(_, target)| target)
+}
+
+/// A mapped value, or the source value when it already names one.
+fn translate<T: Copy + std::str::FromStr>(map: &BTreeMap<String, T>, value: &str) -> Option<T> {
+    map.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(value))
+        .map(|(_, target)| *target)
+        .or_else(|| value.to_lowercase().parse().ok())
+        .or_else(|| lookup(map, value).copied())
+}
+
+/// RFC 3339, or the `+0000` offsets some trackers write.
+fn parse_time(value: &str) -> std::result::Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| format!("'{}': {}", value, e))
+}
+
+#[cfg(test)]
+#[path = "import_map_tests.rs"]
+mod tests;
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use serde_json::json;
+
+const JIRA: &str = r#"
+prefix = "eng"
+
+[fields]
+id = "key"
+title = "fields.summary"
+description = "fields.description"
+status = "fields.status.name"
+type = "fields.issuetype.name"
+assignee = "fields.assignee.emailAddress"
+labels = "fields.labels"
+notes = "fields.comment.comments[].body"
+created_at = "fields.created"
+updated_at = "fields.updated"
+
+[status]
+"To Do" = "todo"
+"In Review" = "in_progress"
+"Won't Do" = "closed"
+
+[type]
+Story = "feature"
+"*" = "task"
+
+[assignee]
+"jdoe@example.com" = "john"
+
+[labels]
+prefix = "jira:"
+[labels.fields]
+"fields.priority.name" = "priority:"
+"fields.components[].name" = "component:"
+
+[deps]
+blocked_by = "fields.issuelinks[].inwardIssue.key"
+tracked_by = "fields.parent.key"
+"#;
+
+fn mapping(toml: &str) -> Mapping {
+    toml::from_str(toml).unwrap()
+}
+
+fn jira_record() -> Value {
+    json!({
+        "key": "ENG-12",
+        "fields": {
+            "summary": "Fix login",
+            "description": "Users are logged out",
+            "status": {"name": "In Review"},
+            "issuetype": {"name": "Story"},
+            "assignee": {"emailAddress": "jdoe@example.com"},
+            "labels": ["backend", "auth"],
+            "priority": {"name": "High"},
+            "components": [{"name": "api"}, {"name": "web"}],
+            "comment": {"comments": [{"body": "Repro attached"}, {"body": "On it"}]},
+            "created": "2024-03-01T09:00:00.000+0000",
+            "updated": "2024-03-02T10:30:00Z",
+            "issuelinks": [{"inwardIssue": {"key": "ENG-7"}}, {"outwardIssue": {"key": "ENG-9"}}],
+            "parent": {"key": "ENG-1"}
+        }
+    })
+}
+
+#[test]
+fn convert_maps_every_field() {
+    let mapped = mapping(JIRA).convert(&jira_record()).unwrap();
+
+    let issue = &mapped.issue;
+    assert_eq!(issue.id, "eng-12");
+    assert_eq!(issue.title, "Fix login");
+    assert_eq!(issue.description.as_deref(), Some("Users are logged out"));
+    assert_eq!(issue.status, Status::InProgress);
+    assert_eq!(issue.issue_type, IssueType::Feature);
+    assert_eq!(issue.assignee.as_deref(), Some("john"));
+    assert_eq!(issue.created_at.to_rfc3339(), "2024-03-01T09:00:00+00:00");
+    assert_eq!(issue.updated_at.to_rfc3339(), "2024-03-02T10:30:00+00:00");
+    assert_eq!(issue.closed_at, None);
+    assert_eq!(
+        mapped.labels,
+        [
+            "jira:backend",
+            "jira:auth",
+            "component:api",
+            "component:web",
+            "priority:High"
+        ]
+    );
+    assert_eq!(mapped.notes, ["Repro attached", "On it"]);
+    assert_eq!(
+        mapped.deps,
+        [
+            ("eng-7".to_string(), "eng-12".to_string(), Relation::Blocks),
+            ("eng-1".to_string(), "eng-12".to_string(), Relation::Tracks),
+        ]
+    );
+}
+
+#[test]
+fn values_that_name_wok_ones_need_no_mapping() {
+    let record = json!({"key": "ENG-3", "fields": {"summary": "Done already", "status": {"name": "Done"}, "issuetype": {"name": "Bug"}}});
+
+    let issue = mapping(JIRA).convert(&record).unwrap().issue;
+
+    assert_eq!(issue.status, Status::Done);
+    assert_eq!(issue.issue_type, IssueType::Bug);
+    assert_eq!(issue.closed_at, Some(issue.updated_at));
+}
+
+#[test]
+fn fallback_maps_unlisted_values() {
+    let record =
+        json!({"key": "ENG-4", "fields": {"summary": "Odd", "issuetype": {"name": "Spike"}}});
+
+    let issue = mapping(JIRA).convert(&record).unwrap().issue;
+
+    assert_eq!(issue.issue_type, IssueType::Task);
+    assert_eq!(issue.status, Status::Todo);
+}
+
+#[test]
+fn unmapped_status_is_an_error() {
+    let record =
+        json!({"key": "ENG-5", "fields": {"summary": "Odd", "status": {"name": "Triage"}}});
+
+    let err = mapping(JIRA).convert(&record).unwrap_err();
+
+    assert_eq!(err, "unknown status 'Triage'; map it under [status]");
+}
+
+#[test]
+fn missing_title_is_an_error() {
+    let record = json!({"key": "ENG-6", "fields": {}});
+
+    let err = mapping(JIRA).convert(&record).unwrap_err();
+
+    assert_eq!(err, "no title at 'fields.summary'");
+}
+
+#[test]
+fn ids_are_kept_without_a_prefix() {
+    let mapping = mapping(
+        r#"
+[fields]
+id = "number"
+title = "title"
+labels = "labels[].name"
+[deps]
+blocks = "blocks"
+"#,
+    );
+    let record = json!({"number": "gh-42", "title": "Crash", "labels": [{"name": "bug"}], "blocks": ["gh-40", "gh-41"]});
+
+    let mapped = mapping.convert(&record).unwrap();
+
+    assert_eq!(mapped.issue.id, "gh-42");
+    assert_eq!(mapped.labels, ["bug"]);
+    assert_eq!(
+        mapped.deps,
+        [
+            ("gh-42".to_string(), "gh-40".to_string(), Relation::Blocks),
+            ("gh-42".to_string(), "gh-41".to_string(), Relation::Blocks),
+        ]
+    );
+}
+
+#[test]
+fn resolve_flattens_arrays_and_skips_nulls() {
+    let record = json!({"a": [{"b": 1}, {"b": null}, {"c": 2}, {"b": 3}]});
+
+    let values = resolve(&record, "a[].b");
+
+    assert_eq!(values, [&json!(1), &json!(3)]);
+}
+
+#[test]
+fn load_rejects_unknown_statuses_and_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("map.toml");
+
+    std::fs::write(
+        &path,
+        "[fields]\nid = \"id\"\ntitle = \"t\"\n[status]\nopen = \"wip\"\n",
+    )
+    .unwrap();
+    assert!(matches!(
+        Mapping::load(&path),
+        Err(Error::InvalidMapping { .. })
+    ));
+
+    std::fs::write(
+        &path,
+        "[fields]\nid = \"id\"\ntitle = \"t\"\nowner = \"o\"\n",
+    )
+    .unwrap();
+    assert!(matches!(
+        Mapping::load(&path),
+        Err(Error::InvalidMapping { .. })
+    ));
+
+    std::fs::write(
+        &path,
+        "prefix = \"E\"\n[fields]\nid = \"id\"\ntitle = \"t\"\n",
+    )
+    .unwrap();
+    assert!(matches!(
+        Mapping::load(&path),
+        Err(Error::InvalidMapping { .. })
+    ));
+
+    std::fs::write(&path, JIRA).unwrap();
+    assert!(Mapping::load(&path).is_ok());
+}
@@ -28,6 +28,7 @@ fn test_detect_format_explicit() {
 fn test_detect_format_auto() {
     assert_eq!(detect_format(".beads/issues.jsonl", "wok"), "bd");
     assert_eq!(detect_format("path/to/.beads/issues.jsonl", "wok"), "bd");
+    assert_eq!(detect_format("backlog.CSV", "wok"), "csv");
     assert_eq!(detect_format("beads.jsonl", "wok"), "wok");
 }
 
@@ -78,7 +79,7 @@ fn test_convert_beads_issue() {
         title: "Test".to_string(),
         description: None,
         status: "open".to_string(),
-        priority: 2,
+        priority: Some(2),
         issue_type: "bug".to_string(),
         created_at: "2024-01-01T00:00:00Z".to_string(),
         updated_at: "2024-01-01T00:00:00Z".to_string(),
@@ -86,13 +87,20 @@ fn test_convert_beads_issue() {
         dependencies: vec![],
         comments: vec![BeadsComment {
             text: "A comment".to_string(),
+            author: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
         }],
         close_reason: None,
         delete_reason: None,
+        ..Default::default()
     };
 
-    let (issue, labels, notes, _deps, _close_data, _links) = convert_beads_issue(bd).unwrap();
+    let ImportedIssue {
+        issue,
+        labels,
+        notes,
+        ..
+    } = convert_beads_issue(bd).unwrap();
     assert_eq!(issue.id, "bd-1");
     assert_eq!(issue.issue_type, IssueType::Bug);
     assert_eq!(issue.status, Status::Todo);
@@ -100,7 +108,7 @@ fn test_convert_beads_issue() {
     assert!(labels.contains(&"urgent".to_string()));
     assert!(labels.contains(&"priority:2".to_string()));
     assert_eq!(notes.len(), 1);
-    assert_eq!(notes[0].1, "A comment");
+    assert_eq!(notes[0].content, "A comment");
 }
 
 #[test]
@@ -110,7 +118,7 @@ fn test_convert_beads_issue_tombstone() {
         title: "Deleted issue".to_string(),
         description: None,
         status: "tombstone".to_string(),
-        priority: 2,
+        priority: Some(2),
         issue_type: "task".to_string(),
         created_at: "2024-01-01T00:00:00Z".to_string(),
         updated_at: "2024-01-01T00:00:00Z".to_string(),
@@ -119,9 +127,15 @@ fn test_convert_beads_issue_tombstone() {
         comments: vec![],
         close_reason: None,
         delete_reason: Some("batch delete".to_string()),
+        ..Default::default()
     };
 
-    let (issue, _labels, notes, _deps, close_data, _links) = convert_beads_issue(bd).unwrap();
+    let ImportedIssue {
+        issue,
+        notes,
+        close_data,
+        ..
+    } = convert_beads_issue(bd).unwrap();
     assert_eq!(issue.id, "bd-tomb");
     assert_eq!(issue.status, Status::Closed);
 
@@ -131,7 +145,7 @@ fn test_convert_beads_issue_tombstone() {
     assert!(close_data.is_failure);
 
     // Should have a note with the delete reason
-    assert!(notes.iter().any(|n| n.1 == "batch delete"));
+    assert!(notes.iter().any(|n| n.content == "batch delete"));
 }
 
 #[test]
@@ -151,7 +165,8 @@ fn test_import_creates_issue() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -180,7 +195,8 @@ fn test_import_with_labels() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -194,6 +210,38 @@ fn test_import_with_labels() {
     assert!(labels.contains(&"project:auth".to_string()));
 }
 
+#[test]
+fn test_import_preserves_note_kinds() {
+    let (mut db, _dir) = setup_test_db();
+    let config = dummy_config();
+
+    let import_file = _dir.path().join("import.jsonl");
+    std::fs::write(
+        &import_file,
+        r#"{"id":"test-kinds","issue_type":"task","title":"Kinds","status":"todo","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","labels":[],"notes":[{"id":1,"issue_id":"test-kinds","status":"todo","content":"Plain","created_at":"2024-01-01T00:00:00Z"},{"id":2,"issue_id":"test-kinds","status":"todo","kind":"decision","content":"Use FTS","created_at":"2024-01-01T00:00:01Z"}],"deps":[],"events":[]}"#,
+    )
+    .unwrap();
+
+    run_impl(
+        &mut db,
+        &config,
+        import_file.to_str().unwrap(),
+        &Source::Wok,
+        OnConflict::Update,
+        false,
+        vec![],
+        vec![],
+        vec![],
+        None,
+    )
+    .unwrap();
+
+    let notes = db.get_notes("test-kinds").unwrap();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].kind, None);
+    assert_eq!(notes[1].kind, Some(NoteKind::Decision));
+}
+
 #[test]
 fn test_import_dry_run() {
     let (mut db, _dir) = setup_test_db();
@@ -210,7 +258,8 @@ fn test_import_dry_run() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         true, // dry_run
         vec![],
         vec![],
@@ -240,7 +289,8 @@ fn test_import_status_filter() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec!["todo".to_string()],
         vec![],
@@ -271,7 +321,8 @@ fn test_import_prefix_filter() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -306,6 +357,7 @@ fn test_import_updates_existing() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&issue).unwrap();
 
@@ -321,7 +373,8 @@ fn test_import_updates_existing() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -355,6 +408,7 @@ fn test_import_updates_existing_status() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&issue).unwrap();
 
@@ -370,7 +424,8 @@ fn test_import_updates_existing_status() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -399,7 +454,8 @@ fn test_import_beads_format() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -429,7 +485,8 @@ fn test_import_empty_file() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -458,7 +515,8 @@ fn test_import_skips_empty_lines() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -483,7 +541,8 @@ fn test_import_invalid_json_fails() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -510,7 +569,8 @@ fn test_import_chore_type() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "wok",
+        &Source::Wok,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -540,7 +600,8 @@ fn test_import_beads_chore_type() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -570,7 +631,8 @@ fn test_import_beads_epic_preserved() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -706,7 +768,8 @@ fn test_import_beads_priority_as_label() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -735,7 +798,8 @@ fn test_import_beads_priority_zero_not_labeled() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -764,7 +828,8 @@ fn test_beads_comment_text_field() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -795,7 +860,8 @@ fn test_beads_comment_content_alias() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -826,7 +892,8 @@ fn test_import_beads_close_reason_failure() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -860,7 +927,8 @@ fn test_import_beads_close_reason_success() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -895,7 +963,8 @@ fn test_import_beads_tombstone_status() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -929,7 +998,8 @@ fn test_import_beads_tombstone_without_delete_reason() {
         &mut db,
         &config,
         import_file.to_str().unwrap(),
-        "bd",
+        &Source::Beads,
+        OnConflict::Update,
         false,
         vec![],
         vec![],
@@ -947,3 +1017,575 @@ fn test_import_beads_tombstone_without_delete_reason() {
         .iter()
         .any(|n| n.content == "deleted" && n.status == Status::Closed));
 }
+
+// === Prefix remapping (wok init --from) ===
+
+fn beads_line(id: &str, blocked_by: &[&str]) -> String {
+    let deps: Vec<String> = blocked_by
+        .iter()
+        .map(|d| format!(r#"{{"depends_on_id":"{}","type":"blocks"}}"#, d))
+        .collect();
+    format!(
+        r#"{{"id":"{}","title":"Issue {}","status":"open","issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","dependencies":[{}]}}"#,
+        id,
+        id,
+        deps.join(",")
+    )
+}
+
+fn read_beads(dir: &TempDir, lines: &[String]) -> ImportFile {
+    let import_file = dir.path().join("import.jsonl");
+    std::fs::write(&import_file, lines.join("\n")).unwrap();
+    ImportFile::read(import_file.to_str().unwrap(), &Source::Beads).unwrap()
+}
+
+#[test]
+fn test_remap_prefix_renames_issues_and_deps() {
+    let (mut db, dir) = setup_test_db();
+    let mut file = read_beads(
+        &dir,
+        &[beads_line("bd-a1", &[]), beads_line("bd-b2", &["bd-a1"])],
+    );
+
+    let remap = file.remap_prefix("proj");
+    assert_eq!(remap.renamed, 2);
+    assert_eq!(remap.from.into_iter().collect::<Vec<_>>(), vec!["bd"]);
+    assert!(remap.kept.is_empty());
+
+    file.import(&mut db).unwrap();
+    assert_eq!(db.get_issue("proj-b2").unwrap().title, "Issue bd-b2");
+    assert!(db.get_issue("bd-a1").is_err());
+    let blockers = db.get_blockers("proj-b2").unwrap();
+    assert_eq!(blockers, vec!["proj-a1"]);
+}
+
+#[test]
+fn test_remap_prefix_keeps_ids_whose_new_form_is_taken() {
+    let (_db, dir) = setup_test_db();
+    let mut file = read_beads(
+        &dir,
+        &[
+            beads_line("proj-a1", &[]),
+            beads_line("bd-a1", &[]),
+            beads_line("bd-c3", &[]),
+        ],
+    );
+
+    let remap = file.remap_prefix("proj");
+    assert_eq!(remap.renamed, 1);
+    assert_eq!(remap.kept, vec!["bd-a1"]);
+}
+
+#[test]
+fn test_import_with_mapping() {
+    let (mut db, dir) = setup_test_db();
+    let config = dummy_config();
+    let import_file = dir.path().join("tracker.jsonl");
+    std::fs::write(
+        &import_file,
+        [
+            r#"{"key":"ENG-1","summary":"Epic","kind":"Epic","state":"Open"}"#,
+            r#"{"key":"ENG-2","summary":"Child","kind":"Story","state":"Shipped","parent":"ENG-1","tags":["ui"]}"#,
+        ]
+        .join("\n"),
+    )
+    .unwrap();
+    let mapping: Mapping = toml::from_str(
+        r#"
+prefix = "eng"
+[fields]
+id = "key"
+title = "summary"
+type = "kind"
+status = "state"
+labels = "tags"
+[status]
+Open = "todo"
+Shipped = "done"
+[type]
+Story = "feature"
+[deps]
+tracked_by = "parent"
+"#,
+    )
+    .unwrap();
+
+    run_impl(
+        &mut db,
+        &config,
+        import_file.to_str().unwrap(),
+        &Source::Mapped(&mapping),
+        OnConflict::Update,
+        false,
+        vec![],
+        vec![],
+        vec![],
+        None,
+    )
+    .unwrap();
+
+    let child = db.get_issue("eng-2").unwrap();
+    assert_eq!(child.issue_type, IssueType::Feature);
+    assert_eq!(child.status, Status::Done);
+    assert_eq!(db.get_labels("eng-2").unwrap(), vec!["ui"]);
+    assert_eq!(db.get_issue("eng-1").unwrap().issue_type, IssueType::Epic);
+    assert_eq!(db.get_tracked("eng-1").unwrap(), vec!["eng-2"]);
+}
+
+fn import_beads(db: &mut Database, dir: &TempDir, lines: &[String], on_conflict: OnConflict) {
+    let import_file = dir.path().join("import.jsonl");
+    std::fs::write(&import_file, lines.join("\n")).unwrap();
+    run_impl(
+        db,
+        &dummy_config(),
+        import_file.to_str().unwrap(),
+        &Source::Beads,
+        on_conflict,
+        false,
+        vec![],
+        vec![],
+        vec![],
+        None,
+    )
+    .unwrap();
+}
+
+fn issue_ids(db: &Database) -> Vec<String> {
+    let mut ids: Vec<String> = db
+        .list_issues(None, None, None)
+        .unwrap()
+        .into_iter()
+        .map(|i| i.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_import_records_beads_id_as_import_link() {
+    let (mut db, dir) = setup_test_db();
+    import_beads(
+        &mut db,
+        &dir,
+        &[beads_line("bd-a1", &[])],
+        OnConflict::Update,
+    );
+    import_beads(
+        &mut db,
+        &dir,
+        &[beads_line("bd-a1", &[])],
+        OnConflict::Update,
+    );
+
+    let links = db.get_links("bd-a1").unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].rel, Some(LinkRel::Import));
+    assert_eq!(
+        links[0].link_type,
+        Some(LinkType::Other("beads".to_string()))
+    );
+    assert_eq!(links[0].external_id.as_deref(), Some("bd-a1"));
+}
+
+#[test]
+fn test_reimport_after_prefix_remap_finds_issues_by_import_link() {
+    let (mut db, dir) = setup_test_db();
+    let lines = [beads_line("bd-a1", &[]), beads_line("bd-b2", &["bd-a1"])];
+    let mut file = read_beads(&dir, &lines);
+    file.remap_prefix("proj");
+    file.import(&mut db).unwrap();
+
+    import_beads(&mut db, &dir, &lines, OnConflict::Skip);
+
+    assert_eq!(issue_ids(&db), vec!["proj-a1", "proj-b2"]);
+}
+
+#[test]
+fn test_on_conflict_skip_leaves_existing_issue() {
+    let (mut db, dir) = setup_test_db();
+    import_beads(
+        &mut db,
+        &dir,
+        &[beads_line("bd-a1", &[])],
+        OnConflict::Update,
+    );
+    db.update_issue_title("bd-a1", "Edited locally").unwrap();
+
+    import_beads(&mut db, &dir, &[beads_line("bd-a1", &[])], OnConflict::Skip);
+
+    assert_eq!(db.get_issue("bd-a1").unwrap().title, "Edited locally");
+}
+
+#[test]
+fn test_on_conflict_update_follows_import_link() {
+    let (mut db, dir) = setup_test_db();
+    let mut file = read_beads(&dir, &[beads_line("bd-a1", &[])]);
+    file.remap_prefix("proj");
+    file.import(&mut db).unwrap();
+    db.update_issue_title("proj-a1", "Edited locally").unwrap();
+
+    import_beads(
+        &mut db,
+        &dir,
+        &[beads_line("bd-a1", &[])],
+        OnConflict::Update,
+    );
+
+    assert_eq!(issue_ids(&db), vec!["proj-a1"]);
+    assert_eq!(db.get_issue("proj-a1").unwrap().title, "Issue bd-a1");
+}
+
+#[test]
+fn test_on_conflict_duplicate_creates_fresh_issues() {
+    let (mut db, dir) = setup_test_db();
+    let lines = [beads_line("bd-a1", &[]), beads_line("bd-b2", &["bd-a1"])];
+    import_beads(&mut db, &dir, &lines, OnConflict::Update);
+
+    import_beads(&mut db, &dir, &lines, OnConflict::Duplicate);
+
+    let copies: Vec<Issue> = issue_ids(&db)
+        .iter()
+        .filter(|id| !["bd-a1", "bd-b2"].contains(&id.as_str()))
+        .map(|id| db.get_issue(id).unwrap())
+        .collect();
+    assert_eq!(copies.len(), 2);
+    let copy_of = |title: &str| copies.iter().find(|i| i.title == title).unwrap().id.clone();
+    let (a1, b2) = (copy_of("Issue bd-a1"), copy_of("Issue bd-b2"));
+    assert!(a1.starts_with("bd-") && b2.starts_with("bd-"));
+    assert_eq!(db.get_blockers(&b2).unwrap(), vec![a1]);
+    assert_eq!(db.get_blockers("bd-b2").unwrap(), vec!["bd-a1"]);
+}
+
+/// Everything stored about each issue, leaving out database-assigned IDs.
+fn snapshot(db: &Database) -> Vec<String> {
+    issue_ids(db)
+        .iter()
+        .map(|id| {
+            let notes: Vec<_> = db
+                .get_notes(id)
+                .unwrap()
+                .into_iter()
+                .map(|n| (n.status, n.kind, n.content, n.created_at))
+                .collect();
+            let mut deps: Vec<_> = db
+                .get_deps_from(id)
+                .unwrap()
+                .into_iter()
+                .map(|d| (d.to_id, d.relation, d.created_at))
+                .collect();
+            deps.sort_by(|a, b| a.0.cmp(&b.0));
+            let links: Vec<_> = db
+                .get_links(id)
+                .unwrap()
+                .into_iter()
+                .map(|l| (l.link_type, l.url, l.external_id, l.rel, l.created_at))
+                .collect();
+            let events: Vec<_> = db
+                .get_events(id)
+                .unwrap()
+                .into_iter()
+                .map(|e| (e.action, e.old_value, e.new_value, e.reason, e.created_at))
+                .collect();
+            // Revisions count local changes, which an import does not replay
+            let issue = Issue {
+                revision: 0,
+                ..db.get_issue(id).unwrap()
+            };
+            format!(
+                "{:?}",
+                (
+                    issue,
+                    db.get_labels(id).unwrap(),
+                    notes,
+                    deps,
+                    links,
+                    events
+                )
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_export_import_round_trip_reproduces_history() {
+    use crate::commands::testing::TestContext;
+
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Feature, "Search")
+        .create_issue("test-2", IssueType::Task, "Index titles")
+        .create_issue("test-3", IssueType::Bug, "Crash on empty query")
+        .tracks("test-1", "test-2")
+        .blocks("test-3", "test-2")
+        .blocks("test-1", "test-3")
+        .add_label("test-2", "team:search")
+        .add_note("test-2", "Use FTS5")
+        .start_issue("test-2")
+        .add_note("test-2", "Halfway")
+        .complete_issue("test-3");
+    ctx.db
+        .add_note_of_kind("test-3", Status::Done, Some(NoteKind::Handoff), "Fixed")
+        .unwrap();
+    ctx.db
+        .update_issue_description("test-1", "Full-text")
+        .unwrap();
+    let mut link = Link::new("test-1".to_string());
+    link.link_type = Some(LinkType::Github);
+    link.url = Some("https://github.com/org/repo/issues/7".to_string());
+    link.external_id = Some("7".to_string());
+    link.rel = Some(LinkRel::Tracks);
+    ctx.db.add_link(&link).unwrap();
+
+    let (mut db, dir) = setup_test_db();
+    let export_file = dir.path().join("export.jsonl");
+    let export_path = export_file.to_str().unwrap();
+    crate::commands::export::run_impl(&ctx.db, export_path, crate::cli::RecordFormat::Wok).unwrap();
+    run_impl(
+        &mut db,
+        &dummy_config(),
+        export_path,
+        &Source::Wok,
+        OnConflict::Update,
+        false,
+        vec![],
+        vec![],
+        vec![],
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(snapshot(&db), snapshot(&ctx.db));
+}
+
+#[test]
+fn test_import_rejects_newer_export_version() {
+    let (_db, dir) = setup_test_db();
+    let import_file = dir.path().join("import.jsonl");
+    std::fs::write(
+        &import_file,
+        r#"{"schema_version":99,"id":"test-v","issue_type":"task","title":"Future","status":"todo","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","labels":[],"notes":[],"deps":[],"events":[]}"#,
+    )
+    .unwrap();
+
+    let result = ImportFile::read(import_file.to_str().unwrap(), &Source::Wok);
+
+    assert!(matches!(
+        result,
+        Err(Error::ParseLineError { line: 1, reason }) if reason.contains("version 99")
+    ));
+}
+
+#[test]
+fn test_csv_reimport_updates_rows_by_id_column() {
+    let (mut db, dir) = setup_test_db();
+    let import_file = dir.path().join("backlog.csv");
+    let columns = CsvColumns::default();
+    let source = Source::Csv {
+        columns: &columns,
+        prefix: "test",
+    };
+    let import = |db: &mut Database, text: &str| {
+        std::fs::write(&import_file, text).unwrap();
+        run_impl(
+            db,
+            &dummy_config(),
+            import_file.to_str().unwrap(),
+            &source,
+            OnConflict::Update,
+            false,
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+        .unwrap();
+    };
+
+    import(&mut db, "Key,Title,Labels\nB-1,Ship it,a;b\nB-2,Test it,\n");
+    import(&mut db, "Key,Title,Labels\nB-1,Ship it now,a\n");
+
+    let ids = issue_ids(&db);
+    assert_eq!(ids.len(), 2);
+    let link = db
+        .find_import_link(&LinkType::from_name("backlog"), "B-1")
+        .unwrap()
+        .unwrap();
+    let issue = db.get_issue(&link.issue_id).unwrap();
+    assert_eq!(issue.title, "Ship it now");
+    assert_eq!(db.get_labels(&issue.id).unwrap(), ["a"]);
+}
+
+fn beads(json: &str) -> ImportedIssue {
+    convert_beads_issue(serde_json::from_str(json).unwrap()).unwrap()
+}
+
+#[test]
+fn test_convert_beads_full_record() {
+    let entry = beads(
+        r#"{"id":"bd-9","title":"Full","status":"closed","priority":1,"issue_type":"bug","assignee":"ann",
+            "created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-03T00:00:00Z","closed_at":"2024-01-02T00:00:00Z",
+            "design":"Use a queue","acceptance_criteria":"It works","notes":"",
+            "comments":[{"author":"bob","text":"Looks good","created_at":"2024-01-01T12:00:00Z"}],
+            "dependencies":[{"depends_on_id":"bd-2","type":"related","created_at":"2024-01-01T06:00:00Z"}],
+            "close_reason":"Fixed"}"#,
+    );
+
+    let issue = &entry.issue;
+    assert_eq!(issue.assignee.as_deref(), Some("ann"));
+    assert_eq!(
+        issue.closed_at.unwrap().to_rfc3339(),
+        "2024-01-02T00:00:00+00:00"
+    );
+    assert_eq!(entry.labels, ["priority:1"]);
+    let notes: Vec<(&str, Option<NoteKind>)> = entry
+        .notes
+        .iter()
+        .map(|n| (n.content.as_str(), n.kind))
+        .collect();
+    assert_eq!(
+        notes,
+        [
+            ("Design:\nUse a queue", Some(NoteKind::Decision)),
+            ("Acceptance criteria:\nIt works", None),
+            ("Looks good", None),
+            ("Fixed", None),
+        ]
+    );
+    assert_eq!(
+        entry.notes[2].created_at.unwrap().to_rfc3339(),
+        "2024-01-01T12:00:00+00:00"
+    );
+    let deps: Vec<(&str, &str, Relation)> = entry
+        .deps
+        .iter()
+        .map(|d| (d.from_id.as_str(), d.to_id.as_str(), d.relation))
+        .collect();
+    assert_eq!(
+        deps,
+        [
+            ("bd-9", "bd-2", Relation::RelatesTo),
+            ("bd-2", "bd-9", Relation::RelatesTo),
+        ]
+    );
+    assert!(entry.deps[0].created_at.is_some());
+    assert_eq!(entry.dropped, ["comment authors"]);
+}
+
+#[test]
+fn test_convert_beads_events_become_history() {
+    let entry = beads(
+        r#"{"id":"bd-h","title":"History","status":"closed","issue_type":"task",
+            "created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-04T00:00:00Z","close_reason":"wontfix",
+            "events":[
+              {"event_type":"created","actor":"ann","created_at":"2024-01-01T00:00:00Z"},
+              {"event_type":"status_changed","old_value":"open","new_value":"in_progress","created_at":"2024-01-02T00:00:00Z"},
+              {"event_type":"label_added","new_value":"backend","created_at":"2024-01-02T01:00:00Z"},
+              {"event_type":"compacted","created_at":"2024-01-03T00:00:00Z"},
+              {"event_type":"closed","comment":"wontfix","created_at":"2024-01-04T00:00:00Z"}
+            ]}"#,
+    );
+
+    let actions: Vec<Action> = entry.events.iter().map(|e| e.action).collect();
+    assert_eq!(
+        actions,
+        [
+            Action::Created,
+            Action::Started,
+            Action::Labeled,
+            Action::Closed
+        ]
+    );
+    assert_eq!(
+        entry.events[1].created_at.to_rfc3339(),
+        "2024-01-02T00:00:00+00:00"
+    );
+    assert_eq!(entry.events[3].reason.as_deref(), Some("wontfix"));
+    // The history's close event stands in for the synthesized one
+    assert!(entry.close_data.is_none());
+    assert_eq!(entry.dropped, ["event actors", "event 'compacted'"]);
+}
+
+#[test]
+fn test_convert_beads_event_status_changes() {
+    let event = |old: &str, new: &str| BeadsEvent {
+        event_type: "status_changed".to_string(),
+        actor: None,
+        old_value: Some(old.to_string()),
+        new_value: Some(new.to_string()),
+        comment: None,
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+    };
+
+    assert_eq!(
+        convert_beads_event(&event("in_progress", "open")),
+        Some(Action::Stopped)
+    );
+    assert_eq!(
+        convert_beads_event(&event("closed", "open")),
+        Some(Action::Reopened)
+    );
+    assert_eq!(
+        convert_beads_event(&event("open", "blocked")),
+        Some(Action::Stopped)
+    );
+    assert_eq!(
+        convert_beads_event(&event("open", "closed")),
+        Some(Action::Done)
+    );
+}
+
+#[test]
+fn test_convert_beads_reports_what_it_drops() {
+    let entry = beads(
+        r#"{"id":"bd-d","title":"Dropped","status":"open","priority":0,"issue_type":"task",
+            "created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z",
+            "estimated_minutes":30,"external_ref":null,
+            "dependencies":[{"depends_on_id":"bd-1","type":"discovered-from"}]}"#,
+    );
+
+    assert!(entry.labels.is_empty());
+    assert_eq!(
+        entry.dropped,
+        [
+            "field 'estimated_minutes'",
+            "priority 0",
+            "dependency type 'discovered-from' on bd-1 (imported as blocks)",
+        ]
+    );
+}
+
+#[test]
+fn test_import_beads_history_is_restored() {
+    let (mut db, dir) = setup_test_db();
+    let import_file = dir.path().join("import.jsonl");
+    std::fs::write(
+        &import_file,
+        r#"{"id":"bd-e","title":"Events","status":"closed","issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-02T00:00:00Z","close_reason":"Shipped","events":[{"event_type":"created","created_at":"2024-01-01T00:00:00Z"},{"event_type":"closed","comment":"Shipped","created_at":"2024-01-02T00:00:00Z"}]}"#,
+    )
+    .unwrap();
+    let import = |db: &mut Database| {
+        run_impl(
+            db,
+            &dummy_config(),
+            import_file.to_str().unwrap(),
+            &Source::Beads,
+            OnConflict::Update,
+            false,
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+        .unwrap();
+    };
+
+    import(&mut db);
+    import(&mut db);
+
+    let actions: Vec<Action> = db
+        .get_events("bd-e")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.action)
+        .collect();
+    assert_eq!(actions, [Action::Created, Action::Done]);
+}
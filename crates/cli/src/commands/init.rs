@@ -3,18 +3,39 @@
 
 use std::path::{Path, PathBuf};
 
+use crate::commands::import::{ImportFile, PrefixRemap, Source};
 use crate::completions;
-use crate::config::{init_work_dir, init_work_dir_private, write_gitignore};
+use crate::config::{
+    get_db_path, init_work_dir, init_work_dir_private, wok_state_dir, write_gitignore, Config,
+};
 use crate::db::Database;
 use crate::error::{Error, Result};
 use crate::id::validate_prefix;
+use crate::workspace::{self, Registry};
 
-pub fn run(prefix: Option<String>, path: Option<String>, private: bool) -> Result<()> {
+/// An export to import into the new tracker (`wok init --from`).
+pub struct ImportFrom {
+    pub file: String,
+    pub format: String,
+    pub keep_ids: bool,
+}
+
+pub fn run(
+    prefix: Option<String>,
+    path: Option<String>,
+    private: bool,
+    from: Option<ImportFrom>,
+    join: Option<String>,
+) -> Result<()> {
     let target_path = match path {
         Some(p) => PathBuf::from(p),
         None => std::env::current_dir()?,
     };
 
+    if let Some(target) = join {
+        return run_join(&target, prefix, &target_path, from);
+    }
+
     let prefix = match prefix {
         Some(p) => p,
         None => derive_prefix_from_path(&target_path)?,
@@ -25,6 +46,9 @@ pub fn run(prefix: Option<String>, path: Option<String>, private: bool) -> Resul
         return Err(Error::InvalidPrefix);
     }
 
+    // Read the export first so a bad file leaves nothing half-initialized.
+    let import = read_import(from)?;
+
     let work_dir = if private {
         init_work_dir_private(&target_path, &prefix)?
     } else {
@@ -32,17 +56,17 @@ pub fn run(prefix: Option<String>, path: Option<String>, private: bool) -> Resul
     };
 
     // Initialize the database
-    if private {
+    let mut db = if private {
         // Private mode: create database in .wok/
         let db_path = work_dir.join("issues.db");
-        Database::open(&db_path)?;
+        Database::open(&db_path)?
     } else {
         // User-level mode: ensure state directory and database exist
         let state_dir = crate::config::wok_state_dir();
         std::fs::create_dir_all(&state_dir)?;
         let db_path = state_dir.join("issues.db");
-        Database::open(&db_path)?;
-    }
+        Database::open(&db_path)?
+    };
 
     // Create .gitignore
     write_gitignore(&work_dir, private)?;
@@ -55,6 +79,69 @@ pub fn run(prefix: Option<String>, path: Option<String>, private: bool) -> Resul
         println!("Mode: user-level (shared database)");
     }
 
+    finish(&mut db, import, &prefix)
+}
+
+/// Initialize a project that joins the workspace `target` names.
+fn run_join(
+    target: &str,
+    prefix: Option<String>,
+    target_path: &Path,
+    from: Option<ImportFrom>,
+) -> Result<()> {
+    if prefix.as_deref().is_some_and(|p| !validate_prefix(p)) {
+        return Err(Error::InvalidPrefix);
+    }
+    let import = read_import(from)?;
+
+    let mut registry = Registry::load(&wok_state_dir())?;
+    let root = workspace::resolve(target, &registry)?;
+    std::fs::create_dir_all(target_path)?;
+    let (work_dir, id) = workspace::join(
+        &mut registry,
+        &root,
+        &target_path.canonicalize()?,
+        prefix.clone(),
+    )?;
+    registry.save()?;
+
+    // The member has no database of its own; open the shared one so it exists.
+    let config = Config::load(&work_dir)?;
+    let db_path = get_db_path(&work_dir, &config);
+    if let Some(dir) = db_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut db = Database::open(&db_path)?;
+    write_gitignore(&work_dir, false)?;
+
+    let root_dir = root.parent().unwrap_or(&root);
+    println!("Joined workspace {} (root: {})", id, root_dir.display());
+    println!("Initialized issue tracker at {}", work_dir.display());
+    match prefix {
+        Some(_) => println!("Prefix: {}", config.prefix),
+        None => println!("Prefix: {} (from the workspace root)", config.prefix),
+    }
+
+    finish(&mut db, import, &config.prefix)
+}
+
+fn read_import(from: Option<ImportFrom>) -> Result<Option<(ImportFile, bool)>> {
+    from.map(|from| {
+        ImportFile::read(&from.file, &Source::jsonl(&from.file, &from.format))
+            .map(|file| (file, from.keep_ids))
+    })
+    .transpose()
+}
+
+/// Import the `--from` export, if any, and install shell completions.
+fn finish(db: &mut Database, import: Option<(ImportFile, bool)>, prefix: &str) -> Result<()> {
+    if let Some((mut import, keep_ids)) = import {
+        if !keep_ids {
+            report_remap(&import.remap_prefix(prefix), prefix);
+        }
+        import.import(db)?;
+    }
+
     // Install shell completions
     if let Err(e) = completions::install_all() {
         eprintln!("Warning: failed to install shell completions: {}", e);
@@ -63,6 +150,28 @@ pub fn run(prefix: Option<String>, path: Option<String>, private: bool) -> Resul
     Ok(())
 }
 
+fn report_remap(remap: &PrefixRemap, prefix: &str) {
+    if remap.renamed > 0 {
+        let from: Vec<String> = remap.from.iter().map(|p| format!("{}-", p)).collect();
+        println!(
+            "Renamed {} imported IDs from {} to {}-",
+            remap.renamed,
+            from.join(", "),
+            prefix
+        );
+    }
+    if !remap.kept.is_empty() {
+        eprintln!(
+            "warning: {} ID(s) kept because their {}- form was already taken:",
+            remap.kept.len(),
+            prefix
+        );
+        for id in &remap.kept {
+            eprintln!("  - {}", id);
+        }
+    }
+}
+
 /// Derive a prefix from the directory path.
 /// Uses the directory name, converted to lowercase, keeping letters and digits.
 fn derive_prefix_from_path(path: &Path) -> Result<String> {
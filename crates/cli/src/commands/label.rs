@@ -3,9 +3,10 @@
 
 use crate::db::Database;
 
-use super::apply_mutation;
+use super::{apply_mutation, apply_mutation_if};
 use crate::error::Result;
 use crate::models::{Action, Event};
+use crate::t;
 use crate::validate::{validate_label, validate_label_count};
 
 /// Add multiple labels to multiple issues. DB is already open.
@@ -57,15 +58,14 @@ fn add_single_resolved(db: &Database, resolved_id: &str, label: &str) -> Result<
     let current_labels = db.get_labels(resolved_id)?;
     validate_label_count(current_labels.len())?;
 
-    db.add_label(resolved_id, label)?;
-
     apply_mutation(
         db,
         Event::new(resolved_id.to_string(), Action::Labeled)
             .with_values(None, Some(label.to_string())),
+        || db.add_label(resolved_id, label),
     )?;
 
-    println!("Labeled {} with {}", resolved_id, label);
+    println!("{}", t!("label.added", id = resolved_id, label = label));
 
     Ok(())
 }
@@ -107,18 +107,17 @@ fn remove_single_resolved(db: &Database, resolved_id: &str, label: &str) -> Resu
     // Verify issue exists
     db.get_issue(resolved_id)?;
 
-    let removed = db.remove_label(resolved_id, label)?;
+    let removed = apply_mutation_if(
+        db,
+        Event::new(resolved_id.to_string(), Action::Unlabeled)
+            .with_values(None, Some(label.to_string())),
+        || db.remove_label(resolved_id, label),
+    )?;
 
     if removed {
-        apply_mutation(
-            db,
-            Event::new(resolved_id.to_string(), Action::Unlabeled)
-                .with_values(None, Some(label.to_string())),
-        )?;
-
-        println!("Removed label {} from {}", label, resolved_id);
+        println!("{}", t!("label.removed", id = resolved_id, label = label));
     } else {
-        println!("Label {} not found on {}", label, resolved_id);
+        println!("{}", t!("label.not_found", id = resolved_id, label = label));
     }
 
     Ok(())
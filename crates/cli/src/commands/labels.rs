@@ -0,0 +1,408 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok labels` - the label registry: usage counts, descriptions, renames
+//! and display colors.
+//!
+//! Registry entries name a label (`team:alpha`) or a whole namespace with its
+//! trailing colon (`team:`). They live in the local database only.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use wk_core::label::{is_namespace, namespace};
+
+use crate::cli::{LabelsCommand, OutputFormat};
+use crate::colors::{self, LabelPalette};
+use crate::config::LabelsConfig;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::{Action, Event};
+use crate::t;
+use crate::validate::validate_label;
+
+use super::{notify_mutation, open_db};
+
+/// One row of `wok labels list`.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct LabelEntry {
+    pub name: String,
+    /// Issues carrying the label; for a namespace, the sum over its labels.
+    pub count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+impl LabelEntry {
+    fn new(name: String) -> Self {
+        LabelEntry {
+            name,
+            count: 0,
+            description: None,
+            color: None,
+        }
+    }
+}
+
+/// A label in use that breaks `[labels]`, for `wok labels audit`.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct AuditFinding {
+    pub label: String,
+    pub reason: String,
+    /// Issues carrying the label.
+    pub issues: Vec<String>,
+    /// The conforming label `--fix` renames it to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+/// Execute a labels subcommand.
+pub fn run(cmd: LabelsCommand) -> Result<()> {
+    let (db, config, _work_dir) = open_db()?;
+    match cmd {
+        LabelsCommand::List { namespace, output } => list_impl(&db, namespace.as_deref(), output),
+        LabelsCommand::Describe { name, description } => describe_impl(&db, &name, &description),
+        LabelsCommand::Rename { old, new } => rename_impl(&db, &config.labels, &old, &new),
+        LabelsCommand::Audit { fix, output } => audit_impl(&db, &config.labels, fix, output),
+        LabelsCommand::Colors { name, color } => match (name, color) {
+            (Some(name), Some(color)) => set_color_impl(&db, &name, &color),
+            _ => colors_impl(&db),
+        },
+    }
+}
+
+/// The display colors of the label registry.
+pub(crate) fn palette(db: &Database) -> Result<LabelPalette> {
+    Ok(LabelPalette::new(
+        db.get_label_meta()?
+            .into_iter()
+            .filter_map(|meta| Some((meta.name, meta.color?))),
+    ))
+}
+
+/// Every label in use or registered, sorted by name.
+pub(crate) fn label_entries(db: &Database, ns: Option<&str>) -> Result<Vec<LabelEntry>> {
+    let mut entries: BTreeMap<String, LabelEntry> = BTreeMap::new();
+    for (label, count) in db.get_label_counts()? {
+        entries.insert(
+            label.clone(),
+            LabelEntry {
+                count,
+                ..LabelEntry::new(label)
+            },
+        );
+    }
+    for meta in db.get_label_meta()? {
+        let e = entries
+            .entry(meta.name.clone())
+            .or_insert_with(|| LabelEntry::new(meta.name));
+        e.description = meta.description;
+        e.color = meta.color;
+    }
+
+    // Namespaces count the uses of their labels
+    let mut totals: BTreeMap<String, usize> = BTreeMap::new();
+    for e in entries.values() {
+        if let Some(ns) = namespace(&e.name) {
+            *totals.entry(format!("{}:", ns)).or_default() += e.count;
+        }
+    }
+    for (name, e) in entries.iter_mut() {
+        if is_namespace(name) {
+            e.count = totals.get(name).copied().unwrap_or_default();
+        }
+    }
+
+    let ns = ns.map(|ns| ns.trim_end_matches(':'));
+    Ok(entries
+        .into_values()
+        .filter(|e| match ns {
+            Some(ns) => namespace(&e.name) == Some(ns) || e.name.strip_suffix(':') == Some(ns),
+            None => true,
+        })
+        .collect())
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn list_impl(db: &Database, ns: Option<&str>, output: OutputFormat) -> Result<()> {
+    let entries = label_entries(db, ns)?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&entries)?),
+        OutputFormat::Id => {
+            for e in &entries {
+                println!("{}", e.name);
+            }
+        }
+        OutputFormat::Text => {
+            if entries.is_empty() {
+                println!("{}", t!("labels.none"));
+            }
+            let palette = palette(db)?;
+            let width = entries
+                .iter()
+                .map(|e| e.name.chars().count())
+                .max()
+                .unwrap_or(0);
+            let count_width = entries
+                .iter()
+                .map(|e| e.count.to_string().len())
+                .max()
+                .unwrap_or(0);
+            for e in &entries {
+                let padding = " ".repeat(width - e.name.chars().count());
+                let line = format!(
+                    "{}{}  {:>cw$}  {}",
+                    palette.paint(&e.name),
+                    padding,
+                    e.count,
+                    e.description.as_deref().unwrap_or_default(),
+                    cw = count_width
+                );
+                println!("{}", line.trim_end());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Set the description of a label or namespace; an empty one clears it.
+pub(crate) fn describe_impl(db: &Database, name: &str, description: &str) -> Result<()> {
+    let name = registry_name(name)?;
+    let description = description.trim();
+    if description.is_empty() {
+        db.set_label_description(name, None)?;
+        println!("{}", t!("labels.description_cleared", name = name));
+    } else {
+        db.set_label_description(name, Some(description))?;
+        println!(
+            "{}",
+            t!("labels.described", name = name, description = description)
+        );
+    }
+    Ok(())
+}
+
+/// List the registered label colors.
+pub(crate) fn colors_impl(db: &Database) -> Result<()> {
+    let palette = palette(db)?;
+    let colored: Vec<(String, String)> = db
+        .get_label_meta()?
+        .into_iter()
+        .filter_map(|meta| Some((meta.name, meta.color?)))
+        .collect();
+
+    if colored.is_empty() {
+        println!("{}", t!("labels.no_colors"));
+    }
+    let width = colored
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (name, color) in &colored {
+        let padding = " ".repeat(width - name.chars().count());
+        println!("{}{}  {}", palette.paint(name), padding, color);
+    }
+    Ok(())
+}
+
+/// Set the color of a label or namespace; `none` clears it.
+pub(crate) fn set_color_impl(db: &Database, name: &str, color: &str) -> Result<()> {
+    let name = registry_name(name)?;
+    let color = color.trim().to_ascii_lowercase();
+    if color == "none" {
+        db.set_label_color(name, None)?;
+        println!("{}", t!("labels.color_cleared", name = name));
+        return Ok(());
+    }
+    if colors::parse_label_color(&color).is_none() {
+        return Err(Error::InvalidLabelColor(color));
+    }
+    db.set_label_color(name, Some(&color))?;
+    println!("{}", t!("labels.colored", name = name, color = color));
+    Ok(())
+}
+
+/// Rename a label on every issue carrying it, or every label of a namespace
+/// when both names end in `:`. The new names must conform to `[labels]`.
+pub(crate) fn rename_impl(
+    db: &Database,
+    schema: &LabelsConfig,
+    old: &str,
+    new: &str,
+) -> Result<()> {
+    let old = registry_name(old)?;
+    let new = registry_name(new)?;
+    let cannot = |reason: &str| Error::CannotRenameLabel {
+        old: old.to_string(),
+        new: new.to_string(),
+        reason: reason.to_string(),
+    };
+    if old == new {
+        return Err(cannot("the names are the same"));
+    }
+    if is_namespace(old) != is_namespace(new) {
+        return Err(cannot(
+            "a namespace can only be renamed to another namespace",
+        ));
+    }
+
+    // Every name in use or registered; a namespace renames each of its own
+    let mut names: Vec<String> = db
+        .get_label_counts()?
+        .into_iter()
+        .map(|(label, _)| label)
+        .chain(db.get_label_meta()?.into_iter().map(|meta| meta.name))
+        .filter(|name| name == old || (is_namespace(old) && name.starts_with(old)))
+        .collect();
+    if names.is_empty() {
+        return Err(cannot("no issue or registry entry uses it"));
+    }
+    names.sort();
+    names.dedup();
+    let renames: Vec<(String, String)> = names
+        .into_iter()
+        .map(|name| {
+            let renamed = format!("{}{}", new, &name[old.len()..]);
+            (name, renamed)
+        })
+        .collect();
+    for (_, renamed) in &renames {
+        validate_label(renamed)?;
+        schema.check(renamed)?;
+    }
+
+    let counts = relabel(db, &renames)?;
+    for ((from, to), count) in renames.iter().zip(counts) {
+        if !is_namespace(from) {
+            println!(
+                "{}",
+                t!("labels.renamed", old = from, new = to, count = count)
+            );
+        }
+    }
+    if is_namespace(old) {
+        println!("{}", t!("labels.renamed_namespace", old = old, new = new));
+    }
+    Ok(())
+}
+
+/// Report labels that break the declared keys of `[labels]`, and with `fix`
+/// rename those that map to a conforming label.
+pub(crate) fn audit_impl(
+    db: &Database,
+    schema: &LabelsConfig,
+    fix: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let mut findings = audit(db, schema)?;
+
+    if fix {
+        let renames: Vec<(String, String)> = findings
+            .iter()
+            .filter_map(|f| Some((f.label.clone(), f.fix.clone()?)))
+            .collect();
+        let counts = relabel(db, &renames)?;
+        if matches!(output, OutputFormat::Text) {
+            for ((from, to), count) in renames.iter().zip(counts) {
+                println!(
+                    "{}",
+                    t!("labels.renamed", old = from, new = to, count = count)
+                );
+            }
+        }
+        findings.retain(|f| f.fix.is_none());
+    }
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&findings)?),
+        OutputFormat::Id => {
+            for f in &findings {
+                println!("{}", f.label);
+            }
+        }
+        OutputFormat::Text => {
+            if findings.is_empty() {
+                println!("{}", t!("labels.audit_clean"));
+            }
+            for f in &findings {
+                println!(
+                    "{}",
+                    t!(
+                        "labels.audit_finding",
+                        label = f.label,
+                        reason = f.reason,
+                        count = f.issues.len()
+                    )
+                );
+                if let Some(fixed) = &f.fix {
+                    println!("{}", t!("labels.audit_fix", label = fixed));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Labels in use that break `[labels]`, sorted by label.
+pub(crate) fn audit(db: &Database, schema: &LabelsConfig) -> Result<Vec<AuditFinding>> {
+    let mut findings = Vec::new();
+    for (label, _) in db.get_label_counts()? {
+        let Some(reason) = schema.violation(&label) else {
+            continue;
+        };
+        findings.push(AuditFinding {
+            issues: db.get_issues_with_label(&label)?,
+            fix: schema.fix(&label),
+            label,
+            reason,
+        });
+    }
+    Ok(findings)
+}
+
+/// Rename each `(from, to)` label on every issue carrying it, in one
+/// transaction, logging the label swaps. Hooks run once it commits. Returns
+/// how many issues each rename changed.
+fn relabel(db: &Database, renames: &[(String, String)]) -> Result<Vec<usize>> {
+    let tx = db.conn.unchecked_transaction()?;
+    let mut events = Vec::new();
+    let mut counts = Vec::new();
+    for (from, to) in renames {
+        let ids = db.rename_label(from, to)?;
+        for id in &ids {
+            let unlabeled =
+                Event::new(id.clone(), Action::Unlabeled).with_values(None, Some(from.clone()));
+            let labeled =
+                Event::new(id.clone(), Action::Labeled).with_values(None, Some(to.clone()));
+            db.log_event(&unlabeled)?;
+            db.log_event(&labeled)?;
+            events.extend([unlabeled, labeled]);
+        }
+        counts.push(ids.len());
+    }
+    tx.commit()?;
+
+    for event in &events {
+        notify_mutation(db, event);
+    }
+    Ok(counts)
+}
+
+/// Check a registry name: a label, or a namespace ending in `:`.
+fn registry_name(name: &str) -> Result<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::FieldEmpty { field: "Label" });
+    }
+    validate_label(name)?;
+    Ok(name)
+}
+
+#[cfg(test)]
+#[path = "labels_tests.rs"]
+mod tests;
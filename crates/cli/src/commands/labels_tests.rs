@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+/// Three issues labeled across the team namespace, plus a bug label.
+fn labeled_context() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One")
+        .create_issue("test-2", IssueType::Task, "Two")
+        .create_issue("test-3", IssueType::Bug, "Three")
+        .add_label("test-1", "team:alpha")
+        .add_label("test-2", "team:alpha")
+        .add_label("test-3", "team:beta")
+        .add_label("test-3", "bug");
+    ctx
+}
+
+fn names(entries: &[LabelEntry]) -> Vec<&str> {
+    entries.iter().map(|e| e.name.as_str()).collect()
+}
+
+#[test]
+fn entries_count_labels_and_namespaces() {
+    let ctx = labeled_context();
+    describe_impl(&ctx.db, "team:", "Owning team").unwrap();
+    describe_impl(&ctx.db, "priority:high", "Drop everything").unwrap();
+
+    let entries = label_entries(&ctx.db, None).unwrap();
+
+    assert_eq!(
+        names(&entries),
+        ["bug", "priority:high", "team:", "team:alpha", "team:beta"]
+    );
+    let count = |name: &str| entries.iter().find(|e| e.name == name).unwrap().count;
+    assert_eq!(count("team:alpha"), 2);
+    assert_eq!(count("team:"), 3);
+    assert_eq!(count("priority:high"), 0);
+}
+
+#[test]
+fn entries_filter_by_namespace() {
+    let ctx = labeled_context();
+    describe_impl(&ctx.db, "team:", "Owning team").unwrap();
+
+    for ns in ["team", "team:"] {
+        let entries = label_entries(&ctx.db, Some(ns)).unwrap();
+        assert_eq!(names(&entries), ["team:", "team:alpha", "team:beta"]);
+    }
+}
+
+#[test]
+fn describe_with_empty_text_clears() {
+    let ctx = labeled_context();
+    describe_impl(&ctx.db, "bug", "Something is broken").unwrap();
+    describe_impl(&ctx.db, "bug", "  ").unwrap();
+
+    assert!(ctx.db.get_label_meta().unwrap().is_empty());
+}
+
+#[test]
+fn set_color_validates_and_clears() {
+    let ctx = labeled_context();
+
+    set_color_impl(&ctx.db, "bug", "Red").unwrap();
+    assert_eq!(
+        ctx.db.get_label_meta().unwrap()[0].color.as_deref(),
+        Some("red")
+    );
+
+    let err = set_color_impl(&ctx.db, "bug", "teal").unwrap_err();
+    assert!(matches!(err, Error::InvalidLabelColor(ref c) if c == "teal"));
+
+    set_color_impl(&ctx.db, "bug", "none").unwrap();
+    assert!(ctx.db.get_label_meta().unwrap().is_empty());
+}
+
+#[test]
+fn rename_relabels_issues_and_logs_events() {
+    let ctx = labeled_context();
+    describe_impl(&ctx.db, "team:alpha", "Platform team").unwrap();
+
+    rename_impl(
+        &ctx.db,
+        &LabelsConfig::default(),
+        "team:alpha",
+        "team:platform",
+    )
+    .unwrap();
+
+    assert_eq!(ctx.db.get_labels("test-1").unwrap(), ["team:platform"]);
+    assert_eq!(ctx.db.get_labels("test-2").unwrap(), ["team:platform"]);
+    let meta = ctx.db.get_label_meta().unwrap();
+    assert_eq!(meta[0].name, "team:platform");
+
+    let actions: Vec<(Action, Option<String>)> = ctx
+        .db
+        .get_events("test-1")
+        .unwrap()
+        .into_iter()
+        .rev()
+        .take(2)
+        .map(|e| (e.action, e.new_value))
+        .collect();
+    assert_eq!(
+        actions,
+        [
+            (Action::Labeled, Some("team:platform".to_string())),
+            (Action::Unlabeled, Some("team:alpha".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn rename_namespace_renames_every_label() {
+    let ctx = labeled_context();
+    set_color_impl(&ctx.db, "team:", "blue").unwrap();
+
+    rename_impl(&ctx.db, &LabelsConfig::default(), "team:", "squad:").unwrap();
+
+    assert_eq!(ctx.db.get_labels("test-1").unwrap(), ["squad:alpha"]);
+    let mut labels = ctx.db.get_labels("test-3").unwrap();
+    labels.sort();
+    assert_eq!(labels, ["bug", "squad:beta"]);
+    assert_eq!(ctx.db.get_label_meta().unwrap()[0].name, "squad:");
+}
+
+#[test]
+fn rename_rejects_bad_targets() {
+    let ctx = labeled_context();
+
+    for (old, new) in [
+        ("bug", "bug"),
+        ("team:", "squad"),
+        ("bug", "team:"),
+        ("unused", "other"),
+    ] {
+        let err = rename_impl(&ctx.db, &LabelsConfig::default(), old, new).unwrap_err();
+        assert!(
+            matches!(err, Error::CannotRenameLabel { .. }),
+            "{} -> {}: {:?}",
+            old,
+            new,
+            err
+        );
+    }
+    assert_eq!(ctx.db.get_labels("test-3").unwrap().len(), 2);
+}
+
+#[test]
+fn palette_uses_registry_colors() {
+    let ctx = labeled_context();
+    set_color_impl(&ctx.db, "team:", "cyan").unwrap();
+
+    let palette = palette(&ctx.db).unwrap();
+
+    assert_eq!(palette.color("team:beta"), Some(6));
+    assert_eq!(palette.color("bug"), None);
+}
+
+fn schema() -> LabelsConfig {
+    let mut schema = LabelsConfig::default();
+    schema.keys.insert(
+        "team".to_string(),
+        crate::config::LabelKey {
+            values: vec!["alpha".to_string(), "platform".to_string()],
+            aliases: [("plat".to_string(), "platform".to_string())].into(),
+            ..Default::default()
+        },
+    );
+    schema
+}
+
+#[test]
+fn audit_reports_nonconforming_labels() {
+    let ctx = labeled_context();
+    ctx.db.add_label("test-1", "team:Plat").unwrap();
+
+    let findings = audit(&ctx.db, &schema()).unwrap();
+
+    let summary: Vec<(&str, Option<&str>)> = findings
+        .iter()
+        .map(|f| (f.label.as_str(), f.fix.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        [("team:Plat", Some("team:platform")), ("team:beta", None)]
+    );
+    assert_eq!(findings[1].issues, ["test-3"]);
+}
+
+#[test]
+fn audit_fix_renames_fixable_labels() {
+    let ctx = labeled_context();
+    ctx.db.add_label("test-2", "team:Plat").unwrap();
+
+    audit_impl(&ctx.db, &schema(), true, OutputFormat::Text).unwrap();
+
+    let mut labels = ctx.db.get_labels("test-2").unwrap();
+    labels.sort();
+    assert_eq!(labels, ["team:alpha", "team:platform"]);
+    assert_eq!(ctx.db.get_labels("test-3").unwrap().len(), 2);
+    let remaining = audit(&ctx.db, &schema()).unwrap();
+    assert_eq!(remaining.len(), 1);
+}
+
+#[test]
+fn rename_checks_declared_keys() {
+    let ctx = labeled_context();
+
+    let err = rename_impl(&ctx.db, &schema(), "team:beta", "team:gamma").unwrap_err();
+
+    assert!(matches!(err, Error::InvalidLabel { .. }));
+    assert_eq!(
+        ctx.db.get_issues_with_label("team:beta").unwrap(),
+        ["test-3"]
+    );
+}
@@ -4,11 +4,14 @@
 use wk_core::detect::is_human_interactive;
 use wk_core::identity::get_user_name;
 
+use crate::config::{WipConfig, WipMode};
 use crate::db::Database;
 
-use super::{apply_mutation, open_db};
+use super::assign::current_user;
+use super::{apply_mutation, expect_revision, notify_mutation, open_db};
 use crate::error::{Error, Result};
-use crate::models::{Action, Event, Status};
+use crate::models::{Action, Event, Issue, Status};
+use crate::t;
 use crate::validate::validate_and_trim_reason;
 
 /// Result of a bulk lifecycle operation
@@ -60,6 +63,8 @@ enum BulkErrorKind {
         context: &'static str,
         operation: &'static str,
     },
+    /// Refused by a WIP limit - add to transition_failures
+    WipLimit(Error),
     /// Unexpected error - fail fast
     Fatal(Error),
 }
@@ -86,6 +91,7 @@ impl BulkErrorKind {
             Error::RequiredFor { context, operation } => {
                 BulkErrorKind::RequiredFor { context, operation }
             }
+            e @ Error::WipLimitExceeded { .. } => BulkErrorKind::WipLimit(e),
             e => BulkErrorKind::Fatal(e),
         }
     }
@@ -108,13 +114,13 @@ impl BulkErrorKind {
             BulkErrorKind::RequiredFor { context, operation } => {
                 Error::RequiredFor { context, operation }
             }
-            BulkErrorKind::Fatal(e) => e,
+            BulkErrorKind::WipLimit(e) | BulkErrorKind::Fatal(e) => e,
         }
     }
 }
 
 /// Print summary for bulk operations
-fn print_bulk_summary(result: &BulkResult, action_verb: &str) {
+fn print_bulk_summary(result: &BulkResult, summary_key: &str) {
     // Only print summary if there were multiple items OR failures
     if result.success_count + result.failure_count() <= 1 && result.is_success() {
         return;
@@ -122,29 +128,32 @@ fn print_bulk_summary(result: &BulkResult, action_verb: &str) {
 
     // Summary line
     let total = result.success_count + result.failure_count();
-    // Capitalize first letter of action verb
-    let capitalized = format!(
-        "{}{}",
-        action_verb
-            .chars()
-            .next()
-            .map(|c| c.to_uppercase().to_string())
-            .unwrap_or_default(),
-        &action_verb[1..]
-    );
     println!(
-        "{} {} of {} issues",
-        capitalized, result.success_count, total
+        "{}",
+        crate::i18n::message(
+            summary_key,
+            &[("count", &result.success_count), ("total", &total)]
+        )
     );
 
     // List unknown IDs
     if !result.unknown_ids.is_empty() {
-        println!("Unknown IDs: {}", result.unknown_ids.join(", "));
+        println!(
+            "{}",
+            t!("lifecycle.unknown_ids", ids = result.unknown_ids.join(", "))
+        );
     }
 
     // List ambiguous IDs
     for (prefix, matches) in &result.ambiguous_ids {
-        println!("Ambiguous ID: {} (matches: {})", prefix, matches.join(", "));
+        println!(
+            "{}",
+            t!(
+                "lifecycle.ambiguous_id",
+                prefix = prefix,
+                matches = matches.join(", ")
+            )
+        );
     }
 
     // List transition failures
@@ -156,9 +165,9 @@ fn print_bulk_summary(result: &BulkResult, action_verb: &str) {
 /// Execute a bulk operation on multiple IDs with consistent error handling.
 ///
 /// - `ids`: The issue IDs to process
-/// - `action_verb`: Past tense verb for summary (e.g., "started", "completed")
+/// - `summary_key`: Message for the summary line, given `count` and `total`
 /// - `operation`: Closure that performs the single-item operation
-fn bulk_operation<F>(ids: &[String], action_verb: &str, mut operation: F) -> Result<()>
+fn bulk_operation<F>(ids: &[String], summary_key: &str, mut operation: F) -> Result<()>
 where
     F: FnMut(&str) -> Result<()>,
 {
@@ -198,6 +207,11 @@ where
                     result.transition_failures.push((id.clone(), msg));
                     last_error = Some(BulkErrorKind::RequiredFor { context, operation });
                 }
+                BulkErrorKind::WipLimit(e) => {
+                    let msg = e.to_string().lines().next().unwrap_or_default().to_string();
+                    result.transition_failures.push((id.clone(), msg));
+                    last_error = Some(BulkErrorKind::WipLimit(e));
+                }
                 BulkErrorKind::Fatal(fatal_error) => {
                     return Err(fatal_error);
                 }
@@ -215,7 +229,7 @@ where
         }));
     }
 
-    print_bulk_summary(&result, action_verb);
+    print_bulk_summary(&result, summary_key);
 
     if result.is_success() {
         Ok(())
@@ -229,18 +243,29 @@ where
     }
 }
 
-pub fn start(ids: &[String]) -> Result<()> {
+pub fn start(ids: &[String], if_match: Option<u32>) -> Result<()> {
     let ids = super::new::expand_ids(ids);
-    let (mut db, _config, _work_dir) = open_db()?;
-    start_impl(&mut db, &ids)
+    let (mut db, config, work_dir) = open_db()?;
+    expect_revision(&db, &ids, if_match)?;
+    let me = current_user(&config, &work_dir);
+    start_impl(&mut db, &ids, &config.wip, me.as_deref())
 }
 
 /// Internal implementation that accepts db for testing.
-pub(crate) fn start_impl(db: &mut Database, ids: &[String]) -> Result<()> {
-    bulk_operation(ids, "started", |id| start_single(db, id))
+///
+/// `me` is who the WIP limit is checked against for unassigned issues.
+pub(crate) fn start_impl(
+    db: &mut Database,
+    ids: &[String],
+    wip: &WipConfig,
+    me: Option<&str>,
+) -> Result<()> {
+    bulk_operation(ids, "lifecycle.bulk.started", |id| {
+        start_single(db, id, wip, me)
+    })
 }
 
-fn start_single(db: &mut Database, id: &str) -> Result<()> {
+fn start_single(db: &mut Database, id: &str, wip: &WipConfig, me: Option<&str>) -> Result<()> {
     let resolved_id = db.resolve_id(id)?;
     let issue = db.get_issue(&resolved_id)?;
 
@@ -248,7 +273,9 @@ fn start_single(db: &mut Database, id: &str) -> Result<()> {
         return Ok(()); // idempotent
     }
 
-    db.update_issue_status(&resolved_id, Status::InProgress)?;
+    if let Some(assignee) = issue.assignee.as_deref().or(me) {
+        check_wip_limit(db, assignee, wip)?;
+    }
 
     apply_mutation(
         db,
@@ -256,14 +283,70 @@ fn start_single(db: &mut Database, id: &str) -> Result<()> {
             Some(issue.status.to_string()),
             Some("in_progress".to_string()),
         ),
+        || db.update_issue_status(&resolved_id, Status::InProgress),
     )?;
 
-    println!("Started {}", resolved_id);
+    println!("{}", t!("lifecycle.started", id = resolved_id));
 
     Ok(())
 }
 
-pub fn done(ids: &[String], reason: Option<&str>) -> Result<()> {
+/// Check that starting one more issue keeps `assignee` within their WIP limit.
+///
+/// In warn mode an excess is reported on stderr; in block mode it is an error.
+pub(crate) fn check_wip_limit(db: &Database, assignee: &str, wip: &WipConfig) -> Result<()> {
+    let Some(limit) = wip.limit_for(assignee) else {
+        return Ok(());
+    };
+    let count = db
+        .count_in_progress_by_assignee()?
+        .get(assignee)
+        .copied()
+        .unwrap_or(0);
+    if count < limit {
+        return Ok(());
+    }
+
+    match wip.mode {
+        WipMode::Warn => {
+            eprintln!(
+                "{}",
+                t!(
+                    "lifecycle.wip_warning",
+                    assignee = assignee,
+                    count = count + 1,
+                    limit = limit
+                )
+            );
+            Ok(())
+        }
+        WipMode::Block => Err(Error::WipLimitExceeded {
+            assignee: assignee.to_string(),
+            count,
+            limit,
+        }),
+    }
+}
+
+/// Assignees with more issues in progress than their limit allows, as
+/// `(assignee, in_progress, limit)` sorted by assignee.
+pub(crate) fn wip_violations(
+    db: &Database,
+    wip: &WipConfig,
+) -> Result<Vec<(String, usize, usize)>> {
+    let mut violations: Vec<_> = db
+        .count_in_progress_by_assignee()?
+        .into_iter()
+        .filter_map(|(assignee, count)| {
+            let limit = wip.limit_for(&assignee)?;
+            (count > limit).then_some((assignee, count, limit))
+        })
+        .collect();
+    violations.sort();
+    Ok(violations)
+}
+
+pub fn done(ids: &[String], reason: Option<&str>, if_match: Option<u32>) -> Result<()> {
     let ids = super::new::expand_ids(ids);
     // Validate and trim reason if provided
     let trimmed_reason = if let Some(r) = reason {
@@ -273,12 +356,15 @@ pub fn done(ids: &[String], reason: Option<&str>) -> Result<()> {
     };
 
     let (mut db, _config, _work_dir) = open_db()?;
+    expect_revision(&db, &ids, if_match)?;
     done_impl(&mut db, &ids, trimmed_reason.as_deref())
 }
 
 /// Internal implementation that accepts db for testing.
 pub(crate) fn done_impl(db: &mut Database, ids: &[String], reason: Option<&str>) -> Result<()> {
-    bulk_operation(ids, "completed", |id| done_single(db, id, reason))
+    bulk_operation(ids, "lifecycle.bulk.completed", |id| {
+        done_single(db, id, reason)
+    })
 }
 
 fn done_single(db: &mut Database, id: &str, reason: Option<&str>) -> Result<()> {
@@ -296,8 +382,6 @@ fn done_single(db: &mut Database, id: &str, reason: Option<&str>) -> Result<()>
         return done_single_with_reason(db, &resolved_id, &issue, &effective_reason);
     }
 
-    db.update_issue_status(&resolved_id, Status::Done)?;
-
     let mut event = Event::new(resolved_id.clone(), Action::Done)
         .with_values(Some(issue.status.to_string()), Some("done".to_string()));
 
@@ -305,21 +389,25 @@ fn done_single(db: &mut Database, id: &str, reason: Option<&str>) -> Result<()>
         event = event.with_reason(Some(r.to_string()));
     }
 
-    // Add reason as note if provided (will appear in "Summary" section)
-    if let Some(r) = reason {
-        db.add_note(&resolved_id, Status::Done, r)?;
-    }
-
-    // Log unblocked events for issues that are now unblocked
-    log_unblocked_events(db, &resolved_id)?;
-
-    apply_mutation(db, event)?;
+    apply_mutation(db, event, || -> Result<()> {
+        db.update_issue_status(&resolved_id, Status::Done)?;
+        // Add reason as note if provided (will appear in "Summary" section)
+        if let Some(r) = reason {
+            db.add_note(&resolved_id, Status::Done, r)?;
+        }
+        Ok(())
+    })?;
+    let unblocked = log_unblocked_events(db, &resolved_id, issue.status)?;
 
     if let Some(r) = reason {
-        println!("Completed {} ({})", resolved_id, r);
+        println!(
+            "{}",
+            t!("lifecycle.completed_reason", id = resolved_id, reason = r)
+        );
     } else {
-        println!("Completed {}", resolved_id);
+        println!("{}", t!("lifecycle.completed", id = resolved_id));
     }
+    print_unblocked(&unblocked);
 
     Ok(())
 }
@@ -330,37 +418,42 @@ fn done_single_with_reason(
     issue: &crate::models::Issue,
     reason: &str,
 ) -> Result<()> {
-    db.update_issue_status(id, Status::Done)?;
-
-    // Add reason as note (will appear in "Summary" section)
-    db.add_note(id, Status::Done, reason)?;
-
-    // Log unblocked events for issues that are now unblocked
-    log_unblocked_events(db, id)?;
-
     apply_mutation(
         db,
         Event::new(id.to_string(), Action::Done)
             .with_values(Some(issue.status.to_string()), Some("done".to_string()))
             .with_reason(Some(reason.to_string())),
+        || {
+            db.update_issue_status(id, Status::Done)?;
+            // Add reason as note (will appear in "Summary" section)
+            db.add_note(id, Status::Done, reason)
+        },
     )?;
+    let unblocked = log_unblocked_events(db, id, issue.status)?;
 
-    println!("Completed {} ({})", id, reason);
+    println!(
+        "{}",
+        t!("lifecycle.completed_reason", id = id, reason = reason)
+    );
+    print_unblocked(&unblocked);
 
     Ok(())
 }
 
-pub fn close(ids: &[String], reason: Option<&str>) -> Result<()> {
+pub fn close(ids: &[String], reason: Option<&str>, if_match: Option<u32>) -> Result<()> {
     let ids = super::new::expand_ids(ids);
     let effective_reason = resolve_reason(reason, "closed")?;
 
     let (mut db, _config, _work_dir) = open_db()?;
+    expect_revision(&db, &ids, if_match)?;
     close_impl(&mut db, &ids, &effective_reason)
 }
 
 /// Internal implementation that accepts db for testing.
 pub(crate) fn close_impl(db: &mut Database, ids: &[String], reason: &str) -> Result<()> {
-    bulk_operation(ids, "closed", |id| close_single(db, id, reason))
+    bulk_operation(ids, "lifecycle.bulk.closed", |id| {
+        close_single(db, id, reason)
+    })
 }
 
 fn close_single(db: &mut Database, id: &str, reason: &str) -> Result<()> {
@@ -371,27 +464,41 @@ fn close_single(db: &mut Database, id: &str, reason: &str) -> Result<()> {
         return Ok(()); // idempotent
     }
 
-    db.update_issue_status(&resolved_id, Status::Closed)?;
+    let unblocked = close_issue(db, &resolved_id, issue.status, reason)?;
 
-    // Add reason as note (will appear in "Close Reason" section)
-    db.add_note(&resolved_id, Status::Closed, reason)?;
+    println!(
+        "{}",
+        t!("lifecycle.closed", id = resolved_id, reason = reason)
+    );
+    print_unblocked(&unblocked);
 
-    // Log unblocked events for issues that are now unblocked
-    log_unblocked_events(db, &resolved_id)?;
+    Ok(())
+}
 
+/// Close issue `id`, currently `status`, with `reason`.
+///
+/// Returns the issues this unblocked.
+pub(crate) fn close_issue(
+    db: &Database,
+    id: &str,
+    status: Status,
+    reason: &str,
+) -> Result<Vec<Issue>> {
     apply_mutation(
         db,
-        Event::new(resolved_id.clone(), Action::Closed)
-            .with_values(Some(issue.status.to_string()), Some("closed".to_string()))
+        Event::new(id.to_string(), Action::Closed)
+            .with_values(Some(status.to_string()), Some("closed".to_string()))
             .with_reason(Some(reason.to_string())),
+        || {
+            db.update_issue_status(id, Status::Closed)?;
+            // Add reason as note (will appear in "Close Reason" section)
+            db.add_note(id, Status::Closed, reason)
+        },
     )?;
-
-    println!("Closed {} ({})", resolved_id, reason);
-
-    Ok(())
+    log_unblocked_events(db, id, status)
 }
 
-pub fn reopen(ids: &[String], reason: Option<&str>) -> Result<()> {
+pub fn reopen(ids: &[String], reason: Option<&str>, if_match: Option<u32>) -> Result<()> {
     let ids = super::new::expand_ids(ids);
     // Validate and trim reason if provided
     let trimmed_reason = if let Some(r) = reason {
@@ -401,12 +508,15 @@ pub fn reopen(ids: &[String], reason: Option<&str>) -> Result<()> {
     };
 
     let (mut db, _config, _work_dir) = open_db()?;
+    expect_revision(&db, &ids, if_match)?;
     reopen_impl(&mut db, &ids, trimmed_reason.as_deref())
 }
 
 /// Internal implementation that accepts db for testing.
 pub(crate) fn reopen_impl(db: &mut Database, ids: &[String], reason: Option<&str>) -> Result<()> {
-    bulk_operation(ids, "reopened", |id| reopen_single(db, id, reason))
+    bulk_operation(ids, "lifecycle.bulk.reopened", |id| {
+        reopen_single(db, id, reason)
+    })
 }
 
 fn reopen_single(db: &mut Database, id: &str, reason: Option<&str>) -> Result<()> {
@@ -425,22 +535,31 @@ fn reopen_single(db: &mut Database, id: &str, reason: Option<&str>) -> Result<()
         return reopen_single_with_reason(db, &resolved_id, &issue, &effective_reason);
     }
 
-    db.update_issue_status(&resolved_id, Status::Todo)?;
-
     let mut event = Event::new(resolved_id.clone(), Action::Reopened)
         .with_values(Some(issue.status.to_string()), Some("todo".to_string()));
 
     if let Some(r) = reason {
         event = event.with_reason(Some(r.to_string()));
+    }
+
+    apply_mutation(db, event, || -> Result<()> {
+        db.update_issue_status(&resolved_id, Status::Todo)?;
         // Add reason as note (will appear in "Description" section)
-        db.add_note(&resolved_id, Status::Todo, r)?;
-        println!("Reopened {} ({})", resolved_id, r);
+        if let Some(r) = reason {
+            db.add_note(&resolved_id, Status::Todo, r)?;
+        }
+        Ok(())
+    })?;
+
+    if let Some(r) = reason {
+        println!(
+            "{}",
+            t!("lifecycle.reopened_reason", id = resolved_id, reason = r)
+        );
     } else {
-        println!("Reopened {}", resolved_id);
+        println!("{}", t!("lifecycle.reopened", id = resolved_id));
     }
 
-    apply_mutation(db, event)?;
-
     Ok(())
 }
 
@@ -450,19 +569,22 @@ fn reopen_single_with_reason(
     issue: &crate::models::Issue,
     reason: &str,
 ) -> Result<()> {
-    db.update_issue_status(id, Status::Todo)?;
-
-    // Add reason as note (will appear in "Description" section)
-    db.add_note(id, Status::Todo, reason)?;
-
     apply_mutation(
         db,
         Event::new(id.to_string(), Action::Reopened)
             .with_values(Some(issue.status.to_string()), Some("todo".to_string()))
             .with_reason(Some(reason.to_string())),
+        || {
+            db.update_issue_status(id, Status::Todo)?;
+            // Add reason as note (will appear in "Description" section)
+            db.add_note(id, Status::Todo, reason)
+        },
     )?;
 
-    println!("Reopened {} ({})", id, reason);
+    println!(
+        "{}",
+        t!("lifecycle.reopened_reason", id = id, reason = reason)
+    );
 
     Ok(())
 }
@@ -494,26 +616,45 @@ pub(crate) fn resolve_reason(reason: Option<&str>, action: &str) -> Result<Strin
     })
 }
 
-/// Log unblocked events for issues that become unblocked when a blocker is completed
-fn log_unblocked_events(db: &crate::db::Database, completed_id: &str) -> Result<()> {
-    // Get all issues that were blocked by this issue
-    let blocked_issues = db.get_blocking(completed_id)?;
-
-    for blocked_id in blocked_issues {
-        // Check if this issue still has any open blockers
-        let remaining_blockers = db.get_transitive_blockers(&blocked_id)?;
-
-        // If no more open blockers, log an unblocked event
-        if remaining_blockers.is_empty() {
-            apply_mutation(
-                db,
-                Event::new(blocked_id, Action::Unblocked)
-                    .with_values(None, Some(completed_id.to_string())),
-            )?;
+/// Log unblocked events for open issues that become unblocked when a
+/// blocker is completed, firing their `issue.unblocked` hooks.
+///
+/// `previous` is the completed issue's status before; finishing an issue
+/// that was already done or closed unblocks nothing. Returns the issues
+/// that were unblocked.
+fn log_unblocked_events(db: &Database, completed_id: &str, previous: Status) -> Result<Vec<Issue>> {
+    let mut unblocked = Vec::new();
+    if previous.is_terminal() {
+        return Ok(unblocked);
+    }
+
+    for blocked_id in db.get_blocking(completed_id)? {
+        let blocked = db.get_issue(&blocked_id)?;
+        // Blocked the same way `wok ready` sees it: any open blocker, even
+        // behind a completed one
+        if blocked.status.is_terminal() || !db.get_transitive_blocker_deps(&blocked_id)?.is_empty()
+        {
+            continue;
         }
+        // Nothing changes on the unblocked issue; the event stands alone
+        let event = Event::new(blocked_id, Action::Unblocked)
+            .with_values(None, Some(completed_id.to_string()));
+        db.log_event(&event)?;
+        notify_mutation(db, &event);
+        unblocked.push(blocked);
     }
 
-    Ok(())
+    Ok(unblocked)
+}
+
+/// Tell the user which issues a completion unblocked.
+fn print_unblocked(unblocked: &[Issue]) {
+    for issue in unblocked {
+        println!(
+            "{}",
+            t!("lifecycle.unblocked", id = issue.id, title = issue.title)
+        );
+    }
 }
 
 #[cfg(test)]
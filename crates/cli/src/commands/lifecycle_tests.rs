@@ -5,7 +5,9 @@
 #![allow(clippy::expect_used)]
 
 use super::*;
-use crate::commands::lifecycle::{close_impl, done_impl, reopen_impl, resolve_reason, start_impl};
+use crate::commands::lifecycle::{
+    close_impl, done_impl, reopen_impl, resolve_reason, start_impl, wip_violations,
+};
 use crate::commands::testing::TestContext;
 use crate::models::{IssueType, Relation};
 
@@ -46,7 +48,7 @@ fn test_log_unblocked_when_blocker_completed() {
     ctx.db.update_issue_status("issue-a", Status::Done).unwrap();
 
     // Call log_unblocked_events
-    log_unblocked_events(&ctx.db, "issue-a").unwrap();
+    log_unblocked_events(&ctx.db, "issue-a", Status::InProgress).unwrap();
 
     // Check that an unblocked event was logged for issue B
     let events = ctx.db.get_events("issue-b").unwrap();
@@ -72,7 +74,7 @@ fn test_no_unblocked_when_multiple_blockers() {
     ctx.db.update_issue_status("issue-a", Status::Done).unwrap();
 
     // Call log_unblocked_events
-    log_unblocked_events(&ctx.db, "issue-a").unwrap();
+    log_unblocked_events(&ctx.db, "issue-a", Status::InProgress).unwrap();
 
     // Check that NO unblocked event was logged for issue B (still blocked by C)
     let events = ctx.db.get_events("issue-b").unwrap();
@@ -96,16 +98,78 @@ fn test_unblocked_after_all_blockers_done() {
 
     // Complete both A and C
     ctx.db.update_issue_status("issue-a", Status::Done).unwrap();
-    log_unblocked_events(&ctx.db, "issue-a").unwrap();
+    log_unblocked_events(&ctx.db, "issue-a", Status::InProgress).unwrap();
 
     ctx.db.update_issue_status("issue-c", Status::Done).unwrap();
-    log_unblocked_events(&ctx.db, "issue-c").unwrap();
+    log_unblocked_events(&ctx.db, "issue-c", Status::InProgress).unwrap();
 
     // Now B should have an unblocked event
     let events = ctx.db.get_events("issue-b").unwrap();
     assert!(events.iter().any(|e| e.action == Action::Unblocked));
 }
 
+#[test]
+fn test_log_unblocked_returns_unblocked_open_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue_with_status("blocker", IssueType::Task, "Blocker", Status::InProgress)
+        .create_issue("open", IssueType::Task, "Open")
+        .create_issue_with_status("closed", IssueType::Task, "Closed", Status::Closed)
+        .blocks("blocker", "open")
+        .blocks("blocker", "closed");
+
+    ctx.db.update_issue_status("blocker", Status::Done).unwrap();
+    let unblocked = log_unblocked_events(&ctx.db, "blocker", Status::InProgress).unwrap();
+
+    // Closed issues are not notified
+    let ids: Vec<&str> = unblocked.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["open"]);
+    let events = ctx.db.get_events("open").unwrap();
+    let event = events
+        .iter()
+        .find(|e| e.action == Action::Unblocked)
+        .unwrap();
+    assert_eq!(event.new_value.as_deref(), Some("blocker"));
+    assert!(!ctx
+        .db
+        .get_events("closed")
+        .unwrap()
+        .iter()
+        .any(|e| e.action == Action::Unblocked));
+}
+
+#[test]
+fn test_no_unblocked_when_blocker_was_already_finished() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue_with_status("blocker", IssueType::Task, "Blocker", Status::Done)
+        .create_issue("open", IssueType::Task, "Open")
+        .blocks("blocker", "open");
+
+    // done -> closed resolves nothing new
+    ctx.db
+        .update_issue_status("blocker", Status::Closed)
+        .unwrap();
+    let unblocked = log_unblocked_events(&ctx.db, "blocker", Status::Done).unwrap();
+    assert!(unblocked.is_empty());
+}
+
+#[test]
+fn test_no_unblocked_while_blocked_behind_completed_issue() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue_with_status("root", IssueType::Task, "Root", Status::Todo)
+        .create_issue_with_status("mid", IssueType::Task, "Mid", Status::Done)
+        .create_issue_with_status("other", IssueType::Task, "Other", Status::InProgress)
+        .create_issue("target", IssueType::Task, "Target")
+        .blocks("root", "mid")
+        .blocks("mid", "target")
+        .blocks("other", "target");
+
+    ctx.db.update_issue_status("other", Status::Done).unwrap();
+    let unblocked = log_unblocked_events(&ctx.db, "other", Status::InProgress).unwrap();
+
+    // `root` still blocks `target` through the completed `mid`
+    assert!(unblocked.is_empty());
+}
+
 // Test error cases
 #[test]
 fn test_invalid_transition_error_format() {
@@ -127,7 +191,12 @@ fn test_start_impl_from_todo() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Start test");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     assert!(result.is_ok());
     let issue = ctx.db.get_issue("test-1").unwrap();
@@ -140,7 +209,12 @@ fn test_start_impl_from_in_progress_idempotent() {
     ctx.create_issue("test-1", IssueType::Task, "Already started")
         .start_issue("test-1");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     assert!(result.is_ok());
     assert_eq!(
@@ -154,7 +228,12 @@ fn test_start_impl_from_done() {
     let mut ctx = TestContext::new();
     ctx.create_completed("test-1", IssueType::Task, "Completed task");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     assert!(result.is_ok());
     assert_eq!(
@@ -169,7 +248,12 @@ fn test_start_impl_from_closed() {
     ctx.create_issue("test-1", IssueType::Task, "Closed task")
         .close_issue("test-1");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     assert!(result.is_ok());
     assert_eq!(
@@ -424,7 +508,12 @@ fn test_start_impl_multiple_from_todo() {
     ctx.create_issue("test-1", IssueType::Task, "Task 1");
     ctx.create_issue("test-2", IssueType::Task, "Task 2");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string(), "test-2".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string(), "test-2".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     assert!(result.is_ok());
     assert_eq!(
@@ -444,7 +533,12 @@ fn test_start_impl_idempotent_on_already_started() {
     ctx.create_issue("test-2", IssueType::Task, "Task 2")
         .start_issue("test-2");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string(), "test-2".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string(), "test-2".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     // Both succeed (test-2 is idempotent)
     assert!(result.is_ok());
@@ -458,6 +552,161 @@ fn test_start_impl_idempotent_on_already_started() {
     );
 }
 
+// === WIP Limit Tests ===
+
+fn wip(limit: usize, mode: WipMode) -> WipConfig {
+    WipConfig {
+        limit: Some(limit),
+        mode,
+        ..WipConfig::default()
+    }
+}
+
+/// Two issues assigned to alice, one already in progress.
+fn alice_context() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "First")
+        .create_issue("test-2", IssueType::Task, "Second")
+        .start_issue("test-1");
+    ctx.db.set_assignee("test-1", "alice").unwrap();
+    ctx.db.set_assignee("test-2", "alice").unwrap();
+    ctx
+}
+
+#[test]
+fn test_start_impl_warn_mode_starts_over_limit() {
+    let mut ctx = alice_context();
+
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-2".to_string()],
+        &wip(1, WipMode::Warn),
+        None,
+    );
+
+    assert!(result.is_ok());
+    assert_eq!(
+        ctx.db.get_issue("test-2").unwrap().status,
+        Status::InProgress
+    );
+}
+
+#[test]
+fn test_start_impl_block_mode_refuses_over_limit() {
+    let mut ctx = alice_context();
+
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-2".to_string()],
+        &wip(1, WipMode::Block),
+        None,
+    );
+
+    assert!(matches!(
+        result,
+        Err(Error::WipLimitExceeded {
+            count: 1,
+            limit: 1,
+            ..
+        })
+    ));
+    assert_eq!(ctx.db.get_issue("test-2").unwrap().status, Status::Todo);
+}
+
+#[test]
+fn test_start_impl_block_mode_allows_under_limit() {
+    let mut ctx = alice_context();
+
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-2".to_string()],
+        &wip(2, WipMode::Block),
+        None,
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_start_impl_block_mode_uses_per_assignee_override() {
+    let mut ctx = alice_context();
+    let mut config = wip(1, WipMode::Block);
+    config.assignees.insert("alice".to_string(), 2);
+
+    let result = start_impl(&mut ctx.db, &["test-2".to_string()], &config, None);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_start_impl_block_mode_checks_current_user_for_unassigned() {
+    let mut ctx = alice_context();
+    ctx.create_issue("test-3", IssueType::Task, "Unassigned");
+
+    let blocked = start_impl(
+        &mut ctx.db,
+        &["test-3".to_string()],
+        &wip(1, WipMode::Block),
+        Some("alice"),
+    );
+    assert!(matches!(blocked, Err(Error::WipLimitExceeded { .. })));
+
+    let other = start_impl(
+        &mut ctx.db,
+        &["test-3".to_string()],
+        &wip(1, WipMode::Block),
+        Some("bob"),
+    );
+    assert!(other.is_ok());
+}
+
+#[test]
+fn test_start_impl_block_mode_in_bulk_reports_partial_failure() {
+    let mut ctx = alice_context();
+    ctx.create_issue("test-3", IssueType::Task, "Unassigned");
+
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-2".to_string(), "test-3".to_string()],
+        &wip(1, WipMode::Block),
+        None,
+    );
+
+    match result {
+        Err(Error::PartialBulkFailure {
+            succeeded,
+            transition_failures,
+            ..
+        }) => {
+            assert_eq!(succeeded, 1);
+            assert_eq!(transition_failures.len(), 1);
+            assert_eq!(transition_failures[0].0, "test-2");
+        }
+        _ => panic!("Expected PartialBulkFailure"),
+    }
+    assert_eq!(
+        ctx.db.get_issue("test-3").unwrap().status,
+        Status::InProgress
+    );
+}
+
+#[test]
+fn test_wip_violations_lists_assignees_over_limit() {
+    let mut ctx = alice_context();
+    ctx.start_issue("test-2");
+
+    assert_eq!(
+        wip_violations(&ctx.db, &wip(1, WipMode::Warn)).unwrap(),
+        vec![("alice".to_string(), 2, 1)]
+    );
+    assert!(wip_violations(&ctx.db, &wip(2, WipMode::Warn))
+        .unwrap()
+        .is_empty());
+    assert!(wip_violations(&ctx.db, &WipConfig::default())
+        .unwrap()
+        .is_empty());
+}
+
 #[test]
 fn test_done_impl_multiple_from_in_progress() {
     let mut ctx = TestContext::new();
@@ -643,6 +892,8 @@ fn test_start_partial_update_with_unknown() {
     let result = start_impl(
         &mut ctx.db,
         &["test-1".to_string(), "unknown-123".to_string()],
+        &WipConfig::default(),
+        None,
     );
 
     // Should fail overall but test-1 should be transitioned
@@ -676,7 +927,12 @@ fn test_start_batch_with_already_started_succeeds() {
         .start_issue("test-1"); // Already started - idempotent
     ctx.create_issue("test-2", IssueType::Task, "Task 2");
 
-    let result = start_impl(&mut ctx.db, &["test-1".to_string(), "test-2".to_string()]);
+    let result = start_impl(
+        &mut ctx.db,
+        &["test-1".to_string(), "test-2".to_string()],
+        &WipConfig::default(),
+        None,
+    );
 
     // Both succeed (test-1 is idempotent)
     assert!(result.is_ok());
@@ -704,6 +960,8 @@ fn test_start_partial_update_mixed_idempotent_and_unknown() {
             "test-2".to_string(),
             "unknown-999".to_string(),
         ],
+        &WipConfig::default(),
+        None,
     );
 
     // test-1 idempotent (counts as success), test-2 succeeds, unknown-999 not found
@@ -815,6 +1073,8 @@ fn test_start_all_unknown_ids() {
             "unknown-2".to_string(),
             "unknown-3".to_string(),
         ],
+        &WipConfig::default(),
+        None,
     );
 
     assert!(result.is_err());
@@ -833,3 +1093,44 @@ fn test_start_all_unknown_ids() {
         _ => panic!("Expected PartialBulkFailure"),
     }
 }
+
+#[test]
+fn test_if_match_needs_a_single_issue() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One")
+        .create_issue("test-2", IssueType::Task, "Two");
+
+    let ids = ["test-1".to_string(), "test-2".to_string()];
+    let result = crate::commands::expect_revision(&ctx.db, &ids, Some(1));
+    assert!(matches!(result, Err(Error::IncompatibleOptions { .. })));
+    crate::commands::expect_revision(&ctx.db, &ids, None).unwrap();
+}
+
+#[test]
+fn test_start_if_match_conflicts_with_a_change_in_between() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Contended");
+    let ids = ["test-1".to_string()];
+    crate::commands::expect_revision(&ctx.db, &ids, Some(1)).unwrap();
+    ctx.db
+        .conn
+        .execute("UPDATE issues SET revision = 2 WHERE id = 'test-1'", [])
+        .unwrap();
+
+    let result = start_impl(&mut ctx.db, &ids, &WipConfig::default(), None);
+    assert!(matches!(
+        result,
+        Err(Error::Conflict {
+            expected: 1,
+            actual: 2,
+            ..
+        })
+    ));
+    assert_eq!(ctx.db.get_issue("test-1").unwrap().status, Status::Todo);
+    assert!(!ctx
+        .db
+        .get_events("test-1")
+        .unwrap()
+        .iter()
+        .any(|e| e.action == Action::Started));
+}
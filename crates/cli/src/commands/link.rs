@@ -3,11 +3,17 @@
 
 //! External link management command.
 
+use chrono::Utc;
+
+use crate::config::{find_work_dir, Config};
 use crate::db::Database;
+use crate::display::format_remote;
 use crate::error::{Error, Result};
-use crate::models::{parse_link_url, Action, Event, Link, LinkRel};
+use crate::models::{parse_link_url_with, Action, Event, Link, LinkRel, LinkType};
+use crate::t;
+use wk_core::link_meta::{self, ApiConfig};
 
-use super::{apply_mutation, open_db};
+use super::{apply_mutation, apply_mutation_if, open_db};
 
 /// Add an external link to an issue.
 pub fn add(id: &str, url: &str, reason: Option<String>) -> Result<()> {
@@ -24,7 +30,7 @@ fn add_impl_with_reason(db: &Database, id: &str, url: &str, reason: Option<Strin
     db.get_issue(&resolved_id)?;
 
     // Parse URL to detect link type and external ID
-    let (link_type, external_id) = parse_link_url(url);
+    let (link_type, external_id) = detect(url);
 
     // Parse relation if provided
     let rel = reason.map(|r| r.parse::<LinkRel>()).transpose()?;
@@ -34,7 +40,8 @@ fn add_impl_with_reason(db: &Database, id: &str, url: &str, reason: Option<Strin
         if link_type.is_none() {
             return Err(Error::LinkRequires {
                 requirement: "import",
-                dependency: "a known provider type (github, jira, gitlab)",
+                dependency:
+                    "a known provider type (github, jira, gitlab) or one configured in [links]",
             });
         }
         if external_id.is_none() {
@@ -52,18 +59,26 @@ fn add_impl_with_reason(db: &Database, id: &str, url: &str, reason: Option<Strin
     link.external_id = external_id;
     link.rel = rel;
 
-    db.add_link(&link)?;
-
-    // Log event
     apply_mutation(
         db,
         Event::new(resolved_id.clone(), Action::Linked).with_values(None, Some(url.to_string())),
+        || db.add_link(&link),
     )?;
 
-    println!("Added link to {}", resolved_id);
+    println!("{}", t!("link.added", id = resolved_id));
     Ok(())
 }
 
+/// Detect the type and external ID of `url`, using the `[links]` providers
+/// of the current project, if any, before the built-in ones.
+fn detect(url: &str) -> (Option<LinkType>, Option<String>) {
+    let providers = find_work_dir()
+        .and_then(|work_dir| Config::load(&work_dir))
+        .map(|config| config.links)
+        .unwrap_or_default();
+    parse_link_url_with(url, &providers)
+}
+
 /// Remove an external link from an issue.
 pub fn remove(id: &str, url: &str) -> Result<()> {
     let (db, _config, _work_dir) = open_db()?;
@@ -84,43 +99,150 @@ fn remove_impl(db: &Database, id: &str, url: &str) -> Result<()> {
 
     match link {
         Some(link) => {
-            db.remove_link(link.id)?;
-
-            // Log event
             apply_mutation(
                 db,
                 Event::new(resolved_id.clone(), Action::Unlinked)
                     .with_values(Some(url.to_string()), None),
+                || db.remove_link(link.id),
             )?;
 
-            println!("Removed link from {}", resolved_id);
+            println!("{}", t!("link.removed", id = resolved_id));
             Ok(())
         }
         None => {
-            println!("Link {} not found on {}", url, resolved_id);
+            println!("{}", t!("link.not_found", url = url, id = resolved_id));
             Ok(())
         }
     }
 }
 
+/// Mark the external issue at `url`, linked from `id`, resolved.
+pub fn resolve(id: &str, url: &str) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    resolve_impl(&db, id, url)
+}
+
+/// Internal implementation for resolving a link.
+pub(crate) fn resolve_impl(db: &Database, id: &str, url: &str) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    db.get_issue(&resolved_id)?;
+
+    match db.get_link_by_url(&resolved_id, url)? {
+        Some(link) => {
+            if link.resolved_at.is_none() {
+                db.set_link_resolved(link.id, Some(Utc::now()))?;
+            }
+            println!("{}", t!("link.resolved", url = url, id = resolved_id));
+        }
+        None => println!("{}", t!("link.not_found", url = url, id = resolved_id)),
+    }
+    Ok(())
+}
+
+/// Bring back the link to `url` most recently removed from `id`.
+pub fn restore(id: &str, url: &str) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    restore_impl(&db, id, url)
+}
+
+/// Internal implementation for restoring a link.
+pub(crate) fn restore_impl(db: &Database, id: &str, url: &str) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    db.get_issue(&resolved_id)?;
+
+    let restored = apply_mutation_if(
+        db,
+        Event::new(resolved_id.clone(), Action::Linked).with_values(None, Some(url.to_string())),
+        || {
+            db.restore_link(&resolved_id, url)
+                .map(|link| link.is_some())
+        },
+    )?;
+    if restored {
+        println!("{}", t!("link.restored", url = url, id = resolved_id));
+    } else {
+        println!("{}", t!("link.not_removed", url = url, id = resolved_id));
+    }
+    Ok(())
+}
+
+/// Fetch the titles and states of the GitHub, GitLab and Jira issues that
+/// `ids`, or with `all` every issue, link to.
+pub fn refresh(ids: &[String], all: bool) -> Result<()> {
+    let (db, config, _work_dir) = open_db()?;
+    refresh_impl(&db, &config.api, ids, all)
+}
+
+/// Internal implementation for refreshing links.
+pub(crate) fn refresh_impl(
+    db: &Database,
+    api: &ApiConfig,
+    ids: &[String],
+    all: bool,
+) -> Result<()> {
+    let links = if all {
+        db.links_to_refresh(Utc::now(), usize::MAX)?
+    } else {
+        let mut links: Vec<Link> = Vec::new();
+        for id in ids {
+            let resolved_id = db.resolve_id(id)?;
+            db.get_issue(&resolved_id)?;
+            for link in db.get_links(&resolved_id)? {
+                if link_meta::is_refreshable(&link) && !links.iter().any(|l| l.url == link.url) {
+                    links.push(link);
+                }
+            }
+        }
+        links
+    };
+
+    if links.is_empty() {
+        println!("{}", t!("link.none_to_refresh"));
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for link in &links {
+        match link_meta::refresh(db, link, api) {
+            Ok(meta) => {
+                let remote = format_remote(link, &meta);
+                let url = link.url.clone().unwrap_or_default();
+                println!("{}: {}", link.issue_id, remote.unwrap_or(url));
+            }
+            Err(e) => {
+                eprintln!("warning: {}", e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(Error::PartialBulkFailure {
+            succeeded: links.len() - failed,
+            failed,
+            unknown_ids: Vec::new(),
+            transition_failures: Vec::new(),
+        });
+    }
+    Ok(())
+}
+
 /// Add a link to an issue (for use by new command).
 ///
 /// This is a helper function used by the `new` command to add links
 /// during issue creation.
 pub(crate) fn add_link_impl(db: &Database, issue_id: &str, url: &str) -> Result<()> {
-    let (link_type, external_id) = parse_link_url(url);
+    let (link_type, external_id) = detect(url);
 
     let mut link = Link::new(issue_id.to_string());
     link.link_type = link_type;
     link.url = Some(url.to_string());
     link.external_id = external_id;
 
-    db.add_link(&link)?;
-
-    // Log event
     apply_mutation(
         db,
         Event::new(issue_id.to_string(), Action::Linked).with_values(None, Some(url.to_string())),
+        || db.add_link(&link),
     )?;
 
     Ok(())
@@ -320,3 +320,135 @@ fn test_remove_link_multiple_links() {
         Some("https://github.com/org/repo/issues/2".to_string())
     );
 }
+
+#[test]
+fn test_refresh_without_provider_links_does_nothing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test");
+    add_impl_with_reason(
+        &ctx.db,
+        "test-1",
+        "https://company.atlassian.net/wiki/spaces/DOC/pages/1",
+        None,
+    )
+    .unwrap();
+
+    refresh_impl(
+        &ctx.db,
+        &ApiConfig::default(),
+        &["test-1".to_string()],
+        false,
+    )
+    .unwrap();
+
+    let url = "https://company.atlassian.net/wiki/spaces/DOC/pages/1";
+    assert_eq!(ctx.db.get_link_meta(url).unwrap(), None);
+}
+
+#[test]
+fn test_refresh_records_failures() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test");
+    add_impl_with_reason(&ctx.db, "test-1", "jira://PE-5555", None).unwrap();
+
+    // Without [api.jira] url there is nowhere to ask about jira:// links
+    let result = refresh_impl(&ctx.db, &ApiConfig::default(), &[], true);
+
+    assert!(matches!(
+        result,
+        Err(Error::PartialBulkFailure {
+            succeeded: 0,
+            failed: 1,
+            ..
+        })
+    ));
+    let meta = ctx.db.get_link_meta("jira://PE-5555").unwrap().unwrap();
+    assert!(meta.title.is_none());
+    assert!(meta.error.unwrap().contains("[api.jira]"));
+}
+
+#[test]
+fn test_refresh_unknown_issue_fails() {
+    let ctx = TestContext::new();
+
+    let result = refresh_impl(&ctx.db, &ApiConfig::default(), &["nope".to_string()], false);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_resolve_link_unblocks_issue() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test");
+    add_impl_with_reason(
+        &ctx.db,
+        "test-1",
+        "jira://PE-5555",
+        Some("blocks".to_string()),
+    )
+    .unwrap();
+    assert_eq!(ctx.db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+
+    resolve_impl(&ctx.db, "test-1", "jira://PE-5555").unwrap();
+
+    let links = ctx.db.get_links("test-1").unwrap();
+    assert!(links[0].resolved_at.is_some());
+    assert!(ctx.db.get_blocked_issue_ids().unwrap().is_empty());
+}
+
+#[test]
+fn test_resolve_unknown_link_changes_nothing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test");
+    add_impl_with_reason(
+        &ctx.db,
+        "test-1",
+        "jira://PE-5555",
+        Some("blocks".to_string()),
+    )
+    .unwrap();
+
+    resolve_impl(&ctx.db, "test-1", "jira://PE-1").unwrap();
+
+    assert_eq!(ctx.db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+}
+
+#[test]
+fn test_restore_link_brings_back_the_removed_link() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test");
+    add_impl_with_reason(
+        &ctx.db,
+        "test-1",
+        "jira://PE-5555",
+        Some("blocks".to_string()),
+    )
+    .unwrap();
+    remove_impl(&ctx.db, "test-1", "jira://PE-5555").unwrap();
+    assert!(ctx.db.get_blocked_issue_ids().unwrap().is_empty());
+
+    restore_impl(&ctx.db, "test-1", "jira://PE-5555").unwrap();
+
+    let links = ctx.db.get_links("test-1").unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].rel, Some(LinkRel::Blocks));
+    assert_eq!(ctx.db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+    let events = ctx.db.get_events("test-1").unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.action, Action::Linked);
+    assert_eq!(last.new_value.as_deref(), Some("jira://PE-5555"));
+}
+
+#[test]
+fn test_restore_link_without_a_removed_link_changes_nothing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test");
+    add_impl_with_reason(&ctx.db, "test-1", "jira://PE-5555", None).unwrap();
+    let events = ctx.db.get_events("test-1").unwrap().len();
+
+    restore_impl(&ctx.db, "test-1", "jira://PE-5555").unwrap();
+    restore_impl(&ctx.db, "test-1", "jira://PE-1").unwrap();
+
+    assert_eq!(ctx.db.get_links("test-1").unwrap().len(), 1);
+    assert_eq!(ctx.db.get_events("test-1").unwrap().len(), events);
+}
@@ -1,27 +1,61 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use chrono::Utc;
 
-use crate::cli::OutputFormat;
+use crate::aging::stale_issues;
+use crate::cli::{GroupBy, ListColumn, ListFormat, Order, PageArgs, SortArgs};
+use crate::colors::LabelPalette;
+use crate::config::{AgingConfig, WipConfig};
 use crate::db::Database;
-use crate::display::format_issue_line;
-use crate::error::Result;
-use crate::filter::{parse_filter, FilterExpr, FilterField};
-use crate::models::{IssueType, Status};
-use crate::schema::list::ListOutputJson;
+use crate::display::{
+    format_issue_line_with_short_id, format_issue_table, terminal_width, truncate_to_width,
+    IssueRow,
+};
+use crate::error::{Error, Result};
+use crate::filter::{parse_query, FilterQuery};
+use crate::models::{Issue, IssueType, Status};
+use crate::ndjson;
+use crate::schema::list::{ListGroupJson, ListGroupedOutputJson, ListOutputJson};
 use crate::schema::IssueJson;
+use wk_core::query::{Cond, IssueQuery, QueryOrder};
 
-use super::filtering::{
-    matches_filter_groups, matches_label_groups, matches_prefix, parse_filter_groups, LabelMatcher,
-};
+use super::assign::resolve_assignees;
+use super::filtering::{filter_groups_cond, label_groups_cond, parse_filter_groups, LabelMatcher};
+use super::grouping::group_issues;
+use super::lifecycle::wip_violations;
 use super::open_db;
+use super::ordering::{downstream_count, format_unblocks, sort_by_downstream};
+use super::paging::{self, PageRequest};
+use super::sorting::sort_issues;
 
 /// Default limit for list output when not explicitly specified.
 /// Prevents large result sets from overwhelming terminal output.
-const DEFAULT_LIMIT: usize = 100;
+pub(crate) const DEFAULT_LIMIT: usize = 100;
+
+/// How list results are ordered and laid out.
+#[derive(Default)]
+pub(crate) struct ListView {
+    pub order: Order,
+    /// Explicit `--sort`, applied over the default priority order.
+    pub sort: SortArgs,
+    /// Text columns; empty means the usual one-line-per-issue format.
+    pub columns: Vec<ListColumn>,
+    /// Page text through `$PAGER`.
+    pub pager: bool,
+    /// Terminal width to fit text lines into; `None` leaves them whole.
+    pub width: Option<usize>,
+    /// Show issues under per-group headers.
+    pub group_by: Option<GroupBy>,
+    /// The `-o json-paged` page to fetch; the first page if unset.
+    pub page: Option<PageRequest>,
+    /// `[aging]` thresholds that mark issues stale.
+    pub aging: AgingConfig,
+    /// Show only stale issues.
+    pub stale_only: bool,
+}
 
 // TODO(refactor): Consider using an options struct to bundle parameters
 #[allow(clippy::too_many_arguments)]
@@ -36,12 +70,44 @@ pub fn run(
     limit: Option<usize>,
     no_limit: bool,
     blocked_only: bool,
+    stale_only: bool,
     all: bool,
-    format: OutputFormat,
+    format: ListFormat,
+    order: Order,
+    sort: SortArgs,
+    columns: Vec<ListColumn>,
+    pager: bool,
+    group_by: Option<GroupBy>,
+    paging: PageArgs,
 ) -> Result<()> {
-    let (db, config, _) = open_db()?;
+    let conflicting = if sort.sort.is_some() {
+        Some("--sort")
+    } else if order == Order::Topo {
+        Some("--order topo")
+    } else if group_by.is_some() {
+        Some("--group-by")
+    } else if stale_only {
+        Some("--stale")
+    } else if limit.is_some() || no_limit {
+        Some("--limit")
+    } else {
+        None
+    };
+    let page = paging::page_request(format, &paging, conflicting)?;
+    let (db, config, work_dir) = open_db()?;
+    if stale_only && !config.aging.is_set() {
+        return Err(Error::RequiredFor {
+            context: "an [aging] threshold",
+            operation: "--stale",
+        });
+    }
+    let assignee = resolve_assignees(assignee, &config, &work_dir)?;
     let prefix = prefix.or((!config.prefix.is_empty()).then_some(config.prefix));
-    let effective_limit = if no_limit { Some(0) } else { limit };
+    let effective_limit = if no_limit {
+        Some(0)
+    } else {
+        limit.or(config.limits.list)
+    };
     run_impl(
         &db,
         status,
@@ -55,6 +121,18 @@ pub fn run(
         blocked_only,
         all,
         format,
+        &config.wip,
+        &ListView {
+            order,
+            sort,
+            columns,
+            pager,
+            width: terminal_width(),
+            group_by,
+            page,
+            aging: config.aging,
+            stale_only,
+        },
     )
 }
 
@@ -72,142 +150,251 @@ pub(crate) fn run_impl(
     limit: Option<usize>,
     blocked_only: bool,
     all: bool,
-    format: OutputFormat,
+    format: ListFormat,
+    wip: &WipConfig,
+    view: &ListView,
 ) -> Result<()> {
+    if format == ListFormat::Ndjson && view.group_by.is_some() {
+        return Err(Error::IncompatibleOptions {
+            option: "--group-by",
+            with: "-o ndjson",
+        });
+    }
+
     // Parse filter groups
     let status_groups = parse_filter_groups(&status, |s| Ok(s.parse::<Status>()?))?;
     let type_groups =
         parse_filter_groups(&issue_type, |s| s.parse::<IssueType>().map_err(Into::into))?;
     let label_groups = parse_filter_groups(&label, LabelMatcher::parse)?;
 
-    // Parse time-based filter expressions
-    let filters: Vec<FilterExpr> = filter
+    // Parse filter expressions
+    let filters: Vec<FilterQuery> = filter
         .iter()
-        .map(|f| parse_filter(f))
+        .map(|f| parse_query(f))
         .collect::<Result<_>>()?;
 
-    // Check if any filter targets a terminal state field (completed, skipped, closed)
-    let has_terminal_filter = filters.iter().any(|f| {
-        matches!(
-            f.field,
-            FilterField::Completed | FilterField::Skipped | FilterField::Closed
-        )
-    });
+    // Check if any filter selects by status (status field, or completed/skipped/closed)
+    let has_status_filter = filters.iter().any(FilterQuery::selects_status);
 
-    // Get all issues (we'll filter in-memory for complex multi-value logic)
-    let mut issues = crate::time_phase!("db::query", { db.list_issues(None, None, None)? });
+    let mut conds = Vec::new();
 
-    // Filter by prefix (cheap string comparison, apply early)
-    if prefix.is_some() {
-        issues.retain(|issue| matches_prefix(&prefix, &issue.id));
+    // Filter by prefix
+    if let Some(prefix) = &prefix {
+        conds.push(Cond::IdPrefix(prefix.clone()));
     }
 
     // Default: show open issues (todo + in_progress) when no status filter and not --all
-    // Exception: when terminal filter is used, include closed issues (they're the target)
-    if !all && status_groups.is_none() && !has_terminal_filter {
-        issues.retain(|issue| issue.status == Status::Todo || issue.status == Status::InProgress);
-    } else if status_groups.is_some() {
-        // Filter by explicit status groups
-        issues.retain(|issue| matches_filter_groups(&status_groups, || issue.status));
-    }
-
-    // Filter by type groups
-    if type_groups.is_some() {
-        issues.retain(|issue| matches_filter_groups(&type_groups, || issue.issue_type));
-    }
-
-    // Filter by label groups
-    if label_groups.is_some() {
-        let start = std::time::Instant::now();
-        issues.retain(|issue| {
-            let issue_labels = db.get_labels(&issue.id).unwrap_or_default();
-            matches_label_groups(&label_groups, &issue_labels)
-        });
-        crate::timings::print_timing("filter::labels", start);
+    // Exception: when a filter selects by status, let it decide which issues to keep
+    if !all && status_groups.is_none() && !has_status_filter {
+        conds.push(Cond::Status(vec![Status::Todo, Status::InProgress]));
     }
+    conds.extend(filter_groups_cond(&status_groups, |g| {
+        Cond::Status(g.to_vec())
+    }));
+    conds.extend(filter_groups_cond(&type_groups, |g| Cond::Type(g.to_vec())));
+    conds.extend(label_groups_cond(&label_groups));
 
     // Filter by assignee
     if unassigned {
-        issues.retain(|issue| issue.assignee.is_none());
+        conds.push(Cond::Unassigned);
     } else if !assignee.is_empty() {
-        issues.retain(|issue| {
-            issue
-                .assignee
-                .as_ref()
-                .is_some_and(|a| assignee.iter().any(|f| a == f))
-        });
+        conds.push(Cond::Assignee(assignee));
     }
 
-    // Apply time-based filters
-    if !filters.is_empty() {
-        let now = Utc::now();
-        issues.retain(|issue| filters.iter().all(|f| f.matches(issue, now)));
-    }
+    // Apply filter expressions
+    let now = Utc::now();
+    conds.extend(filters.iter().map(|f| f.to_cond(now)));
 
-    // Apply blocked filter if specified
     if blocked_only {
-        let blocked_ids: HashSet<String> = crate::time_phase!("filter::blocked", {
-            db.get_blocked_issue_ids()?.into_iter().collect()
+        conds.push(Cond::Blocked);
+    }
+
+    if format == ListFormat::JsonPaged {
+        let page = view.page.clone().unwrap_or_default();
+        return paging::print_page(db, &page, Cond::All(conds));
+    }
+
+    // Sort by priority ASC, then created_at DESC. The limit applies after
+    // sorting, so it can only go into the query when nothing reorders later.
+    let effective_limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let reordered = view.sort.sort.is_some() || view.order == Order::Topo || view.stale_only;
+    let query = IssueQuery::new(Cond::All(conds))
+        .with_order(QueryOrder::Priority)
+        .with_limit((effective_limit > 0 && !reordered).then_some(effective_limit));
+
+    // Nothing to reorder: stream rows straight from the query.
+    if format == ListFormat::Ndjson && !reordered {
+        let mut rows = db.stream_issues(&query)?;
+        let lines = rows.iter()?.map(|issue| {
+            let issue = issue?;
+            let short_id = db.get_short_id(&issue.id)?;
+            issue_json(db, &issue, short_id, None)
         });
-        issues.retain(|issue| blocked_ids.contains(&issue.id));
+        ndjson::print_lines(lines)?;
+        return Ok(());
     }
 
-    // Sort by priority ASC, then created_at DESC
-    let sort_start = std::time::Instant::now();
-    issues.sort_by(|a, b| {
-        let tags_a = db.get_labels(&a.id).unwrap_or_default();
-        let tags_b = db.get_labels(&b.id).unwrap_or_default();
-        let priority_a = crate::db::priority_from_tags(&tags_a);
-        let priority_b = crate::db::priority_from_tags(&tags_b);
-
-        match priority_a.cmp(&priority_b) {
-            std::cmp::Ordering::Equal => b.created_at.cmp(&a.created_at), // DESC
-            other => other,
-        }
-    });
-    crate::timings::print_timing("sort", sort_start);
+    let mut issues = crate::time_phase!("db::query", { db.query_issues(&query)? });
+
+    let stale = {
+        let all: Vec<&Issue> = issues.iter().collect();
+        stale_issues(db, &view.aging, &all, now)?
+    };
+    if view.stale_only {
+        issues.retain(|issue| stale.contains_key(&issue.id));
+    }
+
+    crate::time_phase!("sort", { sort_issues(db, &mut issues, &view.sort)? });
+
+    // --order topo: biggest unblockers first, the order above breaking ties
+    let downstream = match view.order {
+        Order::Topo => Some(sort_by_downstream(db, &mut issues)?),
+        Order::Default => None,
+    };
+    let unblocks = |issue: &Issue| downstream.as_ref().map(|c| downstream_count(c, &issue.id));
 
     // Apply limit after sorting (default 100, or explicit value, 0 = unlimited)
-    let effective_limit = limit.unwrap_or(DEFAULT_LIMIT);
     if effective_limit > 0 {
         issues.truncate(effective_limit);
     }
 
     let format_start = std::time::Instant::now();
+    let _render = tracing::info_span!("render").entered();
+    let issue_ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    let short_ids = db.get_short_ids_batch(&issue_ids)?;
+    let short_id = |issue: &Issue| short_ids.get(&issue.id).cloned();
+    let labels = if view.group_by.is_some() || !view.columns.is_empty() {
+        db.get_labels_batch(&issue_ids)?
+    } else {
+        HashMap::new()
+    };
+    let palette = if view.group_by == Some(GroupBy::LabelPrefix) || !view.columns.is_empty() {
+        super::labels::palette(db)?
+    } else {
+        LabelPalette::default()
+    };
+    let groups: Vec<(Option<String>, Vec<&Issue>)> = match view.group_by {
+        Some(by) => group_issues(&issues, by, &labels)
+            .into_iter()
+            .map(|(name, members)| (Some(name), members))
+            .collect(),
+        None => vec![(None, issues.iter().collect())],
+    };
+    let mut out = String::new();
     match format {
-        OutputFormat::Text => {
-            for issue in &issues {
-                println!("{}", format_issue_line(issue));
+        ListFormat::Text => {
+            for (i, (name, members)) in groups.iter().enumerate() {
+                if let Some(name) = name {
+                    if i > 0 {
+                        out.push('\n');
+                    }
+                    let name = match view.group_by {
+                        Some(GroupBy::LabelPrefix) => palette.paint_namespace(name),
+                        _ => name.clone(),
+                    };
+                    out.push_str(&format!("{} ({})\n", name, members.len()));
+                }
+                if view.columns.is_empty() {
+                    for issue in members {
+                        let mut suffix = unblocks(issue).map(format_unblocks).unwrap_or_default();
+                        if let Some(stale) = stale.get(&issue.id) {
+                            suffix.push_str(&format!(" ({})", stale));
+                        }
+                        let line =
+                            format_issue_line_with_short_id(issue, short_id(issue).as_deref());
+                        let line = format!("{}{}", line, suffix);
+                        match view.width {
+                            Some(width) => out.push_str(&truncate_to_width(&line, width)),
+                            None => out.push_str(&line),
+                        }
+                        out.push('\n');
+                    }
+                } else {
+                    let rows: Vec<IssueRow> = members
+                        .iter()
+                        .map(|issue| IssueRow {
+                            issue,
+                            short_id: short_ids.get(&issue.id).map(String::as_str),
+                            labels: labels.get(&issue.id).map(Vec::as_slice).unwrap_or_default(),
+                        })
+                        .collect();
+                    for line in format_issue_table(&rows, &view.columns, view.width, &palette) {
+                        out.push_str(&line);
+                        out.push('\n');
+                    }
+                }
             }
-        }
-        OutputFormat::Json => {
-            let mut json_issues = Vec::new();
-            for issue in &issues {
-                let labels = db.get_labels(&issue.id)?;
-                json_issues.push(IssueJson::new(
-                    issue.id.clone(),
-                    issue.issue_type,
-                    issue.status,
-                    issue.title.clone(),
-                    issue.assignee.clone(),
-                    labels,
-                ));
+            let listed: HashSet<&str> = issues
+                .iter()
+                .filter_map(|i| i.assignee.as_deref())
+                .collect();
+            let violations: Vec<String> = wip_violations(db, wip)?
+                .into_iter()
+                .filter(|(assignee, _, _)| listed.contains(assignee.as_str()))
+                .map(|(assignee, count, limit)| format!("@{} ({}/{})", assignee, count, limit))
+                .collect();
+            if !violations.is_empty() {
+                out.push_str(&format!("\nOver WIP limit: {}\n", violations.join(", ")));
             }
-            let output = ListOutputJson(json_issues);
-            println!("{}", serde_json::to_string_pretty(&output)?);
         }
-        OutputFormat::Id => {
-            let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
-            if !ids.is_empty() {
-                println!("{}", ids.join(" "));
+        ListFormat::Json | ListFormat::JsonPaged => {
+            let to_json = |issue: &Issue| {
+                issue_json(db, issue, short_id(issue), unblocks(issue))
+                    .map(|json| json.with_stale(stale.get(&issue.id)))
+            };
+            out = if view.group_by.is_some() {
+                let mut json_groups = Vec::new();
+                for (name, members) in groups {
+                    json_groups.push(ListGroupJson {
+                        group: name.unwrap_or_default(),
+                        count: members.len(),
+                        issues: members.into_iter().map(to_json).collect::<Result<_>>()?,
+                    });
+                }
+                serde_json::to_string_pretty(&ListGroupedOutputJson(json_groups))?
+            } else {
+                let json_issues = issues.iter().map(to_json).collect::<Result<_>>()?;
+                serde_json::to_string_pretty(&ListOutputJson(json_issues))?
+            } + "\n";
+        }
+        ListFormat::Ndjson => {
+            let to_json = |issue: &Issue| {
+                issue_json(db, issue, short_id(issue), unblocks(issue))
+                    .map(|json| json.with_stale(stale.get(&issue.id)))
+            };
+            ndjson::print_lines(issues.iter().map(to_json))?;
+        }
+        ListFormat::Id => {
+            if !issue_ids.is_empty() {
+                out = issue_ids.join(" ") + "\n";
             }
         }
     }
+    crate::pager::page(&out, view.pager);
     crate::timings::print_timing("format", format_start);
 
     Ok(())
 }
 
+/// An issue's JSON summary, with its labels read from `db`.
+fn issue_json(
+    db: &Database,
+    issue: &Issue,
+    short_id: Option<String>,
+    unblocks: Option<usize>,
+) -> Result<IssueJson> {
+    Ok(IssueJson::new(
+        issue.id.clone(),
+        issue.issue_type,
+        issue.status,
+        issue.title.clone(),
+        issue.assignee.clone(),
+        db.get_labels(&issue.id)?,
+    )
+    .with_short_id(short_id)
+    .with_unblocks(unblocks))
+}
+
 #[cfg(test)]
 #[path = "list_tests.rs"]
 mod tests;
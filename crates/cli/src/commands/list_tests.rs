@@ -6,7 +6,7 @@
 #![allow(clippy::unnecessary_literal_unwrap)]
 
 use super::*;
-use crate::cli::OutputFormat;
+use crate::cli::ListFormat;
 use crate::db::Database;
 use crate::models::{Issue, IssueType, Relation};
 use chrono::Utc;
@@ -32,6 +32,7 @@ fn create_issue(db: &Database, id: &str, status: Status, issue_type: IssueType)
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&issue).unwrap();
 }
@@ -152,7 +153,9 @@ fn test_run_impl_default() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -175,7 +178,9 @@ fn test_run_impl_with_status_filter() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -198,7 +203,9 @@ fn test_run_impl_with_type_filter() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -221,7 +228,9 @@ fn test_run_impl_with_label_filter() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -246,7 +255,9 @@ fn test_run_impl_blocked_only() {
         None,
         true,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -268,7 +279,9 @@ fn test_run_impl_empty_list() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -290,7 +303,9 @@ fn test_run_impl_invalid_status_filter() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_err());
 }
@@ -312,7 +327,9 @@ fn test_run_impl_invalid_type_filter() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_err());
 }
@@ -335,7 +352,9 @@ fn test_run_impl_comma_separated_status() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -359,7 +378,9 @@ fn test_run_impl_json_format_outputs_valid_json() {
         None,
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -384,7 +405,9 @@ fn test_run_impl_json_format_with_blocked_only() {
         None,
         true,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -405,7 +428,9 @@ fn test_run_impl_json_format_empty_list() {
         None,
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -429,7 +454,9 @@ fn test_run_impl_json_format_with_filters() {
         None,
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -457,7 +484,9 @@ fn test_default_shows_todo_and_in_progress() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
     // The output would contain todo-1 and in-progress-1 but not done-1 or closed-1
@@ -487,7 +516,9 @@ fn test_blocked_filter_with_status() {
         None,
         true,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -515,7 +546,9 @@ fn test_no_blocked_footer() {
         None,
         false,
         false,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -575,6 +608,7 @@ fn test_list_same_priority_sorts_by_created_at_desc() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&older).unwrap();
 
@@ -593,6 +627,7 @@ fn test_list_same_priority_sorts_by_created_at_desc() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&newer).unwrap();
 
@@ -669,7 +704,9 @@ fn test_run_impl_all_flag_shows_all_statuses() {
         None,
         false,
         true,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -694,7 +731,9 @@ fn test_run_impl_ids_format_outputs_space_separated() {
         None,
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -717,7 +756,9 @@ fn test_run_impl_ids_format_with_filters() {
         None,
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -739,7 +780,9 @@ fn test_run_impl_ids_format_empty_list() {
         None,
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -773,6 +816,7 @@ fn test_effective_limit(input: Option<usize>, expected_max: usize) {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
         db.create_issue(&issue).unwrap();
     }
@@ -809,6 +853,7 @@ fn test_default_limit_is_100() {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
         db.create_issue(&issue).unwrap();
     }
@@ -829,7 +874,9 @@ fn test_default_limit_is_100() {
         None, // No explicit limit
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -853,6 +900,7 @@ fn test_limit_zero_is_unlimited() {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
         db.create_issue(&issue).unwrap();
     }
@@ -870,7 +918,9 @@ fn test_limit_zero_is_unlimited() {
         Some(0), // Unlimited
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -894,6 +944,7 @@ fn test_explicit_limit_overrides_default() {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
         db.create_issue(&issue).unwrap();
     }
@@ -911,7 +962,9 @@ fn test_explicit_limit_overrides_default() {
         Some(50), // Explicit limit
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -935,6 +988,7 @@ fn test_ids_format_respects_limit() {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
         db.create_issue(&issue).unwrap();
     }
@@ -952,7 +1006,9 @@ fn test_ids_format_respects_limit() {
         Some(5),
         false,
         false,
-        OutputFormat::Id,
+        ListFormat::Id,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -979,7 +1035,9 @@ fn test_json_output_includes_filters_applied() {
         None,
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -1001,7 +1059,9 @@ fn test_json_output_includes_limit_when_specified() {
         Some(10), // Explicit limit
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -1024,7 +1084,9 @@ fn test_json_output_excludes_null_metadata() {
         None, // No limit
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -1047,7 +1109,9 @@ fn test_json_output_with_multiple_filters() {
         None,
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
     );
     assert!(result.is_ok());
 }
@@ -1070,7 +1134,141 @@ fn test_json_output_with_limit_and_filters() {
         Some(50),
         false,
         false,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &ListView::default(),
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_text_output_with_wip_violation() {
+    let db = setup_db();
+    create_issue(&db, "wip-1", Status::InProgress, IssueType::Task);
+    create_issue(&db, "wip-2", Status::InProgress, IssueType::Task);
+    db.set_assignee("wip-1", "alice").unwrap();
+    db.set_assignee("wip-2", "alice").unwrap();
+    let wip = WipConfig {
+        limit: Some(1),
+        ..WipConfig::default()
+    };
+
+    let result = run_impl(
+        &db,
+        vec![],
+        vec![],
+        vec![],
+        None,
+        vec![],
+        false,
+        vec![],
+        None,
+        false,
+        false,
+        ListFormat::Text,
+        &wip,
+        &ListView::default(),
+    );
+    assert!(result.is_ok());
+}
+
+#[parameterized(
+    columns = { vec![ListColumn::Id, ListColumn::Title, ListColumn::Labels], Some(40) },
+    truncated_lines = { vec![], Some(20) },
+)]
+fn test_text_output_with_view(columns: Vec<ListColumn>, width: Option<usize>) {
+    let db = setup_db();
+    create_issue(&db, "test-1", Status::Todo, IssueType::Task);
+    db.add_label("test-1", "backend").unwrap();
+    let view = ListView {
+        columns,
+        width,
+        ..ListView::default()
+    };
+
+    let result = run_impl(
+        &db,
+        vec![],
+        vec![],
+        vec![],
+        None,
+        vec![],
+        false,
+        vec![],
+        None,
+        false,
+        false,
+        ListFormat::Text,
+        &WipConfig::default(),
+        &view,
+    );
+    assert!(result.is_ok());
+}
+
+#[parameterized(
+    text_status = { ListFormat::Text, GroupBy::Status },
+    text_label_prefix = { ListFormat::Text, GroupBy::LabelPrefix },
+    json_assignee = { ListFormat::Json, GroupBy::Assignee },
+    id_type = { ListFormat::Id, GroupBy::Type },
+)]
+fn test_grouped_output(format: ListFormat, group_by: GroupBy) {
+    let db = setup_db();
+    create_issue(&db, "test-1", Status::Todo, IssueType::Task);
+    create_issue(&db, "test-2", Status::InProgress, IssueType::Bug);
+    db.add_label("test-1", "mod:cli").unwrap();
+    db.set_assignee("test-2", "alice").unwrap();
+    let view = ListView {
+        group_by: Some(group_by),
+        ..ListView::default()
+    };
+
+    let result = run_impl(
+        &db,
+        vec![],
+        vec![],
+        vec![],
+        None,
+        vec![],
+        false,
+        vec![],
+        None,
+        false,
+        false,
+        format,
+        &WipConfig::default(),
+        &view,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_stale_only() {
+    let db = setup_db();
+    create_issue(&db, "test-1", Status::InProgress, IssueType::Task);
+    let view = ListView {
+        aging: AgingConfig {
+            in_progress: Some("1d".to_string()),
+            ..AgingConfig::default()
+        },
+        stale_only: true,
+        ..ListView::default()
+    };
+
+    let result = run_impl(
+        &db,
+        vec![],
+        vec![],
+        vec![],
+        None,
+        vec![],
+        false,
+        vec![],
+        None,
+        false,
+        false,
+        ListFormat::Json,
+        &WipConfig::default(),
+        &view,
     );
     assert!(result.is_ok());
 }
@@ -1,53 +1,64 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+use crate::cli::LogFormat;
 use crate::db::Database;
 use crate::display::{format_event, format_event_with_id};
 use crate::error::Result;
+use crate::ndjson;
 
 use super::open_db;
 
-/// Default limit for log output when not explicitly specified.
-const DEFAULT_LIMIT: usize = 20;
+/// Default limit for log output when neither `--limit` nor `limits.log` is set.
+pub(crate) const DEFAULT_LIMIT: usize = 20;
 
-pub fn run(id: Option<String>, limit: Option<usize>, no_limit: bool) -> Result<()> {
-    let (db, _, _) = open_db()?;
+pub fn run(
+    id: Option<String>,
+    actor: Option<String>,
+    limit: Option<usize>,
+    no_limit: bool,
+    format: LogFormat,
+) -> Result<()> {
+    let (db, config, _) = open_db()?;
     let effective_limit = if no_limit {
         0
     } else {
-        limit.unwrap_or(DEFAULT_LIMIT)
+        limit.or(config.limits.log).unwrap_or(DEFAULT_LIMIT)
     };
-    run_impl(&db, id, effective_limit)
+    run_impl(&db, id, actor.as_deref(), effective_limit, format)
 }
 
 /// Internal implementation that accepts db for testing.
-pub(crate) fn run_impl(db: &Database, id: Option<String>, limit: usize) -> Result<()> {
-    match id {
-        Some(issue_id) => {
-            // Resolve potentially partial ID
-            let resolved_id = db.resolve_id(&issue_id)?;
-
-            // Show events for specific issue
-            let events = db.get_events(&resolved_id)?;
-
-            if events.is_empty() {
-                println!("No events for {}", resolved_id);
-            } else {
-                for event in events.iter().rev().take(limit) {
-                    println!("{}", format_event(event));
-                }
+pub(crate) fn run_impl(
+    db: &Database,
+    id: Option<String>,
+    actor: Option<&str>,
+    limit: usize,
+    format: LogFormat,
+) -> Result<()> {
+    // Resolve potentially partial ID
+    let resolved_id = id.map(|id| db.resolve_id(&id)).transpose()?;
+    // 0 means no limit.
+    let mut rows = db.stream_events(resolved_id.as_deref(), actor, (limit > 0).then_some(limit))?;
+    if format == LogFormat::Ndjson {
+        ndjson::print_lines(rows.iter()?.map(|event| event.map_err(Into::into)))?;
+        return Ok(());
+    }
+
+    let events = rows.iter()?.collect::<std::result::Result<Vec<_>, _>>()?;
+    match resolved_id {
+        // Events for a specific issue
+        Some(resolved_id) if events.is_empty() => println!("No events for {}", resolved_id),
+        Some(_) => {
+            for event in &events {
+                println!("{}", format_event(event));
             }
         }
+        // Recent events across all issues
+        None if events.is_empty() => println!("No events"),
         None => {
-            // Show recent events across all issues
-            let events = db.get_recent_events(limit)?;
-
-            if events.is_empty() {
-                println!("No events");
-            } else {
-                for event in &events {
-                    println!("{}", format_event_with_id(event));
-                }
+            for event in &events {
+                println!("{}", format_event_with_id(event));
             }
         }
     }
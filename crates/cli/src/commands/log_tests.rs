@@ -119,6 +119,7 @@ fn test_event_values() {
 
 // Tests for run_impl
 
+use crate::cli::LogFormat;
 use crate::commands::log::run_impl;
 
 #[test]
@@ -127,7 +128,7 @@ fn test_run_impl_global_log() {
     ctx.create_issue("test-1", IssueType::Task, "Test issue")
         .create_issue("test-2", IssueType::Bug, "Another issue");
 
-    let result = run_impl(&ctx.db, None, 10);
+    let result = run_impl(&ctx.db, None, None, 10, LogFormat::Text);
     assert!(result.is_ok());
 }
 
@@ -136,7 +137,13 @@ fn test_run_impl_issue_log() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue");
 
-    let result = run_impl(&ctx.db, Some("test-1".to_string()), 10);
+    let result = run_impl(
+        &ctx.db,
+        Some("test-1".to_string()),
+        None,
+        10,
+        LogFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -144,7 +151,13 @@ fn test_run_impl_issue_log() {
 fn test_run_impl_nonexistent_issue() {
     let ctx = TestContext::new();
 
-    let result = run_impl(&ctx.db, Some("nonexistent".to_string()), 10);
+    let result = run_impl(
+        &ctx.db,
+        Some("nonexistent".to_string()),
+        None,
+        10,
+        LogFormat::Text,
+    );
     assert!(result.is_err());
 }
 
@@ -155,6 +168,45 @@ fn test_run_impl_with_limit() {
         .create_issue("test-2", IssueType::Task, "Task 2")
         .create_issue("test-3", IssueType::Task, "Task 3");
 
-    let result = run_impl(&ctx.db, None, 2);
+    let result = run_impl(&ctx.db, None, None, 2, LogFormat::Text);
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_run_impl_ndjson() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+
+    assert!(run_impl(&ctx.db, None, None, 0, LogFormat::Ndjson).is_ok());
+    assert!(run_impl(
+        &ctx.db,
+        Some("test-1".to_string()),
+        None,
+        10,
+        LogFormat::Ndjson
+    )
+    .is_ok());
+    assert!(run_impl(
+        &ctx.db,
+        Some("nonexistent".to_string()),
+        None,
+        10,
+        LogFormat::Ndjson
+    )
+    .is_err());
+}
+
+#[test]
+fn test_stream_events_by_actor() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    ctx.db.set_actor(Some("claude-1".to_string()));
+    ctx.set_status("test-1", Status::InProgress);
+
+    let mut rows = ctx.db.stream_events(None, Some("claude-1"), None).unwrap();
+    let events: Vec<_> = rows.iter().unwrap().map(Result::unwrap).collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, Action::Started);
+    assert_eq!(events[0].actor.as_deref(), Some("claude-1"));
+    assert!(run_impl(&ctx.db, None, Some("claude-1"), 10, LogFormat::Text).is_ok());
+}
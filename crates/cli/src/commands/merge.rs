@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok merge` - merging a duplicate issue into the one it duplicates.
+//!
+//! The source's notes, labels, links and dependencies move to the target,
+//! along with the events that recorded them; the source keeps its own
+//! lifecycle and edit history. The source is then marked a duplicate of the
+//! target and closed with the reason "duplicate of <target>". Everything
+//! happens in one transaction, and hooks and rules only see the merge's
+//! events once it has committed.
+
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::{Action, Event, Relation, Status};
+
+use super::{notify_mutation, open_db};
+
+/// Events that move with the rows they describe.
+const MOVED_ACTIONS: &[Action] = &[
+    Action::Labeled,
+    Action::Unlabeled,
+    Action::Noted,
+    Action::Unnoted,
+    Action::Linked,
+    Action::Unlinked,
+    Action::Related,
+    Action::Unrelated,
+    Action::Associated,
+    Action::Dissociated,
+    Action::Duplicated,
+    Action::Unduplicated,
+];
+
+/// What a merge moved onto the target.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub(crate) struct Merged {
+    pub from: String,
+    pub into: String,
+    pub notes: usize,
+    pub labels: Vec<String>,
+    pub links: usize,
+    pub events: usize,
+    /// Dependencies re-pointed at the target, as "blocks <id>" and so on.
+    pub deps: Vec<String>,
+    /// Whether the source was open and got closed.
+    pub closed: bool,
+}
+
+pub fn run(from: &str, into: &str, dry_run: bool, output: OutputFormat) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    let from = db.resolve_id(from)?;
+    let into = db.resolve_id(into)?;
+    let merged = if dry_run {
+        preview(&db, &from, &into)?
+    } else {
+        merge_impl(&db, &from, &into)?
+    };
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&merged)?),
+        OutputFormat::Id => println!("{}", merged.into),
+        OutputFormat::Text => print_text(&merged, dry_run),
+    }
+    Ok(())
+}
+
+fn print_text(merged: &Merged, dry_run: bool) {
+    let verb = if dry_run { "Would merge" } else { "Merged" };
+    println!("{} {} into {}", verb, merged.from, merged.into);
+    println!("  notes: {}", merged.notes);
+    if !merged.labels.is_empty() {
+        println!("  labels: {}", merged.labels.join(", "));
+    }
+    println!("  links: {}", merged.links);
+    println!("  events: {}", merged.events);
+    for dep in &merged.deps {
+        println!("  {}", dep);
+    }
+    if merged.closed {
+        let verb = if dry_run { "would close" } else { "closed" };
+        println!("  {} {}: duplicate of {}", verb, merged.from, merged.into);
+    }
+}
+
+/// Merge issue `from` into `into`. Both IDs must be resolved.
+pub(crate) fn merge_impl(db: &Database, from: &str, into: &str) -> Result<Merged> {
+    let mut events = Vec::new();
+    let merged = db.atomically(|| {
+        events.clear();
+        merge_rows(db, from, into, &mut events)
+    })?;
+    for event in &events {
+        notify_mutation(db, event);
+    }
+    Ok(merged)
+}
+
+/// What merging `from` into `into` would do, leaving the database as it is.
+pub(crate) fn preview(db: &Database, from: &str, into: &str) -> Result<Merged> {
+    db.atomically(|| {
+        // A savepoint, so the preview also rolls back inside a larger write
+        db.conn.execute_batch("SAVEPOINT merge_preview")?;
+        let merged = merge_rows(db, from, into, &mut Vec::new());
+        db.conn
+            .execute_batch("ROLLBACK TO merge_preview; RELEASE merge_preview")?;
+        merged
+    })
+}
+
+/// Move the rows and log the merge's events, collecting them in `events`.
+fn merge_rows(db: &Database, from: &str, into: &str, events: &mut Vec<Event>) -> Result<Merged> {
+    let cannot = |reason: &str| Error::CannotMerge {
+        from: from.to_string(),
+        into: into.to_string(),
+        reason: reason.to_string(),
+    };
+    if from == into {
+        return Err(cannot("an issue cannot be merged into itself"));
+    }
+    let source = db.get_issue(from)?;
+    if db.get_issue(into)?.status.is_terminal() {
+        return Err(cannot("the target is closed"));
+    }
+    let mut log = |event: Event| -> Result<()> {
+        db.log_event(&event)?;
+        events.push(event);
+        Ok(())
+    };
+
+    let mut merged = Merged {
+        from: from.to_string(),
+        into: into.to_string(),
+        events: db.move_events(from, into, MOVED_ACTIONS)?,
+        notes: db.move_notes(from, into)?,
+        links: db.move_links(from, into)?,
+        ..Merged::default()
+    };
+    // Whoever followed the duplicate follows the issue it became
+    db.move_subscriptions(from, into)?;
+
+    let existing = db.get_labels(into)?;
+    for label in db.get_labels(from)? {
+        db.remove_label(from, &label)?;
+        if existing.contains(&label) {
+            continue;
+        }
+        db.add_label(into, &label)?;
+        log(Event::new(into.to_string(), Action::Labeled).with_values(None, Some(label.clone())))?;
+        merged.labels.push(label);
+    }
+
+    for dep in db.get_deps_from(from)? {
+        db.remove_dependency(from, &dep.to_id, dep.relation)?;
+        if repoint(db, into, &dep.to_id, dep.relation)? {
+            if let Some(shown) = describe(dep.relation, &dep.to_id, false) {
+                log(repointed(into, into, &dep.to_id, dep.relation, &shown))?;
+                merged.deps.push(shown);
+            }
+        }
+    }
+    for dep in db.get_deps_to(from)? {
+        db.remove_dependency(&dep.from_id, from, dep.relation)?;
+        if repoint(db, &dep.from_id, into, dep.relation)? {
+            if let Some(shown) = describe(dep.relation, &dep.from_id, true) {
+                log(repointed(into, &dep.from_id, into, dep.relation, &shown))?;
+                merged.deps.push(shown);
+            }
+        }
+    }
+
+    db.add_dependency(from, into, Relation::DuplicateOf)?;
+    let duplicated = Event::new(from.to_string(), Action::Duplicated);
+    log(duplicated.with_values(None, Some(into.to_string())))?;
+    let reason = format!("duplicate of {}", into);
+    if !source.status.is_terminal() {
+        db.update_issue_status(from, Status::Closed)?;
+        db.add_note(from, Status::Closed, &reason)?;
+        log(Event::new(from.to_string(), Action::Closed)
+            .with_values(Some(source.status.to_string()), Some("closed".to_string()))
+            .with_reason(Some(reason)))?;
+        merged.closed = true;
+    }
+    Ok(merged)
+}
+
+/// Add `from_id -> to_id` unless it would link the target to itself or
+/// close a blocking cycle. Returns whether it was added.
+fn repoint(db: &Database, from_id: &str, to_id: &str, relation: Relation) -> Result<bool> {
+    if from_id == to_id {
+        return Ok(false);
+    }
+    match db.add_dependency(from_id, to_id, relation) {
+        Ok(()) => Ok(true),
+        Err(wk_core::Error::CycleDetected) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// The event for re-pointed `from_id -> to_id`: the relation's own action on
+/// `from_id` for those that have one, as `wok dep` logs it, else `related`
+/// on the target with the dependency as it is `shown`.
+fn repointed(into: &str, from_id: &str, to_id: &str, relation: Relation, shown: &str) -> Event {
+    match relation.actions().0 {
+        Action::Related => {
+            Event::new(into.to_string(), Action::Related).with_values(None, Some(shown.to_string()))
+        }
+        action => Event::new(from_id.to_string(), action)
+            .with_values(None, Some(relation.event_value(to_id))),
+    }
+}
+
+/// The dependency as the target's event shows it; `tracked-by` rows mirror
+/// `tracks` rows and incoming `relates-to` rows mirror outgoing ones, so
+/// neither is shown. `incoming` when the target is `to_id`.
+fn describe(relation: Relation, other: &str, incoming: bool) -> Option<String> {
+    let verb = match (relation, incoming) {
+        (Relation::Blocks, false) => "blocks",
+        (Relation::Blocks, true) => "blocked by",
+        (Relation::Tracks, false) => "tracks",
+        (Relation::Tracks, true) => "tracked by",
+        (Relation::References, false) => "references",
+        (Relation::References, true) => "referenced by",
+        (Relation::RelatesTo, false) => "relates to",
+        (Relation::DuplicateOf, false) => "duplicate of",
+        (Relation::DuplicateOf, true) => "duplicated by",
+        (Relation::TrackedBy, _) | (Relation::RelatesTo, true) => return None,
+    };
+    Some(format!("{} {}", verb, other))
+}
+
+#[cfg(test)]
+#[path = "merge_tests.rs"]
+mod tests;
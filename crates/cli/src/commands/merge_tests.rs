@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+#[test]
+fn moves_notes_labels_and_closes_the_source() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Login crash")
+        .create_issue("test-2", IssueType::Task, "Crash on login")
+        .add_note("test-1", "Seen on Safari")
+        .add_label("test-1", "bug")
+        .add_label("test-1", "auth")
+        .add_label("test-2", "bug");
+
+    let merged = merge_impl(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert_eq!(merged.notes, 1);
+    assert_eq!(merged.labels, vec!["auth"]);
+    let notes = ctx.db.get_notes("test-2").unwrap();
+    assert_eq!(notes[0].content, "Seen on Safari");
+    let mut labels = ctx.db.get_labels("test-2").unwrap();
+    labels.sort();
+    assert_eq!(labels, vec!["auth", "bug"]);
+    assert!(ctx.db.get_labels("test-1").unwrap().is_empty());
+
+    let source = ctx.db.get_issue("test-1").unwrap();
+    assert_eq!(source.status, Status::Closed);
+    let notes = ctx.db.get_notes("test-1").unwrap();
+    assert_eq!(notes[0].content, "duplicate of test-2");
+}
+
+#[test]
+fn repoints_dependencies() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Source")
+        .create_issue("test-2", IssueType::Task, "Target")
+        .create_issue("test-3", IssueType::Task, "Blocked")
+        .create_issue("test-4", IssueType::Task, "Blocker")
+        .create_issue("test-5", IssueType::Feature, "Parent")
+        .blocks("test-1", "test-3")
+        .blocks("test-4", "test-1")
+        .tracks("test-5", "test-1");
+
+    let merged = merge_impl(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert_eq!(
+        merged.deps,
+        vec!["blocks test-3", "blocked by test-4", "tracked by test-5"]
+    );
+    assert_eq!(ctx.db.get_blocking("test-2").unwrap(), vec!["test-3"]);
+    assert_eq!(ctx.db.get_blockers("test-2").unwrap(), vec!["test-4"]);
+    assert_eq!(ctx.db.get_tracked("test-5").unwrap(), vec!["test-2"]);
+    assert_eq!(ctx.db.get_tracking("test-2").unwrap(), vec!["test-5"]);
+    let left: Vec<_> = ctx
+        .db
+        .get_deps_from("test-1")
+        .unwrap()
+        .into_iter()
+        .map(|d| (d.relation, d.to_id))
+        .collect();
+    assert_eq!(left, vec![(Relation::DuplicateOf, "test-2".to_string())]);
+    assert!(ctx.db.get_deps_to("test-1").unwrap().is_empty());
+}
+
+#[test]
+fn drops_edges_between_source_and_target_or_closing_a_cycle() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Source")
+        .create_issue("test-2", IssueType::Task, "Target")
+        .create_issue("test-3", IssueType::Task, "Other")
+        .blocks("test-1", "test-2")
+        .blocks("test-1", "test-3")
+        .blocks("test-3", "test-2");
+
+    let merged = merge_impl(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert!(merged.deps.is_empty());
+    assert_eq!(ctx.db.get_blockers("test-2").unwrap(), vec!["test-3"]);
+    assert!(ctx.db.get_blocking("test-2").unwrap().is_empty());
+}
+
+#[test]
+fn rejects_merging_into_itself_or_a_closed_issue() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Source")
+        .create_completed("test-2", IssueType::Task, "Done");
+
+    let err = merge_impl(&ctx.db, "test-1", "test-1").unwrap_err();
+    assert!(matches!(err, Error::CannotMerge { .. }));
+    let err = merge_impl(&ctx.db, "test-1", "test-2").unwrap_err();
+    assert!(err.to_string().contains("the target is closed"), "{}", err);
+    assert_eq!(ctx.db.get_issue("test-1").unwrap().status, Status::Todo);
+}
+
+#[test]
+fn moves_links_and_the_events_that_recorded_them() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Source")
+        .create_issue("test-2", IssueType::Task, "Target")
+        .add_label("test-1", "auth");
+    for (id, url) in [
+        ("test-1", "https://github.com/org/repo/issues/1"),
+        ("test-1", "https://github.com/org/repo/issues/2"),
+        ("test-2", "https://github.com/org/repo/issues/2"),
+    ] {
+        let mut link = crate::models::Link::new(id.to_string());
+        link.url = Some(url.to_string());
+        ctx.db.add_link(&link).unwrap();
+    }
+    let merged = merge_impl(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert_eq!(merged.links, 1);
+    assert_eq!(merged.events, 1);
+    assert!(merged.closed);
+    assert_eq!(ctx.db.get_links("test-2").unwrap().len(), 2);
+    assert!(ctx.db.get_links("test-1").unwrap().is_empty());
+
+    let actions = |id: &str| -> Vec<Action> {
+        ctx.db
+            .get_events(id)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.action)
+            .collect()
+    };
+    assert_eq!(
+        actions("test-1"),
+        vec![Action::Created, Action::Duplicated, Action::Closed]
+    );
+    assert_eq!(
+        actions("test-2"),
+        vec![Action::Created, Action::Labeled, Action::Labeled]
+    );
+    let duplicated = &ctx.db.get_events("test-1").unwrap()[1];
+    assert_eq!(duplicated.new_value.as_deref(), Some("test-2"));
+    assert_eq!(ctx.db.get_duplicate_of("test-1").unwrap(), vec!["test-2"]);
+}
+
+#[test]
+fn repointed_associations_log_their_own_actions() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Source")
+        .create_issue("test-2", IssueType::Task, "Target")
+        .create_issue("test-3", IssueType::Task, "Related")
+        .create_issue("test-4", IssueType::Task, "Copy");
+    for (from, to, relation) in [
+        ("test-1", "test-3", Relation::RelatesTo),
+        ("test-3", "test-1", Relation::RelatesTo),
+        ("test-4", "test-1", Relation::DuplicateOf),
+    ] {
+        ctx.db.add_dependency(from, to, relation).unwrap();
+    }
+
+    let merged = merge_impl(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert_eq!(
+        merged.deps,
+        vec!["relates to test-3", "duplicated by test-4"]
+    );
+    let last = |id: &str| {
+        let event = ctx.db.get_events(id).unwrap().pop().unwrap();
+        (event.action, event.new_value)
+    };
+    assert_eq!(last("test-2"), (Action::Associated, Some("test-3".into())));
+    assert_eq!(last("test-4"), (Action::Duplicated, Some("test-2".into())));
+    assert_eq!(ctx.db.get_duplicate_of("test-4").unwrap(), vec!["test-2"]);
+}
+
+#[test]
+fn merging_a_closed_source_keeps_its_status() {
+    let mut ctx = TestContext::new();
+    ctx.create_completed("test-1", IssueType::Task, "Source")
+        .create_issue("test-2", IssueType::Task, "Target");
+
+    let merged = merge_impl(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert!(!merged.closed);
+    assert_eq!(ctx.db.get_issue("test-1").unwrap().status, Status::Done);
+}
+
+#[test]
+fn preview_reports_without_changing_anything() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Source")
+        .create_issue("test-2", IssueType::Task, "Target")
+        .create_issue("test-3", IssueType::Task, "Blocked")
+        .add_note("test-1", "Seen on Safari")
+        .add_label("test-1", "auth")
+        .blocks("test-1", "test-3");
+    let events = ctx.db.get_events("test-2").unwrap();
+
+    let merged = preview(&ctx.db, "test-1", "test-2").unwrap();
+
+    assert_eq!(merged.notes, 1);
+    assert_eq!(merged.labels, vec!["auth"]);
+    assert_eq!(merged.deps, vec!["blocks test-3"]);
+    assert!(merged.closed);
+    assert_eq!(ctx.db.get_issue("test-1").unwrap().status, Status::Todo);
+    assert_eq!(ctx.db.get_notes("test-1").unwrap().len(), 1);
+    assert_eq!(ctx.db.get_labels("test-1").unwrap(), vec!["auth"]);
+    assert!(ctx.db.get_labels("test-2").unwrap().is_empty());
+    assert_eq!(ctx.db.get_blocking("test-1").unwrap(), vec!["test-3"]);
+    assert_eq!(ctx.db.get_events("test-2").unwrap(), events);
+}
@@ -1,40 +1,72 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+pub mod activity;
+pub mod alias;
+pub mod assign;
+pub mod backup;
+pub mod branch;
+pub mod complete;
 pub mod config;
 pub mod daemon;
+pub mod dedupe;
 pub mod dep;
+pub mod digest;
 pub mod edit;
 pub mod export;
+pub mod export_beads;
 pub mod filtering;
+pub mod grouping;
 #[cfg(test)]
 pub mod hlc_persistence;
 pub mod hook;
 pub mod hooks;
 pub mod import;
+pub mod import_csv;
+pub mod import_forge;
+pub mod import_map;
 pub mod init;
 pub mod label;
+pub mod labels;
 pub mod lifecycle;
 pub mod link;
 pub mod list;
 pub mod log;
+pub mod merge;
+pub mod mode;
 pub mod new;
+pub mod next;
 pub mod note;
+pub mod open;
+pub mod ordering;
+pub mod paging;
+pub mod pr;
 pub mod prime;
+pub mod queue;
 pub mod ready;
+pub mod rpc;
+pub mod rules;
 pub mod schema;
 pub mod search;
+pub mod serve;
+pub mod serve_pages;
 pub mod show;
+pub mod sorting;
+pub mod split;
+pub mod standup;
+pub mod subscribe;
+pub mod sweep;
 #[cfg(test)]
 #[path = "mod_tests.rs"]
 pub mod testing;
 pub mod tree;
+pub mod upgrade;
 
 use std::path::PathBuf;
 
 use crate::config::{find_work_dir, get_db_path, Config};
 use crate::db::Database;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::models::Event;
 
 /// Helper to open the database from the current context.
@@ -42,24 +74,112 @@ pub fn open_db() -> Result<(Database, Config, PathBuf)> {
     let work_dir = find_work_dir()?;
     let config = Config::load(&work_dir)?;
     let db_path = get_db_path(&work_dir, &config);
-    let db = crate::time_phase!("db::open", { Database::open(&db_path)? });
+    let mut db = crate::time_phase!("db::open", {
+        if crate::read_only::is_read_only() {
+            Database::open_read_only(&db_path)?
+        } else {
+            Database::open(&db_path)?
+        }
+    });
+    crate::trace::watch(&db.conn);
+    db.set_actor(assign::current_actor(&config, &work_dir));
     Ok((db, config, work_dir))
 }
 
-/// Apply a mutation by logging an event to the local database.
+/// Require the one issue in `ids` to be at `revision`, for `--if-match`.
+///
+/// The command's first change to the issue then fails with a conflict if
+/// another writer changed it in between.
+pub(crate) fn expect_revision(db: &Database, ids: &[String], revision: Option<u32>) -> Result<()> {
+    let Some(revision) = revision else {
+        return Ok(());
+    };
+    let [id] = ids else {
+        return Err(Error::IncompatibleOptions {
+            option: "--if-match",
+            with: "several issues",
+        });
+    };
+    let resolved_id = db.resolve_id(id)?;
+    db.expect_revision(&resolved_id, revision)?;
+    Ok(())
+}
+
+/// Apply a mutation, logging its event in the same transaction.
 ///
-/// This helper handles the common pattern of logging an event for all
-/// issue mutations to ensure a consistent audit trail. After logging,
-/// it triggers any configured issue hooks.
-pub fn apply_mutation(db: &Database, event: Event) -> Result<()> {
-    db.log_event(&event)?;
+/// `change` makes the change, and it is stored with `event` or not at all,
+/// so the audit trail never misses a change or records one that failed.
+/// After it commits, the event triggers any configured issue hooks and
+/// automation rules. `change` runs again if the database was busy, so it
+/// must be repeatable.
+pub fn apply_mutation<T, E>(
+    db: &Database,
+    event: Event,
+    change: impl FnMut() -> std::result::Result<T, E>,
+) -> Result<T>
+where
+    E: From<wk_core::Error> + std::error::Error + 'static,
+    Error: From<E>,
+{
+    let value = db.record(&event, change)?;
+    notify_mutation(db, &event);
+    Ok(value)
+}
+
+/// Like [`apply_mutation`], for a change whose event depends on how it went:
+/// `change` returns the event to log, such as one carrying an old value.
+pub fn apply_mutation_with<E>(
+    db: &Database,
+    mut change: impl FnMut() -> std::result::Result<Event, E>,
+) -> Result<Event>
+where
+    E: From<wk_core::Error> + std::error::Error + 'static,
+    Error: From<E>,
+{
+    let event = db.atomically(|| -> std::result::Result<Event, E> {
+        let event = change()?;
+        db.log_event(&event)?;
+        Ok(event)
+    })?;
+    notify_mutation(db, &event);
+    Ok(event)
+}
 
-    // Trigger hooks (fire-and-forget, errors are logged but don't fail the mutation)
+/// Like [`apply_mutation`], for a change that may find nothing to do: the
+/// event is logged, and hooks run, only when `change` returns true.
+pub fn apply_mutation_if<E>(
+    db: &Database,
+    event: Event,
+    mut change: impl FnMut() -> std::result::Result<bool, E>,
+) -> Result<bool>
+where
+    E: From<wk_core::Error> + std::error::Error + 'static,
+    Error: From<E>,
+{
+    let changed = db.atomically(|| -> std::result::Result<bool, E> {
+        let changed = change()?;
+        if changed {
+            db.log_event(&event)?;
+        }
+        Ok(changed)
+    })?;
+    if changed {
+        notify_mutation(db, &event);
+    }
+    Ok(changed)
+}
+
+/// Trigger issue hooks and automation rules for an already logged event.
+///
+/// Errors are logged but don't fail the mutation. Commands that log events
+/// inside a transaction call this once it has committed.
+pub(crate) fn notify_mutation(db: &Database, event: &Event) {
     if let Ok(work_dir) = find_work_dir() {
-        if let Err(e) = crate::hooks::run_hooks_for_event(db, &work_dir, &event) {
+        if let Err(e) = crate::hooks::run_hooks_for_event(db, &work_dir, event) {
             eprintln!("warning: hook error: {}", e);
         }
+        if let Err(e) = crate::rules::run_rules_for_event(db, &work_dir, event) {
+            eprintln!("warning: rule error: {}", e);
+        }
     }
-
-    Ok(())
 }
@@ -94,6 +94,7 @@ impl TestContext {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
         self.db
             .create_issue(&issue)
@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok config mode` - move a project between private and shared mode.
+//!
+//! Private mode keeps the project's issues in `.wok/issues.db`; shared mode
+//! keeps them in the user-level database the daemon serves. Switching copies
+//! the project's issues with their labels, notes, events, dependencies,
+//! links, short IDs and prefixes into the other database in one transaction,
+//! then updates the config. The source database is left as it was.
+//!
+//! In a workspace, only the root switches; its members follow it.
+//!
+//! An issue whose ID is already taken in the target by a different issue
+//! (a different creation time) gets a fresh ID; one that is already there
+//! from an earlier switch is skipped.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use rusqlite::{params, OptionalExtension};
+
+use crate::cli::StorageMode;
+use crate::config::{find_work_dir, get_db_path, wok_state_dir, write_gitignore, Config};
+use crate::daemon;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::id::generate_unique_id;
+use crate::workspace::{self, Registry};
+
+use super::backup::backup_before;
+
+/// What [`migrate`] copied.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct MigrateReport {
+    pub copied: usize,
+    /// Issues already in the target from an earlier switch.
+    pub skipped: usize,
+    /// `(old, new)` IDs of issues renamed to avoid a collision.
+    pub renamed: Vec<(String, String)>,
+    /// Dependencies left behind because the other issue is not in the target.
+    pub dropped_deps: usize,
+}
+
+/// Switch the current project to `mode`.
+pub fn run(mode: StorageMode) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let mut config = Config::load_file(&work_dir)?;
+    if let Some(root) = config.workspace_root(&work_dir) {
+        let root_dir = root.parent().unwrap_or(&root);
+        return Err(Error::Config(format!(
+            "this project takes its mode from its workspace\n  hint: run 'wok config mode' in {}",
+            root_dir.display()
+        )));
+    }
+    let private = mode == StorageMode::Private;
+    if config.private == private {
+        println!("Already in {} mode", mode_name(private));
+        return Ok(());
+    }
+
+    // The daemon keeps the shared database open; copying underneath it
+    // would race its writes.
+    let state_dir = crate::config::wok_state_dir();
+    if let Some(info) = daemon::detect_daemon(&state_dir)? {
+        return Err(Error::ModeDaemonRunning { pid: info.pid });
+    }
+
+    if private && config.prefix.is_empty() {
+        return Err(Error::Config(
+            "a project needs a prefix to move its issues out of the shared database".to_string(),
+        ));
+    }
+
+    let source_path = get_db_path(&work_dir, &config);
+    config.private = private;
+    let target_path = get_db_path(&work_dir, &config);
+    if let Some(dir) = target_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let report = if source_path.exists() {
+        // Bring the source up to the current schema before reading it.
+        drop(Database::open(&source_path)?);
+        let target_existed = target_path.exists();
+        let target = Database::open(&target_path)?;
+        if target_existed {
+            let backup = backup_before(&target.conn, &config, &work_dir, "mode")?;
+            println!(
+                "Backed up {} to {}",
+                target_path.display(),
+                backup.display()
+            );
+        }
+        // The shared database holds every project; take only the issues of
+        // this one and the members of its workspace.
+        let prefixes = if private {
+            Some(project_prefixes(&work_dir, &config))
+        } else {
+            None
+        };
+        migrate(&source_path, &target, prefixes.as_deref())?
+    } else {
+        Database::open(&target_path)?;
+        MigrateReport::default()
+    };
+
+    config.save(&work_dir)?;
+    write_gitignore(&work_dir, private)?;
+
+    println!(
+        "Switched to {} mode ({})",
+        mode_name(private),
+        target_path.display()
+    );
+    print_report(&report);
+    println!(
+        "The {} database at {} was left in place.",
+        mode_name(!private),
+        source_path.display()
+    );
+    Ok(())
+}
+
+fn mode_name(private: bool) -> &'static str {
+    if private {
+        "private"
+    } else {
+        "shared"
+    }
+}
+
+fn print_report(report: &MigrateReport) {
+    println!("  copied: {}", report.copied);
+    if report.skipped > 0 {
+        println!("  already present: {}", report.skipped);
+    }
+    if !report.renamed.is_empty() {
+        println!("  renamed: {}", report.renamed.len());
+        for (old, new) in &report.renamed {
+            println!("    {} -> {}", old, new);
+        }
+    }
+    if report.dropped_deps > 0 {
+        eprintln!(
+            "warning: {} dependency(ies) on issues outside the project were not copied",
+            report.dropped_deps
+        );
+    }
+}
+
+/// The prefixes of the project in `work_dir` and of its workspace members.
+fn project_prefixes(work_dir: &Path, config: &Config) -> Vec<String> {
+    let mut prefixes = vec![config.prefix.clone()];
+    if let Some(id) = &config.workspace_id {
+        let registry = Registry::load(&wok_state_dir()).unwrap_or_default();
+        for project in workspace::projects(&registry, id, work_dir, work_dir) {
+            if let Ok(member) = Config::load_file(&project) {
+                if !member.prefix.is_empty() && !prefixes.contains(&member.prefix) {
+                    prefixes.push(member.prefix);
+                }
+            }
+        }
+    }
+    prefixes
+}
+
+/// Copy the issues in the database at `source` into `target`, or only those
+/// with one of `prefixes` when given, in one transaction.
+pub(crate) fn migrate(
+    source: &Path,
+    target: &Database,
+    prefixes: Option<&[String]>,
+) -> Result<MigrateReport> {
+    let conn = &target.conn;
+    conn.execute("ATTACH DATABASE ?1 AS src", [source.to_string_lossy()])?;
+    let result = (|| -> Result<MigrateReport> {
+        let tx = conn.unchecked_transaction()?;
+        let report = copy_attached(&tx, prefixes)?;
+        tx.commit()?;
+        Ok(report)
+    })();
+    conn.execute("DETACH DATABASE src", [])?;
+    result
+}
+
+/// Copy from the attached `src` database into `main`.
+fn copy_attached(
+    conn: &rusqlite::Connection,
+    prefixes: Option<&[String]>,
+) -> Result<MigrateReport> {
+    let mut report = MigrateReport::default();
+
+    let mut stmt =
+        conn.prepare("SELECT id, title, created_at FROM src.issues ORDER BY created_at, id")?;
+    let issues = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .filter(|row| match (row, prefixes) {
+            (Ok((id, _, _)), Some(prefixes)) => id
+                .split_once('-')
+                .is_some_and(|(prefix, _)| prefixes.iter().any(|p| p == prefix)),
+            _ => true,
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    // Decide each issue's ID in the target.
+    conn.execute_batch(
+        "CREATE TEMP TABLE IF NOT EXISTS migrate_ids (old TEXT PRIMARY KEY, new TEXT NOT NULL);
+         DELETE FROM migrate_ids;",
+    )?;
+    let mut planned: HashSet<String> = HashSet::new();
+    for (id, title, created_at) in &issues {
+        let existing: Option<String> = conn
+            .query_row(
+                "SELECT created_at FROM main.issues WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let new_id = match existing {
+            None if !planned.contains(id) => id.clone(),
+            Some(existing) if existing == *created_at => {
+                report.skipped += 1;
+                continue;
+            }
+            _ => {
+                let created = chrono::DateTime::parse_from_rfc3339(created_at)
+                    .map(|dt| dt.with_timezone(&chrono::Utc))
+                    .unwrap_or_else(|_| chrono::Utc::now());
+                let id_prefix = id.split_once('-').map_or(id.as_str(), |(p, _)| p);
+                let new_id = generate_unique_id(id_prefix, title, &created, |candidate| {
+                    planned.contains(candidate) || issue_exists(conn, candidate)
+                });
+                report.renamed.push((id.clone(), new_id.clone()));
+                new_id
+            }
+        };
+        planned.insert(new_id.clone());
+        conn.execute(
+            "INSERT INTO migrate_ids (old, new) VALUES (?1, ?2)",
+            params![id, new_id],
+        )?;
+    }
+    report.copied = planned.len();
+
+    conn.execute_batch(
+        "INSERT INTO main.issues (id, type, title, description, status, assignee,
+             created_at, updated_at, closed_at, last_status_hlc, last_title_hlc,
+             last_type_hlc, last_description_hlc, last_assignee_hlc, revision)
+         SELECT m.new, i.type, i.title, i.description, i.status, i.assignee,
+             i.created_at, i.updated_at, i.closed_at, i.last_status_hlc, i.last_title_hlc,
+             i.last_type_hlc, i.last_description_hlc, i.last_assignee_hlc, i.revision
+         FROM src.issues i JOIN migrate_ids m ON i.id = m.old;
+
+         INSERT OR IGNORE INTO main.labels (issue_id, label)
+         SELECT m.new, l.label FROM src.labels l JOIN migrate_ids m ON l.issue_id = m.old;
+
+         INSERT INTO main.notes (issue_id, status, content, created_at, kind, actor,
+             deleted_at)
+         SELECT m.new, n.status, n.content, n.created_at, n.kind, n.actor, n.deleted_at
+         FROM src.notes n JOIN migrate_ids m ON n.issue_id = m.old ORDER BY n.id;
+
+         INSERT INTO main.events (issue_id, action, old_value, new_value, reason, created_at,
+             actor)
+         SELECT m.new, e.action, e.old_value, e.new_value, e.reason, e.created_at, e.actor
+         FROM src.events e JOIN migrate_ids m ON e.issue_id = m.old ORDER BY e.id;
+
+         INSERT INTO main.links (issue_id, link_type, url, external_id, rel, created_at,
+             resolved_at, deleted_at)
+         SELECT m.new, l.link_type, l.url, l.external_id, l.rel, l.created_at, l.resolved_at,
+             l.deleted_at
+         FROM src.links l JOIN migrate_ids m ON l.issue_id = m.old ORDER BY l.id;",
+    )?;
+
+    // Dependencies touching a copied issue, with both ends renamed as
+    // needed. An end that is neither copied nor already in the target stays
+    // behind.
+    let deps_sql = "FROM src.deps d
+         LEFT JOIN migrate_ids mf ON d.from_id = mf.old
+         LEFT JOIN migrate_ids mt ON d.to_id = mt.old
+         WHERE (mf.old IS NOT NULL OR mt.old IS NOT NULL)";
+    let candidates: i64 = conn.query_row(&format!("SELECT COUNT(*) {}", deps_sql), [], |row| {
+        row.get(0)
+    })?;
+    let inserted = conn.execute(
+        &format!(
+            "INSERT OR IGNORE INTO main.deps (from_id, to_id, rel, created_at)
+             SELECT COALESCE(mf.new, d.from_id), COALESCE(mt.new, d.to_id), d.rel, d.created_at
+             {}
+             AND COALESCE(mf.new, d.from_id) IN (SELECT id FROM main.issues)
+             AND COALESCE(mt.new, d.to_id) IN (SELECT id FROM main.issues)",
+            deps_sql
+        ),
+        [],
+    )?;
+    report.dropped_deps = usize::try_from(candidates)
+        .unwrap_or(0)
+        .saturating_sub(inserted);
+
+    // Keep short ID numbers where they are free, otherwise take the next.
+    let mut stmt = conn.prepare(
+        "SELECT m.new, s.num FROM migrate_ids m
+         LEFT JOIN src.short_ids s ON s.issue_id = m.old",
+    )?;
+    let short_ids = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Option<u32>>(1)?))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let mut prefixes = BTreeSet::new();
+    for (id, num) in short_ids {
+        let Some((prefix, _)) = id.split_once('-') else {
+            continue;
+        };
+        prefixes.insert(prefix.to_string());
+        let kept = match num {
+            Some(num) => conn.execute(
+                "INSERT OR IGNORE INTO main.short_ids (issue_id, prefix, num) VALUES (?1, ?2, ?3)",
+                params![id, prefix, num],
+            )?,
+            None => 0,
+        };
+        if kept == 0 {
+            conn.execute(
+                "INSERT OR IGNORE INTO main.short_ids (issue_id, prefix, num)
+                 SELECT ?1, ?2, COALESCE(MAX(num), 0) + 1 FROM main.short_ids WHERE prefix = ?2",
+                params![id, prefix],
+            )?;
+        }
+    }
+
+    for prefix in &prefixes {
+        conn.execute(
+            "INSERT OR IGNORE INTO main.prefixes (prefix, created_at, issue_count)
+             SELECT prefix, created_at, 0 FROM src.prefixes WHERE prefix = ?1
+             UNION ALL SELECT ?1, ?2, 0 LIMIT 1",
+            params![prefix, chrono::Utc::now().to_rfc3339()],
+        )?;
+        conn.execute(
+            "UPDATE main.prefixes SET issue_count =
+                 (SELECT COUNT(*) FROM main.issues WHERE substr(id, 1, length(?1) + 1) = ?1 || '-')
+             WHERE prefix = ?1",
+            [prefix],
+        )?;
+    }
+
+    Ok(report)
+}
+
+fn issue_exists(conn: &rusqlite::Connection, id: &str) -> bool {
+    conn.query_row("SELECT 1 FROM main.issues WHERE id = ?1", [id], |_| Ok(()))
+        .optional()
+        .ok()
+        .flatten()
+        .is_some()
+}
+
+#[cfg(test)]
+#[path = "mode_tests.rs"]
+mod tests;
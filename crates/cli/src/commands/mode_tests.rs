@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+/// Write `ctx`'s database to a file, as the source of a switch.
+fn save(ctx: &TestContext, dir: &TempDir) -> PathBuf {
+    let path = dir.path().join("source.db");
+    ctx.db
+        .conn
+        .execute("VACUUM INTO ?1", [path.to_string_lossy()])
+        .unwrap();
+    path
+}
+
+fn source() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-a1", IssueType::Task, "First")
+        .create_issue("test-b2", IssueType::Bug, "Second")
+        .add_label("test-a1", "urgent")
+        .add_note("test-a1", "Some context")
+        .blocks("test-a1", "test-b2");
+    ctx
+}
+
+#[test]
+fn copies_issues_with_their_rows() {
+    let dir = TempDir::new().unwrap();
+    let path = save(&source(), &dir);
+    let target = TestContext::new();
+
+    let report = migrate(&path, &target.db, None).unwrap();
+    assert_eq!(report.copied, 2);
+    assert!(report.renamed.is_empty());
+
+    assert_eq!(target.db.get_issue("test-b2").unwrap().title, "Second");
+    assert_eq!(target.db.get_labels("test-a1").unwrap(), vec!["urgent"]);
+    assert_eq!(target.db.get_notes("test-a1").unwrap().len(), 1);
+    assert_eq!(target.db.get_blockers("test-b2").unwrap(), vec!["test-a1"]);
+    assert!(!target.db.get_events("test-a1").unwrap().is_empty());
+    assert!(target.db.resolve_short_id("test-1").unwrap().is_some());
+    let prefixes = target.db.list_prefixes().unwrap();
+    assert!(prefixes
+        .iter()
+        .any(|p| p.prefix == "test" && p.issue_count == 2));
+}
+
+#[test]
+fn keeps_resolved_external_blockers_resolved() {
+    use crate::models::{Link, LinkRel};
+    use chrono::Utc;
+
+    let dir = TempDir::new().unwrap();
+    let ctx = source();
+    let link = Link::new("test-a1".to_string())
+        .with_url("https://github.com/vendor/lib/issues/9".to_string())
+        .with_rel(LinkRel::Blocks);
+    let link_id = ctx.db.add_link(&link).unwrap();
+    ctx.db.set_link_resolved(link_id, Some(Utc::now())).unwrap();
+    let path = save(&ctx, &dir);
+    let target = TestContext::new();
+
+    migrate(&path, &target.db, None).unwrap();
+
+    let links = target.db.get_links("test-a1").unwrap();
+    assert_eq!(links.len(), 1);
+    assert!(links[0].resolved_at.is_some());
+}
+
+#[test]
+fn keeps_who_made_each_change() {
+    let dir = TempDir::new().unwrap();
+    let mut ctx = TestContext::new();
+    ctx.db.set_actor(Some("claude-1".to_string()));
+    ctx.create_issue("test-a1", IssueType::Task, "First")
+        .add_note("test-a1", "Some context");
+    let path = save(&ctx, &dir);
+    let target = TestContext::new();
+
+    migrate(&path, &target.db, None).unwrap();
+
+    let notes = target.db.get_notes("test-a1").unwrap();
+    assert_eq!(notes[0].actor.as_deref(), Some("claude-1"));
+    let events = target.db.get_events("test-a1").unwrap();
+    assert!(events
+        .iter()
+        .all(|e| e.actor.as_deref() == Some("claude-1")));
+}
+
+#[test]
+fn prefix_limits_what_is_copied() {
+    let dir = TempDir::new().unwrap();
+    let mut ctx = source();
+    ctx.create_issue("other-c3", IssueType::Task, "Elsewhere")
+        .blocks("other-c3", "test-a1");
+    let path = save(&ctx, &dir);
+    let target = TestContext::new();
+
+    let report = migrate(&path, &target.db, Some(&["test".to_string()])).unwrap();
+    assert_eq!(report.copied, 2);
+    assert_eq!(report.dropped_deps, 1);
+    assert!(target.db.get_issue("other-c3").is_err());
+}
+
+#[test]
+fn renames_issues_whose_id_is_taken() {
+    let dir = TempDir::new().unwrap();
+    let mut target = TestContext::new();
+    target.create_issue("test-a1", IssueType::Task, "Someone else's");
+    let path = save(&source(), &dir);
+
+    let report = migrate(&path, &target.db, None).unwrap();
+    assert_eq!(report.renamed.len(), 1);
+    let (old, new) = &report.renamed[0];
+    assert_eq!(old, "test-a1");
+
+    assert_eq!(
+        target.db.get_issue("test-a1").unwrap().title,
+        "Someone else's"
+    );
+    assert_eq!(target.db.get_issue(new).unwrap().title, "First");
+    assert_eq!(target.db.get_labels(new).unwrap(), vec!["urgent"]);
+    assert_eq!(
+        target.db.get_blockers("test-b2").unwrap(),
+        vec![new.clone()]
+    );
+}
+
+#[test]
+fn switching_twice_skips_issues_already_there() {
+    let dir = TempDir::new().unwrap();
+    let path = save(&source(), &dir);
+    let target = TestContext::new();
+
+    migrate(&path, &target.db, None).unwrap();
+    let report = migrate(&path, &target.db, None).unwrap();
+    assert_eq!(report.copied, 0);
+    assert_eq!(report.skipped, 2);
+    assert_eq!(target.db.get_notes("test-a1").unwrap().len(), 1);
+}
@@ -7,15 +7,19 @@ use crate::db::Database;
 
 use super::open_db;
 use crate::error::{Error, Result};
+use crate::estimate::Estimate;
 use crate::id::{generate_unique_id, validate_prefix};
 use crate::models::{Action, Event, Issue, IssueType, Status};
+use crate::t;
 use crate::validate::{
     validate_and_normalize_title, validate_and_trim_note, validate_assignee, validate_label,
 };
 
-use super::apply_mutation;
+use super::assign::{check_roster, resolve_assignee};
+use super::dedupe;
 use super::dep;
 use super::link::add_link_impl;
+use super::{apply_mutation, notify_mutation};
 
 use crate::cli::OutputFormat;
 
@@ -24,11 +28,12 @@ use crate::cli::OutputFormat;
 pub fn run(
     type_or_title: String,
     title: Option<String>,
-    labels: Vec<String>,
+    mut labels: Vec<String>,
     note: Option<String>,
     links: Vec<String>,
     assignee: Option<String>,
     priority: Option<u8>,
+    estimate: Option<String>,
     description: Option<String>,
     blocks: Vec<String>,
     blocked_by: Vec<String>,
@@ -36,9 +41,26 @@ pub fn run(
     tracked_by: Vec<String>,
     output: OutputFormat,
     prefix: Option<String>,
+    force: bool,
 ) -> Result<()> {
-    let (db, config, _work_dir) = open_db()?;
-    run_impl(
+    let (db, config, work_dir) = open_db()?;
+    let assignee = match assignee {
+        Some(who) => {
+            let who = resolve_assignee(&who, &config, &work_dir)?;
+            check_roster(&who, &config)?;
+            Some(who)
+        }
+        None => None,
+    };
+    if let Some(estimate) = estimate {
+        labels.push(estimate.parse::<Estimate>()?.label());
+    }
+    let priority_label = priority.map(|p| format!("priority:{}", p));
+    for label in expand_labels(&labels).iter().chain(&priority_label) {
+        config.labels.check(label)?;
+    }
+    let trackers = expand_ids(&tracked_by);
+    let id = run_impl(
         &db,
         &config.prefix,
         type_or_title,
@@ -55,7 +77,15 @@ pub fn run(
         tracked_by,
         output,
         prefix,
-    )
+    )?;
+    if !force {
+        dedupe::warn_similar(&db, &id)?;
+    }
+    let trackers = trackers
+        .iter()
+        .map(|id| db.resolve_id(id))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    dep::promote_epics(&db, &trackers, &config.epic, false)
 }
 
 /// Expand comma-separated labels into individual labels.
@@ -92,7 +122,7 @@ const MAX_ID_COLLISION_RETRIES: u32 = 10;
 /// that an ID doesn't exist and then both try to insert, causing a UNIQUE
 /// constraint violation. This function retries with a new timestamp when
 /// this race condition occurs.
-fn create_issue_with_retry(
+pub(crate) fn create_issue_with_retry(
     db: &Database,
     prefix: &str,
     issue_type: IssueType,
@@ -120,6 +150,7 @@ fn create_issue_with_retry(
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         };
 
         match db.create_issue(&issue).map_err(crate::error::Error::from) {
@@ -135,6 +166,25 @@ fn create_issue_with_retry(
     Err(Error::IdGenerationFailed)
 }
 
+/// Create an issue with [`create_issue_with_retry`], counting it against its
+/// prefix and logging its `created` event in the same transaction.
+pub(crate) fn create_issue_logged(
+    db: &Database,
+    prefix: &str,
+    issue_type: IssueType,
+    title: &str,
+    assignee: Option<String>,
+) -> Result<(String, Issue)> {
+    let (id, issue) = db.atomically(|| -> Result<(String, Issue)> {
+        let created = create_issue_with_retry(db, prefix, issue_type, title, assignee.clone())?;
+        db.increment_prefix_count(prefix)?;
+        db.log_event(&Event::new(created.0.clone(), Action::Created))?;
+        Ok(created)
+    })?;
+    notify_mutation(db, &Event::new(id.clone(), Action::Created));
+    Ok((id, issue))
+}
+
 /// Check if a rusqlite error is a UNIQUE constraint violation.
 fn is_unique_constraint_error(err: &rusqlite::Error) -> bool {
     match err {
@@ -145,7 +195,8 @@ fn is_unique_constraint_error(err: &rusqlite::Error) -> bool {
     }
 }
 
-/// Internal implementation that accepts db/config for testing.
+/// Internal implementation that accepts db/config for testing. Returns the
+/// new issue's ID.
 #[allow(clippy::too_many_arguments)] // TODO(refactor): Consider using an options struct to bundle parameters
 pub(crate) fn run_impl(
     db: &Database,
@@ -164,7 +215,7 @@ pub(crate) fn run_impl(
     tracked_by: Vec<String>,
     output: OutputFormat,
     prefix: Option<String>,
-) -> Result<()> {
+) -> Result<String> {
     // Expand comma-separated labels into individual labels
     let mut labels = expand_labels(&labels);
 
@@ -235,7 +286,7 @@ pub(crate) fn run_impl(
     // Race condition: two processes may generate the same ID simultaneously
     // if they check existence at the same time. We retry with a new timestamp
     // if a UNIQUE constraint violation occurs.
-    let (id, issue) = create_issue_with_retry(
+    let (id, issue) = create_issue_logged(
         db,
         &effective_prefix,
         issue_type,
@@ -243,19 +294,13 @@ pub(crate) fn run_impl(
         assignee,
     )?;
 
-    // Increment the prefix count after successful issue creation
-    db.increment_prefix_count(&effective_prefix)?;
-
-    // Log creation event
-    apply_mutation(db, Event::new(id.clone(), Action::Created))?;
-
     // Validate and add labels
     for label in &labels {
         validate_label(label)?;
-        db.add_label(&id, label)?;
         apply_mutation(
             db,
             Event::new(id.clone(), Action::Labeled).with_values(None, Some(label.clone())),
+            || db.add_label(&id, label),
         )?;
     }
 
@@ -263,10 +308,13 @@ pub(crate) fn run_impl(
     if let Some(note_content) = final_note {
         let trimmed_note = validate_and_trim_note(&note_content)?;
         if !trimmed_note.is_empty() {
-            db.add_note(&id, Status::Todo, &trimmed_note)?;
             apply_mutation(
                 db,
-                Event::new(id.clone(), Action::Noted).with_values(None, Some(trimmed_note)),
+                Event::new(id.clone(), Action::Noted).with_values(None, Some(trimmed_note.clone())),
+                || {
+                    db.add_note(&id, Status::Todo, &trimmed_note)?;
+                    db.record_mentions(&id, &trimmed_note)
+                },
             )?;
         }
     }
@@ -296,8 +344,14 @@ pub(crate) fn run_impl(
     match output {
         OutputFormat::Text => {
             println!(
-                "Created [{}] ({}) {}: {}",
-                issue_type, issue.status, id, normalized.title
+                "{}",
+                t!(
+                    "new.created",
+                    issue_type = issue_type,
+                    status = issue.status,
+                    id = id,
+                    title = normalized.title
+                )
             );
         }
         OutputFormat::Id => {
@@ -317,7 +371,7 @@ pub(crate) fn run_impl(
         }
     }
 
-    Ok(())
+    Ok(id)
 }
 
 #[cfg(test)]
@@ -138,6 +138,7 @@ fn test_duplicate_id_handling() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     let result = ctx.db.create_issue(&issue);
     assert!(result.is_err());
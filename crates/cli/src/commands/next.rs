@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Pick the single best issue to work on next.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::cli::OutputFormat;
+use crate::config::WipConfig;
+use crate::db::Database;
+use crate::display::format_issue_line;
+use crate::error::Result;
+use crate::models::{Action, Event, Issue, IssueType, Status};
+use crate::schema::IssueJson;
+
+use super::assign::{check_roster, current_user, resolve_assignee};
+use super::filtering::{
+    matches_filter_groups, matches_label_groups, matches_prefix, parse_filter_groups, LabelMatcher,
+};
+use super::lifecycle::check_wip_limit;
+use super::{notify_mutation, open_db};
+
+pub fn run(
+    issue_type: Vec<String>,
+    label: Vec<String>,
+    prefix: Option<String>,
+    assignee: Option<String>,
+    claim: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let (db, config, work_dir) = open_db()?;
+    let who = match assignee {
+        Some(name) => Some(resolve_assignee(&name, &config, &work_dir)?),
+        None => current_user(&config, &work_dir),
+    };
+    if claim {
+        if let Some(who) = &who {
+            check_roster(who, &config)?;
+        }
+    }
+    let prefix = prefix.or((!config.prefix.is_empty()).then_some(config.prefix));
+    let filters = NextFilters {
+        issue_type,
+        label,
+        prefix,
+        assignee: who,
+    };
+    run_impl(&db, &filters, claim, &config.wip, output)
+}
+
+/// Which issues `wok next` may pick.
+#[derive(Default)]
+pub(crate) struct NextFilters {
+    pub issue_type: Vec<String>,
+    pub label: Vec<String>,
+    pub prefix: Option<String>,
+    /// Who is asking: their own issues are candidates alongside unassigned ones.
+    pub assignee: Option<String>,
+}
+
+/// Internal implementation that accepts db for testing.
+///
+/// With `claim`, the pick is started and assigned in one conditional update;
+/// if another agent claims it first, the next candidate is tried.
+pub(crate) fn run_impl(
+    db: &Database,
+    filters: &NextFilters,
+    claim: bool,
+    wip: &WipConfig,
+    output: OutputFormat,
+) -> Result<()> {
+    let who = filters.assignee.as_deref();
+    let candidates = candidates(db, filters)?;
+
+    let picked = if claim {
+        if let (Some(who), false) = (who, candidates.is_empty()) {
+            check_wip_limit(db, who, wip)?;
+        }
+        claim_first(db, candidates, who)?
+    } else {
+        candidates.into_iter().next()
+    };
+
+    match output {
+        OutputFormat::Text => match &picked {
+            Some(issue) => println!("{}", format_issue_line(issue)),
+            None => println!("No ready issues"),
+        },
+        OutputFormat::Id => {
+            if let Some(issue) = &picked {
+                println!("{}", issue.id);
+            }
+        }
+        OutputFormat::Json => {
+            let json = match picked {
+                Some(issue) => {
+                    let labels = db.get_labels(&issue.id)?;
+                    Some(IssueJson::new(
+                        issue.id,
+                        issue.issue_type,
+                        issue.status,
+                        issue.title,
+                        issue.assignee,
+                        labels,
+                    ))
+                }
+                None => None,
+            };
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Ready issues matching the filters, best first: priority, then oldest.
+pub(crate) fn candidates(db: &Database, filters: &NextFilters) -> Result<Vec<Issue>> {
+    let type_groups = parse_filter_groups(&filters.issue_type, |s| {
+        s.parse::<IssueType>().map_err(Into::into)
+    })?;
+    let label_groups = parse_filter_groups(&filters.label, LabelMatcher::parse)?;
+    let who = filters.assignee.as_deref();
+
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+    let mut issues: Vec<Issue> = db
+        .list_issues(Some(Status::Todo), None, None)?
+        .into_iter()
+        .filter(|issue| matches_prefix(&filters.prefix, &issue.id))
+        .filter(|issue| issue.assignee.is_none() || issue.assignee.as_deref() == who)
+        .filter(|issue| matches_filter_groups(&type_groups, || issue.issue_type))
+        .filter(|issue| !blocked.contains(&issue.id))
+        .collect();
+
+    let issue_ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    let labels_map: HashMap<String, Vec<String>> = db.get_labels_batch(&issue_ids)?;
+    let empty = Vec::new();
+    let labels_of = |issue: &Issue| labels_map.get(&issue.id).unwrap_or(&empty);
+
+    if label_groups.is_some() {
+        issues.retain(|issue| matches_label_groups(&label_groups, labels_of(issue)));
+    }
+    issues.sort_by(|a, b| {
+        let priority_a = crate::db::priority_from_tags(labels_of(a));
+        let priority_b = crate::db::priority_from_tags(labels_of(b));
+        priority_a
+            .cmp(&priority_b)
+            .then_with(|| a.created_at.cmp(&b.created_at))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    Ok(issues)
+}
+
+/// Start the first candidate nobody else has claimed, logging the events.
+fn claim_first(db: &Database, candidates: Vec<Issue>, who: Option<&str>) -> Result<Option<Issue>> {
+    for issue in candidates {
+        let mut events = Vec::new();
+        if let Some(who) = who.filter(|w| issue.assignee.as_deref() != Some(*w)) {
+            events.push(
+                Event::new(issue.id.clone(), Action::Assigned)
+                    .with_values(issue.assignee.clone(), Some(who.to_string())),
+            );
+        }
+        events.push(Event::new(issue.id.clone(), Action::Started).with_values(
+            Some(issue.status.to_string()),
+            Some(Status::InProgress.to_string()),
+        ));
+        let claimed = db.atomically(|| -> Result<bool> {
+            let claimed = db.claim_and_start(&issue.id, who)?;
+            if claimed {
+                for event in &events {
+                    db.log_event(event)?;
+                }
+            }
+            Ok(claimed)
+        })?;
+        if !claimed {
+            continue;
+        }
+        for event in &events {
+            notify_mutation(db, event);
+        }
+        return Ok(Some(db.get_issue(&issue.id)?));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+#[path = "next_tests.rs"]
+mod tests;
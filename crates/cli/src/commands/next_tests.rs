@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::config::WipMode;
+use crate::error::Error;
+
+fn ids(issues: &[Issue]) -> Vec<&str> {
+    issues.iter().map(|i| i.id.as_str()).collect()
+}
+
+fn filters_for(assignee: Option<&str>) -> NextFilters {
+    NextFilters {
+        assignee: assignee.map(String::from),
+        ..NextFilters::default()
+    }
+}
+
+#[test]
+fn candidates_rank_by_priority_then_age() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Oldest, default priority")
+        .create_issue("test-2", IssueType::Task, "Low priority")
+        .create_issue("test-3", IssueType::Task, "High priority")
+        .create_issue("test-4", IssueType::Task, "Newest, default priority")
+        .add_label("test-2", "priority:4")
+        .add_label("test-3", "priority:0");
+
+    let ranked = candidates(&ctx.db, &NextFilters::default()).unwrap();
+    assert_eq!(ids(&ranked), vec!["test-3", "test-1", "test-4", "test-2"]);
+}
+
+#[test]
+fn candidates_skip_blocked_started_and_others_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Blocked")
+        .create_issue("test-2", IssueType::Task, "Blocker")
+        .create_issue("test-3", IssueType::Task, "Started")
+        .create_issue("test-4", IssueType::Task, "Bob's")
+        .create_issue("test-5", IssueType::Task, "Alice's")
+        .blocks("test-2", "test-1")
+        .start_issue("test-3");
+    ctx.db.set_assignee("test-4", "bob").unwrap();
+    ctx.db.set_assignee("test-5", "alice").unwrap();
+
+    let anyone = candidates(&ctx.db, &filters_for(None)).unwrap();
+    assert_eq!(ids(&anyone), vec!["test-2"]);
+
+    let alice = candidates(&ctx.db, &filters_for(Some("alice"))).unwrap();
+    assert_eq!(ids(&alice), vec!["test-2", "test-5"]);
+}
+
+#[test]
+fn candidates_apply_type_label_and_prefix_filters() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Bug, "Bug")
+        .create_issue("test-2", IssueType::Task, "Labeled task")
+        .create_issue("other-1", IssueType::Bug, "Other project")
+        .add_label("test-2", "backend");
+
+    let bugs = NextFilters {
+        issue_type: vec!["bug".to_string()],
+        prefix: Some("test".to_string()),
+        ..NextFilters::default()
+    };
+    assert_eq!(ids(&candidates(&ctx.db, &bugs).unwrap()), vec!["test-1"]);
+
+    let backend = NextFilters {
+        label: vec!["backend".to_string()],
+        ..NextFilters::default()
+    };
+    assert_eq!(ids(&candidates(&ctx.db, &backend).unwrap()), vec!["test-2"]);
+}
+
+#[test]
+fn run_impl_without_claim_changes_nothing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Open");
+
+    for output in [OutputFormat::Text, OutputFormat::Json, OutputFormat::Id] {
+        run_impl(
+            &ctx.db,
+            &filters_for(Some("alice")),
+            false,
+            &WipConfig::default(),
+            output,
+        )
+        .unwrap();
+    }
+
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    assert_eq!(issue.status, Status::Todo);
+    assert_eq!(issue.assignee, None);
+}
+
+#[test]
+fn run_impl_claim_starts_and_assigns() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Open");
+
+    run_impl(
+        &ctx.db,
+        &filters_for(Some("agent-1")),
+        true,
+        &WipConfig::default(),
+        OutputFormat::Id,
+    )
+    .unwrap();
+
+    let issue = ctx.db.get_issue("test-1").unwrap();
+    assert_eq!(issue.status, Status::InProgress);
+    assert_eq!(issue.assignee.as_deref(), Some("agent-1"));
+    let actions: Vec<Action> = ctx
+        .db
+        .get_events("test-1")
+        .unwrap()
+        .iter()
+        .map(|e| e.action)
+        .collect();
+    assert_eq!(
+        actions,
+        vec![Action::Created, Action::Assigned, Action::Started]
+    );
+}
+
+#[test]
+fn run_impl_claim_moves_on_to_next_candidate() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "First")
+        .create_issue("test-2", IssueType::Task, "Second");
+    let filters = filters_for(Some("agent-1"));
+
+    run_impl(
+        &ctx.db,
+        &filters,
+        true,
+        &WipConfig::default(),
+        OutputFormat::Id,
+    )
+    .unwrap();
+    run_impl(
+        &ctx.db,
+        &filters_for(Some("agent-2")),
+        true,
+        &WipConfig::default(),
+        OutputFormat::Id,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.db.get_issue("test-2").unwrap().assignee.as_deref(),
+        Some("agent-2")
+    );
+}
+
+#[test]
+fn run_impl_claim_with_nothing_ready_succeeds() {
+    let ctx = TestContext::new();
+
+    let result = run_impl(
+        &ctx.db,
+        &filters_for(Some("agent-1")),
+        true,
+        &WipConfig::default(),
+        OutputFormat::Text,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn run_impl_claim_respects_blocking_wip_limit() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "In progress")
+        .create_issue("test-2", IssueType::Task, "Open")
+        .start_issue("test-1");
+    ctx.db.set_assignee("test-1", "agent-1").unwrap();
+    let wip = WipConfig {
+        limit: Some(1),
+        mode: WipMode::Block,
+        ..WipConfig::default()
+    };
+
+    let result = run_impl(
+        &ctx.db,
+        &filters_for(Some("agent-1")),
+        true,
+        &wip,
+        OutputFormat::Id,
+    );
+
+    assert!(matches!(result, Err(Error::WipLimitExceeded { .. })));
+    assert_eq!(ctx.db.get_issue("test-2").unwrap().status, Status::Todo);
+}
@@ -1,20 +1,33 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+use crate::cli::OutputFormat;
 use crate::db::Database;
+use crate::display::format_note_sections_with_ids;
+use crate::markdown;
 
-use super::{apply_mutation, open_db};
+use super::show::render_note_markdown;
+use super::{apply_mutation, apply_mutation_with, open_db};
 use crate::error::{Error, Result};
-use crate::models::{Action, Event, Status};
+use crate::models::{Action, Event, Note, NoteKind, Status};
+use crate::t;
 use crate::validate::validate_and_trim_note;
+use wk_core::db::group_notes_by_status;
 
-pub fn run(id: &str, content: &str, replace: bool) -> Result<()> {
+pub fn run(id: &str, content: &str, replace: bool, kind: Option<&str>) -> Result<()> {
+    let kind = kind.map(str::parse::<NoteKind>).transpose()?;
     let (db, _config, _work_dir) = open_db()?;
-    run_impl(&db, id, content, replace)
+    run_impl(&db, id, content, replace, kind)
 }
 
 /// Internal implementation that accepts db for testing.
-pub(crate) fn run_impl(db: &Database, id: &str, content: &str, replace: bool) -> Result<()> {
+pub(crate) fn run_impl(
+    db: &Database,
+    id: &str,
+    content: &str,
+    replace: bool,
+    kind: Option<NoteKind>,
+) -> Result<()> {
     let resolved_id = db.resolve_id(id)?;
     let issue = db.get_issue(&resolved_id)?;
 
@@ -32,25 +45,169 @@ pub(crate) fn run_impl(db: &Database, id: &str, content: &str, replace: bool) ->
     }
 
     if replace {
-        db.replace_note(&resolved_id, issue.status, &trimmed_content)?;
-
         apply_mutation(
             db,
-            Event::new(resolved_id.clone(), Action::Noted).with_values(None, Some(trimmed_content)),
+            Event::new(resolved_id.clone(), Action::Noted)
+                .with_values(None, Some(trimmed_content.clone())),
+            || {
+                db.replace_note(&resolved_id, issue.status, &trimmed_content)?;
+                db.record_mentions(&resolved_id, &trimmed_content)
+            },
         )?;
 
-        println!("Replaced note on {}", resolved_id);
+        println!("{}", t!("note.replaced", id = resolved_id));
     } else {
-        db.add_note(&resolved_id, issue.status, &trimmed_content)?;
-
         apply_mutation(
             db,
-            Event::new(resolved_id.clone(), Action::Noted).with_values(None, Some(trimmed_content)),
+            Event::new(resolved_id.clone(), Action::Noted)
+                .with_values(None, Some(trimmed_content.clone())),
+            || {
+                db.add_note_of_kind(&resolved_id, issue.status, kind, &trimmed_content)?;
+                db.record_mentions(&resolved_id, &trimmed_content)
+            },
         )?;
 
-        println!("Added note to {} ({})", resolved_id, issue.status);
+        match kind {
+            Some(kind) => println!(
+                "{}",
+                t!(
+                    "note.added_kind",
+                    kind = kind,
+                    id = resolved_id,
+                    status = issue.status
+                )
+            ),
+            None => println!(
+                "{}",
+                t!("note.added", id = resolved_id, status = issue.status)
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Change the content of one of an issue's notes.
+pub fn edit(id: &str, note_id: i64, content: &str) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    edit_impl(&db, id, note_id, content)
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn edit_impl(db: &Database, id: &str, note_id: i64, content: &str) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    db.get_issue(&resolved_id)?;
+
+    let trimmed_content = validate_and_trim_note(content)?;
+    if trimmed_content.is_empty() {
+        return Err(Error::FieldEmpty { field: "Note" });
+    }
+
+    apply_mutation_with(db, || -> Result<Event> {
+        let old = db.edit_note(&resolved_id, note_id, &trimmed_content)?;
+        db.record_mentions(&resolved_id, &trimmed_content)?;
+        Ok(Event::new(resolved_id.clone(), Action::Noted)
+            .with_values(Some(old.content), Some(trimmed_content.clone())))
+    })?;
+
+    println!("{}", t!("note.edited", note = note_id, id = resolved_id));
+    Ok(())
+}
+
+/// Delete one of an issue's notes, keeping it for [`restore`].
+pub fn delete(id: &str, note_id: i64) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    delete_impl(&db, id, note_id)
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn delete_impl(db: &Database, id: &str, note_id: i64) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    db.get_issue(&resolved_id)?;
+
+    apply_mutation_with(db, || -> Result<Event> {
+        let note = db.delete_note(&resolved_id, note_id)?;
+        Ok(Event::new(resolved_id.clone(), Action::Unnoted).with_values(Some(note.content), None))
+    })?;
+
+    println!("{}", t!("note.deleted", note = note_id, id = resolved_id));
+    Ok(())
+}
+
+/// Bring back one of an issue's deleted notes.
+pub fn restore(id: &str, note_id: i64) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    restore_impl(&db, id, note_id)
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn restore_impl(db: &Database, id: &str, note_id: i64) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    db.get_issue(&resolved_id)?;
+
+    apply_mutation_with(db, || -> Result<Event> {
+        let note = db.restore_note(&resolved_id, note_id)?;
+        Ok(Event::new(resolved_id.clone(), Action::Noted).with_values(None, Some(note.content)))
+    })?;
+
+    println!("{}", t!("note.restored", note = note_id, id = resolved_id));
+    Ok(())
+}
+
+/// List an issue's notes, optionally only those of one kind, or with
+/// `deleted` its deleted notes instead.
+pub fn list(
+    id: &str,
+    kind: Option<&str>,
+    deleted: bool,
+    output: OutputFormat,
+    raw: bool,
+) -> Result<()> {
+    let kind = kind.map(str::parse::<NoteKind>).transpose()?;
+    let (db, _config, _work_dir) = open_db()?;
+    list_impl(&db, id, kind, deleted, output, markdown::enabled(raw))
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn list_impl(
+    db: &Database,
+    id: &str,
+    kind: Option<NoteKind>,
+    deleted: bool,
+    output: OutputFormat,
+    render_markdown: bool,
+) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+    let matches = |note: &Note| kind.is_none() || note.kind == kind;
+    let notes = if deleted {
+        db.get_deleted_notes(&resolved_id)?
+    } else {
+        db.get_notes(&resolved_id)?
+    };
+    let notes: Vec<Note> = notes.into_iter().filter(matches).collect();
+
+    if matches!(output, OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(&notes)?);
+        return Ok(());
     }
 
+    let mut grouped = group_notes_by_status(notes);
+    if render_markdown {
+        render_note_markdown(&mut grouped);
+    }
+    let lines = format_note_sections_with_ids(&grouped);
+    if lines.is_empty() {
+        match kind {
+            _ if deleted => println!("{}", t!("note.none_deleted", id = resolved_id)),
+            Some(kind) => println!("{}", t!("note.none_kind", kind = kind, id = resolved_id)),
+            None => println!("{}", t!("note.none", id = resolved_id)),
+        }
+        return Ok(());
+    }
+    // Sections are separated by a leading blank line; drop the first
+    for line in lines.iter().skip(1) {
+        println!("{}", line);
+    }
     Ok(())
 }
 
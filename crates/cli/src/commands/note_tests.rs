@@ -5,7 +5,7 @@
 #![allow(clippy::expect_used)]
 
 use crate::commands::testing::TestContext;
-use crate::models::{Action, IssueType, Status};
+use crate::models::{Action, IssueType, NoteKind, Status};
 
 #[test]
 fn test_add_note_to_issue() {
@@ -141,20 +141,39 @@ fn test_run_impl_add_note() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue");
 
-    let result = run_impl(&ctx.db, "test-1", "A new note", false);
+    let result = run_impl(&ctx.db, "test-1", "A new note", false, None);
     assert!(result.is_ok());
 
     let notes = ctx.db.get_notes("test-1").unwrap();
     assert!(notes.iter().any(|n| n.content == "A new note"));
 }
 
+#[test]
+fn test_run_impl_note_records_mentions() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Target")
+        .create_issue("test-2", IssueType::Task, "Mentioner");
+
+    run_impl(
+        &ctx.db,
+        "test-2",
+        "Blocked on the same thing as test-1",
+        false,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(ctx.db.get_referenced_by("test-1").unwrap(), vec!["test-2"]);
+    assert!(ctx.db.get_blockers("test-2").unwrap().is_empty());
+}
+
 #[test]
 fn test_run_impl_replace_note() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue")
         .add_note("test-1", "Original note");
 
-    let result = run_impl(&ctx.db, "test-1", "Replaced note", true);
+    let result = run_impl(&ctx.db, "test-1", "Replaced note", true, None);
     assert!(result.is_ok());
 
     let notes = ctx.db.get_notes("test-1").unwrap();
@@ -166,7 +185,7 @@ fn test_run_impl_replace_note() {
 fn test_run_impl_nonexistent_issue() {
     let ctx = TestContext::new();
 
-    let result = run_impl(&ctx.db, "nonexistent", "A note", false);
+    let result = run_impl(&ctx.db, "nonexistent", "A note", false, None);
     assert!(result.is_err());
 }
 
@@ -176,7 +195,7 @@ fn test_run_impl_replace_no_existing_note() {
     ctx.create_issue("test-1", IssueType::Task, "Test issue");
 
     // Try to replace when there's no note
-    let result = run_impl(&ctx.db, "test-1", "New note", true);
+    let result = run_impl(&ctx.db, "test-1", "New note", true, None);
     assert!(result.is_err());
 }
 
@@ -186,7 +205,7 @@ fn test_run_impl_closed_issue_rejected() {
     ctx.create_issue("test-1", IssueType::Task, "Test issue")
         .set_status("test-1", Status::Closed);
 
-    let result = run_impl(&ctx.db, "test-1", "Should fail", false);
+    let result = run_impl(&ctx.db, "test-1", "Should fail", false, None);
     assert!(result.is_err());
 
     let err = result.unwrap_err();
@@ -205,7 +224,7 @@ fn test_run_impl_closed_issue_replace_rejected() {
         .add_note("test-1", "Original note")
         .set_status("test-1", Status::Closed);
 
-    let result = run_impl(&ctx.db, "test-1", "Should fail", true);
+    let result = run_impl(&ctx.db, "test-1", "Should fail", true, None);
     assert!(result.is_err());
 
     let err = result.unwrap_err();
@@ -213,3 +232,137 @@ fn test_run_impl_closed_issue_replace_rejected() {
         .to_string()
         .contains("cannot add notes to closed issues"));
 }
+
+#[test]
+fn test_run_impl_add_note_with_kind() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+
+    run_impl(
+        &ctx.db,
+        "test-1",
+        "Use FTS",
+        false,
+        Some(NoteKind::Decision),
+    )
+    .unwrap();
+
+    let notes = ctx.db.get_notes("test-1").unwrap();
+    assert_eq!(notes[0].kind, Some(NoteKind::Decision));
+    assert_eq!(notes[0].status, Status::Todo);
+}
+
+// Tests for edit_impl, delete_impl and restore_impl
+
+use crate::commands::note::{delete_impl, edit_impl, restore_impl};
+use crate::error::Error;
+
+#[test]
+fn test_edit_impl_changes_the_note_and_logs_both_contents() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_note("test-1", "First")
+        .add_note("test-1", "Tried cahcing");
+    let note_id = ctx.db.get_notes("test-1").unwrap()[1].id;
+
+    edit_impl(&ctx.db, "test-1", note_id, "  Tried caching  ").unwrap();
+
+    let notes = ctx.db.get_notes("test-1").unwrap();
+    assert_eq!(notes[0].content, "First");
+    assert_eq!(notes[1].content, "Tried caching");
+    let events = ctx.db.get_events("test-1").unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.action, Action::Noted);
+    assert_eq!(last.old_value.as_deref(), Some("Tried cahcing"));
+    assert_eq!(last.new_value.as_deref(), Some("Tried caching"));
+}
+
+#[test]
+fn test_edit_impl_rejects_empty_content_and_unknown_notes() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .create_issue("test-2", IssueType::Task, "Other issue")
+        .add_note("test-1", "Original");
+    let note_id = ctx.db.get_notes("test-1").unwrap()[0].id;
+
+    assert!(edit_impl(&ctx.db, "test-1", note_id, "   ").is_err());
+    let err = edit_impl(&ctx.db, "test-2", note_id, "Elsewhere").unwrap_err();
+    assert!(matches!(err, Error::NoteNotFound { .. }));
+    assert_eq!(ctx.db.get_notes("test-1").unwrap()[0].content, "Original");
+}
+
+#[test]
+fn test_delete_and_restore_impl_round_trip() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_note("test-1", "Secret token, oops")
+        .set_status("test-1", Status::Closed);
+    let note_id = ctx.db.get_notes("test-1").unwrap()[0].id;
+
+    // Closed issues take no new notes, but their notes can still go
+    delete_impl(&ctx.db, "test-1", note_id).unwrap();
+    assert!(ctx.db.get_notes("test-1").unwrap().is_empty());
+    let events = ctx.db.get_events("test-1").unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.action, Action::Unnoted);
+    assert_eq!(last.old_value.as_deref(), Some("Secret token, oops"));
+    assert!(delete_impl(&ctx.db, "test-1", note_id).is_err());
+
+    restore_impl(&ctx.db, "test-1", note_id).unwrap();
+    assert_eq!(ctx.db.get_notes("test-1").unwrap()[0].id, note_id);
+    let events = ctx.db.get_events("test-1").unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.action, Action::Noted);
+    assert_eq!(last.new_value.as_deref(), Some("Secret token, oops"));
+    assert!(restore_impl(&ctx.db, "test-1", note_id).is_err());
+}
+
+// Tests for list_impl
+
+use crate::cli::OutputFormat;
+use crate::commands::note::list_impl;
+
+#[test]
+fn test_list_impl_succeeds_with_and_without_kind() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_note("test-1", "Plain note");
+    run_impl(&ctx.db, "test-1", "Waiting", false, Some(NoteKind::Blocker)).unwrap();
+
+    for output in [OutputFormat::Text, OutputFormat::Json] {
+        assert!(list_impl(&ctx.db, "test-1", None, false, output, false).is_ok());
+        assert!(list_impl(
+            &ctx.db,
+            "test-1",
+            Some(NoteKind::Blocker),
+            false,
+            output,
+            false
+        )
+        .is_ok());
+        assert!(list_impl(
+            &ctx.db,
+            "test-1",
+            Some(NoteKind::Handoff),
+            false,
+            output,
+            false
+        )
+        .is_ok());
+        assert!(list_impl(&ctx.db, "test-1", None, true, output, false).is_ok());
+    }
+}
+
+#[test]
+fn test_list_impl_nonexistent_issue() {
+    let ctx = TestContext::new();
+    assert!(list_impl(
+        &ctx.db,
+        "nonexistent",
+        None,
+        false,
+        OutputFormat::Text,
+        false
+    )
+    .is_err());
+}
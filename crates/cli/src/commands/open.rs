@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok open` - open an issue's external link in the browser.
+//!
+//! With one link, that link is opened. With several, the one the issue was
+//! imported from wins; otherwise `--link <type>` or `--all` must choose.
+//! Shorthand links such as `jira://PE-5555` are opened through the page
+//! template of the `[links]` provider for their scheme, and `open.url` gives
+//! a page for issues with no links at all.
+
+use std::process::Command;
+
+use crate::config::{self, Config, OpenConfig};
+use crate::error::{Error, Result};
+use crate::models::{Link, LinkRel, LinkType};
+
+use super::open_db;
+
+/// Which of an issue's links to open.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Pick<'a> {
+    /// Only links of this type.
+    pub link_type: Option<&'a str>,
+    /// The `open.url` page instead of a link.
+    pub web: bool,
+    /// Every matching link rather than one.
+    pub all: bool,
+}
+
+pub fn run(id: &str, pick: Pick<'_>, print: bool) -> Result<()> {
+    let (db, config, _work_dir) = open_db()?;
+    let id = db.resolve_id(id)?;
+    db.get_issue(&id)?;
+
+    for url in urls(&id, &db.get_links(&id)?, &config, pick)? {
+        if print {
+            println!("{}", url);
+        } else {
+            browse(&url)?;
+            println!("Opened {}", url);
+        }
+    }
+    Ok(())
+}
+
+/// The URLs to open for issue `id` with `links`.
+pub(crate) fn urls(
+    id: &str,
+    links: &[Link],
+    config: &Config,
+    pick: Pick<'_>,
+) -> Result<Vec<String>> {
+    let open = &config.open;
+    if pick.web {
+        return issue_url(id, open)
+            .map(|url| vec![url])
+            .ok_or_else(|| Error::NoLinkToOpen { id: id.to_string() });
+    }
+    let link_type = pick
+        .link_type
+        .map(|name| link_type(name, config))
+        .transpose()?;
+
+    let candidates: Vec<&Link> = links
+        .iter()
+        .filter(|link| {
+            link_type
+                .as_ref()
+                .is_none_or(|t| link.link_type.as_ref() == Some(t))
+        })
+        .collect();
+    let chosen = match candidates.as_slice() {
+        [] => {
+            return match issue_url(id, open) {
+                Some(url) if link_type.is_none() => Ok(vec![url]),
+                _ => Err(Error::NoLinkToOpen { id: id.to_string() }),
+            };
+        }
+        [_] => candidates,
+        _ if pick.all => candidates,
+        _ => {
+            let imported: Vec<&Link> = candidates
+                .iter()
+                .copied()
+                .filter(|link| link.rel == Some(LinkRel::Import))
+                .collect();
+            if imported.len() != 1 {
+                return Err(ambiguous(id, &candidates));
+            }
+            imported
+        }
+    };
+    chosen
+        .into_iter()
+        .map(|link| link_url(link, config))
+        .collect()
+}
+
+/// The link type `--link` names: a built-in one, or a configured provider's.
+fn link_type(name: &str, config: &Config) -> Result<LinkType> {
+    if let Ok(link_type) = name.parse::<LinkType>() {
+        return Ok(link_type);
+    }
+    let wanted = LinkType::from_name(name);
+    let configured = config
+        .links
+        .iter()
+        .any(|(scheme, provider)| provider.link_type(scheme) == wanted);
+    if configured {
+        Ok(wanted)
+    } else {
+        Err(Error::InvalidLinkType(name.to_string()))
+    }
+}
+
+fn ambiguous(id: &str, links: &[&Link]) -> Error {
+    let urls: Vec<String> = links
+        .iter()
+        .map(|link| {
+            let link_type = link.link_type.as_ref().map_or("url", LinkType::as_str);
+            format!("  {:<10} {}", link_type, link.url.as_deref().unwrap_or(""))
+        })
+        .collect();
+    Error::AmbiguousLink {
+        id: id.to_string(),
+        count: links.len(),
+        urls: urls.join("\n"),
+    }
+}
+
+/// The `open.url` page for issue `id`, if configured.
+fn issue_url(id: &str, open: &OpenConfig) -> Option<String> {
+    open.url
+        .as_ref()
+        .map(|template| config::issue_url(template, id))
+}
+
+/// The web page for `link`: its URL, or for shorthand, the page of the
+/// provider configured for its scheme.
+fn link_url(link: &Link, config: &Config) -> Result<String> {
+    let url = link.url.as_deref().unwrap_or_default();
+    if url.starts_with("http://") || url.starts_with("https://") {
+        return Ok(url.to_string());
+    }
+    let scheme = url.split_once("://").map_or("", |(scheme, _)| scheme);
+    let page = config
+        .links
+        .get(scheme)
+        .zip(link.external_id.as_deref())
+        .map(|(provider, external_id)| provider.page(external_id));
+    page.ok_or_else(|| Error::NoLinkUrl {
+        link: url.to_string(),
+        scheme: if scheme.is_empty() {
+            "<scheme>"
+        } else {
+            scheme
+        }
+        .to_string(),
+    })
+}
+
+/// Open `url` with `$BROWSER`, or the platform's opener.
+fn browse(url: &str) -> Result<()> {
+    let mut command = match crate::env::browser() {
+        // Through the shell so `$BROWSER` may carry arguments.
+        Some(browser) => {
+            let mut command = Command::new("sh");
+            command
+                .arg("-c")
+                .arg(format!("{} \"$1\"", browser))
+                .arg("sh");
+            command
+        }
+        None if cfg!(target_os = "macos") => Command::new("open"),
+        None if cfg!(windows) => {
+            let mut command = Command::new("cmd");
+            command.args(["/C", "start", ""]);
+            command
+        }
+        None => Command::new("xdg-open"),
+    };
+    let failed = |reason: String| Error::OpenFailed {
+        url: url.to_string(),
+        reason,
+    };
+    let status = command
+        .arg(url)
+        .status()
+        .map_err(|e| failed(e.to_string()))?;
+    if !status.success() {
+        return Err(failed(status.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "open_tests.rs"]
+mod tests;
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::config::OpenConfig;
+use crate::models::{parse_link_url, LinkProvider};
+use yare::parameterized;
+
+const GITHUB: &str = "https://github.com/org/repo/issues/7";
+const JIRA: &str = "https://acme.atlassian.net/browse/PE-1";
+
+fn link(url: &str, rel: Option<LinkRel>) -> Link {
+    let (link_type, external_id) = parse_link_url(url);
+    let mut link = Link::new("prj-a1".to_string());
+    link.link_type = link_type;
+    link.url = Some(url.to_string());
+    link.external_id = external_id;
+    link.rel = rel;
+    link
+}
+
+fn config(url: Option<&str>, links: &[(&str, &str)]) -> Config {
+    Config {
+        open: OpenConfig {
+            url: url.map(String::from),
+        },
+        links: links
+            .iter()
+            .map(|(scheme, url)| {
+                let provider = LinkProvider {
+                    url: url.to_string(),
+                    link_type: None,
+                };
+                (scheme.to_string(), provider)
+            })
+            .collect(),
+        ..Config::default()
+    }
+}
+
+fn pick(link_type: Option<&str>) -> Pick<'_> {
+    Pick {
+        link_type,
+        ..Pick::default()
+    }
+}
+
+#[test]
+fn opens_the_only_link() {
+    let links = [link(GITHUB, None)];
+    let urls = urls("prj-a1", &links, &Config::default(), pick(None)).unwrap();
+    assert_eq!(urls, vec![GITHUB]);
+}
+
+#[test]
+fn several_links_are_ambiguous() {
+    let links = [link(GITHUB, None), link(JIRA, None)];
+    let err = urls("prj-a1", &links, &Config::default(), pick(None)).unwrap_err();
+    assert!(matches!(err, Error::AmbiguousLink { count: 2, .. }));
+    let message = err.to_string();
+    assert!(
+        message.contains(GITHUB) && message.contains(JIRA),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn import_link_is_primary() {
+    let links = [link(GITHUB, None), link(JIRA, Some(LinkRel::Import))];
+    let urls = urls("prj-a1", &links, &Config::default(), pick(None)).unwrap();
+    assert_eq!(urls, vec![JIRA]);
+}
+
+#[parameterized(
+    github = { "github", GITHUB },
+    jira = { "jira", JIRA },
+    upper_case = { "JIRA", JIRA },
+)]
+fn link_type_picks_one(link_type: &str, expected: &str) {
+    let links = [link(GITHUB, None), link(JIRA, None)];
+    let urls = urls("prj-a1", &links, &Config::default(), pick(Some(link_type))).unwrap();
+    assert_eq!(urls, vec![expected]);
+}
+
+#[test]
+fn unknown_link_type_is_rejected() {
+    let links = [link(GITHUB, None)];
+    let err = urls("prj-a1", &links, &Config::default(), pick(Some("trello"))).unwrap_err();
+    assert!(matches!(err, Error::InvalidLinkType(_)));
+}
+
+#[test]
+fn all_opens_every_link() {
+    let links = [link(GITHUB, None), link(JIRA, None)];
+    let all = Pick {
+        all: true,
+        ..Pick::default()
+    };
+    let urls = urls("prj-a1", &links, &Config::default(), all).unwrap();
+    assert_eq!(urls, vec![GITHUB, JIRA]);
+}
+
+#[test]
+fn issue_without_links_uses_open_url() {
+    let open = config(Some("https://wok.example.com/{prefix}/{id}"), &[]);
+    let opened = urls("prj-a1", &[], &open, pick(None)).unwrap();
+    assert_eq!(opened, vec!["https://wok.example.com/prj/prj-a1"]);
+
+    let err = urls("prj-a1", &[], &Config::default(), pick(None)).unwrap_err();
+    assert!(matches!(err, Error::NoLinkToOpen { .. }));
+}
+
+#[test]
+fn missing_link_type_does_not_fall_back_to_open_url() {
+    let open = config(Some("https://wok.example.com/{id}"), &[]);
+    let links = [link(GITHUB, None)];
+    let err = urls("prj-a1", &links, &open, pick(Some("jira"))).unwrap_err();
+    assert!(matches!(err, Error::NoLinkToOpen { .. }));
+}
+
+#[test]
+fn web_opens_open_url_even_with_links() {
+    let open = config(Some("https://wok.example.com/{id}"), &[]);
+    let links = [link(GITHUB, None)];
+    let web = Pick {
+        web: true,
+        ..Pick::default()
+    };
+    let urls = urls("prj-a1", &links, &open, web).unwrap();
+    assert_eq!(urls, vec!["https://wok.example.com/prj-a1"]);
+}
+
+#[test]
+fn shorthand_uses_the_provider_page() {
+    let links = [link("jira://PE-5555", None)];
+    let open = config(None, &[("jira", "https://acme.atlassian.net/browse/{id}")]);
+    let opened = urls("prj-a1", &links, &open, pick(None)).unwrap();
+    assert_eq!(opened, vec!["https://acme.atlassian.net/browse/PE-5555"]);
+
+    let err = urls("prj-a1", &links, &Config::default(), pick(None)).unwrap_err();
+    assert!(matches!(err, Error::NoLinkUrl { ref scheme, .. } if scheme == "jira"));
+}
+
+#[test]
+fn link_type_accepts_configured_providers() {
+    let linear = "https://linear.app/acme/issue/ENG-1";
+    let open = config(None, &[("linear", "https://linear.app/acme/issue/{id}")]);
+    let mut links = [link(GITHUB, None), link(linear, None)];
+    links[1].link_type = Some(LinkType::from_name("linear"));
+
+    let opened = urls("prj-a1", &links, &open, pick(Some("linear"))).unwrap();
+    assert_eq!(opened, vec![linear]);
+}
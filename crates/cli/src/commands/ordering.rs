@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dependency-aware ordering for issue lists (`--order topo`).
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::db::Database;
+use crate::error::Result;
+use crate::models::Issue;
+
+/// Open issues each issue blocks, directly or transitively.
+pub(crate) type DownstreamCounts = HashMap<String, usize>;
+
+/// Stable-sort issues so the ones unblocking the most open work come first.
+///
+/// An issue's count includes everything its dependents block, so an open
+/// blocker always sorts ahead of the issues it blocks. Ties keep their
+/// existing order.
+pub(crate) fn sort_by_downstream(db: &Database, issues: &mut [Issue]) -> Result<DownstreamCounts> {
+    let counts = db.get_downstream_counts()?;
+    issues.sort_by_key(|issue| Reverse(downstream_count(&counts, &issue.id)));
+    Ok(counts)
+}
+
+pub(crate) fn downstream_count(counts: &DownstreamCounts, id: &str) -> usize {
+    counts.get(id).copied().unwrap_or(0)
+}
+
+/// Text suffix for an issue line, e.g. ` (unblocks 3 issues)`; empty for 0.
+pub(crate) fn format_unblocks(count: usize) -> String {
+    match count {
+        0 => String::new(),
+        1 => " (unblocks 1 issue)".to_string(),
+        n => format!(" (unblocks {} issues)", n),
+    }
+}
+
+#[cfg(test)]
+#[path = "ordering_tests.rs"]
+mod tests;
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use yare::parameterized;
+
+#[test]
+fn sort_puts_biggest_unblockers_first() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Leaf")
+        .create_issue("test-2", IssueType::Task, "Blocks one")
+        .create_issue("test-3", IssueType::Task, "Blocks chain")
+        .create_issue("test-4", IssueType::Task, "Independent")
+        .blocks("test-2", "test-1")
+        .blocks("test-3", "test-2");
+    let mut issues = ctx.db.list_issues(None, None, None).unwrap();
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let counts = sort_by_downstream(&ctx.db, &mut issues).unwrap();
+
+    let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["test-3", "test-2", "test-1", "test-4"]);
+    assert_eq!(downstream_count(&counts, "test-3"), 2);
+    assert_eq!(downstream_count(&counts, "test-4"), 0);
+}
+
+#[parameterized(
+    none = { 0, "" },
+    one = { 1, " (unblocks 1 issue)" },
+    many = { 4, " (unblocks 4 issues)" },
+)]
+fn unblocks_suffix(count: usize, expected: &str) {
+    assert_eq!(format_unblocks(count), expected);
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Keyset pagination for `-o json-paged` (`--cursor`, `--page-size`).
+//!
+//! Pages walk issues oldest first, ties broken by ID. A cursor names the
+//! last issue of a page, and the next page starts strictly after it, so
+//! issues created between requests never shift or repeat earlier pages.
+
+use std::num::NonZeroUsize;
+
+use chrono::{DateTime, Utc};
+use wk_core::query::{Cond, IssueQuery, QueryOrder};
+
+use crate::cli::{ListFormat, PageArgs};
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::Issue;
+use crate::schema::{IssueJson, IssuePageJson};
+
+/// Issues per page when `--page-size` is not given.
+pub(crate) const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Position after which the next page starts.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: String,
+}
+
+impl Cursor {
+    /// The cursor pointing just past `issue`.
+    pub fn after(issue: &Issue) -> Self {
+        Cursor {
+            created_at: issue.created_at,
+            id: issue.id.clone(),
+        }
+    }
+
+    /// Opaque token form: creation time in nanoseconds, then the ID.
+    pub fn encode(&self) -> String {
+        let nanos = self.created_at.timestamp_nanos_opt().unwrap_or_default();
+        format!("{}:{}", nanos, self.id)
+    }
+
+    pub fn decode(token: &str) -> Result<Self> {
+        let invalid = || Error::InvalidCursor {
+            token: token.to_string(),
+        };
+        let (nanos, id) = token.split_once(':').ok_or_else(invalid)?;
+        let nanos: i64 = nanos.parse().map_err(|_| invalid())?;
+        if id.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Cursor {
+            created_at: DateTime::from_timestamp_nanos(nanos),
+            id: id.to_string(),
+        })
+    }
+}
+
+/// One page of a paged query.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PageRequest {
+    /// Start after this position; `None` starts at the beginning.
+    pub after: Option<Cursor>,
+    pub size: usize,
+}
+
+impl Default for PageRequest {
+    /// The first page, at the default size.
+    fn default() -> Self {
+        PageRequest {
+            after: None,
+            size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+impl PageRequest {
+    /// The query for this page of issues matching `cond`.
+    ///
+    /// Fetches one issue beyond the page so [`Self::finish`] can tell
+    /// whether another page follows.
+    pub fn query(&self, cond: Cond) -> IssueQuery {
+        let cond = match &self.after {
+            Some(cursor) => Cond::All(vec![
+                cond,
+                Cond::After(cursor.created_at, cursor.id.clone()),
+            ]),
+            None => cond,
+        };
+        IssueQuery::new(cond)
+            .with_order(QueryOrder::Created)
+            .with_limit(Some(self.size + 1))
+    }
+
+    /// Trim the extra issue fetched by [`Self::query`], returning the next
+    /// page's cursor if there is one.
+    pub fn finish(&self, issues: &mut Vec<Issue>) -> Option<String> {
+        if issues.len() <= self.size {
+            return None;
+        }
+        issues.truncate(self.size);
+        issues.last().map(|issue| Cursor::after(issue).encode())
+    }
+}
+
+/// The page `-o json-paged` asks for, or `None` for other formats.
+///
+/// `conflicting` names an option the caller was given that paging cannot
+/// honor, such as `--sort` or `--limit`.
+pub(crate) fn page_request(
+    format: ListFormat,
+    args: &PageArgs,
+    conflicting: Option<&'static str>,
+) -> Result<Option<PageRequest>> {
+    if format != ListFormat::JsonPaged {
+        if args.cursor.is_some() || args.page_size.is_some() {
+            return Err(Error::RequiredFor {
+                context: "-o json-paged",
+                operation: "--cursor and --page-size",
+            });
+        }
+        return Ok(None);
+    }
+    if let Some(option) = conflicting {
+        return Err(Error::IncompatibleOptions {
+            option,
+            with: "-o json-paged",
+        });
+    }
+    Ok(Some(PageRequest {
+        after: args.cursor.as_deref().map(Cursor::decode).transpose()?,
+        size: args.page_size.map_or(DEFAULT_PAGE_SIZE, NonZeroUsize::get),
+    }))
+}
+
+/// Print one page of the issues matching `cond` as an [`IssuePageJson`].
+pub(crate) fn print_page(db: &Database, page: &PageRequest, cond: Cond) -> Result<()> {
+    let mut issues = crate::time_phase!("db::query", { db.query_issues(&page.query(cond))? });
+    let next_cursor = page.finish(&mut issues);
+
+    let issue_ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    let short_ids = db.get_short_ids_batch(&issue_ids)?;
+    let mut labels = db.get_labels_batch(&issue_ids)?;
+    let json_issues = issues
+        .iter()
+        .map(|issue| {
+            IssueJson::new(
+                issue.id.clone(),
+                issue.issue_type,
+                issue.status,
+                issue.title.clone(),
+                issue.assignee.clone(),
+                labels.remove(&issue.id).unwrap_or_default(),
+            )
+            .with_short_id(short_ids.get(&issue.id).cloned())
+        })
+        .collect();
+    let output = IssuePageJson {
+        issues: json_issues,
+        next_cursor,
+    };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "paging_tests.rs"]
+mod tests;
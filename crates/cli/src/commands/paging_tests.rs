@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::models::IssueType;
+use chrono::{Duration, TimeZone};
+use yare::parameterized;
+
+fn page_args(cursor: Option<&str>, page_size: Option<usize>) -> PageArgs {
+    PageArgs {
+        cursor: cursor.map(String::from),
+        page_size: page_size.and_then(NonZeroUsize::new),
+    }
+}
+
+#[test]
+fn cursor_round_trips_through_token() {
+    let cursor = Cursor {
+        created_at: Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap()
+            + Duration::nanoseconds(123_456_789),
+        id: "prj-a1b2".to_string(),
+    };
+    let token = cursor.encode();
+    assert_eq!(token, "1772366400123456789:prj-a1b2");
+    assert_eq!(Cursor::decode(&token).unwrap(), cursor);
+}
+
+#[parameterized(
+    empty = { "" },
+    no_separator = { "1772366400" },
+    not_a_number = { "soon:prj-1" },
+    no_id = { "1772366400:" },
+)]
+fn invalid_cursor_is_rejected(token: &str) {
+    assert!(Cursor::decode(token).is_err());
+}
+
+#[test]
+fn json_paged_defaults_to_first_page() {
+    let page = page_request(ListFormat::JsonPaged, &PageArgs::default(), None).unwrap();
+    assert_eq!(page, Some(PageRequest::default()));
+    assert_eq!(PageRequest::default().size, DEFAULT_PAGE_SIZE);
+}
+
+#[parameterized(
+    cursor_without_paged = { ListFormat::Json, Some("1:prj-1"), None, None },
+    size_with_ndjson = { ListFormat::Ndjson, None, Some(10), None },
+    size_without_paged = { ListFormat::Text, None, Some(10), None },
+    bad_cursor = { ListFormat::JsonPaged, Some("next"), None, None },
+    conflicting_option = { ListFormat::JsonPaged, None, None, Some("--sort") },
+)]
+fn page_request_rejects(
+    format: ListFormat,
+    cursor: Option<&str>,
+    page_size: Option<usize>,
+    conflicting: Option<&'static str>,
+) {
+    assert!(page_request(format, &page_args(cursor, page_size), conflicting).is_err());
+}
+
+#[test]
+fn other_formats_ignore_conflicts() {
+    let page = page_request(ListFormat::Ndjson, &PageArgs::default(), Some("--sort")).unwrap();
+    assert!(page.is_none());
+}
+
+#[test]
+fn pages_cover_every_issue_once_in_creation_order() {
+    let db = Database::open_in_memory().unwrap();
+    let base = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+    // Two issues share each creation time, so ties break by ID.
+    for n in 0..7 {
+        let issue = Issue::new(
+            format!("prj-{}", 6 - n),
+            IssueType::Task,
+            format!("Issue {}", n),
+            base + Duration::hours(n / 2),
+        );
+        db.create_issue(&issue).unwrap();
+    }
+
+    let mut seen = Vec::new();
+    let mut pages = 0;
+    let mut after = None;
+    loop {
+        let page = PageRequest { after, size: 3 };
+        let mut issues = db.query_issues(&page.query(Cond::All(vec![]))).unwrap();
+        let next = page.finish(&mut issues);
+        seen.extend(issues.into_iter().map(|i| i.id));
+        pages += 1;
+        match next {
+            Some(token) => after = Some(Cursor::decode(&token).unwrap()),
+            None => break,
+        }
+    }
+    assert_eq!(pages, 3);
+    assert_eq!(
+        seen,
+        vec!["prj-5", "prj-6", "prj-3", "prj-4", "prj-1", "prj-2", "prj-0"]
+    );
+}
+
+#[test]
+fn exactly_full_last_page_has_no_cursor() {
+    let db = Database::open_in_memory().unwrap();
+    let base = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+    for n in 0..2 {
+        let issue = Issue::new(
+            format!("prj-{}", n),
+            IssueType::Task,
+            "Issue".to_string(),
+            base + Duration::hours(n),
+        );
+        db.create_issue(&issue).unwrap();
+    }
+    let page = PageRequest {
+        after: None,
+        size: 2,
+    };
+    let mut issues = db.query_issues(&page.query(Cond::All(vec![]))).unwrap();
+    assert_eq!(page.finish(&mut issues), None);
+    assert_eq!(issues.len(), 2);
+}
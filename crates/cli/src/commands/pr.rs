@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok pr describe` - a pull request body from an issue.
+//!
+//! The body is markdown for `gh pr create --body-file -`. Inside a project,
+//! `.wok/templates/pr.md` overrides the built-in template.
+
+use std::path::Path;
+
+use crate::db::Database;
+use crate::error::Result;
+use crate::models::{Issue, LinkType, NoteKind};
+
+use super::open_db;
+
+/// Built-in template, embedded at compile time
+const TEMPLATE: &str = include_str!("pr/describe.md");
+
+/// Values substituted for `{{name}}` placeholders in the template. The
+/// section values are whole markdown blocks, empty when there is nothing
+/// to show.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PrVars {
+    /// Issue ID (`{{id}}`).
+    pub id: String,
+    /// Issue title (`{{title}}`).
+    pub title: String,
+    /// Issue description (`{{description}}`).
+    pub description: String,
+    /// The description, or the title without one (`{{summary}}`).
+    pub summary: String,
+    /// Decision notes as a "Decisions" list (`{{decisions}}`).
+    pub decisions: String,
+    /// `Closes` lines for the issue, the open issues it tracks, and its
+    /// GitHub and GitLab issue links (`{{closes}}`).
+    pub closes: String,
+}
+
+pub fn describe(id: &str) -> Result<()> {
+    let (db, _config, work_dir) = open_db()?;
+    let issue = db.get_issue(&db.resolve_id(id)?)?;
+    print!("{}", render(&template(&work_dir), &load_vars(&db, &issue)?));
+    Ok(())
+}
+
+/// `.wok/templates/pr.md`, or the built-in template.
+pub(crate) fn template(work_dir: &Path) -> String {
+    std::fs::read_to_string(work_dir.join("templates").join("pr.md"))
+        .unwrap_or_else(|_| TEMPLATE.to_string())
+}
+
+/// Gather substitution values for `issue`.
+pub(crate) fn load_vars(db: &Database, issue: &Issue) -> Result<PrVars> {
+    let description = issue.description.clone().unwrap_or_default();
+    let summary = match description.trim() {
+        "" => issue.title.clone(),
+        text => text.to_string(),
+    };
+
+    let decisions: Vec<String> = db
+        .get_notes(&issue.id)?
+        .into_iter()
+        .filter(|note| note.kind == Some(NoteKind::Decision))
+        .map(|note| format!("- {}", note.content.trim().replace('\n', "\n  ")))
+        .collect();
+    let decisions = if decisions.is_empty() {
+        String::new()
+    } else {
+        format!("### Decisions\n\n{}", decisions.join("\n"))
+    };
+
+    let mut closes = vec![issue.id.clone()];
+    for tracked in db.get_tracked(&issue.id)? {
+        if db.get_issue(&tracked)?.status.is_active() {
+            closes.push(tracked);
+        }
+    }
+    for link in db.get_links(&issue.id)? {
+        let tracker = matches!(link.link_type, Some(LinkType::Github | LinkType::Gitlab));
+        if let Some(url) = link.url.filter(|_| tracker && link.external_id.is_some()) {
+            closes.push(url);
+        }
+    }
+    let closes: Vec<String> = closes.iter().map(|c| format!("Closes {}", c)).collect();
+
+    Ok(PrVars {
+        id: issue.id.clone(),
+        title: issue.title.clone(),
+        description,
+        summary,
+        decisions,
+        closes: closes.join("\n"),
+    })
+}
+
+/// Substitute `{{name}}` placeholders, dropping the blank lines left by
+/// empty sections. Unknown placeholders are left as-is.
+pub(crate) fn render(template: &str, vars: &PrVars) -> String {
+    let rendered = template
+        .replace("{{id}}", &vars.id)
+        .replace("{{title}}", &vars.title)
+        .replace("{{description}}", vars.description.trim())
+        .replace("{{summary}}", &vars.summary)
+        .replace("{{decisions}}", &vars.decisions)
+        .replace("{{closes}}", &vars.closes);
+
+    let mut body = String::new();
+    for line in rendered.trim().lines() {
+        if line.trim().is_empty() && (body.is_empty() || body.ends_with("\n\n")) {
+            continue;
+        }
+        body.push_str(line);
+        body.push('\n');
+    }
+    format!("{}\n", body.trim_end())
+}
+
+#[cfg(test)]
+#[path = "pr_tests.rs"]
+mod tests;
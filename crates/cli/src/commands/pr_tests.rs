@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::{IssueType, Link, Status};
+
+fn describe(ctx: &TestContext, id: &str) -> String {
+    let issue = ctx.db.get_issue(id).unwrap();
+    render(
+        &template(&ctx.work_dir),
+        &load_vars(&ctx.db, &issue).unwrap(),
+    )
+}
+
+fn add_decision(ctx: &TestContext, id: &str, content: &str) {
+    ctx.db
+        .add_note_of_kind(id, Status::Todo, Some(NoteKind::Decision), content)
+        .unwrap();
+}
+
+#[test]
+fn bare_issue_uses_its_title() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "Fix login");
+    assert_eq!(describe(&ctx, "prj-a1"), "Fix login\n\nCloses prj-a1\n");
+}
+
+#[test]
+fn body_has_description_decisions_and_closes() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Feature, "Sessions")
+        .create_issue("prj-b2", IssueType::Task, "Store tokens")
+        .create_completed("prj-c3", IssueType::Task, "Done already")
+        .tracks("prj-a1", "prj-b2")
+        .tracks("prj-a1", "prj-c3")
+        .add_note("prj-a1", "Just a comment");
+    ctx.db
+        .update_issue_description("prj-a1", "Keep users signed in.")
+        .unwrap();
+    add_decision(&ctx, "prj-a1", "Use cookies\nnot local storage");
+    let mut link = Link::new("prj-a1".to_string());
+    link.link_type = Some(LinkType::Github);
+    link.url = Some("https://github.com/org/repo/issues/7".to_string());
+    link.external_id = Some("7".to_string());
+    ctx.db.add_link(&link).unwrap();
+
+    assert_eq!(
+        describe(&ctx, "prj-a1"),
+        "Keep users signed in.\n\
+         \n\
+         ### Decisions\n\
+         \n\
+         - Use cookies\n  not local storage\n\
+         \n\
+         Closes prj-a1\n\
+         Closes prj-b2\n\
+         Closes https://github.com/org/repo/issues/7\n"
+    );
+}
+
+#[test]
+fn project_template_overrides_the_builtin() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("prj-a1", IssueType::Task, "Fix login");
+    let templates = ctx.work_dir.join("templates");
+    std::fs::create_dir_all(&templates).unwrap();
+    std::fs::write(
+        templates.join("pr.md"),
+        "# {{title}} ({{id}})\n\n{{description}}\n\n\n{{decisions}}\n{{unknown}}\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        describe(&ctx, "prj-a1"),
+        "# Fix login (prj-a1)\n\n{{unknown}}\n"
+    );
+}
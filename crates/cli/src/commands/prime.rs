@@ -1,20 +1,132 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use crate::error::Result;
+use std::path::Path;
 
-/// Template content embedded at compile time
-pub(crate) const TEMPLATE: &str = include_str!("prime.md");
+use crate::config::{find_work_dir, Config};
+use crate::error::{Error, Result};
+
+/// Shared command reference, embedded at compile time
+const BODY: &str = include_str!("prime/body.md");
+
+/// Built-in profiles: name and the header that precedes the shared body
+const PROFILES: &[(&str, &str)] = &[
+    ("claude", include_str!("prime/claude.md")),
+    ("cursor", include_str!("prime/cursor.md")),
+    ("generic", include_str!("prime/generic.md")),
+];
+
+/// Profile used when none is given
+pub(crate) const DEFAULT_PROFILE: &str = "claude";
+
+/// Prefix shown in examples outside a project
+const PLACEHOLDER_PREFIX: &str = "prj";
+
+/// Values substituted for `{{name}}` placeholders in templates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PrimeVars {
+    /// Issue ID prefix (`{{prefix}}`).
+    pub prefix: String,
+    /// Assignee whose queue `wok ready` includes (`{{assignee}}`).
+    pub assignee: String,
+    /// Team workflow policy from `.wok/prime/workflow.md` (`{{workflow}}`).
+    pub workflow: String,
+}
 
 /// Run the prime command - outputs the template to stdout
 ///
 /// Always outputs the template regardless of whether we're in a work directory.
 /// This allows the template to be piped to a file for use in system prompts.
-pub fn run() -> Result<()> {
-    print!("{}", TEMPLATE);
+/// Inside a project, `.wok/prime/<profile>.md` overrides the built-in template.
+pub fn run(profile: Option<String>) -> Result<()> {
+    let profile = profile.unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+    let work_dir = find_work_dir().ok();
+    print!("{}", render_profile(work_dir.as_deref(), &profile)?);
     Ok(())
 }
 
+/// Load and render a profile's template.
+pub(crate) fn render_profile(work_dir: Option<&Path>, profile: &str) -> Result<String> {
+    let template = match work_dir.and_then(|dir| read_override(dir, profile)) {
+        Some(template) => template,
+        None => builtin_template(profile).ok_or_else(|| Error::UnknownPrimeProfile {
+            name: profile.to_string(),
+            available: available_profiles(work_dir).join(", "),
+        })?,
+    };
+    let vars = work_dir.map(load_vars).unwrap_or_else(|| PrimeVars {
+        prefix: PLACEHOLDER_PREFIX.to_string(),
+        ..PrimeVars::default()
+    });
+    Ok(render(&template, &vars))
+}
+
+/// The built-in template for a profile.
+pub(crate) fn builtin_template(profile: &str) -> Option<String> {
+    PROFILES
+        .iter()
+        .find(|(name, _)| *name == profile)
+        .map(|(_, head)| format!("{}{}", head, BODY))
+}
+
+/// Built-in profiles plus any defined in `.wok/prime/`.
+fn available_profiles(work_dir: Option<&Path>) -> Vec<String> {
+    let mut names: Vec<String> = PROFILES.iter().map(|(name, _)| name.to_string()).collect();
+    if let Some(entries) = work_dir.and_then(|dir| std::fs::read_dir(dir.join("prime")).ok()) {
+        for path in entries.flatten().map(|e| e.path()) {
+            let is_md = path.extension().is_some_and(|ext| ext == "md");
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                if is_md && stem != "workflow" && !names.iter().any(|n| n == stem) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+    }
+    names
+}
+
+/// Read `.wok/prime/<profile>.md`, if present.
+fn read_override(work_dir: &Path, profile: &str) -> Option<String> {
+    // Profile names become file names; keep them to a single path component
+    if profile.is_empty() || profile.contains(['/', '\\']) || profile.starts_with('.') {
+        return None;
+    }
+    std::fs::read_to_string(work_dir.join("prime").join(format!("{}.md", profile))).ok()
+}
+
+/// Gather substitution values from the project.
+pub(crate) fn load_vars(work_dir: &Path) -> PrimeVars {
+    let prefix = Config::load(work_dir)
+        .ok()
+        .map(|c| c.prefix)
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| PLACEHOLDER_PREFIX.to_string());
+    let read_trimmed = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    };
+    PrimeVars {
+        prefix,
+        assignee: read_trimmed(&work_dir.join("current").join("assignee")),
+        workflow: read_trimmed(&work_dir.join("prime").join("workflow.md")),
+    }
+}
+
+/// Substitute `{{name}}` placeholders. Unknown placeholders are left as-is.
+pub(crate) fn render(template: &str, vars: &PrimeVars) -> String {
+    let workflow = if vars.workflow.is_empty() {
+        String::new()
+    } else {
+        format!("\n{}", vars.workflow)
+    };
+    let rendered = template
+        .replace("{{prefix}}", &vars.prefix)
+        .replace("{{assignee}}", &vars.assignee)
+        .replace("{{workflow}}", &workflow);
+    format!("{}\n", rendered.trim_end())
+}
+
 #[cfg(test)]
 #[path = "prime_tests.rs"]
 mod tests;
@@ -4,56 +4,80 @@
 #![allow(clippy::unwrap_used)]
 
 use super::*;
+use tempfile::TempDir;
+
+/// The default template as printed outside a project
+fn template() -> String {
+    render_profile(None, DEFAULT_PROFILE).unwrap()
+}
+
+fn vars(prefix: &str) -> PrimeVars {
+    PrimeVars {
+        prefix: prefix.to_string(),
+        ..PrimeVars::default()
+    }
+}
+
+/// A `.wok` directory with the given prefix
+fn work_dir(prefix: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("config.toml"),
+        format!("prefix = \"{}\"\n", prefix),
+    )
+    .unwrap();
+    dir
+}
 
 #[test]
 fn template_not_empty() {
-    assert!(!TEMPLATE.is_empty());
+    assert!(!template().is_empty());
 }
 
 #[test]
 fn template_contains_expected_sections() {
-    assert!(TEMPLATE.contains("## Core Rules"));
-    assert!(TEMPLATE.contains("## Finding Work"));
+    assert!(template().contains("## Core Rules"));
+    assert!(template().contains("## Finding Work"));
 }
 
 #[test]
 fn template_contains_common_commands() {
-    assert!(TEMPLATE.contains("wok list"));
-    assert!(TEMPLATE.contains("wok new"));
-    assert!(TEMPLATE.contains("wok start"));
-    assert!(TEMPLATE.contains("wok done"));
+    assert!(template().contains("wok list"));
+    assert!(template().contains("wok new"));
+    assert!(template().contains("wok start"));
+    assert!(template().contains("wok done"));
 }
 
 #[test]
 fn run_succeeds() {
     // The run function should succeed
-    let result = run();
+    let result = run(None);
     assert!(result.is_ok());
 }
 
 #[test]
 fn template_starts_with_header() {
     // Verify template starts with expected markdown header
-    assert!(TEMPLATE.starts_with("# "));
+    assert!(template().starts_with("# "));
 }
 
 #[test]
 fn template_contains_priority_documentation() {
     // Verify priority tag documentation is present
-    assert!(TEMPLATE.contains("priority:"));
+    assert!(template().contains("priority:"));
 }
 
 #[test]
 fn template_contains_dependency_examples() {
     // Verify dep command examples
-    assert!(TEMPLATE.contains("wok dep"));
-    assert!(TEMPLATE.contains("blocks"));
+    assert!(template().contains("wok dep"));
+    assert!(template().contains("blocks"));
 }
 
 #[test]
 fn template_no_trailing_whitespace() {
     // Quality check: no trailing whitespace on lines
-    for line in TEMPLATE.lines() {
+    for line in template().lines() {
         assert_eq!(
             line,
             line.trim_end(),
@@ -62,3 +86,135 @@ fn template_no_trailing_whitespace() {
         );
     }
 }
+
+#[test]
+fn builtin_profiles_share_body() {
+    for profile in ["claude", "cursor", "generic"] {
+        let template = builtin_template(profile).unwrap();
+        assert!(template.starts_with("# "), "{}", profile);
+        assert!(template.contains("## Core Rules"), "{}", profile);
+        assert!(template.contains("## Finding Work"), "{}", profile);
+    }
+}
+
+#[test]
+fn profiles_differ_in_header() {
+    let claude = render_profile(None, "claude").unwrap();
+    let cursor = render_profile(None, "cursor").unwrap();
+    assert_ne!(claude, cursor);
+    assert!(cursor.contains("wok prime --profile cursor"));
+}
+
+#[test]
+fn unknown_profile_lists_available() {
+    let err = render_profile(None, "nope").unwrap_err().to_string();
+    assert!(err.contains("unknown prime profile 'nope'"));
+    assert!(err.contains("claude, cursor, generic"));
+}
+
+#[test]
+fn rendered_templates_have_no_placeholders() {
+    for profile in ["claude", "cursor", "generic"] {
+        let rendered = render_profile(None, profile).unwrap();
+        assert!(!rendered.contains("{{"), "{}", profile);
+    }
+}
+
+#[test]
+fn render_substitutes_prefix() {
+    let rendered = render("wok show {{prefix}}-42\n", &vars("api"));
+    assert_eq!(rendered, "wok show api-42\n");
+}
+
+#[test]
+fn render_substitutes_assignee() {
+    let vars = PrimeVars {
+        assignee: "alice".to_string(),
+        ..vars("api")
+    };
+    assert_eq!(render("queue: {{assignee}}", &vars), "queue: alice\n");
+}
+
+#[test]
+fn render_appends_workflow_policy() {
+    let vars = PrimeVars {
+        workflow: "## Team Workflow\n- Review before done".to_string(),
+        ..vars("api")
+    };
+    let rendered = render("# Head\n{{workflow}}\n", &vars);
+    assert_eq!(
+        rendered,
+        "# Head\n\n## Team Workflow\n- Review before done\n"
+    );
+}
+
+#[test]
+fn render_drops_empty_workflow() {
+    assert_eq!(render("# Head\n{{workflow}}\n", &vars("api")), "# Head\n");
+}
+
+#[test]
+fn render_leaves_unknown_placeholders() {
+    assert_eq!(render("{{other}}", &vars("api")), "{{other}}\n");
+}
+
+#[test]
+fn load_vars_reads_project_files() {
+    let dir = work_dir("api");
+    std::fs::create_dir_all(dir.path().join("current")).unwrap();
+    std::fs::write(dir.path().join("current/assignee"), "alice\n").unwrap();
+    std::fs::create_dir_all(dir.path().join("prime")).unwrap();
+    std::fs::write(dir.path().join("prime/workflow.md"), "Ship it\n\n").unwrap();
+
+    let vars = load_vars(dir.path());
+
+    assert_eq!(vars.prefix, "api");
+    assert_eq!(vars.assignee, "alice");
+    assert_eq!(vars.workflow, "Ship it");
+}
+
+#[test]
+fn project_prefix_used_in_examples() {
+    let dir = work_dir("api");
+    let rendered = render_profile(Some(dir.path()), DEFAULT_PROFILE).unwrap();
+    assert!(rendered.contains("api-42"));
+    assert!(!rendered.contains("prj-42"));
+}
+
+#[test]
+fn override_file_replaces_builtin() {
+    let dir = work_dir("api");
+    std::fs::create_dir_all(dir.path().join("prime")).unwrap();
+    std::fs::write(
+        dir.path().join("prime/claude.md"),
+        "# Custom\nUse {{prefix}}-1\n",
+    )
+    .unwrap();
+
+    let rendered = render_profile(Some(dir.path()), "claude").unwrap();
+
+    assert_eq!(rendered, "# Custom\nUse api-1\n");
+}
+
+#[test]
+fn override_file_defines_new_profile() {
+    let dir = work_dir("api");
+    std::fs::create_dir_all(dir.path().join("prime")).unwrap();
+    std::fs::write(dir.path().join("prime/review.md"), "# Review\n").unwrap();
+
+    assert_eq!(
+        render_profile(Some(dir.path()), "review").unwrap(),
+        "# Review\n"
+    );
+    let err = render_profile(Some(dir.path()), "other")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("claude, cursor, generic, review"));
+}
+
+#[test]
+fn override_ignores_path_like_profiles() {
+    let dir = work_dir("api");
+    std::fs::write(dir.path().join("secret.md"), "# Secret\n").unwrap();
+    assert!(render_profile(Some(dir.path()), "../secret").is_err());
+}
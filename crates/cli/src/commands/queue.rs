@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Work queues: issues assigned to `queue:<name>` waiting to be taken.
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::{Action, Event, Issue, Status};
+use crate::schema::IssueJson;
+
+use super::assign::{check_roster, resolve_assignee, ME};
+use super::{apply_mutation_if, open_db};
+
+/// Assignee prefix that marks an issue as queued rather than owned.
+pub const QUEUE_PREFIX: &str = "queue:";
+
+/// Depth of one queue, for `wok queue list`.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct QueueDepth {
+    pub queue: String,
+    /// Todo issues in the queue.
+    pub depth: usize,
+    /// Todo issues in the queue that are not blocked.
+    pub ready: usize,
+}
+
+pub fn take(queue: &str, assignee: Option<String>, output: OutputFormat) -> Result<()> {
+    let (db, config, work_dir) = open_db()?;
+    let who = resolve_assignee(assignee.as_deref().unwrap_or(ME), &config, &work_dir)?;
+    check_roster(&who, &config)?;
+    take_impl(&db, queue, &who, output)
+}
+
+pub fn list(output: OutputFormat) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    list_impl(&db, output)
+}
+
+/// Internal implementation that accepts db for testing.
+///
+/// Takes the oldest ready issue. Each candidate is claimed with a conditional
+/// update, so if another process takes it first we move on to the next one.
+pub(crate) fn take_impl(db: &Database, queue: &str, who: &str, output: OutputFormat) -> Result<()> {
+    let queue = queue_assignee(queue);
+    let who = who.trim();
+
+    for issue in ready_in_queue(db, &queue)? {
+        let claimed = apply_mutation_if(
+            db,
+            Event::new(issue.id.clone(), Action::Assigned)
+                .with_values(Some(queue.clone()), Some(who.to_string())),
+            || db.claim_issue(&issue.id, &queue, who),
+        )?;
+        if !claimed {
+            continue;
+        }
+
+        match output {
+            OutputFormat::Text => println!("Took {} from {}: {}", issue.id, queue, issue.title),
+            OutputFormat::Id => println!("{}", issue.id),
+            OutputFormat::Json => {
+                let labels = db.get_labels(&issue.id)?;
+                let json = IssueJson::new(
+                    issue.id,
+                    issue.issue_type,
+                    issue.status,
+                    issue.title,
+                    Some(who.to_string()),
+                    labels,
+                );
+                println!("{}", serde_json::to_string_pretty(&json)?);
+            }
+        }
+        return Ok(());
+    }
+
+    Err(Error::QueueEmpty { queue })
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn list_impl(db: &Database, output: OutputFormat) -> Result<()> {
+    let depths = queue_depths(db)?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&depths)?),
+        OutputFormat::Id => {
+            for depth in &depths {
+                println!("{}", depth.queue);
+            }
+        }
+        OutputFormat::Text => {
+            if depths.is_empty() {
+                println!("No queued issues");
+            }
+            let width = depths.iter().map(|d| d.queue.len()).max().unwrap_or(0);
+            for depth in &depths {
+                println!(
+                    "{:width$}  {} ({} ready)",
+                    depth.queue,
+                    depth.depth,
+                    depth.ready,
+                    width = width
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Normalize a queue name to its assignee form: `merge` -> `queue:merge`.
+pub(crate) fn queue_assignee(name: &str) -> String {
+    let name = name.trim();
+    if name.starts_with(QUEUE_PREFIX) {
+        name.to_string()
+    } else {
+        format!("{}{}", QUEUE_PREFIX, name)
+    }
+}
+
+/// Unblocked todo issues in a queue, oldest first.
+fn ready_in_queue(db: &Database, queue: &str) -> Result<Vec<Issue>> {
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+    let mut issues: Vec<Issue> = db
+        .list_issues(Some(Status::Todo), None, None)?
+        .into_iter()
+        .filter(|issue| issue.assignee.as_deref() == Some(queue) && !blocked.contains(&issue.id))
+        .collect();
+    issues.sort_by(|a, b| {
+        a.created_at
+            .cmp(&b.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(issues)
+}
+
+/// Todo depth of every queue with at least one issue, sorted by name.
+pub(crate) fn queue_depths(db: &Database) -> Result<Vec<QueueDepth>> {
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+    let mut depths: BTreeMap<String, QueueDepth> = BTreeMap::new();
+
+    for issue in db.list_issues(Some(Status::Todo), None, None)? {
+        let Some(queue) = issue.assignee.filter(|a| a.starts_with(QUEUE_PREFIX)) else {
+            continue;
+        };
+        let entry = depths.entry(queue.clone()).or_insert(QueueDepth {
+            queue,
+            depth: 0,
+            ready: 0,
+        });
+        entry.depth += 1;
+        if !blocked.contains(&issue.id) {
+            entry.ready += 1;
+        }
+    }
+
+    Ok(depths.into_values().collect())
+}
+
+#[cfg(test)]
+#[path = "queue_tests.rs"]
+mod tests;
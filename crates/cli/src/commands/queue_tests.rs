@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use yare::parameterized;
+
+/// Three merge-queue issues (test-2 blocked by test-9) and one review-queue issue.
+fn queued_context() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Oldest")
+        .create_issue("test-2", IssueType::Task, "Blocked")
+        .create_issue("test-3", IssueType::Task, "Newest")
+        .create_issue("test-4", IssueType::Task, "Review me")
+        .create_issue("test-9", IssueType::Task, "Blocker")
+        .blocks("test-9", "test-2");
+    for id in ["test-1", "test-2", "test-3"] {
+        ctx.db.set_assignee(id, "queue:merge").unwrap();
+    }
+    ctx.db.set_assignee("test-4", "queue:review").unwrap();
+    ctx
+}
+
+fn assignee(ctx: &TestContext, id: &str) -> Option<String> {
+    ctx.db.get_issue(id).unwrap().assignee
+}
+
+#[parameterized(
+    bare = { "merge", "queue:merge" },
+    prefixed = { "queue:merge", "queue:merge" },
+    padded = { " merge ", "queue:merge" },
+)]
+fn queue_assignee_normalizes(input: &str, expected: &str) {
+    assert_eq!(queue_assignee(input), expected);
+}
+
+#[test]
+fn take_assigns_oldest_ready_issue() {
+    let ctx = queued_context();
+
+    take_impl(&ctx.db, "merge", "alice", OutputFormat::Text).unwrap();
+
+    assert_eq!(assignee(&ctx, "test-1").as_deref(), Some("alice"));
+    assert_eq!(assignee(&ctx, "test-3").as_deref(), Some("queue:merge"));
+    let event = ctx.db.get_events("test-1").unwrap().pop().unwrap();
+    assert_eq!(event.action, Action::Assigned);
+    assert_eq!(event.old_value.as_deref(), Some("queue:merge"));
+}
+
+#[test]
+fn take_skips_blocked_issues() {
+    let ctx = queued_context();
+
+    take_impl(&ctx.db, "merge", "alice", OutputFormat::Id).unwrap();
+    take_impl(&ctx.db, "merge", "bob", OutputFormat::Id).unwrap();
+
+    assert_eq!(assignee(&ctx, "test-3").as_deref(), Some("bob"));
+    assert_eq!(assignee(&ctx, "test-2").as_deref(), Some("queue:merge"));
+    let result = take_impl(&ctx.db, "merge", "carol", OutputFormat::Id);
+    assert!(matches!(result, Err(Error::QueueEmpty { queue }) if queue == "queue:merge"));
+}
+
+#[test]
+fn take_from_unknown_queue_fails() {
+    let ctx = queued_context();
+
+    let result = take_impl(&ctx.db, "deploy", "alice", OutputFormat::Json);
+    assert!(matches!(result, Err(Error::QueueEmpty { .. })));
+}
+
+#[test]
+fn concurrent_takes_never_share_an_issue() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let path = temp.path().join("issues.db");
+    {
+        let db = Database::open(&path).unwrap();
+        for id in ["test-1", "test-2"] {
+            let issue = Issue::new(
+                id.to_string(),
+                IssueType::Task,
+                "Queued".to_string(),
+                chrono::Utc::now(),
+            );
+            db.create_issue(&issue).unwrap();
+            db.set_assignee(id, "queue:merge").unwrap();
+        }
+    }
+
+    let handles: Vec<_> = (0..4)
+        .map(|n| {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                let db = Database::open(&path).unwrap();
+                take_impl(&db, "merge", &format!("agent-{}", n), OutputFormat::Id).is_ok()
+            })
+        })
+        .collect();
+    let taken = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .filter(|ok| *ok)
+        .count();
+
+    assert_eq!(taken, 2);
+    let db = Database::open(&path).unwrap();
+    let first = db.get_issue("test-1").unwrap().assignee.unwrap();
+    let second = db.get_issue("test-2").unwrap().assignee.unwrap();
+    assert!(first.starts_with("agent-") && second.starts_with("agent-"));
+    assert_ne!(first, second);
+}
+
+#[test]
+fn queue_depths_counts_todo_and_ready() {
+    let mut ctx = queued_context();
+    ctx.start_issue("test-3");
+
+    let depths = queue_depths(&ctx.db).unwrap();
+
+    assert_eq!(
+        depths,
+        vec![
+            QueueDepth {
+                queue: "queue:merge".to_string(),
+                depth: 2,
+                ready: 1,
+            },
+            QueueDepth {
+                queue: "queue:review".to_string(),
+                depth: 1,
+                ready: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn list_impl_all_formats() {
+    let ctx = queued_context();
+
+    assert!(list_impl(&ctx.db, OutputFormat::Text).is_ok());
+    assert!(list_impl(&ctx.db, OutputFormat::Json).is_ok());
+    assert!(list_impl(&ctx.db, OutputFormat::Id).is_ok());
+}
@@ -6,25 +6,29 @@ use std::path::Path;
 
 use chrono::{Duration, Utc};
 
-use crate::cli::OutputFormat;
+use crate::cli::{Order, OutputFormat, SortArgs};
 use crate::db::Database;
 use crate::display::format_issue_line;
 use crate::error::Result;
-use crate::models::{Issue, IssueType, Status};
-use crate::schema::ready::ReadyOutputJson;
+use crate::estimate::{estimate_from_tags, plan, Estimate};
+use crate::models::{Issue, IssueType, LinkRel, Status};
+use crate::schema::ready::{ExcludedIssueJson, Exclusion, ReadyExplainJson, ReadyOutputJson};
 use crate::schema::IssueJson;
+use crate::t;
+use wk_core::query::{Cond, IssueQuery};
 
-use super::filtering::{
-    matches_filter_groups, matches_label_groups, matches_prefix, parse_filter_groups, LabelMatcher,
-};
+use super::assign::resolve_assignees;
+use super::filtering::{filter_groups_cond, label_groups_cond, parse_filter_groups, LabelMatcher};
 use super::open_db;
+use super::ordering::{downstream_count, format_unblocks, sort_by_downstream};
+use super::sorting::sort_issues;
 
 /// Maximum number of issues to show in ready output.
 /// Keeps output manageable - you can only work on a few things at once.
 const MAX_READY_ISSUES: usize = 5;
 
 /// Assignee filter mode for the ready command.
-enum AssigneeFilter {
+pub(crate) enum AssigneeFilter {
     /// Show all issues regardless of assignment
     All,
     /// Show only unassigned issues
@@ -35,22 +39,103 @@ enum AssigneeFilter {
     UnassignedOrSpecific(Vec<String>),
 }
 
-/// Check if an issue matches the assignee filter.
-fn matches_assignee_filter(issue: &Issue, filter: &AssigneeFilter) -> bool {
-    match filter {
-        AssigneeFilter::All => true,
-        AssigneeFilter::Unassigned => issue.assignee.is_none(),
-        AssigneeFilter::Specific(assignees) => issue
+/// Why an issue fails the assignee filter, if it does.
+fn assignee_exclusion(issue: &Issue, filter: &AssigneeFilter) -> Option<Exclusion> {
+    let listed = |assignees: &[String]| {
+        issue
             .assignee
             .as_ref()
-            .is_some_and(|a| assignees.iter().any(|f| a == f)),
-        AssigneeFilter::UnassignedOrSpecific(assignees) => {
-            issue.assignee.is_none()
-                || issue
-                    .assignee
-                    .as_ref()
-                    .is_some_and(|a| assignees.iter().any(|f| a == f))
+            .is_some_and(|a| assignees.iter().any(|f| a == f))
+    };
+    let assigned_to = || {
+        issue
+            .assignee
+            .clone()
+            .map(|assignee| Exclusion::AssignedTo { assignee })
+    };
+    match filter {
+        AssigneeFilter::All => None,
+        AssigneeFilter::Unassigned => assigned_to(),
+        AssigneeFilter::Specific(assignees) if listed(assignees) => None,
+        AssigneeFilter::Specific(_) => assigned_to().or(Some(Exclusion::Unassigned)),
+        AssigneeFilter::UnassignedOrSpecific(assignees) if listed(assignees) => None,
+        AssigneeFilter::UnassignedOrSpecific(_) => assigned_to(),
+    }
+}
+
+/// Every reason `issue` is not ready; empty if it is.
+///
+/// `blockers` are its open transitive blockers, `external` the URLs of its
+/// unresolved external blockers.
+fn exclusion_reasons(
+    issue: &Issue,
+    blockers: Vec<String>,
+    external: Vec<String>,
+    filter: &AssigneeFilter,
+) -> Vec<Exclusion> {
+    let mut reasons = Vec::new();
+    if issue.status != Status::Todo {
+        reasons.push(Exclusion::Status {
+            status: issue.status,
+        });
+    }
+    if !blockers.is_empty() {
+        reasons.push(Exclusion::Blocked { by: blockers });
+    }
+    if !external.is_empty() {
+        reasons.push(Exclusion::BlockedExternally { by: external });
+    }
+    reasons.extend(assignee_exclusion(issue, filter));
+    reasons
+}
+
+/// Open issues in `scope` that are not ready, with their reasons.
+pub(crate) fn excluded_issues(
+    db: &Database,
+    scope: Vec<Cond>,
+    filter: &AssigneeFilter,
+) -> Result<Vec<(Issue, Vec<Exclusion>)>> {
+    let mut conds = vec![Cond::Status(vec![Status::Todo, Status::InProgress])];
+    conds.extend(scope);
+    let candidates = db.query_issues(&IssueQuery::new(Cond::All(conds)))?;
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+
+    let mut excluded = Vec::new();
+    for issue in candidates {
+        let mut blockers = Vec::new();
+        let mut external = Vec::new();
+        if blocked.contains(&issue.id) {
+            for dep in db.get_transitive_blocker_deps(&issue.id)? {
+                if !blockers.contains(&dep.from_id) {
+                    blockers.push(dep.from_id);
+                }
+            }
+            for link in db.get_links(&issue.id)? {
+                if link.rel == Some(LinkRel::Blocks) && link.resolved_at.is_none() {
+                    external.extend(link.url);
+                }
+            }
+        }
+        let reasons = exclusion_reasons(&issue, blockers, external, filter);
+        if !reasons.is_empty() {
+            excluded.push((issue, reasons));
+        }
+    }
+    Ok(excluded)
+}
+
+/// Text for one exclusion reason.
+fn format_exclusion(reason: &Exclusion) -> String {
+    match reason {
+        Exclusion::Status { status } => t!("ready.reason.status", status = status),
+        Exclusion::Blocked { by } => t!("ready.reason.blocked", ids = by.join(", ")),
+        Exclusion::BlockedExternally { by } => {
+            t!("ready.reason.blocked_externally", urls = by.join(", "))
+        }
+        Exclusion::AssignedTo { assignee } => {
+            t!("ready.reason.assigned_to", assignee = assignee)
         }
+        Exclusion::Unassigned => t!("ready.reason.unassigned"),
     }
 }
 
@@ -68,6 +153,7 @@ fn get_default_assignee_filter(work_dir: &Path) -> AssigneeFilter {
     AssigneeFilter::Unassigned
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     issue_type: Vec<String>,
     label: Vec<String>,
@@ -76,11 +162,15 @@ pub fn run(
     unassigned: bool,
     all_assignees: bool,
     format: OutputFormat,
+    order: Order,
+    sort: SortArgs,
+    explain: bool,
+    capacity: Option<String>,
 ) -> Result<()> {
-    let (db, config, _) = open_db()?;
+    let capacity = capacity.map(|c| c.parse::<Estimate>()).transpose()?;
+    let (db, config, work_dir) = open_db()?;
+    let assignee = resolve_assignees(assignee, &config, &work_dir)?;
     let prefix = prefix.or((!config.prefix.is_empty()).then_some(config.prefix));
-    // Get work directory for default assignee config
-    let work_dir = crate::config::find_work_dir()?;
     run_impl(
         &db,
         &work_dir,
@@ -91,6 +181,10 @@ pub fn run(
         unassigned,
         all_assignees,
         format,
+        order,
+        &sort,
+        explain,
+        capacity,
     )
 }
 
@@ -106,39 +200,31 @@ pub(crate) fn run_impl(
     unassigned: bool,
     all_assignees: bool,
     format: OutputFormat,
+    order: Order,
+    sort: &SortArgs,
+    explain: bool,
+    capacity: Option<Estimate>,
 ) -> Result<()> {
     // Parse filter groups
     let type_groups =
         parse_filter_groups(&issue_type, |s| s.parse::<IssueType>().map_err(Into::into))?;
     let label_groups = parse_filter_groups(&label, LabelMatcher::parse)?;
 
-    // Ready = unblocked todo items only
-    let mut issues = db.list_issues(Some(Status::Todo), None, None)?;
-
-    // Filter by prefix (cheap string comparison, apply early)
-    if prefix.is_some() {
-        issues.retain(|issue| matches_prefix(&prefix, &issue.id));
-    }
-
-    // Apply type filter first (no DB access needed)
-    if type_groups.is_some() {
-        issues.retain(|issue| matches_filter_groups(&type_groups, || issue.issue_type));
+    // The issues the filters ask about
+    let mut scope = Vec::new();
+    if let Some(prefix) = &prefix {
+        scope.push(Cond::IdPrefix(prefix.clone()));
     }
+    scope.extend(filter_groups_cond(&type_groups, |g| Cond::Type(g.to_vec())));
+    scope.extend(label_groups_cond(&label_groups));
 
-    // Pre-fetch all labels for remaining issues in one query
-    let issue_ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
-    let labels_map: HashMap<String, Vec<String>> = db.get_labels_batch(&issue_ids)?;
-
-    // Apply label filter using pre-fetched map
-    if label_groups.is_some() {
-        issues.retain(|issue| {
-            let issue_labels = labels_map
-                .get(&issue.id)
-                .map(|v| v.as_slice())
-                .unwrap_or(&[]);
-            matches_label_groups(&label_groups, issue_labels)
-        });
-    }
+    // Ready = unblocked todo items only
+    let mut conds = vec![
+        Cond::Status(vec![Status::Todo]),
+        Cond::Not(Box::new(Cond::Blocked)),
+    ];
+    conds.extend(scope.iter().cloned());
+    let mut ready_issues = db.query_issues(&IssueQuery::new(Cond::All(conds)))?;
 
     // Determine assignee filter behavior
     let assignee_filter = if all_assignees {
@@ -153,14 +239,17 @@ pub(crate) fn run_impl(
     };
 
     // Apply assignee filter
-    issues.retain(|issue| matches_assignee_filter(issue, &assignee_filter));
+    ready_issues.retain(|issue| assignee_exclusion(issue, &assignee_filter).is_none());
 
-    // Get blocked IDs and filter to ready (unblocked) only
-    let blocked_ids: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
-    let mut ready_issues: Vec<_> = issues
-        .into_iter()
-        .filter(|issue| !blocked_ids.contains(&issue.id))
-        .collect();
+    let excluded = if explain {
+        excluded_issues(db, scope, &assignee_filter)?
+    } else {
+        Vec::new()
+    };
+
+    // Pre-fetch labels for the sort in one query
+    let issue_ids: Vec<&str> = ready_issues.iter().map(|i| i.id.as_str()).collect();
+    let labels_map: HashMap<String, Vec<String>> = db.get_labels_batch(&issue_ids)?;
 
     // Sort with multi-tier comparator:
     // 1. Recent issues (created <48h ago) come first
@@ -195,21 +284,77 @@ pub(crate) fn run_impl(
         }
     });
 
-    // Truncate to hard limit - ready queue shows only top priorities
+    // Planning to a capacity fills it with the most important work first
+    if capacity.is_some() {
+        ready_issues.sort_by_key(|issue| {
+            labels_map
+                .get(&issue.id)
+                .map_or(2, |tags| crate::db::priority_from_tags(tags))
+        });
+    }
+
+    sort_issues(db, &mut ready_issues, sort)?;
+
+    // --order topo: biggest unblockers first, the order above breaking ties
+    let downstream = match order {
+        Order::Topo => Some(sort_by_downstream(db, &mut ready_issues)?),
+        Order::Default => None,
+    };
+    let unblocks = |issue: &Issue| downstream.as_ref().map(|c| downstream_count(c, &issue.id));
+
+    let estimate_of = |issue: &Issue| {
+        labels_map
+            .get(&issue.id)
+            .and_then(|tags| estimate_from_tags(tags))
+    };
+
+    // Truncate to hard limit - ready queue shows only top priorities -
+    // unless a capacity says how much to take
     let total_ready = ready_issues.len();
-    ready_issues.truncate(MAX_READY_ISSUES);
+    let planned = match capacity {
+        Some(capacity) => {
+            let plan = plan(std::mem::take(&mut ready_issues), capacity, estimate_of);
+            ready_issues = plan.taken;
+            Some((capacity, plan.used, plan.unestimated))
+        }
+        None => {
+            ready_issues.truncate(MAX_READY_ISSUES);
+            None
+        }
+    };
 
+    let _render = tracing::info_span!("render").entered();
     match format {
         OutputFormat::Text => {
-            if ready_issues.is_empty() {
-                println!("No ready issues");
+            if total_ready == 0 {
+                println!("{}", t!("ready.none"));
             } else {
                 for issue in &ready_issues {
-                    println!("{}", format_issue_line(issue));
+                    let mut suffix = unblocks(issue).map(format_unblocks).unwrap_or_default();
+                    if let (Some(estimate), Some(_)) = (estimate_of(issue), planned) {
+                        suffix.push_str(&format!(" [{}]", estimate));
+                    }
+                    println!("{}{}", format_issue_line(issue), suffix);
                 }
-                if total_ready > MAX_READY_ISSUES {
+                if let Some((capacity, used, unestimated)) = planned {
+                    let mut summary = t!("ready.capacity", used = used, capacity = capacity);
+                    if unestimated > 0 {
+                        summary.push_str("; ");
+                        summary.push_str(&t!("ready.unestimated", count = unestimated));
+                    }
+                    println!("\n({})", summary);
+                } else if total_ready > MAX_READY_ISSUES {
                     let remaining = total_ready - MAX_READY_ISSUES;
-                    println!("\n({remaining} more — use `wk list` to see all)",);
+                    println!("\n({})", t!("ready.more", count = remaining));
+                }
+            }
+            if !excluded.is_empty() {
+                println!("\n{}", t!("ready.not_ready"));
+                for (issue, reasons) in &excluded {
+                    println!("{}", format_issue_line(issue));
+                    for reason in reasons {
+                        println!("    {}", format_exclusion(reason));
+                    }
                 }
             }
         }
@@ -218,17 +363,44 @@ pub(crate) fn run_impl(
             for issue in &ready_issues {
                 // Use pre-fetched labels - no additional DB access
                 let labels = labels_map.get(&issue.id).cloned().unwrap_or_default();
-                json_issues.push(IssueJson::new(
-                    issue.id.clone(),
-                    issue.issue_type,
-                    issue.status,
-                    issue.title.clone(),
-                    issue.assignee.clone(),
-                    labels,
-                ));
+                json_issues.push(
+                    IssueJson::new(
+                        issue.id.clone(),
+                        issue.issue_type,
+                        issue.status,
+                        issue.title.clone(),
+                        issue.assignee.clone(),
+                        labels,
+                    )
+                    .with_unblocks(unblocks(issue)),
+                );
+            }
+            if explain {
+                let excluded_ids: Vec<&str> = excluded.iter().map(|(i, _)| i.id.as_str()).collect();
+                let excluded_labels = db.get_labels_batch(&excluded_ids)?;
+                let excluded = excluded
+                    .into_iter()
+                    .map(|(issue, reasons)| ExcludedIssueJson {
+                        issue: IssueJson::new(
+                            issue.id.clone(),
+                            issue.issue_type,
+                            issue.status,
+                            issue.title,
+                            issue.assignee,
+                            excluded_labels.get(&issue.id).cloned().unwrap_or_default(),
+                        ),
+                        reasons,
+                    })
+                    .collect();
+                let output = ReadyExplainJson {
+                    ready: json_issues,
+                    excluded,
+                };
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            } else {
+                let output = ReadyOutputJson(json_issues);
+                println!("{}", serde_json::to_string_pretty(&output)?);
             }
-            let output = ReadyOutputJson(json_issues);
-            println!("{}", serde_json::to_string_pretty(&output)?);
         }
         OutputFormat::Id => {
             for issue in &ready_issues {
@@ -4,7 +4,7 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::expect_used)]
 
-use crate::cli::OutputFormat;
+use crate::cli::{Order, OutputFormat, SortArgs};
 use crate::commands::testing::TestContext;
 use crate::models::{IssueType, Status};
 use std::collections::HashSet;
@@ -122,7 +122,9 @@ fn test_ready_unblocked_after_blocker_completes() {
 
 // Tests for run_impl
 
-use crate::commands::ready::run_impl;
+use crate::commands::ready::{excluded_issues, run_impl, AssigneeFilter};
+use crate::schema::ready::Exclusion;
+use wk_core::query::Cond;
 
 #[test]
 fn test_run_impl_default() {
@@ -139,6 +141,35 @@ fn test_run_impl_default() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_run_impl_with_capacity() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Sized")
+        .add_label("test-1", "estimate:3pt")
+        .create_issue("test-2", IssueType::Task, "Unsized");
+
+    let result = run_impl(
+        &ctx.db,
+        &ctx.work_dir,
+        vec![],
+        vec![],
+        None,
+        vec![],
+        false,
+        true,
+        OutputFormat::Json,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        Some(crate::estimate::Estimate::Points(5)),
     );
     assert!(result.is_ok());
 }
@@ -159,6 +190,10 @@ fn test_run_impl_with_label() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -177,6 +212,10 @@ fn test_run_impl_empty() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -199,6 +238,10 @@ fn test_run_impl_all_blocked() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -222,6 +265,10 @@ fn test_run_impl_with_type_filter() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -244,6 +291,10 @@ fn test_run_impl_with_label_filter() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -267,6 +318,10 @@ fn test_run_impl_with_combined_filters() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -286,6 +341,10 @@ fn test_run_impl_invalid_type_filter() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_err());
 }
@@ -308,6 +367,10 @@ fn test_run_impl_comma_separated_types() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -335,6 +398,10 @@ fn test_run_impl_multiple_label_groups() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -359,6 +426,10 @@ fn test_run_impl_filters_exclude_blocked() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -380,6 +451,10 @@ fn test_run_impl_json_format_outputs_valid_json() {
         false,
         true,
         OutputFormat::Json,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -400,6 +475,10 @@ fn test_run_impl_json_format_with_label() {
         false,
         true,
         OutputFormat::Json,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -418,6 +497,10 @@ fn test_run_impl_json_format_empty() {
         false,
         true,
         OutputFormat::Json,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -439,6 +522,10 @@ fn test_run_impl_json_format_excludes_blocked() {
         false,
         true,
         OutputFormat::Json,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -482,6 +569,10 @@ fn test_ready_with_type_filter_works() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -504,6 +595,10 @@ fn test_ready_with_label_filter_works() {
         false,
         true,
         OutputFormat::Text,
+        Order::Default,
+        &SortArgs::default(),
+        false,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -629,6 +724,7 @@ fn test_ready_sorts_recent_before_old() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&old_issue).unwrap();
 
@@ -648,6 +744,7 @@ fn test_ready_sorts_recent_before_old() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&recent_issue).unwrap();
 
@@ -688,6 +785,7 @@ fn test_ready_sorts_old_by_created_at_asc() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&older_issue).unwrap();
 
@@ -706,6 +804,7 @@ fn test_ready_sorts_old_by_created_at_asc() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     };
     db.create_issue(&less_old_issue).unwrap();
 
@@ -727,3 +826,121 @@ fn test_ready_sorts_old_by_created_at_asc() {
     assert_eq!(issues[0].id, "older");
     assert_eq!(issues[1].id, "less_old");
 }
+
+#[test]
+fn test_excluded_issues_give_every_reason() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("root", IssueType::Task, "Root blocker")
+        .create_issue("mid", IssueType::Task, "Middle blocker")
+        .create_issue("blocked", IssueType::Task, "Blocked task")
+        .create_issue("started", IssueType::Task, "Started task")
+        .create_issue("theirs", IssueType::Task, "Someone else's")
+        .create_issue("ready", IssueType::Task, "Ready task")
+        .blocks("root", "mid")
+        .blocks("mid", "blocked")
+        .start_issue("started");
+    ctx.db.set_assignee("theirs", "bob").unwrap();
+    ctx.db.set_assignee("started", "bob").unwrap();
+
+    let excluded = excluded_issues(&ctx.db, vec![], &AssigneeFilter::Unassigned).unwrap();
+    let reasons = |id: &str| {
+        excluded
+            .iter()
+            .find(|(issue, _)| issue.id == id)
+            .map(|(_, reasons)| reasons.clone())
+    };
+
+    assert_eq!(reasons("ready"), None);
+    assert_eq!(reasons("root"), None);
+    let blocked = reasons("blocked").unwrap();
+    assert!(matches!(&blocked[..], [Exclusion::Blocked { by }]
+        if by.contains(&"mid".to_string()) && by.contains(&"root".to_string())));
+    assert_eq!(
+        reasons("started").unwrap(),
+        vec![
+            Exclusion::Status {
+                status: Status::InProgress
+            },
+            Exclusion::AssignedTo {
+                assignee: "bob".to_string()
+            },
+        ]
+    );
+    assert_eq!(
+        reasons("theirs").unwrap(),
+        vec![Exclusion::AssignedTo {
+            assignee: "bob".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_excluded_issues_explains_external_blockers() {
+    use crate::models::{Link, LinkRel};
+
+    let mut ctx = TestContext::new();
+    ctx.create_issue("waiting", IssueType::Task, "Waiting on vendor");
+    let url = "https://github.com/vendor/lib/issues/9";
+    let link = Link::new("waiting".to_string())
+        .with_url(url.to_string())
+        .with_rel(LinkRel::Blocks);
+    let link_id = ctx.db.add_link(&link).unwrap();
+
+    let excluded = excluded_issues(&ctx.db, vec![], &AssigneeFilter::Unassigned).unwrap();
+    assert_eq!(
+        excluded[0].1,
+        vec![Exclusion::BlockedExternally {
+            by: vec![url.to_string()]
+        }]
+    );
+
+    ctx.db.set_link_resolved(link_id, Some(Utc::now())).unwrap();
+    let excluded = excluded_issues(&ctx.db, vec![], &AssigneeFilter::Unassigned).unwrap();
+    assert!(excluded.is_empty());
+}
+
+#[test]
+fn test_excluded_issues_unassigned_when_filtering_by_assignee() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("mine", IssueType::Task, "Mine")
+        .create_issue("loose", IssueType::Task, "Nobody's")
+        .add_label("loose", "backend");
+    ctx.db.set_assignee("mine", "alice").unwrap();
+
+    let filter = AssigneeFilter::Specific(vec!["alice".to_string()]);
+    let excluded = excluded_issues(&ctx.db, vec![], &filter).unwrap();
+    assert_eq!(excluded.len(), 1);
+    assert_eq!(excluded[0].0.id, "loose");
+    assert_eq!(excluded[0].1, vec![Exclusion::Unassigned]);
+
+    // Issues outside the filters are not explained
+    let scope = vec![Cond::Label("frontend".to_string())];
+    assert!(excluded_issues(&ctx.db, scope, &filter).unwrap().is_empty());
+}
+
+#[test]
+fn test_run_impl_explain() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("blocker", IssueType::Task, "Blocker")
+        .create_issue("blocked", IssueType::Task, "Blocked")
+        .blocks("blocker", "blocked");
+
+    for format in [OutputFormat::Text, OutputFormat::Json] {
+        let result = run_impl(
+            &ctx.db,
+            &ctx.work_dir,
+            vec![],
+            vec![],
+            None,
+            vec![],
+            false,
+            true,
+            format,
+            Order::Default,
+            &SortArgs::default(),
+            true,
+            None,
+        );
+        assert!(result.is_ok());
+    }
+}
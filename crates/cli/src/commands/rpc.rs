@@ -0,0 +1,352 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok rpc` - JSON-RPC 2.0 on stdin and stdout, for editor integrations.
+//!
+//! Messages use the Language Server Protocol's framing: a `Content-Length`
+//! header, a blank line, then the JSON body. `wok/query` and `wok/mutate`
+//! take the daemon's [`QueryOp`] and [`MutateOp`] as params and answer with
+//! the same results, so an editor plugin sees the tracker exactly as the CLI
+//! does. A watcher thread sends `wok/didChange` whenever events are logged,
+//! by this session or any other process. `wok schema rpc` documents every
+//! method.
+
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use wk_ipc::{dispatch, MutateOp, QueryOp};
+
+use crate::config::get_db_path;
+use crate::db::Database;
+use crate::error::{Error, ErrorReport, Result};
+use crate::schema::rpc::{DidChangeJson, InitializeResultJson, ServerInfoJson};
+
+use super::{notify_mutation, open_db};
+
+/// How often the watcher looks for new events.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Largest message body read, matching the daemon's frame limit.
+const MAX_MESSAGE_SIZE: usize = wk_ipc::framing::MAX_MESSAGE_SIZE;
+
+/// JSON-RPC error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// A wok error; `data` holds its `--error-format json` report.
+const WOK_ERROR: i64 = -32000;
+
+/// Where responses and notifications are written, shared with the watcher.
+type Output = Arc<Mutex<io::Stdout>>;
+
+/// Answer requests on stdin until `exit` or end of input.
+pub fn run() -> Result<()> {
+    let (db, config, work_dir) = open_db()?;
+    let output: Output = Arc::new(Mutex::new(io::stdout()));
+    let watcher = Watcher::start(
+        &get_db_path(&work_dir, &config),
+        db.last_event_id()?,
+        Arc::clone(&output),
+    );
+
+    let mut session = Session::new(&db, &config.prefix);
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let result = loop {
+        let body = match read_message(&mut input) {
+            Ok(Some(body)) => body,
+            Ok(None) => break Ok(()),
+            Err(e) => break Err(e),
+        };
+        if let Some(reply) = session.handle(&body) {
+            let mut out = output.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Err(e) = write_message(&mut *out, &reply) {
+                break Err(e);
+            }
+        }
+        if session.exited {
+            break Ok(());
+        }
+    };
+    watcher.shutdown();
+    result
+}
+
+/// Read one message body; `None` at end of input.
+pub(crate) fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<String>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            match length {
+                Some(_) => break,
+                None => continue,
+            }
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let value = value.trim();
+                let parsed = value
+                    .parse::<usize>()
+                    .map_err(|_| Error::InvalidRpcMessage {
+                        reason: format!("invalid Content-Length: {}", value),
+                    })?;
+                length = Some(parsed);
+            }
+        }
+    }
+
+    let length = length.unwrap_or_default();
+    if length > MAX_MESSAGE_SIZE {
+        return Err(Error::InvalidRpcMessage {
+            reason: format!("too large: {} bytes (max {})", length, MAX_MESSAGE_SIZE),
+        });
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    String::from_utf8(body)
+        .map(Some)
+        .map_err(|_| Error::InvalidRpcMessage {
+            reason: "not UTF-8".to_string(),
+        })
+}
+
+/// Write one message with its `Content-Length` header.
+pub(crate) fn write_message<W: Write>(writer: &mut W, body: &str) -> Result<()> {
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// A request or notification from the client.
+#[derive(Deserialize)]
+struct Incoming {
+    jsonrpc: Option<String>,
+    id: Option<Value>,
+    method: Option<String>,
+    #[serde(default)]
+    params: Value,
+}
+
+/// An error response's `error` member.
+#[derive(Debug)]
+pub(crate) struct RpcError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        RpcError {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut error = json!({ "code": self.code, "message": self.message });
+        if let Some(data) = &self.data {
+            error["data"] = data.clone();
+        }
+        error
+    }
+}
+
+impl From<wk_core::Error> for RpcError {
+    fn from(e: wk_core::Error) -> Self {
+        let e = Error::from(e);
+        let report = ErrorReport::from_message(e.code(), &e.to_string());
+        RpcError {
+            code: WOK_ERROR,
+            message: report.message.clone(),
+            data: serde_json::to_value(&report).ok(),
+        }
+    }
+}
+
+/// One client's session: answers each message in turn.
+pub(crate) struct Session<'a> {
+    db: &'a Database,
+    prefix: &'a str,
+    /// `shutdown` was called; only `exit` is accepted now.
+    shut_down: bool,
+    /// `exit` was received; the server stops.
+    pub exited: bool,
+}
+
+impl<'a> Session<'a> {
+    pub(crate) fn new(db: &'a Database, prefix: &'a str) -> Self {
+        Session {
+            db,
+            prefix,
+            shut_down: false,
+            exited: false,
+        }
+    }
+
+    /// The response to a message body, or `None` for a notification.
+    pub(crate) fn handle(&mut self, body: &str) -> Option<String> {
+        let incoming = match serde_json::from_str::<Value>(body) {
+            Ok(value) => value,
+            Err(e) => {
+                return Some(response(
+                    Value::Null,
+                    Err(RpcError::new(PARSE_ERROR, e.to_string())),
+                ))
+            }
+        };
+        let incoming = match serde_json::from_value::<Incoming>(incoming) {
+            Ok(incoming) if incoming.jsonrpc.as_deref() == Some("2.0") => incoming,
+            _ => {
+                let error = RpcError::new(INVALID_REQUEST, "not a JSON-RPC 2.0 request");
+                return Some(response(Value::Null, Err(error)));
+            }
+        };
+        let Some(method) = incoming.method else {
+            // A response to nothing we asked; there is nothing to answer
+            return None;
+        };
+
+        let result = self.call(&method, incoming.params);
+        match incoming.id {
+            Some(id) => Some(response(id, result)),
+            None => {
+                if let Err(e) = result {
+                    tracing::debug!("notification {} failed: {}", method, e.message);
+                }
+                None
+            }
+        }
+    }
+
+    fn call(&mut self, method: &str, params: Value) -> std::result::Result<Value, RpcError> {
+        if method == "exit" {
+            self.exited = true;
+            return Ok(Value::Null);
+        }
+        if self.shut_down {
+            return Err(RpcError::new(INVALID_REQUEST, "server is shut down"));
+        }
+        match method {
+            "initialize" => to_value(InitializeResultJson {
+                server_info: ServerInfoJson {
+                    name: "wok".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                prefix: self.prefix.to_string(),
+            }),
+            "shutdown" => {
+                self.shut_down = true;
+                Ok(Value::Null)
+            }
+            "wok/query" => {
+                let op: QueryOp = params_as(params)?;
+                to_value(dispatch::query(self.db, op)?)
+            }
+            "wok/mutate" => {
+                let op: MutateOp = params_as(params)?;
+                let (result, logged) = dispatch::record(self.db, op)?;
+                // Hooks and rules follow logged events, as for CLI commands
+                if let Some(event) = logged {
+                    notify_mutation(self.db, &event);
+                }
+                to_value(result)
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method: {}", method),
+            )),
+        }
+    }
+}
+
+fn params_as<T: DeserializeOwned>(params: Value) -> std::result::Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> std::result::Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(|e| RpcError::new(WOK_ERROR, e.to_string()))
+}
+
+/// A response body answering request `id`.
+fn response(id: Value, result: std::result::Result<Value, RpcError>) -> String {
+    let message = match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error.to_json() }),
+    };
+    message.to_string()
+}
+
+/// The `wok/didChange` notification for events logged after `*after_id`,
+/// advancing it past them; `None` when there are none.
+pub(crate) fn changes(db: &Database, after_id: &mut i64) -> Result<Option<String>> {
+    let events = db.get_events_after(*after_id)?;
+    let Some(last) = events.last() else {
+        return Ok(None);
+    };
+    *after_id = last.id;
+    let params = serde_json::to_value(DidChangeJson { events })?;
+    let message = json!({ "jsonrpc": "2.0", "method": "wok/didChange", "params": params });
+    Ok(Some(message.to_string()))
+}
+
+/// The thread sending `wok/didChange` notifications.
+struct Watcher {
+    stop: mpsc::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl Watcher {
+    /// Watch the database at `db_path` for events after `after_id`.
+    fn start(db_path: &Path, after_id: i64, output: Output) -> Self {
+        let (stop, stopped) = mpsc::channel();
+        let db_path = db_path.to_path_buf();
+        let handle = std::thread::spawn(move || watch(&db_path, after_id, &output, &stopped));
+        Watcher { stop, handle }
+    }
+
+    fn shutdown(self) {
+        drop(self.stop);
+        let _ = self.handle.join();
+    }
+}
+
+fn watch(db_path: &Path, mut after_id: i64, output: &Output, stopped: &mpsc::Receiver<()>) {
+    let db = match Database::open_read_only(db_path) {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::warn!("cannot watch for changes: {}", e);
+            return;
+        }
+    };
+    while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(POLL_INTERVAL) {
+        match changes(&db, &mut after_id) {
+            Ok(Some(notification)) => {
+                let mut out = output.lock().unwrap_or_else(PoisonError::into_inner);
+                if let Err(e) = write_message(&mut *out, &notification) {
+                    tracing::debug!("cannot send change notification: {}", e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => tracing::debug!("cannot read new events: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "rpc_tests.rs"]
+mod tests;
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+/// Send `message` and parse the response.
+fn call(session: &mut Session, message: Value) -> Value {
+    let reply = session.handle(&message.to_string()).unwrap();
+    serde_json::from_str(&reply).unwrap()
+}
+
+fn request(id: i64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+#[test]
+fn messages_are_framed_with_content_length() {
+    let mut written = Vec::new();
+    write_message(&mut written, "{\"a\":\"é\"}").unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&written),
+        "Content-Length: 10\r\n\r\n{\"a\":\"é\"}"
+    );
+
+    let mut input = written.clone();
+    input.extend_from_slice(b"content-length: 2\r\nContent-Type: x\r\n\r\n{}");
+    let mut reader = input.as_slice();
+    assert_eq!(
+        read_message(&mut reader).unwrap().as_deref(),
+        Some("{\"a\":\"é\"}")
+    );
+    assert_eq!(read_message(&mut reader).unwrap().as_deref(), Some("{}"));
+    assert_eq!(read_message(&mut reader).unwrap(), None);
+}
+
+#[test]
+fn bad_frames_are_errors() {
+    assert!(read_message(&mut b"Content-Length: x\r\n\r\n".as_slice()).is_err());
+    assert!(read_message(&mut b"Content-Length: 99999999\r\n\r\n".as_slice()).is_err());
+    assert!(read_message(&mut b"Content-Length: 5\r\n\r\n{}".as_slice()).is_err());
+}
+
+#[test]
+fn initialize_reports_server_and_prefix() {
+    let ctx = TestContext::new();
+    let mut session = Session::new(&ctx.db, "demo");
+
+    let reply = call(&mut session, request(1, "initialize", json!({})));
+
+    assert_eq!(reply["id"], 1);
+    assert_eq!(reply["result"]["serverInfo"]["name"], "wok");
+    assert_eq!(reply["result"]["prefix"], "demo");
+}
+
+#[test]
+fn query_and_mutate_use_daemon_operations() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "First");
+    let mut session = Session::new(&ctx.db, "test");
+
+    let reply = call(
+        &mut session,
+        request(
+            1,
+            "wok/mutate",
+            json!({ "op": "AddLabel", "id": "test-1", "label": "ui" }),
+        ),
+    );
+    assert_eq!(reply["result"], json!({ "result": "Ok" }));
+
+    let reply = call(
+        &mut session,
+        request(2, "wok/query", json!({ "op": "GetLabels", "id": "test-1" })),
+    );
+    assert_eq!(
+        reply["result"],
+        json!({ "result": "Labels", "labels": ["ui"] })
+    );
+}
+
+#[test]
+fn wok_errors_carry_their_report() {
+    let ctx = TestContext::new();
+    let mut session = Session::new(&ctx.db, "test");
+
+    let reply = call(
+        &mut session,
+        request(
+            1,
+            "wok/query",
+            json!({ "op": "GetIssue", "id": "test-404" }),
+        ),
+    );
+
+    assert_eq!(reply["error"]["code"], WOK_ERROR);
+    assert_eq!(reply["error"]["data"]["code"], "not_found");
+}
+
+#[test]
+fn protocol_errors_use_json_rpc_codes() {
+    let ctx = TestContext::new();
+    let mut session = Session::new(&ctx.db, "test");
+
+    let reply: Value = serde_json::from_str(&session.handle("{").unwrap()).unwrap();
+    assert_eq!(reply["error"]["code"], PARSE_ERROR);
+    assert_eq!(reply["id"], Value::Null);
+
+    let reply = call(&mut session, json!({ "id": 1, "method": "initialize" }));
+    assert_eq!(reply["error"]["code"], INVALID_REQUEST);
+
+    let reply = call(&mut session, request(2, "wok/nope", Value::Null));
+    assert_eq!(reply["error"]["code"], METHOD_NOT_FOUND);
+
+    let reply = call(
+        &mut session,
+        request(3, "wok/query", json!({ "op": "Nope" })),
+    );
+    assert_eq!(reply["error"]["code"], INVALID_PARAMS);
+}
+
+#[test]
+fn notifications_get_no_response() {
+    let ctx = TestContext::new();
+    let mut session = Session::new(&ctx.db, "test");
+
+    let notification = json!({ "jsonrpc": "2.0", "method": "wok/nope" });
+    assert_eq!(session.handle(&notification.to_string()), None);
+}
+
+#[test]
+fn shutdown_then_exit() {
+    let ctx = TestContext::new();
+    let mut session = Session::new(&ctx.db, "test");
+
+    assert_eq!(
+        call(&mut session, request(1, "shutdown", Value::Null))["result"],
+        Value::Null
+    );
+    let reply = call(&mut session, request(2, "initialize", Value::Null));
+    assert_eq!(reply["error"]["code"], INVALID_REQUEST);
+    assert!(!session.exited);
+
+    let exit = json!({ "jsonrpc": "2.0", "method": "exit" });
+    assert_eq!(session.handle(&exit.to_string()), None);
+    assert!(session.exited);
+}
+
+#[test]
+fn changes_notify_new_events_once() {
+    let mut ctx = TestContext::new();
+    let mut after_id = ctx.db.last_event_id().unwrap();
+    assert_eq!(changes(&ctx.db, &mut after_id).unwrap(), None);
+
+    ctx.create_issue("test-1", IssueType::Task, "First");
+    ctx.add_label("test-1", "ui");
+
+    let notification: Value =
+        serde_json::from_str(&changes(&ctx.db, &mut after_id).unwrap().unwrap()).unwrap();
+    assert_eq!(notification["method"], "wok/didChange");
+    let events = notification["params"]["events"].as_array().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1]["action"], "labeled");
+    assert_eq!(changes(&ctx.db, &mut after_id).unwrap(), None);
+}
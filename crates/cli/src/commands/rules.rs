@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Automation rules management commands.
+//!
+//! Commands for listing configured rules and tracing them in dry-run mode.
+
+use std::path::Path;
+
+use crate::cli::{OutputFormat, RulesCommand};
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::hooks::HookEvent;
+use crate::rules::{evaluate_rules, load_rules_config, RuleAction, MAX_RULE_STEPS};
+
+use super::hook::parse_event;
+use super::open_db;
+
+/// Execute a rules subcommand.
+pub fn run(cmd: RulesCommand) -> Result<()> {
+    let (db, _, work_dir) = open_db()?;
+    match cmd {
+        RulesCommand::List { output } => list_impl(&work_dir, output),
+        RulesCommand::Test { id, event } => {
+            let test_event = match event.as_deref() {
+                Some(e) => parse_event(e)?,
+                None => HookEvent::Created,
+            };
+            test_impl(&db, &work_dir, &id, test_event)
+        }
+    }
+}
+
+/// List configured rules.
+pub(crate) fn list_impl(work_dir: &Path, output: OutputFormat) -> Result<()> {
+    let config = load_rules_config(work_dir)?;
+
+    match config {
+        None => {
+            if matches!(output, OutputFormat::Json) {
+                println!("[]");
+            } else {
+                println!("No rules configured.");
+                println!();
+                println!("To configure rules, create .wok/rules.toml:");
+                println!("  [[rules]]");
+                println!("  name = \"changelog-on-release\"");
+                println!("  events = [\"issue.done\"]");
+                println!("  filter = \"-l release:*\"");
+                println!("  add_labels = [\"changelog\"]");
+            }
+        }
+        Some(config) => {
+            if matches!(output, OutputFormat::Json) {
+                let json = serde_json::to_string_pretty(&config.rules)
+                    .map_err(|e| Error::Config(e.to_string()))?;
+                println!("{}", json);
+            } else {
+                println!("Configured rules:");
+                for rule in &config.rules {
+                    println!();
+                    println!("  {}", rule.name);
+                    println!("    Events: {}", rule.events.join(", "));
+                    if let Some(filter) = &rule.filter {
+                        println!("    Filter: {}", filter);
+                    }
+                    if !rule.add_labels.is_empty() {
+                        println!("    Add labels: {}", rule.add_labels.join(", "));
+                    }
+                    if !rule.remove_labels.is_empty() {
+                        println!("    Remove labels: {}", rule.remove_labels.join(", "));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Trace which rules would fire for an issue, without writing anything.
+pub(crate) fn test_impl(
+    db: &Database,
+    work_dir: &Path,
+    id: &str,
+    test_event: HookEvent,
+) -> Result<()> {
+    let resolved_id = db.resolve_id(id)?;
+
+    let config = match load_rules_config(work_dir)? {
+        Some(c) => c,
+        None => {
+            println!("No rules configured.");
+            return Ok(());
+        }
+    };
+
+    let trace = evaluate_rules(db, &config.rules, &resolved_id, test_event, true)?;
+
+    println!(
+        "Dry run for {} on {}:",
+        resolved_id,
+        test_event.as_event_name()
+    );
+    if trace.steps.is_empty() {
+        println!("  No rules would fire.");
+    }
+    for step in &trace.steps {
+        let change = match &step.action {
+            RuleAction::AddLabel(label) => format!("+{}", label),
+            RuleAction::RemoveLabel(label) => format!("-{}", label),
+        };
+        println!(
+            "  {}: {} (on {})",
+            step.rule,
+            change,
+            step.trigger.as_event_name()
+        );
+    }
+    if trace.truncated {
+        println!(
+            "  Stopped after {} actions (possible rule loop).",
+            MAX_RULE_STEPS
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "rules_tests.rs"]
+mod tests;
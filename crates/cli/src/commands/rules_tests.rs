@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+fn write_rules(ctx: &TestContext) {
+    std::fs::write(
+        ctx.work_dir.join("rules.toml"),
+        r#"
+[[rules]]
+name = "changelog"
+events = ["issue.done"]
+add_labels = ["changelog"]
+"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_list_without_rules() {
+    let ctx = TestContext::new();
+    list_impl(&ctx.work_dir, OutputFormat::Text).unwrap();
+    list_impl(&ctx.work_dir, OutputFormat::Json).unwrap();
+}
+
+#[test]
+fn test_list_with_rules() {
+    let ctx = TestContext::new();
+    write_rules(&ctx);
+    list_impl(&ctx.work_dir, OutputFormat::Text).unwrap();
+    list_impl(&ctx.work_dir, OutputFormat::Json).unwrap();
+}
+
+#[test]
+fn test_trace_does_not_apply() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Ship it");
+    write_rules(&ctx);
+
+    test_impl(&ctx.db, &ctx.work_dir, "test-1", HookEvent::Done).unwrap();
+
+    assert!(ctx.db.get_labels("test-1").unwrap().is_empty());
+}
+
+#[test]
+fn test_trace_unknown_issue() {
+    let ctx = TestContext::new();
+    write_rules(&ctx);
+    assert!(test_impl(&ctx.db, &ctx.work_dir, "missing", HookEvent::Done).is_err());
+}
@@ -3,27 +3,238 @@
 
 //! Schema command implementation.
 //!
-//! Outputs JSON Schema specifications for commands that support JSON output.
+//! Outputs JSON Schema specifications for commands that support JSON output,
+//! and reports the database's schema version.
 
-use crate::cli::SchemaCommand;
+use std::path::Path;
+
+use rusqlite::{Connection, OpenFlags};
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::RootSchema;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use wk_core::migrate::{self, LATEST_VERSION};
+
+use crate::cli::{OutputFormat, SchemaCommand};
+use crate::config::{find_work_dir, get_db_path, Config};
 use crate::error::Result;
-use crate::schema::{list, ready, search, show};
-use schemars::schema_for;
+use crate::hooks::HookPayload;
+use crate::schema::{
+    activity, digest, export, list, ready, rpc, search, show, standup, tree, Event, IssuePageJson,
+};
+
+/// Generates one named schema, adding the types it references to the
+/// generator's definitions.
+type SchemaFn = fn(&mut SchemaGenerator) -> RootSchema;
+
+/// Every schema `wok schema` can print, by name.
+const SCHEMAS: &[(&str, SchemaFn)] = &[
+    (
+        "list",
+        SchemaGenerator::root_schema_for::<list::ListOutputJson>,
+    ),
+    (
+        "show",
+        SchemaGenerator::root_schema_for::<show::ShowOutputJson>,
+    ),
+    (
+        "tree",
+        SchemaGenerator::root_schema_for::<tree::TreeOutputJson>,
+    ),
+    (
+        "ready",
+        SchemaGenerator::root_schema_for::<ready::ReadyOutputJson>,
+    ),
+    (
+        "search",
+        SchemaGenerator::root_schema_for::<search::SearchOutputJson>,
+    ),
+    ("page", SchemaGenerator::root_schema_for::<IssuePageJson>),
+    (
+        "activity",
+        SchemaGenerator::root_schema_for::<activity::ActivityOutputJson<'static>>,
+    ),
+    (
+        "digest",
+        SchemaGenerator::root_schema_for::<digest::DigestOutputJson>,
+    ),
+    (
+        "standup",
+        SchemaGenerator::root_schema_for::<standup::StandupOutputJson>,
+    ),
+    ("log", SchemaGenerator::root_schema_for::<Event>),
+    (
+        "export",
+        SchemaGenerator::root_schema_for::<export::ExportRecord>,
+    ),
+    ("hook", SchemaGenerator::root_schema_for::<HookPayload>),
+    (
+        "rpc",
+        SchemaGenerator::root_schema_for::<rpc::RpcProtocolJson>,
+    ),
+];
 
 /// Run the schema command.
 pub fn run(cmd: SchemaCommand) -> Result<()> {
-    let schema = match cmd {
-        SchemaCommand::List => schema_for!(list::ListOutputJson),
-        SchemaCommand::Show => schema_for!(show::IssueDetails),
-        SchemaCommand::Ready => schema_for!(ready::ReadyOutputJson),
-        SchemaCommand::Search => schema_for!(search::SearchOutputJson),
+    let output = match cmd {
+        SchemaCommand::List => named("list"),
+        SchemaCommand::Show => named("show"),
+        SchemaCommand::Tree => named("tree"),
+        SchemaCommand::Ready => named("ready"),
+        SchemaCommand::Search => named("search"),
+        SchemaCommand::Page => named("page"),
+        SchemaCommand::Activity => named("activity"),
+        SchemaCommand::Digest => named("digest"),
+        SchemaCommand::Standup => named("standup"),
+        SchemaCommand::Log => named("log"),
+        SchemaCommand::Export => named("export"),
+        SchemaCommand::Hook => named("hook"),
+        SchemaCommand::Rpc => named("rpc"),
+        SchemaCommand::All { bundle: false } => all(),
+        SchemaCommand::All { bundle: true } => bundle()?,
+        SchemaCommand::DbVersion { output } => return db_version(output),
     };
 
-    let json = serde_json::to_string_pretty(&schema)?;
+    let json = serde_json::to_string_pretty(&output)?;
     println!("{}", json);
     Ok(())
 }
 
+/// The standalone schema registered under `name`.
+pub(crate) fn named(name: &str) -> Value {
+    SCHEMAS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, schema)| standalone(*schema))
+        .unwrap_or(Value::Null)
+}
+
+/// A complete schema document with its own definitions, as `schema_for!`
+/// would produce.
+fn standalone(schema: SchemaFn) -> Value {
+    let mut gen = SchemaSettings::draft07().into_generator();
+    serde_json::to_value(schema(&mut gen)).unwrap_or(Value::Null)
+}
+
+/// Every standalone schema in one object, keyed by name.
+fn all() -> Value {
+    SCHEMAS
+        .iter()
+        .map(|(name, schema)| (name.to_string(), standalone(*schema)))
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+/// One draft-07 document covering every schema.
+///
+/// Each named schema becomes a property, and the types they share are
+/// defined once under `definitions`, so the document can be fed straight to
+/// an OpenAPI `components.schemas` section or a code generator.
+pub(crate) fn bundle() -> Result<Value> {
+    let mut gen = SchemaSettings::draft07().into_generator();
+    let mut properties = Map::new();
+    for (name, schema) in SCHEMAS {
+        let root = schema(&mut gen);
+        properties.insert(name.to_string(), serde_json::to_value(root.schema)?);
+    }
+    let meta_schema = gen.settings().meta_schema.clone();
+    Ok(json!({
+        "$schema": meta_schema,
+        "title": "wok",
+        "description": "JSON output of every wok command that emits it, keyed by schema name.",
+        "type": "object",
+        "properties": properties,
+        "definitions": gen.take_definitions(),
+    }))
+}
+
+/// A database's schema version, as `wok schema db-version` reports it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct DbVersion {
+    pub path: String,
+    /// 0 for a database from before versioning, or one not created yet.
+    pub version: u32,
+    /// The version this build migrates databases to.
+    pub latest: u32,
+    pub pending: Vec<PendingMigration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct PendingMigration {
+    pub version: u32,
+    pub description: &'static str,
+}
+
+fn db_version(output: OutputFormat) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let config = Config::load(&work_dir)?;
+    let db_path = get_db_path(&work_dir, &config);
+    let exists = db_path.exists();
+    let info = if exists {
+        // Opened read-only: Database::open would migrate it first.
+        let conn = Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        conn.execute_batch("PRAGMA busy_timeout = 5000;")?;
+        db_version_impl(&conn, &db_path)?
+    } else {
+        DbVersion {
+            path: db_path.display().to_string(),
+            version: 0,
+            latest: LATEST_VERSION,
+            pending: Vec::new(),
+        }
+    };
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&info)?),
+        OutputFormat::Id => println!("{}", info.version),
+        OutputFormat::Text => {
+            if exists {
+                println!("Database: {}", info.path);
+            } else {
+                println!("Database: {} (not created yet)", info.path);
+            }
+            print_version(&info);
+        }
+    }
+    Ok(())
+}
+
+fn print_version(info: &DbVersion) {
+    if info.version > info.latest {
+        println!(
+            "Schema version: {} (newer than this wok, which supports {})",
+            info.version, info.latest
+        );
+        return;
+    }
+    println!("Schema version: {} (latest {})", info.version, info.latest);
+    if info.pending.is_empty() {
+        return;
+    }
+    println!("Pending migrations:");
+    for migration in &info.pending {
+        println!("  {}: {}", migration.version, migration.description);
+    }
+    println!();
+    println!("hint: 'wok upgrade' backs up the database and applies them");
+}
+
+/// The version of the database behind `conn` and what migrating it would do.
+pub(crate) fn db_version_impl(conn: &Connection, path: &Path) -> Result<DbVersion> {
+    Ok(DbVersion {
+        path: path.display().to_string(),
+        version: migrate::db_version(conn)?,
+        latest: LATEST_VERSION,
+        pending: migrate::pending(conn)?
+            .into_iter()
+            .map(|m| PendingMigration {
+                version: m.version,
+                description: m.description,
+            })
+            .collect(),
+    })
+}
+
 #[cfg(test)]
 #[path = "schema_tests.rs"]
 mod tests;
@@ -3,8 +3,7 @@
 
 #![allow(clippy::unwrap_used)]
 
-use crate::cli::SchemaCommand;
-use crate::schema::{list, ready, search, show};
+use super::*;
 
 #[test]
 fn schema_list_produces_valid_json() {
@@ -31,7 +30,8 @@ fn schema_show_includes_nested_types() {
 
     // Should include definitions for nested types
     assert!(json.contains("\"Note\""));
-    assert!(json.contains("\"Link\""));
+    assert!(json.contains("\"LinkDetails\""));
+    assert!(json.contains("\"LinkMeta\""));
     assert!(json.contains("\"Event\""));
 }
 
@@ -73,22 +73,112 @@ fn schema_search_is_array_schema() {
 
 #[test]
 fn all_schema_commands_produce_output() {
-    for cmd in [
-        SchemaCommand::List,
-        SchemaCommand::Show,
-        SchemaCommand::Ready,
-        SchemaCommand::Search,
+    for (name, _) in SCHEMAS {
+        let schema = named(name);
+        assert_eq!(
+            schema["$schema"], "http://json-schema.org/draft-07/schema#",
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn all_keys_every_schema_by_name() {
+    let schemas = all();
+    let mut names: Vec<&str> = schemas
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    let mut expected: Vec<&str> = SCHEMAS.iter().map(|(name, _)| *name).collect();
+    names.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn bundle_shares_definitions() {
+    let bundle = bundle().unwrap();
+    assert_eq!(bundle["$schema"], "http://json-schema.org/draft-07/schema#");
+    for (name, _) in SCHEMAS {
+        assert!(bundle["properties"][name].is_object(), "{}", name);
+    }
+    let definitions = bundle["definitions"].as_object().unwrap();
+    for name in ["IssueJson", "Event", "Note", "IssuePayload"] {
+        assert!(definitions.contains_key(name), "{}", name);
+    }
+    // References resolve against the bundle's own definitions.
+    let json = serde_json::to_string(&bundle).unwrap();
+    assert!(json.contains("\"$ref\":\"#/definitions/IssueJson\""));
+}
+
+#[test]
+fn export_schema_flattens_issue_fields() {
+    let schema = named("export");
+    let properties = schema["properties"].as_object().unwrap();
+    for field in [
+        "id",
+        "title",
+        "created_at",
+        "labels",
+        "notes",
+        "deps",
+        "links",
+        "events",
     ] {
-        // Verify no panic - actual output tested in e2e
-        let _schema = match cmd {
-            SchemaCommand::List => schemars::schema_for!(list::ListOutputJson),
-            SchemaCommand::Show => schemars::schema_for!(show::IssueDetails),
-            SchemaCommand::Ready => schemars::schema_for!(ready::ReadyOutputJson),
-            SchemaCommand::Search => schemars::schema_for!(search::SearchOutputJson),
-        };
+        assert!(properties.contains_key(field), "{}", field);
     }
 }
 
+#[test]
+fn digest_schema_has_every_section() {
+    let schema = named("digest");
+    assert_eq!(schema["title"], "DigestOutputJson");
+    for section in ["completed", "created", "stalled", "blocked", "due"] {
+        assert!(schema["properties"][section].is_object(), "{}", section);
+    }
+    assert!(schema["definitions"]["DigestIssueJson"].is_object());
+}
+
+#[test]
+fn standup_schema_is_an_array_of_standups() {
+    let schema = named("standup");
+    assert_eq!(schema["type"], "array");
+    let standup = &schema["definitions"]["StandupJson"]["properties"];
+    for section in ["yesterday", "today", "blockers"] {
+        assert!(standup[section].is_object(), "{}", section);
+    }
+}
+
+#[test]
+fn hook_schema_describes_payload() {
+    let schema = named("hook");
+    assert_eq!(schema["title"], "HookPayload");
+    assert!(schema["properties"]["issue"].is_object());
+    assert!(schema["properties"]["change"].is_object());
+}
+
+#[test]
+fn rpc_schema_lists_methods() {
+    let schema = named("rpc");
+    assert_eq!(schema["title"], "RpcProtocolJson");
+    let properties = schema["properties"].as_object().unwrap();
+    for method in [
+        "initialize",
+        "wok/query",
+        "wok/mutate",
+        "shutdown",
+        "exit",
+        "wok/didChange",
+    ] {
+        assert!(properties.contains_key(method), "{}", method);
+    }
+    assert!(schema["definitions"]["QueryOp"].is_object());
+    assert!(schema["definitions"]["MutateResult"].is_object());
+}
+
 #[test]
 fn schema_issue_json_has_all_fields() {
     let schema = schemars::schema_for!(crate::schema::IssueJson);
@@ -139,3 +229,36 @@ fn schema_status_has_all_variants() {
     assert!(json.contains("\"done\""));
     assert!(json.contains("\"closed\""));
 }
+
+#[test]
+fn db_version_of_new_database_is_latest() {
+    let db = crate::db::Database::open_in_memory().unwrap();
+    let info = db_version_impl(&db.conn, Path::new("issues.db")).unwrap();
+    assert_eq!(info.version, LATEST_VERSION);
+    assert_eq!(info.latest, LATEST_VERSION);
+    assert!(info.pending.is_empty());
+}
+
+#[test]
+fn db_version_lists_pending_migrations() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE issues (
+            id TEXT PRIMARY KEY,
+            type TEXT NOT NULL,
+            title TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL DEFAULT 'todo',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );",
+    )
+    .unwrap();
+
+    let info = db_version_impl(&conn, Path::new("issues.db")).unwrap();
+    assert_eq!(info.version, 0);
+    assert_eq!(info.pending.first().map(|m| m.version), Some(1));
+    assert_eq!(info.pending.last().map(|m| m.version), Some(LATEST_VERSION));
+    // Reporting does not migrate.
+    assert_eq!(migrate::db_version(&conn).unwrap(), 0);
+}
@@ -3,22 +3,25 @@
 
 use chrono::Utc;
 
-use crate::cli::OutputFormat;
+use crate::cli::{ListFormat, PageArgs, SortArgs};
 use crate::db::Database;
 use crate::display::format_issue_line;
 use crate::error::Result;
-use crate::filter::{parse_filter, FilterExpr};
-use crate::models::{IssueType, Status};
+use crate::filter::{parse_query, FilterQuery};
+use crate::models::{Issue, IssueType, Status};
+use crate::ndjson;
 use crate::schema::search::SearchOutputJson;
 use crate::schema::IssueJson;
+use wk_core::query::{Cond, IssueQuery, QueryOrder};
 
-use super::filtering::{
-    matches_filter_groups, matches_label_groups, matches_prefix, parse_filter_groups, LabelMatcher,
-};
+use super::assign::resolve_assignees;
+use super::filtering::{filter_groups_cond, label_groups_cond, parse_filter_groups, LabelMatcher};
 use super::open_db;
+use super::paging::{self, PageRequest};
+use super::sorting::sort_issues;
 
 /// Default limit for search results in text output.
-const DEFAULT_LIMIT: usize = 25;
+pub(crate) const DEFAULT_LIMIT: usize = 25;
 
 // TODO(refactor): Consider using an options struct to bundle parameters
 #[allow(clippy::too_many_arguments)]
@@ -33,11 +36,26 @@ pub fn run(
     filter: Vec<String>,
     limit: Option<usize>,
     no_limit: bool,
-    format: OutputFormat,
+    format: ListFormat,
+    sort: SortArgs,
+    paging: PageArgs,
 ) -> Result<()> {
-    let (db, config, _) = open_db()?;
+    let conflicting = if sort.sort.is_some() {
+        Some("--sort")
+    } else if limit.is_some() || no_limit {
+        Some("--limit")
+    } else {
+        None
+    };
+    let page = paging::page_request(format, &paging, conflicting)?;
+    let (db, config, work_dir) = open_db()?;
+    let assignee = resolve_assignees(assignee, &config, &work_dir)?;
     let prefix = prefix.or((!config.prefix.is_empty()).then_some(config.prefix));
-    let effective_limit = if no_limit { Some(0) } else { limit };
+    let effective_limit = if no_limit {
+        Some(0)
+    } else {
+        limit.or(config.limits.search)
+    };
     run_impl(
         &db,
         query,
@@ -50,6 +68,8 @@ pub fn run(
         filter,
         effective_limit,
         format,
+        &sort,
+        page.as_ref(),
     )
 }
 
@@ -66,7 +86,9 @@ pub(crate) fn run_impl(
     unassigned: bool,
     filter: Vec<String>,
     limit: Option<usize>,
-    format: OutputFormat,
+    format: ListFormat,
+    sort: &SortArgs,
+    page: Option<&PageRequest>,
 ) -> Result<()> {
     // Parse filter groups
     let status_groups = parse_filter_groups(&status, |s| Ok(s.parse::<Status>()?))?;
@@ -74,71 +96,58 @@ pub(crate) fn run_impl(
         parse_filter_groups(&issue_type, |s| s.parse::<IssueType>().map_err(Into::into))?;
     let label_groups = parse_filter_groups(&label, LabelMatcher::parse)?;
 
-    // Parse time-based filter expressions
-    let filters: Vec<FilterExpr> = filter
+    // Parse filter expressions
+    let filters: Vec<FilterQuery> = filter
         .iter()
-        .map(|f| parse_filter(f))
+        .map(|f| parse_query(f))
         .collect::<Result<_>>()?;
 
-    // Search issues
-    let mut issues = db.search_issues(query)?;
-
-    // Filter by prefix (cheap string comparison, apply early)
-    if prefix.is_some() {
-        issues.retain(|issue| matches_prefix(&prefix, &issue.id));
+    let mut conds = vec![Cond::Text(query.to_string())];
+    if let Some(prefix) = &prefix {
+        conds.push(Cond::IdPrefix(prefix.clone()));
     }
 
     // Apply filters (same logic as list)
-    if status_groups.is_some() {
-        issues.retain(|issue| matches_filter_groups(&status_groups, || issue.status));
-    }
-
-    if type_groups.is_some() {
-        issues.retain(|issue| matches_filter_groups(&type_groups, || issue.issue_type));
-    }
-
-    if label_groups.is_some() {
-        issues.retain(|issue| {
-            let issue_labels = db.get_labels(&issue.id).unwrap_or_default();
-            matches_label_groups(&label_groups, &issue_labels)
-        });
-    }
+    conds.extend(filter_groups_cond(&status_groups, |g| {
+        Cond::Status(g.to_vec())
+    }));
+    conds.extend(filter_groups_cond(&type_groups, |g| Cond::Type(g.to_vec())));
+    conds.extend(label_groups_cond(&label_groups));
 
     // Apply assignee filter
     if unassigned {
-        issues.retain(|issue| issue.assignee.is_none());
+        conds.push(Cond::Unassigned);
     } else if !assignee.is_empty() {
-        issues.retain(|issue| {
-            issue
-                .assignee
-                .as_ref()
-                .is_some_and(|a| assignee.iter().any(|f| a == f))
-        });
+        conds.push(Cond::Assignee(assignee));
     }
 
-    // Apply time-based filters
-    if !filters.is_empty() {
-        let now = Utc::now();
-        issues.retain(|issue| filters.iter().all(|f| f.matches(issue, now)));
-    }
+    // Apply filter expressions
+    let now = Utc::now();
+    conds.extend(filters.iter().map(|f| f.to_cond(now)));
 
-    // Sort by priority ASC, then created_at DESC (same as list)
-    issues.sort_by(|a, b| {
-        let tags_a = db.get_labels(&a.id).unwrap_or_default();
-        let tags_b = db.get_labels(&b.id).unwrap_or_default();
-        let priority_a = crate::db::priority_from_tags(&tags_a);
-        let priority_b = crate::db::priority_from_tags(&tags_b);
-
-        match priority_a.cmp(&priority_b) {
-            std::cmp::Ordering::Equal => b.created_at.cmp(&a.created_at), // DESC
-            other => other,
-        }
-    });
+    if format == ListFormat::JsonPaged {
+        let page = page.cloned().unwrap_or_default();
+        return paging::print_page(db, &page, Cond::All(conds));
+    }
 
     // Use explicit limit or default (0 = unlimited)
     let effective_limit = limit.unwrap_or(DEFAULT_LIMIT);
     let unlimited = effective_limit == 0;
 
+    // Sort by priority ASC, then created_at DESC (same as list). Every match is
+    // fetched so the "... N more" count stays exact, except when streaming
+    // unsorted ndjson, which has no such line.
+    let query = IssueQuery::new(Cond::All(conds)).with_order(QueryOrder::Priority);
+    if format == ListFormat::Ndjson && sort.sort.is_none() {
+        let mut rows =
+            db.stream_issues(&query.with_limit((!unlimited).then_some(effective_limit)))?;
+        ndjson::print_lines(rows.iter()?.map(|issue| issue_json(db, &issue?)))?;
+        return Ok(());
+    }
+    let mut issues = db.query_issues(&query)?;
+
+    sort_issues(db, &mut issues, sort)?;
+
     // Calculate how many more results exist beyond the limit
     let total_count = issues.len();
     let more_count = if !unlimited && total_count > effective_limit {
@@ -153,8 +162,9 @@ pub(crate) fn run_impl(
         effective_limit
     };
 
+    let _render = tracing::info_span!("render").entered();
     match format {
-        OutputFormat::Text => {
+        ListFormat::Text => {
             for issue in issues.iter().take(take_count) {
                 println!("{}", format_issue_line(issue));
             }
@@ -162,23 +172,23 @@ pub(crate) fn run_impl(
                 println!("... {} more", count);
             }
         }
-        OutputFormat::Json => {
-            let mut json_issues = Vec::new();
-            for issue in issues.iter().take(take_count) {
-                let labels = db.get_labels(&issue.id)?;
-                json_issues.push(IssueJson::new(
-                    issue.id.clone(),
-                    issue.issue_type,
-                    issue.status,
-                    issue.title.clone(),
-                    issue.assignee.clone(),
-                    labels,
-                ));
-            }
+        ListFormat::Json | ListFormat::JsonPaged => {
+            let json_issues = issues
+                .iter()
+                .take(take_count)
+                .map(|issue| issue_json(db, issue))
+                .collect::<Result<_>>()?;
             let output = SearchOutputJson(json_issues);
             println!("{}", serde_json::to_string_pretty(&output)?);
         }
-        OutputFormat::Id => {
+        ListFormat::Ndjson => {
+            let lines = issues
+                .iter()
+                .take(take_count)
+                .map(|issue| issue_json(db, issue));
+            ndjson::print_lines(lines)?;
+        }
+        ListFormat::Id => {
             for issue in issues.iter().take(take_count) {
                 println!("{}", issue.id);
             }
@@ -188,6 +198,18 @@ pub(crate) fn run_impl(
     Ok(())
 }
 
+/// An issue's JSON summary, with its labels read from `db`.
+fn issue_json(db: &Database, issue: &Issue) -> Result<IssueJson> {
+    Ok(IssueJson::new(
+        issue.id.clone(),
+        issue.issue_type,
+        issue.status,
+        issue.title.clone(),
+        issue.assignee.clone(),
+        db.get_labels(&issue.id)?,
+    ))
+}
+
 #[cfg(test)]
 #[path = "search_tests.rs"]
 mod tests;
@@ -39,7 +39,9 @@ fn search_finds_title_match() {
         false,
         vec![],
         None,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -76,7 +78,9 @@ fn search_with_status_filter() {
         false,
         vec![],
         None,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -111,7 +115,9 @@ fn search_with_type_filter() {
         false,
         vec![],
         None,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -148,7 +154,9 @@ fn search_with_label_filter() {
         false,
         vec![],
         None,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -176,7 +184,9 @@ fn search_no_matches_returns_empty() {
         false,
         vec![],
         None,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -204,7 +214,9 @@ fn search_json_output() {
         false,
         vec![],
         None,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -240,7 +252,9 @@ fn search_limits_results_to_25() {
         false,
         vec![],
         None,
-        OutputFormat::Text,
+        ListFormat::Text,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
@@ -272,7 +286,9 @@ fn search_json_includes_more_count() {
         false,
         vec![],
         None,
-        OutputFormat::Json,
+        ListFormat::Json,
+        &SortArgs::default(),
+        None,
     )
     .unwrap();
 }
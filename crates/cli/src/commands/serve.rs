@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok serve` - a read-only web dashboard for the local database.
+//!
+//! A small HTTP/1.1 server on `std::net` answers one connection at a time,
+//! rendering each page from the database as it is requested, so a refresh
+//! always shows the current state. The database is opened read-only: no
+//! request can change it.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use crate::config::AgingConfig;
+use crate::db::Database;
+use crate::error::{ErrorCode, Result};
+use crate::t;
+
+use super::open_db;
+use super::serve_pages::{board_page, error_page, graph_page, issue_page, list_page};
+
+/// How long a client may take to send its request or read the response.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest request line or header line read.
+const MAX_LINE: u64 = 8 * 1024;
+
+/// Serve the dashboard on `host:port` until interrupted.
+pub fn run(host: &str, port: u16) -> Result<()> {
+    crate::read_only::set_read_only(true);
+    let (db, config, _work_dir) = open_db()?;
+    let listener = TcpListener::bind((host, port))?;
+    let url = format!("http://{}", listener.local_addr()?);
+    println!("{}", t!("serve.listening", url = url));
+
+    for stream in listener.incoming() {
+        let result = stream
+            .map_err(Into::into)
+            .and_then(|stream| handle(&db, &config.prefix, &config.aging, stream));
+        if let Err(e) = result {
+            tracing::debug!("request failed: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Answer one connection.
+fn handle(db: &Database, project: &str, aging: &AgingConfig, stream: TcpStream) -> Result<()> {
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+
+    let request_line = read_line(&mut reader)?;
+    // The headers say nothing a read-only dashboard needs
+    while !read_line(&mut reader)?.is_empty() {}
+
+    let response = match Request::parse(&request_line) {
+        Some(request) => route(db, project, aging, &request),
+        None => Response::error(project, 400, "Bad Request", &request_line),
+    };
+    let head_only = request_line.starts_with("HEAD ");
+    let mut stream = &stream;
+    stream.write_all(&response.to_bytes(head_only))?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// One CRLF-terminated line, without the line ending.
+fn read_line<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut line = String::new();
+    Read::take(reader, MAX_LINE).read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// The parts of a request line the dashboard routes on.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Request {
+    pub method: String,
+    /// Percent-decoded path, e.g. `/issue/prj-a3f2`.
+    pub path: String,
+    /// Query parameter names, e.g. `all` for `/?all`.
+    pub query: Vec<String>,
+}
+
+impl Request {
+    /// Parse `GET /path?query HTTP/1.1`.
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split(' ');
+        let method = parts.next()?.to_string();
+        let target = parts.next()?;
+        if !parts.next()?.starts_with("HTTP/") || !target.starts_with('/') {
+            return None;
+        }
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|p| !p.is_empty())
+            .map(|p| percent_decode(p.split('=').next().unwrap_or_default()))
+            .collect();
+        Some(Request {
+            method,
+            path: percent_decode(path),
+            query,
+        })
+    }
+}
+
+/// Decode `%XX` escapes, leaving malformed ones as they are.
+pub(crate) fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok());
+        match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// A complete response.
+#[derive(Debug)]
+pub(crate) struct Response {
+    pub status: u16,
+    pub reason: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Self {
+        Response {
+            status: 200,
+            reason: "OK",
+            body,
+        }
+    }
+
+    fn error(project: &str, status: u16, reason: &'static str, message: &str) -> Self {
+        Response {
+            status,
+            reason,
+            body: error_page(project, reason, message),
+        }
+    }
+
+    /// Status line, headers, and the body unless `head_only`.
+    fn to_bytes(&self, head_only: bool) -> Vec<u8> {
+        let mut bytes = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: text/html; charset=utf-8\r\n\
+             Content-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+            self.status,
+            self.reason,
+            self.body.len()
+        )
+        .into_bytes();
+        if !head_only {
+            bytes.extend_from_slice(self.body.as_bytes());
+        }
+        bytes
+    }
+}
+
+/// The page for `request`.
+pub(crate) fn route(
+    db: &Database,
+    project: &str,
+    aging: &AgingConfig,
+    request: &Request,
+) -> Response {
+    if request.method != "GET" && request.method != "HEAD" {
+        return Response::error(project, 405, "Method Not Allowed", &request.method);
+    }
+    let page = match request.path.as_str() {
+        "/" => list_page(db, project, request.query.iter().any(|q| q == "all")),
+        "/board" => board_page(db, project, aging),
+        "/graph" => graph_page(db, project),
+        path => match path.strip_prefix("/issue/") {
+            Some(id) if !id.is_empty() => issue_page(db, project, id),
+            _ => return Response::error(project, 404, "Not Found", path),
+        },
+    };
+    match page {
+        Ok(body) => Response::ok(body),
+        Err(e) => match e.code() {
+            ErrorCode::NotFound => Response::error(project, 404, "Not Found", &e.to_string()),
+            ErrorCode::AmbiguousId | ErrorCode::InvalidArgument => {
+                Response::error(project, 400, "Bad Request", &e.to_string())
+            }
+            _ => Response::error(project, 500, "Internal Server Error", &e.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "serve_tests.rs"]
+mod tests;
@@ -0,0 +1,520 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! HTML pages for `wok serve`.
+//!
+//! Each page is rendered from the database on request as one self-contained
+//! document: styles are inlined and the dependency graph is an inline SVG,
+//! so the dashboard works without network access.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+use chrono::Utc;
+
+use crate::aging::{stale_issues, Stale};
+use crate::config::AgingConfig;
+use crate::db::Database;
+use crate::display::{
+    format_event, format_remote, is_external_blocker, truncate_to_width, ChainBlocker,
+};
+use crate::error::Result;
+use crate::estimate::{estimate_from_tags, Estimate};
+use crate::models::{Issue, Relation, Status};
+use crate::schema::show::LinkDetails;
+use crate::t;
+use wk_core::query::{Cond, IssueQuery, QueryOrder};
+
+use super::show::{blocker_chain, link_details};
+
+/// Inlined into every page.
+const STYLE: &str = include_str!("serve/style.css");
+
+/// Most done or closed issues on the board, most recently closed first.
+pub(crate) const BOARD_CLOSED_LIMIT: usize = 20;
+
+/// Graph node size and spacing, in SVG units.
+const NODE_WIDTH: usize = 200;
+const NODE_HEIGHT: usize = 44;
+const COLUMN_GAP: usize = 80;
+const ROW_GAP: usize = 16;
+
+/// Escape text for HTML element content and quoted attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// A whole document: `body` under the navigation bar.
+pub(crate) fn layout(project: &str, title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{title} · {project}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n\
+         <nav><strong>{project}</strong> <a href=\"/\">{issues}</a> \
+         <a href=\"/board\">{board}</a> <a href=\"/graph\">{graph}</a></nav>\n\
+         <main>\n{body}</main>\n</body>\n</html>\n",
+        title = escape(title),
+        project = escape(project),
+        issues = t!("serve.issues"),
+        board = t!("serve.board"),
+        graph = t!("serve.graph"),
+    )
+}
+
+/// The error page for a status code.
+pub(crate) fn error_page(project: &str, title: &str, message: &str) -> String {
+    layout(
+        project,
+        title,
+        &format!("<h1>{}</h1>\n<p>{}</p>\n", escape(title), escape(message)),
+    )
+}
+
+fn issue_link(id: &str) -> String {
+    format!("<a href=\"/issue/{0}\">{0}</a>", escape(id))
+}
+
+fn status_badge(status: Status) -> String {
+    format!("<span class=\"status status-{0}\">{0}</span>", status)
+}
+
+/// Open issues, or every issue with `all`, in priority order.
+pub(crate) fn list_page(db: &Database, project: &str, all: bool) -> Result<String> {
+    let statuses = match all {
+        true => vec![
+            Status::Todo,
+            Status::InProgress,
+            Status::Done,
+            Status::Closed,
+        ],
+        false => vec![Status::Todo, Status::InProgress],
+    };
+    let issues =
+        db.query_issues(&IssueQuery::new(Cond::Status(statuses)).with_order(QueryOrder::Priority))?;
+    let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    let labels = db.get_labels_batch(&ids)?;
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+
+    let mut body = format!("<h1>{}</h1>\n<p class=\"filters\">", t!("serve.issues"));
+    let (open, every) = (t!("serve.open"), t!("serve.all"));
+    body.push_str(&match all {
+        true => format!("<a href=\"/\">{}</a> · <strong>{}</strong>", open, every),
+        false => format!(
+            "<strong>{}</strong> · <a href=\"/?all\">{}</a>",
+            open, every
+        ),
+    });
+    body.push_str("</p>\n");
+
+    if issues.is_empty() {
+        let _ = writeln!(body, "<p>{}</p>", t!("serve.none"));
+        return Ok(layout(project, &t!("serve.issues"), &body));
+    }
+
+    let _ = writeln!(
+        body,
+        "<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+        t!("serve.id"),
+        t!("serve.type"),
+        t!("serve.status"),
+        t!("serve.title"),
+        t!("serve.assignee"),
+        t!("serve.labels"),
+    );
+    for issue in &issues {
+        let mut status = status_badge(issue.status);
+        if blocked.contains(&issue.id) {
+            let _ = write!(
+                status,
+                " <span class=\"blocked\">{}</span>",
+                t!("serve.blocked")
+            );
+        }
+        let issue_labels = labels
+            .get(&issue.id)
+            .map(|l| l.join(", "))
+            .unwrap_or_default();
+        let _ = writeln!(
+            body,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            issue_link(&issue.id),
+            issue.issue_type,
+            status,
+            escape(&issue.title),
+            escape(issue.assignee.as_deref().unwrap_or("")),
+            escape(&issue_labels),
+        );
+    }
+    body.push_str("</table>\n");
+    Ok(layout(project, &t!("serve.issues"), &body))
+}
+
+/// One column per status. Done and closed show only the
+/// [`BOARD_CLOSED_LIMIT`] most recently closed. Cards past their `aging`
+/// threshold are marked stale.
+pub(crate) fn board_page(db: &Database, project: &str, aging: &AgingConfig) -> Result<String> {
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+    let now = Utc::now();
+    let mut body = format!("<h1>{}</h1>\n<div class=\"board\">\n", t!("serve.board"));
+    for status in [
+        Status::Todo,
+        Status::InProgress,
+        Status::Done,
+        Status::Closed,
+    ] {
+        let mut issues = db.query_issues(
+            &IssueQuery::new(Cond::Status(vec![status])).with_order(QueryOrder::Priority),
+        )?;
+        if !status.is_active() {
+            issues.sort_by_key(|issue| std::cmp::Reverse(issue.closed_at));
+            issues.truncate(BOARD_CLOSED_LIMIT);
+        }
+        let _ = writeln!(
+            body,
+            "<section class=\"column\">\n<h2>{} <span class=\"count\">{}</span></h2>",
+            status,
+            issues.len()
+        );
+        let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+        let labels = db.get_labels_batch(&ids)?;
+        let members: Vec<&Issue> = issues.iter().collect();
+        let stale = stale_issues(db, aging, &members, now)?;
+        for issue in &issues {
+            let estimate = labels
+                .get(&issue.id)
+                .and_then(|tags| estimate_from_tags(tags));
+            body.push_str(&card(
+                issue,
+                estimate,
+                blocked.contains(&issue.id),
+                stale.get(&issue.id),
+            ));
+        }
+        body.push_str("</section>\n");
+    }
+    body.push_str("</div>\n");
+    Ok(layout(project, &t!("serve.board"), &body))
+}
+
+fn card(issue: &Issue, estimate: Option<Estimate>, blocked: bool, stale: Option<&Stale>) -> String {
+    let mut meta = format!("{} · {}", issue_link(&issue.id), issue.issue_type);
+    if let Some(assignee) = &issue.assignee {
+        meta.push_str(&format!(" · {}", escape(assignee)));
+    }
+    if let Some(estimate) = estimate {
+        meta.push_str(&format!(" · {}", estimate));
+    }
+    if blocked {
+        meta.push_str(&format!(
+            " <span class=\"blocked\">{}</span>",
+            t!("serve.blocked")
+        ));
+    }
+    if let Some(stale) = stale {
+        meta.push_str(&format!(" <span class=\"stale\">{}</span>", stale));
+    }
+    format!(
+        "<div class=\"card\"><div class=\"meta\">{}</div><div>{}</div></div>\n",
+        meta,
+        escape(&issue.title)
+    )
+}
+
+/// Everything `wok show` prints about an issue.
+pub(crate) fn issue_page(db: &Database, project: &str, id: &str) -> Result<String> {
+    let id = db.resolve_id(id)?;
+    let issue = db.get_issue(&id)?;
+    let links = link_details(db, db.get_links(&id)?)?;
+
+    let mut body = format!(
+        "<h1><span class=\"type\">[{}]</span> {}</h1>\n<p>{}</p>\n<dl>\n",
+        issue.issue_type,
+        escape(&issue.id),
+        escape(&issue.title)
+    );
+    let mut field = |name: String, value: String| {
+        let _ = writeln!(body, "<dt>{}</dt><dd>{}</dd>", name, value);
+    };
+    field(t!("serve.status"), status_badge(issue.status));
+    if let Some(assignee) = &issue.assignee {
+        field(t!("serve.assignee"), escape(assignee));
+    }
+    let labels = db.get_labels(&id)?;
+    if !labels.is_empty() {
+        field(t!("serve.labels"), escape(&labels.join(", ")));
+    }
+    field(
+        t!("serve.created"),
+        issue.created_at.format("%Y-%m-%d %H:%M").to_string(),
+    );
+    field(
+        t!("serve.updated"),
+        issue.updated_at.format("%Y-%m-%d %H:%M").to_string(),
+    );
+    body.push_str("</dl>\n");
+
+    if let Some(description) = issue.description.as_deref().filter(|d| !d.is_empty()) {
+        let _ = writeln!(
+            body,
+            "<h2>{}</h2>\n<pre class=\"text\">{}</pre>",
+            t!("serve.description"),
+            escape(description)
+        );
+    }
+
+    let mut blocked_by: Vec<String> = blocker_chain(db, &id)?.iter().map(chain_item).collect();
+    blocked_by.extend(
+        links
+            .iter()
+            .filter(|l| is_external_blocker(l))
+            .map(link_item),
+    );
+    section(&mut body, &t!("serve.blocked_by"), &blocked_by);
+    let related = |ids: Vec<String>| -> Result<Vec<String>> {
+        ids.iter().map(|id| related_item(db, id)).collect()
+    };
+    section(
+        &mut body,
+        &t!("serve.blocks"),
+        &related(db.get_blocking(&id)?)?,
+    );
+    section(
+        &mut body,
+        &t!("serve.tracked_by"),
+        &related(db.get_tracking(&id)?)?,
+    );
+    section(
+        &mut body,
+        &t!("serve.tracks"),
+        &related(db.get_tracked(&id)?)?,
+    );
+    section(
+        &mut body,
+        &t!("serve.links"),
+        &links.iter().map(link_item).collect::<Vec<_>>(),
+    );
+
+    let notes = db.get_notes_by_status(&id)?;
+    if !notes.is_empty() {
+        let _ = writeln!(body, "<h2>{}</h2>", t!("serve.notes"));
+        for (status, notes) in &notes {
+            let _ = writeln!(body, "<h3>{}</h3>", status);
+            for note in notes {
+                let _ = writeln!(
+                    body,
+                    "<div class=\"note\"><div class=\"meta\">{}</div><pre class=\"text\">{}</pre></div>",
+                    note.created_at.format("%Y-%m-%d %H:%M"),
+                    escape(&note.content)
+                );
+            }
+        }
+    }
+
+    let events: Vec<String> = db
+        .get_events(&id)?
+        .iter()
+        .map(|e| escape(format_event(e).trim()))
+        .collect();
+    section(&mut body, &t!("serve.log"), &events);
+
+    Ok(layout(project, &issue.id, &body))
+}
+
+/// A list section under `title`, left out when `items` is empty.
+fn section(body: &mut String, title: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    let _ = writeln!(body, "<h2>{}</h2>\n<ul>", title);
+    for item in items {
+        let _ = writeln!(body, "<li>{}</li>", item);
+    }
+    body.push_str("</ul>\n");
+}
+
+fn chain_item(blocker: &ChainBlocker) -> String {
+    let mut item = format!(
+        "<span class=\"depth\" style=\"margin-left: {}em\"></span>{}",
+        (blocker.depth - 1) * 2,
+        issue_link(&blocker.id)
+    );
+    if let Some((title, status)) = &blocker.issue {
+        item.push_str(&format!(" {} {}", status_badge(*status), escape(title)));
+    }
+    if blocker.actionable {
+        item.push_str(&format!(
+            " <span class=\"actionable\">{}</span>",
+            t!("show.actionable")
+        ));
+    }
+    item
+}
+
+fn related_item(db: &Database, id: &str) -> Result<String> {
+    match db.get_issue(id) {
+        Ok(issue) => Ok(format!(
+            "{} {} {}",
+            issue_link(id),
+            status_badge(issue.status),
+            escape(&issue.title)
+        )),
+        Err(wk_core::Error::IssueNotFound(_)) => Ok(escape(id)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn link_item(details: &LinkDetails) -> String {
+    let link = &details.link;
+    let url = link.url.as_deref().unwrap_or_default();
+    let text = details
+        .remote
+        .as_ref()
+        .and_then(|meta| format_remote(link, meta))
+        .unwrap_or_else(|| url.to_string());
+    let mut item = match url.starts_with("http://") || url.starts_with("https://") {
+        true => format!("<a href=\"{}\">{}</a>", escape(url), escape(&text)),
+        false => escape(&text),
+    };
+    if let Some(link_type) = &link.link_type {
+        item = format!("[{}] {}", escape(link_type.as_str()), item);
+    }
+    match (&link.rel, link.resolved_at) {
+        (Some(rel), Some(_)) => item.push_str(&format!(" ({}, resolved)", rel)),
+        (Some(rel), None) => item.push_str(&format!(" ({})", rel)),
+        (None, _) => {}
+    }
+    item
+}
+
+/// Open issues that block or are blocked by other open issues, laid out in
+/// columns by how deep in a blocking chain they sit.
+pub(crate) fn graph_page(db: &Database, project: &str) -> Result<String> {
+    let open = db.query_issues(
+        &IssueQuery::new(Cond::Status(vec![Status::Todo, Status::InProgress]))
+            .with_order(QueryOrder::Priority),
+    )?;
+    let open_ids: HashSet<&str> = open.iter().map(|i| i.id.as_str()).collect();
+    let mut edges = Vec::new();
+    for issue in &open {
+        for dep in db.get_deps_from(&issue.id)? {
+            if dep.relation == Relation::Blocks && open_ids.contains(dep.to_id.as_str()) {
+                edges.push((dep.from_id, dep.to_id));
+            }
+        }
+    }
+    let in_graph: HashSet<&str> = edges
+        .iter()
+        .flat_map(|(from, to)| [from.as_str(), to.as_str()])
+        .collect();
+    let nodes: Vec<&Issue> = open
+        .iter()
+        .filter(|i| in_graph.contains(i.id.as_str()))
+        .collect();
+
+    let mut body = format!("<h1>{}</h1>\n", t!("serve.graph"));
+    if nodes.is_empty() {
+        let _ = writeln!(body, "<p>{}</p>", t!("serve.graph_empty"));
+    } else {
+        body.push_str(&graph_svg(&nodes, &edges));
+    }
+    Ok(layout(project, &t!("serve.graph"), &body))
+}
+
+/// Each issue's column: 0 for issues nothing open blocks, else one past
+/// the deepest of their blockers.
+pub(crate) fn graph_columns(ids: &[&str], edges: &[(String, String)]) -> HashMap<String, usize> {
+    let mut columns: HashMap<String, usize> = ids.iter().map(|id| (id.to_string(), 0)).collect();
+    // A longest path visits each issue once, so this settles within
+    // ids.len() rounds even if the edges were to form a cycle
+    for _ in 0..ids.len() {
+        let mut changed = false;
+        for (from, to) in edges {
+            let next = columns.get(from).copied().unwrap_or(0) + 1;
+            if columns.get(to).is_some_and(|&column| column < next) {
+                columns.insert(to.clone(), next);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    columns
+}
+
+fn graph_svg(nodes: &[&Issue], edges: &[(String, String)]) -> String {
+    let ids: Vec<&str> = nodes.iter().map(|i| i.id.as_str()).collect();
+    let columns = graph_columns(&ids, edges);
+
+    // Row within the column, in the nodes' (priority) order
+    let mut positions: HashMap<&str, (usize, usize)> = HashMap::new();
+    let mut rows: HashMap<usize, usize> = HashMap::new();
+    for node in nodes {
+        let column = columns.get(&node.id).copied().unwrap_or(0);
+        let row = rows.entry(column).or_insert(0);
+        let x = column * (NODE_WIDTH + COLUMN_GAP) + ROW_GAP;
+        let y = *row * (NODE_HEIGHT + ROW_GAP) + ROW_GAP;
+        positions.insert(node.id.as_str(), (x, y));
+        *row += 1;
+    }
+    let width = (rows.len()) * (NODE_WIDTH + COLUMN_GAP) + ROW_GAP;
+    let height = rows.values().max().copied().unwrap_or(0) * (NODE_HEIGHT + ROW_GAP) + ROW_GAP;
+
+    let mut svg = format!(
+        "<svg class=\"graph\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" \
+         xmlns=\"http://www.w3.org/2000/svg\">\n<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" \
+         refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto\">\
+         <path d=\"M0,0 L10,5 L0,10 z\"/></marker></defs>\n",
+        width, height
+    );
+    for (from, to) in edges {
+        let (Some(&(x1, y1)), Some(&(x2, y2))) =
+            (positions.get(from.as_str()), positions.get(to.as_str()))
+        else {
+            continue;
+        };
+        let _ = writeln!(
+            svg,
+            "<line class=\"edge\" x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" marker-end=\"url(#arrow)\"/>",
+            x1 + NODE_WIDTH,
+            y1 + NODE_HEIGHT / 2,
+            x2,
+            y2 + NODE_HEIGHT / 2
+        );
+    }
+    for node in nodes {
+        let Some(&(x, y)) = positions.get(node.id.as_str()) else {
+            continue;
+        };
+        let _ = writeln!(
+            svg,
+            "<a href=\"/issue/{id}\"><rect class=\"node status-{status}\" x=\"{x}\" y=\"{y}\" \
+             width=\"{NODE_WIDTH}\" height=\"{NODE_HEIGHT}\" rx=\"4\"/>\
+             <text x=\"{tx}\" y=\"{y1}\" class=\"node-id\">{id}</text>\
+             <text x=\"{tx}\" y=\"{y2}\">{title}</text></a>",
+            id = escape(&node.id),
+            status = node.status,
+            tx = x + 8,
+            y1 = y + 17,
+            y2 = y + 35,
+            title = escape(&truncate_to_width(&node.title, 28)),
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+#[path = "serve_pages_tests.rs"]
+mod tests;
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::config::AgingConfig;
+use crate::models::{Action, Event, IssueType, Link, LinkRel, Status};
+
+#[test]
+fn escape_covers_markup_and_quotes() {
+    assert_eq!(
+        escape(r#"<a href="x">Tom & 'Jerry'</a>"#),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn list_page_shows_open_issues_unless_all() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Open <one>")
+        .create_issue("test-2", IssueType::Bug, "Blocked")
+        .create_completed("test-3", IssueType::Task, "Finished")
+        .blocks("test-1", "test-2")
+        .add_label("test-1", "backend");
+
+    let page = list_page(&ctx.db, "demo", false).unwrap();
+    assert!(page.contains("Open &lt;one&gt;"));
+    assert!(page.contains("backend"));
+    assert!(page.contains("<span class=\"blocked\">blocked</span>"));
+    assert!(!page.contains("Finished"));
+
+    assert!(list_page(&ctx.db, "demo", true)
+        .unwrap()
+        .contains("Finished"));
+}
+
+#[test]
+fn board_page_has_a_column_per_status() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Waiting")
+        .create_and_start("test-2", IssueType::Task, "Going");
+
+    let page = board_page(&ctx.db, "demo", &AgingConfig::default()).unwrap();
+
+    for status in ["todo", "in_progress", "done", "closed"] {
+        assert!(page.contains(&format!("<h2>{} ", status)), "{status}");
+    }
+    assert!(page.find("Waiting").unwrap() < page.find("Going").unwrap());
+}
+
+#[test]
+fn board_cards_show_estimates() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Sized")
+        .add_label("test-1", "estimate:2d");
+
+    let page = board_page(&ctx.db, "demo", &AgingConfig::default()).unwrap();
+
+    assert!(page.contains("task · 2d"));
+}
+
+#[test]
+fn board_cards_mark_stale_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue_with_status("test-1", IssueType::Task, "Stuck", Status::InProgress)
+        .create_issue("test-2", IssueType::Task, "Fresh");
+    let started = Event::new("test-1".to_string(), Action::Started)
+        .with_timestamp(Utc::now() - chrono::Duration::days(9));
+    ctx.db.log_event(&started).unwrap();
+    let aging = AgingConfig {
+        in_progress: Some("7d".to_string()),
+        ..AgingConfig::default()
+    };
+
+    let page = board_page(&ctx.db, "demo", &aging).unwrap();
+
+    assert_eq!(page.matches("<span class=\"stale\">").count(), 1);
+    assert!(page.contains("<span class=\"stale\">stale 9d</span>"));
+}
+
+#[test]
+fn issue_page_lists_blockers_links_and_notes() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Blocker")
+        .create_issue("test-2", IssueType::Task, "Target")
+        .blocks("test-1", "test-2")
+        .add_note("test-2", "Looked into it");
+    let link = Link::new("test-2".to_string())
+        .with_url("https://github.com/o/r/issues/1".to_string())
+        .with_rel(LinkRel::Blocks);
+    ctx.db.add_link(&link).unwrap();
+
+    let page = issue_page(&ctx.db, "demo", "test-2").unwrap();
+
+    assert!(page.contains("<a href=\"/issue/test-1\">test-1</a>"));
+    assert!(page.contains("<a href=\"https://github.com/o/r/issues/1\">"));
+    assert!(page.contains("Looked into it"));
+    assert!(page.contains("<h2>Log</h2>"));
+}
+
+#[test]
+fn graph_columns_follow_the_longest_chain() {
+    let edges = vec![
+        ("a".to_string(), "b".to_string()),
+        ("b".to_string(), "c".to_string()),
+        ("a".to_string(), "c".to_string()),
+    ];
+
+    let columns = graph_columns(&["a", "b", "c", "d"], &edges);
+
+    assert_eq!(columns["a"], 0);
+    assert_eq!(columns["b"], 1);
+    assert_eq!(columns["c"], 2);
+    assert_eq!(columns["d"], 0);
+}
+
+#[test]
+fn graph_page_draws_only_blocking_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Blocker")
+        .create_issue("test-2", IssueType::Task, "Blocked")
+        .create_issue("test-3", IssueType::Task, "Alone")
+        .blocks("test-1", "test-2");
+
+    let page = graph_page(&ctx.db, "demo").unwrap();
+
+    assert_eq!(page.matches("<rect").count(), 2);
+    assert_eq!(page.matches("<line").count(), 1);
+    assert!(!page.contains("Alone"));
+
+    let empty = graph_page(&TestContext::new().db, "demo").unwrap();
+    assert!(empty.contains("No open issues block each other"));
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::config::AgingConfig;
+use crate::models::IssueType;
+
+fn get(ctx: &TestContext, target: &str) -> Response {
+    let request = Request::parse(&format!("GET {} HTTP/1.1", target)).unwrap();
+    route(&ctx.db, "demo", &AgingConfig::default(), &request)
+}
+
+#[test]
+fn parse_splits_path_and_query() {
+    let request = Request::parse("GET /?all&x=1 HTTP/1.1").unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/");
+    assert_eq!(request.query, ["all", "x"]);
+
+    let request = Request::parse("HEAD /issue/prj%2Da1 HTTP/1.0").unwrap();
+    assert_eq!(request.path, "/issue/prj-a1");
+}
+
+#[test]
+fn parse_rejects_malformed_lines() {
+    assert_eq!(Request::parse(""), None);
+    assert_eq!(Request::parse("GET /"), None);
+    assert_eq!(Request::parse("GET http://host/ HTTP/1.1"), None);
+    assert_eq!(Request::parse("GET / SPDY/3"), None);
+}
+
+#[test]
+fn percent_decode_leaves_malformed_escapes() {
+    assert_eq!(percent_decode("a%20b"), "a b");
+    assert_eq!(percent_decode("100%"), "100%");
+    assert_eq!(percent_decode("%zz"), "%zz");
+}
+
+#[test]
+fn routes_pages() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "First");
+
+    for target in ["/", "/?all", "/board", "/graph", "/issue/test-1"] {
+        assert_eq!(get(&ctx, target).status, 200, "{target}");
+    }
+    assert!(get(&ctx, "/issue/test-1").body.contains("First"));
+}
+
+#[test]
+fn unknown_pages_and_issues_are_not_found() {
+    let ctx = TestContext::new();
+
+    assert_eq!(get(&ctx, "/nope").status, 404);
+    assert_eq!(get(&ctx, "/issue/").status, 404);
+    let missing = get(&ctx, "/issue/test-404");
+    assert_eq!(missing.status, 404);
+    assert!(missing.body.contains("test-404"));
+}
+
+#[test]
+fn only_get_and_head_are_allowed() {
+    let ctx = TestContext::new();
+    let request = Request::parse("POST / HTTP/1.1").unwrap();
+
+    assert_eq!(
+        route(&ctx.db, "demo", &AgingConfig::default(), &request).status,
+        405
+    );
+}
+
+#[test]
+fn head_responses_have_no_body() {
+    let response = Response::ok("<p>hi</p>".to_string());
+
+    let head = String::from_utf8(response.to_bytes(true)).unwrap();
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(head.contains("Content-Length: 9\r\n"));
+    assert!(head.ends_with("\r\n\r\n"));
+    assert!(String::from_utf8(response.to_bytes(false))
+        .unwrap()
+        .ends_with("<p>hi</p>"));
+}
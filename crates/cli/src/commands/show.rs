@@ -1,37 +1,65 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 
+use crate::aging::{format_age, stale_issues};
+use crate::config::AgingConfig;
 use crate::db::Database;
-use crate::display::format_issue_details;
+use crate::display::{format_issue_details, is_external_blocker, Associations, ChainBlocker};
 use crate::error::{Error, Result};
-use crate::models::{Event, Issue, Link, Note};
+use crate::history::{self, Change, IssueState};
+use crate::markdown;
+use crate::models::{Event, Link, Note, Relation, Status};
+use crate::schema::show::{
+    IssueDetails, LinkDetails, NoteGroup, RelatedIssue, ShowOutputJson, SHOW_SCHEMA_VERSION,
+};
 
 use super::open_db;
 
 #[derive(Serialize)]
-struct IssueDetails {
-    #[serde(flatten)]
-    issue: Issue,
-    labels: Vec<String>,
-    blockers: Vec<String>,
-    blocking: Vec<String>,
-    parents: Vec<String>,
-    children: Vec<String>,
-    notes: Vec<Note>,
-    links: Vec<Link>,
-    events: Vec<Event>,
+struct IssueChanges {
+    id: String,
+    since: DateTime<Utc>,
+    changes: Vec<Change>,
 }
 
-pub fn run(ids: &[String], format: &str) -> Result<()> {
+pub fn run(
+    ids: &[String],
+    format: &str,
+    raw: bool,
+    as_of: Option<&str>,
+    diff: Option<&str>,
+) -> Result<()> {
     let ids = super::new::expand_ids(ids);
-    let (db, _, _) = open_db()?;
-    run_impl(&db, &ids, format)
+    let (db, config, _) = open_db()?;
+    let render_markdown = markdown::enabled(raw);
+    match (as_of, diff) {
+        (Some(at), _) => {
+            let at = history::parse_point(at, Utc::now())?;
+            as_of_impl(&db, &ids, format, at, render_markdown)
+        }
+        (None, Some(since)) => {
+            let since = history::parse_point(since, Utc::now())?;
+            diff_impl(&db, &ids, format, since)
+        }
+        (None, None) => run_impl(&db, &ids, format, &config.aging, render_markdown),
+    }
 }
 
 /// Internal implementation that accepts db for testing.
-pub(crate) fn run_impl(db: &Database, ids: &[String], format: &str) -> Result<()> {
+///
+/// `render_markdown` applies to text output only; JSON is always raw.
+pub(crate) fn run_impl(
+    db: &Database,
+    ids: &[String],
+    format: &str,
+    aging: &AgingConfig,
+    render_markdown: bool,
+) -> Result<()> {
     // Resolve all IDs first (fail fast if any is invalid)
     let resolved_ids: Vec<String> = ids
         .iter()
@@ -40,76 +68,420 @@ pub(crate) fn run_impl(db: &Database, ids: &[String], format: &str) -> Result<()
 
     match format {
         "json" => output_json(db, &resolved_ids),
-        "text" => output_text(db, &resolved_ids),
+        "text" => output_text(db, &resolved_ids, aging, render_markdown),
         _ => Err(Error::UnknownFormat {
             format: format.to_string(),
         }),
     }
 }
 
-fn build_issue_details(db: &Database, id: &str) -> Result<IssueDetails> {
-    let issue = db.get_issue(id)?;
-    let labels = db.get_labels(id)?;
-    let blockers = db.get_blockers(id)?;
-    let blocking = db.get_blocking(id)?;
-    let parents = db.get_tracking(id)?;
-    let children = db.get_tracked(id)?;
-    let notes = db.get_notes(id)?;
-    let links = db.get_links(id)?;
-    let events = db.get_events(id)?;
-
+/// The full `wok show` JSON document for an issue in `state`.
+pub(crate) fn build_issue_details(
+    db: &Database,
+    state: IssueState,
+    referenced_by: Vec<String>,
+    notes: Vec<(Status, Vec<Note>)>,
+    links: Vec<Link>,
+    events: Vec<Event>,
+) -> Result<IssueDetails> {
+    let related = |ids: Vec<String>| -> Result<Vec<RelatedIssue>> {
+        ids.into_iter().map(|id| related_issue(db, id)).collect()
+    };
+    let issue = state.issue;
+    let links = link_details(db, links)?;
     Ok(IssueDetails {
-        issue,
-        labels,
-        blockers,
-        blocking,
-        parents,
-        children,
-        notes,
+        schema_version: SHOW_SCHEMA_VERSION,
+        short_id: db.get_short_id(&issue.id)?,
+        blockers: related(state.blockers)?,
+        external_blockers: links
+            .iter()
+            .filter(|l| is_external_blocker(l))
+            .cloned()
+            .collect(),
+        blocking: related(state.blocking)?,
+        parents: related(state.parents)?,
+        children: related(state.children)?,
+        related: related(state.related)?,
+        duplicate_of: related(state.duplicate_of)?,
+        duplicates: related(state.duplicates)?,
+        referenced_by: related(referenced_by)?,
+        labels: state.labels,
+        notes: notes
+            .into_iter()
+            .map(|(status, notes)| NoteGroup { status, notes })
+            .collect(),
         links,
         events,
+        id: issue.id,
+        issue_type: issue.issue_type,
+        title: issue.title,
+        description: issue.description,
+        status: issue.status,
+        assignee: issue.assignee,
+        created_at: issue.created_at,
+        updated_at: issue.updated_at,
+        closed_at: issue.closed_at,
+        revision: issue.revision,
     })
 }
 
-fn output_json(db: &Database, ids: &[String]) -> Result<()> {
-    for id in ids {
-        let details = build_issue_details(db, id)?;
-        let json = serde_json::to_string(&details)?;
-        println!("{json}");
+/// `links` with their stored metadata, as `wok link refresh` last found it.
+pub(crate) fn link_details(db: &Database, links: Vec<Link>) -> Result<Vec<LinkDetails>> {
+    links
+        .into_iter()
+        .map(|link| {
+            let remote = match &link.url {
+                Some(url) => db.get_link_meta(url)?,
+                None => None,
+            };
+            Ok(LinkDetails { link, remote })
+        })
+        .collect()
+}
+
+/// The other end of a dependency, with its current title and status if it
+/// still exists.
+fn related_issue(db: &Database, id: String) -> Result<RelatedIssue> {
+    match db.get_issue(&id) {
+        Ok(issue) => Ok(RelatedIssue {
+            id,
+            title: Some(issue.title),
+            status: Some(issue.status),
+        }),
+        Err(wk_core::Error::IssueNotFound(_)) => Ok(RelatedIssue {
+            id,
+            title: None,
+            status: None,
+        }),
+        Err(e) => Err(e.into()),
     }
+}
+
+/// Print one document for a single issue, or an array for several.
+fn print_json(mut details: Vec<IssueDetails>) -> Result<()> {
+    let output = if details.len() == 1 {
+        ShowOutputJson::One(Box::new(details.remove(0)))
+    } else {
+        ShowOutputJson::Many(details)
+    };
+    println!("{}", serde_json::to_string(&output)?);
     Ok(())
 }
 
-fn output_text(db: &Database, ids: &[String]) -> Result<()> {
+fn output_json(db: &Database, ids: &[String]) -> Result<()> {
+    let details = ids
+        .iter()
+        .map(|id| {
+            let state = IssueState::load(db, id)?;
+            build_issue_details(
+                db,
+                state,
+                db.get_referenced_by(id)?,
+                db.get_notes_by_status(id)?,
+                db.get_links(id)?,
+                db.get_events(id)?,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    print_json(details)
+}
+
+fn output_text(
+    db: &Database,
+    ids: &[String],
+    aging: &AgingConfig,
+    render_markdown: bool,
+) -> Result<()> {
     for (i, id) in ids.iter().enumerate() {
         if i > 0 {
             println!("---");
         }
-        output_single_text(db, id)?;
+        output_single_text(db, id, aging, render_markdown)?;
     }
     Ok(())
 }
 
-fn output_single_text(db: &Database, id: &str) -> Result<()> {
+fn output_single_text(
+    db: &Database,
+    id: &str,
+    aging: &AgingConfig,
+    render_markdown: bool,
+) -> Result<()> {
     let issue = db.get_issue(id)?;
-    let labels = db.get_labels(id)?;
-    let blockers = db.get_blockers(id)?;
+    let palette = super::labels::palette(db)?;
+    let labels: Vec<String> = db
+        .get_labels(id)?
+        .iter()
+        .map(|l| palette.paint(l))
+        .collect();
+    let blockers = blocker_chain(db, id)?;
     let blocking = db.get_blocking(id)?;
     let parents = db.get_tracking(id)?;
     let children = db.get_tracked(id)?;
-    let notes = db.get_notes_by_status(id)?;
-    let links = db.get_links(id)?;
+    let related = db.get_related(id)?;
+    let duplicate_of = db.get_duplicate_of(id)?;
+    let duplicates = db.get_duplicates(id)?;
+    let referenced_by = db.get_referenced_by(id)?;
+    let mut notes = db.get_notes_by_status(id)?;
+    let links = link_details(db, db.get_links(id)?)?;
     let events = db.get_events(id)?;
+    let stale_for = stale_issues(db, aging, &[&issue], Utc::now())?
+        .remove(id)
+        .map(|stale| format_age(stale.age));
+
+    let _render = tracing::info_span!("render").entered();
+    if render_markdown {
+        render_note_markdown(&mut notes);
+    }
 
     print!(
         "{}",
         format_issue_details(
-            &issue, &labels, &blockers, &blocking, &parents, &children, &notes, &links, &events,
+            &issue,
+            &labels,
+            &blockers,
+            &blocking,
+            &parents,
+            &children,
+            Associations {
+                related: &related,
+                duplicate_of: &duplicate_of,
+                duplicates: &duplicates,
+                referenced_by: &referenced_by,
+            },
+            &notes,
+            &links,
+            &events,
+            stale_for.as_deref(),
         )
     );
     Ok(())
 }
 
+/// Direct and transitive blockers of `id`, each listed once under the
+/// issue it blocks along the shortest chain, direct blockers first.
+pub(crate) fn blocker_chain(db: &Database, id: &str) -> Result<Vec<ChainBlocker>> {
+    // Breadth-first, so each blocker is found at its shortest depth
+    let mut below: HashMap<String, Vec<String>> = HashMap::new();
+    let mut seen = HashSet::from([id.to_string()]);
+    let mut queue = VecDeque::from([id.to_string()]);
+    while let Some(current) = queue.pop_front() {
+        for blocker in db.get_blockers(&current)? {
+            if seen.insert(blocker.clone()) {
+                below
+                    .entry(current.clone())
+                    .or_default()
+                    .push(blocker.clone());
+                queue.push_back(blocker);
+            }
+        }
+    }
+
+    let blocked: HashSet<String> = db.get_blocked_issue_ids()?.into_iter().collect();
+    let mut chain = Vec::new();
+    let mut stack: Vec<(String, usize)> = below
+        .remove(id)
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .map(|b| (b, 1))
+        .collect();
+    while let Some((blocker, depth)) = stack.pop() {
+        let issue = db.get_issue(&blocker)?;
+        let actionable = issue.status.is_active() && !blocked.contains(&blocker);
+        for next in below.remove(&blocker).unwrap_or_default().into_iter().rev() {
+            stack.push((next, depth + 1));
+        }
+        chain.push(ChainBlocker {
+            id: blocker,
+            issue: Some((issue.title, issue.status)),
+            depth,
+            actionable,
+        });
+    }
+    Ok(chain)
+}
+
+/// Show issues as they were at `at`, replayed from the event log.
+pub(crate) fn as_of_impl(
+    db: &Database,
+    ids: &[String],
+    format: &str,
+    at: DateTime<Utc>,
+    render_markdown: bool,
+) -> Result<()> {
+    let resolved_ids = resolve_all(db, ids)?;
+    check_format(format)?;
+    let palette = super::labels::palette(db)?;
+
+    let mut details = Vec::new();
+    for (i, id) in resolved_ids.iter().enumerate() {
+        let events = db.get_events(id)?;
+        let state = IssueState::load(db, id)?.rewind(&events, at)?;
+        let mut notes = db.get_notes_by_status(id)?;
+        for (_, status_notes) in notes.iter_mut() {
+            status_notes.retain(|n| n.created_at <= at);
+        }
+        notes.retain(|(_, status_notes)| !status_notes.is_empty());
+        let mut links = db.get_links(id)?;
+        links.retain(|l| l.created_at <= at);
+        for link in &mut links {
+            if link.resolved_at.is_some_and(|resolved_at| resolved_at > at) {
+                link.resolved_at = None;
+            }
+        }
+        let events: Vec<Event> = events.into_iter().filter(|e| e.created_at <= at).collect();
+        let referenced_by = referenced_by_at(db, id, at)?;
+
+        if format == "json" {
+            details.push(build_issue_details(
+                db,
+                state,
+                referenced_by,
+                notes,
+                links,
+                events,
+            )?);
+            continue;
+        }
+
+        if i > 0 {
+            println!("---");
+        }
+        if render_markdown {
+            render_note_markdown(&mut notes);
+        }
+        let links = link_details(db, links)?;
+        // Blockers as they were; their chains and statuses are today's
+        let blockers: Vec<ChainBlocker> = state
+            .blockers
+            .iter()
+            .map(|b| ChainBlocker::direct(b))
+            .collect();
+        let labels: Vec<String> = state.labels.iter().map(|l| palette.paint(l)).collect();
+        print!(
+            "{}",
+            format_issue_details(
+                &state.issue,
+                &labels,
+                &blockers,
+                &state.blocking,
+                &state.parents,
+                &state.children,
+                Associations {
+                    related: &state.related,
+                    duplicate_of: &state.duplicate_of,
+                    duplicates: &state.duplicates,
+                    referenced_by: &referenced_by,
+                },
+                &notes,
+                &links,
+                &events,
+                None,
+            )
+        );
+    }
+    if format == "json" {
+        print_json(details)?;
+    }
+    Ok(())
+}
+
+/// Issues that had mentioned `id` by `at`.
+fn referenced_by_at(db: &Database, id: &str, at: DateTime<Utc>) -> Result<Vec<String>> {
+    Ok(db
+        .get_deps_to(id)?
+        .into_iter()
+        .filter(|dep| dep.relation == Relation::References && dep.created_at <= at)
+        .map(|dep| dep.from_id)
+        .collect())
+}
+
+/// Show what changed on each issue since `since`.
+pub(crate) fn diff_impl(
+    db: &Database,
+    ids: &[String],
+    format: &str,
+    since: DateTime<Utc>,
+) -> Result<()> {
+    let resolved_ids = resolve_all(db, ids)?;
+    check_format(format)?;
+
+    for (i, id) in resolved_ids.iter().enumerate() {
+        let current = IssueState::load(db, id)?;
+        let events = db.get_events(id)?;
+        // An issue created later is diffed against its state at creation
+        let from = since.max(current.issue.created_at);
+        let before = current.clone().rewind(&events, from)?;
+        let changes = history::diff(&before, &current);
+
+        if format == "json" {
+            let json = IssueChanges {
+                id: id.clone(),
+                since,
+                changes,
+            };
+            println!("{}", serde_json::to_string(&json)?);
+            continue;
+        }
+
+        if i > 0 {
+            println!("---");
+        }
+        print!("{}", format_changes(id, since, &changes));
+    }
+    Ok(())
+}
+
+/// Text listing of changes, one field per line.
+pub(crate) fn format_changes(id: &str, since: DateTime<Utc>, changes: &[Change]) -> String {
+    let since = history::format_point(since);
+    if changes.is_empty() {
+        return format!("No changes to {} since {}\n", id, since);
+    }
+
+    let mut output = format!("Changes to {} since {}:\n", id, since);
+    for change in changes {
+        let field = change.field.replace('_', " ");
+        let line = match change.field {
+            // Descriptions can be long; note the change without printing them
+            "description" => "updated".to_string(),
+            _ if change.added.is_empty() && change.removed.is_empty() => format!(
+                "{} -> {}",
+                change.from.as_deref().unwrap_or("(none)"),
+                change.to.as_deref().unwrap_or("(none)")
+            ),
+            _ => {
+                let added = change.added.iter().map(|v| format!("+{}", v));
+                let removed = change.removed.iter().map(|v| format!("-{}", v));
+                added.chain(removed).collect::<Vec<_>>().join(" ")
+            }
+        };
+        output.push_str(&format!("  {}: {}\n", field, line));
+    }
+    output
+}
+
+fn resolve_all(db: &Database, ids: &[String]) -> Result<Vec<String>> {
+    ids.iter().map(|id| Ok(db.resolve_id(id)?)).collect()
+}
+
+fn check_format(format: &str) -> Result<()> {
+    match format {
+        "json" | "text" => Ok(()),
+        _ => Err(Error::UnknownFormat {
+            format: format.to_string(),
+        }),
+    }
+}
+
+/// Replace note contents with their terminal-rendered markdown.
+pub(crate) fn render_note_markdown(notes: &mut [(Status, Vec<Note>)]) {
+    for note in notes.iter_mut().flat_map(|(_, notes)| notes.iter_mut()) {
+        note.content = markdown::render(&note.content);
+    }
+}
+
 #[cfg(test)]
 #[path = "show_tests.rs"]
 mod tests;
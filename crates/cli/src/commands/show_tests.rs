@@ -5,7 +5,8 @@
 #![allow(clippy::expect_used)]
 
 use crate::commands::testing::TestContext;
-use crate::models::{IssueType, Status};
+use crate::config::AgingConfig;
+use crate::models::{IssueType, Link, LinkMeta, LinkRel, Status};
 
 #[test]
 fn test_get_issue_details() {
@@ -175,51 +176,169 @@ fn test_notes_for_json_output() {
 }
 
 #[test]
-fn test_issue_details_serialization() {
-    use serde::Serialize;
+fn test_build_issue_details_resolves_related_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Blocker")
+        .create_issue("test-2", IssueType::Task, "Blocked")
+        .blocks("test-1", "test-2")
+        .start_issue("test-1");
+
+    let state = IssueState::load(&ctx.db, "test-2").unwrap();
+    let details = build_issue_details(&ctx.db, state, vec![], vec![], vec![], vec![]).unwrap();
+
+    assert_eq!(details.schema_version, SHOW_SCHEMA_VERSION);
+    assert_eq!(details.blockers.len(), 1);
+    assert_eq!(details.blockers[0].id, "test-1");
+    assert_eq!(details.blockers[0].title.as_deref(), Some("Blocker"));
+    assert_eq!(details.blockers[0].status, Some(Status::InProgress));
+    assert!(details.blocking.is_empty());
+}
 
-    #[derive(Serialize)]
-    struct IssueDetails {
-        #[serde(flatten)]
-        issue: crate::models::Issue,
-        labels: Vec<String>,
-        notes: Vec<crate::models::Note>,
-    }
+#[test]
+fn test_build_issue_details_includes_related_and_duplicates() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Original")
+        .create_issue("test-2", IssueType::Task, "Neighbor")
+        .create_issue("test-3", IssueType::Task, "Copy");
+    crate::commands::dep::add_impl(&ctx.db, "test-1", "relates-to", &["test-2".into()]).unwrap();
+    crate::commands::dep::add_impl(&ctx.db, "test-3", "duplicate-of", &["test-1".into()]).unwrap();
+
+    let state = IssueState::load(&ctx.db, "test-1").unwrap();
+    let details = build_issue_details(&ctx.db, state, vec![], vec![], vec![], vec![]).unwrap();
+
+    assert_eq!(details.related[0].id, "test-2");
+    assert_eq!(details.related[0].title.as_deref(), Some("Neighbor"));
+    assert_eq!(details.duplicates[0].id, "test-3");
+    assert!(details.duplicate_of.is_empty());
+    assert!(details.blockers.is_empty());
+}
 
+#[test]
+fn test_output_json_includes_referenced_by() {
     let mut ctx = TestContext::new();
-    ctx.create_issue("test-1", IssueType::Task, "Test issue")
-        .add_label("test-1", "backend")
-        .add_note("test-1", "A note");
+    ctx.create_issue("test-1", IssueType::Task, "Target")
+        .create_issue("test-2", IssueType::Task, "Mentioner")
+        .add_note("test-2", "Same root cause as test-1");
+    ctx.db
+        .record_mentions("test-2", "Same root cause as test-1")
+        .unwrap();
 
-    let issue = ctx.db.get_issue("test-1").unwrap();
-    let labels = ctx.db.get_labels("test-1").unwrap();
-    let notes = ctx.db.get_notes("test-1").unwrap();
+    let state = IssueState::load(&ctx.db, "test-1").unwrap();
+    let referenced_by = ctx.db.get_referenced_by("test-1").unwrap();
+    let details =
+        build_issue_details(&ctx.db, state, referenced_by, vec![], vec![], vec![]).unwrap();
+
+    assert_eq!(details.referenced_by.len(), 1);
+    assert_eq!(details.referenced_by[0].id, "test-2");
+    assert_eq!(details.referenced_by[0].title.as_deref(), Some("Mentioner"));
+    // Mentions are not dependencies
+    assert!(details.blockers.is_empty());
+}
 
-    let details = IssueDetails {
-        issue,
-        labels,
-        notes,
+#[test]
+fn test_build_issue_details_includes_link_remote() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let url = "https://github.com/o/r/issues/12";
+    let link = Link::new("test-1".to_string()).with_url(url.to_string());
+    ctx.db.add_link(&link).unwrap();
+    ctx.db
+        .add_link(&Link::new("test-1".to_string()).with_url("https://example.com".to_string()))
+        .unwrap();
+    let meta = LinkMeta {
+        title: Some("Crash".to_string()),
+        state: Some("open".to_string()),
+        error: None,
+        refreshed_at: chrono::Utc::now(),
     };
+    ctx.db.set_link_meta(url, &meta).unwrap();
+
+    let state = IssueState::load(&ctx.db, "test-1").unwrap();
+    let links = ctx.db.get_links("test-1").unwrap();
+    let details = build_issue_details(&ctx.db, state, vec![], vec![], links, vec![]).unwrap();
 
-    // Should serialize to valid JSON
-    let json = serde_json::to_string_pretty(&details).unwrap();
-    assert!(json.contains("\"id\": \"test-1\""));
-    assert!(json.contains("\"title\": \"Test issue\""));
-    assert!(json.contains("\"issue_type\": \"task\""));
-    assert!(json.contains("\"backend\""));
-    assert!(json.contains("A note"));
+    let json = serde_json::to_value(&details).unwrap();
+    assert_eq!(json["links"][0]["url"], url);
+    assert_eq!(json["links"][0]["remote"]["title"], "Crash");
+    assert_eq!(json["links"][0]["remote"]["state"], "open");
+    assert!(json["links"][1].get("remote").is_none());
+}
+
+#[test]
+fn test_build_issue_details_lists_unresolved_external_blockers() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let blocks = |url: &str| {
+        Link::new("test-1".to_string())
+            .with_url(url.to_string())
+            .with_rel(LinkRel::Blocks)
+    };
+    ctx.db.add_link(&blocks("jira://PE-1")).unwrap();
+    let done = ctx.db.add_link(&blocks("jira://PE-2")).unwrap();
+    ctx.db
+        .set_link_resolved(done, Some(chrono::Utc::now()))
+        .unwrap();
+
+    let state = IssueState::load(&ctx.db, "test-1").unwrap();
+    let links = ctx.db.get_links("test-1").unwrap();
+    let details = build_issue_details(&ctx.db, state, vec![], vec![], links, vec![]).unwrap();
+
+    let json = serde_json::to_value(&details).unwrap();
+    assert_eq!(json["external_blockers"].as_array().unwrap().len(), 1);
+    assert_eq!(json["external_blockers"][0]["url"], "jira://PE-1");
+    assert!(json["links"][1]["resolved_at"].is_string());
+}
+
+#[test]
+fn test_build_issue_details_groups_notes_by_status() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_label("test-1", "backend")
+        .add_note("test-1", "Planned")
+        .start_issue("test-1")
+        .add_note("test-1", "Working");
+
+    let state = IssueState::load(&ctx.db, "test-1").unwrap();
+    let notes = ctx.db.get_notes_by_status("test-1").unwrap();
+    let details = build_issue_details(&ctx.db, state, vec![], notes, vec![], vec![]).unwrap();
+
+    let json = serde_json::to_value(&details).unwrap();
+    assert_eq!(json["labels"][0], "backend");
+    assert_eq!(json["notes"].as_array().unwrap().len(), 2);
+    assert!(json["notes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|g| g["status"] == "in_progress" && g["notes"][0]["content"] == "Working"));
+    // Absent optional fields are still present, as null
+    assert!(json["description"].is_null());
+    assert!(json.as_object().unwrap().contains_key("closed_at"));
+    // HLC bookkeeping stays out of the document
+    assert!(!json.as_object().unwrap().keys().any(|k| k.contains("hlc")));
 }
 
 // Tests for run_impl
 
-use crate::commands::show::run_impl;
+use crate::commands::show::{
+    as_of_impl, blocker_chain, build_issue_details, diff_impl, format_changes, run_impl,
+};
+use crate::error::Error;
+use crate::history::Change;
+use crate::history::IssueState;
+use crate::schema::show::SHOW_SCHEMA_VERSION;
 
 #[test]
 fn test_run_impl_text_format() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue");
 
-    let result = run_impl(&ctx.db, &["test-1".to_string()], "text");
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        "text",
+        &AgingConfig::default(),
+        false,
+    );
     assert!(result.is_ok());
 }
 
@@ -228,17 +347,57 @@ fn test_run_impl_json_format() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue");
 
-    let result = run_impl(&ctx.db, &["test-1".to_string()], "json");
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        "json",
+        &AgingConfig::default(),
+        false,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_run_impl_text_with_markdown() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_note("test-1", "# Plan\n- use `wok`");
+
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        "text",
+        &AgingConfig::default(),
+        true,
+    );
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_render_note_markdown_rewrites_content() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue")
+        .add_note("test-1", "- first\n- second");
+
+    let mut notes = ctx.db.get_notes_by_status("test-1").unwrap();
+    crate::commands::show::render_note_markdown(&mut notes);
+
+    assert_eq!(notes[0].1[0].content, "• first\n• second");
+}
+
 #[test]
 fn test_run_impl_with_labels() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue")
         .add_label("test-1", "urgent");
 
-    let result = run_impl(&ctx.db, &["test-1".to_string()], "text");
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        "text",
+        &AgingConfig::default(),
+        false,
+    );
     assert!(result.is_ok());
 }
 
@@ -246,7 +405,13 @@ fn test_run_impl_with_labels() {
 fn test_run_impl_nonexistent_issue() {
     let ctx = TestContext::new();
 
-    let result = run_impl(&ctx.db, &["nonexistent".to_string()], "text");
+    let result = run_impl(
+        &ctx.db,
+        &["nonexistent".to_string()],
+        "text",
+        &AgingConfig::default(),
+        false,
+    );
     assert!(result.is_err());
 }
 
@@ -255,7 +420,13 @@ fn test_run_impl_invalid_format() {
     let mut ctx = TestContext::new();
     ctx.create_issue("test-1", IssueType::Task, "Test issue");
 
-    let result = run_impl(&ctx.db, &["test-1".to_string()], "invalid");
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        "invalid",
+        &AgingConfig::default(),
+        false,
+    );
     assert!(result.is_err());
 }
 
@@ -269,6 +440,8 @@ fn test_run_impl_multiple_issues_text() {
         &ctx.db,
         &["test-1".to_string(), "test-2".to_string()],
         "text",
+        &AgingConfig::default(),
+        false,
     );
     assert!(result.is_ok());
 }
@@ -283,6 +456,8 @@ fn test_run_impl_multiple_issues_json() {
         &ctx.db,
         &["test-1".to_string(), "test-2".to_string()],
         "json",
+        &AgingConfig::default(),
+        false,
     );
     assert!(result.is_ok());
 }
@@ -297,6 +472,118 @@ fn test_run_impl_fails_if_any_id_invalid() {
         &ctx.db,
         &["test-1".to_string(), "nonexistent".to_string()],
         "text",
+        &AgingConfig::default(),
+        false,
     );
     assert!(result.is_err());
 }
+
+#[test]
+fn test_as_of_impl_text_and_json() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let now = chrono::Utc::now();
+
+    assert!(as_of_impl(&ctx.db, &["test-1".to_string()], "text", now, false).is_ok());
+    assert!(as_of_impl(&ctx.db, &["test-1".to_string()], "json", now, false).is_ok());
+}
+
+#[test]
+fn test_as_of_impl_before_creation_fails() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let before = chrono::Utc::now() - chrono::Duration::days(1);
+
+    let result = as_of_impl(&ctx.db, &["test-1".to_string()], "text", before, false);
+    assert!(matches!(result, Err(Error::NotYetCreated { .. })));
+}
+
+#[test]
+fn test_diff_impl_before_creation_succeeds() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+    let before = chrono::Utc::now() - chrono::Duration::days(1);
+
+    assert!(diff_impl(&ctx.db, &["test-1".to_string()], "json", before).is_ok());
+}
+
+#[test]
+fn test_diff_impl_unknown_format() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Test issue");
+
+    let result = diff_impl(&ctx.db, &["test-1".to_string()], "xml", chrono::Utc::now());
+    assert!(matches!(result, Err(Error::UnknownFormat { .. })));
+}
+
+#[test]
+fn test_format_changes() {
+    let since = chrono::DateTime::parse_from_rfc3339("2026-01-15T09:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let changes = vec![
+        Change {
+            field: "status",
+            from: Some("todo".to_string()),
+            to: Some("done".to_string()),
+            added: vec![],
+            removed: vec![],
+        },
+        Change {
+            field: "assignee",
+            from: None,
+            to: Some("alice".to_string()),
+            added: vec![],
+            removed: vec![],
+        },
+        Change {
+            field: "blocked_by",
+            from: None,
+            to: None,
+            added: vec!["test-2".to_string()],
+            removed: vec!["test-3".to_string()],
+        },
+    ];
+
+    assert_eq!(
+        format_changes("test-1", since, &changes),
+        "Changes to test-1 since 2026-01-15 09:00:\n  status: todo -> done\n  assignee: (none) -> alice\n  blocked by: +test-2 -test-3\n"
+    );
+    assert_eq!(
+        format_changes("test-1", since, &[]),
+        "No changes to test-1 since 2026-01-15 09:00\n"
+    );
+}
+
+#[test]
+fn test_blocker_chain_orders_by_depth_and_marks_actionable() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("target", IssueType::Task, "Target")
+        .create_issue("direct", IssueType::Task, "Direct")
+        .create_issue("deep", IssueType::Task, "Deep")
+        .create_issue("other", IssueType::Task, "Other direct")
+        .create_issue("done", IssueType::Task, "Done")
+        .blocks("direct", "target")
+        .blocks("other", "target")
+        .blocks("deep", "direct")
+        // Reachable two ways; listed once, at its shortest depth
+        .blocks("deep", "target")
+        .blocks("done", "other")
+        .start_issue("done")
+        .complete_issue("done");
+
+    let chain = blocker_chain(&ctx.db, "target").unwrap();
+    let summary: Vec<(&str, usize, bool)> = chain
+        .iter()
+        .map(|b| (b.id.as_str(), b.depth, b.actionable))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("direct", 1, false),
+            ("other", 1, true),
+            ("done", 2, false),
+            ("deep", 1, true),
+        ]
+    );
+}
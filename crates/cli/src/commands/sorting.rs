@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explicit sort order for issue lists (`--sort`).
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use crate::cli::{SortArgs, SortKey};
+use crate::db::Database;
+use crate::error::Result;
+use crate::models::Issue;
+
+/// Label prefix carrying an issue's due date: `due:2026-03-01`.
+const DUE_PREFIX: &str = "due:";
+
+/// Stable-sort issues by `--sort`, if given.
+///
+/// Ties keep the command's own order, so `--sort due` on list still shows
+/// higher priority first among issues due the same day. `--reverse` flips
+/// the key's direction; issues without a due date stay last either way.
+pub(crate) fn sort_issues(db: &Database, issues: &mut [Issue], sort: &SortArgs) -> Result<()> {
+    let Some(key) = sort.sort else {
+        return Ok(());
+    };
+    let labels: HashMap<String, Vec<String>> = match key {
+        SortKey::Priority | SortKey::Due => {
+            let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+            db.get_labels_batch(&ids)?
+        }
+        SortKey::Created | SortKey::Updated | SortKey::Title => HashMap::new(),
+    };
+    let empty = Vec::new();
+    let labels_of = |issue: &Issue| labels.get(&issue.id).unwrap_or(&empty);
+    let directed = |ordering: Ordering| {
+        if sort.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+
+    match key {
+        SortKey::Created => issues.sort_by(|a, b| directed(b.created_at.cmp(&a.created_at))),
+        SortKey::Updated => issues.sort_by(|a, b| directed(b.updated_at.cmp(&a.updated_at))),
+        SortKey::Title => {
+            issues.sort_by(|a, b| directed(a.title.to_lowercase().cmp(&b.title.to_lowercase())))
+        }
+        SortKey::Priority => issues.sort_by(|a, b| {
+            let priority_a = crate::db::priority_from_tags(labels_of(a));
+            let priority_b = crate::db::priority_from_tags(labels_of(b));
+            directed(priority_a.cmp(&priority_b))
+        }),
+        SortKey::Due => issues.sort_by(|a, b| {
+            match (due_from_tags(labels_of(a)), due_from_tags(labels_of(b))) {
+                (Some(due_a), Some(due_b)) => directed(due_a.cmp(&due_b)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }),
+    }
+    Ok(())
+}
+
+/// Earliest valid `due:YYYY-MM-DD` date among the labels.
+pub(crate) fn due_from_tags(tags: &[String]) -> Option<NaiveDate> {
+    tags.iter()
+        .filter_map(|tag| tag.strip_prefix(DUE_PREFIX))
+        .filter_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .min()
+}
+
+#[cfg(test)]
+#[path = "sorting_tests.rs"]
+mod tests;
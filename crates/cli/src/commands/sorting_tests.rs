@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use chrono::{Duration, TimeZone, Utc};
+use yare::parameterized;
+
+/// test-1 "beta" (oldest, updated last), test-2 "Alpha", test-3 "gamma" (newest).
+fn issues() -> Vec<Issue> {
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    [
+        ("test-1", "beta", 0, 10),
+        ("test-2", "Alpha", 1, 2),
+        ("test-3", "gamma", 2, 3),
+    ]
+    .into_iter()
+    .map(|(id, title, created, updated)| {
+        let mut issue = Issue::new(
+            id.to_string(),
+            IssueType::Task,
+            title.to_string(),
+            base + Duration::days(created),
+        );
+        issue.updated_at = base + Duration::days(updated);
+        issue
+    })
+    .collect()
+}
+
+fn sorted(ctx: &TestContext, key: SortKey, reverse: bool) -> Vec<String> {
+    let mut issues = issues();
+    let sort = SortArgs {
+        sort: Some(key),
+        reverse,
+    };
+    sort_issues(&ctx.db, &mut issues, &sort).unwrap();
+    issues.into_iter().map(|i| i.id).collect()
+}
+
+#[parameterized(
+    created = { SortKey::Created, false, &["test-3", "test-2", "test-1"] },
+    created_reversed = { SortKey::Created, true, &["test-1", "test-2", "test-3"] },
+    updated = { SortKey::Updated, false, &["test-1", "test-3", "test-2"] },
+    title_ignores_case = { SortKey::Title, false, &["test-2", "test-1", "test-3"] },
+    title_reversed = { SortKey::Title, true, &["test-3", "test-1", "test-2"] },
+)]
+fn sorts_by_issue_fields(key: SortKey, reverse: bool, expected: &[&str]) {
+    let ctx = TestContext::new();
+    assert_eq!(sorted(&ctx, key, reverse), expected);
+}
+
+#[parameterized(
+    priority = { SortKey::Priority, false, &["test-3", "test-1", "test-2"] },
+    priority_reversed = { SortKey::Priority, true, &["test-2", "test-1", "test-3"] },
+    due = { SortKey::Due, false, &["test-2", "test-3", "test-1"] },
+    due_reversed_keeps_undated_last = { SortKey::Due, true, &["test-3", "test-2", "test-1"] },
+)]
+fn sorts_by_labels(key: SortKey, reverse: bool, expected: &[&str]) {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "beta")
+        .create_issue("test-2", IssueType::Task, "Alpha")
+        .create_issue("test-3", IssueType::Task, "gamma")
+        .add_label("test-2", "priority:3")
+        .add_label("test-3", "priority:0")
+        .add_label("test-2", "due:2026-02-01")
+        .add_label("test-3", "due:2026-03-01");
+
+    assert_eq!(sorted(&ctx, key, reverse), expected);
+}
+
+#[test]
+fn no_sort_key_keeps_order() {
+    let ctx = TestContext::new();
+    let mut issues = issues();
+    issues.reverse();
+
+    sort_issues(&ctx.db, &mut issues, &SortArgs::default()).unwrap();
+
+    let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["test-3", "test-2", "test-1"]);
+}
+
+#[parameterized(
+    none = { &[], None },
+    one = { &["due:2026-05-01"], Some((2026, 5, 1)) },
+    earliest_wins = { &["due:2026-05-01", "due:2026-04-30"], Some((2026, 4, 30)) },
+    invalid_ignored = { &["due:soon", "due:2026-13-01"], None },
+    other_labels_ignored = { &["priority:1", "overdue:2026-01-01"], None },
+)]
+fn due_from_tags_parses_dates(tags: &[&str], expected: Option<(i32, u32, u32)>) {
+    let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+    let expected = expected.map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    assert_eq!(due_from_tags(&tags), expected);
+}
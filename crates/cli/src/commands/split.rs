@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok split` - break an issue into parts tracked by it.
+//!
+//! Each part is a new issue with the parent's prefix. A parent that is not
+//! already an epic or feature becomes an epic, and a note on it lists the
+//! parts.
+
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::models::{Action, Event, IssueType, Relation, Status};
+use crate::validate::{validate_and_normalize_title, validate_and_trim_note};
+
+use super::new::create_issue_logged;
+use super::{apply_mutation, open_db};
+
+/// What to carry over from the parent to each part.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct CopyFields {
+    pub labels: bool,
+    pub assignee: bool,
+}
+
+/// A part created by a split.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Part {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub issue_type: IssueType,
+    pub title: String,
+}
+
+/// The result of a split.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Split {
+    pub parent: String,
+    /// The parent's type before it became an epic, if it was converted.
+    pub converted_from: Option<IssueType>,
+    pub parts: Vec<Part>,
+}
+
+pub fn run(
+    id: &str,
+    titles: &[String],
+    issue_type: &str,
+    copy: CopyFields,
+    output: OutputFormat,
+) -> Result<()> {
+    let (db, _config, _work_dir) = open_db()?;
+    let id = db.resolve_id(id)?;
+    let issue_type: IssueType = issue_type.parse()?;
+    let split = split_impl(&db, &id, titles, issue_type, copy)?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&split)?),
+        OutputFormat::Id => {
+            for part in &split.parts {
+                println!("{}", part.id);
+            }
+        }
+        OutputFormat::Text => {
+            if let Some(from) = split.converted_from {
+                println!("Converted {} from {} to epic", split.parent, from);
+            }
+            println!("Split {} into:", split.parent);
+            for part in &split.parts {
+                println!("  [{}] {}: {}", part.issue_type, part.id, part.title);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Split issue `id` (resolved) into parts titled `titles`.
+pub(crate) fn split_impl(
+    db: &Database,
+    id: &str,
+    titles: &[String],
+    issue_type: IssueType,
+    copy: CopyFields,
+) -> Result<Split> {
+    let parent = db.get_issue(id)?;
+    if parent.status.is_terminal() {
+        return Err(Error::CannotSplit {
+            id: id.to_string(),
+            reason: format!("it is {}", parent.status),
+        });
+    }
+    // Validate every title before creating anything.
+    let titles = titles
+        .iter()
+        .map(|title| validate_and_normalize_title(title))
+        .collect::<Result<Vec<_>>>()?;
+
+    let prefix = id.split_once('-').map_or(id, |(prefix, _)| prefix);
+    let labels = if copy.labels {
+        db.get_labels(id)?
+    } else {
+        Vec::new()
+    };
+    let assignee = parent.assignee.clone().filter(|_| copy.assignee);
+
+    let mut parts = Vec::new();
+    for title in titles {
+        let (part_id, _) =
+            create_issue_logged(db, prefix, issue_type, &title.title, assignee.clone())?;
+
+        for label in &labels {
+            apply_mutation(
+                db,
+                Event::new(part_id.clone(), Action::Labeled).with_values(None, Some(label.clone())),
+                || db.add_label(&part_id, label),
+            )?;
+        }
+        if let Some(description) = title.extracted_description {
+            let note = validate_and_trim_note(&description)?;
+            apply_mutation(
+                db,
+                Event::new(part_id.clone(), Action::Noted).with_values(None, Some(note.clone())),
+                || db.add_note(&part_id, Status::Todo, &note),
+            )?;
+        }
+
+        apply_mutation(
+            db,
+            Event::new(id.to_string(), Action::Related)
+                .with_values(None, Some(format!("tracks {}", part_id))),
+            || {
+                db.add_dependency(id, &part_id, Relation::Tracks)?;
+                db.add_dependency(&part_id, id, Relation::TrackedBy)
+            },
+        )?;
+
+        parts.push(Part {
+            id: part_id,
+            issue_type,
+            title: title.title,
+        });
+    }
+
+    let converted_from = match parent.issue_type {
+        IssueType::Epic | IssueType::Feature => None,
+        other => {
+            apply_mutation(
+                db,
+                Event::new(id.to_string(), Action::Edited).with_values(
+                    Some(other.as_str().to_string()),
+                    Some(IssueType::Epic.as_str().to_string()),
+                ),
+                || db.update_issue_type(id, IssueType::Epic),
+            )?;
+            Some(other)
+        }
+    };
+
+    let lines: Vec<String> = parts
+        .iter()
+        .map(|part| format!("- {}: {}", part.id, part.title))
+        .collect();
+    let note = format!("Split into:\n{}", lines.join("\n"));
+    apply_mutation(
+        db,
+        Event::new(id.to_string(), Action::Noted).with_values(None, Some(note.clone())),
+        || db.add_note(id, parent.status, &note),
+    )?;
+
+    Ok(Split {
+        parent: id.to_string(),
+        converted_from,
+        parts,
+    })
+}
+
+#[cfg(test)]
+#[path = "split_tests.rs"]
+mod tests;
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use yare::parameterized;
+
+fn titles(titles: &[&str]) -> Vec<String> {
+    titles.iter().map(|t| t.to_string()).collect()
+}
+
+fn split(ctx: &TestContext, parts: &[&str], copy: CopyFields) -> Result<Split> {
+    split_impl(&ctx.db, "test-1", &titles(parts), IssueType::Task, copy)
+}
+
+#[test]
+fn parts_are_tracked_by_the_parent() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Big task");
+
+    let split = split(&ctx, &["Part one", "Part two"], CopyFields::default()).unwrap();
+
+    assert_eq!(split.parts.len(), 2);
+    let mut ids: Vec<String> = split.parts.iter().map(|p| p.id.clone()).collect();
+    for id in &ids {
+        assert!(id.starts_with("test-"));
+        assert_eq!(ctx.db.get_tracking(id).unwrap(), vec!["test-1"]);
+    }
+    let part = ctx.db.get_issue(&ids[0]).unwrap();
+    assert_eq!(part.title, "Part one");
+    assert_eq!(part.issue_type, IssueType::Task);
+
+    let mut tracked = ctx.db.get_tracked("test-1").unwrap();
+    tracked.sort();
+    ids.sort();
+    assert_eq!(tracked, ids);
+}
+
+#[parameterized(
+    task = { IssueType::Task, Some(IssueType::Task) },
+    bug = { IssueType::Bug, Some(IssueType::Bug) },
+    feature = { IssueType::Feature, None },
+    epic = { IssueType::Epic, None },
+)]
+fn parent_becomes_an_epic(parent_type: IssueType, converted_from: Option<IssueType>) {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", parent_type, "Big one");
+
+    let split = split(&ctx, &["Part"], CopyFields::default()).unwrap();
+
+    assert_eq!(split.converted_from, converted_from);
+    let expected = if converted_from.is_some() {
+        IssueType::Epic
+    } else {
+        parent_type
+    };
+    assert_eq!(ctx.db.get_issue("test-1").unwrap().issue_type, expected);
+}
+
+#[test]
+fn notes_the_split_on_the_parent() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Big task");
+
+    let split = split(&ctx, &["Part one", "Part two"], CopyFields::default()).unwrap();
+
+    let notes = ctx.db.get_notes("test-1").unwrap();
+    assert_eq!(
+        notes[0].content,
+        format!(
+            "Split into:\n- {}: Part one\n- {}: Part two",
+            split.parts[0].id, split.parts[1].id
+        )
+    );
+}
+
+#[parameterized(
+    nothing = { false, false },
+    labels = { true, false },
+    assignee = { false, true },
+    both = { true, true },
+)]
+fn copies_what_is_asked(labels: bool, assignee: bool) {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Big task")
+        .add_label("test-1", "auth");
+    ctx.db.set_assignee("test-1", "alice").unwrap();
+
+    let split = split(&ctx, &["Part"], CopyFields { labels, assignee }).unwrap();
+
+    let part = &split.parts[0].id;
+    assert_eq!(ctx.db.get_labels(part).unwrap().is_empty(), !labels);
+    assert_eq!(ctx.db.get_issue(part).unwrap().assignee.is_some(), assignee);
+}
+
+#[test]
+fn parts_take_the_given_type() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Feature, "Big feature");
+
+    let split = split_impl(
+        &ctx.db,
+        "test-1",
+        &titles(&["Crash"]),
+        IssueType::Bug,
+        CopyFields::default(),
+    )
+    .unwrap();
+
+    let part = ctx.db.get_issue(&split.parts[0].id).unwrap();
+    assert_eq!(part.issue_type, IssueType::Bug);
+}
+
+#[test]
+fn closed_issues_cannot_be_split() {
+    let mut ctx = TestContext::new();
+    ctx.create_completed("test-1", IssueType::Task, "Done");
+
+    let err = split(&ctx, &["Part"], CopyFields::default()).unwrap_err();
+    assert!(matches!(err, Error::CannotSplit { .. }));
+}
+
+#[test]
+fn an_invalid_title_creates_nothing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Big task");
+
+    assert!(split(&ctx, &["Part one", "   "], CopyFields::default()).is_err());
+    assert!(ctx.db.get_tracked("test-1").unwrap().is_empty());
+    assert_eq!(ctx.db.get_all_issues().unwrap().len(), 1);
+}
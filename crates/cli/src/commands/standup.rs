@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok standup` - yesterday, today, and blockers, from the event log.
+
+use chrono::{DateTime, Utc};
+
+use crate::cli::StandupFormat;
+use crate::db::Database;
+use crate::error::Result;
+use crate::history::parse_point;
+use crate::models::{Action, Issue, LinkRel, Status};
+use crate::schema::standup::{BlockerJson, StandupIssueJson, StandupJson, StandupOutputJson};
+
+use super::assign::{resolve_assignees, ME};
+use super::open_db;
+
+/// How far back "yesterday" goes when `--since` is not given.
+const DEFAULT_SINCE: &str = "1d";
+
+/// Events that count as progress for "yesterday".
+const PROGRESS: &[Action] = &[
+    Action::Started,
+    Action::Stopped,
+    Action::Done,
+    Action::Closed,
+    Action::Reopened,
+    Action::Noted,
+];
+
+pub fn run(
+    assignee: Vec<String>,
+    team: bool,
+    since: Option<String>,
+    output: StandupFormat,
+) -> Result<()> {
+    let since = parse_point(since.as_deref().unwrap_or(DEFAULT_SINCE), Utc::now())?;
+    let (db, config, work_dir) = open_db()?;
+
+    let standups = if team {
+        let (who, keep_empty) = if config.team.is_empty() {
+            (db.get_distinct_assignees()?, false)
+        } else {
+            (config.team.clone(), true)
+        };
+        let mut standups = Vec::new();
+        for name in who {
+            let standup = build(&db, &name, since)?;
+            if keep_empty || !is_empty(&standup) {
+                standups.push(standup);
+            }
+        }
+        standups
+    } else {
+        let assignee = if assignee.is_empty() {
+            vec![ME.to_string()]
+        } else {
+            assignee
+        };
+        resolve_assignees(assignee, &config, &work_dir)?
+            .iter()
+            .map(|name| build(&db, name, since))
+            .collect::<Result<_>>()?
+    };
+
+    match output {
+        StandupFormat::Text => print!("{}", format_standups(&standups)),
+        StandupFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&StandupOutputJson(standups))?
+        ),
+    }
+    Ok(())
+}
+
+/// `assignee`'s standup: issues currently assigned to them that progressed
+/// since `since`, those in progress, and what blocks the latter.
+pub(crate) fn build(db: &Database, assignee: &str, since: DateTime<Utc>) -> Result<StandupJson> {
+    let mut yesterday: Vec<StandupIssueJson> = Vec::new();
+    for event in db.get_events_since(since)? {
+        if !PROGRESS.contains(&event.action) {
+            continue;
+        }
+        if let Some(item) = yesterday.iter_mut().find(|item| item.id == event.issue_id) {
+            if !item.actions.contains(&event.action) {
+                item.actions.push(event.action);
+            }
+            continue;
+        }
+        let Ok(issue) = db.get_issue(&event.issue_id) else {
+            continue;
+        };
+        if issue.assignee.as_deref() == Some(assignee) {
+            yesterday.push(StandupIssueJson {
+                actions: vec![event.action],
+                ..entry(&issue)
+            });
+        }
+    }
+
+    let mut today = Vec::new();
+    let mut blockers = Vec::new();
+    let mut in_progress: Vec<Issue> = db
+        .get_all_issues()?
+        .into_iter()
+        .filter(|issue| {
+            issue.status == Status::InProgress && issue.assignee.as_deref() == Some(assignee)
+        })
+        .collect();
+    in_progress.reverse();
+    for issue in &in_progress {
+        today.push(entry(issue));
+        let blocked_by = blocked_by(db, &issue.id)?;
+        if !blocked_by.is_empty() {
+            blockers.push(StandupIssueJson {
+                blocked_by,
+                ..entry(issue)
+            });
+        }
+    }
+
+    Ok(StandupJson {
+        assignee: assignee.to_string(),
+        yesterday,
+        today,
+        blockers,
+    })
+}
+
+/// Open issues blocking `issue_id`, transitively, then its unresolved
+/// external blockers.
+fn blocked_by(db: &Database, issue_id: &str) -> Result<Vec<BlockerJson>> {
+    let mut blockers = Vec::new();
+    for id in db.get_transitive_blockers(issue_id)? {
+        let blocker = db.get_issue(&id)?;
+        blockers.push(BlockerJson {
+            id,
+            title: Some(blocker.title),
+            assignee: blocker.assignee,
+        });
+    }
+    for link in db.get_links(issue_id)? {
+        if link.rel == Some(LinkRel::Blocks) && link.resolved_at.is_none() {
+            if let Some(url) = link.url {
+                blockers.push(BlockerJson {
+                    id: url,
+                    title: None,
+                    assignee: None,
+                });
+            }
+        }
+    }
+    Ok(blockers)
+}
+
+fn entry(issue: &Issue) -> StandupIssueJson {
+    StandupIssueJson {
+        id: issue.id.clone(),
+        issue_type: issue.issue_type,
+        status: issue.status,
+        title: issue.title.clone(),
+        actions: Vec::new(),
+        blocked_by: Vec::new(),
+    }
+}
+
+fn is_empty(standup: &StandupJson) -> bool {
+    standup.yesterday.is_empty() && standup.today.is_empty()
+}
+
+/// Text standups, one block per assignee.
+pub(crate) fn format_standups(standups: &[StandupJson]) -> String {
+    let mut output = String::new();
+    for (i, standup) in standups.iter().enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+        output.push_str(&format!("{}\n", standup.assignee));
+
+        let yesterday = standup.yesterday.iter().map(|item| {
+            let actions: Vec<&str> = item.actions.iter().map(Action::as_str).collect();
+            format!("{} {} ({})\n", item.id, item.title, actions.join(", "))
+        });
+        push_section(&mut output, "Yesterday", yesterday.collect());
+
+        let today = standup
+            .today
+            .iter()
+            .map(|item| format!("{} {}\n", item.id, item.title));
+        push_section(&mut output, "Today", today.collect());
+
+        let blockers = standup.blockers.iter().map(|item| {
+            let mut lines = format!("{} {}\n", item.id, item.title);
+            for blocker in &item.blocked_by {
+                lines.push_str(&format!("  blocked by {}", blocker.id));
+                if let Some(title) = &blocker.title {
+                    lines.push_str(&format!(" {}", title));
+                }
+                if let Some(assignee) = &blocker.assignee {
+                    lines.push_str(&format!(" ({})", assignee));
+                }
+                lines.push('\n');
+            }
+            lines
+        });
+        push_section(&mut output, "Blockers", blockers.collect());
+    }
+    output
+}
+
+/// A heading, then each entry indented under it, or `(none)`.
+fn push_section(output: &mut String, heading: &str, entries: Vec<String>) {
+    output.push_str(&format!("  {}:\n", heading));
+    if entries.is_empty() {
+        output.push_str("    (none)\n");
+    }
+    for line in entries.iter().flat_map(|entry| entry.lines()) {
+        output.push_str(&format!("    {}\n", line));
+    }
+}
+
+#[cfg(test)]
+#[path = "standup_tests.rs"]
+mod tests;
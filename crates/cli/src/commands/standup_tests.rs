@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use chrono::Duration;
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::{IssueType, Link};
+
+fn ids(items: &[StandupIssueJson]) -> Vec<&str> {
+    items.iter().map(|item| item.id.as_str()).collect()
+}
+
+fn assign(ctx: &TestContext, ids: &[&str], who: &str) {
+    for id in ids {
+        ctx.db.set_assignee(id, who).unwrap();
+    }
+}
+
+#[test]
+fn yesterday_lists_progress_on_assigned_issues() {
+    let mut ctx = TestContext::new();
+    ctx.create_completed("test-1", IssueType::Task, "Shipped")
+        .create_issue("test-2", IssueType::Task, "Noted")
+        .add_note("test-2", "Found the cause")
+        .create_issue("test-3", IssueType::Task, "Only labeled")
+        .add_label("test-3", "ui")
+        .create_completed("test-4", IssueType::Task, "Someone else's");
+    assign(&ctx, &["test-1", "test-2", "test-3"], "alice");
+    assign(&ctx, &["test-4"], "bob");
+
+    let standup = build(&ctx.db, "alice", Utc::now() - Duration::hours(1)).unwrap();
+
+    assert_eq!(ids(&standup.yesterday), ["test-1", "test-2"]);
+    assert_eq!(
+        standup.yesterday[0].actions,
+        [Action::Started, Action::Done]
+    );
+    assert_eq!(standup.yesterday[1].actions, [Action::Noted]);
+
+    let later = build(&ctx.db, "alice", Utc::now() + Duration::hours(1)).unwrap();
+    assert!(later.yesterday.is_empty());
+}
+
+#[test]
+fn today_and_blockers_follow_in_progress_work() {
+    let mut ctx = TestContext::new();
+    ctx.create_and_start("test-1", IssueType::Task, "Blocked work")
+        .create_and_start("test-2", IssueType::Task, "Free work")
+        .create_issue("test-3", IssueType::Task, "Direct blocker")
+        .create_issue("test-4", IssueType::Task, "Transitive blocker")
+        .create_issue("test-5", IssueType::Task, "Queued")
+        .blocks("test-3", "test-1")
+        .blocks("test-4", "test-3");
+    assign(&ctx, &["test-1", "test-2", "test-5"], "alice");
+    assign(&ctx, &["test-3"], "bob");
+    let mut link = Link::new("test-1".to_string());
+    link.url = Some("https://github.com/org/repo/issues/7".to_string());
+    link.rel = Some(LinkRel::Blocks);
+    ctx.db.add_link(&link).unwrap();
+
+    let standup = build(&ctx.db, "alice", Utc::now()).unwrap();
+
+    assert_eq!(ids(&standup.today), ["test-1", "test-2"]);
+    assert_eq!(ids(&standup.blockers), ["test-1"]);
+    let blockers: Vec<&str> = standup.blockers[0]
+        .blocked_by
+        .iter()
+        .map(|b| b.id.as_str())
+        .collect();
+    assert_eq!(
+        blockers,
+        ["test-3", "test-4", "https://github.com/org/repo/issues/7"]
+    );
+    assert_eq!(
+        standup.blockers[0].blocked_by[0].assignee.as_deref(),
+        Some("bob")
+    );
+}
+
+#[test]
+fn text_has_three_sections_per_assignee() {
+    let item = |id: &str, title: &str| StandupIssueJson {
+        id: id.to_string(),
+        issue_type: IssueType::Task,
+        status: Status::InProgress,
+        title: title.to_string(),
+        actions: Vec::new(),
+        blocked_by: Vec::new(),
+    };
+    let standups = vec![
+        StandupJson {
+            assignee: "alice".to_string(),
+            yesterday: vec![StandupIssueJson {
+                actions: vec![Action::Started, Action::Noted],
+                ..item("prj-1", "Fix login")
+            }],
+            today: vec![item("prj-1", "Fix login")],
+            blockers: vec![StandupIssueJson {
+                blocked_by: vec![
+                    BlockerJson {
+                        id: "prj-2".to_string(),
+                        title: Some("Deploy keys".to_string()),
+                        assignee: Some("bob".to_string()),
+                    },
+                    BlockerJson {
+                        id: "https://x.test/7".to_string(),
+                        title: None,
+                        assignee: None,
+                    },
+                ],
+                ..item("prj-1", "Fix login")
+            }],
+        },
+        StandupJson {
+            assignee: "bob".to_string(),
+            yesterday: vec![],
+            today: vec![],
+            blockers: vec![],
+        },
+    ];
+
+    assert_eq!(
+        format_standups(&standups),
+        "alice\n  Yesterday:\n    prj-1 Fix login (started, noted)\n  Today:\n    prj-1 Fix login\n  Blockers:\n    prj-1 Fix login\n      blocked by prj-2 Deploy keys (bob)\n      blocked by https://x.test/7\n\
+         \nbob\n  Yesterday:\n    (none)\n  Today:\n    (none)\n  Blockers:\n    (none)\n"
+    );
+}
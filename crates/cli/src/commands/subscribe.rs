@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Per-issue subscriptions, and the inbox of events on subscribed issues.
+//!
+//! Subscriptions and the inbox's read cursor are kept per subscriber: the
+//! current user (`user` in config), else the git user name.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use wk_core::identity::get_user_name;
+
+use crate::cli::OutputFormat;
+use crate::config::Config;
+use crate::db::Database;
+use crate::display::format_issue_line;
+use crate::error::Result;
+use crate::models::{Event, Issue};
+use crate::read_only;
+use crate::schema::activity::{ActivityEntryJson, ActivityOutputJson};
+use crate::t;
+
+use super::activity::format_activity;
+use super::assign::current_user;
+use super::open_db;
+
+pub fn subscribe(ids: &[String]) -> Result<()> {
+    let ids = super::new::expand_ids(ids);
+    let (db, config, work_dir) = open_db()?;
+    let who = subscriber(&config, &work_dir);
+    if ids.is_empty() {
+        return list_impl(&db, &who);
+    }
+    subscribe_impl(&db, &ids, &who)
+}
+
+pub fn unsubscribe(ids: &[String]) -> Result<()> {
+    let ids = super::new::expand_ids(ids);
+    let (db, config, work_dir) = open_db()?;
+    unsubscribe_impl(&db, &ids, &subscriber(&config, &work_dir))
+}
+
+pub fn inbox(peek: bool, output: OutputFormat) -> Result<()> {
+    let (db, config, work_dir) = open_db()?;
+    let who = subscriber(&config, &work_dir);
+    // A read-only inbox can't record what was read
+    inbox_impl(&db, &who, !peek && !read_only::is_read_only(), output)
+}
+
+/// Who subscriptions are kept for: the current user, else the git user.
+pub(crate) fn subscriber(config: &Config, work_dir: &Path) -> String {
+    current_user(config, work_dir).unwrap_or_else(get_user_name)
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn subscribe_impl(db: &Database, ids: &[String], who: &str) -> Result<()> {
+    let resolved_ids = resolve_all(db, ids)?;
+    for id in resolved_ids {
+        if db.subscribe(&id, who)? {
+            println!("{}", t!("subscribe.subscribed", id = id));
+        } else {
+            println!("{}", t!("subscribe.already", id = id));
+        }
+    }
+    Ok(())
+}
+
+/// Internal implementation that accepts db for testing.
+pub(crate) fn unsubscribe_impl(db: &Database, ids: &[String], who: &str) -> Result<()> {
+    let resolved_ids = resolve_all(db, ids)?;
+    for id in resolved_ids {
+        if db.unsubscribe(&id, who)? {
+            println!("{}", t!("subscribe.unsubscribed", id = id));
+        } else {
+            println!("{}", t!("subscribe.not_subscribed", id = id));
+        }
+    }
+    Ok(())
+}
+
+/// List the issues `who` is subscribed to.
+pub(crate) fn list_impl(db: &Database, who: &str) -> Result<()> {
+    let ids = db.get_subscriptions(who)?;
+    if ids.is_empty() {
+        println!("{}", t!("subscribe.none"));
+    }
+    for id in ids {
+        // Subscriptions can outlive their issue
+        if let Ok(issue) = db.get_issue(&id) {
+            println!("{}", format_issue_line(&issue));
+        }
+    }
+    Ok(())
+}
+
+/// Internal implementation that accepts db for testing.
+///
+/// Prints the events `who` has not read yet, grouped by day like `wok
+/// activity`, and with `mark_read` moves their cursor past them. Events for
+/// issues that no longer exist are skipped.
+pub(crate) fn inbox_impl(
+    db: &Database,
+    who: &str,
+    mark_read: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let events = db.get_inbox_events(who)?;
+    let last_event_id = events.last().map(|event| event.id);
+
+    let mut issues: HashMap<String, Option<Issue>> = HashMap::new();
+    for event in &events {
+        issues
+            .entry(event.issue_id.clone())
+            .or_insert_with(|| db.get_issue(&event.issue_id).ok());
+    }
+    let feed: Vec<(Event, &Issue)> = events
+        .into_iter()
+        .filter_map(|event| match issues.get(&event.issue_id) {
+            Some(Some(issue)) => Some((event, issue)),
+            _ => None,
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => {
+            let entries = feed
+                .iter()
+                .map(|(event, issue)| ActivityEntryJson {
+                    event,
+                    title: &issue.title,
+                    assignee: issue.assignee.as_deref(),
+                })
+                .collect();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&ActivityOutputJson(entries))?
+            );
+        }
+        OutputFormat::Id => {
+            let mut seen = HashSet::new();
+            for (event, _) in &feed {
+                if seen.insert(&event.issue_id) {
+                    println!("{}", event.issue_id);
+                }
+            }
+        }
+        OutputFormat::Text => {
+            if feed.is_empty() {
+                println!("{}", t!("inbox.empty"));
+            } else {
+                print!("{}", format_activity(&feed));
+            }
+        }
+    }
+
+    if let (true, Some(id)) = (mark_read, last_event_id) {
+        db.mark_inbox_read(who, id)?;
+    }
+    Ok(())
+}
+
+fn resolve_all(db: &Database, ids: &[String]) -> Result<Vec<String>> {
+    ids.iter().map(|id| Ok(db.resolve_id(id)?)).collect()
+}
+
+#[cfg(test)]
+#[path = "subscribe_tests.rs"]
+mod tests;
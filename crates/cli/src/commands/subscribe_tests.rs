@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::{Action, IssueType};
+
+fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn test_subscribe_and_unsubscribe() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One")
+        .create_issue("test-2", IssueType::Task, "Two");
+
+    subscribe_impl(&ctx.db, &ids(&["test-1", "test-2"]), "alice").unwrap();
+    // Subscribing again is a no-op
+    subscribe_impl(&ctx.db, &ids(&["test-1"]), "alice").unwrap();
+    assert_eq!(
+        ctx.db.get_subscriptions("alice").unwrap(),
+        vec!["test-1", "test-2"]
+    );
+
+    unsubscribe_impl(&ctx.db, &ids(&["test-1"]), "alice").unwrap();
+    unsubscribe_impl(&ctx.db, &ids(&["test-1"]), "alice").unwrap();
+    assert_eq!(ctx.db.get_subscriptions("alice").unwrap(), vec!["test-2"]);
+    assert!(list_impl(&ctx.db, "alice").is_ok());
+}
+
+#[test]
+fn test_subscribe_unknown_issue_fails_before_subscribing() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One");
+
+    assert!(subscribe_impl(&ctx.db, &ids(&["test-1", "nope-1"]), "alice").is_err());
+    assert!(ctx.db.get_subscriptions("alice").unwrap().is_empty());
+}
+
+#[test]
+fn test_inbox_shows_new_events_then_marks_them_read() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One")
+        .create_issue("test-2", IssueType::Task, "Two");
+    subscribe_impl(&ctx.db, &ids(&["test-1"]), "alice").unwrap();
+    ctx.start_issue("test-1").start_issue("test-2");
+
+    // Peeking leaves events unread
+    inbox_impl(&ctx.db, "alice", false, OutputFormat::Text).unwrap();
+    let unread = ctx.db.get_inbox_events("alice").unwrap();
+    assert_eq!(unread.len(), 1);
+    assert_eq!(unread[0].issue_id, "test-1");
+    assert_eq!(unread[0].action, Action::Started);
+
+    inbox_impl(&ctx.db, "alice", true, OutputFormat::Json).unwrap();
+    assert!(ctx.db.get_inbox_events("alice").unwrap().is_empty());
+
+    ctx.complete_issue("test-1");
+    assert_eq!(ctx.db.get_inbox_events("alice").unwrap().len(), 1);
+}
+
+#[test]
+fn test_inbox_is_per_subscriber() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "One");
+    subscribe_impl(&ctx.db, &ids(&["test-1"]), "alice").unwrap();
+    subscribe_impl(&ctx.db, &ids(&["test-1"]), "bob").unwrap();
+    ctx.start_issue("test-1");
+
+    inbox_impl(&ctx.db, "alice", true, OutputFormat::Id).unwrap();
+    assert!(ctx.db.get_inbox_events("alice").unwrap().is_empty());
+    assert_eq!(ctx.db.get_inbox_events("bob").unwrap().len(), 1);
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `wok sweep` - find stale open issues and close them in bulk.
+//!
+//! Without `--filter`, the `[sweep]` policy decides what is stale (by
+//! default, no activity for 90 days). Without `--close`, the sweep only
+//! reports what it found.
+
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+use crate::config::SweepConfig;
+use crate::db::Database;
+use crate::error::Result;
+use crate::filter::{parse_query, FilterQuery};
+use crate::models::{Action, Event, Issue, Status};
+use crate::validate::{validate_and_trim_reason, validate_label};
+
+use super::filtering::{matches_filter_groups, matches_prefix, parse_filter_groups};
+use super::lifecycle::close_issue;
+use super::{apply_mutation, open_db};
+
+/// What a sweep selects and does.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct SweepArgs {
+    /// Filter expressions, all of which must match; the policy's when empty.
+    pub filter: Vec<String>,
+    /// Status groups, as for `wok list --status`.
+    pub status: Vec<String>,
+    pub prefix: Option<String>,
+    /// Close the issues found instead of reporting them.
+    pub close: bool,
+    pub reason: Option<String>,
+    pub label: Option<String>,
+    pub dry_run: bool,
+}
+
+/// The outcome of a sweep.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Swept {
+    /// The filter expressions used.
+    pub filter: Vec<String>,
+    /// Reason the issues were (or would be) closed with; `None` for a report.
+    pub reason: Option<String>,
+    pub label: Option<String>,
+    /// Whether the issues were closed.
+    pub closed: bool,
+    pub issues: Vec<SweptIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct SweptIssue {
+    pub id: String,
+    pub status: Status,
+    pub title: String,
+    pub updated_at: chrono::DateTime<Utc>,
+}
+
+pub fn run(
+    filter: Vec<String>,
+    status: Vec<String>,
+    close: bool,
+    reason: Option<String>,
+    label: Option<String>,
+    dry_run: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let (db, config, work_dir) = open_db()?;
+    if close && !dry_run {
+        super::backup::auto_backup(&db.conn, &config, &work_dir, "sweep")?;
+    }
+    let args = SweepArgs {
+        filter,
+        status,
+        prefix: (!config.prefix.is_empty()).then_some(config.prefix.clone()),
+        close,
+        reason,
+        label,
+        dry_run,
+    };
+    let swept = sweep_impl(&db, &args, &config.sweep)?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&swept)?),
+        OutputFormat::Id => {
+            for issue in &swept.issues {
+                println!("{}", issue.id);
+            }
+        }
+        OutputFormat::Text => print_text(&swept, &args),
+    }
+    Ok(())
+}
+
+fn print_text(swept: &Swept, args: &SweepArgs) {
+    let filter = swept.filter.join(" and ");
+    if swept.issues.is_empty() {
+        println!("No open issues match {}", filter);
+        return;
+    }
+    let verb = match (args.close, args.dry_run) {
+        (true, false) => "Closed",
+        (true, true) => "Would close",
+        (false, _) => "Stale",
+    };
+    println!(
+        "{} {} issue(s) matching {}:",
+        verb,
+        swept.issues.len(),
+        filter
+    );
+    let now = Utc::now();
+    for issue in &swept.issues {
+        let idle = (now - issue.updated_at).num_days();
+        println!(
+            "  {} ({}, {}d idle): {}",
+            issue.id, issue.status, idle, issue.title
+        );
+    }
+    if let Some(reason) = swept.reason.as_ref().filter(|_| args.close) {
+        match &swept.label {
+            Some(label) => println!("reason: {}, label: {}", reason, label),
+            None => println!("reason: {}", reason),
+        }
+    } else {
+        println!();
+        println!("hint: 'wok sweep --close' closes them");
+    }
+}
+
+/// Select stale open issues and, with `args.close`, close them.
+pub(crate) fn sweep_impl(db: &Database, args: &SweepArgs, policy: &SweepConfig) -> Result<Swept> {
+    let filter = if args.filter.is_empty() {
+        vec![policy.filter().to_string()]
+    } else {
+        args.filter.clone()
+    };
+    let queries: Vec<FilterQuery> = filter
+        .iter()
+        .map(|f| parse_query(f))
+        .collect::<Result<_>>()?;
+    let status_groups = parse_filter_groups(&args.status, |s| Ok(s.parse::<Status>()?))?;
+
+    let reason = match &args.reason {
+        Some(reason) => validate_and_trim_reason(reason)?,
+        None => policy.reason().to_string(),
+    };
+    let label = args.label.clone().or_else(|| policy.label.clone());
+    if let Some(label) = &label {
+        validate_label(label)?;
+    }
+
+    let now = Utc::now();
+    let mut issues = Vec::new();
+    for issue in db.get_all_issues()? {
+        if !issue.status.is_active()
+            || !matches_prefix(&args.prefix, &issue.id)
+            || !matches_filter_groups(&status_groups, || issue.status)
+        {
+            continue;
+        }
+        let labels = if queries.iter().any(FilterQuery::needs_labels) {
+            db.get_labels(&issue.id)?
+        } else {
+            Vec::new()
+        };
+        if queries.iter().all(|q| q.matches(&issue, &labels, now)) {
+            issues.push(issue);
+        }
+    }
+    issues.sort_by(|a, b| {
+        a.updated_at
+            .cmp(&b.updated_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let closing = args.close && !args.dry_run;
+    if closing {
+        for issue in &issues {
+            close_stale(db, issue, &reason, label.as_deref())?;
+        }
+    }
+
+    Ok(Swept {
+        filter,
+        reason: args.close.then_some(reason),
+        label: label.filter(|_| args.close),
+        closed: closing,
+        issues: issues
+            .into_iter()
+            .map(|issue| SweptIssue {
+                id: issue.id,
+                status: issue.status,
+                title: issue.title,
+                updated_at: issue.updated_at,
+            })
+            .collect(),
+    })
+}
+
+fn close_stale(db: &Database, issue: &Issue, reason: &str, label: Option<&str>) -> Result<()> {
+    if let Some(label) = label {
+        if !db.get_labels(&issue.id)?.iter().any(|l| l == label) {
+            apply_mutation(
+                db,
+                Event::new(issue.id.clone(), Action::Labeled)
+                    .with_values(None, Some(label.to_string())),
+                || db.add_label(&issue.id, label),
+            )?;
+        }
+    }
+    close_issue(db, &issue.id, issue.status, reason)?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "sweep_tests.rs"]
+mod tests;
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+use chrono::Duration;
+use yare::parameterized;
+
+/// Make `id` look untouched for `days`.
+fn idle(ctx: &TestContext, id: &str, days: i64) {
+    let updated = (Utc::now() - Duration::days(days)).to_rfc3339();
+    ctx.db
+        .conn
+        .execute(
+            "UPDATE issues SET updated_at = ?1 WHERE id = ?2",
+            [updated.as_str(), id],
+        )
+        .unwrap();
+}
+
+fn setup() -> TestContext {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-old", IssueType::Task, "Old todo")
+        .create_issue("test-new", IssueType::Task, "Fresh todo")
+        .create_and_start("test-wip", IssueType::Task, "Old and started")
+        .create_completed("test-done", IssueType::Task, "Old and done");
+    idle(&ctx, "test-old", 120);
+    idle(&ctx, "test-wip", 100);
+    idle(&ctx, "test-done", 200);
+    ctx
+}
+
+fn ids(swept: &Swept) -> Vec<&str> {
+    swept.issues.iter().map(|i| i.id.as_str()).collect()
+}
+
+#[test]
+fn default_policy_reports_idle_open_issues() {
+    let ctx = setup();
+
+    let swept = sweep_impl(&ctx.db, &SweepArgs::default(), &SweepConfig::default()).unwrap();
+
+    assert_eq!(ids(&swept), vec!["test-old", "test-wip"]);
+    assert_eq!(swept.filter, vec!["activity > 90d"]);
+    assert!(!swept.closed);
+    assert_eq!(swept.reason, None);
+    assert_eq!(ctx.db.get_issue("test-old").unwrap().status, Status::Todo);
+}
+
+#[parameterized(
+    todo = { "todo", vec!["test-old"] },
+    in_progress = { "in_progress", vec!["test-wip"] },
+    both = { "todo,in_progress", vec!["test-old", "test-wip"] },
+)]
+fn status_narrows_the_sweep(status: &str, expected: Vec<&str>) {
+    let ctx = setup();
+    let args = SweepArgs {
+        status: vec![status.to_string()],
+        ..SweepArgs::default()
+    };
+
+    let swept = sweep_impl(&ctx.db, &args, &SweepConfig::default()).unwrap();
+
+    assert_eq!(ids(&swept), expected);
+}
+
+#[test]
+fn filters_replace_the_policy() {
+    let ctx = setup();
+    let policy = SweepConfig {
+        filter: Some("activity > 110d".to_string()),
+        ..SweepConfig::default()
+    };
+    let swept = sweep_impl(&ctx.db, &SweepArgs::default(), &policy).unwrap();
+    assert_eq!(ids(&swept), vec!["test-old"]);
+
+    let args = SweepArgs {
+        filter: vec!["activity > 1d".to_string(), "title = x".to_string()],
+        ..SweepArgs::default()
+    };
+    assert!(sweep_impl(&ctx.db, &args, &policy).is_err());
+
+    let args = SweepArgs {
+        filter: vec!["age < 1d".to_string()],
+        ..SweepArgs::default()
+    };
+    let swept = sweep_impl(&ctx.db, &args, &policy).unwrap();
+    assert_eq!(ids(&swept), vec!["test-old", "test-wip", "test-new"]);
+}
+
+#[test]
+fn close_closes_with_reason_and_label() {
+    let ctx = setup();
+    let args = SweepArgs {
+        status: vec!["todo".to_string()],
+        close: true,
+        label: Some("sweep:2024q3".to_string()),
+        ..SweepArgs::default()
+    };
+
+    let swept = sweep_impl(&ctx.db, &args, &SweepConfig::default()).unwrap();
+
+    assert!(swept.closed);
+    assert_eq!(swept.reason.as_deref(), Some("stale"));
+    let issue = ctx.db.get_issue("test-old").unwrap();
+    assert_eq!(issue.status, Status::Closed);
+    assert_eq!(ctx.db.get_labels("test-old").unwrap(), vec!["sweep:2024q3"]);
+    let notes = ctx.db.get_notes("test-old").unwrap();
+    assert_eq!(notes[0].content, "stale");
+    let events = ctx.db.get_events("test-old").unwrap();
+    let closed = events.iter().find(|e| e.action == Action::Closed).unwrap();
+    assert_eq!(closed.reason.as_deref(), Some("stale"));
+    assert_eq!(
+        ctx.db.get_issue("test-wip").unwrap().status,
+        Status::InProgress
+    );
+}
+
+#[test]
+fn policy_supplies_reason_and_label() {
+    let ctx = setup();
+    let policy = SweepConfig {
+        reason: Some("no activity".to_string()),
+        label: Some("swept".to_string()),
+        ..SweepConfig::default()
+    };
+    let args = SweepArgs {
+        close: true,
+        ..SweepArgs::default()
+    };
+
+    sweep_impl(&ctx.db, &args, &policy).unwrap();
+
+    assert_eq!(
+        ctx.db.get_notes("test-wip").unwrap()[0].content,
+        "no activity"
+    );
+    assert_eq!(ctx.db.get_labels("test-wip").unwrap(), vec!["swept"]);
+}
+
+#[test]
+fn dry_run_changes_nothing() {
+    let ctx = setup();
+    let args = SweepArgs {
+        close: true,
+        dry_run: true,
+        label: Some("swept".to_string()),
+        ..SweepArgs::default()
+    };
+
+    let swept = sweep_impl(&ctx.db, &args, &SweepConfig::default()).unwrap();
+
+    assert_eq!(ids(&swept), vec!["test-old", "test-wip"]);
+    assert!(!swept.closed);
+    assert_eq!(swept.reason.as_deref(), Some("stale"));
+    assert_eq!(ctx.db.get_issue("test-old").unwrap().status, Status::Todo);
+    assert!(ctx.db.get_labels("test-old").unwrap().is_empty());
+}
+
+#[test]
+fn prefix_limits_the_sweep() {
+    let ctx = setup();
+    let args = SweepArgs {
+        prefix: Some("other".to_string()),
+        ..SweepArgs::default()
+    };
+
+    let swept = sweep_impl(&ctx.db, &args, &SweepConfig::default()).unwrap();
+
+    assert!(swept.issues.is_empty());
+}
@@ -1,134 +1,370 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+use crate::cli::{OutputFormat, TreeRel};
 use crate::db::Database;
 use crate::display::{format_tree_child, format_tree_root, RelationType};
 use crate::error::Result;
+use crate::estimate::{estimate_from_tags, EstimateTotal};
+use crate::markdown;
+use crate::models::Issue;
+use crate::schema::tree::{TreeEdge, TreeNodeJson, TreeOutputJson};
+use crate::t;
 
 use super::open_db;
 
-pub fn run(ids: &[String]) -> Result<()> {
+/// Which part of the dependency graph a tree shows.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct TreeOptions {
+    /// Levels below the root to expand; `None` for all.
+    pub depth: Option<usize>,
+    /// Edges to follow.
+    pub rel: TreeRel,
+    /// Walk toward what the root depends on (parents and blockers) instead
+    /// of what it contains.
+    pub up: bool,
+    /// Style code spans and bold text in titles (text output only).
+    pub render_markdown: bool,
+}
+
+pub fn run(
+    ids: &[String],
+    depth: Option<usize>,
+    rel: TreeRel,
+    up: bool,
+    raw: bool,
+    format: OutputFormat,
+) -> Result<()> {
     let ids = super::new::expand_ids(ids);
     let (db, _, _) = open_db()?;
-    run_impl(&db, &ids)
+    let opts = TreeOptions {
+        depth,
+        rel,
+        up,
+        render_markdown: matches!(format, OutputFormat::Text) && markdown::enabled(raw),
+    };
+    run_impl(&db, &ids, opts, format)
 }
 
 /// Internal implementation that accepts db for testing.
-pub(crate) fn run_impl(db: &Database, ids: &[String]) -> Result<()> {
+pub(crate) fn run_impl(
+    db: &Database,
+    ids: &[String],
+    opts: TreeOptions,
+    format: OutputFormat,
+) -> Result<()> {
     // Resolve all IDs first (fail fast if any is invalid)
     let resolved_ids: Vec<String> = ids
         .iter()
         .map(|id| Ok(db.resolve_id(id)?))
         .collect::<Result<Vec<_>>>()?;
 
-    for (i, resolved_id) in resolved_ids.iter().enumerate() {
-        if i > 0 {
-            println!("---");
+    let mut trees = resolved_ids
+        .iter()
+        .map(|id| build_tree(db, id, opts))
+        .collect::<Result<Vec<_>>>()?;
+
+    let _render = tracing::info_span!("render").entered();
+    match format {
+        OutputFormat::Text => {
+            for (i, tree) in trees.iter().enumerate() {
+                if i > 0 {
+                    println!("---");
+                }
+                for line in tree_lines(tree) {
+                    println!("{}", line);
+                }
+                if !opts.up {
+                    let tracked = tracked_estimate(db, &tree.issue.id)?;
+                    if tracked.estimated > 0 {
+                        println!("{}", format_tracked_estimate(&tracked));
+                    }
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let output = if trees.len() == 1 {
+                TreeOutputJson::One(Box::new(trees.remove(0).into_json()))
+            } else {
+                TreeOutputJson::Many(trees.into_iter().map(TreeNode::into_json).collect())
+            };
+            println!("{}", serde_json::to_string(&output)?);
+        }
+        OutputFormat::Id => {
+            let mut seen = std::collections::HashSet::new();
+            for tree in &trees {
+                tree.walk(&mut |node| {
+                    let associated = node.relation.is_some_and(|rel| !rel.is_structural());
+                    if !associated && seen.insert(node.issue.id.clone()) {
+                        println!("{}", node.issue.id);
+                    }
+                });
+            }
         }
-        output_single_tree(db, resolved_id)?;
     }
-
     Ok(())
 }
 
-fn output_single_tree(db: &Database, resolved_id: &str) -> Result<()> {
-    let issue = db.get_issue(resolved_id)?;
-
-    // Get blockers for root issue
-    let blockers = db.get_transitive_blockers(resolved_id)?;
-    let blocked_by = if blockers.is_empty() {
-        None
-    } else {
-        Some(blockers.as_slice())
-    };
+/// An issue in a tree and the issues below it.
+#[derive(Debug)]
+pub(crate) struct TreeNode {
+    pub issue: Issue,
+    /// Edge from the parent; `None` on the root.
+    pub relation: Option<RelationType>,
+    /// Open transitive blockers.
+    pub blocked_by: Vec<String>,
+    /// Already shown above on this path; not expanded again.
+    pub cycle: bool,
+    /// Children not expanded because of the depth limit.
+    pub hidden: usize,
+    pub children: Vec<TreeNode>,
+}
 
-    // Print root issue
-    println!("{}", format_tree_root(&issue, blocked_by));
+impl TreeNode {
+    fn walk(&self, f: &mut impl FnMut(&TreeNode)) {
+        f(self);
+        for child in &self.children {
+            child.walk(f);
+        }
+    }
 
-    // Get tracked and blocking issues
-    let tracked = db.get_tracked(resolved_id)?;
-    let blocking = db.get_blocking(resolved_id)?;
+    fn into_json(self) -> TreeNodeJson {
+        TreeNodeJson {
+            id: self.issue.id,
+            issue_type: self.issue.issue_type,
+            title: self.issue.title,
+            status: self.issue.status,
+            assignee: self.issue.assignee,
+            relation: self.relation.map(|rel| match rel {
+                RelationType::Tracks => TreeEdge::Tracks,
+                RelationType::Blocks => TreeEdge::Blocks,
+                RelationType::TrackedBy => TreeEdge::TrackedBy,
+                RelationType::BlockedBy => TreeEdge::BlockedBy,
+                RelationType::Related => TreeEdge::RelatesTo,
+                RelationType::DuplicateOf => TreeEdge::DuplicateOf,
+                RelationType::DuplicatedBy => TreeEdge::DuplicatedBy,
+            }),
+            blocked_by: self.blocked_by,
+            cycle: self.cycle,
+            hidden_children: self.hidden,
+            children: self.children.into_iter().map(TreeNode::into_json).collect(),
+        }
+    }
+}
 
-    // Determine if we need relation labels (only if both types exist)
-    let show_labels = !tracked.is_empty() && !blocking.is_empty();
+/// What the open issues under an epic or feature are estimated to take.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct TrackedEstimate {
+    pub total: EstimateTotal,
+    /// Open issues with an estimate.
+    pub estimated: usize,
+    /// Open issues without one.
+    pub unestimated: usize,
+}
 
-    // Print tracked children first
-    let tracked_is_last_group = blocking.is_empty();
-    print_children(
-        db,
-        &tracked,
-        "",
-        RelationType::Tracks,
-        show_labels,
-        tracked_is_last_group,
-    )?;
+/// Sum the estimates of the open issues `id` tracks, directly or through
+/// the issues it tracks. Each issue counts once however it is reached.
+pub(crate) fn tracked_estimate(db: &Database, id: &str) -> Result<TrackedEstimate> {
+    let mut tracked = TrackedEstimate::default();
+    let mut seen = vec![id.to_string()];
+    let mut queue = db.get_tracked(id)?;
+    while let Some(child) = queue.pop() {
+        if seen.contains(&child) {
+            continue;
+        }
+        seen.push(child.clone());
+        queue.extend(db.get_tracked(&child)?);
+        if db.get_issue(&child)?.status.is_terminal() {
+            continue;
+        }
+        match estimate_from_tags(&db.get_labels(&child)?) {
+            Some(estimate) => {
+                tracked.total.add(estimate);
+                tracked.estimated += 1;
+            }
+            None => tracked.unestimated += 1,
+        }
+    }
+    Ok(tracked)
+}
 
-    // Print blocking children (issues this one blocks)
-    print_children(db, &blocking, "", RelationType::Blocks, show_labels, true)?;
+/// The line under a tree summing its open estimates.
+pub(crate) fn format_tracked_estimate(tracked: &TrackedEstimate) -> String {
+    let mut line = t!(
+        "tree.estimate",
+        total = tracked.total,
+        count = tracked.estimated
+    );
+    if tracked.unestimated > 0 {
+        line.push_str(&format!(
+            ", {}",
+            t!("tree.unestimated", count = tracked.unestimated)
+        ));
+    }
+    line
+}
 
-    Ok(())
+/// Build the tree rooted at `id`.
+pub(crate) fn build_tree(db: &Database, id: &str, opts: TreeOptions) -> Result<TreeNode> {
+    build_node(db, id, None, &mut Vec::new(), opts)
 }
 
-fn print_children(
-    db: &crate::db::Database,
-    children: &[String],
-    prefix: &str,
-    relation: RelationType,
-    show_labels: bool,
-    is_last_group: bool,
-) -> Result<()> {
-    for (i, child_id) in children.iter().enumerate() {
-        let is_last_in_group = i == children.len() - 1;
-        let is_last = is_last_in_group && is_last_group;
-        let issue = db.get_issue(child_id)?;
+/// `path` holds the IDs from the root down to this node's parent.
+fn build_node(
+    db: &Database,
+    id: &str,
+    relation: Option<RelationType>,
+    path: &mut Vec<String>,
+    opts: TreeOptions,
+) -> Result<TreeNode> {
+    let mut node = TreeNode {
+        issue: load_issue(db, id, opts.render_markdown)?,
+        relation,
+        // Looking up, blockers are already the node's children
+        blocked_by: if opts.up {
+            Vec::new()
+        } else {
+            db.get_transitive_blockers(id)?
+        },
+        cycle: false,
+        hidden: 0,
+        children: Vec::new(),
+    };
+    if path.iter().any(|p| p == id) {
+        node.cycle = true;
+        return Ok(node);
+    }
+    if relation.is_some_and(|rel| !rel.is_structural()) {
+        return Ok(node);
+    }
 
-        // Get transitive blockers for this issue (already filtered for open status)
-        let blockers = db.get_transitive_blockers(child_id)?;
+    let mut edges = child_edges(db, id, opts)?;
+    if path.is_empty() && !opts.up && opts.rel == TreeRel::All {
+        edges.extend(association_edges(db, id)?);
+    }
+    if opts.depth.is_some_and(|depth| path.len() >= depth) {
+        node.hidden = edges.len();
+        return Ok(node);
+    }
 
-        let blocked_by = if blockers.is_empty() {
-            None
+    path.push(id.to_string());
+    for (child, rel) in edges {
+        node.children
+            .push(build_node(db, &child, Some(rel), path, opts)?);
+    }
+    path.pop();
+    Ok(node)
+}
+
+/// Children of `id` along the chosen edges, tracked before blocked.
+fn child_edges(db: &Database, id: &str, opts: TreeOptions) -> Result<Vec<(String, RelationType)>> {
+    let (tracks, blocks) = if opts.up {
+        (RelationType::TrackedBy, RelationType::BlockedBy)
+    } else {
+        (RelationType::Tracks, RelationType::Blocks)
+    };
+    let mut edges = Vec::new();
+    if opts.rel != TreeRel::Blocks {
+        let tracked = if opts.up {
+            db.get_tracking(id)?
         } else {
-            Some(blockers.as_slice())
+            db.get_tracked(id)?
         };
+        edges.extend(tracked.into_iter().map(|child| (child, tracks)));
+    }
+    if opts.rel != TreeRel::Tracks {
+        let blocked = if opts.up {
+            db.get_blockers(id)?
+        } else {
+            db.get_blocking(id)?
+        };
+        edges.extend(blocked.into_iter().map(|child| (child, blocks)));
+    }
+    Ok(edges)
+}
 
-        let label = if show_labels { Some(relation) } else { None };
-        for line in format_tree_child(&issue, prefix, is_last, blocked_by, label) {
-            println!("{}", line);
-        }
+/// Related issues and duplicates of the root, listed after its children.
+fn association_edges(db: &Database, id: &str) -> Result<Vec<(String, RelationType)>> {
+    let mut edges = Vec::new();
+    for (ids, rel) in [
+        (db.get_related(id)?, RelationType::Related),
+        (db.get_duplicate_of(id)?, RelationType::DuplicateOf),
+        (db.get_duplicates(id)?, RelationType::DuplicatedBy),
+    ] {
+        edges.extend(ids.into_iter().map(|other| (other, rel)));
+    }
+    Ok(edges)
+}
 
-        // Recursively print grandchildren (only for tracked relations)
-        if relation == RelationType::Tracks {
-            let grandchildren = db.get_tracked(child_id)?;
-            let grandblocking = db.get_blocking(child_id)?;
-            if !grandchildren.is_empty() || !grandblocking.is_empty() {
-                let child_prefix = if is_last {
-                    format!("{}    ", prefix)
-                } else {
-                    format!("{}│   ", prefix)
-                };
-                let show_grandlabels = !grandchildren.is_empty() && !grandblocking.is_empty();
-                let tracked_is_last = grandblocking.is_empty();
-                print_children(
-                    db,
-                    &grandchildren,
-                    &child_prefix,
-                    RelationType::Tracks,
-                    show_grandlabels,
-                    tracked_is_last,
-                )?;
-                print_children(
-                    db,
-                    &grandblocking,
-                    &child_prefix,
-                    RelationType::Blocks,
-                    show_grandlabels,
-                    true,
-                )?;
+/// Text rendering of a tree, one line per entry.
+pub(crate) fn tree_lines(root: &TreeNode) -> Vec<String> {
+    let mut lines = vec![format_tree_root(&root.issue, non_empty(&root.blocked_by))];
+    child_lines(root, "", &mut lines);
+    lines
+}
+
+fn child_lines(node: &TreeNode, prefix: &str, lines: &mut Vec<String>) {
+    if node.hidden > 0 {
+        lines.push(format!(
+            "{}└── {}",
+            prefix,
+            t!("tree.more", count = node.hidden)
+        ));
+        return;
+    }
+
+    // Label tracked and blocked edges only when a node has both kinds of
+    // children; associations are always labeled
+    let structural: Vec<_> = node
+        .children
+        .iter()
+        .filter_map(|child| child.relation.filter(RelationType::is_structural))
+        .collect();
+    let show_labels = structural.windows(2).any(|pair| pair[0] != pair[1]);
+
+    for (i, child) in node.children.iter().enumerate() {
+        let is_last = i == node.children.len() - 1;
+        let label = child
+            .relation
+            .filter(|rel| show_labels || !rel.is_structural());
+        let mut entry = format_tree_child(
+            &child.issue,
+            prefix,
+            is_last,
+            non_empty(&child.blocked_by),
+            label,
+        );
+        if child.cycle {
+            if let Some(first) = entry.first_mut() {
+                first.push_str(&format!(" ({})", t!("tree.cycle")));
             }
         }
+        lines.extend(entry);
+
+        let child_prefix = if is_last {
+            format!("{}    ", prefix)
+        } else {
+            format!("{}│   ", prefix)
+        };
+        child_lines(child, &child_prefix, lines);
     }
+}
 
-    Ok(())
+fn non_empty(ids: &[String]) -> Option<&[String]> {
+    if ids.is_empty() {
+        None
+    } else {
+        Some(ids)
+    }
+}
+
+/// Load an issue, rendering markdown in its title if requested.
+fn load_issue(db: &Database, id: &str, render_markdown: bool) -> Result<Issue> {
+    let mut issue = db.get_issue(id)?;
+    if render_markdown {
+        issue.title = markdown::render_inline(&issue.title);
+    }
+    Ok(issue)
 }
 
 #[cfg(test)]
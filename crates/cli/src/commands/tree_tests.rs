@@ -127,7 +127,12 @@ fn test_multiple_direct_blockers() {
 
 // Tests for run_impl
 
-use crate::commands::tree::run_impl;
+use crate::cli::{OutputFormat, TreeRel};
+use crate::commands::dep::add_impl;
+use crate::commands::tree::{
+    build_tree, format_tracked_estimate, run_impl, tracked_estimate, tree_lines, TreeOptions,
+};
+use crate::display::RelationType;
 
 #[test]
 fn test_run_impl_simple() {
@@ -136,7 +141,31 @@ fn test_run_impl_simple() {
         .create_issue("test-2", IssueType::Task, "Child task")
         .tracks("test-1", "test-2");
 
-    let result = run_impl(&ctx.db, &["test-1".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_run_impl_with_markdown_titles() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Feature, "Rework `wok tree`")
+        .create_issue("test-2", IssueType::Task, "**Urgent** child")
+        .tracks("test-1", "test-2");
+
+    let result = run_impl(
+        &ctx.db,
+        &["test-1".to_string()],
+        TreeOptions {
+            render_markdown: true,
+            ..TreeOptions::default()
+        },
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -145,7 +174,12 @@ fn test_run_impl_leaf_node() {
     let mut ctx = TestContext::new();
     ctx.create_issue("leaf", IssueType::Task, "Leaf task");
 
-    let result = run_impl(&ctx.db, &["leaf".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["leaf".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -153,7 +187,12 @@ fn test_run_impl_leaf_node() {
 fn test_run_impl_nonexistent() {
     let ctx = TestContext::new();
 
-    let result = run_impl(&ctx.db, &["nonexistent".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["nonexistent".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_err());
 }
 
@@ -164,7 +203,12 @@ fn test_run_impl_with_blockers() {
         .create_issue("blocked", IssueType::Task, "Blocked")
         .blocks("blocker", "blocked");
 
-    let result = run_impl(&ctx.db, &["blocked".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["blocked".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -177,7 +221,12 @@ fn test_run_impl_deep_hierarchy() {
         .tracks("l1", "l2")
         .tracks("l2", "l3");
 
-    let result = run_impl(&ctx.db, &["l1".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["l1".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -199,7 +248,12 @@ fn test_run_impl_with_tracked_and_blocked() {
     assert_eq!(blocking.len(), 1);
     assert_eq!(blocking[0], "dependent");
 
-    let result = run_impl(&ctx.db, &["epic".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["epic".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -219,7 +273,12 @@ fn test_run_impl_blocks_only() {
     let blocking = ctx.db.get_blocking("blocker").unwrap();
     assert_eq!(blocking.len(), 2);
 
-    let result = run_impl(&ctx.db, &["blocker".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["blocker".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -233,7 +292,12 @@ fn test_run_impl_multiple_ids() {
         .tracks("feature1", "task1")
         .tracks("feature2", "task2");
 
-    let result = run_impl(&ctx.db, &["feature1".to_string(), "feature2".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["feature1".to_string(), "feature2".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
 
@@ -243,7 +307,12 @@ fn test_run_impl_multiple_ids_fail_fast() {
     ctx.create_issue("valid", IssueType::Task, "Valid task");
 
     // Second ID is invalid - should fail fast before printing anything
-    let result = run_impl(&ctx.db, &["valid".to_string(), "nonexistent".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["valid".to_string(), "nonexistent".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_err());
 }
 
@@ -253,6 +322,243 @@ fn test_run_impl_single_id_backward_compatible() {
     ctx.create_issue("single", IssueType::Task, "Single task");
 
     // Single ID should work exactly as before
-    let result = run_impl(&ctx.db, &["single".to_string()]);
+    let result = run_impl(
+        &ctx.db,
+        &["single".to_string()],
+        TreeOptions::default(),
+        OutputFormat::Text,
+    );
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_depth_limits_expansion() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("l1", IssueType::Feature, "Level 1")
+        .create_issue("l2", IssueType::Task, "Level 2")
+        .create_issue("l3", IssueType::Task, "Level 3")
+        .create_issue("l4", IssueType::Task, "Level 4")
+        .tracks("l1", "l2")
+        .tracks("l2", "l3")
+        .tracks("l2", "l4");
+
+    let opts = TreeOptions {
+        depth: Some(1),
+        ..TreeOptions::default()
+    };
+    let tree = build_tree(&ctx.db, "l1", opts).unwrap();
+    assert_eq!(tree.children.len(), 1);
+    assert!(tree.children[0].children.is_empty());
+    assert_eq!(tree.children[0].hidden, 2);
+
+    let lines = tree_lines(&tree);
+    assert_eq!(lines.len(), 3);
+    assert!(lines[2].contains("2 more"));
+
+    let opts = TreeOptions {
+        depth: Some(0),
+        ..TreeOptions::default()
+    };
+    let tree = build_tree(&ctx.db, "l1", opts).unwrap();
+    assert!(tree.children.is_empty());
+    assert_eq!(tree.hidden, 1);
+}
+
+#[test]
+fn test_rel_selects_edges() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("epic", IssueType::Epic, "Epic")
+        .create_issue("tracked", IssueType::Task, "Tracked")
+        .create_issue("blocked", IssueType::Task, "Blocked")
+        .create_issue("deeper", IssueType::Task, "Blocked by blocked")
+        .tracks("epic", "tracked")
+        .blocks("epic", "blocked")
+        .blocks("blocked", "deeper");
+
+    let ids = |rel| {
+        let opts = TreeOptions {
+            rel,
+            ..TreeOptions::default()
+        };
+        let tree = build_tree(&ctx.db, "epic", opts).unwrap();
+        let mut ids = Vec::new();
+        collect_ids(&tree, &mut ids);
+        ids
+    };
+
+    assert_eq!(ids(TreeRel::Tracks), vec!["epic", "tracked"]);
+    assert_eq!(ids(TreeRel::Blocks), vec!["epic", "blocked", "deeper"]);
+    assert_eq!(
+        ids(TreeRel::All),
+        vec!["epic", "tracked", "blocked", "deeper"]
+    );
+}
+
+fn collect_ids(node: &crate::commands::tree::TreeNode, ids: &mut Vec<String>) {
+    ids.push(node.issue.id.clone());
+    for child in &node.children {
+        collect_ids(child, ids);
+    }
+}
+
+#[test]
+fn test_cycle_across_relations_is_not_expanded_twice() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("a", IssueType::Feature, "A")
+        .create_issue("b", IssueType::Task, "B")
+        .tracks("a", "b")
+        .blocks("b", "a");
+
+    let tree = build_tree(&ctx.db, "a", TreeOptions::default()).unwrap();
+    let b = &tree.children[0];
+    assert_eq!(b.issue.id, "b");
+    let repeated = &b.children[0];
+    assert_eq!(repeated.issue.id, "a");
+    assert!(repeated.cycle);
+    assert!(repeated.children.is_empty());
+
+    let lines = tree_lines(&tree);
+    assert!(lines
+        .iter()
+        .any(|l| l.contains("a: A") && l.ends_with("(cycle)")));
+}
+
+#[test]
+fn test_assignee_badge() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("parent", IssueType::Feature, "Parent")
+        .create_issue("child", IssueType::Task, "Child")
+        .tracks("parent", "child")
+        .start_issue("child");
+    ctx.db.set_assignee("child", "alice").unwrap();
+
+    let tree = build_tree(&ctx.db, "parent", TreeOptions::default()).unwrap();
+    let lines = tree_lines(&tree);
+    assert_eq!(lines[0], "parent: Parent");
+    assert_eq!(lines[1], "└── child: Child [in_progress, @alice]");
+}
+
+#[test]
+fn test_run_impl_json_and_id_formats() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("parent", IssueType::Feature, "Parent")
+        .create_issue("child", IssueType::Task, "Child")
+        .tracks("parent", "child");
+
+    let ids = ["parent".to_string()];
+    assert!(run_impl(&ctx.db, &ids, TreeOptions::default(), OutputFormat::Json).is_ok());
+    assert!(run_impl(&ctx.db, &ids, TreeOptions::default(), OutputFormat::Id).is_ok());
+}
+
+#[test]
+fn test_up_walks_parents_and_transitive_blockers() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("epic", IssueType::Epic, "Epic")
+        .create_issue("task", IssueType::Task, "Task")
+        .create_issue("b1", IssueType::Task, "Blocker 1")
+        .create_issue("b2", IssueType::Task, "Blocker of blocker")
+        .create_issue("child", IssueType::Task, "Unrelated child")
+        .tracks("epic", "task")
+        .blocks("b1", "task")
+        .blocks("b2", "b1")
+        .tracks("task", "child");
+
+    let opts = TreeOptions {
+        up: true,
+        ..TreeOptions::default()
+    };
+    let tree = build_tree(&ctx.db, "task", opts).unwrap();
+    let mut ids = Vec::new();
+    collect_ids(&tree, &mut ids);
+    assert_eq!(ids, vec!["task", "epic", "b1", "b2"]);
+    assert_eq!(tree.children[0].relation, Some(RelationType::TrackedBy));
+    assert_eq!(tree.children[1].relation, Some(RelationType::BlockedBy));
+    assert!(tree.blocked_by.is_empty());
+
+    let lines = tree_lines(&tree);
+    assert!(lines[1].ends_with("(parent)"));
+    assert!(lines[2].ends_with("(blocker)"));
+
+    let opts = TreeOptions {
+        up: true,
+        rel: TreeRel::Blocks,
+        depth: Some(1),
+        ..TreeOptions::default()
+    };
+    let tree = build_tree(&ctx.db, "task", opts).unwrap();
+    assert_eq!(tree.children.len(), 1);
+    assert_eq!(tree.children[0].issue.id, "b1");
+    assert_eq!(tree.children[0].hidden, 1);
+}
+
+#[test]
+fn test_root_lists_associations_as_labeled_leaves() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("root", IssueType::Task, "Root")
+        .create_issue("child", IssueType::Task, "Child")
+        .create_issue("near", IssueType::Task, "Near")
+        .create_issue("copy", IssueType::Task, "Copy")
+        .tracks("root", "child");
+    add_impl(&ctx.db, "root", "relates-to", &["near".to_string()]).unwrap();
+    add_impl(&ctx.db, "copy", "duplicate-of", &["root".to_string()]).unwrap();
+
+    let tree = build_tree(&ctx.db, "root", TreeOptions::default()).unwrap();
+    let edges: Vec<_> = tree
+        .children
+        .iter()
+        .map(|c| (c.issue.id.as_str(), c.relation))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            ("child", Some(RelationType::Tracks)),
+            ("near", Some(RelationType::Related)),
+            ("copy", Some(RelationType::DuplicatedBy)),
+        ]
+    );
+    // Associated issues are leaves, even though "near" relates back
+    assert!(tree.children[1].children.is_empty());
+
+    let lines = tree_lines(&tree);
+    assert!(lines[1].ends_with("child: Child"));
+    assert!(lines[2].ends_with("near: Near (related)"));
+    assert!(lines[3].ends_with("copy: Copy (duplicated by)"));
+
+    // Only the full tree shows them
+    let opts = TreeOptions {
+        rel: TreeRel::Tracks,
+        ..TreeOptions::default()
+    };
+    assert_eq!(build_tree(&ctx.db, "root", opts).unwrap().children.len(), 1);
+}
+
+#[test]
+fn tracked_estimate_sums_open_descendants_once() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("epic", IssueType::Epic, "Epic")
+        .create_issue("feature", IssueType::Feature, "Feature")
+        .create_issue("a", IssueType::Task, "A")
+        .create_issue("b", IssueType::Task, "B")
+        .create_issue("c", IssueType::Task, "C")
+        .create_completed("d", IssueType::Task, "D")
+        .tracks("epic", "feature")
+        .tracks("feature", "a")
+        .tracks("feature", "b")
+        .tracks("epic", "b")
+        .tracks("epic", "c")
+        .tracks("epic", "d")
+        .add_label("a", "estimate:3pt")
+        .add_label("b", "estimate:5pt")
+        .add_label("c", "estimate:1d")
+        .add_label("d", "estimate:8pt");
+
+    let tracked = tracked_estimate(&ctx.db, "epic").unwrap();
+
+    assert_eq!(tracked.estimated, 3);
+    // The feature itself has no estimate
+    assert_eq!(tracked.unestimated, 1);
+    assert_eq!(
+        format_tracked_estimate(&tracked),
+        "Open estimate: 8pt + 1d across 3 issues, 1 without an estimate"
+    );
+}
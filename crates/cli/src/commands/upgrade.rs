@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Upgrade command: apply pending database migrations safely.
+//!
+//! Every command already migrates the database implicitly when it opens it.
+//! `wok upgrade` makes that step explicit: it reports CLI/daemon
+//! compatibility, lists the pending migrations, backs up the database and
+//! only then migrates. It refuses to run while a daemon holds the database.
+
+use std::path::Path;
+
+use rusqlite::Connection;
+
+use crate::config::{find_work_dir, get_db_path, wok_state_dir, Config};
+use crate::daemon;
+use crate::error::{Error, Result};
+
+use super::backup::backup_before;
+
+/// State of the daemon relative to this CLI.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DaemonState {
+    /// Private mode has no daemon.
+    NotApplicable,
+    /// No daemon is running.
+    NotRunning,
+    /// A daemon is running with the given PID and version.
+    Running { pid: u32, version: String },
+}
+
+/// Run the upgrade command.
+pub fn run(check: bool) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let config = Config::load(&work_dir)?;
+    let db_path = get_db_path(&work_dir, &config);
+
+    let daemon_state = if config.private {
+        DaemonState::NotApplicable
+    } else {
+        detect_daemon_state(&wok_state_dir())?
+    };
+
+    if !db_path.exists() {
+        print_compatibility(&daemon_state);
+        println!("Database: {} (not created yet)", db_path.display());
+        println!("Nothing to upgrade.");
+        return Ok(());
+    }
+
+    // Open without Database::open, which would migrate implicitly
+    let conn = Connection::open(&db_path)?;
+    conn.execute_batch("PRAGMA busy_timeout = 5000;")?;
+    run_impl(&conn, &db_path, &work_dir, &config, &daemon_state, check)
+}
+
+/// Detect a running daemon and ask it for its version.
+fn detect_daemon_state(daemon_dir: &Path) -> Result<DaemonState> {
+    match daemon::detect_daemon(daemon_dir)? {
+        Some(info) => {
+            let version =
+                daemon::get_daemon_version(daemon_dir)?.unwrap_or_else(|| "unknown".to_string());
+            Ok(DaemonState::Running {
+                pid: info.pid,
+                version,
+            })
+        }
+        None => Ok(DaemonState::NotRunning),
+    }
+}
+
+/// Report compatibility, then back up and migrate unless `check` is set.
+pub(crate) fn run_impl(
+    conn: &Connection,
+    db_path: &Path,
+    work_dir: &Path,
+    config: &Config,
+    daemon_state: &DaemonState,
+    check: bool,
+) -> Result<()> {
+    print_compatibility(daemon_state);
+    println!("Database: {}", db_path.display());
+
+    let pending = wk_core::db::pending_migrations(conn)?;
+    if pending.is_empty() {
+        println!("Schema: up to date");
+        println!("Nothing to upgrade.");
+        return Ok(());
+    }
+
+    println!("Schema: {} pending migration(s)", pending.len());
+    for step in &pending {
+        println!("  - {}", step);
+    }
+
+    if check {
+        return Ok(());
+    }
+
+    // A running daemon keeps its own connection open; upgrading underneath it
+    // would leave the trio half-upgraded.
+    match daemon_state {
+        DaemonState::Running { version, .. } if version != daemon::CLI_VERSION => {
+            return Err(Error::DaemonVersionMismatch {
+                daemon_version: version.clone(),
+                cli_version: daemon::CLI_VERSION.to_string(),
+            });
+        }
+        DaemonState::Running { pid, .. } => {
+            return Err(Error::UpgradeDaemonRunning { pid: *pid });
+        }
+        DaemonState::NotApplicable | DaemonState::NotRunning => {}
+    }
+
+    let backup_path = backup_before(conn, config, work_dir, "upgrade")?;
+    println!("Backed up database to {}", backup_path.display());
+
+    wk_core::db::run_migrations(conn)?;
+    println!("Applied {} migration(s).", pending.len());
+    Ok(())
+}
+
+fn print_compatibility(daemon_state: &DaemonState) {
+    println!("CLI: v{}", daemon::CLI_VERSION);
+    match daemon_state {
+        DaemonState::NotApplicable => println!("Daemon: n/a (private mode)"),
+        DaemonState::NotRunning => println!("Daemon: not running"),
+        DaemonState::Running { pid, version } => {
+            let compat = if version == daemon::CLI_VERSION {
+                "compatible"
+            } else {
+                "version mismatch"
+            };
+            println!("Daemon: v{} (PID {}, {})", version, pid, compat);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "upgrade_tests.rs"]
+mod tests;
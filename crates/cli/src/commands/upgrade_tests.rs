@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use tempfile::tempdir;
+
+/// Create a database missing the assignee column, as written by early versions.
+fn old_database(path: &Path) -> Connection {
+    let conn = Connection::open(path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE issues (
+            id TEXT PRIMARY KEY,
+            type TEXT NOT NULL,
+            title TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL DEFAULT 'todo',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        INSERT INTO issues (id, type, title, status, created_at, updated_at)
+        VALUES ('prj-a1', 'task', 'Old task', 'todo', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z');",
+    )
+    .unwrap();
+    conn
+}
+
+/// Run the upgrade with `dir` as the work directory.
+fn upgrade(dir: &Path, conn: &Connection, state: &DaemonState, check: bool) -> Result<()> {
+    run_impl(
+        conn,
+        &dir.join("issues.db"),
+        dir,
+        &Config::default(),
+        state,
+        check,
+    )
+}
+
+#[test]
+fn check_reports_without_migrating() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let conn = old_database(&db_path);
+
+    upgrade(dir.path(), &conn, &DaemonState::NotRunning, true).unwrap();
+
+    assert!(!wk_core::db::pending_migrations(&conn).unwrap().is_empty());
+    let backups = std::fs::read_dir(dir.path()).unwrap().count();
+    assert_eq!(backups, 1, "check mode must not write a backup");
+}
+
+#[test]
+fn upgrade_backs_up_then_migrates() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let conn = old_database(&db_path);
+    Config::default().save(dir.path()).unwrap();
+
+    upgrade(dir.path(), &conn, &DaemonState::NotApplicable, false).unwrap();
+
+    assert!(wk_core::db::pending_migrations(&conn).unwrap().is_empty());
+
+    let backups = dir.path().join(crate::commands::backup::BACKUPS_DIR);
+    let backup = std::fs::read_dir(&backups)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.to_string_lossy().ends_with("-auto-upgrade"))
+        .unwrap();
+    let manifest = crate::commands::backup::verify(&backup).unwrap();
+    assert_eq!(manifest.reason.as_deref(), Some("upgrade"));
+    let backup_conn = Connection::open(backup.join("issues.db")).unwrap();
+    let title: String = backup_conn
+        .query_row("SELECT title FROM issues WHERE id = 'prj-a1'", [], |r| {
+            r.get(0)
+        })
+        .unwrap();
+    assert_eq!(title, "Old task");
+    // The backup is the pre-upgrade state
+    assert!(!wk_core::db::pending_migrations(&backup_conn)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn upgrade_refuses_while_daemon_running() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let conn = old_database(&db_path);
+    let state = DaemonState::Running {
+        pid: 42,
+        version: daemon::CLI_VERSION.to_string(),
+    };
+
+    let err = upgrade(dir.path(), &conn, &state, false).unwrap_err();
+
+    assert!(matches!(err, Error::UpgradeDaemonRunning { pid: 42 }));
+    assert!(!wk_core::db::pending_migrations(&conn).unwrap().is_empty());
+}
+
+#[test]
+fn upgrade_refuses_on_daemon_version_mismatch() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let conn = old_database(&db_path);
+    let state = DaemonState::Running {
+        pid: 42,
+        version: "0.0.1".to_string(),
+    };
+
+    let err = upgrade(dir.path(), &conn, &state, false).unwrap_err();
+
+    assert!(matches!(err, Error::DaemonVersionMismatch { .. }));
+}
+
+#[test]
+fn up_to_date_database_is_untouched() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let conn = Connection::open(&db_path).unwrap();
+    wk_core::db::run_migrations(&conn).unwrap();
+
+    // A running daemon is fine when there is nothing to do
+    let state = DaemonState::Running {
+        pid: 42,
+        version: daemon::CLI_VERSION.to_string(),
+    };
+    upgrade(dir.path(), &conn, &state, false).unwrap();
+
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
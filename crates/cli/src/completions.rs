@@ -5,6 +5,10 @@
 //!
 //! Installs shell completion scripts and adds sourcing lines to shell RC files.
 //! Follows the marker-based pattern from `git_hooks.rs` for safe, idempotent installation.
+//!
+//! Also holds the `wok completion --dynamic` scripts, which hand every
+//! completion to `wok _complete` so issue IDs and labels come from the
+//! database.
 
 use std::fs::{self, OpenOptions};
 use std::io::Write;
@@ -65,6 +69,16 @@ impl ShellKind {
         }
     }
 
+    /// The shell for a clap_complete shell type, if wok supports it.
+    pub fn from_clap(shell: clap_complete::Shell) -> Option<Self> {
+        match shell {
+            clap_complete::Shell::Bash => Some(ShellKind::Bash),
+            clap_complete::Shell::Zsh => Some(ShellKind::Zsh),
+            clap_complete::Shell::Fish => Some(ShellKind::Fish),
+            _ => None,
+        }
+    }
+
     /// Get the clap_complete shell type.
     fn clap_shell(&self) -> clap_complete::Shell {
         match self {
@@ -84,6 +98,63 @@ impl ShellKind {
     }
 }
 
+/// Bash: bash-completion's helpers, when loaded, keep `label:value` words
+/// whole instead of splitting them at the colon.
+const BASH_DYNAMIC: &str = r#"# wok dynamic completion for bash
+_wok() {
+    local cur words cword
+    if declare -F _get_comp_words_by_ref >/dev/null; then
+        _get_comp_words_by_ref -n : cur words cword
+    else
+        cur=${COMP_WORDS[COMP_CWORD]} words=("${COMP_WORDS[@]}") cword=$COMP_CWORD
+    fi
+    local IFS=$'\n'
+    COMPREPLY=($(wok _complete --index "$cword" -- "${words[@]}" 2>/dev/null))
+    if declare -F __ltrim_colon_completions >/dev/null; then
+        __ltrim_colon_completions "$cur"
+    fi
+}
+complete -o default -F _wok wok
+"#;
+
+/// Zsh: falls back to file completion when wok offers nothing.
+const ZSH_DYNAMIC: &str = r#"#compdef wok
+# wok dynamic completion for zsh
+_wok() {
+    local -a candidates
+    candidates=(${(f)"$(wok _complete --index $((CURRENT - 1)) -- "${words[@]}" 2>/dev/null)"})
+    if (( ${#candidates} )); then
+        compadd -a candidates
+    else
+        _files
+    fi
+}
+if [ "$funcstack[1]" = "_wok" ]; then
+    _wok "$@"
+else
+    compdef _wok wok
+fi
+"#;
+
+/// Fish: the token under the cursor is passed separately from the ones
+/// before it, so its index is the count of those.
+const FISH_DYNAMIC: &str = r#"# wok dynamic completion for fish
+complete -c wok -e
+complete -c wok -f -a '(wok _complete --index (count (commandline -opc)) -- (commandline -opc) (commandline -ct))'
+"#;
+
+/// The `wok completion --dynamic` script for `shell`.
+pub fn dynamic_script(shell: clap_complete::Shell) -> Result<&'static str> {
+    match ShellKind::from_clap(shell) {
+        Some(ShellKind::Bash) => Ok(BASH_DYNAMIC),
+        Some(ShellKind::Zsh) => Ok(ZSH_DYNAMIC),
+        Some(ShellKind::Fish) => Ok(FISH_DYNAMIC),
+        None => Err(Error::UnsupportedShell {
+            shell: shell.to_string(),
+        }),
+    }
+}
+
 /// Detect which shells are installed on the system.
 pub fn detect_shells() -> Vec<ShellKind> {
     let mut shells = Vec::new();
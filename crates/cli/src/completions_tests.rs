@@ -162,3 +162,36 @@ fn test_install_all_no_shells() {
 fn test_marker_constant() {
     assert_eq!(WK_COMPLETION_MARKER, "# wok-shell-completion");
 }
+
+#[test]
+fn test_dynamic_scripts_call_complete() {
+    for shell in [
+        clap_complete::Shell::Bash,
+        clap_complete::Shell::Zsh,
+        clap_complete::Shell::Fish,
+    ] {
+        let script = dynamic_script(shell).unwrap();
+        assert!(script.contains("wok _complete --index"), "{}", shell);
+    }
+}
+
+#[test]
+fn test_dynamic_script_unsupported_shell() {
+    let err = dynamic_script(clap_complete::Shell::PowerShell).unwrap_err();
+    assert!(matches!(err, Error::UnsupportedShell { .. }));
+}
+
+#[test]
+fn test_static_scripts_generate_with_hidden_commands() {
+    for shell in [
+        clap_complete::Shell::Bash,
+        clap_complete::Shell::Zsh,
+        clap_complete::Shell::Fish,
+        clap_complete::Shell::Elvish,
+        clap_complete::Shell::PowerShell,
+    ] {
+        let mut out = Vec::new();
+        generate(shell, &mut Cli::command(), "wok", &mut out);
+        assert!(!out.is_empty(), "{}", shell);
+    }
+}
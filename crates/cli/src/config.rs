@@ -6,21 +6,46 @@
 //! Configuration is stored in `.wok/config.toml` and includes:
 //! - `prefix`: The project-specific prefix for issue IDs (e.g., "proj" → "proj-a1b2")
 //! - `private`: Whether to use private mode (direct SQLite) vs user-level (daemon)
+//! - `user`: Who `@me` refers to in assignee arguments
+//! - `team`: Optional roster of assignees; when set, assigning anyone else fails
+//! - `wip`: Limits on how many issues each assignee may have in progress
+//! - `epic`: Whether issues tracking many others are suggested or converted to epics
+//! - `alias`: User-defined subcommands, expanded before parsing (see [`crate::alias`])
+//! - `limits`: Default `--limit` for list, search, and log
+//! - `color`: Whether output is colored (`auto`, `always`, `never`)
+//! - `locale`: The language of messages (see [`crate::i18n`])
+//! - `open`: The issue page for `wok open`
+//! - `links`: Link providers beyond the built-in ones
+//! - `api`: Tokens for the GitHub, GitLab and Jira APIs, for `wok link refresh`
+//! - `git`: Branch names for `wok branch`
+//! - `labels`: Declared `key:value` label keys and their values
+//! - `aging`: How long an issue may stay in a status before it is stale
+//! - `workspace`, `workspace_id`: The workspace a project joined with
+//!   `wok init --join` (see [`crate::workspace`])
+//!
+//! [`crate::settings`] lists every key for `wok config get/set/list`, and
+//! [`crate::layers`] combines this file with the user file and environment.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::error::{Error, Result};
+use crate::filter::parse_duration;
+use crate::i18n::Locale;
 use crate::id::validate_prefix;
+use crate::layers::Layers;
+use crate::models::{LinkProvider, Status};
+use wk_core::ApiConfig;
 
 const WORK_DIR_NAME: &str = ".wok";
-const CONFIG_FILE_NAME: &str = "config.toml";
+pub(crate) const CONFIG_FILE_NAME: &str = "config.toml";
 const DB_FILE_NAME: &str = "issues.db";
 const GITIGNORE_FILE_NAME: &str = ".gitignore";
 
 /// Project configuration stored in `.wok/config.toml`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     /// Project-specific prefix for issue IDs (2+ lowercase alphanumeric with at least one letter).
     /// Empty when linking to workspace without local prefix.
@@ -30,6 +55,579 @@ pub struct Config {
     /// If false (default), use user-level mode (daemon at ~/.local/state/wok/).
     #[serde(default)]
     pub private: bool,
+    /// Name that `@me` resolves to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Who events and notes are recorded as; unset falls back to `user`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    /// Known assignees. Empty means anyone can be assigned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub team: Vec<String>,
+    /// When to color output.
+    #[serde(default, skip_serializing_if = "ColorMode::is_auto")]
+    pub color: ColorMode,
+    /// Language of messages; unset follows `LANG`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+    /// Work-in-progress limits.
+    #[serde(default, skip_serializing_if = "WipConfig::is_empty")]
+    pub wip: WipConfig,
+    /// Automatic promotion of large trackers to epics.
+    #[serde(default, skip_serializing_if = "EpicConfig::is_default")]
+    pub epic: EpicConfig,
+    /// Default result limits.
+    #[serde(default, skip_serializing_if = "LimitsConfig::is_empty")]
+    pub limits: LimitsConfig,
+    /// Alias name to the command line it stands for.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub alias: BTreeMap<String, String>,
+    /// URLs for `wok open`.
+    #[serde(default, skip_serializing_if = "OpenConfig::is_empty")]
+    pub open: OpenConfig,
+    /// Clickable issue IDs and links in terminal output.
+    #[serde(default, skip_serializing_if = "HyperlinkConfig::is_empty")]
+    pub hyperlinks: HyperlinkConfig,
+    /// Link providers by scheme, detected when links are added.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, LinkProvider>,
+    /// Provider API access for `wok link refresh`; best kept in the user
+    /// file, which the daemon's refresher also reads.
+    #[serde(default, skip_serializing_if = "ApiConfig::is_empty")]
+    pub api: ApiConfig,
+    /// Git integration.
+    #[serde(default, skip_serializing_if = "GitConfig::is_empty")]
+    pub git: GitConfig,
+    /// Staleness policy for `wok sweep`.
+    #[serde(default, skip_serializing_if = "SweepConfig::is_empty")]
+    pub sweep: SweepConfig,
+    /// Automatic backups before destructive commands.
+    #[serde(default, skip_serializing_if = "BackupConfig::is_empty")]
+    pub backup: BackupConfig,
+    /// Declared `key:value` labels.
+    #[serde(default, skip_serializing_if = "LabelsConfig::is_empty")]
+    pub labels: LabelsConfig,
+    /// Time in a status after which an issue is stale.
+    #[serde(default, skip_serializing_if = "AgingConfig::is_empty")]
+    pub aging: AgingConfig,
+    /// The workspace root's `.wok/` directory, whose database and mode this
+    /// project shares. Relative paths are from the project directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<String>,
+    /// Identity of the workspace this project belongs to, root or member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
+}
+
+/// When output is colored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// Color when stdout is a terminal.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    fn is_auto(&self) -> bool {
+        *self == ColorMode::Auto
+    }
+}
+
+/// Default `--limit` values from the `[limits]` table. 0 means no limit.
+///
+/// ```toml
+/// [limits]
+/// list = 50
+/// log = 0
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<usize>,
+}
+
+impl LimitsConfig {
+    fn is_empty(&self) -> bool {
+        self == &LimitsConfig::default()
+    }
+}
+
+/// The `[open]` table for `wok open`.
+///
+/// ```toml
+/// [open]
+/// url = "https://wok.example.com/{prefix}/{id}"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenConfig {
+    /// Page for an issue, opened by `--web` or when it has no links.
+    /// `{id}` and `{prefix}` are replaced with the issue's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl OpenConfig {
+    fn is_empty(&self) -> bool {
+        self == &OpenConfig::default()
+    }
+}
+
+/// `template` with `{id}` and `{prefix}` replaced with issue `id`'s.
+pub fn issue_url(template: &str, id: &str) -> String {
+    let prefix = id.split_once('-').map_or(id, |(prefix, _)| prefix);
+    template.replace("{id}", id).replace("{prefix}", prefix)
+}
+
+/// OSC 8 hyperlinks from the `[hyperlinks]` table.
+///
+/// ```toml
+/// [hyperlinks]
+/// mode = "auto"
+/// url = "http://127.0.0.1:8080/issue/{id}"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HyperlinkConfig {
+    /// When issue IDs and external links are printed as hyperlinks.
+    #[serde(default, skip_serializing_if = "HyperlinkMode::is_auto")]
+    pub mode: HyperlinkMode,
+    /// Page an issue ID links to; `{id}` and `{prefix}` are replaced with
+    /// the issue's. Unset uses `open.url`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl HyperlinkConfig {
+    fn is_empty(&self) -> bool {
+        self == &HyperlinkConfig::default()
+    }
+}
+
+/// When output carries hyperlinks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HyperlinkMode {
+    /// Hyperlinks when stdout is a terminal known to support them.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl HyperlinkMode {
+    fn is_auto(&self) -> bool {
+        *self == HyperlinkMode::Auto
+    }
+}
+
+/// Git integration from the `[git]` table.
+///
+/// ```toml
+/// [git]
+/// branch = "{id}-{slug}"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Branch name for an issue; `{id}`, `{type}`, `{prefix}` and `{slug}`
+    /// (the title, lowercased and hyphenated) are replaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+impl GitConfig {
+    /// Branch names unless `[git] branch` is set.
+    pub const DEFAULT_BRANCH: &'static str = "{type}/{id}-{slug}";
+
+    pub fn branch_template(&self) -> &str {
+        self.branch.as_deref().unwrap_or(Self::DEFAULT_BRANCH)
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &GitConfig::default()
+    }
+}
+
+/// Staleness policy for `wok sweep` from the `[sweep]` table.
+///
+/// ```toml
+/// [sweep]
+/// filter = "activity > 60d and label != keep"
+/// reason = "stale"
+/// label = "sweep:stale"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SweepConfig {
+    /// Filter expression selecting stale issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Reason stale issues are closed with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Label added to each issue the sweep closes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl SweepConfig {
+    /// Issues count as stale unless `[sweep] filter` is set.
+    pub const DEFAULT_FILTER: &'static str = "activity > 90d";
+    /// Close reason unless `[sweep] reason` is set.
+    pub const DEFAULT_REASON: &'static str = "stale";
+
+    pub fn filter(&self) -> &str {
+        self.filter.as_deref().unwrap_or(Self::DEFAULT_FILTER)
+    }
+
+    pub fn reason(&self) -> &str {
+        self.reason.as_deref().unwrap_or(Self::DEFAULT_REASON)
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &SweepConfig::default()
+    }
+}
+
+/// Aging thresholds from the `[aging]` table: how long an issue may stay
+/// in a status before `wok list`, `wok show` and the board mark it stale.
+///
+/// ```toml
+/// [aging]
+/// todo = "30d"
+/// in_progress = "7d"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgingConfig {
+    /// Longest an issue may wait in `todo`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub todo: Option<String>,
+    /// Longest an issue may stay in `in_progress`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<String>,
+}
+
+impl AgingConfig {
+    /// The threshold for `status`; `None` when unset, invalid, or for
+    /// done and closed issues, which never go stale.
+    pub fn threshold(&self, status: Status) -> Option<chrono::Duration> {
+        let value = match status {
+            Status::Todo => self.todo.as_deref(),
+            Status::InProgress => self.in_progress.as_deref(),
+            Status::Done | Status::Closed => None,
+        }?;
+        parse_duration(value).ok()
+    }
+
+    /// Whether any status has a threshold.
+    pub fn is_set(&self) -> bool {
+        self.threshold(Status::Todo).is_some() || self.threshold(Status::InProgress).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &AgingConfig::default()
+    }
+}
+
+/// Automatic backups from the `[backup]` table.
+///
+/// ```toml
+/// [backup]
+/// auto = true
+/// keep = 10
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupConfig {
+    /// Back up before import, prefix renames and `wok sweep --close`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto: Option<bool>,
+    /// Automatic backups kept; older ones are removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep: Option<usize>,
+}
+
+impl BackupConfig {
+    /// Automatic backups kept unless `[backup] keep` is set.
+    pub const DEFAULT_KEEP: usize = 5;
+
+    pub fn auto(&self) -> bool {
+        self.auto.unwrap_or(false)
+    }
+
+    pub fn keep(&self) -> usize {
+        self.keep.unwrap_or(Self::DEFAULT_KEEP)
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &BackupConfig::default()
+    }
+}
+
+/// Declared `key:value` labels from the `[labels]` table.
+///
+/// Labels without a colon are always allowed. So are `key:value` labels with
+/// an undeclared key, unless `strict` is set.
+///
+/// ```toml
+/// [labels]
+/// strict = true
+///
+/// [labels.keys.priority]
+/// type = "int"
+/// min = 0
+/// max = 4
+///
+/// [labels.keys.team]
+/// values = ["alpha", "beta"]
+/// aliases = { platform = "alpha" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelsConfig {
+    /// Reject `key:value` labels whose key is not declared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+    /// Declared keys and the values they take.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<String, LabelKey>,
+}
+
+impl LabelsConfig {
+    pub fn strict(&self) -> bool {
+        self.strict.unwrap_or(false)
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &LabelsConfig::default()
+    }
+
+    /// Why `label` breaks the declared keys, or `None` when it conforms.
+    pub fn violation(&self, label: &str) -> Option<String> {
+        let (key, value) = label.split_once(':')?;
+        if key.is_empty() || value.is_empty() {
+            return None;
+        }
+        match self.keys.get(key) {
+            Some(declared) => declared.violation(value),
+            None if self.strict() => Some(format!(
+                "'{}' is not a declared label key (declared: {})",
+                key,
+                self.keys.keys().cloned().collect::<Vec<_>>().join(", ")
+            )),
+            None => None,
+        }
+    }
+
+    /// The conforming label `label` maps to: the key and value matched
+    /// without case, a value alias, or a whole number written another way.
+    pub fn fix(&self, label: &str) -> Option<String> {
+        let (key, value) = label.split_once(':')?;
+        let (name, declared) = self
+            .keys
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key.trim()))?;
+        let fixed = format!("{}:{}", name, declared.fix(value.trim())?);
+        (fixed != label).then_some(fixed)
+    }
+
+    /// Check that `label` conforms, suggesting its fix when it doesn't.
+    pub fn check(&self, label: &str) -> Result<()> {
+        let Some(reason) = self.violation(label) else {
+            return Ok(());
+        };
+        let hint = match self.fix(label) {
+            Some(fixed) => format!("did you mean '{}'?", fixed),
+            None => "label keys are declared under [labels.keys] in .wok/config.toml".to_string(),
+        };
+        Err(Error::InvalidLabel {
+            label: label.to_string(),
+            reason,
+            hint,
+        })
+    }
+}
+
+/// One declared label key, such as `priority`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelKey {
+    /// What kind of value the key takes.
+    #[serde(
+        default,
+        rename = "type",
+        skip_serializing_if = "LabelValueType::is_text"
+    )]
+    pub value_type: LabelValueType,
+    /// Allowed values; empty allows any of the type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+    /// Smallest allowed `int` value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<i64>,
+    /// Largest allowed `int` value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<i64>,
+    /// Values `wok labels audit --fix` replaces, with their replacements.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+}
+
+impl LabelKey {
+    fn violation(&self, value: &str) -> Option<String> {
+        if self.value_type == LabelValueType::Int {
+            let Ok(n) = value.parse::<i64>() else {
+                return Some(format!("'{}' is not a whole number", value));
+            };
+            if let Some(min) = self.min.filter(|&min| n < min) {
+                return Some(format!("{} is below the minimum {}", n, min));
+            }
+            if let Some(max) = self.max.filter(|&max| n > max) {
+                return Some(format!("{} is above the maximum {}", n, max));
+            }
+        }
+        if !self.values.is_empty() && !self.values.iter().any(|v| v == value) {
+            return Some(format!(
+                "'{}' is not one of {}",
+                value,
+                self.values.join(", ")
+            ));
+        }
+        None
+    }
+
+    fn fix(&self, value: &str) -> Option<String> {
+        let value = self
+            .aliases
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(value))
+            .map_or(value, |(_, target)| target.as_str());
+        let fixed = match self.value_type {
+            LabelValueType::Int => value.parse::<i64>().ok()?.to_string(),
+            LabelValueType::Text => self
+                .values
+                .iter()
+                .find(|v| v.eq_ignore_ascii_case(value))
+                .map_or(value, String::as_str)
+                .to_string(),
+        };
+        self.violation(&fixed).is_none().then_some(fixed)
+    }
+}
+
+/// The kind of value a label key takes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelValueType {
+    /// Any text.
+    #[default]
+    #[serde(rename = "string")]
+    Text,
+    /// A whole number, optionally bounded by `min` and `max`.
+    Int,
+}
+
+impl LabelValueType {
+    fn is_text(&self) -> bool {
+        *self == LabelValueType::Text
+    }
+}
+
+/// Work-in-progress limits from the `[wip]` table.
+///
+/// ```toml
+/// [wip]
+/// limit = 2
+/// mode = "block"
+///
+/// [wip.assignees]
+/// alice = 3
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WipConfig {
+    /// Maximum in-progress issues per assignee. None means no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// Per-assignee overrides of `limit`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub assignees: BTreeMap<String, usize>,
+    /// What `wok start` does when a limit would be exceeded.
+    #[serde(default)]
+    pub mode: WipMode,
+}
+
+impl WipConfig {
+    fn is_empty(&self) -> bool {
+        self == &WipConfig::default()
+    }
+
+    /// The limit that applies to an assignee, if any.
+    pub fn limit_for(&self, assignee: &str) -> Option<usize> {
+        self.assignees.get(assignee).copied().or(self.limit)
+    }
+}
+
+/// How a WIP limit is enforced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WipMode {
+    /// Start anyway, printing a warning.
+    #[default]
+    Warn,
+    /// Refuse to start the issue.
+    Block,
+}
+
+/// Automatic epic promotion from the `[epic]` table.
+///
+/// ```toml
+/// [epic]
+/// auto = "convert"
+/// threshold = 8
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpicConfig {
+    /// What happens when an issue starts tracking `threshold` or more issues.
+    #[serde(default)]
+    pub auto: AutoEpic,
+    /// Number of tracked issues that makes an issue an epic candidate.
+    #[serde(default = "EpicConfig::default_threshold")]
+    pub threshold: usize,
+}
+
+impl EpicConfig {
+    const DEFAULT_THRESHOLD: usize = 5;
+
+    fn default_threshold() -> usize {
+        Self::DEFAULT_THRESHOLD
+    }
+
+    fn is_default(&self) -> bool {
+        self == &EpicConfig::default()
+    }
+}
+
+impl Default for EpicConfig {
+    fn default() -> Self {
+        EpicConfig {
+            auto: AutoEpic::default(),
+            threshold: Self::DEFAULT_THRESHOLD,
+        }
+    }
+}
+
+/// How a tracker that crosses the epic threshold is handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoEpic {
+    /// Do nothing.
+    Off,
+    /// Print a hint suggesting the conversion.
+    #[default]
+    Suggest,
+    /// Change the issue's type to epic.
+    Convert,
 }
 
 impl Config {
@@ -44,7 +642,7 @@ impl Config {
         }
         Ok(Config {
             prefix,
-            private: false,
+            ..Config::default()
         })
     }
 
@@ -56,11 +654,36 @@ impl Config {
         Ok(Config {
             prefix,
             private: true,
+            ..Config::default()
         })
     }
 
-    /// Loads configuration from the given `.wok/` directory.
+    /// Loads the effective configuration for the given `.wok/` directory:
+    /// its config file layered over the user file, with environment
+    /// overrides applied. A workspace member takes its mode, and its prefix
+    /// when it has none, from the workspace root, along with any link
+    /// providers it does not configure itself.
     pub fn load(work_dir: &Path) -> Result<Self> {
+        let mut config = Layers::load(work_dir)?.resolve()?;
+        if let Some(root) = config.workspace_root(work_dir) {
+            if !root.join(CONFIG_FILE_NAME).is_file() {
+                return Err(Error::WorkspaceNotFound(root.display().to_string()));
+            }
+            let root_config = Config::load_file(&root)?;
+            config.private = root_config.private;
+            if config.prefix.is_empty() {
+                config.prefix = root_config.prefix;
+            }
+            for (scheme, provider) in root_config.links {
+                config.links.entry(scheme).or_insert(provider);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Loads only the given `.wok/` directory's config file, as edits that
+    /// are saved back to it must.
+    pub fn load_file(work_dir: &Path) -> Result<Self> {
         let config_path = work_dir.join(CONFIG_FILE_NAME);
         let content = fs::read_to_string(&config_path)
             .map_err(|e| Error::Config(format!("failed to read config: {}", e)))?;
@@ -69,6 +692,18 @@ impl Config {
         Ok(config)
     }
 
+    /// The `.wok/` directory of the workspace root this project joined, or
+    /// `None` outside a workspace and in the root itself.
+    pub fn workspace_root(&self, work_dir: &Path) -> Option<PathBuf> {
+        let path = Path::new(self.workspace.as_deref()?);
+        let path = match work_dir.parent() {
+            Some(project_dir) => project_dir.join(path),
+            None => path.to_path_buf(),
+        };
+        let nested = path.join(WORK_DIR_NAME);
+        Some(if nested.is_dir() { nested } else { path })
+    }
+
     /// Saves configuration to the given `.wok/` directory.
     pub fn save(&self, work_dir: &Path) -> Result<()> {
         let config_path = work_dir.join(CONFIG_FILE_NAME);
@@ -79,9 +714,17 @@ impl Config {
     }
 }
 
-/// Find the .wok directory by walking up from the current directory
+/// Find the .wok directory by walking up from the current directory.
+///
+/// A workspace member finds its own `.wok/`; [`Config::workspace_root`]
+/// leads from there to the root whose database it shares.
 pub fn find_work_dir() -> Result<PathBuf> {
-    let mut current = std::env::current_dir()?;
+    find_work_dir_from(&std::env::current_dir()?)
+}
+
+/// Find the .wok directory by walking up from `start`
+pub fn find_work_dir_from(start: &Path) -> Result<PathBuf> {
+    let mut current = start.to_path_buf();
     loop {
         let work_dir = current.join(WORK_DIR_NAME);
         if work_dir.is_dir() {
@@ -93,11 +736,27 @@ pub fn find_work_dir() -> Result<PathBuf> {
     }
 }
 
+/// The config of the project a command line will run in, honoring a
+/// leading `-C <path>`. `None` outside a project or when the config cannot
+/// be read; the command itself reports that.
+pub fn load_for_args(args: &[String]) -> Option<Config> {
+    let cwd = std::env::current_dir().ok()?;
+    let start = match crate::alias::directory(args) {
+        Some(dir) => cwd.join(dir),
+        None => cwd,
+    };
+    Config::load(&find_work_dir_from(&start).ok()?).ok()
+}
+
 /// Get the database path from config
 pub fn get_db_path(work_dir: &Path, config: &Config) -> PathBuf {
     if config.private {
-        // Private mode: database stored in .wok/issues.db
-        work_dir.join(DB_FILE_NAME)
+        // Private mode: database stored in .wok/issues.db, the workspace
+        // root's for a member
+        config
+            .workspace_root(work_dir)
+            .unwrap_or_else(|| work_dir.to_path_buf())
+            .join(DB_FILE_NAME)
     } else {
         // User-level mode: database stored in state directory
         wok_state_dir().join(DB_FILE_NAME)
@@ -124,22 +783,16 @@ pub fn wok_state_dir() -> PathBuf {
 
 /// Initialize a new .wok directory at the given path
 pub fn init_work_dir(path: &Path, prefix: &str) -> Result<PathBuf> {
-    let work_dir = path.join(WORK_DIR_NAME);
-
-    if work_dir.join(CONFIG_FILE_NAME).exists() {
-        return Err(Error::AlreadyInitialized(work_dir.display().to_string()));
-    }
-
-    fs::create_dir_all(&work_dir)?;
-
-    let config = Config::new(prefix.to_string())?;
-    config.save(&work_dir)?;
-
-    Ok(work_dir)
+    init_work_dir_with(path, &Config::new(prefix.to_string())?)
 }
 
 /// Initialize a new .wok directory in private mode
 pub fn init_work_dir_private(path: &Path, prefix: &str) -> Result<PathBuf> {
+    init_work_dir_with(path, &Config::new_private(prefix.to_string())?)
+}
+
+/// Initialize a new .wok directory at the given path with `config`
+pub fn init_work_dir_with(path: &Path, config: &Config) -> Result<PathBuf> {
     let work_dir = path.join(WORK_DIR_NAME);
 
     if work_dir.join(CONFIG_FILE_NAME).exists() {
@@ -147,8 +800,6 @@ pub fn init_work_dir_private(path: &Path, prefix: &str) -> Result<PathBuf> {
     }
 
     fs::create_dir_all(&work_dir)?;
-
-    let config = Config::new_private(prefix.to_string())?;
     config.save(&work_dir)?;
 
     Ok(work_dir)
@@ -82,6 +82,7 @@ fn test_config_save_and_reload() {
     let config = Config {
         prefix: "myproj".to_string(),
         private: true,
+        ..Config::default()
     };
     config.save(&work_dir).unwrap();
 
@@ -199,3 +200,108 @@ fn test_config_serialization() {
         "Serialized TOML should contain prefix"
     );
 }
+
+#[test]
+fn test_wip_config_parses_limits_and_mode() {
+    let config: Config = toml::from_str(
+        "prefix = \"prj\"\n\n[wip]\nlimit = 2\nmode = \"block\"\n\n[wip.assignees]\nalice = 3\n",
+    )
+    .unwrap();
+
+    assert_eq!(config.wip.mode, WipMode::Block);
+    assert_eq!(config.wip.limit_for("alice"), Some(3));
+    assert_eq!(config.wip.limit_for("bob"), Some(2));
+}
+
+#[test]
+fn test_wip_config_defaults_to_no_limit() {
+    let config = Config::new("prj".to_string()).unwrap();
+
+    assert_eq!(config.wip.mode, WipMode::Warn);
+    assert_eq!(config.wip.limit_for("alice"), None);
+    assert!(!toml::to_string_pretty(&config).unwrap().contains("wip"));
+}
+
+#[test]
+fn test_epic_config_parses_mode_and_threshold() {
+    let config: Config =
+        toml::from_str("prefix = \"prj\"\n\n[epic]\nauto = \"convert\"\nthreshold = 3\n").unwrap();
+
+    assert_eq!(config.epic.auto, AutoEpic::Convert);
+    assert_eq!(config.epic.threshold, 3);
+}
+
+#[test]
+fn test_epic_config_defaults_to_suggest_at_five() {
+    let config: Config = toml::from_str("prefix = \"prj\"\n\n[epic]\nauto = \"off\"\n").unwrap();
+    assert_eq!(config.epic.threshold, 5);
+
+    let config = Config::new("prj".to_string()).unwrap();
+    assert_eq!(config.epic.auto, AutoEpic::Suggest);
+    assert!(!toml::to_string_pretty(&config).unwrap().contains("epic"));
+}
+
+/// `priority` takes 0 to 4, `team` takes alpha or beta (platform means alpha).
+fn label_schema(strict: bool) -> LabelsConfig {
+    let config: Config = toml::from_str(&format!(
+        "prefix = \"prj\"\n\n[labels]\nstrict = {}\n\n\
+         [labels.keys.priority]\ntype = \"int\"\nmin = 0\nmax = 4\n\n\
+         [labels.keys.team]\nvalues = [\"alpha\", \"beta\"]\naliases = {{ platform = \"alpha\" }}\n",
+        strict
+    ))
+    .unwrap();
+    config.labels
+}
+
+#[test]
+fn test_labels_config_accepts_conforming_labels() {
+    let schema = label_schema(true);
+    for label in ["priority:0", "priority:4", "team:beta", "urgent", "note:"] {
+        assert_eq!(schema.violation(label), None, "{}", label);
+    }
+}
+
+#[test]
+fn test_labels_config_reports_violations() {
+    let schema = label_schema(true);
+    let reason = |label: &str| schema.violation(label).unwrap();
+
+    assert_eq!(reason("priority:high"), "'high' is not a whole number");
+    assert_eq!(reason("priority:7"), "7 is above the maximum 4");
+    assert_eq!(reason("priority:-1"), "-1 is below the minimum 0");
+    assert_eq!(reason("team:gamma"), "'gamma' is not one of alpha, beta");
+    assert_eq!(
+        reason("area:ui"),
+        "'area' is not a declared label key (declared: priority, team)"
+    );
+    assert_eq!(label_schema(false).violation("area:ui"), None);
+}
+
+#[test]
+fn test_labels_config_fixes_case_aliases_and_numbers() {
+    let schema = label_schema(true);
+
+    assert_eq!(schema.fix("Team:Alpha").as_deref(), Some("team:alpha"));
+    assert_eq!(schema.fix("team:platform").as_deref(), Some("team:alpha"));
+    assert_eq!(schema.fix("priority:02").as_deref(), Some("priority:2"));
+    assert_eq!(schema.fix("priority:9"), None);
+    assert_eq!(schema.fix("team:alpha"), None);
+}
+
+#[test]
+fn test_labels_config_check_suggests_fix() {
+    let schema = label_schema(true);
+
+    let err = schema.check("team:Alpha").unwrap_err().to_string();
+    assert!(err.contains("invalid label 'team:Alpha'"), "{}", err);
+    assert!(err.contains("did you mean 'team:alpha'?"), "{}", err);
+
+    let err = schema.check("team:gamma").unwrap_err().to_string();
+    assert!(err.contains("[labels.keys]"), "{}", err);
+}
+
+#[test]
+fn test_labels_config_omitted_when_empty() {
+    let config = Config::new("prj".to_string()).unwrap();
+    assert!(!toml::to_string_pretty(&config).unwrap().contains("labels"));
+}
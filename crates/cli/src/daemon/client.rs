@@ -10,8 +10,10 @@ use std::path::Path;
 use std::time::Duration;
 
 use crate::error::{Error, Result};
+use wk_ipc::framing::Encoding;
 use wk_ipc::{
-    framing, DaemonRequest, DaemonResponse, MutateOp, MutateResult, QueryOp, QueryResult,
+    framing, DaemonDbError, DaemonRequest, DaemonResponse, HookJob, MutateOp, MutateResult,
+    QueryOp, QueryResult,
 };
 
 /// Connection timeout for daemon communication.
@@ -20,6 +22,7 @@ const TIMEOUT_SECS: u64 = 5;
 /// A client connection to the daemon.
 pub struct DaemonClient {
     stream: UnixStream,
+    encoding: Encoding,
 }
 
 impl DaemonClient {
@@ -35,12 +38,23 @@ impl DaemonClient {
             .set_write_timeout(Some(Duration::from_secs(TIMEOUT_SECS)))
             .map_err(|e| Error::Daemon(format!("failed to set write timeout: {}", e)))?;
 
-        Ok(DaemonClient { stream })
+        Ok(DaemonClient {
+            stream,
+            encoding: Encoding::Json,
+        })
+    }
+
+    /// Write requests in `encoding`; the daemon answers in the same one.
+    /// Only use an encoding [`negotiate_encoding`] returned.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
     }
 
     /// Send a request and receive a response.
     fn request(&mut self, request: DaemonRequest) -> Result<DaemonResponse> {
-        framing::write_message(&mut self.stream, &request)?;
+        let _span = tracing::info_span!("ipc", request = request.name()).entered();
+        framing::write_message_as(&mut self.stream, &request, self.encoding)?;
         framing::read_message(&mut self.stream).map_err(Into::into)
     }
 
@@ -48,7 +62,7 @@ impl DaemonClient {
     pub fn query(&mut self, op: QueryOp) -> Result<QueryResult> {
         match self.request(DaemonRequest::Query(op))? {
             DaemonResponse::QueryResult(result) => Ok(result),
-            DaemonResponse::Error { message } => Err(Error::Daemon(message)),
+            DaemonResponse::Error { message, code } => Err(daemon_error(message, code)),
             other => Err(Error::Daemon(format!("unexpected response: {:?}", other))),
         }
     }
@@ -57,8 +71,56 @@ impl DaemonClient {
     pub fn mutate(&mut self, op: MutateOp) -> Result<MutateResult> {
         match self.request(DaemonRequest::Mutate(op))? {
             DaemonResponse::MutateResult(result) => Ok(result),
-            DaemonResponse::Error { message } => Err(Error::Daemon(message)),
+            DaemonResponse::Error { message, code } => Err(daemon_error(message, code)),
             other => Err(Error::Daemon(format!("unexpected response: {:?}", other))),
         }
     }
+
+    /// Queue an issue hook to run in the background.
+    pub fn enqueue_hook(&mut self, job: HookJob) -> Result<()> {
+        match self.request(DaemonRequest::EnqueueHook { job })? {
+            DaemonResponse::HookQueued => Ok(()),
+            DaemonResponse::Error { message, code } => Err(daemon_error(message, code)),
+            other => Err(Error::Daemon(format!("unexpected response: {:?}", other))),
+        }
+    }
+
+    /// Have the daemon check the project at `work_dir` for stale issues.
+    pub fn watch_stale(&mut self, work_dir: &Path) -> Result<()> {
+        let request = DaemonRequest::WatchStale {
+            work_dir: work_dir.to_path_buf(),
+        };
+        match self.request(request)? {
+            DaemonResponse::WatchingStale => Ok(()),
+            DaemonResponse::Error { message, code } => Err(daemon_error(message, code)),
+            other => Err(Error::Daemon(format!("unexpected response: {:?}", other))),
+        }
+    }
+}
+
+/// The encoding to talk to the daemon at `socket_path` in, from a Hello
+/// handshake. JSON when the daemon predates CBOR or does not answer.
+pub fn negotiate_encoding(socket_path: &Path) -> Encoding {
+    let hello = DaemonRequest::Hello {
+        version: super::CLI_VERSION.to_string(),
+        encodings: Encoding::ALL.to_vec(),
+    };
+    let response = DaemonClient::connect(socket_path).and_then(|mut client| client.request(hello));
+    match response {
+        Ok(DaemonResponse::Hello { encodings, .. }) => Encoding::negotiate(&encodings),
+        _ => Encoding::Json,
+    }
 }
+
+/// The error for a daemon error response: typed when the daemon sent a
+/// database error category, a plain daemon error otherwise.
+fn daemon_error(message: String, code: Option<DaemonDbError>) -> Error {
+    match code {
+        Some(kind) => Error::DaemonDb { kind, message },
+        None => Error::Daemon(message),
+    }
+}
+
+#[cfg(test)]
+#[path = "client_tests.rs"]
+mod tests;
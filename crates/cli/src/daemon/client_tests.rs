@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::error::ErrorCode;
+use yare::parameterized;
+
+#[parameterized(
+    not_found = { DaemonDbError::NotFound, ErrorCode::NotFound },
+    conflict = { DaemonDbError::Conflict, ErrorCode::InvalidArgument },
+    constraint = { DaemonDbError::Constraint, ErrorCode::InvalidArgument },
+    busy = { DaemonDbError::Busy, ErrorCode::Database },
+    corrupt = { DaemonDbError::Corrupt, ErrorCode::CorruptedData },
+)]
+fn test_daemon_db_error_code(kind: DaemonDbError, expected: ErrorCode) {
+    let err = daemon_error("issue not found: prj-a1".to_string(), Some(kind));
+    assert_eq!(err.code(), expected);
+    assert_eq!(err.to_string(), "issue not found: prj-a1");
+}
+
+#[test]
+fn test_daemon_error_without_code_stays_a_daemon_error() {
+    let err = daemon_error("queue full".to_string(), None);
+    assert!(matches!(err, Error::Daemon(_)));
+    assert_eq!(err.code(), ErrorCode::Daemon);
+}
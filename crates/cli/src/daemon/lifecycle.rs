@@ -15,14 +15,15 @@ use std::time::Duration;
 
 use crate::error::{Error, Result};
 
-use wk_ipc::{framing, DaemonRequest, DaemonResponse, DaemonStatus};
+use wk_ipc::{framing, stale, DaemonRequest, DaemonResponse, DaemonStatus};
+
+pub use wk_ipc::stale::process_alive;
 
 /// Socket filename within daemon directory.
 const SOCKET_NAME: &str = "daemon.sock";
 /// PID filename within daemon directory.
 const PID_NAME: &str = "daemon.pid";
 /// Lock filename for single instance guarantee.
-#[cfg(test)]
 const LOCK_NAME: &str = "daemon.lock";
 
 /// Information about a running daemon.
@@ -43,7 +44,6 @@ pub fn get_pid_path(daemon_dir: &Path) -> PathBuf {
 }
 
 /// Get the lock file path for the given daemon directory.
-#[cfg(test)]
 pub fn get_lock_path(daemon_dir: &Path) -> PathBuf {
     daemon_dir.join(LOCK_NAME)
 }
@@ -51,7 +51,8 @@ pub fn get_lock_path(daemon_dir: &Path) -> PathBuf {
 /// Detect if a daemon is running for the given daemon directory.
 ///
 /// Returns Some(DaemonInfo) if a daemon is running and responding,
-/// None otherwise. Cleans up stale PID/socket files if found.
+/// None otherwise. Cleans up stale PID/socket files if found, but never
+/// those of a daemon that holds the lock (it may still be starting up).
 pub fn detect_daemon(daemon_dir: &Path) -> Result<Option<DaemonInfo>> {
     let socket_path = get_socket_path(daemon_dir);
     let pid_path = get_pid_path(daemon_dir);
@@ -59,9 +60,7 @@ pub fn detect_daemon(daemon_dir: &Path) -> Result<Option<DaemonInfo>> {
     // Check if socket exists
     if !socket_path.exists() {
         // No socket, clean up stale PID file if it exists
-        if pid_path.exists() {
-            let _ = fs::remove_file(&pid_path);
-        }
+        take_over_stale(daemon_dir);
         return Ok(None);
     }
 
@@ -75,7 +74,7 @@ pub fn detect_daemon(daemon_dir: &Path) -> Result<Option<DaemonInfo>> {
             // Send ping request
             if framing::write_message(&mut stream, &DaemonRequest::Ping).is_err() {
                 // Failed to write, daemon is dead
-                cleanup_stale_files(daemon_dir);
+                take_over_stale(daemon_dir);
                 return Ok(None);
             }
 
@@ -93,14 +92,14 @@ pub fn detect_daemon(daemon_dir: &Path) -> Result<Option<DaemonInfo>> {
                 }
                 _ => {
                     // Unexpected response or error
-                    cleanup_stale_files(daemon_dir);
+                    take_over_stale(daemon_dir);
                     Ok(None)
                 }
             }
         }
         Err(_) => {
             // Cannot connect, clean up stale files
-            cleanup_stale_files(daemon_dir);
+            take_over_stale(daemon_dir);
             Ok(None)
         }
     }
@@ -123,7 +122,9 @@ pub fn get_daemon_status(daemon_dir: &Path) -> Result<Option<DaemonStatus>> {
 
             match framing::read_message(&mut stream)? {
                 DaemonResponse::Status(status) => Ok(Some(status)),
-                DaemonResponse::Error { message } => Err(Error::Io(std::io::Error::other(message))),
+                DaemonResponse::Error { message, .. } => {
+                    Err(Error::Io(std::io::Error::other(message)))
+                }
                 _ => Err(Error::Io(std::io::Error::other(
                     "unexpected response".to_string(),
                 ))),
@@ -131,12 +132,41 @@ pub fn get_daemon_status(daemon_dir: &Path) -> Result<Option<DaemonStatus>> {
         }
         Err(e) => {
             // Cannot connect
-            cleanup_stale_files(daemon_dir);
+            take_over_stale(daemon_dir);
             Err(Error::Io(e))
         }
     }
 }
 
+/// Ask the daemon for its version via the Hello handshake.
+///
+/// Returns None if no daemon socket exists.
+pub fn get_daemon_version(daemon_dir: &Path) -> Result<Option<String>> {
+    let socket_path = get_socket_path(daemon_dir);
+
+    if !socket_path.exists() {
+        return Ok(None);
+    }
+
+    let mut stream = UnixStream::connect(&socket_path)?;
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(2)));
+
+    let hello = DaemonRequest::Hello {
+        version: CLI_VERSION.to_string(),
+        encodings: framing::Encoding::ALL.to_vec(),
+    };
+    framing::write_message(&mut stream, &hello)?;
+
+    match framing::read_message(&mut stream)? {
+        DaemonResponse::Hello { version, .. } => Ok(Some(version)),
+        DaemonResponse::Error { message, .. } => Err(Error::Io(std::io::Error::other(message))),
+        _ => Err(Error::Io(std::io::Error::other(
+            "unexpected response".to_string(),
+        ))),
+    }
+}
+
 /// Send a shutdown request to the daemon.
 fn stop_daemon(daemon_dir: &Path) -> Result<()> {
     let socket_path = get_socket_path(daemon_dir);
@@ -155,7 +185,7 @@ fn stop_daemon(daemon_dir: &Path) -> Result<()> {
 
     match framing::read_message(&mut stream)? {
         DaemonResponse::ShuttingDown => Ok(()),
-        DaemonResponse::Error { message } => Err(Error::Io(std::io::Error::other(message))),
+        DaemonResponse::Error { message, .. } => Err(Error::Io(std::io::Error::other(message))),
         _ => Err(Error::Io(std::io::Error::other(
             "unexpected response".to_string(),
         ))),
@@ -163,7 +193,7 @@ fn stop_daemon(daemon_dir: &Path) -> Result<()> {
 }
 
 /// Find the wokd binary.
-fn find_wokd_binary() -> Result<PathBuf> {
+pub(crate) fn find_wokd_binary() -> Result<PathBuf> {
     // 1. Check WOK_DAEMON_BINARY env var
     if let Some(path) = crate::env::daemon_binary() {
         return Ok(path);
@@ -191,6 +221,11 @@ pub fn spawn_daemon(daemon_dir: &Path) -> Result<DaemonInfo> {
         return Ok(info);
     }
 
+    // A new wokd could not take the lock from a daemon that still holds it
+    if let Some(info) = wait_for_lock_holder(daemon_dir)? {
+        return Ok(info);
+    }
+
     // Ensure daemon directory exists
     fs::create_dir_all(daemon_dir)?;
 
@@ -255,8 +290,51 @@ pub fn spawn_daemon(daemon_dir: &Path) -> Result<DaemonInfo> {
     ))
 }
 
+/// Wait for the daemon holding the lock of `daemon_dir` to answer.
+///
+/// Returns None once no daemon holds the lock, and fails when the holder
+/// does not answer in time (it is hung, or was stopped with SIGSTOP).
+fn wait_for_lock_holder(daemon_dir: &Path) -> Result<Option<DaemonInfo>> {
+    for _ in 0..150 {
+        if !lock_held(daemon_dir) {
+            return Ok(None);
+        }
+        if let Some(info) = detect_daemon(daemon_dir)? {
+            return Ok(Some(info));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let holder = match read_pid_file(&get_pid_path(daemon_dir)) {
+        Some(pid) => format!("daemon (PID {})", pid),
+        None => "daemon".to_string(),
+    };
+    Err(Error::Daemon(format!(
+        "{} holds {} but is not answering\n  hint: run `wok daemon restart`",
+        holder,
+        get_lock_path(daemon_dir).display()
+    )))
+}
+
+/// Remove the pid file and socket left by a daemon that did not shut down
+/// cleanly, returning why they were judged stale.
+///
+/// Does nothing while a daemon holds the lock: the kernel releases the
+/// lock of a killed daemon, so only a live (if slow) daemon holds it.
+pub fn take_over_stale(daemon_dir: &Path) -> Option<String> {
+    if lock_held(daemon_dir) {
+        return None;
+    }
+    let pid_path = get_pid_path(daemon_dir);
+    let socket_path = get_socket_path(daemon_dir);
+    let reason = stale::stale_reason(&pid_path, &socket_path)?;
+    tracing::warn!("removing stale daemon files: {}", reason);
+    cleanup_stale_files(daemon_dir);
+    Some(reason)
+}
+
 /// Clean up stale socket and PID files.
-fn cleanup_stale_files(daemon_dir: &Path) {
+pub fn cleanup_stale_files(daemon_dir: &Path) {
     let socket_path = get_socket_path(daemon_dir);
     let pid_path = get_pid_path(daemon_dir);
 
@@ -264,15 +342,34 @@ fn cleanup_stale_files(daemon_dir: &Path) {
     let _ = fs::remove_file(&pid_path);
 }
 
+/// Whether a daemon holds the single-instance lock of `daemon_dir`.
+///
+/// The lock is released when its holder exits, however it exits, so this
+/// is the one signal a SIGKILLed daemon cannot leave behind.
+pub fn lock_held(daemon_dir: &Path) -> bool {
+    use fs2::FileExt;
+
+    let lock_path = get_lock_path(daemon_dir);
+    let Ok(file) = fs::File::open(&lock_path) else {
+        return false;
+    };
+    match file.try_lock_exclusive() {
+        Ok(()) => {
+            let _ = FileExt::unlock(&file);
+            false
+        }
+        Err(_) => true,
+    }
+}
+
 /// Read PID from the PID file.
-fn read_pid_file(pid_path: &Path) -> Option<u32> {
+pub fn read_pid_file(pid_path: &Path) -> Option<u32> {
     fs::read_to_string(pid_path)
         .ok()
         .and_then(|s| s.trim().parse().ok())
 }
 
 /// CLI version for handshake.
-#[cfg(test)]
 pub const CLI_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Stop the daemon forcefully if graceful shutdown fails.
@@ -316,12 +413,8 @@ fn wait_for_process_exit(pid: u32, timeout: Duration) {
     let start = std::time::Instant::now();
 
     while start.elapsed() < timeout {
-        let result = Command::new("kill").arg("-0").arg(pid.to_string()).output();
-
-        match result {
-            Ok(output) if !output.status.success() => return,
-            Err(_) => return,
-            _ => {}
+        if !process_alive(pid) {
+            return;
         }
         std::thread::sleep(Duration::from_millis(50));
     }
@@ -123,6 +123,12 @@ fn test_get_daemon_status_not_running() {
     assert!(result.is_none());
 }
 
+#[test]
+fn test_get_daemon_version_not_running() {
+    let dir = tempdir().unwrap();
+    assert!(get_daemon_version(dir.path()).unwrap().is_none());
+}
+
 #[test]
 fn test_cli_version_is_set() {
     // CLI_VERSION should be set from Cargo.toml
@@ -157,3 +163,108 @@ fn test_stop_daemon_forcefully_cleans_stale_files() {
     assert!(!socket_path.exists());
     assert!(!pid_path.exists());
 }
+
+#[test]
+fn test_lock_held_follows_the_lock() {
+    use fs2::FileExt;
+
+    let dir = tempdir().unwrap();
+    assert!(!lock_held(dir.path()));
+
+    let lock = std::fs::File::create(get_lock_path(dir.path())).unwrap();
+    assert!(!lock_held(dir.path()));
+    lock.lock_exclusive().unwrap();
+    assert!(lock_held(dir.path()));
+    FileExt::unlock(&lock).unwrap();
+    assert!(!lock_held(dir.path()));
+}
+
+#[test]
+fn test_process_alive() {
+    assert!(process_alive(std::process::id()));
+
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    assert!(!process_alive(pid));
+}
+
+// Unclean shutdown tests
+
+/// A PID no process has: that of a child that has exited.
+fn dead_pid() -> u32 {
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    pid
+}
+
+#[test]
+fn test_take_over_stale_after_sigkill() {
+    let dir = tempdir().unwrap();
+    let pid = dead_pid();
+    // What a SIGKILLed daemon leaves: an unlocked lock file, pid file, socket
+    std::fs::write(get_lock_path(dir.path()), "").unwrap();
+    std::fs::write(get_pid_path(dir.path()), pid.to_string()).unwrap();
+    std::fs::write(get_socket_path(dir.path()), "").unwrap();
+
+    let reason = take_over_stale(dir.path());
+
+    assert_eq!(
+        reason,
+        Some(format!("PID {} in the pid file is not running", pid))
+    );
+    assert!(!get_pid_path(dir.path()).exists());
+    assert!(!get_socket_path(dir.path()).exists());
+    assert_eq!(take_over_stale(dir.path()), None);
+}
+
+#[test]
+fn test_take_over_stale_detects_reused_pid() {
+    let dir = tempdir().unwrap();
+    std::fs::write(get_pid_path(dir.path()), std::process::id().to_string()).unwrap();
+
+    let reason = take_over_stale(dir.path()).unwrap();
+
+    assert!(reason.ends_with("(PID reused)"), "{}", reason);
+    assert!(!get_pid_path(dir.path()).exists());
+}
+
+#[test]
+fn test_detect_daemon_spares_lock_holder_files() {
+    use fs2::FileExt;
+
+    let dir = tempdir().unwrap();
+    let lock = std::fs::File::create(get_lock_path(dir.path())).unwrap();
+    lock.lock_exclusive().unwrap();
+    // A daemon starting up has written its pid file but not bound its socket
+    std::fs::write(get_pid_path(dir.path()), std::process::id().to_string()).unwrap();
+
+    assert!(detect_daemon(dir.path()).unwrap().is_none());
+    assert_eq!(take_over_stale(dir.path()), None);
+    assert!(get_pid_path(dir.path()).exists());
+
+    // Nor does a socket that cannot be connected to yet
+    std::fs::write(get_socket_path(dir.path()), "").unwrap();
+    assert!(detect_daemon(dir.path()).unwrap().is_none());
+    assert!(get_socket_path(dir.path()).exists());
+}
+
+#[test]
+fn test_spawn_daemon_reports_unanswering_lock_holder() {
+    use fs2::FileExt;
+
+    let dir = tempdir().unwrap();
+    let lock = std::fs::File::create(get_lock_path(dir.path())).unwrap();
+    lock.lock_exclusive().unwrap();
+    std::fs::write(get_pid_path(dir.path()), "4242").unwrap();
+
+    let err = spawn_daemon(dir.path()).unwrap_err();
+
+    assert!(
+        err.to_string().contains("daemon (PID 4242) holds"),
+        "{}",
+        err
+    );
+    assert!(err.to_string().contains("wok daemon restart"), "{}", err);
+}
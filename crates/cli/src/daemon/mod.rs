@@ -8,11 +8,15 @@
 
 mod client;
 mod lifecycle;
+pub mod service;
 
-pub use client::DaemonClient;
+pub use client::{negotiate_encoding, DaemonClient};
 pub use lifecycle::{
-    detect_daemon, get_daemon_status, get_socket_path, spawn_daemon, stop_daemon_forcefully,
+    cleanup_stale_files, detect_daemon, get_daemon_status, get_daemon_version, get_lock_path,
+    get_pid_path, get_socket_path, lock_held, process_alive, read_pid_file, spawn_daemon,
+    stop_daemon_forcefully, take_over_stale, CLI_VERSION,
 };
+pub use wk_ipc::framing::Encoding;
 pub use wk_ipc::{DependencyRef, MutateOp, MutateResult, QueryOp, QueryResult};
 
 #[cfg(test)]
@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Service manager units that keep wokd running across logins.
+//!
+//! On Linux this is a systemd unit, on macOS a launchd property list. A
+//! user install runs wokd in the user's session (`systemctl --user`,
+//! `~/Library/LaunchAgents`); a system install runs it at boot as the
+//! installing user, and needs root to write the unit.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// systemd unit name.
+pub const SYSTEMD_UNIT: &str = "wokd.service";
+/// launchd job label, also the plist's file stem.
+pub const LAUNCHD_LABEL: &str = "dev.wok.wokd";
+
+/// The service manager of this platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Manager {
+    Systemd,
+    Launchd,
+}
+
+impl Manager {
+    /// The manager for the platform wok was built for.
+    pub fn current() -> Result<Self> {
+        if cfg!(target_os = "macos") {
+            Ok(Manager::Launchd)
+        } else if cfg!(target_os = "linux") {
+            Ok(Manager::Systemd)
+        } else {
+            Err(Error::ServiceUnsupported {
+                os: std::env::consts::OS.to_string(),
+            })
+        }
+    }
+}
+
+/// A wokd unit: where it goes and what it runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Service {
+    pub manager: Manager,
+    /// Installed for the current user rather than system-wide.
+    pub user: bool,
+    pub unit_path: PathBuf,
+    /// Absolute path of the wokd binary.
+    pub wokd: PathBuf,
+    pub state_dir: PathBuf,
+    /// Account a system unit runs as.
+    pub run_as: Option<String>,
+}
+
+impl Service {
+    /// A unit for `manager`; `config_dir` is where systemd user units live
+    /// under (`~/.config`) and `home` the user's home directory.
+    pub fn new(
+        manager: Manager,
+        user: bool,
+        config_dir: &Path,
+        home: &Path,
+        wokd: PathBuf,
+        state_dir: PathBuf,
+    ) -> Self {
+        let unit_path = match (manager, user) {
+            (Manager::Systemd, true) => config_dir.join("systemd/user").join(SYSTEMD_UNIT),
+            (Manager::Systemd, false) => Path::new("/etc/systemd/system").join(SYSTEMD_UNIT),
+            (Manager::Launchd, true) => home
+                .join("Library/LaunchAgents")
+                .join(format!("{}.plist", LAUNCHD_LABEL)),
+            (Manager::Launchd, false) => {
+                Path::new("/Library/LaunchDaemons").join(format!("{}.plist", LAUNCHD_LABEL))
+            }
+        };
+        Service {
+            manager,
+            user,
+            unit_path,
+            wokd,
+            state_dir,
+            run_as: if user { None } else { crate::env::user() },
+        }
+    }
+
+    /// The unit for this platform, this user, and this CLI's wokd and
+    /// state directory. wokd is not looked up on `PATH` until
+    /// [`Service::resolve_wokd`], so removing a unit works without it.
+    pub fn detect(user: bool) -> Result<Self> {
+        let home = dirs::home_dir()
+            .ok_or_else(|| Error::Daemon("cannot find the home directory".to_string()))?;
+        let config_dir = crate::env::xdg_config_home().unwrap_or_else(|| home.join(".config"));
+        Ok(Service::new(
+            Manager::current()?,
+            user,
+            &config_dir,
+            &home,
+            super::lifecycle::find_wokd_binary()?,
+            crate::config::wok_state_dir(),
+        ))
+    }
+
+    /// Make `wokd` absolute, searching `PATH`; a unit cannot rely on the
+    /// installing shell's `PATH`.
+    pub fn resolve_wokd(mut self) -> Result<Self> {
+        self.wokd = absolute(&self.wokd)?;
+        Ok(self)
+    }
+
+    /// Short description for status output.
+    pub fn kind(&self) -> &'static str {
+        match (self.manager, self.user) {
+            (Manager::Systemd, true) => "systemd user unit",
+            (Manager::Systemd, false) => "systemd system unit",
+            (Manager::Launchd, true) => "launchd agent",
+            (Manager::Launchd, false) => "launchd daemon",
+        }
+    }
+
+    pub fn installed(&self) -> bool {
+        self.unit_path.exists()
+    }
+
+    /// The unit file's contents.
+    pub fn render(&self) -> String {
+        match self.manager {
+            Manager::Systemd => self.render_systemd(),
+            Manager::Launchd => self.render_launchd(),
+        }
+    }
+
+    fn render_systemd(&self) -> String {
+        let mut unit = String::new();
+        unit.push_str("[Unit]\nDescription=wok daemon (wokd)\n\n[Service]\n");
+        unit.push_str(&format!(
+            "ExecStart={} --state-dir {}\n",
+            systemd_quote(&self.wokd),
+            systemd_quote(&self.state_dir)
+        ));
+        if let Some(user) = &self.run_as {
+            unit.push_str(&format!("User={}\n", user));
+        }
+        // wokd exits 0 on `wok daemon stop`; only crashes restart it
+        unit.push_str("Restart=on-failure\nRestartSec=5\n\n[Install]\n");
+        let target = if self.user {
+            "default.target"
+        } else {
+            "multi-user.target"
+        };
+        unit.push_str(&format!("WantedBy={}\n", target));
+        unit
+    }
+
+    fn render_launchd(&self) -> String {
+        let mut plist = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+            "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+            "<plist version=\"1.0\">\n<dict>\n",
+        ));
+        plist.push_str(&format!(
+            "  <key>Label</key>\n  <string>{}</string>\n",
+            LAUNCHD_LABEL
+        ));
+        plist.push_str("  <key>ProgramArguments</key>\n  <array>\n");
+        for arg in [
+            self.wokd.to_string_lossy(),
+            "--state-dir".into(),
+            self.state_dir.to_string_lossy(),
+        ] {
+            plist.push_str(&format!("    <string>{}</string>\n", xml_escape(&arg)));
+        }
+        plist.push_str("  </array>\n");
+        if let Some(user) = &self.run_as {
+            plist.push_str(&format!(
+                "  <key>UserName</key>\n  <string>{}</string>\n",
+                xml_escape(user)
+            ));
+        }
+        plist.push_str(concat!(
+            "  <key>RunAtLoad</key>\n  <true/>\n",
+            "  <key>KeepAlive</key>\n",
+            "  <dict>\n    <key>SuccessfulExit</key>\n    <false/>\n  </dict>\n",
+            "</dict>\n</plist>\n",
+        ));
+        plist
+    }
+
+    /// Commands that load the installed unit and start it.
+    pub fn enable_commands(&self) -> Vec<Vec<String>> {
+        let mut commands = self.reload_commands();
+        commands.push(match self.manager {
+            Manager::Systemd => self.systemctl(&["enable", "--now", SYSTEMD_UNIT]),
+            Manager::Launchd => self.launchctl("load"),
+        });
+        commands
+    }
+
+    /// Commands that make the manager reread its unit files; launchd reads
+    /// a plist when it is loaded, so it needs none.
+    pub fn reload_commands(&self) -> Vec<Vec<String>> {
+        match self.manager {
+            Manager::Systemd => vec![self.systemctl(&["daemon-reload"])],
+            Manager::Launchd => Vec::new(),
+        }
+    }
+
+    /// Commands that stop the unit and unload it, before it is removed.
+    pub fn disable_commands(&self) -> Vec<Vec<String>> {
+        match self.manager {
+            Manager::Systemd => vec![self.systemctl(&["disable", "--now", SYSTEMD_UNIT])],
+            Manager::Launchd => vec![self.launchctl("unload")],
+        }
+    }
+
+    fn systemctl(&self, args: &[&str]) -> Vec<String> {
+        let mut command = vec!["systemctl".to_string()];
+        if self.user {
+            command.push("--user".to_string());
+        }
+        command.extend(args.iter().map(|a| a.to_string()));
+        command
+    }
+
+    fn launchctl(&self, verb: &str) -> Vec<String> {
+        vec![
+            "launchctl".to_string(),
+            verb.to_string(),
+            "-w".to_string(),
+            self.unit_path.display().to_string(),
+        ]
+    }
+}
+
+/// Run each of `commands`, failing on the first that does not succeed.
+pub fn run_commands(commands: &[Vec<String>]) -> Result<()> {
+    for command in commands {
+        let Some((program, args)) = command.split_first() else {
+            continue;
+        };
+        let output = std::process::Command::new(program)
+            .args(args)
+            .output()
+            .map_err(|e| Error::Daemon(format!("failed to run {}: {}", program, e)))?;
+        if !output.status.success() {
+            return Err(Error::Daemon(format!(
+                "`{}` failed: {}",
+                command.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// `wokd` as an absolute path, searching `PATH` when it is a bare name.
+fn absolute(wokd: &Path) -> Result<PathBuf> {
+    if wokd.is_absolute() {
+        return Ok(wokd.to_path_buf());
+    }
+    crate::env::path()
+        .iter()
+        .flat_map(std::env::split_paths)
+        .map(|dir| dir.join(wokd))
+        .find(|candidate| candidate.is_file())
+        .ok_or_else(|| {
+            Error::Daemon(
+                "cannot find wokd\n  hint: install it next to wok or set WOK_DAEMON_BINARY"
+                    .to_string(),
+            )
+        })
+}
+
+/// A path as one systemd command-line word.
+fn systemd_quote(path: &Path) -> String {
+    // `%` starts a unit specifier
+    let path = path.to_string_lossy().replace('%', "%%");
+    if path.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\') {
+        format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        path
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+#[path = "service_tests.rs"]
+mod tests;
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+fn service(manager: Manager, user: bool) -> Service {
+    let mut service = Service::new(
+        manager,
+        user,
+        Path::new("/home/ann/.config"),
+        Path::new("/home/ann"),
+        PathBuf::from("/opt/wok/bin/wokd"),
+        PathBuf::from("/home/ann/.local/state/wok"),
+    );
+    service.run_as = (!user).then(|| "ann".to_string());
+    service
+}
+
+#[test]
+fn unit_paths_follow_manager_and_scope() {
+    let paths: Vec<PathBuf> = [
+        (Manager::Systemd, true),
+        (Manager::Systemd, false),
+        (Manager::Launchd, true),
+        (Manager::Launchd, false),
+    ]
+    .into_iter()
+    .map(|(manager, user)| service(manager, user).unit_path)
+    .collect();
+
+    assert_eq!(
+        paths,
+        [
+            PathBuf::from("/home/ann/.config/systemd/user/wokd.service"),
+            PathBuf::from("/etc/systemd/system/wokd.service"),
+            PathBuf::from("/home/ann/Library/LaunchAgents/dev.wok.wokd.plist"),
+            PathBuf::from("/Library/LaunchDaemons/dev.wok.wokd.plist"),
+        ]
+    );
+}
+
+#[test]
+fn systemd_user_unit_runs_wokd_in_the_session() {
+    let unit = service(Manager::Systemd, true).render();
+
+    assert!(unit.contains("ExecStart=/opt/wok/bin/wokd --state-dir /home/ann/.local/state/wok\n"));
+    assert!(unit.contains("Restart=on-failure\n"));
+    assert!(unit.contains("WantedBy=default.target\n"));
+    assert!(!unit.contains("User="));
+}
+
+#[test]
+fn systemd_system_unit_runs_as_the_installing_user() {
+    let unit = service(Manager::Systemd, false).render();
+
+    assert!(unit.contains("User=ann\n"));
+    assert!(unit.contains("WantedBy=multi-user.target\n"));
+}
+
+#[test]
+fn launchd_plist_escapes_arguments() {
+    let mut service = service(Manager::Launchd, false);
+    service.state_dir = PathBuf::from("/Users/ann/R&D <wok>");
+
+    let plist = service.render();
+
+    assert!(plist.contains("<string>dev.wok.wokd</string>"));
+    assert!(plist.contains("<string>/Users/ann/R&amp;D &lt;wok&gt;</string>"));
+    assert!(plist.contains("<key>UserName</key>\n  <string>ann</string>"));
+    assert!(plist.contains("<key>SuccessfulExit</key>\n    <false/>"));
+}
+
+#[test]
+fn systemd_quote_handles_spaces_and_specifiers() {
+    assert_eq!(systemd_quote(Path::new("/opt/wokd")), "/opt/wokd");
+    assert_eq!(systemd_quote(Path::new("/opt/50%/wokd")), "/opt/50%%/wokd");
+    assert_eq!(
+        systemd_quote(Path::new("/Application Support/wokd")),
+        "\"/Application Support/wokd\""
+    );
+}
+
+#[test]
+fn commands_target_the_scope() {
+    let user = service(Manager::Systemd, true);
+    assert_eq!(
+        user.enable_commands(),
+        [
+            vec!["systemctl", "--user", "daemon-reload"],
+            vec!["systemctl", "--user", "enable", "--now", "wokd.service"],
+        ]
+    );
+    assert_eq!(
+        service(Manager::Systemd, false).disable_commands(),
+        [vec!["systemctl", "disable", "--now", "wokd.service"]]
+    );
+
+    let agent = service(Manager::Launchd, true);
+    assert_eq!(
+        agent.enable_commands(),
+        [vec![
+            "launchctl",
+            "load",
+            "-w",
+            "/home/ann/Library/LaunchAgents/dev.wok.wokd.plist"
+        ]]
+    );
+    assert!(agent.reload_commands().is_empty());
+}
@@ -32,7 +32,7 @@ pub fn priority_from_tags(tags: &[String]) -> u8 {
 }
 
 /// Parse priority value (numeric 0-4 or named).
-fn parse_priority_value(value: &str) -> Option<u8> {
+pub fn parse_priority_value(value: &str) -> Option<u8> {
     match value {
         "0" | "highest" => Some(0),
         "1" | "high" => Some(1),
@@ -1,23 +1,267 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use crate::models::{Action, Event, Issue, Link, Note, Status};
+use std::io::IsTerminal;
+use std::sync::{PoisonError, RwLock};
+
+use crate::cli::ListColumn;
+use crate::colors::LabelPalette;
+use crate::config::{issue_url, Config, HyperlinkMode};
+use crate::estimate::estimate_from_tags;
+use crate::models::{Action, Event, Issue, Link, LinkMeta, LinkRel, Note, NoteKind, Status};
+use crate::schema::show::LinkDetails;
+use crate::t;
 
 /// Maximum line width for wrapped text content (excluding 4-space indent).
 const WRAP_WIDTH: usize = 96;
 
+/// Titles are never squeezed narrower than this to fit the terminal.
+const MIN_TITLE_WIDTH: usize = 10;
+
+/// Width to fit list output into: `COLUMNS` if set, else the terminal's
+/// width when stdout is a terminal. `None` means don't truncate.
+pub fn terminal_width() -> Option<usize> {
+    if let Some(columns) = crate::env::columns() {
+        return Some(columns);
+    }
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    crossterm::terminal::size()
+        .ok()
+        .map(|(cols, _)| usize::from(cols))
+        .filter(|&cols| cols > 0)
+}
+
+/// Cut text to at most `width` characters, ending in `…` when shortened.
+///
+/// Hyperlink escape sequences take no width, and a link cut short is closed.
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    if visible_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut truncated = String::new();
+    let mut shown = 0;
+    let mut linked = false;
+    let mut rest = text;
+    while shown < width - 1 {
+        if let Some((sequence, url, after)) = split_osc8(rest) {
+            truncated.push_str(sequence);
+            linked = !url.is_empty();
+            rest = after;
+            continue;
+        }
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+        truncated.push(c);
+        shown += 1;
+        rest = &rest[c.len_utf8()..];
+    }
+    if linked {
+        truncated.push_str(&osc8_sequence(""));
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// Characters `text` takes on screen, not counting hyperlink sequences.
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        match split_osc8(rest) {
+            Some((_, _, after)) => rest = after,
+            None => {
+                width += 1;
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    width
+}
+
+/// Start of an OSC 8 hyperlink sequence, followed by its URL.
+const OSC8_START: &str = "\x1b]8;;";
+
+/// String terminator ending an OSC 8 sequence.
+const OSC8_END: &str = "\x1b\\";
+
+/// The OSC 8 sequence opening a link to `url`, or closing one when empty.
+fn osc8_sequence(url: &str) -> String {
+    format!("{}{}{}", OSC8_START, url, OSC8_END)
+}
+
+/// Split an OSC 8 sequence off the start of `text`: the sequence, its URL,
+/// and what follows.
+fn split_osc8(text: &str) -> Option<(&str, &str, &str)> {
+    let url_and_rest = text.strip_prefix(OSC8_START)?;
+    let end = url_and_rest.find(OSC8_END)?;
+    let len = OSC8_START.len() + end + OSC8_END.len();
+    Some((&text[..len], &url_and_rest[..end], &text[len..]))
+}
+
+/// `text` as an OSC 8 hyperlink to `url`.
+pub fn osc8(text: &str, url: &str) -> String {
+    format!("{}{}{}", osc8_sequence(url), text, osc8_sequence(""))
+}
+
+/// Whether and where printed issue IDs and links point, for the rest of the
+/// process; see [`set_hyperlinks`].
+static HYPERLINKS: RwLock<Hyperlinks> = RwLock::new(Hyperlinks::OFF);
+
+/// Whether output carries OSC 8 hyperlinks, and where issue IDs lead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hyperlinks {
+    pub enabled: bool,
+    /// Page for an issue, with `{id}` and `{prefix}` placeholders.
+    pub issue_url: Option<String>,
+}
+
+impl Hyperlinks {
+    /// Plain text everywhere.
+    pub const OFF: Hyperlinks = Hyperlinks {
+        enabled: false,
+        issue_url: None,
+    };
+
+    /// The `[hyperlinks]` settings as they apply to this process.
+    ///
+    /// `FORCE_HYPERLINK=1` (or `0`) takes precedence over `hyperlinks.mode`;
+    /// in `auto` mode, stdout must be a terminal known to support them.
+    pub fn from_config(config: &Config) -> Self {
+        let enabled =
+            crate::env::force_hyperlink().unwrap_or_else(|| match config.hyperlinks.mode {
+                HyperlinkMode::Always => true,
+                HyperlinkMode::Never => false,
+                HyperlinkMode::Auto => {
+                    std::io::stdout().is_terminal()
+                        && TerminalHints::from_env().supports_hyperlinks()
+                }
+            });
+        let issue_url = config
+            .hyperlinks
+            .url
+            .clone()
+            .or_else(|| config.open.url.clone());
+        Hyperlinks { enabled, issue_url }
+    }
+
+    /// Issue `id`, linked to its page when there is one.
+    pub fn issue(&self, id: &str) -> String {
+        match &self.issue_url {
+            Some(template) if self.enabled => self.url(id, &issue_url(template, id)),
+            _ => id.to_string(),
+        }
+    }
+
+    /// `text`, linked to `url` when it is a web address.
+    pub fn url(&self, text: &str, url: &str) -> String {
+        let web = url.starts_with("http://") || url.starts_with("https://");
+        // A control character would end the sequence early
+        if self.enabled && web && !url.chars().any(char::is_control) {
+            osc8(text, url)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Apply hyperlink settings for the rest of the process.
+pub fn set_hyperlinks(hyperlinks: Hyperlinks) {
+    *HYPERLINKS.write().unwrap_or_else(PoisonError::into_inner) = hyperlinks;
+}
+
+/// Issue `id` as printed: a hyperlink to its page when output carries them.
+pub fn issue_link(id: &str) -> String {
+    HYPERLINKS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .issue(id)
+}
+
+/// `text` as printed: a hyperlink to `url` when output carries them.
+fn url_link(text: &str, url: &str) -> String {
+    HYPERLINKS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .url(text, url)
+}
+
+/// What the environment says about the terminal, for guessing whether it
+/// renders OSC 8 hyperlinks.
+#[derive(Debug, Clone, Default)]
+pub struct TerminalHints {
+    pub term: Option<String>,
+    pub program: Option<String>,
+    pub vte_version: Option<u32>,
+    pub konsole_version: Option<u32>,
+    pub windows_terminal: bool,
+}
+
+impl TerminalHints {
+    pub fn from_env() -> Self {
+        TerminalHints {
+            term: crate::env::term(),
+            program: crate::env::term_program(),
+            vte_version: crate::env::vte_version(),
+            konsole_version: crate::env::konsole_version(),
+            windows_terminal: crate::env::windows_terminal(),
+        }
+    }
+
+    /// Whether the terminal is one known to render hyperlinks. Unknown
+    /// terminals get plain text, since some print the sequences verbatim.
+    pub fn supports_hyperlinks(&self) -> bool {
+        let term = self.term.as_deref().unwrap_or_default();
+        if term == "dumb" {
+            return false;
+        }
+        let program = self.program.as_deref().unwrap_or_default();
+        self.windows_terminal
+            || self.vte_version.is_some_and(|v| v >= 5000)
+            || self.konsole_version.is_some_and(|v| v >= 201200)
+            || matches!(
+                program,
+                "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+            )
+            || [
+                "xterm-kitty",
+                "alacritty",
+                "foot",
+                "wezterm",
+                "xterm-ghostty",
+            ]
+            .iter()
+            .any(|known| term.starts_with(known))
+    }
+}
+
 /// Map issue status to semantic note section label.
 ///
 /// - `todo` → "Description" (requirements, context before work starts)
 /// - `in_progress` → "Progress" (updates during active work)
 /// - `done` → "Summary" (what was accomplished)
 /// - `closed` → "Close Reason" (why the issue was closed without completion)
-pub fn note_section_label(status: Status) -> &'static str {
+pub fn note_section_label(status: Status) -> String {
     match status {
-        Status::Todo => "Description",
-        Status::InProgress => "Progress",
-        Status::Done => "Summary",
-        Status::Closed => "Close Reason",
+        Status::Todo => t!("notes.section.description"),
+        Status::InProgress => t!("notes.section.progress"),
+        Status::Done => t!("notes.section.summary"),
+        Status::Closed => t!("notes.section.close_reason"),
+    }
+}
+
+/// Section label for notes of a given kind in show output.
+pub fn note_kind_section_label(kind: NoteKind) -> String {
+    match kind {
+        NoteKind::Decision => t!("notes.section.decisions"),
+        NoteKind::Blocker => t!("notes.section.blockers"),
+        NoteKind::Worklog => t!("notes.section.worklog"),
+        NoteKind::Handoff => t!("notes.section.handoff"),
     }
 }
 
@@ -68,9 +312,9 @@ pub fn wrap_text(content: &str, width: usize) -> String {
 
 /// Format a single note with metadata line and indented content.
 ///
-/// Output format:
+/// Output format, naming the writer when known:
 /// ```text
-///   2024-01-10 10:30
+///   2024-01-10 10:30 by alice
 ///     Content goes here, potentially
 ///     wrapped across multiple lines.
 /// ```
@@ -79,7 +323,11 @@ pub fn format_note(note: &Note) -> Vec<String> {
 
     // Metadata line: 2 spaces + timestamp
     let timestamp = note.created_at.format("%Y-%m-%d %H:%M");
-    lines.push(format!("  {}", timestamp));
+    lines.push(format!(
+        "  {}{}",
+        timestamp,
+        by_actor(note.actor.as_deref())
+    ));
 
     // Content: wrap if single line, then indent each line with 4 spaces
     let wrapped = wrap_text(&note.content, WRAP_WIDTH);
@@ -92,113 +340,254 @@ pub fn format_note(note: &Note) -> Vec<String> {
 
 /// Format a single issue line for list output
 pub fn format_issue_line(issue: &Issue) -> String {
+    format_issue_line_with_short_id(issue, None)
+}
+
+/// Format an issue line with its short ID alias after the full ID:
+/// `- [task] (todo) prj-a1b2 (prj-3): Title`
+pub fn format_issue_line_with_short_id(issue: &Issue, short_id: Option<&str>) -> String {
     let status_display = match &issue.assignee {
         Some(assignee) => format!("{}, @{}", issue.status, assignee),
         None => issue.status.to_string(),
     };
+    let alias = short_id
+        .map(|short| format!(" ({})", short))
+        .unwrap_or_default();
     format!(
-        "- [{}] ({}) {}: {}",
-        issue.issue_type, status_display, issue.id, issue.title
+        "- [{}] ({}) {}{}: {}",
+        issue.issue_type,
+        status_display,
+        issue_link(&issue.id),
+        alias,
+        issue.title
     )
 }
 
+/// One issue's data for `wok list --columns` output.
+pub struct IssueRow<'a> {
+    pub issue: &'a Issue,
+    pub short_id: Option<&'a str>,
+    pub labels: &'a [String],
+}
+
+impl IssueRow<'_> {
+    fn cell(&self, column: ListColumn) -> String {
+        match column {
+            ListColumn::Id => self.issue.id.clone(),
+            ListColumn::Short => self.short_id.unwrap_or_default().to_string(),
+            ListColumn::Type => self.issue.issue_type.to_string(),
+            ListColumn::Status => self.issue.status.to_string(),
+            ListColumn::Title => self.issue.title.clone(),
+            ListColumn::Assignee => self
+                .issue
+                .assignee
+                .as_deref()
+                .map(|a| format!("@{}", a))
+                .unwrap_or_default(),
+            ListColumn::Labels => self.labels.join(","),
+            ListColumn::Estimate => estimate_from_tags(self.labels)
+                .map(|e| e.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Format rows as aligned columns, two spaces apart.
+///
+/// With a `width`, the title column shrinks (down to a minimum) so each line
+/// fits; titles that don't fit end in `…`. Labels take their `palette` colors.
+pub fn format_issue_table(
+    rows: &[IssueRow<'_>],
+    columns: &[ListColumn],
+    width: Option<usize>,
+    palette: &LabelPalette,
+) -> Vec<String> {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| columns.iter().map(|&c| row.cell(c)).collect())
+        .collect();
+    let mut widths: Vec<usize> = (0..columns.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    if let (Some(width), Some(title)) =
+        (width, columns.iter().position(|&c| c == ListColumn::Title))
+    {
+        let others: usize = widths
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != title)
+            .map(|(_, w)| w + 2)
+            .sum();
+        widths[title] = widths[title].min(width.saturating_sub(others).max(MIN_TITLE_WIDTH));
+    }
+
+    let last = columns.len().saturating_sub(1);
+    rows.iter()
+        .zip(&cells)
+        .map(|(row, cells)| {
+            let line: Vec<String> = cells
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    let cell = truncate_to_width(cell, widths[i]);
+                    let padding = if i == last {
+                        String::new()
+                    } else {
+                        " ".repeat(widths[i].saturating_sub(cell.chars().count()))
+                    };
+                    // Color and link after measuring, so escape codes don't count as width
+                    let cell = match columns[i] {
+                        ListColumn::Labels => paint_labels(row.labels, palette),
+                        ListColumn::Id if cell == row.issue.id => issue_link(&cell),
+                        _ => cell,
+                    };
+                    format!("{}{}", cell, padding)
+                })
+                .collect();
+            line.join("  ").trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Labels joined with commas, each in its palette color.
+fn paint_labels(labels: &[String], palette: &LabelPalette) -> String {
+    labels
+        .iter()
+        .map(|label| palette.paint(label))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Non-blocking relations to other issues, shown after the dependencies.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Associations<'a> {
+    /// Issues linked with `relates-to`.
+    pub related: &'a [String],
+    /// Issues this one duplicates.
+    pub duplicate_of: &'a [String],
+    /// Issues marked as duplicates of this one.
+    pub duplicates: &'a [String],
+    /// Issues whose notes or description mention this one.
+    pub referenced_by: &'a [String],
+}
+
 /// Format issue details for show command
 #[allow(clippy::too_many_arguments)] // TODO(refactor): Consider using an options struct to bundle parameters
 pub fn format_issue_details(
     issue: &Issue,
     labels: &[String],
-    blockers: &[String],
+    blockers: &[ChainBlocker],
     blocking: &[String],
     parents: &[String],
     children: &[String],
+    associations: Associations<'_>,
     notes: &[(Status, Vec<Note>)],
-    links: &[Link],
+    links: &[LinkDetails],
     events: &[Event],
+    stale_for: Option<&str>,
 ) -> String {
     let mut output = Vec::new();
 
     // Header: [type] id
-    output.push(format!("[{}] {}", issue.issue_type, issue.id));
+    output.push(format!("[{}] {}", issue.issue_type, issue_link(&issue.id)));
 
     // Metadata on separate lines
-    output.push(format!("Title: {}", issue.title));
-    output.push(format!("Status: {}", issue.status));
+    output.push(t!("show.title", title = issue.title));
+    match stale_for {
+        Some(age) => output.push(t!("show.status_stale", status = issue.status, age = age)),
+        None => output.push(t!("show.status", status = issue.status)),
+    }
     if let Some(assignee) = &issue.assignee {
-        output.push(format!("Assignee: {}", assignee));
+        output.push(t!("show.assignee", assignee = assignee));
     }
-    output.push(format!(
-        "Created: {}",
-        issue.created_at.format("%Y-%m-%d %H:%M")
+    output.push(t!(
+        "show.created",
+        at = issue.created_at.format("%Y-%m-%d %H:%M")
     ));
-    output.push(format!(
-        "Updated: {}",
-        issue.updated_at.format("%Y-%m-%d %H:%M")
+    output.push(t!(
+        "show.updated",
+        at = issue.updated_at.format("%Y-%m-%d %H:%M")
     ));
 
     // Labels
     if !labels.is_empty() {
-        output.push(format!("Labels: {}", labels.join(", ")));
+        output.push(t!("show.labels", labels = labels.join(", ")));
     }
 
-    // Blocked by
-    if !blockers.is_empty() {
+    // Blocked by, indented by chain depth, then unresolved external issues
+    let external: Vec<&LinkDetails> = links.iter().filter(|l| is_external_blocker(l)).collect();
+    if !blockers.is_empty() || !external.is_empty() {
         output.push(String::new());
-        output.push("Blocked by:".to_string());
-        for id in blockers {
-            output.push(format!("  - {}", id));
+        output.push(t!("show.blocked_by"));
+        for blocker in blockers {
+            output.push(format_chain_blocker(blocker));
+        }
+        for details in external {
+            output.push(format!("  - {}", link_target(details)));
         }
     }
 
     // Blocks
     if !blocking.is_empty() {
         output.push(String::new());
-        output.push("Blocks:".to_string());
+        output.push(t!("show.blocks"));
         for id in blocking {
-            output.push(format!("  - {}", id));
+            output.push(format!("  - {}", issue_link(id)));
         }
     }
 
     // Tracked by
     if !parents.is_empty() {
         output.push(String::new());
-        output.push("Tracked by:".to_string());
+        output.push(t!("show.tracked_by"));
         for id in parents {
-            output.push(format!("  - {}", id));
+            output.push(format!("  - {}", issue_link(id)));
         }
     }
 
     // Tracks
     if !children.is_empty() {
         output.push(String::new());
-        output.push("Tracks:".to_string());
+        output.push(t!("show.tracks"));
         for id in children {
-            output.push(format!("  - {}", id));
+            output.push(format!("  - {}", issue_link(id)));
+        }
+    }
+
+    let sections = [
+        (t!("show.related"), associations.related),
+        (t!("show.duplicate_of"), associations.duplicate_of),
+        (t!("show.duplicates"), associations.duplicates),
+        // Mentioned in other issues' notes or descriptions
+        (t!("show.referenced_by"), associations.referenced_by),
+    ];
+    for (heading, ids) in sections {
+        if !ids.is_empty() {
+            output.push(String::new());
+            output.push(heading);
+            for id in ids {
+                output.push(format!("  - {}", issue_link(id)));
+            }
         }
     }
 
     // External links
     if !links.is_empty() {
         output.push(String::new());
-        output.push("Links:".to_string());
+        output.push(t!("show.links"));
         for link in links {
             output.push(format_link(link));
         }
     }
 
-    // Notes grouped by status with semantic labels
-    for (status, status_notes) in notes {
-        if !status_notes.is_empty() {
-            output.push(String::new());
-            output.push(format!("{}:", note_section_label(*status)));
-            for (i, note) in status_notes.iter().enumerate() {
-                // Add blank line between notes within a section
-                if i > 0 {
-                    output.push(String::new());
-                }
-                output.extend(format_note(note));
-            }
-        }
-    }
+    output.extend(format_note_sections(notes));
 
     // Event log (skip Created event since it's redundant with Created: line,
     // and skip Noted events at creation time since they appear in Description section)
@@ -216,7 +605,7 @@ pub fn format_issue_details(
         .collect();
     if !filtered_events.is_empty() {
         output.push(String::new());
-        output.push("Log:".to_string());
+        output.push(t!("show.log"));
         for event in filtered_events {
             output.push(format_event(event));
         }
@@ -225,8 +614,133 @@ pub fn format_issue_details(
     output.join("\n")
 }
 
+/// An issue in the "Blocked by" section of `wok show`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBlocker {
+    pub id: String,
+    /// Title and status, when known.
+    pub issue: Option<(String, Status)>,
+    /// 1 for a direct blocker, 2 for a blocker of a blocker, and so on.
+    pub depth: usize,
+    /// Open with no open blockers of its own: work on this first.
+    pub actionable: bool,
+}
+
+impl ChainBlocker {
+    /// A direct blocker known only by ID.
+    pub fn direct(id: &str) -> Self {
+        ChainBlocker {
+            id: id.to_string(),
+            issue: None,
+            depth: 1,
+            actionable: false,
+        }
+    }
+}
+
+fn format_chain_blocker(blocker: &ChainBlocker) -> String {
+    let indent = "  ".repeat(blocker.depth);
+    let id = issue_link(&blocker.id);
+    let Some((title, status)) = &blocker.issue else {
+        return format!("{}- {}", indent, id);
+    };
+    let mut line = format!("{}- {}: {} [{}]", indent, id, title, status);
+    if blocker.actionable {
+        line.push_str(&format!(" ← {}", t!("show.actionable")));
+    }
+    line
+}
+
+/// Format notes as labeled sections, each preceded by a blank line.
+///
+/// Plain notes are grouped by status with semantic labels; notes with a kind
+/// get their own sections (Decisions, Blockers, ...) regardless of status.
+pub fn format_note_sections(notes: &[(Status, Vec<Note>)]) -> Vec<String> {
+    note_sections(notes, format_note)
+}
+
+/// Like [`format_note_sections`], with each note's ID after its timestamp:
+/// what `wok note --edit` and `--delete` take.
+pub fn format_note_sections_with_ids(notes: &[(Status, Vec<Note>)]) -> Vec<String> {
+    note_sections(notes, |note| {
+        let mut lines = format_note(note);
+        lines[0].push_str(&format!("  #{}", note.id));
+        lines
+    })
+}
+
+fn note_sections(
+    notes: &[(Status, Vec<Note>)],
+    format: impl Fn(&Note) -> Vec<String> + Copy,
+) -> Vec<String> {
+    let mut output = Vec::new();
+
+    for (status, status_notes) in notes {
+        let plain: Vec<&Note> = status_notes.iter().filter(|n| n.kind.is_none()).collect();
+        push_note_section(&mut output, &note_section_label(*status), &plain, format);
+    }
+
+    let mut kinded: Vec<&Note> = notes
+        .iter()
+        .flat_map(|(_, status_notes)| status_notes)
+        .filter(|n| n.kind.is_some())
+        .collect();
+    kinded.sort_by_key(|n| n.created_at);
+    for kind in NoteKind::ALL {
+        let of_kind: Vec<&Note> = kinded
+            .iter()
+            .copied()
+            .filter(|n| n.kind == Some(kind))
+            .collect();
+        push_note_section(
+            &mut output,
+            &note_kind_section_label(kind),
+            &of_kind,
+            format,
+        );
+    }
+
+    output
+}
+
+/// Append a labeled section of notes, separated by blank lines.
+fn push_note_section(
+    output: &mut Vec<String>,
+    label: &str,
+    notes: &[&Note],
+    format: impl Fn(&Note) -> Vec<String>,
+) {
+    if notes.is_empty() {
+        return;
+    }
+    output.push(String::new());
+    output.push(t!("notes.heading", label = label));
+    for (i, note) in notes.iter().enumerate() {
+        if i > 0 {
+            output.push(String::new());
+        }
+        output.extend(format(note));
+    }
+}
+
 /// Format a single external link for display.
-fn format_link(link: &Link) -> String {
+fn format_link(details: &LinkDetails) -> String {
+    let link = &details.link;
+    let mut text = link_target(details);
+
+    // Add relationship if present
+    match (&link.rel, link.resolved_at) {
+        (Some(rel), Some(_)) => text.push_str(&format!(" ({}, resolved)", rel)),
+        (Some(rel), None) => text.push_str(&format!(" ({})", rel)),
+        (None, _) => {}
+    }
+
+    format!("  - {}", text)
+}
+
+/// A link's type and what the provider said, else its URL or external ID.
+fn link_target(details: &LinkDetails) -> String {
+    let link = &details.link;
     let mut parts = Vec::new();
 
     // Add link type if known
@@ -234,25 +748,71 @@ fn format_link(link: &Link) -> String {
         parts.push(format!("[{}]", link_type));
     }
 
-    // Add URL or external ID
-    if let Some(url) = &link.url {
-        parts.push(url.clone());
-    } else if let Some(ext_id) = &link.external_id {
-        parts.push(ext_id.clone());
+    // Add what the provider said, else the URL or external ID
+    let text = match details.remote.as_ref().and_then(|m| format_remote(link, m)) {
+        Some(remote) => Some(remote),
+        None => link.url.clone().or_else(|| link.external_id.clone()),
+    };
+    if let Some(text) = text {
+        parts.push(match &link.url {
+            Some(url) => url_link(&text, url),
+            None => text,
+        });
     }
 
-    // Add relationship if present
-    if let Some(rel) = &link.rel {
-        parts.push(format!("({})", rel));
-    }
+    parts.join(" ")
+}
 
-    format!("  - {}", parts.join(" "))
+/// Whether a link keeps its issue blocked: a `blocks` link whose external
+/// issue is not yet resolved.
+pub fn is_external_blocker(details: &LinkDetails) -> bool {
+    details.link.rel == Some(LinkRel::Blocks) && details.link.resolved_at.is_none()
+}
+
+/// The external issue a link points at, as its provider last described it:
+/// `PE-5555 (Closed): Fix auth`, or `#12 (open): Crash` for numbered ones.
+/// `None` until a refresh has found a title or state.
+pub fn format_remote(link: &Link, meta: &LinkMeta) -> Option<String> {
+    if meta.title.is_none() && meta.state.is_none() {
+        return None;
+    }
+    let mut text = match (&link.external_id, &link.url) {
+        (Some(id), _) if id.chars().all(|c| c.is_ascii_digit()) => format!("#{}", id),
+        (Some(id), _) => id.clone(),
+        (None, Some(url)) => url.clone(),
+        (None, None) => String::new(),
+    };
+    if let Some(state) = &meta.state {
+        text.push_str(&format!(" ({})", state));
+    }
+    if let Some(title) = &meta.title {
+        text.push_str(&format!(": {}", title));
+    }
+    Some(text)
 }
 
 /// Format a single event for log output
 pub fn format_event(event: &Event) -> String {
     let timestamp = event.created_at.format("%Y-%m-%d %H:%M");
-    let mut line = format!("  {}  {}", timestamp, event.action);
+    format!(
+        "  {}  {}{}{}",
+        timestamp,
+        event.action,
+        format_event_detail(event),
+        by_actor(event.actor.as_deref())
+    )
+}
+
+/// ` by <actor>` when who made a change or wrote a note is known.
+fn by_actor(actor: Option<&str>) -> String {
+    actor
+        .map(|actor| format!(" by {}", actor))
+        .unwrap_or_default()
+}
+
+/// The part of an event line after the action: values, reasons, or a note excerpt.
+pub fn format_event_detail(event: &Event) -> String {
+    let mut line = String::new();
 
     match event.action {
         Action::Edited => {
@@ -266,7 +826,12 @@ pub fn format_event(event: &Event) -> String {
                 line.push_str(&format!(" {}", val));
             }
         }
-        Action::Related | Action::Unrelated => {
+        Action::Related
+        | Action::Unrelated
+        | Action::Associated
+        | Action::Dissociated
+        | Action::Duplicated
+        | Action::Unduplicated => {
             if let Some(val) = &event.new_value {
                 line.push_str(&format!(" {}", val));
             }
@@ -281,8 +846,13 @@ pub fn format_event(event: &Event) -> String {
                 line.push_str(&format!(" \"{}\"", reason));
             }
         }
-        Action::Noted => {
-            if let Some(val) = &event.new_value {
+        Action::Noted | Action::Unnoted => {
+            // A deleted note's content is the old value
+            let content = match event.action {
+                Action::Unnoted => &event.old_value,
+                _ => &event.new_value,
+            };
+            if let Some(val) = content {
                 // Truncate long notes
                 let display = if val.len() > 50 {
                     format!("{}...", &val[..47])
@@ -294,12 +864,20 @@ pub fn format_event(event: &Event) -> String {
         }
         Action::Assigned => {
             if let Some(val) = &event.new_value {
-                line.push_str(&format!(" to {}", val));
+                line.push_str(&format!(" {}", t!("log.assigned_to", assignee = val)));
             }
         }
         Action::Unassigned => {
             if let Some(val) = &event.old_value {
-                line.push_str(&format!(" (was {})", val));
+                line.push_str(&format!(" {}", t!("log.unassigned_was", assignee = val)));
+            }
+        }
+        Action::Stale => {
+            if let (Some(status), Some(age)) = (&event.old_value, &event.new_value) {
+                line.push_str(&format!(
+                    " {}",
+                    t!("log.stale_for", status = status, age = age)
+                ));
             }
         }
         _ => {}
@@ -326,6 +904,7 @@ pub fn format_event_with_id(event: &Event) -> String {
         }
         _ => {}
     }
+    line.push_str(&by_actor(event.actor.as_deref()));
 
     line
 }
@@ -337,32 +916,79 @@ pub enum RelationType {
     Tracks,
     /// Issue is blocked by the parent
     Blocks,
+    /// Issue tracks the parent (`wok tree --up`)
+    TrackedBy,
+    /// Issue blocks the parent (`wok tree --up`)
+    BlockedBy,
+    /// Issue relates to the parent
+    Related,
+    /// The parent duplicates the issue
+    DuplicateOf,
+    /// Issue duplicates the parent
+    DuplicatedBy,
 }
 
 impl RelationType {
     /// Returns the display label for this relation type
-    pub fn label(&self) -> &'static str {
+    pub fn label(&self) -> String {
         match self {
-            RelationType::Tracks => "tracks",
-            RelationType::Blocks => "blocks",
+            RelationType::Tracks => t!("tree.tracks"),
+            RelationType::Blocks => t!("tree.blocks"),
+            RelationType::TrackedBy => t!("tree.parent"),
+            RelationType::BlockedBy => t!("tree.blocker"),
+            RelationType::Related => t!("tree.related"),
+            RelationType::DuplicateOf => t!("tree.duplicate_of"),
+            RelationType::DuplicatedBy => t!("tree.duplicated_by"),
         }
     }
+
+    /// Whether the edge is part of the hierarchy (tracks or blocks) rather
+    /// than a non-blocking association, which is never expanded.
+    pub fn is_structural(&self) -> bool {
+        matches!(
+            self,
+            RelationType::Tracks
+                | RelationType::Blocks
+                | RelationType::TrackedBy
+                | RelationType::BlockedBy
+        )
+    }
 }
 
-/// Format tree output for root node
-pub fn format_tree_root(issue: &Issue, blocked_by: Option<&[String]>) -> String {
-    let status_str = if issue.status != Status::Todo {
-        format!(" [{}]", issue.status)
-    } else {
+/// Status (unless todo) and assignee of a tree node, e.g. ` [in_progress, @alice]`.
+fn tree_badge(issue: &Issue) -> String {
+    let status = (issue.status != Status::Todo).then(|| issue.status.to_string());
+    let assignee = issue.assignee.as_ref().map(|a| format!("@{}", a));
+    let parts: Vec<String> = status.into_iter().chain(assignee).collect();
+    if parts.is_empty() {
         String::new()
-    };
+    } else {
+        format!(" [{}]", parts.join(", "))
+    }
+}
 
-    let mut output = format!("{}: {}{}", issue.id, issue.title, status_str);
+/// Issue IDs joined with commas, each linked to its page.
+fn issue_links(ids: &[String]) -> String {
+    ids.iter()
+        .map(|id| issue_link(id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format tree output for root node
+pub fn format_tree_root(issue: &Issue, blocked_by: Option<&[String]>) -> String {
+    let mut output = format!(
+        "{}: {}{}",
+        issue_link(&issue.id),
+        issue.title,
+        tree_badge(issue)
+    );
 
     // Show blockers if any
     if let Some(blockers) = blocked_by {
         if !blockers.is_empty() {
-            output.push_str(&format!("\n└── (blocked by {})", blockers.join(", ")));
+            let blocked_by = t!("tree.blocked_by", ids = issue_links(blockers));
+            output.push_str(&format!("\n└── ({})", blocked_by));
         }
     }
 
@@ -381,12 +1007,6 @@ pub fn format_tree_child(
 
     let connector = if is_last { "└── " } else { "├── " };
 
-    let status_str = if issue.status != Status::Todo {
-        format!(" [{}]", issue.status)
-    } else {
-        String::new()
-    };
-
     let label_str = match relation_label {
         Some(rel) => format!(" ({})", rel.label()),
         None => String::new(),
@@ -394,7 +1014,12 @@ pub fn format_tree_child(
 
     lines.push(format!(
         "{}{}{}: {}{}{}",
-        prefix, connector, issue.id, issue.title, status_str, label_str
+        prefix,
+        connector,
+        issue_link(&issue.id),
+        issue.title,
+        tree_badge(issue),
+        label_str
     ));
 
     // Show blockers if any
@@ -405,11 +1030,8 @@ pub fn format_tree_child(
             } else {
                 format!("{}│   ", prefix)
             };
-            lines.push(format!(
-                "{}└── (blocked by {})",
-                child_prefix,
-                blockers.join(", ")
-            ));
+            let blocked_by = t!("tree.blocked_by", ids = issue_links(blockers));
+            lines.push(format!("{}└── ({})", child_prefix, blocked_by));
         }
     }
 
@@ -5,7 +5,7 @@
 #![allow(clippy::expect_used)]
 
 use super::*;
-use crate::models::IssueType;
+use crate::models::{IssueType, LinkRel, LinkType};
 use chrono::{TimeZone, Utc};
 
 fn create_test_issue(id: &str, title: &str, issue_type: IssueType, status: Status) -> Issue {
@@ -24,6 +24,7 @@ fn create_test_issue(id: &str, title: &str, issue_type: IssueType, status: Statu
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -35,6 +36,7 @@ fn create_test_event(issue_id: &str, action: Action) -> Event {
         old_value: None,
         new_value: None,
         reason: None,
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap(),
     }
 }
@@ -50,6 +52,163 @@ fn test_format_issue_line() {
     assert!(line.contains("Test issue"));
 }
 
+#[test]
+fn test_format_issue_line_with_short_id() {
+    let issue = create_test_issue("prj-1234", "Test issue", IssueType::Task, Status::Todo);
+    assert_eq!(
+        format_issue_line_with_short_id(&issue, Some("prj-7")),
+        "- [task] (todo) prj-1234 (prj-7): Test issue"
+    );
+    assert_eq!(
+        format_issue_line_with_short_id(&issue, None),
+        format_issue_line(&issue)
+    );
+}
+
+#[test]
+fn test_truncate_to_width() {
+    assert_eq!(truncate_to_width("short", 10), "short");
+    assert_eq!(truncate_to_width("exactly10!", 10), "exactly10!");
+    assert_eq!(truncate_to_width("a longer title", 8), "a longe…");
+    assert_eq!(truncate_to_width("héllo wörld", 6), "héllo…");
+    assert_eq!(truncate_to_width("anything", 0), "");
+}
+
+#[test]
+fn truncate_to_width_skips_and_closes_hyperlinks() {
+    let linked = format!("{} title", osc8("prj-1", "https://x.test/prj-1"));
+    assert_eq!(truncate_to_width(&linked, 11), linked);
+    assert_eq!(
+        truncate_to_width(&linked, 3),
+        "\x1b]8;;https://x.test/prj-1\x1b\\pr\x1b]8;;\x1b\\…"
+    );
+    assert_eq!(
+        truncate_to_width(&linked, 8),
+        format!("{} t…", osc8("prj-1", "https://x.test/prj-1"))
+    );
+}
+
+#[test]
+fn hyperlinks_link_issue_ids_to_their_page() {
+    let on = Hyperlinks {
+        enabled: true,
+        issue_url: Some("http://127.0.0.1:8080/{prefix}/{id}".to_string()),
+    };
+    assert_eq!(
+        on.issue("prj-a1"),
+        osc8("prj-a1", "http://127.0.0.1:8080/prj/prj-a1")
+    );
+
+    let no_page = Hyperlinks {
+        issue_url: None,
+        ..on.clone()
+    };
+    assert_eq!(no_page.issue("prj-a1"), "prj-a1");
+    let off = Hyperlinks {
+        enabled: false,
+        ..on
+    };
+    assert_eq!(off.issue("prj-a1"), "prj-a1");
+}
+
+#[test]
+fn hyperlinks_only_link_web_urls() {
+    let on = Hyperlinks {
+        enabled: true,
+        issue_url: None,
+    };
+    assert_eq!(
+        on.url("PE-1", "https://jira.test/PE-1"),
+        osc8("PE-1", "https://jira.test/PE-1")
+    );
+    assert_eq!(on.url("PE-1", "jira://PE-1"), "PE-1");
+    assert_eq!(on.url("x", "https://evil.test/\x1b]0;title"), "x");
+    assert_eq!(
+        Hyperlinks::OFF.url("PE-1", "https://jira.test/PE-1"),
+        "PE-1"
+    );
+}
+
+#[test]
+fn known_terminals_support_hyperlinks() {
+    let hints = |term: &str, program: &str| TerminalHints {
+        term: Some(term.to_string()),
+        program: Some(program.to_string()),
+        ..Default::default()
+    };
+    assert!(hints("xterm-kitty", "").supports_hyperlinks());
+    assert!(hints("xterm-256color", "iTerm.app").supports_hyperlinks());
+    assert!(hints("xterm-256color", "WezTerm").supports_hyperlinks());
+    assert!(!hints("xterm-256color", "Apple_Terminal").supports_hyperlinks());
+    assert!(!hints("dumb", "").supports_hyperlinks());
+
+    let vte = |version| TerminalHints {
+        vte_version: Some(version),
+        ..Default::default()
+    };
+    assert!(vte(6003).supports_hyperlinks());
+    assert!(!vte(4205).supports_hyperlinks());
+    let wt = TerminalHints {
+        windows_terminal: true,
+        ..Default::default()
+    };
+    assert!(wt.supports_hyperlinks());
+}
+
+#[test]
+fn test_format_issue_table_aligns_columns() {
+    let mut first = create_test_issue("prj-1", "First", IssueType::Task, Status::Todo);
+    first.assignee = Some("alice".to_string());
+    let second = create_test_issue("prj-22", "Second", IssueType::Bug, Status::InProgress);
+    let labels = vec!["a".to_string(), "b".to_string()];
+    let rows = [
+        IssueRow {
+            issue: &first,
+            short_id: Some("prj-1"),
+            labels: &labels,
+        },
+        IssueRow {
+            issue: &second,
+            short_id: None,
+            labels: &[],
+        },
+    ];
+    let columns = [
+        ListColumn::Id,
+        ListColumn::Status,
+        ListColumn::Assignee,
+        ListColumn::Labels,
+    ];
+
+    assert_eq!(
+        format_issue_table(&rows, &columns, None, &LabelPalette::default()),
+        vec!["prj-1   todo         @alice  a,b", "prj-22  in_progress",]
+    );
+}
+
+#[test]
+fn test_format_issue_table_truncates_title_to_width() {
+    let issue = create_test_issue(
+        "prj-1",
+        "A title far too long for the terminal",
+        IssueType::Task,
+        Status::Todo,
+    );
+    let rows = [IssueRow {
+        issue: &issue,
+        short_id: None,
+        labels: &[],
+    }];
+    let columns = [ListColumn::Id, ListColumn::Title, ListColumn::Status];
+
+    let lines = format_issue_table(&rows, &columns, Some(30), &LabelPalette::default());
+    assert_eq!(lines, vec!["prj-1  A title far too …  todo"]);
+
+    // Titles keep a minimum width even on very narrow terminals
+    let lines = format_issue_table(&rows, &columns, Some(5), &LabelPalette::default());
+    assert_eq!(lines, vec!["prj-1  A title f…  todo"]);
+}
+
 #[test]
 fn test_format_issue_line_different_types() {
     let feature = create_test_issue("f-1", "Feature", IssueType::Feature, Status::InProgress);
@@ -88,7 +247,19 @@ fn test_format_issue_line_with_assignee_in_progress() {
 #[test]
 fn test_format_issue_details_minimal() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &[], &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     // First line: [type] id
     assert!(output.starts_with("[task] prj-1234"));
@@ -108,18 +279,61 @@ fn test_format_issue_details_minimal() {
 fn test_format_issue_details_with_assignee() {
     let mut issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
     issue.assignee = Some("alice".to_string());
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &[], &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     // Assignee should appear after Status
     assert!(output.contains("Status: todo"));
     assert!(output.contains("Assignee: alice"));
 }
 
+#[test]
+fn test_format_issue_details_stale_status() {
+    let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::InProgress);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        Some("9d"),
+    );
+    assert!(output.contains("Status: in_progress (stale 9d)"));
+}
+
 #[test]
 fn test_format_issue_details_with_labels() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
     let labels = vec!["urgent".to_string(), "frontend".to_string()];
-    let output = format_issue_details(&issue, &labels, &[], &[], &[], &[], &[], &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &labels,
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     assert!(output.contains("Labels: urgent, frontend"));
 }
@@ -127,18 +341,76 @@ fn test_format_issue_details_with_labels() {
 #[test]
 fn test_format_issue_details_with_blockers() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
-    let blockers = vec!["prj-aaaa".to_string()];
-    let output = format_issue_details(&issue, &[], &blockers, &[], &[], &[], &[], &[], &[]);
+    let blockers = vec![ChainBlocker::direct("prj-aaaa")];
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &blockers,
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     assert!(output.contains("Blocked by:"));
-    assert!(output.contains("prj-aaaa"));
+    assert!(output.contains("\n  - prj-aaaa"));
+}
+
+#[test]
+fn test_format_issue_details_with_blocker_chain() {
+    let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
+    let blockers = vec![
+        ChainBlocker {
+            id: "prj-aaaa".to_string(),
+            issue: Some(("Direct".to_string(), Status::InProgress)),
+            depth: 1,
+            actionable: false,
+        },
+        ChainBlocker {
+            id: "prj-bbbb".to_string(),
+            issue: Some(("Underneath".to_string(), Status::Todo)),
+            depth: 2,
+            actionable: true,
+        },
+    ];
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &blockers,
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
+
+    assert!(output.contains("Blocked by:\n  - prj-aaaa: Direct [in_progress]\n    - prj-bbbb: Underneath [todo] ← actionable"));
 }
 
 #[test]
 fn test_format_issue_details_with_blocking() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
     let blocking = vec!["prj-bbbb".to_string()];
-    let output = format_issue_details(&issue, &[], &[], &blocking, &[], &[], &[], &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &blocking,
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     assert!(output.contains("Blocks:"));
     assert!(output.contains("prj-bbbb"));
@@ -148,7 +420,19 @@ fn test_format_issue_details_with_blocking() {
 fn test_format_issue_details_with_parents() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
     let parents = vec!["prj-parent".to_string()];
-    let output = format_issue_details(&issue, &[], &[], &[], &parents, &[], &[], &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &parents,
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     assert!(output.contains("Tracked by:"));
     assert!(output.contains("prj-parent"));
@@ -158,13 +442,79 @@ fn test_format_issue_details_with_parents() {
 fn test_format_issue_details_with_children() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Feature, Status::Todo);
     let children = vec!["prj-child1".to_string(), "prj-child2".to_string()];
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &children, &[], &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &children,
+        Associations::default(),
+        &[],
+        &[],
+        &[],
+        None,
+    );
 
     assert!(output.contains("Tracks:"));
     assert!(output.contains("prj-child1"));
     assert!(output.contains("prj-child2"));
 }
 
+#[test]
+fn test_format_issue_details_with_referenced_by() {
+    let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
+    let referenced_by = vec!["prj-other".to_string()];
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations {
+            referenced_by: &referenced_by,
+            ..Associations::default()
+        },
+        &[],
+        &[],
+        &[],
+        None,
+    );
+
+    assert!(output.contains("Referenced by:\n  - prj-other"));
+}
+
+#[test]
+fn test_format_issue_details_with_related_and_duplicates() {
+    let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
+    let related = vec!["prj-near".to_string()];
+    let duplicate_of = vec!["prj-orig".to_string()];
+    let duplicates = vec!["prj-copy".to_string()];
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations {
+            related: &related,
+            duplicate_of: &duplicate_of,
+            duplicates: &duplicates,
+            ..Associations::default()
+        },
+        &[],
+        &[],
+        &[],
+        None,
+    );
+
+    assert!(output.contains(
+        "Related:\n  - prj-near\n\nDuplicate of:\n  - prj-orig\n\nDuplicates:\n  - prj-copy"
+    ));
+}
+
 #[test]
 fn test_format_issue_details_with_notes() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::InProgress);
@@ -172,11 +522,25 @@ fn test_format_issue_details_with_notes() {
         id: 1,
         issue_id: "prj-1234".to_string(),
         status: Status::InProgress,
+        kind: None,
         content: "Working on it".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 14, 15, 0).unwrap(),
     };
     let notes = vec![(Status::InProgress, vec![note])];
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &notes, &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &notes,
+        &[],
+        &[],
+        None,
+    );
 
     // Semantic label instead of status
     assert!(output.contains("Progress:"));
@@ -190,7 +554,19 @@ fn test_format_issue_details_with_notes() {
 fn test_format_issue_details_with_events() {
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
     let event = create_test_event("prj-1234", Action::Started);
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &[], &[], &[event]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[event],
+        None,
+    );
 
     assert!(output.contains("Log:"));
     assert!(output.contains("started"));
@@ -201,7 +577,19 @@ fn test_format_issue_details_omits_created_event() {
     // Created event is redundant with the Created: line, so should be omitted
     let issue = create_test_issue("prj-1234", "Test", IssueType::Task, Status::Todo);
     let created_event = create_test_event("prj-1234", Action::Created);
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &[], &[], &[created_event]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[created_event],
+        None,
+    );
 
     // Log section should not appear when only event is Created
     assert!(!output.contains("Log:"));
@@ -220,9 +608,11 @@ fn test_format_issue_details_filters_created_from_multiple_events() {
         &[],
         &[],
         &[],
+        Associations::default(),
         &[],
         &[],
         &[created_event, started_event],
+        None,
     );
 
     // Log should appear with started but not created
@@ -238,7 +628,19 @@ fn test_format_issue_details_omits_noted_at_creation_time() {
     let mut noted_event = create_test_event("prj-1234", Action::Noted);
     noted_event.new_value = Some("Description note".to_string());
     // Event timestamp matches issue creation time (both use same default in helpers)
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &[], &[], &[noted_event]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[noted_event],
+        None,
+    );
 
     // Log section should not appear when only event is Noted at creation time
     assert!(!output.contains("Log:"));
@@ -256,10 +658,23 @@ fn test_format_issue_details_shows_noted_after_creation() {
         old_value: None,
         new_value: Some("Progress note".to_string()),
         reason: None,
+        actor: None,
         // Different timestamp from issue creation
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 14, 0, 0).unwrap(),
     };
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &[], &[], &[noted_event]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[],
+        &[noted_event],
+        None,
+    );
 
     // Log should show the noted event since it's after creation
     assert!(output.contains("Log:"));
@@ -361,6 +776,15 @@ fn test_format_event_noted_truncated() {
     assert!(!line.contains("truncated"));
 }
 
+#[test]
+fn test_format_event_stale() {
+    let mut event = create_test_event("prj-1234", Action::Stale);
+    event.old_value = Some("in_progress".to_string());
+    event.new_value = Some("9d".to_string());
+    let line = format_event(&event);
+    assert!(line.ends_with("stale in_progress for 9d"));
+}
+
 #[test]
 fn test_format_event_simple_actions() {
     // These actions don't have extra formatting
@@ -376,6 +800,14 @@ fn test_format_event_simple_actions() {
     }
 }
 
+#[test]
+fn test_format_event_names_the_actor() {
+    let mut event = create_test_event("prj-1234", Action::Started);
+    event.actor = Some("claude-1".to_string());
+    assert!(format_event(&event).ends_with("started by claude-1"));
+    assert!(format_event_with_id(&event).ends_with("prj-1234 started by claude-1"));
+}
+
 // format_event_with_id tests
 #[test]
 fn test_format_event_with_id_basic() {
@@ -589,7 +1021,9 @@ fn test_format_note_basic() {
         id: 1,
         issue_id: "prj-1234".to_string(),
         status: Status::Todo,
+        kind: None,
         content: "This is a note".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 10, 30, 0).unwrap(),
     };
     let lines = format_note(&note);
@@ -601,13 +1035,29 @@ fn test_format_note_basic() {
     assert_eq!(lines[1], "    This is a note");
 }
 
+#[test]
+fn test_format_note_names_the_actor() {
+    let note = Note {
+        id: 1,
+        issue_id: "prj-1234".to_string(),
+        status: Status::Todo,
+        kind: None,
+        content: "This is a note".to_string(),
+        actor: Some("claude-1".to_string()),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 10, 10, 30, 0).unwrap(),
+    };
+    assert_eq!(format_note(&note)[0], "  2024-01-10 10:30 by claude-1");
+}
+
 #[test]
 fn test_format_note_multiline() {
     let note = Note {
         id: 1,
         issue_id: "prj-1234".to_string(),
         status: Status::InProgress,
+        kind: None,
         content: "Line 1\nLine 2\nLine 3".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 14, 15, 0).unwrap(),
     };
     let lines = format_note(&note);
@@ -626,7 +1076,9 @@ fn test_format_note_long_wraps() {
         id: 1,
         issue_id: "prj-1234".to_string(),
         status: Status::Todo,
+        kind: None,
         content: long_content.to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 10, 30, 0).unwrap(),
     };
     let lines = format_note(&note);
@@ -655,18 +1107,34 @@ fn test_format_issue_details_multiple_notes_same_status() {
         id: 1,
         issue_id: "prj-1234".to_string(),
         status: Status::Todo,
+        kind: None,
         content: "First note".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap(),
     };
     let note2 = Note {
         id: 2,
         issue_id: "prj-1234".to_string(),
         status: Status::Todo,
+        kind: None,
         content: "Second note".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 11, 0, 0).unwrap(),
     };
     let notes = vec![(Status::Todo, vec![note1, note2])];
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &notes, &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &notes,
+        &[],
+        &[],
+        None,
+    );
 
     // Should have Description: label
     assert!(output.contains("Description:"));
@@ -682,21 +1150,27 @@ fn test_format_issue_details_notes_different_statuses() {
         id: 1,
         issue_id: "prj-1234".to_string(),
         status: Status::Todo,
+        kind: None,
         content: "Initial requirements".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap(),
     };
     let progress_note = Note {
         id: 2,
         issue_id: "prj-1234".to_string(),
         status: Status::InProgress,
+        kind: None,
         content: "Working on implementation".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 14, 0, 0).unwrap(),
     };
     let summary_note = Note {
         id: 3,
         issue_id: "prj-1234".to_string(),
         status: Status::Done,
+        kind: None,
         content: "Completed successfully".to_string(),
+        actor: None,
         created_at: Utc.with_ymd_and_hms(2024, 1, 10, 17, 0, 0).unwrap(),
     };
     let notes = vec![
@@ -704,7 +1178,19 @@ fn test_format_issue_details_notes_different_statuses() {
         (Status::InProgress, vec![progress_note]),
         (Status::Done, vec![summary_note]),
     ];
-    let output = format_issue_details(&issue, &[], &[], &[], &[], &[], &notes, &[], &[]);
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &notes,
+        &[],
+        &[],
+        None,
+    );
 
     // All three semantic labels
     assert!(output.contains("Description:"));
@@ -715,3 +1201,178 @@ fn test_format_issue_details_notes_different_statuses() {
     assert!(output.contains("Working on implementation"));
     assert!(output.contains("Completed successfully"));
 }
+
+#[test]
+fn test_format_note_sections_groups_kinds_after_status_sections() {
+    let note = |id: i64, status: Status, kind: Option<NoteKind>, content: &str, hour: u32| Note {
+        id,
+        issue_id: "prj-1234".to_string(),
+        status,
+        kind,
+        content: content.to_string(),
+        actor: None,
+        created_at: Utc.with_ymd_and_hms(2024, 1, 10, hour, 0, 0).unwrap(),
+    };
+    let notes = vec![
+        (
+            Status::Todo,
+            vec![
+                note(1, Status::Todo, None, "Requirements", 9),
+                note(2, Status::Todo, Some(NoteKind::Decision), "Use FTS", 10),
+            ],
+        ),
+        (
+            Status::InProgress,
+            vec![
+                note(
+                    3,
+                    Status::InProgress,
+                    Some(NoteKind::Handoff),
+                    "Docs left",
+                    12,
+                ),
+                note(
+                    4,
+                    Status::InProgress,
+                    Some(NoteKind::Decision),
+                    "Skip cache",
+                    11,
+                ),
+            ],
+        ),
+    ];
+
+    let output = format_note_sections(&notes).join("\n");
+
+    // Todo has a plain note; in_progress has only kinded notes
+    assert!(output.contains("Description:\n"));
+    assert!(!output.contains("Progress:"));
+
+    let pos = |s: &str| output.find(s).unwrap();
+    assert!(pos("Description:") < pos("Decisions:"));
+    assert!(pos("Decisions:") < pos("Handoff:"));
+    // Kinded notes are in creation order across statuses
+    assert!(pos("Use FTS") < pos("Skip cache"));
+    assert!(!output.contains("Blockers:"));
+    assert!(!output.contains("Worklog:"));
+}
+
+#[test]
+fn test_format_note_sections_with_ids_tags_each_note() {
+    let note = Note {
+        id: 12,
+        issue_id: "prj-1234".to_string(),
+        status: Status::Todo,
+        kind: None,
+        content: "Requirements".to_string(),
+        actor: Some("claude-1".to_string()),
+        created_at: Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap(),
+    };
+    let notes = vec![(Status::Todo, vec![note])];
+
+    let output = format_note_sections_with_ids(&notes);
+    assert_eq!(output[2], "  2024-01-10 09:00 by claude-1  #12");
+    assert_eq!(output[3], "    Requirements");
+    assert!(!format_note_sections(&notes).join("\n").contains("#12"));
+}
+
+#[test]
+fn test_note_kind_section_labels() {
+    assert_eq!(note_kind_section_label(NoteKind::Decision), "Decisions");
+    assert_eq!(note_kind_section_label(NoteKind::Blocker), "Blockers");
+    assert_eq!(note_kind_section_label(NoteKind::Worklog), "Worklog");
+    assert_eq!(note_kind_section_label(NoteKind::Handoff), "Handoff");
+}
+
+fn remote(title: Option<&str>, state: Option<&str>) -> LinkMeta {
+    LinkMeta {
+        title: title.map(str::to_string),
+        state: state.map(str::to_string),
+        error: None,
+        refreshed_at: Utc::now(),
+    }
+}
+
+#[test]
+fn test_format_link_shows_refreshed_title_and_state() {
+    let link = Link::new("test-1".to_string())
+        .with_type(LinkType::Jira)
+        .with_url("https://acme.atlassian.net/browse/PE-5555".to_string())
+        .with_external_id("PE-5555".to_string())
+        .with_rel(LinkRel::Import);
+    let details = LinkDetails {
+        link,
+        remote: Some(remote(Some("Fix auth"), Some("Closed"))),
+    };
+
+    assert_eq!(
+        format_link(&details),
+        "  - [jira] PE-5555 (Closed): Fix auth (import)"
+    );
+}
+
+#[test]
+fn test_format_link_falls_back_to_url_until_refreshed() {
+    let link = Link::new("test-1".to_string())
+        .with_type(LinkType::Github)
+        .with_url("https://github.com/o/r/issues/12".to_string())
+        .with_external_id("12".to_string());
+    let never = LinkDetails {
+        link: link.clone(),
+        remote: None,
+    };
+    let failed = LinkDetails {
+        link: link.clone(),
+        remote: Some(remote(None, None)),
+    };
+
+    assert_eq!(
+        format_link(&never),
+        "  - [github] https://github.com/o/r/issues/12"
+    );
+    assert_eq!(format_link(&failed), format_link(&never));
+    assert_eq!(
+        format_remote(&link, &remote(Some("Crash"), Some("open"))).as_deref(),
+        Some("#12 (open): Crash")
+    );
+}
+
+#[test]
+fn test_format_issue_details_lists_external_blockers() {
+    let issue = create_test_issue("prj-1234", "Test issue", IssueType::Task, Status::Todo);
+    let blocks = |url: &str| {
+        Link::new("prj-1234".to_string())
+            .with_type(LinkType::Jira)
+            .with_url(url.to_string())
+            .with_external_id(url.trim_start_matches("jira://").to_string())
+            .with_rel(LinkRel::Blocks)
+    };
+    let open = LinkDetails {
+        link: blocks("jira://PE-1"),
+        remote: Some(remote(Some("Fix auth"), Some("In Progress"))),
+    };
+    let mut resolved = blocks("jira://PE-2");
+    resolved.resolved_at = Some(Utc::now());
+    let resolved = LinkDetails {
+        link: resolved,
+        remote: None,
+    };
+
+    let output = format_issue_details(
+        &issue,
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        Associations::default(),
+        &[],
+        &[open, resolved],
+        &[],
+        None,
+    );
+
+    assert!(output.contains("Blocked by:\n  - [jira] PE-1 (In Progress): Fix auth\n"));
+    assert!(!output.contains("  - [jira] jira://PE-2\n"));
+    assert!(output.contains("  - [jira] jira://PE-2 (blocks, resolved)"));
+}
@@ -39,6 +39,21 @@ pub fn xdg_state_home() -> Option<PathBuf> {
     std::env::var(vars::XDG_STATE_HOME).ok().map(PathBuf::from)
 }
 
+/// Returns the value of `XDG_CONFIG_HOME` if set.
+pub fn xdg_config_home() -> Option<PathBuf> {
+    std::env::var(vars::XDG_CONFIG_HOME).ok().map(PathBuf::from)
+}
+
+/// The variable that overrides setting `key`: `wip.limit` is `WOK_WIP_LIMIT`.
+pub fn setting_var(key: &str) -> String {
+    format!("WOK_{}", key.to_uppercase().replace('.', "_"))
+}
+
+/// Returns the value of the `WOK_<KEY>` override for setting `key` if set.
+pub fn setting(key: &str) -> Option<String> {
+    std::env::var(setting_var(key)).ok()
+}
+
 /// Returns the value of `WOK_DAEMON_BINARY` if set.
 pub fn daemon_binary() -> Option<PathBuf> {
     std::env::var(vars::WOK_DAEMON_BINARY)
@@ -46,6 +61,92 @@ pub fn daemon_binary() -> Option<PathBuf> {
         .map(PathBuf::from)
 }
 
+/// Returns the value of `WOK_ERROR_FORMAT` if set.
+pub fn error_format() -> Option<String> {
+    std::env::var(vars::WOK_ERROR_FORMAT).ok()
+}
+
+/// Returns `true` if `WOK_TRACE_JSON=1`.
+pub fn wok_trace_json() -> bool {
+    std::env::var(vars::WOK_TRACE_JSON).is_ok_and(|v| v == "1")
+}
+
+/// The locale messages should use: the first of `LC_ALL`, `LC_MESSAGES`,
+/// and `LANG` that is set and non-empty.
+pub fn message_locale() -> Option<String> {
+    [vars::LC_ALL, vars::LC_MESSAGES, vars::LANG]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|v| !v.is_empty())
+}
+
+/// Returns `COLUMNS` as a width, if set to a positive number.
+pub fn columns() -> Option<usize> {
+    std::env::var(vars::COLUMNS)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|&n| n > 0)
+}
+
+/// Returns the value of `PAGER` if set.
+pub fn pager() -> Option<String> {
+    std::env::var(vars::PAGER).ok()
+}
+
+/// Returns the value of `BROWSER` if set and not blank.
+pub fn browser() -> Option<String> {
+    std::env::var(vars::BROWSER)
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+}
+
+/// Returns the value of `USER` if set and not blank.
+pub fn user() -> Option<String> {
+    std::env::var(vars::USER)
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+}
+
+/// Returns the value of `PATH` if set.
+pub fn path() -> Option<std::ffi::OsString> {
+    std::env::var_os(vars::PATH)
+}
+
+/// Returns `Some(true)` if `FORCE_HYPERLINK=1`, `Some(false)` if it is set
+/// to anything else.
+pub fn force_hyperlink() -> Option<bool> {
+    std::env::var(vars::FORCE_HYPERLINK).ok().map(|v| v == "1")
+}
+
+/// Returns the value of `TERM` if set.
+pub fn term() -> Option<String> {
+    std::env::var(vars::TERM).ok()
+}
+
+/// Returns the value of `TERM_PROGRAM` if set.
+pub fn term_program() -> Option<String> {
+    std::env::var(vars::TERM_PROGRAM).ok()
+}
+
+/// Returns `VTE_VERSION` as a number, if set to one.
+pub fn vte_version() -> Option<u32> {
+    std::env::var(vars::VTE_VERSION)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// Returns `KONSOLE_VERSION` as a number, if set to one.
+pub fn konsole_version() -> Option<u32> {
+    std::env::var(vars::KONSOLE_VERSION)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// Returns `true` if `WT_SESSION` is set, as in Windows Terminal.
+pub fn windows_terminal() -> bool {
+    std::env::var_os(vars::WT_SESSION).is_some()
+}
+
 #[cfg(test)]
 #[path = "env_tests.rs"]
 mod tests;
@@ -13,7 +13,16 @@ fn test_vars_constants() {
     assert_eq!(vars::COLOR, "COLOR");
     assert_eq!(vars::WOK_STATE_DIR, "WOK_STATE_DIR");
     assert_eq!(vars::XDG_STATE_HOME, "XDG_STATE_HOME");
+    assert_eq!(vars::XDG_CONFIG_HOME, "XDG_CONFIG_HOME");
     assert_eq!(vars::WOK_DAEMON_BINARY, "WOK_DAEMON_BINARY");
+    assert_eq!(vars::WOK_ERROR_FORMAT, "WOK_ERROR_FORMAT");
+    assert_eq!(vars::COLUMNS, "COLUMNS");
+    assert_eq!(vars::PAGER, "PAGER");
+    assert_eq!(vars::BROWSER, "BROWSER");
+    assert_eq!(vars::USER, "USER");
+    assert_eq!(vars::PATH, "PATH");
+    assert_eq!(vars::FORCE_HYPERLINK, "FORCE_HYPERLINK");
+    assert_eq!(vars::VTE_VERSION, "VTE_VERSION");
 }
 
 #[test]
@@ -114,3 +123,23 @@ fn test_daemon_binary_set() {
     assert_eq!(daemon_binary(), Some(PathBuf::from("/usr/local/bin/wokd")));
     std::env::remove_var("WOK_DAEMON_BINARY");
 }
+
+#[test]
+fn test_setting_var_names() {
+    assert_eq!(setting_var("color"), "WOK_COLOR");
+    assert_eq!(setting_var("wip.limit"), "WOK_WIP_LIMIT");
+    assert_eq!(setting_var("limits.search"), "WOK_LIMITS_SEARCH");
+}
+
+#[test]
+fn test_message_locale_precedence() {
+    std::env::set_var("LANG", "en_US.UTF-8");
+    std::env::set_var("LC_MESSAGES", "");
+    std::env::set_var("LC_ALL", "de_DE.UTF-8");
+    assert_eq!(message_locale().as_deref(), Some("de_DE.UTF-8"));
+    std::env::remove_var("LC_ALL");
+    assert_eq!(message_locale().as_deref(), Some("en_US.UTF-8"));
+    std::env::remove_var("LC_MESSAGES");
+    std::env::remove_var("LANG");
+    assert_eq!(message_locale(), None);
+}
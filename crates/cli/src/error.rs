@@ -1,41 +1,49 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+use serde::Serialize;
 use thiserror::Error;
 
+use crate::t;
+
 /// All possible errors that can occur in the wkrs library.
 ///
 /// Errors provide user-friendly messages with hints for common issues.
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("not initialized: run 'wok init' first")]
+    #[error("{}", t!("error.not_initialized"))]
     NotInitialized,
 
     #[error("already initialized at {0}")]
     AlreadyInitialized(String),
 
-    #[error("issue not found: {0}")]
+    #[error("{}", t!("error.issue_not_found", id = .0))]
     IssueNotFound(String),
 
-    #[error("ambiguous issue ID '{prefix}' matches: {}", matches.join(", "))]
+    #[error("{}", t!("error.ambiguous_id", prefix = .prefix, matches = .matches.join(", ")))]
     AmbiguousId {
         prefix: String,
         matches: Vec<String>,
     },
 
-    #[error("invalid status transition: cannot go from {from} to {to}\n  hint: from '{from}' you can go to: {valid_targets}")]
+    #[error("{}", t!("error.invalid_transition", from = .from, to = .to, valid_targets = .valid_targets))]
     InvalidTransition {
         from: String,
         to: String,
         valid_targets: String,
     },
 
-    #[error(
-        "would create a dependency cycle\n  hint: this would create a circular dependency chain"
-    )]
+    #[error("{}", t!("error.conflict", id = .id, expected = .expected, actual = .actual))]
+    Conflict {
+        id: String,
+        expected: u32,
+        actual: u32,
+    },
+
+    #[error("{}", t!("error.cycle_detected"))]
     CycleDetected,
 
-    #[error("cannot create self-dependency\n  hint: an issue cannot block or track itself")]
+    #[error("{}", t!("error.self_dependency"))]
     SelfDependency,
 
     #[error("dependency not found: {from} {rel} {to}")]
@@ -51,7 +59,7 @@ pub enum Error {
     #[error("invalid status: '{0}'\n  hint: valid statuses are: todo, in_progress, done, closed")]
     InvalidStatus(String),
 
-    #[error("invalid relation: '{0}'\n  hint: valid relations are: blocks, blocked-by, tracks, tracked-by")]
+    #[error("invalid relation: '{0}'\n  hint: valid relations are: blocks, blocked-by, tracks, tracked-by, relates-to, duplicate-of")]
     InvalidRelation(String),
 
     #[error("invalid link type: '{0}'\n  hint: valid types are: github, jira, gitlab, confluence")]
@@ -60,10 +68,15 @@ pub enum Error {
     #[error("invalid link relation: '{0}'\n  hint: valid relations are: import, blocks, tracks, tracked-by")]
     InvalidLinkRel(String),
 
+    #[error(
+        "invalid note kind: '{0}'\n  hint: valid kinds are: decision, blocker, worklog, handoff"
+    )]
+    InvalidNoteKind(String),
+
     #[error("invalid prefix: must be 2+ lowercase alphanumeric with at least one letter")]
     InvalidPrefix,
 
-    #[error("workspace not found: {0}\n  hint: the workspace directory must exist before creating a link")]
+    #[error("workspace not found: {0}\n  hint: pass the path of a project in the workspace, or an ID from 'wok config workspaces'")]
     WorkspaceNotFound(String),
 
     // Phase 1: Filter Parser Errors
@@ -82,6 +95,11 @@ pub enum Error {
     #[error("invalid duration: {reason}")]
     InvalidDuration { reason: String },
 
+    #[error(
+        "invalid estimate '{value}'\n  hint: use points (3, 3pt) or a duration (30m, 4h, 2d, 1w)"
+    )]
+    InvalidEstimate { value: String },
+
     // Phase 2: Command Validation Errors
     #[error("operation cancelled")]
     Cancelled,
@@ -113,6 +131,32 @@ pub enum Error {
     #[error("invalid timestamp: {reason}")]
     InvalidTimestamp { reason: String },
 
+    #[error("invalid mapping file {path}: {reason}")]
+    InvalidMapping { path: String, reason: String },
+
+    #[error("cannot import {record}: {reason}")]
+    InvalidRecord { record: String, reason: String },
+
+    #[error("unknown CSV columns: {columns}\n  hint: map them with --column '{example}=FIELD' (FIELD: title, type, status, labels, assignee, description, id, label:PREFIX, or ignore), or pass --interactive")]
+    UnknownCsvColumns { columns: String, example: String },
+
+    #[error("invalid CSV column mapping '{spec}': {reason}")]
+    InvalidCsvColumn { spec: String, reason: String },
+
+    #[error(
+        "invalid cursor: '{token}'\n  hint: pass the next_cursor of a previous -o json-paged page"
+    )]
+    InvalidCursor { token: String },
+
+    #[error("{option} cannot be used with {with}")]
+    IncompatibleOptions {
+        option: &'static str,
+        with: &'static str,
+    },
+
+    #[error("{id} did not exist at {at}")]
+    NotYetCreated { id: String, at: String },
+
     // Phase 3: Link and Edit Errors
     #[error("{requirement} requires {dependency}")]
     LinkRequires {
@@ -127,6 +171,9 @@ pub enum Error {
     #[error("no notes to replace for issue {issue_id}")]
     NoNotesToReplace { issue_id: String },
 
+    #[error("note {note_id} not found on issue {issue_id}")]
+    NoteNotFound { issue_id: String, note_id: i64 },
+
     #[error("{field} is required")]
     FieldRequired { field: &'static str },
 
@@ -136,6 +183,104 @@ pub enum Error {
     #[error("unknown format '{format}'")]
     UnknownFormat { format: String },
 
+    #[error("alias not found: {0}")]
+    AliasNotFound(String),
+
+    #[error("invalid alias '{name}': {reason}")]
+    InvalidAlias { name: String, reason: String },
+
+    #[error("alias loop: {chain}\n  hint: an alias cannot expand back to itself")]
+    AliasLoop { chain: String },
+
+    #[error("unknown setting '{0}'\n  hint: run 'wok config list' to see every setting")]
+    UnknownSetting(String),
+
+    #[error("invalid value for {key}: {reason}")]
+    InvalidSetting { key: String, reason: String },
+
+    #[error("{id} has no link to open\n  hint: add one with 'wok link {id} <url>', or set open.url to a page for every issue")]
+    NoLinkToOpen { id: String },
+
+    #[error("{id} has {count} links to open:\n{urls}\n  hint: pick one with --link <type>, or open them all with --all")]
+    AmbiguousLink {
+        id: String,
+        count: usize,
+        urls: String,
+    },
+
+    #[error("no web page for {link}\n  hint: set url = \"https://.../{{id}}\" under [links.{scheme}] in .wok/config.toml")]
+    NoLinkUrl { link: String, scheme: String },
+
+    #[error("could not open {url}: {reason}\n  hint: set BROWSER, or print the URL with --print")]
+    OpenFailed { url: String, reason: String },
+
+    #[error("cannot fetch {url}: {reason}\n  hint: set a token under [api.<provider>] in ~/.config/wok/config.toml")]
+    FetchFailed { url: String, reason: String },
+
+    #[error("cannot merge {from} into {into}: {reason}")]
+    CannotMerge {
+        from: String,
+        into: String,
+        reason: String,
+    },
+
+    #[error("cannot rename {old} to {new}: {reason}")]
+    CannotRenameLabel {
+        old: String,
+        new: String,
+        reason: String,
+    },
+
+    #[error("invalid label '{label}': {reason}\n  hint: {hint}")]
+    InvalidLabel {
+        label: String,
+        reason: String,
+        hint: String,
+    },
+
+    #[error("invalid label color: '{0}'\n  hint: use a name such as red or blue, or a 256-color code (0-255)")]
+    InvalidLabelColor(String),
+
+    #[error("cannot split {id}: {reason}")]
+    CannotSplit { id: String, reason: String },
+
+    #[error("invalid rpc message: {reason}")]
+    InvalidRpcMessage { reason: String },
+
+    #[error("cannot change issues: the database was opened with --read-only\n  hint: drop --read-only to make changes")]
+    ReadOnly,
+
+    #[error("git {command} failed: {reason}")]
+    GitFailed { command: String, reason: String },
+
+    #[error("{path} is not a wok hook\n  hint: remove it, or have it run '{command}'")]
+    GitHookExists { path: String, command: String },
+
+    #[error("dynamic completions are not available for {shell}\n  hint: use bash, zsh or fish")]
+    UnsupportedShell { shell: String },
+
+    #[error("no service manager support on {os}\n  hint: wokd services use systemd on Linux and launchd on macOS")]
+    ServiceUnsupported { os: String },
+
+    #[error("no current user for @me\n  hint: set user = \"name\" in .wok/config.toml")]
+    UserNotConfigured,
+
+    #[error("unknown assignee '{name}'\n  hint: team members are: {team}")]
+    UnknownAssignee { name: String, team: String },
+
+    #[error("{assignee} is at the WIP limit ({count}/{limit} in progress)\n  hint: finish or stop one first, or raise the limit under [wip] in .wok/config.toml")]
+    WipLimitExceeded {
+        assignee: String,
+        count: usize,
+        limit: usize,
+    },
+
+    #[error("no ready issues in {queue}")]
+    QueueEmpty { queue: String },
+
+    #[error("unknown prime profile '{name}'\n  hint: available profiles: {available}")]
+    UnknownPrimeProfile { name: String, available: String },
+
     #[error("cannot create issue: {reason}")]
     CannotCreateIssue { reason: String },
 
@@ -162,6 +307,9 @@ pub enum Error {
     #[error("export path cannot be empty")]
     ExportPathEmpty,
 
+    #[error("database schema version {version} is newer than this wok supports ({supported})\n  hint: upgrade wok to use this database")]
+    SchemaTooNew { version: u32, supported: u32 },
+
     #[error("database error: {0}")]
     Database(#[from] rusqlite::Error),
 
@@ -180,12 +328,39 @@ pub enum Error {
     #[error("daemon error: {0}")]
     Daemon(String),
 
+    /// A database failure inside the daemon, with its category.
+    #[error("{message}")]
+    DaemonDb {
+        kind: wk_ipc::DaemonDbError,
+        message: String,
+    },
+
     #[error("daemon version mismatch: daemon is v{daemon_version}, CLI is v{cli_version}")]
     DaemonVersionMismatch {
         daemon_version: String,
         cli_version: String,
     },
 
+    #[error("cannot upgrade while the daemon is running (PID {pid})\n  hint: run 'wok daemon stop' first")]
+    UpgradeDaemonRunning { pid: u32 },
+
+    #[error("cannot change mode while the daemon is running (PID {pid})\n  hint: run 'wok daemon stop' first")]
+    ModeDaemonRunning { pid: u32 },
+
+    #[error("cannot restore while the daemon is running (PID {pid})\n  hint: run 'wok daemon stop' first")]
+    RestoreDaemonRunning { pid: u32 },
+
+    #[error("backup destination already exists: {0}\n  hint: choose another --to path")]
+    BackupExists(String),
+
+    #[error("{path} is not a valid backup: {reason}")]
+    InvalidBackup { path: String, reason: String },
+
+    #[error(
+        "hook '{name}' failed: {reason}\n  hint: see 'wok hook runs --failed' for recent failures"
+    )]
+    HookFailed { name: String, reason: String },
+
     #[error("daemon timeout: {0}")]
     DaemonTimeout(String),
 
@@ -201,6 +376,267 @@ pub enum Error {
 /// A specialized Result type for wkrs operations.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Stable category of an [`Error`], reported by `--error-format json`.
+///
+/// Scripts branch on these instead of message text, so existing codes must
+/// not be renamed; new variants map onto an existing code where one fits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    NotInitialized,
+    AlreadyInitialized,
+    NotFound,
+    AmbiguousId,
+    InvalidTransition,
+    DependencyCycle,
+    SelfDependency,
+    InvalidArgument,
+    InvalidFilter,
+    Cancelled,
+    PermissionDenied,
+    LimitExceeded,
+    QueueEmpty,
+    /// The issue changed since the revision given with `--if-match`.
+    Conflict,
+    HookFailed,
+    PartialFailure,
+    Config,
+    Daemon,
+    Database,
+    Io,
+    CorruptedData,
+    /// Command-line parsing failed before any command ran.
+    Usage,
+}
+
+impl ErrorCode {
+    /// Process exit code for this kind of failure.
+    ///
+    /// | code | meaning |
+    /// |------|---------|
+    /// | 1 | other failure (I/O, database, hooks, ...) |
+    /// | 2 | invalid input or usage |
+    /// | 3 | issue or other target not found |
+    /// | 4 | refused by workflow rules (transition, WIP limit, empty queue) |
+    /// | 5 | daemon unavailable or incompatible |
+    /// | 6 | bulk operation partly failed |
+    /// | 7 | not initialized |
+    /// | 8 | issue changed since it was read (`--if-match`) |
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorCode::InvalidArgument
+            | ErrorCode::InvalidFilter
+            | ErrorCode::AmbiguousId
+            | ErrorCode::DependencyCycle
+            | ErrorCode::SelfDependency
+            | ErrorCode::Usage => 2,
+            ErrorCode::NotFound => 3,
+            ErrorCode::InvalidTransition | ErrorCode::LimitExceeded | ErrorCode::QueueEmpty => 4,
+            ErrorCode::Daemon => 5,
+            ErrorCode::PartialFailure => 6,
+            ErrorCode::NotInitialized => 7,
+            ErrorCode::Conflict => 8,
+            ErrorCode::AlreadyInitialized
+            | ErrorCode::Cancelled
+            | ErrorCode::PermissionDenied
+            | ErrorCode::HookFailed
+            | ErrorCode::Config
+            | ErrorCode::Database
+            | ErrorCode::Io
+            | ErrorCode::CorruptedData => 1,
+        }
+    }
+}
+
+/// Structured form of an error, printed as JSON on stderr.
+#[derive(Debug, Serialize)]
+pub struct ErrorReport {
+    pub code: ErrorCode,
+    /// The error message without its hint lines.
+    pub message: String,
+    /// Issue IDs the error is about.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<String>,
+    /// Hints and candidate fixes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+}
+
+impl ErrorReport {
+    /// Build a report from a message whose hint lines start with `hint:`,
+    /// or its translation.
+    pub fn from_message(code: ErrorCode, text: &str) -> Self {
+        let marker = t!("error.hint_marker");
+        let mut message = Vec::new();
+        let mut suggestions = Vec::new();
+        for line in text.lines() {
+            let line_start = line.trim_start();
+            let hint = line_start
+                .strip_prefix("hint:")
+                .or_else(|| line_start.strip_prefix(marker.as_str()));
+            match hint {
+                Some(hint) => suggestions.push(hint.trim().to_string()),
+                None => message.push(line),
+            }
+        }
+        ErrorReport {
+            code,
+            message: message.join("\n"),
+            ids: Vec::new(),
+            suggestions,
+        }
+    }
+}
+
+impl Error {
+    /// The stable code for this error.
+    #[allow(deprecated)]
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::NotInitialized => ErrorCode::NotInitialized,
+            Error::AlreadyInitialized(_) => ErrorCode::AlreadyInitialized,
+            Error::IssueNotFound(_)
+            | Error::DependencyNotFound { .. }
+            | Error::WorkspaceNotFound(_)
+            | Error::NotYetCreated { .. }
+            | Error::NoNotesToReplace { .. }
+            | Error::NoteNotFound { .. }
+            | Error::NoInputFile
+            | Error::NoLinkToOpen { .. }
+            | Error::AliasNotFound(_) => ErrorCode::NotFound,
+            Error::AmbiguousId { .. } => ErrorCode::AmbiguousId,
+            Error::InvalidTransition { .. } | Error::CannotNoteClosedIssue => {
+                ErrorCode::InvalidTransition
+            }
+            Error::Conflict { .. } => ErrorCode::Conflict,
+            Error::CycleDetected => ErrorCode::DependencyCycle,
+            Error::SelfDependency => ErrorCode::SelfDependency,
+            Error::InvalidIssueType(_)
+            | Error::InvalidStatus(_)
+            | Error::InvalidRelation(_)
+            | Error::InvalidLinkType(_)
+            | Error::InvalidLinkRel(_)
+            | Error::InvalidNoteKind(_)
+            | Error::InvalidPrefix
+            | Error::InvalidDuration { .. }
+            | Error::InvalidEstimate { .. }
+            | Error::RequiredFor { .. }
+            | Error::CannotDerive { .. }
+            | Error::ParseLineError { .. }
+            | Error::InvalidScope { .. }
+            | Error::TtyRequired
+            | Error::InvalidTimestamp { .. }
+            | Error::InvalidMapping { .. }
+            | Error::InvalidRecord { .. }
+            | Error::UnknownCsvColumns { .. }
+            | Error::InvalidCsvColumn { .. }
+            | Error::InvalidCursor { .. }
+            | Error::IncompatibleOptions { .. }
+            | Error::LinkRequires { .. }
+            | Error::UnknownAttribute { .. }
+            | Error::FieldRequired { .. }
+            | Error::UnknownFormat { .. }
+            | Error::UnsupportedShell { .. }
+            | Error::ServiceUnsupported { .. }
+            | Error::AmbiguousLink { .. }
+            | Error::NoLinkUrl { .. }
+            | Error::GitHookExists { .. }
+            | Error::CannotMerge { .. }
+            | Error::CannotSplit { .. }
+            | Error::InvalidRpcMessage { .. }
+            | Error::CannotRenameLabel { .. }
+            | Error::InvalidLabelColor(_)
+            | Error::InvalidLabel { .. }
+            | Error::ReadOnly
+            | Error::InvalidAlias { .. }
+            | Error::AliasLoop { .. }
+            | Error::UnknownSetting(_)
+            | Error::InvalidSetting { .. }
+            | Error::UserNotConfigured
+            | Error::UnknownAssignee { .. }
+            | Error::UnknownPrimeProfile { .. }
+            | Error::CannotCreateIssue { .. }
+            | Error::InvalidInput(_)
+            | Error::FieldEmpty { .. }
+            | Error::ExportPathEmpty
+            | Error::BackupExists(_) => ErrorCode::InvalidArgument,
+            Error::FilterEmpty
+            | Error::FilterUnknownField { .. }
+            | Error::FilterInvalidOperator { .. }
+            | Error::FilterInvalidValue { .. } => ErrorCode::InvalidFilter,
+            Error::Cancelled => ErrorCode::Cancelled,
+            Error::PermissionDenied { .. } => ErrorCode::PermissionDenied,
+            Error::WipLimitExceeded { .. }
+            | Error::FieldTooLong { .. }
+            | Error::LabelLimitExceeded { .. } => ErrorCode::LimitExceeded,
+            Error::QueueEmpty { .. } => ErrorCode::QueueEmpty,
+            Error::HookFailed { .. } => ErrorCode::HookFailed,
+            Error::PartialBulkFailure { .. } => ErrorCode::PartialFailure,
+            Error::Config(_) => ErrorCode::Config,
+            Error::Daemon(_)
+            | Error::DaemonVersionMismatch { .. }
+            | Error::UpgradeDaemonRunning { .. }
+            | Error::ModeDaemonRunning { .. }
+            | Error::RestoreDaemonRunning { .. }
+            | Error::DaemonTimeout(_) => ErrorCode::Daemon,
+            Error::Database(_) | Error::IdGenerationFailed | Error::SchemaTooNew { .. } => {
+                ErrorCode::Database
+            }
+            Error::Io(_)
+            | Error::Json(_)
+            | Error::OpenFailed { .. }
+            | Error::FetchFailed { .. }
+            | Error::GitFailed { .. } => ErrorCode::Io,
+            Error::CorruptedData(_) | Error::InvalidBackup { .. } => ErrorCode::CorruptedData,
+            Error::DaemonDb { kind, .. } => match kind {
+                wk_ipc::DaemonDbError::NotFound => ErrorCode::NotFound,
+                wk_ipc::DaemonDbError::Conflict | wk_ipc::DaemonDbError::Constraint => {
+                    ErrorCode::InvalidArgument
+                }
+                wk_ipc::DaemonDbError::Busy => ErrorCode::Database,
+                wk_ipc::DaemonDbError::Corrupt => ErrorCode::CorruptedData,
+            },
+        }
+    }
+
+    /// Issue IDs this error is about, in the order they appear.
+    pub fn ids(&self) -> Vec<String> {
+        match self {
+            Error::IssueNotFound(id)
+            | Error::NotYetCreated { id, .. }
+            | Error::Conflict { id, .. } => vec![id.clone()],
+            Error::NoNotesToReplace { issue_id } | Error::NoteNotFound { issue_id, .. } => {
+                vec![issue_id.clone()]
+            }
+            Error::NoLinkToOpen { id } | Error::AmbiguousLink { id, .. } => vec![id.clone()],
+            Error::CannotMerge { from, into, .. } => vec![from.clone(), into.clone()],
+            Error::CannotSplit { id, .. } => vec![id.clone()],
+            Error::AmbiguousId { prefix, .. } => vec![prefix.clone()],
+            Error::DependencyNotFound { from, to, .. } => vec![from.clone(), to.clone()],
+            Error::PartialBulkFailure {
+                unknown_ids,
+                transition_failures,
+                ..
+            } => unknown_ids
+                .iter()
+                .cloned()
+                .chain(transition_failures.iter().map(|(id, _)| id.clone()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Structured form of this error for `--error-format json`.
+    pub fn report(&self) -> ErrorReport {
+        let mut report = ErrorReport::from_message(self.code(), &self.to_string());
+        report.ids = self.ids();
+        if let Error::AmbiguousId { matches, .. } = self {
+            report.suggestions.extend(matches.iter().cloned());
+        }
+        report
+    }
+}
+
 // NOTE(compat): maps core errors that hit the deprecated InvalidInput variant
 #[allow(deprecated)]
 impl From<wk_core::Error> for Error {
@@ -219,6 +655,15 @@ impl From<wk_core::Error> for Error {
                 to,
                 valid_targets,
             },
+            wk_core::Error::Conflict {
+                id,
+                expected,
+                actual,
+            } => Error::Conflict {
+                id,
+                expected,
+                actual,
+            },
             wk_core::Error::CycleDetected => Error::CycleDetected,
             wk_core::Error::SelfDependency => Error::SelfDependency,
             wk_core::Error::DependencyNotFound { from, rel, to } => {
@@ -232,15 +677,32 @@ impl From<wk_core::Error> for Error {
             }
             wk_core::Error::InvalidLinkType(s) => Error::InvalidLinkType(s),
             wk_core::Error::InvalidLinkRel(s) => Error::InvalidLinkRel(s),
+            wk_core::Error::InvalidNoteKind(s) => Error::InvalidNoteKind(s),
             wk_core::Error::InvalidInput(s) => Error::InvalidInput(s),
             wk_core::Error::Database(e) => Error::Database(e),
             wk_core::Error::Io(e) => Error::Io(e),
             wk_core::Error::Json(e) => Error::Json(e),
             wk_core::Error::CorruptedData(s) => Error::CorruptedData(s),
+            wk_core::Error::SchemaTooNew { version, supported } => {
+                Error::SchemaTooNew { version, supported }
+            }
             wk_core::Error::DuplicateOp(s) => Error::InvalidInput(format!("duplicate op: {}", s)),
             wk_core::Error::InvalidHlc(s) => Error::InvalidInput(format!("invalid HLC: {}", s)),
             wk_core::Error::Oplog(s) => Error::Daemon(format!("oplog error: {}", s)),
             wk_core::Error::NoNotesToReplace { issue_id } => Error::NoNotesToReplace { issue_id },
+            wk_core::Error::NoteNotFound { issue_id, note_id } => {
+                Error::NoteNotFound { issue_id, note_id }
+            }
+            wk_core::Error::FetchFailed { url, reason } => Error::FetchFailed { url, reason },
+        }
+    }
+}
+
+impl From<wk_ipc::framing::FrameError> for Error {
+    fn from(e: wk_ipc::framing::FrameError) -> Self {
+        match e {
+            wk_ipc::framing::FrameError::Io(e) => Error::Io(e),
+            other => Error::Daemon(other.to_string()),
         }
     }
 }
@@ -5,6 +5,7 @@
 #![allow(clippy::expect_used)]
 
 use super::*;
+use yare::parameterized;
 
 #[test]
 fn test_error_not_initialized_display() {
@@ -92,6 +93,7 @@ fn test_error_invalid_relation_display() {
     assert!(msg.contains("invalid relation"));
     assert!(msg.contains("depends"));
     assert!(msg.contains("blocks, blocked-by, tracks, tracked-by"));
+    assert!(msg.contains("relates-to, duplicate-of"));
 }
 
 #[test]
@@ -378,3 +380,88 @@ fn test_error_export_path_empty_display() {
     assert!(err.to_string().contains("export path"));
     assert!(err.to_string().contains("cannot be empty"));
 }
+
+#[test]
+fn test_error_codes_are_stable_snake_case() {
+    let cases = [
+        (Error::NotInitialized, "\"not_initialized\""),
+        (Error::IssueNotFound("prj-1".to_string()), "\"not_found\""),
+        (Error::CycleDetected, "\"dependency_cycle\""),
+        (
+            Error::InvalidStatus("x".to_string()),
+            "\"invalid_argument\"",
+        ),
+        (Error::FilterEmpty, "\"invalid_filter\""),
+        (
+            Error::QueueEmpty {
+                queue: "queue:merge".to_string(),
+            },
+            "\"queue_empty\"",
+        ),
+        (
+            Error::Conflict {
+                id: "prj-1".to_string(),
+                expected: 2,
+                actual: 3,
+            },
+            "\"conflict\"",
+        ),
+    ];
+    for (err, code) in cases {
+        assert_eq!(serde_json::to_string(&err.code()).unwrap(), code);
+    }
+}
+
+#[test]
+fn test_error_report_splits_hints_into_suggestions() {
+    let report = Error::UpgradeDaemonRunning { pid: 42 }.report();
+    assert_eq!(report.code, ErrorCode::Daemon);
+    assert_eq!(
+        report.message,
+        "cannot upgrade while the daemon is running (PID 42)"
+    );
+    assert_eq!(report.suggestions, vec!["run 'wok daemon stop' first"]);
+    assert!(report.ids.is_empty());
+}
+
+#[test]
+fn test_error_report_ambiguous_id_suggests_matches() {
+    let report = Error::AmbiguousId {
+        prefix: "prj-a".to_string(),
+        matches: vec!["prj-a1".to_string(), "prj-a2".to_string()],
+    }
+    .report();
+    assert_eq!(report.code, ErrorCode::AmbiguousId);
+    assert_eq!(report.ids, vec!["prj-a"]);
+    assert_eq!(report.suggestions, vec!["prj-a1", "prj-a2"]);
+}
+
+#[test]
+fn test_error_report_partial_failure_lists_ids() {
+    let err = Error::PartialBulkFailure {
+        succeeded: 1,
+        failed: 2,
+        unknown_ids: vec!["prj-x".to_string()],
+        transition_failures: vec![("prj-y".to_string(), "already done".to_string())],
+    };
+    let json = serde_json::to_value(err.report()).unwrap();
+    assert_eq!(json["code"], "partial_failure");
+    assert_eq!(json["ids"], serde_json::json!(["prj-x", "prj-y"]));
+    assert!(json.get("suggestions").is_none());
+}
+
+#[parameterized(
+    usage = { ErrorCode::Usage, 2 },
+    invalid_argument = { ErrorCode::InvalidArgument, 2 },
+    not_found = { ErrorCode::NotFound, 3 },
+    invalid_transition = { ErrorCode::InvalidTransition, 4 },
+    limit_exceeded = { ErrorCode::LimitExceeded, 4 },
+    daemon = { ErrorCode::Daemon, 5 },
+    partial_failure = { ErrorCode::PartialFailure, 6 },
+    not_initialized = { ErrorCode::NotInitialized, 7 },
+    conflict = { ErrorCode::Conflict, 8 },
+    database = { ErrorCode::Database, 1 },
+)]
+fn test_error_code_exit_code(code: ErrorCode, expected: i32) {
+    assert_eq!(code.exit_code(), expected);
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Issue estimates, kept as `estimate:` labels like priorities and due dates.
+//!
+//! An estimate is either story points or a duration of working time, where
+//! a day is 8 hours and a week is 5 days. Points and durations never mix:
+//! totals keep them apart and a capacity only fits estimates of its kind.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// Label prefix holding an issue's estimate.
+pub(crate) const ESTIMATE_PREFIX: &str = "estimate:";
+
+const HOUR: u32 = 60;
+const DAY: u32 = 8 * HOUR;
+const WEEK: u32 = 5 * DAY;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Estimate {
+    Points(u32),
+    Minutes(u32),
+}
+
+impl Estimate {
+    /// The `estimate:` label recording this estimate.
+    pub(crate) fn label(&self) -> String {
+        format!("{}{}", ESTIMATE_PREFIX, self)
+    }
+
+    /// The amount, in points or minutes.
+    fn amount(&self) -> u32 {
+        match self {
+            Estimate::Points(n) | Estimate::Minutes(n) => *n,
+        }
+    }
+
+    /// The same kind of estimate, with a different amount.
+    fn with_amount(&self, amount: u32) -> Estimate {
+        match self {
+            Estimate::Points(_) => Estimate::Points(amount),
+            Estimate::Minutes(_) => Estimate::Minutes(amount),
+        }
+    }
+
+    fn same_kind(&self, other: &Estimate) -> bool {
+        matches!(
+            (self, other),
+            (Estimate::Points(_), Estimate::Points(_))
+                | (Estimate::Minutes(_), Estimate::Minutes(_))
+        )
+    }
+}
+
+impl FromStr for Estimate {
+    type Err = Error;
+
+    /// Points as `3`, `3pt` or `3 points`; durations as `30m`, `4h`, `1.5d`
+    /// or `1w`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidEstimate {
+            value: s.to_string(),
+        };
+        let value = s.trim().to_lowercase();
+        let split = value
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+        let (numerator, denominator) = decimal(number).ok_or_else(invalid)?;
+        let per_unit = match unit.trim() {
+            "" | "p" | "pt" | "pts" | "point" | "points" => {
+                if numerator % denominator != 0 {
+                    return Err(invalid());
+                }
+                let points = u32::try_from(numerator / denominator).map_err(|_| invalid())?;
+                return Ok(Estimate::Points(points));
+            }
+            "m" | "min" => 1,
+            "h" => HOUR,
+            "d" => DAY,
+            "w" => WEEK,
+            _ => return Err(invalid()),
+        };
+        // Rounded to the nearest minute
+        let minutes = numerator
+            .checked_mul(u64::from(per_unit))
+            .and_then(|n| n.checked_add(denominator / 2))
+            .ok_or_else(invalid)?
+            / denominator;
+        let minutes = u32::try_from(minutes).map_err(|_| invalid())?;
+        Ok(Estimate::Minutes(minutes))
+    }
+}
+
+/// `number`, digits with at most one `.`, as a fraction: `1.5` is
+/// `(15, 10)`.
+fn decimal(number: &str) -> Option<(u64, u64)> {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if fraction.contains('.') {
+        return None;
+    }
+    let numerator = format!("{}{}", whole, fraction).parse().ok()?;
+    let denominator = 10u64.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+    Some((numerator, denominator))
+}
+
+impl fmt::Display for Estimate {
+    /// Points as `3pt`; durations in the largest unit that divides them.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Estimate::Points(n) => write!(f, "{}pt", n),
+            Estimate::Minutes(m) if m > 0 && m % WEEK == 0 => write!(f, "{}w", m / WEEK),
+            Estimate::Minutes(m) if m > 0 && m % DAY == 0 => write!(f, "{}d", m / DAY),
+            Estimate::Minutes(m) if m > 0 && m % HOUR == 0 => write!(f, "{}h", m / HOUR),
+            Estimate::Minutes(m) => write!(f, "{}m", m),
+        }
+    }
+}
+
+/// The estimate in `tags`, if any is valid.
+pub(crate) fn estimate_from_tags(tags: &[String]) -> Option<Estimate> {
+    tags.iter()
+        .filter_map(|tag| tag.strip_prefix(ESTIMATE_PREFIX))
+        .find_map(|value| value.parse().ok())
+}
+
+/// Estimates added up, points and durations apart.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EstimateTotal {
+    pub points: u32,
+    pub minutes: u32,
+}
+
+impl EstimateTotal {
+    pub(crate) fn add(&mut self, estimate: Estimate) {
+        match estimate {
+            Estimate::Points(n) => self.points = self.points.saturating_add(n),
+            Estimate::Minutes(m) => self.minutes = self.minutes.saturating_add(m),
+        }
+    }
+}
+
+impl fmt::Display for EstimateTotal {
+    /// `8pt + 2d`, leaving out a kind with nothing in it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.points, self.minutes) {
+            (0, 0) => write!(f, "{}", Estimate::Points(0)),
+            (points, 0) => write!(f, "{}", Estimate::Points(points)),
+            (0, minutes) => write!(f, "{}", Estimate::Minutes(minutes)),
+            (points, minutes) => write!(
+                f,
+                "{} + {}",
+                Estimate::Points(points),
+                Estimate::Minutes(minutes)
+            ),
+        }
+    }
+}
+
+/// The items chosen to fit a capacity.
+#[derive(Debug)]
+pub(crate) struct Plan<T> {
+    pub taken: Vec<T>,
+    /// How much of the capacity the taken items use.
+    pub used: Estimate,
+    /// Items skipped for having no estimate of the capacity's kind.
+    pub unestimated: usize,
+}
+
+/// Take items greedily, in order, while their estimates fit in what is left
+/// of `capacity`. An item too big for the rest is passed over so a smaller
+/// one after it can still fit.
+pub(crate) fn plan<T>(
+    items: Vec<T>,
+    capacity: Estimate,
+    estimate_of: impl Fn(&T) -> Option<Estimate>,
+) -> Plan<T> {
+    let mut remaining = capacity.amount();
+    let mut taken = Vec::new();
+    let mut unestimated = 0;
+    for item in items {
+        match estimate_of(&item) {
+            Some(estimate) if estimate.same_kind(&capacity) => {
+                if estimate.amount() <= remaining {
+                    remaining -= estimate.amount();
+                    taken.push(item);
+                }
+            }
+            _ => unestimated += 1,
+        }
+    }
+    Plan {
+        taken,
+        used: capacity.with_amount(capacity.amount() - remaining),
+        unestimated,
+    }
+}
+
+#[cfg(test)]
+#[path = "estimate_tests.rs"]
+mod tests;
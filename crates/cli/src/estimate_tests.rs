@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use yare::parameterized;
+
+#[parameterized(
+    bare_number = { "3", Estimate::Points(3) },
+    pt = { "3pt", Estimate::Points(3) },
+    spaced_points = { "5 points", Estimate::Points(5) },
+    uppercase = { "2PTS", Estimate::Points(2) },
+    zero = { "0", Estimate::Points(0) },
+    minutes = { "30m", Estimate::Minutes(30) },
+    hours = { "4h", Estimate::Minutes(240) },
+    days = { "2d", Estimate::Minutes(2 * DAY) },
+    fractional_days = { "1.5d", Estimate::Minutes(12 * HOUR) },
+    whole_fraction = { "2.0pt", Estimate::Points(2) },
+    rounded = { "0.01h", Estimate::Minutes(1) },
+    weeks = { "1w", Estimate::Minutes(WEEK) },
+)]
+fn parses(input: &str, expected: Estimate) {
+    assert_eq!(input.parse::<Estimate>().unwrap(), expected);
+}
+
+#[parameterized(
+    empty = { "" },
+    no_number = { "pt" },
+    fractional_points = { "1.5pt" },
+    negative = { "-2h" },
+    unknown_unit = { "3y" },
+    trailing = { "3h2m" },
+    two_points = { "1.2.3h" },
+    too_many_points = { "4294967296" },
+    too_long = { "9000000w" },
+)]
+fn rejects(input: &str) {
+    assert!(matches!(
+        input.parse::<Estimate>(),
+        Err(Error::InvalidEstimate { .. })
+    ));
+}
+
+#[parameterized(
+    points = { Estimate::Points(3), "3pt" },
+    minutes = { Estimate::Minutes(90), "90m" },
+    hours = { Estimate::Minutes(4 * HOUR), "4h" },
+    days = { Estimate::Minutes(12 * HOUR), "12h" },
+    whole_days = { Estimate::Minutes(3 * DAY), "3d" },
+    weeks = { Estimate::Minutes(2 * WEEK), "2w" },
+    zero = { Estimate::Minutes(0), "0m" },
+)]
+fn displays_in_largest_whole_unit(estimate: Estimate, expected: &str) {
+    assert_eq!(estimate.to_string(), expected);
+}
+
+#[test]
+fn label_round_trips_through_tags() {
+    let estimate = Estimate::Minutes(DAY);
+    let tags = vec!["backend".to_string(), estimate.label()];
+    assert_eq!(estimate.label(), "estimate:1d");
+    assert_eq!(estimate_from_tags(&tags), Some(estimate));
+}
+
+#[test]
+fn tags_without_valid_estimate_have_none() {
+    let tags = vec!["estimate:lots".to_string(), "priority:1".to_string()];
+    assert_eq!(estimate_from_tags(&tags), None);
+}
+
+#[test]
+fn totals_keep_points_and_durations_apart() {
+    let mut total = EstimateTotal::default();
+    assert_eq!(total.to_string(), "0pt");
+    total.add(Estimate::Points(3));
+    assert_eq!(total.to_string(), "3pt");
+    total.add(Estimate::Points(5));
+    total.add(Estimate::Minutes(DAY));
+    total.add(Estimate::Minutes(DAY));
+    assert_eq!(total.to_string(), "8pt + 2d");
+}
+
+#[test]
+fn plan_takes_what_fits_in_order() {
+    let items = vec![
+        Some(Estimate::Points(3)),
+        Some(Estimate::Points(5)),
+        None,
+        Some(Estimate::Points(2)),
+        Some(Estimate::Minutes(HOUR)),
+        Some(Estimate::Points(1)),
+    ];
+
+    let plan = plan(items, Estimate::Points(6), |item| *item);
+
+    assert_eq!(
+        plan.taken,
+        vec![
+            Some(Estimate::Points(3)),
+            Some(Estimate::Points(2)),
+            Some(Estimate::Points(1)),
+        ]
+    );
+    assert_eq!(plan.used, Estimate::Points(6));
+    assert_eq!(plan.unestimated, 2);
+}
+
+#[test]
+fn plan_with_durations_reports_time_used() {
+    let items = vec![Estimate::Minutes(4 * HOUR), Estimate::Minutes(DAY)];
+
+    let plan = plan(items, Estimate::Minutes(DAY), |item| Some(*item));
+
+    assert_eq!(plan.taken, vec![Estimate::Minutes(4 * HOUR)]);
+    assert_eq!(plan.used.to_string(), "4h");
+}
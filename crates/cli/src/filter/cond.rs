@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Translation of filter queries into database conditions.
+//!
+//! Lets commands push `-q` filters down into SQL instead of evaluating
+//! [`FilterQuery::matches`] on every issue. The translation keeps the
+//! in-memory semantics, including date-granular `<=`, `>`, `=`, and `!=`.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use wk_core::query::{Cmp, Cond, TimeField};
+
+use crate::models::Status;
+
+use super::expr::{AttrFilter, CompareOp, FilterExpr, FilterField, FilterQuery, FilterValue};
+
+impl FilterQuery {
+    /// The equivalent database condition, with durations measured from `now`.
+    pub fn to_cond(&self, now: DateTime<Utc>) -> Cond {
+        match self {
+            FilterQuery::Time(expr) => expr.to_cond(now),
+            FilterQuery::Attr(attr) => attr.to_cond(),
+            FilterQuery::And(parts) => Cond::All(parts.iter().map(|q| q.to_cond(now)).collect()),
+            FilterQuery::Or(parts) => Cond::Any(parts.iter().map(|q| q.to_cond(now)).collect()),
+        }
+    }
+}
+
+impl AttrFilter {
+    /// The equivalent database condition.
+    pub fn to_cond(&self) -> Cond {
+        let (cond, negated) = match self {
+            AttrFilter::Label { values, negated } => (
+                Cond::Any(values.iter().cloned().map(Cond::Label).collect()),
+                *negated,
+            ),
+            AttrFilter::Assignee { values, negated } => (Cond::Assignee(values.clone()), *negated),
+            AttrFilter::Status { values, negated } => (Cond::Status(values.clone()), *negated),
+            AttrFilter::Type { values, negated } => (Cond::Type(values.clone()), *negated),
+            AttrFilter::Priority { op, value } => return Cond::Priority(op.to_cmp(), *value),
+        };
+        if negated {
+            Cond::Not(Box::new(cond))
+        } else {
+            cond
+        }
+    }
+}
+
+impl FilterExpr {
+    /// The equivalent database condition, with durations measured from `now`.
+    pub fn to_cond(&self, now: DateTime<Utc>) -> Cond {
+        let (field, status) = match self.field {
+            FilterField::Age => (TimeField::Created, None),
+            FilterField::Updated => (TimeField::Updated, None),
+            FilterField::Completed => (TimeField::Closed, Some(vec![Status::Done])),
+            FilterField::Skipped => (TimeField::Closed, Some(vec![Status::Closed])),
+            FilterField::Closed => (TimeField::Closed, Some(vec![Status::Done, Status::Closed])),
+        };
+
+        let time = match &self.value {
+            // `age < 3d` is "created after now - 3d": the operator flips.
+            FilterValue::Duration(d) => Cond::Time(field, self.op.flipped().to_cmp(), now - *d),
+            FilterValue::Date(date) => {
+                let midnight = date.and_time(NaiveTime::MIN).and_utc();
+                self.date_cond(field, midnight, *date)
+            }
+            FilterValue::Now => self.date_cond(field, now, now.date_naive()),
+        };
+
+        match status {
+            Some(status) => Cond::All(vec![Cond::Status(status), Cond::HasTime(field), time]),
+            None => time,
+        }
+    }
+
+    /// Mirror `compare_datetime`, where some operators compare whole days.
+    fn date_cond(&self, field: TimeField, at: DateTime<Utc>, day: NaiveDate) -> Cond {
+        let on_day = || Cond::OnDate(field, day);
+        let not_on_day = || Cond::All(vec![Cond::HasTime(field), Cond::Not(Box::new(on_day()))]);
+        match self.op {
+            CompareOp::Lt => Cond::Time(field, Cmp::Lt, at),
+            CompareOp::Le => Cond::Any(vec![Cond::Time(field, Cmp::Lt, at), on_day()]),
+            CompareOp::Gt => Cond::All(vec![Cond::Time(field, Cmp::Ge, at), not_on_day()]),
+            CompareOp::Ge => Cond::Time(field, Cmp::Ge, at),
+            CompareOp::Eq => on_day(),
+            CompareOp::Ne => not_on_day(),
+        }
+    }
+}
+
+impl CompareOp {
+    fn to_cmp(self) -> Cmp {
+        match self {
+            CompareOp::Lt => Cmp::Lt,
+            CompareOp::Le => Cmp::Le,
+            CompareOp::Gt => Cmp::Gt,
+            CompareOp::Ge => Cmp::Ge,
+            CompareOp::Eq => Cmp::Eq,
+            CompareOp::Ne => Cmp::Ne,
+        }
+    }
+
+    /// The operator with its operands swapped.
+    fn flipped(self) -> Self {
+        match self {
+            CompareOp::Lt => CompareOp::Gt,
+            CompareOp::Le => CompareOp::Ge,
+            CompareOp::Gt => CompareOp::Lt,
+            CompareOp::Ge => CompareOp::Le,
+            CompareOp::Eq => CompareOp::Eq,
+            CompareOp::Ne => CompareOp::Ne,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "cond_tests.rs"]
+mod tests;
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::db::Database;
+use crate::filter::parse_query;
+use crate::models::{Issue, IssueType};
+use chrono::{Duration, TimeZone};
+use std::collections::HashMap;
+use wk_core::query::IssueQuery;
+use yare::parameterized;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap()
+}
+
+/// id, days ago, status, assignee, labels
+type IssueSpec = (
+    &'static str,
+    i64,
+    Status,
+    Option<&'static str>,
+    &'static [&'static str],
+);
+
+/// Issues spread over the last two weeks with a mix of states and labels.
+fn fixture() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    let specs: [IssueSpec; 6] = [
+        (
+            "prj-1",
+            1,
+            Status::Todo,
+            Some("alice"),
+            &["urgent", "priority:0"],
+        ),
+        ("prj-2", 4, Status::InProgress, Some("bob"), &["team:api"]),
+        ("prj-3", 9, Status::Done, None, &["p:1"]),
+        ("prj-4", 10, Status::Closed, Some("alice"), &[]),
+        ("prj-5", 14, Status::Todo, None, &["team:web", "urgent"]),
+        ("prj-6", 0, Status::Todo, None, &[]),
+    ];
+    for (id, days_ago, status, assignee, labels) in specs {
+        let created = now() - Duration::days(days_ago) - Duration::hours(1);
+        let mut issue = Issue::new(id.to_string(), IssueType::Task, id.to_string(), created);
+        issue.status = status;
+        issue.assignee = assignee.map(String::from);
+        if matches!(status, Status::Done | Status::Closed) {
+            issue.closed_at = Some(created + Duration::days(1));
+        }
+        db.create_issue(&issue).unwrap();
+        for label in labels {
+            db.add_label(id, label).unwrap();
+        }
+    }
+    db
+}
+
+#[parameterized(
+    age_lt = { "age < 3d" },
+    age_ge = { "age >= 1w" },
+    age_eq_duration = { "age = 1w" },
+    created_le_date = { "created <= 2026-03-06" },
+    created_gt_date = { "created > 2026-03-06" },
+    created_eq_date = { "created = 2026-03-09" },
+    created_ne_date = { "created != 2026-03-09" },
+    updated_lt_now = { "updated < now" },
+    closed_bare = { "closed" },
+    completed_within = { "completed < 1w" },
+    skipped_ne_date = { "skipped != 2026-03-01" },
+    label = { "label = urgent" },
+    label_wildcard = { "label = team:*" },
+    label_ne = { "label != urgent" },
+    assignee_ne = { "assignee != alice" },
+    status_in = { "status in (todo, done)" },
+    type_ne = { "type != task" },
+    priority = { "priority <= 1" },
+    combined = { "label = urgent and (assignee != alice or priority > 1)" },
+    or_of_time = { "age < 2d or closed > 2026-02-01" },
+)]
+fn cond_selects_same_issues_as_matches(input: &str) {
+    let db = fixture();
+    let query = parse_query(input).unwrap();
+
+    let all = db.list_issues(None, None, None).unwrap();
+    let ids: Vec<&str> = all.iter().map(|i| i.id.as_str()).collect();
+    let labels: HashMap<String, Vec<String>> = db.get_labels_batch(&ids).unwrap();
+    let mut expected: Vec<String> = all
+        .iter()
+        .filter(|i| query.matches(i, labels.get(&i.id).map_or(&[], Vec::as_slice), now()))
+        .map(|i| i.id.clone())
+        .collect();
+    expected.sort();
+
+    let mut pushed: Vec<String> = db
+        .query_issues(&IssueQuery::new(query.to_cond(now())))
+        .unwrap()
+        .into_iter()
+        .map(|i| i.id)
+        .collect();
+    pushed.sort();
+
+    assert_eq!(pushed, expected, "{}", input);
+}
+
+#[test]
+fn duration_operators_flip_onto_timestamps() {
+    let cond = parse_query("age < 3d").unwrap().to_cond(now());
+    assert_eq!(
+        cond,
+        Cond::Time(TimeField::Created, Cmp::Gt, now() - Duration::days(3))
+    );
+}
@@ -5,9 +5,83 @@
 
 use chrono::{DateTime, Duration, NaiveTime, Utc};
 
+use crate::commands::filtering::label_matches;
 use crate::models::{Issue, Status};
 
-use super::expr::{CompareOp, FilterExpr, FilterField, FilterValue};
+use super::expr::{AttrFilter, CompareOp, FilterExpr, FilterField, FilterQuery, FilterValue};
+
+impl FilterQuery {
+    /// Evaluate this query against an issue and its labels.
+    pub fn matches(&self, issue: &Issue, labels: &[String], now: DateTime<Utc>) -> bool {
+        match self {
+            FilterQuery::Time(expr) => expr.matches(issue, now),
+            FilterQuery::Attr(attr) => attr.matches(issue, labels),
+            FilterQuery::And(parts) => parts.iter().all(|q| q.matches(issue, labels, now)),
+            FilterQuery::Or(parts) => parts.iter().any(|q| q.matches(issue, labels, now)),
+        }
+    }
+
+    /// Whether evaluating needs the issue's labels.
+    pub fn needs_labels(&self) -> bool {
+        self.any_leaf(&|q| {
+            matches!(
+                q,
+                FilterQuery::Attr(AttrFilter::Label { .. } | AttrFilter::Priority { .. })
+            )
+        })
+    }
+
+    /// Whether the query picks issues by status, through a status comparison
+    /// or a terminal-state time field. Commands that show only open issues by
+    /// default drop that default when this is true.
+    pub fn selects_status(&self) -> bool {
+        self.any_leaf(&|q| match q {
+            FilterQuery::Attr(AttrFilter::Status { .. }) => true,
+            FilterQuery::Time(expr) => matches!(
+                expr.field,
+                FilterField::Completed | FilterField::Skipped | FilterField::Closed
+            ),
+            _ => false,
+        })
+    }
+
+    fn any_leaf(&self, pred: &dyn Fn(&FilterQuery) -> bool) -> bool {
+        match self {
+            FilterQuery::And(parts) | FilterQuery::Or(parts) => {
+                parts.iter().any(|q| q.any_leaf(pred))
+            }
+            leaf => pred(leaf),
+        }
+    }
+}
+
+impl AttrFilter {
+    /// Evaluate this comparison against an issue and its labels.
+    pub fn matches(&self, issue: &Issue, labels: &[String]) -> bool {
+        let (found, negated) = match self {
+            AttrFilter::Label { values, negated } => (
+                values
+                    .iter()
+                    .any(|v| labels.iter().any(|l| label_matches(v, l))),
+                *negated,
+            ),
+            AttrFilter::Assignee { values, negated } => (
+                issue
+                    .assignee
+                    .as_ref()
+                    .is_some_and(|a| values.iter().any(|v| v == a)),
+                *negated,
+            ),
+            AttrFilter::Status { values, negated } => (values.contains(&issue.status), *negated),
+            AttrFilter::Type { values, negated } => (values.contains(&issue.issue_type), *negated),
+            AttrFilter::Priority { op, value } => {
+                let priority = crate::db::priority_from_tags(labels);
+                return op.compare(&priority, value);
+            }
+        };
+        found != negated
+    }
+}
 
 impl FilterExpr {
     /// Evaluate this filter against an issue at a given reference time.
@@ -72,6 +146,18 @@ impl FilterExpr {
 }
 
 impl CompareOp {
+    /// Compare two ordered values.
+    fn compare<T: PartialOrd>(&self, actual: &T, threshold: &T) -> bool {
+        match self {
+            CompareOp::Lt => actual < threshold,
+            CompareOp::Le => actual <= threshold,
+            CompareOp::Gt => actual > threshold,
+            CompareOp::Ge => actual >= threshold,
+            CompareOp::Eq => actual == threshold,
+            CompareOp::Ne => actual != threshold,
+        }
+    }
+
     /// Compare two durations.
     fn compare_duration(&self, actual: Duration, threshold: Duration) -> bool {
         match self {
@@ -23,6 +23,7 @@ fn make_issue_at(created: DateTime<Utc>, updated: DateTime<Utc>) -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -360,6 +361,7 @@ fn make_closed_issue(closed: DateTime<Utc>) -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -476,6 +478,7 @@ fn make_done_issue(closed: DateTime<Utc>) -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -495,6 +498,7 @@ fn make_cancelled_issue(closed: DateTime<Utc>) -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -722,3 +726,97 @@ fn now_value_with_ge_operator() {
     };
     assert!(!expr.matches(&issue, now));
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Queries
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn query_matches(query: &str, issue: &Issue, labels: &[&str]) -> bool {
+    let labels: Vec<String> = labels.iter().map(|l| l.to_string()).collect();
+    crate::filter::parse_query(query)
+        .unwrap()
+        .matches(issue, &labels, Utc::now())
+}
+
+fn assigned_issue(assignee: Option<&str>) -> Issue {
+    let mut issue = make_issue_created_at(Utc::now() - Duration::days(1));
+    issue.assignee = assignee.map(String::from);
+    issue
+}
+
+#[test]
+fn query_label_matches_any_listed_value() {
+    let issue = assigned_issue(None);
+    assert!(query_matches("label = urgent", &issue, &["urgent"]));
+    assert!(query_matches("label in (a, urgent)", &issue, &["urgent"]));
+    assert!(query_matches("label = team:*", &issue, &["team:api"]));
+    assert!(!query_matches("label = urgent", &issue, &["later"]));
+    assert!(!query_matches("label != urgent", &issue, &["urgent"]));
+    assert!(query_matches("label != urgent", &issue, &[]));
+}
+
+#[test]
+fn query_assignee_treats_unassigned_as_no_name() {
+    let alice = assigned_issue(Some("alice"));
+    let nobody = assigned_issue(None);
+    assert!(query_matches("assignee = alice", &alice, &[]));
+    assert!(!query_matches("assignee != alice", &alice, &[]));
+    assert!(!query_matches("assignee = alice", &nobody, &[]));
+    assert!(query_matches("assignee != alice", &nobody, &[]));
+}
+
+#[test]
+fn query_status_and_type() {
+    let mut issue = assigned_issue(None);
+    issue.status = Status::InProgress;
+    issue.issue_type = IssueType::Bug;
+    assert!(query_matches("status in (todo, in_progress)", &issue, &[]));
+    assert!(!query_matches("status = todo", &issue, &[]));
+    assert!(query_matches("type = bug", &issue, &[]));
+    assert!(!query_matches("type != bug", &issue, &[]));
+}
+
+#[test]
+fn query_priority_uses_labels_with_default() {
+    let issue = assigned_issue(None);
+    assert!(query_matches("priority <= 1", &issue, &["priority:1"]));
+    assert!(!query_matches("priority <= 1", &issue, &["priority:3"]));
+    assert!(query_matches("priority = 2", &issue, &[]));
+}
+
+#[test]
+fn query_combinators() {
+    let issue = assigned_issue(Some("alice"));
+    assert!(query_matches(
+        "label = urgent and assignee = alice",
+        &issue,
+        &["urgent"]
+    ));
+    assert!(!query_matches(
+        "label = urgent and assignee = bob",
+        &issue,
+        &["urgent"]
+    ));
+    assert!(query_matches(
+        "assignee = bob or (label = urgent and age < 3d)",
+        &issue,
+        &["urgent"]
+    ));
+    assert!(!query_matches(
+        "assignee = bob or (label = urgent and age > 3d)",
+        &issue,
+        &["urgent"]
+    ));
+}
+
+#[test]
+fn query_needs_labels_and_selects_status() {
+    let needs = |q: &str| crate::filter::parse_query(q).unwrap().needs_labels();
+    let selects = |q: &str| crate::filter::parse_query(q).unwrap().selects_status();
+    assert!(needs("age < 3d or label = a"));
+    assert!(needs("priority < 2"));
+    assert!(!needs("assignee = a and age < 3d"));
+    assert!(selects("label = a and status = done"));
+    assert!(selects("closed < 1w"));
+    assert!(!selects("label = a or age < 3d"));
+}
@@ -1,13 +1,59 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Filter expression types for time-based filtering.
+//! Filter expression types.
 //!
 //! Filter expressions allow filtering issues by age or update time using
-//! expressions like `age < 3d` or `updated > 1w`.
+//! expressions like `age < 3d` or `updated > 1w`, by attributes like
+//! `label = urgent`, and by combinations of these joined with `and`/`or`.
 
 use chrono::{Duration, NaiveDate};
 
+use crate::models::{IssueType, Status};
+
+/// A full filter query: comparisons joined with `and`, `or`, and parentheses.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterQuery {
+    /// A time comparison such as `age < 3d`.
+    Time(FilterExpr),
+    /// An attribute comparison such as `label = urgent`.
+    Attr(AttrFilter),
+    /// Every part must match.
+    And(Vec<FilterQuery>),
+    /// At least one part must match.
+    Or(Vec<FilterQuery>),
+}
+
+/// A comparison against an issue attribute.
+///
+/// `=` and `in (a, b)` match any listed value; `!=` matches none of them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttrFilter {
+    /// Labels, with a trailing `*` matching any label with that prefix.
+    Label {
+        values: Vec<String>,
+        negated: bool,
+    },
+    /// Assignee; unassigned issues never equal a name.
+    Assignee {
+        values: Vec<String>,
+        negated: bool,
+    },
+    Status {
+        values: Vec<Status>,
+        negated: bool,
+    },
+    Type {
+        values: Vec<IssueType>,
+        negated: bool,
+    },
+    /// Priority from `priority:N` labels (0 = highest, default 2).
+    Priority {
+        op: CompareOp,
+        value: u8,
+    },
+}
+
 /// A parsed filter expression.
 ///
 /// Filter expressions have the form `field op value`, for example:
@@ -1,28 +1,34 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Filter expressions for time-based issue filtering.
+//! Filter expressions for issue filtering.
 //!
 //! This module provides a flexible filter expression syntax for filtering
-//! issues by age or update time. Expressions take the form:
+//! issues by time and attributes. Expressions take the form:
 //!
 //! ```text
 //! field op value
 //! ```
 //!
+//! and can be combined with `and`, `or` and parentheses (`and` binds tighter).
+//!
 //! # Fields
 //!
 //! - `age` / `created` - Time since creation (synonyms)
 //! - `updated` / `activity` - Time since last update (synonyms)
+//! - `completed` / `skipped` / `closed` - Time since reaching a terminal state
+//! - `label`, `assignee`, `status`, `type` - Compared with `=`, `!=` or `in (a, b)`
+//! - `priority` - Priority from `priority:N` labels, compared as a number
 //!
 //! # Operators
 //!
-//! - `<`, `<=`, `>`, `>=`, `=`, `!=`
+//! - `<`, `<=`, `>`, `>=`, `=`, `!=`, `in`
 //!
 //! # Values
 //!
 //! - Duration: `3d`, `1w`, `24h`, `30m`, `1M`, `1y`
 //! - Date: `2024-01-01` (YYYY-MM-DD format)
+//! - Names: `urgent`, `"two words"`, `(todo, in_progress)`
 //!
 //! # Examples
 //!
@@ -32,11 +38,14 @@
 //! updated < 24h     # Updated in the last 24 hours
 //! activity > 7d     # Not updated in 7+ days (stale)
 //! created > 2024-01-01  # Created after a specific date
+//! label = urgent and assignee != alice
+//! status in (todo, in_progress) or priority <= 1
 //! ```
 
+mod cond;
 mod eval;
 mod expr;
 mod parser;
 
-pub use expr::{CompareOp, FilterExpr, FilterField, FilterValue};
-pub use parser::{parse_duration, parse_filter};
+pub use expr::{AttrFilter, CompareOp, FilterExpr, FilterField, FilterQuery, FilterValue};
+pub use parser::{parse_duration, parse_filter, parse_query};
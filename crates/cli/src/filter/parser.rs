@@ -4,13 +4,263 @@
 //! Parser for filter expressions.
 //!
 //! Parses expressions like `age < 3d` or `updated > 1w` into structured
-//! [`FilterExpr`] values.
+//! [`FilterExpr`] values, and full queries like
+//! `label = urgent and (age < 3d or priority <= 1)` into [`FilterQuery`] trees.
 
 use chrono::{Duration, NaiveDate};
 
 use crate::error::{Error, Result};
+use crate::models::{IssueType, Status};
 
-use super::expr::{CompareOp, FilterExpr, FilterField, FilterValue};
+use super::expr::{AttrFilter, CompareOp, FilterExpr, FilterField, FilterQuery, FilterValue};
+
+/// Parse a filter query: comparisons joined with `and`/`or` and grouped
+/// with parentheses. `and` binds tighter than `or`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let query = parse_query("label = urgent")?;
+/// let query = parse_query("status in (todo, in_progress) and assignee != alice")?;
+/// let query = parse_query("priority <= 1 or (label = bug and age < 1w)")?;
+/// ```
+pub fn parse_query(input: &str) -> Result<FilterQuery> {
+    if input.trim().is_empty() {
+        return Err(Error::FilterEmpty);
+    }
+    let mut parser = QueryParser { rest: input };
+    let query = parser.parse_or()?;
+    let rest = parser.rest.trim_start();
+    if !rest.is_empty() {
+        return Err(Error::FilterInvalidValue {
+            field: "(filter)".to_string(),
+            reason: format!("unexpected '{}'", rest),
+        });
+    }
+    Ok(query)
+}
+
+/// Recursive-descent parser over the unconsumed input.
+struct QueryParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> QueryParser<'a> {
+    fn parse_or(&mut self) -> Result<FilterQuery> {
+        let mut parts = vec![self.parse_and()?];
+        while self.eat_keyword("or") {
+            parts.push(self.parse_and()?);
+        }
+        Ok(collapse(parts, FilterQuery::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<FilterQuery> {
+        let mut parts = vec![self.parse_primary()?];
+        while self.eat_keyword("and") {
+            parts.push(self.parse_primary()?);
+        }
+        Ok(collapse(parts, FilterQuery::And))
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterQuery> {
+        self.rest = self.rest.trim_start();
+        if let Some(inner) = self.rest.strip_prefix('(') {
+            self.rest = inner;
+            let query = self.parse_or()?;
+            self.rest = self.rest.trim_start();
+            return match self.rest.strip_prefix(')') {
+                Some(after) => {
+                    self.rest = after;
+                    Ok(query)
+                }
+                None => Err(unbalanced()),
+            };
+        }
+        let leaf = self.take_leaf()?;
+        parse_leaf(leaf)
+    }
+
+    /// Consume `keyword` if it is the next whole word.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let trimmed = self.rest.trim_start();
+        match trimmed.get(..keyword.len()) {
+            Some(word)
+                if word.eq_ignore_ascii_case(keyword) && is_word_end(trimmed, keyword.len()) =>
+            {
+                self.rest = &trimmed[keyword.len()..];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Take one comparison: everything up to a top-level `)`, `and`, or `or`.
+    /// Parentheses inside it (as in `in (a, b)`) are kept.
+    fn take_leaf(&mut self) -> Result<&'a str> {
+        let input = self.rest;
+        let mut depth = 0usize;
+        let mut end = input.len();
+        let mut at_word_start = true;
+        for (i, c) in input.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => {
+                    end = i;
+                    break;
+                }
+                ')' => depth -= 1,
+                _ if depth == 0 && at_word_start && i > 0 => {
+                    let word_here = |kw: &str| {
+                        input[i..]
+                            .get(..kw.len())
+                            .is_some_and(|w| w.eq_ignore_ascii_case(kw))
+                            && is_word_end(&input[i..], kw.len())
+                    };
+                    if word_here("and") || word_here("or") {
+                        end = i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            at_word_start = c.is_whitespace();
+        }
+        if depth > 0 {
+            return Err(unbalanced());
+        }
+        let leaf = input[..end].trim();
+        if leaf.is_empty() {
+            return Err(Error::FilterEmpty);
+        }
+        self.rest = &input[end..];
+        Ok(leaf)
+    }
+}
+
+/// True if `s[len..]` starts at a word boundary (end, space, or parenthesis).
+fn is_word_end(s: &str, len: usize) -> bool {
+    s[len..]
+        .chars()
+        .next()
+        .is_none_or(|c| c.is_whitespace() || c == '(' || c == ')')
+}
+
+fn collapse(mut parts: Vec<FilterQuery>, join: fn(Vec<FilterQuery>) -> FilterQuery) -> FilterQuery {
+    if parts.len() == 1 {
+        parts.remove(0)
+    } else {
+        join(parts)
+    }
+}
+
+fn unbalanced() -> Error {
+    Error::FilterInvalidValue {
+        field: "(filter)".to_string(),
+        reason: "unbalanced parentheses".to_string(),
+    }
+}
+
+/// Parse one comparison: an attribute comparison, or else a time filter.
+fn parse_leaf(input: &str) -> Result<FilterQuery> {
+    let (field_str, rest) = split_field(input)?;
+    let field = field_str.to_lowercase();
+    if !matches!(
+        field.as_str(),
+        "label" | "labels" | "assignee" | "status" | "type" | "priority"
+    ) {
+        return parse_filter(input).map(FilterQuery::Time);
+    }
+
+    let rest = rest.trim_start();
+    if field == "priority" {
+        let (op, value) = parse_operator(rest)?;
+        let value = unquote(value.trim());
+        let value = value
+            .parse::<u8>()
+            .ok()
+            .filter(|p| *p <= 4)
+            .ok_or_else(|| Error::FilterInvalidValue {
+                field: field_str.to_string(),
+                reason: format!("expected a priority from 0 to 4, got '{}'", value),
+            })?;
+        return Ok(FilterQuery::Attr(AttrFilter::Priority { op, value }));
+    }
+
+    let (negated, values) = parse_set(field_str, rest)?;
+    let attr = match field.as_str() {
+        "label" | "labels" => AttrFilter::Label { values, negated },
+        "assignee" => AttrFilter::Assignee { values, negated },
+        "status" => AttrFilter::Status {
+            values: values
+                .iter()
+                .map(|v| v.parse::<Status>())
+                .collect::<std::result::Result<_, _>>()?,
+            negated,
+        },
+        _ => AttrFilter::Type {
+            values: values
+                .iter()
+                .map(|v| v.parse::<IssueType>())
+                .collect::<std::result::Result<_, _>>()?,
+            negated,
+        },
+    };
+    Ok(FilterQuery::Attr(attr))
+}
+
+/// Parse `= v`, `!= v`, or `in (a, b)` into (negated, values).
+fn parse_set(field: &str, rest: &str) -> Result<(bool, Vec<String>)> {
+    if rest.get(..2).is_some_and(|w| w.eq_ignore_ascii_case("in")) && is_word_end(rest, 2) {
+        let list = rest[2..].trim();
+        let inner = list
+            .strip_prefix('(')
+            .and_then(|l| l.strip_suffix(')'))
+            .ok_or_else(|| Error::FilterInvalidValue {
+                field: field.to_string(),
+                reason: "expected a list like in (a, b)".to_string(),
+            })?;
+        let values: Vec<String> = inner
+            .split(',')
+            .map(|v| unquote(v.trim()).to_string())
+            .filter(|v| !v.is_empty())
+            .collect();
+        if values.is_empty() {
+            return Err(Error::FilterInvalidValue {
+                field: field.to_string(),
+                reason: "empty list".to_string(),
+            });
+        }
+        return Ok((false, values));
+    }
+
+    let (op, value) = parse_operator(rest)?;
+    let negated = match op {
+        CompareOp::Eq => false,
+        CompareOp::Ne => true,
+        _ => {
+            return Err(Error::FilterInvalidOperator {
+                field: field.to_string(),
+                op: rest[..rest.len() - value.len()].trim().to_string(),
+            })
+        }
+    };
+    let value = unquote(value.trim());
+    if value.is_empty() {
+        return Err(Error::FilterInvalidValue {
+            field: field.to_string(),
+            reason: "missing value".to_string(),
+        });
+    }
+    Ok((negated, vec![value.to_string()]))
+}
+
+/// Strip one pair of matching single or double quotes.
+fn unquote(s: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|q| s.strip_prefix(*q).and_then(|v| v.strip_suffix(*q)))
+        .unwrap_or(s)
+}
 
 /// Parse a filter expression from a string.
 ///
@@ -109,8 +359,8 @@ fn parse_field(s: &str) -> Result<FilterField> {
 /// Parse an operator from the start of the string.
 fn parse_operator(s: &str) -> Result<(CompareOp, &str)> {
     // Try two-character operators first
-    if s.len() >= 2 {
-        match &s[..2] {
+    if let Some(pair) = s.get(..2) {
+        match pair {
             "<=" => return Ok((CompareOp::Le, &s[2..])),
             ">=" => return Ok((CompareOp::Ge, &s[2..])),
             "!=" => return Ok((CompareOp::Ne, &s[2..])),
@@ -118,7 +368,7 @@ fn parse_operator(s: &str) -> Result<(CompareOp, &str)> {
             "<<" | ">>" | "==" => {
                 return Err(Error::FilterInvalidOperator {
                     field: "(filter)".to_string(),
-                    op: s[..2].to_string(),
+                    op: pair.to_string(),
                 });
             }
             _ => {}
@@ -143,7 +393,7 @@ fn parse_operator(s: &str) -> Result<(CompareOp, &str)> {
     // Extract what looks like an operator for error message
     let op_end = s
         .find(|c: char| c.is_whitespace())
-        .unwrap_or(s.len().min(5));
+        .unwrap_or_else(|| s.char_indices().nth(5).map_or(s.len(), |(i, _)| i));
     let bad_op = if op_end > 0 { &s[..op_end] } else { "(none)" };
 
     Err(Error::FilterInvalidOperator {
@@ -230,21 +480,34 @@ pub fn parse_duration(s: &str) -> Result<Duration> {
     }
 
     // Convert to Duration based on unit
-    match unit {
-        "ms" => Ok(Duration::milliseconds(num)),
-        "s" => Ok(Duration::seconds(num)),
-        "m" => Ok(Duration::minutes(num)),
-        "h" => Ok(Duration::hours(num)),
-        "d" => Ok(Duration::days(num)),
-        "w" => Ok(Duration::weeks(num)),
-        "M" => Ok(Duration::days(num.saturating_mul(30))), // Approximate month
-        "y" => Ok(Duration::days(num.saturating_mul(365))), // Approximate year
-        _ => Err(Error::InvalidDuration {
-            reason: format!("unknown unit '{unit}'. Valid units: ms, s, m, h, d, w, M, y"),
-        }),
-    }
+    let duration = match unit {
+        "ms" => Duration::try_milliseconds(num),
+        "s" => Duration::try_seconds(num),
+        "m" => Duration::try_minutes(num),
+        "h" => Duration::try_hours(num),
+        "d" => Duration::try_days(num),
+        "w" => Duration::try_weeks(num),
+        "M" => num.checked_mul(30).and_then(Duration::try_days), // Approximate month
+        "y" => num.checked_mul(365).and_then(Duration::try_days), // Approximate year
+        _ => {
+            return Err(Error::InvalidDuration {
+                reason: format!("unknown unit '{unit}'. Valid units: ms, s, m, h, d, w, M, y"),
+            })
+        }
+    };
+
+    // Bounded so that `now - duration` stays a valid timestamp
+    duration
+        .filter(|d| d.num_days() <= MAX_DURATION_DAYS)
+        .ok_or_else(|| Error::InvalidDuration {
+            reason: format!("'{s}' is too long (at most {MAX_DURATION_YEARS}y)"),
+        })
 }
 
+/// Longest duration accepted, in years and (approximate) days.
+const MAX_DURATION_YEARS: i64 = 10_000;
+const MAX_DURATION_DAYS: i64 = MAX_DURATION_YEARS * 365;
+
 /// Split a duration string into number and unit parts.
 fn split_number_unit(s: &str) -> Result<(&str, &str)> {
     // Find where digits end
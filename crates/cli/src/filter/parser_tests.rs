@@ -129,6 +129,16 @@ fn parse_operator_unknown_error() {
     assert!(msg.contains("invalid filter operator"));
 }
 
+#[parameterized(
+    multibyte_after_first = { "age <é 3d" },
+    multibyte_first = { "age é< 3d" },
+    long_multibyte = { "age ééééééé" },
+)]
+fn parse_operator_multibyte_error(input: &str) {
+    let err = parse_filter(input).unwrap_err();
+    assert!(err.to_string().contains("invalid"));
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Word-based operators (shell-friendly aliases)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -524,3 +534,140 @@ fn rejects_bare_created() {
         .to_string()
         .contains("requires operator"));
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Queries
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn label(values: &[&str], negated: bool) -> FilterQuery {
+    FilterQuery::Attr(AttrFilter::Label {
+        values: values.iter().map(|v| v.to_string()).collect(),
+        negated,
+    })
+}
+
+#[test]
+fn query_single_time_filter() {
+    let query = parse_query("age < 3d").unwrap();
+    assert_eq!(query, FilterQuery::Time(parse_filter("age < 3d").unwrap()));
+}
+
+#[parameterized(
+    eq = { "label = urgent", &["urgent"], false },
+    ne = { "label != urgent", &["urgent"], true },
+    no_spaces = { "label=urgent", &["urgent"], false },
+    plural = { "labels = urgent", &["urgent"], false },
+    quoted = { "label = \"needs review\"", &["needs review"], false },
+    wildcard = { "label = team:*", &["team:*"], false },
+    in_list = { "label in (a, b)", &["a", "b"], false },
+    in_upper = { "LABEL IN (a,b)", &["a", "b"], false },
+)]
+fn query_label(input: &str, values: &[&str], negated: bool) {
+    assert_eq!(parse_query(input).unwrap(), label(values, negated));
+}
+
+#[test]
+fn query_status_and_type_parse_values() {
+    assert_eq!(
+        parse_query("status in (todo, in_progress)").unwrap(),
+        FilterQuery::Attr(AttrFilter::Status {
+            values: vec![Status::Todo, Status::InProgress],
+            negated: false,
+        })
+    );
+    assert_eq!(
+        parse_query("type != bug").unwrap(),
+        FilterQuery::Attr(AttrFilter::Type {
+            values: vec![IssueType::Bug],
+            negated: true,
+        })
+    );
+}
+
+#[test]
+fn query_priority_accepts_ordering_operators() {
+    assert_eq!(
+        parse_query("priority <= 1").unwrap(),
+        FilterQuery::Attr(AttrFilter::Priority {
+            op: CompareOp::Le,
+            value: 1,
+        })
+    );
+}
+
+#[test]
+fn query_and_binds_tighter_than_or() {
+    let query = parse_query("label = a or label = b and label = c").unwrap();
+    assert_eq!(
+        query,
+        FilterQuery::Or(vec![
+            label(&["a"], false),
+            FilterQuery::And(vec![label(&["b"], false), label(&["c"], false)]),
+        ])
+    );
+}
+
+#[test]
+fn query_parentheses_group() {
+    let query = parse_query("(label = a or label = b) and age < 3d").unwrap();
+    assert_eq!(
+        query,
+        FilterQuery::And(vec![
+            FilterQuery::Or(vec![label(&["a"], false), label(&["b"], false)]),
+            FilterQuery::Time(parse_filter("age < 3d").unwrap()),
+        ])
+    );
+}
+
+#[test]
+fn query_keywords_inside_words_are_values() {
+    assert_eq!(
+        parse_query("label = brand").unwrap(),
+        label(&["brand"], false)
+    );
+    assert_eq!(
+        parse_query("assignee = orla").unwrap(),
+        FilterQuery::Attr(AttrFilter::Assignee {
+            values: vec!["orla".to_string()],
+            negated: false,
+        })
+    );
+}
+
+#[parameterized(
+    empty = { "" },
+    dangling_and = { "label = a and" },
+    unbalanced_open = { "(label = a" },
+    unbalanced_close = { "label = a)" },
+    label_ordering = { "label < a" },
+    missing_value = { "assignee =" },
+    empty_list = { "status in ()" },
+    list_without_parens = { "status in todo" },
+    bad_status = { "status = nope" },
+    bad_priority = { "priority < 9" },
+    unknown_field = { "colour = red" },
+)]
+fn query_rejects(input: &str) {
+    assert!(parse_query(input).is_err());
+}
+
+#[parameterized(
+    past_i64 = { "99999999999999999999d" },
+    past_chrono = { "9223372036854775807w" },
+    past_limit = { "10001y" },
+    months_past_limit = { "200000M" },
+)]
+fn parse_duration_too_long(input: &str) {
+    assert!(matches!(
+        parse_duration(input),
+        Err(Error::InvalidDuration { .. })
+    ));
+}
+
+#[test]
+fn parse_duration_at_limit() {
+    assert_eq!(
+        parse_duration("10000y").unwrap(),
+        Duration::days(MAX_DURATION_DAYS)
+    );
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Running git for `wok branch` and the git hooks.
+//!
+//! Commands run in the current directory, so they act on the repository the
+//! user is in rather than the one holding `.wok/`.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::error::{Error, Result};
+
+/// Run `git args`, returning its trimmed stdout.
+pub fn git(args: &[&str]) -> Result<String> {
+    let failed = |reason: String| Error::GitFailed {
+        command: args.join(" "),
+        reason,
+    };
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| failed(e.to_string()))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.trim().trim_start_matches("fatal: ");
+        return Err(failed(reason.to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The checked-out branch; `None` with a detached HEAD or outside a repository.
+pub fn current_branch() -> Option<String> {
+    git(&["symbolic-ref", "--quiet", "--short", "HEAD"]).ok()
+}
+
+pub fn branch_exists(name: &str) -> bool {
+    let reference = format!("refs/heads/{}", name);
+    git(&["rev-parse", "--verify", "--quiet", &reference]).is_ok()
+}
+
+/// The repository's hooks directory, honoring `core.hooksPath`.
+pub fn hooks_dir() -> Result<PathBuf> {
+    git(&["rev-parse", "--git-path", "hooks"]).map(PathBuf::from)
+}
+
+/// The SHA and message of the commit at HEAD.
+pub fn head_commit() -> Result<(String, String)> {
+    let output = git(&["log", "-1", "--format=%H%n%B"])?;
+    let (sha, message) = output.split_once('\n').unwrap_or((output.as_str(), ""));
+    Ok((sha.to_string(), message.to_string()))
+}
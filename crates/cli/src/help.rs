@@ -21,6 +21,8 @@ use clap::Command;
 use regex::Regex;
 
 use crate::colors;
+use crate::i18n::message;
+use crate::t;
 
 /// Regex to parse option lines in help output.
 /// Captures: 1=indent, 2=short+comma, 3=long flag name, 4=value placeholder, 5=description
@@ -65,7 +67,7 @@ pub fn format_help(cmd: &mut Command) -> String {
     };
 
     // Consolidate --flag/--no-flag pairs
-    let consolidated = consolidate_negatable_flags(&raw_help);
+    let consolidated = localize_headings(&consolidate_negatable_flags(&raw_help));
 
     // Apply colors if enabled
     let output = if colors::should_colorize() {
@@ -98,6 +100,31 @@ pub fn eprint_help(cmd: &mut Command) {
     let _ = stderr.flush();
 }
 
+/// Section headings clap writes in English, with the keys of their translations.
+const CLAP_HEADINGS: &[(&str, &str)] = &[
+    ("Usage:", "help.heading.usage"),
+    ("Arguments:", "help.heading.arguments"),
+    ("Options:", "help.heading.options"),
+    ("Commands:", "help.heading.commands"),
+];
+
+/// Translate clap's section headings into the active locale.
+fn localize_headings(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            for (heading, key) in CLAP_HEADINGS {
+                if let Some(rest) = line.strip_prefix(heading) {
+                    if rest.is_empty() || rest.starts_with(' ') {
+                        return format!("{}{}", message(key, &[]), rest);
+                    }
+                }
+            }
+            line.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Consolidate `--flag` and `--no-flag` pairs into `--[no-]flag` format.
 ///
 /// Scans the help text looking for adjacent option lines where one is `--no-X`
@@ -182,6 +209,7 @@ fn merge_flag_pair(positive_caps: &regex::Captures, flag_name: &str) -> String {
 /// Used by format_help when colorization is enabled, and directly by tests.
 pub fn colorize_help_forced(text: &str) -> String {
     let option_re = &OPTION_LINE_RE;
+    let usage_heading = t!("help.heading.usage");
     let mut result = Vec::new();
 
     for line in text.lines() {
@@ -198,7 +226,7 @@ pub fn colorize_help_forced(text: &str) -> String {
         }
 
         // Check if this looks like a usage line
-        if line.starts_with("Usage:") {
+        if line.starts_with(&usage_heading) {
             result.push(colorize_usage_line(line));
             continue;
         }
@@ -537,49 +565,99 @@ pub fn template() -> String {
 pub fn commands() -> String {
     // Return plain text - colors are applied later by colorize_help()
     // because clap's Styles::plain() strips ANSI codes from template values
-    "\
-Issue Tracking:
-  new         Create a new issue
-  [un]dep     Add/remove dependency between issues
-  show        Show issue details
-  tree        Show dependency tree
-  list        List issues
-  ready       Show ready issues (unblocked todos)
-  search      Search issues by text
-  start       Start work on issue(s)
-  done        Mark issue(s) as done
-  close       Close issue(s) without completing
-  reopen      Return issue(s) to todo
-  edit        Edit an issue's properties
-  note        Add a note to an issue
-  [un]label   Add/remove a label from issue(s)
-  [un]link    Add/remove external link from an issue
-  log         View event log
-
-Setup & Configuration:
-  init        Initialize issue tracker
-  hooks       Manage Claude Code hooks
-  config      Manage configuration
-  daemon      Manage wokd daemon
-  export      Export issues to JSONL
-  import      Import issues from JSONL
-  schema      Output JSON Schema for commands
-  completion  Generate shell completions
-  prime       Generate onboarding template"
-        .to_string()
+    let mut lines = vec![t!("help.section.issue_tracking")];
+    lines.extend(command_lines(ISSUE_COMMANDS));
+    lines.push(String::new());
+    lines.push(t!("help.section.setup"));
+    lines.extend(command_lines(SETUP_COMMANDS));
+    lines.join("\n")
+}
+
+/// Commands under "Issue Tracking:", with the keys of their descriptions.
+const ISSUE_COMMANDS: &[(&str, &str)] = &[
+    ("new", "help.command.new"),
+    ("[un]dep", "help.command.dep"),
+    ("show", "help.command.show"),
+    ("tree", "help.command.tree"),
+    ("list", "help.command.list"),
+    ("ready", "help.command.ready"),
+    ("next", "help.command.next"),
+    ("search", "help.command.search"),
+    ("dedupe", "help.command.dedupe"),
+    ("merge", "help.command.merge"),
+    ("split", "help.command.split"),
+    ("sweep", "help.command.sweep"),
+    ("start", "help.command.start"),
+    ("done", "help.command.done"),
+    ("close", "help.command.close"),
+    ("reopen", "help.command.reopen"),
+    ("edit", "help.command.edit"),
+    ("[un]assign", "help.command.assign"),
+    ("note", "help.command.note"),
+    ("notes", "help.command.notes"),
+    ("[un]label", "help.command.label"),
+    ("labels", "help.command.labels"),
+    ("[un]link", "help.command.link"),
+    ("open", "help.command.open"),
+    ("branch", "help.command.branch"),
+    ("pr", "help.command.pr"),
+    ("log", "help.command.log"),
+    ("activity", "help.command.activity"),
+    ("digest", "help.command.digest"),
+    ("standup", "help.command.standup"),
+    ("[un]subscribe", "help.command.subscribe"),
+    ("inbox", "help.command.inbox"),
+    ("queue", "help.command.queue"),
+    ("serve", "help.command.serve"),
+];
+
+/// Commands under "Setup & Configuration:", with the keys of their descriptions.
+const SETUP_COMMANDS: &[(&str, &str)] = &[
+    ("init", "help.command.init"),
+    ("hooks", "help.command.hooks"),
+    ("config", "help.command.config"),
+    ("alias", "help.command.alias"),
+    ("daemon", "help.command.daemon"),
+    ("upgrade", "help.command.upgrade"),
+    ("export", "help.command.export"),
+    ("import", "help.command.import"),
+    ("backup", "help.command.backup"),
+    ("restore", "help.command.restore"),
+    ("schema", "help.command.schema"),
+    ("rpc", "help.command.rpc"),
+    ("completion", "help.command.completion"),
+    ("prime", "help.command.prime"),
+];
+
+fn command_lines(commands: &[(&str, &str)]) -> Vec<String> {
+    // One column width for both sections, so their descriptions line up
+    let width = ISSUE_COMMANDS
+        .iter()
+        .chain(SETUP_COMMANDS)
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    commands
+        .iter()
+        .map(|(name, key)| format!("  {:<width$}  {}", name, message(key, &[])))
+        .collect()
 }
 
 /// Quickstart help shown after options in main help.
 /// Colors are applied later by colorize_help() since clap strips ANSI codes.
 pub fn quickstart() -> String {
-    "\
-Get started:
-  wok init                 Initialize tracker
-  wok new task \"My task\"   Create a new task
-  wok list                 List all issues
-  wok start <id>           Start working on an issue
-  wok done <id>            Mark issue as complete"
-        .to_string()
+    let examples = [
+        ("wok init", t!("help.quickstart.init")),
+        ("wok new task \"My task\"", t!("help.quickstart.new")),
+        ("wok list", t!("help.quickstart.list")),
+        ("wok start <id>", t!("help.quickstart.start")),
+        ("wok done <id>", t!("help.quickstart.done")),
+    ];
+    let mut lines = vec![t!("help.section.get_started")];
+    for (example, about) in examples {
+        lines.push(format!("  {:<23}  {}", example, about));
+    }
+    lines.join("\n")
 }
 
 #[cfg(test)]
@@ -336,6 +336,7 @@ mod full_help {
             "tree",
             "list",
             "ready",
+            "next",
             "search",
             "start",
             "done",
@@ -343,9 +344,12 @@ mod full_help {
             "reopen",
             "edit",
             "note",
+            "notes",
             "label",
             "link",
             "log",
+            "activity",
+            "queue",
             "init",
             "hooks",
             "config",
@@ -367,6 +371,7 @@ mod full_help {
         assert!(result.contains("[un]dep"), "Should have [un]dep");
         assert!(result.contains("[un]label"), "Should have [un]label");
         assert!(result.contains("[un]link"), "Should have [un]link");
+        assert!(result.contains("[un]assign"), "Should have [un]assign");
     }
 
     #[test]
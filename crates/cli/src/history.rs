@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Historical issue state reconstructed from the event log.
+//!
+//! Replay starts from the issue as it is now and undoes its events newest
+//! first, stopping at the requested point in time. Relations are logged on
+//! the issue they were added from, so a dependency added from the other issue
+//! is not rewound.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::filter::parse_duration;
+use crate::models::{Action, Event, Issue, IssueType, Status};
+use wk_core::Hlc;
+
+/// An issue's fields, labels, and dependencies at one point in time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueState {
+    pub issue: Issue,
+    pub labels: Vec<String>,
+    pub blockers: Vec<String>,
+    pub blocking: Vec<String>,
+    pub parents: Vec<String>,
+    pub children: Vec<String>,
+    pub related: Vec<String>,
+    pub duplicate_of: Vec<String>,
+    pub duplicates: Vec<String>,
+}
+
+impl IssueState {
+    /// The current state of an issue.
+    pub fn load(db: &Database, id: &str) -> Result<Self> {
+        Ok(IssueState {
+            issue: db.get_issue(id)?,
+            labels: db.get_labels(id)?,
+            blockers: db.get_blockers(id)?,
+            blocking: db.get_blocking(id)?,
+            parents: db.get_tracking(id)?,
+            children: db.get_tracked(id)?,
+            related: db.get_related(id)?,
+            duplicate_of: db.get_duplicate_of(id)?,
+            duplicates: db.get_duplicates(id)?,
+        })
+    }
+
+    /// Rewind to the state at `at` by undoing every later event.
+    ///
+    /// `events` is the issue's log in chronological order. Fails if the issue
+    /// was created after `at`.
+    pub fn rewind(mut self, events: &[Event], at: DateTime<Utc>) -> Result<Self> {
+        if self.issue.created_at > at {
+            return Err(Error::NotYetCreated {
+                id: self.issue.id,
+                at: format_point(at),
+            });
+        }
+
+        for event in events.iter().rev().filter(|e| e.created_at > at) {
+            self.undo(event);
+        }
+
+        self.issue.updated_at = events
+            .iter()
+            .map(|e| e.created_at)
+            .filter(|t| *t <= at)
+            .max()
+            .unwrap_or(self.issue.created_at);
+        if !self.issue.status.is_terminal() {
+            self.issue.closed_at = None;
+        }
+        Ok(self)
+    }
+
+    fn undo(&mut self, event: &Event) {
+        let old = event.old_value.clone();
+        let new = event.new_value.as_deref();
+        match event.action {
+            Action::Started
+            | Action::Stopped
+            | Action::Done
+            | Action::Closed
+            | Action::Reopened => {
+                if let Some(status) = old.as_deref().and_then(|s| Status::from_str(s).ok()) {
+                    self.issue.status = status;
+                }
+            }
+            Action::Edited => self.undo_edit(old, new),
+            Action::Assigned | Action::Unassigned => self.issue.assignee = old,
+            Action::Labeled => remove(&mut self.labels, new),
+            Action::Unlabeled => insert(&mut self.labels, new),
+            Action::Related => {
+                if let Some((list, target)) = new.and_then(|n| self.relation_list(n)) {
+                    remove(list, Some(target));
+                }
+            }
+            Action::Unrelated => {
+                if let Some((list, target)) = new.and_then(|n| self.relation_list(n)) {
+                    insert(list, Some(target));
+                }
+            }
+            // The CLI logs the other issue as the new value; synced
+            // removals log it as the old value
+            Action::Associated => remove(&mut self.related, new),
+            Action::Dissociated => insert(&mut self.related, new.or(old.as_deref())),
+            Action::Duplicated => remove(&mut self.duplicate_of, new),
+            Action::Unduplicated => insert(&mut self.duplicate_of, new.or(old.as_deref())),
+            Action::Created
+            | Action::Linked
+            | Action::Unlinked
+            | Action::Noted
+            | Action::Unnoted
+            | Action::Unblocked
+            | Action::Stale => {}
+        }
+    }
+
+    /// Edit events don't name the field, so match the new value against the
+    /// current title and type; anything else was a description edit.
+    fn undo_edit(&mut self, old: Option<String>, new: Option<&str>) {
+        let old_type = old.as_deref().and_then(|s| IssueType::from_str(s).ok());
+        if new == Some(self.issue.title.as_str()) {
+            if let Some(title) = old {
+                self.issue.title = title;
+            }
+        } else if let (Some(old_type), Some(new_type)) =
+            (old_type, new.and_then(|s| IssueType::from_str(s).ok()))
+        {
+            if new_type == self.issue.issue_type {
+                self.issue.issue_type = old_type;
+            }
+        } else {
+            self.issue.description = old;
+        }
+    }
+
+    /// The dependency list a relation event (e.g. `blocked by prj-2`) changed.
+    fn relation_list<'a>(&mut self, value: &'a str) -> Option<(&mut Vec<String>, &'a str)> {
+        if let Some(id) = value.strip_prefix("blocked by ") {
+            Some((&mut self.blockers, id))
+        } else if let Some(id) = value.strip_prefix("blocks ") {
+            Some((&mut self.blocking, id))
+        } else if let Some(id) = value.strip_prefix("tracked by ") {
+            Some((&mut self.parents, id))
+        } else if let Some(id) = value.strip_prefix("tracks ") {
+            Some((&mut self.children, id))
+        } else if let Some(id) = value.strip_prefix("relates to ") {
+            Some((&mut self.related, id))
+        } else if let Some(id) = value.strip_prefix("duplicate of ") {
+            Some((&mut self.duplicate_of, id))
+        } else if let Some(id) = value.strip_prefix("duplicated by ") {
+            Some((&mut self.duplicates, id))
+        } else {
+            None
+        }
+    }
+}
+
+fn insert(list: &mut Vec<String>, value: Option<&str>) {
+    if let Some(value) = value {
+        if !list.iter().any(|v| v == value) {
+            list.push(value.to_string());
+        }
+    }
+}
+
+fn remove(list: &mut Vec<String>, value: Option<&str>) {
+    list.retain(|v| Some(v.as_str()) != value);
+}
+
+/// One field that differs between two states.
+///
+/// Scalar fields use `from`/`to`; labels and dependencies use `added`/`removed`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub field: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+}
+
+impl Change {
+    fn scalar(field: &'static str, from: Option<String>, to: Option<String>) -> Option<Self> {
+        (from != to).then(|| Change {
+            field,
+            from,
+            to,
+            added: Vec::new(),
+            removed: Vec::new(),
+        })
+    }
+
+    fn set(field: &'static str, before: &[String], after: &[String]) -> Option<Self> {
+        let added: Vec<String> = after
+            .iter()
+            .filter(|v| !before.contains(v))
+            .cloned()
+            .collect();
+        let removed: Vec<String> = before
+            .iter()
+            .filter(|v| !after.contains(v))
+            .cloned()
+            .collect();
+        (!added.is_empty() || !removed.is_empty()).then_some(Change {
+            field,
+            from: None,
+            to: None,
+            added,
+            removed,
+        })
+    }
+}
+
+/// Fields that changed between `before` and `after`, in display order.
+pub fn diff(before: &IssueState, after: &IssueState) -> Vec<Change> {
+    let (old, new) = (&before.issue, &after.issue);
+    [
+        Change::scalar(
+            "status",
+            Some(old.status.to_string()),
+            Some(new.status.to_string()),
+        ),
+        Change::scalar(
+            "type",
+            Some(old.issue_type.to_string()),
+            Some(new.issue_type.to_string()),
+        ),
+        Change::scalar("title", Some(old.title.clone()), Some(new.title.clone())),
+        Change::scalar(
+            "description",
+            old.description.clone(),
+            new.description.clone(),
+        ),
+        Change::scalar("assignee", old.assignee.clone(), new.assignee.clone()),
+        Change::set("labels", &before.labels, &after.labels),
+        Change::set("blocked_by", &before.blockers, &after.blockers),
+        Change::set("blocks", &before.blocking, &after.blocking),
+        Change::set("tracked_by", &before.parents, &after.parents),
+        Change::set("tracks", &before.children, &after.children),
+        Change::set("relates_to", &before.related, &after.related),
+        Change::set("duplicate_of", &before.duplicate_of, &after.duplicate_of),
+        Change::set("duplicates", &before.duplicates, &after.duplicates),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Parse a point in time for `--as-of` and `--diff`.
+///
+/// Accepts a date (`2026-01-15`, midnight UTC), a date and time
+/// (`2026-01-15 14:30` or RFC 3339), an HLC (`wall_ms-counter-node_id`), or a
+/// duration before `now` (`3d`).
+pub fn parse_point(s: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let s = s.trim();
+
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(Utc.from_utc_datetime(&date.and_time(chrono::NaiveTime::MIN)));
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(s) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(time) = NaiveDateTime::parse_from_str(s, format) {
+            return Ok(Utc.from_utc_datetime(&time));
+        }
+    }
+    // A wall clock in milliseconds is far longer than a year, so dates aren't HLCs
+    let is_hlc_shaped = s.split('-').next().is_some_and(|wall| wall.len() > 4);
+    if let Some(hlc) = is_hlc_shaped.then(|| Hlc::from_str(s).ok()).flatten() {
+        return i64::try_from(hlc.wall_ms)
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or_else(|| Error::InvalidTimestamp {
+                reason: format!("HLC '{s}' is out of range"),
+            });
+    }
+    if s.starts_with(|c: char| c.is_ascii_digit()) {
+        if let Ok(ago) = parse_duration(s) {
+            return Ok(now - ago);
+        }
+    }
+
+    Err(Error::InvalidTimestamp {
+        reason: format!(
+            "'{s}'\n  hint: use a date (2026-01-15), a time (2026-01-15 14:30), an HLC, or a duration (3d)"
+        ),
+    })
+}
+
+/// Format a point in time the way `show` prints event timestamps.
+pub fn format_point(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M").to_string()
+}
+
+#[cfg(test)]
+#[path = "history_tests.rs"]
+mod tests;
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use chrono::Duration;
+use yare::parameterized;
+
+/// A state loaded from a fresh issue, plus a clock starting at its creation.
+fn state() -> (IssueState, DateTime<Utc>) {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Current title");
+    let state = IssueState::load(&ctx.db, "test-1").unwrap();
+    let t0 = state.issue.created_at;
+    (state, t0)
+}
+
+fn event(action: Action, old: Option<&str>, new: Option<&str>, at: DateTime<Utc>) -> Event {
+    let mut event = Event::new("test-1".to_string(), action)
+        .with_values(old.map(String::from), new.map(String::from));
+    event.created_at = at;
+    event
+}
+
+fn minutes(t0: DateTime<Utc>, n: i64) -> DateTime<Utc> {
+    t0 + Duration::minutes(n)
+}
+
+#[test]
+fn rewind_undoes_status_changes_after_point() {
+    let (mut state, t0) = state();
+    state.issue.status = Status::Done;
+    let events = vec![
+        event(
+            Action::Started,
+            Some("todo"),
+            Some("in_progress"),
+            minutes(t0, 1),
+        ),
+        event(
+            Action::Done,
+            Some("in_progress"),
+            Some("done"),
+            minutes(t0, 3),
+        ),
+    ];
+
+    let at_two = state.clone().rewind(&events, minutes(t0, 2)).unwrap();
+    assert_eq!(at_two.issue.status, Status::InProgress);
+
+    let at_zero = state.rewind(&events, t0).unwrap();
+    assert_eq!(at_zero.issue.status, Status::Todo);
+}
+
+#[test]
+fn rewind_keeps_events_at_or_before_point() {
+    let (mut state, t0) = state();
+    state.issue.status = Status::InProgress;
+    let events = vec![event(
+        Action::Started,
+        Some("todo"),
+        Some("in_progress"),
+        minutes(t0, 1),
+    )];
+
+    let rewound = state.rewind(&events, minutes(t0, 1)).unwrap();
+    assert_eq!(rewound.issue.status, Status::InProgress);
+    assert_eq!(rewound.issue.updated_at, minutes(t0, 1));
+}
+
+#[test]
+fn rewind_clears_closed_at_when_reopened_in_the_past() {
+    let (mut state, t0) = state();
+    state.issue.status = Status::Closed;
+    state.issue.closed_at = Some(minutes(t0, 1));
+    let events = vec![event(
+        Action::Closed,
+        Some("todo"),
+        Some("closed"),
+        minutes(t0, 1),
+    )];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert_eq!(rewound.issue.status, Status::Todo);
+    assert_eq!(rewound.issue.closed_at, None);
+}
+
+#[test]
+fn rewind_undoes_title_edit() {
+    let (state, t0) = state();
+    let events = vec![event(
+        Action::Edited,
+        Some("Old title"),
+        Some("Current title"),
+        minutes(t0, 1),
+    )];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert_eq!(rewound.issue.title, "Old title");
+}
+
+#[test]
+fn rewind_undoes_type_edit() {
+    let (mut state, t0) = state();
+    state.issue.issue_type = IssueType::Bug;
+    let events = vec![event(
+        Action::Edited,
+        Some("task"),
+        Some("bug"),
+        minutes(t0, 1),
+    )];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert_eq!(rewound.issue.issue_type, IssueType::Task);
+    assert_eq!(rewound.issue.title, "Current title");
+}
+
+#[test]
+fn rewind_undoes_description_edit() {
+    let (mut state, t0) = state();
+    state.issue.description = Some("New description".to_string());
+    let events = vec![event(
+        Action::Edited,
+        None,
+        Some("New description"),
+        minutes(t0, 1),
+    )];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert_eq!(rewound.issue.description, None);
+}
+
+#[test]
+fn rewind_undoes_assignment() {
+    let (mut state, t0) = state();
+    state.issue.assignee = Some("bob".to_string());
+    let events = vec![event(
+        Action::Assigned,
+        Some("alice"),
+        Some("bob"),
+        minutes(t0, 1),
+    )];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert_eq!(rewound.issue.assignee.as_deref(), Some("alice"));
+}
+
+#[test]
+fn rewind_undoes_label_changes() {
+    let (mut state, t0) = state();
+    state.labels = vec!["urgent".to_string()];
+    let events = vec![
+        event(Action::Unlabeled, None, Some("wip"), minutes(t0, 1)),
+        event(Action::Labeled, None, Some("urgent"), minutes(t0, 2)),
+    ];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert_eq!(rewound.labels, vec!["wip"]);
+}
+
+#[parameterized(
+    blocked_by = { "blocked by test-2" },
+    blocks = { "blocks test-2" },
+    tracked_by = { "tracked by test-2" },
+    tracks = { "tracks test-2" },
+)]
+fn rewind_undoes_relations(value: &str) {
+    let (state, t0) = state();
+    let events = vec![event(Action::Unrelated, None, Some(value), minutes(t0, 1))];
+
+    let before = state.rewind(&events, t0).unwrap();
+    let lists = [
+        &before.blockers,
+        &before.blocking,
+        &before.parents,
+        &before.children,
+    ];
+    assert_eq!(
+        lists.iter().filter(|l| l.as_slice() == ["test-2"]).count(),
+        1
+    );
+
+    let events = vec![event(Action::Related, None, Some(value), minutes(t0, 2))];
+    let after = before.clone().rewind(&events, minutes(t0, 1)).unwrap();
+    assert!(after.blockers.is_empty() && after.blocking.is_empty());
+    assert!(after.parents.is_empty() && after.children.is_empty());
+}
+
+#[test]
+fn rewind_undoes_associations() {
+    let (mut state, t0) = state();
+    state.related = vec!["test-2".to_string()];
+    let events = vec![
+        event(Action::Unduplicated, Some("test-3"), None, minutes(t0, 1)),
+        event(Action::Associated, None, Some("test-2"), minutes(t0, 2)),
+    ];
+
+    let rewound = state.rewind(&events, t0).unwrap();
+    assert!(rewound.related.is_empty());
+    assert_eq!(rewound.duplicate_of, vec!["test-3"]);
+}
+
+#[test]
+fn rewind_before_creation_fails() {
+    let (state, t0) = state();
+    let result = state.rewind(&[], t0 - Duration::days(1));
+    assert!(matches!(result, Err(Error::NotYetCreated { .. })));
+}
+
+#[test]
+fn diff_reports_changed_fields() {
+    let (before, _) = state();
+    let mut after = before.clone();
+    after.issue.status = Status::Done;
+    after.labels = vec!["urgent".to_string()];
+    after.blockers = vec!["test-2".to_string()];
+
+    let changes = diff(&before, &after);
+    let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+    assert_eq!(fields, vec!["status", "labels", "blocked_by"]);
+    assert_eq!(changes[0].from.as_deref(), Some("todo"));
+    assert_eq!(changes[0].to.as_deref(), Some("done"));
+    assert_eq!(changes[1].added, vec!["urgent"]);
+    assert!(changes[1].removed.is_empty());
+}
+
+#[test]
+fn diff_of_identical_states_is_empty() {
+    let (state, _) = state();
+    assert!(diff(&state, &state).is_empty());
+}
+
+#[parameterized(
+    date = { "2026-01-15", "2026-01-15T00:00:00Z" },
+    minute = { "2026-01-15 14:30", "2026-01-15T14:30:00Z" },
+    rfc3339 = { "2026-01-15T14:30:00+02:00", "2026-01-15T12:30:00Z" },
+    hlc = { "1768487400000-3-1", "2026-01-15T14:30:00Z" },
+    duration = { "2d", "2026-01-18T00:00:00Z" },
+)]
+fn parse_point_formats(input: &str, expected: &str) {
+    let now = DateTime::parse_from_rfc3339("2026-01-20T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let expected = DateTime::parse_from_rfc3339(expected)
+        .unwrap()
+        .with_timezone(&Utc);
+    assert_eq!(parse_point(input, now).unwrap(), expected);
+}
+
+#[parameterized(
+    word = { "yesterday" },
+    bad_date = { "2026-13-45" },
+    empty = { "" },
+)]
+fn parse_point_rejects_invalid(input: &str) {
+    let result = parse_point(input, Utc::now());
+    assert!(matches!(result, Err(Error::InvalidTimestamp { .. })));
+}
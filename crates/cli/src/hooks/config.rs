@@ -20,7 +20,45 @@ pub struct HookConfig {
     #[serde(default)]
     pub filter: Option<String>,
     /// Command to execute when hook is triggered.
-    pub run: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<String>,
+    /// URL to POST the payload to, instead of running a command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Environment variable holding the webhook signing secret.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_env: Option<String>,
+    /// Webhook request timeout in seconds.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Webhook delivery retries on transient failures.
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+}
+
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+impl HookConfig {
+    /// Check that the hook declares exactly one of `run` or `url`.
+    pub fn validate(&self) -> Result<()> {
+        match (&self.run, &self.url) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            (Some(_), Some(_)) => Err(crate::error::Error::Config(format!(
+                "hook '{}' sets both run and url; use one",
+                self.name
+            ))),
+            (None, None) => Err(crate::error::Error::Config(format!(
+                "hook '{}' must set run or url",
+                self.name
+            ))),
+        }
+    }
 }
 
 /// Root configuration structure for hooks files.
@@ -71,6 +109,10 @@ pub fn load_hooks_config(work_dir: &Path) -> Result<Option<HooksConfig>> {
         config.merge(json_config);
     }
 
+    for hook in &config.hooks {
+        hook.validate()?;
+    }
+
     if config.hooks.is_empty() {
         Ok(None)
     } else {
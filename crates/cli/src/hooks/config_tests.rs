@@ -31,7 +31,7 @@ run = "./test.sh"
     assert_eq!(config.hooks.len(), 1);
     assert_eq!(config.hooks[0].name, "test-hook");
     assert_eq!(config.hooks[0].events, vec!["issue.created"]);
-    assert_eq!(config.hooks[0].run, "./test.sh");
+    assert_eq!(config.hooks[0].run.as_deref(), Some("./test.sh"));
     assert!(config.hooks[0].filter.is_none());
 }
 
@@ -55,7 +55,7 @@ fn test_load_hooks_config_json() {
     assert_eq!(config.hooks[0].name, "json-hook");
     assert_eq!(config.hooks[0].events, vec!["issue.done", "issue.closed"]);
     assert_eq!(config.hooks[0].filter, Some("-t bug".to_string()));
-    assert_eq!(config.hooks[0].run, "./notify.sh");
+    assert_eq!(config.hooks[0].run.as_deref(), Some("./notify.sh"));
 }
 
 #[test]
@@ -106,10 +106,66 @@ fn test_hooks_config_merge() {
             name: "test".to_string(),
             events: vec!["issue.created".to_string()],
             filter: None,
-            run: "./test.sh".to_string(),
+            run: Some("./test.sh".to_string()),
+            url: None,
+            secret_env: None,
+            timeout_secs: 10,
+            retries: 3,
         }],
     };
 
     config.merge(other);
     assert_eq!(config.hooks.len(), 1);
 }
+
+#[test]
+fn test_load_hooks_config_webhook() {
+    let tmp = TempDir::new().unwrap();
+    let toml_content = r#"
+[[hooks]]
+name = "slack"
+events = ["issue.done"]
+url = "https://hooks.example.com/wok"
+secret_env = "WOK_WEBHOOK_SECRET"
+timeout_secs = 5
+"#;
+    fs::write(tmp.path().join("hooks.toml"), toml_content).unwrap();
+
+    let config = load_hooks_config(tmp.path()).unwrap().unwrap();
+    let hook = &config.hooks[0];
+    assert!(hook.run.is_none());
+    assert_eq!(hook.url.as_deref(), Some("https://hooks.example.com/wok"));
+    assert_eq!(hook.secret_env.as_deref(), Some("WOK_WEBHOOK_SECRET"));
+    assert_eq!(hook.timeout_secs, 5);
+    assert_eq!(hook.retries, 3);
+}
+
+#[test]
+fn test_load_hooks_config_requires_run_or_url() {
+    let tmp = TempDir::new().unwrap();
+    let toml_content = r#"
+[[hooks]]
+name = "nothing"
+events = ["issue.done"]
+"#;
+    fs::write(tmp.path().join("hooks.toml"), toml_content).unwrap();
+
+    let err = load_hooks_config(tmp.path()).unwrap_err();
+    assert!(err.to_string().contains("must set run or url"));
+}
+
+#[test]
+fn test_load_hooks_config_rejects_run_and_url() {
+    let tmp = TempDir::new().unwrap();
+    let toml_content = r#"
+[[hooks]]
+name = "both"
+events = ["issue.done"]
+run = "./x.sh"
+url = "https://example.com"
+"#;
+    fs::write(tmp.path().join("hooks.toml"), toml_content).unwrap();
+
+    let err = load_hooks_config(tmp.path()).unwrap_err();
+    assert!(err.to_string().contains("sets both run and url"));
+}
@@ -22,11 +22,17 @@ pub enum HookEvent {
     Assigned,
     Unassigned,
     Noted,
+    Unnoted,
     Linked,
     Unlinked,
     Related,
     Unrelated,
     Unblocked,
+    Associated,
+    Dissociated,
+    Duplicated,
+    Unduplicated,
+    Stale,
 }
 
 impl HookEvent {
@@ -45,11 +51,17 @@ impl HookEvent {
             HookEvent::Assigned => "issue.assigned",
             HookEvent::Unassigned => "issue.unassigned",
             HookEvent::Noted => "issue.noted",
+            HookEvent::Unnoted => "issue.unnoted",
             HookEvent::Linked => "issue.linked",
             HookEvent::Unlinked => "issue.unlinked",
             HookEvent::Related => "issue.related",
             HookEvent::Unrelated => "issue.unrelated",
             HookEvent::Unblocked => "issue.unblocked",
+            HookEvent::Associated => "issue.associated",
+            HookEvent::Dissociated => "issue.dissociated",
+            HookEvent::Duplicated => "issue.duplicated",
+            HookEvent::Unduplicated => "issue.unduplicated",
+            HookEvent::Stale => "issue.stale",
         }
     }
 
@@ -76,11 +88,47 @@ impl From<Action> for HookEvent {
             Action::Assigned => HookEvent::Assigned,
             Action::Unassigned => HookEvent::Unassigned,
             Action::Noted => HookEvent::Noted,
+            Action::Unnoted => HookEvent::Unnoted,
             Action::Linked => HookEvent::Linked,
             Action::Unlinked => HookEvent::Unlinked,
             Action::Related => HookEvent::Related,
             Action::Unrelated => HookEvent::Unrelated,
             Action::Unblocked => HookEvent::Unblocked,
+            Action::Associated => HookEvent::Associated,
+            Action::Dissociated => HookEvent::Dissociated,
+            Action::Duplicated => HookEvent::Duplicated,
+            Action::Unduplicated => HookEvent::Unduplicated,
+            Action::Stale => HookEvent::Stale,
+        }
+    }
+}
+
+impl From<HookEvent> for Action {
+    fn from(event: HookEvent) -> Self {
+        match event {
+            HookEvent::Created => Action::Created,
+            HookEvent::Edited => Action::Edited,
+            HookEvent::Started => Action::Started,
+            HookEvent::Stopped => Action::Stopped,
+            HookEvent::Done => Action::Done,
+            HookEvent::Closed => Action::Closed,
+            HookEvent::Reopened => Action::Reopened,
+            HookEvent::Labeled => Action::Labeled,
+            HookEvent::Unlabeled => Action::Unlabeled,
+            HookEvent::Assigned => Action::Assigned,
+            HookEvent::Unassigned => Action::Unassigned,
+            HookEvent::Noted => Action::Noted,
+            HookEvent::Unnoted => Action::Unnoted,
+            HookEvent::Linked => Action::Linked,
+            HookEvent::Unlinked => Action::Unlinked,
+            HookEvent::Related => Action::Related,
+            HookEvent::Unrelated => Action::Unrelated,
+            HookEvent::Unblocked => Action::Unblocked,
+            HookEvent::Associated => Action::Associated,
+            HookEvent::Dissociated => Action::Dissociated,
+            HookEvent::Duplicated => Action::Duplicated,
+            HookEvent::Unduplicated => Action::Unduplicated,
+            HookEvent::Stale => Action::Stale,
         }
     }
 }
@@ -10,6 +10,7 @@ fn test_hook_event_as_event_name() {
     assert_eq!(HookEvent::Done.as_event_name(), "issue.done");
     assert_eq!(HookEvent::Closed.as_event_name(), "issue.closed");
     assert_eq!(HookEvent::Labeled.as_event_name(), "issue.labeled");
+    assert_eq!(HookEvent::Stale.as_event_name(), "issue.stale");
 }
 
 #[test]
@@ -48,9 +49,34 @@ fn test_hook_event_from_action() {
     assert_eq!(HookEvent::from(Action::Assigned), HookEvent::Assigned);
     assert_eq!(HookEvent::from(Action::Unassigned), HookEvent::Unassigned);
     assert_eq!(HookEvent::from(Action::Noted), HookEvent::Noted);
+    assert_eq!(HookEvent::from(Action::Unnoted), HookEvent::Unnoted);
     assert_eq!(HookEvent::from(Action::Linked), HookEvent::Linked);
     assert_eq!(HookEvent::from(Action::Unlinked), HookEvent::Unlinked);
     assert_eq!(HookEvent::from(Action::Related), HookEvent::Related);
     assert_eq!(HookEvent::from(Action::Unrelated), HookEvent::Unrelated);
     assert_eq!(HookEvent::from(Action::Unblocked), HookEvent::Unblocked);
+    assert_eq!(HookEvent::from(Action::Associated), HookEvent::Associated);
+    assert_eq!(HookEvent::from(Action::Dissociated), HookEvent::Dissociated);
+    assert_eq!(HookEvent::from(Action::Duplicated), HookEvent::Duplicated);
+    assert_eq!(
+        HookEvent::from(Action::Unduplicated),
+        HookEvent::Unduplicated
+    );
+    assert_eq!(HookEvent::from(Action::Stale), HookEvent::Stale);
+}
+
+#[test]
+fn test_action_from_hook_event_round_trips() {
+    for action in [
+        Action::Created,
+        Action::Done,
+        Action::Labeled,
+        Action::Unassigned,
+        Action::Unblocked,
+        Action::Duplicated,
+        Action::Unnoted,
+        Action::Stale,
+    ] {
+        assert_eq!(Action::from(HookEvent::from(action)), action);
+    }
 }
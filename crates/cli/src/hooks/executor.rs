@@ -1,62 +1,93 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Hook execution in fire-and-forget mode.
+//! Hook execution: turning a hook and payload into a runnable job.
 
-use std::io::Write;
 use std::path::Path;
-use std::process::{Command, Stdio};
 
-use crate::error::Result;
+use chrono::Utc;
+
+use crate::models::{HookJob, HookRun};
 
 use super::config::HookConfig;
 use super::payload::HookPayload;
+use super::webhook::{total_timeout_secs, webhook_args};
 
-/// Execute a hook script with the given payload.
+/// Execute a hook script (or webhook) with the given payload.
 ///
-/// Fire-and-forget: spawns process, writes stdin, doesn't wait for completion.
-/// Does not check exit codes or handle timeouts.
-pub fn execute_hook(hook: &HookConfig, payload: &HookPayload, work_dir: &Path) -> Result<()> {
-    // Serialize payload to JSON
-    let json = payload.to_json().map_err(|e| {
-        crate::error::Error::Config(format!("failed to serialize hook payload: {}", e))
-    })?;
+/// Waits for the process to exit, killing it after the hook's timeout, and
+/// returns a record of the run. Never fails: spawn errors and timeouts are
+/// reported in [`HookRun::error`].
+pub fn execute_hook(hook: &HookConfig, payload: &HookPayload, work_dir: &Path) -> HookRun {
+    match prepare_job(hook, payload, work_dir) {
+        Ok(job) => job.run(),
+        Err(error) => unprepared_run(hook, payload, error),
+    }
+}
 
-    // Get the project root (parent of .wok/)
-    let project_root = work_dir.parent().unwrap_or(work_dir);
+/// The record for a hook that could not be turned into a job.
+pub(crate) fn unprepared_run(hook: &HookConfig, payload: &HookPayload, error: String) -> HookRun {
+    HookRun {
+        id: 0,
+        hook_name: hook.name.clone(),
+        event: payload.event.clone(),
+        issue_id: payload.issue.id.clone(),
+        exit_code: None,
+        duration_ms: 0,
+        stderr_tail: None,
+        error: Some(error),
+        created_at: Utc::now(),
+    }
+}
 
-    // Build the command
-    let mut child = Command::new("sh")
-        .arg("-c")
-        .arg(&hook.run)
-        .current_dir(project_root)
-        .env("WOK_EVENT", &payload.event)
-        .env("WOK_ISSUE_ID", &payload.issue.id)
-        .env("WOK_ISSUE_TYPE", &payload.issue.r#type)
-        .env("WOK_ISSUE_STATUS", &payload.issue.status)
-        .env(
-            "WOK_CHANGE_VALUE",
-            payload.change.new_value.as_deref().unwrap_or(""),
-        )
-        .stdin(Stdio::piped())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()
-        .map_err(|e| {
-            crate::error::Error::Config(format!("failed to spawn hook '{}': {}", hook.name, e))
-        })?;
+/// Build the job for a hook: its command, environment, stdin, and timeout.
+pub fn prepare_job(
+    hook: &HookConfig,
+    payload: &HookPayload,
+    work_dir: &Path,
+) -> std::result::Result<HookJob, String> {
+    let json = payload
+        .to_json()
+        .map_err(|e| format!("failed to serialize hook payload: {}", e))?;
 
-    // Write JSON to stdin
-    if let Some(mut stdin) = child.stdin.take() {
-        // Best effort write, ignore errors (fire-and-forget)
-        let _ = stdin.write_all(json.as_bytes());
-    }
+    // Run from the project root (parent of .wok/)
+    let project_root = work_dir.parent().unwrap_or(work_dir);
 
-    // Don't wait for completion - fire and forget
-    // The process will be adopted by init when we drop the handle
-    drop(child);
+    let (program, args, env, timeout_secs) = match (&hook.run, &hook.url) {
+        (_, Some(url)) => {
+            let args = webhook_args(hook, url, &payload.event, &json);
+            // Leave curl room to report its own timeout before we kill it
+            let timeout = total_timeout_secs(hook).saturating_add(5);
+            ("curl".to_string(), args, Vec::new(), timeout)
+        }
+        (Some(run), None) => {
+            let env = vec![
+                ("WOK_EVENT".to_string(), payload.event.clone()),
+                ("WOK_ISSUE_ID".to_string(), payload.issue.id.clone()),
+                ("WOK_ISSUE_TYPE".to_string(), payload.issue.r#type.clone()),
+                ("WOK_ISSUE_STATUS".to_string(), payload.issue.status.clone()),
+                (
+                    "WOK_CHANGE_VALUE".to_string(),
+                    payload.change.new_value.clone().unwrap_or_default(),
+                ),
+            ];
+            let args = vec!["-c".to_string(), run.clone()];
+            ("sh".to_string(), args, env, hook.timeout_secs)
+        }
+        (None, None) => return Err(format!("hook '{}' must set run or url", hook.name)),
+    };
 
-    Ok(())
+    Ok(HookJob {
+        hook_name: hook.name.clone(),
+        event: payload.event.clone(),
+        issue_id: payload.issue.id.clone(),
+        program,
+        args,
+        env,
+        cwd: project_root.to_path_buf(),
+        stdin: json,
+        timeout_secs,
+    })
 }
 
 #[cfg(test)]
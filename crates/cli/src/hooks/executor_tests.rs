@@ -1,5 +1,141 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-// Executor tests are covered by integration tests in tests/specs/
-// since they require spawning actual processes.
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::models::{Action, Event, Issue, IssueType};
+use tempfile::TempDir;
+
+fn script_hook(run: &str, timeout_secs: u64) -> HookConfig {
+    HookConfig {
+        name: "script".to_string(),
+        events: vec!["issue.*".to_string()],
+        filter: None,
+        run: Some(run.to_string()),
+        url: None,
+        secret_env: None,
+        timeout_secs,
+        retries: 0,
+    }
+}
+
+fn payload() -> HookPayload {
+    let issue = Issue::new(
+        "prj-1".to_string(),
+        IssueType::Task,
+        "Task".to_string(),
+        Utc::now(),
+    );
+    let event = Event::new("prj-1".to_string(), Action::Done);
+    HookPayload::from_event(&event, &issue, vec![])
+}
+
+fn work_dir() -> TempDir {
+    let tmp = TempDir::new().unwrap();
+    std::fs::create_dir(tmp.path().join(".wok")).unwrap();
+    tmp
+}
+
+#[test]
+fn test_execute_hook_success() {
+    let tmp = work_dir();
+    let run = execute_hook(
+        &script_hook("cat > /dev/null", 5),
+        &payload(),
+        &tmp.path().join(".wok"),
+    );
+
+    assert!(run.succeeded());
+    assert_eq!(run.exit_code, Some(0));
+    assert_eq!(run.hook_name, "script");
+    assert_eq!(run.event, "issue.done");
+    assert_eq!(run.issue_id, "prj-1");
+    assert!(run.stderr_tail.is_none());
+}
+
+#[test]
+fn test_execute_hook_captures_exit_code_and_stderr() {
+    let tmp = work_dir();
+    let hook = script_hook("echo \"bad $WOK_ISSUE_ID\" >&2; exit 3", 5);
+    let run = execute_hook(&hook, &payload(), &tmp.path().join(".wok"));
+
+    assert!(!run.succeeded());
+    assert_eq!(run.exit_code, Some(3));
+    assert_eq!(run.stderr_tail.as_deref(), Some("bad prj-1"));
+    assert!(run.error.is_none());
+}
+
+#[test]
+fn test_execute_hook_receives_payload_on_stdin() {
+    let tmp = work_dir();
+    let hook = script_hook("grep -q '\"event\":\"issue.done\"'", 5);
+    let run = execute_hook(&hook, &payload(), &tmp.path().join(".wok"));
+    assert_eq!(run.exit_code, Some(0));
+}
+
+#[test]
+fn test_execute_hook_times_out() {
+    let tmp = work_dir();
+    let run = execute_hook(
+        &script_hook("sleep 5", 0),
+        &payload(),
+        &tmp.path().join(".wok"),
+    );
+
+    assert!(!run.succeeded());
+    assert!(run.exit_code.is_none());
+    assert_eq!(run.error.as_deref(), Some("timed out after 0s"));
+}
+
+#[test]
+fn test_prepare_job_for_script() {
+    let tmp = work_dir();
+    let job = prepare_job(
+        &script_hook("./notify.sh", 7),
+        &payload(),
+        &tmp.path().join(".wok"),
+    )
+    .unwrap();
+
+    assert_eq!(job.program, "sh");
+    assert_eq!(job.args, vec!["-c", "./notify.sh"]);
+    assert_eq!(job.cwd, tmp.path());
+    assert_eq!(job.timeout_secs, 7);
+    assert!(job
+        .env
+        .contains(&("WOK_EVENT".to_string(), "issue.done".to_string())));
+    assert!(job.stdin.contains("\"issue.done\""));
+}
+
+#[test]
+fn test_prepare_job_for_webhook() {
+    let tmp = work_dir();
+    let mut hook = script_hook("unused", 10);
+    hook.run = None;
+    hook.url = Some("https://example.com/hook".to_string());
+    hook.retries = 2;
+
+    let job = prepare_job(&hook, &payload(), &tmp.path().join(".wok")).unwrap();
+
+    assert_eq!(job.program, "curl");
+    assert!(job.args.contains(&"https://example.com/hook".to_string()));
+    // Three attempts of 10s each, plus slack for curl to report
+    assert_eq!(job.timeout_secs, 35);
+    assert!(job.env.is_empty());
+}
+
+#[test]
+fn test_execute_hook_without_command_is_recorded_as_error() {
+    let tmp = work_dir();
+    let mut hook = script_hook("unused", 5);
+    hook.run = None;
+
+    let run = execute_hook(&hook, &payload(), &tmp.path().join(".wok"));
+
+    assert!(!run.succeeded());
+    assert_eq!(
+        run.error.as_deref(),
+        Some("hook 'script' must set run or url")
+    );
+}
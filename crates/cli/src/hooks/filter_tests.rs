@@ -22,6 +22,7 @@ fn make_test_issue(issue_type: IssueType, status: Status, assignee: Option<&str>
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -8,7 +8,7 @@
 //! - Event name mapping from `Action` enum to hook event names
 //! - Filter string parsing (e.g., "-t bug -l urgent")
 //! - Payload building for hook stdin
-//! - Fire-and-forget hook execution
+//! - Hook execution with timeouts, recorded in the `hook_runs` table
 //!
 //! # Configuration Format
 //!
@@ -22,6 +22,16 @@
 //! run = "./scripts/page-oncall.sh"
 //! ```
 //!
+//! A hook can POST the payload to a URL instead of running a command:
+//!
+//! ```toml
+//! [[hooks]]
+//! name = "notify-ci"
+//! events = ["issue.done"]
+//! url = "https://ci.example.com/wok"
+//! secret_env = "WOK_WEBHOOK_SECRET"
+//! ```
+//!
 //! Or in `.wok/hooks.json`:
 //!
 //! ```json
@@ -41,6 +51,7 @@ pub mod executor;
 pub mod filter;
 pub mod payload;
 pub mod runner;
+pub mod webhook;
 
 pub use config::{load_hooks_config, HookConfig, HooksConfig};
 pub use event::HookEvent;
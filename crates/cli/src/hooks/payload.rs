@@ -5,6 +5,7 @@
 
 use crate::models::Issue;
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::Serialize;
 
 use crate::models::Event;
@@ -12,7 +13,7 @@ use crate::models::Event;
 use super::event::HookEvent;
 
 /// JSON payload passed to hook scripts via stdin.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct HookPayload {
     /// The event that triggered this hook (e.g., "issue.created").
     pub event: String,
@@ -22,10 +23,14 @@ pub struct HookPayload {
     pub issue: IssuePayload,
     /// Details about the change.
     pub change: ChangePayload,
+    /// The other issue involved, when there is one. For `issue.unblocked`,
+    /// the issue whose completion unblocked this one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related: Option<IssuePayload>,
 }
 
 /// Issue information included in the hook payload.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct IssuePayload {
     /// Issue ID.
     pub id: String,
@@ -42,8 +47,22 @@ pub struct IssuePayload {
     pub labels: Vec<String>,
 }
 
+impl IssuePayload {
+    /// Describe `issue` with its `labels`.
+    pub fn new(issue: &Issue, labels: Vec<String>) -> Self {
+        IssuePayload {
+            id: issue.id.clone(),
+            r#type: issue.issue_type.as_str().to_string(),
+            title: issue.title.clone(),
+            status: issue.status.as_str().to_string(),
+            assignee: issue.assignee.clone(),
+            labels,
+        }
+    }
+}
+
 /// Change information included in the hook payload.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct ChangePayload {
     /// Previous value (for edits, status changes).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,22 +83,22 @@ impl HookPayload {
         HookPayload {
             event: hook_event.as_event_name().to_string(),
             timestamp: event.created_at,
-            issue: IssuePayload {
-                id: issue.id.clone(),
-                r#type: issue.issue_type.as_str().to_string(),
-                title: issue.title.clone(),
-                status: issue.status.as_str().to_string(),
-                assignee: issue.assignee.clone(),
-                labels,
-            },
+            issue: IssuePayload::new(issue, labels),
             change: ChangePayload {
                 old_value: event.old_value.clone(),
                 new_value: event.new_value.clone(),
                 reason: event.reason.clone(),
             },
+            related: None,
         }
     }
 
+    /// Attach the other issue involved in the event.
+    pub fn with_related(mut self, related: Option<IssuePayload>) -> Self {
+        self.related = related;
+        self
+    }
+
     /// Serialize to JSON string.
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
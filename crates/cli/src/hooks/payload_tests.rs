@@ -23,6 +23,7 @@ fn make_test_issue() -> Issue {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 1,
     }
 }
 
@@ -34,6 +35,7 @@ fn make_test_event(action: Action) -> Event {
         old_value: Some("todo".to_string()),
         new_value: Some("in_progress".to_string()),
         reason: None,
+        actor: None,
         created_at: Utc::now(),
     }
 }
@@ -97,3 +99,23 @@ fn test_payload_event_mapping() {
         assert_eq!(payload.event, expected_event);
     }
 }
+
+#[test]
+fn test_payload_related_issue() {
+    let issue = make_test_issue();
+    let event = make_test_event(Action::Unblocked);
+    let mut blocker = make_test_issue();
+    blocker.id = "test-456".to_string();
+    blocker.status = Status::Done;
+
+    let payload = HookPayload::from_event(&event, &issue, vec![]);
+    let json = payload.to_json().unwrap();
+    assert!(!json.contains("\"related\""));
+
+    let payload = payload.with_related(Some(IssuePayload::new(&blocker, vec![])));
+    let related = payload.related.as_ref().unwrap();
+    assert_eq!(related.id, "test-456");
+    assert_eq!(related.status, "done");
+    let json = payload.to_json().unwrap();
+    assert!(json.contains("\"related\":{\"id\":\"test-456\""));
+}
@@ -3,22 +3,81 @@
 
 //! Hook runner orchestration.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::config::{wok_state_dir, Config};
+use crate::daemon::{get_socket_path, negotiate_encoding, DaemonClient, Encoding};
 use crate::db::Database;
 use crate::error::Result;
-use crate::models::Event;
+use crate::models::{Action, Event, HookJob, HookRun};
 
 use super::config::{load_hooks_config, HookConfig};
 use super::event::HookEvent;
-use super::executor::execute_hook;
+use super::executor::{prepare_job, unprepared_run};
 use super::filter::HookFilter;
-use super::payload::HookPayload;
+use super::payload::{HookPayload, IssuePayload};
+
+/// Where hook jobs run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Dispatch {
+    /// In this process, waiting for each hook to exit.
+    Inline,
+    /// Queued to the daemon listening on this socket, in the encoding it
+    /// agreed to.
+    Daemon(PathBuf, Encoding),
+}
+
+impl Dispatch {
+    /// Use the daemon when one is listening, except in private mode.
+    pub(crate) fn detect(work_dir: &Path) -> Self {
+        let private = Config::load(work_dir).map(|c| c.private).unwrap_or(true);
+        if private {
+            return Dispatch::Inline;
+        }
+        let socket_path = get_socket_path(&wok_state_dir());
+        if socket_path.exists() {
+            let encoding = negotiate_encoding(&socket_path);
+            Dispatch::Daemon(socket_path, encoding)
+        } else {
+            Dispatch::Inline
+        }
+    }
+
+    /// Run a job, or hand it to the daemon.
+    ///
+    /// If the daemon can't take the job (it stopped, or predates hook
+    /// queueing), the job runs inline instead.
+    pub(crate) fn run(&self, db: &Database, job: HookJob) {
+        let _span = tracing::info_span!("hook", name = %job.hook_name).entered();
+        if let Dispatch::Daemon(socket_path, encoding) = self {
+            let queued = DaemonClient::connect(socket_path)
+                .and_then(|client| client.with_encoding(*encoding).enqueue_hook(job.clone()));
+            if queued.is_ok() {
+                return;
+            }
+        }
+        record_run(db, &job.run());
+    }
+
+    /// Have the daemon check the project of `work_dir` for stale issues
+    /// periodically. Inline, stale checks run only through `wok hook stale`.
+    pub(crate) fn watch_stale(&self, work_dir: &Path) {
+        if let Dispatch::Daemon(socket_path, encoding) = self {
+            let project_root = work_dir.parent().unwrap_or(work_dir);
+            let watched = DaemonClient::connect(socket_path)
+                .and_then(|client| client.with_encoding(*encoding).watch_stale(project_root));
+            if let Err(e) = watched {
+                tracing::debug!("daemon cannot watch for stale issues: {}", e);
+            }
+        }
+    }
+}
 
 /// Find and execute matching hooks for an event.
 ///
-/// Loads hook configuration, filters matching hooks, and executes them.
-/// Errors during individual hook execution are logged but don't fail the operation.
+/// Loads hook configuration, filters matching hooks, and runs them: in the
+/// background via the daemon when one is running, otherwise inline, waiting
+/// for each. Hook failures are logged but don't fail the operation.
 pub fn run_hooks_for_event(db: &Database, work_dir: &Path, event: &Event) -> Result<()> {
     // Load hooks config (if exists)
     let config = match load_hooks_config(work_dir)? {
@@ -40,6 +99,19 @@ pub fn run_hooks_for_event(db: &Database, work_dir: &Path, event: &Event) -> Res
 
     // Convert action to hook event
     let hook_event: HookEvent = event.action.into();
+    let mut dispatch = None;
+    let related = related_issue(db, event);
+
+    // Stale issues are found by the daemon's periodic check, not a mutation
+    let watches_stale = config
+        .hooks
+        .iter()
+        .any(|hook| matches_hook_events(hook, HookEvent::Stale));
+    if watches_stale {
+        dispatch
+            .get_or_insert_with(|| Dispatch::detect(work_dir))
+            .watch_stale(work_dir);
+    }
 
     // Process each hook
     for hook in &config.hooks {
@@ -63,16 +135,45 @@ pub fn run_hooks_for_event(db: &Database, work_dir: &Path, event: &Event) -> Res
             }
         }
 
-        // Build payload and execute
-        let payload = HookPayload::from_event(event, &issue, labels.clone());
-        if let Err(e) = execute_hook(hook, &payload, work_dir) {
-            eprintln!("warning: failed to execute hook '{}': {}", hook.name, e);
+        // Build payload and run (or queue) the hook
+        let payload =
+            HookPayload::from_event(event, &issue, labels.clone()).with_related(related.clone());
+        match prepare_job(hook, &payload, work_dir) {
+            Ok(job) => dispatch
+                .get_or_insert_with(|| Dispatch::detect(work_dir))
+                .run(db, job),
+            Err(error) => record_run(db, &unprepared_run(hook, &payload, error)),
         }
     }
 
     Ok(())
 }
 
+/// The other issue an event involves: for `issue.unblocked`, the blocker
+/// whose completion unblocked it.
+fn related_issue(db: &Database, event: &Event) -> Option<IssuePayload> {
+    if event.action != Action::Unblocked {
+        return None;
+    }
+    let id = event.new_value.as_deref()?;
+    let issue = db.get_issue(id).ok()?;
+    let labels = db.get_labels(id).unwrap_or_default();
+    Some(IssuePayload::new(&issue, labels))
+}
+
+/// Persist a hook run, warning on failure.
+///
+/// Failures are reported on stderr so they are visible at the point they
+/// happen, not only in `wok hook runs`.
+pub(crate) fn record_run(db: &Database, run: &HookRun) {
+    if let Some(reason) = run.failure_reason() {
+        eprintln!("warning: hook '{}' failed: {}", run.hook_name, reason);
+    }
+    if let Err(e) = db.log_hook_run(run) {
+        eprintln!("warning: failed to record hook run: {}", e);
+    }
+}
+
 /// Check if a hook's event patterns match the given event.
 fn matches_hook_events(hook: &HookConfig, event: HookEvent) -> bool {
     hook.events
@@ -1,5 +1,51 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-// Runner tests are covered by integration tests in tests/specs/
+// Hook matching and execution are covered by integration tests in tests/specs/
 // since they require a full database setup and hook execution.
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+
+fn job() -> HookJob {
+    HookJob {
+        hook_name: "notify".to_string(),
+        event: "issue.done".to_string(),
+        issue_id: "test-1".to_string(),
+        program: "sh".to_string(),
+        args: vec!["-c".to_string(), "exit 4".to_string()],
+        env: Vec::new(),
+        cwd: std::env::temp_dir(),
+        stdin: "{}".to_string(),
+        timeout_secs: 5,
+    }
+}
+
+#[test]
+fn test_detect_without_config_runs_inline() {
+    let ctx = TestContext::new();
+    assert_eq!(Dispatch::detect(&ctx.work_dir), Dispatch::Inline);
+}
+
+#[test]
+fn test_inline_dispatch_records_run() {
+    let ctx = TestContext::new();
+
+    Dispatch::Inline.run(&ctx.db, job());
+
+    let runs = ctx.db.get_hook_runs(10, false).unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].exit_code, Some(4));
+}
+
+#[test]
+fn test_unreachable_daemon_falls_back_to_inline() {
+    let ctx = TestContext::new();
+    let socket = ctx.work_dir.join("missing.sock");
+
+    Dispatch::Daemon(socket, Encoding::Json).run(&ctx.db, job());
+
+    assert_eq!(ctx.db.get_hook_runs(10, false).unwrap().len(), 1);
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Webhook delivery for hooks that declare a `url`.
+//!
+//! The payload is POSTed by a `curl` process, which handles TLS, timeouts,
+//! and retries. When `secret_env` names a set environment variable,
+//! the body is signed with HMAC-SHA256 in the `X-Wok-Signature-256` header.
+
+use sha2::{Digest, Sha256};
+
+use super::config::HookConfig;
+
+/// Header carrying the payload signature.
+pub const SIGNATURE_HEADER: &str = "X-Wok-Signature-256";
+
+/// SHA-256 block size in bytes.
+const BLOCK_SIZE: usize = 64;
+
+/// Compute the HMAC-SHA256 of `body` as lowercase hex.
+pub fn sign_payload(secret: &[u8], body: &[u8]) -> String {
+    let mut key = [0u8; BLOCK_SIZE];
+    if secret.len() > BLOCK_SIZE {
+        let digest = Sha256::digest(secret);
+        key[..digest.len()].copy_from_slice(&digest);
+    } else {
+        key[..secret.len()].copy_from_slice(secret);
+    }
+
+    let inner_pad: Vec<u8> = key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = key.iter().map(|b| b ^ 0x5c).collect();
+
+    let inner = Sha256::new()
+        .chain_update(&inner_pad)
+        .chain_update(body)
+        .finalize();
+    let outer = Sha256::new()
+        .chain_update(&outer_pad)
+        .chain_update(inner)
+        .finalize();
+    hex::encode(outer)
+}
+
+/// Build the curl arguments for delivering a payload.
+///
+/// The body itself is passed on stdin (`--data-binary @-`).
+pub fn curl_args(
+    hook: &HookConfig,
+    url: &str,
+    event: &str,
+    signature: Option<&str>,
+) -> Vec<String> {
+    let mut args = vec![
+        "--silent".to_string(),
+        "--show-error".to_string(),
+        "--fail".to_string(),
+        "--request".to_string(),
+        "POST".to_string(),
+        "--max-time".to_string(),
+        hook.timeout_secs.to_string(),
+        "--retry".to_string(),
+        hook.retries.to_string(),
+        "--retry-max-time".to_string(),
+        total_timeout_secs(hook).to_string(),
+        "--header".to_string(),
+        "Content-Type: application/json".to_string(),
+        "--header".to_string(),
+        format!("X-Wok-Event: {}", event),
+    ];
+    if let Some(signature) = signature {
+        args.push("--header".to_string());
+        args.push(format!("{}: sha256={}", SIGNATURE_HEADER, signature));
+    }
+    args.push("--data-binary".to_string());
+    args.push("@-".to_string());
+    args.push(url.to_string());
+    args
+}
+
+/// Arguments for the curl process that POSTs `json` to the hook's URL.
+///
+/// The caller writes `json` to the process's stdin.
+pub fn webhook_args(hook: &HookConfig, url: &str, event: &str, json: &str) -> Vec<String> {
+    let secret = hook
+        .secret_env
+        .as_ref()
+        .and_then(|var| std::env::var(var).ok());
+    let signature = secret.map(|s| sign_payload(s.as_bytes(), json.as_bytes()));
+    curl_args(hook, url, event, signature.as_deref())
+}
+
+/// Total time curl may spend on all attempts, in seconds.
+pub fn total_timeout_secs(hook: &HookConfig) -> u64 {
+    hook.timeout_secs
+        .saturating_mul(u64::from(hook.retries) + 1)
+}
+
+#[cfg(test)]
+#[path = "webhook_tests.rs"]
+mod tests;
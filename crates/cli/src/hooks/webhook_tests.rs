@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+fn webhook_hook() -> HookConfig {
+    HookConfig {
+        name: "notify".to_string(),
+        events: vec!["issue.done".to_string()],
+        filter: None,
+        run: None,
+        url: Some("https://example.com/hook".to_string()),
+        secret_env: None,
+        timeout_secs: 7,
+        retries: 2,
+    }
+}
+
+#[test]
+fn test_sign_payload_rfc4231_case_2() {
+    let sig = sign_payload(b"Jefe", b"what do ya want for nothing?");
+    assert_eq!(
+        sig,
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[test]
+fn test_sign_payload_long_key_is_hashed() {
+    // RFC 4231 test case 6: 131-byte key
+    let key = [0xaau8; 131];
+    let sig = sign_payload(
+        &key,
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+    );
+    assert_eq!(
+        sig,
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn test_curl_args_without_signature() {
+    let hook = webhook_hook();
+    let args = curl_args(&hook, "https://example.com/hook", "issue.done", None);
+
+    assert!(args.windows(2).any(|w| w == ["--max-time", "7"]));
+    assert!(args.windows(2).any(|w| w == ["--retry", "2"]));
+    assert!(args.contains(&"X-Wok-Event: issue.done".to_string()));
+    assert!(!args.iter().any(|a| a.starts_with(SIGNATURE_HEADER)));
+    assert_eq!(args.last().unwrap(), "https://example.com/hook");
+}
+
+#[test]
+fn test_curl_args_with_signature() {
+    let hook = webhook_hook();
+    let args = curl_args(&hook, "https://example.com/hook", "issue.done", Some("abc"));
+
+    assert!(args.contains(&"X-Wok-Signature-256: sha256=abc".to_string()));
+}
+
+#[test]
+fn test_total_timeout_covers_all_attempts() {
+    let hook = webhook_hook();
+    assert_eq!(total_timeout_secs(&hook), 21);
+    let args = curl_args(&hook, "https://example.com/hook", "issue.done", None);
+    assert!(args.windows(2).any(|w| w == ["--retry-max-time", "21"]));
+}
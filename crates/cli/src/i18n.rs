@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Translated user-facing strings.
+//!
+//! Messages live in one catalog per locale under `crates/cli/locales/`,
+//! compiled into the binary. Each message has a dotted key such as
+//! `show.title` and may name `{placeholders}` that [`t!`](crate::t) fills
+//! in. English is the source catalog: a message missing from another
+//! catalog falls back to it.
+//!
+//! The locale comes from the `locale` setting, then `LC_ALL`,
+//! `LC_MESSAGES`, and `LANG`, so `LANG=de_DE.UTF-8` picks German. Anything
+//! without a catalog, including `C` and `POSIX`, is English.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+/// A language wok has a message catalog for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+impl Locale {
+    /// Every locale, English first.
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::De];
+
+    /// The language code, as written in config.toml.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+        }
+    }
+
+    /// The locale a POSIX locale name like `de_DE.UTF-8` or `de-AT` asks
+    /// for, if wok has its language.
+    pub fn from_posix(name: &str) -> Option<Locale> {
+        let language = name
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Locale::ALL.into_iter().find(|l| l.code() == language)
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../locales/en.toml"),
+            Locale::De => include_str!("../locales/de.toml"),
+        }
+    }
+
+    /// The message `key` in this locale, with `args` filled in.
+    pub fn message(self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let template = catalog(self)
+            .get(key)
+            .or_else(|| catalog(Locale::En).get(key))
+            .map_or(key, String::as_str);
+        fill(template, args)
+    }
+}
+
+/// The active locale, stored as `Locale as u8`.
+static LOCALE: AtomicU8 = AtomicU8::new(Locale::En as u8);
+
+/// Choose the locale for the rest of the process: `setting` if given, else
+/// the environment's.
+pub fn set_locale(setting: Option<Locale>) {
+    let locale = setting
+        .or_else(|| crate::env::message_locale().and_then(|name| Locale::from_posix(&name)))
+        .unwrap_or_default();
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// The active locale.
+pub fn locale() -> Locale {
+    Locale::ALL
+        .into_iter()
+        .find(|&l| l as u8 == LOCALE.load(Ordering::Relaxed))
+        .unwrap_or_default()
+}
+
+/// The message `key` in the active locale. Use [`t!`](crate::t) instead.
+pub fn message(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    locale().message(key, args)
+}
+
+static CATALOGS: LazyLock<HashMap<Locale, HashMap<String, String>>> = LazyLock::new(|| {
+    Locale::ALL
+        .into_iter()
+        .map(|locale| (locale, parse_catalog(locale.source())))
+        .collect()
+});
+
+pub(crate) fn catalog(locale: Locale) -> &'static HashMap<String, String> {
+    static EMPTY: LazyLock<HashMap<String, String>> = LazyLock::new(HashMap::new);
+    CATALOGS.get(&locale).unwrap_or(&EMPTY)
+}
+
+/// Flatten a catalog's tables into dotted keys.
+pub(crate) fn parse_catalog(source: &str) -> HashMap<String, String> {
+    fn walk(prefix: &str, table: &toml::Table, out: &mut HashMap<String, String>) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            match value {
+                toml::Value::String(s) => {
+                    out.insert(key, s.clone());
+                }
+                toml::Value::Table(t) => walk(&key, t, out),
+                _ => {}
+            }
+        }
+    }
+    let mut out = HashMap::new();
+    if let Ok(table) = source.parse::<toml::Table>() {
+        walk("", &table, &mut out);
+    }
+    out
+}
+
+/// Replace each `{name}` in `template` with its argument. Unknown names are
+/// left as written.
+fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let arg = after.find('}').and_then(|close| {
+            let name = &after[..close];
+            args.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| (value, close))
+        });
+        match arg {
+            Some((value, close)) => {
+                out.push_str(&value.to_string());
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Look up a message in the active locale, filling in named arguments.
+///
+/// ```rust,ignore
+/// println!("{}", t!("lifecycle.started", id = resolved_id));
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:literal) => {
+        $crate::i18n::message($key, &[])
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::message(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+#[cfg(test)]
+#[path = "i18n_tests.rs"]
+mod tests;
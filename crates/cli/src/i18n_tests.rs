@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use yare::parameterized;
+
+use super::*;
+
+/// The `{placeholders}` a message names.
+fn placeholders(message: &str) -> BTreeSet<&str> {
+    message
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// Every `.rs` file under `dir`, with its contents.
+fn sources(dir: &Path, out: &mut Vec<(String, String)>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            sources(&path, out);
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            let text = std::fs::read_to_string(&path).unwrap();
+            out.push((path.display().to_string(), text));
+        }
+    }
+}
+
+fn crate_sources() -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    sources(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut out);
+    out
+}
+
+#[test]
+fn catalogs_parse() {
+    for locale in Locale::ALL {
+        assert!(
+            catalog(locale).len() > 100,
+            "{} catalog is missing or unparseable",
+            locale.code()
+        );
+    }
+}
+
+#[test]
+fn translations_cover_english_with_the_same_placeholders() {
+    let english = catalog(Locale::En);
+    for locale in Locale::ALL {
+        let messages = catalog(locale);
+        for (key, message) in english {
+            let translated = messages
+                .get(key)
+                .unwrap_or_else(|| panic!("{} is missing {key}", locale.code()));
+            assert_eq!(
+                placeholders(translated),
+                placeholders(message),
+                "{} {key}",
+                locale.code()
+            );
+        }
+        for key in messages.keys() {
+            assert!(
+                english.contains_key(key),
+                "{} has unknown key {key}",
+                locale.code()
+            );
+        }
+    }
+}
+
+#[test]
+fn every_key_used_in_source_exists() {
+    let english = catalog(Locale::En);
+    for (path, text) in crate_sources() {
+        for (i, _) in text.match_indices("t!(") {
+            // Skip `format!(` and other macros ending in `t`
+            let before = text[..i].chars().next_back();
+            if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+            let rest = text[i + 3..].trim_start();
+            let Some(rest) = rest.strip_prefix('"') else {
+                continue;
+            };
+            let key = rest.split('"').next().unwrap();
+            if path.ends_with("i18n_tests.rs") {
+                continue;
+            }
+            assert!(english.contains_key(key), "{path} uses unknown key {key}");
+        }
+    }
+}
+
+#[test]
+fn every_key_is_used_in_source() {
+    let sources: String = crate_sources()
+        .into_iter()
+        .filter(|(path, _)| !path.ends_with("_tests.rs"))
+        .map(|(_, text)| text)
+        .collect();
+    for key in catalog(Locale::En).keys() {
+        assert!(
+            sources.contains(&format!("\"{key}\"")),
+            "{key} is never used"
+        );
+    }
+}
+
+#[parameterized(
+    bare = { "de", Some(Locale::De) },
+    region = { "de_DE", Some(Locale::De) },
+    encoding = { "de_AT.UTF-8", Some(Locale::De) },
+    modifier = { "de_DE@euro", Some(Locale::De) },
+    bcp47 = { "de-CH", Some(Locale::De) },
+    english = { "en_GB.UTF-8", Some(Locale::En) },
+    upper = { "DE", Some(Locale::De) },
+    posix = { "POSIX", None },
+    c = { "C.UTF-8", None },
+    unknown = { "fr_FR.UTF-8", None },
+)]
+fn from_posix(name: &str, expected: Option<Locale>) {
+    assert_eq!(Locale::from_posix(name), expected);
+}
+
+#[test]
+fn message_fills_placeholders() {
+    let message = Locale::En.message(
+        "lifecycle.completed_reason",
+        &[("id", &"prj-a1"), ("reason", &"shipped")],
+    );
+    assert_eq!(message, "Completed prj-a1 (shipped)");
+}
+
+#[test]
+fn message_fills_repeated_placeholders() {
+    let message = Locale::De.message("dep.epic_hint", &[("id", &"prj-a1"), ("count", &5)]);
+    assert_eq!(
+        message,
+        "Hinweis: prj-a1 verfolgt 5 Issues; mit `wok edit prj-a1 type epic` zum Epic machen"
+    );
+}
+
+#[test]
+fn message_in_german() {
+    let message = Locale::De.message("show.title", &[("title", &"Fix login")]);
+    assert_eq!(message, "Titel: Fix login");
+}
+
+#[test]
+fn unknown_key_is_shown_as_written() {
+    assert_eq!(Locale::De.message("no.such.key", &[]), "no.such.key");
+}
+
+#[test]
+fn fill_keeps_unknown_placeholders_and_lone_braces() {
+    assert_eq!(fill("{a} {b} {", &[("a", &1)]), "1 {b} {");
+    assert_eq!(fill("{a}{a}", &[("a", &"x")]), "xx");
+}
+
+#[test]
+fn parse_catalog_flattens_tables() {
+    let messages = parse_catalog("top = \"t\"\n[a.b]\nc = \"d\"\nn = 1\n");
+    assert_eq!(messages.get("top").map(String::as_str), Some("t"));
+    assert_eq!(messages.get("a.b.c").map(String::as_str), Some("d"));
+    assert!(!messages.contains_key("a.b.n"));
+}
+
+#[test]
+fn parse_catalog_ignores_invalid_toml() {
+    assert!(parse_catalog("not = [toml").is_empty());
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Layered configuration.
+//!
+//! The effective [`Config`] is built from, lowest precedence first:
+//!
+//! 1. built-in defaults
+//! 2. the user file, `~/.config/wok/config.toml` (or `$XDG_CONFIG_HOME/wok/`)
+//! 3. the project file, `.wok/config.toml`
+//! 4. `WOK_<KEY>` environment variables, e.g. `WOK_WIP_LIMIT` for `wip.limit`
+//!
+//! Tables merge key by key, so a user `[alias]` table and a project one
+//! combine; any other value, arrays included, replaces the one beneath it.
+//! Project-only keys (`prefix`, `private`, `workspace`, `workspace_id`) are
+//! ignored in the user file.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, CONFIG_FILE_NAME};
+use crate::error::{Error, Result};
+use crate::settings::{Setting, SETTINGS};
+
+/// Keys that only make sense for one project.
+const PROJECT_ONLY: [&str; 4] = ["prefix", "private", "workspace", "workspace_id"];
+
+/// Where an effective value was set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    Default,
+    User(PathBuf),
+    Project(PathBuf),
+    Env(String),
+}
+
+impl Origin {
+    /// The layer's name: `default`, `user`, `project` or `env`.
+    pub fn layer(&self) -> &'static str {
+        match self {
+            Origin::Default => "default",
+            Origin::User(_) => "user",
+            Origin::Project(_) => "project",
+            Origin::Env(_) => "env",
+        }
+    }
+
+    /// The file or variable the value came from.
+    pub fn source(&self) -> Option<String> {
+        match self {
+            Origin::Default => None,
+            Origin::User(path) | Origin::Project(path) => Some(path.display().to_string()),
+            Origin::Env(var) => Some(var.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source() {
+            Some(source) => write!(f, "{}: {}", self.layer(), source),
+            None => f.write_str(self.layer()),
+        }
+    }
+}
+
+/// A config file and its parsed contents.
+#[derive(Debug, Default)]
+struct FileLayer {
+    path: PathBuf,
+    table: toml::Table,
+}
+
+impl FileLayer {
+    /// Read `path`; a missing file is an empty layer.
+    fn read(path: PathBuf, required: bool) -> Result<Self> {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::Config(format!("failed to read config: {}", e))),
+        };
+        let table = toml::from_str(&content)
+            .map_err(|e| Error::Config(format!("failed to parse {}: {}", path.display(), e)))?;
+        Ok(FileLayer { path, table })
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        lookup(&self.table, key).is_some()
+    }
+}
+
+/// The layers the effective configuration of one project is built from.
+#[derive(Debug, Default)]
+pub struct Layers {
+    user: FileLayer,
+    project: FileLayer,
+    /// `(setting key, variable, value)` for each `WOK_<KEY>` that is set.
+    env: Vec<(&'static str, String, String)>,
+}
+
+impl Layers {
+    /// Read every layer for the project in `work_dir`.
+    pub fn load(work_dir: &Path) -> Result<Self> {
+        let user = match user_config_path() {
+            Some(path) => FileLayer::read(path, false)?,
+            None => FileLayer::default(),
+        };
+        let project = FileLayer::read(work_dir.join(CONFIG_FILE_NAME), true)?;
+        let env = SETTINGS
+            .iter()
+            .filter_map(|s| crate::env::setting(s.key).map(|value| (s.key, value)))
+            .collect();
+        Ok(Layers::new(user, project, env))
+    }
+
+    /// Layers from parsed files and `(setting key, value)` overrides.
+    fn new(mut user: FileLayer, project: FileLayer, env: Vec<(&'static str, String)>) -> Self {
+        for key in PROJECT_ONLY {
+            user.table.remove(key);
+        }
+        let env = env
+            .into_iter()
+            .map(|(key, value)| (key, crate::env::setting_var(key), value))
+            .collect();
+        Layers { user, project, env }
+    }
+
+    /// The effective configuration.
+    pub fn resolve(&self) -> Result<Config> {
+        let mut table = self.user.table.clone();
+        merge(&mut table, self.project.table.clone());
+        let mut config: Config = toml::Value::Table(table)
+            .try_into()
+            .map_err(|e| Error::Config(format!("failed to parse config: {}", e)))?;
+        for (key, var, value) in &self.env {
+            setting(key)?
+                .set(&mut config, Some(value))
+                .map_err(|e| match e {
+                    Error::InvalidSetting { reason, .. } => Error::InvalidSetting {
+                        key: var.clone(),
+                        reason,
+                    },
+                    e => e,
+                })?;
+        }
+        Ok(config)
+    }
+
+    /// Where the effective value of setting `key` comes from.
+    pub fn origin(&self, key: &str) -> Origin {
+        if let Some((_, var, _)) = self.env.iter().find(|(k, _, _)| *k == key) {
+            Origin::Env(var.clone())
+        } else if self.project.contains(key) {
+            Origin::Project(self.project.path.clone())
+        } else if self.user.contains(key) {
+            Origin::User(self.user.path.clone())
+        } else {
+            Origin::Default
+        }
+    }
+}
+
+/// The user-level config file: `$XDG_CONFIG_HOME/wok/config.toml`, else
+/// `~/.config/wok/config.toml`.
+pub fn user_config_path() -> Option<PathBuf> {
+    let config_home = crate::env::xdg_config_home()
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))?;
+    Some(config_home.join("wok").join(CONFIG_FILE_NAME))
+}
+
+/// Set `key` in the user file, or restore its default for `None`.
+pub fn set_user(key: &str, value: Option<&str>) -> Result<PathBuf> {
+    if PROJECT_ONLY.contains(&key) {
+        return Err(Error::InvalidSetting {
+            key: key.to_string(),
+            reason: "it can only be set for a project".to_string(),
+        });
+    }
+    let setting = setting(key)?;
+    let path = user_config_path()
+        .ok_or_else(|| Error::Config("cannot find the home directory".to_string()))?;
+    let mut user = FileLayer::read(path, false)?;
+    let mut config: Config = toml::Value::Table(user.table.clone())
+        .try_into()
+        .map_err(|e| Error::Config(format!("failed to parse {}: {}", user.path.display(), e)))?;
+    setting.set(&mut config, value)?;
+
+    // Rewrite only this key so the file keeps what the user wrote.
+    let written = toml::Table::try_from(&config)
+        .map_err(|e| Error::Config(format!("failed to serialize config: {}", e)))?;
+    remove(&mut user.table, key);
+    if let Some(value) = lookup(&written, key) {
+        insert(&mut user.table, key, value.clone());
+    }
+    if let Some(dir) = user.path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let content = toml::to_string_pretty(&user.table)
+        .map_err(|e| Error::Config(format!("failed to serialize config: {}", e)))?;
+    fs::write(&user.path, content)?;
+    Ok(user.path)
+}
+
+fn setting(key: &str) -> Result<&'static Setting> {
+    crate::settings::find(key)
+}
+
+/// Merge `over` into `base`, table by table.
+fn merge(base: &mut toml::Table, over: toml::Table) {
+    for (key, value) in over {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(over)) => merge(base, over),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// The value at dotted `key`.
+fn lookup<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Value> {
+    match key.split_once('.') {
+        Some((head, rest)) => lookup(table.get(head)?.as_table()?, rest),
+        None => table.get(key),
+    }
+}
+
+fn insert(table: &mut toml::Table, key: &str, value: toml::Value) {
+    match key.split_once('.') {
+        Some((head, rest)) => {
+            let entry = table
+                .entry(head)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let toml::Value::Table(inner) = entry {
+                insert(inner, rest, value);
+            }
+        }
+        None => {
+            table.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// Remove dotted `key`, and any table it leaves empty.
+fn remove(table: &mut toml::Table, key: &str) {
+    match key.split_once('.') {
+        Some((head, rest)) => {
+            if let Some(toml::Value::Table(inner)) = table.get_mut(head) {
+                remove(inner, rest);
+                if inner.is_empty() {
+                    table.remove(head);
+                }
+            }
+        }
+        None => {
+            table.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "layers_tests.rs"]
+mod tests;
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::config::ColorMode;
+
+const USER: &str = "/home/alice/.config/wok/config.toml";
+const PROJECT: &str = "/src/app/.wok/config.toml";
+
+fn file(path: &str, content: &str) -> FileLayer {
+    FileLayer {
+        path: PathBuf::from(path),
+        table: toml::from_str(content).unwrap(),
+    }
+}
+
+fn layers(user: &str, project: &str, env: &[(&'static str, &str)]) -> Layers {
+    let env = env.iter().map(|(k, v)| (*k, v.to_string())).collect();
+    Layers::new(file(USER, user), file(PROJECT, project), env)
+}
+
+#[test]
+fn project_overrides_user() {
+    let layers = layers(
+        "user = \"alice\"\ncolor = \"never\"\n",
+        "prefix = \"app\"\ncolor = \"always\"\n",
+        &[],
+    );
+    let config = layers.resolve().unwrap();
+    assert_eq!(config.prefix, "app");
+    assert_eq!(config.user.as_deref(), Some("alice"));
+    assert_eq!(config.color, ColorMode::Always);
+}
+
+#[test]
+fn env_overrides_everything() {
+    let layers = layers(
+        "[wip]\nlimit = 1\n",
+        "prefix = \"app\"\n[wip]\nlimit = 2\n",
+        &[("wip.limit", "3")],
+    );
+    assert_eq!(layers.resolve().unwrap().wip.limit, Some(3));
+    assert_eq!(
+        layers.origin("wip.limit"),
+        Origin::Env("WOK_WIP_LIMIT".to_string())
+    );
+}
+
+#[test]
+fn tables_merge_key_by_key() {
+    let layers = layers(
+        "[alias]\nmine = \"list --mine\"\n[wip]\nmode = \"block\"\n",
+        "prefix = \"app\"\n[alias]\nwip = \"list -s in_progress\"\n[wip]\nlimit = 2\n",
+        &[],
+    );
+    let config = layers.resolve().unwrap();
+    assert_eq!(config.alias.len(), 2);
+    assert_eq!(config.wip.limit, Some(2));
+    assert_eq!(config.wip.mode, crate::config::WipMode::Block);
+}
+
+#[test]
+fn arrays_replace_rather_than_append() {
+    let layers = layers(
+        "team = [\"alice\"]\n",
+        "prefix = \"app\"\nteam = [\"bob\"]\n",
+        &[],
+    );
+    assert_eq!(layers.resolve().unwrap().team, vec!["bob"]);
+}
+
+#[test]
+fn user_file_cannot_set_project_only_keys() {
+    let layers = layers(
+        "prefix = \"usr\"\nprivate = true\n",
+        "prefix = \"app\"\n",
+        &[],
+    );
+    let config = layers.resolve().unwrap();
+    assert_eq!(config.prefix, "app");
+    assert!(!config.private);
+    assert_eq!(layers.origin("private"), Origin::Default);
+}
+
+#[test]
+fn origin_names_the_winning_layer() {
+    let layers = layers(
+        "user = \"alice\"\ncolor = \"never\"\n",
+        "prefix = \"app\"\ncolor = \"always\"\n",
+        &[],
+    );
+    assert_eq!(
+        layers.origin("prefix"),
+        Origin::Project(PathBuf::from(PROJECT))
+    );
+    assert_eq!(
+        layers.origin("color"),
+        Origin::Project(PathBuf::from(PROJECT))
+    );
+    assert_eq!(layers.origin("user"), Origin::User(PathBuf::from(USER)));
+    assert_eq!(layers.origin("limits.list"), Origin::Default);
+}
+
+#[test]
+fn invalid_env_value_names_the_variable() {
+    let layers = layers("", "prefix = \"app\"\n", &[("color", "loud")]);
+    let err = layers.resolve().unwrap_err();
+    assert!(err.to_string().starts_with("invalid value for WOK_COLOR:"));
+}
+
+#[test]
+fn origin_display() {
+    assert_eq!(Origin::Default.to_string(), "default");
+    assert_eq!(
+        Origin::Env("WOK_COLOR".to_string()).to_string(),
+        "env: WOK_COLOR"
+    );
+    assert_eq!(
+        Origin::Project(PathBuf::from(PROJECT)).to_string(),
+        format!("project: {}", PROJECT)
+    );
+}
+
+#[test]
+fn dotted_keys_insert_and_remove() {
+    let mut table = toml::Table::new();
+    insert(&mut table, "wip.limit", toml::Value::Integer(2));
+    assert_eq!(lookup(&table, "wip.limit"), Some(&toml::Value::Integer(2)));
+    remove(&mut table, "wip.limit");
+    assert!(table.is_empty());
+}
@@ -30,20 +30,54 @@
 //! let db = Database::open(&db_path)?;
 //! ```
 
+// Shadow std's stdout macros for every module below so `--quiet` applies
+// to all command output.
+macro_rules! print {
+    ($($arg:tt)*) => {
+        if !$crate::quiet::is_quiet() {
+            ::std::print!($($arg)*)
+        }
+    };
+}
+
+macro_rules! println {
+    ($($arg:tt)*) => {
+        if !$crate::quiet::is_quiet() {
+            ::std::println!($($arg)*)
+        }
+    };
+}
+
+mod aging;
+pub mod alias;
 mod cli;
 pub mod colors;
 mod commands;
 mod completions;
 pub mod daemon;
-mod display;
+pub mod display;
 pub mod env;
+mod estimate;
 pub mod filter;
+mod git;
 pub mod help;
+mod history;
 pub mod hooks;
+pub mod i18n;
+mod layers;
+mod markdown;
+mod ndjson;
 mod normalize;
+mod pager;
+pub mod quiet;
+pub mod read_only;
+pub mod rules;
 mod schema;
+mod settings;
 pub mod timings;
+pub mod trace;
 mod validate;
+mod workspace;
 
 pub mod config;
 pub mod db;
@@ -52,8 +86,10 @@ pub mod id;
 pub mod models;
 
 pub use cli::{
-    AssigneeArgs, Cli, Command, ConfigCommand, DaemonCommand, HookCommand, HooksCommand, LimitArgs,
-    OutputFormat, SchemaCommand, TypeLabelArgs,
+    AliasCommand, AssigneeArgs, Cli, Command, ConfigCommand, DaemonCommand, ErrorFormat,
+    ExportFormat, GroupBy, HookCommand, HooksCommand, LimitArgs, LinkCommand, ListColumn,
+    ListFormat, LogFormat, Order, OutputFormat, PageArgs, PrCommand, QueueCommand, RecordFormat,
+    RulesCommand, SchemaCommand, SortArgs, TreeRel, TypeLabelArgs,
 };
 pub use config::{find_work_dir, get_db_path, init_work_dir, Config};
 pub use db::Database;
@@ -120,7 +156,18 @@ pub fn run(command: Command) -> Result<()> {
             prefix,
             path,
             private,
-        } => commands::init::run(prefix, path, private),
+            from,
+            format,
+            keep_ids,
+            join,
+        } => {
+            let from = from.map(|file| commands::init::ImportFrom {
+                file,
+                format,
+                keep_ids,
+            });
+            commands::init::run(prefix, path, private, from, join)
+        }
         Command::New {
             type_or_title,
             title,
@@ -129,6 +176,7 @@ pub fn run(command: Command) -> Result<()> {
             link,
             assignee,
             priority,
+            estimate,
             description,
             blocks,
             blocked_by,
@@ -136,6 +184,7 @@ pub fn run(command: Command) -> Result<()> {
             tracked_by,
             output,
             prefix,
+            force,
         } => commands::new::run(
             type_or_title,
             title,
@@ -144,6 +193,7 @@ pub fn run(command: Command) -> Result<()> {
             link,
             assignee,
             priority,
+            estimate,
             description,
             blocks,
             blocked_by,
@@ -151,11 +201,24 @@ pub fn run(command: Command) -> Result<()> {
             tracked_by,
             output,
             prefix,
+            force,
         ),
-        Command::Start { ids } => commands::lifecycle::start(&ids),
-        Command::Done { ids, reason } => commands::lifecycle::done(&ids, reason.as_deref()),
-        Command::Close { ids, reason } => commands::lifecycle::close(&ids, reason.as_deref()),
-        Command::Reopen { ids, reason } => commands::lifecycle::reopen(&ids, reason.as_deref()),
+        Command::Start { ids, if_match } => commands::lifecycle::start(&ids, if_match),
+        Command::Done {
+            ids,
+            reason,
+            if_match,
+        } => commands::lifecycle::done(&ids, reason.as_deref(), if_match),
+        Command::Close {
+            ids,
+            reason,
+            if_match,
+        } => commands::lifecycle::close(&ids, reason.as_deref(), if_match),
+        Command::Reopen {
+            ids,
+            reason,
+            if_match,
+        } => commands::lifecycle::reopen(&ids, reason.as_deref(), if_match),
         Command::Edit {
             id,
             attr,
@@ -164,6 +227,7 @@ pub fn run(command: Command) -> Result<()> {
             flag_description,
             flag_type,
             flag_assignee,
+            if_match,
         } => {
             let (resolved_attr, resolved_value) = if let Some(v) = flag_title {
                 ("title".to_string(), v)
@@ -180,7 +244,7 @@ pub fn run(command: Command) -> Result<()> {
                     field: "attribute and value",
                 });
             };
-            commands::edit::run(&id, &resolved_attr, &resolved_value)
+            commands::edit::run(&id, &resolved_attr, &resolved_value, if_match)
         }
         Command::List {
             status,
@@ -189,39 +253,136 @@ pub fn run(command: Command) -> Result<()> {
             filter,
             limits,
             blocked,
+            stale,
             all,
+            order,
+            sorting,
+            columns,
+            pager,
+            group_by,
+            paging,
             output,
         } => commands::list::run(
             status,
             type_label.r#type,
             type_label.label,
             type_label.prefix,
-            assignee_args.assignee,
+            assignee_args.assignees(),
             assignee_args.unassigned,
             filter,
             limits.limit,
             limits.no_limit,
             blocked,
+            stale,
             all,
             output,
+            order,
+            sorting,
+            columns,
+            pager,
+            group_by,
+            paging,
         ),
-        Command::Show { ids, output } => commands::show::run(&ids, &output),
-        Command::Tree { ids } => commands::tree::run(&ids),
-        Command::Link { id, url, reason } => commands::link::add(&id, &url, reason),
+        Command::Show {
+            ids,
+            output,
+            raw,
+            as_of,
+            diff,
+        } => commands::show::run(&ids, &output, raw, as_of.as_deref(), diff.as_deref()),
+        Command::Tree {
+            ids,
+            depth,
+            rel,
+            up,
+            raw,
+            output,
+        } => commands::tree::run(&ids, depth, rel, up, raw, output),
+        Command::Link {
+            command: Some(LinkCommand::Refresh { ids, all }),
+            ..
+        } => commands::link::refresh(&ids, all),
+        Command::Link {
+            command: Some(LinkCommand::Resolve { id, url }),
+            ..
+        } => commands::link::resolve(&id, &url),
+        Command::Link {
+            command: Some(LinkCommand::Restore { id, url }),
+            ..
+        } => commands::link::restore(&id, &url),
+        Command::Link {
+            id, url, reason, ..
+        } => commands::link::add(&id.unwrap_or_default(), &url.unwrap_or_default(), reason),
         Command::Unlink { id, url } => commands::link::remove(&id, &url),
+        Command::Dedupe {
+            interactive,
+            output,
+        } => commands::dedupe::run(output, interactive),
+        Command::Merge {
+            from,
+            into,
+            dry_run,
+            output,
+        } => commands::merge::run(&from, &into, dry_run, output),
+        Command::Sweep {
+            filter,
+            status,
+            close,
+            reason,
+            label,
+            dry_run,
+            output,
+        } => commands::sweep::run(filter, status, close, reason, label, dry_run, output),
+        Command::Split {
+            id,
+            titles,
+            issue_type,
+            copy_labels,
+            copy_assignee,
+            output,
+        } => commands::split::run(
+            &id,
+            &titles,
+            &issue_type,
+            commands::split::CopyFields {
+                labels: copy_labels,
+                assignee: copy_assignee,
+            },
+            output,
+        ),
+        Command::Branch { id } => commands::branch::run(&id),
+        Command::Pr(PrCommand::Describe { id }) => commands::pr::describe(&id),
+        Command::Open {
+            id,
+            link,
+            web,
+            all,
+            print,
+        } => {
+            let pick = commands::open::Pick {
+                link_type: link.as_deref(),
+                web,
+                all,
+            };
+            commands::open::run(&id, pick, print)
+        }
         Command::Dep {
             from_id,
             rel,
             to_ids,
-        } => commands::dep::add(&from_id, &rel, &to_ids),
+            auto_epic,
+        } => commands::dep::add(&from_id, &rel, &to_ids, auto_epic),
         Command::Undep {
             from_id,
             rel,
             to_ids,
         } => commands::dep::remove(&from_id, &rel, &to_ids),
         Command::Label { args } => {
-            let (db, _config, _work_dir) = commands::open_db()?;
+            let (db, config, _work_dir) = commands::open_db()?;
             let (ids, labels) = split_ids_and_labels(&db, &args)?;
+            for label in &labels {
+                config.labels.check(label)?;
+            }
             commands::label::add_with_db(&db, &ids, &labels)
         }
         Command::Unlabel { args } => {
@@ -229,17 +390,80 @@ pub fn run(command: Command) -> Result<()> {
             let (ids, labels) = split_ids_and_labels(&db, &args)?;
             commands::label::remove_with_db(&db, &ids, &labels)
         }
+        Command::Note {
+            id,
+            delete: Some(note_id),
+            ..
+        } => commands::note::delete(&id, note_id),
+        Command::Note {
+            id,
+            restore: Some(note_id),
+            ..
+        } => commands::note::restore(&id, note_id),
+        Command::Note {
+            id,
+            content,
+            edit: Some(note_id),
+            ..
+        } => commands::note::edit(&id, note_id, &content.unwrap_or_default()),
         Command::Note {
             id,
             content,
             replace,
-        } => commands::note::run(&id, &content, replace),
-        Command::Log { id, limits } => commands::log::run(id, limits.limit, limits.no_limit),
-        Command::Export { filepath } => commands::export::run(&filepath),
+            kind,
+            ..
+        } => commands::note::run(&id, &content.unwrap_or_default(), replace, kind.as_deref()),
+        Command::Notes {
+            id,
+            kind,
+            deleted,
+            output,
+            raw,
+        } => commands::note::list(&id, kind.as_deref(), deleted, output, raw),
+        Command::Assign { id, who } => commands::assign::assign(&id, &who),
+        Command::Unassign { id } => commands::assign::unassign(&id),
+        Command::Log {
+            id,
+            actor,
+            limits,
+            output,
+        } => commands::log::run(id, actor, limits.limit, limits.no_limit, output),
+        Command::Activity {
+            since,
+            assignee,
+            prefix,
+            output,
+        } => commands::activity::run(since, assignee, prefix, output),
+        Command::Digest {
+            since,
+            assignee,
+            prefix,
+            output,
+        } => commands::digest::run(since, assignee, prefix, output),
+        Command::Standup {
+            assignee,
+            team,
+            since,
+            output,
+        } => commands::standup::run(assignee, team, since, output),
+        Command::Serve { port, host } => commands::serve::run(&host, port),
+        Command::Rpc { stdio: _ } => commands::rpc::run(),
+        Command::Subscribe { ids } => commands::subscribe::subscribe(&ids),
+        Command::Unsubscribe { ids } => commands::subscribe::unsubscribe(&ids),
+        Command::Inbox { peek, output } => commands::subscribe::inbox(peek, output),
+        Command::Export {
+            filepath,
+            output,
+            format,
+        } => commands::export::run(filepath.as_deref(), output, format),
         Command::Import {
             file,
             input,
             format,
+            column,
+            interactive,
+            map,
+            on_conflict,
             dry_run,
             status,
             type_label,
@@ -247,17 +471,31 @@ pub fn run(command: Command) -> Result<()> {
             file,
             input,
             &format,
+            column,
+            interactive,
+            map,
+            on_conflict,
             dry_run,
             status,
             type_label.r#type,
             type_label.label,
             type_label.prefix,
         ),
+        Command::Backup { to, output } => commands::backup::backup(to, output),
+        Command::Restore {
+            archive,
+            check,
+            output,
+        } => commands::backup::restore(archive, check, output),
         Command::Ready {
             type_label,
             assignee,
             unassigned,
             all_assignees,
+            order,
+            sorting,
+            explain,
+            capacity,
             output,
         } => commands::ready::run(
             type_label.r#type,
@@ -267,6 +505,23 @@ pub fn run(command: Command) -> Result<()> {
             unassigned,
             all_assignees,
             output,
+            order,
+            sorting,
+            explain,
+            capacity,
+        ),
+        Command::Next {
+            type_label,
+            assignee,
+            claim,
+            output,
+        } => commands::next::run(
+            type_label.r#type,
+            type_label.label,
+            type_label.prefix,
+            assignee,
+            claim,
+            output,
         ),
         Command::Search {
             query,
@@ -275,6 +530,8 @@ pub fn run(command: Command) -> Result<()> {
             assignee_args,
             filter,
             limits,
+            sorting,
+            paging,
             output,
         } => commands::search::run(
             &query,
@@ -282,23 +539,43 @@ pub fn run(command: Command) -> Result<()> {
             type_label.r#type,
             type_label.label,
             type_label.prefix,
-            assignee_args.assignee,
+            assignee_args.assignees(),
             assignee_args.unassigned,
             filter,
             limits.limit,
             limits.no_limit,
             output,
+            sorting,
+            paging,
         ),
-        Command::Completion { shell } => {
+        Command::Completion {
+            shell,
+            dynamic: false,
+        } => {
             let mut cmd = Cli::command();
             generate(shell, &mut cmd, "wok", &mut std::io::stdout());
             Ok(())
         }
-        Command::Prime => commands::prime::run(),
+        Command::Completion {
+            shell,
+            dynamic: true,
+        } => {
+            print!("{}", completions::dynamic_script(shell)?);
+            Ok(())
+        }
+        Command::Complete { index, words } => commands::complete::run(index, &words),
+        Command::Prime { profile } => commands::prime::run(profile),
+        Command::Upgrade { check } => commands::upgrade::run(check),
         Command::Daemon(cmd) => match cmd {
             DaemonCommand::Status => commands::daemon::status(),
             DaemonCommand::Stop => commands::daemon::stop(),
             DaemonCommand::Start { foreground } => commands::daemon::start(foreground),
+            DaemonCommand::Restart => commands::daemon::restart(),
+            DaemonCommand::Health => commands::daemon::health(),
+            DaemonCommand::InstallService { user, print } => {
+                commands::daemon::install_service(user, print)
+            }
+            DaemonCommand::UninstallService { user } => commands::daemon::uninstall_service(user),
             DaemonCommand::Logs { follow } => commands::daemon::logs(follow),
         },
         Command::Hooks(cmd) => match cmd {
@@ -306,15 +583,42 @@ pub fn run(command: Command) -> Result<()> {
                 scope,
                 interactive,
                 yes,
-            } => commands::hooks::install(scope, interactive, yes),
+                context,
+                auto_note,
+            } => commands::hooks::install(scope, interactive, yes, context, auto_note),
             HooksCommand::Uninstall { scope } => commands::hooks::uninstall(scope),
             HooksCommand::Status => commands::hooks::status(),
+            HooksCommand::Context => commands::hooks::context(),
+            HooksCommand::NoteFailure => commands::hooks::note_failure(),
+            HooksCommand::CommitMsg { file } => commands::hooks::commit_msg(&file),
+            HooksCommand::PostCommit => commands::hooks::post_commit(),
         },
         Command::Hook(cmd) => match cmd {
             HookCommand::List { output } => commands::hook::list(output),
-            HookCommand::Test { name, id, event } => commands::hook::test(name, id, event),
+            HookCommand::Test {
+                name,
+                id,
+                event,
+                run,
+            } => commands::hook::test(name, id, event, run),
+            HookCommand::Runs {
+                failed,
+                limit,
+                output,
+            } => commands::hook::runs(failed, limit, output),
+            HookCommand::Stale => commands::hook::stale(),
+        },
+        Command::Queue(cmd) => match cmd {
+            QueueCommand::Take {
+                queue,
+                assignee,
+                output,
+            } => commands::queue::take(&queue, assignee, output),
+            QueueCommand::List { output } => commands::queue::list(output),
         },
         Command::Config(cmd) => commands::config::run(cmd),
+        Command::Alias(cmd) => commands::alias::run(cmd),
+        Command::Labels(cmd) => commands::labels::run(cmd),
         Command::Schema(cmd) => commands::schema::run(cmd),
     }
 }
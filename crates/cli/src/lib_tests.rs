@@ -13,7 +13,10 @@
 //! This file contains tests for command variants that can be tested without
 //! filesystem dependencies, validating the routing logic works correctly.
 
-use crate::{AssigneeArgs, Command, LimitArgs, OutputFormat, TypeLabelArgs};
+use crate::{
+    AssigneeArgs, Command, LimitArgs, ListFormat, LogFormat, Order, OutputFormat, PageArgs,
+    RecordFormat, SortArgs, TreeRel, TypeLabelArgs,
+};
 
 // Note: Most Command variants require open_db() which needs filesystem access.
 // Those are tested via integration tests in tests/integration.rs.
@@ -25,12 +28,17 @@ fn test_command_init_construction() {
         prefix: Some("test".to_string()),
         path: None,
         private: false,
+        from: None,
+        format: "wok".to_string(),
+        keep_ids: false,
+        join: None,
     };
     // Verify pattern matching works
     if let Command::Init {
         prefix,
         path,
         private,
+        ..
     } = cmd
     {
         assert_eq!(prefix, Some("test".to_string()));
@@ -47,11 +55,16 @@ fn test_command_init_private_construction() {
         prefix: Some("test".to_string()),
         path: None,
         private: true,
+        from: None,
+        format: "wok".to_string(),
+        keep_ids: false,
+        join: None,
     };
     if let Command::Init {
         prefix,
         path,
         private,
+        ..
     } = cmd
     {
         assert_eq!(prefix, Some("test".to_string()));
@@ -72,6 +85,7 @@ fn test_command_new_construction() {
         link: vec!["https://github.com/org/repo/issues/123".to_string()],
         assignee: Some("alice".to_string()),
         priority: Some(2),
+        estimate: None,
         description: None,
         blocks: vec![],
         blocked_by: vec![],
@@ -79,6 +93,7 @@ fn test_command_new_construction() {
         tracked_by: vec![],
         output: OutputFormat::Text,
         prefix: None,
+        force: false,
     };
     if let Command::New {
         type_or_title,
@@ -126,6 +141,7 @@ fn test_command_new_with_dependencies_construction() {
         link: vec![],
         assignee: None,
         priority: None,
+        estimate: None,
         description: None,
         blocks: vec!["task-1".to_string()],
         blocked_by: vec!["task-2".to_string()],
@@ -133,6 +149,7 @@ fn test_command_new_with_dependencies_construction() {
         tracked_by: vec!["feature-1".to_string()],
         output: OutputFormat::Text,
         prefix: None,
+        force: false,
     };
     if let Command::New {
         blocks,
@@ -156,58 +173,65 @@ fn test_command_lifecycle_construction() {
     // Test Start (single ID)
     let cmd = Command::Start {
         ids: vec!["test-1".to_string()],
+        if_match: None,
     };
-    assert!(matches!(cmd, Command::Start { ids } if ids == vec!["test-1"]));
+    assert!(matches!(cmd, Command::Start { ids, .. } if ids == vec!["test-1"]));
 
     // Test Start (multiple IDs)
     let cmd = Command::Start {
         ids: vec!["test-1".to_string(), "test-2".to_string()],
+        if_match: None,
     };
-    assert!(matches!(cmd, Command::Start { ids } if ids == vec!["test-1", "test-2"]));
+    assert!(matches!(cmd, Command::Start { ids, .. } if ids == vec!["test-1", "test-2"]));
 
     // Test Done
     let cmd = Command::Done {
         ids: vec!["test-1".to_string()],
         reason: Some("completed".to_string()),
+        if_match: None,
     };
     assert!(
-        matches!(cmd, Command::Done { ids, reason } if ids == vec!["test-1"] && reason == Some("completed".to_string()))
+        matches!(cmd, Command::Done { ids, reason, .. } if ids == vec!["test-1"] && reason == Some("completed".to_string()))
     );
 
     // Test Close
     let cmd = Command::Close {
         ids: vec!["test-1".to_string()],
         reason: Some("wont fix".to_string()),
+        if_match: None,
     };
     assert!(
-        matches!(cmd, Command::Close { ids, reason } if ids == vec!["test-1"] && reason == Some("wont fix".to_string()))
+        matches!(cmd, Command::Close { ids, reason, .. } if ids == vec!["test-1"] && reason == Some("wont fix".to_string()))
     );
 
     // Test Close without reason (for human interactive mode)
     let cmd = Command::Close {
         ids: vec!["test-1".to_string()],
         reason: None,
+        if_match: None,
     };
     assert!(
-        matches!(cmd, Command::Close { ids, reason } if ids == vec!["test-1"] && reason.is_none())
+        matches!(cmd, Command::Close { ids, reason, .. } if ids == vec!["test-1"] && reason.is_none())
     );
 
     // Test Reopen
     let cmd = Command::Reopen {
         ids: vec!["test-1".to_string()],
         reason: Some("need more work".to_string()),
+        if_match: None,
     };
     assert!(
-        matches!(cmd, Command::Reopen { ids, reason } if ids == vec!["test-1"] && reason == Some("need more work".to_string()))
+        matches!(cmd, Command::Reopen { ids, reason, .. } if ids == vec!["test-1"] && reason == Some("need more work".to_string()))
     );
 
     // Test Reopen without reason (for human interactive mode)
     let cmd = Command::Reopen {
         ids: vec!["test-1".to_string()],
         reason: None,
+        if_match: None,
     };
     assert!(
-        matches!(cmd, Command::Reopen { ids, reason } if ids == vec!["test-1"] && reason.is_none())
+        matches!(cmd, Command::Reopen { ids, reason, .. } if ids == vec!["test-1"] && reason.is_none())
     );
 }
 
@@ -223,6 +247,7 @@ fn test_command_list_construction() {
         assignee_args: AssigneeArgs {
             assignee: vec![],
             unassigned: false,
+            mine: false,
         },
         filter: vec![],
         limits: LimitArgs {
@@ -230,8 +255,15 @@ fn test_command_list_construction() {
             no_limit: false,
         },
         blocked: false,
+        stale: false,
         all: false,
-        output: OutputFormat::Text,
+        order: Order::Default,
+        sorting: SortArgs::default(),
+        columns: vec![],
+        pager: false,
+        group_by: None,
+        paging: PageArgs::default(),
+        output: ListFormat::Text,
     };
     if let Command::List {
         status,
@@ -254,9 +286,12 @@ fn test_command_show_construction() {
     let cmd = Command::Show {
         ids: vec!["test-1".to_string()],
         output: "json".to_string(),
+        raw: false,
+        as_of: None,
+        diff: None,
     };
     assert!(
-        matches!(cmd, Command::Show { ids, output } if ids == vec!["test-1"] && output == "json")
+        matches!(cmd, Command::Show { ids, output, .. } if ids == vec!["test-1"] && output == "json")
     );
 }
 
@@ -266,11 +301,13 @@ fn test_command_dep_construction() {
         from_id: "feature-1".to_string(),
         rel: "blocks".to_string(),
         to_ids: vec!["task-1".to_string(), "task-2".to_string()],
+        auto_epic: false,
     };
     if let Command::Dep {
         from_id,
         rel,
         to_ids,
+        ..
     } = cmd
     {
         assert_eq!(from_id, "feature-1");
@@ -309,11 +346,15 @@ fn test_command_label_construction() {
 fn test_command_note_construction() {
     let cmd = Command::Note {
         id: "test-1".to_string(),
-        content: "My note".to_string(),
+        content: Some("My note".to_string()),
         replace: true,
+        kind: None,
+        edit: None,
+        delete: None,
+        restore: None,
     };
     assert!(
-        matches!(cmd, Command::Note { id, content, replace } if id == "test-1" && content == "My note" && replace)
+        matches!(cmd, Command::Note { id, content, replace, .. } if id == "test-1" && content.as_deref() == Some("My note") && replace)
     );
 }
 
@@ -321,33 +362,41 @@ fn test_command_note_construction() {
 fn test_command_log_construction() {
     let cmd = Command::Log {
         id: Some("test-1".to_string()),
+        actor: None,
         limits: LimitArgs {
             limit: Some(50),
             no_limit: false,
         },
+        output: LogFormat::Text,
     };
     assert!(
-        matches!(cmd, Command::Log { id, limits } if id == Some("test-1".to_string()) && limits.limit == Some(50) && !limits.no_limit)
+        matches!(cmd, Command::Log { id, limits, .. } if id == Some("test-1".to_string()) && limits.limit == Some(50) && !limits.no_limit)
     );
 
     let cmd = Command::Log {
         id: None,
+        actor: Some("claude-1".to_string()),
         limits: LimitArgs {
             limit: None,
             no_limit: true,
         },
+        output: LogFormat::Ndjson,
     };
     assert!(
-        matches!(cmd, Command::Log { id, limits } if id.is_none() && limits.limit.is_none() && limits.no_limit)
+        matches!(cmd, Command::Log { id, actor, limits, .. } if id.is_none() && actor.as_deref() == Some("claude-1") && limits.limit.is_none() && limits.no_limit)
     );
 }
 
 #[test]
 fn test_command_export_construction() {
     let cmd = Command::Export {
-        filepath: "/tmp/export.jsonl".to_string(),
+        filepath: Some("/tmp/export.jsonl".to_string()),
+        output: None,
+        format: RecordFormat::Wok,
     };
-    assert!(matches!(cmd, Command::Export { filepath } if filepath == "/tmp/export.jsonl"));
+    assert!(
+        matches!(cmd, Command::Export { filepath, .. } if filepath.as_deref() == Some("/tmp/export.jsonl"))
+    );
 }
 
 #[test]
@@ -361,6 +410,10 @@ fn test_command_ready_construction() {
         assignee: vec![],
         unassigned: false,
         all_assignees: false,
+        order: Order::Topo,
+        sorting: SortArgs::default(),
+        explain: false,
+        capacity: None,
         output: OutputFormat::Text,
     };
     assert!(matches!(cmd, Command::Ready { type_label, output, .. }
@@ -372,8 +425,13 @@ fn test_command_ready_construction() {
 fn test_command_tree_construction() {
     let cmd = Command::Tree {
         ids: vec!["feature-1".to_string()],
+        depth: None,
+        rel: TreeRel::All,
+        up: false,
+        raw: false,
+        output: OutputFormat::Text,
     };
-    assert!(matches!(cmd, Command::Tree { ids } if ids == vec!["feature-1"]));
+    assert!(matches!(cmd, Command::Tree { ids, .. } if ids == vec!["feature-1"]));
 }
 
 #[test]
@@ -386,14 +444,20 @@ fn test_command_edit_construction() {
         flag_description: None,
         flag_type: None,
         flag_assignee: None,
+        if_match: Some(3),
     };
     if let Command::Edit {
-        id, attr, value, ..
+        id,
+        attr,
+        value,
+        if_match,
+        ..
     } = cmd
     {
         assert_eq!(id, "test-1");
         assert_eq!(attr, Some("title".to_string()));
         assert_eq!(value, Some("New title".to_string()));
+        assert_eq!(if_match, Some(3));
     } else {
         panic!("Expected Edit command");
     }
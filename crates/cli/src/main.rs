@@ -5,37 +5,72 @@
 #![deny(clippy::unwrap_used)]
 #![deny(clippy::expect_used)]
 
-use clap::{CommandFactory, Parser};
-use wkrs::Cli;
+use clap::{CommandFactory, Parser, ValueEnum};
+use wkrs::error::{ErrorCode, ErrorReport};
+use wkrs::{Cli, ErrorFormat};
 
 fn main() {
+    // Project settings that shape parsing and help: aliases are expanded
+    // first so clap only ever sees built-in commands.
+    let raw_args: Vec<String> = std::env::args().collect();
+    let project = wkrs::config::load_for_args(&raw_args).unwrap_or_default();
+    wkrs::colors::set_mode(project.color);
+    wkrs::display::set_hyperlinks(wkrs::display::Hyperlinks::from_config(&project));
+    wkrs::i18n::set_locale(project.locale);
+    let args = match wkrs::alias::expand(raw_args.clone(), &project.alias) {
+        Ok(args) => args,
+        Err(e) => {
+            let error_format = ErrorFormat::resolve(error_format_from_args(&raw_args));
+            report_error(error_format, e.report());
+            std::process::exit(e.code().exit_code());
+        }
+    };
+
     // Try to parse; if help/version is requested, clap will handle it
     // with our plain styles. We then post-process for consolidation.
-    let result = Cli::try_parse();
+    let result = Cli::try_parse_from(&args);
 
     match result {
         Ok(cli) => {
+            let error_format = ErrorFormat::resolve(cli.error_format);
+            wkrs::quiet::set_quiet(cli.quiet);
+            wkrs::read_only::set_read_only(cli.read_only);
             if let Some(ref dir) = cli.directory {
                 let path = std::path::Path::new(dir);
                 if let Err(e) = std::env::set_current_dir(path) {
-                    eprintln!("error: cannot change to directory '{}': {}", dir, e);
-                    std::process::exit(1);
+                    let message = format!("cannot change to directory '{}': {}", dir, e);
+                    report_error(
+                        error_format,
+                        ErrorReport::from_message(ErrorCode::Io, &message),
+                    );
+                    std::process::exit(ErrorCode::Io.exit_code());
                 }
             }
-            if let Err(e) = wkrs::run(cli.command) {
-                eprintln!("error: {}", e);
-                std::process::exit(1);
+            let trace = wkrs::trace::start(cli.trace);
+            let result = {
+                let _span = trace.as_ref().map(|_| {
+                    tracing::info_span!("wok", command = subcommand_name(&args)).entered()
+                });
+                wkrs::run(cli.command)
+            };
+            if let Some(trace) = trace {
+                trace.finish();
+            }
+            if let Err(e) = result.map_err(wkrs::read_only::explain) {
+                match error_format {
+                    ErrorFormat::Text => eprintln!("{}", wkrs::t!("error.prefix", message = e)),
+                    ErrorFormat::Json => report_error(error_format, e.report()),
+                }
+                std::process::exit(e.code().exit_code());
             }
         }
         Err(e) => {
             // Check if this is a help or version request
             if e.kind() == clap::error::ErrorKind::DisplayHelp {
                 // User explicitly requested help (--help)
-                let args: Vec<String> = std::env::args().collect();
                 let args = strip_dash_c(&args);
                 print_formatted_help(&args, false);
             } else if e.kind() == clap::error::ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand {
-                let args: Vec<String> = std::env::args().collect();
                 let args = strip_dash_c(&args);
                 // Check if this is just bare "wok" with no arguments
                 let has_subcommand = args.iter().skip(1).any(|a| !a.starts_with('-'));
@@ -52,12 +87,66 @@ fn main() {
                 e.exit();
             } else {
                 // For other errors (invalid args, etc.), let clap handle it
+                // unless JSON errors were asked for
+                let error_format = ErrorFormat::resolve(error_format_from_args(&args));
+                if error_format == ErrorFormat::Json {
+                    let rendered = e.to_string();
+                    let message = rendered.lines().next().unwrap_or_default();
+                    let message = message.strip_prefix("error: ").unwrap_or(message);
+                    report_error(
+                        error_format,
+                        ErrorReport::from_message(ErrorCode::Usage, message),
+                    );
+                    std::process::exit(e.exit_code());
+                }
                 e.exit();
             }
         }
     }
 }
 
+/// The subcommand `args` run, for the root `--trace` span.
+fn subcommand_name(args: &[String]) -> String {
+    Cli::command()
+        .try_get_matches_from(args)
+        .ok()
+        .and_then(|m| m.subcommand_name().map(String::from))
+        .unwrap_or_default()
+}
+
+/// Print an error report on stderr in the requested format.
+fn report_error(format: ErrorFormat, report: ErrorReport) {
+    match format {
+        ErrorFormat::Text => {
+            eprintln!("{}", wkrs::t!("error.prefix", message = report.message));
+            for hint in &report.suggestions {
+                eprintln!("  {} {}", wkrs::t!("error.hint_marker"), hint);
+            }
+        }
+        ErrorFormat::Json => match serde_json::to_string(&report) {
+            Ok(json) => eprintln!("{}", json),
+            Err(_) => eprintln!("{}", wkrs::t!("error.prefix", message = report.message)),
+        },
+    }
+}
+
+/// Find `--error-format <value>` or `--error-format=<value>` in raw args.
+/// Used when clap rejects the command line and never yields a parsed `Cli`.
+fn error_format_from_args(args: &[String]) -> Option<ErrorFormat> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = if arg == "--error-format" {
+            args.next().map(String::as_str)
+        } else {
+            arg.strip_prefix("--error-format=")
+        };
+        if let Some(value) = value {
+            return ErrorFormat::from_str(value, true).ok();
+        }
+    }
+    None
+}
+
 /// Print help with negatable flag consolidation.
 fn print_formatted_help(args: &[String], to_stderr: bool) {
     use wkrs::help;
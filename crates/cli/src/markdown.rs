@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Minimal markdown rendering for terminal output.
+//!
+//! Handles the subset that shows up in issue notes: headings, bullet lists,
+//! fenced code blocks, code spans, and bold text. Anything else passes
+//! through unchanged, so plain text renders as itself.
+
+use crate::colors;
+
+/// Whether markdown should be rendered: colors are enabled and `--raw` was not given.
+pub fn enabled(raw: bool) -> bool {
+    !raw && colors::should_colorize()
+}
+
+/// Render a block of markdown text for the terminal.
+pub fn render(text: &str) -> String {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(colors::literal(line));
+            continue;
+        }
+
+        if let Some(heading) = heading_text(trimmed) {
+            lines.push(format!("{}{}", indent, colors::header(heading)));
+        } else if let Some(item) = bullet_text(trimmed) {
+            lines.push(format!("{}• {}", indent, render_inline(item)));
+        } else {
+            lines.push(render_inline(line));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Render inline markdown (code spans and bold) within a single line.
+///
+/// Unterminated markers are left as-is.
+pub fn render_inline(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['`', '*']) {
+        result.push_str(&rest[..start]);
+        let marker = if rest[start..].starts_with("**") {
+            "**"
+        } else {
+            &rest[start..=start]
+        };
+        let after = &rest[start + marker.len()..];
+
+        match (marker, after.find(marker)) {
+            ("`", Some(end)) if end > 0 => {
+                result.push_str(&colors::literal(&after[..end]));
+                rest = &after[end + 1..];
+            }
+            ("**", Some(end)) if end > 0 => {
+                result.push_str(&colors::bold(&after[..end]));
+                rest = &after[end + 2..];
+            }
+            _ => {
+                result.push_str(marker);
+                rest = after;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Text of an ATX heading (`# Title` through `###### Title`).
+fn heading_text(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..].strip_prefix(' ').map(str::trim)
+}
+
+/// Text of a bullet list item (`- item`, `* item`, `+ item`).
+fn bullet_text(line: &str) -> Option<&str> {
+    ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+}
+
+#[cfg(test)]
+#[path = "markdown_tests.rs"]
+mod tests;
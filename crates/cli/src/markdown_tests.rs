@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::colors::codes::{BOLD_START, HEADER_START, LITERAL_START, RESET};
+use yare::parameterized;
+
+fn literal(s: &str) -> String {
+    format!("{LITERAL_START}{s}{RESET}")
+}
+
+#[test]
+fn plain_text_unchanged() {
+    let text = "Just a note.\nWith two lines.";
+    assert_eq!(render(text), text);
+}
+
+#[parameterized(
+    h1 = { "# Title", "Title" },
+    h3 = { "### Title", "Title" },
+    trailing_space = { "## Title  ", "Title" },
+)]
+fn headings_use_header_color(input: &str, text: &str) {
+    assert_eq!(render(input), format!("{HEADER_START}{text}{RESET}"));
+}
+
+#[parameterized(
+    no_space = { "#hashtag" },
+    too_deep = { "####### seven" },
+)]
+fn non_headings_unchanged(input: &str) {
+    assert_eq!(render(input), input);
+}
+
+#[parameterized(
+    dash = { "- item" },
+    star = { "* item" },
+    plus = { "+ item" },
+)]
+fn bullets_render_as_dots(input: &str) {
+    assert_eq!(render(input), "• item");
+}
+
+#[test]
+fn nested_bullets_keep_indent() {
+    assert_eq!(render("- a\n  - b"), "• a\n  • b");
+}
+
+#[test]
+fn fenced_code_drops_fences() {
+    let rendered = render("Run:\n```sh\ncargo test\n```\nDone");
+    assert_eq!(rendered, format!("Run:\n{}\nDone", literal("cargo test")));
+}
+
+#[test]
+fn code_block_contents_not_rendered_as_markdown() {
+    assert_eq!(
+        render("```\n# not a heading\n```"),
+        literal("# not a heading")
+    );
+}
+
+#[test]
+fn code_spans_use_literal_color() {
+    assert_eq!(
+        render_inline("run `wok ready` now"),
+        format!("run {} now", literal("wok ready"))
+    );
+}
+
+#[test]
+fn bold_text() {
+    assert_eq!(
+        render_inline("a **big** deal"),
+        format!("a {BOLD_START}big{RESET} deal")
+    );
+}
+
+#[test]
+fn bullet_items_render_inline() {
+    assert_eq!(render("- use `sh`"), format!("• use {}", literal("sh")));
+}
+
+#[parameterized(
+    unterminated_code = { "a `b" },
+    unterminated_bold = { "a **b" },
+    single_star = { "2 * 3 = 6" },
+    empty_code = { "``" },
+)]
+fn unterminated_markers_unchanged(input: &str) {
+    assert_eq!(render_inline(input), input);
+}
+
+#[test]
+fn enabled_respects_raw() {
+    assert!(!enabled(true));
+}
@@ -8,7 +8,8 @@ use crate::error::{Error, Result};
 /// User-facing relationship types for CLI commands.
 ///
 /// This provides a simplified view of relationships compared to [`Relation`].
-/// `tracks` is translated to tracks/tracked-by relationships internally.
+/// `tracks` is translated to tracks/tracked-by relationships internally, and
+/// `relates-to` is stored in both directions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UserRelation {
     /// A blocks B (B cannot proceed until A is done).
@@ -21,6 +22,10 @@ pub enum UserRelation {
     /// A is tracked by B (B is the parent/epic containing A).
     /// Equivalent to "B tracks A"
     TrackedBy,
+    /// A relates to B, and B to A. Never blocks.
+    RelatesTo,
+    /// A duplicates B. Never blocks.
+    DuplicateOf,
 }
 
 impl FromStr for UserRelation {
@@ -32,6 +37,8 @@ impl FromStr for UserRelation {
             "blocked-by" | "blocked_by" | "blockedby" => Ok(UserRelation::BlockedBy),
             "tracks" | "contains" => Ok(UserRelation::Tracks),
             "tracked-by" | "tracked_by" | "trackedby" => Ok(UserRelation::TrackedBy),
+            "relates-to" | "relates_to" | "related" => Ok(UserRelation::RelatesTo),
+            "duplicate-of" | "duplicate_of" | "duplicates" => Ok(UserRelation::DuplicateOf),
             _ => Err(Error::InvalidRelation(s.to_string())),
         }
     }
@@ -22,6 +22,10 @@ use yare::parameterized;
     tracked_by_underscore = { "tracked_by", UserRelation::TrackedBy },
     trackedby = { "trackedby", UserRelation::TrackedBy },
     trackedby_upper = { "TRACKED-BY", UserRelation::TrackedBy },
+    relates_to = { "relates-to", UserRelation::RelatesTo },
+    related = { "related", UserRelation::RelatesTo },
+    duplicate_of = { "duplicate-of", UserRelation::DuplicateOf },
+    duplicates = { "duplicates", UserRelation::DuplicateOf },
 )]
 fn test_user_relation_from_str_valid(input: &str, expected: UserRelation) {
     assert_eq!(input.parse::<UserRelation>().unwrap(), expected);
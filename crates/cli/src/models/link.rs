@@ -2,10 +2,79 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::LazyLock;
 
 use wk_ipc::LinkType;
 
+/// A link provider from the `[links]` table, keyed by its scheme.
+///
+/// ```toml
+/// [links.linear]
+/// url = "https://linear.app/acme/issue/{id}"
+///
+/// [links.ado]
+/// url = "https://dev.azure.com/acme/web/_workitems/edit/{id}"
+/// type = "azure"
+/// ```
+///
+/// `linear://ENG-12` and `https://linear.app/acme/issue/ENG-12` are then both
+/// `linear` links to `ENG-12`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkProvider {
+    /// Page for an external ID, which replaces `{id}`.
+    pub url: String,
+    /// Link type to record; defaults to the scheme.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub link_type: Option<String>,
+}
+
+impl LinkProvider {
+    /// The type of this provider's links when its scheme is `scheme`.
+    pub fn link_type(&self, scheme: &str) -> LinkType {
+        LinkType::from_name(self.link_type.as_deref().unwrap_or(scheme))
+    }
+
+    /// The page for `external_id`.
+    pub fn page(&self, external_id: &str) -> String {
+        self.url.replace("{id}", external_id)
+    }
+
+    /// The external ID in `url`, if it is one of this provider's pages.
+    fn external_id(&self, url: &str) -> Option<String> {
+        let (before, after) = self.url.split_once("{id}")?;
+        let rest = url.strip_prefix(before).filter(|_| !before.is_empty())?;
+        let id = match after.chars().next() {
+            Some(_) => &rest[..rest.find(after)?],
+            None => rest.split(['/', '?', '#']).next().unwrap_or_default(),
+        };
+        (!id.is_empty()).then(|| id.to_string())
+    }
+}
+
+/// Parse URL as [`parse_link_url`] does, trying the configured `providers`
+/// first: `<scheme>://<id>` shorthand, then each provider's page URL.
+pub fn parse_link_url_with(
+    url: &str,
+    providers: &BTreeMap<String, LinkProvider>,
+) -> (Option<LinkType>, Option<String>) {
+    for (scheme, provider) in providers {
+        let shorthand = url
+            .strip_prefix(scheme.as_str())
+            .and_then(|rest| rest.strip_prefix("://"));
+        if let Some(id) = shorthand {
+            return (Some(provider.link_type(scheme)), Some(id.to_string()));
+        }
+    }
+    for (scheme, provider) in providers {
+        if let Some(id) = provider.external_id(url) {
+            return (Some(provider.link_type(scheme)), Some(id));
+        }
+    }
+    parse_link_url(url)
+}
+
 // Pre-compiled regexes for URL parsing.
 // These are compile-time constant patterns that are verified at test time.
 // Using match with unreachable! since these patterns are hard-coded and known-valid.
@@ -161,6 +161,63 @@ fn test_parse_empty_string() {
     assert_eq!(external_id, None);
 }
 
+// Configured provider tests
+fn providers() -> BTreeMap<String, LinkProvider> {
+    let linear = LinkProvider {
+        url: "https://linear.app/acme/issue/{id}".to_string(),
+        link_type: None,
+    };
+    let ado = LinkProvider {
+        url: "https://dev.azure.com/acme/_workitems/edit/{id}?view=full".to_string(),
+        link_type: Some("azure".to_string()),
+    };
+    BTreeMap::from([("linear".to_string(), linear), ("ado".to_string(), ado)])
+}
+
+#[parameterized(
+    shorthand = { "linear://ENG-12", "linear", "ENG-12" },
+    page = { "https://linear.app/acme/issue/ENG-12", "linear", "ENG-12" },
+    page_with_slug = { "https://linear.app/acme/issue/ENG-12/fix-login", "linear", "ENG-12" },
+    typed_shorthand = { "ado://4711", "azure", "4711" },
+    typed_page = { "https://dev.azure.com/acme/_workitems/edit/4711?view=full", "azure", "4711" },
+)]
+fn test_parse_configured_provider(url: &str, link_type: &str, id: &str) {
+    let (detected, external_id) = parse_link_url_with(url, &providers());
+    assert_eq!(detected, Some(LinkType::Other(link_type.to_string())));
+    assert_eq!(external_id.as_deref(), Some(id));
+}
+
+#[parameterized(
+    github = { "https://github.com/org/repo/issues/123", LinkType::Github },
+    jira_shorthand = { "jira://PE-5555", LinkType::Jira },
+)]
+fn test_parse_builtin_alongside_providers(url: &str, expected: LinkType) {
+    assert_eq!(parse_link_url_with(url, &providers()), parse_link_url(url));
+    assert_eq!(parse_link_url_with(url, &providers()).0, Some(expected));
+}
+
+#[test]
+fn test_provider_named_after_builtin_overrides_it() {
+    let jira = LinkProvider {
+        url: "https://jira.acme.internal/browse/{id}".to_string(),
+        link_type: None,
+    };
+    let providers = BTreeMap::from([("jira".to_string(), jira)]);
+    let (link_type, external_id) =
+        parse_link_url_with("https://jira.acme.internal/browse/PE-1", &providers);
+    assert_eq!(link_type, Some(LinkType::Jira));
+    assert_eq!(external_id.as_deref(), Some("PE-1"));
+}
+
+#[test]
+fn test_provider_page() {
+    let provider = &providers()["linear"];
+    assert_eq!(
+        provider.page("ENG-12"),
+        "https://linear.app/acme/issue/ENG-12"
+    );
+}
+
 // Link serialization tests
 #[test]
 fn test_link_type_serde() {
@@ -4,14 +4,15 @@
 //! Core data models for the issue tracker.
 //!
 //! Model types are defined in the shared `wk_core` crate and re-exported here.
-//! CLI-specific additions (UserRelation, parse_link_url) are defined locally.
+//! CLI-specific additions (UserRelation, link URL parsing and providers) are
+//! defined locally.
 
 mod dependency;
 mod link;
 
 pub use dependency::UserRelation;
-pub use link::parse_link_url;
+pub use link::{parse_link_url, parse_link_url_with, LinkProvider};
 pub use wk_core::{
-    Action, Dependency, Event, Issue, IssueType, Link, LinkRel, LinkType, Note, PrefixInfo,
-    Relation, Status,
+    Action, Dependency, Event, HookJob, HookRun, Issue, IssueType, Link, LinkMeta, LinkRel,
+    LinkType, Note, NoteKind, PrefixInfo, Relation, Status,
 };
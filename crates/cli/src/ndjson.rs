@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Newline-delimited JSON output (`-o ndjson`).
+//!
+//! Each record is written as soon as it is produced, so piping a large
+//! result set into `jq` never holds the whole array in memory.
+
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::error::Result;
+
+/// Write each record as one line of JSON to `out`, returning how many were
+/// written.
+///
+/// A reader that closes the pipe early (`| head`) ends the output quietly.
+pub fn write_lines<T: Serialize>(
+    out: &mut impl Write,
+    records: impl Iterator<Item = Result<T>>,
+) -> Result<usize> {
+    let mut count = 0;
+    for record in records {
+        let line = serde_json::to_string(&record?)?;
+        match writeln!(out, "{}", line).and_then(|()| out.flush()) {
+            Ok(()) => count += 1,
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => break,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(count)
+}
+
+/// [`write_lines`] to stdout, silent under `--quiet`.
+pub fn print_lines<T: Serialize>(records: impl Iterator<Item = Result<T>>) -> Result<usize> {
+    if crate::quiet::is_quiet() {
+        return write_lines(&mut io::sink(), records);
+    }
+    write_lines(&mut io::stdout().lock(), records)
+}
+
+#[cfg(test)]
+#[path = "ndjson_tests.rs"]
+mod tests;
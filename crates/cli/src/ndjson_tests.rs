@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::error::Error;
+
+/// Accepts `capacity` bytes, then reports a closed pipe.
+struct ClosedAfter {
+    written: Vec<u8>,
+    capacity: usize,
+}
+
+impl Write for ClosedAfter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written.len() + buf.len() > self.capacity {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writes_one_object_per_line() {
+    let mut out = Vec::new();
+    let records = [1, 2]
+        .into_iter()
+        .map(|n| Ok(serde_json::json!({ "n": n })));
+    assert_eq!(write_lines(&mut out, records).unwrap(), 2);
+    assert_eq!(String::from_utf8(out).unwrap(), "{\"n\":1}\n{\"n\":2}\n");
+}
+
+#[test]
+fn closed_pipe_stops_quietly() {
+    let mut out = ClosedAfter {
+        written: Vec::new(),
+        capacity: 10,
+    };
+    let records = (0..100).map(|n| Ok(serde_json::json!({ "n": n })));
+    assert_eq!(write_lines(&mut out, records).unwrap(), 1);
+    assert_eq!(out.written, b"{\"n\":0}\n");
+}
+
+#[test]
+fn record_errors_stop_output() {
+    let mut out = Vec::new();
+    let records = vec![
+        Ok(serde_json::json!(1)),
+        Err(Error::IssueNotFound("prj-1".to_string())),
+        Ok(serde_json::json!(2)),
+    ];
+    assert!(write_lines(&mut out, records.into_iter()).is_err());
+    assert_eq!(out, b"1\n");
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Paging long output through `$PAGER`.
+
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// Pager used when `$PAGER` is unset: quit if it fits on one screen, keep colors.
+const DEFAULT_PAGER: &str = "less -FRX";
+
+/// Write `text` to stdout, through the pager when `enabled` and stdout is a
+/// terminal. Falls back to printing directly if the pager can't be started.
+pub fn page(text: &str, enabled: bool) {
+    if !enabled || crate::quiet::is_quiet() || !std::io::stdout().is_terminal() {
+        print!("{}", text);
+        return;
+    }
+    let pager = crate::env::pager().unwrap_or_else(|| DEFAULT_PAGER.to_string());
+    if pager.trim().is_empty() || pager.trim() == "cat" || pipe_to(&pager, text).is_err() {
+        print!("{}", text);
+    }
+}
+
+/// Run the pager through the shell so `$PAGER` may carry arguments.
+fn pipe_to(pager: &str, text: &str) -> std::io::Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(pager)
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user quitting the pager early closes the pipe; that's not an error.
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child.wait()?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `--quiet`: suppress normal output.
+//!
+//! The crate root shadows `print!` and `println!` with versions that check
+//! this flag, so commands print as usual and stay silent under `--quiet`.
+//! Errors and warnings go to stderr and are unaffected.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Turn quiet mode on or off for the rest of the process.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether normal output is suppressed.
+#[inline]
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `--read-only`: open the database without write access.
+//!
+//! Commands open the database as usual through `open_db`, which checks this
+//! flag. Reads work alongside any number of writers; a command that tries to
+//! change something fails with [`Error::ReadOnly`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use rusqlite::ErrorCode;
+
+use crate::error::Error;
+
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Turn read-only mode on or off for the rest of the process.
+pub fn set_read_only(read_only: bool) {
+    READ_ONLY.store(read_only, Ordering::Relaxed);
+}
+
+/// Whether the database is opened read-only.
+#[inline]
+pub fn is_read_only() -> bool {
+    READ_ONLY.load(Ordering::Relaxed)
+}
+
+/// Replace SQLite's write refusal with [`Error::ReadOnly`].
+pub fn explain(err: Error) -> Error {
+    match err {
+        Error::Database(rusqlite::Error::SqliteFailure(e, _)) if e.code == ErrorCode::ReadOnly => {
+            Error::ReadOnly
+        }
+        err => err,
+    }
+}
+
+#[cfg(test)]
+#[path = "read_only_tests.rs"]
+mod tests;
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn sqlite_error(code: i32) -> Error {
+    Error::Database(rusqlite::Error::SqliteFailure(
+        rusqlite::ffi::Error::new(code),
+        None,
+    ))
+}
+
+#[test]
+fn explain_replaces_sqlite_readonly() {
+    let err = explain(sqlite_error(rusqlite::ffi::SQLITE_READONLY));
+    assert!(matches!(err, Error::ReadOnly));
+    assert!(err.to_string().contains("hint: drop --read-only"));
+}
+
+#[test]
+fn explain_keeps_other_errors() {
+    let err = explain(sqlite_error(rusqlite::ffi::SQLITE_BUSY));
+    assert!(matches!(err, Error::Database(_)));
+    let err = explain(Error::IssueNotFound("test-1".to_string()));
+    assert!(matches!(err, Error::IssueNotFound(_)));
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rule configuration loading from .wok/rules.toml.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+
+/// A single rule definition from configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RuleConfig {
+    /// Unique identifier for this rule.
+    pub name: String,
+    /// Event patterns this rule responds to (e.g., "issue.done", "issue.*").
+    pub events: Vec<String>,
+    /// Optional filter string (e.g., "-t bug -l release:*").
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// Labels to add when the rule fires.
+    #[serde(default)]
+    pub add_labels: Vec<String>,
+    /// Labels to remove when the rule fires.
+    #[serde(default)]
+    pub remove_labels: Vec<String>,
+}
+
+/// Root configuration structure for the rules file.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RulesConfig {
+    /// List of configured rules.
+    #[serde(default)]
+    pub rules: Vec<RuleConfig>,
+}
+
+/// Load rules config from .wok/rules.toml.
+///
+/// Returns None if the file does not exist or defines no rules.
+pub fn load_rules_config(work_dir: &Path) -> Result<Option<RulesConfig>> {
+    let path = work_dir.join("rules.toml");
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let config: RulesConfig = toml::from_str(&content)
+        .map_err(|e| Error::Config(format!("failed to parse rules.toml: {}", e)))?;
+
+    if config.rules.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(config))
+    }
+}
+
+#[cfg(test)]
+#[path = "config_tests.rs"]
+mod tests;
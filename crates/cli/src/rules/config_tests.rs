@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_load_rules_config_no_file() {
+    let tmp = TempDir::new().unwrap();
+    assert!(load_rules_config(tmp.path()).unwrap().is_none());
+}
+
+#[test]
+fn test_load_rules_config_toml() {
+    let tmp = TempDir::new().unwrap();
+    fs::write(
+        tmp.path().join("rules.toml"),
+        r#"
+[[rules]]
+name = "changelog"
+events = ["issue.done"]
+filter = "-l release:*"
+add_labels = ["changelog"]
+"#,
+    )
+    .unwrap();
+
+    let config = load_rules_config(tmp.path()).unwrap().unwrap();
+    assert_eq!(config.rules.len(), 1);
+    let rule = &config.rules[0];
+    assert_eq!(rule.name, "changelog");
+    assert_eq!(rule.events, vec!["issue.done"]);
+    assert_eq!(rule.filter.as_deref(), Some("-l release:*"));
+    assert_eq!(rule.add_labels, vec!["changelog"]);
+    assert!(rule.remove_labels.is_empty());
+}
+
+#[test]
+fn test_load_rules_config_empty_is_none() {
+    let tmp = TempDir::new().unwrap();
+    fs::write(tmp.path().join("rules.toml"), "").unwrap();
+    assert!(load_rules_config(tmp.path()).unwrap().is_none());
+}
+
+#[test]
+fn test_load_rules_config_invalid() {
+    let tmp = TempDir::new().unwrap();
+    fs::write(tmp.path().join("rules.toml"), "[[rules]]\nname = 1").unwrap();
+    let err = load_rules_config(tmp.path()).unwrap_err();
+    assert!(err.to_string().contains("rules.toml"));
+}
@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rule evaluation with cascade and loop protection.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use crate::db::Database;
+use crate::error::Result;
+use crate::hooks::{HookEvent, HookFilter};
+use crate::models::{Action, Event};
+use crate::validate::validate_label_count;
+
+use super::config::{load_rules_config, RuleConfig};
+
+/// Maximum number of rule actions a single mutation can cause.
+pub const MAX_RULE_STEPS: usize = 32;
+
+/// A change a rule makes to an issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleAction {
+    AddLabel(String),
+    RemoveLabel(String),
+}
+
+/// One rule action, with the event that triggered it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleStep {
+    /// Name of the rule that fired.
+    pub rule: String,
+    /// Issue the action applies to.
+    pub issue_id: String,
+    /// Event that made the rule fire.
+    pub trigger: HookEvent,
+    /// The change made (or that would be made in a dry run).
+    pub action: RuleAction,
+}
+
+impl RuleStep {
+    /// The event recorded for this step, attributed to the rule.
+    pub fn to_event(&self) -> Event {
+        let event = match &self.action {
+            RuleAction::AddLabel(label) => Event::new(self.issue_id.clone(), Action::Labeled)
+                .with_values(None, Some(label.clone())),
+            RuleAction::RemoveLabel(label) => Event::new(self.issue_id.clone(), Action::Unlabeled)
+                .with_values(None, Some(label.clone())),
+        };
+        event.with_reason(Some(format!("rule: {}", self.rule)))
+    }
+}
+
+/// The outcome of evaluating rules for one mutation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleTrace {
+    /// Actions in the order they were taken.
+    pub steps: Vec<RuleStep>,
+    /// True if evaluation stopped at [`MAX_RULE_STEPS`].
+    pub truncated: bool,
+}
+
+/// Load rules and apply any that match the event.
+///
+/// Errors in individual rules are logged but don't fail the mutation.
+pub fn run_rules_for_event(db: &Database, work_dir: &Path, event: &Event) -> Result<()> {
+    let config = match load_rules_config(work_dir)? {
+        Some(c) => c,
+        None => return Ok(()),
+    };
+
+    let trace = evaluate_rules(
+        db,
+        &config.rules,
+        &event.issue_id,
+        event.action.into(),
+        false,
+    )?;
+    for step in &trace.steps {
+        if let Err(e) = crate::hooks::run_hooks_for_event(db, work_dir, &step.to_event()) {
+            eprintln!("warning: hook error: {}", e);
+        }
+    }
+    if trace.truncated {
+        eprintln!(
+            "warning: rules stopped after {} actions (possible rule loop)",
+            MAX_RULE_STEPS
+        );
+    }
+
+    Ok(())
+}
+
+/// Evaluate rules starting from an event on an issue.
+///
+/// When `dry_run` is false, label changes are written and logged as events as
+/// they happen, so later rules see the effects of earlier ones. When true,
+/// nothing is written and the trace describes what would happen.
+pub fn evaluate_rules(
+    db: &Database,
+    rules: &[RuleConfig],
+    issue_id: &str,
+    trigger: HookEvent,
+    dry_run: bool,
+) -> Result<RuleTrace> {
+    let mut trace = RuleTrace::default();
+    let mut queue = VecDeque::from([(issue_id.to_string(), trigger)]);
+    let mut fired: HashSet<(String, String)> = HashSet::new();
+    let mut labels_by_issue: HashMap<String, Vec<String>> = HashMap::new();
+
+    while let Some((issue_id, trigger)) = queue.pop_front() {
+        let issue = db.get_issue(&issue_id)?;
+
+        for rule in rules {
+            let key = (rule.name.clone(), issue_id.clone());
+            if fired.contains(&key) || !rule.events.iter().any(|p| trigger.matches_pattern(p)) {
+                continue;
+            }
+
+            let labels = match labels_by_issue.entry(issue_id.clone()) {
+                Entry::Occupied(e) => e.into_mut(),
+                Entry::Vacant(e) => e.insert(db.get_labels(&issue_id)?),
+            };
+
+            if let Some(filter_str) = &rule.filter {
+                match HookFilter::parse(filter_str) {
+                    Ok(filter) if filter.matches(&issue, labels) => {}
+                    Ok(_) => continue,
+                    Err(e) => {
+                        eprintln!("warning: invalid filter for rule '{}': {}", rule.name, e);
+                        continue;
+                    }
+                }
+            }
+            fired.insert(key);
+
+            let adds = rule
+                .add_labels
+                .iter()
+                .map(|l| RuleAction::AddLabel(l.clone()));
+            let removes = rule
+                .remove_labels
+                .iter()
+                .map(|l| RuleAction::RemoveLabel(l.clone()));
+
+            for action in adds.chain(removes) {
+                let (changed_event, changes) = match &action {
+                    RuleAction::AddLabel(label) => (HookEvent::Labeled, !labels.contains(label)),
+                    RuleAction::RemoveLabel(label) => {
+                        (HookEvent::Unlabeled, labels.contains(label))
+                    }
+                };
+                if !changes {
+                    continue;
+                }
+                if trace.steps.len() >= MAX_RULE_STEPS {
+                    trace.truncated = true;
+                    return Ok(trace);
+                }
+
+                let step = RuleStep {
+                    rule: rule.name.clone(),
+                    issue_id: issue_id.clone(),
+                    trigger,
+                    action,
+                };
+                match &step.action {
+                    RuleAction::AddLabel(label) => {
+                        if let Err(e) = validate_label_count(labels.len()) {
+                            eprintln!("warning: rule '{}' skipped: {}", rule.name, e);
+                            continue;
+                        }
+                        if !dry_run {
+                            db.record(&step.to_event(), || db.add_label(&issue_id, label))?;
+                        }
+                        labels.push(label.clone());
+                    }
+                    RuleAction::RemoveLabel(label) => {
+                        if !dry_run {
+                            db.record(&step.to_event(), || db.remove_label(&issue_id, label))?;
+                        }
+                        labels.retain(|l| l != label);
+                    }
+                }
+                trace.steps.push(step);
+                queue.push_back((issue_id.clone(), changed_event));
+            }
+        }
+    }
+
+    Ok(trace)
+}
+
+#[cfg(test)]
+#[path = "engine_tests.rs"]
+mod tests;
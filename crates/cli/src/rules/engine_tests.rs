@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::commands::testing::TestContext;
+use crate::models::IssueType;
+
+fn rule(
+    name: &str,
+    events: &[&str],
+    filter: Option<&str>,
+    add: &[&str],
+    remove: &[&str],
+) -> RuleConfig {
+    RuleConfig {
+        name: name.to_string(),
+        events: events.iter().map(|s| s.to_string()).collect(),
+        filter: filter.map(String::from),
+        add_labels: add.iter().map(|s| s.to_string()).collect(),
+        remove_labels: remove.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_rule_adds_label_when_filter_matches() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Ship it")
+        .add_label("test-1", "release:1.0");
+    let rules = [rule(
+        "changelog",
+        &["issue.done"],
+        Some("-l release:*"),
+        &["changelog"],
+        &[],
+    )];
+
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Done, false).unwrap();
+
+    assert_eq!(trace.steps.len(), 1);
+    assert_eq!(
+        trace.steps[0].action,
+        RuleAction::AddLabel("changelog".to_string())
+    );
+    assert!(!trace.truncated);
+    let labels = ctx.db.get_labels("test-1").unwrap();
+    assert!(labels.contains(&"changelog".to_string()));
+
+    let events = ctx.db.get_events("test-1").unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.action, Action::Labeled);
+    assert_eq!(last.reason.as_deref(), Some("rule: changelog"));
+}
+
+#[test]
+fn test_rule_skipped_when_event_or_filter_mismatch() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Ship it");
+    let rules = [rule(
+        "changelog",
+        &["issue.done"],
+        Some("-l release:*"),
+        &["changelog"],
+        &[],
+    )];
+
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Done, false).unwrap();
+    assert!(trace.steps.is_empty());
+
+    ctx.add_label("test-1", "release:1.0");
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Started, false).unwrap();
+    assert!(trace.steps.is_empty());
+}
+
+#[test]
+fn test_dry_run_does_not_write() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Ship it")
+        .add_label("test-1", "needs-review");
+    let rules = [rule(
+        "reviewed",
+        &["issue.*"],
+        None,
+        &["reviewed"],
+        &["needs-review"],
+    )];
+
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Done, true).unwrap();
+
+    assert_eq!(
+        trace
+            .steps
+            .iter()
+            .map(|s| s.action.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            RuleAction::AddLabel("reviewed".to_string()),
+            RuleAction::RemoveLabel("needs-review".to_string()),
+        ]
+    );
+    assert_eq!(ctx.db.get_labels("test-1").unwrap(), vec!["needs-review"]);
+}
+
+#[test]
+fn test_rules_cascade_through_label_events() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Bug, "Crash");
+    let rules = [
+        rule(
+            "triage",
+            &["issue.created"],
+            Some("-t bug"),
+            &["triage"],
+            &[],
+        ),
+        rule(
+            "notify",
+            &["issue.labeled"],
+            Some("-l triage"),
+            &["notify"],
+            &[],
+        ),
+    ];
+
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Created, true).unwrap();
+
+    assert_eq!(trace.steps.len(), 2);
+    assert_eq!(trace.steps[1].rule, "notify");
+    assert_eq!(trace.steps[1].trigger, HookEvent::Labeled);
+}
+
+#[test]
+fn test_conflicting_rules_fire_once_each() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Flip flop");
+    let rules = [
+        rule("add", &["issue.*"], None, &["x"], &[]),
+        rule("remove", &["issue.*"], None, &[], &["x"]),
+    ];
+
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Edited, false).unwrap();
+
+    assert_eq!(trace.steps.len(), 2);
+    assert!(!trace.truncated);
+    assert!(ctx.db.get_labels("test-1").unwrap().is_empty());
+}
+
+#[test]
+fn test_steps_capped_at_max() {
+    let mut ctx = TestContext::new();
+    ctx.create_issue("test-1", IssueType::Task, "Churn");
+    // Each rule adds then removes its own label: two steps per rule
+    let rules: Vec<RuleConfig> = (0..MAX_RULE_STEPS)
+        .map(|i| {
+            let label = format!("l{}", i);
+            rule(
+                &format!("churn-{}", i),
+                &["issue.edited"],
+                None,
+                &[&label],
+                &[&label],
+            )
+        })
+        .collect();
+
+    let trace = evaluate_rules(&ctx.db, &rules, "test-1", HookEvent::Edited, true).unwrap();
+
+    assert!(trace.truncated);
+    assert_eq!(trace.steps.len(), MAX_RULE_STEPS);
+}
+
+#[test]
+fn test_unlabel_step_records_label_like_unlabel_command() {
+    let step = RuleStep {
+        rule: "cleanup".to_string(),
+        issue_id: "test-1".to_string(),
+        trigger: HookEvent::Done,
+        action: RuleAction::RemoveLabel("wip".to_string()),
+    };
+
+    let event = step.to_event();
+
+    assert_eq!(event.action, Action::Unlabeled);
+    assert_eq!(event.old_value, None);
+    assert_eq!(event.new_value.as_deref(), Some("wip"));
+    assert_eq!(event.reason.as_deref(), Some("rule: cleanup"));
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Declarative automation rules evaluated after each mutation.
+//!
+//! Rules are the in-process counterpart to hooks: instead of running a
+//! script, a matching rule adds or removes labels on the issue. Changes made
+//! by a rule are logged as events (with `rule: <name>` as the reason) and can
+//! trigger further rules.
+//!
+//! # Configuration Format
+//!
+//! Rules are configured in `.wok/rules.toml`:
+//!
+//! ```toml
+//! [[rules]]
+//! name = "changelog-on-release"
+//! events = ["issue.done"]
+//! filter = "-l release:*"
+//! add_labels = ["changelog"]
+//! remove_labels = ["needs-review"]
+//! ```
+//!
+//! `events` and `filter` use the same syntax as hooks.
+//!
+//! # Loop Protection
+//!
+//! Each rule fires at most once per issue per mutation, and a single
+//! mutation can cause at most [`MAX_RULE_STEPS`] rule actions.
+
+pub mod config;
+pub mod engine;
+
+pub use config::{load_rules_config, RuleConfig, RulesConfig};
+pub use engine::{
+    evaluate_rules, run_rules_for_event, RuleAction, RuleStep, RuleTrace, MAX_RULE_STEPS,
+};
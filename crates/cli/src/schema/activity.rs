@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Schema types for `wok activity` JSON output.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::Event;
+
+/// JSON output for `wok activity`: matching events, oldest first.
+#[derive(JsonSchema, Serialize)]
+#[serde(transparent)]
+pub struct ActivityOutputJson<'a>(pub Vec<ActivityEntryJson<'a>>);
+
+/// An event with its issue's title and assignee.
+#[derive(JsonSchema, Serialize)]
+pub struct ActivityEntryJson<'a> {
+    #[serde(flatten)]
+    pub event: &'a Event,
+    /// Title of the issue the event belongs to.
+    pub title: &'a str,
+    /// The issue's current assignee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<&'a str>,
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Schema types for `wok digest` JSON output.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::{IssueType, Status};
+
+/// JSON output for `wok digest`: one list of issues per section.
+#[derive(JsonSchema, Serialize)]
+pub struct DigestOutputJson {
+    /// Start of the period.
+    pub since: DateTime<Utc>,
+    /// End of the period: when the digest was made.
+    pub until: DateTime<Utc>,
+    /// Issues marked done in the period, in the order they were finished.
+    pub completed: Vec<DigestIssueJson>,
+    /// Issues created in the period, oldest first.
+    pub created: Vec<DigestIssueJson>,
+    /// In-progress issues with no events in the period, idle longest first.
+    pub stalled: Vec<DigestIssueJson>,
+    /// Open issues that gained a blocker in the period that is still open.
+    pub blocked: Vec<DigestIssueJson>,
+    /// Open issues due within 7 days of `until`, or overdue, soonest first.
+    pub due: Vec<DigestIssueJson>,
+}
+
+/// An issue in a digest section.
+#[derive(JsonSchema, Serialize)]
+pub struct DigestIssueJson {
+    /// Unique issue identifier.
+    pub id: String,
+    /// Classification of the issue.
+    pub issue_type: IssueType,
+    /// Current workflow state.
+    pub status: Status,
+    /// Short description of the work.
+    pub title: String,
+    /// Person or queue this issue is assigned to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// When the issue was done. Only in `completed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+    /// When the issue last had an event. Only in `stalled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_activity: Option<DateTime<Utc>>,
+    /// Blockers added in the period: issue IDs, then external URLs.
+    /// Only in `blocked`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+    /// The issue's `due:` date. Only in `due`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<NaiveDate>,
+}
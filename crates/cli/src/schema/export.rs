@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Schema types for `wok export` records.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::{Event, Link, Note};
+use crate::models::{Dependency, Issue};
+
+/// Version of the export record layout, in its `schema_version` field.
+///
+/// Version 2 added `schema_version`, and import restores the timestamps of
+/// notes, dependencies, and links and the event log. Version 1 had no
+/// `schema_version` field.
+pub const EXPORT_SCHEMA_VERSION: u32 = 2;
+
+/// One line of an export file (or of `wok export -o ndjson`): an issue's
+/// fields with everything attached to it.
+#[derive(JsonSchema, Serialize)]
+pub struct ExportRecord {
+    /// Version of this record's layout; see [`EXPORT_SCHEMA_VERSION`].
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub issue: Issue,
+    /// Labels attached to the issue.
+    pub labels: Vec<String>,
+    /// Notes, oldest first.
+    pub notes: Vec<Note>,
+    /// Dependencies from this issue to others.
+    pub deps: Vec<Dependency>,
+    /// External links.
+    pub links: Vec<Link>,
+    /// The issue's event log, oldest first.
+    pub events: Vec<Event>,
+}
@@ -10,7 +10,25 @@ use super::IssueJson;
 
 /// JSON output structure for the list command.
 ///
-/// The list command returns an array of issue summaries directly.
+/// The list command returns an array of issue summaries directly, in
+/// display order: `--sort <key>` (and `--reverse`) when given, else the
+/// command's default order.
 #[derive(JsonSchema, Serialize)]
 #[serde(transparent)]
 pub struct ListOutputJson(pub Vec<IssueJson>);
+
+/// JSON output for `wok list --group-by`: groups in display order.
+#[derive(JsonSchema, Serialize)]
+#[serde(transparent)]
+pub struct ListGroupedOutputJson(pub Vec<ListGroupJson>);
+
+/// One group of `wok list --group-by` output.
+#[derive(JsonSchema, Serialize)]
+pub struct ListGroupJson {
+    /// Group name: a status, type, assignee, label prefix, or ID prefix.
+    pub group: String,
+    /// Number of issues in the group.
+    pub count: usize,
+    /// The group's issues, in list order.
+    pub issues: Vec<IssueJson>,
+}
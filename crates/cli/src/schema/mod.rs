@@ -10,13 +10,21 @@
 use schemars::JsonSchema;
 use serde::Serialize;
 
+use crate::aging::{format_age, Stale};
+
 // Re-export core types that carry JsonSchema derives (via `schemars` feature).
-pub use wk_core::{Event, IssueType, Link, Note, Status};
+pub use wk_core::{Event, IssueType, Link, LinkMeta, Note, Status};
 
+pub mod activity;
+pub mod digest;
+pub mod export;
 pub mod list;
 pub mod ready;
+pub mod rpc;
 pub mod search;
 pub mod show;
+pub mod standup;
+pub mod tree;
 
 /// JSON representation of an issue summary.
 /// Used by list, ready, and search command outputs.
@@ -24,6 +32,10 @@ pub mod show;
 pub struct IssueJson {
     /// Unique issue identifier.
     pub id: String,
+    /// Per-prefix short alias (`prj-12`), accepted wherever an ID is.
+    /// Only present in list output and paged (`-o json-paged`) output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_id: Option<String>,
     /// Classification of the issue.
     pub issue_type: IssueType,
     /// Current workflow state.
@@ -35,6 +47,14 @@ pub struct IssueJson {
     pub assignee: Option<String>,
     /// Labels attached to the issue.
     pub labels: Vec<String>,
+    /// Open issues this one blocks, directly or transitively.
+    /// Only present with `--order topo`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unblocks: Option<usize>,
+    /// Time in the current status, as `9d`, once past its `[aging]`
+    /// threshold. Only present in list output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_for: Option<String>,
 }
 
 impl IssueJson {
@@ -49,11 +69,42 @@ impl IssueJson {
     ) -> Self {
         IssueJson {
             id,
+            short_id: None,
             issue_type,
             status,
             title,
             assignee,
             labels,
+            unblocks: None,
+            stale_for: None,
         }
     }
+
+    /// Attach the issue's short ID alias.
+    pub fn with_short_id(mut self, short_id: Option<String>) -> Self {
+        self.short_id = short_id;
+        self
+    }
+
+    /// Attach the downstream count computed for `--order topo`.
+    pub fn with_unblocks(mut self, unblocks: Option<usize>) -> Self {
+        self.unblocks = unblocks;
+        self
+    }
+
+    /// Attach the issue's age in its status when it is stale.
+    pub(crate) fn with_stale(mut self, stale: Option<&Stale>) -> Self {
+        self.stale_for = stale.map(|stale| format_age(stale.age));
+        self
+    }
+}
+
+/// JSON output for `-o json-paged` on list and search: one page of issues,
+/// oldest first.
+#[derive(JsonSchema, Serialize)]
+pub struct IssuePageJson {
+    /// This page's issues, ordered by creation time, then ID.
+    pub issues: Vec<IssueJson>,
+    /// Pass to `--cursor` for the next page; null on the last page.
+    pub next_cursor: Option<String>,
 }
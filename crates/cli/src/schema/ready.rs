@@ -6,11 +6,48 @@
 use schemars::JsonSchema;
 use serde::Serialize;
 
-use super::IssueJson;
+use super::{IssueJson, Status};
 
 /// JSON output structure for the ready command.
 ///
-/// The ready command returns an array of issue summaries directly.
+/// The ready command returns an array of issue summaries directly, in
+/// display order: `--sort <key>` (and `--reverse`) when given, else the
+/// command's default order.
 #[derive(JsonSchema, Serialize)]
 #[serde(transparent)]
 pub struct ReadyOutputJson(pub Vec<IssueJson>);
+
+/// JSON output of `wok ready --explain`: the ready queue, plus every other
+/// open issue matching the filters and why it was left out.
+#[derive(JsonSchema, Serialize)]
+pub struct ReadyExplainJson {
+    /// The ready issues, as `wok ready -o json` prints them.
+    pub ready: Vec<IssueJson>,
+    /// Open issues that are not ready.
+    pub excluded: Vec<ExcludedIssueJson>,
+}
+
+/// An open issue left out of the ready queue.
+#[derive(JsonSchema, Serialize)]
+pub struct ExcludedIssueJson {
+    #[serde(flatten)]
+    pub issue: IssueJson,
+    /// Every reason it is not ready.
+    pub reasons: Vec<Exclusion>,
+}
+
+/// Why an open issue is not ready.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum Exclusion {
+    /// Not in todo (already started).
+    Status { status: Status },
+    /// Open issues block it, directly or through a chain.
+    Blocked { by: Vec<String> },
+    /// External issues it links with `blocks` are not resolved yet.
+    BlockedExternally { by: Vec<String> },
+    /// Assigned to someone the assignee filter leaves out.
+    AssignedTo { assignee: String },
+    /// Unassigned, but the assignee filter asks for specific people.
+    Unassigned,
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Schema types for the `wok rpc` protocol.
+//!
+//! `wok schema rpc` prints [`RpcProtocolJson`]: every method with the
+//! params it takes and the result it returns, generated from the same
+//! types the server reads and writes.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use wk_ipc::{MutateOp, MutateResult, QueryOp, QueryResult};
+
+use super::Event;
+
+/// Every method `wok rpc` serves, keyed by method name.
+#[derive(JsonSchema, Serialize)]
+pub struct RpcProtocolJson {
+    /// First request of a session. Takes no params.
+    pub initialize: RpcMethodJson<(), InitializeResultJson>,
+    /// Read from the database, as the daemon's `Query` requests do.
+    #[serde(rename = "wok/query")]
+    pub query: RpcMethodJson<QueryOp, QueryResult>,
+    /// Write to the database, as the daemon's `Mutate` requests do.
+    #[serde(rename = "wok/mutate")]
+    pub mutate: RpcMethodJson<MutateOp, MutateResult>,
+    /// Stop answering requests. Takes no params; returns null.
+    pub shutdown: RpcMethodJson<(), ()>,
+    /// Notification from the client: end the session.
+    pub exit: RpcNotificationJson<()>,
+    /// Notification from the server: events were logged, by this session
+    /// or by any other process.
+    #[serde(rename = "wok/didChange")]
+    pub did_change: RpcNotificationJson<DidChangeJson>,
+}
+
+/// A request's params and the result of a successful response.
+#[derive(JsonSchema, Serialize)]
+pub struct RpcMethodJson<P, R> {
+    pub params: P,
+    pub result: R,
+}
+
+/// A notification's params.
+#[derive(JsonSchema, Serialize)]
+pub struct RpcNotificationJson<P> {
+    pub params: P,
+}
+
+/// Result of `initialize`.
+#[derive(JsonSchema, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeResultJson {
+    pub server_info: ServerInfoJson,
+    /// The project's issue ID prefix.
+    pub prefix: String,
+}
+
+/// The server's name and version.
+#[derive(JsonSchema, Serialize)]
+pub struct ServerInfoJson {
+    pub name: String,
+    pub version: String,
+}
+
+/// Params of `wok/didChange`.
+#[derive(JsonSchema, Serialize)]
+pub struct DidChangeJson {
+    /// The new events, in the order they were logged.
+    pub events: Vec<Event>,
+}
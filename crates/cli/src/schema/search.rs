@@ -10,7 +10,9 @@ use super::IssueJson;
 
 /// JSON output structure for the search command.
 ///
-/// The search command returns an array of issue summaries directly.
+/// The search command returns an array of issue summaries directly, in
+/// display order: `--sort <key>` (and `--reverse`) when given, else the
+/// command's default order.
 #[derive(JsonSchema, Serialize)]
 #[serde(transparent)]
 pub struct SearchOutputJson(pub Vec<IssueJson>);
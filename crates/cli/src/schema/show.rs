@@ -7,46 +7,112 @@ use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::Serialize;
 
-use super::{Event, IssueType, Link, Note, Status};
+use super::{Event, IssueType, Link, LinkMeta, Note, Status};
+
+/// Version of the `wok show` JSON document, in its `schema_version` field.
+///
+/// Version 2 resolved dependency titles, grouped notes by status, always
+/// includes `description` and `closed_at`, and prints an array for several
+/// IDs. Version 1 had no `schema_version` field.
+pub const SHOW_SCHEMA_VERSION: u32 = 2;
+
+/// JSON output of `wok show -o json`: one document for a single ID, an
+/// array of them, in argument order, for several.
+#[derive(JsonSchema, Serialize)]
+#[serde(untagged)]
+pub enum ShowOutputJson {
+    One(Box<IssueDetails>),
+    Many(Vec<IssueDetails>),
+}
 
 /// Full issue details including notes, links, and events.
 #[derive(JsonSchema, Serialize)]
 pub struct IssueDetails {
+    /// Version of this document's layout; see [`SHOW_SCHEMA_VERSION`].
+    pub schema_version: u32,
     /// Unique issue identifier.
     pub id: String,
+    /// Per-prefix short alias (`prj-12`), accepted wherever an ID is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_id: Option<String>,
     /// Classification of the issue.
     pub issue_type: IssueType,
     /// Short description of the work.
     pub title: String,
-    /// Longer description providing context.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Longer description providing context; null when there is none.
     pub description: Option<String>,
     /// Current workflow state.
     pub status: Status,
-    /// Person or queue this issue is assigned to.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Person or queue this issue is assigned to; null when unassigned.
     pub assignee: Option<String>,
     /// When the issue was created.
     pub created_at: DateTime<Utc>,
     /// When the issue was last modified.
     pub updated_at: DateTime<Utc>,
-    /// When the issue was closed (done or closed status).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// When the issue was closed (done or closed status); null while open.
     pub closed_at: Option<DateTime<Utc>>,
+    /// Bumped by every change to the issue's fields or labels; pass it to
+    /// `--if-match` to make a change fail if the issue moved on.
+    pub revision: u32,
     /// Labels attached to the issue.
     pub labels: Vec<String>,
-    /// Issue IDs that block this issue.
-    pub blockers: Vec<String>,
-    /// Issue IDs that this issue blocks.
-    pub blocking: Vec<String>,
-    /// Parent issue IDs (tracking this issue).
-    pub parents: Vec<String>,
-    /// Child issue IDs (tracked by this issue).
-    pub children: Vec<String>,
-    /// Notes attached to the issue.
-    pub notes: Vec<Note>,
+    /// Issues that block this issue.
+    pub blockers: Vec<RelatedIssue>,
+    /// External issues that block this issue: `blocks` links not yet
+    /// resolved. Also listed in `links`.
+    pub external_blockers: Vec<LinkDetails>,
+    /// Issues that this issue blocks.
+    pub blocking: Vec<RelatedIssue>,
+    /// Issues tracking this issue.
+    pub parents: Vec<RelatedIssue>,
+    /// Issues this issue tracks.
+    pub children: Vec<RelatedIssue>,
+    /// Issues linked to this one with `relates-to`.
+    pub related: Vec<RelatedIssue>,
+    /// Issues this issue duplicates.
+    pub duplicate_of: Vec<RelatedIssue>,
+    /// Issues marked as duplicates of this issue.
+    pub duplicates: Vec<RelatedIssue>,
+    /// Issues whose notes or description mention this issue.
+    pub referenced_by: Vec<RelatedIssue>,
+    /// Notes grouped by the status the issue had when they were added, in
+    /// the order the groups first appeared.
+    pub notes: Vec<NoteGroup>,
     /// External links attached to the issue.
-    pub links: Vec<Link>,
-    /// Event history for the issue.
+    pub links: Vec<LinkDetails>,
+    /// Event history for the issue, oldest first.
     pub events: Vec<Event>,
 }
+
+/// An issue at the other end of a dependency.
+#[derive(JsonSchema, Serialize)]
+pub struct RelatedIssue {
+    /// Unique issue identifier.
+    pub id: String,
+    /// The issue's current title; absent if it no longer exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The issue's current status; absent if it no longer exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+/// An external link, with what its provider last said about the issue it
+/// points at.
+#[derive(Debug, Clone, JsonSchema, Serialize)]
+pub struct LinkDetails {
+    #[serde(flatten)]
+    pub link: Link,
+    /// Title and state fetched by `wok link refresh`; absent until then.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote: Option<LinkMeta>,
+}
+
+/// Notes added while the issue had one status.
+#[derive(JsonSchema, Serialize)]
+pub struct NoteGroup {
+    /// Status of the issue when these notes were added.
+    pub status: Status,
+    /// The notes, oldest first.
+    pub notes: Vec<Note>,
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Schema types for `wok standup` JSON output.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use wk_core::Action;
+
+use super::{IssueType, Status};
+
+/// JSON output for `wok standup`: one standup per assignee.
+#[derive(JsonSchema, Serialize)]
+#[serde(transparent)]
+pub struct StandupOutputJson(pub Vec<StandupJson>);
+
+/// One assignee's standup.
+#[derive(JsonSchema, Serialize)]
+pub struct StandupJson {
+    /// Whose standup this is.
+    pub assignee: String,
+    /// Issues done or progressed since `--since`, with what happened.
+    pub yesterday: Vec<StandupIssueJson>,
+    /// Issues in progress.
+    pub today: Vec<StandupIssueJson>,
+    /// In-progress issues that are blocked, with what blocks them.
+    pub blockers: Vec<StandupIssueJson>,
+}
+
+/// An issue in a standup section.
+#[derive(JsonSchema, Serialize)]
+pub struct StandupIssueJson {
+    /// Unique issue identifier.
+    pub id: String,
+    /// Classification of the issue.
+    pub issue_type: IssueType,
+    /// Current workflow state.
+    pub status: Status,
+    /// Short description of the work.
+    pub title: String,
+    /// What happened to it in the period, in order. Only in `yesterday`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<Action>,
+    /// Open issues blocking it, directly or transitively, then unresolved
+    /// external blockers. Only in `blockers`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<BlockerJson>,
+}
+
+/// Something blocking an issue: another issue, or an external link.
+#[derive(JsonSchema, Serialize)]
+pub struct BlockerJson {
+    /// The blocking issue's ID, or the external blocker's URL.
+    pub id: String,
+    /// The blocking issue's title. Absent for external blockers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Who the blocking issue is assigned to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+}
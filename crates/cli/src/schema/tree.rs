@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Schema types for `wok tree` JSON output.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::{IssueType, Status};
+
+/// JSON output of `wok tree -o json`: one tree for a single ID, an array
+/// of them, in argument order, for several.
+#[derive(JsonSchema, Serialize)]
+#[serde(untagged)]
+pub enum TreeOutputJson {
+    One(Box<TreeNodeJson>),
+    Many(Vec<TreeNodeJson>),
+}
+
+/// How a node hangs off its parent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, JsonSchema, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TreeEdge {
+    /// The parent tracks this issue.
+    Tracks,
+    /// The parent blocks this issue.
+    Blocks,
+    /// This issue tracks the parent (`--up`).
+    TrackedBy,
+    /// This issue blocks the parent (`--up`).
+    BlockedBy,
+    /// This issue relates to the root.
+    RelatesTo,
+    /// The root duplicates this issue.
+    DuplicateOf,
+    /// This issue duplicates the root.
+    DuplicatedBy,
+}
+
+/// An issue in a dependency tree, with the issues below it.
+#[derive(JsonSchema, Serialize)]
+pub struct TreeNodeJson {
+    /// Unique issue identifier.
+    pub id: String,
+    /// Classification of the issue.
+    pub issue_type: IssueType,
+    /// Short description of the work.
+    pub title: String,
+    /// Current workflow state.
+    pub status: Status,
+    /// Person or queue this issue is assigned to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Edge from the parent; absent on the root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation: Option<TreeEdge>,
+    /// Open issues blocking this one, directly or transitively. Omitted
+    /// with `--up`, where blockers are the node's children.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+    /// True if this issue already appears above it on the same path. Its
+    /// children are not repeated.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cycle: bool,
+    /// Children left out because the tree stopped at `--depth`.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub hidden_children: usize,
+    /// Issues below this one, tracked before blocked. With `--up`, the
+    /// issues this one depends on: parents, then blockers. The root also
+    /// lists related issues and duplicates, which are not expanded.
+    pub children: Vec<TreeNodeJson>,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
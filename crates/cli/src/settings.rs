@@ -0,0 +1,378 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! The `.wok/config.toml` keys known to `wok config get/set/list`.
+//!
+//! Each [`Setting`] reads its effective value from a [`Config`], defaults
+//! included, and validates a new value before storing it, so a bad `set`
+//! never reaches the file.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::commands::{list, log, search};
+use crate::config::{Config, EpicConfig};
+use crate::error::{Error, Result};
+use crate::filter::{parse_duration, parse_query};
+use crate::id::validate_prefix;
+use crate::validate::validate_label;
+
+/// One dotted key, such as `wip.limit`.
+pub struct Setting {
+    pub key: &'static str,
+    pub about: &'static str,
+    get: fn(&Config) -> Option<String>,
+    set: fn(&mut Config, Option<&str>) -> Result<()>,
+}
+
+impl Setting {
+    /// The effective value, or `None` when unset with no default.
+    pub fn get(&self, config: &Config) -> Option<String> {
+        (self.get)(config)
+    }
+
+    /// Store `value`, or restore the default when it is `None`.
+    pub fn set(&self, config: &mut Config, value: Option<&str>) -> Result<()> {
+        (self.set)(config, value)
+    }
+}
+
+/// Every setting, in `wok config list` order.
+pub const SETTINGS: &[Setting] = &[
+    Setting {
+        key: "prefix",
+        about: "Prefix for new issue IDs",
+        get: |c| (!c.prefix.is_empty()).then(|| c.prefix.clone()),
+        set: |c, v| {
+            let v = v.ok_or_else(|| invalid("prefix", "a project always has a prefix"))?;
+            if !validate_prefix(v) {
+                return Err(Error::InvalidPrefix);
+            }
+            c.prefix = v.to_string();
+            Ok(())
+        },
+    },
+    Setting {
+        key: "private",
+        about: "Keep issues.db in .wok/ instead of the shared state directory",
+        get: |c| Some(c.private.to_string()),
+        set: |_, _| {
+            Err(invalid(
+                "private",
+                "changing it moves the database; run 'wok config mode' instead",
+            ))
+        },
+    },
+    Setting {
+        key: "user",
+        about: "Name that @me refers to",
+        get: |c| c.user.clone(),
+        set: |c, v| {
+            c.user = v.map(|v| text("user", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "actor",
+        about: "Who events and notes are recorded as; defaults to user",
+        get: |c| c.actor.clone(),
+        set: |c, v| {
+            c.actor = v.map(|v| text("actor", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "team",
+        about: "Comma-separated assignees; when set, assigning anyone else fails",
+        get: |c| (!c.team.is_empty()).then(|| c.team.join(",")),
+        set: |c, v| {
+            c.team = v
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default();
+            Ok(())
+        },
+    },
+    Setting {
+        key: "color",
+        about: "Color output: auto, always or never (NO_COLOR and COLOR take precedence)",
+        get: |c| name(&c.color),
+        set: |c, v| {
+            c.color = v
+                .map(|v| choice("color", v, "auto, always, never"))
+                .transpose()?
+                .unwrap_or_default();
+            Ok(())
+        },
+    },
+    Setting {
+        key: "locale",
+        about: "Language of messages: en or de (unset follows LC_ALL, LC_MESSAGES, LANG)",
+        get: |c| c.locale.as_ref().and_then(name),
+        set: |c, v| {
+            c.locale = v.map(|v| choice("locale", v, "en, de")).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "wip.limit",
+        about: "Maximum in-progress issues per assignee",
+        get: |c| c.wip.limit.map(|n| n.to_string()),
+        set: |c, v| {
+            c.wip.limit = v.map(|v| count("wip.limit", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "wip.mode",
+        about: "What 'wok start' does past the WIP limit: warn or block",
+        get: |c| name(&c.wip.mode),
+        set: |c, v| {
+            c.wip.mode = v
+                .map(|v| choice("wip.mode", v, "warn, block"))
+                .transpose()?
+                .unwrap_or_default();
+            Ok(())
+        },
+    },
+    Setting {
+        key: "epic.auto",
+        about: "Large trackers: off, suggest or convert to epics",
+        get: |c| name(&c.epic.auto),
+        set: |c, v| {
+            c.epic.auto = v
+                .map(|v| choice("epic.auto", v, "off, suggest, convert"))
+                .transpose()?
+                .unwrap_or_default();
+            Ok(())
+        },
+    },
+    Setting {
+        key: "epic.threshold",
+        about: "Tracked issues that make an issue an epic candidate",
+        get: |c| Some(c.epic.threshold.to_string()),
+        set: |c, v| {
+            c.epic.threshold = match v {
+                Some(v) => count("epic.threshold", v)?,
+                None => EpicConfig::default().threshold,
+            };
+            Ok(())
+        },
+    },
+    Setting {
+        key: "open.url",
+        about: "Page 'wok open' shows for an issue without links ({id}, {prefix})",
+        get: |c| c.open.url.clone(),
+        set: |c, v| {
+            c.open.url = v.map(|v| text("open.url", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "hyperlinks.mode",
+        about: "Clickable IDs and links: auto (supporting terminals), always or never",
+        get: |c| name(&c.hyperlinks.mode),
+        set: |c, v| {
+            c.hyperlinks.mode = v
+                .map(|v| choice("hyperlinks.mode", v, "auto, always, never"))
+                .transpose()?
+                .unwrap_or_default();
+            Ok(())
+        },
+    },
+    Setting {
+        key: "hyperlinks.url",
+        about: "Page an issue ID links to ({id}, {prefix}); unset uses open.url",
+        get: |c| c.hyperlinks.url.clone(),
+        set: |c, v| {
+            c.hyperlinks.url = v.map(|v| text("hyperlinks.url", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "git.branch",
+        about: "Branch name 'wok branch' uses ({id}, {type}, {prefix}, {slug})",
+        get: |c| Some(c.git.branch_template().to_string()),
+        set: |c, v| {
+            c.git.branch = v.map(|v| text("git.branch", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "sweep.filter",
+        about: "Filter expression for issues 'wok sweep' treats as stale",
+        get: |c| Some(c.sweep.filter().to_string()),
+        set: |c, v| {
+            c.sweep.filter = v.map(|v| filter("sweep.filter", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "sweep.reason",
+        about: "Reason 'wok sweep --close' closes stale issues with",
+        get: |c| Some(c.sweep.reason().to_string()),
+        set: |c, v| {
+            c.sweep.reason = v.map(|v| text("sweep.reason", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "sweep.label",
+        about: "Label 'wok sweep --close' adds to the issues it closes",
+        get: |c| c.sweep.label.clone(),
+        set: |c, v| {
+            c.sweep.label = v.map(|v| label("sweep.label", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "aging.todo",
+        about: "How long an issue may wait in todo before it is stale (e.g. 30d)",
+        get: |c| c.aging.todo.clone(),
+        set: |c, v| {
+            c.aging.todo = v.map(|v| duration("aging.todo", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "aging.in_progress",
+        about: "How long an issue may stay in progress before it is stale (e.g. 7d)",
+        get: |c| c.aging.in_progress.clone(),
+        set: |c, v| {
+            c.aging.in_progress = v.map(|v| duration("aging.in_progress", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "backup.auto",
+        about: "Back up before import, prefix renames and 'wok sweep --close'",
+        get: |c| Some(c.backup.auto().to_string()),
+        set: |c, v| {
+            c.backup.auto = v.map(|v| flag("backup.auto", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "backup.keep",
+        about: "Automatic backups kept in .wok/backups/",
+        get: |c| Some(c.backup.keep().to_string()),
+        set: |c, v| {
+            c.backup.keep = v.map(|v| count("backup.keep", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "labels.strict",
+        about: "Reject key:value labels whose key is not under [labels.keys]",
+        get: |c| Some(c.labels.strict().to_string()),
+        set: |c, v| {
+            c.labels.strict = v.map(|v| flag("labels.strict", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "limits.list",
+        about: "Default --limit for 'wok list' (0 for no limit)",
+        get: |c| Some(c.limits.list.unwrap_or(list::DEFAULT_LIMIT).to_string()),
+        set: |c, v| {
+            c.limits.list = v.map(|v| count("limits.list", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "limits.search",
+        about: "Default --limit for 'wok search' (0 for no limit)",
+        get: |c| Some(c.limits.search.unwrap_or(search::DEFAULT_LIMIT).to_string()),
+        set: |c, v| {
+            c.limits.search = v.map(|v| count("limits.search", v)).transpose()?;
+            Ok(())
+        },
+    },
+    Setting {
+        key: "limits.log",
+        about: "Default --limit for 'wok log' (0 for no limit)",
+        get: |c| Some(c.limits.log.unwrap_or(log::DEFAULT_LIMIT).to_string()),
+        set: |c, v| {
+            c.limits.log = v.map(|v| count("limits.log", v)).transpose()?;
+            Ok(())
+        },
+    },
+];
+
+/// The setting named `key`.
+pub fn find(key: &str) -> Result<&'static Setting> {
+    SETTINGS
+        .iter()
+        .find(|s| s.key == key)
+        .ok_or_else(|| Error::UnknownSetting(key.to_string()))
+}
+
+fn invalid(key: &str, reason: impl Into<String>) -> Error {
+    Error::InvalidSetting {
+        key: key.to_string(),
+        reason: reason.into(),
+    }
+}
+
+fn text(key: &str, value: &str) -> Result<String> {
+    match value.trim() {
+        "" => Err(invalid(key, "cannot be empty")),
+        v => Ok(v.to_string()),
+    }
+}
+
+fn count(key: &str, value: &str) -> Result<usize> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| invalid(key, format!("'{}' is not a whole number", value)))
+}
+
+fn flag(key: &str, value: &str) -> Result<bool> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| invalid(key, format!("'{}' is not true or false", value)))
+}
+
+fn filter(key: &str, value: &str) -> Result<String> {
+    let value = text(key, value)?;
+    parse_query(&value).map_err(|e| invalid(key, e.to_string()))?;
+    Ok(value)
+}
+
+fn duration(key: &str, value: &str) -> Result<String> {
+    let value = text(key, value)?;
+    parse_duration(&value).map_err(|e| invalid(key, e.to_string()))?;
+    Ok(value)
+}
+
+fn label(key: &str, value: &str) -> Result<String> {
+    let value = text(key, value)?;
+    validate_label(&value).map_err(|e| invalid(key, e.to_string()))?;
+    Ok(value)
+}
+
+/// Parse one of an enum's names as written in config.toml.
+fn choice<T: DeserializeOwned>(key: &str, value: &str, names: &str) -> Result<T> {
+    toml::Value::String(value.trim().to_string())
+        .try_into()
+        .map_err(|_| invalid(key, format!("'{}' is not one of {}", value, names)))
+}
+
+/// An enum's name as written in config.toml.
+fn name<T: Serialize>(value: &T) -> Option<String> {
+    match toml::Value::try_from(value).ok()? {
+        toml::Value::String(name) => Some(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "settings_tests.rs"]
+mod tests;
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::config::{ColorMode, WipMode};
+use yare::parameterized;
+
+fn project() -> Config {
+    Config::new("prj".to_string()).unwrap()
+}
+
+fn get(config: &Config, key: &str) -> Option<String> {
+    find(key).unwrap().get(config)
+}
+
+fn set(config: &mut Config, key: &str, value: &str) -> Result<()> {
+    find(key).unwrap().set(config, Some(value))
+}
+
+#[test]
+fn keys_are_unique() {
+    for (i, setting) in SETTINGS.iter().enumerate() {
+        assert!(
+            SETTINGS[i + 1..].iter().all(|s| s.key != setting.key),
+            "duplicate key {}",
+            setting.key
+        );
+    }
+}
+
+#[test]
+fn unknown_key_is_an_error() {
+    assert!(matches!(find("wip.limt"), Err(Error::UnknownSetting(k)) if k == "wip.limt"));
+}
+
+#[parameterized(
+    prefix = { "prefix", Some("prj") },
+    private = { "private", Some("false") },
+    user = { "user", None },
+    actor = { "actor", None },
+    team = { "team", None },
+    color = { "color", Some("auto") },
+    hyperlinks_mode = { "hyperlinks.mode", Some("auto") },
+    hyperlinks_url = { "hyperlinks.url", None },
+    wip_limit = { "wip.limit", None },
+    wip_mode = { "wip.mode", Some("warn") },
+    epic_auto = { "epic.auto", Some("suggest") },
+    epic_threshold = { "epic.threshold", Some("5") },
+    limits_list = { "limits.list", Some("100") },
+    limits_search = { "limits.search", Some("25") },
+    limits_log = { "limits.log", Some("20") },
+    sweep_filter = { "sweep.filter", Some("activity > 90d") },
+    sweep_reason = { "sweep.reason", Some("stale") },
+    sweep_label = { "sweep.label", None },
+    aging_todo = { "aging.todo", None },
+    aging_in_progress = { "aging.in_progress", None },
+    backup_auto = { "backup.auto", Some("false") },
+    backup_keep = { "backup.keep", Some("5") },
+)]
+fn reports_defaults(key: &str, expected: Option<&str>) {
+    let config = project();
+    assert_eq!(get(&config, key).as_deref(), expected);
+}
+
+#[parameterized(
+    prefix = { "prefix", "app", "app" },
+    user = { "user", " alice ", "alice" },
+    actor = { "actor", " claude-1 ", "claude-1" },
+    team = { "team", "alice, bob,", "alice,bob" },
+    color = { "color", "never", "never" },
+    hyperlinks_mode = { "hyperlinks.mode", "always", "always" },
+    hyperlinks_url = { "hyperlinks.url", "http://127.0.0.1:8080/issue/{id}", "http://127.0.0.1:8080/issue/{id}" },
+    wip_limit = { "wip.limit", "3", "3" },
+    wip_mode = { "wip.mode", "block", "block" },
+    epic_auto = { "epic.auto", "convert", "convert" },
+    epic_threshold = { "epic.threshold", "8", "8" },
+    limits_list = { "limits.list", "0", "0" },
+    limits_log = { "limits.log", "50", "50" },
+    sweep_filter = { "sweep.filter", "activity > 60d and label != keep", "activity > 60d and label != keep" },
+    sweep_label = { "sweep.label", "sweep:stale", "sweep:stale" },
+    aging_in_progress = { "aging.in_progress", " 7d ", "7d" },
+    backup_auto = { "backup.auto", " true ", "true" },
+    backup_keep = { "backup.keep", "10", "10" },
+)]
+fn set_then_get(key: &str, value: &str, expected: &str) {
+    let mut config = project();
+    set(&mut config, key, value).unwrap();
+    assert_eq!(get(&config, key).as_deref(), Some(expected));
+}
+
+#[parameterized(
+    prefix = { "prefix", "A" },
+    user = { "user", "  " },
+    actor = { "actor", "" },
+    color = { "color", "sometimes" },
+    hyperlinks_mode = { "hyperlinks.mode", "yes" },
+    wip_limit = { "wip.limit", "-1" },
+    wip_mode = { "wip.mode", "3" },
+    limits_list = { "limits.list", "ten" },
+    private = { "private", "true" },
+    sweep_filter = { "sweep.filter", "activity >" },
+    sweep_label = { "sweep.label", &"x".repeat(101) },
+    aging_todo = { "aging.todo", "a month" },
+    backup_auto = { "backup.auto", "yes" },
+)]
+fn rejects_invalid_values(key: &str, value: &str) {
+    let mut config = project();
+    let before = toml::to_string(&config).unwrap();
+    assert!(set(&mut config, key, value).is_err());
+    assert_eq!(toml::to_string(&config).unwrap(), before);
+}
+
+#[test]
+fn invalid_choice_lists_the_options() {
+    let mut config = project();
+    let err = set(&mut config, "wip.mode", "loud").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value for wip.mode: 'loud' is not one of warn, block"
+    );
+}
+
+#[test]
+fn unset_restores_defaults() {
+    let mut config = project();
+    set(&mut config, "color", "always").unwrap();
+    set(&mut config, "wip.mode", "block").unwrap();
+    set(&mut config, "limits.search", "5").unwrap();
+    set(&mut config, "epic.threshold", "9").unwrap();
+    for key in ["color", "wip.mode", "limits.search", "epic.threshold"] {
+        find(key).unwrap().set(&mut config, None).unwrap();
+    }
+    assert_eq!(config.color, ColorMode::Auto);
+    assert_eq!(config.wip.mode, WipMode::Warn);
+    assert_eq!(config.limits.search, None);
+    assert_eq!(get(&config, "epic.threshold").as_deref(), Some("5"));
+}
+
+#[test]
+fn prefix_cannot_be_unset() {
+    let mut config = project();
+    assert!(find("prefix").unwrap().set(&mut config, None).is_err());
+}
@@ -25,6 +25,9 @@ pub fn print_timing(phase: &str, start: Instant) {
 
 /// Macro for timing a block of code.
 ///
+/// The block also runs in a [`tracing`] span named after the phase, so it
+/// shows up in `wok --trace`. The phase must be a string literal.
+///
 /// Usage:
 /// ```rust,ignore
 /// let result = time_phase!("db::open", {
@@ -34,6 +37,7 @@ pub fn print_timing(phase: &str, start: Instant) {
 #[macro_export]
 macro_rules! time_phase {
     ($phase:expr, $block:expr) => {{
+        let __span = ::tracing::info_span!($phase).entered();
         let __start = std::time::Instant::now();
         let __result = $block;
         $crate::timings::print_timing($phase, __start);
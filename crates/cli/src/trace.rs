@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Span timing for `wok --trace`.
+//!
+//! Commands open [`tracing`] spans around database work, daemon round trips,
+//! hooks and rendering. When tracing is on, a recorder collects every span
+//! and each SQL statement the database runs, and prints them when the
+//! command finishes: as an indented timing tree with `--trace`, or as one
+//! JSON object per span with `WOK_TRACE_JSON=1`. Both go to stderr.
+//!
+//! With tracing off no subscriber is installed and the spans cost next to
+//! nothing.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use rusqlite::trace::{TraceEvent, TraceEventCodes};
+use rusqlite::Connection;
+use serde_json::{json, Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Target of the events that report finished SQL statements.
+const SQL_TARGET: &str = "wok::sql";
+
+/// Longest SQL text shown in the tree.
+const MAX_SQL_LEN: usize = 60;
+
+/// Longest label in the tree before the durations column.
+const MAX_LABEL_WIDTH: usize = 72;
+
+/// A finished span or SQL statement.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SpanRecord {
+    pub id: u64,
+    pub parent: Option<u64>,
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+    /// Time from the start of tracing to the start of the span.
+    pub start: Duration,
+    pub elapsed: Duration,
+}
+
+/// Collected spans, printed by [`Trace::finish`].
+pub struct Trace {
+    records: Arc<Mutex<Vec<SpanRecord>>>,
+    json: bool,
+}
+
+/// Start tracing if `--trace` was given or `WOK_TRACE_JSON=1` is set.
+pub fn start(enabled: bool) -> Option<Trace> {
+    let json = crate::env::wok_trace_json();
+    if !enabled && !json {
+        return None;
+    }
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let recorder = Recorder {
+        records: Arc::clone(&records),
+        epoch: Instant::now(),
+        next_id: Mutex::new(0),
+    };
+    tracing::subscriber::set_global_default(tracing_subscriber::registry().with(recorder)).ok()?;
+    Some(Trace { records, json })
+}
+
+impl Trace {
+    /// Print everything recorded so far to stderr.
+    pub fn finish(self) {
+        let records = self
+            .records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let out = if self.json {
+            json_lines(&records)
+        } else {
+            tree(&records)
+        };
+        eprint!("{out}");
+    }
+}
+
+thread_local! {
+    /// Statements that have started running on this thread, by SQL text.
+    static RUNNING: RefCell<HashMap<String, Instant>> = RefCell::new(HashMap::new());
+}
+
+/// Report each SQL statement `conn` runs as a finished span, when tracing.
+///
+/// A statement's time runs from its first step to its reset, so rows read
+/// lazily count towards it. SQLite's own profile times are only
+/// millisecond-accurate, so statements are timed here instead.
+pub fn watch(conn: &Connection) {
+    if tracing::enabled!(target: SQL_TARGET, tracing::Level::INFO) {
+        let events = TraceEventCodes::SQLITE_TRACE_STMT | TraceEventCodes::SQLITE_TRACE_PROFILE;
+        conn.trace_v2(events, Some(on_statement));
+    }
+}
+
+fn on_statement(event: TraceEvent<'_>) {
+    match event {
+        TraceEvent::Stmt(stmt, _) => RUNNING.with(|running| {
+            running
+                .borrow_mut()
+                .insert(stmt.sql().into_owned(), Instant::now());
+        }),
+        TraceEvent::Profile(stmt, _) => {
+            let sql = stmt.sql();
+            let started = RUNNING.with(|running| running.borrow_mut().remove(sql.as_ref()));
+            if let Some(started) = started {
+                let elapsed_us = micros(started.elapsed());
+                tracing::info!(target: SQL_TARGET, elapsed_us, sql = %sql);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Open span state, kept in the span's extensions.
+struct Open {
+    id: u64,
+    start: Instant,
+    fields: Vec<(String, String)>,
+}
+
+struct Recorder {
+    records: Arc<Mutex<Vec<SpanRecord>>>,
+    epoch: Instant,
+    next_id: Mutex<u64>,
+}
+
+impl Recorder {
+    /// Registry span IDs are reused once spans close, so records get their own.
+    fn next_id(&self) -> u64 {
+        let mut next = self.next_id.lock().unwrap_or_else(PoisonError::into_inner);
+        *next += 1;
+        *next
+    }
+
+    fn push(&self, record: SpanRecord) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(record);
+    }
+}
+
+impl<S> Layer<S> for Recorder
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Fields::default();
+        attrs.record(&mut fields);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Open {
+                id: self.next_id(),
+                start: Instant::now(),
+                fields: fields.0,
+            });
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(open) = span.extensions_mut().get_mut::<Open>() {
+                let mut fields = Fields::default();
+                values.record(&mut fields);
+                open.fields.extend(fields.0);
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if event.metadata().target() != SQL_TARGET {
+            return;
+        }
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let mut elapsed = Duration::ZERO;
+        let mut sql = String::new();
+        for (key, value) in fields.0 {
+            match key.as_str() {
+                "elapsed_us" => elapsed = Duration::from_micros(value.parse().unwrap_or(0)),
+                "sql" => sql = value,
+                _ => {}
+            }
+        }
+        let parent = ctx
+            .event_span(event)
+            .and_then(|span| span.extensions().get::<Open>().map(|open| open.id));
+        let end = self.epoch.elapsed();
+        self.push(SpanRecord {
+            id: self.next_id(),
+            parent,
+            name: "sql".to_string(),
+            fields: vec![("sql".to_string(), sql)],
+            start: end.saturating_sub(elapsed),
+            elapsed,
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let parent = span
+            .parent()
+            .and_then(|p| p.extensions().get::<Open>().map(|open| open.id));
+        let extensions = span.extensions();
+        let Some(open) = extensions.get::<Open>() else {
+            return;
+        };
+        self.push(SpanRecord {
+            id: open.id,
+            parent,
+            name: span.name().to_string(),
+            fields: open.fields.clone(),
+            start: open.start.saturating_duration_since(self.epoch),
+            elapsed: open.start.elapsed(),
+        });
+    }
+}
+
+/// Span or event fields as strings, in declaration order.
+#[derive(Default)]
+struct Fields(Vec<(String, String)>);
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+/// One JSON object per span, in start order.
+pub(crate) fn json_lines(records: &[SpanRecord]) -> String {
+    let mut sorted: Vec<_> = records.iter().collect();
+    sorted.sort_by_key(|r| (r.start, r.id));
+    let mut out = String::new();
+    for record in sorted {
+        let fields: Map<String, Value> = record
+            .fields
+            .iter()
+            .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+            .collect();
+        let line = json!({
+            "id": record.id,
+            "parent": record.parent,
+            "name": record.name,
+            "fields": fields,
+            "start_us": micros(record.start),
+            "duration_us": micros(record.elapsed),
+        });
+        let _ = writeln!(out, "{line}");
+    }
+    out
+}
+
+/// A line of the tree: a span, or a run of identical SQL statements.
+struct Line {
+    depth: usize,
+    label: String,
+    elapsed: Duration,
+}
+
+/// The indented timing tree, children in start order under their parents.
+///
+/// Consecutive runs of the same SQL statement under one parent are merged
+/// into one line with a count.
+pub(crate) fn tree(records: &[SpanRecord]) -> String {
+    let mut sorted: Vec<_> = records.iter().collect();
+    sorted.sort_by_key(|r| (r.start, r.id));
+    let known = |id: u64| sorted.iter().any(|r| r.id == id);
+
+    let mut lines = Vec::new();
+    let roots: Vec<_> = sorted
+        .iter()
+        .filter(|r| r.parent.is_none_or(|p| !known(p)))
+        .copied()
+        .collect();
+    push_lines(&sorted, &roots, 0, &mut lines);
+
+    let width = lines
+        .iter()
+        .map(|l| l.depth * 2 + l.label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    let mut out = String::new();
+    for line in lines {
+        let indent = "  ".repeat(line.depth);
+        let room = width.saturating_sub(indent.len());
+        let label = truncate(&line.label, room.max(8));
+        let _ = writeln!(
+            out,
+            "[trace] {indent}{label:<room$}  {}",
+            duration(line.elapsed)
+        );
+    }
+    out
+}
+
+fn push_lines(all: &[&SpanRecord], level: &[&SpanRecord], depth: usize, lines: &mut Vec<Line>) {
+    let mut i = 0;
+    while let Some(record) = level.get(i) {
+        if record.name == "sql" {
+            let run = level[i..]
+                .iter()
+                .take_while(|r| r.name == "sql" && r.fields == record.fields)
+                .count();
+            let elapsed = level[i..i + run].iter().map(|r| r.elapsed).sum();
+            let mut label = format!("sql {}", sql_text(record));
+            if run > 1 {
+                let _ = write!(label, " ×{run}");
+            }
+            lines.push(Line {
+                depth,
+                label,
+                elapsed,
+            });
+            i += run;
+            continue;
+        }
+
+        lines.push(Line {
+            depth,
+            label: span_label(record),
+            elapsed: record.elapsed,
+        });
+        let children: Vec<_> = all
+            .iter()
+            .filter(|r| r.parent == Some(record.id))
+            .copied()
+            .collect();
+        push_lines(all, &children, depth + 1, lines);
+        i += 1;
+    }
+}
+
+fn span_label(record: &SpanRecord) -> String {
+    let mut label = record.name.clone();
+    for (key, value) in &record.fields {
+        let _ = write!(label, " {key}={value}");
+    }
+    label
+}
+
+fn sql_text(record: &SpanRecord) -> String {
+    let sql = record
+        .fields
+        .iter()
+        .find(|(k, _)| k == "sql")
+        .map_or("", |(_, v)| v.as_str());
+    let collapsed = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    truncate(&collapsed, MAX_SQL_LEN)
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let mut out: String = s.chars().take(max.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+fn duration(d: Duration) -> String {
+    format!("{:>9.2}ms", d.as_secs_f64() * 1000.0)
+}
+
+fn micros(d: Duration) -> u64 {
+    u64::try_from(d.as_micros()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+#[path = "trace_tests.rs"]
+mod tests;
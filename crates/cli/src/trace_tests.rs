@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+fn span(id: u64, parent: Option<u64>, name: &str, start_ms: u64, ms: u64) -> SpanRecord {
+    SpanRecord {
+        id,
+        parent,
+        name: name.to_string(),
+        fields: Vec::new(),
+        start: Duration::from_millis(start_ms),
+        elapsed: Duration::from_millis(ms),
+    }
+}
+
+fn sql(id: u64, parent: u64, text: &str, start_ms: u64) -> SpanRecord {
+    SpanRecord {
+        fields: vec![("sql".to_string(), text.to_string())],
+        ..span(id, Some(parent), "sql", start_ms, 1)
+    }
+}
+
+#[test]
+fn tree_nests_children_in_start_order() {
+    // Spans are recorded as they close, so children come before parents
+    let records = vec![
+        span(3, Some(1), "render", 5, 2),
+        span(2, Some(1), "db::query", 1, 3),
+        span(1, None, "wok", 0, 10),
+    ];
+    let out = tree(&records);
+    let labels: Vec<&str> = out
+        .lines()
+        .map(|l| l.strip_prefix("[trace] ").unwrap().trim_end())
+        .map(|l| l.rsplit_once("  ").unwrap().0.trim_end())
+        .collect();
+    assert_eq!(labels, vec!["wok", "  db::query", "  render"]);
+}
+
+#[test]
+fn tree_shows_fields_and_durations() {
+    let mut root = span(1, None, "wok", 0, 12);
+    root.fields
+        .push(("command".to_string(), "list".to_string()));
+    let out = tree(&[root]);
+    assert!(out.starts_with("[trace] wok command=list"));
+    assert!(out.trim_end().ends_with("12.00ms"));
+}
+
+#[test]
+fn tree_merges_repeated_sql() {
+    let records = vec![
+        span(1, None, "db::query", 0, 10),
+        sql(2, 1, "SELECT * FROM issues WHERE id = ?1", 1),
+        sql(3, 1, "SELECT * FROM issues WHERE id = ?1", 2),
+        sql(4, 1, "SELECT * FROM labels", 3),
+        sql(5, 1, "SELECT * FROM issues WHERE id = ?1", 4),
+    ];
+    let out = tree(&records);
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].contains("sql SELECT * FROM issues WHERE id = ?1 ×2"));
+    assert!(lines[1].contains("2.00ms"));
+    assert!(lines[2].contains("sql SELECT * FROM labels"));
+    assert!(!lines[3].contains('×'));
+}
+
+#[test]
+fn tree_collapses_and_truncates_long_sql() {
+    let long = format!("SELECT\n    {}\nFROM issues", "a, ".repeat(40));
+    let out = tree(&[span(1, None, "wok", 0, 5), sql(2, 1, &long, 1)]);
+    let line = out.lines().nth(1).unwrap();
+    assert!(line.contains("sql SELECT a, a,"));
+    assert!(line.contains('…'));
+    assert!(!line.contains("FROM issues"));
+}
+
+#[test]
+fn tree_treats_orphans_as_roots() {
+    let out = tree(&[span(2, Some(99), "hook", 0, 1)]);
+    assert!(out.starts_with("[trace] hook"));
+}
+
+#[test]
+fn json_lines_emit_one_object_per_span() {
+    let mut root = span(1, None, "wok", 0, 10);
+    root.fields
+        .push(("command".to_string(), "ready".to_string()));
+    let records = vec![sql(2, 1, "SELECT 1", 2), root];
+    let out = json_lines(&records);
+    let values: Vec<Value> = out
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0]["name"], "wok");
+    assert_eq!(values[0]["parent"], Value::Null);
+    assert_eq!(values[0]["fields"]["command"], "ready");
+    assert_eq!(values[0]["duration_us"], 10_000);
+    assert_eq!(values[1]["parent"], 1);
+    assert_eq!(values[1]["fields"]["sql"], "SELECT 1");
+    assert_eq!(values[1]["start_us"], 2_000);
+}
+
+#[test]
+fn truncate_keeps_short_text() {
+    assert_eq!(truncate("abc", 3), "abc");
+    assert_eq!(truncate("abcd", 3), "ab…");
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Workspaces: several projects sharing one database.
+//!
+//! `wok init --join <path-or-id>` links a new project to an existing one, the
+//! workspace root. The root gets a `workspace_id`; each member records the
+//! same ID and `workspace`, the path of the root's `.wok/`, and takes its
+//! database and mode from there, so issues created in any member land in the
+//! same tracker.
+//!
+//! The registry, `workspaces.toml` in the state directory, maps each ID to
+//! its root and members so a workspace can be joined by ID and listed from
+//! any of its projects.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{find_work_dir_from, init_work_dir_with, Config, CONFIG_FILE_NAME};
+use crate::error::{Error, Result};
+use crate::id::generate_unique_id;
+
+const REGISTRY_FILE_NAME: &str = "workspaces.toml";
+
+/// One workspace in the registry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The root project's `.wok/` directory.
+    pub root: PathBuf,
+    /// Each member's `.wok/` directory, in joining order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<PathBuf>,
+}
+
+/// The workspaces known on this machine, by ID.
+#[derive(Debug, Default)]
+pub struct Registry {
+    path: PathBuf,
+    entries: BTreeMap<String, Entry>,
+}
+
+impl Registry {
+    /// Read the registry in `state_dir`; a missing file is an empty registry.
+    pub fn load(state_dir: &Path) -> Result<Self> {
+        let path = state_dir.join(REGISTRY_FILE_NAME);
+        let entries = match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| Error::Config(format!("failed to parse {}: {}", path.display(), e)))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Registry { path, entries })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let content = toml::to_string_pretty(&self.entries)
+            .map_err(|e| Error::Config(format!("failed to serialize workspaces: {}", e)))?;
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Entry> {
+        self.entries.get(id)
+    }
+}
+
+/// The root `.wok/` directory of the workspace `target` names: the path of
+/// any project in it, or its ID.
+pub fn resolve(target: &str, registry: &Registry) -> Result<PathBuf> {
+    let not_found = || Error::WorkspaceNotFound(target.to_string());
+    let path = Path::new(target);
+    let root = if path.exists() {
+        let work_dir = find_work_dir_from(&path.canonicalize()?).map_err(|_| not_found())?;
+        // Joining a member joins its workspace.
+        let config = Config::load_file(&work_dir)?;
+        config.workspace_root(&work_dir).unwrap_or(work_dir)
+    } else {
+        registry.get(target).ok_or_else(not_found)?.root.clone()
+    };
+    if !root.join(CONFIG_FILE_NAME).is_file() {
+        return Err(Error::WorkspaceNotFound(root.display().to_string()));
+    }
+    Ok(root)
+}
+
+/// Initialize a `.wok/` in `project_dir` as a member of the workspace whose
+/// root is `root`, giving the root an ID first if it has none. Without a
+/// `prefix` the member uses the root's.
+///
+/// Returns the member's `.wok/` directory and the workspace ID.
+pub fn join(
+    registry: &mut Registry,
+    root: &Path,
+    project_dir: &Path,
+    prefix: Option<String>,
+) -> Result<(PathBuf, String)> {
+    let mut root_config = Config::load_file(root)?;
+    let id = match &root_config.workspace_id {
+        Some(id) => id.clone(),
+        None => {
+            let id = generate_unique_id(
+                "ws",
+                &root.display().to_string(),
+                &chrono::Utc::now(),
+                |candidate| registry.entries.contains_key(candidate),
+            );
+            root_config.workspace_id = Some(id.clone());
+            root_config.save(root)?;
+            id
+        }
+    };
+
+    let member = Config {
+        prefix: prefix.unwrap_or_default(),
+        workspace: Some(root.display().to_string()),
+        workspace_id: Some(id.clone()),
+        ..Config::default()
+    };
+    let work_dir = init_work_dir_with(project_dir, &member)?;
+
+    let entry = registry.entries.entry(id.clone()).or_default();
+    entry.root = root.to_path_buf();
+    if !entry.members.contains(&work_dir) {
+        entry.members.push(work_dir.clone());
+    }
+    Ok((work_dir, id))
+}
+
+/// The `.wok/` directories of workspace `id`, root first. Without a registry
+/// entry, only the root and `work_dir` are known.
+pub fn projects(registry: &Registry, id: &str, root: &Path, work_dir: &Path) -> Vec<PathBuf> {
+    let mut projects = vec![root.to_path_buf()];
+    match registry.get(id) {
+        Some(entry) => projects.extend(entry.members.iter().cloned()),
+        None => projects.push(work_dir.to_path_buf()),
+    }
+    projects.dedup();
+    projects
+}
+
+#[cfg(test)]
+#[path = "workspace_tests.rs"]
+mod tests;
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::config::{get_db_path, init_work_dir_private};
+use tempfile::TempDir;
+
+struct Fixture {
+    temp: TempDir,
+    registry: Registry,
+    root: PathBuf,
+}
+
+/// A private project `mono` and an empty registry.
+fn fixture() -> Fixture {
+    let temp = TempDir::new().unwrap();
+    let base = temp.path().canonicalize().unwrap();
+    let root = init_work_dir_private(&base.join("mono"), "mono").unwrap();
+    let registry = Registry::load(&base.join("state")).unwrap();
+    Fixture {
+        temp,
+        registry,
+        root,
+    }
+}
+
+impl Fixture {
+    fn dir(&self, name: &str) -> PathBuf {
+        let dir = self.temp.path().canonicalize().unwrap().join(name);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn join(&mut self, name: &str, prefix: Option<&str>) -> (PathBuf, String) {
+        let dir = self.dir(name);
+        join(
+            &mut self.registry,
+            &self.root,
+            &dir,
+            prefix.map(String::from),
+        )
+        .unwrap()
+    }
+}
+
+#[test]
+fn join_gives_the_root_an_id_and_links_the_member() {
+    let mut fx = fixture();
+    let (member, id) = fx.join("app", Some("app"));
+
+    assert!(id.starts_with("ws-"));
+    let root = Config::load_file(&fx.root).unwrap();
+    assert_eq!(root.workspace_id.as_deref(), Some(id.as_str()));
+    assert_eq!(root.workspace, None);
+
+    let config = Config::load_file(&member).unwrap();
+    assert_eq!(config.workspace_id.as_deref(), Some(id.as_str()));
+    assert_eq!(config.workspace_root(&member), Some(fx.root.clone()));
+    assert_eq!(fx.registry.get(&id).unwrap().members, vec![member]);
+}
+
+#[test]
+fn members_share_the_root_database_and_mode() {
+    let mut fx = fixture();
+    let (member, _) = fx.join("app", None);
+
+    let config = Config::load(&member).unwrap();
+    assert!(config.private);
+    assert_eq!(config.prefix, "mono");
+    assert_eq!(get_db_path(&member, &config), fx.root.join("issues.db"));
+}
+
+#[test]
+fn member_prefix_overrides_the_root_prefix() {
+    let mut fx = fixture();
+    let (member, _) = fx.join("app", Some("app"));
+    assert_eq!(Config::load(&member).unwrap().prefix, "app");
+}
+
+#[test]
+fn second_member_reuses_the_id() {
+    let mut fx = fixture();
+    let (first, id) = fx.join("app", Some("app"));
+    let (second, again) = fx.join("lib", Some("lib"));
+
+    assert_eq!(id, again);
+    let projects = projects(&fx.registry, &id, &fx.root, &second);
+    assert_eq!(projects, vec![fx.root.clone(), first, second]);
+}
+
+#[test]
+fn resolve_accepts_a_path_or_an_id() {
+    let mut fx = fixture();
+    let (member, id) = fx.join("app", Some("app"));
+
+    let mono = fx.root.parent().unwrap().to_string_lossy().to_string();
+    assert_eq!(resolve(&mono, &fx.registry).unwrap(), fx.root);
+    assert_eq!(resolve(&id, &fx.registry).unwrap(), fx.root);
+
+    // A member leads to its root.
+    let app = member.parent().unwrap().to_string_lossy().to_string();
+    assert_eq!(resolve(&app, &fx.registry).unwrap(), fx.root);
+}
+
+#[test]
+fn resolve_rejects_unknown_workspaces() {
+    let fx = fixture();
+    let empty = fx.dir("empty").to_string_lossy().to_string();
+    for target in ["ws-00000000", empty.as_str()] {
+        let err = resolve(target, &fx.registry).unwrap_err();
+        assert!(matches!(err, Error::WorkspaceNotFound(_)), "{}", target);
+    }
+}
+
+#[test]
+fn missing_root_is_reported_on_load() {
+    let mut fx = fixture();
+    let (member, _) = fx.join("app", Some("app"));
+    fs::remove_dir_all(&fx.root).unwrap();
+
+    let err = Config::load(&member).unwrap_err();
+    assert!(matches!(err, Error::WorkspaceNotFound(_)));
+}
+
+#[test]
+fn registry_round_trips() {
+    let mut fx = fixture();
+    let (_, id) = fx.join("app", Some("app"));
+    fx.registry.save().unwrap();
+
+    let state = fx.temp.path().canonicalize().unwrap().join("state");
+    let loaded = Registry::load(&state).unwrap();
+    assert_eq!(loaded.get(&id), fx.registry.get(&id));
+}
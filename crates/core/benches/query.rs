@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Benchmarks for SQL query pushdown against in-memory filtering.
+//!
+//! Runs on a 100k-issue database (override with `WK_BENCH_ISSUES`). Each
+//! scenario has a `pushdown` variant using [`Database::query_issues`] and an
+//! `in_memory` variant reproducing the fetch-then-filter approach it replaces.
+
+#![allow(clippy::expect_used)]
+
+use std::collections::HashSet;
+
+use chrono::{Duration, Utc};
+use criterion::{criterion_group, criterion_main, Criterion};
+use wk_core::query::{Cmp, Cond, IssueQuery, QueryOrder};
+use wk_core::{Database, Issue, IssueType, Relation, Status};
+
+const DEFAULT_ISSUES: usize = 100_000;
+
+/// A database of `count` issues: a quarter done, a tenth in progress, every
+/// third labeled by team, every fifth with a priority, and every twentieth
+/// blocked by its predecessor.
+fn populate(count: usize) -> (tempfile::TempDir, Database) {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    let db = Database::open(&dir.path().join("issues.db")).expect("open db");
+    let start = Utc::now() - Duration::days(365);
+    let teams = ["team:api", "team:web", "team:cli"];
+
+    db.conn.execute_batch("BEGIN").expect("begin");
+    for n in 0..count {
+        let id = format!("prj-{:06x}", n);
+        let created = start + Duration::minutes(i64::try_from(n).unwrap_or(0));
+        let mut issue =
+            Issue::new(id.clone(), IssueType::Task, format!("Issue {} about auth", n), created);
+        issue.status = match n % 20 {
+            0..=4 => Status::Done,
+            5 | 6 => Status::InProgress,
+            _ => Status::Todo,
+        };
+        if n % 7 == 0 {
+            issue.assignee = Some(format!("agent-{}", n % 5));
+        }
+        db.create_issue(&issue).expect("create issue");
+        if n % 3 == 0 {
+            db.add_label(&id, teams[n % teams.len()]).expect("label");
+        }
+        if n % 5 == 0 {
+            db.add_label(&id, &format!("priority:{}", n % 4)).expect("label");
+        }
+        if n % 20 == 19 {
+            let blocker = format!("prj-{:06x}", n - 1);
+            db.add_dependency(&blocker, &id, Relation::Blocks).expect("dep");
+        }
+    }
+    db.conn.execute_batch("COMMIT").expect("commit");
+    (dir, db)
+}
+
+/// Open issues labeled `team:api` with priority <= 1, highest priority first, top 100.
+fn open_label_priority(c: &mut Criterion, db: &Database) {
+    let mut group = c.benchmark_group("open_label_priority");
+    group.sample_size(10);
+
+    group.bench_function("pushdown", |b| {
+        let query = IssueQuery::new(Cond::All(vec![
+            Cond::Status(vec![Status::Todo, Status::InProgress]),
+            Cond::Label("team:api".to_string()),
+            Cond::Priority(Cmp::Le, 1),
+        ]))
+        .with_order(QueryOrder::Priority)
+        .with_limit(Some(100));
+        b.iter(|| db.query_issues(&query).expect("query"))
+    });
+
+    group.bench_function("in_memory", |b| {
+        b.iter(|| {
+            let mut issues: Vec<Issue> = db
+                .list_issues(None, None, None)
+                .expect("list")
+                .into_iter()
+                .filter(|i| matches!(i.status, Status::Todo | Status::InProgress))
+                .collect();
+            let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+            let labels = db.get_labels_batch(&ids).expect("labels");
+            let empty = Vec::new();
+            let labels_of = |i: &Issue| labels.get(&i.id).unwrap_or(&empty);
+            issues.retain(|i| {
+                labels_of(i).iter().any(|l| l == "team:api")
+                    && Database::priority_from_tags(labels_of(i)) <= 1
+            });
+            issues.sort_by(|a, b| {
+                Database::priority_from_tags(labels_of(a))
+                    .cmp(&Database::priority_from_tags(labels_of(b)))
+                    .then_with(|| b.created_at.cmp(&a.created_at))
+            });
+            issues.truncate(100);
+            issues
+        })
+    });
+    group.finish();
+}
+
+/// Unblocked todo issues, as `wok ready` selects them.
+fn ready(c: &mut Criterion, db: &Database) {
+    let mut group = c.benchmark_group("ready");
+    group.sample_size(10);
+
+    group.bench_function("pushdown", |b| {
+        let query = IssueQuery::new(Cond::All(vec![
+            Cond::Status(vec![Status::Todo]),
+            Cond::Not(Box::new(Cond::Blocked)),
+        ]));
+        b.iter(|| db.query_issues(&query).expect("query"))
+    });
+
+    group.bench_function("in_memory", |b| {
+        b.iter(|| {
+            let blocked: HashSet<String> =
+                db.get_blocked_issue_ids().expect("blocked").into_iter().collect();
+            db.list_issues(Some(Status::Todo), None, None)
+                .expect("list")
+                .into_iter()
+                .filter(|i| !blocked.contains(&i.id))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+/// Issues assigned to one agent, newest first.
+fn assignee(c: &mut Criterion, db: &Database) {
+    let mut group = c.benchmark_group("assignee");
+    group.sample_size(10);
+
+    group.bench_function("pushdown", |b| {
+        let query = IssueQuery::new(Cond::Assignee(vec!["agent-3".to_string()]));
+        b.iter(|| db.query_issues(&query).expect("query"))
+    });
+
+    group.bench_function("in_memory", |b| {
+        b.iter(|| {
+            db.list_issues(None, None, None)
+                .expect("list")
+                .into_iter()
+                .filter(|i| i.assignee.as_deref() == Some("agent-3"))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn query_pushdown(c: &mut Criterion) {
+    let count = std::env::var("WK_BENCH_ISSUES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_ISSUES);
+    let (_dir, db) = populate(count);
+
+    open_label_priority(c, &db);
+    ready(c, &db);
+    assignee(c, &db);
+}
+
+criterion_group!(benches, query_pushdown);
+criterion_main!(benches);
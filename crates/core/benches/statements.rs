@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Benchmarks for prepared-statement caching in [`Database`].
+//!
+//! Runs on a 50k-issue database (override with `WK_BENCH_ISSUES`). Each
+//! scenario has a `cached` variant, as the code now runs, and an `uncached`
+//! variant that flushes the statement cache before every call, so each call
+//! prepares its SQL again as it did before statements were cached. The gap is
+//! largest for the small, frequent queries a long-lived connection (the
+//! daemon's) serves.
+
+#![allow(clippy::expect_used)]
+
+use chrono::{Duration, Utc};
+use criterion::{criterion_group, criterion_main, Criterion};
+use wk_core::{Database, Issue, IssueType, Status};
+
+const DEFAULT_ISSUES: usize = 50_000;
+
+/// Issues looked up per iteration in the per-issue scenarios.
+const LOOKUPS: usize = 200;
+
+/// A database of `count` issues: a quarter done, every third labeled by team,
+/// every tenth with a note.
+fn populate(count: usize) -> (tempfile::TempDir, Database) {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    let db = Database::open(&dir.path().join("issues.db")).expect("open db");
+    let start = Utc::now() - Duration::days(365);
+    let teams = ["team:api", "team:web", "team:cli"];
+
+    db.conn.execute_batch("BEGIN").expect("begin");
+    for n in 0..count {
+        let id = id(n);
+        let created = start + Duration::minutes(i64::try_from(n).unwrap_or(0));
+        let mut issue =
+            Issue::new(id.clone(), IssueType::Task, format!("Issue {} about auth", n), created);
+        if n % 4 == 0 {
+            issue.status = Status::Done;
+        }
+        db.create_issue(&issue).expect("create issue");
+        if n % 3 == 0 {
+            db.add_label(&id, teams[n % teams.len()]).expect("label");
+        }
+        if n % 10 == 0 {
+            db.add_note(&id, Status::Todo, &format!("Note on issue {}", n)).expect("note");
+        }
+    }
+    db.conn.execute_batch("COMMIT").expect("commit");
+    (dir, db)
+}
+
+fn id(n: usize) -> String {
+    format!("prj-{:06x}", n)
+}
+
+/// Register `cached` and `uncached` variants of `f` in a group named `name`.
+fn compare<T>(c: &mut Criterion, db: &Database, name: &str, f: impl Fn(&Database) -> T) {
+    let mut group = c.benchmark_group(name);
+    group.sample_size(20);
+    group.bench_function("cached", |b| b.iter(|| f(db)));
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            db.conn.flush_prepared_statement_cache();
+            f(db)
+        })
+    });
+    group.finish();
+}
+
+/// Uncached lookups flush before every call, not once per iteration.
+fn compare_each<T>(
+    c: &mut Criterion,
+    db: &Database,
+    name: &str,
+    ids: &[String],
+    f: impl Fn(&Database, &str) -> T,
+) {
+    let mut group = c.benchmark_group(name);
+    group.sample_size(20);
+    group.bench_function("cached", |b| {
+        b.iter(|| ids.iter().map(|id| f(db, id)).collect::<Vec<_>>())
+    });
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            ids.iter()
+                .map(|id| {
+                    db.conn.flush_prepared_statement_cache();
+                    f(db, id)
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn statements(c: &mut Criterion) {
+    let count = std::env::var("WK_BENCH_ISSUES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_ISSUES);
+    let (_dir, db) = populate(count);
+    let step = (count / LOOKUPS).max(1);
+    let ids: Vec<String> = (0..count).step_by(step).take(LOOKUPS).map(id).collect();
+    let refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+    // `wok show`: the issue, its labels and its notes.
+    compare_each(c, &db, "show", &ids, |db, id| {
+        (
+            db.get_issue(id).expect("issue"),
+            db.get_labels(id).expect("labels"),
+            db.get_notes(id).expect("notes"),
+        )
+    });
+    // `wok list -l team:api -s done`: a selective list.
+    compare(c, &db, "list", |db| {
+        db.list_issues(Some(Status::Done), None, Some("team:api")).expect("list")
+    });
+    // `wok search`: a term matching a single issue.
+    let needle = format!("Issue {} about", count / 2);
+    compare(c, &db, "search", |db| db.search_issues(&needle).expect("search"));
+    // Labels for a page of issues, as `wok list` fetches them.
+    compare(c, &db, "labels_batch", |db| db.get_labels_batch(&refs).expect("labels"));
+}
+
+criterion_group!(benches, statements);
+criterion_main!(benches);
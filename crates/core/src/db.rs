@@ -7,17 +7,33 @@
 //! events, notes, tags, and dependencies.
 
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection, OptionalExtension};
+use rusqlite::types::Value;
+use rusqlite::vtab::array::Array;
+use rusqlite::{
+    params, Connection, OpenFlags, OptionalExtension, Params, Transaction, TransactionBehavior,
+};
 use std::path::Path;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 use crate::error::{Error, Result};
 use crate::hlc::Hlc;
-use crate::issue::{Dependency, Event, Issue, IssueType, Note, Relation, Status};
+use crate::hook_run::HookRun;
+use crate::issue::{Action, Dependency, Event, Issue, IssueType, Note, NoteKind, Relation, Status};
+use crate::label::LabelMeta;
 use crate::link::{Link, LinkRel, LinkType, PrefixInfo};
+use crate::link_meta::LinkMeta;
+use crate::query::IssueQuery;
+use crate::retry::{retry_busy, retry_busy_wrapped};
+
+pub use crate::migrate::{pending_migrations, run_migrations};
 
 /// SQL schema for the issue tracker database.
+///
+/// This is the baseline that migration 1 creates; later schema changes are
+/// migrations in [`crate::migrate`].
 pub const SCHEMA: &str = r#"
 -- Core issue table with HLC columns for conflict resolution
 CREATE TABLE IF NOT EXISTS issues (
@@ -64,6 +80,7 @@ CREATE TABLE IF NOT EXISTS notes (
     status TEXT NOT NULL,
     content TEXT NOT NULL,
     created_at TEXT NOT NULL,
+    kind TEXT,                   -- decision|blocker|worklog|handoff|NULL
     FOREIGN KEY (issue_id) REFERENCES issues(id)
 );
 
@@ -83,7 +100,7 @@ CREATE TABLE IF NOT EXISTS events (
 CREATE TABLE IF NOT EXISTS links (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     issue_id TEXT NOT NULL,
-    link_type TEXT,              -- github|jira|gitlab|confluence|NULL
+    link_type TEXT,              -- github|jira|gitlab|confluence|<provider>|NULL
     url TEXT,                    -- full URL (may be NULL for shorthand)
     external_id TEXT,            -- external issue ID (e.g., "PE-5555")
     rel TEXT,                    -- import|blocks|tracks|tracked-by|NULL
@@ -98,15 +115,38 @@ CREATE TABLE IF NOT EXISTS prefixes (
     issue_count INTEGER NOT NULL DEFAULT 0
 );
 
+-- Short per-prefix aliases (prj-12) for issue IDs
+CREATE TABLE IF NOT EXISTS short_ids (
+    issue_id TEXT PRIMARY KEY,
+    prefix TEXT NOT NULL,
+    num INTEGER NOT NULL,
+    UNIQUE (prefix, num)
+);
+
+-- Issue hook execution log
+CREATE TABLE IF NOT EXISTS hook_runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    hook_name TEXT NOT NULL,
+    event TEXT NOT NULL,
+    issue_id TEXT NOT NULL,
+    exit_code INTEGER,
+    duration_ms INTEGER NOT NULL,
+    stderr_tail TEXT,
+    error TEXT,
+    created_at TEXT NOT NULL
+);
+
 -- Indexes
 CREATE INDEX IF NOT EXISTS idx_issues_status ON issues(status);
 CREATE INDEX IF NOT EXISTS idx_issues_type ON issues(type);
 CREATE INDEX IF NOT EXISTS idx_deps_to ON deps(to_id);
+CREATE INDEX IF NOT EXISTS idx_deps_to_rel ON deps(to_id, rel);
 CREATE INDEX IF NOT EXISTS idx_deps_rel ON deps(rel);
 CREATE INDEX IF NOT EXISTS idx_labels_label ON labels(label);
 CREATE INDEX IF NOT EXISTS idx_events_issue ON events(issue_id);
 CREATE INDEX IF NOT EXISTS idx_links_issue ON links(issue_id);
 CREATE INDEX IF NOT EXISTS idx_prefixes_count ON prefixes(issue_count DESC);
+CREATE INDEX IF NOT EXISTS idx_hook_runs_created ON hook_runs(created_at);
 "#;
 
 /// Parse a string value from the database, returning a rusqlite error on parse failure.
@@ -157,7 +197,7 @@ fn parse_hlc_opt(value: Option<String>) -> std::result::Result<Option<Hlc>, rusq
 ///
 /// Expected columns: id, type, title, description, status, assignee,
 /// created_at, updated_at, closed_at, last_status_hlc, last_title_hlc,
-/// last_type_hlc, last_description_hlc, last_assignee_hlc
+/// last_type_hlc, last_description_hlc, last_assignee_hlc, revision
 fn row_to_issue(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
     let type_str: String = row.get(1)?;
     let status_str: String = row.get(4)?;
@@ -185,12 +225,13 @@ fn row_to_issue(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
         last_type_hlc: parse_hlc_opt(type_hlc)?,
         last_description_hlc: parse_hlc_opt(desc_hlc)?,
         last_assignee_hlc: parse_hlc_opt(assignee_hlc)?,
+        revision: row.get(14)?,
     })
 }
 
 /// Map a row to an Event.
 ///
-/// Expected columns: id, issue_id, action, old_value, new_value, reason, created_at
+/// Expected columns: id, issue_id, action, old_value, new_value, reason, created_at, actor
 fn row_to_event(row: &rusqlite::Row) -> rusqlite::Result<Event> {
     let action_str: String = row.get(2)?;
     let created_str: String = row.get(6)?;
@@ -201,25 +242,44 @@ fn row_to_event(row: &rusqlite::Row) -> rusqlite::Result<Event> {
         old_value: row.get(3)?,
         new_value: row.get(4)?,
         reason: row.get(5)?,
+        actor: row.get(7)?,
         created_at: parse_timestamp(&created_str, "created_at")?,
     })
 }
 
 /// Map a row to a Note.
 ///
-/// Expected columns: id, issue_id, status, content, created_at
+/// Expected columns: id, issue_id, status, content, created_at, kind, actor
 fn row_to_note(row: &rusqlite::Row) -> rusqlite::Result<Note> {
     let status_str: String = row.get(2)?;
     let created_str: String = row.get(4)?;
+    let kind_str: Option<String> = row.get(5)?;
     Ok(Note {
         id: row.get(0)?,
         issue_id: row.get(1)?,
         status: parse_db(&status_str, "status")?,
+        kind: kind_str.map(|s| parse_db::<NoteKind>(&s, "kind")).transpose()?,
         content: row.get(3)?,
+        actor: row.get(6)?,
         created_at: parse_timestamp(&created_str, "created_at")?,
     })
 }
 
+/// Group notes by status, preserving first-occurrence order.
+pub fn group_notes_by_status(notes: Vec<Note>) -> Vec<(Status, Vec<Note>)> {
+    let mut grouped: Vec<(Status, Vec<Note>)> = Vec::new();
+
+    for note in notes {
+        if let Some((_, notes_vec)) = grouped.iter_mut().find(|(s, _)| *s == note.status) {
+            notes_vec.push(note);
+        } else {
+            grouped.push((note.status, vec![note]));
+        }
+    }
+
+    grouped
+}
+
 /// Map a row to a Dependency.
 ///
 /// Expected columns: from_id, to_id, rel, created_at
@@ -236,13 +296,15 @@ fn row_to_dependency(row: &rusqlite::Row) -> rusqlite::Result<Dependency> {
 
 /// Map a row to a Link.
 ///
-/// Expected columns: id, issue_id, link_type, url, external_id, rel, created_at
+/// Expected columns: id, issue_id, link_type, url, external_id, rel, created_at,
+/// resolved_at
 fn row_to_link(row: &rusqlite::Row) -> rusqlite::Result<Link> {
     let link_type_str: Option<String> = row.get(2)?;
-    let link_type = link_type_str.map(|s| parse_db::<LinkType>(&s, "link_type")).transpose()?;
+    let link_type = link_type_str.map(|s| LinkType::from_name(&s));
     let rel_str: Option<String> = row.get(5)?;
     let rel = rel_str.map(|s| parse_db::<LinkRel>(&s, "rel")).transpose()?;
     let created_at_str: String = row.get(6)?;
+    let resolved_at_str: Option<String> = row.get(7)?;
     Ok(Link {
         id: row.get(0)?,
         issue_id: row.get(1)?,
@@ -251,142 +313,116 @@ fn row_to_link(row: &rusqlite::Row) -> rusqlite::Result<Link> {
         external_id: row.get(4)?,
         rel,
         created_at: parse_timestamp(&created_at_str, "created_at")?,
+        resolved_at: resolved_at_str.map(|s| parse_timestamp(&s, "resolved_at")).transpose()?,
     })
 }
 
-/// Run schema creation and all migrations on a database connection.
-///
-/// This is the single migration path for all crates (core, CLI, daemon).
-/// It applies the canonical schema and runs idempotent migrations to upgrade
-/// older databases that may be missing columns or data.
-pub fn run_migrations(conn: &Connection) -> Result<()> {
-    conn.execute_batch(SCHEMA)?;
-    migrate_add_assignee(conn)?;
-    migrate_add_hlc_columns(conn)?;
-    migrate_add_closed_at(conn)?;
-    migrate_backfill_prefixes(conn)?;
-    migrate_tracked_by_relation(conn)?;
-    Ok(())
+fn row_to_hook_run(row: &rusqlite::Row) -> rusqlite::Result<HookRun> {
+    let created_at_str: String = row.get(8)?;
+    Ok(HookRun {
+        id: row.get(0)?,
+        hook_name: row.get(1)?,
+        event: row.get(2)?,
+        issue_id: row.get(3)?,
+        exit_code: row.get(4)?,
+        duration_ms: row.get(5)?,
+        stderr_tail: row.get(6)?,
+        error: row.get(7)?,
+        created_at: parse_timestamp(&created_at_str, "created_at")?,
+    })
 }
 
-/// Migration: Add assignee column to existing databases.
-fn migrate_add_assignee(conn: &Connection) -> Result<()> {
-    let has_assignee: bool = conn
-        .query_row(
-            "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = 'assignee'",
-            [],
-            |row| row.get(0),
-        )
-        .unwrap_or(false);
-
-    if !has_assignee {
-        conn.execute("ALTER TABLE issues ADD COLUMN assignee TEXT", [])?;
-    }
-    Ok(())
+/// The prefix of an issue ID: `prj-a1b2-2` -> `prj`.
+fn id_prefix(id: &str) -> Option<&str> {
+    id.split_once('-').map(|(prefix, _)| prefix).filter(|p| !p.is_empty())
 }
 
-/// Migration: Add HLC columns for CRDT sync compatibility.
+/// Record a short ID for an issue, returning its number.
 ///
-/// Adds all HLC (Hybrid Logical Clock) columns used for conflict resolution
-/// during sync. Older databases may be missing some or all of these.
-fn migrate_add_hlc_columns(conn: &Connection) -> Result<()> {
-    let columns = [
-        "last_status_hlc",
-        "last_title_hlc",
-        "last_type_hlc",
-        "last_description_hlc",
-        "last_assignee_hlc",
-    ];
-
-    for column in columns {
-        let has_column: bool = conn
-            .query_row(
-                "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = ?1",
-                [column],
-                |row| row.get(0),
-            )
-            .unwrap_or(false);
-
-        if !has_column {
-            let sql = format!("ALTER TABLE issues ADD COLUMN {column} TEXT");
-            conn.execute(&sql, [])?;
+/// `wanted` is honoured when that number is free for the prefix (e.g. when a
+/// sync op carries the number its creator allocated); otherwise the next
+/// number after the prefix's highest is used. Issues without a prefix get none.
+pub(crate) fn allocate_short_id(
+    conn: &Connection,
+    issue_id: &str,
+    wanted: Option<u32>,
+) -> Result<Option<u32>> {
+    let Some(prefix) = id_prefix(issue_id) else {
+        return Ok(None);
+    };
+    if let Some(num) = wanted {
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO short_ids (issue_id, prefix, num) VALUES (?1, ?2, ?3)",
+            params![issue_id, prefix, num],
+        )?;
+        if inserted > 0 {
+            return Ok(Some(num));
         }
     }
-
-    Ok(())
-}
-
-/// Migration: Add closed_at column and backfill from events.
-///
-/// Stores the timestamp when an issue was closed (done/closed status) directly
-/// on the issues table, replacing the correlated subquery that computed it.
-fn migrate_add_closed_at(conn: &Connection) -> Result<()> {
-    let has_col: bool = conn.query_row(
-        "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = 'closed_at'",
-        [],
+    conn.execute(
+        "INSERT OR IGNORE INTO short_ids (issue_id, prefix, num)
+         SELECT ?1, ?2, COALESCE(MAX(num), 0) + 1 FROM short_ids WHERE prefix = ?2",
+        params![issue_id, prefix],
+    )?;
+    let num = conn.query_row(
+        "SELECT num FROM short_ids WHERE issue_id = ?1",
+        params![issue_id],
         |row| row.get(0),
     )?;
+    Ok(Some(num))
+}
 
-    if !has_col {
-        conn.execute("ALTER TABLE issues ADD COLUMN closed_at TEXT", [])?;
-
-        // Backfill closed_at from events table
-        conn.execute(
-            "UPDATE issues SET closed_at = (
-                SELECT MAX(e.created_at) FROM events e
-                WHERE e.issue_id = issues.id AND e.action IN ('done', 'closed')
-                AND NOT EXISTS (
-                    SELECT 1 FROM events e2
-                    WHERE e2.issue_id = e.issue_id
-                    AND e2.action = 'reopened'
-                    AND e2.created_at > e.created_at
-                )
-            ) WHERE status IN ('done', 'closed')",
-            [],
-        )?;
-    }
+/// Prepared statements kept per connection. Every statement this file runs
+/// with a fixed SQL string fits, so a long-lived connection (the daemon's)
+/// prepares each one once.
+const STATEMENT_CACHE_CAPACITY: usize = 128;
 
+/// Set up what every connection needs before use: the statement cache, and
+/// the `rarray()` table function that batch lookups bind their IDs to.
+fn prepare_connection(conn: &Connection) -> Result<()> {
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+    rusqlite::vtab::array::load_module(conn)?;
     Ok(())
 }
 
-/// Migration: Backfill prefixes table from existing issues.
-///
-/// Extracts prefixes from issue IDs and populates the prefixes table
-/// with correct issue counts. Only runs if the table is empty but
-/// issues exist.
-fn migrate_backfill_prefixes(conn: &Connection) -> Result<()> {
-    let prefix_count: i64 =
-        conn.query_row("SELECT COUNT(*) FROM prefixes", [], |row| row.get(0)).unwrap_or(0);
-
-    if prefix_count == 0 {
-        conn.execute(
-            "INSERT OR IGNORE INTO prefixes (prefix, created_at, issue_count)
-             SELECT
-                 substr(id, 1, instr(id, '-') - 1) as prefix,
-                 MIN(created_at) as created_at,
-                 COUNT(*) as issue_count
-             FROM issues
-             WHERE id LIKE '%-%'
-             GROUP BY prefix",
-            [],
-        )?;
-    }
-    Ok(())
+/// Bind `ids` as one `rarray(?)` parameter, however many there are.
+fn id_array(ids: &[&str]) -> Array {
+    Rc::new(ids.iter().map(|id| Value::from((*id).to_string())).collect())
 }
 
-/// Migration: Rewrite "tracked_by" to "tracked-by" in deps table.
+/// A prepared query whose rows are read one at a time.
 ///
-/// Early versions serialized TrackedBy as "tracked_by" (underscore).
-/// The canonical form is "tracked-by" (kebab-case).
-fn migrate_tracked_by_relation(conn: &Connection) -> Result<()> {
-    conn.execute("UPDATE deps SET rel = 'tracked-by' WHERE rel = 'tracked_by'", [])?;
-    Ok(())
+/// Returned by [`Database::stream_issues`] and [`Database::stream_events`]
+/// for output that should not hold every row in memory:
+///
+/// ```ignore
+/// let mut rows = db.stream_issues(&query)?;
+/// for issue in rows.iter()? {
+///     println!("{}", issue?.id);
+/// }
+/// ```
+pub struct RowStream<'conn, T> {
+    stmt: rusqlite::Statement<'conn>,
+    params: Vec<rusqlite::types::Value>,
+    map: fn(&rusqlite::Row) -> rusqlite::Result<T>,
+}
+
+impl<T> RowStream<'_, T> {
+    /// Run the query, yielding rows as SQLite produces them.
+    pub fn iter(&mut self) -> Result<impl Iterator<Item = Result<T>> + '_> {
+        let rows = self.stmt.query_map(rusqlite::params_from_iter(self.params.iter()), self.map)?;
+        Ok(rows.map(|row| row.map_err(Error::from)))
+    }
 }
 
 /// SQLite database connection with issue tracker operations.
 pub struct Database {
     /// The underlying SQLite connection.
     pub conn: Connection,
+    /// Who events and notes are recorded as when they name no one.
+    pub(crate) actor: Option<String>,
+    /// The issue and revision the next change to that issue must match.
+    pub(crate) expected: RefCell<Option<(String, u32)>>,
 }
 
 impl Database {
@@ -401,30 +437,148 @@ impl Database {
 
         let conn = Connection::open(path)?;
 
-        // Enable foreign keys and WAL mode for concurrency
+        // Enable foreign keys and WAL mode for concurrency. Switching to WAL
+        // and migrating fail at once, without waiting, when another process
+        // holds the database, so both are retried.
+        conn.execute_batch("PRAGMA busy_timeout = 5000;")?;
+        prepare_connection(&conn)?;
+        retry_busy(|| {
+            conn.execute_batch(
+                "PRAGMA foreign_keys = ON;
+                 PRAGMA journal_mode = WAL;",
+            )?;
+            run_migrations(&conn)
+        })?;
+
+        Ok(Database { conn, actor: None, expected: RefCell::default() })
+    }
+
+    /// Open an existing database for reading only.
+    ///
+    /// Nothing is created or migrated, and any write fails with
+    /// `SQLITE_READONLY`. Readers never wait on writers under WAL.
+    pub fn open_read_only(path: &Path) -> Result<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
         conn.execute_batch(
-            "PRAGMA foreign_keys = ON;
-             PRAGMA journal_mode = WAL;
-             PRAGMA busy_timeout = 5000;",
+            "PRAGMA busy_timeout = 5000;
+             PRAGMA query_only = ON;",
         )?;
+        prepare_connection(&conn)?;
+        Ok(Database { conn, actor: None, expected: RefCell::default() })
+    }
 
-        let db = Database { conn };
-        run_migrations(&db.conn)?;
-        Ok(db)
+    /// Record events and notes as `actor` from now on, unless an event
+    /// names its own.
+    pub fn set_actor(&mut self, actor: Option<String>) {
+        self.actor = actor;
+    }
+
+    /// Who events and notes are recorded as.
+    pub fn actor(&self) -> Option<&str> {
+        self.actor.as_deref()
+    }
+
+    /// Require issue `id` to be at `revision`.
+    ///
+    /// Fails with [`Error::Conflict`] at once if it is not. Otherwise the
+    /// next change to the issue checks the revision again in the same
+    /// statement, so a writer that gets there first makes it fail the same
+    /// way instead of being silently overwritten.
+    pub fn expect_revision(&self, id: &str, revision: u32) -> Result<()> {
+        let actual = self.get_revision(id)?;
+        if actual != revision {
+            return Err(Error::Conflict { id: id.to_string(), expected: revision, actual });
+        }
+        *self.expected.borrow_mut() = Some((id.to_string(), revision));
+        Ok(())
+    }
+
+    /// Drop an expectation set by [`Database::expect_revision`] that no
+    /// change has used.
+    pub fn clear_expected_revision(&self) {
+        self.expected.borrow_mut().take();
+    }
+
+    /// The current revision of issue `id`.
+    pub fn get_revision(&self, id: &str) -> Result<u32> {
+        self.query_row("SELECT revision FROM issues WHERE id = ?1", params![id], |row| row.get(0))
+            .optional()?
+            .ok_or_else(|| Error::IssueNotFound(id.to_string()))
+    }
+
+    /// Take the revision the next change to `id` must match, if any.
+    fn take_expected(&self, id: &str) -> Option<u32> {
+        let mut expected = self.expected.borrow_mut();
+        match expected.as_ref() {
+            Some((expected_id, _)) if expected_id == id => expected.take().map(|(_, rev)| rev),
+            _ => None,
+        }
+    }
+
+    /// The error for a change to `id`, guarded by `expected`, that matched
+    /// no row: a conflict when the issue exists, else not found.
+    fn missed(&self, id: &str, expected: Option<u32>) -> Error {
+        match (expected, self.get_revision(id)) {
+            (Some(expected), Ok(actual)) => {
+                Error::Conflict { id: id.to_string(), expected, actual }
+            }
+            (_, Err(e)) => e,
+            (None, Ok(_)) => Error::IssueNotFound(id.to_string()),
+        }
+    }
+
+    /// Count a change to the labels of `id`, checking its expected revision.
+    fn bump_revision(&self, id: &str) -> Result<()> {
+        let expected = self.take_expected(id);
+        let affected = self.execute(
+            "UPDATE issues SET revision = revision + 1
+             WHERE id = ?1 AND revision = COALESCE(?2, revision)",
+            params![id, expected],
+        )?;
+        if affected == 0 && expected.is_some() {
+            return Err(self.missed(id, expected));
+        }
+        Ok(())
+    }
+
+    /// Run one write statement, retrying while the database is busy.
+    fn execute<P: Params + Clone>(&self, sql: &str, params: P) -> Result<usize> {
+        retry_busy(|| Ok(self.conn.prepare_cached(sql)?.execute(params.clone())?))
+    }
+
+    /// Read one row with a cached statement.
+    fn query_row<T, P: Params>(
+        &self,
+        sql: &str,
+        params: P,
+        f: impl FnOnce(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+    ) -> rusqlite::Result<T> {
+        self.conn.prepare_cached(sql)?.query_row(params, f)
     }
 
     /// Open an in-memory database (for testing).
     pub fn open_in_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
         conn.execute_batch("PRAGMA foreign_keys = ON;")?;
-        let db = Database { conn };
+        prepare_connection(&conn)?;
+        let db = Database { conn, actor: None, expected: RefCell::default() };
         run_migrations(&db.conn)?;
         Ok(db)
     }
 
     /// Create a new issue.
     pub fn create_issue(&self, issue: &Issue) -> Result<()> {
-        self.conn.execute(
+        self.create_issue_with_short_id(issue, None)
+    }
+
+    /// Insert a new issue, preferring a specific short ID number if it is free.
+    ///
+    /// Used when merging a create op that carries its creator's short ID.
+    pub fn create_issue_with_short_id(&self, issue: &Issue, short_id: Option<u32>) -> Result<()> {
+        self.execute(
             "INSERT INTO issues (id, type, title, description, status, assignee,
              created_at, updated_at, closed_at, last_status_hlc, last_title_hlc,
              last_type_hlc, last_description_hlc, last_assignee_hlc)
@@ -446,18 +600,18 @@ impl Database {
                 issue.last_assignee_hlc.map(|h| h.to_string()),
             ],
         )?;
+        allocate_short_id(&self.conn, &issue.id, short_id)?;
         Ok(())
     }
 
     /// Get an issue by ID.
     pub fn get_issue(&self, id: &str) -> Result<Issue> {
         let issue = self
-            .conn
             .query_row(
                 "SELECT id, type, title, description, status, assignee,
                         created_at, updated_at, closed_at, last_status_hlc,
                         last_title_hlc, last_type_hlc, last_description_hlc,
-                        last_assignee_hlc
+                        last_assignee_hlc, revision
                  FROM issues WHERE id = ?1",
                 params![id],
                 row_to_issue,
@@ -469,11 +623,10 @@ impl Database {
 
     /// Check if an issue exists.
     pub fn issue_exists(&self, id: &str) -> Result<bool> {
-        let count: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM issues WHERE id = ?1",
-            params![id],
-            |row| row.get(0),
-        )?;
+        let count: i64 =
+            self.query_row("SELECT COUNT(*) FROM issues WHERE id = ?1", params![id], |row| {
+                row.get(0)
+            })?;
         Ok(count > 0)
     }
 
@@ -482,23 +635,25 @@ impl Database {
     /// Sets `closed_at` to now when transitioning to a terminal state (done/closed),
     /// and clears it when transitioning to an active state (todo/in_progress).
     pub fn update_issue_status(&self, id: &str, status: Status) -> Result<()> {
+        let expected = self.take_expected(id);
         let now = Utc::now();
         let closed_at = if status.is_terminal() { Some(now.to_rfc3339()) } else { None };
 
-        let affected = self.conn.execute(
-            "UPDATE issues SET status = ?1, updated_at = ?2, closed_at = ?3 WHERE id = ?4",
-            params![status.as_str(), now.to_rfc3339(), closed_at, id],
+        let affected = self.execute(
+            "UPDATE issues SET status = ?1, updated_at = ?2, closed_at = ?3, revision = revision + 1
+             WHERE id = ?4 AND revision = COALESCE(?5, revision)",
+            params![status.as_str(), now.to_rfc3339(), closed_at, id, expected],
         )?;
 
         if affected == 0 {
-            return Err(Error::IssueNotFound(id.to_string()));
+            return Err(self.missed(id, expected));
         }
         Ok(())
     }
 
     /// Update issue status HLC.
     pub fn update_issue_status_hlc(&self, id: &str, hlc: Hlc) -> Result<()> {
-        self.conn.execute(
+        self.execute(
             "UPDATE issues SET last_status_hlc = ?1 WHERE id = ?2",
             params![hlc.to_string(), id],
         )?;
@@ -507,20 +662,22 @@ impl Database {
 
     /// Update issue title.
     pub fn update_issue_title(&self, id: &str, title: &str) -> Result<()> {
-        let affected = self.conn.execute(
-            "UPDATE issues SET title = ?1, updated_at = ?2 WHERE id = ?3",
-            params![title, Utc::now().to_rfc3339(), id],
+        let expected = self.take_expected(id);
+        let affected = self.execute(
+            "UPDATE issues SET title = ?1, updated_at = ?2, revision = revision + 1
+             WHERE id = ?3 AND revision = COALESCE(?4, revision)",
+            params![title, Utc::now().to_rfc3339(), id, expected],
         )?;
 
         if affected == 0 {
-            return Err(Error::IssueNotFound(id.to_string()));
+            return Err(self.missed(id, expected));
         }
         Ok(())
     }
 
     /// Update issue title HLC.
     pub fn update_issue_title_hlc(&self, id: &str, hlc: Hlc) -> Result<()> {
-        self.conn.execute(
+        self.execute(
             "UPDATE issues SET last_title_hlc = ?1 WHERE id = ?2",
             params![hlc.to_string(), id],
         )?;
@@ -529,20 +686,22 @@ impl Database {
 
     /// Update issue type.
     pub fn update_issue_type(&self, id: &str, issue_type: IssueType) -> Result<()> {
-        let affected = self.conn.execute(
-            "UPDATE issues SET type = ?1, updated_at = ?2 WHERE id = ?3",
-            params![issue_type.as_str(), Utc::now().to_rfc3339(), id],
+        let expected = self.take_expected(id);
+        let affected = self.execute(
+            "UPDATE issues SET type = ?1, updated_at = ?2, revision = revision + 1
+             WHERE id = ?3 AND revision = COALESCE(?4, revision)",
+            params![issue_type.as_str(), Utc::now().to_rfc3339(), id, expected],
         )?;
 
         if affected == 0 {
-            return Err(Error::IssueNotFound(id.to_string()));
+            return Err(self.missed(id, expected));
         }
         Ok(())
     }
 
     /// Update issue type HLC.
     pub fn update_issue_type_hlc(&self, id: &str, hlc: Hlc) -> Result<()> {
-        self.conn.execute(
+        self.execute(
             "UPDATE issues SET last_type_hlc = ?1 WHERE id = ?2",
             params![hlc.to_string(), id],
         )?;
@@ -559,7 +718,7 @@ impl Database {
         let mut sql = String::from(
             "SELECT DISTINCT i.id, i.type, i.title, i.description, i.status, i.assignee,
              i.created_at, i.updated_at, i.closed_at, i.last_status_hlc, i.last_title_hlc,
-             i.last_type_hlc, i.last_description_hlc, i.last_assignee_hlc
+             i.last_type_hlc, i.last_description_hlc, i.last_assignee_hlc, i.revision
              FROM issues i",
         );
 
@@ -592,7 +751,7 @@ impl Database {
 
         sql.push_str(" ORDER BY i.created_at DESC");
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let mut stmt = self.conn.prepare_cached(&sql)?;
 
         let params_refs: Vec<&dyn rusqlite::ToSql> =
             params_vec.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
@@ -604,19 +763,72 @@ impl Database {
         Ok(issues)
     }
 
-    /// Get IDs of blocked issues (issues with at least one open blocker).
+    /// Run a filtered, ordered query in a single SQL statement.
+    pub fn query_issues(&self, query: &IssueQuery) -> Result<Vec<Issue>> {
+        let (sql, params) = query.to_sql();
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let issues = stmt
+            .query_map(rusqlite::params_from_iter(params), row_to_issue)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(issues)
+    }
+
+    /// Prepare `query` for reading one issue at a time; see [`RowStream`].
+    pub fn stream_issues(&self, query: &IssueQuery) -> Result<RowStream<'_, Issue>> {
+        let (sql, params) = query.to_sql();
+        Ok(RowStream { stmt: self.conn.prepare(&sql)?, params, map: row_to_issue })
+    }
+
+    /// Prepare a newest-first read of events, for one issue or all of them,
+    /// optionally only those by `actor` and limited; see [`RowStream`].
+    pub fn stream_events(
+        &self,
+        issue_id: Option<&str>,
+        actor: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<RowStream<'_, Event>> {
+        let mut sql = "SELECT id, issue_id, action, old_value, new_value, reason, created_at, actor
+             FROM events"
+            .to_string();
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(issue_id) = issue_id {
+            conditions.push("issue_id = ?");
+            params.push(rusqlite::types::Value::Text(issue_id.to_string()));
+        }
+        if let Some(actor) = actor {
+            conditions.push("actor = ?");
+            params.push(rusqlite::types::Value::Text(actor.to_string()));
+        }
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(" ORDER BY created_at DESC, id DESC");
+        if let Some(limit) = limit {
+            sql.push_str(" LIMIT ?");
+            params.push(rusqlite::types::Value::Integer(i64::try_from(limit).unwrap_or(i64::MAX)));
+        }
+        Ok(RowStream { stmt: self.conn.prepare(&sql)?, params, map: row_to_event })
+    }
+
+    /// Get IDs of blocked issues: issues with at least one open blocker, or
+    /// with a `blocks` link to an external issue not yet resolved.
     pub fn get_blocked_issue_ids(&self) -> Result<Vec<String>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "WITH RECURSIVE all_blockers(issue_id, blocker_id) AS (
                 SELECT to_id, from_id FROM deps WHERE rel = 'blocks'
                 UNION
                 SELECT ab.issue_id, d.from_id
                 FROM all_blockers ab
-                JOIN deps d ON d.to_id = ab.blocker_id AND d.rel = 'blocks'
+                CROSS JOIN deps d ON d.to_id = ab.blocker_id AND d.rel = 'blocks'
             )
-            SELECT DISTINCT issue_id FROM all_blockers ab
-            JOIN issues i ON i.id = ab.blocker_id
-            WHERE i.status IN ('todo', 'in_progress')",
+            SELECT issue_id FROM all_blockers ab
+            CROSS JOIN issues i ON i.id = ab.blocker_id
+            WHERE i.status IN ('todo', 'in_progress')
+            UNION
+            SELECT issue_id FROM links
+            WHERE rel = 'blocks' AND resolved_at IS NULL AND deleted_at IS NULL",
         )?;
 
         let ids = stmt
@@ -626,16 +838,90 @@ impl Database {
         Ok(ids)
     }
 
+    /// Count the open issues each issue blocks, directly or transitively.
+    ///
+    /// Issues that block nothing open are omitted.
+    pub fn get_downstream_counts(&self) -> Result<HashMap<String, usize>> {
+        let mut stmt = self.conn.prepare_cached(
+            "WITH RECURSIVE downstream(root, issue_id) AS (
+                SELECT from_id, to_id FROM deps WHERE rel = 'blocks'
+                UNION
+                SELECT ds.root, d.to_id
+                FROM downstream ds
+                JOIN deps d ON d.from_id = ds.issue_id AND d.rel = 'blocks'
+            )
+            SELECT ds.root, COUNT(DISTINCT ds.issue_id) FROM downstream ds
+            JOIN issues i ON i.id = ds.issue_id
+            WHERE i.status IN ('todo', 'in_progress')
+            GROUP BY ds.root",
+        )?;
+        let rows =
+            stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?;
+
+        let mut counts = HashMap::new();
+        for row in rows {
+            let (id, count) = row?;
+            counts.insert(id, usize::try_from(count).unwrap_or(0));
+        }
+        Ok(counts)
+    }
+
     /// Get all issues.
     pub fn get_all_issues(&self) -> Result<Vec<Issue>> {
         self.list_issues(None, None, None)
     }
 
-    /// Log an event.
+    /// Apply `change` and log `event` for it in one transaction, so the
+    /// change is never stored without its event or the event without it.
+    pub fn record<T, E>(
+        &self,
+        event: &Event,
+        mut change: impl FnMut() -> std::result::Result<T, E>,
+    ) -> std::result::Result<T, E>
+    where
+        E: From<Error> + std::error::Error + 'static,
+    {
+        self.atomically(|| {
+            let value = change()?;
+            self.log_event(event)?;
+            Ok(value)
+        })
+    }
+
+    /// Run `change` in a transaction of its own, or as part of the one in
+    /// progress; a failure leaves the database as it was.
+    ///
+    /// The transaction takes the write lock up front, and if another
+    /// connection holds the database all of `change` is retried, since a
+    /// transaction that read a stale snapshot cannot retry one statement.
+    pub fn atomically<T, E>(
+        &self,
+        mut change: impl FnMut() -> std::result::Result<T, E>,
+    ) -> std::result::Result<T, E>
+    where
+        E: From<Error> + std::error::Error + 'static,
+    {
+        if !self.conn.is_autocommit() {
+            return change();
+        }
+        retry_busy_wrapped(|| {
+            let tx = self.write_transaction()?;
+            let value = change()?;
+            tx.commit().map_err(Error::from)?;
+            Ok(value)
+        })
+    }
+
+    /// Begin a transaction holding the write lock.
+    fn write_transaction(&self) -> Result<Transaction<'_>> {
+        Ok(Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?)
+    }
+
+    /// Log an event, recorded as the database's actor unless it names one.
     pub fn log_event(&self, event: &Event) -> Result<i64> {
-        self.conn.execute(
-            "INSERT INTO events (issue_id, action, old_value, new_value, reason, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        self.execute(
+            "INSERT INTO events (issue_id, action, old_value, new_value, reason, created_at, actor)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             params![
                 event.issue_id,
                 event.action.as_str(),
@@ -643,6 +929,7 @@ impl Database {
                 event.new_value,
                 event.reason,
                 event.created_at.to_rfc3339(),
+                event.actor.as_deref().or(self.actor()),
             ],
         )?;
         Ok(self.conn.last_insert_rowid())
@@ -650,8 +937,8 @@ impl Database {
 
     /// Get all events for an issue, ordered by creation time.
     pub fn get_events(&self, issue_id: &str) -> Result<Vec<Event>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, issue_id, action, old_value, new_value, reason, created_at
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, action, old_value, new_value, reason, created_at, actor
              FROM events WHERE issue_id = ?1 ORDER BY created_at",
         )?;
 
@@ -664,8 +951,8 @@ impl Database {
 
     /// Get recent events across all issues.
     pub fn get_recent_events(&self, limit: usize) -> Result<Vec<Event>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, issue_id, action, old_value, new_value, reason, created_at
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, action, old_value, new_value, reason, created_at, actor
              FROM events ORDER BY created_at DESC LIMIT ?1",
         )?;
 
@@ -677,21 +964,112 @@ impl Database {
         Ok(events)
     }
 
+    /// Get events across all issues at or after `since`, oldest first.
+    pub fn get_events_since(&self, since: DateTime<Utc>) -> Result<Vec<Event>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, action, old_value, new_value, reason, created_at, actor
+             FROM events WHERE created_at >= ?1 ORDER BY created_at, id",
+        )?;
+
+        let events = stmt
+            .query_map(params![since.to_rfc3339()], row_to_event)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(events)
+    }
+
+    /// Get events logged after the event with ID `after_id`, in the order
+    /// they were logged.
+    pub fn get_events_after(&self, after_id: i64) -> Result<Vec<Event>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, action, old_value, new_value, reason, created_at, actor
+             FROM events WHERE id > ?1 ORDER BY id",
+        )?;
+
+        let events = stmt
+            .query_map(params![after_id], row_to_event)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(events)
+    }
+
+    /// When each issue last changed status, from its status events. Issues
+    /// that never changed status are left out: theirs dates from creation.
+    pub fn get_status_changed_at_batch(
+        &self,
+        issue_ids: &[&str],
+    ) -> Result<HashMap<String, DateTime<Utc>>> {
+        let mut map = HashMap::new();
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, MAX(created_at) FROM events
+             WHERE issue_id IN rarray(?1)
+               AND action IN ('started', 'stopped', 'done', 'closed', 'reopened')
+             GROUP BY issue_id",
+        )?;
+        let mut rows = stmt.query([id_array(issue_ids)])?;
+        while let Some(row) = rows.next()? {
+            let issue_id: String = row.get(0)?;
+            let at: String = row.get(1)?;
+            map.insert(issue_id, parse_timestamp(&at, "created_at")?);
+        }
+
+        Ok(map)
+    }
+
+    /// ID of the most recently logged event, or 0 when there are none.
+    pub fn last_event_id(&self) -> Result<i64> {
+        let id =
+            self.conn.query_row("SELECT COALESCE(MAX(id), 0) FROM events", [], |row| row.get(0))?;
+        Ok(id)
+    }
+
     /// Add a note to an issue.
     pub fn add_note(&self, issue_id: &str, status: Status, content: &str) -> Result<i64> {
-        self.conn.execute(
-            "INSERT INTO notes (issue_id, status, content, created_at)
-             VALUES (?1, ?2, ?3, ?4)",
-            params![issue_id, status.as_str(), content, Utc::now().to_rfc3339()],
+        self.add_note_of_kind(issue_id, status, None, content)
+    }
+
+    /// Add a note to an issue, optionally tagged with a kind.
+    pub fn add_note_of_kind(
+        &self,
+        issue_id: &str,
+        status: Status,
+        kind: Option<NoteKind>,
+        content: &str,
+    ) -> Result<i64> {
+        self.add_note_at(issue_id, status, kind, content, Utc::now())
+    }
+
+    /// Add a note written at `created_at`, as when restoring an export. The
+    /// note is recorded as the database's actor.
+    pub fn add_note_at(
+        &self,
+        issue_id: &str,
+        status: Status,
+        kind: Option<NoteKind>,
+        content: &str,
+        created_at: DateTime<Utc>,
+    ) -> Result<i64> {
+        self.execute(
+            "INSERT INTO notes (issue_id, status, content, created_at, kind, actor)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                issue_id,
+                status.as_str(),
+                content,
+                created_at.to_rfc3339(),
+                kind.map(|k| k.as_str()),
+                self.actor(),
+            ],
         )?;
         Ok(self.conn.last_insert_rowid())
     }
 
-    /// Get all notes for an issue, ordered by creation time.
+    /// Get all notes for an issue, ordered by creation time. Deleted notes
+    /// are left out.
     pub fn get_notes(&self, issue_id: &str) -> Result<Vec<Note>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, issue_id, status, content, created_at
-             FROM notes WHERE issue_id = ?1 ORDER BY created_at",
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, status, content, created_at, kind, actor
+             FROM notes WHERE issue_id = ?1 AND deleted_at IS NULL ORDER BY created_at",
         )?;
 
         let notes = stmt
@@ -701,12 +1079,108 @@ impl Database {
         Ok(notes)
     }
 
+    /// Get an issue's deleted notes, most recently deleted first.
+    pub fn get_deleted_notes(&self, issue_id: &str) -> Result<Vec<Note>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, status, content, created_at, kind, actor
+             FROM notes WHERE issue_id = ?1 AND deleted_at IS NOT NULL
+             ORDER BY deleted_at DESC, id DESC",
+        )?;
+
+        let notes = stmt
+            .query_map(params![issue_id], row_to_note)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(notes)
+    }
+
+    /// Get one of an issue's notes by its ID, deleted or not as `deleted`
+    /// says.
+    fn find_note(&self, issue_id: &str, note_id: i64, deleted: bool) -> Result<Note> {
+        self.query_row(
+            "SELECT id, issue_id, status, content, created_at, kind, actor
+             FROM notes WHERE id = ?1 AND issue_id = ?2 AND (deleted_at IS NOT NULL) = ?3",
+            params![note_id, issue_id, deleted],
+            row_to_note,
+        )
+        .optional()?
+        .ok_or_else(|| Error::NoteNotFound { issue_id: issue_id.to_string(), note_id })
+    }
+
+    /// Get one of an issue's notes by its ID.
+    pub fn get_note(&self, issue_id: &str, note_id: i64) -> Result<Note> {
+        self.find_note(issue_id, note_id, false)
+    }
+
+    /// Change the content of one of an issue's notes, keeping when it was
+    /// written. Returns the note as it was.
+    pub fn edit_note(&self, issue_id: &str, note_id: i64, content: &str) -> Result<Note> {
+        let note = self.get_note(issue_id, note_id)?;
+        self.execute("UPDATE notes SET content = ?1 WHERE id = ?2", params![content, note_id])?;
+        Ok(note)
+    }
+
+    /// Delete one of an issue's notes. The note is kept as a tombstone that
+    /// [`Database::restore_note`] brings back. Returns the deleted note.
+    pub fn delete_note(&self, issue_id: &str, note_id: i64) -> Result<Note> {
+        let note = self.get_note(issue_id, note_id)?;
+        self.execute(
+            "UPDATE notes SET deleted_at = ?1 WHERE id = ?2",
+            params![Utc::now().to_rfc3339(), note_id],
+        )?;
+        Ok(note)
+    }
+
+    /// Bring back one of an issue's deleted notes. Returns the note.
+    pub fn restore_note(&self, issue_id: &str, note_id: i64) -> Result<Note> {
+        let note = self.find_note(issue_id, note_id, true)?;
+        self.execute("UPDATE notes SET deleted_at = NULL WHERE id = ?1", [note_id])?;
+        Ok(note)
+    }
+
+    /// Move every note on `from_id` to `to_id`, keeping their timestamps.
+    /// Returns how many moved.
+    pub fn move_notes(&self, from_id: &str, to_id: &str) -> Result<usize> {
+        let moved = self.execute(
+            "UPDATE notes SET issue_id = ?2 WHERE issue_id = ?1",
+            params![from_id, to_id],
+        )?;
+        Ok(moved)
+    }
+
+    /// Move `from_id`'s links to `to_id`, dropping those whose URL `to_id`
+    /// already links to. Returns how many moved.
+    pub fn move_links(&self, from_id: &str, to_id: &str) -> Result<usize> {
+        let moved = self.execute(
+            "UPDATE links SET issue_id = ?2 WHERE issue_id = ?1
+             AND (url IS NULL OR url NOT IN
+                  (SELECT url FROM links
+                   WHERE issue_id = ?2 AND url IS NOT NULL AND deleted_at IS NULL))",
+            params![from_id, to_id],
+        )?;
+        self.execute("DELETE FROM links WHERE issue_id = ?1", [from_id])?;
+        Ok(moved)
+    }
+
+    /// Move `from_id`'s events with one of `actions` to `to_id`, keeping
+    /// their timestamps. Returns how many moved.
+    pub fn move_events(&self, from_id: &str, to_id: &str, actions: &[Action]) -> Result<usize> {
+        let mut moved = 0;
+        for action in actions {
+            moved += self.execute(
+                "UPDATE events SET issue_id = ?2 WHERE issue_id = ?1 AND action = ?3",
+                params![from_id, to_id, action.as_str()],
+            )?;
+        }
+        Ok(moved)
+    }
+
     /// Replace the most recent note for an issue with new content.
     pub fn replace_note(&self, issue_id: &str, status: Status, content: &str) -> Result<i64> {
         let note_id: Option<i64> = self
-            .conn
             .query_row(
-                "SELECT id FROM notes WHERE issue_id = ?1 ORDER BY created_at DESC LIMIT 1",
+                "SELECT id FROM notes WHERE issue_id = ?1 AND deleted_at IS NULL
+                 ORDER BY created_at DESC LIMIT 1",
                 params![issue_id],
                 |row| row.get(0),
             )
@@ -714,7 +1188,7 @@ impl Database {
 
         match note_id {
             Some(id) => {
-                self.conn.execute(
+                self.execute(
                     "UPDATE notes SET content = ?1, status = ?2, created_at = ?3 WHERE id = ?4",
                     params![content, status.as_str(), Utc::now().to_rfc3339(), id],
                 )?;
@@ -726,43 +1200,57 @@ impl Database {
 
     /// Get notes grouped by status, preserving first-occurrence order.
     pub fn get_notes_by_status(&self, issue_id: &str) -> Result<Vec<(Status, Vec<Note>)>> {
-        let notes = self.get_notes(issue_id)?;
-
-        let mut grouped: Vec<(Status, Vec<Note>)> = Vec::new();
-
-        for note in notes {
-            if let Some((_, notes_vec)) = grouped.iter_mut().find(|(s, _)| *s == note.status) {
-                notes_vec.push(note);
-            } else {
-                grouped.push((note.status, vec![note]));
-            }
-        }
-
-        Ok(grouped)
+        Ok(group_notes_by_status(self.get_notes(issue_id)?))
     }
 
-    /// Add a label to an issue.
-    pub fn add_label(&self, issue_id: &str, label: &str) -> Result<()> {
-        self.conn.execute(
+    /// Add a label to an issue. Returns false if it already had it.
+    pub fn add_label(&self, issue_id: &str, label: &str) -> Result<bool> {
+        let tx = self.label_transaction(issue_id)?;
+        let inserted = self.execute(
             "INSERT OR IGNORE INTO labels (issue_id, label) VALUES (?1, ?2)",
             params![issue_id, label],
         )?;
-        Ok(())
+        if inserted > 0 {
+            self.bump_revision(issue_id)?;
+        }
+        if let Some(tx) = tx {
+            tx.commit()?;
+        }
+        Ok(inserted > 0)
     }
 
     /// Remove a label from an issue.
     pub fn remove_label(&self, issue_id: &str, label: &str) -> Result<bool> {
-        let affected = self.conn.execute(
+        let tx = self.label_transaction(issue_id)?;
+        let affected = self.execute(
             "DELETE FROM labels WHERE issue_id = ?1 AND label = ?2",
             params![issue_id, label],
         )?;
+        if affected > 0 {
+            self.bump_revision(issue_id)?;
+        }
+        if let Some(tx) = tx {
+            tx.commit()?;
+        }
         Ok(affected > 0)
     }
 
+    /// A transaction for a label change to `issue_id` that must match an
+    /// expected revision, so a conflict leaves the labels as they were.
+    fn label_transaction(&self, issue_id: &str) -> Result<Option<Transaction<'_>>> {
+        let guarded = matches!(self.expected.borrow().as_ref(), Some((id, _)) if id == issue_id);
+        Ok(if guarded && self.conn.is_autocommit() {
+            Some(self.write_transaction()?)
+        } else {
+            None
+        })
+    }
+
     /// Get all labels for an issue.
     pub fn get_labels(&self, issue_id: &str) -> Result<Vec<String>> {
-        let mut stmt =
-            self.conn.prepare("SELECT label FROM labels WHERE issue_id = ?1 ORDER BY label")?;
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT label FROM labels WHERE issue_id = ?1 ORDER BY label")?;
 
         let labels = stmt
             .query_map(params![issue_id], |row| row.get(0))?
@@ -773,8 +1261,9 @@ impl Database {
 
     /// Get all labels as (issue_id, label) pairs.
     pub fn get_all_labels(&self) -> Result<Vec<(String, String)>> {
-        let mut stmt =
-            self.conn.prepare("SELECT issue_id, label FROM labels ORDER BY issue_id, label")?;
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id, label FROM labels ORDER BY issue_id, label")?;
 
         let labels = stmt
             .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
@@ -783,8 +1272,123 @@ impl Database {
         Ok(labels)
     }
 
+    /// Every label in use, once each, alphabetically.
+    pub fn get_distinct_labels(&self) -> Result<Vec<String>> {
+        let mut stmt =
+            self.conn.prepare_cached("SELECT DISTINCT label FROM labels ORDER BY label")?;
+
+        let labels = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+
+        Ok(labels)
+    }
+
+    /// IDs of the issues carrying `label`, sorted.
+    pub fn get_issues_with_label(&self, label: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id FROM labels WHERE label = ?1 ORDER BY issue_id")?;
+        let ids = stmt
+            .query_map([label], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Every label in use with the number of issues carrying it,
+    /// alphabetically.
+    pub fn get_label_counts(&self) -> Result<Vec<(String, usize)>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT label, COUNT(*) FROM labels GROUP BY label ORDER BY label")?;
+
+        let counts = stmt
+            .query_map([], |row| {
+                let count: i64 = row.get(1)?;
+                let count = usize::try_from(count)
+                    .map_err(|_| rusqlite::Error::IntegralValueOutOfRange(1, count))?;
+                Ok((row.get(0)?, count))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(counts)
+    }
+
+    /// Registry entries for labels and namespaces, by name.
+    pub fn get_label_meta(&self) -> Result<Vec<LabelMeta>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT name, description, color FROM labels_meta ORDER BY name")?;
+
+        let meta = stmt
+            .query_map([], |row| {
+                Ok(LabelMeta { name: row.get(0)?, description: row.get(1)?, color: row.get(2)? })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(meta)
+    }
+
+    /// Set or clear the description of a label or namespace (`team:`).
+    pub fn set_label_description(&self, name: &str, description: Option<&str>) -> Result<()> {
+        self.execute(
+            "INSERT INTO labels_meta (name, description, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(name) DO UPDATE SET description = ?2, updated_at = ?3",
+            params![name, description, Utc::now().to_rfc3339()],
+        )?;
+        self.prune_label_meta(name)
+    }
+
+    /// Set or clear the display color of a label or namespace (`team:`).
+    pub fn set_label_color(&self, name: &str, color: Option<&str>) -> Result<()> {
+        self.execute(
+            "INSERT INTO labels_meta (name, color, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(name) DO UPDATE SET color = ?2, updated_at = ?3",
+            params![name, color, Utc::now().to_rfc3339()],
+        )?;
+        self.prune_label_meta(name)
+    }
+
+    /// Drop a registry entry left with neither description nor color.
+    fn prune_label_meta(&self, name: &str) -> Result<()> {
+        self.execute(
+            "DELETE FROM labels_meta
+             WHERE name = ?1 AND description IS NULL AND color IS NULL",
+            [name],
+        )?;
+        Ok(())
+    }
+
+    /// Replace label `old` with `new` on every issue carrying it, and move
+    /// its registry entry unless `new` already has one. Returns the IDs of
+    /// the issues that carried `old`.
+    pub fn rename_label(&self, old: &str, new: &str) -> Result<Vec<String>> {
+        let ids = self.get_issues_with_label(old)?;
+
+        // Issues that already carry `new` just lose `old`
+        self.execute("UPDATE OR IGNORE labels SET label = ?2 WHERE label = ?1", params![old, new])?;
+        self.execute("DELETE FROM labels WHERE label = ?1", [old])?;
+        self.execute(
+            "UPDATE OR IGNORE labels_meta SET name = ?2 WHERE name = ?1",
+            params![old, new],
+        )?;
+        self.execute("DELETE FROM labels_meta WHERE name = ?1", [old])?;
+        Ok(ids)
+    }
+
     /// Add a dependency between two issues.
     pub fn add_dependency(&self, from_id: &str, to_id: &str, relation: Relation) -> Result<()> {
+        self.add_dependency_at(from_id, to_id, relation, Utc::now())
+    }
+
+    /// Add a dependency made at `created_at`, as when restoring an export.
+    pub fn add_dependency_at(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        relation: Relation,
+        created_at: DateTime<Utc>,
+    ) -> Result<()> {
         if from_id == to_id {
             return Err(Error::SelfDependency);
         }
@@ -794,10 +1398,10 @@ impl Database {
             return Err(Error::CycleDetected);
         }
 
-        self.conn.execute(
+        self.execute(
             "INSERT OR IGNORE INTO deps (from_id, to_id, rel, created_at)
              VALUES (?1, ?2, ?3, ?4)",
-            params![from_id, to_id, relation.as_str(), Utc::now().to_rfc3339()],
+            params![from_id, to_id, relation.as_str(), created_at.to_rfc3339()],
         )?;
 
         Ok(())
@@ -805,7 +1409,7 @@ impl Database {
 
     /// Remove a dependency between two issues.
     pub fn remove_dependency(&self, from_id: &str, to_id: &str, relation: Relation) -> Result<()> {
-        let affected = self.conn.execute(
+        let affected = self.execute(
             "DELETE FROM deps WHERE from_id = ?1 AND to_id = ?2 AND rel = ?3",
             params![from_id, to_id, relation.as_str()],
         )?;
@@ -823,7 +1427,7 @@ impl Database {
 
     /// Check if adding from_id -> to_id would create a cycle.
     fn would_create_cycle(&self, from_id: &str, to_id: &str) -> Result<bool> {
-        let count: i64 = self.conn.query_row(
+        let count: i64 = self.query_row(
             "WITH RECURSIVE chain(id) AS (
                 SELECT from_id FROM deps WHERE to_id = ?1 AND rel = 'blocks'
                 UNION
@@ -865,8 +1469,9 @@ impl Database {
 
     /// Get issues that directly block the given issue.
     pub fn get_blockers(&self, issue_id: &str) -> Result<Vec<String>> {
-        let mut stmt =
-            self.conn.prepare("SELECT from_id FROM deps WHERE to_id = ?1 AND rel = 'blocks'")?;
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT from_id FROM deps WHERE to_id = ?1 AND rel = 'blocks'")?;
 
         let ids = stmt
             .query_map(params![issue_id], |row| row.get(0))?
@@ -877,7 +1482,7 @@ impl Database {
 
     /// Get all issues that transitively block the given issue (active blockers only).
     pub fn get_transitive_blockers(&self, issue_id: &str) -> Result<Vec<String>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "WITH RECURSIVE blockers AS (
                 SELECT d.from_id as blocker_id
                 FROM deps d JOIN issues i ON i.id = d.from_id
@@ -904,7 +1509,7 @@ impl Database {
     /// Traverses all blocking chains regardless of intermediate issue status,
     /// then filters to only return blockers that are still active (todo/in_progress).
     pub fn get_transitive_blocker_deps(&self, issue_id: &str) -> Result<Vec<Dependency>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "WITH RECURSIVE blockers(id) AS (
                 SELECT from_id FROM deps WHERE to_id = ?1 AND rel = 'blocks'
                 UNION
@@ -927,8 +1532,9 @@ impl Database {
 
     /// Get issues that this issue blocks.
     pub fn get_blocking(&self, issue_id: &str) -> Result<Vec<String>> {
-        let mut stmt =
-            self.conn.prepare("SELECT to_id FROM deps WHERE from_id = ?1 AND rel = 'blocks'")?;
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT to_id FROM deps WHERE from_id = ?1 AND rel = 'blocks'")?;
 
         let ids = stmt
             .query_map(params![issue_id], |row| row.get(0))?
@@ -952,8 +1558,48 @@ impl Database {
 
     /// Get tracked issues (issues this tracks).
     pub fn get_tracked(&self, issue_id: &str) -> Result<Vec<String>> {
-        let mut stmt =
-            self.conn.prepare("SELECT to_id FROM deps WHERE from_id = ?1 AND rel = 'tracks'")?;
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT to_id FROM deps WHERE from_id = ?1 AND rel = 'tracks'")?;
+
+        let ids = stmt
+            .query_map(params![issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Get related issues (`relates-to` in either direction).
+    pub fn get_related(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT to_id FROM deps WHERE from_id = ?1 AND rel = 'relates-to'")?;
+
+        let ids = stmt
+            .query_map(params![issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Get issues this issue duplicates.
+    pub fn get_duplicate_of(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT to_id FROM deps WHERE from_id = ?1 AND rel = 'duplicate-of'")?;
+
+        let ids = stmt
+            .query_map(params![issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Get issues marked as duplicates of this issue.
+    pub fn get_duplicates(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT from_id FROM deps WHERE to_id = ?1 AND rel = 'duplicate-of'")?;
 
         let ids = stmt
             .query_map(params![issue_id], |row| row.get(0))?
@@ -971,19 +1617,24 @@ impl Database {
     ///
     /// Resolution strategy:
     /// 1. Exact match (fast path)
-    /// 2. Prefix match if length >= 3
-    /// 3. Error if no match or multiple matches
+    /// 2. Short ID alias (`prj-12`)
+    /// 3. Prefix match if length >= 3
+    /// 4. Error if no match or multiple matches
     pub fn resolve_id(&self, partial_id: &str) -> Result<String> {
         if self.issue_exists(partial_id)? {
             return Ok(partial_id.to_string());
         }
 
+        if let Some(id) = self.resolve_short_id(partial_id)? {
+            return Ok(id);
+        }
+
         if partial_id.len() < Self::MIN_PREFIX_LENGTH {
             return Err(Error::IssueNotFound(partial_id.to_string()));
         }
 
         let pattern = format!("{}%", partial_id);
-        let mut stmt = self.conn.prepare("SELECT id FROM issues WHERE id LIKE ?1")?;
+        let mut stmt = self.conn.prepare_cached("SELECT id FROM issues WHERE id LIKE ?1")?;
 
         let matches: Vec<String> = stmt
             .query_map([&pattern], |row| row.get(0))?
@@ -996,21 +1647,104 @@ impl Database {
         }
     }
 
+    /// IDs of issues starting with `start` (all issues when empty), sorted.
+    ///
+    /// Unlike [`Self::resolve_id`] there is no minimum length, since this
+    /// lists candidates rather than picking one.
+    pub fn get_ids_starting_with(&self, start: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id FROM issues WHERE substr(id, 1, length(?1)) = ?1 ORDER BY id")?;
+
+        let ids = stmt
+            .query_map(params![start], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+
+        Ok(ids)
+    }
+
+    /// Look up the issue behind a short ID alias like `prj-12`.
+    pub fn resolve_short_id(&self, alias: &str) -> Result<Option<String>> {
+        let Some((prefix, num)) = alias.split_once('-') else {
+            return Ok(None);
+        };
+        let Ok(num) = num.parse::<u32>() else {
+            return Ok(None);
+        };
+        let id = self
+            .query_row(
+                "SELECT issue_id FROM short_ids WHERE prefix = ?1 AND num = ?2",
+                params![prefix, num],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(id)
+    }
+
+    /// Move short IDs to a renamed prefix.
+    ///
+    /// Numbers are kept when the new prefix has no short IDs yet; when merging
+    /// into an existing prefix they are shifted past its highest number.
+    pub fn rename_short_id_prefix(&self, old: &str, new: &str) -> Result<()> {
+        if old == new {
+            return Ok(());
+        }
+        let offset: u32 = self.query_row(
+            "SELECT COALESCE(MAX(num), 0) FROM short_ids WHERE prefix = ?1",
+            params![new],
+            |row| row.get(0),
+        )?;
+        self.execute(
+            "UPDATE short_ids
+             SET prefix = ?2, num = num + ?3, issue_id = ?2 || substr(issue_id, length(?1) + 1)
+             WHERE prefix = ?1",
+            params![old, new, offset],
+        )?;
+        Ok(())
+    }
+
+    /// Get the short ID alias (`prj-12`) of an issue, if it has one.
+    pub fn get_short_id(&self, issue_id: &str) -> Result<Option<String>> {
+        let alias = self
+            .query_row(
+                "SELECT prefix || '-' || num FROM short_ids WHERE issue_id = ?1",
+                params![issue_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(alias)
+    }
+
+    /// Get short ID aliases for multiple issues, keyed by issue ID.
+    pub fn get_short_ids_batch(&self, issue_ids: &[&str]) -> Result<HashMap<String, String>> {
+        let mut map = HashMap::new();
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, prefix || '-' || num FROM short_ids
+             WHERE issue_id IN rarray(?1)",
+        )?;
+        let mut rows = stmt.query([id_array(issue_ids)])?;
+        while let Some(row) = rows.next()? {
+            map.insert(row.get(0)?, row.get(1)?);
+        }
+
+        Ok(map)
+    }
+
     /// Search issues by query string across title, description, and assignee.
     ///
     /// Special characters % and _ are escaped to prevent SQL LIKE interpretation.
     pub fn search_issues(&self, query: &str) -> Result<Vec<Issue>> {
         let escaped_query = query.replace('%', "\\%").replace('_', "\\_");
         let pattern = format!("%{}%", escaped_query);
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "SELECT DISTINCT i.id, i.type, i.title, i.description, i.status, i.assignee,
                     i.created_at, i.updated_at, i.closed_at, i.last_status_hlc,
                     i.last_title_hlc, i.last_type_hlc, i.last_description_hlc,
-                    i.last_assignee_hlc
+                    i.last_assignee_hlc, i.revision
              FROM issues i
-             LEFT JOIN notes n ON n.issue_id = i.id
+             LEFT JOIN notes n ON n.issue_id = i.id AND n.deleted_at IS NULL
              LEFT JOIN labels l ON l.issue_id = i.id
-             LEFT JOIN links lk ON lk.issue_id = i.id
+             LEFT JOIN links lk ON lk.issue_id = i.id AND lk.deleted_at IS NULL
              WHERE i.title LIKE ?1 COLLATE NOCASE ESCAPE '\\'
                 OR i.description LIKE ?1 COLLATE NOCASE ESCAPE '\\'
                 OR i.assignee LIKE ?1 COLLATE NOCASE ESCAPE '\\'
@@ -1030,61 +1764,170 @@ impl Database {
 
     /// Update issue description.
     pub fn update_issue_description(&self, id: &str, description: &str) -> Result<()> {
-        let affected = self.conn.execute(
-            "UPDATE issues SET description = ?1, updated_at = ?2 WHERE id = ?3",
-            params![description, Utc::now().to_rfc3339(), id],
+        let expected = self.take_expected(id);
+        let affected = self.execute(
+            "UPDATE issues SET description = ?1, updated_at = ?2, revision = revision + 1
+             WHERE id = ?3 AND revision = COALESCE(?4, revision)",
+            params![description, Utc::now().to_rfc3339(), id, expected],
         )?;
 
         if affected == 0 {
-            return Err(Error::IssueNotFound(id.to_string()));
+            return Err(self.missed(id, expected));
         }
         Ok(())
     }
 
+    /// Record a `references` dependency from `issue_id` to each other
+    /// existing issue that `text` mentions by full or short ID. Returns the
+    /// IDs referenced for the first time.
+    ///
+    /// Called when a user writes a note or description, and when a synced
+    /// note arrives.
+    pub fn record_mentions(&self, issue_id: &str, text: &str) -> Result<Vec<String>> {
+        let mut added = Vec::new();
+        for candidate in crate::mention::candidate_ids(text) {
+            let target = if self.issue_exists(candidate)? {
+                candidate.to_string()
+            } else {
+                match self.resolve_short_id(candidate)? {
+                    Some(id) => id,
+                    None => continue,
+                }
+            };
+            if target == issue_id {
+                continue;
+            }
+            let inserted = self.execute(
+                "INSERT OR IGNORE INTO deps (from_id, to_id, rel, created_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![issue_id, target, Relation::References.as_str(), Utc::now().to_rfc3339()],
+            )?;
+            if inserted > 0 {
+                added.push(target);
+            }
+        }
+        Ok(added)
+    }
+
+    /// IDs of issues whose notes or description mention `issue_id`.
+    pub fn get_referenced_by(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT from_id FROM deps WHERE to_id = ?1 AND rel = 'references'
+             ORDER BY created_at, from_id",
+        )?;
+        let ids = stmt
+            .query_map(params![issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+        Ok(ids)
+    }
+
     /// Set issue assignee.
     pub fn set_assignee(&self, id: &str, assignee: &str) -> Result<()> {
-        let affected = self.conn.execute(
-            "UPDATE issues SET assignee = ?1, updated_at = ?2 WHERE id = ?3",
-            params![assignee, Utc::now().to_rfc3339(), id],
+        let expected = self.take_expected(id);
+        let affected = self.execute(
+            "UPDATE issues SET assignee = ?1, updated_at = ?2, revision = revision + 1
+             WHERE id = ?3 AND revision = COALESCE(?4, revision)",
+            params![assignee, Utc::now().to_rfc3339(), id, expected],
         )?;
 
         if affected == 0 {
-            return Err(Error::IssueNotFound(id.to_string()));
+            return Err(self.missed(id, expected));
         }
         Ok(())
     }
 
     /// Clear issue assignee.
     pub fn clear_assignee(&self, id: &str) -> Result<()> {
-        let affected = self.conn.execute(
-            "UPDATE issues SET assignee = NULL, updated_at = ?1 WHERE id = ?2",
-            params![Utc::now().to_rfc3339(), id],
+        let expected = self.take_expected(id);
+        let affected = self.execute(
+            "UPDATE issues SET assignee = NULL, updated_at = ?1, revision = revision + 1
+             WHERE id = ?2 AND revision = COALESCE(?3, revision)",
+            params![Utc::now().to_rfc3339(), id, expected],
         )?;
 
         if affected == 0 {
-            return Err(Error::IssueNotFound(id.to_string()));
+            return Err(self.missed(id, expected));
         }
         Ok(())
     }
 
-    /// Get labels for multiple issues in a single query.
-    pub fn get_labels_batch(&self, issue_ids: &[&str]) -> Result<HashMap<String, Vec<String>>> {
-        if issue_ids.is_empty() {
-            return Ok(HashMap::new());
-        }
+    /// Reassign a todo issue from `from` to `to`, only if it is still
+    /// assigned to `from`.
+    ///
+    /// The check and the update are one statement, so when several processes
+    /// race for the same issue exactly one of them gets `true`.
+    pub fn claim_issue(&self, id: &str, from: &str, to: &str) -> Result<bool> {
+        let affected = self.execute(
+            "UPDATE issues SET assignee = ?1, updated_at = ?2, revision = revision + 1
+             WHERE id = ?3 AND assignee = ?4 AND status = 'todo'",
+            params![to, Utc::now().to_rfc3339(), id, from],
+        )?;
+        Ok(affected == 1)
+    }
 
-        let placeholders: Vec<_> = (1..=issue_ids.len()).map(|i| format!("?{}", i)).collect();
-        let sql = format!(
-            "SELECT issue_id, label FROM labels WHERE issue_id IN ({}) ORDER BY issue_id, label",
-            placeholders.join(", ")
-        );
+    /// Start a todo issue for `assignee`, only if it is still todo and either
+    /// unassigned or already theirs. With no assignee only unassigned issues
+    /// can be started.
+    ///
+    /// Like [`Database::claim_issue`], racing callers cannot both succeed.
+    pub fn claim_and_start(&self, id: &str, assignee: Option<&str>) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+        let affected = match assignee {
+            Some(who) => self.execute(
+                "UPDATE issues SET status = 'in_progress', assignee = ?1, updated_at = ?2,
+                 revision = revision + 1
+                 WHERE id = ?3 AND status = 'todo' AND (assignee IS NULL OR assignee = ?1)",
+                params![who, now, id],
+            )?,
+            None => self.execute(
+                "UPDATE issues SET status = 'in_progress', updated_at = ?1,
+                 revision = revision + 1
+                 WHERE id = ?2 AND status = 'todo' AND assignee IS NULL",
+                params![now, id],
+            )?,
+        };
+        Ok(affected == 1)
+    }
+
+    /// Every assignee with at least one issue, once each, alphabetically.
+    pub fn get_distinct_assignees(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT DISTINCT assignee FROM issues WHERE assignee IS NOT NULL ORDER BY assignee",
+        )?;
+
+        let assignees = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+
+        Ok(assignees)
+    }
+
+    /// Count in-progress issues per assignee. Unassigned issues are not counted.
+    pub fn count_in_progress_by_assignee(&self) -> Result<HashMap<String, usize>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT assignee, COUNT(*) FROM issues
+             WHERE status = 'in_progress' AND assignee IS NOT NULL
+             GROUP BY assignee",
+        )?;
+        let rows =
+            stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?;
 
-        let mut stmt = self.conn.prepare(&sql)?;
-        let params: Vec<&dyn rusqlite::ToSql> =
-            issue_ids.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
+        let mut counts = HashMap::new();
+        for row in rows {
+            let (assignee, count) = row?;
+            counts.insert(assignee, usize::try_from(count).unwrap_or(0));
+        }
+        Ok(counts)
+    }
 
+    /// Get labels for multiple issues in a single query.
+    pub fn get_labels_batch(&self, issue_ids: &[&str]) -> Result<HashMap<String, Vec<String>>> {
         let mut map: HashMap<String, Vec<String>> = HashMap::new();
-        let mut rows = stmt.query(params.as_slice())?;
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, label FROM labels
+             WHERE issue_id IN rarray(?1) ORDER BY issue_id, label",
+        )?;
+        let mut rows = stmt.query([id_array(issue_ids)])?;
         while let Some(row) = rows.next()? {
             let issue_id: String = row.get(0)?;
             let label: String = row.get(1)?;
@@ -1094,11 +1937,11 @@ impl Database {
         Ok(map)
     }
 
-    /// Get all external links for an issue.
+    /// Get all external links for an issue. Removed links are left out.
     pub fn get_links(&self, issue_id: &str) -> Result<Vec<Link>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, issue_id, link_type, url, external_id, rel, created_at
-             FROM links WHERE issue_id = ?1 ORDER BY created_at ASC",
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, link_type, url, external_id, rel, created_at, resolved_at
+             FROM links WHERE issue_id = ?1 AND deleted_at IS NULL ORDER BY created_at ASC",
         )?;
 
         let links =
@@ -1110,10 +1953,9 @@ impl Database {
     /// Get a specific link by issue ID and URL.
     pub fn get_link_by_url(&self, issue_id: &str, url: &str) -> Result<Option<Link>> {
         let link = self
-            .conn
             .query_row(
-                "SELECT id, issue_id, link_type, url, external_id, rel, created_at
-                 FROM links WHERE issue_id = ?1 AND url = ?2",
+                "SELECT id, issue_id, link_type, url, external_id, rel, created_at, resolved_at
+                 FROM links WHERE issue_id = ?1 AND url = ?2 AND deleted_at IS NULL",
                 params![issue_id, url],
                 row_to_link,
             )
@@ -1122,14 +1964,44 @@ impl Database {
         Ok(link)
     }
 
+    /// Get every issue's links to `url`, oldest first.
+    pub fn find_links(&self, url: &str) -> Result<Vec<Link>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, link_type, url, external_id, rel, created_at, resolved_at
+             FROM links WHERE url = ?1 AND deleted_at IS NULL ORDER BY created_at ASC, id ASC",
+        )?;
+
+        let links =
+            stmt.query_map([url], row_to_link)?.collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(links)
+    }
+
+    /// Get the oldest import link recording `external_id` from `source`:
+    /// the issue an earlier import made of that record.
+    pub fn find_import_link(&self, source: &LinkType, external_id: &str) -> Result<Option<Link>> {
+        let link = self
+            .query_row(
+                "SELECT id, issue_id, link_type, url, external_id, rel, created_at, resolved_at
+                 FROM links WHERE rel = 'import' AND link_type = ?1 AND external_id = ?2
+                     AND deleted_at IS NULL
+                 ORDER BY created_at ASC, id ASC LIMIT 1",
+                params![source.as_str(), external_id],
+                row_to_link,
+            )
+            .optional()?;
+
+        Ok(link)
+    }
+
     /// Add an external link to an issue.
     pub fn add_link(&self, link: &Link) -> Result<i64> {
-        let link_type_str = link.link_type.map(|t| t.as_str().to_string());
+        let link_type_str = link.link_type.as_ref().map(|t| t.as_str().to_string());
         let rel_str = link.rel.map(|r| r.as_str().to_string());
 
-        self.conn.execute(
-            "INSERT INTO links (issue_id, link_type, url, external_id, rel, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        self.execute(
+            "INSERT INTO links (issue_id, link_type, url, external_id, rel, created_at, resolved_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             params![
                 link.issue_id,
                 link_type_str,
@@ -1137,30 +2009,270 @@ impl Database {
                 link.external_id,
                 rel_str,
                 link.created_at.to_rfc3339(),
+                link.resolved_at.map(|t| t.to_rfc3339()),
             ],
         )?;
 
         Ok(self.conn.last_insert_rowid())
     }
 
-    /// Remove an external link by its ID.
+    /// Mark a link's external issue resolved at `at`, or unresolved when
+    /// `at` is `None`.
+    pub fn set_link_resolved(&self, link_id: i64, at: Option<DateTime<Utc>>) -> Result<()> {
+        self.execute(
+            "UPDATE links SET resolved_at = ?2 WHERE id = ?1",
+            params![link_id, at.map(|t| t.to_rfc3339())],
+        )?;
+        Ok(())
+    }
+
+    /// Mark every unresolved `blocks` link to `url` resolved at `at`, as
+    /// when its provider reports the external issue closed. Returns how
+    /// many links changed.
+    pub fn resolve_url_links(&self, url: &str, at: DateTime<Utc>) -> Result<usize> {
+        self.execute(
+            "UPDATE links SET resolved_at = ?2
+             WHERE url = ?1 AND rel = 'blocks' AND resolved_at IS NULL AND deleted_at IS NULL",
+            params![url, at.to_rfc3339()],
+        )
+    }
+
+    /// Remove an external link by its ID. The link is kept as a tombstone
+    /// that [`Database::restore_link`] brings back.
     pub fn remove_link(&self, link_id: i64) -> Result<()> {
-        self.conn.execute("DELETE FROM links WHERE id = ?1", [link_id])?;
+        self.execute(
+            "UPDATE links SET deleted_at = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+            params![link_id, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Bring back the link to `url` most recently removed from `issue_id`.
+    /// Returns it, or `None` when there is none or the issue links to `url`
+    /// again already.
+    pub fn restore_link(&self, issue_id: &str, url: &str) -> Result<Option<Link>> {
+        if self.get_link_by_url(issue_id, url)?.is_some() {
+            return Ok(None);
+        }
+        let link = self
+            .query_row(
+                "SELECT id, issue_id, link_type, url, external_id, rel, created_at, resolved_at
+                 FROM links WHERE issue_id = ?1 AND url = ?2 AND deleted_at IS NOT NULL
+                 ORDER BY deleted_at DESC, id DESC LIMIT 1",
+                params![issue_id, url],
+                row_to_link,
+            )
+            .optional()?;
+        if let Some(link) = &link {
+            self.execute("UPDATE links SET deleted_at = NULL WHERE id = ?1", [link.id])?;
+        }
+        Ok(link)
+    }
+
+    /// What `url`'s provider last said about it, if it was ever asked.
+    pub fn get_link_meta(&self, url: &str) -> Result<Option<LinkMeta>> {
+        let meta = self
+            .query_row(
+                "SELECT title, state, error, refreshed_at FROM link_meta WHERE url = ?1",
+                [url],
+                |row| {
+                    let refreshed_at: String = row.get(3)?;
+                    Ok(LinkMeta {
+                        title: row.get(0)?,
+                        state: row.get(1)?,
+                        error: row.get(2)?,
+                        refreshed_at: parse_timestamp(&refreshed_at, "refreshed_at")?,
+                    })
+                },
+            )
+            .optional()?;
+
+        Ok(meta)
+    }
+
+    /// Store a successful refresh of `url`.
+    pub fn set_link_meta(&self, url: &str, meta: &LinkMeta) -> Result<()> {
+        self.execute(
+            "INSERT INTO link_meta (url, title, state, error, refreshed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(url) DO UPDATE SET title = ?2, state = ?3, error = ?4, refreshed_at = ?5",
+            params![url, meta.title, meta.state, meta.error, meta.refreshed_at.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Store a failed refresh of `url`, keeping the title and state an
+    /// earlier one found.
+    pub fn set_link_meta_error(&self, url: &str, error: &str, at: DateTime<Utc>) -> Result<()> {
+        self.execute(
+            "INSERT INTO link_meta (url, error, refreshed_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(url) DO UPDATE SET error = ?2, refreshed_at = ?3",
+            params![url, error, at.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Links whose metadata is missing or was refreshed before
+    /// `stale_before`, one per URL, least recently refreshed first.
+    pub fn links_to_refresh(&self, stale_before: DateTime<Utc>, limit: usize) -> Result<Vec<Link>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT l.id, l.issue_id, l.link_type, l.url, l.external_id, l.rel, l.created_at,
+                l.resolved_at
+             FROM links l LEFT JOIN link_meta m ON m.url = l.url
+             WHERE l.id IN (
+                 SELECT MIN(id) FROM links
+                 WHERE url IS NOT NULL AND link_type IN ('github', 'gitlab', 'jira')
+                     AND deleted_at IS NULL
+                 GROUP BY url
+             )
+             AND (m.refreshed_at IS NULL OR m.refreshed_at < ?1)
+             ORDER BY m.refreshed_at ASC, l.id ASC
+             LIMIT ?2",
+        )?;
+
+        let links = stmt
+            .query_map(
+                params![stale_before.to_rfc3339(), i64::try_from(limit).unwrap_or(i64::MAX)],
+                row_to_link,
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(links)
+    }
+
+    /// Record an issue hook execution.
+    pub fn log_hook_run(&self, run: &HookRun) -> Result<i64> {
+        self.execute(
+            "INSERT INTO hook_runs
+             (hook_name, event, issue_id, exit_code, duration_ms, stderr_tail, error, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                run.hook_name,
+                run.event,
+                run.issue_id,
+                run.exit_code,
+                run.duration_ms,
+                run.stderr_tail,
+                run.error,
+                run.created_at.to_rfc3339(),
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Get the most recent hook executions, newest first.
+    ///
+    /// With `failed_only`, only runs that did not exit 0 are returned.
+    pub fn get_hook_runs(&self, limit: usize, failed_only: bool) -> Result<Vec<HookRun>> {
+        let sql = if failed_only {
+            "SELECT id, hook_name, event, issue_id, exit_code, duration_ms, stderr_tail, error, created_at
+             FROM hook_runs
+             WHERE error IS NOT NULL OR exit_code IS NULL OR exit_code != 0
+             ORDER BY created_at DESC, id DESC LIMIT ?1"
+        } else {
+            "SELECT id, hook_name, event, issue_id, exit_code, duration_ms, stderr_tail, error, created_at
+             FROM hook_runs
+             ORDER BY created_at DESC, id DESC LIMIT ?1"
+        };
+        let mut stmt = self.conn.prepare_cached(sql)?;
+        let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
+        let runs = stmt
+            .query_map(params![limit_i64], row_to_hook_run)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(runs)
+    }
+
+    /// Subscribe `who` to events on an issue from now on. Returns false if
+    /// they already were.
+    pub fn subscribe(&self, issue_id: &str, who: &str) -> Result<bool> {
+        let added = self.execute(
+            "INSERT OR IGNORE INTO subscriptions (issue_id, subscriber, after_event_id, created_at)
+             VALUES (?1, ?2, (SELECT COALESCE(MAX(id), 0) FROM events), ?3)",
+            params![issue_id, who, Utc::now().to_rfc3339()],
+        )?;
+        Ok(added > 0)
+    }
+
+    /// Unsubscribe `who` from an issue. Returns false if they were not
+    /// subscribed.
+    pub fn unsubscribe(&self, issue_id: &str, who: &str) -> Result<bool> {
+        let removed = self.execute(
+            "DELETE FROM subscriptions WHERE issue_id = ?1 AND subscriber = ?2",
+            params![issue_id, who],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// IDs of the issues `who` is subscribed to, oldest subscription first.
+    pub fn get_subscriptions(&self, who: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id FROM subscriptions WHERE subscriber = ?1
+             ORDER BY created_at, issue_id",
+        )?;
+        let ids = stmt
+            .query_map(params![who], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Events on issues `who` is subscribed to that they have not read:
+    /// newer than both the subscription and their inbox cursor, in the
+    /// order they were recorded.
+    pub fn get_inbox_events(&self, who: &str) -> Result<Vec<Event>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT e.id, e.issue_id, e.action, e.old_value, e.new_value, e.reason, e.created_at,
+                    e.actor
+             FROM events e
+             JOIN subscriptions s ON s.issue_id = e.issue_id AND s.subscriber = ?1
+             WHERE e.id > s.after_event_id
+               AND e.id > COALESCE(
+                   (SELECT last_event_id FROM inbox_cursors WHERE subscriber = ?1), 0)
+             ORDER BY e.id",
+        )?;
+        let events = stmt
+            .query_map(params![who], row_to_event)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(events)
+    }
+
+    /// Mark `who`'s inbox read up to and including event `last_event_id`.
+    pub fn mark_inbox_read(&self, who: &str, last_event_id: i64) -> Result<()> {
+        self.execute(
+            "INSERT INTO inbox_cursors (subscriber, last_event_id) VALUES (?1, ?2)
+             ON CONFLICT (subscriber) DO UPDATE
+             SET last_event_id = MAX(last_event_id, excluded.last_event_id)",
+            params![who, last_event_id],
+        )?;
         Ok(())
     }
 
-    /// Remove an external link by issue ID and URL.
+    /// Move subscriptions on `from_id` to `to_id`, dropping those already
+    /// subscribed to `to_id`. Returns how many moved.
+    pub fn move_subscriptions(&self, from_id: &str, to_id: &str) -> Result<usize> {
+        let moved = self.execute(
+            "UPDATE OR IGNORE subscriptions SET issue_id = ?2 WHERE issue_id = ?1",
+            params![from_id, to_id],
+        )?;
+        self.execute("DELETE FROM subscriptions WHERE issue_id = ?1", [from_id])?;
+        Ok(moved)
+    }
+
+    /// Remove an external link by issue ID and URL, keeping a tombstone.
     pub fn remove_link_by_url(&self, issue_id: &str, url: &str) -> Result<()> {
-        self.conn.execute(
-            "DELETE FROM links WHERE issue_id = ?1 AND url = ?2",
-            params![issue_id, url],
+        self.execute(
+            "UPDATE links SET deleted_at = ?3
+             WHERE issue_id = ?1 AND url = ?2 AND deleted_at IS NULL",
+            params![issue_id, url, Utc::now().to_rfc3339()],
         )?;
         Ok(())
     }
 
-    /// Remove all links for an issue.
+    /// Remove all links for an issue, keeping tombstones.
     pub fn remove_all_links(&self, issue_id: &str) -> Result<()> {
-        self.conn.execute("DELETE FROM links WHERE issue_id = ?1", [issue_id])?;
+        self.execute(
+            "UPDATE links SET deleted_at = ?2 WHERE issue_id = ?1 AND deleted_at IS NULL",
+            params![issue_id, Utc::now().to_rfc3339()],
+        )?;
         Ok(())
     }
 
@@ -1169,7 +2281,7 @@ impl Database {
     /// Creates the prefix entry if it doesn't exist, using the current timestamp.
     pub fn ensure_prefix(&self, prefix: &str) -> Result<()> {
         let now = Utc::now().to_rfc3339();
-        self.conn.execute(
+        self.execute(
             "INSERT OR IGNORE INTO prefixes (prefix, created_at, issue_count) VALUES (?1, ?2, 0)",
             params![prefix, now],
         )?;
@@ -1180,7 +2292,7 @@ impl Database {
     ///
     /// Should be called after creating an issue with this prefix.
     pub fn increment_prefix_count(&self, prefix: &str) -> Result<()> {
-        self.conn.execute(
+        self.execute(
             "UPDATE prefixes SET issue_count = issue_count + 1 WHERE prefix = ?1",
             params![prefix],
         )?;
@@ -1191,7 +2303,7 @@ impl Database {
     ///
     /// Should be called after deleting an issue with this prefix.
     pub fn decrement_prefix_count(&self, prefix: &str) -> Result<()> {
-        self.conn.execute(
+        self.execute(
             "UPDATE prefixes SET issue_count = issue_count - 1 WHERE prefix = ?1",
             params![prefix],
         )?;
@@ -1202,7 +2314,7 @@ impl Database {
     ///
     /// Results are ordered by issue count (descending).
     pub fn list_prefixes(&self) -> Result<Vec<PrefixInfo>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "SELECT prefix, created_at, issue_count FROM prefixes ORDER BY issue_count DESC, prefix ASC",
         )?;
         let rows = stmt.query_map([], |row| {
@@ -1226,7 +2338,6 @@ impl Database {
     /// If the new prefix already exists, merges the counts.
     pub fn rename_prefix(&self, old: &str, new: &str) -> Result<()> {
         let old_info: Option<(String, i64)> = self
-            .conn
             .query_row(
                 "SELECT created_at, issue_count FROM prefixes WHERE prefix = ?1",
                 params![old],
@@ -1236,13 +2347,12 @@ impl Database {
 
         if let Some((created_at, issue_count)) = old_info {
             let new_exists: bool = self
-                .conn
                 .query_row("SELECT 1 FROM prefixes WHERE prefix = ?1", params![new], |_| Ok(true))
                 .unwrap_or(false);
 
             if new_exists {
                 // Merge: add old count to new, keep earlier created_at
-                self.conn.execute(
+                self.execute(
                     "UPDATE prefixes SET
                         issue_count = issue_count + ?1,
                         created_at = MIN(created_at, ?2)
@@ -1250,13 +2360,13 @@ impl Database {
                     params![issue_count, created_at, new],
                 )?;
             } else {
-                self.conn.execute(
+                self.execute(
                     "INSERT INTO prefixes (prefix, created_at, issue_count) VALUES (?1, ?2, ?3)",
                     params![new, created_at, issue_count],
                 )?;
             }
 
-            self.conn.execute("DELETE FROM prefixes WHERE prefix = ?1", params![old])?;
+            self.execute("DELETE FROM prefixes WHERE prefix = ?1", params![old])?;
         }
 
         Ok(())
@@ -129,6 +129,30 @@ fn list_issues_filter_status() {
     assert_eq!(in_progress[0].id, "test-2");
 }
 
+#[test]
+fn get_downstream_counts_follows_chains() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-1", "test-2", "test-3", "test-4"] {
+        db.create_issue(&test_issue(id, "Issue")).unwrap();
+    }
+    // test-1 -> test-2 -> test-3, and test-1 -> test-3 directly
+    db.add_dependency("test-1", "test-2", Relation::Blocks).unwrap();
+    db.add_dependency("test-2", "test-3", Relation::Blocks).unwrap();
+    db.add_dependency("test-1", "test-3", Relation::Blocks).unwrap();
+    db.add_dependency("test-4", "test-2", Relation::Tracks).unwrap();
+
+    let counts = db.get_downstream_counts().unwrap();
+    assert_eq!(counts.get("test-1"), Some(&2));
+    assert_eq!(counts.get("test-2"), Some(&1));
+    assert_eq!(counts.get("test-3"), None);
+    assert_eq!(counts.get("test-4"), None);
+
+    db.update_issue_status("test-3", Status::Done).unwrap();
+    let counts = db.get_downstream_counts().unwrap();
+    assert_eq!(counts.get("test-1"), Some(&1));
+    assert_eq!(counts.get("test-2"), None);
+}
+
 #[test]
 fn log_and_get_events() {
     let db = Database::open_in_memory().unwrap();
@@ -143,6 +167,277 @@ fn log_and_get_events() {
     assert_eq!(events[0].action, Action::Created);
 }
 
+#[test]
+fn record_stores_the_change_with_its_event() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let event =
+        Event::new("test-1".to_string(), Action::Labeled).with_values(None, Some("ui".to_string()));
+
+    db.record(&event, || db.add_label("test-1", "ui")).unwrap();
+
+    assert_eq!(db.get_labels("test-1").unwrap(), vec!["ui"]);
+    let events = db.get_events("test-1").unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, Action::Labeled);
+}
+
+#[test]
+fn failed_change_records_nothing() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let event = Event::new("test-1".to_string(), Action::Labeled);
+
+    let result = db.record(&event, || {
+        db.add_label("test-1", "ui")?;
+        db.add_dependency("test-1", "test-1", Relation::Blocks)
+    });
+
+    assert!(result.is_err());
+    assert!(db.get_labels("test-1").unwrap().is_empty());
+    assert!(db.get_events("test-1").unwrap().is_empty());
+}
+
+#[test]
+fn atomically_joins_the_transaction_in_progress() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let event = Event::new("test-1".to_string(), Action::Labeled);
+
+    let result: Result<()> = db.atomically(|| {
+        db.record(&event, || db.add_label("test-1", "ui"))?;
+        Err(Error::IssueNotFound("test-9".to_string()))
+    });
+
+    assert!(result.is_err());
+    assert!(db.get_labels("test-1").unwrap().is_empty());
+    assert!(db.get_events("test-1").unwrap().is_empty());
+}
+
+#[test]
+fn atomically_retries_the_whole_change_when_busy() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let event = Event::new("test-1".to_string(), Action::Labeled);
+
+    let mut tries = 0;
+    db.record(&event, || {
+        tries += 1;
+        db.add_label("test-1", &format!("try-{}", tries))?;
+        if tries == 1 {
+            let busy = rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY);
+            return Err(Error::Database(rusqlite::Error::SqliteFailure(busy, None)));
+        }
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(tries, 2);
+    assert_eq!(db.get_labels("test-1").unwrap(), vec!["try-2"]);
+    assert_eq!(db.get_events("test-1").unwrap().len(), 1);
+}
+
+#[test]
+fn get_events_since_filters_and_orders() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.create_issue(&test_issue("test-2", "Second")).unwrap();
+
+    let now = Utc::now();
+    for (id, action, hours_ago) in [
+        ("test-1", Action::Created, 48),
+        ("test-2", Action::Started, 1),
+        ("test-1", Action::Done, 2),
+    ] {
+        let mut event = Event::new(id.to_string(), action);
+        event.created_at = now - chrono::Duration::hours(hours_ago);
+        db.log_event(&event).unwrap();
+    }
+
+    let events = db.get_events_since(now - chrono::Duration::hours(24)).unwrap();
+    let actions: Vec<Action> = events.iter().map(|e| e.action).collect();
+    assert_eq!(actions, vec![Action::Done, Action::Started]);
+}
+
+#[test]
+fn get_events_after_follows_log_order() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    assert_eq!(db.last_event_id().unwrap(), 0);
+
+    let first = db.log_event(&Event::new("test-1".to_string(), Action::Created)).unwrap();
+    let mut backdated = Event::new("test-1".to_string(), Action::Started);
+    backdated.created_at = Utc::now() - chrono::Duration::hours(1);
+    let second = db.log_event(&backdated).unwrap();
+
+    assert_eq!(db.last_event_id().unwrap(), second);
+    let events = db.get_events_after(0).unwrap();
+    let ids: Vec<i64> = events.iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![first, second]);
+    let events = db.get_events_after(first).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, Action::Started);
+}
+
+#[test]
+fn get_status_changed_at_batch_takes_last_status_event() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.create_issue(&test_issue("test-2", "Second")).unwrap();
+
+    let now = Utc::now();
+    for (action, hours_ago) in
+        [(Action::Started, 5), (Action::Stopped, 3), (Action::Labeled, 1), (Action::Stale, 1)]
+    {
+        let mut event = Event::new("test-1".to_string(), action);
+        event.created_at = now - chrono::Duration::hours(hours_ago);
+        db.log_event(&event).unwrap();
+    }
+
+    let changed = db.get_status_changed_at_batch(&["test-1", "test-2"]).unwrap();
+    assert_eq!(changed.get("test-1"), Some(&(now - chrono::Duration::hours(3))));
+    assert!(!changed.contains_key("test-2"));
+}
+
+#[test]
+fn stream_events_newest_first_with_limit() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.create_issue(&test_issue("test-2", "Second")).unwrap();
+
+    let now = Utc::now();
+    for (id, action, hours_ago) in [
+        ("test-1", Action::Created, 3),
+        ("test-2", Action::Created, 2),
+        ("test-1", Action::Started, 1),
+    ] {
+        let mut event = Event::new(id.to_string(), action);
+        event.created_at = now - chrono::Duration::hours(hours_ago);
+        db.log_event(&event).unwrap();
+    }
+
+    let actions = |issue_id: Option<&str>, limit: Option<usize>| -> Vec<(String, Action)> {
+        let mut rows = db.stream_events(issue_id, None, limit).unwrap();
+        let events = rows.iter().unwrap().map(|e| e.unwrap());
+        events.map(|e| (e.issue_id, e.action)).collect()
+    };
+    assert_eq!(
+        actions(None, Some(2)),
+        vec![("test-1".to_string(), Action::Started), ("test-2".to_string(), Action::Created)]
+    );
+    assert_eq!(
+        actions(Some("test-1"), None),
+        vec![("test-1".to_string(), Action::Started), ("test-1".to_string(), Action::Created)]
+    );
+}
+
+#[test]
+fn events_and_notes_record_the_actor() {
+    let mut db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.log_event(&Event::new("test-1".to_string(), Action::Created)).unwrap();
+
+    db.set_actor(Some("claude-1".to_string()));
+    db.log_event(&Event::new("test-1".to_string(), Action::Started)).unwrap();
+    let named = Event::new("test-1".to_string(), Action::Labeled).with_actor(Some("bob".into()));
+    db.log_event(&named).unwrap();
+    db.add_note("test-1", Status::InProgress, "Halfway").unwrap();
+
+    let actors: Vec<Option<String>> =
+        db.get_events("test-1").unwrap().into_iter().map(|e| e.actor).collect();
+    assert_eq!(actors, vec![None, Some("claude-1".to_string()), Some("bob".to_string())]);
+    assert_eq!(db.get_notes("test-1").unwrap()[0].actor.as_deref(), Some("claude-1"));
+
+    let mut rows = db.stream_events(None, Some("claude-1"), None).unwrap();
+    let by_claude: Vec<Action> = rows.iter().unwrap().map(|e| e.unwrap().action).collect();
+    assert_eq!(by_claude, vec![Action::Started]);
+}
+
+#[test]
+fn changes_to_fields_and_labels_bump_the_revision() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    assert_eq!(db.get_issue("test-1").unwrap().revision, 1);
+
+    db.update_issue_title("test-1", "Renamed").unwrap();
+    db.add_label("test-1", "ui").unwrap();
+    db.add_label("test-1", "ui").unwrap();
+    db.remove_label("test-1", "absent").unwrap();
+    db.update_issue_title_hlc("test-1", Hlc::new(1, 0, 1)).unwrap();
+    assert_eq!(db.get_revision("test-1").unwrap(), 3);
+
+    db.update_issue_status("test-1", Status::InProgress).unwrap();
+    db.set_assignee("test-1", "alice").unwrap();
+    assert_eq!(db.get_issue("test-1").unwrap().revision, 5);
+}
+
+#[test]
+fn expect_revision_fails_on_a_stale_revision() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.update_issue_title("test-1", "Renamed").unwrap();
+
+    let err = db.expect_revision("test-1", 1).unwrap_err();
+    assert!(matches!(err, Error::Conflict { expected: 1, actual: 2, .. }), "{err}");
+    assert!(matches!(db.expect_revision("test-9", 1), Err(Error::IssueNotFound(_))));
+    db.expect_revision("test-1", 2).unwrap();
+}
+
+#[test]
+fn guarded_change_fails_when_another_writer_got_there_first() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.expect_revision("test-1", 1).unwrap();
+    // Another process changes the issue after the check
+    db.conn.execute("UPDATE issues SET revision = revision + 1 WHERE id = 'test-1'", []).unwrap();
+
+    let err = db.update_issue_title("test-1", "Mine").unwrap_err();
+    assert!(matches!(err, Error::Conflict { expected: 1, actual: 2, .. }), "{err}");
+    assert_eq!(db.get_issue("test-1").unwrap().title, "First");
+
+    // The expectation is used up: later changes go through
+    db.update_issue_title("test-1", "Mine").unwrap();
+    assert_eq!(db.get_revision("test-1").unwrap(), 3);
+}
+
+#[test]
+fn guarded_label_change_leaves_labels_on_conflict() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.expect_revision("test-1", 1).unwrap();
+    db.conn.execute("UPDATE issues SET revision = revision + 1 WHERE id = 'test-1'", []).unwrap();
+
+    assert!(matches!(db.add_label("test-1", "ui"), Err(Error::Conflict { .. })));
+    assert!(db.get_labels("test-1").unwrap().is_empty());
+}
+
+#[test]
+fn cleared_expectation_guards_nothing() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "First")).unwrap();
+    db.expect_revision("test-1", 1).unwrap();
+    db.clear_expected_revision();
+    db.conn.execute("UPDATE issues SET revision = revision + 1 WHERE id = 'test-1'", []).unwrap();
+
+    db.update_issue_title("test-1", "Mine").unwrap();
+    assert_eq!(db.get_revision("test-1").unwrap(), 3);
+}
+
+#[test]
+fn stream_issues_matches_query_issues() {
+    let db = Database::open_in_memory().unwrap();
+    for n in 1..=3 {
+        db.create_issue(&test_issue(&format!("test-{}", n), "Issue")).unwrap();
+    }
+    let query = IssueQuery::default().with_limit(Some(2));
+
+    let mut rows = db.stream_issues(&query).unwrap();
+    let streamed: Vec<String> = rows.iter().unwrap().map(|i| i.unwrap().id).collect();
+    let queried: Vec<String> = db.query_issues(&query).unwrap().into_iter().map(|i| i.id).collect();
+    assert_eq!(streamed, queried);
+    assert_eq!(streamed.len(), 2);
+}
+
 #[test]
 fn add_and_get_notes() {
     let db = Database::open_in_memory().unwrap();
@@ -269,6 +564,30 @@ fn get_all_labels() {
     assert_eq!(all_labels.len(), 2);
 }
 
+#[test]
+fn get_distinct_labels_lists_each_label_once() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-1", "test-2"] {
+        db.create_issue(&test_issue(id, "Issue")).unwrap();
+        db.add_label(id, "urgent").unwrap();
+    }
+    db.add_label("test-2", "backend").unwrap();
+
+    assert_eq!(db.get_distinct_labels().unwrap(), vec!["backend", "urgent"]);
+}
+
+#[test]
+fn get_ids_starting_with_has_no_minimum_length() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-b", "test-a", "other-1"] {
+        db.create_issue(&test_issue(id, "Issue")).unwrap();
+    }
+
+    assert_eq!(db.get_ids_starting_with("t").unwrap(), vec!["test-a", "test-b"]);
+    assert_eq!(db.get_ids_starting_with("").unwrap().len(), 3);
+    assert!(db.get_ids_starting_with("%").unwrap().is_empty());
+}
+
 #[test]
 fn resolve_id_exact_match() {
     let db = Database::open_in_memory().unwrap();
@@ -316,6 +635,85 @@ fn resolve_id_not_found() {
     assert!(matches!(result, Err(Error::IssueNotFound(_))));
 }
 
+#[test]
+fn short_ids_are_allocated_per_prefix() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1b2", "First")).unwrap();
+    db.create_issue(&test_issue("api-c3d4", "Other prefix")).unwrap();
+    db.create_issue(&test_issue("prj-e5f6", "Second")).unwrap();
+    db.create_issue(&test_issue("prj-a1b2-2", "Hash collision")).unwrap();
+    db.create_issue(&test_issue("noprefix", "No prefix")).unwrap();
+
+    assert_eq!(db.get_short_id("prj-a1b2").unwrap().as_deref(), Some("prj-1"));
+    assert_eq!(db.get_short_id("prj-e5f6").unwrap().as_deref(), Some("prj-2"));
+    assert_eq!(db.get_short_id("api-c3d4").unwrap().as_deref(), Some("api-1"));
+    assert_eq!(db.get_short_id("prj-a1b2-2").unwrap().as_deref(), Some("prj-3"));
+    assert_eq!(db.get_short_id("noprefix").unwrap(), None);
+
+    let batch = db.get_short_ids_batch(&["prj-e5f6", "api-c3d4"]).unwrap();
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch["prj-e5f6"], "prj-2");
+}
+
+#[test]
+fn resolve_id_accepts_short_id() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1b2", "First")).unwrap();
+    db.create_issue(&test_issue("prj-2bcd", "Second")).unwrap();
+
+    assert_eq!(db.resolve_id("prj-1").unwrap(), "prj-a1b2");
+    // The alias wins over a prefix match on the full ID
+    assert_eq!(db.resolve_id("prj-2").unwrap(), "prj-2bcd");
+    assert!(matches!(db.resolve_id("prj-3"), Err(Error::IssueNotFound(_))));
+}
+
+#[test]
+fn create_issue_with_short_id_prefers_carried_number() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue_with_short_id(&test_issue("prj-a1b2", "Remote"), Some(7)).unwrap();
+    db.create_issue_with_short_id(&test_issue("prj-c3d4", "Clash"), Some(7)).unwrap();
+
+    assert_eq!(db.get_short_id("prj-a1b2").unwrap().as_deref(), Some("prj-7"));
+    assert_eq!(db.get_short_id("prj-c3d4").unwrap().as_deref(), Some("prj-8"));
+}
+
+#[test]
+fn rename_short_id_prefix_shifts_when_merging() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("new-a1b2", "Existing")).unwrap();
+    db.create_issue(&test_issue("old-c3d4", "Moved")).unwrap();
+    db.conn.execute("UPDATE issues SET id = 'new-c3d4' WHERE id = 'old-c3d4'", []).unwrap();
+
+    db.rename_short_id_prefix("old", "new").unwrap();
+
+    assert_eq!(db.get_short_id("new-c3d4").unwrap().as_deref(), Some("new-2"));
+    assert_eq!(db.resolve_id("new-2").unwrap(), "new-c3d4");
+}
+
+#[test]
+fn migration_backfills_short_ids_in_creation_order() {
+    let db = Database::open_in_memory().unwrap();
+    let older = Issue::new(
+        "prj-ffff".to_string(),
+        IssueType::Task,
+        "Older".to_string(),
+        Utc::now() - chrono::Duration::hours(1),
+    );
+    db.create_issue(&test_issue("prj-0000", "Newer")).unwrap();
+    db.create_issue(&older).unwrap();
+    // As an unversioned database from before short IDs would be.
+    db.conn.execute("DELETE FROM short_ids", []).unwrap();
+    db.conn.pragma_update(None, "user_version", 0).unwrap();
+
+    assert!(pending_migrations(&db.conn)
+        .unwrap()
+        .contains(&"allocate short IDs for existing issues"));
+    run_migrations(&db.conn).unwrap();
+
+    assert_eq!(db.get_short_id("prj-ffff").unwrap().as_deref(), Some("prj-1"));
+    assert_eq!(db.get_short_id("prj-0000").unwrap().as_deref(), Some("prj-2"));
+}
+
 #[test]
 fn search_issues_by_title() {
     let db = Database::open_in_memory().unwrap();
@@ -362,6 +760,83 @@ fn set_and_clear_assignee() {
     assert!(retrieved.assignee.is_none());
 }
 
+#[test]
+fn claim_issue_succeeds_only_once() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Queued")).unwrap();
+    db.set_assignee("test-1", "queue:merge").unwrap();
+
+    assert!(db.claim_issue("test-1", "queue:merge", "alice").unwrap());
+    assert!(!db.claim_issue("test-1", "queue:merge", "bob").unwrap());
+    assert_eq!(db.get_issue("test-1").unwrap().assignee.as_deref(), Some("alice"));
+}
+
+#[test]
+fn claim_issue_requires_todo() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Queued")).unwrap();
+    db.set_assignee("test-1", "queue:merge").unwrap();
+    db.update_issue_status("test-1", Status::InProgress).unwrap();
+
+    assert!(!db.claim_issue("test-1", "queue:merge", "alice").unwrap());
+}
+
+#[test]
+fn claim_and_start_assigns_unassigned_todo() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Open")).unwrap();
+
+    assert!(db.claim_and_start("test-1", Some("alice")).unwrap());
+    assert!(!db.claim_and_start("test-1", Some("bob")).unwrap());
+
+    let issue = db.get_issue("test-1").unwrap();
+    assert_eq!(issue.status, Status::InProgress);
+    assert_eq!(issue.assignee.as_deref(), Some("alice"));
+}
+
+#[test]
+fn claim_and_start_respects_other_assignee() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Taken")).unwrap();
+    db.set_assignee("test-1", "alice").unwrap();
+
+    assert!(!db.claim_and_start("test-1", Some("bob")).unwrap());
+    assert!(!db.claim_and_start("test-1", None).unwrap());
+    assert!(db.claim_and_start("test-1", Some("alice")).unwrap());
+}
+
+#[test]
+fn count_in_progress_by_assignee_groups_started_issues() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-1", "test-2", "test-3", "test-4"] {
+        db.create_issue(&test_issue(id, "Issue")).unwrap();
+    }
+    db.set_assignee("test-1", "alice").unwrap();
+    db.set_assignee("test-2", "alice").unwrap();
+    db.set_assignee("test-3", "bob").unwrap();
+    for id in ["test-1", "test-2", "test-4"] {
+        db.update_issue_status(id, Status::InProgress).unwrap();
+    }
+
+    let counts = db.count_in_progress_by_assignee().unwrap();
+    assert_eq!(counts.get("alice"), Some(&2));
+    assert_eq!(counts.get("bob"), None);
+    assert_eq!(counts.len(), 1);
+}
+
+#[test]
+fn get_distinct_assignees_skips_unassigned() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-1", "test-2", "test-3"] {
+        db.create_issue(&test_issue(id, "Issue")).unwrap();
+    }
+    db.set_assignee("test-1", "bob").unwrap();
+    db.set_assignee("test-2", "alice").unwrap();
+    db.set_assignee("test-3", "bob").unwrap();
+
+    assert_eq!(db.get_distinct_assignees().unwrap(), vec!["alice", "bob"]);
+}
+
 #[test]
 fn get_labels_batch() {
     let db = Database::open_in_memory().unwrap();
@@ -377,6 +852,49 @@ fn get_labels_batch() {
     assert_eq!(batch.get("test-2").map(|v| v.len()), Some(1));
 }
 
+#[test]
+fn batch_lookups_exceed_sqlite_variable_limit() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Issue 1")).unwrap();
+    db.add_label("test-1", "urgent").unwrap();
+    let ids: Vec<String> = (0..40_000).map(|n| format!("missing-{}", n)).collect();
+    let mut refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+    refs.push("test-1");
+
+    let labels = db.get_labels_batch(&refs).unwrap();
+    assert_eq!(labels.get("test-1"), Some(&vec!["urgent".to_string()]));
+    let short_ids = db.get_short_ids_batch(&refs).unwrap();
+    assert_eq!(short_ids.len(), 1);
+}
+
+#[test]
+fn get_labels_batch_10k_ids_stays_fast() {
+    let db = Database::open_in_memory().unwrap();
+    let ids: Vec<String> = (0..10_000).map(|n| format!("test-{}", n)).collect();
+    db.conn.execute_batch("BEGIN").unwrap();
+    for (n, id) in ids.iter().enumerate() {
+        db.create_issue(&test_issue(id, "Issue")).unwrap();
+        db.add_label(id, if n % 2 == 0 { "even" } else { "odd" }).unwrap();
+        if n % 3 == 0 {
+            db.add_label(id, "third").unwrap();
+        }
+    }
+    db.conn.execute_batch("COMMIT").unwrap();
+    let refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+    let start = std::time::Instant::now();
+    let batch = db.get_labels_batch(&refs).unwrap();
+    let batched = start.elapsed();
+
+    assert_eq!(batch.len(), 10_000);
+    assert_eq!(batch["test-0"], vec!["even", "third"]);
+    assert_eq!(batch["test-1"], vec!["odd"]);
+    assert_eq!(batch.values().map(Vec::len).sum::<usize>(), 10_000 + 3_334);
+
+    // About 30ms unoptimized; a plan that scans labels per ID takes far longer.
+    assert!(batched < std::time::Duration::from_secs(2), "batch took {:?}", batched);
+}
+
 #[test]
 fn get_labels_batch_empty() {
     let db = Database::open_in_memory().unwrap();
@@ -398,6 +916,7 @@ fn add_and_get_links() {
         external_id: Some("1".to_string()),
         rel: None,
         created_at: Utc::now(),
+        resolved_at: None,
     };
     db.add_link(&link).unwrap();
 
@@ -420,6 +939,7 @@ fn get_link_by_url() {
         external_id: None,
         rel: None,
         created_at: Utc::now(),
+        resolved_at: None,
     };
     db.add_link(&link).unwrap();
 
@@ -430,6 +950,178 @@ fn get_link_by_url() {
     assert!(not_found.is_none());
 }
 
+#[test]
+fn link_meta_keeps_title_and_state_after_a_failure() {
+    let db = Database::open_in_memory().unwrap();
+    let url = "https://github.com/org/repo/issues/1";
+    assert_eq!(db.get_link_meta(url).unwrap(), None);
+
+    let meta = LinkMeta {
+        title: Some("Crash".to_string()),
+        state: Some("open".to_string()),
+        error: None,
+        refreshed_at: Utc::now(),
+    };
+    db.set_link_meta(url, &meta).unwrap();
+    assert_eq!(db.get_link_meta(url).unwrap(), Some(meta));
+
+    db.set_link_meta_error(url, "timed out", Utc::now()).unwrap();
+    let failed = db.get_link_meta(url).unwrap().unwrap();
+    assert_eq!(failed.title.as_deref(), Some("Crash"));
+    assert_eq!(failed.error.as_deref(), Some("timed out"));
+}
+
+#[test]
+fn links_to_refresh_lists_stale_provider_links_once() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "One")).unwrap();
+    db.create_issue(&test_issue("test-2", "Two")).unwrap();
+    let add = |issue: &str, link_type: LinkType, url: &str| {
+        let link = Link::new(issue.to_string()).with_type(link_type).with_url(url.to_string());
+        db.add_link(&link).unwrap();
+    };
+    add("test-1", LinkType::Github, "https://github.com/o/r/issues/1");
+    add("test-2", LinkType::Github, "https://github.com/o/r/issues/1");
+    add("test-1", LinkType::Jira, "jira://PE-1");
+    add("test-1", LinkType::Confluence, "https://acme.atlassian.net/wiki/x");
+    let fresh = LinkMeta { title: None, state: None, error: None, refreshed_at: Utc::now() };
+    db.set_link_meta("jira://PE-1", &fresh).unwrap();
+
+    let stale_before = Utc::now() - chrono::Duration::hours(1);
+    let urls: Vec<_> = db
+        .links_to_refresh(stale_before, 10)
+        .unwrap()
+        .into_iter()
+        .map(|l| (l.issue_id, l.url.unwrap()))
+        .collect();
+    assert_eq!(urls, [("test-1".to_string(), "https://github.com/o/r/issues/1".to_string())]);
+
+    let all = db.links_to_refresh(Utc::now() + chrono::Duration::hours(1), usize::MAX).unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].url.as_deref(), Some("https://github.com/o/r/issues/1"));
+}
+
+#[test]
+fn unresolved_blocks_links_block_their_issue() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "One")).unwrap();
+    db.create_issue(&test_issue("test-2", "Two")).unwrap();
+    let url = "https://github.com/o/r/issues/1";
+    let link = |issue: &str, rel: LinkRel| {
+        Link::new(issue.to_string()).with_url(url.to_string()).with_rel(rel)
+    };
+    db.add_link(&link("test-1", LinkRel::Blocks)).unwrap();
+    db.add_link(&link("test-2", LinkRel::Tracks)).unwrap();
+    assert_eq!(db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+
+    assert_eq!(db.resolve_url_links(url, Utc::now()).unwrap(), 1);
+    assert!(db.get_blocked_issue_ids().unwrap().is_empty());
+    assert!(db.get_links("test-1").unwrap()[0].resolved_at.is_some());
+    assert!(db.get_links("test-2").unwrap()[0].resolved_at.is_none());
+
+    let link_id = db.get_links("test-1").unwrap()[0].id;
+    db.set_link_resolved(link_id, None).unwrap();
+    assert_eq!(db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+}
+
+#[test]
+fn move_notes_keeps_order_and_status() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Source")).unwrap();
+    db.create_issue(&test_issue("test-2", "Target")).unwrap();
+    db.add_note("test-2", Status::Todo, "Target note").unwrap();
+    db.add_note("test-1", Status::InProgress, "Source note").unwrap();
+
+    assert_eq!(db.move_notes("test-1", "test-2").unwrap(), 1);
+    assert!(db.get_notes("test-1").unwrap().is_empty());
+    let notes = db.get_notes("test-2").unwrap();
+    let contents: Vec<&str> = notes.iter().map(|n| n.content.as_str()).collect();
+    assert_eq!(contents, vec!["Target note", "Source note"]);
+    assert_eq!(notes[1].status, Status::InProgress);
+}
+
+#[test]
+fn find_links_across_issues() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-1", "test-2"] {
+        db.create_issue(&test_issue(id, "Test issue")).unwrap();
+        let mut link = Link::new(id.to_string());
+        link.url = Some("branch://fix-login".to_string());
+        db.add_link(&link).unwrap();
+    }
+
+    let found = db.find_links("branch://fix-login").unwrap();
+    let ids: Vec<&str> = found.iter().map(|link| link.issue_id.as_str()).collect();
+    assert_eq!(ids, vec!["test-1", "test-2"]);
+    assert!(db.find_links("branch://other").unwrap().is_empty());
+}
+
+#[test]
+fn find_import_link_by_source_and_external_id() {
+    let db = Database::open_in_memory().unwrap();
+    for (id, source, rel) in [
+        ("test-1", LinkType::Jira, LinkRel::Tracks),
+        ("test-2", LinkType::Jira, LinkRel::Import),
+        ("test-3", LinkType::Other("beads".to_string()), LinkRel::Import),
+    ] {
+        db.create_issue(&test_issue(id, "Test issue")).unwrap();
+        let mut link = Link::new(id.to_string());
+        link.link_type = Some(source);
+        link.external_id = Some("ENG-12".to_string());
+        link.rel = Some(rel);
+        db.add_link(&link).unwrap();
+    }
+
+    let found = db.find_import_link(&LinkType::Jira, "ENG-12").unwrap().unwrap();
+    assert_eq!(found.issue_id, "test-2");
+    let beads = LinkType::Other("beads".to_string());
+    assert_eq!(db.find_import_link(&beads, "ENG-12").unwrap().unwrap().issue_id, "test-3");
+    assert!(db.find_import_link(&LinkType::Jira, "ENG-13").unwrap().is_none());
+}
+
+#[test]
+fn move_links_skips_urls_the_target_has() {
+    let db = Database::open_in_memory().unwrap();
+    for id in ["test-1", "test-2"] {
+        db.create_issue(&test_issue(id, "Test issue")).unwrap();
+    }
+    for (id, url) in [
+        ("test-1", "https://example.com/a"),
+        ("test-1", "https://example.com/b"),
+        ("test-2", "https://example.com/b"),
+    ] {
+        let mut link = Link::new(id.to_string());
+        link.url = Some(url.to_string());
+        db.add_link(&link).unwrap();
+    }
+
+    assert_eq!(db.move_links("test-1", "test-2").unwrap(), 1);
+    assert!(db.get_links("test-1").unwrap().is_empty());
+    let urls: Vec<String> =
+        db.get_links("test-2").unwrap().into_iter().filter_map(|link| link.url).collect();
+    assert_eq!(urls, vec!["https://example.com/a", "https://example.com/b"]);
+}
+
+#[test]
+fn move_events_only_moves_given_actions() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Source")).unwrap();
+    db.create_issue(&test_issue("test-2", "Target")).unwrap();
+    db.log_event(&Event::new("test-1".to_string(), Action::Created)).unwrap();
+    db.log_event(
+        &Event::new("test-1".to_string(), Action::Labeled).with_values(None, Some("bug".into())),
+    )
+    .unwrap();
+
+    assert_eq!(db.move_events("test-1", "test-2", &[Action::Labeled, Action::Noted]).unwrap(), 1);
+    let source: Vec<Action> =
+        db.get_events("test-1").unwrap().into_iter().map(|e| e.action).collect();
+    assert_eq!(source, vec![Action::Created]);
+    let target = db.get_events("test-2").unwrap();
+    assert_eq!(target[0].action, Action::Labeled);
+    assert_eq!(target[0].new_value.as_deref(), Some("bug"));
+}
+
 #[test]
 fn remove_link() {
     let db = Database::open_in_memory().unwrap();
@@ -444,6 +1136,7 @@ fn remove_link() {
         external_id: Some("1".to_string()),
         rel: None,
         created_at: Utc::now(),
+        resolved_at: None,
     };
     let link_id = db.add_link(&link).unwrap();
 
@@ -452,6 +1145,42 @@ fn remove_link() {
     assert_eq!(db.get_links("test-1").unwrap().len(), 0);
 }
 
+#[test]
+fn removed_link_is_kept_and_can_be_restored() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let url = "https://github.com/org/repo/issues/1";
+    let link = Link::new("test-1".to_string()).with_url(url.to_string()).with_rel(LinkRel::Blocks);
+    let link_id = db.add_link(&link).unwrap();
+    assert_eq!(db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+
+    db.remove_link(link_id).unwrap();
+    assert!(db.get_link_by_url("test-1", url).unwrap().is_none());
+    assert!(db.find_links(url).unwrap().is_empty());
+    assert!(db.get_blocked_issue_ids().unwrap().is_empty());
+
+    let restored = db.restore_link("test-1", url).unwrap().unwrap();
+    assert_eq!(restored.id, link_id);
+    assert_eq!(restored.rel, Some(LinkRel::Blocks));
+    assert_eq!(db.get_links("test-1").unwrap().len(), 1);
+    assert_eq!(db.get_blocked_issue_ids().unwrap(), vec!["test-1"]);
+
+    // Nothing left to restore while the link is back
+    assert!(db.restore_link("test-1", url).unwrap().is_none());
+}
+
+#[test]
+fn restore_link_skips_a_url_linked_again() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let link = Link::new("test-1".to_string()).with_url("https://example.com".to_string());
+    db.remove_link(db.add_link(&link).unwrap()).unwrap();
+    db.add_link(&link).unwrap();
+
+    assert!(db.restore_link("test-1", "https://example.com").unwrap().is_none());
+    assert_eq!(db.get_links("test-1").unwrap().len(), 1);
+}
+
 #[test]
 fn replace_note() {
     let db = Database::open_in_memory().unwrap();
@@ -517,6 +1246,47 @@ fn get_notes_by_status() {
     assert_eq!(notes.len(), 2);
 }
 
+#[test]
+fn add_note_of_kind_stores_kind() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+
+    db.add_note("test-1", Status::Todo, "Plain").unwrap();
+    db.add_note_of_kind("test-1", Status::Todo, Some(NoteKind::Decision), "Use FTS").unwrap();
+
+    let notes = db.get_notes("test-1").unwrap();
+    assert_eq!(notes[0].kind, None);
+    assert_eq!(notes[1].kind, Some(NoteKind::Decision));
+    assert_eq!(notes[1].content, "Use FTS");
+}
+
+#[test]
+fn add_note_and_dependency_at_keep_timestamps() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Issue 1")).unwrap();
+    db.create_issue(&test_issue("test-2", "Issue 2")).unwrap();
+    let at = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z").unwrap().with_timezone(&Utc);
+
+    db.add_note_at("test-1", Status::Done, Some(NoteKind::Handoff), "Shipped", at).unwrap();
+    db.add_dependency_at("test-1", "test-2", Relation::Tracks, at).unwrap();
+
+    let notes = db.get_notes("test-1").unwrap();
+    assert_eq!((notes[0].created_at, notes[0].kind), (at, Some(NoteKind::Handoff)));
+    assert_eq!(db.get_deps_from("test-1").unwrap()[0].created_at, at);
+}
+
+#[test]
+fn replace_note_keeps_kind() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+
+    db.add_note_of_kind("test-1", Status::Todo, Some(NoteKind::Blocker), "Waiting").unwrap();
+    db.replace_note("test-1", Status::Todo, "Still waiting").unwrap();
+
+    let notes = db.get_notes("test-1").unwrap();
+    assert_eq!(notes[0].kind, Some(NoteKind::Blocker));
+}
+
 #[test]
 fn get_notes_by_status_empty() {
     let db = Database::open_in_memory().unwrap();
@@ -527,6 +1297,70 @@ fn get_notes_by_status_empty() {
     assert!(grouped.is_empty());
 }
 
+#[test]
+fn deleted_note_is_kept_and_can_be_restored() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    db.create_issue(&test_issue("test-2", "Other issue")).unwrap();
+    let first = db.add_note("test-1", Status::Todo, "First").unwrap();
+    db.add_note("test-1", Status::Todo, "Second").unwrap();
+
+    let deleted = db.delete_note("test-1", first).unwrap();
+    assert_eq!(deleted.content, "First");
+    let notes = db.get_notes("test-1").unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].content, "Second");
+    assert_eq!(db.get_deleted_notes("test-1").unwrap()[0].id, first);
+
+    // Gone from lookups, searches and replace
+    assert!(matches!(
+        db.delete_note("test-1", first),
+        Err(Error::NoteNotFound { note_id, .. }) if note_id == first
+    ));
+    assert!(db.search_issues("First").unwrap().is_empty());
+    assert!(db.restore_note("test-2", first).is_err());
+
+    let restored = db.restore_note("test-1", first).unwrap();
+    assert_eq!(restored.content, "First");
+    assert_eq!(db.get_notes("test-1").unwrap().len(), 2);
+    assert!(db.get_deleted_notes("test-1").unwrap().is_empty());
+    assert!(db.restore_note("test-1", first).is_err());
+}
+
+#[test]
+fn replace_note_skips_deleted_notes() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    let kept = db.add_note("test-1", Status::Todo, "Kept").unwrap();
+    let latest = db.add_note("test-1", Status::Todo, "Latest").unwrap();
+    db.delete_note("test-1", latest).unwrap();
+
+    assert_eq!(db.replace_note("test-1", Status::Todo, "Replaced").unwrap(), kept);
+    assert_eq!(db.get_deleted_notes("test-1").unwrap()[0].content, "Latest");
+}
+
+#[test]
+fn edit_note_keeps_when_it_was_written() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "Test issue")).unwrap();
+    db.add_note("test-1", Status::Todo, "Earlier").unwrap();
+    let id = db.add_note_of_kind("test-1", Status::Todo, Some(NoteKind::Decision), "Tpyo").unwrap();
+    let before = db.get_note("test-1", id).unwrap();
+
+    let old = db.edit_note("test-1", id, "Typo").unwrap();
+    assert_eq!(old.content, "Tpyo");
+
+    let after = db.get_note("test-1", id).unwrap();
+    assert_eq!(after.content, "Typo");
+    assert_eq!(after.created_at, before.created_at);
+    assert_eq!(after.kind, Some(NoteKind::Decision));
+    assert_eq!(db.get_notes("test-1").unwrap()[1].id, id);
+
+    db.delete_note("test-1", id).unwrap();
+    assert!(db.edit_note("test-1", id, "Again").is_err());
+    assert!(db.edit_note("test-1", 999, "Missing").is_err());
+}
+
 #[test]
 fn priority_from_tags_numeric() {
     assert_eq!(Database::priority_from_tags(&["priority:0".into()]), 0);
@@ -603,6 +1437,7 @@ fn remove_all_links() {
         external_id: None,
         rel: None,
         created_at: Utc::now(),
+        resolved_at: None,
     };
     let link2 = Link {
         id: 0,
@@ -612,6 +1447,7 @@ fn remove_all_links() {
         external_id: None,
         rel: None,
         created_at: Utc::now(),
+        resolved_at: None,
     };
     db.add_link(&link1).unwrap();
     db.add_link(&link2).unwrap();
@@ -794,17 +1630,9 @@ fn closed_at_in_list_issues() {
     assert!(issues[0].closed_at.is_some());
 }
 
-/// Create a database with a minimal old-style schema (no assignee, no HLC columns,
-/// no closed_at, no prefixes table), insert data, then run migrations and verify
-/// everything works.
-#[test]
-fn migrate_old_schema_adds_missing_columns_and_backfills() {
-    use rusqlite::Connection;
-
-    let conn = Connection::open_in_memory().unwrap();
-    conn.execute_batch("PRAGMA foreign_keys = ON;").unwrap();
-
-    // Old-style schema: no assignee, no HLC columns, no closed_at, no prefixes table
+/// Create a minimal old-style schema (no assignee, no HLC columns, no
+/// closed_at, no prefixes table).
+fn create_old_schema(conn: &rusqlite::Connection) {
     conn.execute_batch(
         "CREATE TABLE issues (
             id TEXT PRIMARY KEY,
@@ -854,6 +1682,19 @@ fn migrate_old_schema_adds_missing_columns_and_backfills() {
         );",
     )
     .unwrap();
+}
+
+/// Create a database with a minimal old-style schema (no assignee, no HLC columns,
+/// no closed_at, no prefixes table), insert data, then run migrations and verify
+/// everything works.
+#[test]
+fn migrate_old_schema_adds_missing_columns_and_backfills() {
+    use rusqlite::Connection;
+
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch("PRAGMA foreign_keys = ON;").unwrap();
+
+    create_old_schema(&conn);
 
     // Insert issues using old schema (no assignee / HLC / closed_at)
     conn.execute(
@@ -877,6 +1718,14 @@ fn migrate_old_schema_adds_missing_columns_and_backfills() {
     )
     .unwrap();
 
+    // Add a note (no kind column)
+    conn.execute(
+        "INSERT INTO notes (issue_id, status, content, created_at)
+         VALUES ('proj-abc1', 'todo', 'Old note', '2026-01-01T00:00:00Z')",
+        [],
+    )
+    .unwrap();
+
     // Add an old-style tracked_by dependency
     conn.execute(
         "INSERT INTO deps (from_id, to_id, rel, created_at)
@@ -886,7 +1735,7 @@ fn migrate_old_schema_adds_missing_columns_and_backfills() {
     .unwrap();
 
     // Now wrap with Database and run migrations via free function
-    let db = Database { conn };
+    let db = Database { conn, actor: None, expected: Default::default() };
     run_migrations(&db.conn).unwrap();
 
     // Verify assignee column exists and is readable
@@ -918,6 +1767,67 @@ fn migrate_old_schema_adds_missing_columns_and_backfills() {
     let deps = db.get_deps_from("proj-abc1").unwrap();
     assert_eq!(deps.len(), 1);
     assert_eq!(deps[0].relation, Relation::TrackedBy);
+
+    // Verify existing notes have no kind after adding the column
+    let notes = db.get_notes("proj-abc1").unwrap();
+    assert_eq!(notes.len(), 1);
+    assert!(notes[0].kind.is_none());
+
+    // Verify short IDs were allocated in creation order
+    assert_eq!(db.resolve_id("proj-1").unwrap(), "proj-abc1");
+}
+
+#[test]
+fn pending_migrations_empty_for_current_schema() {
+    let db = Database::open_in_memory().unwrap();
+    assert!(pending_migrations(&db.conn).unwrap().is_empty());
+}
+
+#[test]
+fn pending_migrations_reports_old_schema_without_applying() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    create_old_schema(&conn);
+    conn.execute(
+        "INSERT INTO issues (id, type, title, status, created_at, updated_at)
+         VALUES ('proj-abc1', 'task', 'Task', 'todo', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z')",
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO deps (from_id, to_id, rel, created_at)
+         VALUES ('proj-abc1', 'proj-def2', 'tracked_by', '2026-01-01T00:00:00Z')",
+        [],
+    )
+    .unwrap();
+
+    let pending = pending_migrations(&conn).unwrap();
+    assert_eq!(
+        pending,
+        vec![
+            "create missing tables and indexes",
+            "add and index assignee column",
+            "add HLC columns",
+            "add closed_at column and backfill from events",
+            "backfill prefixes table",
+            "rewrite tracked_by relations to tracked-by",
+            "add note kind column",
+            "allocate short IDs for existing issues",
+            "index notes by issue",
+            "add issue subscriptions and inbox cursors",
+            "add label registry",
+            "add external link metadata",
+            "add link resolved_at column",
+            "add actor columns to events and notes",
+            "add issue revisions",
+            "add deleted_at columns to notes and links",
+        ]
+    );
+
+    // Detection is read-only
+    assert_eq!(pending_migrations(&conn).unwrap(), pending);
+
+    run_migrations(&conn).unwrap();
+    assert!(pending_migrations(&conn).unwrap().is_empty());
 }
 
 #[test]
@@ -977,6 +1887,7 @@ fn remove_link_by_url() {
         external_id: None,
         rel: None,
         created_at: Utc::now(),
+        resolved_at: None,
     };
     db.add_link(&link).unwrap();
     assert_eq!(db.get_links("test-1").unwrap().len(), 1);
@@ -984,3 +1895,226 @@ fn remove_link_by_url() {
     db.remove_link_by_url("test-1", "https://example.com").unwrap();
     assert_eq!(db.get_links("test-1").unwrap().len(), 0);
 }
+
+fn hook_run(name: &str, exit_code: Option<i32>, created_at: &str) -> HookRun {
+    HookRun {
+        id: 0,
+        hook_name: name.to_string(),
+        event: "issue.done".to_string(),
+        issue_id: "prj-1".to_string(),
+        exit_code,
+        duration_ms: 12,
+        stderr_tail: exit_code.filter(|c| *c != 0).map(|_| "boom".to_string()),
+        error: None,
+        created_at: chrono::DateTime::parse_from_rfc3339(created_at).unwrap().with_timezone(&Utc),
+    }
+}
+
+#[test]
+fn hook_runs_round_trip_newest_first() {
+    let db = Database::open_in_memory().unwrap();
+    db.log_hook_run(&hook_run("ok", Some(0), "2026-01-01T00:00:00Z")).unwrap();
+    db.log_hook_run(&hook_run("bad", Some(2), "2026-01-02T00:00:00Z")).unwrap();
+
+    let runs = db.get_hook_runs(10, false).unwrap();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].hook_name, "bad");
+    assert_eq!(runs[0].exit_code, Some(2));
+    assert_eq!(runs[0].stderr_tail.as_deref(), Some("boom"));
+    assert_eq!(runs[1].hook_name, "ok");
+
+    assert_eq!(db.get_hook_runs(1, false).unwrap().len(), 1);
+}
+
+#[test]
+fn hook_runs_failed_only() {
+    let db = Database::open_in_memory().unwrap();
+    db.log_hook_run(&hook_run("ok", Some(0), "2026-01-01T00:00:00Z")).unwrap();
+    db.log_hook_run(&hook_run("bad", Some(1), "2026-01-02T00:00:00Z")).unwrap();
+    db.log_hook_run(&hook_run("killed", None, "2026-01-03T00:00:00Z")).unwrap();
+
+    let names: Vec<String> =
+        db.get_hook_runs(10, true).unwrap().into_iter().map(|r| r.hook_name).collect();
+    assert_eq!(names, vec!["killed", "bad"]);
+}
+
+#[test]
+fn open_read_only_reads_but_refuses_writes() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let path = temp.path().join("issues.db");
+    let db = Database::open(&path).unwrap();
+    db.create_issue(&test_issue("test-1", "Existing")).unwrap();
+
+    let reader = Database::open_read_only(&path).unwrap();
+    assert_eq!(reader.get_issue("test-1").unwrap().title, "Existing");
+    let err = reader.create_issue(&test_issue("test-2", "New")).unwrap_err();
+    assert!(
+        matches!(&err, Error::Database(rusqlite::Error::SqliteFailure(e, _))
+            if e.code == rusqlite::ErrorCode::ReadOnly),
+        "{:?}",
+        err
+    );
+
+    // The writer still works while the reader is open.
+    db.create_issue(&test_issue("test-2", "New")).unwrap();
+    assert!(reader.issue_exists("test-2").unwrap());
+}
+
+#[test]
+fn open_read_only_does_not_create_a_database() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let path = temp.path().join("missing.db");
+    assert!(Database::open_read_only(&path).is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn subscribe_is_idempotent_and_lists_subscriptions() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "One")).unwrap();
+    db.create_issue(&test_issue("test-2", "Two")).unwrap();
+
+    assert!(db.subscribe("test-1", "alice").unwrap());
+    assert!(!db.subscribe("test-1", "alice").unwrap());
+    db.subscribe("test-2", "bob").unwrap();
+
+    assert_eq!(db.get_subscriptions("alice").unwrap(), vec!["test-1"]);
+    assert!(db.unsubscribe("test-1", "alice").unwrap());
+    assert!(!db.unsubscribe("test-1", "alice").unwrap());
+    assert!(db.get_subscriptions("alice").unwrap().is_empty());
+}
+
+#[test]
+fn inbox_events_start_at_subscription_and_advance_with_cursor() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "One")).unwrap();
+    db.create_issue(&test_issue("test-2", "Two")).unwrap();
+    db.log_event(&Event::new("test-1".to_string(), Action::Created)).unwrap();
+
+    db.subscribe("test-1", "alice").unwrap();
+    let started = db.log_event(&Event::new("test-1".to_string(), Action::Started)).unwrap();
+    db.log_event(&Event::new("test-2".to_string(), Action::Started)).unwrap();
+
+    let events = db.get_inbox_events("alice").unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].id, started);
+    assert_eq!(events[0].action, Action::Started);
+    // Other subscribers have their own view
+    assert!(db.get_inbox_events("bob").unwrap().is_empty());
+
+    db.mark_inbox_read("alice", started).unwrap();
+    assert!(db.get_inbox_events("alice").unwrap().is_empty());
+    // The cursor never moves backwards
+    db.mark_inbox_read("alice", 0).unwrap();
+    assert!(db.get_inbox_events("alice").unwrap().is_empty());
+
+    db.log_event(&Event::new("test-1".to_string(), Action::Done)).unwrap();
+    assert_eq!(db.get_inbox_events("alice").unwrap()[0].action, Action::Done);
+}
+
+#[test]
+fn move_subscriptions_merges_subscribers() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("test-1", "One")).unwrap();
+    db.create_issue(&test_issue("test-2", "Two")).unwrap();
+    db.subscribe("test-1", "alice").unwrap();
+    db.subscribe("test-1", "bob").unwrap();
+    db.subscribe("test-2", "bob").unwrap();
+
+    assert_eq!(db.move_subscriptions("test-1", "test-2").unwrap(), 1);
+    assert_eq!(db.get_subscriptions("alice").unwrap(), vec!["test-2"]);
+    assert_eq!(db.get_subscriptions("bob").unwrap(), vec!["test-2"]);
+}
+
+#[test]
+fn record_mentions_adds_references() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1", "Target")).unwrap();
+    db.create_issue(&test_issue("prj-b2", "Other")).unwrap();
+    db.create_issue(&test_issue("prj-c3", "Mentioner")).unwrap();
+
+    // Short IDs resolve; unknown IDs and the issue itself are ignored
+    let recorded = db.record_mentions("prj-c3", "See prj-1 and prj-zz, not prj-c3").unwrap();
+    assert_eq!(recorded, vec!["prj-a1"]);
+    db.record_mentions("prj-b2", "Follows up on prj-a1.").unwrap();
+
+    assert_eq!(db.get_referenced_by("prj-a1").unwrap(), vec!["prj-c3", "prj-b2"]);
+    assert!(db.get_referenced_by("prj-c3").unwrap().is_empty());
+    // Mentioning again records nothing new
+    assert!(db.record_mentions("prj-c3", "prj-a1 again").unwrap().is_empty());
+}
+
+#[test]
+fn related_and_duplicate_deps_do_not_block() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1", "Original")).unwrap();
+    db.create_issue(&test_issue("prj-b2", "Copy")).unwrap();
+    db.create_issue(&test_issue("prj-c3", "Neighbor")).unwrap();
+    db.add_dependency("prj-b2", "prj-a1", Relation::DuplicateOf).unwrap();
+    db.add_dependency("prj-a1", "prj-c3", Relation::RelatesTo).unwrap();
+    db.add_dependency("prj-c3", "prj-a1", Relation::RelatesTo).unwrap();
+
+    assert_eq!(db.get_duplicate_of("prj-b2").unwrap(), vec!["prj-a1"]);
+    assert_eq!(db.get_duplicates("prj-a1").unwrap(), vec!["prj-b2"]);
+    assert_eq!(db.get_related("prj-c3").unwrap(), vec!["prj-a1"]);
+    assert!(db.get_blockers("prj-a1").unwrap().is_empty());
+    assert!(db.get_blocked_issue_ids().unwrap().is_empty());
+}
+
+#[test]
+fn references_do_not_block() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1", "Target")).unwrap();
+    db.create_issue(&test_issue("prj-b2", "Mentioner")).unwrap();
+    db.record_mentions("prj-a1", "depends on prj-b2 somehow").unwrap();
+
+    assert!(db.get_blockers("prj-a1").unwrap().is_empty());
+    assert!(db.get_blocked_issue_ids().unwrap().is_empty());
+    assert_eq!(db.get_deps_from("prj-a1").unwrap()[0].relation, Relation::References);
+}
+
+#[test]
+fn label_counts_and_registry() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1", "One")).unwrap();
+    db.create_issue(&test_issue("prj-b2", "Two")).unwrap();
+    db.add_label("prj-a1", "team:alpha").unwrap();
+    db.add_label("prj-b2", "team:alpha").unwrap();
+    db.add_label("prj-b2", "urgent").unwrap();
+
+    assert_eq!(
+        db.get_label_counts().unwrap(),
+        vec![("team:alpha".to_string(), 2), ("urgent".to_string(), 1)]
+    );
+    assert_eq!(db.get_issues_with_label("team:alpha").unwrap(), ["prj-a1", "prj-b2"]);
+
+    db.set_label_description("team:", Some("Owning team")).unwrap();
+    db.set_label_color("team:", Some("blue")).unwrap();
+    db.set_label_color("urgent", Some("red")).unwrap();
+    db.set_label_color("team:", None).unwrap();
+    let meta = db.get_label_meta().unwrap();
+    assert_eq!(meta.len(), 2);
+    assert_eq!(meta[0].name, "team:");
+    assert_eq!(meta[0].description.as_deref(), Some("Owning team"));
+    assert_eq!(meta[0].color, None);
+
+    // Clearing the last field drops the entry
+    db.set_label_color("urgent", None).unwrap();
+    assert_eq!(db.get_label_meta().unwrap().len(), 1);
+}
+
+#[test]
+fn rename_label_merges_into_existing() {
+    let db = Database::open_in_memory().unwrap();
+    db.create_issue(&test_issue("prj-a1", "One")).unwrap();
+    db.create_issue(&test_issue("prj-b2", "Two")).unwrap();
+    db.add_label("prj-a1", "bug-fix").unwrap();
+    db.add_label("prj-b2", "bug-fix").unwrap();
+    db.add_label("prj-b2", "bugfix").unwrap();
+    db.set_label_description("bug-fix", Some("Fixes")).unwrap();
+
+    assert_eq!(db.rename_label("bug-fix", "bugfix").unwrap(), vec!["prj-a1", "prj-b2"]);
+    assert_eq!(db.get_labels("prj-a1").unwrap(), vec!["bugfix"]);
+    assert_eq!(db.get_labels("prj-b2").unwrap(), vec!["bugfix"]);
+    assert_eq!(db.get_label_meta().unwrap()[0].name, "bugfix");
+}
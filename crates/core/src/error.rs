@@ -48,6 +48,11 @@ pub enum Error {
     #[error("invalid link relation: '{0}'\n  hint: valid relations are: import, blocks, tracks, tracked-by")]
     InvalidLinkRel(String),
 
+    #[error(
+        "invalid note kind: '{0}'\n  hint: valid kinds are: decision, blocker, worklog, handoff"
+    )]
+    InvalidNoteKind(String),
+
     #[error("{0}")]
     InvalidInput(String),
 
@@ -63,6 +68,9 @@ pub enum Error {
     #[error("corrupted data: {0}")]
     CorruptedData(String),
 
+    #[error("database schema version {version} is newer than this wok supports ({supported})\n  hint: upgrade wok to use this database")]
+    SchemaTooNew { version: u32, supported: u32 },
+
     #[error("duplicate operation: {0}")]
     DuplicateOp(String),
 
@@ -74,6 +82,15 @@ pub enum Error {
 
     #[error("no notes to replace for issue {issue_id}")]
     NoNotesToReplace { issue_id: String },
+
+    #[error("note {note_id} not found on issue {issue_id}")]
+    NoteNotFound { issue_id: String, note_id: i64 },
+
+    #[error("issue {id} has changed: expected revision {expected}, found {actual}\n  hint: run 'wok show {id}' to read it again, then retry")]
+    Conflict { id: String, expected: u32, actual: u32 },
+
+    #[error("cannot fetch {url}: {reason}")]
+    FetchFailed { url: String, reason: String },
 }
 
 /// A specialized Result type for wk-core operations.
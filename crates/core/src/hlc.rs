@@ -25,6 +25,7 @@ use crate::error::{Error, Result};
 
 /// A Hybrid Logical Clock timestamp.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Hlc {
     /// Wall clock time in milliseconds since Unix epoch.
     pub wall_ms: u64,
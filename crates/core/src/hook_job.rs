@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Prepared issue hook executions.
+//!
+//! The CLI resolves a hook's configuration into a [`HookJob`], which carries
+//! everything needed to run it. The job can then be run inline or sent to the
+//! daemon, which runs it in the background.
+
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::hook_run::HookRun;
+
+/// Maximum bytes of stderr kept in the hook run log.
+pub const STDERR_TAIL_BYTES: usize = 2000;
+
+/// Interval between checks for process exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long to wait for stderr to close after the process exits.
+const STDERR_GRACE: Duration = Duration::from_millis(200);
+
+/// A hook process ready to run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookJob {
+    /// Name of the hook from hooks config.
+    pub hook_name: String,
+    /// Event that triggered the hook (e.g., "issue.done").
+    pub event: String,
+    /// Issue the event was for.
+    pub issue_id: String,
+    /// Program to execute.
+    pub program: String,
+    /// Arguments to the program.
+    pub args: Vec<String>,
+    /// Environment variables added to the inherited environment.
+    pub env: Vec<(String, String)>,
+    /// Working directory for the process.
+    pub cwd: PathBuf,
+    /// Data written to the process's stdin (the JSON payload).
+    pub stdin: String,
+    /// Seconds before the process is killed.
+    pub timeout_secs: u64,
+}
+
+impl HookJob {
+    /// Run the process to completion, killing it after the timeout.
+    ///
+    /// Never fails: spawn errors and timeouts are reported in
+    /// [`HookRun::error`].
+    pub fn run(&self) -> HookRun {
+        let created_at = Utc::now();
+        let started = Instant::now();
+
+        let mut cmd = Command::new(&self.program);
+        cmd.args(&self.args).current_dir(&self.cwd).envs(self.env.iter().cloned());
+        let (exit_code, stderr_tail, error) =
+            run_with_timeout(cmd, self.stdin.as_bytes(), Duration::from_secs(self.timeout_secs));
+
+        HookRun {
+            id: 0,
+            hook_name: self.hook_name.clone(),
+            event: self.event.clone(),
+            issue_id: self.issue_id.clone(),
+            exit_code,
+            duration_ms: i64::try_from(started.elapsed().as_millis()).unwrap_or(i64::MAX),
+            stderr_tail,
+            error,
+            created_at,
+        }
+    }
+}
+
+/// Spawn a command, feed it stdin, and wait up to `timeout` for it to exit.
+///
+/// Returns (exit code, stderr tail, error).
+fn run_with_timeout(
+    mut cmd: Command,
+    stdin: &[u8],
+    timeout: Duration,
+) -> (Option<i32>, Option<String>, Option<String>) {
+    let mut child =
+        match cmd.stdin(Stdio::piped()).stdout(Stdio::null()).stderr(Stdio::piped()).spawn() {
+            Ok(child) => child,
+            Err(e) => return (None, None, Some(format!("failed to spawn: {}", e))),
+        };
+
+    if let Some(mut pipe) = child.stdin.take() {
+        // The hook may exit without reading stdin; that's not an error
+        let _ = pipe.write_all(stdin);
+    }
+
+    // Drain stderr on a thread so a chatty hook can't fill the pipe and block.
+    // A background process started by the hook may hold the pipe open after
+    // the hook exits, so the result is collected with a grace period rather
+    // than by joining.
+    let (tx, rx) = mpsc::channel();
+    if let Some(mut pipe) = child.stderr.take() {
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = pipe.read_to_end(&mut buf);
+            let _ = tx.send(buf);
+        });
+    }
+
+    let deadline = Instant::now() + timeout;
+    let (exit_code, error) = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break (status.code(), None),
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break (None, Some(format!("timed out after {}s", timeout.as_secs())));
+            }
+            Ok(None) => std::thread::sleep(POLL_INTERVAL),
+            Err(e) => break (None, Some(format!("failed to wait: {}", e))),
+        }
+    };
+
+    let stderr =
+        rx.recv_timeout(STDERR_GRACE).ok().map(|buf| stderr_tail(&buf)).filter(|s| !s.is_empty());
+
+    (exit_code, stderr, error)
+}
+
+/// Keep the last [`STDERR_TAIL_BYTES`] of stderr, trimmed.
+pub fn stderr_tail(buf: &[u8]) -> String {
+    let text = String::from_utf8_lossy(buf);
+    let text = text.trim();
+    if text.len() <= STDERR_TAIL_BYTES {
+        return text.to_string();
+    }
+    let mut start = text.len() - STDERR_TAIL_BYTES;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    text[start..].to_string()
+}
+
+#[cfg(test)]
+#[path = "hook_job_tests.rs"]
+mod tests;
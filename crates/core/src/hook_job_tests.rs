@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+fn job(script: &str, timeout_secs: u64) -> HookJob {
+    HookJob {
+        hook_name: "script".to_string(),
+        event: "issue.done".to_string(),
+        issue_id: "prj-1".to_string(),
+        program: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        env: vec![("WOK_ISSUE_ID".to_string(), "prj-1".to_string())],
+        cwd: std::env::temp_dir(),
+        stdin: "{\"event\":\"issue.done\"}".to_string(),
+        timeout_secs,
+    }
+}
+
+#[test]
+fn run_records_success() {
+    let run = job("cat > /dev/null", 5).run();
+
+    assert!(run.succeeded());
+    assert_eq!(run.hook_name, "script");
+    assert_eq!(run.event, "issue.done");
+    assert_eq!(run.issue_id, "prj-1");
+    assert!(run.stderr_tail.is_none());
+}
+
+#[test]
+fn run_passes_env_and_stdin() {
+    let run = job("grep -q issue.done && test \"$WOK_ISSUE_ID\" = prj-1", 5).run();
+    assert_eq!(run.exit_code, Some(0));
+}
+
+#[test]
+fn run_captures_exit_code_and_stderr() {
+    let run = job("echo oops >&2; exit 3", 5).run();
+
+    assert_eq!(run.exit_code, Some(3));
+    assert_eq!(run.stderr_tail.as_deref(), Some("oops"));
+    assert!(run.error.is_none());
+}
+
+#[test]
+fn run_kills_on_timeout() {
+    let run = job("sleep 5", 0).run();
+
+    assert!(run.exit_code.is_none());
+    assert_eq!(run.error.as_deref(), Some("timed out after 0s"));
+}
+
+#[test]
+fn run_reports_spawn_failure() {
+    let mut job = job("", 5);
+    job.program = "/nonexistent/wok-hook".to_string();
+
+    let run = job.run();
+
+    assert!(run.exit_code.is_none());
+    assert!(run.error.unwrap().starts_with("failed to spawn"));
+}
+
+#[test]
+fn job_round_trips_through_json() {
+    let job = job("true", 5);
+    let json = serde_json::to_string(&job).unwrap();
+    assert_eq!(serde_json::from_str::<HookJob>(&json).unwrap(), job);
+}
+
+#[test]
+fn stderr_tail_keeps_end() {
+    let long = "x".repeat(STDERR_TAIL_BYTES) + "END";
+    let tail = stderr_tail(long.as_bytes());
+    assert_eq!(tail.len(), STDERR_TAIL_BYTES);
+    assert!(tail.ends_with("END"));
+}
+
+#[test]
+fn stderr_tail_respects_char_boundaries() {
+    let long = "é".repeat(STDERR_TAIL_BYTES);
+    let tail = stderr_tail(long.as_bytes());
+    assert!(tail.len() <= STDERR_TAIL_BYTES);
+    assert!(tail.chars().all(|c| c == 'é'));
+}
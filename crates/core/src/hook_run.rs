@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Records of issue hook executions.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The outcome of one issue hook execution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookRun {
+    /// Database row ID (0 before insertion).
+    pub id: i64,
+    /// Name of the hook from hooks config.
+    pub hook_name: String,
+    /// Event that triggered the hook (e.g., "issue.done").
+    pub event: String,
+    /// Issue the event was for.
+    pub issue_id: String,
+    /// Process exit code; None if it was killed, timed out, or never started.
+    pub exit_code: Option<i32>,
+    /// Wall-clock duration in milliseconds.
+    pub duration_ms: i64,
+    /// Last part of the process's stderr, if any.
+    pub stderr_tail: Option<String>,
+    /// Failure to spawn or complete the process (e.g., timeout).
+    pub error: Option<String>,
+    /// When the run started.
+    pub created_at: DateTime<Utc>,
+}
+
+impl HookRun {
+    /// True if the hook ran to completion and exited 0.
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none() && self.exit_code == Some(0)
+    }
+
+    /// Why the run failed, or None if it succeeded.
+    pub fn failure_reason(&self) -> Option<String> {
+        match (&self.error, self.exit_code) {
+            (Some(error), _) => Some(error.clone()),
+            (None, Some(0)) => None,
+            (None, Some(code)) => Some(format!("exit code {}", code)),
+            (None, None) => Some("terminated by signal".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "hook_run_tests.rs"]
+mod tests;
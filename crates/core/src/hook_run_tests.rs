@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn run(exit_code: Option<i32>, error: Option<&str>) -> HookRun {
+    HookRun {
+        id: 0,
+        hook_name: "notify".to_string(),
+        event: "issue.done".to_string(),
+        issue_id: "prj-1".to_string(),
+        exit_code,
+        duration_ms: 5,
+        stderr_tail: None,
+        error: error.map(String::from),
+        created_at: Utc::now(),
+    }
+}
+
+#[test]
+fn succeeded_requires_zero_exit_and_no_error() {
+    assert!(run(Some(0), None).succeeded());
+    assert!(!run(Some(1), None).succeeded());
+    assert!(!run(None, None).succeeded());
+    assert!(!run(None, Some("timed out after 10s")).succeeded());
+}
+
+#[test]
+fn failure_reason_prefers_error_over_exit_code() {
+    assert_eq!(run(Some(0), None).failure_reason(), None);
+    assert_eq!(run(Some(3), None).failure_reason().as_deref(), Some("exit code 3"));
+    assert_eq!(run(None, None).failure_reason().as_deref(), Some("terminated by signal"));
+    assert_eq!(
+        run(None, Some("timed out after 10s")).failure_reason().as_deref(),
+        Some("timed out after 10s")
+    );
+}
@@ -71,46 +71,95 @@ pub struct HookStatus {
 /// The events where wk hooks should be installed.
 const WK_HOOK_EVENTS: &[&str] = &["PreCompact", "SessionStart"];
 
-/// Check if a hook entry contains a "wk prime" command.
-///
-/// A hook entry is considered a wk hook if any command in its hooks array
-/// contains "wk prime". This handles:
-/// - `wk prime` (plain command)
-/// - `/path/to/wk prime` (full path)
-/// - `wk prime --args` (with arguments)
-pub fn is_wk_hook(hook_entry: &serde_json::Value) -> bool {
+/// Commands wk installs as hooks. Entries running any of them are wk hooks.
+const WK_HOOK_COMMANDS: &[&str] = &["wk prime", "wk hooks context", "wk hooks note-failure"];
+
+/// Optional wk hooks installed alongside `wk prime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookFeature {
+    /// Inject in-progress and ready issues at session start.
+    Context,
+    /// Note failed shell commands on the in-progress issue.
+    FailureNotes,
+}
+
+/// A hook entry wk installs into Claude Code settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HookTemplate {
+    /// Claude Code event name (e.g., "SessionStart").
+    pub event: &'static str,
+    /// Tool matcher; empty matches everything.
+    pub matcher: &'static str,
+    /// Command to run.
+    pub command: &'static str,
+}
+
+impl HookFeature {
+    /// The hook entry this feature installs.
+    pub fn template(&self) -> HookTemplate {
+        match self {
+            HookFeature::Context => {
+                HookTemplate { event: "SessionStart", matcher: "", command: "wk hooks context" }
+            }
+            HookFeature::FailureNotes => HookTemplate {
+                event: "PostToolUse",
+                matcher: "Bash",
+                command: "wk hooks note-failure",
+            },
+        }
+    }
+}
+
+/// The hook entries to install: `wk prime` plus any optional features.
+pub fn hook_templates(features: &[HookFeature]) -> Vec<HookTemplate> {
+    let prime =
+        WK_HOOK_EVENTS.iter().map(|event| HookTemplate { event, matcher: "", command: "wk prime" });
+    prime.chain(features.iter().map(HookFeature::template)).collect()
+}
+
+/// Check if a command runs the given wk command, by name or full path.
+fn runs_command(cmd: &str, wk_command: &str) -> bool {
+    let trimmed = cmd.trim();
+    trimmed.starts_with(wk_command) || trimmed.contains(&format!("/{}", wk_command))
+}
+
+/// Check if any command in a hook entry satisfies `pred`.
+fn any_command(hook_entry: &serde_json::Value, pred: impl Fn(&str) -> bool) -> bool {
     hook_entry
         .get("hooks")
         .and_then(|h| h.as_array())
         .map(|hooks| {
-            hooks.iter().any(|h| {
-                h.get("command")
-                    .and_then(|c| c.as_str())
-                    .map(|cmd| {
-                        let trimmed = cmd.trim();
-                        trimmed.starts_with("wk prime") || trimmed.contains("/wk prime")
-                    })
-                    .unwrap_or(false)
-            })
+            hooks.iter().any(|h| h.get("command").and_then(|c| c.as_str()).is_some_and(&pred))
         })
         .unwrap_or(false)
 }
 
-/// Create a wk hook entry for installation.
-fn create_wk_hook_entry() -> serde_json::Value {
+/// Check if a hook entry contains a wk hook command.
+///
+/// A hook entry is considered a wk hook if any command in its hooks array
+/// runs `wk prime` or one of the `wk hooks` handlers. This handles:
+/// - `wk prime` (plain command)
+/// - `/path/to/wk prime` (full path)
+/// - `wk prime --args` (with arguments)
+pub fn is_wk_hook(hook_entry: &serde_json::Value) -> bool {
+    any_command(hook_entry, |cmd| WK_HOOK_COMMANDS.iter().any(|wk| runs_command(cmd, wk)))
+}
+
+/// Create a hook entry for installation.
+fn create_hook_entry(template: &HookTemplate) -> serde_json::Value {
     serde_json::json!({
-        "matcher": "",
-        "hooks": [{"type": "command", "command": "wk prime"}]
+        "matcher": template.matcher,
+        "hooks": [{"type": "command", "command": template.command}]
     })
 }
 
-/// Merge wk hooks into existing configuration.
+/// Merge hook entries into existing configuration.
 ///
 /// This function:
 /// - Preserves all non-wk hooks
-/// - Only adds wk hooks if not already present (idempotent)
+/// - Only adds an entry if its command is not already present (idempotent)
 /// - Maintains existing hook order, appends wk hooks at end
-fn merge_wk_hooks(settings: &mut serde_json::Value) {
+fn merge_hook_templates(settings: &mut serde_json::Value, templates: &[HookTemplate]) {
     // Ensure hooks object exists
     if settings.get("hooks").is_none() {
         settings["hooks"] = serde_json::json!({});
@@ -121,17 +170,17 @@ fn merge_wk_hooks(settings: &mut serde_json::Value) {
         None => return,
     };
 
-    for event in WK_HOOK_EVENTS {
+    for template in templates {
         // Ensure event array exists
-        if !hooks.contains_key(*event) {
-            hooks.insert(event.to_string(), serde_json::json!([]));
-        }
-
-        if let Some(event_hooks) = hooks.get_mut(*event).and_then(|e| e.as_array_mut()) {
-            // Check if wk hook already exists
-            let has_wk = event_hooks.iter().any(is_wk_hook);
-            if !has_wk {
-                event_hooks.push(create_wk_hook_entry());
+        let event_hooks =
+            hooks.entry(template.event.to_string()).or_insert_with(|| serde_json::json!([]));
+
+        if let Some(event_hooks) = event_hooks.as_array_mut() {
+            let present = event_hooks
+                .iter()
+                .any(|entry| any_command(entry, |cmd| runs_command(cmd, template.command)));
+            if !present {
+                event_hooks.push(create_hook_entry(template));
             }
         }
     }
@@ -144,6 +193,11 @@ fn merge_wk_hooks(settings: &mut serde_json::Value) {
 /// - Only add wk hooks if not already present (idempotent)
 /// - Maintain existing hook order
 pub fn install_hooks(scope: HookScope) -> io::Result<PathBuf> {
+    install_hooks_with(scope, &[])
+}
+
+/// Install hooks, including optional features, to the specified scope.
+pub fn install_hooks_with(scope: HookScope, features: &[HookFeature]) -> io::Result<PathBuf> {
     let path = scope.settings_path()?;
 
     // Ensure parent directory exists
@@ -160,7 +214,7 @@ pub fn install_hooks(scope: HookScope) -> io::Result<PathBuf> {
     };
 
     // Smart merge wk hooks
-    merge_wk_hooks(&mut settings);
+    merge_hook_templates(&mut settings, &hook_templates(features));
 
     // Write back
     let content = serde_json::to_string_pretty(&settings)
@@ -173,7 +227,7 @@ pub fn install_hooks(scope: HookScope) -> io::Result<PathBuf> {
 /// Remove wk hooks from configuration while preserving others.
 ///
 /// This function:
-/// - Only removes hooks containing "wk prime"
+/// - Only removes wk hooks (see [`is_wk_hook`])
 /// - Preserves other hooks in the same event array
 /// - Removes event key only if array becomes empty
 /// - Removes hooks key only if object becomes empty
@@ -210,7 +264,7 @@ fn remove_wk_hooks(settings: &mut serde_json::Value) {
 /// Uninstall hooks from the specified scope.
 ///
 /// Uses smart removal to:
-/// - Only remove hooks containing "wk prime"
+/// - Only remove wk hooks (see [`is_wk_hook`])
 /// - Preserve other hooks in the same event array
 /// - Remove event key only if array becomes empty
 /// - Remove hooks key only if object becomes empty
@@ -20,6 +20,11 @@ where
     // temp is dropped here, cleaning up the directory
 }
 
+/// Merge the default `wk prime` hooks.
+fn merge_wk_hooks(settings: &mut serde_json::Value) {
+    merge_hook_templates(settings, &hook_templates(&[]));
+}
+
 /// Install hooks using absolute paths.
 fn install_hooks_at(base: &Path, scope: HookScope) -> io::Result<PathBuf> {
     let rel_path = scope.settings_path()?;
@@ -530,3 +535,62 @@ fn install_hooks_handles_malformed_json() {
         let _: serde_json::Value = serde_json::from_str(&content).unwrap();
     });
 }
+
+#[test]
+fn is_wk_hook_detects_hooks_handlers() {
+    for command in ["wk hooks context", "/usr/local/bin/wk hooks note-failure"] {
+        let hook = serde_json::json!({
+            "matcher": "",
+            "hooks": [{"type": "command", "command": command}]
+        });
+        assert!(is_wk_hook(&hook), "{command}");
+    }
+}
+
+#[test]
+fn hook_templates_add_features_after_prime() {
+    let templates = hook_templates(&[HookFeature::Context, HookFeature::FailureNotes]);
+    let commands: Vec<_> = templates.iter().map(|t| (t.event, t.command)).collect();
+    assert_eq!(
+        commands,
+        vec![
+            ("PreCompact", "wk prime"),
+            ("SessionStart", "wk prime"),
+            ("SessionStart", "wk hooks context"),
+            ("PostToolUse", "wk hooks note-failure"),
+        ]
+    );
+}
+
+#[test]
+fn merge_features_adds_entries_alongside_prime() {
+    let mut settings = serde_json::json!({});
+    let templates = hook_templates(&[HookFeature::Context, HookFeature::FailureNotes]);
+    merge_hook_templates(&mut settings, &templates);
+    merge_hook_templates(&mut settings, &templates);
+
+    let session_start = settings["hooks"]["SessionStart"].as_array().unwrap();
+    assert_eq!(session_start.len(), 2);
+    let post_tool_use = settings["hooks"]["PostToolUse"].as_array().unwrap();
+    assert_eq!(post_tool_use.len(), 1);
+    assert_eq!(post_tool_use[0]["matcher"], "Bash");
+    assert_eq!(post_tool_use[0]["hooks"][0]["command"], "wk hooks note-failure");
+}
+
+#[test]
+fn merge_feature_into_existing_prime_install() {
+    let mut settings = serde_json::json!({});
+    merge_wk_hooks(&mut settings);
+    merge_hook_templates(&mut settings, &hook_templates(&[HookFeature::Context]));
+
+    assert_eq!(settings["hooks"]["PreCompact"].as_array().unwrap().len(), 1);
+    assert_eq!(settings["hooks"]["SessionStart"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn remove_deletes_feature_hooks() {
+    let mut settings = serde_json::json!({});
+    merge_hook_templates(&mut settings, &hook_templates(&[HookFeature::FailureNotes]));
+    remove_wk_hooks(&mut settings);
+    assert!(settings.get("hooks").is_none());
+}
@@ -145,6 +145,7 @@ impl FromStr for Status {
 
 /// The primary entity representing a tracked work item.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Issue {
     /// Unique identifier (format: `{prefix}-{hash}`).
     pub id: String,
@@ -182,6 +183,16 @@ pub struct Issue {
     /// HLC timestamp of last assignee change (for conflict resolution).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_assignee_hlc: Option<Hlc>,
+    /// Bumped by every change to the issue's fields or labels; a mutation
+    /// can require the revision it read with `--if-match`. Local to the
+    /// database, so not exported or synced.
+    #[serde(skip, default = "first_revision")]
+    pub revision: u32,
+}
+
+/// The revision of a newly created issue.
+fn first_revision() -> u32 {
+    1
 }
 
 impl Issue {
@@ -207,6 +218,7 @@ impl Issue {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: 1,
         }
     }
 }
@@ -242,14 +254,26 @@ pub enum Action {
     Linked,
     /// An external link was removed.
     Unlinked,
-    /// A note was added.
+    /// A note was added, edited or restored.
     Noted,
+    /// A note was deleted.
+    Unnoted,
     /// A blocking issue was resolved.
     Unblocked,
     /// Issue was assigned to someone.
     Assigned,
     /// Issue assignment was removed.
     Unassigned,
+    /// A `relates-to` link to another issue was added.
+    Associated,
+    /// A `relates-to` link to another issue was removed.
+    Dissociated,
+    /// Issue was marked as a duplicate of another.
+    Duplicated,
+    /// A duplicate-of mark was removed.
+    Unduplicated,
+    /// Issue stayed in its status longer than the configured aging threshold.
+    Stale,
 }
 
 impl Action {
@@ -270,9 +294,26 @@ impl Action {
             Action::Linked => "linked",
             Action::Unlinked => "unlinked",
             Action::Noted => "noted",
+            Action::Unnoted => "unnoted",
             Action::Unblocked => "unblocked",
             Action::Assigned => "assigned",
             Action::Unassigned => "unassigned",
+            Action::Associated => "associated",
+            Action::Dissociated => "dissociated",
+            Action::Duplicated => "duplicated",
+            Action::Unduplicated => "unduplicated",
+            Action::Stale => "stale",
+        }
+    }
+
+    /// The action logged when an issue's status changes from `from` to `to`.
+    pub fn for_status_change(from: Status, to: Status) -> Action {
+        match to {
+            Status::Todo | Status::InProgress if from.is_terminal() => Action::Reopened,
+            Status::InProgress => Action::Started,
+            Status::Todo => Action::Stopped,
+            Status::Done => Action::Done,
+            Status::Closed => Action::Closed,
         }
     }
 }
@@ -302,9 +343,15 @@ impl FromStr for Action {
             "linked" => Ok(Action::Linked),
             "unlinked" => Ok(Action::Unlinked),
             "noted" => Ok(Action::Noted),
+            "unnoted" => Ok(Action::Unnoted),
             "unblocked" => Ok(Action::Unblocked),
             "assigned" => Ok(Action::Assigned),
             "unassigned" => Ok(Action::Unassigned),
+            "associated" => Ok(Action::Associated),
+            "dissociated" => Ok(Action::Dissociated),
+            "duplicated" => Ok(Action::Duplicated),
+            "unduplicated" => Ok(Action::Unduplicated),
+            "stale" => Ok(Action::Stale),
             _ => Err(Error::InvalidAction(s.to_string())),
         }
     }
@@ -329,6 +376,9 @@ pub struct Event {
     /// User-provided explanation (for closes, reopens).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    /// Who made the change: a person or agent name, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
     /// When the event occurred.
     pub created_at: DateTime<Utc>,
 }
@@ -343,6 +393,7 @@ impl Event {
             old_value: None,
             new_value: None,
             reason: None,
+            actor: None,
             created_at: Utc::now(),
         }
     }
@@ -360,6 +411,12 @@ impl Event {
         self
     }
 
+    /// Sets who made the change (builder pattern).
+    pub fn with_actor(mut self, actor: Option<String>) -> Self {
+        self.actor = actor;
+        self
+    }
+
     /// Sets a specific timestamp for this event.
     pub fn with_timestamp(mut self, created_at: DateTime<Utc>) -> Self {
         self.created_at = created_at;
@@ -378,6 +435,14 @@ pub enum Relation {
     TrackedBy,
     /// The from_id tracks to_id (organizational hierarchy).
     Tracks,
+    /// The from_id mentions to_id in a note or its description. Recorded
+    /// automatically; never blocks.
+    References,
+    /// The from_id is related to to_id. Stored in both directions; never
+    /// blocks.
+    RelatesTo,
+    /// The from_id duplicates to_id. Never blocks.
+    DuplicateOf,
 }
 
 impl Relation {
@@ -387,6 +452,30 @@ impl Relation {
             Relation::Blocks => "blocks",
             Relation::TrackedBy => "tracked-by",
             Relation::Tracks => "tracks",
+            Relation::References => "references",
+            Relation::RelatesTo => "relates-to",
+            Relation::DuplicateOf => "duplicate-of",
+        }
+    }
+
+    /// The actions logged when this relation is added and removed.
+    pub fn actions(&self) -> (Action, Action) {
+        match self {
+            Relation::RelatesTo => (Action::Associated, Action::Dissociated),
+            Relation::DuplicateOf => (Action::Duplicated, Action::Unduplicated),
+            Relation::Blocks | Relation::TrackedBy | Relation::Tracks | Relation::References => {
+                (Action::Related, Action::Unrelated)
+            }
+        }
+    }
+
+    /// The event value for this relation to `to_id`: `blocks prj-2` under
+    /// the generic related/unrelated actions, the bare ID under the
+    /// dedicated ones.
+    pub fn event_value(&self, to_id: &str) -> String {
+        match self.actions().0 {
+            Action::Related => format!("{self} {to_id}"),
+            _ => to_id.to_string(),
         }
     }
 }
@@ -405,6 +494,9 @@ impl FromStr for Relation {
             "blocks" => Ok(Relation::Blocks),
             "tracked-by" | "tracked_by" => Ok(Relation::TrackedBy),
             "tracks" => Ok(Relation::Tracks),
+            "references" => Ok(Relation::References),
+            "relates-to" | "relates_to" => Ok(Relation::RelatesTo),
+            "duplicate-of" | "duplicate_of" => Ok(Relation::DuplicateOf),
             _ => Err(Error::InvalidRelation(s.to_string())),
         }
     }
@@ -424,6 +516,59 @@ pub struct Dependency {
     pub created_at: DateTime<Utc>,
 }
 
+/// Structured purpose of a note.
+///
+/// Notes without a kind are grouped by the status they were added at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NoteKind {
+    /// A decision and its rationale.
+    Decision,
+    /// Something preventing progress.
+    Blocker,
+    /// A record of work done.
+    Worklog,
+    /// Context for whoever picks up the issue next.
+    Handoff,
+}
+
+impl NoteKind {
+    /// All kinds, in display order.
+    pub const ALL: [NoteKind; 4] =
+        [NoteKind::Decision, NoteKind::Blocker, NoteKind::Worklog, NoteKind::Handoff];
+
+    /// Returns the string representation used in storage and display.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteKind::Decision => "decision",
+            NoteKind::Blocker => "blocker",
+            NoteKind::Worklog => "worklog",
+            NoteKind::Handoff => "handoff",
+        }
+    }
+}
+
+impl fmt::Display for NoteKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for NoteKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "decision" => Ok(NoteKind::Decision),
+            "blocker" => Ok(NoteKind::Blocker),
+            "worklog" => Ok(NoteKind::Worklog),
+            "handoff" => Ok(NoteKind::Handoff),
+            _ => Err(Error::InvalidNoteKind(s.to_string())),
+        }
+    }
+}
+
 /// A note attached to an issue.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -434,8 +579,14 @@ pub struct Note {
     pub issue_id: String,
     /// Status when note was added.
     pub status: Status,
+    /// Structured kind, if the note was given one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<NoteKind>,
     /// The note content.
     pub content: String,
+    /// Who wrote it: a person or agent name, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
     /// When the note was created.
     pub created_at: DateTime<Utc>,
 }
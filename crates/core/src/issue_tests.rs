@@ -62,6 +62,40 @@ fn status_from_str_invalid(input: &str) {
     assert!(input.parse::<Status>().is_err());
 }
 
+// NoteKind parsing tests
+#[parameterized(
+    decision = { "decision", NoteKind::Decision },
+    blocker = { "blocker", NoteKind::Blocker },
+    worklog = { "worklog", NoteKind::Worklog },
+    handoff = { "handoff", NoteKind::Handoff },
+    uppercase = { "DECISION", NoteKind::Decision },
+)]
+fn note_kind_from_str_valid(input: &str, expected: NoteKind) {
+    assert_eq!(input.parse::<NoteKind>().unwrap(), expected);
+}
+
+#[test]
+fn note_kind_from_str_invalid() {
+    let err = "todo".parse::<NoteKind>().unwrap_err();
+    assert!(err.to_string().contains("valid kinds are"));
+}
+
+#[test]
+fn note_kind_round_trips_as_str() {
+    for kind in NoteKind::ALL {
+        assert_eq!(kind.as_str().parse::<NoteKind>().unwrap(), kind);
+        assert_eq!(serde_json::to_string(&kind).unwrap(), format!("\"{}\"", kind));
+    }
+}
+
+#[test]
+fn note_without_kind_omits_field() {
+    let json = r#"{"id":1,"issue_id":"prj-1","status":"todo","content":"x","created_at":"2026-01-01T00:00:00Z"}"#;
+    let note: Note = serde_json::from_str(json).unwrap();
+    assert!(note.kind.is_none());
+    assert!(!serde_json::to_string(&note).unwrap().contains("kind"));
+}
+
 // Valid status transitions (all non-self transitions are valid)
 #[parameterized(
     todo_to_in_progress = { Status::Todo, Status::InProgress },
@@ -126,7 +160,13 @@ fn status_is_active(status: Status, expected: bool) {
     related = { "related", Action::Related },
     unrelated = { "unrelated", Action::Unrelated },
     noted = { "noted", Action::Noted },
+    unnoted = { "unnoted", Action::Unnoted },
     unblocked = { "unblocked", Action::Unblocked },
+    associated = { "associated", Action::Associated },
+    dissociated = { "dissociated", Action::Dissociated },
+    duplicated = { "duplicated", Action::Duplicated },
+    unduplicated = { "unduplicated", Action::Unduplicated },
+    stale = { "stale", Action::Stale },
 )]
 fn action_from_str_valid(input: &str, expected: Action) {
     assert_eq!(input.parse::<Action>().unwrap(), expected);
@@ -137,12 +177,26 @@ fn action_from_str_invalid() {
     assert!("invalid".parse::<Action>().is_err());
 }
 
+#[parameterized(
+    start = { Status::Todo, Status::InProgress, Action::Started },
+    stop = { Status::InProgress, Status::Todo, Action::Stopped },
+    done = { Status::InProgress, Status::Done, Action::Done },
+    close = { Status::Todo, Status::Closed, Action::Closed },
+    reopen_to_todo = { Status::Done, Status::Todo, Action::Reopened },
+    reopen_in_progress = { Status::Closed, Status::InProgress, Action::Reopened },
+)]
+fn action_for_status_change(from: Status, to: Status, expected: Action) {
+    assert_eq!(Action::for_status_change(from, to), expected);
+}
+
 // Relation parsing tests
 #[parameterized(
     blocks = { "blocks", Relation::Blocks },
     tracked_by_kebab = { "tracked-by", Relation::TrackedBy },
     tracked_by_snake = { "tracked_by", Relation::TrackedBy },
     tracks = { "tracks", Relation::Tracks },
+    relates_to = { "relates-to", Relation::RelatesTo },
+    duplicate_of = { "duplicate-of", Relation::DuplicateOf },
 )]
 fn relation_from_str_valid(input: &str, expected: Relation) {
     assert_eq!(input.parse::<Relation>().unwrap(), expected);
@@ -153,6 +207,13 @@ fn relation_from_str_invalid() {
     assert!("invalid".parse::<Relation>().is_err());
 }
 
+#[test]
+fn relation_event_value() {
+    assert_eq!(Relation::Blocks.event_value("prj-2"), "blocks prj-2");
+    assert_eq!(Relation::TrackedBy.event_value("prj-2"), "tracked-by prj-2");
+    assert_eq!(Relation::DuplicateOf.event_value("prj-2"), "prj-2");
+}
+
 #[test]
 fn event_builder_pattern() {
     let event = Event::new("test-123".to_string(), Action::Edited)
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Label namespaces and registry entries.
+//!
+//! Labels are free-form strings. One written `name:value` (`team:alpha`)
+//! belongs to the `name` namespace. The registry attaches a description and
+//! a display color to a label, or to a whole namespace written with its
+//! trailing colon (`team:`).
+
+use serde::{Deserialize, Serialize};
+
+/// Registry entry for a label or a namespace (`team:`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LabelMeta {
+    /// The label, or a namespace ending in `:`.
+    pub name: String,
+    /// What the label means.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Display color: a name such as `red` or a 256-color code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// The namespace of `name:value` labels; `None` for labels without one.
+pub fn namespace(label: &str) -> Option<&str> {
+    match label.split_once(':') {
+        Some((ns, value)) if !ns.is_empty() && !value.is_empty() => Some(ns),
+        _ => None,
+    }
+}
+
+/// Whether `name` names a namespace (`team:`) rather than a label.
+pub fn is_namespace(name: &str) -> bool {
+    name.len() > 1 && name.ends_with(':') && !name[..name.len() - 1].contains(':')
+}
+
+#[cfg(test)]
+#[path = "label_tests.rs"]
+mod tests;
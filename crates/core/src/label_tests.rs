@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn namespace_is_the_part_before_the_colon() {
+    assert_eq!(namespace("team:alpha"), Some("team"));
+    assert_eq!(namespace("due:2026-01-15"), Some("due"));
+    assert_eq!(namespace("urgent"), None);
+    assert_eq!(namespace("team:"), None);
+    assert_eq!(namespace(":alpha"), None);
+}
+
+#[test]
+fn namespace_keys_end_in_a_single_colon() {
+    assert!(is_namespace("team:"));
+    assert!(!is_namespace("team:alpha"));
+    assert!(!is_namespace(":"));
+    assert!(!is_namespace("a:b:"));
+}
@@ -10,18 +10,32 @@ pub mod db;
 pub mod detect;
 pub mod error;
 pub mod hlc;
+pub mod hook_job;
+pub mod hook_run;
 pub mod hooks;
 pub mod identity;
 pub mod issue;
 pub mod jsonl;
+pub mod label;
 pub mod link;
+pub mod link_meta;
+pub mod mention;
 pub mod merge;
+pub mod migrate;
 pub mod op;
+pub mod query;
+pub mod retry;
 
-pub use db::Database;
+pub use db::{Database, RowStream};
 pub use error::{Error, Result};
 pub use hlc::{ClockSource, Hlc, HlcClock, SystemClock};
-pub use issue::{Action, Dependency, Event, Issue, IssueType, Note, Relation, Status};
+pub use hook_job::HookJob;
+pub use hook_run::HookRun;
+pub use issue::{Action, Dependency, Event, Issue, IssueType, Note, NoteKind, Relation, Status};
+pub use label::LabelMeta;
 pub use link::{Link, LinkRel, LinkType, PrefixInfo};
+pub use link_meta::{ApiConfig, LinkMeta};
 pub use merge::Merge;
 pub use op::{Op, OpId, OpPayload};
+pub use query::{Cmp, Cond, IssueQuery, QueryOrder, TimeField};
+pub use retry::retry_busy;
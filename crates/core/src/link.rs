@@ -14,26 +14,36 @@ use std::str::FromStr;
 use crate::error::{Error, Result};
 
 /// Type of external link (auto-detected from URL).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LinkType {
     Github,
     Jira,
     Gitlab,
     Confluence,
+    /// A provider configured by a project, by name (e.g. "linear").
+    Other(String),
 }
 
 impl LinkType {
     /// Returns the string representation used in storage and display.
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             LinkType::Github => "github",
             LinkType::Jira => "jira",
             LinkType::Gitlab => "gitlab",
             LinkType::Confluence => "confluence",
+            LinkType::Other(name) => name,
         }
     }
+
+    /// The built-in type called `name`, or [`LinkType::Other`].
+    ///
+    /// Unlike [`FromStr`], which only accepts built-in names, this never
+    /// fails: stored and imported links may name a provider configured in
+    /// some project.
+    pub fn from_name(name: &str) -> Self {
+        name.parse().unwrap_or_else(|_| LinkType::Other(name.to_lowercase()))
+    }
 }
 
 impl fmt::Display for LinkType {
@@ -56,6 +66,39 @@ impl FromStr for LinkType {
     }
 }
 
+impl Serialize for LinkType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(LinkType::from_name(&name))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for LinkType {
+    fn schema_name() -> String {
+        "LinkType".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = generator.subschema_for::<String>().into_object();
+        schema.metadata().description = Some(
+            "github, jira, gitlab, confluence, or a provider configured in [links]".to_string(),
+        );
+        schema.into()
+    }
+}
+
 /// Relationship of external link to issue.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -125,6 +168,10 @@ pub struct Link {
     pub rel: Option<LinkRel>,
     /// When the link was created.
     pub created_at: DateTime<Utc>,
+    /// When the external issue was marked resolved. A `blocks` link only
+    /// blocks its issue until then.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime<Utc>>,
 }
 
 impl Link {
@@ -138,6 +185,7 @@ impl Link {
             external_id: None,
             rel: None,
             created_at: Utc::now(),
+            resolved_at: None,
         }
     }
 
@@ -0,0 +1,386 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Titles and states of the external issues that links point at.
+//!
+//! `wok link refresh` and the daemon ask the provider's API about GitHub,
+//! GitLab and Jira links and store the answer by URL, so `wok show` can
+//! print `PE-5555 (Closed): Fix auth` where it would print the URL. Requests
+//! go through `curl`, which reads them from stdin so tokens stay out of the
+//! process list.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::link::{Link, LinkType};
+
+/// Seconds a single API request may take.
+const TIMEOUT_SECS: u32 = 20;
+
+/// Minutes between the daemon's refreshes when `refresh_minutes` is unset.
+pub const DEFAULT_REFRESH_MINUTES: u64 = 60;
+
+/// Longest time between refreshes, a year; longer settings are clamped to it.
+pub const MAX_REFRESH_MINUTES: u64 = 365 * 24 * 60;
+
+/// States, lowercased, in which an external issue no longer blocks.
+const RESOLVED_STATES: &[&str] =
+    &["closed", "merged", "done", "resolved", "won't do", "won't fix", "cancelled", "canceled"];
+
+/// What a link's provider last said about the external issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LinkMeta {
+    /// The external issue's title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Its state as the provider names it (`open`, `merged`, `Closed`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Why the last refresh failed; title and state are then older.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// When the provider was last asked.
+    pub refreshed_at: DateTime<Utc>,
+}
+
+impl LinkMeta {
+    /// Whether the state says the external issue is finished: closed,
+    /// merged, done, resolved or abandoned.
+    pub fn is_resolved(&self) -> bool {
+        self.state.as_deref().is_some_and(|s| RESOLVED_STATES.contains(&s.to_lowercase().as_str()))
+    }
+}
+
+/// The `[api]` config table: how to reach each provider's API.
+///
+/// ```toml
+/// [api]
+/// refresh_minutes = 30
+///
+/// [api.github]
+/// token_env = "GH_TOKEN"
+///
+/// [api.jira]
+/// url = "https://acme.atlassian.net"
+/// user = "me@acme.com"
+/// token_env = "JIRA_TOKEN"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<ApiAccess>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitlab: Option<ApiAccess>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira: Option<ApiAccess>,
+    /// Minutes between the daemon's refreshes; 0 stops them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_minutes: Option<u64>,
+}
+
+impl ApiConfig {
+    pub fn is_empty(&self) -> bool {
+        *self == ApiConfig::default()
+    }
+
+    /// Whether any provider has a table, which opts in to the daemon's
+    /// background refreshes.
+    pub fn has_providers(&self) -> bool {
+        self.github.is_some() || self.gitlab.is_some() || self.jira.is_some()
+    }
+
+    /// Minutes between the daemon's refreshes, at most
+    /// [`MAX_REFRESH_MINUTES`]; `None` when it should not refresh at all.
+    pub fn refresh_minutes(&self) -> Option<u64> {
+        match self.refresh_minutes.unwrap_or(DEFAULT_REFRESH_MINUTES) {
+            0 => None,
+            _ if !self.has_providers() => None,
+            minutes => Some(minutes.min(MAX_REFRESH_MINUTES)),
+        }
+    }
+
+    fn access(&self, link_type: &LinkType) -> ApiAccess {
+        let access = match link_type {
+            LinkType::Github => &self.github,
+            LinkType::Gitlab => &self.gitlab,
+            LinkType::Jira => &self.jira,
+            _ => &None,
+        };
+        access.clone().unwrap_or_default()
+    }
+}
+
+/// One provider's table under `[api]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiAccess {
+    /// API token. Prefer `token_env` outside a private user config.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Environment variable holding the token; defaults to `GITHUB_TOKEN`,
+    /// `GITLAB_TOKEN` or `JIRA_API_TOKEN`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_env: Option<String>,
+    /// Jira account the token belongs to, for basic auth; without it the
+    /// token is sent as a bearer token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// API base URL, for self-hosted instances and `jira://` links.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl ApiAccess {
+    fn token(&self, default_env: &str) -> Option<String> {
+        if let Some(token) = &self.token {
+            return Some(token.clone());
+        }
+        let var = self.token_env.as_deref().unwrap_or(default_env);
+        std::env::var(var).ok().filter(|t| !t.is_empty())
+    }
+
+    fn base(&self, fallback: impl FnOnce() -> Option<String>) -> Option<String> {
+        self.url.clone().or_else(fallback).map(|url| url.trim_end_matches('/').to_string())
+    }
+}
+
+/// Whether `link` points at an issue [`fetch`] can ask about.
+pub fn is_refreshable(link: &Link) -> bool {
+    matches!(link.link_type, Some(LinkType::Github | LinkType::Gitlab | LinkType::Jira))
+        && link.url.is_some()
+}
+
+/// An API request for one external issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiRequest {
+    pub url: String,
+    pub headers: Vec<String>,
+    /// `user:token` for basic auth.
+    pub user: Option<String>,
+}
+
+impl ApiRequest {
+    fn new(url: String) -> Self {
+        ApiRequest { url, headers: Vec::new(), user: None }
+    }
+
+    fn header(mut self, header: String) -> Self {
+        self.headers.push(header);
+        self
+    }
+
+    /// The request as a curl config file.
+    pub fn curl_config(&self) -> String {
+        let mut config = format!("url = {}\n", quote(&self.url));
+        for header in &self.headers {
+            config.push_str(&format!("header = {}\n", quote(header)));
+        }
+        if let Some(user) = &self.user {
+            config.push_str(&format!("user = {}\n", quote(user)));
+        }
+        config
+    }
+
+    /// Send the request, returning the response body.
+    fn send(&self) -> Result<String> {
+        let failed = |reason: String| Error::FetchFailed { url: self.url.clone(), reason };
+        let timeout = TIMEOUT_SECS.to_string();
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--location", "--max-time", &timeout])
+            .args(["--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| failed(format!("cannot run curl: {}", e)))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(self.curl_config().as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(failed(stderr.trim().trim_start_matches("curl: ").to_string()));
+        }
+        String::from_utf8(output.stdout).map_err(|e| failed(e.to_string()))
+    }
+}
+
+/// Quote `value` for a curl config file.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The API request for `link`'s external issue.
+pub fn request(link: &Link, api: &ApiConfig) -> Result<ApiRequest> {
+    let url = link.url.as_deref().unwrap_or_default();
+    let unsupported =
+        |reason: &str| Error::FetchFailed { url: url.to_string(), reason: reason.to_string() };
+    let link_type = link
+        .link_type
+        .as_ref()
+        .filter(|_| is_refreshable(link))
+        .ok_or_else(|| unsupported("not a GitHub, GitLab or Jira link"))?;
+    let access = api.access(link_type);
+    let page = Page::parse(url);
+
+    match link_type {
+        LinkType::Github => {
+            let page = page.ok_or_else(|| unsupported("not a GitHub issue URL"))?;
+            let (owner, repo, number) = match page.path.as_slice() {
+                [owner, repo, "issues" | "pull", number, ..] => (owner, repo, number),
+                _ => return Err(unsupported("not a GitHub issue URL")),
+            };
+            let base = access.base(|| match page.host {
+                "github.com" => Some("https://api.github.com".to_string()),
+                host => Some(format!("{}://{}/api/v3", page.scheme, host)),
+            });
+            let base = base.unwrap_or_default();
+            let mut request =
+                ApiRequest::new(format!("{}/repos/{}/{}/issues/{}", base, owner, repo, number))
+                    .header("Accept: application/vnd.github+json".to_string())
+                    .header("User-Agent: wok".to_string());
+            if let Some(token) = access.token("GITHUB_TOKEN") {
+                request = request.header(format!("Authorization: Bearer {}", token));
+            }
+            Ok(request)
+        }
+        LinkType::Gitlab => {
+            let page = page.ok_or_else(|| unsupported("not a GitLab issue URL"))?;
+            let split = page.path.iter().position(|s| *s == "-");
+            let (project, kind, number) = match split.map(|i| page.path.split_at(i)) {
+                Some((project, [_, kind @ ("issues" | "merge_requests"), number, ..]))
+                    if !project.is_empty() =>
+                {
+                    (project.join("/"), kind, number)
+                }
+                _ => return Err(unsupported("not a GitLab issue URL")),
+            };
+            let base = access
+                .base(|| Some(format!("{}://{}", page.scheme, page.host)))
+                .unwrap_or_default();
+            let mut request = ApiRequest::new(format!(
+                "{}/api/v4/projects/{}/{}/{}",
+                base,
+                project.replace('/', "%2F"),
+                kind,
+                number
+            ));
+            if let Some(token) = access.token("GITLAB_TOKEN") {
+                request = request.header(format!("PRIVATE-TOKEN: {}", token));
+            }
+            Ok(request)
+        }
+        LinkType::Jira => {
+            let key = link
+                .external_id
+                .clone()
+                .or_else(|| page.as_ref().and_then(|p| p.path.last().map(|s| s.to_string())))
+                .ok_or_else(|| unsupported("no Jira issue key"))?;
+            let base = access
+                .base(|| page.as_ref().map(|p| format!("{}://{}", p.scheme, p.host)))
+                .ok_or_else(|| unsupported("set url under [api.jira] to refresh jira:// links"))?;
+            let mut request =
+                ApiRequest::new(format!("{}/rest/api/2/issue/{}?fields=summary,status", base, key))
+                    .header("Accept: application/json".to_string());
+            match (access.token("JIRA_API_TOKEN"), &access.user) {
+                (Some(token), Some(user)) => request.user = Some(format!("{}:{}", user, token)),
+                (Some(token), None) => {
+                    request = request.header(format!("Authorization: Bearer {}", token))
+                }
+                (None, _) => {}
+            }
+            Ok(request)
+        }
+        _ => Err(unsupported("not a GitHub, GitLab or Jira link")),
+    }
+}
+
+/// An `http(s)` URL split into scheme, host and path segments.
+struct Page<'a> {
+    scheme: &'a str,
+    host: &'a str,
+    path: Vec<&'a str>,
+}
+
+impl<'a> Page<'a> {
+    fn parse(url: &'a str) -> Option<Self> {
+        let (scheme, rest) = url.split_once("://")?;
+        if scheme != "https" && scheme != "http" {
+            return None;
+        }
+        let rest = rest.split(['?', '#']).next().unwrap_or_default();
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let path = path.split('/').filter(|s| !s.is_empty()).collect();
+        Some(Page { scheme, host, path })
+    }
+}
+
+/// Read the title and state out of a provider's response.
+pub fn parse_response(link_type: &LinkType, body: &str) -> Result<LinkMeta> {
+    let value: Value = serde_json::from_str(body)?;
+    let text = |v: Option<&Value>| v.and_then(Value::as_str).map(str::to_string);
+    let (title, state) = match link_type {
+        LinkType::Jira => {
+            let fields = value.get("fields");
+            (
+                text(fields.and_then(|f| f.get("summary"))),
+                text(fields.and_then(|f| f.get("status")).and_then(|s| s.get("name"))),
+            )
+        }
+        LinkType::Github => {
+            let merged = value
+                .get("pull_request")
+                .and_then(|pr| pr.get("merged_at"))
+                .is_some_and(|at| !at.is_null());
+            let state = match merged {
+                true => Some("merged".to_string()),
+                false => text(value.get("state")),
+            };
+            (text(value.get("title")), state)
+        }
+        _ => (text(value.get("title")), text(value.get("state"))),
+    };
+    Ok(LinkMeta { title, state, error: None, refreshed_at: Utc::now() })
+}
+
+/// Ask `link`'s provider about the external issue.
+pub fn fetch(link: &Link, api: &ApiConfig) -> Result<LinkMeta> {
+    let request = request(link, api)?;
+    let body = request.send()?;
+    let link_type = link.link_type.clone().unwrap_or(LinkType::Other(String::new()));
+    parse_response(&link_type, &body).map_err(|e| Error::FetchFailed {
+        url: request.url.clone(),
+        reason: format!("unexpected response: {}", e),
+    })
+}
+
+/// Fetch and store `link`'s metadata. A failure is stored too, keeping
+/// what an earlier refresh found, so the daemon does not retry it until
+/// the link is stale again. When the external issue is resolved, every
+/// `blocks` link to it is marked resolved.
+pub fn refresh(db: &Database, link: &Link, api: &ApiConfig) -> Result<LinkMeta> {
+    let url = link.url.as_deref().unwrap_or_default();
+    match fetch(link, api) {
+        Ok(meta) => {
+            db.set_link_meta(url, &meta)?;
+            if meta.is_resolved() {
+                db.resolve_url_links(url, meta.refreshed_at)?;
+            }
+            Ok(meta)
+        }
+        Err(e) => {
+            db.set_link_meta_error(url, &e.to_string(), Utc::now())?;
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "link_meta_tests.rs"]
+mod tests;
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+fn link(link_type: LinkType, url: &str, external_id: Option<&str>) -> Link {
+    let mut link = Link::new("prj-1".to_string()).with_type(link_type).with_url(url.to_string());
+    link.external_id = external_id.map(str::to_string);
+    link
+}
+
+fn with_token(url: Option<&str>, user: Option<&str>) -> ApiAccess {
+    ApiAccess {
+        token: Some("secret".to_string()),
+        token_env: None,
+        user: user.map(str::to_string),
+        url: url.map(str::to_string),
+    }
+}
+
+#[test]
+fn github_request_uses_the_issues_api() {
+    let api = ApiConfig { github: Some(with_token(None, None)), ..Default::default() };
+    let link = link(LinkType::Github, "https://github.com/acme/app/issues/12", Some("12"));
+
+    let request = request(&link, &api).unwrap();
+
+    assert_eq!(request.url, "https://api.github.com/repos/acme/app/issues/12");
+    assert!(request.headers.contains(&"Authorization: Bearer secret".to_string()));
+}
+
+#[test]
+fn github_enterprise_request_uses_its_host() {
+    let api = ApiConfig::default();
+    let link = link(LinkType::Github, "https://git.acme.com/acme/app/pull/3", None);
+
+    let request = request(&link, &api).unwrap();
+
+    assert_eq!(request.url, "https://git.acme.com/api/v3/repos/acme/app/issues/3");
+}
+
+#[test]
+fn gitlab_request_encodes_the_project_path() {
+    let api = ApiConfig { gitlab: Some(with_token(None, None)), ..Default::default() };
+    let link = link(LinkType::Gitlab, "https://gitlab.com/acme/sub/app/-/issues/7", Some("7"));
+
+    let request = request(&link, &api).unwrap();
+
+    assert_eq!(request.url, "https://gitlab.com/api/v4/projects/acme%2Fsub%2Fapp/issues/7");
+    assert_eq!(request.headers, ["PRIVATE-TOKEN: secret"]);
+}
+
+#[test]
+fn jira_request_uses_basic_auth_with_a_user() {
+    let api = ApiConfig { jira: Some(with_token(None, Some("me@acme.com"))), ..Default::default() };
+    let link = link(LinkType::Jira, "https://acme.atlassian.net/browse/PE-5555", Some("PE-5555"));
+
+    let request = request(&link, &api).unwrap();
+
+    assert_eq!(
+        request.url,
+        "https://acme.atlassian.net/rest/api/2/issue/PE-5555?fields=summary,status"
+    );
+    assert_eq!(request.user.as_deref(), Some("me@acme.com:secret"));
+}
+
+#[test]
+fn jira_shorthand_needs_a_configured_url() {
+    let link = link(LinkType::Jira, "jira://PE-5555", Some("PE-5555"));
+
+    let err = request(&link, &ApiConfig::default()).unwrap_err();
+    assert!(err.to_string().contains("[api.jira]"), "{err}");
+
+    let access =
+        ApiAccess { url: Some("https://acme.atlassian.net/".to_string()), ..Default::default() };
+    let api = ApiConfig { jira: Some(access), ..Default::default() };
+    let request = request(&link, &api).unwrap();
+    assert!(request.url.starts_with("https://acme.atlassian.net/rest/api/2/issue/PE-5555"));
+}
+
+#[test]
+fn other_links_are_not_refreshable() {
+    let confluence = link(LinkType::Confluence, "https://acme.atlassian.net/wiki/x", None);
+    assert!(!is_refreshable(&confluence));
+    assert!(request(&confluence, &ApiConfig::default()).is_err());
+
+    let untyped = Link::new("prj-1".to_string()).with_url("https://example.com".to_string());
+    assert!(!is_refreshable(&untyped));
+}
+
+#[test]
+fn curl_config_quotes_values() {
+    let request = ApiRequest {
+        url: "https://example.com/a".to_string(),
+        headers: vec!["X-Note: say \"hi\"".to_string()],
+        user: Some("me:p\\w".to_string()),
+    };
+
+    assert_eq!(
+        request.curl_config(),
+        "url = \"https://example.com/a\"\nheader = \"X-Note: say \\\"hi\\\"\"\nuser = \"me:p\\\\w\"\n"
+    );
+}
+
+#[test]
+fn responses_give_title_and_state() {
+    let jira =
+        r#"{"key": "PE-5555", "fields": {"summary": "Fix auth", "status": {"name": "Closed"}}}"#;
+    let meta = parse_response(&LinkType::Jira, jira).unwrap();
+    assert_eq!(meta.title.as_deref(), Some("Fix auth"));
+    assert_eq!(meta.state.as_deref(), Some("Closed"));
+
+    let gitlab = r#"{"iid": 7, "title": "Crash", "state": "opened"}"#;
+    let meta = parse_response(&LinkType::Gitlab, gitlab).unwrap();
+    assert_eq!(meta.state.as_deref(), Some("opened"));
+
+    let pull = r#"{"title": "Fix", "state": "closed", "pull_request": {"merged_at": "2026-01-01T00:00:00Z"}}"#;
+    let meta = parse_response(&LinkType::Github, pull).unwrap();
+    assert_eq!(meta.title.as_deref(), Some("Fix"));
+    assert_eq!(meta.state.as_deref(), Some("merged"));
+
+    assert!(parse_response(&LinkType::Github, "not json").is_err());
+}
+
+#[test]
+fn refresh_minutes_needs_a_provider() {
+    assert_eq!(ApiConfig::default().refresh_minutes(), None);
+
+    let api = ApiConfig { github: Some(ApiAccess::default()), ..Default::default() };
+    assert_eq!(api.refresh_minutes(), Some(DEFAULT_REFRESH_MINUTES));
+
+    let huge = ApiConfig { refresh_minutes: Some(u64::MAX), ..api.clone() };
+    assert_eq!(huge.refresh_minutes(), Some(MAX_REFRESH_MINUTES));
+
+    let off = ApiConfig { refresh_minutes: Some(0), ..api };
+    assert_eq!(off.refresh_minutes(), None);
+}
+
+#[test]
+fn closed_states_are_resolved() {
+    let meta = |state: Option<&str>| LinkMeta {
+        title: None,
+        state: state.map(str::to_string),
+        error: None,
+        refreshed_at: Utc::now(),
+    };
+
+    for state in ["closed", "merged", "Done", "Won't Do"] {
+        assert!(meta(Some(state)).is_resolved(), "{state}");
+    }
+    for state in ["open", "opened", "In Progress"] {
+        assert!(!meta(Some(state)).is_resolved(), "{state}");
+    }
+    assert!(!meta(None).is_resolved());
+}
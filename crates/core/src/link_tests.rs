@@ -21,6 +21,22 @@ fn link_type_from_str() {
     assert!("invalid".parse::<LinkType>().is_err());
 }
 
+#[test]
+fn link_type_from_name_keeps_providers() {
+    assert_eq!(LinkType::from_name("GitHub"), LinkType::Github);
+    assert_eq!(LinkType::from_name("Linear"), LinkType::Other("linear".to_string()));
+    assert_eq!(LinkType::Other("linear".to_string()).as_str(), "linear");
+}
+
+#[test]
+fn link_type_serializes_as_its_name() {
+    let other = LinkType::Other("linear".to_string());
+    assert_eq!(serde_json::to_string(&other).unwrap(), "\"linear\"");
+    assert_eq!(serde_json::to_string(&LinkType::Jira).unwrap(), "\"jira\"");
+    let parsed: LinkType = serde_json::from_str("\"ado\"").unwrap();
+    assert_eq!(parsed, LinkType::Other("ado".to_string()));
+}
+
 #[test]
 fn link_rel_as_str() {
     assert_eq!(LinkRel::Import.as_str(), "import");
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Issue IDs mentioned in free text.
+//!
+//! A note or description that names another issue ("see prj-7") records a
+//! `references` dependency to it (see [`Database::record_mentions`]). Any
+//! word shaped like an ID is a candidate; only those naming an existing
+//! issue, by full or short ID, are recorded.
+//!
+//! [`Database::record_mentions`]: crate::Database::record_mentions
+
+/// Words in `text` shaped like issue IDs, in order of first appearance:
+/// a prefix of 2+ lowercase letters and digits, a dash, then letters,
+/// digits, and dashes (`prj-a3f2`, `prj-a3f2-2`, `prj-12`).
+pub fn candidate_ids(text: &str) -> Vec<&str> {
+    let mut ids: Vec<&str> = Vec::new();
+    let words = text
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .map(|word| word.trim_matches('-'));
+    for word in words {
+        if is_id_shaped(word) && !ids.contains(&word) {
+            ids.push(word);
+        }
+    }
+    ids
+}
+
+fn is_id_shaped(word: &str) -> bool {
+    let Some((prefix, rest)) = word.split_once('-') else {
+        return false;
+    };
+    prefix.len() >= 2
+        && prefix.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && prefix.chars().any(|c| c.is_ascii_lowercase())
+        && !rest.is_empty()
+        && rest.split('-').all(|part| !part.is_empty())
+}
+
+#[cfg(test)]
+#[path = "mention_tests.rs"]
+mod tests;
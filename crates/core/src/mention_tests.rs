@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn finds_full_and_short_ids() {
+    assert_eq!(
+        candidate_ids("see prj-a3f2 and prj-12, also (auth-9bc1-2)."),
+        vec!["prj-a3f2", "prj-12", "auth-9bc1-2"]
+    );
+}
+
+#[test]
+fn lists_each_id_once() {
+    assert_eq!(candidate_ids("prj-1 then prj-1 again"), vec!["prj-1"]);
+}
+
+#[test]
+fn ignores_words_not_shaped_like_ids() {
+    assert!(candidate_ids("a-b Foo-1 x-ray - -- 12-34 prj- -prj").is_empty());
+    // `well-known` is shaped like an ID; only existing issues are recorded
+    assert_eq!(candidate_ids("well-known"), vec!["well-known"]);
+}
@@ -4,7 +4,8 @@
 //! Merge operations into database state with HLC conflict resolution.
 //!
 //! Merge rules:
-//! - CreateIssue: first write wins (duplicate creates are ignored)
+//! - CreateIssue: first write wins (duplicate creates are ignored); a carried
+//!   short ID is kept unless another issue already holds that number
 //! - SetStatus/SetTitle/SetType: last HLC wins
 //! - AddLabel/RemoveLabel: add always succeeds, remove always succeeds
 //! - AddNote: always append
@@ -13,7 +14,7 @@
 //! All merge operations are idempotent and commutative.
 
 use crate::db::Database;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::hlc::Hlc;
 use crate::issue::{Action, Event, Issue, Status};
 use crate::op::{Op, OpPayload};
@@ -43,7 +44,7 @@ pub trait Merge {
 impl Merge for Database {
     fn apply(&mut self, op: &Op) -> Result<bool> {
         match &op.payload {
-            OpPayload::CreateIssue { id, issue_type, title } => {
+            OpPayload::CreateIssue { id, issue_type, title, short_id } => {
                 // First write wins
                 if self.issue_exists(id)? {
                     return Ok(false);
@@ -64,11 +65,10 @@ impl Merge for Database {
                     last_type_hlc: None,
                     last_description_hlc: None,
                     last_assignee_hlc: None,
+                    revision: 1,
                 };
-                self.create_issue(&issue)?;
-
                 let event = Event::new(id.clone(), Action::Created);
-                self.log_event(&event)?;
+                self.record(&event, || self.create_issue_with_short_id(&issue, *short_id))?;
 
                 Ok(true)
             }
@@ -88,12 +88,13 @@ impl Merge for Database {
                 if !self.issue_exists(issue_id)? {
                     return Ok(false);
                 }
-                // add_label is already idempotent (unique constraint)
-                let _ = self.add_label(issue_id, label);
-
                 let event = Event::new(issue_id.clone(), Action::Labeled)
                     .with_values(None, Some(label.clone()));
-                self.log_event(&event)?;
+                // add_label is already idempotent (unique constraint)
+                self.record(&event, || -> Result<()> {
+                    let _ = self.add_label(issue_id, label);
+                    Ok(())
+                })?;
 
                 Ok(true)
             }
@@ -103,13 +104,14 @@ impl Merge for Database {
                 if !self.issue_exists(issue_id)? {
                     return Ok(false);
                 }
-                let removed = self.remove_label(issue_id, label)?;
-                if removed {
-                    let event = Event::new(issue_id.clone(), Action::Unlabeled)
-                        .with_values(Some(label.clone()), None);
-                    self.log_event(&event)?;
-                }
-                Ok(true)
+                self.atomically(|| {
+                    if self.remove_label(issue_id, label)? {
+                        let event = Event::new(issue_id.clone(), Action::Unlabeled)
+                            .with_values(Some(label.clone()), None);
+                        self.log_event(&event)?;
+                    }
+                    Ok(true)
+                })
             }
 
             OpPayload::AddNote { issue_id, content, status } => {
@@ -117,10 +119,11 @@ impl Merge for Database {
                 if !self.issue_exists(issue_id)? {
                     return Ok(false);
                 }
-                self.add_note(issue_id, *status, content)?;
-
                 let event = Event::new(issue_id.clone(), Action::Noted);
-                self.log_event(&event)?;
+                self.record(&event, || {
+                    self.add_note(issue_id, *status, content)?;
+                    self.record_mentions(issue_id, content)
+                })?;
 
                 Ok(true)
             }
@@ -130,15 +133,15 @@ impl Merge for Database {
                 if !self.issue_exists(from_id)? || !self.issue_exists(to_id)? {
                     return Ok(false);
                 }
-                // May fail if already exists or would create cycle
-                match self.add_dependency(from_id, to_id, *relation) {
-                    Ok(()) => {
-                        let event = Event::new(from_id.clone(), Action::Related)
-                            .with_values(None, Some(format!("{relation} {to_id}")));
-                        self.log_event(&event)?;
-                        Ok(true)
-                    }
-                    Err(_) => Ok(false), // Already exists or would create cycle
+                let (action, _) = relation.actions();
+                let event = Event::new(from_id.clone(), action)
+                    .with_values(None, Some(relation.event_value(to_id)));
+                // Adding an existing dependency is a no-op, but one that
+                // would create a cycle is not applied
+                match self.record(&event, || self.add_dependency(from_id, to_id, *relation)) {
+                    Ok(()) => Ok(true),
+                    Err(Error::CycleDetected | Error::SelfDependency) => Ok(false),
+                    Err(e) => Err(e),
                 }
             }
 
@@ -147,14 +150,13 @@ impl Merge for Database {
                 if !self.issue_exists(from_id)? {
                     return Ok(false);
                 }
-                match self.remove_dependency(from_id, to_id, *relation) {
-                    Ok(()) => {
-                        let event = Event::new(from_id.clone(), Action::Unrelated)
-                            .with_values(Some(format!("{relation} {to_id}")), None);
-                        self.log_event(&event)?;
-                        Ok(true)
-                    }
-                    Err(_) => Ok(false), // Doesn't exist
+                let (_, action) = relation.actions();
+                let event = Event::new(from_id.clone(), action)
+                    .with_values(Some(relation.event_value(to_id)), None);
+                match self.record(&event, || self.remove_dependency(from_id, to_id, *relation)) {
+                    Ok(()) => Ok(true),
+                    Err(Error::DependencyNotFound { .. }) => Ok(false),
+                    Err(e) => Err(e),
                 }
             }
 
@@ -186,21 +188,13 @@ impl Database {
         }
 
         let old_status = issue.status;
-        self.update_issue_status(issue_id, status)?;
-        self.update_issue_status_hlc(issue_id, hlc)?;
-
-        let action = match status {
-            Status::InProgress if old_status.is_terminal() => Action::Reopened,
-            Status::InProgress => Action::Started,
-            Status::Todo => Action::Stopped,
-            Status::Done => Action::Done,
-            Status::Closed => Action::Closed,
-        };
-
-        let event = Event::new(issue_id.to_string(), action)
+        let event = Event::new(issue_id.to_string(), Action::for_status_change(old_status, status))
             .with_values(Some(old_status.to_string()), Some(status.to_string()))
             .with_reason(reason);
-        self.log_event(&event)?;
+        self.record(&event, || {
+            self.update_issue_status(issue_id, status)?;
+            self.update_issue_status_hlc(issue_id, hlc)
+        })?;
 
         Ok(true)
     }
@@ -218,13 +212,12 @@ impl Database {
             }
         }
 
-        let old_title = issue.title;
-        self.update_issue_title(issue_id, title)?;
-        self.update_issue_title_hlc(issue_id, hlc)?;
-
         let event = Event::new(issue_id.to_string(), Action::Edited)
-            .with_values(Some(old_title), Some(title.to_string()));
-        self.log_event(&event)?;
+            .with_values(Some(issue.title), Some(title.to_string()));
+        self.record(&event, || {
+            self.update_issue_title(issue_id, title)?;
+            self.update_issue_title_hlc(issue_id, hlc)
+        })?;
 
         Ok(true)
     }
@@ -247,13 +240,12 @@ impl Database {
             }
         }
 
-        let old_type = issue.issue_type;
-        self.update_issue_type(issue_id, issue_type)?;
-        self.update_issue_type_hlc(issue_id, hlc)?;
-
         let event = Event::new(issue_id.to_string(), Action::Edited)
-            .with_values(Some(old_type.to_string()), Some(issue_type.to_string()));
-        self.log_event(&event)?;
+            .with_values(Some(issue.issue_type.to_string()), Some(issue_type.to_string()));
+        self.record(&event, || {
+            self.update_issue_type(issue_id, issue_type)?;
+            self.update_issue_type_hlc(issue_id, hlc)
+        })?;
 
         Ok(true)
     }
@@ -307,6 +299,7 @@ impl Database {
         self.conn.execute("PRAGMA foreign_keys = ON", [])?;
 
         result?;
+        self.rename_short_id_prefix(old_prefix, new_prefix)?;
         Ok(true)
     }
 }
@@ -43,6 +43,40 @@ fn merge_create_issue_duplicate() {
     assert!(!db.apply(&op).unwrap()); // Duplicate is a no-op
 }
 
+#[test]
+fn merge_create_issue_keeps_carried_short_id() {
+    let mut db = test_db();
+    let remote = Op::new(
+        Hlc::new(1000, 0, 2),
+        OpPayload::create_issue_with_short_id("prj-a1b2".into(), IssueType::Task, "A".into(), 3),
+    );
+
+    assert!(db.apply(&remote).unwrap());
+    assert_eq!(db.get_short_id("prj-a1b2").unwrap().as_deref(), Some("prj-3"));
+
+    // Both replicas end up with the same alias whichever applies it
+    let mut other = test_db();
+    other.apply(&remote).unwrap();
+    assert_eq!(other.resolve_id("prj-3").unwrap(), "prj-a1b2");
+}
+
+#[test]
+fn merge_create_issue_short_id_clash_takes_next_free() {
+    let mut db = test_db();
+    db.apply(&Op::new(
+        Hlc::new(1000, 0, 1),
+        OpPayload::create_issue_with_short_id("prj-a1b2".into(), IssueType::Task, "A".into(), 1),
+    ))
+    .unwrap();
+    db.apply(&Op::new(
+        Hlc::new(1001, 0, 2),
+        OpPayload::create_issue_with_short_id("prj-c3d4".into(), IssueType::Task, "B".into(), 1),
+    ))
+    .unwrap();
+
+    assert_eq!(db.get_short_id("prj-c3d4").unwrap().as_deref(), Some("prj-2"));
+}
+
 #[test]
 fn merge_set_status() {
     let mut db = test_db();
@@ -251,6 +285,67 @@ fn merge_remove_dep() {
     assert!(!blockers.contains(&"test-1".to_string()));
 }
 
+#[test]
+fn merge_dep_ops_that_cannot_apply_log_nothing() {
+    let mut db = test_db();
+    for (i, id) in ["test-1", "test-2"].into_iter().enumerate() {
+        let create = Op::new(
+            Hlc::new(1000 + i as u64, 0, 1),
+            OpPayload::create_issue(id.into(), IssueType::Task, "Issue".into()),
+        );
+        db.apply(&create).unwrap();
+    }
+    db.add_dependency("test-1", "test-2", Relation::Blocks).unwrap();
+
+    let cycle = OpPayload::add_dep("test-2".into(), "test-1".into(), Relation::Blocks);
+    assert!(!db.apply(&Op::new(Hlc::new(2000, 0, 1), cycle)).unwrap());
+    let missing = OpPayload::remove_dep("test-2".into(), "test-1".into(), Relation::RelatesTo);
+    assert!(!db.apply(&Op::new(Hlc::new(2001, 0, 1), missing)).unwrap());
+
+    let events = db.get_events("test-2").unwrap();
+    assert!(events.iter().all(|e| e.action == Action::Created));
+}
+
+#[test]
+fn merge_non_blocking_deps_log_dedicated_actions() {
+    let mut db = test_db();
+    for (i, id) in ["test-1", "test-2"].into_iter().enumerate() {
+        let create = Op::new(
+            Hlc::new(1000 + i as u64, 0, 1),
+            OpPayload::create_issue(id.into(), IssueType::Task, "Issue".into()),
+        );
+        db.apply(&create).unwrap();
+    }
+
+    let ops = [
+        OpPayload::add_dep("test-1".into(), "test-2".into(), Relation::RelatesTo),
+        OpPayload::add_dep("test-1".into(), "test-2".into(), Relation::DuplicateOf),
+        OpPayload::remove_dep("test-1".into(), "test-2".into(), Relation::DuplicateOf),
+    ];
+    for (i, payload) in ops.into_iter().enumerate() {
+        assert!(db.apply(&Op::new(Hlc::new(2000 + i as u64, 0, 1), payload)).unwrap());
+    }
+
+    assert_eq!(db.get_related("test-1").unwrap(), vec!["test-2"]);
+    assert!(db.get_duplicate_of("test-1").unwrap().is_empty());
+    assert!(db.get_blockers("test-2").unwrap().is_empty());
+    let actions: Vec<(Action, Option<String>, Option<String>)> = db
+        .get_events("test-1")
+        .unwrap()
+        .into_iter()
+        .filter(|e| e.action != Action::Created)
+        .map(|e| (e.action, e.old_value, e.new_value))
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            (Action::Associated, None, Some("test-2".into())),
+            (Action::Duplicated, None, Some("test-2".into())),
+            (Action::Unduplicated, Some("test-2".into()), None),
+        ]
+    );
+}
+
 #[test]
 fn merge_on_nonexistent_issue() {
     let mut db = test_db();
@@ -749,6 +844,9 @@ fn merge_config_rename_updates_all_tables() {
     // Verify dependencies were updated
     let blockers = db.get_blockers("new-2").unwrap();
     assert!(blockers.contains(&"new-1".to_string()));
+
+    // Verify short IDs moved with the prefix
+    assert_eq!(db.get_short_id("new-2").unwrap().as_deref(), Some("new-2"));
 }
 
 #[test]
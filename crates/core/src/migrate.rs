@@ -0,0 +1,602 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Versioned schema migrations.
+//!
+//! This is the single migration path for all crates (core, CLI, daemon). The
+//! schema version is SQLite's `user_version`. [`MIGRATIONS`] is ordered and
+//! up-only: [`migrate`] applies each migration past the database's version in
+//! its own transaction, recording the new version in the same transaction, so
+//! a failed migration leaves the database at the last version that applied.
+//!
+//! Databases written before versioning report version 0. Migrations up to
+//! [`LEGACY_VERSION`] are idempotent and each carries a check for whether such
+//! a database still needs it, so an unversioned database that is already up
+//! to date only has its version stamped.
+//!
+//! To change the schema, append a migration. Editing [`SCHEMA`] or an
+//! existing migration has no effect on databases already past it.
+
+use rusqlite::{Connection, Transaction, TransactionBehavior};
+
+use crate::db::{allocate_short_id, SCHEMA};
+use crate::error::{Error, Result};
+
+/// One step of the schema's history.
+pub struct Migration {
+    /// The schema version this migration brings a database to.
+    pub version: u32,
+    /// What it does, as `wok upgrade` reports it.
+    pub description: &'static str,
+    up: fn(&Connection) -> Result<()>,
+    /// Whether an unversioned database still needs this migration; `None`
+    /// for migrations written after versioning, which always apply to one.
+    legacy_needed: Option<fn(&Connection) -> Result<bool>>,
+}
+
+/// Every migration, in version order.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create missing tables and indexes",
+        up: create_schema,
+        legacy_needed: Some(missing_tables),
+    },
+    Migration {
+        version: 2,
+        description: "add and index assignee column",
+        up: migrate_add_assignee,
+        legacy_needed: Some(needs_assignee),
+    },
+    Migration {
+        version: 3,
+        description: "add HLC columns",
+        up: migrate_add_hlc_columns,
+        legacy_needed: Some(needs_hlc_columns),
+    },
+    Migration {
+        version: 4,
+        description: "add closed_at column and backfill from events",
+        up: migrate_add_closed_at,
+        legacy_needed: Some(needs_closed_at),
+    },
+    Migration {
+        version: 5,
+        description: "backfill prefixes table",
+        up: migrate_backfill_prefixes,
+        legacy_needed: Some(needs_prefixes),
+    },
+    Migration {
+        version: 6,
+        description: "rewrite tracked_by relations to tracked-by",
+        up: migrate_tracked_by_relation,
+        legacy_needed: Some(has_old_relations),
+    },
+    Migration {
+        version: 7,
+        description: "add note kind column",
+        up: migrate_add_note_kind,
+        legacy_needed: Some(needs_note_kind),
+    },
+    Migration {
+        version: 8,
+        description: "allocate short IDs for existing issues",
+        up: migrate_backfill_short_ids,
+        legacy_needed: Some(needs_short_ids),
+    },
+    Migration {
+        version: 9,
+        description: "index notes by issue",
+        up: migrate_index_notes,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 10,
+        description: "add issue subscriptions and inbox cursors",
+        up: migrate_add_subscriptions,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 11,
+        description: "add label registry",
+        up: migrate_add_labels_meta,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 12,
+        description: "add external link metadata",
+        up: migrate_add_link_meta,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 13,
+        description: "add link resolved_at column",
+        up: migrate_add_link_resolved_at,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 14,
+        description: "add actor columns to events and notes",
+        up: migrate_add_actor,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 15,
+        description: "add issue revisions",
+        up: migrate_add_revision,
+        legacy_needed: None,
+    },
+    Migration {
+        version: 16,
+        description: "add deleted_at columns to notes and links",
+        up: migrate_add_deleted_at,
+        legacy_needed: None,
+    },
+];
+
+/// The last version whose migration predates versioning.
+pub const LEGACY_VERSION: u32 = 8;
+
+/// The schema version this build writes.
+pub const LATEST_VERSION: u32 = 16;
+
+/// The database's schema version; 0 for a new or unversioned database.
+pub fn db_version(conn: &Connection) -> Result<u32> {
+    Ok(conn.pragma_query_value(None, "user_version", |row| row.get(0))?)
+}
+
+/// The migrations [`migrate`] would apply, without changing anything.
+///
+/// Empty for a database newer than this build; `migrate` refuses those.
+pub fn pending(conn: &Connection) -> Result<Vec<&'static Migration>> {
+    let version = db_version(conn)?;
+    let mut pending = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        let needed = match (version, migration.legacy_needed) {
+            (0, Some(needed)) => needed(conn)?,
+            _ => true,
+        };
+        if needed {
+            pending.push(migration);
+        }
+    }
+    Ok(pending)
+}
+
+/// Bring the database to [`LATEST_VERSION`], returning how many migrations ran.
+pub fn migrate(conn: &Connection) -> Result<usize> {
+    apply(conn, MIGRATIONS)
+}
+
+/// Apply `migrations` past the database's version, one transaction each.
+pub(crate) fn apply(conn: &Connection, migrations: &[Migration]) -> Result<usize> {
+    let latest = migrations.last().map_or(0, |m| m.version);
+    let version = db_version(conn)?;
+    if version > latest {
+        return Err(Error::SchemaTooNew { version, supported: latest });
+    }
+
+    let mut applied = 0;
+    for migration in migrations.iter().filter(|m| m.version > version) {
+        let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+        // Another process may have migrated while this one waited for the lock.
+        if db_version(&tx)? >= migration.version {
+            continue;
+        }
+        (migration.up)(&tx)?;
+        tx.pragma_update(None, "user_version", migration.version)?;
+        tx.commit()?;
+        applied += 1;
+    }
+    Ok(applied)
+}
+
+/// Run all pending migrations.
+pub fn run_migrations(conn: &Connection) -> Result<()> {
+    migrate(conn).map(|_| ())
+}
+
+/// Describe the migrations that `run_migrations` would apply, in order.
+///
+/// Inspects the schema and data without modifying anything, so callers can
+/// report what an upgrade will do (and back up first) before running it.
+pub fn pending_migrations(conn: &Connection) -> Result<Vec<&'static str>> {
+    Ok(pending(conn)?.into_iter().map(|m| m.description).collect())
+}
+
+// Checks for unversioned databases, one per legacy migration.
+
+fn missing_tables(conn: &Connection) -> Result<bool> {
+    let tables = [
+        "issues",
+        "deps",
+        "labels",
+        "notes",
+        "events",
+        "links",
+        "prefixes",
+        "short_ids",
+        "hook_runs",
+    ];
+    for table in tables {
+        if !has_table(conn, table)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn needs_assignee(conn: &Connection) -> Result<bool> {
+    Ok(!has_table(conn, "issues")?
+        || !has_column(conn, "issues", "assignee")?
+        || !has_index(conn, "idx_issues_assignee")?)
+}
+
+fn needs_hlc_columns(conn: &Connection) -> Result<bool> {
+    if !has_table(conn, "issues")? {
+        return Ok(false);
+    }
+    for column in HLC_COLUMNS {
+        if !has_column(conn, "issues", column)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn needs_closed_at(conn: &Connection) -> Result<bool> {
+    Ok(has_table(conn, "issues")? && !has_column(conn, "issues", "closed_at")?)
+}
+
+fn needs_prefixes(conn: &Connection) -> Result<bool> {
+    if !has_table(conn, "issues")? {
+        return Ok(false);
+    }
+    let prefix_count: i64 = if has_table(conn, "prefixes")? {
+        conn.query_row("SELECT COUNT(*) FROM prefixes", [], |row| row.get(0))?
+    } else {
+        0
+    };
+    Ok(prefix_count == 0 && prefixed_issues(conn)? > 0)
+}
+
+fn has_old_relations(conn: &Connection) -> Result<bool> {
+    if !has_table(conn, "deps")? {
+        return Ok(false);
+    }
+    let old_relations: i64 =
+        conn.query_row("SELECT COUNT(*) FROM deps WHERE rel = 'tracked_by'", [], |row| row.get(0))?;
+    Ok(old_relations > 0)
+}
+
+fn needs_note_kind(conn: &Connection) -> Result<bool> {
+    Ok(has_table(conn, "notes")? && !has_column(conn, "notes", "kind")?)
+}
+
+fn needs_short_ids(conn: &Connection) -> Result<bool> {
+    if !has_table(conn, "issues")? {
+        return Ok(false);
+    }
+    let unaliased: i64 = if has_table(conn, "short_ids")? {
+        conn.query_row(
+            "SELECT COUNT(*) FROM issues
+             WHERE id LIKE '%-%' AND id NOT IN (SELECT issue_id FROM short_ids)",
+            [],
+            |row| row.get(0),
+        )?
+    } else {
+        prefixed_issues(conn)?
+    };
+    Ok(unaliased > 0)
+}
+
+fn prefixed_issues(conn: &Connection) -> Result<i64> {
+    Ok(conn.query_row("SELECT COUNT(*) FROM issues WHERE id LIKE '%-%'", [], |row| row.get(0))?)
+}
+
+/// Check whether a table exists.
+fn has_table(conn: &Connection, table: &str) -> Result<bool> {
+    let exists = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+        [table],
+        |row| row.get(0),
+    )?;
+    Ok(exists)
+}
+
+/// Check whether an index exists.
+fn has_index(conn: &Connection, index: &str) -> Result<bool> {
+    let exists = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'index' AND name = ?1",
+        [index],
+        |row| row.get(0),
+    )?;
+    Ok(exists)
+}
+
+/// Check whether a table has the given column.
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let exists = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = ?2",
+        [table, column],
+        |row| row.get(0),
+    )?;
+    Ok(exists)
+}
+
+/// Migration: Create every table and index in [`SCHEMA`] that is missing.
+///
+/// On a new database this creates the whole baseline schema, so the legacy
+/// migrations after it find nothing to do.
+fn create_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(SCHEMA)?;
+    Ok(())
+}
+
+/// Columns holding the HLC of each field's last change.
+const HLC_COLUMNS: [&str; 5] = [
+    "last_status_hlc",
+    "last_title_hlc",
+    "last_type_hlc",
+    "last_description_hlc",
+    "last_assignee_hlc",
+];
+
+/// Migration: Add assignee column to existing databases.
+fn migrate_add_assignee(conn: &Connection) -> Result<()> {
+    let has_assignee: bool = conn
+        .query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = 'assignee'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or(false);
+
+    if !has_assignee {
+        conn.execute("ALTER TABLE issues ADD COLUMN assignee TEXT", [])?;
+    }
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_issues_assignee ON issues(assignee)", [])?;
+    Ok(())
+}
+
+/// Migration: Add HLC columns for CRDT sync compatibility.
+///
+/// Adds all HLC (Hybrid Logical Clock) columns used for conflict resolution
+/// during sync. Older databases may be missing some or all of these.
+fn migrate_add_hlc_columns(conn: &Connection) -> Result<()> {
+    for column in HLC_COLUMNS {
+        let has_column: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = ?1",
+                [column],
+                |row| row.get(0),
+            )
+            .unwrap_or(false);
+
+        if !has_column {
+            let sql = format!("ALTER TABLE issues ADD COLUMN {column} TEXT");
+            conn.execute(&sql, [])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Migration: Add closed_at column and backfill from events.
+///
+/// Stores the timestamp when an issue was closed (done/closed status) directly
+/// on the issues table, replacing the correlated subquery that computed it.
+fn migrate_add_closed_at(conn: &Connection) -> Result<()> {
+    let has_col: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = 'closed_at'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if !has_col {
+        conn.execute("ALTER TABLE issues ADD COLUMN closed_at TEXT", [])?;
+
+        // Backfill closed_at from events table
+        conn.execute(
+            "UPDATE issues SET closed_at = (
+                SELECT MAX(e.created_at) FROM events e
+                WHERE e.issue_id = issues.id AND e.action IN ('done', 'closed')
+                AND NOT EXISTS (
+                    SELECT 1 FROM events e2
+                    WHERE e2.issue_id = e.issue_id
+                    AND e2.action = 'reopened'
+                    AND e2.created_at > e.created_at
+                )
+            ) WHERE status IN ('done', 'closed')",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Migration: Backfill prefixes table from existing issues.
+///
+/// Extracts prefixes from issue IDs and populates the prefixes table
+/// with correct issue counts. Only runs if the table is empty but
+/// issues exist.
+fn migrate_backfill_prefixes(conn: &Connection) -> Result<()> {
+    let prefix_count: i64 =
+        conn.query_row("SELECT COUNT(*) FROM prefixes", [], |row| row.get(0)).unwrap_or(0);
+
+    if prefix_count == 0 {
+        conn.execute(
+            "INSERT OR IGNORE INTO prefixes (prefix, created_at, issue_count)
+             SELECT
+                 substr(id, 1, instr(id, '-') - 1) as prefix,
+                 MIN(created_at) as created_at,
+                 COUNT(*) as issue_count
+             FROM issues
+             WHERE id LIKE '%-%'
+             GROUP BY prefix",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// Migration: Give every prefixed issue without one a short ID.
+///
+/// Numbers are allocated per prefix in (created_at, id) order, so two
+/// replicas backfilling the same issues agree on the result.
+fn migrate_backfill_short_ids(conn: &Connection) -> Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT id FROM issues
+         WHERE id LIKE '%-%' AND id NOT IN (SELECT issue_id FROM short_ids)
+         ORDER BY created_at, id",
+    )?;
+    let ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    for id in ids {
+        allocate_short_id(conn, &id, None)?;
+    }
+    Ok(())
+}
+
+/// Migration: Rewrite "tracked_by" to "tracked-by" in deps table.
+///
+/// Early versions serialized TrackedBy as "tracked_by" (underscore).
+/// The canonical form is "tracked-by" (kebab-case).
+fn migrate_tracked_by_relation(conn: &Connection) -> Result<()> {
+    conn.execute("UPDATE deps SET rel = 'tracked-by' WHERE rel = 'tracked_by'", [])?;
+    Ok(())
+}
+
+/// Migration: Add kind column to notes. Existing notes keep no kind.
+fn migrate_add_note_kind(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "notes", "kind")? {
+        conn.execute("ALTER TABLE notes ADD COLUMN kind TEXT", [])?;
+    }
+    Ok(())
+}
+
+/// Migration: Index notes by issue, which `wok show` reads them by.
+fn migrate_index_notes(conn: &Connection) -> Result<()> {
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_notes_issue ON notes(issue_id)", [])?;
+    Ok(())
+}
+
+/// Migration: Add tables for `wok subscribe` and `wok inbox`.
+///
+/// A subscription remembers the last event before it began, so the inbox
+/// starts from there; each subscriber's cursor is the last event they read.
+fn migrate_add_subscriptions(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS subscriptions (
+            issue_id TEXT NOT NULL,
+            subscriber TEXT NOT NULL,
+            after_event_id INTEGER NOT NULL,
+            created_at TEXT NOT NULL,
+            PRIMARY KEY (issue_id, subscriber)
+        );
+        CREATE INDEX IF NOT EXISTS idx_subscriptions_subscriber ON subscriptions(subscriber);
+        CREATE TABLE IF NOT EXISTS inbox_cursors (
+            subscriber TEXT PRIMARY KEY,
+            last_event_id INTEGER NOT NULL
+        );",
+    )?;
+    Ok(())
+}
+
+/// Migration: Add the table behind `wok labels describe` and `wok labels
+/// color`, keyed by label or by namespace (`team:`).
+fn migrate_add_labels_meta(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS labels_meta (
+            name TEXT PRIMARY KEY,
+            description TEXT,
+            color TEXT,
+            updated_at TEXT NOT NULL
+        );",
+    )?;
+    Ok(())
+}
+
+/// Migration: Add the table behind `wok link refresh`: the title and state
+/// of an external issue, keyed by link URL so issues linking the same one
+/// share it.
+fn migrate_add_link_meta(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS link_meta (
+            url TEXT PRIMARY KEY,
+            title TEXT,
+            state TEXT,
+            error TEXT,
+            refreshed_at TEXT NOT NULL
+        );",
+    )?;
+    Ok(())
+}
+
+/// Migration: Record when a link's external issue was resolved, so a
+/// `blocks` link stops holding its issue out of `wok ready`.
+fn migrate_add_link_resolved_at(conn: &Connection) -> Result<()> {
+    let has_col: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM pragma_table_info('links') WHERE name = 'resolved_at'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if !has_col {
+        conn.execute("ALTER TABLE links ADD COLUMN resolved_at TEXT", [])?;
+    }
+    Ok(())
+}
+
+/// Migration: Record who made each change and wrote each note, and index
+/// events by it for `wok log --actor`.
+fn migrate_add_actor(conn: &Connection) -> Result<()> {
+    for table in ["events", "notes"] {
+        let has_col: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = 'actor'",
+            [table],
+            |row| row.get(0),
+        )?;
+        if !has_col {
+            conn.execute(&format!("ALTER TABLE {table} ADD COLUMN actor TEXT"), [])?;
+        }
+    }
+    conn.execute_batch("CREATE INDEX IF NOT EXISTS idx_events_actor ON events(actor);")?;
+    Ok(())
+}
+
+/// Migration: Number each issue's changes, for compare-and-set with
+/// `--if-match`. Existing issues start at revision 1.
+fn migrate_add_revision(conn: &Connection) -> Result<()> {
+    let has_col: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM pragma_table_info('issues') WHERE name = 'revision'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if !has_col {
+        conn.execute("ALTER TABLE issues ADD COLUMN revision INTEGER NOT NULL DEFAULT 1", [])?;
+    }
+    Ok(())
+}
+
+/// Migration: Keep deleted notes and links as tombstones, so they can be
+/// restored.
+fn migrate_add_deleted_at(conn: &Connection) -> Result<()> {
+    for table in ["notes", "links"] {
+        let has_col: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = 'deleted_at'",
+            [table],
+            |row| row.get(0),
+        )?;
+        if !has_col {
+            conn.execute(&format!("ALTER TABLE {table} ADD COLUMN deleted_at TEXT"), [])?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "migrate_tests.rs"]
+mod tests;
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::db::Database;
+use crate::issue::Relation;
+use yare::parameterized;
+
+/// A database as the first releases wrote it: no versioning, no assignee,
+/// HLC, closed_at or note kind columns, no prefixes, short IDs or hook runs,
+/// and tracked-by spelled `tracked_by`.
+fn legacy_fixture() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE issues (
+            id TEXT PRIMARY KEY,
+            type TEXT NOT NULL,
+            title TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL DEFAULT 'todo',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        CREATE TABLE deps (
+            from_id TEXT NOT NULL,
+            to_id TEXT NOT NULL,
+            rel TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            PRIMARY KEY (from_id, to_id, rel)
+        );
+        CREATE TABLE labels (
+            issue_id TEXT NOT NULL,
+            label TEXT NOT NULL,
+            PRIMARY KEY (issue_id, label)
+        );
+        CREATE TABLE notes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            issue_id TEXT NOT NULL,
+            status TEXT NOT NULL,
+            content TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        );
+        CREATE TABLE events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            issue_id TEXT NOT NULL,
+            action TEXT NOT NULL,
+            old_value TEXT,
+            new_value TEXT,
+            reason TEXT,
+            created_at TEXT NOT NULL
+        );
+        CREATE TABLE links (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            issue_id TEXT NOT NULL,
+            link_type TEXT,
+            url TEXT,
+            external_id TEXT,
+            rel TEXT,
+            created_at TEXT NOT NULL
+        );
+
+        INSERT INTO issues (id, type, title, status, created_at, updated_at) VALUES
+            ('prj-a1', 'feature', 'Old feature', 'done', '2026-01-01T00:00:00Z', '2026-01-02T00:00:00Z'),
+            ('prj-b2', 'task', 'Old task', 'todo', '2026-01-03T00:00:00Z', '2026-01-03T00:00:00Z');
+        INSERT INTO events (issue_id, action, new_value, created_at) VALUES
+            ('prj-a1', 'created', NULL, '2026-01-01T00:00:00Z'),
+            ('prj-a1', 'done', 'done', '2026-01-02T00:00:00Z');
+        INSERT INTO deps (from_id, to_id, rel, created_at) VALUES
+            ('prj-b2', 'prj-a1', 'tracked_by', '2026-01-03T00:00:00Z');
+        INSERT INTO labels (issue_id, label) VALUES ('prj-b2', 'backend');
+        INSERT INTO notes (issue_id, status, content, created_at) VALUES
+            ('prj-b2', 'todo', 'Old note', '2026-01-03T00:00:00Z');",
+    )
+    .unwrap();
+    conn
+}
+
+/// The legacy fixture as wok at schema `version` left it.
+fn fixture(version: u32) -> Connection {
+    let conn = legacy_fixture();
+    let upto = MIGRATIONS.iter().take_while(|m| m.version <= version).count();
+    apply(&conn, &MIGRATIONS[..upto]).unwrap();
+    assert_eq!(db_version(&conn).unwrap(), version);
+    conn
+}
+
+/// Table columns and index names, sorted, to compare schemas by.
+fn schema_of(conn: &Connection) -> Vec<String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.name || '.' || p.name FROM sqlite_master m, pragma_table_info(m.name) p
+             WHERE m.type = 'table' AND m.name NOT LIKE 'sqlite_%'
+             UNION ALL
+             SELECT name FROM sqlite_master
+             WHERE type = 'index' AND name NOT LIKE 'sqlite_autoindex_%'",
+        )
+        .unwrap();
+    let mut names: Vec<String> = stmt
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<std::result::Result<_, _>>()
+        .unwrap();
+    names.sort();
+    names
+}
+
+#[test]
+fn versions_are_contiguous() {
+    let versions: Vec<u32> = MIGRATIONS.iter().map(|m| m.version).collect();
+    assert_eq!(versions, (1..=LATEST_VERSION).collect::<Vec<_>>());
+    for migration in MIGRATIONS {
+        assert_eq!(
+            migration.legacy_needed.is_some(),
+            migration.version <= LEGACY_VERSION,
+            "version {}",
+            migration.version
+        );
+    }
+}
+
+#[test]
+fn new_database_is_at_latest_version() {
+    let db = Database::open_in_memory().unwrap();
+    assert_eq!(db_version(&db.conn).unwrap(), LATEST_VERSION);
+    assert!(pending(&db.conn).unwrap().is_empty());
+    assert_eq!(migrate(&db.conn).unwrap(), 0);
+}
+
+#[parameterized(
+    unversioned = { 0 },
+    v1 = { 1 },
+    v2 = { 2 },
+    v3 = { 3 },
+    v4 = { 4 },
+    v5 = { 5 },
+    v6 = { 6 },
+    v7 = { 7 },
+    v8 = { 8 },
+    v9 = { 9 },
+)]
+fn upgrades_fixture_from_version(version: u32) {
+    let conn = fixture(version);
+    let expected = MIGRATIONS.iter().filter(|m| m.version > version).count();
+    assert_eq!(migrate(&conn).unwrap(), expected);
+    assert_eq!(db_version(&conn).unwrap(), LATEST_VERSION);
+    assert!(pending(&conn).unwrap().is_empty());
+
+    let fresh = Database::open_in_memory().unwrap();
+    assert_eq!(schema_of(&conn), schema_of(&fresh.conn));
+
+    let db = Database { conn, actor: None, expected: Default::default() };
+    let done = db.get_issue("prj-a1").unwrap();
+    assert_eq!(done.title, "Old feature");
+    assert!(done.assignee.is_none());
+    assert!(done.last_status_hlc.is_none());
+    assert_eq!(
+        done.closed_at.map(|t| t.to_rfc3339()).as_deref(),
+        Some("2026-01-02T00:00:00+00:00")
+    );
+    assert_eq!(db.get_deps_from("prj-b2").unwrap()[0].relation, Relation::TrackedBy);
+    assert_eq!(db.get_labels("prj-b2").unwrap(), vec!["backend"]);
+    let notes = db.get_notes("prj-b2").unwrap();
+    assert_eq!(notes.len(), 1);
+    assert!(notes[0].kind.is_none());
+    assert!(notes[0].actor.is_none());
+    assert_eq!(db.resolve_id("prj-1").unwrap(), "prj-a1");
+    assert_eq!(db.resolve_id("prj-2").unwrap(), "prj-b2");
+    assert_eq!(db.list_prefixes().unwrap()[0].issue_count, 2);
+}
+
+#[test]
+fn unversioned_current_database_only_needs_new_migrations() {
+    // What wok wrote just before versioning: fully migrated, version 0.
+    let conn = fixture(LEGACY_VERSION);
+    conn.pragma_update(None, "user_version", 0).unwrap();
+
+    let pending: Vec<u32> = pending(&conn).unwrap().iter().map(|m| m.version).collect();
+    assert_eq!(pending, ((LEGACY_VERSION + 1)..=LATEST_VERSION).collect::<Vec<_>>());
+
+    migrate(&conn).unwrap();
+    assert_eq!(db_version(&conn).unwrap(), LATEST_VERSION);
+    assert_eq!(schema_of(&conn), schema_of(&Database::open_in_memory().unwrap().conn));
+}
+
+#[test]
+fn pending_does_not_migrate() {
+    let conn = fixture(3);
+    let before = schema_of(&conn);
+    let pending: Vec<u32> = pending(&conn).unwrap().iter().map(|m| m.version).collect();
+    assert_eq!(pending, (4..=LATEST_VERSION).collect::<Vec<_>>());
+    assert_eq!(db_version(&conn).unwrap(), 3);
+    assert_eq!(schema_of(&conn), before);
+}
+
+#[test]
+fn newer_database_is_refused() {
+    let conn = fixture(LATEST_VERSION);
+    conn.pragma_update(None, "user_version", LATEST_VERSION + 1).unwrap();
+
+    assert!(pending(&conn).unwrap().is_empty());
+    let err = migrate(&conn).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::SchemaTooNew { version, supported }
+            if version == LATEST_VERSION + 1 && supported == LATEST_VERSION
+    ));
+    assert_eq!(db_version(&conn).unwrap(), LATEST_VERSION + 1);
+}
+
+#[test]
+fn failed_migration_rolls_back_to_last_version() {
+    fn half_done(conn: &Connection) -> Result<()> {
+        conn.execute("CREATE TABLE scratch (id INTEGER)", [])?;
+        Err(Error::CorruptedData("boom".to_string()))
+    }
+    let migrations = [
+        Migration {
+            version: 1,
+            description: "create missing tables and indexes",
+            up: create_schema,
+            legacy_needed: None,
+        },
+        Migration { version: 2, description: "fail halfway", up: half_done, legacy_needed: None },
+    ];
+    let conn = Connection::open_in_memory().unwrap();
+
+    assert!(apply(&conn, &migrations).is_err());
+    assert_eq!(db_version(&conn).unwrap(), 1);
+    assert!(!has_table(&conn, "scratch").unwrap());
+    assert!(has_table(&conn, "issues").unwrap());
+}
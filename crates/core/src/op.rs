@@ -73,7 +73,14 @@ impl Ord for Op {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum OpPayload {
     /// Create a new issue.
-    CreateIssue { id: String, issue_type: IssueType, title: String },
+    CreateIssue {
+        id: String,
+        issue_type: IssueType,
+        title: String,
+        /// Short ID number (`prj-12`) allocated by the creating replica.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        short_id: Option<u32>,
+    },
 
     /// Set the status of an issue.
     SetStatus {
@@ -116,7 +123,17 @@ pub enum OpPayload {
 impl OpPayload {
     /// Creates a CreateIssue payload.
     pub fn create_issue(id: String, issue_type: IssueType, title: String) -> Self {
-        OpPayload::CreateIssue { id, issue_type, title }
+        OpPayload::CreateIssue { id, issue_type, title, short_id: None }
+    }
+
+    /// Creates a CreateIssue payload carrying the creator's short ID number.
+    pub fn create_issue_with_short_id(
+        id: String,
+        issue_type: IssueType,
+        title: String,
+        short_id: u32,
+    ) -> Self {
+        OpPayload::CreateIssue { id, issue_type, title, short_id: Some(short_id) }
     }
 
     /// Creates a SetStatus payload.
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Issue queries compiled to a single SQL statement.
+//!
+//! Commands describe which issues they want as a [`Cond`] tree (status, type,
+//! labels, assignee, blocked state, priority, timestamps, text) and run it with
+//! [`Database::query_issues`](crate::Database::query_issues). Filtering,
+//! ordering, and limits all happen inside SQLite, so only matching rows are
+//! loaded and no per-issue label lookups are needed.
+//!
+//! ```ignore
+//! let query = IssueQuery::new(Cond::All(vec![
+//!     Cond::Status(vec![Status::Todo]),
+//!     Cond::Label("team:*".to_string()),
+//!     Cond::Not(Box::new(Cond::Blocked)),
+//! ]))
+//! .with_order(QueryOrder::Priority)
+//! .with_limit(Some(10));
+//! let issues = db.query_issues(&query)?;
+//! ```
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rusqlite::types::Value;
+
+use crate::issue::{IssueType, Status};
+
+/// Comparison operator for priority and time conditions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Cmp {
+    fn as_sql(self) -> &'static str {
+        match self {
+            Cmp::Lt => "<",
+            Cmp::Le => "<=",
+            Cmp::Gt => ">",
+            Cmp::Ge => ">=",
+            Cmp::Eq => "=",
+            Cmp::Ne => "!=",
+        }
+    }
+}
+
+/// Issue timestamp a time condition applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeField {
+    Created,
+    Updated,
+    Closed,
+}
+
+impl TimeField {
+    fn column(self) -> &'static str {
+        match self {
+            TimeField::Created => "i.created_at",
+            TimeField::Updated => "i.updated_at",
+            TimeField::Closed => "i.closed_at",
+        }
+    }
+}
+
+/// A condition on issues.
+///
+/// Leaves never match an unset column (an unassigned issue, an open issue's
+/// close time), so `Not` of a leaf does match it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cond {
+    /// Every part matches; an empty list matches everything.
+    All(Vec<Cond>),
+    /// At least one part matches; an empty list matches nothing.
+    Any(Vec<Cond>),
+    Not(Box<Cond>),
+    /// Status is one of these.
+    Status(Vec<Status>),
+    /// Type is one of these.
+    Type(Vec<IssueType>),
+    /// Has this label, or with a trailing `*`, any label with that prefix.
+    Label(String),
+    /// Assigned to one of these.
+    Assignee(Vec<String>),
+    Unassigned,
+    /// ID prefix: the part before the first `-`.
+    IdPrefix(String),
+    /// Has an open blocker, directly or transitively.
+    Blocked,
+    /// Priority from `priority:`/`p:` labels, as in
+    /// [`Database::priority_from_tags`](crate::Database::priority_from_tags).
+    Priority(Cmp, u8),
+    /// Timestamp compared against an instant.
+    Time(TimeField, Cmp, DateTime<Utc>),
+    /// Timestamp falls on this (UTC) day.
+    OnDate(TimeField, NaiveDate),
+    /// Timestamp is set.
+    HasTime(TimeField),
+    /// Case-insensitive substring of title, description, assignee, notes,
+    /// labels, or links, as in [`Database::search_issues`](crate::Database::search_issues).
+    Text(String),
+    /// Sorts after the issue with this creation time and ID in
+    /// [`QueryOrder::Created`]: the keyset condition for the next page.
+    After(DateTime<Utc>, String),
+}
+
+/// Result order for [`IssueQuery`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QueryOrder {
+    /// Newest first.
+    #[default]
+    Newest,
+    /// Highest priority first, then newest.
+    Priority,
+    /// Oldest first, ties broken by ID. A stable order for paging with
+    /// [`Cond::After`].
+    Created,
+}
+
+/// A filtered, ordered, optionally limited issue query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueQuery {
+    pub cond: Cond,
+    pub order: QueryOrder,
+    pub limit: Option<usize>,
+}
+
+impl Default for IssueQuery {
+    fn default() -> Self {
+        IssueQuery::new(Cond::All(Vec::new()))
+    }
+}
+
+impl IssueQuery {
+    pub fn new(cond: Cond) -> Self {
+        IssueQuery { cond, order: QueryOrder::default(), limit: None }
+    }
+
+    pub fn with_order(mut self, order: QueryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Return at most `limit` issues; `None` returns all of them.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// The SQL statement and its positional parameters.
+    ///
+    /// Selects the issue columns in the order `row_to_issue` expects.
+    pub fn to_sql(&self) -> (String, Vec<Value>) {
+        let mut builder = SqlBuilder::default();
+        let where_clause = builder.cond(&self.cond);
+
+        let mut sql = String::new();
+        if builder.uses_blocked {
+            sql.push_str(BLOCKED_CTE);
+            sql.push(' ');
+        }
+        sql.push_str(
+            "SELECT i.id, i.type, i.title, i.description, i.status, i.assignee,
+             i.created_at, i.updated_at, i.closed_at, i.last_status_hlc, i.last_title_hlc,
+             i.last_type_hlc, i.last_description_hlc, i.last_assignee_hlc, i.revision
+             FROM issues i WHERE ",
+        );
+        sql.push_str(&where_clause);
+        match self.order {
+            QueryOrder::Newest => sql.push_str(" ORDER BY i.created_at DESC"),
+            QueryOrder::Priority => {
+                sql.push_str(&format!(" ORDER BY {} ASC, i.created_at DESC", priority_expr()))
+            }
+            QueryOrder::Created => sql.push_str(" ORDER BY julianday(i.created_at) ASC, i.id ASC"),
+        }
+        if let Some(limit) = self.limit {
+            sql.push_str(" LIMIT ?");
+            builder.params.push(Value::Integer(i64::try_from(limit).unwrap_or(i64::MAX)));
+        }
+        (sql, builder.params)
+    }
+}
+
+/// Issues with an open blocker, following `blocks` edges transitively, or
+/// an unresolved external blocker.
+///
+/// Must agree with [`Database::get_blocked_issue_ids`](crate::Database::get_blocked_issue_ids).
+const BLOCKED_CTE: &str = "WITH RECURSIVE all_blockers(issue_id, blocker_id) AS (
+        SELECT to_id, from_id FROM deps WHERE rel = 'blocks'
+        UNION
+        SELECT ab.issue_id, d.from_id
+        FROM all_blockers ab
+        CROSS JOIN deps d ON d.to_id = ab.blocker_id AND d.rel = 'blocks'
+    ),
+    blocked(id) AS (
+        SELECT ab.issue_id FROM all_blockers ab
+        CROSS JOIN issues b ON b.id = ab.blocker_id
+        WHERE b.status IN ('todo', 'in_progress')
+        UNION
+        SELECT issue_id FROM links
+        WHERE rel = 'blocks' AND resolved_at IS NULL AND deleted_at IS NULL
+    )";
+
+/// Accumulates parameters while rendering a condition.
+#[derive(Default)]
+struct SqlBuilder {
+    params: Vec<Value>,
+    uses_blocked: bool,
+}
+
+impl SqlBuilder {
+    fn cond(&mut self, cond: &Cond) -> String {
+        match cond {
+            Cond::All(parts) => self.join(parts, " AND ", "1"),
+            Cond::Any(parts) => self.join(parts, " OR ", "0"),
+            Cond::Not(inner) => format!("NOT ({})", self.cond(inner)),
+            Cond::Status(values) => {
+                self.in_list("i.status", values.iter().map(|s| s.as_str().to_string()))
+            }
+            Cond::Type(values) => {
+                self.in_list("i.type", values.iter().map(|t| t.as_str().to_string()))
+            }
+            Cond::Label(pattern) => match pattern.strip_suffix('*') {
+                Some(prefix) => {
+                    self.params.push(Value::Text(format!("{}*", glob_escape(prefix))));
+                    "i.id IN (SELECT issue_id FROM labels WHERE label GLOB ?)".to_string()
+                }
+                None => {
+                    self.params.push(Value::Text(pattern.clone()));
+                    "i.id IN (SELECT issue_id FROM labels WHERE label = ?)".to_string()
+                }
+            },
+            Cond::Assignee(values) => format!(
+                "(i.assignee IS NOT NULL AND {})",
+                self.in_list("i.assignee", values.iter().cloned())
+            ),
+            Cond::Unassigned => "i.assignee IS NULL".to_string(),
+            Cond::IdPrefix(prefix) => {
+                self.params.push(Value::Text(prefix.clone()));
+                self.params.push(Value::Text(format!("{}-*", glob_escape(prefix))));
+                "(i.id = ? OR i.id GLOB ?)".to_string()
+            }
+            Cond::Blocked => {
+                self.uses_blocked = true;
+                "i.id IN (SELECT id FROM blocked)".to_string()
+            }
+            Cond::Priority(cmp, value) => {
+                self.params.push(Value::Integer(i64::from(*value)));
+                format!("{} {} ?", priority_expr(), cmp.as_sql())
+            }
+            Cond::Time(field, cmp, at) => {
+                self.params.push(Value::Text(at.to_rfc3339()));
+                let column = field.column();
+                format!(
+                    "({column} IS NOT NULL AND julianday({column}) {} julianday(?))",
+                    cmp.as_sql()
+                )
+            }
+            Cond::OnDate(field, day) => {
+                self.params.push(Value::Text(day.format("%Y-%m-%d").to_string()));
+                let column = field.column();
+                format!("({column} IS NOT NULL AND date({column}) = ?)")
+            }
+            Cond::HasTime(field) => format!("{} IS NOT NULL", field.column()),
+            Cond::Text(query) => {
+                let escaped = query.replace('%', "\\%").replace('_', "\\_");
+                self.params.push(Value::Text(format!("%{}%", escaped)));
+                let n = self.params.len();
+                let like = |column: &str| format!("{column} LIKE ?{n} COLLATE NOCASE ESCAPE '\\'");
+                format!(
+                    "({} OR {} OR {}
+                      OR EXISTS (SELECT 1 FROM notes n WHERE n.issue_id = i.id
+                                 AND n.deleted_at IS NULL AND {})
+                      OR EXISTS (SELECT 1 FROM labels l WHERE l.issue_id = i.id AND {})
+                      OR EXISTS (SELECT 1 FROM links lk WHERE lk.issue_id = i.id
+                                 AND lk.deleted_at IS NULL AND ({} OR {})))",
+                    like("i.title"),
+                    like("i.description"),
+                    like("i.assignee"),
+                    like("n.content"),
+                    like("l.label"),
+                    like("lk.url"),
+                    like("lk.external_id"),
+                )
+            }
+            Cond::After(created, id) => {
+                // Compare the same julianday values the order uses, so ties
+                // at SQLite's time resolution fall through to the ID.
+                self.params.push(Value::Text(created.to_rfc3339()));
+                self.params.push(Value::Text(id.clone()));
+                let (at, id) = (self.params.len() - 1, self.params.len());
+                format!(
+                    "(julianday(i.created_at) > julianday(?{at})
+                      OR (julianday(i.created_at) = julianday(?{at}) AND i.id > ?{id}))"
+                )
+            }
+        }
+    }
+
+    fn join(&mut self, parts: &[Cond], sep: &str, empty: &str) -> String {
+        if parts.is_empty() {
+            return empty.to_string();
+        }
+        let rendered: Vec<String> = parts.iter().map(|c| self.cond(c)).collect();
+        format!("({})", rendered.join(sep))
+    }
+
+    fn in_list(&mut self, column: &str, values: impl Iterator<Item = String>) -> String {
+        let start = self.params.len();
+        self.params.extend(values.map(Value::Text));
+        let count = self.params.len() - start;
+        if count == 0 {
+            return "0".to_string();
+        }
+        format!("{} IN ({})", column, vec!["?"; count].join(", "))
+    }
+}
+
+/// SQL expression for an issue's priority, matching
+/// [`Database::priority_from_tags`](crate::Database::priority_from_tags):
+/// the first valid `priority:` label, else the first valid `p:` label, else 2.
+fn priority_expr() -> String {
+    let first_valid = |prefix: &str| {
+        let start = prefix.len() + 1;
+        format!(
+            "(SELECT CASE substr(l.label, {start})
+                 WHEN '0' THEN 0 WHEN 'highest' THEN 0
+                 WHEN '1' THEN 1 WHEN 'high' THEN 1
+                 WHEN '2' THEN 2 WHEN 'medium' THEN 2 WHEN 'med' THEN 2
+                 WHEN '3' THEN 3 WHEN 'low' THEN 3
+                 WHEN '4' THEN 4 WHEN 'lowest' THEN 4
+               END AS p
+             FROM labels l
+             WHERE l.issue_id = i.id AND l.label GLOB '{prefix}*' AND p IS NOT NULL
+             ORDER BY l.label LIMIT 1)"
+        )
+    };
+    format!("COALESCE({}, {}, 2)", first_valid("priority:"), first_valid("p:"))
+}
+
+/// Escape GLOB metacharacters so `s` matches literally.
+fn glob_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '*' | '?' | '[' => {
+                out.push('[');
+                out.push(c);
+                out.push(']');
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "query_tests.rs"]
+mod tests;
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::db::Database;
+use crate::issue::{Issue, Relation};
+use crate::link::{Link, LinkRel};
+use chrono::{Duration, TimeZone};
+use yare::parameterized;
+
+fn at(day: u32, hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+}
+
+fn insert(db: &Database, id: &str, issue_type: IssueType, created: DateTime<Utc>) {
+    let issue = Issue::new(id.to_string(), issue_type, format!("Issue {}", id), created);
+    db.create_issue(&issue).unwrap();
+}
+
+/// Five issues created a day apart:
+/// - prj-1 task, todo, alice, labels urgent + team:api, priority:0
+/// - prj-2 bug, in_progress, bob, label team:web
+/// - prj-3 task, done on March 4, label p:high
+/// - prj-4 task, todo, blocked by prj-1 (transitively by nothing else)
+/// - other-1 feature, todo, title "Weird [glob]*"
+fn fixture() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    insert(&db, "prj-1", IssueType::Task, at(1, 0));
+    insert(&db, "prj-2", IssueType::Bug, at(2, 0));
+    insert(&db, "prj-3", IssueType::Task, at(3, 0));
+    insert(&db, "prj-4", IssueType::Task, at(4, 0));
+    insert(&db, "other-1", IssueType::Feature, at(5, 0));
+
+    db.set_assignee("prj-1", "alice").unwrap();
+    db.set_assignee("prj-2", "bob").unwrap();
+    db.update_issue_status("prj-2", Status::InProgress).unwrap();
+    db.update_issue_status("prj-3", Status::Done).unwrap();
+    db.conn
+        .execute("UPDATE issues SET closed_at = ?1 WHERE id = 'prj-3'", [at(4, 12).to_rfc3339()])
+        .unwrap();
+    db.conn.execute("UPDATE issues SET title = 'Weird [glob]*' WHERE id = 'other-1'", []).unwrap();
+
+    for (id, label) in [
+        ("prj-1", "urgent"),
+        ("prj-1", "team:api"),
+        ("prj-1", "priority:0"),
+        ("prj-2", "team:web"),
+        ("prj-3", "p:high"),
+        ("other-1", "we*rd"),
+    ] {
+        db.add_label(id, label).unwrap();
+    }
+    db.add_dependency("prj-1", "prj-4", Relation::Blocks).unwrap();
+    db
+}
+
+fn ids(db: &Database, cond: Cond) -> Vec<String> {
+    let mut ids: Vec<String> =
+        db.query_issues(&IssueQuery::new(cond)).unwrap().into_iter().map(|i| i.id).collect();
+    ids.sort();
+    ids
+}
+
+fn labels(values: &[&str]) -> Cond {
+    Cond::Any(values.iter().map(|v| Cond::Label(v.to_string())).collect())
+}
+
+#[test]
+fn empty_all_matches_everything_and_empty_any_nothing() {
+    let db = fixture();
+    assert_eq!(ids(&db, Cond::All(vec![])).len(), 5);
+    assert!(ids(&db, Cond::Any(vec![])).is_empty());
+    assert!(ids(&db, Cond::Status(vec![])).is_empty());
+}
+
+#[test]
+fn status_and_type() {
+    let db = fixture();
+    assert_eq!(ids(&db, Cond::Status(vec![Status::Todo])), vec!["other-1", "prj-1", "prj-4"]);
+    assert_eq!(
+        ids(
+            &db,
+            Cond::All(vec![
+                Cond::Type(vec![IssueType::Task]),
+                Cond::Not(Box::new(Cond::Status(vec![Status::Done]))),
+            ])
+        ),
+        vec!["prj-1", "prj-4"]
+    );
+}
+
+#[parameterized(
+    exact = { &["urgent"], &["prj-1"] },
+    either = { &["urgent", "team:web"], &["prj-1", "prj-2"] },
+    prefix = { &["team:*"], &["prj-1", "prj-2"] },
+    any_label = { &["*"], &["other-1", "prj-1", "prj-2", "prj-3"] },
+    literal_star = { &["we*rd"], &["other-1"] },
+    escaped_prefix = { &["we**"], &["other-1"] },
+    no_match = { &["team"], &[] },
+)]
+fn label_patterns(patterns: &[&str], expected: &[&str]) {
+    let db = fixture();
+    assert_eq!(ids(&db, labels(patterns)), expected);
+}
+
+#[test]
+fn assignee_negation_includes_unassigned() {
+    let db = fixture();
+    assert_eq!(ids(&db, Cond::Assignee(vec!["alice".to_string()])), vec!["prj-1"]);
+    assert_eq!(
+        ids(&db, Cond::Not(Box::new(Cond::Assignee(vec!["alice".to_string()])))),
+        vec!["other-1", "prj-2", "prj-3", "prj-4"]
+    );
+    assert_eq!(ids(&db, Cond::Unassigned), vec!["other-1", "prj-3", "prj-4"]);
+}
+
+#[test]
+fn id_prefix_matches_whole_prefix() {
+    let db = fixture();
+    insert(&db, "prjx-1", IssueType::Task, at(6, 0));
+    assert_eq!(
+        ids(&db, Cond::IdPrefix("prj".to_string())),
+        vec!["prj-1", "prj-2", "prj-3", "prj-4"]
+    );
+}
+
+#[test]
+fn blocked_follows_open_blockers_transitively() {
+    let db = fixture();
+    insert(&db, "prj-5", IssueType::Task, at(6, 0));
+    db.add_dependency("prj-4", "prj-5", Relation::Blocks).unwrap();
+    assert_eq!(ids(&db, Cond::Blocked), vec!["prj-4", "prj-5"]);
+
+    db.update_issue_status("prj-1", Status::Done).unwrap();
+    db.update_issue_status("prj-4", Status::Done).unwrap();
+    assert!(ids(&db, Cond::Blocked).is_empty());
+}
+
+#[test]
+fn blocked_includes_unresolved_external_blockers() {
+    let db = fixture();
+    let link = Link::new("prj-3".to_string())
+        .with_url("https://github.com/o/r/issues/1".to_string())
+        .with_rel(LinkRel::Blocks);
+    let link_id = db.add_link(&link).unwrap();
+    assert!(ids(&db, Cond::Blocked).contains(&"prj-3".to_string()));
+
+    db.set_link_resolved(link_id, Some(Utc::now())).unwrap();
+    assert!(!ids(&db, Cond::Blocked).contains(&"prj-3".to_string()));
+}
+
+#[test]
+fn time_conditions() {
+    let db = fixture();
+    assert_eq!(ids(&db, Cond::Time(TimeField::Created, Cmp::Lt, at(3, 0))), vec!["prj-1", "prj-2"]);
+    assert_eq!(
+        ids(&db, Cond::Time(TimeField::Created, Cmp::Ge, at(4, 0))),
+        vec!["other-1", "prj-4"]
+    );
+    assert_eq!(ids(&db, Cond::OnDate(TimeField::Closed, at(4, 0).date_naive())), vec!["prj-3"]);
+    assert_eq!(ids(&db, Cond::Not(Box::new(Cond::HasTime(TimeField::Closed)))).len(), 4);
+}
+
+#[test]
+fn text_searches_titles_and_labels_literally() {
+    let db = fixture();
+    assert_eq!(ids(&db, Cond::Text("[GLOB]".to_string())), vec!["other-1"]);
+    assert_eq!(ids(&db, Cond::Text("team:".to_string())), vec!["prj-1", "prj-2"]);
+    assert!(ids(&db, Cond::Text("%".to_string())).is_empty());
+}
+
+#[parameterized(
+    none = { &[] },
+    numeric = { &["priority:1"] },
+    named = { &["priority:lowest"] },
+    short = { &["p:3"] },
+    long_wins = { &["p:0", "priority:4"] },
+    invalid_falls_back = { &["priority:urgent", "p:low"] },
+    first_sorted_wins = { &["priority:3", "priority:1"] },
+    case_sensitive = { &["Priority:0"] },
+)]
+fn priority_matches_priority_from_tags(tags: &[&str]) {
+    let db = Database::open_in_memory().unwrap();
+    insert(&db, "prj-1", IssueType::Task, at(1, 0));
+    for tag in tags {
+        db.add_label("prj-1", tag).unwrap();
+    }
+    let expected = Database::priority_from_tags(&db.get_labels("prj-1").unwrap());
+
+    for value in 0..=4u8 {
+        let matched = !ids(&db, Cond::Priority(Cmp::Eq, value)).is_empty();
+        assert_eq!(matched, value == expected, "priority {} vs {}", value, expected);
+    }
+}
+
+#[test]
+fn priority_order_and_limit() {
+    let db = fixture();
+    let query = IssueQuery::new(Cond::Not(Box::new(Cond::Status(vec![Status::Done]))))
+        .with_order(QueryOrder::Priority)
+        .with_limit(Some(3));
+    let ordered: Vec<String> = db.query_issues(&query).unwrap().into_iter().map(|i| i.id).collect();
+    assert_eq!(ordered, vec!["prj-1", "other-1", "prj-4"]);
+}
+
+#[test]
+fn newest_order_is_default() {
+    let db = fixture();
+    let ordered: Vec<String> = db
+        .query_issues(&IssueQuery::default().with_limit(Some(2)))
+        .unwrap()
+        .into_iter()
+        .map(|i| i.id)
+        .collect();
+    assert_eq!(ordered, vec!["other-1", "prj-4"]);
+}
+
+#[test]
+fn relative_time_bounds() {
+    let db = Database::open_in_memory().unwrap();
+    let now = Utc::now();
+    insert(&db, "prj-1", IssueType::Task, now - Duration::days(5));
+    insert(&db, "prj-2", IssueType::Task, now - Duration::hours(1));
+    assert_eq!(
+        ids(&db, Cond::Time(TimeField::Created, Cmp::Gt, now - Duration::days(3))),
+        vec!["prj-2"]
+    );
+}
+
+#[test]
+fn keyset_pages_follow_created_order() {
+    let db = fixture();
+    // Same creation time as prj-2: the ID breaks the tie.
+    insert(&db, "prj-0", IssueType::Task, at(2, 0));
+
+    let page = |after: Option<(DateTime<Utc>, String)>| -> Vec<Issue> {
+        let cond = after.map_or(Cond::All(vec![]), |(at, id)| Cond::After(at, id));
+        let query = IssueQuery::new(cond).with_order(QueryOrder::Created).with_limit(Some(2));
+        db.query_issues(&query).unwrap()
+    };
+
+    let mut seen = Vec::new();
+    let mut after = None;
+    loop {
+        let issues = page(after.take());
+        let Some(last) = issues.last() else { break };
+        after = Some((last.created_at, last.id.clone()));
+        seen.extend(issues.into_iter().map(|i| i.id));
+    }
+    assert_eq!(seen, vec!["prj-1", "prj-0", "prj-2", "prj-3", "prj-4", "other-1"]);
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Retrying database work that lost a race for the SQLite lock.
+//!
+//! `busy_timeout` makes SQLite wait for most locks, but some conflicts are
+//! reported at once as `SQLITE_BUSY` or `SQLITE_LOCKED`: switching to WAL
+//! while another connection holds the database, or a write that finds its
+//! snapshot stale. Parallel CLI invocations on one database hit these, so
+//! writes are retried with exponential backoff and jitter.
+
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rusqlite::ErrorCode;
+
+use crate::error::{Error, Result};
+
+/// Attempts before a busy error is returned.
+pub const MAX_ATTEMPTS: u32 = 8;
+
+/// Delay before the first retry; each later retry waits twice as long.
+const BASE_DELAY: Duration = Duration::from_millis(10);
+
+/// Longest single delay.
+const MAX_DELAY: Duration = Duration::from_millis(500);
+
+/// Whether `err` means another connection held the database.
+pub fn is_busy(err: &Error) -> bool {
+    is_busy_error(err)
+}
+
+/// Whether `err`, or an error it wraps, means another connection held the
+/// database.
+pub fn is_busy_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    std::iter::successors(Some(err), |e| e.source()).any(|e| {
+        matches!(
+            e.downcast_ref::<rusqlite::Error>(),
+            Some(rusqlite::Error::SqliteFailure(failure, _))
+                if matches!(failure.code, ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+        )
+    })
+}
+
+/// Run `f`, retrying with backoff while it fails because the database is busy.
+pub fn retry_busy<T>(f: impl FnMut() -> Result<T>) -> Result<T> {
+    retry_busy_wrapped(f)
+}
+
+/// Like [`retry_busy`], for work whose error type wraps the database's.
+pub fn retry_busy_wrapped<T, E: std::error::Error + 'static>(
+    mut f: impl FnMut() -> std::result::Result<T, E>,
+) -> std::result::Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(e) if is_busy_error(&e) && attempt < MAX_ATTEMPTS => {
+                thread::sleep(backoff(attempt));
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Delay after failed attempt `attempt` (1-based): exponential, capped, plus
+/// up to half again so that processes retrying together spread out.
+pub(crate) fn backoff(attempt: u32) -> Duration {
+    let exp = BASE_DELAY.saturating_mul(1 << attempt.saturating_sub(1).min(16));
+    let delay = exp.min(MAX_DELAY);
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.subsec_nanos());
+    let jitter_ms =
+        u64::from(nanos) % (u64::try_from(delay.as_millis()).unwrap_or(u64::MAX) / 2 + 1);
+    delay + Duration::from_millis(jitter_ms)
+}
+
+#[cfg(test)]
+#[path = "retry_tests.rs"]
+mod tests;
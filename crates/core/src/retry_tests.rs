@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn sqlite_error(code: i32) -> Error {
+    Error::Database(rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(code), None))
+}
+
+#[test]
+fn busy_and_locked_are_busy() {
+    assert!(is_busy(&sqlite_error(rusqlite::ffi::SQLITE_BUSY)));
+    assert!(is_busy(&sqlite_error(rusqlite::ffi::SQLITE_LOCKED)));
+    assert!(!is_busy(&sqlite_error(rusqlite::ffi::SQLITE_READONLY)));
+    assert!(!is_busy(&Error::IssueNotFound("x".to_string())));
+}
+
+#[test]
+fn retries_until_the_lock_is_free() {
+    let mut calls = 0;
+    let result = retry_busy(|| {
+        calls += 1;
+        if calls < 3 {
+            Err(sqlite_error(rusqlite::ffi::SQLITE_BUSY))
+        } else {
+            Ok(calls)
+        }
+    });
+    assert_eq!(result.ok(), Some(3));
+}
+
+#[test]
+fn gives_up_after_max_attempts() {
+    let mut calls = 0;
+    let result: Result<()> = retry_busy(|| {
+        calls += 1;
+        Err(sqlite_error(rusqlite::ffi::SQLITE_LOCKED))
+    });
+    assert!(result.is_err_and(|e| is_busy(&e)));
+    assert_eq!(calls, MAX_ATTEMPTS);
+}
+
+#[test]
+fn other_errors_are_not_retried() {
+    let mut calls = 0;
+    let result: Result<()> = retry_busy(|| {
+        calls += 1;
+        Err(Error::IssueNotFound("test-1".to_string()))
+    });
+    assert!(result.is_err());
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn backoff_grows_and_is_capped() {
+    for attempt in 1..12 {
+        let delay = backoff(attempt);
+        let base = (BASE_DELAY * (1 << (attempt - 1))).min(MAX_DELAY);
+        assert!(delay >= base, "attempt {}: {:?}", attempt, delay);
+        assert!(delay <= base + base / 2, "attempt {}: {:?}", attempt, delay);
+    }
+}
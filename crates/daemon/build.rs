@@ -30,6 +30,9 @@ pub const WOK_STATE_DIR: &str = "WOK_STATE_DIR";
 /// Environment variable: XDG base directory for state data.
 pub const XDG_STATE_HOME: &str = "XDG_STATE_HOME";
 
+/// Environment variable: XDG base directory for user configuration.
+pub const XDG_CONFIG_HOME: &str = "XDG_CONFIG_HOME";
+
 /// Environment variable: controls log level filtering (used by tracing-subscriber).
 pub const RUST_LOG: &str = "RUST_LOG";
 "#;
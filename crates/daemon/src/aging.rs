@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Periodic stale checks for projects with `issue.stale` hooks.
+//!
+//! `wok` registers a project with a `WatchStale` request when its hooks
+//! listen for `issue.stale`. Every [`CHECK_INTERVAL`] a thread runs
+//! `wok hook stale` in each registered project, which records a `stale`
+//! event for issues past their `[aging]` threshold and fires the hooks.
+//! Registrations last until the daemon stops; projects that are gone are
+//! dropped.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Time between checks.
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// The thread checking registered projects for stale issues.
+pub struct StaleWatcher {
+    projects: Arc<Mutex<BTreeSet<PathBuf>>>,
+    stop: mpsc::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl StaleWatcher {
+    /// Start checking projects with the `wok` binary at `program`.
+    pub fn start(program: PathBuf) -> Self {
+        let projects = Arc::new(Mutex::new(BTreeSet::new()));
+        let (stop, stopped) = mpsc::channel();
+        let watched = Arc::clone(&projects);
+        let handle = std::thread::spawn(move || run(&program, &watched, &stopped));
+        StaleWatcher { projects, stop, handle }
+    }
+
+    /// Check the project at `work_dir` from the next pass on. Returns
+    /// whether it was new.
+    pub fn watch(&self, work_dir: PathBuf) -> bool {
+        let mut projects = self.projects.lock().unwrap_or_else(PoisonError::into_inner);
+        let added = projects.insert(work_dir.clone());
+        if added {
+            tracing::info!("watching {} for stale issues", work_dir.display());
+        }
+        added
+    }
+
+    /// Stop the thread, waiting for a check in flight to finish.
+    pub fn shutdown(self) {
+        drop(self.stop);
+        let _ = self.handle.join();
+    }
+}
+
+fn run(program: &Path, projects: &Mutex<BTreeSet<PathBuf>>, stopped: &mpsc::Receiver<()>) {
+    loop {
+        match stopped.recv_timeout(CHECK_INTERVAL) {
+            Err(RecvTimeoutError::Timeout) => check_pass(program, projects),
+            _ => return,
+        }
+    }
+}
+
+/// Check every registered project once, dropping those that are gone.
+pub(crate) fn check_pass(program: &Path, projects: &Mutex<BTreeSet<PathBuf>>) {
+    let snapshot: Vec<PathBuf> =
+        projects.lock().unwrap_or_else(PoisonError::into_inner).iter().cloned().collect();
+    for work_dir in snapshot {
+        if !work_dir.join(".wok").is_dir() {
+            tracing::info!("no longer watching {}: not a wok project", work_dir.display());
+            projects.lock().unwrap_or_else(PoisonError::into_inner).remove(&work_dir);
+            continue;
+        }
+        check(program, &work_dir);
+    }
+}
+
+/// Run `wok hook stale` in `work_dir`, logging failures.
+fn check(program: &Path, work_dir: &Path) {
+    let output = Command::new(program)
+        .args(["hook", "stale"])
+        .current_dir(work_dir)
+        .stdin(Stdio::null())
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => tracing::warn!(
+            "stale check in {} failed: {}",
+            work_dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => tracing::warn!("cannot run {}: {}", program.display(), e),
+    }
+}
+
+/// The `wok` binary next to this daemon, else `wok` from `PATH`.
+pub fn wok_binary() -> PathBuf {
+    std::env::current_exe()
+        .map(|exe| exe.with_file_name("wok"))
+        .ok()
+        .filter(|wok| wok.exists())
+        .unwrap_or_else(|| PathBuf::from("wok"))
+}
+
+#[cfg(test)]
+#[path = "aging_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::time::Instant;
+
+use super::*;
+
+/// A stand-in `wok` that records its arguments in the project it runs in.
+fn fake_wok(dir: &Path) -> PathBuf {
+    let path = dir.join("wok");
+    std::fs::write(&path, "#!/bin/sh\necho \"$@\" > ran\n").unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+#[test]
+fn check_pass_runs_hook_stale_in_each_project() {
+    let dir = tempfile::tempdir().unwrap();
+    let program = fake_wok(dir.path());
+    let project = dir.path().join("project");
+    std::fs::create_dir_all(project.join(".wok")).unwrap();
+    let projects = Mutex::new(BTreeSet::from([project.clone()]));
+
+    check_pass(&program, &projects);
+
+    assert_eq!(std::fs::read_to_string(project.join("ran")).unwrap(), "hook stale\n");
+    assert_eq!(projects.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn check_pass_drops_projects_that_are_gone() {
+    let dir = tempfile::tempdir().unwrap();
+    let program = fake_wok(dir.path());
+    let gone = dir.path().join("gone");
+    let projects = Mutex::new(BTreeSet::from([gone.clone()]));
+
+    check_pass(&program, &projects);
+
+    assert!(projects.lock().unwrap().is_empty());
+    assert!(!gone.join("ran").exists());
+}
+
+#[test]
+fn watch_reports_new_projects_once() {
+    let watcher = StaleWatcher::start(PathBuf::from("wok"));
+
+    assert!(watcher.watch(PathBuf::from("/tmp/project")));
+    assert!(!watcher.watch(PathBuf::from("/tmp/project")));
+
+    let started = Instant::now();
+    watcher.shutdown();
+    assert!(started.elapsed() < CHECK_INTERVAL);
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use tempfile::TempDir;
+
+use super::*;
+use crate::ipc::{Action, DependencyRef, Issue, IssueType, Relation, Status};
+
+fn open() -> (TempDir, Database) {
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open(&dir.path().join("issues.db")).unwrap();
+    (dir, db)
+}
+
+fn create(db: &mut Database, id: &str) -> wk_core::Result<MutateResult> {
+    let issue = Issue::new(id.to_string(), IssueType::Task, "Title".to_string());
+    db.execute_mutate(MutateOp::CreateIssue { issue })
+}
+
+fn sqlite_error(code: i32) -> Error {
+    Error::Database(rusqlite::Error::SqliteFailure(ffi::Error::new(code), None))
+}
+
+#[test]
+fn missing_issue_is_not_found() {
+    let (_dir, db) = open();
+
+    let err = db.execute_query(QueryOp::GetIssue { id: "prj-none".to_string() }).unwrap_err();
+
+    assert_eq!(error_code(&err), Some(DaemonDbError::NotFound));
+}
+
+#[test]
+fn self_dependency_is_a_conflict() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+
+    let err = db
+        .execute_mutate(MutateOp::AddDependency(DependencyRef {
+            from_id: "prj-a1".to_string(),
+            to_id: "prj-a1".to_string(),
+            relation: Relation::Blocks,
+        }))
+        .unwrap_err();
+
+    assert_eq!(error_code(&err), Some(DaemonDbError::Conflict));
+}
+
+#[test]
+fn stale_if_match_is_a_conflict() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+    let retitle = |title: &str, if_match| MutateOp::UpdateIssueTitle {
+        id: "prj-a1".to_string(),
+        title: title.to_string(),
+        if_match,
+    };
+    db.execute_mutate(retitle("First", Some(1))).unwrap();
+
+    let err = db.execute_mutate(retitle("Second", Some(1))).unwrap_err();
+
+    assert_eq!(error_code(&err), Some(DaemonDbError::Conflict));
+}
+
+#[test]
+fn unused_if_match_does_not_carry_over() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+    let remove = MutateOp::RemoveLabel {
+        id: "prj-a1".to_string(),
+        label: "absent".to_string(),
+        if_match: Some(1),
+    };
+    db.execute_mutate(remove).unwrap();
+    db.core.conn.execute("UPDATE issues SET revision = 5", []).unwrap();
+
+    let add =
+        MutateOp::AddLabel { id: "prj-a1".to_string(), label: "ui".to_string(), if_match: None };
+    db.execute_mutate(add).unwrap();
+}
+
+fn actions(db: &Database, id: &str) -> Vec<Action> {
+    db.core.get_events(id).unwrap().into_iter().map(|e| e.action).collect()
+}
+
+#[test]
+fn mutations_log_their_events() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+    let label =
+        MutateOp::AddLabel { id: "prj-a1".to_string(), label: "ui".to_string(), if_match: None };
+    db.execute_mutate(label).unwrap();
+    db.execute_mutate(MutateOp::UpdateIssueStatus {
+        id: "prj-a1".to_string(),
+        status: Status::Done,
+        reason: Some("shipped".to_string()),
+        if_match: None,
+    })
+    .unwrap();
+
+    assert_eq!(actions(&db, "prj-a1"), vec![Action::Created, Action::Labeled, Action::Done]);
+    let events = db.core.get_events("prj-a1").unwrap();
+    assert_eq!(events[2].reason.as_deref(), Some("shipped"));
+}
+
+#[test]
+fn adding_a_label_twice_logs_it_once() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+    for _ in 0..2 {
+        let label = MutateOp::AddLabel {
+            id: "prj-a1".to_string(),
+            label: "ui".to_string(),
+            if_match: None,
+        };
+        db.execute_mutate(label).unwrap();
+    }
+
+    assert_eq!(actions(&db, "prj-a1"), vec![Action::Created, Action::Labeled]);
+}
+
+#[test]
+fn removed_dependency_logs_its_target_as_new_value() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+    create(&mut db, "prj-b2").unwrap();
+    let dep = DependencyRef {
+        from_id: "prj-a1".to_string(),
+        to_id: "prj-b2".to_string(),
+        relation: Relation::Blocks,
+    };
+    db.execute_mutate(MutateOp::AddDependency(dep.clone())).unwrap();
+    db.execute_mutate(MutateOp::RemoveDependency(dep)).unwrap();
+
+    let events = db.core.get_events("prj-a1").unwrap();
+    let removed = events.iter().find(|e| e.action == Action::Unrelated).unwrap();
+    assert_eq!(removed.old_value, None);
+    assert_eq!(removed.new_value.as_deref(), Some("blocks prj-b2"));
+}
+
+#[test]
+fn failed_mutation_logs_no_event() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+
+    let retitle = MutateOp::UpdateIssueTitle {
+        id: "prj-a1".to_string(),
+        title: "Second".to_string(),
+        if_match: Some(7),
+    };
+    db.execute_mutate(retitle).unwrap_err();
+
+    assert_eq!(actions(&db, "prj-a1"), vec![Action::Created]);
+    assert_eq!(db.core.get_issue("prj-a1").unwrap().title, "Title");
+}
+
+#[test]
+fn duplicate_issue_is_a_conflict() {
+    let (_dir, mut db) = open();
+    create(&mut db, "prj-a1").unwrap();
+
+    let err = create(&mut db, "prj-a1").unwrap_err();
+
+    assert_eq!(error_code(&err), Some(DaemonDbError::Conflict));
+}
+
+#[test]
+fn sqlite_failures_are_classified_by_code() {
+    let cases = [
+        (ffi::SQLITE_BUSY, Some(DaemonDbError::Busy)),
+        (ffi::SQLITE_LOCKED, Some(DaemonDbError::Busy)),
+        (ffi::SQLITE_CORRUPT, Some(DaemonDbError::Corrupt)),
+        (ffi::SQLITE_NOTADB, Some(DaemonDbError::Corrupt)),
+        (ffi::SQLITE_CONSTRAINT_UNIQUE, Some(DaemonDbError::Conflict)),
+        (ffi::SQLITE_CONSTRAINT_NOTNULL, Some(DaemonDbError::Constraint)),
+        (ffi::SQLITE_FULL, None),
+    ];
+    for (code, expected) in cases {
+        assert_eq!(error_code(&sqlite_error(code)), expected, "sqlite code {code}");
+    }
+}
+
+#[test]
+fn io_errors_have_no_code() {
+    let err = Error::Io(std::io::Error::other("disk gone"));
+    assert_eq!(error_code(&err), None);
+}
@@ -29,6 +29,13 @@ pub fn xdg_state_home() -> Option<PathBuf> {
     std::env::var(names::XDG_STATE_HOME).ok().map(PathBuf::from)
 }
 
+/// Returns the value of `XDG_CONFIG_HOME` as a `PathBuf`, if set.
+///
+/// The user config the link refresher reads is `$XDG_CONFIG_HOME/wok/config.toml`.
+pub fn xdg_config_home() -> Option<PathBuf> {
+    std::env::var(names::XDG_CONFIG_HOME).ok().map(PathBuf::from)
+}
+
 #[cfg(test)]
 #[path = "env_tests.rs"]
 mod tests;
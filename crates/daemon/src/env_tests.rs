@@ -11,6 +11,7 @@ use std::path::PathBuf;
 fn constants_match_env_var_names() {
     assert_eq!(names::WOK_STATE_DIR, "WOK_STATE_DIR");
     assert_eq!(names::XDG_STATE_HOME, "XDG_STATE_HOME");
+    assert_eq!(names::XDG_CONFIG_HOME, "XDG_CONFIG_HOME");
     assert_eq!(names::RUST_LOG, "RUST_LOG");
 }
 
@@ -38,6 +39,12 @@ fn xdg_state_home_returns_none_when_unset() {
     assert_eq!(xdg_state_home(), None);
 }
 
+#[test]
+fn xdg_config_home_returns_path_when_set() {
+    let _guard = EnvGuard::set(names::XDG_CONFIG_HOME, "/custom/config");
+    assert_eq!(xdg_config_home(), Some(PathBuf::from("/custom/config")));
+}
+
 /// RAII guard that sets/removes an env var and restores it on drop.
 struct EnvGuard {
     key: &'static str,
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Background execution of issue hooks.
+//!
+//! CLI processes hand prepared [`HookJob`]s to the daemon instead of waiting
+//! for them. A fixed pool of worker threads runs the jobs, so at most
+//! [`HOOK_WORKERS`] hooks run at once, and each worker records results in
+//! `hook_runs` through its own database connection.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::JoinHandle;
+
+use crate::ipc::HookJob;
+
+/// Maximum number of hooks running at the same time.
+pub const HOOK_WORKERS: usize = 4;
+
+/// Queue of hook jobs served by a worker pool.
+pub struct HookQueue {
+    sender: mpsc::Sender<HookJob>,
+    workers: Vec<JoinHandle<()>>,
+    stopping: Arc<AtomicBool>,
+}
+
+impl HookQueue {
+    /// Start `workers` threads recording runs in the database at `db_path`.
+    pub fn start(db_path: &Path, workers: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let stopping = Arc::new(AtomicBool::new(false));
+
+        let workers = (0..workers.max(1))
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                let stopping = Arc::clone(&stopping);
+                let db_path = db_path.to_path_buf();
+                std::thread::spawn(move || worker(&db_path, &receiver, &stopping))
+            })
+            .collect();
+
+        HookQueue { sender, workers, stopping }
+    }
+
+    /// Add a job to the queue.
+    pub fn enqueue(&self, job: HookJob) -> Result<(), String> {
+        self.sender.send(job).map_err(|_| "hook queue is closed".to_string())
+    }
+
+    /// Stop the workers, waiting for running hooks to finish.
+    ///
+    /// Jobs that have not started are discarded.
+    pub fn shutdown(self) {
+        self.stopping.store(true, Ordering::SeqCst);
+        drop(self.sender);
+        for handle in self.workers {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn worker(db_path: &Path, receiver: &Mutex<mpsc::Receiver<HookJob>>, stopping: &AtomicBool) {
+    let db = match wk_core::Database::open(db_path) {
+        Ok(db) => Some(db),
+        Err(e) => {
+            tracing::error!("hook worker cannot open database, runs will not be recorded: {}", e);
+            None
+        }
+    };
+
+    loop {
+        // Hold the lock only while waiting, so other workers can run jobs
+        let job = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(job) = job else {
+            return;
+        };
+
+        if stopping.load(Ordering::SeqCst) {
+            tracing::warn!(
+                "discarding hook '{}' for {}: shutting down",
+                job.hook_name,
+                job.issue_id
+            );
+            continue;
+        }
+
+        let run = job.run();
+        match run.failure_reason() {
+            Some(reason) => {
+                tracing::warn!("hook '{}' for {} failed: {}", run.hook_name, run.issue_id, reason)
+            }
+            None => tracing::info!(
+                "hook '{}' for {} finished in {}ms",
+                run.hook_name,
+                run.issue_id,
+                run.duration_ms
+            ),
+        }
+        if let Some(db) = &db {
+            if let Err(e) = db.log_hook_run(&run) {
+                tracing::error!("failed to record hook run: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "hooks_tests.rs"]
+mod tests;
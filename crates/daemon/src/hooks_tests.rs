@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use std::time::{Duration, Instant};
+
+use super::*;
+
+fn job(name: &str, script: &str) -> HookJob {
+    HookJob {
+        hook_name: name.to_string(),
+        event: "issue.done".to_string(),
+        issue_id: "prj-1".to_string(),
+        program: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        env: Vec::new(),
+        cwd: std::env::temp_dir(),
+        stdin: "{}".to_string(),
+        timeout_secs: 5,
+    }
+}
+
+/// Poll the database until `count` runs are recorded.
+fn wait_for_runs(db: &wk_core::Database, count: usize) -> Vec<wk_core::HookRun> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let runs = db.get_hook_runs(100, false).unwrap();
+        if runs.len() >= count || Instant::now() > deadline {
+            return runs;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn queued_jobs_run_and_are_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let db = wk_core::Database::open(&db_path).unwrap();
+    let queue = HookQueue::start(&db_path, 2);
+
+    queue.enqueue(job("ok", "true")).unwrap();
+    queue.enqueue(job("fails", "exit 2")).unwrap();
+
+    let runs = wait_for_runs(&db, 2);
+    queue.shutdown();
+
+    assert_eq!(runs.len(), 2);
+    let failed = runs.iter().find(|r| r.hook_name == "fails").unwrap();
+    assert_eq!(failed.exit_code, Some(2));
+    assert!(runs.iter().any(|r| r.hook_name == "ok" && r.succeeded()));
+}
+
+#[test]
+fn workers_limit_concurrency() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    let db = wk_core::Database::open(&db_path).unwrap();
+    let marker = dir.path().join("running");
+    // Each job fails if another job is running at the same time
+    let script =
+        format!("test -e {m} && exit 1; touch {m}; sleep 0.1; rm {m}", m = marker.display());
+    let queue = HookQueue::start(&db_path, 1);
+
+    for _ in 0..3 {
+        queue.enqueue(job("serial", &script)).unwrap();
+    }
+
+    let runs = wait_for_runs(&db, 3);
+    queue.shutdown();
+
+    assert_eq!(runs.len(), 3);
+    assert!(runs.iter().all(|r| r.succeeded()));
+}
@@ -15,12 +15,18 @@ use std::os::unix::net::UnixListener;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+mod aging;
 mod db;
 mod env;
+mod hooks;
 mod ipc;
+mod refresh;
 
+use aging::StaleWatcher;
 use db::Database;
+use hooks::{HookQueue, HOOK_WORKERS};
 use ipc::{framing, DaemonRequest, DaemonResponse, DaemonStatus};
+use refresh::Refresher;
 
 /// Socket filename within daemon directory.
 const SOCKET_NAME: &str = "daemon.sock";
@@ -50,8 +56,14 @@ fn main() {
         }
     };
 
-    // Write PID file
+    // Holding the lock, anything the last daemon left behind is stale
     let pid_path = state_dir.join(PID_NAME);
+    let socket_path = state_dir.join(SOCKET_NAME);
+    if let Some(reason) = ipc::stale::stale_reason(&pid_path, &socket_path) {
+        tracing::warn!("taking over after an unclean shutdown: {}", reason);
+    }
+
+    // Write PID file
     if let Err(e) = write_pid_file(&pid_path) {
         tracing::error!("failed to write PID file: {}", e);
         std::process::exit(1);
@@ -63,15 +75,17 @@ fn main() {
         Ok(db) => db,
         Err(e) => {
             tracing::error!("failed to open database: {}", e);
-            cleanup(&pid_path, &state_dir.join(SOCKET_NAME));
+            cleanup(&pid_path, &socket_path);
             std::process::exit(1);
         }
     };
     tracing::info!("database opened at {}", db_path.display());
 
-    // Bind Unix socket
-    let socket_path = state_dir.join(SOCKET_NAME);
-    // Remove stale socket if it exists
+    let hook_queue = HookQueue::start(&db_path, HOOK_WORKERS);
+    let refresher = Refresher::start(&db_path, refresh::user_config_path());
+    let stale_watcher = StaleWatcher::start(aging::wok_binary());
+
+    // Bind Unix socket, replacing a stale one
     let _ = fs::remove_file(&socket_path);
 
     let listener = match UnixListener::bind(&socket_path) {
@@ -99,11 +113,18 @@ fn main() {
                 let _ = stream.set_read_timeout(Some(std::time::Duration::from_secs(5)));
                 let _ = stream.set_write_timeout(Some(std::time::Duration::from_secs(5)));
 
-                match framing::read_message::<_, DaemonRequest>(&mut stream) {
-                    Ok(request) => {
-                        let response = handle_request(request, &start_time, &mut db);
+                match framing::read_message_encoded::<_, DaemonRequest>(&mut stream) {
+                    Ok((request, encoding)) => {
+                        let response = handle_request(
+                            request,
+                            &start_time,
+                            &mut db,
+                            &hook_queue,
+                            &stale_watcher,
+                        );
                         let should_shutdown = matches!(response, DaemonResponse::ShuttingDown);
-                        let _ = framing::write_message(&mut stream, &response);
+                        // Answer in the encoding the client wrote
+                        let _ = framing::write_message_as(&mut stream, &response, encoding);
                         if should_shutdown {
                             tracing::info!("shutting down");
                             break;
@@ -121,6 +142,9 @@ fn main() {
     }
 
     // Cleanup
+    hook_queue.shutdown();
+    refresher.shutdown();
+    stale_watcher.shutdown();
     cleanup(&pid_path, &socket_path);
     drop(lock_file);
     tracing::info!("wokd stopped");
@@ -130,6 +154,8 @@ fn handle_request(
     request: DaemonRequest,
     start_time: &Instant,
     db: &mut Database,
+    hook_queue: &HookQueue,
+    stale_watcher: &StaleWatcher,
 ) -> DaemonResponse {
     match request {
         DaemonRequest::Ping => DaemonResponse::Pong,
@@ -139,20 +165,33 @@ fn handle_request(
             DaemonResponse::Status(DaemonStatus::new(pid, uptime_secs))
         }
         DaemonRequest::Shutdown => DaemonResponse::ShuttingDown,
-        DaemonRequest::Hello { version: _ } => {
-            DaemonResponse::Hello { version: env!("CARGO_PKG_VERSION").to_string() }
-        }
+        DaemonRequest::Hello { .. } => DaemonResponse::Hello {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            encodings: framing::Encoding::ALL.to_vec(),
+        },
         DaemonRequest::Query(op) => match db.execute_query(op) {
             Ok(result) => DaemonResponse::QueryResult(result),
-            Err(e) => DaemonResponse::Error { message: e },
+            Err(e) => db_error(&e),
         },
         DaemonRequest::Mutate(op) => match db.execute_mutate(op) {
             Ok(result) => DaemonResponse::MutateResult(result),
-            Err(e) => DaemonResponse::Error { message: e },
+            Err(e) => db_error(&e),
+        },
+        DaemonRequest::EnqueueHook { job } => match hook_queue.enqueue(job) {
+            Ok(()) => DaemonResponse::HookQueued,
+            Err(message) => DaemonResponse::Error { message, code: None },
         },
+        DaemonRequest::WatchStale { work_dir } => {
+            stale_watcher.watch(work_dir);
+            DaemonResponse::WatchingStale
+        }
     }
 }
 
+fn db_error(err: &wk_core::Error) -> DaemonResponse {
+    DaemonResponse::Error { message: err.to_string(), code: db::error_code(err) }
+}
+
 fn parse_state_dir(args: &[String]) -> PathBuf {
     for i in 0..args.len() {
         if args[i] == "--state-dir" {
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Background refresh of external link metadata.
+//!
+//! When the user config has an `[api.github]`, `[api.gitlab]` or
+//! `[api.jira]` table, a thread re-fetches the titles and states of linked
+//! issues once they are `api.refresh_minutes` old (60 by default), at most
+//! [`REFRESH_BATCH`] per pass. The config is read before every pass, so
+//! edits apply without restarting the daemon.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, TryRecvError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use wk_core::link_meta;
+use wk_core::ApiConfig;
+
+/// Time between passes.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Most links refreshed in one pass.
+pub const REFRESH_BATCH: usize = 20;
+
+/// The thread refreshing link metadata.
+pub struct Refresher {
+    stop: mpsc::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl Refresher {
+    /// Start refreshing links in the database at `db_path`, as the user
+    /// config at `config_path` allows.
+    pub fn start(db_path: &Path, config_path: Option<PathBuf>) -> Self {
+        let (stop, stopped) = mpsc::channel();
+        let db_path = db_path.to_path_buf();
+        let handle = std::thread::spawn(move || run(&db_path, config_path.as_deref(), &stopped));
+        Refresher { stop, handle }
+    }
+
+    /// Stop the thread, waiting for a request in flight to finish.
+    pub fn shutdown(self) {
+        drop(self.stop);
+        let _ = self.handle.join();
+    }
+}
+
+fn run(db_path: &Path, config_path: Option<&Path>, stopped: &mpsc::Receiver<()>) {
+    let db = match wk_core::Database::open(db_path) {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::error!("link refresher cannot open database, links will not refresh: {}", e);
+            return;
+        }
+    };
+
+    loop {
+        if let Some(api) = config_path.and_then(load_api) {
+            refresh_pass(&db, &api, stopped);
+        }
+        match stopped.recv_timeout(REFRESH_INTERVAL) {
+            Err(RecvTimeoutError::Timeout) => continue,
+            _ => return,
+        }
+    }
+}
+
+/// The `[api]` table of the user config at `path`; `None` when the file is
+/// missing or unreadable.
+pub(crate) fn load_api(path: &Path) -> Option<ApiConfig> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let parsed =
+        toml::from_str::<toml::Table>(&text).and_then(|mut table| match table.remove("api") {
+            Some(api) => api.try_into(),
+            None => Ok(ApiConfig::default()),
+        });
+    match parsed {
+        Ok(api) => Some(api),
+        Err(e) => {
+            tracing::warn!("cannot read [api] from {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Refresh the stalest links, returning how many refreshed. Stops early
+/// when the daemon is shutting down.
+pub(crate) fn refresh_pass(
+    db: &wk_core::Database,
+    api: &ApiConfig,
+    stopped: &mpsc::Receiver<()>,
+) -> usize {
+    let Some(age) = api
+        .refresh_minutes()
+        .and_then(|minutes| i64::try_from(minutes).ok())
+        .and_then(TimeDelta::try_minutes)
+    else {
+        return 0;
+    };
+    let stale_before = Utc::now() - age;
+    let links = match db.links_to_refresh(stale_before, REFRESH_BATCH) {
+        Ok(links) => links,
+        Err(e) => {
+            tracing::error!("cannot list links to refresh: {}", e);
+            return 0;
+        }
+    };
+
+    let mut refreshed = 0;
+    for link in links {
+        if !matches!(stopped.try_recv(), Err(TryRecvError::Empty)) {
+            break;
+        }
+        match link_meta::refresh(db, &link, api) {
+            Ok(_) => refreshed += 1,
+            Err(e) => tracing::warn!("link refresh for {} failed: {}", link.issue_id, e),
+        }
+    }
+    if refreshed > 0 {
+        tracing::info!("refreshed {} links", refreshed);
+    }
+    refreshed
+}
+
+/// The user config file, `$XDG_CONFIG_HOME/wok/config.toml` or
+/// `~/.config/wok/config.toml`, as `wok` finds it.
+pub fn user_config_path() -> Option<PathBuf> {
+    let config_home = crate::env::xdg_config_home()
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))?;
+    Some(config_home.join("wok").join("config.toml"))
+}
+
+#[cfg(test)]
+#[path = "refresh_tests.rs"]
+mod tests;
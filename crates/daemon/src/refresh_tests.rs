@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use std::time::Instant;
+
+use wk_core::{Issue, IssueType, Link, LinkMeta, LinkType};
+
+use super::*;
+
+fn write_config(dir: &Path, text: &str) -> PathBuf {
+    let path = dir.join("config.toml");
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+#[test]
+fn load_api_reads_the_api_table() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_config(
+        dir.path(),
+        "user = \"ann\"\n\n[api]\nrefresh_minutes = 15\n\n[api.jira]\nurl = \"https://acme.atlassian.net\"\n",
+    );
+
+    let api = load_api(&path).unwrap();
+
+    assert_eq!(api.refresh_minutes(), Some(15));
+    assert_eq!(api.jira.unwrap().url.as_deref(), Some("https://acme.atlassian.net"));
+}
+
+#[test]
+fn load_api_without_providers_does_not_refresh() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_config(dir.path(), "user = \"ann\"\n");
+
+    assert_eq!(load_api(&path).unwrap().refresh_minutes(), None);
+    assert!(load_api(&dir.path().join("missing.toml")).is_none());
+}
+
+#[test]
+fn load_api_rejects_a_malformed_table() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_config(dir.path(), "[api]\nrefresh_minutes = \"soon\"\n");
+
+    assert!(load_api(&path).is_none());
+}
+
+#[test]
+fn refresh_pass_skips_links_refreshed_recently() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = wk_core::Database::open(&dir.path().join("issues.db")).unwrap();
+    let issue = Issue::new("prj-1".to_string(), IssueType::Task, "Task".to_string(), Utc::now());
+    db.create_issue(&issue).unwrap();
+    let url = "https://github.com/o/r/issues/1";
+    let link = Link::new("prj-1".to_string())
+        .with_type(LinkType::Github)
+        .with_url(url.to_string())
+        .with_external_id("1".to_string());
+    db.add_link(&link).unwrap();
+    let meta = LinkMeta {
+        title: Some("Crash".to_string()),
+        state: Some("open".to_string()),
+        error: None,
+        refreshed_at: Utc::now(),
+    };
+    db.set_link_meta(url, &meta).unwrap();
+    let api = ApiConfig { github: Some(Default::default()), ..Default::default() };
+    let (_stop, stopped) = mpsc::channel();
+
+    assert_eq!(refresh_pass(&db, &api, &stopped), 0);
+    assert_eq!(db.get_link_meta(url).unwrap(), Some(meta));
+}
+
+#[test]
+fn refresher_stops_promptly() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("issues.db");
+    wk_core::Database::open(&db_path).unwrap();
+    let refresher = Refresher::start(&db_path, None);
+
+    let started = Instant::now();
+    refresher.shutdown();
+
+    assert!(started.elapsed() < REFRESH_INTERVAL);
+}
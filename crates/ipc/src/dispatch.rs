@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Execution of protocol operations against a [`wk_core::Database`].
+//!
+//! The daemon answers [`QueryOp`] and [`MutateOp`] requests from the CLI
+//! with these, and `wok rpc` answers the same operations from editors, so
+//! both speak one set of semantics.
+
+use crate::{Action, DependencyRef, Event, MutateOp, MutateResult, QueryOp, QueryResult};
+
+/// Execute a query operation and return the result.
+pub fn query(db: &wk_core::Database, op: QueryOp) -> wk_core::Result<QueryResult> {
+    match op {
+        QueryOp::ResolveId { partial_id } => {
+            let id = db.resolve_id(&partial_id)?;
+            Ok(QueryResult::ResolvedId { id })
+        }
+        QueryOp::IssueExists { id } => {
+            let value = db.issue_exists(&id)?;
+            Ok(QueryResult::Bool { value })
+        }
+        QueryOp::GetIssue { id } => {
+            let issue = db.get_issue(&id)?;
+            Ok(QueryResult::Issue { issue: issue.into() })
+        }
+        QueryOp::ListIssues { status, issue_type, label } => {
+            let issues = db.list_issues(status, issue_type, label.as_deref())?;
+            Ok(QueryResult::Issues { issues: issues.into_iter().map(Into::into).collect() })
+        }
+        QueryOp::SearchIssues { query } => {
+            let issues = db.search_issues(&query)?;
+            Ok(QueryResult::Issues { issues: issues.into_iter().map(Into::into).collect() })
+        }
+        QueryOp::GetBlockedIssueIds => {
+            let ids = db.get_blocked_issue_ids()?;
+            Ok(QueryResult::Ids { ids })
+        }
+        QueryOp::GetLabels { id } => {
+            let labels = db.get_labels(&id)?;
+            Ok(QueryResult::Labels { labels })
+        }
+        QueryOp::GetLabelsBatch { ids } => {
+            let refs: Vec<&str> = ids.iter().map(|s| s.as_str()).collect();
+            let labels = db.get_labels_batch(&refs)?;
+            Ok(QueryResult::LabelsBatch { labels })
+        }
+        QueryOp::GetNotes { id } => {
+            let notes = db.get_notes(&id)?;
+            Ok(QueryResult::Notes { notes })
+        }
+        QueryOp::GetEvents { id } => {
+            let events = db.get_events(&id)?;
+            Ok(QueryResult::Events { events })
+        }
+        QueryOp::GetAllEvents { limit } => {
+            let events = db.get_recent_events(limit.unwrap_or(usize::MAX))?;
+            Ok(QueryResult::Events { events })
+        }
+        QueryOp::GetDepsFrom { id } => {
+            let deps = db.get_deps_from(&id)?;
+            Ok(QueryResult::Dependencies { deps })
+        }
+        QueryOp::GetBlockers { id } => {
+            let ids = db.get_blockers(&id)?;
+            Ok(QueryResult::Ids { ids })
+        }
+        QueryOp::GetBlocking { id } => {
+            let ids = db.get_blocking(&id)?;
+            Ok(QueryResult::Ids { ids })
+        }
+        QueryOp::GetTracked { id } => {
+            let ids = db.get_tracked(&id)?;
+            Ok(QueryResult::Ids { ids })
+        }
+        QueryOp::GetTracking { id } => {
+            let ids = db.get_tracking(&id)?;
+            Ok(QueryResult::Ids { ids })
+        }
+        QueryOp::GetTransitiveBlockers { id } => {
+            let ids = db.get_transitive_blockers(&id)?;
+            Ok(QueryResult::Ids { ids })
+        }
+        QueryOp::GetLinks { id } => {
+            let links = db.get_links(&id)?;
+            Ok(QueryResult::Links { links })
+        }
+        QueryOp::GetLinkByUrl { id, url } => {
+            let link = db.get_link_by_url(&id, &url)?;
+            Ok(QueryResult::Link { link })
+        }
+        QueryOp::ListPrefixes => {
+            let prefixes = db.list_prefixes()?;
+            Ok(QueryResult::Prefixes { prefixes })
+        }
+    }
+}
+
+/// Execute a mutation operation and return the result.
+pub fn mutate(db: &wk_core::Database, op: MutateOp) -> wk_core::Result<MutateResult> {
+    record(db, op).map(|(result, _)| result)
+}
+
+/// Execute a mutation operation, returning its result and the event it
+/// logged, if it changed anything.
+///
+/// The change and its event are stored in one transaction, so clients never
+/// follow an op with a [`MutateOp::LogEvent`] of their own.
+pub fn record(
+    db: &wk_core::Database,
+    op: MutateOp,
+) -> wk_core::Result<(MutateResult, Option<Event>)> {
+    // The whole op is retried if the database is busy, so each try gets a copy.
+    let result = db.atomically(|| apply(db, op.clone()));
+    // An op that changed nothing leaves its expectation unused; it must
+    // not carry over to the next op on a shared connection.
+    db.clear_expected_revision();
+    result
+}
+
+/// Require issue `id` to be at `revision`, when the op gives one.
+fn expect(db: &wk_core::Database, id: &str, revision: Option<u32>) -> wk_core::Result<()> {
+    match revision {
+        Some(revision) => db.expect_revision(id, revision),
+        None => Ok(()),
+    }
+}
+
+/// Log `event` for the change just made.
+fn logged(db: &wk_core::Database, event: Event) -> wk_core::Result<(MutateResult, Option<Event>)> {
+    db.log_event(&event)?;
+    Ok((MutateResult::Ok, Some(event)))
+}
+
+/// Log an `Edited` event from `old` to `new`, unless they are the same.
+fn edited(
+    db: &wk_core::Database,
+    id: String,
+    old: Option<String>,
+    new: String,
+) -> wk_core::Result<(MutateResult, Option<Event>)> {
+    if old.as_deref() == Some(new.as_str()) {
+        return Ok((MutateResult::Ok, None));
+    }
+    logged(db, Event::new(id, Action::Edited).with_values(old, Some(new)))
+}
+
+fn apply(db: &wk_core::Database, op: MutateOp) -> wk_core::Result<(MutateResult, Option<Event>)> {
+    match op {
+        MutateOp::CreateIssue { issue } => {
+            let core_issue: wk_core::Issue = issue.into();
+            db.create_issue(&core_issue)?;
+            logged(db, Event::new(core_issue.id, Action::Created))
+        }
+        MutateOp::UpdateIssueStatus { id, status, reason, if_match } => {
+            expect(db, &id, if_match)?;
+            let old = db.get_issue(&id)?.status;
+            db.update_issue_status(&id, status)?;
+            if old == status {
+                return Ok((MutateResult::Ok, None));
+            }
+            let event = Event::new(id, Action::for_status_change(old, status))
+                .with_values(Some(old.to_string()), Some(status.to_string()))
+                .with_reason(reason);
+            logged(db, event)
+        }
+        MutateOp::UpdateIssueTitle { id, title, if_match } => {
+            expect(db, &id, if_match)?;
+            let old = db.get_issue(&id)?.title;
+            db.update_issue_title(&id, &title)?;
+            edited(db, id, Some(old), title)
+        }
+        MutateOp::UpdateIssueDescription { id, description, if_match } => {
+            expect(db, &id, if_match)?;
+            let old = db.get_issue(&id)?.description;
+            db.update_issue_description(&id, &description)?;
+            edited(db, id, old, description)
+        }
+        MutateOp::UpdateIssueType { id, issue_type, if_match } => {
+            expect(db, &id, if_match)?;
+            let old = db.get_issue(&id)?.issue_type;
+            db.update_issue_type(&id, issue_type)?;
+            edited(db, id, Some(old.to_string()), issue_type.to_string())
+        }
+        MutateOp::SetAssignee { id, assignee, if_match } => {
+            expect(db, &id, if_match)?;
+            let old = db.get_issue(&id)?.assignee;
+            db.set_assignee(&id, &assignee)?;
+            if old.as_deref() == Some(assignee.as_str()) {
+                return Ok((MutateResult::Ok, None));
+            }
+            logged(db, Event::new(id, Action::Assigned).with_values(old, Some(assignee)))
+        }
+        MutateOp::ClearAssignee { id, if_match } => {
+            expect(db, &id, if_match)?;
+            let old = db.get_issue(&id)?.assignee;
+            db.clear_assignee(&id)?;
+            if old.is_none() {
+                return Ok((MutateResult::Ok, None));
+            }
+            logged(db, Event::new(id, Action::Unassigned).with_values(old, None))
+        }
+        MutateOp::AddLabel { id, label, if_match } => {
+            expect(db, &id, if_match)?;
+            if !db.add_label(&id, &label)? {
+                return Ok((MutateResult::Ok, None));
+            }
+            logged(db, Event::new(id, Action::Labeled).with_values(None, Some(label)))
+        }
+        MutateOp::RemoveLabel { id, label, if_match } => {
+            expect(db, &id, if_match)?;
+            let removed = db.remove_label(&id, &label)?;
+            let event = if removed {
+                let event = Event::new(id, Action::Unlabeled).with_values(None, Some(label));
+                db.log_event(&event)?;
+                Some(event)
+            } else {
+                None
+            };
+            Ok((MutateResult::LabelRemoved { removed }, event))
+        }
+        MutateOp::AddNote { id, status, content } => {
+            db.add_note(&id, status, &content)?;
+            logged(db, Event::new(id, Action::Noted).with_values(None, Some(content)))
+        }
+        MutateOp::EditNote { id, note_id, content } => {
+            let old = db.edit_note(&id, note_id, &content)?;
+            logged(db, Event::new(id, Action::Noted).with_values(Some(old.content), Some(content)))
+        }
+        MutateOp::DeleteNote { id, note_id } => {
+            let note = db.delete_note(&id, note_id)?;
+            logged(db, Event::new(id, Action::Unnoted).with_values(Some(note.content), None))
+        }
+        MutateOp::RestoreNote { id, note_id } => {
+            let note = db.restore_note(&id, note_id)?;
+            logged(db, Event::new(id, Action::Noted).with_values(None, Some(note.content)))
+        }
+        MutateOp::LogEvent { event } => logged(db, event),
+        MutateOp::AddDependency(DependencyRef { from_id, to_id, relation }) => {
+            db.add_dependency(&from_id, &to_id, relation)?;
+            let (action, _) = relation.actions();
+            let value = relation.event_value(&to_id);
+            logged(db, Event::new(from_id, action).with_values(None, Some(value)))
+        }
+        MutateOp::RemoveDependency(DependencyRef { from_id, to_id, relation }) => {
+            db.remove_dependency(&from_id, &to_id, relation)?;
+            let (_, action) = relation.actions();
+            let value = relation.event_value(&to_id);
+            logged(db, Event::new(from_id, action).with_values(None, Some(value)))
+        }
+        MutateOp::AddLink { id, link_type, url, external_id, rel } => {
+            let mut link = wk_core::Link::new(id.clone());
+            if let Some(lt) = link_type {
+                link = link.with_type(lt);
+            }
+            if let Some(u) = url {
+                link = link.with_url(u);
+            }
+            if let Some(eid) = external_id {
+                link = link.with_external_id(eid);
+            }
+            if let Some(r) = rel {
+                link = link.with_rel(r);
+            }
+            db.add_link(&link)?;
+            let target = link.url.or(link.external_id);
+            logged(db, Event::new(id, Action::Linked).with_values(None, target))
+        }
+        MutateOp::RemoveLink { id, url } => {
+            let Some(link) = db.get_link_by_url(&id, &url)? else {
+                return Ok((MutateResult::Ok, None));
+            };
+            db.remove_link(link.id)?;
+            logged(db, Event::new(id, Action::Unlinked).with_values(Some(url), None))
+        }
+        MutateOp::RestoreLink { id, url } => {
+            if db.restore_link(&id, &url)?.is_none() {
+                return Ok((MutateResult::Ok, None));
+            }
+            logged(db, Event::new(id, Action::Linked).with_values(None, Some(url)))
+        }
+        MutateOp::EnsurePrefix { prefix } => {
+            db.ensure_prefix(&prefix)?;
+            Ok((MutateResult::Ok, None))
+        }
+        MutateOp::IncrementPrefixCount { prefix } => {
+            db.increment_prefix_count(&prefix)?;
+            Ok((MutateResult::Ok, None))
+        }
+    }
+}
@@ -4,30 +4,36 @@
 //! Shared IPC protocol for CLI-daemon communication.
 //!
 //! This crate defines the message types and framing protocol used between
-//! the `wok` CLI and the `wokd` daemon. Messages are serialized as JSON
-//! with length-prefixed framing.
+//! the `wok` CLI and the `wokd` daemon. Messages are serialized as JSON,
+//! or as CBOR when both sides say they read it, with length-prefixed
+//! framing.
 //!
 //! Domain model types (enums, structs) are re-exported from `wk_core`.
 //! Only the IPC `Issue` struct differs from `wk_core::Issue`: it omits
 //! HLC fields used for CRDT sync and includes `closed_at` for display.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 // Re-export domain model types from core (canonical definitions).
 pub use wk_core::{
-    Action, Dependency, Event, IssueType, Link, LinkRel, LinkType, Note, PrefixInfo, Relation,
-    Status,
+    Action, Dependency, Event, HookJob, IssueType, Link, LinkRel, LinkType, Note, PrefixInfo,
+    Relation, Status,
 };
 
+pub mod dispatch;
+pub mod stale;
+
 /// IPC representation of a tracked work item.
 ///
 /// This differs from [`wk_core::Issue`] which includes HLC fields for CRDT
 /// conflict resolution. The IPC Issue omits those fields and includes
 /// `closed_at` for display purposes.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Issue {
     /// Unique identifier (format: `{prefix}-{hash}`).
     pub id: String,
@@ -50,6 +56,9 @@ pub struct Issue {
     /// When the issue was closed (done or closed status). None if not closed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed_at: Option<DateTime<Utc>>,
+    /// Bumped by every change to the issue's fields or labels.
+    #[serde(default)]
+    pub revision: u32,
 }
 
 impl From<wk_core::Issue> for Issue {
@@ -64,6 +73,7 @@ impl From<wk_core::Issue> for Issue {
             created_at: core.created_at,
             updated_at: core.updated_at,
             closed_at: core.closed_at,
+            revision: core.revision,
         }
     }
 }
@@ -85,6 +95,7 @@ impl From<Issue> for wk_core::Issue {
             last_type_hlc: None,
             last_description_hlc: None,
             last_assignee_hlc: None,
+            revision: ipc.revision,
         }
     }
 }
@@ -103,6 +114,7 @@ impl Issue {
             created_at: now,
             updated_at: now,
             closed_at: None,
+            revision: 1,
         }
     }
 }
@@ -121,16 +133,42 @@ pub enum DaemonRequest {
     Shutdown,
     /// Ping to check if daemon is alive.
     Ping,
-    /// Version handshake request.
-    Hello { version: String },
+    /// Version handshake request, with the encodings the client reads.
+    Hello {
+        version: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        encodings: Vec<framing::Encoding>,
+    },
     /// Database query operation.
     Query(QueryOp),
     /// Database mutation operation.
     Mutate(MutateOp),
+    /// Queue an issue hook to run in the background.
+    EnqueueHook { job: HookJob },
+    /// Check the project at `work_dir` for stale issues periodically, for
+    /// its `issue.stale` hooks.
+    WatchStale { work_dir: PathBuf },
+}
+
+impl DaemonRequest {
+    /// Short name of the request kind, for logs and traces.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DaemonRequest::Status => "status",
+            DaemonRequest::Shutdown => "shutdown",
+            DaemonRequest::Ping => "ping",
+            DaemonRequest::Hello { .. } => "hello",
+            DaemonRequest::Query(_) => "query",
+            DaemonRequest::Mutate(_) => "mutate",
+            DaemonRequest::EnqueueHook { .. } => "enqueue_hook",
+            DaemonRequest::WatchStale { .. } => "watch_stale",
+        }
+    }
 }
 
 /// Query operations for reading from the database.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "op")]
 pub enum QueryOp {
     /// Resolve a partial ID to a full ID.
@@ -177,6 +215,7 @@ pub enum QueryOp {
 
 /// Identifies a dependency between two issues with its relationship type.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DependencyRef {
     pub from_id: String,
     pub to_id: String,
@@ -185,29 +224,87 @@ pub struct DependencyRef {
 
 /// Mutation operations for writing to the database.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "op")]
 pub enum MutateOp {
     /// Create a new issue.
     CreateIssue { issue: Issue },
     /// Update issue status.
-    UpdateIssueStatus { id: String, status: Status },
+    UpdateIssueStatus {
+        id: String,
+        status: Status,
+        /// Why the status changed, recorded on its event.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Update issue title.
-    UpdateIssueTitle { id: String, title: String },
+    UpdateIssueTitle {
+        id: String,
+        title: String,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Update issue description.
-    UpdateIssueDescription { id: String, description: String },
+    UpdateIssueDescription {
+        id: String,
+        description: String,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Update issue type.
-    UpdateIssueType { id: String, issue_type: IssueType },
+    UpdateIssueType {
+        id: String,
+        issue_type: IssueType,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Set issue assignee.
-    SetAssignee { id: String, assignee: String },
+    SetAssignee {
+        id: String,
+        assignee: String,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Clear issue assignee.
-    ClearAssignee { id: String },
+    ClearAssignee {
+        id: String,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Add a label to an issue.
-    AddLabel { id: String, label: String },
+    AddLabel {
+        id: String,
+        label: String,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Remove a label from an issue.
-    RemoveLabel { id: String, label: String },
+    RemoveLabel {
+        id: String,
+        label: String,
+        /// Fail with a conflict unless the issue is at this revision.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        if_match: Option<u32>,
+    },
     /// Add a note to an issue.
     AddNote { id: String, status: Status, content: String },
-    /// Log an event.
+    /// Change the content of one of an issue's notes.
+    EditNote { id: String, note_id: i64, content: String },
+    /// Delete one of an issue's notes, keeping a tombstone.
+    DeleteNote { id: String, note_id: i64 },
+    /// Bring back one of an issue's deleted notes.
+    RestoreNote { id: String, note_id: i64 },
+    /// Log an event that goes with no other op, such as `unblocked`. The
+    /// other ops log their own events.
     LogEvent { event: Event },
     /// Add a dependency.
     AddDependency(DependencyRef),
@@ -223,6 +320,8 @@ pub enum MutateOp {
     },
     /// Remove a link from an issue.
     RemoveLink { id: String, url: String },
+    /// Bring back the link to a URL most recently removed from an issue.
+    RestoreLink { id: String, url: String },
     /// Ensure a prefix exists.
     EnsurePrefix { prefix: String },
     /// Increment prefix issue count.
@@ -239,18 +338,71 @@ pub enum DaemonResponse {
     ShuttingDown,
     /// Pong response.
     Pong,
-    /// Error response.
-    Error { message: String },
-    /// Version handshake response.
-    Hello { version: String },
+    /// Error response. `code` says what kind of database failure it was,
+    /// and is absent for errors outside the database.
+    Error {
+        message: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<DaemonDbError>,
+    },
+    /// Version handshake response, with the encodings the daemon reads.
+    /// Daemons that predate CBOR send none: they read only JSON.
+    Hello {
+        version: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        encodings: Vec<framing::Encoding>,
+    },
     /// Query result.
     QueryResult(QueryResult),
     /// Mutation acknowledgment.
     MutateResult(MutateResult),
+    /// Hook job accepted; it runs after the response is sent.
+    HookQueued,
+    /// The project is checked for stale issues from now on.
+    WatchingStale,
+}
+
+/// Category of a failed database operation, sent with
+/// [`DaemonResponse::Error`] so clients can react to the kind of failure
+/// rather than its message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DaemonDbError {
+    /// The issue, dependency or note does not exist.
+    NotFound,
+    /// The change clashes with existing data: a cycle, a duplicate, an
+    /// ambiguous ID or a disallowed status transition.
+    Conflict,
+    /// The request has an invalid value or breaks a schema constraint.
+    Constraint,
+    /// Another connection holds the database lock.
+    Busy,
+    /// The database or a stored value is damaged or from a newer version.
+    Corrupt,
+}
+
+impl DaemonDbError {
+    /// Returns the string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DaemonDbError::NotFound => "not_found",
+            DaemonDbError::Conflict => "conflict",
+            DaemonDbError::Constraint => "constraint",
+            DaemonDbError::Busy => "busy",
+            DaemonDbError::Corrupt => "corrupt",
+        }
+    }
+}
+
+impl std::fmt::Display for DaemonDbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Results from query operations.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "result")]
 pub enum QueryResult {
     /// Resolved ID.
@@ -283,6 +435,7 @@ pub enum QueryResult {
 
 /// Results from mutation operations.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "result")]
 pub enum MutateResult {
     /// Mutation succeeded.
@@ -310,50 +463,173 @@ impl DaemonStatus {
 /// IPC message framing.
 ///
 /// Messages are framed as:
-/// - 4 bytes: message length (big-endian u32)
-/// - N bytes: JSON-encoded message
+/// - 4 bytes: message length (big-endian u32); the top bit is set when
+///   the body is CBOR rather than JSON
+/// - N bytes: the encoded message
+///
+/// Readers accept either encoding, so a side that writes CBOR only needs
+/// to know the other side reads it; the daemon answers in the encoding
+/// of the request.
 pub mod framing {
-    use std::io::{Read, Write};
+    use std::io::{self, Read, Write};
 
     use serde::de::DeserializeOwned;
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
+    use thiserror::Error;
 
     /// Maximum message size (1MB) to prevent malformed messages from causing hangs.
-    const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+    pub const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+
+    /// Size of the big-endian length prefix before each message.
+    const PREFIX_SIZE: usize = 4;
+
+    /// Length prefix bit marking a CBOR body.
+    const CBOR_FLAG: u32 = 1 << 31;
+
+    /// How a message body is serialized.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Encoding {
+        /// JSON text; every version reads it.
+        #[default]
+        Json,
+        /// CBOR (RFC 8949), smaller and faster to parse for bulk messages.
+        Cbor,
+    }
+
+    impl Encoding {
+        /// Every encoding this build reads.
+        pub const ALL: [Encoding; 2] = [Encoding::Json, Encoding::Cbor];
+
+        /// The best encoding both this build and a peer that reads
+        /// `theirs` understand.
+        pub fn negotiate(theirs: &[Encoding]) -> Encoding {
+            if theirs.contains(&Encoding::Cbor) {
+                Encoding::Cbor
+            } else {
+                Encoding::Json
+            }
+        }
+    }
+
+    /// Why a frame could not be written or read.
+    #[derive(Debug, Error)]
+    pub enum FrameError {
+        /// The stream closed before the first byte of a frame.
+        #[error("connection closed")]
+        Closed,
+
+        /// The stream closed partway through a frame.
+        #[error("truncated frame: expected {expected} bytes, got {got}")]
+        Truncated { expected: usize, got: usize },
+
+        /// The length prefix exceeds [`MAX_MESSAGE_SIZE`].
+        #[error("message too large: {len} bytes (max {max})")]
+        TooLarge { len: usize, max: usize },
+
+        /// The message could not be encoded as JSON.
+        #[error("serialize error: {0}")]
+        Serialize(#[source] serde_json::Error),
+
+        /// The frame body is not a valid message.
+        #[error("deserialize error: {0}")]
+        Deserialize(#[source] serde_json::Error),
 
-    /// Write a serializable message to the given writer.
+        /// The message could not be encoded as CBOR.
+        #[error("serialize error: {0}")]
+        SerializeCbor(#[source] ciborium::ser::Error<io::Error>),
+
+        /// The CBOR frame body is not a valid message.
+        #[error("deserialize error: {0}")]
+        DeserializeCbor(#[source] ciborium::de::Error<io::Error>),
+
+        #[error(transparent)]
+        Io(#[from] io::Error),
+    }
+
+    /// Write a serializable message to the given writer as JSON.
     pub fn write_message<W: Write, T: Serialize>(
         writer: &mut W,
         message: &T,
-    ) -> std::io::Result<()> {
-        let json = serde_json::to_vec(message)
-            .map_err(|e| std::io::Error::other(format!("serialize error: {}", e)))?;
-        let len =
-            u32::try_from(json.len()).map_err(|_| std::io::Error::other("message too large"))?;
-        writer.write_all(&len.to_be_bytes())?;
-        writer.write_all(&json)?;
+    ) -> Result<(), FrameError> {
+        write_message_as(writer, message, Encoding::Json)
+    }
+
+    /// Write a serializable message to the given writer in `encoding`.
+    pub fn write_message_as<W: Write, T: Serialize>(
+        writer: &mut W,
+        message: &T,
+        encoding: Encoding,
+    ) -> Result<(), FrameError> {
+        let (body, flag) = match encoding {
+            Encoding::Json => (serde_json::to_vec(message).map_err(FrameError::Serialize)?, 0),
+            Encoding::Cbor => {
+                let mut body = Vec::new();
+                ciborium::into_writer(message, &mut body).map_err(FrameError::SerializeCbor)?;
+                (body, CBOR_FLAG)
+            }
+        };
+        if body.len() > MAX_MESSAGE_SIZE {
+            return Err(FrameError::TooLarge { len: body.len(), max: MAX_MESSAGE_SIZE });
+        }
+        let len = u32::try_from(body.len())
+            .map_err(|_| FrameError::TooLarge { len: body.len(), max: MAX_MESSAGE_SIZE })?;
+        writer.write_all(&(len | flag).to_be_bytes())?;
+        writer.write_all(&body)?;
         writer.flush()?;
         Ok(())
     }
 
     /// Read a deserializable message from the given reader.
-    pub fn read_message<R: Read, T: DeserializeOwned>(reader: &mut R) -> std::io::Result<T> {
-        let mut len_buf = [0u8; 4];
-        reader.read_exact(&mut len_buf)?;
-        let len = u32::from_be_bytes(len_buf) as usize;
+    pub fn read_message<R: Read, T: DeserializeOwned>(reader: &mut R) -> Result<T, FrameError> {
+        read_message_encoded(reader).map(|(message, _)| message)
+    }
 
+    /// Read a deserializable message and the encoding it was sent in.
+    pub fn read_message_encoded<R: Read, T: DeserializeOwned>(
+        reader: &mut R,
+    ) -> Result<(T, Encoding), FrameError> {
+        let mut len_buf = [0u8; PREFIX_SIZE];
+        match read_full(reader, &mut len_buf)? {
+            0 => return Err(FrameError::Closed),
+            PREFIX_SIZE => {}
+            got => return Err(FrameError::Truncated { expected: PREFIX_SIZE, got }),
+        }
+        let prefix = u32::from_be_bytes(len_buf);
+        let encoding = if prefix & CBOR_FLAG == 0 { Encoding::Json } else { Encoding::Cbor };
+        let len = usize::try_from(prefix & !CBOR_FLAG).unwrap_or(usize::MAX);
         if len > MAX_MESSAGE_SIZE {
-            return Err(std::io::Error::other(format!(
-                "message too large: {} bytes (max {})",
-                len, MAX_MESSAGE_SIZE
-            )));
+            return Err(FrameError::TooLarge { len, max: MAX_MESSAGE_SIZE });
         }
 
         let mut buf = vec![0u8; len];
-        reader.read_exact(&mut buf)?;
+        let got = read_full(reader, &mut buf)?;
+        if got < len {
+            return Err(FrameError::Truncated { expected: len, got });
+        }
 
-        serde_json::from_slice(&buf)
-            .map_err(|e| std::io::Error::other(format!("deserialize error: {}", e)))
+        let message = match encoding {
+            Encoding::Json => serde_json::from_slice(&buf).map_err(FrameError::Deserialize)?,
+            Encoding::Cbor => {
+                ciborium::from_reader(buf.as_slice()).map_err(FrameError::DeserializeCbor)?
+            }
+        };
+        Ok((message, encoding))
+    }
+
+    /// Fill `buf` from `reader`, stopping early only at end of stream.
+    /// Returns the number of bytes read.
+    fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(filled)
     }
 }
 
@@ -12,11 +12,32 @@ use chrono::Utc;
 use super::*;
 use yare::parameterized;
 
+fn hook_job() -> HookJob {
+    HookJob {
+        hook_name: "notify".to_string(),
+        event: "issue.done".to_string(),
+        issue_id: "prj-1".to_string(),
+        program: "sh".to_string(),
+        args: vec!["-c".to_string(), "./notify.sh".to_string()],
+        env: vec![("WOK_EVENT".to_string(), "issue.done".to_string())],
+        cwd: std::path::PathBuf::from("/tmp/project"),
+        stdin: "{}".to_string(),
+        timeout_secs: 10,
+    }
+}
+
 #[parameterized(
     status = { DaemonRequest::Status },
     shutdown = { DaemonRequest::Shutdown },
     ping = { DaemonRequest::Ping },
-    hello = { DaemonRequest::Hello { version: "0.1.0".to_string() } },
+    hello = { DaemonRequest::Hello { version: "0.1.0".to_string(), encodings: vec![] } },
+    enqueue_hook = { DaemonRequest::EnqueueHook { job: hook_job() } },
+    watch_stale = { DaemonRequest::WatchStale { work_dir: std::path::PathBuf::from("/tmp/project") } },
+    add_duplicate_of = { DaemonRequest::Mutate(MutateOp::AddDependency(DependencyRef {
+        from_id: "prj-2".to_string(),
+        to_id: "prj-1".to_string(),
+        relation: Relation::DuplicateOf,
+    })) },
 )]
 fn daemon_request_serialization(request: DaemonRequest) {
     let json = serde_json::to_string(&request).unwrap();
@@ -24,12 +45,64 @@ fn daemon_request_serialization(request: DaemonRequest) {
     assert_eq!(request, parsed);
 }
 
+#[test]
+fn framing_empty_stream_is_closed() {
+    let err = framing::read_message::<_, DaemonRequest>(&mut Cursor::new(Vec::new())).unwrap_err();
+    assert!(matches!(err, framing::FrameError::Closed));
+}
+
+#[test]
+fn framing_rejects_truncated_prefix() {
+    let err = framing::read_message::<_, DaemonRequest>(&mut Cursor::new(vec![0, 0])).unwrap_err();
+    assert!(matches!(err, framing::FrameError::Truncated { expected: 4, got: 2 }));
+}
+
+#[test]
+fn framing_rejects_truncated_body() {
+    let mut buf = Vec::new();
+    framing::write_message(&mut buf, &DaemonRequest::Ping).unwrap();
+    buf.truncate(buf.len() - 1);
+
+    let err = framing::read_message::<_, DaemonRequest>(&mut Cursor::new(buf)).unwrap_err();
+    assert!(matches!(err, framing::FrameError::Truncated { .. }));
+}
+
+#[test]
+fn framing_rejects_oversized_prefix() {
+    let buf = u32::MAX.to_be_bytes().to_vec();
+    let err = framing::read_message::<_, DaemonRequest>(&mut Cursor::new(buf)).unwrap_err();
+    assert!(matches!(err, framing::FrameError::TooLarge { max: framing::MAX_MESSAGE_SIZE, .. }));
+}
+
+#[test]
+fn framing_refuses_to_write_oversized_message() {
+    let response =
+        DaemonResponse::Error { message: "x".repeat(framing::MAX_MESSAGE_SIZE), code: None };
+    let mut buf = Vec::new();
+    let err = framing::write_message(&mut buf, &response).unwrap_err();
+    assert!(matches!(err, framing::FrameError::TooLarge { .. }));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn framing_rejects_malformed_json() {
+    let body = b"{\"Ping\"";
+    let mut buf = u32::try_from(body.len()).unwrap().to_be_bytes().to_vec();
+    buf.extend_from_slice(body);
+
+    let err = framing::read_message::<_, DaemonRequest>(&mut Cursor::new(buf)).unwrap_err();
+    assert!(matches!(err, framing::FrameError::Deserialize(_)));
+}
+
 #[parameterized(
     status = { DaemonResponse::Status(DaemonStatus::new(1234, 3600)) },
     shutting_down = { DaemonResponse::ShuttingDown },
     pong = { DaemonResponse::Pong },
-    error = { DaemonResponse::Error { message: "test error".to_string() } },
-    hello = { DaemonResponse::Hello { version: "0.1.0".to_string() } },
+    error = { DaemonResponse::Error { message: "test error".to_string(), code: None } },
+    db_error = { DaemonResponse::Error { message: "issue not found: x".to_string(), code: Some(DaemonDbError::NotFound) } },
+    hello = { DaemonResponse::Hello { version: "0.1.0".to_string(), encodings: framing::Encoding::ALL.to_vec() } },
+    hook_queued = { DaemonResponse::HookQueued },
+    watching_stale = { DaemonResponse::WatchingStale },
 )]
 fn daemon_response_serialization(response: DaemonResponse) {
     let json = serde_json::to_string(&response).unwrap();
@@ -37,6 +110,37 @@ fn daemon_response_serialization(response: DaemonResponse) {
     assert_eq!(response, parsed);
 }
 
+#[test]
+fn daemon_error_code_is_snake_case_and_optional() {
+    let response =
+        DaemonResponse::Error { message: "busy".to_string(), code: Some(DaemonDbError::Busy) };
+    assert_eq!(
+        serde_json::to_string(&response).unwrap(),
+        r#"{"type":"Error","message":"busy","code":"busy"}"#
+    );
+
+    let parsed: DaemonResponse =
+        serde_json::from_str(r#"{"type":"Error","message":"old daemon"}"#).unwrap();
+    assert_eq!(parsed, DaemonResponse::Error { message: "old daemon".to_string(), code: None });
+}
+
+#[test]
+fn mutate_if_match_is_optional() {
+    let op = MutateOp::UpdateIssueStatus {
+        id: "prj-1".to_string(),
+        status: Status::Done,
+        reason: None,
+        if_match: Some(3),
+    };
+    assert_eq!(
+        serde_json::to_string(&op).unwrap(),
+        r#"{"op":"UpdateIssueStatus","id":"prj-1","status":"done","if_match":3}"#
+    );
+
+    let parsed: MutateOp = serde_json::from_str(r#"{"op":"ClearAssignee","id":"prj-1"}"#).unwrap();
+    assert_eq!(parsed, MutateOp::ClearAssignee { id: "prj-1".to_string(), if_match: None });
+}
+
 #[test]
 fn daemon_status_new() {
     let status = DaemonStatus::new(5678, 7200);
@@ -48,7 +152,7 @@ fn daemon_status_new() {
     status = { DaemonRequest::Status },
     shutdown = { DaemonRequest::Shutdown },
     ping = { DaemonRequest::Ping },
-    hello = { DaemonRequest::Hello { version: "0.1.0".to_string() } },
+    hello = { DaemonRequest::Hello { version: "0.1.0".to_string(), encodings: vec![] } },
 )]
 fn framing_roundtrip_request(request: DaemonRequest) {
     let mut buf = Vec::new();
@@ -63,8 +167,9 @@ fn framing_roundtrip_request(request: DaemonRequest) {
     status = { DaemonResponse::Status(DaemonStatus::new(1000, 100)) },
     shutting_down = { DaemonResponse::ShuttingDown },
     pong = { DaemonResponse::Pong },
-    error = { DaemonResponse::Error { message: "test".to_string() } },
-    hello = { DaemonResponse::Hello { version: "0.1.0".to_string() } },
+    error = { DaemonResponse::Error { message: "test".to_string(), code: None } },
+    db_error = { DaemonResponse::Error { message: "locked".to_string(), code: Some(DaemonDbError::Busy) } },
+    hello = { DaemonResponse::Hello { version: "0.1.0".to_string(), encodings: framing::Encoding::ALL.to_vec() } },
 )]
 fn framing_roundtrip_response(response: DaemonResponse) {
     let mut buf = Vec::new();
@@ -75,6 +180,94 @@ fn framing_roundtrip_response(response: DaemonResponse) {
     assert_eq!(response, decoded);
 }
 
+fn sample_issue() -> Issue {
+    let mut issue = Issue::new("prj-1".to_string(), IssueType::Bug, "Fix login".to_string());
+    issue.description = Some("Users are logged out".to_string());
+    issue.assignee = Some("alice".to_string());
+    issue.closed_at = Some(Utc::now());
+    issue
+}
+
+#[parameterized(
+    hello = { DaemonRequest::Hello { version: "0.1.0".to_string(), encodings: framing::Encoding::ALL.to_vec() } },
+    enqueue_hook = { DaemonRequest::EnqueueHook { job: hook_job() } },
+    list = { DaemonRequest::Query(QueryOp::ListIssues { status: Some(Status::Todo), issue_type: None, label: Some("auth".to_string()) }) },
+    add_dependency = { DaemonRequest::Mutate(MutateOp::AddDependency(DependencyRef {
+        from_id: "prj-2".to_string(),
+        to_id: "prj-1".to_string(),
+        relation: Relation::Blocks,
+    })) },
+)]
+fn framing_roundtrip_request_cbor(request: DaemonRequest) {
+    let mut buf = Vec::new();
+    framing::write_message_as(&mut buf, &request, framing::Encoding::Cbor).unwrap();
+
+    let (decoded, encoding) =
+        framing::read_message_encoded::<_, DaemonRequest>(&mut Cursor::new(buf)).unwrap();
+    assert_eq!(request, decoded);
+    assert_eq!(encoding, framing::Encoding::Cbor);
+}
+
+#[parameterized(
+    issues = { DaemonResponse::QueryResult(QueryResult::Issues { issues: vec![sample_issue(), sample_issue()] }) },
+    labels_batch = { DaemonResponse::QueryResult(QueryResult::LabelsBatch { labels: HashMap::from([("prj-1".to_string(), vec!["auth".to_string()])]) }) },
+    db_error = { DaemonResponse::Error { message: "locked".to_string(), code: Some(DaemonDbError::Busy) } },
+    hello = { DaemonResponse::Hello { version: "0.1.0".to_string(), encodings: framing::Encoding::ALL.to_vec() } },
+)]
+fn framing_roundtrip_response_cbor(response: DaemonResponse) {
+    let mut buf = Vec::new();
+    framing::write_message_as(&mut buf, &response, framing::Encoding::Cbor).unwrap();
+
+    let decoded: DaemonResponse = framing::read_message(&mut Cursor::new(buf)).unwrap();
+    assert_eq!(response, decoded);
+}
+
+#[test]
+fn framing_marks_cbor_in_length_prefix() {
+    let mut json = Vec::new();
+    framing::write_message(&mut json, &DaemonRequest::Ping).unwrap();
+    let mut cbor = Vec::new();
+    framing::write_message_as(&mut cbor, &DaemonRequest::Ping, framing::Encoding::Cbor).unwrap();
+
+    assert_eq!(json[0] & 0x80, 0);
+    assert_eq!(cbor[0] & 0x80, 0x80);
+    let (_, encoding) =
+        framing::read_message_encoded::<_, DaemonRequest>(&mut Cursor::new(json)).unwrap();
+    assert_eq!(encoding, framing::Encoding::Json);
+}
+
+#[test]
+fn framing_cbor_is_smaller_for_bulk_results() {
+    let response = DaemonResponse::QueryResult(QueryResult::Issues {
+        issues: (0..50).map(|_| sample_issue()).collect(),
+    });
+    let mut json = Vec::new();
+    framing::write_message(&mut json, &response).unwrap();
+    let mut cbor = Vec::new();
+    framing::write_message_as(&mut cbor, &response, framing::Encoding::Cbor).unwrap();
+
+    assert!(cbor.len() < json.len(), "cbor {} >= json {}", cbor.len(), json.len());
+}
+
+#[test]
+fn framing_rejects_malformed_cbor() {
+    let body = [0xff, 0x00];
+    let mut buf = (u32::try_from(body.len()).unwrap() | 1 << 31).to_be_bytes().to_vec();
+    buf.extend_from_slice(&body);
+
+    let err = framing::read_message::<_, DaemonRequest>(&mut Cursor::new(buf)).unwrap_err();
+    assert!(matches!(err, framing::FrameError::DeserializeCbor(_)));
+}
+
+#[test]
+fn hello_without_encodings_means_json_only() {
+    let parsed: DaemonResponse =
+        serde_json::from_str(r#"{"type":"Hello","version":"0.4.1"}"#).unwrap();
+    assert!(matches!(&parsed, DaemonResponse::Hello { encodings, .. } if encodings.is_empty()));
+    assert_eq!(framing::Encoding::negotiate(&[]), framing::Encoding::Json);
+    assert_eq!(framing::Encoding::negotiate(&framing::Encoding::ALL), framing::Encoding::Cbor);
+}
+
 #[test]
 fn status_display() {
     assert_eq!(Status::Todo.to_string(), "todo");
@@ -96,6 +289,8 @@ fn action_display() {
     assert_eq!(Action::Created.to_string(), "created");
     assert_eq!(Action::Linked.to_string(), "linked");
     assert_eq!(Action::Unlinked.to_string(), "unlinked");
+    assert_eq!(Action::Associated.to_string(), "associated");
+    assert_eq!(Action::Duplicated.to_string(), "duplicated");
 }
 
 #[test]
@@ -103,6 +298,8 @@ fn relation_display() {
     assert_eq!(Relation::Blocks.to_string(), "blocks");
     assert_eq!(Relation::TrackedBy.to_string(), "tracked-by");
     assert_eq!(Relation::Tracks.to_string(), "tracks");
+    assert_eq!(Relation::RelatesTo.to_string(), "relates-to");
+    assert_eq!(Relation::DuplicateOf.to_string(), "duplicate-of");
 }
 
 #[test]
@@ -137,6 +334,7 @@ fn core_to_ipc_issue_drops_hlc() {
         last_type_hlc: None,
         last_description_hlc: None,
         last_assignee_hlc: None,
+        revision: 4,
     };
 
     let ipc_issue: Issue = core_issue.clone().into();
@@ -150,6 +348,7 @@ fn core_to_ipc_issue_drops_hlc() {
     assert_eq!(ipc_issue.created_at, core_issue.created_at);
     assert_eq!(ipc_issue.updated_at, core_issue.updated_at);
     assert_eq!(ipc_issue.closed_at, core_issue.closed_at);
+    assert_eq!(ipc_issue.revision, 4);
 }
 
 #[test]
@@ -165,6 +364,7 @@ fn ipc_to_core_issue_sets_hlc_none() {
         created_at: now,
         updated_at: now,
         closed_at: Some(now),
+        revision: 2,
     };
 
     let core_issue: wk_core::Issue = ipc_issue.clone().into();
@@ -178,6 +378,7 @@ fn ipc_to_core_issue_sets_hlc_none() {
     assert_eq!(core_issue.created_at, ipc_issue.created_at);
     assert_eq!(core_issue.updated_at, ipc_issue.updated_at);
     assert_eq!(core_issue.closed_at, ipc_issue.closed_at);
+    assert_eq!(core_issue.revision, 2);
     assert!(core_issue.last_status_hlc.is_none());
     assert!(core_issue.last_title_hlc.is_none());
     assert!(core_issue.last_type_hlc.is_none());
@@ -198,6 +399,7 @@ fn roundtrip_preserves_non_hlc_fields() {
         created_at: now,
         updated_at: now,
         closed_at: None,
+        revision: 1,
     };
 
     let core_issue: wk_core::Issue = original.clone().into();
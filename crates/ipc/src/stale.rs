@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Judging the files of a daemon that did not shut down cleanly.
+//!
+//! A SIGKILLed or crashed wokd leaves its pid file and socket behind, but
+//! the kernel releases its instance lock. Once the lock is free, anything
+//! left in the state directory is stale: `wokd` replaces it at startup and
+//! the CLI removes it, both logging the reason given here.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Why the pid file and socket left in the state directory are stale, or
+/// None when the last daemon cleaned up after itself. Call only when no
+/// daemon holds the lock.
+pub fn stale_reason(pid_path: &Path, socket_path: &Path) -> Option<String> {
+    let pid = fs::read_to_string(pid_path).ok();
+    match pid.as_deref().map(str::trim) {
+        Some(pid) => match pid.parse::<u32>() {
+            Ok(pid) if process_alive(pid) => Some(format!(
+                "PID {} in the pid file is running but does not hold the lock (PID reused)",
+                pid
+            )),
+            Ok(pid) => Some(format!("PID {} in the pid file is not running", pid)),
+            Err(_) => Some(format!("pid file holds no PID ({:?})", pid)),
+        },
+        None if socket_path.exists() => Some("socket left without a pid file".to_string()),
+        None => None,
+    }
+}
+
+/// Whether process `pid` exists.
+pub fn process_alive(pid: u32) -> bool {
+    Command::new("kill")
+        .arg("-0")
+        .arg(pid.to_string())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(test)]
+#[path = "stale_tests.rs"]
+mod tests;
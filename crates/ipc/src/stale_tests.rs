@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+fn dead_pid() -> u32 {
+    let mut child = Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    pid
+}
+
+#[test]
+fn clean_directory_is_not_stale() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let reason = stale_reason(&dir.path().join("daemon.pid"), &dir.path().join("daemon.sock"));
+
+    assert_eq!(reason, None);
+}
+
+#[test]
+fn pid_of_a_killed_daemon_is_stale() {
+    let dir = tempfile::tempdir().unwrap();
+    let pid = dead_pid();
+    fs::write(dir.path().join("daemon.pid"), pid.to_string()).unwrap();
+
+    let reason = stale_reason(&dir.path().join("daemon.pid"), &dir.path().join("daemon.sock"));
+
+    assert_eq!(reason, Some(format!("PID {} in the pid file is not running", pid)));
+}
+
+#[test]
+fn reused_pid_is_stale() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("daemon.pid"), std::process::id().to_string()).unwrap();
+
+    let reason = stale_reason(&dir.path().join("daemon.pid"), &dir.path().join("daemon.sock"));
+
+    assert!(reason.unwrap().ends_with("(PID reused)"));
+}
+
+#[test]
+fn socket_without_pid_file_is_stale() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("daemon.sock"), "").unwrap();
+
+    let reason = stale_reason(&dir.path().join("daemon.pid"), &dir.path().join("daemon.sock"));
+
+    assert_eq!(reason.as_deref(), Some("socket left without a pid file"));
+}
+
+#[test]
+fn garbled_pid_file_is_stale() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("daemon.pid"), "12ab").unwrap();
+
+    let reason = stale_reason(&dir.path().join("daemon.pid"), &dir.path().join("daemon.sock"));
+
+    assert_eq!(reason.as_deref(), Some("pid file holds no PID (\"12ab\")"));
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Errors from parsing and loading fixtures.
+
+use thiserror::Error;
+
+/// A fixture that does not parse or cannot be loaded.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("fixture line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    #[error("fixture line {line}: {source}")]
+    Load {
+        line: usize,
+        #[source]
+        source: wk_core::Error,
+    },
+
+    #[error(transparent)]
+    Database(#[from] wk_core::Error),
+}
+
+/// Result type for fixture operations.
+pub type Result<T> = std::result::Result<T, Error>;
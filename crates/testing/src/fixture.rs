@@ -0,0 +1,430 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parsing fixture text and loading it into a database.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::params;
+use wk_core::{Action, Database, Event, Issue, IssueType, NoteKind, Relation, Status};
+
+use crate::error::Error;
+
+/// Parsed fixture records, ready to load into any number of databases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture {
+    lines: Vec<Line>,
+    now: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Line {
+    /// 1-based line number in the fixture text, for errors.
+    number: usize,
+    record: Record,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Record {
+    Issue {
+        id: String,
+        issue_type: IssueType,
+        title: String,
+        status: Status,
+        assignee: Option<String>,
+        description: Option<String>,
+        labels: Vec<String>,
+        created: Option<Time>,
+        updated: Option<Time>,
+        closed: Option<Time>,
+    },
+    Dep {
+        from: String,
+        relation: Relation,
+        to: String,
+    },
+    Label {
+        id: String,
+        label: String,
+    },
+    Note {
+        id: String,
+        content: String,
+        status: Option<Status>,
+        kind: Option<NoteKind>,
+        at: Option<Time>,
+    },
+    Event {
+        id: String,
+        action: Action,
+        from: Option<String>,
+        to: Option<String>,
+        reason: Option<String>,
+        at: Option<Time>,
+    },
+}
+
+/// A point in time as written in a fixture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Time {
+    At(DateTime<Utc>),
+    /// Relative to the fixture's clock.
+    Offset(Duration),
+}
+
+impl Time {
+    fn resolve(self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Time::At(at) => at,
+            Time::Offset(offset) => now + offset,
+        }
+    }
+}
+
+impl FromStr for Time {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        if let Ok(at) = DateTime::parse_from_rfc3339(s) {
+            return Ok(Time::At(at.with_timezone(&Utc)));
+        }
+        let invalid = || format!("'{}' is not an RFC 3339 time or an offset like -3d", s);
+        let (negative, rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => return Err(invalid()),
+        };
+        let unit_at = rest.len().checked_sub(1).ok_or_else(invalid)?;
+        let amount: i64 = rest[..unit_at].parse().map_err(|_| invalid())?;
+        let offset = match &rest[unit_at..] {
+            "s" => Duration::try_seconds(amount),
+            "m" => Duration::try_minutes(amount),
+            "h" => Duration::try_hours(amount),
+            "d" => Duration::try_days(amount),
+            "w" => Duration::try_weeks(amount),
+            _ => None,
+        }
+        .ok_or_else(invalid)?;
+        Ok(Time::Offset(if negative { -offset } else { offset }))
+    }
+}
+
+impl Fixture {
+    /// Parse fixture `text`; see the crate docs for the format.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = Vec::new();
+        for (index, raw) in text.lines().enumerate() {
+            let number = index + 1;
+            let raw = raw.trim();
+            if raw.is_empty() || raw.starts_with('#') {
+                continue;
+            }
+            let record =
+                parse_record(raw).map_err(|message| Error::Parse { line: number, message });
+            lines.push(Line { number, record: record? });
+        }
+        Ok(Fixture { lines, now: None })
+    }
+
+    /// Resolve relative times against `now` instead of the time of loading,
+    /// so that loading is deterministic.
+    pub fn at(mut self, now: DateTime<Utc>) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Load the records into `db`, in order.
+    pub fn load(&self, db: &Database) -> Result<()> {
+        let now = self.now.unwrap_or_else(Utc::now);
+        let statuses: HashMap<&str, Status> = self
+            .lines
+            .iter()
+            .filter_map(|line| match &line.record {
+                Record::Issue { id, status, .. } => Some((id.as_str(), *status)),
+                _ => None,
+            })
+            .collect();
+
+        let total = self.lines.len();
+        for (index, line) in self.lines.iter().enumerate() {
+            // Untimed records count up to `now`, one second apart
+            let behind = u32::try_from(total - 1 - index).map_or(0, i64::from);
+            let default_time = now - Duration::seconds(behind);
+            let time = |t: &Option<Time>| t.map_or(default_time, |t| t.resolve(now));
+            load_record(db, &line.record, &statuses, time)
+                .map_err(|source| Error::Load { line: line.number, source })?;
+        }
+        Ok(())
+    }
+
+    /// A new in-memory database holding the fixture.
+    pub fn database(&self) -> Result<Database> {
+        let db = Database::open_in_memory()?;
+        self.load(&db)?;
+        Ok(db)
+    }
+
+    /// Open the database at `path`, creating it if needed, and load the
+    /// fixture into it; for example a project's `.wok/issues.db` before
+    /// running `wok` against it.
+    pub fn database_at(&self, path: &Path) -> Result<Database> {
+        let db = Database::open(path)?;
+        self.load(&db)?;
+        Ok(db)
+    }
+}
+
+impl FromStr for Fixture {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        Fixture::parse(text)
+    }
+}
+
+fn load_record(
+    db: &Database,
+    record: &Record,
+    statuses: &HashMap<&str, Status>,
+    time: impl Fn(&Option<Time>) -> DateTime<Utc>,
+) -> wk_core::Result<()> {
+    match record {
+        Record::Issue {
+            id,
+            issue_type,
+            title,
+            status,
+            assignee,
+            description,
+            labels,
+            created,
+            updated,
+            closed,
+        } => {
+            let created_at = time(created);
+            let mut issue = Issue::new(id.clone(), *issue_type, title.clone(), created_at);
+            issue.status = *status;
+            issue.assignee = assignee.clone();
+            issue.description = description.clone();
+            issue.updated_at = updated.map_or(created_at, |_| time(updated));
+            if matches!(status, Status::Done | Status::Closed) {
+                issue.closed_at = Some(closed.map_or(issue.updated_at, |_| time(closed)));
+            }
+            let prefix = id.split_once('-').map(|(prefix, _)| prefix);
+            if let Some(prefix) = prefix {
+                db.ensure_prefix(prefix)?;
+            }
+            db.create_issue(&issue)?;
+            if let Some(prefix) = prefix {
+                db.increment_prefix_count(prefix)?;
+            }
+            db.log_event(&Event::new(id.clone(), Action::Created).with_timestamp(created_at))?;
+            for label in labels {
+                db.add_label(id, label)?;
+            }
+        }
+        Record::Dep { from, relation, to } => db.add_dependency(from, to, *relation)?,
+        Record::Label { id, label } => {
+            db.add_label(id, label)?;
+        }
+        Record::Note { id, content, status, kind, at } => {
+            let status =
+                status.or_else(|| statuses.get(id.as_str()).copied()).unwrap_or(Status::Todo);
+            let note_id = db.add_note_of_kind(id, status, *kind, content)?;
+            db.conn.execute(
+                "UPDATE notes SET created_at = ?1 WHERE id = ?2",
+                params![time(at).to_rfc3339(), note_id],
+            )?;
+        }
+        Record::Event { id, action, from, to, reason, at } => {
+            let event = Event::new(id.clone(), *action)
+                .with_values(from.clone(), to.clone())
+                .with_reason(reason.clone())
+                .with_timestamp(time(at));
+            db.log_event(&event)?;
+        }
+    }
+    Ok(())
+}
+
+/// Options after a record's positional fields, by key.
+struct Options {
+    record: &'static str,
+    values: Vec<(String, String)>,
+}
+
+impl Options {
+    fn parse(record: &'static str, tokens: &[String], keys: &[&str]) -> Result<Self, String> {
+        let mut values = Vec::new();
+        for token in tokens {
+            let (key, value) = token.split_once('=').ok_or_else(|| {
+                format!("expected key=value, found '{}' (quote values with spaces)", token)
+            })?;
+            if !keys.contains(&key) {
+                return Err(format!(
+                    "unknown option '{}' for {} (expected one of: {})",
+                    key,
+                    record,
+                    keys.join(", ")
+                ));
+            }
+            values.push((key.to_string(), value.to_string()));
+        }
+        Ok(Options { record, values })
+    }
+
+    fn text(&self, key: &str) -> Option<String> {
+        self.values.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+    }
+
+    fn parsed<T: FromStr>(&self, key: &str) -> Result<Option<T>, String> {
+        self.text(key)
+            .map(|v| v.parse().map_err(|_| format!("invalid {} '{}' for {}", key, v, self.record)))
+            .transpose()
+    }
+
+    fn parsed_time(&self, key: &str) -> Result<Option<Time>, String> {
+        self.text(key).map(|v| v.parse()).transpose()
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+fn parse_record(line: &str) -> Result<Record, String> {
+    let tokens = tokenize(line)?;
+    let (kind, args) = tokens.split_first().ok_or("empty record")?;
+    let positional = |count: usize, usage: &str| -> Result<&[String], String> {
+        if args.len() < count {
+            Err(format!("expected '{}'", usage))
+        } else {
+            Ok(&args[..count])
+        }
+    };
+
+    match kind.as_str() {
+        "issue" => {
+            let fields = positional(3, "issue <id> <type> <title> [key=value ...]")?;
+            let options = Options::parse(
+                "issue",
+                &args[3..],
+                &["status", "assignee", "description", "labels", "created", "updated", "closed"],
+            )?;
+            Ok(Record::Issue {
+                id: fields[0].clone(),
+                issue_type: parse_field("type", &fields[1])?,
+                title: fields[2].clone(),
+                status: options.parsed("status")?.unwrap_or(Status::Todo),
+                assignee: options.text("assignee"),
+                description: options.text("description"),
+                labels: options
+                    .text("labels")
+                    .map(|labels| {
+                        labels
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|l| !l.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                created: options.parsed_time("created")?,
+                updated: options.parsed_time("updated")?,
+                closed: options.parsed_time("closed")?,
+            })
+        }
+        "dep" => {
+            let fields = positional(3, "dep <from> <relation> <to>")?;
+            Options::parse("dep", &args[3..], &[])?;
+            Ok(Record::Dep {
+                from: fields[0].clone(),
+                relation: parse_field("relation", &fields[1])?,
+                to: fields[2].clone(),
+            })
+        }
+        "label" => {
+            let fields = positional(2, "label <id> <label>")?;
+            Options::parse("label", &args[2..], &[])?;
+            Ok(Record::Label { id: fields[0].clone(), label: fields[1].clone() })
+        }
+        "note" => {
+            let fields = positional(2, "note <id> <content> [key=value ...]")?;
+            let options = Options::parse("note", &args[2..], &["status", "kind", "at"])?;
+            Ok(Record::Note {
+                id: fields[0].clone(),
+                content: fields[1].clone(),
+                status: options.parsed("status")?,
+                kind: options.parsed("kind")?,
+                at: options.parsed_time("at")?,
+            })
+        }
+        "event" => {
+            let fields = positional(2, "event <id> <action> [key=value ...]")?;
+            let options = Options::parse("event", &args[2..], &["from", "to", "reason", "at"])?;
+            Ok(Record::Event {
+                id: fields[0].clone(),
+                action: parse_field("action", &fields[1])?,
+                from: options.text("from"),
+                to: options.text("to"),
+                reason: options.text("reason"),
+                at: options.parsed_time("at")?,
+            })
+        }
+        other => {
+            Err(format!("unknown record '{}' (expected issue, dep, label, note or event)", other))
+        }
+    }
+}
+
+fn parse_field<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid {} '{}'", name, value))
+}
+
+/// Split a line on whitespace, honoring double quotes anywhere in a token
+/// and the `\"`, `\\` and `\n` escapes inside them.
+pub(crate) fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current: Option<String> = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let token = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => token.push('\n'),
+                            Some(c @ ('"' | '\\')) => token.push(c),
+                            Some(c) => return Err(format!("unknown escape '\\{}'", c)),
+                            None => return Err("unterminated quote".to_string()),
+                        },
+                        Some(c) => token.push(c),
+                        None => return Err("unterminated quote".to_string()),
+                    }
+                }
+            }
+            c if c.is_whitespace() => tokens.extend(current.take()),
+            c => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    tokens.extend(current);
+    Ok(tokens)
+}
+
+/// Quote `value` for fixture text when it needs it.
+pub(crate) fn quote(value: &str) -> String {
+    let plain = !value.is_empty()
+        && !value.chars().any(|c| c.is_whitespace() || matches!(c, '"' | '\\' | '#' | '='));
+    if plain {
+        return value.to_string();
+    }
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+#[path = "fixture_tests.rs"]
+mod tests;
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use chrono::TimeZone;
+use yare::parameterized;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 14, 12, 0, 0).unwrap()
+}
+
+fn load(text: &str) -> Database {
+    Fixture::parse(text).unwrap().at(now()).database().unwrap()
+}
+
+#[test]
+fn loads_issues_with_their_fields() {
+    let db = load(
+        r#"
+        # A comment
+        issue prj-a1 task "Write the docs" status=in_progress assignee=alice labels=docs,p:1
+        issue prj-b2 bug Crash description="Steps:\nopen, save"
+        "#,
+    );
+
+    let a1 = db.get_issue("prj-a1").unwrap();
+    assert_eq!(a1.issue_type, IssueType::Task);
+    assert_eq!(a1.title, "Write the docs");
+    assert_eq!(a1.status, Status::InProgress);
+    assert_eq!(a1.assignee.as_deref(), Some("alice"));
+    let mut labels = db.get_labels("prj-a1").unwrap();
+    labels.sort();
+    assert_eq!(labels, vec!["docs", "p:1"]);
+
+    let b2 = db.get_issue("prj-b2").unwrap();
+    assert_eq!(b2.description.as_deref(), Some("Steps:\nopen, save"));
+    assert_eq!(db.get_events("prj-b2").unwrap()[0].action, Action::Created);
+    assert_eq!(db.list_prefixes().unwrap()[0].issue_count, 2);
+}
+
+#[test]
+fn untimed_records_are_a_second_apart_in_file_order() {
+    let db = load(
+        "issue prj-a1 task Older
+         issue prj-b2 task Newer",
+    );
+
+    let older = db.get_issue("prj-a1").unwrap();
+    let newer = db.get_issue("prj-b2").unwrap();
+    assert_eq!(newer.created_at, now());
+    assert_eq!(older.created_at, now() - Duration::seconds(1));
+}
+
+#[parameterized(
+    days = { "-3d", Duration::days(-3) },
+    hours = { "-2h", Duration::hours(-2) },
+    minutes = { "-15m", Duration::minutes(-15) },
+    seconds = { "-30s", Duration::seconds(-30) },
+    weeks = { "-1w", Duration::weeks(-1) },
+    future = { "+1d", Duration::days(1) },
+)]
+fn relative_times_count_from_the_fixture_clock(offset: &str, expected: Duration) {
+    let db = load(&format!("issue prj-a1 task Old created={}", offset));
+
+    assert_eq!(db.get_issue("prj-a1").unwrap().created_at, now() + expected);
+}
+
+#[test]
+fn absolute_times_and_closed_issues() {
+    let db = load(
+        "issue prj-a1 task Done status=done created=2026-01-01T00:00:00Z updated=2026-01-02T00:00:00Z",
+    );
+
+    let issue = db.get_issue("prj-a1").unwrap();
+    let updated = Utc.with_ymd_and_hms(2026, 1, 2, 0, 0, 0).unwrap();
+    assert_eq!(issue.created_at, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+    assert_eq!(issue.updated_at, updated);
+    assert_eq!(issue.closed_at, Some(updated));
+}
+
+#[test]
+fn loads_deps_labels_notes_and_events() {
+    let db = load(
+        r#"
+        issue prj-a1 task Blocker status=in_progress
+        issue prj-b2 task Blocked
+        dep prj-a1 blocks prj-b2
+        label prj-b2 urgent
+        note prj-a1 "Halfway there" kind=worklog at=-1h
+        event prj-b2 edited from=Old to="New title" at=-2d
+        "#,
+    );
+
+    assert_eq!(db.get_blockers("prj-b2").unwrap(), vec!["prj-a1"]);
+    assert_eq!(db.get_labels("prj-b2").unwrap(), vec!["urgent"]);
+
+    let notes = db.get_notes("prj-a1").unwrap();
+    assert_eq!(notes[0].content, "Halfway there");
+    assert_eq!(notes[0].status, Status::InProgress);
+    assert_eq!(notes[0].kind, Some(NoteKind::Worklog));
+    assert_eq!(notes[0].created_at, now() - Duration::hours(1));
+
+    let edited = &db.get_events("prj-b2").unwrap()[0];
+    assert_eq!(edited.action, Action::Edited);
+    assert_eq!(edited.old_value.as_deref(), Some("Old"));
+    assert_eq!(edited.new_value.as_deref(), Some("New title"));
+    assert_eq!(edited.created_at, now() - Duration::days(2));
+}
+
+#[parameterized(
+    unknown_record = { "issues prj-a1 task A", "unknown record 'issues'" },
+    missing_title = { "issue prj-a1 task", "expected 'issue <id> <type> <title>" },
+    bad_type = { "issue prj-a1 story A", "invalid type 'story'" },
+    bad_status = { "issue prj-a1 task A status=open", "invalid status 'open' for issue" },
+    unquoted = { "issue prj-a1 task Two words", "found 'words' (quote values with spaces)" },
+    unknown_option = { "issue prj-a1 task A owner=bob", "unknown option 'owner' for issue" },
+    bad_time = { "issue prj-a1 task A created=yesterday", "'yesterday' is not an RFC 3339 time" },
+    bad_relation = { "dep prj-a1 needs prj-b2", "invalid relation 'needs'" },
+    unterminated = { "issue prj-a1 task \"Open", "unterminated quote" },
+)]
+fn parse_errors_name_the_line(text: &str, message: &str) {
+    let err = Fixture::parse(&format!("\n{}", text)).unwrap_err();
+
+    let err = err.to_string();
+    assert!(err.starts_with("fixture line 2: "), "{}", err);
+    assert!(err.contains(message), "{}", err);
+}
+
+#[test]
+fn load_errors_name_the_line() {
+    let fixture = Fixture::parse("issue prj-a1 task A\nlabel prj-zz urgent").unwrap();
+
+    let Err(err) = fixture.database() else {
+        panic!("loaded a label on a missing issue");
+    };
+
+    assert!(matches!(err, Error::Load { line: 2, .. }), "{}", err);
+}
+
+#[parameterized(
+    plain = { "title", "title" },
+    spaces = { "two words", "\"two words\"" },
+    quotes = { "say \"hi\"", "\"say \\\"hi\\\"\"" },
+    newline = { "a\nb", "\"a\\nb\"" },
+    empty = { "", "\"\"" },
+    equals = { "a=b", "\"a=b\"" },
+)]
+fn quote_round_trips_through_tokenize(value: &str, quoted: &str) {
+    assert_eq!(quote(value), quoted);
+    assert_eq!(tokenize(&quote(value)).unwrap(), vec![value.to_string()]);
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Test fixtures for wok databases.
+//!
+//! A [`Fixture`] describes issues, dependencies, labels, notes and events in
+//! a line-based text format and loads them into a [`wk_core::Database`],
+//! in memory or on disk, with whatever timestamps the test needs. Tests
+//! state "this issue is three days old" instead of sleeping between
+//! commands, and tools built on `wk-core` get populated databases without
+//! running the `wok` binary. [`snapshot`] renders a database back into the
+//! same format, so the state after an operation can be compared as text.
+//!
+//! ```text
+//! # One record per line; blank lines and lines starting with '#' are skipped.
+//! issue prj-a1 task "Write the docs" status=in_progress assignee=alice labels=docs,p:1 created=-3d
+//! issue prj-b2 bug "Crash on save" description="Steps:\nopen, save" created=2026-01-05T09:00:00Z
+//! dep prj-a1 blocks prj-b2
+//! label prj-b2 urgent
+//! note prj-a1 "Halfway there" kind=worklog at=-1h
+//! event prj-b2 edited from="Crash" to="Crash on save" at=-2d
+//! ```
+//!
+//! Records:
+//!
+//! - `issue <id> <type> <title>` with optional `status=`, `assignee=`,
+//!   `description=`, `labels=` (comma-separated), `created=`, `updated=`
+//!   (which defaults to `created`) and, for done and closed issues,
+//!   `closed=` (which defaults to `updated`). A `created` event is logged
+//!   for each.
+//! - `dep <from> <relation> <to>`, where the relation is `blocks`, `tracks`
+//!   or `tracked-by`.
+//! - `label <id> <label>`.
+//! - `note <id> <content>` with optional `status=` (the issue's status by
+//!   default), `kind=` and `at=`.
+//! - `event <id> <action>` with optional `from=`, `to=`, `reason=` and `at=`.
+//!
+//! Values containing spaces are double-quoted; `\"`, `\\` and `\n` escape
+//! inside quotes. Times are RFC 3339, or offsets from the fixture's clock
+//! such as `-3d`, `-2h`, `-15m` or `-30s`. Records without a time are
+//! spaced one second apart in file order, the last at the fixture's clock,
+//! so later lines are always newer.
+
+mod error;
+mod fixture;
+mod snapshot;
+
+pub use error::{Error, Result};
+pub use fixture::Fixture;
+pub use snapshot::snapshot;
+
+/// An in-memory database loaded from fixture `text`.
+pub fn database(text: &str) -> Result<wk_core::Database> {
+    Fixture::parse(text)?.database()
+}
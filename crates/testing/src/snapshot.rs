@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rendering a database as fixture text.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use wk_core::{Action, Database, Status};
+
+use crate::error::Result;
+use crate::fixture::quote;
+
+/// Render everything in `db` as fixture text, with absolute times.
+///
+/// Issues come first, in ID order with their labels inline, then
+/// dependencies, notes and events. `created` events that match their
+/// issue's creation time are left out, since loading an `issue` record
+/// logs them. Loading the result into an empty database reproduces `db`,
+/// and two databases with the same snapshot hold the same issues.
+pub fn snapshot(db: &Database) -> Result<String> {
+    let mut issues = db.get_all_issues()?;
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut out = String::new();
+    for issue in &issues {
+        let mut line =
+            format!("issue {} {} {}", quote(&issue.id), issue.issue_type, quote(&issue.title));
+        if issue.status != Status::Todo {
+            option(&mut line, "status", issue.status.as_str());
+        }
+        if let Some(assignee) = &issue.assignee {
+            option(&mut line, "assignee", assignee);
+        }
+        if let Some(description) = &issue.description {
+            option(&mut line, "description", description);
+        }
+        let mut labels = db.get_labels(&issue.id)?;
+        if !labels.is_empty() {
+            labels.sort();
+            option(&mut line, "labels", &labels.join(","));
+        }
+        option(&mut line, "created", &time(issue.created_at));
+        if issue.updated_at != issue.created_at {
+            option(&mut line, "updated", &time(issue.updated_at));
+        }
+        if let Some(closed_at) = issue.closed_at.filter(|c| *c != issue.updated_at) {
+            option(&mut line, "closed", &time(closed_at));
+        }
+        push(&mut out, line);
+    }
+
+    for issue in &issues {
+        let mut deps = db.get_deps_from(&issue.id)?;
+        deps.sort_by(|a, b| (a.relation.as_str(), &a.to_id).cmp(&(b.relation.as_str(), &b.to_id)));
+        for dep in deps {
+            push(
+                &mut out,
+                format!("dep {} {} {}", quote(&dep.from_id), dep.relation, quote(&dep.to_id)),
+            );
+        }
+    }
+
+    for issue in &issues {
+        for note in db.get_notes(&issue.id)? {
+            let mut line = format!("note {} {}", quote(&note.issue_id), quote(&note.content));
+            option(&mut line, "status", note.status.as_str());
+            if let Some(kind) = note.kind {
+                option(&mut line, "kind", kind.as_str());
+            }
+            option(&mut line, "at", &time(note.created_at));
+            push(&mut out, line);
+        }
+    }
+
+    for issue in &issues {
+        for event in db.get_events(&issue.id)? {
+            if event.action == Action::Created && event.created_at == issue.created_at {
+                continue;
+            }
+            let mut line = format!("event {} {}", quote(&event.issue_id), event.action);
+            if let Some(from) = &event.old_value {
+                option(&mut line, "from", from);
+            }
+            if let Some(to) = &event.new_value {
+                option(&mut line, "to", to);
+            }
+            if let Some(reason) = &event.reason {
+                option(&mut line, "reason", reason);
+            }
+            option(&mut line, "at", &time(event.created_at));
+            push(&mut out, line);
+        }
+    }
+    Ok(out)
+}
+
+fn option(line: &mut String, key: &str, value: &str) {
+    let _ = write!(line, " {}={}", key, quote(value));
+}
+
+fn push(out: &mut String, line: String) {
+    out.push_str(&line);
+    out.push('\n');
+}
+
+fn time(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+#[cfg(test)]
+#[path = "snapshot_tests.rs"]
+mod tests;
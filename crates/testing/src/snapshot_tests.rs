@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::Fixture;
+use chrono::TimeZone;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 14, 12, 0, 0).unwrap()
+}
+
+const FIXTURE: &str = r#"
+issue prj-a1 task "Write the docs" status=in_progress assignee=alice labels=p:1,docs created=-3d
+issue prj-b2 bug Crash description="Steps:\nopen" created=-2d
+issue prj-c3 epic Release status=done created=-5d updated=-1d
+dep prj-a1 blocks prj-b2
+dep prj-c3 tracks prj-a1
+note prj-a1 "Halfway there" kind=worklog at=-1h
+event prj-a1 started at=-2d
+"#;
+
+#[test]
+fn renders_the_database_as_fixture_text() {
+    let db = Fixture::parse(FIXTURE).unwrap().at(now()).database().unwrap();
+
+    assert_eq!(
+        snapshot(&db).unwrap(),
+        "\
+issue prj-a1 task \"Write the docs\" status=in_progress assignee=alice labels=docs,p:1 created=2026-03-11T12:00:00Z
+issue prj-b2 bug Crash description=\"Steps:\\nopen\" created=2026-03-12T12:00:00Z
+issue prj-c3 epic Release status=done created=2026-03-09T12:00:00Z updated=2026-03-13T12:00:00Z
+dep prj-a1 blocks prj-b2
+dep prj-c3 tracks prj-a1
+note prj-a1 \"Halfway there\" status=in_progress kind=worklog at=2026-03-14T11:00:00Z
+event prj-a1 started at=2026-03-12T12:00:00Z
+"
+    );
+}
+
+#[test]
+fn snapshot_round_trips() {
+    let db = Fixture::parse(FIXTURE).unwrap().at(now()).database().unwrap();
+    let text = snapshot(&db).unwrap();
+
+    let copy = crate::database(&text).unwrap();
+
+    assert_eq!(snapshot(&copy).unwrap(), text);
+}
+
+#[test]
+fn empty_database_has_an_empty_snapshot() {
+    let db = Database::open_in_memory().unwrap();
+
+    assert_eq!(snapshot(&db).unwrap(), "");
+}
@@ -0,0 +1,388 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Fuzz targets for the IPC framing and the filter parser.
+//!
+//! Each target takes arbitrary bytes, feeds them to the code under test and
+//! checks the properties that must hold for every input: no panics, typed
+//! errors, and round trips for anything that decodes. A broken property is
+//! returned as an `Err` describing it.
+//!
+//! The generators build inputs that reach past the first few bytes: valid
+//! frames with mutated length prefixes, truncations and corrupt bodies, and
+//! filter queries assembled from real field names, operators and values with
+//! junk mixed in. `wk-fuzz` drives them from the command line; the tests in
+//! `tests/` run a fixed number of seeded cases on every `cargo test`.
+
+use std::io::Cursor;
+
+use chrono::{DateTime, TimeZone, Utc};
+use fastrand::Rng;
+use wk_ipc::framing::{self, Encoding, FrameError, MAX_MESSAGE_SIZE};
+use wk_ipc::{DaemonDbError, DaemonRequest, DaemonResponse, DaemonStatus, QueryOp};
+use wkrs::filter::{parse_duration, parse_filter, parse_query};
+use wkrs::models::{Issue, IssueType, Status};
+use wkrs::Error;
+
+/// Outcome of one fuzz case: `Err` names the property that broke.
+pub type Outcome = Result<(), String>;
+
+/// A fuzz target, by name.
+pub struct Target {
+    pub name: &'static str,
+    pub run: fn(&[u8]) -> Outcome,
+    pub generate: fn(&mut Rng) -> Vec<u8>,
+}
+
+/// All targets, for the command-line driver.
+pub const TARGETS: &[Target] = &[
+    Target { name: "framing", run: framing, generate: frame_input },
+    Target { name: "filter", run: filter, generate: filter_input },
+];
+
+/// Look up a target by name.
+pub fn target(name: &str) -> Option<&'static Target> {
+    TARGETS.iter().find(|t| t.name == name)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Framing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Read `data` as a request frame and as a response frame.
+///
+/// Errors must be consistent with the bytes (a `TooLarge` prefix really is
+/// too large, a `Truncated` frame really is short), and anything that
+/// decodes must encode back to a frame that decodes to the same message and
+/// whose every strict prefix is reported as closed or truncated.
+pub fn framing(data: &[u8]) -> Outcome {
+    check_frame::<DaemonRequest>(data)?;
+    check_frame::<DaemonResponse>(data)
+}
+
+fn check_frame<T>(data: &[u8]) -> Outcome
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let (message, encoding) = match framing::read_message_encoded::<_, T>(&mut Cursor::new(data)) {
+        Ok(read) => read,
+        Err(err) => return check_frame_error(data, &err),
+    };
+
+    let mut encoded = Vec::new();
+    framing::write_message_as(&mut encoded, &message, encoding)
+        .map_err(|e| format!("decoded message does not encode: {e}"))?;
+    let decoded: T = framing::read_message(&mut Cursor::new(&encoded))
+        .map_err(|e| format!("re-encoded message does not decode: {e}"))?;
+    if decoded != message {
+        return Err(format!("round trip changed {message:?} into {decoded:?}"));
+    }
+
+    for cut in prefix_cuts(encoded.len()) {
+        match framing::read_message::<_, T>(&mut Cursor::new(&encoded[..cut])) {
+            Err(FrameError::Closed) if cut == 0 => {}
+            Err(FrameError::Truncated { expected, got }) if got < expected => {}
+            other => return Err(format!("{cut}-byte prefix read as {other:?}")),
+        }
+    }
+    Ok(())
+}
+
+fn check_frame_error(data: &[u8], err: &FrameError) -> Outcome {
+    let prefix_len = data
+        .get(..4)
+        .and_then(|p| <[u8; 4]>::try_from(p).ok())
+        .map(|p| u32::from_be_bytes(p) & !(1 << 31))
+        .and_then(|len| usize::try_from(len).ok());
+    let consistent = match err {
+        FrameError::Closed => data.is_empty(),
+        FrameError::Truncated { expected, got } if data.len() < 4 => {
+            *expected == 4 && data.len() == *got
+        }
+        FrameError::Truncated { expected, got } => {
+            prefix_len == Some(*expected) && data.len() == got + 4 && got < expected
+        }
+        FrameError::TooLarge { len, max } => {
+            *max == MAX_MESSAGE_SIZE && prefix_len == Some(*len) && len > max
+        }
+        FrameError::Deserialize(_) | FrameError::DeserializeCbor(_) => {
+            prefix_len.is_some_and(|len| data.len() >= len + 4)
+        }
+        FrameError::Serialize(_) | FrameError::SerializeCbor(_) | FrameError::Io(_) => false,
+    };
+    if consistent {
+        Ok(())
+    } else {
+        Err(format!("{} bytes read as {err:?}", data.len()))
+    }
+}
+
+/// Cut points to check in a frame of `len` bytes: the whole prefix, each
+/// byte of the length prefix, and a spread through the body.
+fn prefix_cuts(len: usize) -> impl Iterator<Item = usize> {
+    let step = (len / 16).max(1);
+    (0..len.min(5)).chain((5..len).step_by(step)).chain(len.checked_sub(1))
+}
+
+/// A frame input: random bytes, or an encoded message with its prefix,
+/// body or length damaged.
+pub fn frame_input(rng: &mut Rng) -> Vec<u8> {
+    let mut frame = Vec::new();
+    let encoding = if rng.bool() { Encoding::Json } else { Encoding::Cbor };
+    let encoded = if rng.bool() {
+        framing::write_message_as(&mut frame, &request(rng), encoding)
+    } else {
+        framing::write_message_as(&mut frame, &response(rng), encoding)
+    };
+    if encoded.is_err() {
+        frame.clear();
+    }
+
+    match rng.u8(..8) {
+        0 => random_bytes(rng, 64),
+        1 => frame,
+        2 => {
+            let cut = rng.usize(..=frame.len());
+            frame.truncate(cut);
+            frame
+        }
+        3 => {
+            let len = rng.u32(..);
+            if let Some(prefix) = frame.get_mut(..4) {
+                prefix.copy_from_slice(&len.to_be_bytes());
+            }
+            frame
+        }
+        4 => {
+            let len = u32::try_from(MAX_MESSAGE_SIZE).unwrap_or(u32::MAX);
+            let len = len.saturating_add(rng.u32(..=16));
+            let mut data = len.to_be_bytes().to_vec();
+            data.extend(random_bytes(rng, 16));
+            data
+        }
+        5 => {
+            frame.extend(random_bytes(rng, 8));
+            frame
+        }
+        _ => {
+            for _ in 0..rng.usize(1..4) {
+                if !frame.is_empty() {
+                    let at = rng.usize(..frame.len());
+                    frame[at] = rng.u8(..);
+                }
+            }
+            frame
+        }
+    }
+}
+
+fn request(rng: &mut Rng) -> DaemonRequest {
+    match rng.u8(..5) {
+        0 => DaemonRequest::Status,
+        1 => DaemonRequest::Shutdown,
+        2 => DaemonRequest::Ping,
+        3 => DaemonRequest::Hello { version: random_text(rng, 12), encodings: encodings(rng) },
+        _ => DaemonRequest::Query(QueryOp::SearchIssues { query: random_text(rng, 32) }),
+    }
+}
+
+fn encodings(rng: &mut Rng) -> Vec<Encoding> {
+    Encoding::ALL.into_iter().filter(|_| rng.bool()).collect()
+}
+
+fn response(rng: &mut Rng) -> DaemonResponse {
+    match rng.u8(..5) {
+        0 => DaemonResponse::Status(DaemonStatus::new(rng.u32(..), rng.u64(..))),
+        1 => DaemonResponse::ShuttingDown,
+        2 => DaemonResponse::Pong,
+        3 => DaemonResponse::Hello { version: random_text(rng, 12), encodings: encodings(rng) },
+        _ => DaemonResponse::Error {
+            message: random_text(rng, 64),
+            code: rng.bool().then_some(DaemonDbError::Conflict),
+        },
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Filters
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Parse `data` as a filter query, a single time filter and a duration.
+///
+/// Parsing must return a filter error rather than panic, and anything that
+/// parses must evaluate and translate to a database condition against
+/// issues at the edges of the supported time range.
+pub fn filter(data: &[u8]) -> Outcome {
+    let input = String::from_utf8_lossy(data);
+    let now = Utc::now();
+
+    match parse_query(&input) {
+        Ok(query) => {
+            let labels = ["priority:1".to_string(), "urgent".to_string()];
+            for issue in issues(now) {
+                query.matches(&issue, &labels, now);
+            }
+            query.to_cond(now);
+        }
+        Err(err) => check_filter_error(&input, &err)?,
+    }
+    match parse_filter(&input) {
+        Ok(expr) => {
+            for issue in issues(now) {
+                expr.matches(&issue, now);
+            }
+            expr.to_cond(now);
+        }
+        Err(err) => check_filter_error(&input, &err)?,
+    }
+    if let Ok(duration) = parse_duration(&input) {
+        if now.checked_sub_signed(duration).is_none() {
+            return Err(format!("{input:?} parsed to {duration}, past the date range"));
+        }
+    }
+    Ok(())
+}
+
+fn check_filter_error(input: &str, err: &Error) -> Outcome {
+    match err {
+        Error::FilterEmpty
+        | Error::FilterUnknownField { .. }
+        | Error::FilterInvalidOperator { .. }
+        | Error::FilterInvalidValue { .. }
+        | Error::InvalidDuration { .. }
+        | Error::InvalidStatus(_)
+        | Error::InvalidIssueType(_) => Ok(()),
+        other => Err(format!("{input:?} failed with {other:?}")),
+    }
+}
+
+/// Issues created at the epoch, now, and the far ends of chrono's range,
+/// one of them closed.
+fn issues(now: DateTime<Utc>) -> Vec<Issue> {
+    let times = [
+        DateTime::<Utc>::MIN_UTC,
+        Utc.timestamp_opt(0, 0).single().unwrap_or(now),
+        now,
+        DateTime::<Utc>::MAX_UTC,
+    ];
+    times
+        .iter()
+        .map(|at| {
+            let mut issue = Issue::new("fz-1".to_string(), IssueType::Task, "t".to_string(), *at);
+            issue.status = Status::Done;
+            issue.closed_at = Some(*at);
+            issue
+        })
+        .chain([Issue::new("fz-2".to_string(), IssueType::Bug, "t".to_string(), now)])
+        .collect()
+}
+
+const FILTER_WORDS: &[&str] = &[
+    "age",
+    "created",
+    "updated",
+    "activity",
+    "completed",
+    "done",
+    "skipped",
+    "cancelled",
+    "closed",
+    "label",
+    "labels",
+    "assignee",
+    "status",
+    "type",
+    "priority",
+    "<",
+    "<=",
+    ">",
+    ">=",
+    "=",
+    "!=",
+    "==",
+    "<<",
+    "!",
+    "lt",
+    "lte",
+    "gt",
+    "gte",
+    "eq",
+    "ne",
+    "in",
+    "and",
+    "or",
+    "AND",
+    "Or",
+    "(",
+    ")",
+    ",",
+    "\"",
+    "'",
+    "3d",
+    "1w",
+    "24h",
+    "30m",
+    "500ms",
+    "1M",
+    "1y",
+    "10000y",
+    "10001y",
+    "-3d",
+    "9223372036854775807d",
+    "99999999999999999999w",
+    "0s",
+    "2024-01-01",
+    "9999-12-31",
+    "2024-02-30",
+    "now",
+    "NOW",
+    "todo",
+    "in_progress",
+    "bug",
+    "task",
+    "urgent",
+    "alice",
+    "0",
+    "4",
+    "5",
+    "255",
+    "256",
+    "é",
+    "日本",
+    "🦀",
+    "\u{0}",
+    "\t",
+];
+
+/// A filter input: words from the filter grammar, with random characters
+/// and missing spaces mixed in.
+pub fn filter_input(rng: &mut Rng) -> Vec<u8> {
+    let mut out = String::new();
+    for _ in 0..rng.usize(0..12) {
+        match rng.u8(..10) {
+            0 => out.push(rng.char(..)),
+            1 => {}
+            _ => {
+                if let Some(word) = FILTER_WORDS.get(rng.usize(..FILTER_WORDS.len())) {
+                    out.push_str(word);
+                }
+            }
+        }
+        if rng.u8(..4) != 0 {
+            out.push(' ');
+        }
+    }
+    out.into_bytes()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn random_bytes(rng: &mut Rng, max: usize) -> Vec<u8> {
+    (0..rng.usize(..=max)).map(|_| rng.u8(..)).collect()
+}
+
+fn random_text(rng: &mut Rng, max: usize) -> String {
+    (0..rng.usize(..=max)).map(|_| rng.char(..)).collect()
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Command-line driver for the fuzz targets.
+//!
+//! ```text
+//! wk-fuzz <target> [--runs N] [--seed S]   # generate and check N inputs
+//! wk-fuzz <target> FILE...                 # replay saved inputs
+//! ```
+//!
+//! A failing input is written to `fuzz/artifacts/<target>/` so it can be
+//! replayed, and kept as a regression case.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use wk_fuzz::{target, Target, TARGETS};
+
+const DEFAULT_RUNS: u64 = 100_000;
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            let names: Vec<_> = TARGETS.iter().map(|t| t.name).collect();
+            eprintln!("error: {message}");
+            eprintln!("usage: wk-fuzz <{}> [--runs N] [--seed S] [FILE...]", names.join("|"));
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<bool, String> {
+    let mut args = args.into_iter();
+    let name = args.next().ok_or("missing target")?;
+    let target = target(&name).ok_or_else(|| format!("unknown target '{name}'"))?;
+
+    let mut runs = DEFAULT_RUNS;
+    let mut seed = None;
+    let mut files = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => runs = number(args.next(), "--runs")?,
+            "--seed" => seed = Some(number(args.next(), "--seed")?),
+            _ => files.push(PathBuf::from(arg)),
+        }
+    }
+
+    if !files.is_empty() {
+        return replay(target, &files);
+    }
+    let seed = seed
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()));
+    Ok(generate(target, runs, seed))
+}
+
+fn number(value: Option<String>, flag: &str) -> Result<u64, String> {
+    value.and_then(|v| v.parse().ok()).ok_or_else(|| format!("{flag} needs a number"))
+}
+
+fn replay(target: &Target, files: &[PathBuf]) -> Result<bool, String> {
+    let mut ok = true;
+    for file in files {
+        let data = std::fs::read(file).map_err(|e| format!("{}: {e}", file.display()))?;
+        if let Err(failure) = (target.run)(&data) {
+            println!("FAIL {}: {failure}", file.display());
+            ok = false;
+        }
+    }
+    Ok(ok)
+}
+
+fn generate(target: &Target, runs: u64, seed: u64) -> bool {
+    println!("{}: {runs} runs from seed {seed}", target.name);
+    let mut rng = fastrand::Rng::with_seed(seed);
+    for run in 0..runs {
+        let data = (target.generate)(&mut rng);
+        if let Err(failure) = (target.run)(&data) {
+            println!("FAIL run {run}: {failure}");
+            match save_artifact(target.name, &data) {
+                Ok(path) => println!("input saved to {}", path.display()),
+                Err(e) => println!("input not saved: {e}"),
+            }
+            return false;
+        }
+    }
+    println!("ok");
+    true
+}
+
+fn save_artifact(target: &str, data: &[u8]) -> std::io::Result<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("artifacts").join(target);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("crash-{:016x}", fingerprint(data)));
+    std::fs::write(&path, data)?;
+    Ok(path)
+}
+
+/// FNV-1a, to name artifacts after their contents.
+fn fingerprint(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Seeded runs of every fuzz target, plus inputs that once broke them.
+
+#![allow(clippy::unwrap_used)]
+
+use wk_fuzz::{filter, framing, Target, TARGETS};
+
+const RUNS: u64 = 2_000;
+const SEEDS: [u64; 3] = [1, 0x5eed, 20_260_101];
+
+fn check(target: &Target) {
+    for seed in SEEDS {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        for run in 0..RUNS {
+            let data = (target.generate)(&mut rng);
+            let outcome = (target.run)(&data);
+            assert!(
+                outcome.is_ok(),
+                "{} seed {seed} run {run} on {:?}: {outcome:?}",
+                target.name,
+                String::from_utf8_lossy(&data)
+            );
+        }
+    }
+}
+
+#[test]
+fn all_targets_hold_for_generated_inputs() {
+    for target in TARGETS {
+        check(target);
+    }
+}
+
+#[test]
+fn framing_regressions() {
+    let inputs: &[&[u8]] = &[
+        b"",
+        b"\x00",
+        b"\x00\x00\x00",
+        b"\xff\xff\xff\xff",
+        b"\x00\x10\x00\x01{}",
+        b"\x00\x00\x00\x00",
+        b"\x00\x00\x00\x06\"Ping\"",
+        b"\x00\x00\x00\x10\"Ping\"",
+        b"\x00\x00\x00\x02\xff\xfe",
+    ];
+    for input in inputs {
+        framing(input).unwrap();
+    }
+}
+
+#[test]
+fn filter_regressions() {
+    let inputs = [
+        "age <é 3d",
+        "age ééééééé",
+        "age < 9223372036854775807w",
+        "age < 99999999999y",
+        "created > 200000M",
+        "updated >= 10000y",
+        "(((",
+        "label in (",
+        "status = é",
+        "priority <= 🦀",
+        "and or and",
+    ];
+    for input in inputs {
+        filter(input.as_bytes()).unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok activity` command.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, type_: &str, title: &str) -> String {
+    let output =
+        wk().args(["new", type_, title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn activity_shows_events_with_titles_grouped_by_day() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Activity task");
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+
+    wk().args(["activity"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^\d{4}-\d{2}-\d{2}\n").unwrap())
+        .stdout(predicate::str::contains(format!("{} created  (Activity task)", id)))
+        .stdout(predicate::str::contains(format!("{} started  (Activity task)", id)));
+}
+
+#[test]
+fn activity_filters_by_assignee() {
+    let temp = init_temp();
+    let mine = create_issue(&temp, "task", "Alice task");
+    let other = create_issue(&temp, "task", "Unassigned task");
+    wk().args(["edit", &mine, "assignee", "alice"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["activity", "-a", "alice"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&mine))
+        .stdout(predicate::str::contains(&other).not());
+}
+
+#[test]
+fn activity_filters_by_prefix() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Default prefix task");
+
+    wk().args(["activity", "-p", "other"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No activity since"))
+        .stdout(predicate::str::contains(&id).not());
+}
+
+#[test]
+fn activity_since_future_is_empty() {
+    let temp = init_temp();
+    create_issue(&temp, "task", "Old task");
+
+    wk().args(["activity", "--since", "2999-01-01"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No activity since 2999-01-01 00:00\n");
+}
+
+#[test]
+fn activity_json_includes_titles() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Json activity task");
+
+    let output = wk().args(["activity", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+    let entries: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let entries = entries.as_array().unwrap();
+    assert!(entries.iter().any(|e| e["issue_id"] == id.as_str()
+        && e["action"] == "created"
+        && e["title"] == "Json activity task"));
+}
+
+#[test]
+fn activity_rejects_invalid_since() {
+    let temp = init_temp();
+
+    wk().args(["activity", "--since", "whenever"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid timestamp"));
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for recording who made each change: the `actor` setting,
+//! `WOK_ACTOR` and `wok log --actor`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, type_: &str, title: &str) -> String {
+    let output =
+        wk().args(["new", type_, title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn log_and_show_name_the_actor() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Shared work");
+    wk().args(["start", &id])
+        .env("WOK_ACTOR", "claude-1")
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["note", &id, "Halfway there"])
+        .env("WOK_ACTOR", "claude-2")
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["log", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("started by claude-1"))
+        .stdout(predicate::str::contains("noted \"Halfway there\" by claude-2"));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("by claude-2\n    Halfway there"));
+}
+
+#[test]
+fn log_filters_by_actor() {
+    let temp = init_temp();
+    let first = create_issue(&temp, "task", "First");
+    let second = create_issue(&temp, "task", "Second");
+    for (id, actor) in [(&first, "claude-1"), (&second, "claude-2")] {
+        wk().args(["start", id])
+            .env("WOK_ACTOR", actor)
+            .current_dir(temp.path())
+            .assert()
+            .success();
+    }
+
+    wk().args(["log", "--actor", "claude-1"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{} started by claude-1", first)))
+        .stdout(predicate::str::contains(&second).not());
+
+    let output = wk()
+        .args(["log", "--actor", "claude-2", "-o", "ndjson"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let event: serde_json::Value =
+        serde_json::from_str(String::from_utf8_lossy(&output.stdout).trim()).unwrap();
+    assert_eq!(event["issue_id"], second.as_str());
+    assert_eq!(event["actor"], "claude-2");
+}
+
+#[test]
+fn actor_defaults_to_the_configured_user() {
+    let temp = init_temp();
+    wk().args(["config", "set", "user", "alice"]).current_dir(temp.path()).assert().success();
+    let id = create_issue(&temp, "task", "Mine");
+
+    wk().args(["log", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("created by alice"));
+}
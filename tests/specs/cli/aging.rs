@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `[aging]` thresholds, `wok list --stale` and `wok hook stale`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn init_aging() -> TempDir {
+    let temp = init_temp();
+    wk().args(["config", "set", "aging.in_progress", "7d"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    load_fixture(
+        &temp,
+        r#"
+        issue test-stuck task "Stuck" status=in_progress created=-20d
+        event test-stuck started at=-9d
+        issue test-moving task "Moving" status=in_progress created=-20d
+        event test-moving started at=-1d
+        issue test-waiting task "Waiting" created=-40d
+        "#,
+    );
+    temp
+}
+
+#[test]
+fn list_marks_and_filters_stale_issues() {
+    let temp = init_aging();
+
+    wk().arg("list")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Stuck (stale 9d)"))
+        .stdout(predicate::str::contains("Moving\n"));
+
+    wk().args(["list", "--stale", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("test-stuck\n");
+}
+
+#[test]
+fn list_stale_needs_a_threshold() {
+    let temp = init_temp();
+    wk().args(["list", "--stale"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("[aging] threshold is required for --stale"));
+}
+
+#[test]
+fn show_marks_a_stale_status() {
+    let temp = init_aging();
+    wk().args(["show", "test-stuck"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Status: in_progress (stale 9d)"));
+}
+
+#[test]
+fn hook_stale_fires_once_per_stay() {
+    let temp = init_aging();
+    std::fs::write(
+        temp.path().join(".wok/hooks.toml"),
+        r#"
+[[hooks]]
+name = "nag"
+events = ["issue.stale"]
+run = "echo \"$WOK_ISSUE_ID $WOK_CHANGE_VALUE\" >> stale.log"
+"#,
+    )
+    .unwrap();
+
+    wk().args(["hook", "stale"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("test-stuck is stale: in_progress for 9d\n");
+    wk().args(["hook", "stale"]).current_dir(temp.path()).assert().success().stdout("");
+
+    let log = std::fs::read_to_string(temp.path().join("stale.log")).unwrap();
+    assert_eq!(log, "test-stuck 9d\n");
+
+    wk().args(["log", "test-stuck"])
+        .current_dir(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("stale in_progress for 9d"));
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok alias` and alias expansion.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, args: &[&str]) -> String {
+    let output =
+        wk().arg("new").args(args).args(["-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn add_alias(temp: &TempDir, name: &str, expansion: &str) {
+    wk().args(["alias", "add", name, expansion]).current_dir(temp.path()).assert().success();
+}
+
+#[test]
+fn alias_runs_its_expansion_with_extra_args() {
+    let temp = init_temp();
+    let started = create_issue(&temp, &["task", "Started"]);
+    create_issue(&temp, &["task", "Waiting"]);
+    wk().args(["start", &started]).current_dir(temp.path()).assert().success();
+    add_alias(&temp, "wip", "list -s in_progress");
+
+    wk().args(["wip", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", started));
+}
+
+#[test]
+fn alias_is_found_through_directory_option() {
+    let temp = init_temp();
+    create_issue(&temp, &["task", "Only"]);
+    add_alias(&temp, "ids", "list -o id");
+
+    wk().arg("-C")
+        .arg(temp.path())
+        .arg("ids")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("test-"));
+}
+
+#[test]
+fn alias_list_shows_expansions() {
+    let temp = init_temp();
+    add_alias(&temp, "wip", "list -s in_progress");
+
+    wk().args(["alias", "list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("wip = list -s in_progress\n");
+    wk().args(["alias", "remove", "wip"]).current_dir(temp.path()).assert().success();
+    wk().args(["alias", "list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No aliases defined.\n");
+}
+
+#[test]
+fn alias_cannot_shadow_builtin() {
+    let temp = init_temp();
+    wk().args(["alias", "add", "list", "ready"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("built-in command"));
+}
+
+#[test]
+fn alias_loop_in_config_is_reported() {
+    let temp = init_temp();
+    let config = temp.path().join(".wok/config.toml");
+    let mut content = std::fs::read_to_string(&config).unwrap();
+    content.push_str("\n[alias]\nping = \"pong\"\npong = \"ping\"\n");
+    std::fs::write(&config, content).unwrap();
+
+    wk().arg("ping")
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("alias loop: ping -> pong -> ping"));
+}
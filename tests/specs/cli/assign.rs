@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok assign` and `wok unassign` commands.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, type_: &str, title: &str) -> String {
+    let output =
+        wk().args(["new", type_, title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Append lines to `.wok/config.toml`.
+fn add_config(temp: &TempDir, lines: &str) {
+    let path = temp.path().join(".wok").join("config.toml");
+    let mut config = std::fs::read_to_string(&path).unwrap();
+    config.push_str(lines);
+    std::fs::write(&path, config).unwrap();
+}
+
+#[test]
+fn assign_and_unassign() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Assign task");
+
+    wk().args(["assign", &id, "alice"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Assigned {} to alice\n", id));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Assignee: alice"));
+
+    wk().args(["unassign", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Unassigned {}\n", id));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Assignee:").not());
+}
+
+#[test]
+fn assign_me_uses_configured_user() {
+    let temp = init_temp();
+    add_config(&temp, "user = \"alice\"\n");
+    let id = create_issue(&temp, "task", "My task");
+
+    wk().args(["assign", &id, "@me"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Assigned {} to alice\n", id));
+}
+
+#[test]
+fn assign_me_without_user_fails() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Nobody's task");
+
+    wk().args(["assign", &id, "@me"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no current user for @me"));
+}
+
+#[test]
+fn assign_outside_team_fails() {
+    let temp = init_temp();
+    add_config(&temp, "team = [\"alice\", \"bob\"]\n");
+    let id = create_issue(&temp, "task", "Team task");
+
+    wk().args(["assign", &id, "mallory"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown assignee 'mallory'"))
+        .stderr(predicate::str::contains("alice, bob"));
+    wk().args(["assign", &id, "bob"]).current_dir(temp.path()).assert().success();
+    wk().args(["edit", &id, "assignee", "mallory"]).current_dir(temp.path()).assert().failure();
+    wk().args(["edit", &id, "assignee", "none"]).current_dir(temp.path()).assert().success();
+}
+
+#[test]
+fn new_assignee_accepts_me() {
+    let temp = init_temp();
+    add_config(&temp, "user = \"alice\"\n");
+
+    wk().args(["new", "task", "Self-assigned", "--assignee", "@me", "-o", "json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""assignee": "alice""#));
+}
+
+#[test]
+fn list_mine_shows_only_my_issues() {
+    let temp = init_temp();
+    add_config(&temp, "user = \"alice\"\n");
+    let mine = create_issue(&temp, "task", "Mine");
+    let theirs = create_issue(&temp, "task", "Theirs");
+    wk().args(["assign", &mine, "@me"]).current_dir(temp.path()).assert().success();
+    wk().args(["assign", &theirs, "bob"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["list", "--mine"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&mine))
+        .stdout(predicate::str::contains(&theirs).not());
+    wk().args(["list", "-a", "@me"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&mine))
+        .stdout(predicate::str::contains(&theirs).not());
+}
+
+#[test]
+fn start_over_wip_limit_warns_by_default() {
+    let temp = init_temp();
+    add_config(&temp, "\n[wip]\nlimit = 1\n");
+    let first = create_issue(&temp, "task", "First");
+    let second = create_issue(&temp, "task", "Second");
+    wk().args(["assign", &first, "alice"]).current_dir(temp.path()).assert().success();
+    wk().args(["assign", &second, "alice"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["start", &first]).current_dir(temp.path()).assert().success();
+    wk().args(["start", &second])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("alice now has 2 issues in progress (limit 1)"));
+    wk().args(["list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Over WIP limit: @alice (2/1)"));
+}
+
+#[test]
+fn start_over_wip_limit_fails_in_block_mode() {
+    let temp = init_temp();
+    add_config(&temp, "\n[wip]\nlimit = 1\nmode = \"block\"\n");
+    let first = create_issue(&temp, "task", "First");
+    let second = create_issue(&temp, "task", "Second");
+    wk().args(["assign", &first, "alice"]).current_dir(temp.path()).assert().success();
+    wk().args(["assign", &second, "alice"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["start", &first]).current_dir(temp.path()).assert().success();
+    wk().args(["start", &second])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("alice is at the WIP limit (1/1 in progress)"));
+    wk().args(["show", &second])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Status: todo"));
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok backup` and `wok restore`.
+
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::common::*;
+
+fn new_task(temp: &TempDir, title: &str) {
+    wk().args(["new", "task", title]).current_dir(temp.path()).assert().success();
+}
+
+fn backups(temp: &TempDir) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(temp.path().join(".wok/backups"))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name != ".gitignore")
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn backup_then_restore_brings_issues_back() {
+    let temp = init_temp();
+    new_task(&temp, "Keep me");
+    let archive = temp.path().join("archive");
+
+    wk().args(["backup", "--to"])
+        .arg(&archive)
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Backed up 1 issue(s) to"));
+    new_task(&temp, "Lose me");
+
+    wk().arg("restore")
+        .arg(&archive)
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Backed up the current state to"))
+        .stdout(predicate::str::contains("Restored 1 issue(s) from"));
+
+    wk().arg("list")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Keep me"))
+        .stdout(predicate::str::contains("Lose me").not());
+
+    // The state the restore replaced was saved and can be restored in turn
+    let previous = backups(&temp);
+    assert_eq!(previous.len(), 1);
+    assert!(previous[0].ends_with("-auto-restore"));
+}
+
+#[test]
+fn backup_defaults_to_the_backups_directory() {
+    let temp = init_temp();
+    new_task(&temp, "Back me up");
+
+    let output = wk().args(["backup", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+    let path = String::from_utf8(output.stdout).unwrap();
+
+    assert!(path.trim().contains(".wok/backups/"));
+    assert_eq!(backups(&temp).len(), 1);
+    assert!(std::path::Path::new(path.trim()).join("manifest.json").is_file());
+}
+
+#[test]
+fn restore_check_rejects_a_tampered_backup() {
+    let temp = init_temp();
+    new_task(&temp, "Original");
+    let archive = temp.path().join("archive");
+    wk().args(["backup", "--to"]).arg(&archive).current_dir(temp.path()).assert().success();
+
+    wk().args(["restore", "--check"])
+        .arg(&archive)
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is a valid backup of 1 issue(s)"));
+
+    std::fs::write(archive.join("issues.db"), "not a database").unwrap();
+
+    wk().arg("restore")
+        .arg(&archive)
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a valid backup"))
+        .stderr(predicate::str::contains("issues.db does not match its checksum"));
+
+    wk().arg("list")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Original"));
+}
+
+#[test]
+fn backup_refuses_an_existing_destination() {
+    let temp = init_temp();
+    let archive = temp.path().join("archive");
+    std::fs::create_dir_all(&archive).unwrap();
+    std::fs::write(archive.join("notes.txt"), "mine").unwrap();
+
+    wk().args(["backup", "--to"])
+        .arg(&archive)
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("backup destination already exists"));
+}
+
+#[test]
+fn restore_is_refused_with_read_only() {
+    let temp = init_temp();
+    let archive = temp.path().join("archive");
+    wk().args(["backup", "--to"]).arg(&archive).current_dir(temp.path()).assert().success();
+
+    wk().args(["--read-only", "restore"])
+        .arg(&archive)
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--read-only"));
+}
+
+#[test]
+fn auto_backup_runs_before_import_and_rename() {
+    let temp = init_temp();
+    new_task(&temp, "Imported twice");
+    wk().args(["config", "set", "backup.auto", "true"]).current_dir(temp.path()).assert().success();
+    let export = temp.path().join("issues.jsonl");
+    wk().arg("export").arg(&export).current_dir(temp.path()).assert().success();
+
+    wk().args(["import", "--dry-run"]).arg(&export).current_dir(temp.path()).assert().success();
+    assert!(!temp.path().join(".wok/backups").exists());
+
+    wk().arg("import").arg(&export).current_dir(temp.path()).assert().success();
+    wk().args(["config", "rename", "test", "demo"]).current_dir(temp.path()).assert().success();
+
+    let names = backups(&temp);
+    assert_eq!(names.len(), 2);
+    assert!(names[0].ends_with("-auto-import"));
+    assert!(names[1].ends_with("-auto-rename"));
+}
+
+#[test]
+fn auto_backups_are_off_by_default() {
+    let temp = init_temp();
+    new_task(&temp, "No backup");
+
+    wk().args(["config", "rename", "test", "demo"]).current_dir(temp.path()).assert().success();
+
+    assert!(!temp.path().join(".wok/backups").exists());
+}
@@ -14,9 +14,12 @@ use assert_cmd::Command;
 pub use predicates::prelude::*;
 pub use tempfile::TempDir;
 
+/// The `wok` binary, with messages in English whatever the caller's locale.
 pub fn wk() -> Command {
     #[allow(deprecated)]
-    Command::cargo_bin("wok").unwrap()
+    let mut cmd = Command::cargo_bin("wok").unwrap();
+    cmd.env_remove("LC_ALL").env_remove("LC_MESSAGES").env_remove("LANG");
+    cmd
 }
 
 /// Helper to create an initialized temp directory in private mode.
@@ -33,6 +36,15 @@ pub fn init_temp() -> TempDir {
     temp
 }
 
+/// Load fixture records (see `wk_testing`) into the project's database, so
+/// tests can give issues distinct ages without sleeping between commands.
+pub fn load_fixture(temp: &TempDir, fixture: &str) {
+    wk_testing::Fixture::parse(fixture)
+        .unwrap()
+        .database_at(&temp.path().join(".wok/issues.db"))
+        .unwrap();
+}
+
 /// Helper to create an initialized temp directory in private mode
 pub fn init_temp_private() -> TempDir {
     let temp = TempDir::new().unwrap();
@@ -171,3 +171,70 @@ fn completion_invalid_shell_fails() {
         .assert()
         .failure();
 }
+
+// =============================================================================
+// Dynamic completion tests
+// =============================================================================
+
+#[yare::parameterized(
+    bash = { "bash" },
+    zsh = { "zsh" },
+    fish = { "fish" },
+)]
+fn completion_dynamic_script_calls_wok(shell: &str) {
+    let output = wk().args(["completion", shell, "--dynamic"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("wok _complete"));
+}
+
+#[test]
+fn completion_dynamic_rejects_other_shells() {
+    wk().args(["completion", "elvish", "--dynamic"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn complete_lists_issue_ids_and_labels() {
+    let temp = tempfile::TempDir::new().unwrap();
+    wk().args(["init", "--prefix", "test", "--private"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    let output = wk()
+        .args(["new", "Fix login", "-l", "auth", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let complete = |words: &[&str]| {
+        let output = wk()
+            .args(["_complete", "--index", &(words.len() - 1).to_string(), "--"])
+            .args(words)
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    assert_eq!(complete(&["wok", "start", "te"]), format!("{}\n", id));
+    assert_eq!(complete(&["wok", "list", "-l", "a"]), "auth\n");
+}
+
+#[test]
+fn complete_outside_project_offers_commands_only() {
+    let temp = tempfile::TempDir::new().unwrap();
+    wk().args(["_complete", "--index", "1", "--", "wok", "star"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("start\n");
+    wk().args(["_complete", "--index", "2", "--", "wok", "start", ""])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("");
+}
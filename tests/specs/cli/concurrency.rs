@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for concurrent invocations on one private database, and for
+//! `--read-only`.
+//!
+//! Each thread runs its own `wok` process, so writers really do race for the
+//! SQLite lock.
+
+#![allow(clippy::unwrap_used)]
+
+use std::thread;
+
+use super::common::*;
+
+const WRITERS: usize = 8;
+const ISSUES_PER_WRITER: usize = 5;
+
+fn ids(temp: &TempDir) -> Vec<String> {
+    let output =
+        wk().args(["list", "--all", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).split_whitespace().map(str::to_string).collect()
+}
+
+#[test]
+fn concurrent_writers_all_succeed() {
+    let temp = init_temp();
+
+    thread::scope(|s| {
+        for writer in 0..WRITERS {
+            let temp = &temp;
+            s.spawn(move || {
+                for n in 0..ISSUES_PER_WRITER {
+                    let title = format!("Writer {} issue {}", writer, n);
+                    wk().args(["new", "task", &title, "--force", "-l", "load"])
+                        .current_dir(temp.path())
+                        .assert()
+                        .success();
+                }
+            });
+        }
+    });
+
+    let mut ids = ids(&temp);
+    assert_eq!(ids.len(), WRITERS * ISSUES_PER_WRITER);
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), WRITERS * ISSUES_PER_WRITER);
+}
+
+#[test]
+fn concurrent_transitions_on_distinct_issues() {
+    let temp = init_temp();
+    let created: Vec<String> = (0..WRITERS)
+        .map(|n| {
+            let output = wk()
+                .args(["new", "task", &format!("Task {}", n), "--force", "-o", "id"])
+                .current_dir(temp.path())
+                .output()
+                .unwrap();
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        })
+        .collect();
+
+    thread::scope(|s| {
+        for id in &created {
+            let temp = &temp;
+            s.spawn(move || {
+                wk().args(["start", id]).current_dir(temp.path()).assert().success();
+                wk().args(["note", id, "working on it"])
+                    .current_dir(temp.path())
+                    .assert()
+                    .success();
+                wk().args(["done", id]).current_dir(temp.path()).assert().success();
+            });
+        }
+    });
+
+    let output =
+        wk().args(["list", "-s", "done", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).split_whitespace().count(), WRITERS);
+}
+
+#[test]
+fn read_only_readers_run_alongside_writers() {
+    let temp = init_temp();
+    wk().args(["new", "task", "Seed issue", "--force"]).current_dir(temp.path()).assert().success();
+
+    thread::scope(|s| {
+        for writer in 0..WRITERS / 2 {
+            let temp = &temp;
+            s.spawn(move || {
+                for n in 0..ISSUES_PER_WRITER {
+                    let title = format!("Writer {} issue {}", writer, n);
+                    wk().args(["new", "task", &title, "--force"])
+                        .current_dir(temp.path())
+                        .assert()
+                        .success();
+                }
+            });
+        }
+        for _ in 0..WRITERS / 2 {
+            let temp = &temp;
+            s.spawn(move || {
+                for _ in 0..ISSUES_PER_WRITER {
+                    wk().args(["--read-only", "list"])
+                        .current_dir(temp.path())
+                        .assert()
+                        .success()
+                        .stdout(predicate::str::contains("Seed issue"));
+                    wk().args(["search", "Seed", "--read-only"])
+                        .current_dir(temp.path())
+                        .assert()
+                        .success()
+                        .stdout(predicate::str::contains("Seed issue"));
+                }
+            });
+        }
+    });
+
+    assert_eq!(ids(&temp).len(), 1 + WRITERS / 2 * ISSUES_PER_WRITER);
+}
+
+#[test]
+fn read_only_show_works() {
+    let temp = init_temp();
+    let output = wk()
+        .args(["new", "task", "Look at me", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    wk().args(["--read-only", "show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Look at me"));
+}
+
+#[test]
+fn read_only_refuses_writes() {
+    let temp = init_temp();
+    let output = wk()
+        .args(["new", "task", "Leave me be", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    wk().args(["--read-only", "new", "task", "Another"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("opened with --read-only"))
+        .stderr(predicate::str::contains("hint: drop --read-only"));
+    wk().args(["start", &id, "--read-only"]).current_dir(temp.path()).assert().failure().code(2);
+
+    assert_eq!(ids(&temp), vec![id.clone()]);
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("todo"));
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok daemon health`, recovery from an unclean shutdown,
+//! and the service commands.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+#[test]
+fn health_without_daemon_passes() {
+    let state = TempDir::new().unwrap();
+
+    wk().args(["daemon", "health"])
+        .env("WOK_STATE_DIR", state.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("not held (daemon not running)"))
+        .stdout(predicate::str::contains("database"));
+}
+
+#[test]
+fn health_removes_stale_pid_file_and_socket() {
+    let state = TempDir::new().unwrap();
+    std::fs::write(state.path().join("daemon.pid"), "999999999").unwrap();
+    std::fs::write(state.path().join("daemon.sock"), "").unwrap();
+
+    wk().args(["daemon", "health"])
+        .env("WOK_STATE_DIR", state.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("removed stale pid file for PID 999999999"))
+        .stdout(predicate::str::contains("removed stale socket"));
+
+    assert!(!state.path().join("daemon.pid").exists());
+    assert!(!state.path().join("daemon.sock").exists());
+}
+
+/// Start a daemon for `state`, SIGKILL it, and wait for it to die; returns
+/// its PID.
+fn start_and_kill_daemon(state: &TempDir) -> String {
+    wk().args(["daemon", "start"]).env("WOK_STATE_DIR", state.path()).assert().success();
+    let pid = std::fs::read_to_string(state.path().join("daemon.pid")).unwrap();
+    let pid = pid.trim().to_string();
+    let kill = |signal: &str| {
+        std::process::Command::new("kill")
+            .args([signal, &pid])
+            .stderr(std::process::Stdio::null())
+            .status()
+            .unwrap()
+            .success()
+    };
+    assert!(kill("-9"));
+    while kill("-0") {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    pid
+}
+
+#[test]
+fn start_takes_over_after_sigkill() {
+    let state = TempDir::new().unwrap();
+    let old = start_and_kill_daemon(&state);
+    assert!(state.path().join("daemon.sock").exists());
+
+    wk().args(["daemon", "start"])
+        .env("WOK_STATE_DIR", state.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Daemon started"));
+
+    let new = std::fs::read_to_string(state.path().join("daemon.pid")).unwrap();
+    assert_ne!(new.trim(), old);
+    wk().args(["daemon", "stop"]).env("WOK_STATE_DIR", state.path()).assert().success();
+}
+
+#[test]
+fn wokd_logs_why_it_took_over() {
+    use std::io::BufRead;
+
+    let state = TempDir::new().unwrap();
+    let old = start_and_kill_daemon(&state);
+
+    // Started directly, as a service manager would, with the files left over
+    #[allow(deprecated)]
+    let wokd = assert_cmd::cargo::cargo_bin("wokd");
+    let mut child = std::process::Command::new(wokd)
+        .arg("--state-dir")
+        .arg(state.path())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut ready = String::new();
+    std::io::BufReader::new(child.stdout.take().unwrap()).read_line(&mut ready).unwrap();
+    assert_eq!(ready.trim(), "READY");
+
+    wk().args(["daemon", "stop"]).env("WOK_STATE_DIR", state.path()).assert().success();
+    child.wait().unwrap();
+    let log = std::fs::read_to_string(state.path().join("daemon.log")).unwrap();
+    assert!(
+        log.contains(&format!(
+            "taking over after an unclean shutdown: PID {} in the pid file is not running",
+            old
+        )),
+        "{}",
+        log
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn install_service_print_shows_user_unit() {
+    let state = TempDir::new().unwrap();
+
+    wk().args(["daemon", "install-service", "--user", "--print"])
+        .env("WOK_STATE_DIR", state.path())
+        .env("WOK_DAEMON_BINARY", "/opt/wok/wokd")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ExecStart=/opt/wok/wokd --state-dir"))
+        .stdout(predicate::str::contains("WantedBy=default.target"));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn uninstall_service_without_unit_is_a_no_op() {
+    let config = TempDir::new().unwrap();
+
+    wk().args(["daemon", "uninstall-service", "--user"])
+        .env("XDG_CONFIG_HOME", config.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No systemd user unit installed"));
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok digest` command.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str, extra: &[&str]) -> String {
+    let output = wk()
+        .args(["new", "task", title, "-o", "id"])
+        .args(extra)
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn digest_prints_every_section_as_markdown() {
+    let temp = init_temp();
+    let done = create_issue(&temp, "Shipped it", &[]);
+    wk().args(["start", &done]).current_dir(temp.path()).assert().success();
+    wk().args(["done", &done]).current_dir(temp.path()).assert().success();
+    let blocker = create_issue(&temp, "Blocker", &[]);
+    let blocked = create_issue(&temp, "Waiting", &[]);
+    wk().args(["dep", &blocker, "blocks", &blocked]).current_dir(temp.path()).assert().success();
+
+    wk().args(["digest", "--since", "1w"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("# Digest: "))
+        .stdout(predicate::str::contains(format!("## Completed (1)\n\n- `{}` Shipped it\n", done)))
+        .stdout(predicate::str::contains("## Created (3)"))
+        .stdout(predicate::str::contains("## Stalled (0)\n\n_None_\n"))
+        .stdout(predicate::str::contains(format!(
+            "- `{}` Waiting - blocked by {}\n",
+            blocked, blocker
+        )))
+        .stdout(predicate::str::contains("## Due soon (0)"));
+}
+
+#[test]
+fn digest_json_lists_due_issues() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Long overdue", &["-l", "due:2020-01-01"]);
+    create_issue(&temp, "Due far ahead", &["-l", "due:2999-01-01"]);
+    create_issue(&temp, "No date", &[]);
+
+    let output = wk().args(["digest", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["due"].as_array().unwrap().len(), 1);
+    assert_eq!(json["due"][0]["id"], id);
+    assert_eq!(json["due"][0]["due"], "2020-01-01");
+    assert_eq!(json["created"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn digest_filters_by_assignee() {
+    let temp = init_temp();
+    let mine = create_issue(&temp, "Team task", &["-a", "team"]);
+    let other = create_issue(&temp, "Other task", &[]);
+
+    wk().args(["digest", "-a", "team"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(team)"))
+        .stdout(predicate::str::contains(&mine))
+        .stdout(predicate::str::contains(&other).not());
+}
+
+#[test]
+fn digest_rejects_a_bad_since() {
+    let temp = init_temp();
+    wk().args(["digest", "--since", "soon"]).current_dir(temp.path()).assert().failure();
+}
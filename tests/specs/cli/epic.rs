@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for automatic epic promotion of large trackers.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, args: &[&str]) -> String {
+    let output =
+        wk().arg("new").args(args).args(["-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Append lines to `.wok/config.toml`.
+fn add_config(temp: &TempDir, lines: &str) {
+    let path = temp.path().join(".wok").join("config.toml");
+    let mut config = std::fs::read_to_string(&path).unwrap();
+    config.push_str(lines);
+    std::fs::write(&path, config).unwrap();
+}
+
+fn show_json(temp: &TempDir, id: &str) -> serde_json::Value {
+    let output = wk().args(["show", id, "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn dep_suggests_epic_by_default() {
+    let temp = init_temp();
+    add_config(&temp, "\n[epic]\nthreshold = 2\n");
+    let parent = create_issue(&temp, &["feature", "Parent"]);
+    let a = create_issue(&temp, &["task", "A"]);
+    let b = create_issue(&temp, &["task", "B"]);
+
+    wk().args(["dep", &parent, "tracks", &a, &b])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("tracks 2 issues"))
+        .stderr(predicate::str::contains("type epic"));
+    assert_eq!(show_json(&temp, &parent)["issue_type"], "feature");
+}
+
+#[test]
+fn dep_auto_epic_flag_converts_and_logs() {
+    let temp = init_temp();
+    add_config(&temp, "\n[epic]\nauto = \"off\"\nthreshold = 2\n");
+    let parent = create_issue(&temp, &["feature", "Parent"]);
+    let a = create_issue(&temp, &["task", "A"]);
+    let b = create_issue(&temp, &["task", "B"]);
+
+    wk().args(["dep", &parent, "tracks", &a, &b, "--auto-epic"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Converted"));
+    assert_eq!(show_json(&temp, &parent)["issue_type"], "epic");
+    wk().args(["log", &parent])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("epic"));
+}
+
+#[test]
+fn new_tracked_by_converts_when_configured() {
+    let temp = init_temp();
+    add_config(&temp, "\n[epic]\nauto = \"convert\"\nthreshold = 2\n");
+    let parent = create_issue(&temp, &["feature", "Parent"]);
+
+    create_issue(&temp, &["task", "A", "--tracked-by", &parent]);
+    assert_eq!(show_json(&temp, &parent)["issue_type"], "feature");
+
+    wk().args(["new", "task", "B", "--tracked-by", &parent])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Converted"));
+    assert_eq!(show_json(&temp, &parent)["issue_type"], "epic");
+}
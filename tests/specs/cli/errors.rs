@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `--error-format json` / `WOK_ERROR_FORMAT`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn stderr_json(output: &std::process::Output) -> serde_json::Value {
+    serde_json::from_slice(&output.stderr).unwrap()
+}
+
+#[test]
+fn not_found_reports_code_and_ids() {
+    let temp = init_temp();
+
+    let output = wk()
+        .args(["show", "test-missing", "--error-format", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(3));
+    let json = stderr_json(&output);
+    assert_eq!(json["code"], "not_found");
+    assert_eq!(json["ids"], serde_json::json!(["test-missing"]));
+    assert!(json["message"].as_str().unwrap().contains("issue not found"));
+}
+
+#[test]
+fn env_var_selects_json() {
+    let temp = init_temp();
+
+    let output = wk()
+        .args(["list", "--type", "nope"])
+        .env("WOK_ERROR_FORMAT", "json")
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let json = stderr_json(&output);
+    assert_eq!(json["code"], "invalid_argument");
+    // The hint line moves out of the message into suggestions
+    assert!(!json["message"].as_str().unwrap().contains("hint"));
+    assert!(json["suggestions"][0].as_str().unwrap().contains("valid types"));
+}
+
+#[test]
+fn flag_overrides_env() {
+    let temp = init_temp();
+
+    wk().args(["show", "test-missing", "--error-format", "text"])
+        .env("WOK_ERROR_FORMAT", "json")
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with("error: issue not found"));
+}
+
+#[test]
+fn uninitialized_directory_has_its_own_code() {
+    let temp = TempDir::new().unwrap();
+
+    let output = wk()
+        .args(["list", "--error-format", "json"])
+        .current_dir(temp.path())
+        .env("WOK_STATE_DIR", temp.path())
+        .output()
+        .unwrap();
+
+    let json = stderr_json(&output);
+    assert_eq!(json["code"], "not_initialized");
+}
+
+#[test]
+fn usage_errors_are_json_too() {
+    let temp = init_temp();
+
+    let output = wk()
+        .args(["list", "--bogus", "--error-format=json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let json = stderr_json(&output);
+    assert_eq!(json["code"], "usage");
+    assert!(json["message"].as_str().unwrap().contains("--bogus"));
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for issue estimates and `wok ready --capacity`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str, args: &[&str]) -> String {
+    let output = wk()
+        .args(["new", "task", title, "-o", "id"])
+        .args(args)
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn new_and_edit_set_the_estimate_label() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Sized", &["--estimate", "3pt"]);
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("estimate:3pt"));
+
+    wk().args(["edit", &id, "estimate", "1.5d"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated estimate of"));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("Labels: estimate:12h\n"));
+
+    wk().args(["list", "--columns", "title,estimate"])
+        .current_dir(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("Sized  12h"));
+}
+
+#[test]
+fn invalid_estimate_is_rejected() {
+    let temp = init_temp();
+    wk().args(["new", "task", "Sized", "--estimate", "lots"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid estimate 'lots'"));
+}
+
+#[test]
+fn ready_capacity_fills_budget_by_priority() {
+    let temp = init_temp();
+    let big = create_issue(&temp, "Big", &["--estimate", "5pt"]);
+    let urgent = create_issue(&temp, "Urgent", &["--estimate", "3pt", "-l", "priority:0"]);
+    let small = create_issue(&temp, "Small", &["--estimate", "2pt"]);
+    create_issue(&temp, "Unsized", &[]);
+
+    wk().args(["ready", "--capacity", "5pt", "--all-assignees"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{}: Urgent [3pt]", urgent)))
+        .stdout(predicate::str::contains(format!("{}: Small [2pt]", small)))
+        .stdout(predicate::str::contains(&big).not())
+        .stdout(predicate::str::contains(
+            "(5pt of 5pt planned; 1 ready without an estimate to plan by)",
+        ));
+}
+
+#[test]
+fn tree_sums_open_tracked_estimates() {
+    let temp = init_temp();
+    let epic =
+        wk().args(["new", "epic", "Launch", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    let epic = String::from_utf8_lossy(&epic.stdout).trim().to_string();
+    create_issue(&temp, "One", &["--estimate", "2d", "--tracked-by", &epic]);
+    create_issue(&temp, "Two", &["--estimate", "4h", "--tracked-by", &epic]);
+
+    wk().args(["tree", &epic])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Open estimate: 20h across 2 issues"));
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for distinct exit codes and `--quiet`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn missing_issue_exits_3() {
+    let temp = init_temp();
+
+    wk().args(["done", "test-missing"]).current_dir(temp.path()).assert().code(3);
+}
+
+#[test]
+fn invalid_input_exits_2() {
+    let temp = init_temp();
+
+    wk().args(["new", "nonsense", "Title"]).current_dir(temp.path()).assert().code(2);
+}
+
+#[test]
+fn refused_by_workflow_exits_4() {
+    let temp = init_temp();
+
+    wk().args(["queue", "take", "merge", "--assignee", "alice"])
+        .current_dir(temp.path())
+        .assert()
+        .code(4);
+}
+
+#[test]
+fn uninitialized_directory_exits_7() {
+    let temp = TempDir::new().unwrap();
+
+    wk().args(["list"]).env("WOK_STATE_DIR", temp.path()).current_dir(temp.path()).assert().code(7);
+}
+
+#[test]
+fn quiet_suppresses_stdout() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Quiet task");
+
+    wk().args(["--quiet", "start", &id]).current_dir(temp.path()).assert().success().stdout("");
+
+    wk().args(["list", "--quiet"]).current_dir(temp.path()).assert().success().stdout("");
+}
+
+#[test]
+fn quiet_keeps_errors_on_stderr() {
+    let temp = init_temp();
+
+    wk().args(["--quiet", "show", "test-missing"])
+        .current_dir(temp.path())
+        .assert()
+        .code(3)
+        .stdout("")
+        .stderr(predicate::str::contains("issue not found"));
+}
@@ -74,6 +74,22 @@ fn export_requires_filepath() {
     wk().arg("export").current_dir(temp.path()).assert().failure();
 }
 
+#[test]
+fn export_ndjson_streams_records_to_stdout() {
+    let temp = init_temp();
+    create_issue(&temp, "task", "ExportStream first");
+    create_issue(&temp, "bug", "ExportStream second");
+
+    let output = wk().args(["export", "-o", "ndjson"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let records: Vec<serde_json::Value> =
+        stdout.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|r| r["events"].is_array() && r["labels"].is_array()));
+}
+
 // =============================================================================
 // Export includes issue data
 // =============================================================================
@@ -212,3 +228,90 @@ fn export_accepts_dotdot_path() {
 
     assert!(temp.path().join("export2.jsonl").exists(), "Export should resolve .. in path");
 }
+
+// =============================================================================
+// Round trip through import
+// =============================================================================
+
+/// Export records keyed by issue ID, without database-assigned row IDs.
+fn export_records(temp: &TempDir) -> Vec<serde_json::Value> {
+    let output = wk().args(["export", "-o", "ndjson"]).current_dir(temp.path()).output().unwrap();
+    let mut records: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    for record in &mut records {
+        for key in ["notes", "events", "links"] {
+            for row in record[key].as_array_mut().unwrap() {
+                row.as_object_mut().unwrap().remove("id");
+            }
+        }
+    }
+    records.sort_by_key(|r| r["id"].as_str().unwrap().to_string());
+    records
+}
+
+#[test]
+fn export_import_round_trip_reproduces_history() {
+    let temp = init_temp();
+    let feature = create_issue(&temp, "feature", "RoundTrip feature");
+    let task = create_issue_with_opts(&temp, "task", "RoundTrip task", &["--label", "team:a"]);
+    wk().args(["dep", &feature, "tracks", &task]).current_dir(temp.path()).assert().success();
+    wk().args(["start", &task]).current_dir(temp.path()).assert().success();
+    wk().args(["note", &task, "Halfway there"]).current_dir(temp.path()).assert().success();
+    wk().args(["done", &task]).current_dir(temp.path()).assert().success();
+    let export_path = temp.path().join("export.jsonl");
+    wk().arg("export").arg(&export_path).current_dir(temp.path()).assert().success();
+
+    let fresh = init_temp();
+    wk().arg("import").arg(&export_path).current_dir(fresh.path()).assert().success();
+
+    let records = export_records(&fresh);
+    assert_eq!(records, export_records(&temp));
+    assert_eq!(records[0]["schema_version"], 2);
+    assert!(records.iter().any(|r| r["events"].as_array().unwrap().len() > 2));
+}
+
+#[test]
+fn export_bd_writes_beads_records_that_import_back() {
+    let temp = init_temp();
+    let blocker = create_issue_with_opts(&temp, "bug", "ExportBd blocker", &["--label", "p:1"]);
+    let blocked = create_issue(&temp, "task", "ExportBd blocked");
+    wk().args(["dep", &blocker, "blocks", &blocked]).current_dir(temp.path()).assert().success();
+    wk().args(["close", &blocked, "--reason", "not needed"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let output = wk()
+        .args(["export", "--format", "bd", "-o", "ndjson"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let records: Vec<serde_json::Value> =
+        stdout.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    let bug = records.iter().find(|r| r["id"] == blocker.as_str()).unwrap();
+    assert_eq!(bug["status"], "open");
+    assert_eq!(bug["priority"], 1);
+    let task = records.iter().find(|r| r["id"] == blocked.as_str()).unwrap();
+    assert_eq!(task["status"], "closed");
+    assert_eq!(task["close_reason"], "wontfix: not needed");
+    assert_eq!(task["dependencies"][0]["depends_on_id"], blocker.as_str());
+    assert_eq!(task["dependencies"][0]["type"], "blocks");
+
+    let export_path = temp.path().join("beads.jsonl");
+    fs::write(&export_path, stdout.as_bytes()).unwrap();
+    let target = init_temp();
+    wk().args(["import", "--format", "bd"])
+        .arg(&export_path)
+        .current_dir(target.path())
+        .assert()
+        .success();
+    wk().args(["show", &blocked])
+        .current_dir(target.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("closed"));
+}
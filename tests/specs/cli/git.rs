@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok branch`, `wok pr` and the git hooks.
+
+#![allow(clippy::unwrap_used)]
+
+use std::process::Command;
+
+use super::common::*;
+
+fn git(temp: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(temp.path())
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// A tracker in a fresh git repository with one commit.
+fn init_repo() -> TempDir {
+    let temp = init_temp();
+    git(&temp, &["init", "-q", "-b", "main"]);
+    git(&temp, &["commit", "-q", "--allow-empty", "-m", "Initial commit"]);
+    temp
+}
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn branch_creates_switches_and_links() {
+    let temp = init_repo();
+    let id = create_issue(&temp, "Fix login page");
+    let name = format!("task/{}-fix-login-page", id);
+
+    wk().args(["branch", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Created branch {}", name)))
+        .stdout(predicate::str::contains(format!("Linked {} to {}", name, id)));
+    assert_eq!(git(&temp, &["branch", "--show-current"]), name);
+
+    git(&temp, &["switch", "-q", "main"]);
+    wk().args(["branch", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Switched to branch {}\n", name));
+    assert_eq!(git(&temp, &["branch", "--show-current"]), name);
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("branch://{}", name)));
+}
+
+#[test]
+fn branch_template_comes_from_config() {
+    let temp = init_repo();
+    wk().args(["config", "set", "git.branch", "{id}"]).current_dir(temp.path()).assert().success();
+    let id = create_issue(&temp, "Anything");
+
+    wk().args(["branch", &id]).current_dir(temp.path()).assert().success();
+    assert_eq!(git(&temp, &["branch", "--show-current"]), id);
+}
+
+#[test]
+fn branch_outside_a_repository_fails() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "No repo");
+    wk().args(["branch", &id])
+        .current_dir(temp.path())
+        .env("GIT_CEILING_DIRECTORIES", temp.path().parent().unwrap())
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("git switch"));
+}
+
+#[test]
+fn hooks_tag_commits_and_note_them() {
+    let temp = init_repo();
+    let id = create_issue(&temp, "Fix login page");
+    wk().args(["hooks", "install", "git"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Installed git hooks"));
+    wk().args(["branch", &id]).current_dir(temp.path()).assert().success();
+
+    git(&temp, &["commit", "-q", "--allow-empty", "-m", "Check the password"]);
+
+    let message = git(&temp, &["log", "-1", "--format=%B"]);
+    assert_eq!(message, format!("Check the password\n\nRefs: {}", id));
+    let sha = git(&temp, &["rev-parse", "--short=7", "HEAD"]);
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Commit {}: Check the password", sha)));
+
+    wk().args(["hooks", "uninstall", "git"]).current_dir(temp.path()).assert().success();
+    assert!(!temp.path().join(".git/hooks/commit-msg").exists());
+}
+
+#[test]
+fn hooks_install_keeps_foreign_hooks() {
+    let temp = init_repo();
+    let hook = temp.path().join(".git/hooks/commit-msg");
+    std::fs::write(&hook, "#!/bin/sh\nexit 0\n").unwrap();
+
+    wk().args(["hooks", "install", "git"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("is not a wok hook"));
+    assert_eq!(std::fs::read_to_string(&hook).unwrap(), "#!/bin/sh\nexit 0\n");
+    assert!(!temp.path().join(".git/hooks/post-commit").exists());
+}
+
+#[test]
+fn pr_describe_prints_the_body() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Fix login page");
+    wk().args(["note", &id, "--kind", "decision", "Keep the old endpoint"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["pr", "describe", &id]).current_dir(temp.path()).assert().success().stdout(format!(
+        "Fix login page\n\n### Decisions\n\n- Keep the old endpoint\n\nCloses {}\n",
+        id
+    ));
+
+    std::fs::create_dir_all(temp.path().join(".wok/templates")).unwrap();
+    std::fs::write(temp.path().join(".wok/templates/pr.md"), "{{title}}\n{{closes}}\n").unwrap();
+    wk().args(["pr", "describe", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Fix login page\nCloses {}\n", id));
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the issue hook run log (`wok hook runs`, `wok hook test --run`).
+
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn write_hooks(temp: &TempDir) {
+    std::fs::write(
+        temp.path().join(".wok/hooks.toml"),
+        r#"
+[[hooks]]
+name = "ok"
+events = ["issue.created"]
+run = "cat > /dev/null"
+
+[[hooks]]
+name = "broken"
+events = ["issue.created"]
+run = "echo 'cannot reach server' >&2; exit 3"
+"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn failing_hook_warns_but_mutation_succeeds() {
+    let temp = init_temp();
+    write_hooks(&temp);
+
+    wk().args(["new", "task", "Task"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("hook 'broken' failed: exit code 3"));
+}
+
+#[test]
+fn runs_lists_every_execution() {
+    let temp = init_temp();
+    write_hooks(&temp);
+    let id = create_issue(&temp, "Task");
+
+    wk().args(["hook", "runs"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ok"))
+        .stdout(predicate::str::contains("broken"))
+        .stdout(predicate::str::contains(&id));
+}
+
+#[test]
+fn runs_failed_shows_exit_code_and_stderr() {
+    let temp = init_temp();
+    write_hooks(&temp);
+    create_issue(&temp, "Task");
+
+    let output = wk().args(["hook", "runs", "--failed"]).current_dir(temp.path()).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("broken"));
+    assert!(stdout.contains("exit code 3"));
+    assert!(stdout.contains("cannot reach server"));
+    assert!(!stdout.contains("  ok  "));
+}
+
+#[test]
+fn runs_json_output() {
+    let temp = init_temp();
+    write_hooks(&temp);
+    create_issue(&temp, "Task");
+
+    let output = wk()
+        .args(["hook", "runs", "--failed", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let runs: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(runs.as_array().unwrap().len(), 1);
+    assert_eq!(runs[0]["hook_name"], "broken");
+    assert_eq!(runs[0]["exit_code"], 3);
+}
+
+#[test]
+fn runs_empty() {
+    let temp = init_temp();
+
+    wk().args(["hook", "runs"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No hook runs recorded."));
+}
+
+#[test]
+fn test_run_fires_hook_and_reports_failure() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Task");
+    write_hooks(&temp);
+
+    wk().args(["hook", "test", "broken", &id, "--event", "done", "--run"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("cannot reach server"))
+        .stderr(predicate::str::contains("hook 'broken' failed: exit code 3"));
+
+    wk().args(["hook", "runs", "-o", "json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("issue.done"));
+}
+
+#[test]
+fn test_run_succeeds_for_passing_hook() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Task");
+    write_hooks(&temp);
+
+    wk().args(["hook", "test", "ok", &id, "--run"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fired hook 'ok'"));
+}
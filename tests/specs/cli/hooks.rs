@@ -747,3 +747,85 @@ fn detects_wk_prime_with_args() {
     let count = content.matches("PreCompact").count();
     assert_eq!(count, 1, "Should only have one PreCompact entry, got content: {}", content);
 }
+
+// =============================================================================
+// Context Injection and Failure Notes
+// =============================================================================
+
+#[test]
+fn install_with_features_adds_handler_hooks() {
+    let temp = TempDir::new().unwrap();
+
+    wk().args(["hooks", "install", "-y", "--context", "--auto-note"])
+        .current_dir(temp.path())
+        .env("HOME", temp.path())
+        .assert()
+        .success();
+
+    let content = std::fs::read_to_string(temp.path().join(".claude/settings.local.json")).unwrap();
+    let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(settings["hooks"]["SessionStart"].as_array().unwrap().len(), 2);
+    assert_eq!(settings["hooks"]["PostToolUse"][0]["matcher"], "Bash");
+    assert!(content.contains("wk hooks context"));
+    assert!(content.contains("wk hooks note-failure"));
+
+    wk().args(["hooks", "uninstall", "local"])
+        .current_dir(temp.path())
+        .env("HOME", temp.path())
+        .assert()
+        .success();
+    assert!(!temp.path().join(".claude/settings.local.json").exists());
+}
+
+#[test]
+fn context_lists_in_progress_and_ready_issues() {
+    let temp = init_temp();
+    let output =
+        wk().args(["new", "task", "Doing", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    let doing = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    wk().args(["start", &doing]).current_dir(temp.path()).assert().success();
+    wk().args(["new", "task", "Next up"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["hooks", "context"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("In progress:"))
+        .stdout(predicate::str::contains("Doing"))
+        .stdout(predicate::str::contains("Ready:"))
+        .stdout(predicate::str::contains("Next up"));
+}
+
+#[test]
+fn context_outside_project_prints_nothing() {
+    let temp = TempDir::new().unwrap();
+
+    wk().args(["hooks", "context"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn note_failure_notes_in_progress_issue() {
+    let temp = init_temp();
+    let output =
+        wk().args(["new", "task", "Doing", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+
+    let payload = r#"{"tool_name":"Bash","tool_input":{"command":"make check"},"tool_response":{"exit_code":2,"stderr":"lint failed"}}"#;
+    wk().args(["hooks", "note-failure"])
+        .current_dir(temp.path())
+        .write_stdin(payload)
+        .assert()
+        .success();
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Shell command failed (exit 2): `make check`"))
+        .stdout(predicate::str::contains("lint failed"));
+}
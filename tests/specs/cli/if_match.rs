@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for compare-and-set changes: issue revisions and `--if-match`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, type_: &str, title: &str) -> String {
+    let output =
+        wk().args(["new", type_, title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn revision(temp: &TempDir, id: &str) -> u64 {
+    let output = wk().args(["show", id, "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    json["revision"].as_u64().unwrap()
+}
+
+#[test]
+fn changes_bump_the_revision() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Counted");
+    assert_eq!(revision(&temp, &id), 1);
+
+    wk().args(["edit", &id, "title", "Renamed"]).current_dir(temp.path()).assert().success();
+    wk().args(["label", &id, "ui"]).current_dir(temp.path()).assert().success();
+    wk().args(["note", &id, "Notes leave it alone"]).current_dir(temp.path()).assert().success();
+
+    assert_eq!(revision(&temp, &id), 3);
+}
+
+#[test]
+fn matching_revision_applies_the_change() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Mine");
+
+    wk().args(["start", &id, "--if-match", "1"]).current_dir(temp.path()).assert().success();
+    wk().args(["edit", &id, "title", "Still mine", "--if-match", "2"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    assert_eq!(revision(&temp, &id), 3);
+}
+
+#[test]
+fn stale_revision_fails_with_a_conflict() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Contended");
+    wk().args(["edit", &id, "title", "Theirs"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["edit", &id, "title", "Mine", "--if-match", "1"])
+        .current_dir(temp.path())
+        .assert()
+        .code(8)
+        .stderr(predicate::str::contains("expected revision 1, found 2"));
+    wk().args(["done", &id, "--if-match", "1", "--error-format", "json"])
+        .current_dir(temp.path())
+        .assert()
+        .code(8)
+        .stderr(predicate::str::contains("\"code\":\"conflict\""));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Theirs"))
+        .stdout(predicate::str::contains("Status: todo"));
+}
+
+#[test]
+fn if_match_takes_one_issue() {
+    let temp = init_temp();
+    let a = create_issue(&temp, "task", "One");
+    let b = create_issue(&temp, "task", "Two");
+
+    wk().args(["start", &a, &b, "--if-match", "1"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--if-match cannot be used with several issues"));
+}
@@ -25,6 +25,10 @@
 //!   -> import_bd_close_reason_* tests
 //! - "import beads format converts priority and preserves comments"
 //!   -> import_bd_priority, import_bd_no_priority_zero, import_bd_comment
+//! - "import beads history, assignee, and compatibility report"
+//!   -> import_bd_history_and_assignee, import_bd_reports_dropped_fields
+//! - "import GitLab and Gitea API dumps"
+//!   -> import_gitlab_pages_with_links, import_gitea_skips_pull_requests
 //! - "import rejects -i and -p shorthands"
 //!   -> parameterized import_rejects_shorthand tests
 
@@ -657,6 +661,120 @@ fn import_bd_comment() {
         .stdout(predicate::str::contains("This is a comment from beads"));
 }
 
+#[test]
+fn import_bd_history_and_assignee() {
+    let temp = init_temp();
+    write_jsonl(
+        &temp,
+        "import.jsonl",
+        r#"{"id":"bd-hist","title":"With history","status":"in_progress","priority":2,"issue_type":"task","assignee":"ann","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-02T00:00:00Z","events":[{"event_type":"created","created_at":"2024-01-01T00:00:00Z"},{"event_type":"status_changed","old_value":"open","new_value":"in_progress","created_at":"2024-01-02T00:00:00Z"}]}"#,
+    );
+
+    wk().args(["import", "--format", "bd", "import.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Compatibility report").not());
+
+    wk().args(["show", "bd-hist"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ann"))
+        .stdout(predicate::str::contains("started"));
+}
+
+#[test]
+fn import_bd_reports_dropped_fields() {
+    let temp = init_temp();
+    write_jsonl(
+        &temp,
+        "import.jsonl",
+        r#"{"id":"bd-drop","title":"Estimated","status":"open","priority":0,"issue_type":"task","estimated_minutes":45,"created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}"#,
+    );
+
+    wk().args(["import", "--format", "bd", "--dry-run", "import.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Compatibility report: 2 item(s) not carried over:",
+        ))
+        .stderr(predicate::str::contains(
+            "bd-drop: field 'estimated_minutes'",
+        ))
+        .stderr(predicate::str::contains("bd-drop: priority 0"));
+}
+
+// =============================================================================
+// GitLab and Gitea API dumps
+// =============================================================================
+
+#[test]
+fn import_gitlab_pages_with_links() {
+    let temp = init_temp();
+    write_jsonl(
+        &temp,
+        "gitlab.json",
+        r#"[
+  {"iid":1,"project_id":7,"title":"Schema","state":"closed","labels":["db"],"created_at":"2026-03-01T10:00:00Z","updated_at":"2026-03-02T10:00:00Z","web_url":"https://gitlab.com/acme/app/-/issues/1","references":{"full":"acme/app#1"},"links":[{"iid":2,"project_id":7,"link_type":"blocks"}]}
+]
+[
+  {"iid":2,"project_id":7,"title":"Migrate","state":"opened","issue_type":"incident","assignee":{"username":"ann"},"created_at":"2026-03-01T11:00:00Z","updated_at":"2026-03-01T11:00:00Z","web_url":"https://gitlab.com/acme/app/-/issues/2","references":{"full":"acme/app#2"}}
+]
+"#,
+    );
+
+    wk().args(["import", "--format", "gitlab", "gitlab.json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create: 2"));
+
+    wk().args(["show", "test-2"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Migrate"))
+        .stdout(predicate::str::contains("test-1"))
+        .stdout(predicate::str::contains(
+            "https://gitlab.com/acme/app/-/issues/2",
+        ));
+
+    // A second import of the same dump finds the issues again
+    wk().args(["import", "--format", "gitlab", "gitlab.json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("update: 2"));
+}
+
+#[test]
+fn import_gitea_skips_pull_requests() {
+    let temp = init_temp();
+    write_jsonl(
+        &temp,
+        "gitea.json",
+        r#"[{"number":3,"title":"Crash","body":"Panics","state":"open","labels":[{"name":"bug"}],"created_at":"2026-03-01T10:00:00Z","updated_at":"2026-03-01T10:00:00Z","html_url":"https://git.example.com/acme/app/issues/3","repository":{"full_name":"acme/app"},"pull_request":null},{"number":4,"title":"Fix crash","state":"open","created_at":"2026-03-01T10:00:00Z","updated_at":"2026-03-01T10:00:00Z","pull_request":{"merged":false}}]"#,
+    );
+
+    wk().args(["import", "--format", "gitea", "gitea.json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create: 1"));
+
+    wk().args(["show", "test-3"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[gitea]"));
+    wk().args(["show", "test-4"])
+        .current_dir(temp.path())
+        .assert()
+        .failure();
+}
+
 // =============================================================================
 // Phase 11: Shorthand Rejection Tests
 // From: "import rejects -i and -p shorthands"
@@ -699,3 +817,269 @@ fn import_prefix_short_flag_p_accepted() {
         .assert()
         .success();
 }
+
+// =============================================================================
+// Conflict strategies
+// =============================================================================
+
+const BEADS_LINE: &str = r#"{"id":"bd-re1","title":"Beads task","status":"open","issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}"#;
+
+#[test]
+fn import_on_conflict_skip_reports_skipped() {
+    let temp = init_temp();
+    write_jsonl(&temp, "beads.jsonl", BEADS_LINE);
+    wk().args(["import", "--format", "bd", "beads.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create: 1"));
+
+    wk().args([
+        "import",
+        "--format",
+        "bd",
+        "--on-conflict",
+        "skip",
+        "beads.jsonl",
+    ])
+    .current_dir(temp.path())
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("skip: 1"))
+    .stdout(predicate::str::contains("create").not());
+
+    wk().args(["show", "bd-re1"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[beads] bd-re1 (import)"));
+}
+
+#[test]
+fn import_on_conflict_duplicate_creates_copy() {
+    let temp = init_temp();
+    write_jsonl(&temp, "beads.jsonl", BEADS_LINE);
+    for _ in 0..2 {
+        wk().args([
+            "import",
+            "--format",
+            "bd",
+            "--on-conflict",
+            "duplicate",
+            "beads.jsonl",
+        ])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create: 1"));
+    }
+
+    wk().args(["list", "--all", "-p", "bd", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::function(|out: &str| {
+            out.split_whitespace().count() == 2
+        }));
+}
+
+// =============================================================================
+// Field mapping files
+// =============================================================================
+
+const TRACKER_MAP: &str = r#"
+prefix = "eng"
+
+[fields]
+id = "key"
+title = "summary"
+status = "state.name"
+type = "kind"
+assignee = "owner"
+labels = "tags"
+notes = "comments[].body"
+
+[status]
+"In Review" = "in_progress"
+
+[type]
+Story = "feature"
+
+[assignee]
+"jdoe@example.com" = "john"
+
+[labels]
+prefix = "tracker:"
+"#;
+
+#[test]
+fn import_map_converts_records() {
+    let temp = init_temp();
+    write_jsonl(&temp, "map.toml", TRACKER_MAP);
+    write_jsonl(
+        &temp,
+        "tracker.jsonl",
+        r#"{"key":"ENG-12","summary":"Fix login","state":{"name":"In Review"},"kind":"Story","owner":"jdoe@example.com","tags":["auth"],"comments":[{"body":"Repro attached"}]}"#,
+    );
+
+    wk().args(["import", "--map", "map.toml", "tracker.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create: 1"));
+
+    wk().args(["show", "eng-12"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fix login"))
+        .stdout(predicate::str::contains("in_progress"))
+        .stdout(predicate::str::contains("feature"))
+        .stdout(predicate::str::contains("john"))
+        .stdout(predicate::str::contains("tracker:auth"))
+        .stdout(predicate::str::contains("Repro attached"));
+}
+
+#[test]
+fn import_map_reports_unmapped_values() {
+    let temp = init_temp();
+    write_jsonl(&temp, "map.toml", TRACKER_MAP);
+    write_jsonl(
+        &temp,
+        "tracker.jsonl",
+        r#"{"key":"ENG-13","summary":"Triage me","state":{"name":"Triage"}}"#,
+    );
+
+    wk().args(["import", "--map", "map.toml", "tracker.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("line 1"))
+        .stderr(predicate::str::contains("unknown status 'Triage'"));
+}
+
+#[test]
+fn import_map_rejects_invalid_mapping() {
+    let temp = init_temp();
+    write_jsonl(&temp, "map.toml", "[fields]\nid = \"key\"\n");
+    write_jsonl(&temp, "tracker.jsonl", r#"{"key":"ENG-1"}"#);
+
+    wk().args(["import", "--map", "map.toml", "tracker.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid mapping file map.toml"));
+}
+
+#[test]
+fn import_map_conflicts_with_format() {
+    let temp = init_temp();
+    write_jsonl(&temp, "map.toml", TRACKER_MAP);
+
+    wk().args(["import", "--map", "map.toml", "--format", "bd", "x.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+// =============================================================================
+// CSV import
+// =============================================================================
+
+#[test]
+fn import_csv_creates_issues_from_rows() {
+    let temp = init_temp();
+    write_jsonl(
+        &temp,
+        "backlog.csv",
+        "Title,Type,Status,Labels,Assignee,Description\n\
+         Fix login,bug,in progress,backend;auth,alice,\"Users are logged out, again\"\n\
+         Write docs,chore,todo,,,\n",
+    );
+
+    wk().args(["import", "backlog.csv"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create: 2"));
+
+    wk().args(["list", "--label", "auth"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fix login"))
+        .stdout(predicate::str::contains("alice"));
+}
+
+#[test]
+fn import_csv_rejects_unknown_columns_with_hint() {
+    let temp = init_temp();
+    write_jsonl(&temp, "backlog.csv", "Title,Priority\nShip it,high\n");
+
+    wk().args(["import", "backlog.csv"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown CSV columns: Priority"))
+        .stderr(predicate::str::contains("--column 'Priority=FIELD'"));
+}
+
+#[test]
+fn import_csv_column_maps_unknown_headers() {
+    let temp = init_temp();
+    write_jsonl(&temp, "backlog.txt", "Task,Priority\nShip it,high\n");
+
+    wk().args([
+        "import",
+        "--format",
+        "csv",
+        "--column",
+        "Task=title",
+        "--column",
+        "Priority=label:priority:",
+        "backlog.txt",
+    ])
+    .current_dir(temp.path())
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("create: 1"));
+
+    wk().args(["list", "--label", "priority:high"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Ship it"));
+}
+
+#[test]
+fn import_csv_dry_run_previews_table() {
+    let temp = init_temp();
+    write_jsonl(&temp, "backlog.csv", "Title,Type\nShip it,feature\n");
+
+    wk().args(["import", "--dry-run", "backlog.csv"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ACTION  ID"))
+        .stdout(
+            predicate::str::is_match(r"create  test-[0-9a-f]+  feature  todo    Ship it").unwrap(),
+        );
+
+    wk().args(["list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Ship it").not());
+}
+
+#[test]
+fn import_column_requires_csv() {
+    let temp = init_temp();
+
+    wk().args(["import", "--column", "Task=title", "issues.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--column"));
+}
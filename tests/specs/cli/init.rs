@@ -443,3 +443,117 @@ fn defaults_to_user_level_mode() {
     // No local database in user-level mode
     assert!(!temp.path().join(".wok/issues.db").exists());
 }
+
+// =============================================================================
+// Init with import
+// =============================================================================
+
+const BEADS_EXPORT: &str = concat!(
+    r#"{"id":"bd-a1","title":"First","status":"open","issue_type":"task","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}"#,
+    "\n",
+    r#"{"id":"bd-b2","title":"Second","status":"open","issue_type":"bug","created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z","dependencies":[{"depends_on_id":"bd-a1","type":"blocks"}]}"#,
+    "\n",
+);
+
+#[test]
+fn from_imports_and_renames_ids_to_prefix() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("beads.jsonl"), BEADS_EXPORT).unwrap();
+
+    wk().args(["init", "--private", "--prefix", "proj", "--from", "beads.jsonl", "--format", "bd"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed 2 imported IDs from bd- to proj-"))
+        .stdout(predicate::str::contains("create: 2"));
+
+    wk().args(["show", "proj-b2"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("proj-a1"));
+}
+
+#[test]
+fn from_with_keep_ids_imports_unchanged() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("beads.jsonl"), BEADS_EXPORT).unwrap();
+
+    wk().args(["init", "--private", "--prefix", "proj", "--from", "beads.jsonl"])
+        .args(["--format", "bd", "--keep-ids"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed").not());
+    wk().args(["show", "bd-a1"]).current_dir(temp.path()).assert().success();
+}
+
+#[test]
+fn from_with_bad_file_initializes_nothing() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("bad.jsonl"), "not json\n").unwrap();
+
+    wk().args(["init", "--private", "--prefix", "proj", "--from", "bad.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .failure();
+    assert!(!temp.path().join(".wok").exists());
+}
+
+#[test]
+fn keep_ids_requires_from() {
+    let temp = TempDir::new().unwrap();
+    wk().args(["init", "--private", "--keep-ids"]).current_dir(temp.path()).assert().failure();
+}
+
+#[test]
+fn join_shares_the_workspace_database() {
+    let temp = TempDir::new().unwrap();
+    let state = TempDir::new().unwrap();
+    let mono = temp.path().join("mono");
+    let app = temp.path().join("app");
+    std::fs::create_dir_all(&mono).unwrap();
+    let wk_in = |dir: &std::path::Path, args: &[&str]| {
+        let mut cmd = wk();
+        cmd.args(args).env("WOK_STATE_DIR", state.path()).current_dir(dir);
+        cmd.assert()
+    };
+
+    wk_in(&mono, &["init", "--private", "--prefix", "mono"]).success();
+    wk_in(temp.path(), &["init", "--path", "app", "--join", "mono", "--prefix", "app"])
+        .success()
+        .stdout(predicate::str::contains("Joined workspace ws-"));
+    assert!(!app.join(".wok/issues.db").exists());
+
+    let output = wk_in(&app, &["new", "task", "From the app", "-o", "id"]).success();
+    let id = String::from_utf8_lossy(&output.get_output().stdout).trim().to_string();
+    assert!(id.starts_with("app-"));
+    wk_in(&mono, &["show", &id]).success();
+
+    wk_in(&app, &["config", "workspaces"])
+        .success()
+        .stdout(predicate::str::contains("mono (root)"))
+        .stdout(predicate::str::contains("  app\n"));
+
+    // Joining by ID, without a prefix, takes the root's.
+    let output = wk_in(&mono, &["config", "workspaces", "-o", "json"]).success();
+    let json: serde_json::Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+    let ws = json["id"].as_str().unwrap().to_string();
+    std::fs::create_dir_all(temp.path().join("lib")).unwrap();
+    wk_in(&temp.path().join("lib"), &["init", "--join", &ws])
+        .success()
+        .stdout(predicate::str::contains("Prefix: mono (from the workspace root)"));
+    assert_eq!(json["projects"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn join_unknown_workspace_fails() {
+    let temp = TempDir::new().unwrap();
+    wk().args(["init", "--join", "ws-00000000"])
+        .env("WOK_STATE_DIR", temp.path())
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("workspace not found: ws-00000000"));
+    assert!(!temp.path().join(".wok").exists());
+}
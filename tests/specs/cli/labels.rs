@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok labels` registry commands.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_labeled(temp: &TempDir, title: &str, labels: &[&str]) -> String {
+    let mut args = vec!["new", "task", title, "-o", "id"];
+    for label in labels {
+        args.extend(["-l", label]);
+    }
+    let output = wk().args(&args).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn labels(temp: &TempDir, args: &[&str]) -> assert_cmd::assert::Assert {
+    wk().arg("labels").args(args).env("NO_COLOR", "1").current_dir(temp.path()).assert()
+}
+
+#[test]
+fn list_shows_counts_and_descriptions() {
+    let temp = init_temp();
+    create_labeled(&temp, "One", &["team:alpha", "bug"]);
+    create_labeled(&temp, "Two", &["team:alpha"]);
+
+    labels(&temp, &["describe", "team:alpha", "Platform team"])
+        .success()
+        .stdout("Described team:alpha: Platform team\n");
+
+    labels(&temp, &["list"]).success().stdout("bug         1\nteam:alpha  2  Platform team\n");
+    labels(&temp, &["list", "--namespace", "team", "-o", "id"]).success().stdout("team:alpha\n");
+}
+
+#[test]
+fn rename_updates_every_issue() {
+    let temp = init_temp();
+    let first = create_labeled(&temp, "One", &["team:alpha"]);
+    let second = create_labeled(&temp, "Two", &["team:alpha"]);
+
+    labels(&temp, &["rename", "team:alpha", "team:platform"])
+        .success()
+        .stdout("Renamed team:alpha to team:platform on 2 issue(s)\n");
+
+    for id in [&first, &second] {
+        wk().args(["show", id])
+            .current_dir(temp.path())
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Labels: team:platform"));
+    }
+    wk().args(["log", &first])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("team:alpha"));
+
+    labels(&temp, &["rename", "team:", "squad:"])
+        .success()
+        .stdout(predicate::str::contains("Renamed namespace team: to squad:"));
+    labels(&temp, &["list", "-o", "id"]).success().stdout("squad:platform\n");
+}
+
+#[test]
+fn rename_unused_label_fails() {
+    let temp = init_temp();
+
+    labels(&temp, &["rename", "nope", "other"])
+        .failure()
+        .stderr(predicate::str::contains("cannot rename nope to other"));
+}
+
+#[test]
+fn colors_paint_labels_in_list_and_show() {
+    let temp = init_temp();
+    let id = create_labeled(&temp, "One", &["team:alpha", "bug"]);
+
+    labels(&temp, &["colors", "team:", "cyan"]).success().stdout("Colored team: cyan\n");
+    labels(&temp, &["colors"]).success().stdout("team:  cyan\n");
+    labels(&temp, &["colors", "bug", "teal"])
+        .failure()
+        .stderr(predicate::str::contains("invalid label color: 'teal'"));
+
+    wk().args(["show", &id])
+        .env("COLOR", "1")
+        .env_remove("NO_COLOR")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[38;5;6mteam:alpha\x1b[0m"));
+    wk().args(["list", "--columns", "id,labels"])
+        .env("COLOR", "1")
+        .env_remove("NO_COLOR")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[38;5;6mteam:alpha\x1b[0m"));
+    wk().args(["show", &id])
+        .env("NO_COLOR", "1")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+fn declare_keys(temp: &TempDir) {
+    let path = temp.path().join(".wok/config.toml");
+    let mut config = std::fs::read_to_string(&path).unwrap();
+    config.push_str(
+        "\n[labels.keys.priority]\ntype = \"int\"\nmin = 0\nmax = 2\n\n\
+         [labels.keys.team]\nvalues = [\"alpha\", \"beta\"]\n",
+    );
+    std::fs::write(&path, config).unwrap();
+}
+
+#[test]
+fn declared_keys_reject_invalid_labels() {
+    let temp = init_temp();
+    declare_keys(&temp);
+    let id = create_labeled(&temp, "One", &["team:alpha"]);
+
+    wk().args(["label", &id, "team:Beta"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid label 'team:Beta': 'Beta' is not one of alpha, beta",
+        ))
+        .stderr(predicate::str::contains("did you mean 'team:beta'?"));
+    wk().args(["new", "task", "Two", "--priority", "3"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("3 is above the maximum 2"));
+    wk().args(["label", &id, "priority:2", "urgent"]).current_dir(temp.path()).assert().success();
+}
+
+#[test]
+fn audit_fix_remaps_nonconforming_labels() {
+    let temp = init_temp();
+    let id = create_labeled(&temp, "One", &["team:Alpha", "team:gamma"]);
+    declare_keys(&temp);
+
+    labels(&temp, &["audit"]).success().stdout(
+        "team:Alpha: 'Alpha' is not one of alpha, beta (1 issue(s))\n  fix: team:alpha\n\
+         team:gamma: 'gamma' is not one of alpha, beta (1 issue(s))\n",
+    );
+    labels(&temp, &["audit", "--fix"]).success().stdout(
+        "Renamed team:Alpha to team:alpha on 1 issue(s)\n\
+         team:gamma: 'gamma' is not one of alpha, beta (1 issue(s))\n",
+    );
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("team:alpha"));
+}
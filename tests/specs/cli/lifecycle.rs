@@ -916,3 +916,42 @@ fn lenient_transition(
             expected_state
         )));
 }
+
+// =============================================================================
+// Unblocked Notifications
+// =============================================================================
+
+#[test]
+fn done_reports_issues_it_unblocks() {
+    let temp = init_temp();
+    let blocker = create_issue(&temp, "task", "Blocker");
+    let waiting = create_issue(&temp, "task", "Waiting");
+    let other = create_issue(&temp, "task", "Other blocker");
+    let still = create_issue(&temp, "task", "Still blocked");
+    wk().args(["dep", &blocker, "blocks", &waiting, &still])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["dep", &other, "blocks", &still]).current_dir(temp.path()).assert().success();
+
+    wk().args(["done", &blocker, "--reason", "shipped"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("unblocked: {} 'Waiting'", waiting)))
+        .stdout(predicate::str::contains(&still).not());
+}
+
+#[test]
+fn close_reports_issues_it_unblocks() {
+    let temp = init_temp();
+    let blocker = create_issue(&temp, "task", "Blocker");
+    let waiting = create_issue(&temp, "task", "Waiting");
+    wk().args(["dep", &blocker, "blocks", &waiting]).current_dir(temp.path()).assert().success();
+
+    wk().args(["close", &blocker, "--reason", "wontfix"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("unblocked: {} 'Waiting'", waiting)));
+}
@@ -501,3 +501,50 @@ fn log_shows_unlinked_event() {
         .success()
         .stdout(predicate::str::contains("unlinked"));
 }
+
+#[test]
+fn link_restore_brings_back_an_unlinked_url() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Link Restore Test");
+    let url = "https://github.com/org/repo/issues/123";
+
+    wk().args(["link", &id, url, "--reason", "blocks"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["unlink", &id, url]).current_dir(temp.path()).assert().success();
+    wk().args(["ready"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&id));
+
+    wk().args(["link", "restore", &id, url])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Restored link"));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(url));
+    wk().args(["ready"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&id).not());
+}
+
+#[test]
+fn link_restore_without_a_removed_link_says_so() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Link Restore Nothing");
+
+    wk().args(["link", "restore", &id, "https://example.com/never"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No removed link"));
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok link refresh`. Nothing here reaches a provider: the
+//! links are ones it skips or cannot ask about without config.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn link(temp: &TempDir, id: &str, url: &str) {
+    wk().args(["link", id, url]).current_dir(temp.path()).assert().success();
+}
+
+#[test]
+fn refresh_without_provider_links_says_so() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Docs only");
+    link(&temp, &id, "https://acme.atlassian.net/wiki/spaces/DOC/pages/123");
+
+    wk().args(["link", "refresh", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No GitHub, GitLab or Jira links to refresh\n");
+}
+
+#[test]
+fn refresh_reports_links_it_cannot_fetch() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Tracked in Jira");
+    link(&temp, &id, "jira://PE-5555");
+
+    wk().args(["link", "refresh", "--all"])
+        .current_dir(temp.path())
+        .assert()
+        .code(6)
+        .stderr(predicate::str::contains("warning: cannot fetch jira://PE-5555"))
+        .stderr(predicate::str::contains("[api.jira]"));
+
+    // Until a refresh succeeds the link shows as it was added
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[jira] jira://PE-5555"));
+}
+
+#[test]
+fn refresh_needs_ids_or_all() {
+    let temp = init_temp();
+
+    wk().args(["link", "refresh"]).current_dir(temp.path()).assert().failure();
+}
+
+#[test]
+fn link_still_adds_links() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Linked");
+    link(&temp, &id, "https://github.com/org/repo/issues/1");
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[github] https://github.com/org/repo/issues/1"));
+}
@@ -29,6 +29,15 @@ fn init_temp() -> TempDir {
     temp
 }
 
+/// Load fixture records (see `wk_testing`) into the project's database, so
+/// tests can give issues distinct ages without sleeping between commands.
+fn load_fixture(temp: &TempDir, fixture: &str) {
+    wk_testing::Fixture::parse(fixture)
+        .unwrap()
+        .database_at(&temp.path().join(".wok/issues.db"))
+        .unwrap();
+}
+
 fn create_issue(temp: &TempDir, type_: &str, title: &str) -> String {
     create_issue_with_opts(temp, type_, title, &[])
 }
@@ -477,9 +486,13 @@ fn list_sorts_by_priority_asc() {
 #[test]
 fn list_same_priority_newer_first() {
     let temp = init_temp();
-    create_issue(&temp, "task", "SortList2 Older");
-    std::thread::sleep(std::time::Duration::from_millis(100));
-    create_issue(&temp, "task", "SortList2 Newer");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "SortList2 Older" created=-1m
+        issue test-new task "SortList2 Newer"
+        "#,
+    );
 
     let output = wk().arg("list").current_dir(temp.path()).output().unwrap();
 
@@ -537,9 +550,13 @@ fn list_prefers_priority_over_p() {
 #[test]
 fn list_filter_age_less_than() {
     let temp = init_temp();
-    create_issue(&temp, "task", "AgeFilter Old");
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    create_issue(&temp, "task", "AgeFilter New");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "AgeFilter Old" created=-1h
+        issue test-new task "AgeFilter New"
+        "#,
+    );
 
     wk().arg("list")
         .arg("--filter")
@@ -554,9 +571,13 @@ fn list_filter_age_less_than() {
 #[test]
 fn list_filter_age_gte() {
     let temp = init_temp();
-    create_issue(&temp, "task", "AgeFilter2 Old");
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    create_issue(&temp, "task", "AgeFilter2 New");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "AgeFilter2 Old" created=-1h
+        issue test-new task "AgeFilter2 New"
+        "#,
+    );
 
     wk().arg("list")
         .arg("--filter")
@@ -1006,9 +1027,13 @@ fn list_filter_accepts_now() {
 #[test]
 fn list_filter_word_lt() {
     let temp = init_temp();
-    create_issue(&temp, "task", "WordOp Old");
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    create_issue(&temp, "task", "WordOp New");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "WordOp Old" created=-1h
+        issue test-new task "WordOp New"
+        "#,
+    );
 
     wk().arg("list")
         .arg("--filter")
@@ -1023,9 +1048,13 @@ fn list_filter_word_lt() {
 #[test]
 fn list_filter_word_gte() {
     let temp = init_temp();
-    create_issue(&temp, "task", "WordOp2 Old");
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    create_issue(&temp, "task", "WordOp2 New");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "WordOp2 Old" created=-1h
+        issue test-new task "WordOp2 New"
+        "#,
+    );
 
     wk().arg("list")
         .arg("--filter")
@@ -1040,9 +1069,13 @@ fn list_filter_word_gte() {
 #[test]
 fn list_filter_word_gt() {
     let temp = init_temp();
-    create_issue(&temp, "task", "WordOp3 Old");
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    create_issue(&temp, "task", "WordOp3 New");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "WordOp3 Old" created=-1h
+        issue test-new task "WordOp3 New"
+        "#,
+    );
 
     wk().arg("list")
         .arg("--filter")
@@ -1358,3 +1391,234 @@ fn list_output_ids_clean_format() {
         assert!(re.is_match(word), "ID format should be alphanumeric with hyphens: {}", word);
     }
 }
+
+// Column layout and paging
+
+#[test]
+fn list_truncates_lines_to_columns_width() {
+    let temp = init_temp();
+    create_issue(&temp, "task", "A rather long title that will not fit in a narrow terminal");
+
+    let output = wk().arg("list").env("COLUMNS", "40").current_dir(temp.path()).output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout.lines().next().unwrap();
+    assert_eq!(line.chars().count(), 40);
+    assert!(line.ends_with('…'));
+}
+
+#[test]
+fn list_without_width_keeps_full_titles() {
+    let temp = init_temp();
+    let title = "A rather long title that will not fit in a narrow terminal";
+    create_issue(&temp, "task", title);
+
+    wk().arg("list")
+        .env_remove("COLUMNS")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(title));
+}
+
+#[test]
+fn list_columns_selects_and_orders_fields() {
+    let temp = init_temp();
+    let id = create_issue_with_opts(&temp, "bug", "Columns Bug", &["--label", "backend"]);
+
+    let output = wk()
+        .args(["list", "--columns", "title,id,labels"])
+        .env_remove("COLUMNS")
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), format!("Columns Bug  {}  backend", id));
+}
+
+#[test]
+fn list_pager_is_skipped_when_not_a_terminal() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Paged Issue");
+
+    wk().args(["list", "--pager"])
+        .env("PAGER", "false")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(id));
+}
+
+// Grouped output
+
+#[test]
+fn list_group_by_status_shows_headers_with_counts() {
+    let temp = init_temp();
+    create_issue(&temp, "task", "Todo One");
+    create_issue(&temp, "task", "Todo Two");
+    let started = create_issue(&temp, "task", "Started One");
+    wk().args(["start", &started]).current_dir(temp.path()).assert().success();
+
+    let output =
+        wk().args(["list", "--group-by", "status"]).current_dir(temp.path()).output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let todo = stdout.find("todo (2)").expect("todo header");
+    let in_progress = stdout.find("in_progress (1)").expect("in_progress header");
+    assert!(todo < in_progress);
+    assert!(stdout[in_progress..].contains("Started One"));
+}
+
+#[test]
+fn list_group_by_json_nests_issues_in_groups() {
+    let temp = init_temp();
+    create_issue_with_opts(&temp, "task", "Backend", &["--label", "mod:backend"]);
+    create_issue_with_opts(&temp, "task", "Both", &["--label", "mod:cli", "--label", "area:ux"]);
+    create_issue(&temp, "task", "Unlabeled");
+
+    let output = wk()
+        .args(["list", "--group-by", "label-prefix", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let groups: Vec<(&str, u64)> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|g| (g["group"].as_str().unwrap(), g["count"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(groups, vec![("area", 1), ("mod", 2), ("(none)", 1)]);
+    assert_eq!(json[2]["issues"][0]["title"], "Unlabeled");
+}
+
+// Filter combinators
+
+#[test]
+fn list_filter_combines_attribute_fields() {
+    let temp = init_temp();
+    create_issue_with_opts(&temp, "task", "Urgent Mine", &["--label", "urgent", "-a", "alice"]);
+    create_issue_with_opts(&temp, "task", "Urgent Theirs", &["--label", "urgent", "-a", "bob"]);
+    create_issue_with_opts(&temp, "bug", "Top Priority", &["--label", "priority:0"]);
+    create_issue(&temp, "task", "Plain");
+
+    wk().args(["list", "-q", "label = urgent and assignee != alice or priority <= 1"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Urgent Theirs"))
+        .stdout(predicate::str::contains("Top Priority"))
+        .stdout(predicate::str::contains("Urgent Mine").not())
+        .stdout(predicate::str::contains("Plain").not());
+}
+
+#[test]
+fn list_filter_status_replaces_open_default() {
+    let temp = init_temp();
+    let closed = create_issue(&temp, "task", "Closed One");
+    create_issue(&temp, "task", "Open One");
+    wk().args(["close", &closed, "--reason", "obsolete"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["list", "-q", "status in (closed, done)"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Closed One"))
+        .stdout(predicate::str::contains("Open One").not());
+}
+
+#[test]
+fn list_filter_rejects_unbalanced_parentheses() {
+    let temp = init_temp();
+
+    wk().args(["list", "-q", "(label = a or label = b"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unbalanced parentheses"));
+}
+
+#[test]
+fn list_json_paged_walks_all_issues_with_cursor() {
+    let temp = init_temp();
+    for title in ["Paged One", "Paged Two", "Paged Three"] {
+        create_issue(&temp, "task", title);
+    }
+
+    let page = |cursor: Option<&str>| -> serde_json::Value {
+        let mut args = vec!["list", "-o", "json-paged", "--page-size", "2"];
+        if let Some(cursor) = cursor {
+            args.extend(["--cursor", cursor]);
+        }
+        let output = wk().args(&args).current_dir(temp.path()).output().unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    let first = page(None);
+    let titles: Vec<&str> =
+        first["issues"].as_array().unwrap().iter().map(|i| i["title"].as_str().unwrap()).collect();
+    assert_eq!(titles, vec!["Paged One", "Paged Two"]);
+
+    let second = page(first["next_cursor"].as_str());
+    let titles: Vec<&str> =
+        second["issues"].as_array().unwrap().iter().map(|i| i["title"].as_str().unwrap()).collect();
+    assert_eq!(titles, vec!["Paged Three"]);
+    assert!(second["next_cursor"].is_null());
+}
+
+#[test]
+fn list_json_paged_rejects_sort() {
+    let temp = init_temp();
+
+    wk().args(["list", "-o", "json-paged", "--sort", "title"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--sort cannot be used with -o json-paged"));
+}
+
+#[test]
+fn list_cursor_requires_json_paged() {
+    let temp = init_temp();
+
+    wk().args(["list", "--cursor", "1:prj-1"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("-o json-paged is required for --cursor"));
+}
+
+#[test]
+fn list_ndjson_prints_one_issue_per_line() {
+    let temp = init_temp();
+    create_issue(&temp, "task", "Stream One");
+    create_issue(&temp, "task", "Stream Two");
+
+    let output = wk().args(["list", "-o", "ndjson"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let titles: Vec<String> = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .map(|issue| issue["title"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(titles, vec!["Stream Two", "Stream One"]);
+}
+
+#[test]
+fn list_ndjson_rejects_group_by() {
+    let temp = init_temp();
+
+    wk().args(["list", "-o", "ndjson", "--group-by", "status"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--group-by cannot be used with -o ndjson"));
+}
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for translated output: the `locale` setting and `LANG`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn lang_selects_german() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Fix login");
+
+    wk().args(["show", &id])
+        .env("LANG", "de_DE.UTF-8")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Titel: Fix login"))
+        .stdout(predicate::str::contains("Status: todo"));
+
+    wk().args(["start", &id])
+        .env("LANG", "de_DE.UTF-8")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Begonnen: {}\n", id));
+}
+
+#[test]
+fn lc_all_overrides_lang() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Fix login");
+
+    wk().args(["show", &id])
+        .env("LANG", "de_DE.UTF-8")
+        .env("LC_ALL", "C")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Title: Fix login"));
+}
+
+#[test]
+fn unknown_language_falls_back_to_english() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Fix login");
+
+    wk().args(["show", &id])
+        .env("LANG", "fr_FR.UTF-8")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Title: Fix login"));
+}
+
+#[test]
+fn locale_setting_overrides_lang() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Fix login");
+
+    wk().args(["config", "set", "locale", "de"]).current_dir(temp.path()).assert().success();
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Titel: Fix login"));
+
+    wk().args(["config", "set", "locale", "en"]).current_dir(temp.path()).assert().success();
+    wk().args(["show", &id])
+        .env("LANG", "de_DE.UTF-8")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Title: Fix login"));
+}
+
+#[test]
+fn locale_setting_rejects_unknown_language() {
+    let temp = init_temp();
+
+    wk().args(["config", "set", "locale", "fr"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'fr' is not one of en, de"));
+}
+
+#[test]
+fn errors_are_translated() {
+    let temp = init_temp();
+
+    wk().args(["show", "test-zzzz"])
+        .env("LANG", "de_DE.UTF-8")
+        .current_dir(temp.path())
+        .assert()
+        .code(3)
+        .stderr("Fehler: Issue nicht gefunden: test-zzzz\n");
+}
+
+#[test]
+fn json_errors_split_translated_hints() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Fix login");
+
+    let output = wk()
+        .args(["dep", &id, "blocks", &id, "--error-format", "json"])
+        .env("LANG", "de_DE.UTF-8")
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(report["code"], "self_dependency");
+    assert_eq!(
+        report["suggestions"][0],
+        "ein Issue kann sich nicht selbst blockieren oder verfolgen"
+    );
+}
+
+#[test]
+fn help_is_translated() {
+    wk().arg("--help")
+        .env("LANG", "de_DE.UTF-8")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Issue-Verwaltung:"))
+        .stdout(predicate::str::contains("new            Neues Issue anlegen"))
+        .stdout(predicate::str::contains("Verwendung: wok"))
+        .stdout(predicate::str::contains("Optionen:"));
+}
@@ -251,3 +251,21 @@ fn log_shows_close_reason(reason: &str) {
         .stdout(predicate::str::contains("closed"))
         .stdout(predicate::str::contains(reason));
 }
+
+#[test]
+fn log_ndjson_prints_one_event_per_line() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "LogStream task");
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+
+    let output = wk().args(["log", &id, "-o", "ndjson"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let actions: Vec<String> = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .map(|event| event["action"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(actions, vec!["started", "created"]);
+}
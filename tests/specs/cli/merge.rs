@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok merge` command.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create(temp: &TempDir, title: &str, opts: &[&str]) -> String {
+    let output = wk()
+        .args(["new", "task", title, "--force", "-o", "id"])
+        .args(opts)
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().last().unwrap_or_default().to_string()
+}
+
+#[test]
+fn merge_moves_everything_and_closes_the_source() {
+    let temp = init_temp();
+    let keep = create(&temp, "Fix login crash", &[]);
+    let blocked = create(&temp, "Ship release", &[]);
+    let dup = create(
+        &temp,
+        "Fix crash on login",
+        &["-l", "auth", "--note", "Seen on Safari", "--blocks", &blocked],
+    );
+
+    wk().args(["merge", &dup, &keep])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Merged {} into {}", dup, keep)))
+        .stdout(predicate::str::contains(format!("blocks {}", blocked)));
+
+    wk().args(["show", &keep])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("auth"))
+        .stdout(predicate::str::contains("Seen on Safari"))
+        .stdout(predicate::str::contains(&blocked));
+    wk().args(["show", &dup])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("closed"))
+        .stdout(predicate::str::contains(format!("duplicate of {}", keep)));
+}
+
+#[test]
+fn merge_dry_run_changes_nothing() {
+    let temp = init_temp();
+    let keep = create(&temp, "Fix login crash", &[]);
+    let dup = create(&temp, "Fix crash on login", &["-l", "auth"]);
+
+    let output = wk()
+        .args(["merge", &dup, &keep, "--dry-run", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let merged: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(merged["labels"], serde_json::json!(["auth"]));
+    assert_eq!(merged["closed"], true);
+
+    wk().args(["show", &dup])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("todo"))
+        .stdout(predicate::str::contains("auth"));
+}
+
+#[test]
+fn merge_into_a_closed_issue_fails() {
+    let temp = init_temp();
+    let keep = create(&temp, "Fix login crash", &[]);
+    let dup = create(&temp, "Fix crash on login", &[]);
+    wk().args(["close", &keep, "--reason", "wontfix"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["merge", &dup, &keep])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("the target is closed"));
+    wk().args(["merge", &dup, &dup])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be merged into itself"));
+}
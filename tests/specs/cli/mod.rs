@@ -1,19 +1,58 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+mod activity;
+mod actor;
+mod aging;
+mod alias;
+mod assign;
+mod backup;
 mod common;
+mod concurrency;
+mod daemon;
+mod digest;
 mod edge_cases;
 mod edit;
+mod epic;
+mod errors;
+mod estimate;
+mod exit_codes;
 mod export;
 mod flags;
+mod git;
 mod help;
+mod hook_runs;
 mod hooks;
+mod if_match;
+mod import;
 mod init;
 mod integration;
+mod labels;
+mod lifecycle;
+mod link_refresh;
+mod locale;
 mod log;
+mod merge;
 mod new;
+mod next;
+mod note;
+mod open;
+mod order;
+mod prime;
+mod queue;
+mod ready;
+mod rpc;
+mod rules;
 mod search;
+mod serve;
+mod settings;
+mod short_ids;
 mod show;
+mod split;
+mod standup;
+mod subscribe;
+mod sweep;
 mod sync;
 mod tree;
+mod upgrade;
 mod version;
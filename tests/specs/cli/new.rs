@@ -562,3 +562,81 @@ fn new_prefix_with_other_flags() {
         .success()
         .stdout(predicate::str::contains("urgent"));
 }
+
+// =============================================================================
+// Duplicate Detection Tests
+// =============================================================================
+
+#[test]
+fn new_warns_about_similar_open_issues() {
+    let temp = init_temp();
+    let first = create_issue_with_opts(&temp, "bug", "Fix login crash", &[]);
+
+    wk().args(["new", "bug", "Fix crash on login"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created [bug]"))
+        .stderr(predicate::str::contains("warning: 1 similar issue exists"))
+        .stderr(predicate::str::contains(&first));
+}
+
+#[test]
+fn new_force_skips_the_similarity_check() {
+    let temp = init_temp();
+    create_issue_with_opts(&temp, "bug", "Fix login crash", &[]);
+
+    wk().args(["new", "bug", "Fix crash on login", "--force"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn new_ignores_closed_and_unrelated_issues() {
+    let temp = init_temp();
+    let closed = create_issue_with_opts(&temp, "bug", "Fix login crash", &[]);
+    wk().args(["close", &closed, "--reason", "fixed"]).current_dir(temp.path()).assert().success();
+    create_issue_with_opts(&temp, "task", "Write release notes", &[]);
+
+    wk().args(["new", "bug", "Fix crash on login"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn dedupe_lists_pairs_and_merges_nothing() {
+    let temp = init_temp();
+    let keep = create_issue_with_opts(&temp, "bug", "Fix login crash", &[]);
+    let duplicate = create_issue_with_opts(&temp, "bug", "Fix crash on login", &["--force"]);
+    create_issue_with_opts(&temp, "task", "Write release notes", &[]);
+
+    let output = wk().args(["dedupe", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+    let pairs: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let pairs = pairs.as_array().unwrap();
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0]["keep"], keep.as_str());
+    assert_eq!(pairs[0]["duplicate"], duplicate.as_str());
+
+    wk().arg("dedupe")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&duplicate))
+        .stdout(predicate::str::contains("wok dedupe -i"));
+    wk().args(["show", &duplicate])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("todo"));
+}
+
+#[test]
+fn dedupe_interactive_requires_a_terminal() {
+    let temp = init_temp();
+    wk().args(["dedupe", "-i"]).current_dir(temp.path()).assert().failure();
+}
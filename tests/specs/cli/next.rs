@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok next` command.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, args: &[&str]) -> String {
+    let output =
+        wk().arg("new").args(args).args(["-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn next_picks_highest_priority_without_changing_it() {
+    let temp = init_temp();
+    create_issue(&temp, &["task", "Routine"]);
+    let urgent = create_issue(&temp, &["task", "Urgent", "-l", "priority:0"]);
+
+    wk().args(["next", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", urgent));
+    wk().args(["show", &urgent])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Status: todo"));
+}
+
+#[test]
+fn next_claim_starts_and_assigns() {
+    let temp = init_temp();
+    let first = create_issue(&temp, &["task", "First"]);
+    let second = create_issue(&temp, &["task", "Second"]);
+
+    wk().args(["next", "--claim", "-a", "agent-1", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", first));
+    wk().args(["next", "--claim", "-a", "agent-2", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", second));
+    wk().args(["show", &first])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Status: in_progress"))
+        .stdout(predicate::str::contains("Assignee: agent-1"));
+}
+
+#[test]
+fn next_with_nothing_ready() {
+    let temp = init_temp();
+
+    wk().args(["next"]).current_dir(temp.path()).assert().success().stdout("No ready issues\n");
+    wk().args(["next", "-o", "json"]).current_dir(temp.path()).assert().success().stdout("null\n");
+}
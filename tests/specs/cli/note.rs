@@ -246,3 +246,298 @@ fn note_semantic_labels_by_status(
         .stdout(predicate::str::contains(expected_label))
         .stdout(predicate::str::contains(note_text));
 }
+
+// =============================================================================
+// Note Kind Tests
+// =============================================================================
+
+#[parameterized(
+    decision = { "decision", "Decisions:" },
+    blocker = { "blocker", "Blockers:" },
+    worklog = { "worklog", "Worklog:" },
+    handoff = { "handoff", "Handoff:" },
+)]
+fn note_kind_gets_own_section_in_show(kind: &str, expected_label: &str) {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "NoteKind Test task");
+
+    wk().args(["note", &id, "--kind", kind, "Kinded note"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Added {} note", kind)));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{}\n", expected_label)))
+        .stdout(predicate::str::contains("Description:").not());
+}
+
+#[test]
+fn note_invalid_kind_fails() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "NoteKind Invalid task");
+
+    wk().args(["note", &id, "--kind", "idea", "Nope"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid note kind"));
+}
+
+#[test]
+fn notes_filters_by_kind() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Notes Filter task");
+
+    wk().args(["note", &id, "Plain note"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["note", &id, "--kind", "blocker", "Waiting on API"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["notes", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Description:"))
+        .stdout(predicate::str::contains("Plain note"))
+        .stdout(predicate::str::contains("Blockers:"));
+
+    wk().args(["notes", &id, "--kind", "blocker"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Waiting on API"))
+        .stdout(predicate::str::contains("Plain note").not());
+
+    wk().args(["notes", &id, "--kind", "handoff"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No handoff notes"));
+}
+
+#[test]
+fn notes_json_includes_kind() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Notes Json task");
+
+    wk().args(["note", &id, "--kind", "decision", "Use FTS"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let output = wk()
+        .args(["notes", &id, "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let notes: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(notes[0]["kind"], "decision");
+    assert_eq!(notes[0]["content"], "Use FTS");
+}
+
+#[test]
+fn note_kind_survives_export_and_import() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Notes Export task");
+
+    wk().args(["note", &id, "--kind", "handoff", "Docs left"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["export", "out.jsonl"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let exported = std::fs::read_to_string(temp.path().join("out.jsonl")).unwrap();
+    assert!(exported.contains(r#""kind":"handoff""#));
+
+    let other = init_temp();
+    wk().args(["import", temp.path().join("out.jsonl").to_str().unwrap()])
+        .current_dir(other.path())
+        .assert()
+        .success();
+    wk().args(["notes", &id, "--kind", "handoff"])
+        .current_dir(other.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Docs left"));
+}
+
+// =============================================================================
+// Editing, Deleting and Restoring Notes
+// =============================================================================
+
+/// The ID of the issue's only note.
+fn note_id(temp: &TempDir, id: &str) -> String {
+    let output = wk()
+        .args(["notes", id, "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let notes: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    notes[0]["id"].to_string()
+}
+
+#[test]
+fn notes_lists_note_ids() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Notes Ids task");
+    wk().args(["note", &id, "Numbered"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    let note = note_id(&temp, &id);
+
+    wk().args(["notes", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("#{note}")));
+}
+
+#[test]
+fn note_edit_changes_content_and_logs_it() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Note Edit task");
+    wk().args(["note", &id, "Tried cahcing"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    let note = note_id(&temp, &id);
+
+    wk().args(["note", &id, "--edit", &note, "Tried caching"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Edited note #{note}")));
+
+    wk().args(["notes", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Tried caching"))
+        .stdout(predicate::str::contains("cahcing").not());
+    wk().args(["log", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("noted \"Tried caching\""));
+}
+
+#[test]
+fn note_edit_unknown_note_fails() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Note Edit Unknown task");
+
+    wk().args(["note", &id, "--edit", "999", "Text"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("note 999 not found"));
+}
+
+#[test]
+fn note_delete_hides_note_until_restored() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Note Delete task");
+    wk().args(["note", &id, "Pasted a secret"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    let note = note_id(&temp, &id);
+
+    wk().args(["note", &id, "--delete", &note])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Deleted note #{note}")));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unnoted"))
+        .stdout(predicate::str::contains("Description:").not());
+    wk().args(["notes", &id, "--deleted"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pasted a secret"));
+
+    wk().args(["note", &id, "--restore", &note])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Restored note #{note}")));
+
+    wk().args(["notes", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pasted a secret"));
+    wk().args(["notes", &id, "--deleted"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No deleted notes"));
+}
+
+#[test]
+fn note_delete_takes_no_content() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Note Delete Content task");
+
+    wk().args(["note", &id, "--delete", "1", "text"])
+        .current_dir(temp.path())
+        .assert()
+        .failure();
+}
+
+#[test]
+fn deleted_note_is_not_exported() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Note Export Deleted task");
+    wk().args(["note", &id, "Kept"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["note", &id, "Dropped"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    let output = wk()
+        .args(["notes", &id, "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let notes: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let dropped = notes[1]["id"].to_string();
+    wk().args(["note", &id, "--delete", &dropped])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let output = wk()
+        .args(["export", "-o", "ndjson"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let record: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let exported: Vec<&str> = record["notes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["content"].as_str().unwrap())
+        .collect();
+    assert_eq!(exported, vec!["Kept"]);
+}
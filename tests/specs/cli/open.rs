@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok open`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn link(temp: &TempDir, id: &str, url: &str) {
+    wk().args(["link", id, url]).current_dir(temp.path()).assert().success();
+}
+
+fn open(temp: &TempDir, args: &[&str]) -> assert_cmd::assert::Assert {
+    wk().arg("open").args(args).current_dir(temp.path()).assert()
+}
+
+#[test]
+fn print_shows_the_only_link() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Linked");
+    link(&temp, &id, "https://github.com/org/repo/issues/1");
+
+    open(&temp, &[&id, "--print"]).success().stdout("https://github.com/org/repo/issues/1\n");
+}
+
+#[test]
+fn several_links_need_a_choice() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Linked twice");
+    link(&temp, &id, "https://github.com/org/repo/issues/1");
+    link(&temp, &id, "https://acme.atlassian.net/browse/PE-1");
+
+    open(&temp, &[&id, "--print"])
+        .code(2)
+        .stderr(predicate::str::contains("has 2 links to open"))
+        .stderr(predicate::str::contains("--link <type>"));
+    open(&temp, &[&id, "--print", "--link", "jira"])
+        .success()
+        .stdout("https://acme.atlassian.net/browse/PE-1\n");
+    open(&temp, &[&id, "--print", "--all"])
+        .success()
+        .stdout("https://github.com/org/repo/issues/1\nhttps://acme.atlassian.net/browse/PE-1\n");
+}
+
+#[test]
+fn templates_cover_shorthand_and_unlinked_issues() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Shorthand");
+    link(&temp, &id, "jira://PE-5555");
+
+    open(&temp, &[&id, "--print"]).failure().stderr(predicate::str::contains("[links.jira]"));
+
+    let config = temp.path().join(".wok/config.toml");
+    let mut content = std::fs::read_to_string(&config).unwrap();
+    content.push_str("\n[open]\nurl = \"https://wok.example.com/{id}\"\n\n[links.jira]\nurl = \"https://acme.atlassian.net/browse/{id}\"\n");
+    std::fs::write(&config, content).unwrap();
+
+    open(&temp, &[&id, "--print"]).success().stdout("https://acme.atlassian.net/browse/PE-5555\n");
+    open(&temp, &[&id, "--print", "--web"])
+        .success()
+        .stdout(format!("https://wok.example.com/{}\n", id));
+
+    let bare = create_issue(&temp, "No links");
+    open(&temp, &[&bare, "--print"])
+        .success()
+        .stdout(format!("https://wok.example.com/{}\n", bare));
+}
+
+#[test]
+fn configured_providers_type_their_links() {
+    let temp = init_temp();
+    let config = temp.path().join(".wok/config.toml");
+    let mut content = std::fs::read_to_string(&config).unwrap();
+    content.push_str("\n[links.linear]\nurl = \"https://linear.app/acme/issue/{id}\"\n");
+    std::fs::write(&config, content).unwrap();
+
+    let id = create_issue(&temp, "Tracked in Linear");
+    link(&temp, &id, "https://github.com/org/repo/issues/1");
+    link(&temp, &id, "linear://ENG-12");
+
+    open(&temp, &[&id, "--print", "--link", "linear"])
+        .success()
+        .stdout("https://linear.app/acme/issue/ENG-12\n");
+    open(&temp, &[&id, "--print", "--link", "trello"]).code(2);
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("linear"));
+}
+
+#[test]
+fn browser_receives_the_url() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Linked");
+    link(&temp, &id, "https://github.com/org/repo/issues/1");
+    let log = temp.path().join("opened.txt");
+
+    wk().args(["open", &id])
+        .env("BROWSER", format!("echo >{}", log.display()))
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("Opened https://github.com/org/repo/issues/1\n");
+    assert_eq!(std::fs::read_to_string(&log).unwrap(), "https://github.com/org/repo/issues/1\n");
+}
+
+#[test]
+fn issue_without_links_fails() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Lonely");
+    open(&temp, &[&id, "--print"]).code(3).stderr(predicate::str::contains("has no link to open"));
+}
+
+#[test]
+fn hyperlinks_point_ids_at_their_page() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "Clickable");
+    let config = temp.path().join(".wok/config.toml");
+    let mut content = std::fs::read_to_string(&config).unwrap();
+    content.push_str(
+        "\n[hyperlinks]\nmode = \"always\"\nurl = \"http://127.0.0.1:8080/issue/{id}\"\n",
+    );
+    std::fs::write(&config, content).unwrap();
+    let linked =
+        format!("\x1b]8;;http://127.0.0.1:8080/issue/{id}\x1b\\{id}\x1b]8;;\x1b\\", id = id);
+
+    wk().arg("list")
+        .env_remove("FORCE_HYPERLINK")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(linked.as_str()));
+    wk().args(["show", &id])
+        .env_remove("FORCE_HYPERLINK")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(linked.as_str()));
+    wk().arg("list")
+        .env("FORCE_HYPERLINK", "0")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b]8;;").not());
+}
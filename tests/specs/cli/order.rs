@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `--order topo` on `wok ready` and `wok list`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Creates a lone issue and a blocker of a two-issue chain, oldest first.
+fn chain(temp: &TempDir) -> (String, String) {
+    let lone = create_issue(temp, "Lone task");
+    let root = create_issue(temp, "Root blocker");
+    let middle = create_issue(temp, "Middle");
+    let leaf = create_issue(temp, "Leaf");
+    wk().args(["dep", &root, "blocks", &middle]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &middle, "blocks", &leaf]).current_dir(temp.path()).assert().success();
+    (lone, root)
+}
+
+#[test]
+fn ready_topo_puts_biggest_unblocker_first() {
+    let temp = init_temp();
+    let (lone, root) = chain(&temp);
+
+    wk().args(["ready", "--order", "topo", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n{}\n", root, lone));
+    wk().args(["ready", "--order", "topo"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Root blocker (unblocks 2 issues)"));
+}
+
+#[test]
+fn ready_topo_json_includes_unblocks() {
+    let temp = init_temp();
+    let (_, root) = chain(&temp);
+
+    let output = wk()
+        .args(["ready", "--order", "topo", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let first = &json[0];
+    assert_eq!(first["id"], root.as_str());
+    assert_eq!(first["unblocks"], 2);
+    assert_eq!(json[1]["unblocks"], 0);
+}
+
+#[test]
+fn list_topo_orders_blockers_before_blocked() {
+    let temp = init_temp();
+    let (_, root) = chain(&temp);
+
+    let output = wk()
+        .args(["list", "--order", "topo", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let ids = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    assert!(ids.starts_with(&root), "expected {} first in {}", root, ids);
+}
+
+#[test]
+fn default_order_omits_unblocks() {
+    let temp = init_temp();
+    chain(&temp);
+
+    wk().args(["ready"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unblocks").not());
+}
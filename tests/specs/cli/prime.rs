@@ -27,6 +27,8 @@
 //!   -> ignores_extra_arguments_or_errors
 //! - "prime works from subdirectory"
 //!   -> works_from_subdirectory
+//!
+//! Profile and template override tests have no BATS equivalent.
 
 #![allow(clippy::panic)]
 #![allow(clippy::unwrap_used)]
@@ -146,7 +148,7 @@ fn output_is_valid_markdown() {
     // Check code blocks are properly opened and closed (count must be even)
     let count = stdout.matches("```").count();
     assert!(
-        count % 2 == 0,
+        count.is_multiple_of(2),
         "Unbalanced code blocks: {} (should be even)",
         count
     );
@@ -213,3 +215,96 @@ fn works_from_subdirectory() {
         .success()
         .stdout(predicate::str::contains("## Core Rules"));
 }
+
+// =============================================================================
+// Profile Tests
+// =============================================================================
+
+#[parameterized(
+    claude = { "claude" },
+    cursor = { "cursor" },
+    generic = { "generic" },
+)]
+fn builtin_profile_outputs_template(profile: &str) {
+    let temp = TempDir::new().unwrap();
+
+    wk().args(["prime", "--profile", profile])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## Core Rules"))
+        .stdout(predicate::str::contains("## Finding Work"));
+}
+
+#[test]
+fn unknown_profile_fails_with_hint() {
+    let temp = TempDir::new().unwrap();
+
+    wk().args(["prime", "--profile", "nope"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown prime profile 'nope'"))
+        .stderr(predicate::str::contains("claude, cursor, generic"));
+}
+
+#[test]
+fn examples_use_project_prefix() {
+    let temp = init_temp();
+
+    wk().arg("prime")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--blocks test-42"));
+}
+
+#[test]
+fn project_template_overrides_builtin() {
+    let temp = init_temp();
+    let prime_dir = temp.path().join(".wok/prime");
+    std::fs::create_dir_all(&prime_dir).unwrap();
+    std::fs::write(
+        prime_dir.join("claude.md"),
+        "# Our Tracker\nStart with {{prefix}}-1\n",
+    )
+    .unwrap();
+
+    wk().arg("prime")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("# Our Tracker\nStart with test-1\n");
+}
+
+#[test]
+fn project_template_defines_custom_profile() {
+    let temp = init_temp();
+    let prime_dir = temp.path().join(".wok/prime");
+    std::fs::create_dir_all(&prime_dir).unwrap();
+    std::fs::write(prime_dir.join("review.md"), "# Reviewer\n").unwrap();
+
+    wk().args(["prime", "--profile", "review"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("# Reviewer\n");
+}
+
+#[test]
+fn workflow_policy_is_included() {
+    let temp = init_temp();
+    let prime_dir = temp.path().join(".wok/prime");
+    std::fs::create_dir_all(&prime_dir).unwrap();
+    std::fs::write(
+        prime_dir.join("workflow.md"),
+        "## Team Workflow\n- Pair on bugs\n",
+    )
+    .unwrap();
+
+    wk().args(["prime", "--profile", "generic"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## Team Workflow\n- Pair on bugs"));
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok queue` commands.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_queued(temp: &TempDir, title: &str, queue: &str) -> String {
+    let output = wk()
+        .args(["new", "task", title, "-a", queue, "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn take_assigns_oldest_issue_to_caller() {
+    let temp = init_temp();
+    let first = create_queued(&temp, "First", "queue:merge");
+    let second = create_queued(&temp, "Second", "queue:merge");
+
+    wk().args(["queue", "take", "merge", "-a", "alice"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Took {} from queue:merge: First\n", first));
+    wk().args(["queue", "take", "merge", "-a", "bob", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", second));
+    wk().args(["queue", "take", "merge", "-a", "carol"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no ready issues in queue:merge"));
+
+    wk().args(["list", "-a", "alice"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&first));
+}
+
+#[test]
+fn take_without_user_fails() {
+    let temp = init_temp();
+    create_queued(&temp, "Queued", "queue:merge");
+
+    wk().args(["queue", "take", "merge"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no current user for @me"));
+}
+
+#[test]
+fn list_shows_depth_per_queue() {
+    let temp = init_temp();
+    create_queued(&temp, "Merge one", "queue:merge");
+    create_queued(&temp, "Merge two", "queue:merge");
+    create_queued(&temp, "Review one", "queue:review");
+
+    wk().args(["queue", "list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("queue:merge   2 (2 ready)\nqueue:review  1 (1 ready)\n");
+}
+
+#[test]
+fn list_with_no_queues() {
+    let temp = init_temp();
+
+    wk().args(["queue", "list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No queued issues\n");
+}
@@ -511,3 +511,106 @@ fn ready_auto_filters_by_configured_project_prefix() {
         .stdout(predicate::str::contains("AutoReady Own task"))
         .stdout(predicate::str::contains("AutoReady Other task").not());
 }
+
+#[test]
+fn ready_explain_lists_reasons_for_excluded_issues() {
+    let temp = init_temp();
+    let blocker = create_issue(&temp, "task", "Blocker task");
+    let blocked = create_issue(&temp, "task", "Blocked task");
+    let started = create_issue(&temp, "task", "Started task");
+    let theirs = create_issue(&temp, "task", "Their task");
+
+    wk().args(["dep", &blocker, "blocks", &blocked])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["start", &started])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["assign", &theirs, "bob"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["ready", "--explain"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Not ready:"))
+        .stdout(predicate::str::contains(format!("blocked by {}", blocker)))
+        .stdout(predicate::str::contains("status is in_progress"))
+        .stdout(predicate::str::contains("assigned to bob"));
+
+    wk().args(["ready"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Not ready:").not());
+}
+
+#[test]
+fn ready_explain_json_has_ready_and_excluded() {
+    let temp = init_temp();
+    let blocker = create_issue(&temp, "task", "Blocker task");
+    let blocked = create_issue(&temp, "task", "Blocked task");
+    wk().args(["dep", &blocker, "blocks", &blocked])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let output = wk()
+        .args(["ready", "--explain", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["ready"][0]["id"], blocker.as_str());
+    assert_eq!(json["excluded"][0]["id"], blocked.as_str());
+    assert_eq!(json["excluded"][0]["reasons"][0]["reason"], "blocked");
+    assert_eq!(json["excluded"][0]["reasons"][0]["by"][0], blocker.as_str());
+}
+
+#[test]
+fn ready_excludes_issues_with_unresolved_external_blockers() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Waiting on vendor");
+    wk().args(["link", &id, "jira://PE-5555", "--reason", "blocks"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["ready", "--explain"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "blocked by external jira://PE-5555",
+        ));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Blocked by:\n  - [jira] jira://PE-5555\n",
+        ));
+
+    wk().args(["link", "resolve", &id, "jira://PE-5555"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Resolved jira://PE-5555 on {}\n", id));
+
+    wk().arg("ready")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Waiting on vendor"));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Blocked by:").not())
+        .stdout(predicate::str::contains("(blocks, resolved)"));
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok rpc --stdio`, talking to a real process.
+
+#![allow(clippy::unwrap_used)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Stdio};
+
+use serde_json::{json, Value};
+
+use super::common::*;
+
+/// A `wok rpc --stdio` process, killed when dropped.
+struct Server {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl Server {
+    fn start(temp: &TempDir) -> Self {
+        #[allow(deprecated)]
+        let wok = assert_cmd::cargo::cargo_bin("wok");
+        let mut child = std::process::Command::new(wok)
+            .args(["rpc", "--stdio"])
+            .current_dir(temp.path())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        Server { child, stdin, stdout }
+    }
+
+    fn send(&mut self, message: Value) {
+        let body = message.to_string();
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            self.stdout.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length: ") {
+                length = value.parse().unwrap();
+            }
+        }
+        let mut body = vec![0; length];
+        self.stdout.read_exact(&mut body).unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    /// Send a request and read messages until its response.
+    fn request(&mut self, id: i64, method: &str, params: Value) -> Value {
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        loop {
+            let message = self.receive();
+            if message["id"] == id {
+                return message;
+            }
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn rpc_answers_queries_and_mutations() {
+    let temp = init_temp();
+    let output = wk()
+        .args(["new", "task", "Wire up", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let mut server = Server::start(&temp);
+
+    let init = server.request(1, "initialize", json!({}));
+    assert_eq!(init["result"]["serverInfo"]["name"], "wok");
+
+    let issue = server.request(2, "wok/query", json!({ "op": "GetIssue", "id": id }));
+    assert_eq!(issue["result"]["issue"]["title"], "Wire up");
+
+    let label = json!({ "op": "AddLabel", "id": id, "label": "editor" });
+    assert_eq!(server.request(3, "wok/mutate", label)["result"]["result"], "Ok");
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .stdout(predicate::str::contains("editor"));
+
+    let missing = server.request(4, "wok/query", json!({ "op": "GetIssue", "id": "test-none" }));
+    assert_eq!(missing["error"]["data"]["code"], "not_found");
+}
+
+#[test]
+fn rpc_notifies_changes_from_other_processes() {
+    let temp = init_temp();
+    let mut server = Server::start(&temp);
+    server.request(1, "initialize", json!({}));
+
+    wk().args(["new", "task", "From the CLI"]).current_dir(temp.path()).assert().success();
+
+    let notification = server.receive();
+    assert_eq!(notification["method"], "wok/didChange");
+    assert_eq!(notification["params"]["events"][0]["action"], "created");
+}
+
+#[test]
+fn rpc_exits_after_shutdown() {
+    let temp = init_temp();
+    let mut server = Server::start(&temp);
+
+    assert_eq!(server.request(1, "shutdown", Value::Null)["result"], Value::Null);
+    server.send(json!({ "jsonrpc": "2.0", "method": "exit" }));
+
+    assert!(server.child.wait().unwrap().success());
+}
+
+#[test]
+fn rpc_requires_stdio() {
+    let temp = init_temp();
+    wk().arg("rpc")
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--stdio"));
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for automation rules (`.wok/rules.toml`, `wok config rules`).
+
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, args: &[&str]) -> String {
+    let output =
+        wk().arg("new").args(args).args(["-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn write_rules(temp: &TempDir) {
+    std::fs::write(
+        temp.path().join(".wok/rules.toml"),
+        r#"
+[[rules]]
+name = "changelog-on-release"
+events = ["issue.done"]
+filter = "-l release:*"
+add_labels = ["changelog"]
+"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn rule_adds_label_on_matching_event() {
+    let temp = init_temp();
+    write_rules(&temp);
+    let id = create_issue(&temp, &["task", "Ship it", "-l", "release:1.0"]);
+
+    wk().args(["done", &id, "--reason", "shipped"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("changelog"));
+}
+
+#[test]
+fn rule_ignores_non_matching_issue() {
+    let temp = init_temp();
+    write_rules(&temp);
+    let id = create_issue(&temp, &["task", "Ship it"]);
+
+    wk().args(["done", &id, "--reason", "shipped"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("changelog").not());
+}
+
+#[test]
+fn rules_test_traces_without_applying() {
+    let temp = init_temp();
+    write_rules(&temp);
+    let id = create_issue(&temp, &["task", "Ship it", "-l", "release:1.0"]);
+
+    wk().args(["config", "rules", "test", &id, "--event", "done"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("changelog-on-release: +changelog (on issue.done)"));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("changelog").not());
+}
+
+#[test]
+fn rules_list_without_config() {
+    let temp = init_temp();
+    wk().args(["config", "rules", "list"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No rules configured."));
+}
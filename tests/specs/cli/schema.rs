@@ -131,6 +131,14 @@ fn schema_search_is_array_type() {
     show = { "show" },
     ready = { "ready" },
     search = { "search" },
+    page = { "page" },
+    activity = { "activity" },
+    digest = { "digest" },
+    standup = { "standup" },
+    log = { "log" },
+    export = { "export" },
+    hook = { "hook" },
+    rpc = { "rpc" },
 )]
 fn schema_subcommand_has_schema_field(subcmd: &str) {
     wk().args(["schema", subcmd])
@@ -139,6 +147,25 @@ fn schema_subcommand_has_schema_field(subcmd: &str) {
         .stdout(predicate::str::contains(r#""$schema""#));
 }
 
+#[test]
+fn schema_all_keys_schemas_by_name() {
+    let output = wk().args(["schema", "all"]).output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["hook"]["title"], "HookPayload");
+    assert!(json["list"]["$schema"].is_string());
+}
+
+#[test]
+fn schema_all_bundle_is_one_document() {
+    let output = wk().args(["schema", "all", "--bundle"]).output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json["$schema"].is_string());
+    assert!(json["properties"]["export"].is_object());
+    assert!(json["definitions"]["IssueJson"].is_object());
+}
+
 // =============================================================================
 // Help Tests
 // =============================================================================
@@ -176,7 +203,8 @@ fn schema_list_includes_issue_type_enum_values() {
         .stdout(predicate::str::contains(r#""task""#))
         .stdout(predicate::str::contains(r#""bug""#))
         .stdout(predicate::str::contains(r#""chore""#))
-        .stdout(predicate::str::contains(r#""idea""#));
+        .stdout(predicate::str::contains(r#""idea""#))
+        .stdout(predicate::str::contains(r#""epic""#));
 }
 
 #[test]
@@ -285,6 +285,26 @@ fn search_output_json_short_flag() {
         serde_json::from_str(&stdout).expect("Output should be valid JSON");
 }
 
+#[test]
+fn search_output_json_paged() {
+    let temp = init_temp();
+    create_issue(&temp, "task", "Paged match one");
+    create_issue(&temp, "task", "Paged match two");
+    create_issue(&temp, "task", "Unrelated");
+
+    let output = wk()
+        .args(["search", "Paged match", "-o", "json-paged", "--page-size", "1"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["issues"][0]["title"], "Paged match one");
+    assert_eq!(json["issues"].as_array().unwrap().len(), 1);
+    assert!(json["next_cursor"].is_string());
+}
+
 // =============================================================================
 // Help Tests
 // =============================================================================
@@ -364,9 +384,13 @@ fn search_under_limit_no_more_message() {
 #[test]
 fn search_filter_age() {
     let temp = init_temp();
-    create_issue(&temp, "task", "SearchAge Old");
-    std::thread::sleep(std::time::Duration::from_millis(200));
-    create_issue(&temp, "task", "SearchAge New");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "SearchAge Old" created=-1h
+        issue test-new task "SearchAge New"
+        "#,
+    );
 
     wk().args(["search", "SearchAge", "--filter", "age < 100ms"])
         .current_dir(temp.path())
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok serve`, over a real socket on a free port.
+
+#![allow(clippy::unwrap_used)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Stdio};
+
+use super::common::*;
+
+/// A `wok serve` process, killed when dropped.
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start(temp: &TempDir) -> Self {
+        #[allow(deprecated)]
+        let wok = assert_cmd::cargo::cargo_bin("wok");
+        let mut child = std::process::Command::new(wok)
+            .args(["serve", "--port", "0"])
+            .current_dir(temp.path())
+            .env_remove("LC_ALL")
+            .env_remove("LC_MESSAGES")
+            .env_remove("LANG")
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        let addr = line
+            .split_whitespace()
+            .find_map(|word| word.strip_prefix("http://"))
+            .unwrap()
+            .to_string();
+        Server { child, addr }
+    }
+
+    fn request(&self, method: &str, path: &str) -> String {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(stream, "{} {} HTTP/1.1\r\nHost: {}\r\n\r\n", method, path, self.addr).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn serve_renders_pages_from_the_database() {
+    let temp = init_temp();
+    let blocker = create_issue(&temp, "Design the API");
+    let blocked = create_issue(&temp, "Ship the API");
+    wk().args(["dep", &blocker, "blocks", &blocked]).current_dir(temp.path()).assert().success();
+    let server = Server::start(&temp);
+
+    let list = server.request("GET", "/");
+    assert!(list.starts_with("HTTP/1.1 200 OK\r\n"), "{list}");
+    assert!(list.contains("Design the API"));
+
+    let detail = server.request("GET", &format!("/issue/{}", blocked));
+    assert!(detail.contains("Blocked by"));
+    assert!(detail.contains(&format!("<a href=\"/issue/{0}\">{0}</a>", blocker)));
+
+    assert!(server.request("GET", "/graph").contains("<svg"));
+    assert!(server.request("GET", "/board").contains("in_progress"));
+
+    // Pages read the database when loaded
+    create_issue(&temp, "Added later");
+    assert!(server.request("GET", "/").contains("Added later"));
+}
+
+#[test]
+fn serve_refuses_writes_and_unknown_pages() {
+    let temp = init_temp();
+    let server = Server::start(&temp);
+
+    assert!(server.request("POST", "/").starts_with("HTTP/1.1 405 "));
+    assert!(server.request("GET", "/issue/test-nope").starts_with("HTTP/1.1 404 "));
+    assert!(server.request("GET", "/admin").starts_with("HTTP/1.1 404 "));
+}
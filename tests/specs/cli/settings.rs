@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok config get/set/unset/list/mode` and layered config.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn config(temp: &TempDir, args: &[&str]) -> assert_cmd::assert::Assert {
+    wk().arg("config").args(args).current_dir(temp.path()).assert()
+}
+
+fn create_issues(temp: &TempDir, count: usize) {
+    for i in 0..count {
+        wk().args(["new", "task", &format!("Task {}", i)])
+            .current_dir(temp.path())
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn get_prints_value_and_default() {
+    let temp = init_temp();
+    config(&temp, &["get", "prefix"]).success().stdout("test\n");
+    config(&temp, &["get", "limits.list"]).success().stdout("100\n");
+    config(&temp, &["get", "user"]).success().stdout("");
+}
+
+#[test]
+fn set_then_get_round_trips() {
+    let temp = init_temp();
+    config(&temp, &["set", "wip.limit", "3"]).success().stdout("Set wip.limit = 3\n");
+    config(&temp, &["get", "wip.limit"]).success().stdout("3\n");
+    config(&temp, &["unset", "wip.limit"]).success();
+    config(&temp, &["get", "wip.limit"]).success().stdout("");
+}
+
+#[test]
+fn set_rejects_unknown_keys_and_bad_values() {
+    let temp = init_temp();
+    config(&temp, &["set", "colour", "never"])
+        .failure()
+        .stderr(predicate::str::contains("unknown setting 'colour'"));
+    config(&temp, &["set", "wip.mode", "loud"])
+        .failure()
+        .stderr(predicate::str::contains("'loud' is not one of warn, block"));
+    config(&temp, &["set", "private", "true"]).failure();
+}
+
+#[test]
+fn list_shows_every_setting() {
+    let temp = init_temp();
+    config(&temp, &["list"])
+        .success()
+        .stdout(predicate::str::contains("prefix = test\n"))
+        .stdout(predicate::str::contains("user = (unset)\n"))
+        .stdout(predicate::str::contains("limits.log = 20\n"));
+
+    let output =
+        wk().args(["config", "list", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let prefix = json.as_array().unwrap().iter().find(|s| s["key"] == "prefix").unwrap();
+    assert_eq!(prefix["value"], "test");
+}
+
+#[test]
+fn limits_change_default_list_size() {
+    let temp = init_temp();
+    create_issues(&temp, 3);
+    config(&temp, &["set", "limits.list", "2"]).success();
+
+    let output = wk().args(["list", "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).split_whitespace().count(), 2);
+
+    // An explicit --limit still wins.
+    let output =
+        wk().args(["list", "-o", "id", "--limit", "3"]).current_dir(temp.path()).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).split_whitespace().count(), 3);
+}
+
+#[test]
+fn zero_log_limit_shows_everything() {
+    let temp = init_temp();
+    create_issues(&temp, 3);
+    config(&temp, &["set", "limits.log", "0"]).success();
+
+    let output = wk().arg("log").current_dir(temp.path()).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).lines().count(), 3);
+}
+
+#[test]
+fn color_setting_forces_color() {
+    let temp = init_temp();
+    config(&temp, &["set", "color", "always"]).success();
+
+    wk().args(["list", "--help"])
+        .env_remove("NO_COLOR")
+        .env_remove("COLOR")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b["));
+    wk().args(["list", "--help"])
+        .env("NO_COLOR", "1")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn user_config_applies_beneath_project() {
+    let temp = init_temp();
+    let home = TempDir::new().unwrap();
+    let wk_in = |args: &[&str]| {
+        let mut cmd = wk();
+        cmd.arg("config").args(args).env("XDG_CONFIG_HOME", home.path()).current_dir(temp.path());
+        cmd
+    };
+
+    wk_in(&["set", "--user", "user", "alice"]).assert().success();
+    wk_in(&["set", "--user", "wip.limit", "4"]).assert().success();
+    wk_in(&["set", "wip.limit", "2"]).assert().success();
+    assert!(home.path().join("wok/config.toml").exists());
+
+    wk_in(&["get", "user"]).assert().success().stdout("alice\n");
+    wk_in(&["get", "wip.limit"]).assert().success().stdout("2\n");
+    wk_in(&["set", "--user", "prefix", "usr"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("it can only be set for a project"));
+}
+
+#[test]
+fn env_overrides_are_resolved_last() {
+    let temp = init_temp();
+    config(&temp, &["set", "limits.list", "50"]).success();
+
+    wk().args(["config", "get", "limits.list"])
+        .env("WOK_LIMITS_LIST", "7")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("7\n");
+    wk().args(["list"])
+        .env("WOK_LIMITS_LIST", "many")
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value for WOK_LIMITS_LIST"));
+}
+
+#[test]
+fn show_origin_names_each_layer() {
+    let temp = init_temp();
+    let home = TempDir::new().unwrap();
+    std::fs::create_dir_all(home.path().join("wok")).unwrap();
+    std::fs::write(home.path().join("wok/config.toml"), "user = \"alice\"\n").unwrap();
+
+    wk().args(["config", "show", "--origin"])
+        .env("XDG_CONFIG_HOME", home.path())
+        .env("WOK_COLOR", "never")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("prefix = test  (project: "))
+        .stdout(predicate::str::contains("user = alice  (user: "))
+        .stdout(predicate::str::contains("color = never  (env: WOK_COLOR)\n"))
+        .stdout(predicate::str::contains("limits.log = 20  (default)\n"));
+
+    let output = wk()
+        .args(["config", "show", "--origin", "-o", "json"])
+        .env("XDG_CONFIG_HOME", home.path())
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let user = json.as_array().unwrap().iter().find(|s| s["key"] == "user").unwrap();
+    assert_eq!(user["origin"], "user");
+}
+
+#[test]
+fn mode_moves_issues_between_databases() {
+    let temp = init_temp();
+    let state = TempDir::new().unwrap();
+    let wk_in = |args: &[&str]| {
+        let mut cmd = wk();
+        cmd.args(args).env("WOK_STATE_DIR", state.path()).current_dir(temp.path());
+        cmd.assert()
+    };
+    let output = wk_in(&["new", "task", "Carry me", "-l", "moved", "-o", "id"]).success();
+    let id = String::from_utf8_lossy(&output.get_output().stdout).trim().to_string();
+
+    wk_in(&["config", "mode", "shared"])
+        .success()
+        .stdout(predicate::str::contains("Switched to shared mode"))
+        .stdout(predicate::str::contains("copied: 1"));
+    assert!(state.path().join("issues.db").exists());
+    wk_in(&["config", "get", "private"]).success().stdout("false\n");
+    wk_in(&["list", "--label", "moved", "-o", "id"])
+        .success()
+        .stdout(predicate::str::contains(id.as_str()));
+    wk_in(&["config", "mode", "shared"]).success().stdout("Already in shared mode\n");
+
+    // The private database was left in place, so nothing new is copied back.
+    wk_in(&["config", "mode", "private"])
+        .success()
+        .stdout(predicate::str::contains("already present: 1"));
+    wk_in(&["config", "get", "private"]).success().stdout("true\n");
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for per-prefix short ID aliases (`test-1`).
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn list_shows_short_ids() {
+    let temp = init_temp();
+    let first = create_issue(&temp, "First");
+    let second = create_issue(&temp, "Second");
+
+    wk().arg("list")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{} (test-1): First", first)))
+        .stdout(predicate::str::contains(format!("{} (test-2): Second", second)));
+
+    let output = wk().args(["list", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let short_ids: Vec<&str> =
+        json.as_array().unwrap().iter().map(|i| i["short_id"].as_str().unwrap()).collect();
+    assert!(short_ids.contains(&"test-1") && short_ids.contains(&"test-2"));
+}
+
+#[test]
+fn commands_accept_short_ids() {
+    let temp = init_temp();
+    let first = create_issue(&temp, "First");
+    let second = create_issue(&temp, "Second");
+
+    wk().args(["start", "test-2"]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", "test-1", "blocks", "test-2"]).current_dir(temp.path()).assert().success();
+
+    let output =
+        wk().args(["show", "test-2", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["id"], second.as_str());
+    assert_eq!(json["status"], "in_progress");
+    assert_eq!(json["blockers"][0]["id"], first.as_str());
+}
+
+#[test]
+fn unknown_short_id_is_not_found() {
+    let temp = init_temp();
+    create_issue(&temp, "Only");
+
+    wk().args(["show", "test-999999"]).current_dir(temp.path()).assert().failure();
+}
@@ -140,6 +140,24 @@ fn show_displays_blocked_by() {
         .stdout(predicate::str::contains(&a));
 }
 
+#[test]
+fn show_displays_transitive_blocker_chain() {
+    let temp = init_temp();
+    let root = create_issue(&temp, "task", "Root blocker");
+    let mid = create_issue(&temp, "task", "Middle blocker");
+    let target = create_issue(&temp, "task", "Target");
+
+    wk().args(["dep", &root, "blocks", &mid]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &mid, "blocks", &target]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &target]).current_dir(temp.path()).assert().success().stdout(
+        predicate::str::contains(format!(
+        "Blocked by:\n  - {}: Middle blocker [todo]\n    - {}: Root blocker [todo] ← actionable",
+        mid, root
+    )),
+    );
+}
+
 #[test]
 fn show_displays_blocks() {
     let temp = init_temp();
@@ -156,6 +174,71 @@ fn show_displays_blocks() {
         .stdout(predicate::str::contains(&b));
 }
 
+#[test]
+fn show_displays_referenced_by_for_mentions() {
+    let temp = init_temp();
+    let target = create_issue(&temp, "bug", "ShowRef Target");
+    let other = create_issue(&temp, "task", "ShowRef Mentioner");
+
+    wk().args(["note", &other, &format!("same crash as {}", target)])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["show", &target])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Referenced by:\n  - {}", other)));
+    // A mention is not a dependency
+    wk().args(["ready", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&other));
+}
+
+#[test]
+fn show_displays_related_and_duplicates() {
+    let temp = init_temp();
+    let orig = create_issue(&temp, "bug", "ShowDup Original");
+    let copy = create_issue(&temp, "bug", "ShowDup Copy");
+    let near = create_issue(&temp, "task", "ShowDup Neighbor");
+
+    wk().args(["dep", &copy, "duplicate-of", &orig])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{} is a duplicate of {}", copy, orig)));
+    wk().args(["dep", &orig, "relates-to", &near]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &orig])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Related:\n  - {}", near)))
+        .stdout(predicate::str::contains(format!("Duplicates:\n  - {}", copy)));
+    wk().args(["show", &near])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Related:\n  - {}", orig)));
+    wk().args(["show", &copy])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Duplicate of:\n  - {}", orig)))
+        .stdout(predicate::str::contains(format!("duplicated {}", orig)));
+    // Neither relation blocks
+    wk().args(["ready", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&orig))
+        .stdout(predicate::str::contains(&copy))
+        .stdout(predicate::str::contains(&near));
+}
+
 #[test]
 fn show_displays_tracked_by() {
     let temp = init_temp();
@@ -269,7 +352,7 @@ fn show_json_single_issue_compact() {
 }
 
 #[test]
-fn show_json_multiple_issues_jsonl() {
+fn show_json_multiple_issues_array() {
     let temp = init_temp();
     let id1 = create_issue(&temp, "task", "First issue");
     let id2 = create_issue(&temp, "task", "Second issue");
@@ -278,14 +361,168 @@ fn show_json_multiple_issues_jsonl() {
         wk().args(["show", &id1, &id2, "-o", "json"]).current_dir(temp.path()).output().unwrap();
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let lines: Vec<&str> = stdout.trim().lines().collect();
+    assert_eq!(stdout.trim().lines().count(), 1);
 
-    // Two lines (one per issue)
-    assert_eq!(lines.len(), 2);
+    // One array, in argument order
+    let json: serde_json::Value = serde_json::from_str(stdout.trim()).unwrap();
+    let issues = json.as_array().expect("Output should be a JSON array");
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0]["id"], id1.as_str());
+    assert_eq!(issues[1]["id"], id2.as_str());
+}
 
-    // Each line is valid JSON
-    for line in lines {
-        let _: serde_json::Value =
-            serde_json::from_str(line).expect("Each line should be valid JSON");
-    }
+#[test]
+fn show_json_full_detail() {
+    let temp = init_temp();
+    let blocker = create_issue(&temp, "task", "Blocker issue");
+    let id = create_issue(&temp, "task", "Detailed issue");
+    wk().args(["dep", &blocker, "blocks", &id]).current_dir(temp.path()).assert().success();
+    wk().args(["note", &id, "Before starting"]).current_dir(temp.path()).assert().success();
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+    wk().args(["note", &id, "While working"]).current_dir(temp.path()).assert().success();
+
+    let output = wk().args(["show", &id, "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(json["schema_version"].is_u64());
+    assert_eq!(json["blockers"][0]["id"], blocker.as_str());
+    assert_eq!(json["blockers"][0]["title"], "Blocker issue");
+    assert_eq!(json["blockers"][0]["status"], "todo");
+    assert_eq!(json["notes"].as_array().unwrap().len(), 2);
+    assert_eq!(json["notes"][0]["status"], "todo");
+    assert_eq!(json["notes"][0]["notes"][0]["content"], "Before starting");
+    assert_eq!(json["notes"][1]["status"], "in_progress");
+    assert!(json["description"].is_null());
+    assert!(json["closed_at"].is_null());
+    assert!(!json["events"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn show_json_closed_issue_has_closed_at() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Closing issue");
+    wk().args(["close", &id, "--reason", "not needed"]).current_dir(temp.path()).assert().success();
+
+    let output = wk().args(["show", &id, "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json["closed_at"].is_string());
+}
+
+// =============================================================================
+// Markdown Rendering
+// =============================================================================
+
+#[test]
+fn show_renders_markdown_when_colors_enabled() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Markdown show task");
+    wk().args(["note", &id, "Plan:\n- use `wok ready`"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["show", &id])
+        .env("COLOR", "1")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("• use \x1b[38;5;250mwok ready\x1b[0m"));
+}
+
+#[test]
+fn show_raw_prints_markdown_as_written() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Markdown raw task");
+    wk().args(["note", &id, "Plan:\n- use `wok ready`"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    wk().args(["show", &id, "--raw"])
+        .env("COLOR", "1")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("- use `wok ready`"));
+}
+
+#[test]
+fn show_without_tty_prints_markdown_as_written() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Markdown pipe task");
+    wk().args(["note", &id, "**bold** move"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &id])
+        .env_remove("COLOR")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("**bold** move"));
+}
+
+#[test]
+fn show_diff_lists_changes_since_point() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Diff task");
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+    wk().args(["label", &id, "urgent"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &id, "--diff", "1h"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Changes to {}", id)))
+        .stdout(predicate::str::contains("status: todo -> in_progress"))
+        .stdout(predicate::str::contains("labels: +urgent"));
+}
+
+#[test]
+fn show_diff_json_lists_changed_fields() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Diff json task");
+    wk().args(["edit", &id, "title", "Renamed task"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &id, "--diff", "1h", "-o", "json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""field":"title""#))
+        .stdout(predicate::str::contains(r#""to":"Renamed task""#));
+}
+
+#[test]
+fn show_as_of_future_shows_current_state() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "As of task");
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+
+    wk().args(["show", &id, "--as-of", "2999-01-01"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Status: in_progress"));
+}
+
+#[test]
+fn show_as_of_before_creation_fails() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Too early task");
+
+    wk().args(["show", &id, "--as-of", "2020-01-01"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("did not exist at 2020-01-01 00:00"));
+}
+
+#[test]
+fn show_as_of_rejects_invalid_time() {
+    let temp = init_temp();
+    let id = create_issue(&temp, "task", "Bad time task");
+
+    wk().args(["show", &id, "--as-of", "yesterday"])
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid timestamp"));
 }
@@ -35,6 +35,15 @@ fn init_temp() -> TempDir {
     temp
 }
 
+/// Load fixture records (see `wk_testing`) into the project's database, so
+/// tests can give issues distinct ages without sleeping between commands.
+fn load_fixture(temp: &TempDir, fixture: &str) {
+    wk_testing::Fixture::parse(fixture)
+        .unwrap()
+        .database_at(&temp.path().join(".wok/issues.db"))
+        .unwrap();
+}
+
 fn create_issue(temp: &TempDir, type_: &str, title: &str) -> String {
     let mut cmd = wk();
     cmd.arg("new").arg(type_).arg(title).arg("-o").arg("id");
@@ -163,9 +172,13 @@ fn list_sorts_by_priority_asc() {
 #[test]
 fn list_same_priority_sorts_by_created_at_desc() {
     let temp = init_temp();
-    create_issue(&temp, "task", "Older list task");
-    std::thread::sleep(std::time::Duration::from_millis(100));
-    create_issue(&temp, "task", "Newer list task");
+    load_fixture(
+        &temp,
+        r#"
+        issue test-old task "Older list task" created=-1m
+        issue test-new task "Newer list task"
+        "#,
+    );
 
     let output = wk().arg("list").current_dir(temp.path()).output().unwrap();
 
@@ -244,3 +257,63 @@ fn json_format_respects_priority_sorting(command: &str) {
         low_idx
     );
 }
+
+// =============================================================================
+// --sort Tests - Parameterized for list, ready, and search
+// =============================================================================
+
+fn json_ids(temp: &TempDir, args: &[&str]) -> Vec<String> {
+    let output = wk()
+        .args(args)
+        .args(["-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    json.as_array()
+        .expect("Output should be an array")
+        .iter()
+        .map(|i| i["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[parameterized(
+    list = { &["list"] },
+    ready = { &["ready"] },
+    search = { &["search", "sortable"] },
+)]
+fn sort_title_orders_alphabetically(command: &[&str]) {
+    let temp = init_temp();
+    let charlie = create_issue(&temp, "task", "charlie sortable");
+    let alpha = create_issue(&temp, "task", "Alpha sortable");
+    let bravo = create_issue(&temp, "task", "bravo sortable");
+
+    let sorted = [command, &["--sort", "title"]].concat();
+    assert_eq!(
+        json_ids(&temp, &sorted),
+        vec![alpha.clone(), bravo.clone(), charlie.clone()]
+    );
+
+    let reversed = [command, &["--sort", "title", "--reverse"]].concat();
+    assert_eq!(json_ids(&temp, &reversed), vec![charlie, bravo, alpha]);
+}
+
+#[test]
+fn sort_due_puts_undated_issues_last() {
+    let temp = init_temp();
+    let undated = create_issue(&temp, "task", "No due date");
+    let later = create_issue(&temp, "task", "Due later");
+    add_label(&temp, &later, "due:2026-06-01");
+    let sooner = create_issue(&temp, "task", "Due sooner");
+    add_label(&temp, &sooner, "due:2026-05-01");
+
+    assert_eq!(
+        json_ids(&temp, &["list", "--sort", "due"]),
+        vec![sooner.clone(), later.clone(), undated.clone()]
+    );
+    assert_eq!(
+        json_ids(&temp, &["list", "--sort", "due", "--reverse"]),
+        vec![later, sooner, undated]
+    );
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok split` command.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create(temp: &TempDir, args: &[&str]) -> String {
+    let output =
+        wk().arg("new").args(args).args(["-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn split_creates_tracked_parts_and_converts_to_epic() {
+    let temp = init_temp();
+    let id = create(&temp, &["task", "Rewrite the importer"]);
+
+    wk().args(["split", &id, "Parse input", "Write output"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Converted {} from task to epic", id)))
+        .stdout(predicate::str::contains("[task]"))
+        .stdout(predicate::str::contains("Parse input"))
+        .stdout(predicate::str::contains("Write output"));
+
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[epic]"))
+        .stdout(predicate::str::contains("Split into:"))
+        .stdout(predicate::str::contains("Parse input"));
+}
+
+#[test]
+fn split_copies_labels_and_assignee_when_asked() {
+    let temp = init_temp();
+    let id = create(&temp, &["feature", "Login", "-l", "auth", "-a", "alice"]);
+
+    let output = wk()
+        .args(["split", &id, "Form", "Session", "--copy-labels", "--copy-assignee", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parts: Vec<&str> = stdout.lines().collect();
+    assert_eq!(parts.len(), 2);
+
+    wk().args(["show", parts[0]])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("auth"))
+        .stdout(predicate::str::contains("alice"))
+        .stdout(predicate::str::contains(&id));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[feature]"));
+}
+
+#[test]
+fn split_requires_titles() {
+    let temp = init_temp();
+    let id = create(&temp, &["task", "Big task"]);
+
+    wk().args(["split", &id]).current_dir(temp.path()).assert().failure();
+}
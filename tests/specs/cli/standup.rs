@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok standup` command.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create_issue(temp: &TempDir, title: &str, assignee: &str) -> String {
+    let output = wk()
+        .args(["new", "task", title, "-a", assignee, "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn run(temp: &TempDir, args: &[&str]) {
+    wk().args(args).current_dir(temp.path()).assert().success();
+}
+
+#[test]
+fn standup_shows_yesterday_today_and_blockers() {
+    let temp = init_temp();
+    let done = create_issue(&temp, "Shipped", "alice");
+    let work = create_issue(&temp, "Underway", "alice");
+    let blocker = create_issue(&temp, "Keys", "bob");
+    run(&temp, &["start", &done]);
+    run(&temp, &["done", &done]);
+    run(&temp, &["start", &work]);
+    run(&temp, &["dep", &blocker, "blocks", &work]);
+
+    wk().args(["standup", "-a", "alice"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("alice\n  Yesterday:\n"))
+        .stdout(predicate::str::contains(format!("    {} Shipped (started, done)\n", done)))
+        .stdout(predicate::str::contains(format!("  Today:\n    {} Underway\n", work)))
+        .stdout(predicate::str::contains(format!(
+            "  Blockers:\n    {} Underway\n      blocked by {} Keys (bob)\n",
+            work, blocker
+        )));
+}
+
+#[test]
+fn standup_defaults_to_the_current_user() {
+    let temp = init_temp();
+    wk().arg("standup")
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no current user"));
+
+    let config = temp.path().join(".wok/config.toml");
+    let mut content = std::fs::read_to_string(&config).unwrap();
+    content.insert_str(0, "user = \"alice\"\n");
+    std::fs::write(&config, content).unwrap();
+
+    wk().arg("standup")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("alice\n"));
+}
+
+#[test]
+fn standup_team_covers_every_assignee_with_work() {
+    let temp = init_temp();
+    let alice = create_issue(&temp, "Alice's", "alice");
+    let bob = create_issue(&temp, "Bob's", "bob");
+    create_issue(&temp, "Carol's backlog", "carol");
+    run(&temp, &["start", &alice]);
+    run(&temp, &["start", &bob]);
+
+    let output =
+        wk().args(["standup", "--team", "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let who: Vec<&str> =
+        json.as_array().unwrap().iter().map(|s| s["assignee"].as_str().unwrap()).collect();
+    assert_eq!(who, ["alice", "bob"]);
+    assert_eq!(json[0]["today"][0]["id"], alice);
+    assert_eq!(json[0]["yesterday"][0]["actions"][0], "started");
+}
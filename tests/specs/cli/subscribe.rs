@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for `wok subscribe`, `wok unsubscribe`, and `wok inbox`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create(temp: &TempDir, title: &str) -> String {
+    let output =
+        wk().args(["new", "task", title, "-o", "id"]).current_dir(temp.path()).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn init_as(user: &str) -> TempDir {
+    let temp = init_temp();
+    wk().args(["config", "set", "user", user]).current_dir(temp.path()).assert().success();
+    temp
+}
+
+#[test]
+fn subscribe_lists_and_unsubscribes() {
+    let temp = init_as("alice");
+    let id = create(&temp, "Followed");
+
+    wk().args(["subscribe", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Subscribed to {}\n", id));
+    wk().arg("subscribe")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Followed"));
+
+    wk().args(["unsubscribe", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("Unsubscribed from {}\n", id));
+    wk().arg("subscribe")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("Not subscribed to any issues\n");
+}
+
+#[test]
+fn inbox_shows_events_since_last_check() {
+    let temp = init_as("alice");
+    let followed = create(&temp, "Followed");
+    let other = create(&temp, "Other");
+    wk().args(["subscribe", &followed]).current_dir(temp.path()).assert().success();
+
+    // Events from before the subscription are not in the inbox
+    wk().arg("inbox")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No new events on subscribed issues\n");
+
+    wk().args(["start", &followed, &other]).current_dir(temp.path()).assert().success();
+
+    wk().args(["inbox", "--peek", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", followed));
+    wk().arg("inbox")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{} started  (Followed)", followed)))
+        .stdout(predicate::str::contains(&other).not());
+
+    // Read events are not shown again
+    wk().arg("inbox")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No new events on subscribed issues\n");
+}
+
+#[test]
+fn inbox_is_kept_per_user() {
+    let temp = init_as("alice");
+    let id = create(&temp, "Shared");
+    wk().args(["subscribe", &id]).current_dir(temp.path()).assert().success();
+    wk().args(["start", &id]).current_dir(temp.path()).assert().success();
+
+    wk().args(["config", "set", "user", "bob"]).current_dir(temp.path()).assert().success();
+    wk().arg("inbox")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No new events on subscribed issues\n");
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok sweep` command.
+//!
+//! Issues created by the specs are fresh, so the stale ones are picked with
+//! filters such as `age < 1d`.
+
+#![allow(clippy::unwrap_used)]
+
+use super::common::*;
+
+fn create(temp: &TempDir, title: &str) -> String {
+    let output = wk()
+        .args(["new", "task", title, "--force", "-o", "id"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn sweep_reports_by_default_policy() {
+    let temp = init_temp();
+    create(&temp, "Fresh task");
+
+    wk().arg("sweep")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("No open issues match activity > 90d\n");
+}
+
+#[test]
+fn sweep_close_dry_run_lists_without_closing() {
+    let temp = init_temp();
+    let id = create(&temp, "Old idea");
+
+    wk().args(["sweep", "-q", "age < 1d", "--close", "--dry-run"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would close 1 issue(s)"))
+        .stdout(predicate::str::contains(&id));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("todo"));
+}
+
+#[test]
+fn sweep_close_closes_with_reason_and_label() {
+    let temp = init_temp();
+    let todo = create(&temp, "Old idea");
+    let started = create(&temp, "Half done");
+    wk().args(["start", &started]).current_dir(temp.path()).assert().success();
+
+    wk().args([
+        "sweep",
+        "-q",
+        "age < 1d",
+        "-s",
+        "todo",
+        "--close",
+        "--reason",
+        "stale",
+        "-l",
+        "sweep:2024q3",
+        "-o",
+        "id",
+    ])
+    .current_dir(temp.path())
+    .assert()
+    .success()
+    .stdout(format!("{}\n", todo));
+
+    wk().args(["show", &todo])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("closed"))
+        .stdout(predicate::str::contains("sweep:2024q3"))
+        .stdout(predicate::str::contains("stale"));
+    wk().args(["show", &started])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("in_progress"));
+}
+
+#[test]
+fn sweep_uses_the_configured_policy() {
+    let temp = init_temp();
+    let id = create(&temp, "Old idea");
+    for (key, value) in [("sweep.filter", "age < 1d"), ("sweep.reason", "aged out")] {
+        wk().args(["config", "set", key, value]).current_dir(temp.path()).assert().success();
+    }
+
+    wk().args(["sweep", "--close"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Closed 1 issue(s) matching age < 1d"))
+        .stdout(predicate::str::contains("reason: aged out"));
+    wk().args(["show", &id])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("aged out"));
+}
+
+#[test]
+fn sweep_reason_requires_close() {
+    let temp = init_temp();
+    wk().args(["sweep", "--reason", "stale"]).current_dir(temp.path()).assert().failure();
+}
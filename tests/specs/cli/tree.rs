@@ -100,6 +100,142 @@ fn tree_with_no_children_shows_just_the_issue() {
         .stdout(predicate::str::contains("Standalone task"));
 }
 
+#[test]
+fn tree_depth_limits_levels() {
+    let temp = init_temp();
+    let feature = create_issue(&temp, "feature", "Feature");
+    let sub = create_issue(&temp, "task", "Subtask");
+    let subsub = create_issue(&temp, "task", "Sub-subtask");
+
+    wk().args(["dep", &feature, "tracks", &sub]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &sub, "tracks", &subsub]).current_dir(temp.path()).assert().success();
+
+    wk().args(["tree", &feature, "--depth", "1"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Subtask"))
+        .stdout(predicate::str::contains("Sub-subtask").not())
+        .stdout(predicate::str::contains("1 more"));
+}
+
+#[test]
+fn tree_rel_picks_edges() {
+    let temp = init_temp();
+    let feature = create_issue(&temp, "feature", "Feature");
+    let tracked = create_issue(&temp, "task", "Tracked task");
+    let blocked = create_issue(&temp, "task", "Blocked task");
+
+    wk().args(["dep", &feature, "tracks", &tracked]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &feature, "blocks", &blocked]).current_dir(temp.path()).assert().success();
+
+    wk().args(["tree", &feature, "--rel", "tracks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Tracked task"))
+        .stdout(predicate::str::contains("Blocked task").not());
+
+    wk().args(["tree", &feature, "--rel", "blocks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Tracked task").not())
+        .stdout(predicate::str::contains("Blocked task"));
+}
+
+#[test]
+fn tree_shows_assignee_badge() {
+    let temp = init_temp();
+    let feature = create_issue(&temp, "feature", "Feature");
+    let task = create_issue(&temp, "task", "Child");
+
+    wk().args(["dep", &feature, "tracks", &task]).current_dir(temp.path()).assert().success();
+    wk().args(["assign", &task, "alice"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["tree", &feature])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Child [@alice]"));
+}
+
+#[test]
+fn tree_json_is_nested() {
+    let temp = init_temp();
+    let feature = create_issue(&temp, "feature", "Feature");
+    let sub = create_issue(&temp, "task", "Subtask");
+    let blocked = create_issue(&temp, "task", "Blocked task");
+
+    wk().args(["dep", &feature, "tracks", &sub]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &sub, "blocks", &blocked]).current_dir(temp.path()).assert().success();
+
+    let output =
+        wk().args(["tree", &feature, "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["id"], feature.as_str());
+    assert!(json.get("relation").is_none());
+    let child = &json["children"][0];
+    assert_eq!(child["id"], sub.as_str());
+    assert_eq!(child["relation"], "tracks");
+    let grandchild = &child["children"][0];
+    assert_eq!(grandchild["id"], blocked.as_str());
+    assert_eq!(grandchild["relation"], "blocks");
+    assert_eq!(grandchild["blocked_by"][0], sub.as_str());
+}
+
+#[test]
+fn tree_json_multiple_ids_is_array() {
+    let temp = init_temp();
+    let a = create_issue(&temp, "task", "A");
+    let b = create_issue(&temp, "task", "B");
+
+    let output =
+        wk().args(["tree", &a, &b, "-o", "json"]).current_dir(temp.path()).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json.as_array().map(Vec::len), Some(2));
+}
+
+#[test]
+fn tree_up_shows_what_an_issue_depends_on() {
+    let temp = init_temp();
+    let feature = create_issue(&temp, "feature", "Parent feature");
+    let task = create_issue(&temp, "task", "The task");
+    let blocker = create_issue(&temp, "task", "Direct blocker");
+    let root_blocker = create_issue(&temp, "task", "Root blocker");
+    let child = create_issue(&temp, "task", "Contained child");
+
+    wk().args(["dep", &feature, "tracks", &task]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &blocker, "blocks", &task]).current_dir(temp.path()).assert().success();
+    wk().args(["dep", &root_blocker, "blocks", &blocker])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    wk().args(["dep", &task, "tracks", &child]).current_dir(temp.path()).assert().success();
+
+    wk().args(["tree", &task, "--up"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Parent feature"))
+        .stdout(predicate::str::contains("Direct blocker"))
+        .stdout(predicate::str::contains("Root blocker"))
+        .stdout(predicate::str::contains("Contained child").not());
+
+    let output = wk()
+        .args(["tree", &task, "--up", "--rel", "blocks", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["children"].as_array().map(Vec::len), Some(1));
+    assert_eq!(json["children"][0]["id"], blocker.as_str());
+    assert_eq!(json["children"][0]["relation"], "blocked-by");
+    assert_eq!(json["children"][0]["children"][0]["id"], root_blocker.as_str());
+}
+
 // =============================================================================
 // Error handling tests
 // =============================================================================
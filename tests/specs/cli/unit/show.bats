@@ -105,17 +105,17 @@ load '../../helpers/common'
     assert_output --partial "Second issue"
 }
 
-@test "show: multiple issues in json mode outputs JSONL" {
+@test "show: multiple issues in json mode outputs an array" {
     id1=$(create_issue task "First issue")
     id2=$(create_issue task "Second issue")
     run "$WK_BIN" show "$id1" "$id2" -o json
     assert_success
-    # Count lines - should be 2 (one per issue)
+    # One compact line holding an array, in argument order
     line_count=$(echo "$output" | wc -l | tr -d ' ')
-    assert_equal "$line_count" "2"
-    # Each line should be valid JSON
-    echo "$output" | head -1 | jq . >/dev/null
-    echo "$output" | tail -1 | jq . >/dev/null
+    assert_equal "$line_count" "1"
+    assert_equal "$(echo "$output" | jq -r 'length')" "2"
+    assert_equal "$(echo "$output" | jq -r '.[0].id')" "$id1"
+    assert_equal "$(echo "$output" | jq -r '.[1].id')" "$id2"
 }
 
 @test "show: single issue json format is compact (JSONL)" {
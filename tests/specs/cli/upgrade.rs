@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust specs for the `wok upgrade` command.
+
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::common::*;
+
+#[test]
+fn upgrade_check_reports_versions_and_schema() {
+    let temp = init_temp();
+    wk().args(["new", "task", "Upgrade me"]).current_dir(temp.path()).assert().success();
+
+    wk().args(["upgrade", "--check"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CLI: v"))
+        .stdout(predicate::str::contains("Daemon: n/a (private mode)"))
+        .stdout(predicate::str::contains("Schema: up to date"));
+}
+
+#[test]
+fn upgrade_current_database_is_noop() {
+    let temp = init_temp();
+    wk().args(["new", "task", "Upgrade me"]).current_dir(temp.path()).assert().success();
+
+    wk().arg("upgrade")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nothing to upgrade."));
+
+    assert!(!temp.path().join(".wok/backups").exists());
+}
+
+#[test]
+fn upgrade_requires_init() {
+    let temp = TempDir::new().unwrap();
+    wk().arg("upgrade")
+        .current_dir(temp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not initialized"));
+}
+
+#[test]
+fn schema_db_version_reports_a_new_database_as_latest() {
+    let temp = init_temp();
+    wk().args(["new", "task", "Version me"]).current_dir(temp.path()).assert().success();
+
+    let output = wk()
+        .args(["schema", "db-version", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json["version"].as_u64().unwrap() > 0);
+    assert_eq!(json["version"], json["latest"]);
+    assert_eq!(json["pending"], serde_json::json!([]));
+
+    wk().args(["schema", "db-version"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Schema version: "))
+        .stdout(predicate::str::contains("Pending").not());
+}
+
+#[test]
+fn schema_db_version_does_not_create_the_database() {
+    let temp = init_temp();
+    let db = temp.path().join(".wok").join("issues.db");
+    if db.exists() {
+        std::fs::remove_file(&db).unwrap();
+    }
+
+    wk().args(["schema", "db-version", "-o", "id"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("0\n");
+    assert!(!db.exists());
+}